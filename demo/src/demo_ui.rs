@@ -11,6 +11,8 @@ use rafx_plugins::pipelines::basic::AntiAliasMethodBasic as AntiAliasMethod;
 #[cfg(feature = "basic-pipeline")]
 use rafx_plugins::pipelines::basic::TonemapperTypeBasic as TonemapperType;
 
+#[cfg(not(feature = "basic-pipeline"))]
+use rafx::render_features::RenderViewCameraLens;
 #[cfg(not(feature = "basic-pipeline"))]
 use rafx_plugins::pipelines::modern::AntiAliasMethodAdv as AntiAliasMethod;
 #[cfg(not(feature = "basic-pipeline"))]
@@ -20,7 +22,10 @@ use rafx_plugins::pipelines::modern::ModernPipelineTonemapDebugData;
 #[cfg(not(feature = "basic-pipeline"))]
 use rafx_plugins::pipelines::modern::TonemapperTypeAdv as TonemapperType;
 #[cfg(not(feature = "basic-pipeline"))]
-use rafx_plugins::pipelines::modern::{JitterPattern, TemporalAAOptions};
+use rafx_plugins::pipelines::modern::{
+    DepthOfFieldOptions, DynamicResolutionOptions, HdrOutputOptions, JitterPattern,
+    MotionBlurOptions, ScreenSpaceReflectionOptions, TemporalAAOptions, VolumetricFogOptions,
+};
 
 #[derive(Clone)]
 pub struct RenderOptions {
@@ -41,18 +46,62 @@ pub struct RenderOptions {
     pub show_lights_debug_draw: bool,
     pub blur_pass_count: usize,
     pub tonemapper_type: TonemapperType,
+    #[cfg(not(feature = "basic-pipeline"))]
+    pub hdr_output_options: HdrOutputOptions,
     pub enable_visibility_update: bool,
+    pub enable_software_occlusion_culling: bool,
     pub use_clustered_lighting: bool,
     #[cfg(not(feature = "basic-pipeline"))]
     pub ndf_filter_amount: f32,
     #[cfg(not(feature = "basic-pipeline"))]
     pub taa_options: TemporalAAOptions,
     #[cfg(not(feature = "basic-pipeline"))]
+    pub render_scale: f32,
+    #[cfg(not(feature = "basic-pipeline"))]
+    pub dynamic_resolution_options: DynamicResolutionOptions,
+    #[cfg(not(feature = "basic-pipeline"))]
     pub enable_sharpening: bool,
     #[cfg(not(feature = "basic-pipeline"))]
     pub sharpening_amount: f32,
     #[cfg(not(feature = "basic-pipeline"))]
     pub enable_occlusion_culling: bool,
+    #[cfg(not(feature = "basic-pipeline"))]
+    pub enable_order_independent_transparency: bool,
+    #[cfg(not(feature = "basic-pipeline"))]
+    pub enable_ibl: bool,
+    #[cfg(not(feature = "basic-pipeline"))]
+    pub enable_reflection_probes: bool,
+    #[cfg(not(feature = "basic-pipeline"))]
+    pub enable_ssr: bool,
+    #[cfg(not(feature = "basic-pipeline"))]
+    pub ssr_options: ScreenSpaceReflectionOptions,
+    #[cfg(not(feature = "basic-pipeline"))]
+    pub enable_volumetric_fog: bool,
+    #[cfg(not(feature = "basic-pipeline"))]
+    pub volumetric_fog_options: VolumetricFogOptions,
+    #[cfg(not(feature = "basic-pipeline"))]
+    pub enable_depth_of_field: bool,
+    #[cfg(not(feature = "basic-pipeline"))]
+    pub depth_of_field_options: DepthOfFieldOptions,
+    // Lens of the main view's camera, used by depth of field
+    #[cfg(not(feature = "basic-pipeline"))]
+    pub camera_lens: RenderViewCameraLens,
+    #[cfg(not(feature = "basic-pipeline"))]
+    pub enable_motion_blur: bool,
+    #[cfg(not(feature = "basic-pipeline"))]
+    pub motion_blur_options: MotionBlurOptions,
+    #[cfg(not(feature = "basic-pipeline"))]
+    pub enable_decals: bool,
+    #[cfg(not(feature = "basic-pipeline"))]
+    pub enable_lods: bool,
+    #[cfg(not(feature = "basic-pipeline"))]
+    pub lod_bias: f32,
+    #[cfg(not(feature = "basic-pipeline"))]
+    pub enable_lod_cross_fade: bool,
+    #[cfg(not(feature = "basic-pipeline"))]
+    pub shadow_cascade_count: u32,
+    #[cfg(not(feature = "basic-pipeline"))]
+    pub max_shadow_distance: f32,
 }
 
 impl RenderOptions {
@@ -75,18 +124,61 @@ impl RenderOptions {
             show_lights_debug_draw: false,
             blur_pass_count: 0,
             tonemapper_type: TonemapperType::None,
+            #[cfg(not(feature = "basic-pipeline"))]
+            hdr_output_options: Default::default(),
             enable_visibility_update: true,
+            enable_software_occlusion_culling: false,
             use_clustered_lighting: true,
             #[cfg(not(feature = "basic-pipeline"))]
             ndf_filter_amount: 1.0,
             #[cfg(not(feature = "basic-pipeline"))]
             taa_options: Default::default(),
             #[cfg(not(feature = "basic-pipeline"))]
+            render_scale: 1.0,
+            #[cfg(not(feature = "basic-pipeline"))]
+            dynamic_resolution_options: Default::default(),
+            #[cfg(not(feature = "basic-pipeline"))]
             enable_sharpening: false,
             #[cfg(not(feature = "basic-pipeline"))]
             sharpening_amount: 0.0,
             #[cfg(not(feature = "basic-pipeline"))]
             enable_occlusion_culling: false,
+            #[cfg(not(feature = "basic-pipeline"))]
+            enable_order_independent_transparency: false,
+            #[cfg(not(feature = "basic-pipeline"))]
+            enable_ibl: true,
+            #[cfg(not(feature = "basic-pipeline"))]
+            enable_reflection_probes: true,
+            #[cfg(not(feature = "basic-pipeline"))]
+            enable_ssr: false,
+            #[cfg(not(feature = "basic-pipeline"))]
+            ssr_options: Default::default(),
+            #[cfg(not(feature = "basic-pipeline"))]
+            enable_volumetric_fog: false,
+            #[cfg(not(feature = "basic-pipeline"))]
+            volumetric_fog_options: Default::default(),
+            #[cfg(not(feature = "basic-pipeline"))]
+            enable_depth_of_field: false,
+            #[cfg(not(feature = "basic-pipeline"))]
+            depth_of_field_options: Default::default(),
+            #[cfg(not(feature = "basic-pipeline"))]
+            camera_lens: Default::default(),
+            #[cfg(not(feature = "basic-pipeline"))]
+            enable_motion_blur: false,
+            #[cfg(not(feature = "basic-pipeline"))]
+            motion_blur_options: Default::default(),
+            #[cfg(not(feature = "basic-pipeline"))]
+            enable_decals: true,
+            #[cfg(not(feature = "basic-pipeline"))]
+            enable_lods: true,
+            #[cfg(not(feature = "basic-pipeline"))]
+            lod_bias: 1.0,
+            #[cfg(not(feature = "basic-pipeline"))]
+            enable_lod_cross_fade: false,
+            #[cfg(not(feature = "basic-pipeline"))]
+            shadow_cascade_count: 4,
+            #[cfg(not(feature = "basic-pipeline"))]
+            max_shadow_distance: 100.0,
         }
     }
 
@@ -116,18 +208,61 @@ impl RenderOptions {
             show_lights_debug_draw: false,
             blur_pass_count: 5,
             tonemapper_type: TonemapperType::default(),
+            #[cfg(not(feature = "basic-pipeline"))]
+            hdr_output_options: Default::default(),
             enable_visibility_update: true,
+            enable_software_occlusion_culling: false,
             use_clustered_lighting: true,
             #[cfg(not(feature = "basic-pipeline"))]
             ndf_filter_amount: 1.0,
             #[cfg(not(feature = "basic-pipeline"))]
             taa_options: Default::default(),
             #[cfg(not(feature = "basic-pipeline"))]
+            render_scale: 1.0,
+            #[cfg(not(feature = "basic-pipeline"))]
+            dynamic_resolution_options: Default::default(),
+            #[cfg(not(feature = "basic-pipeline"))]
             enable_sharpening: true,
             #[cfg(not(feature = "basic-pipeline"))]
             sharpening_amount: 1.0,
             #[cfg(not(feature = "basic-pipeline"))]
             enable_occlusion_culling: true,
+            #[cfg(not(feature = "basic-pipeline"))]
+            enable_order_independent_transparency: false,
+            #[cfg(not(feature = "basic-pipeline"))]
+            enable_ibl: true,
+            #[cfg(not(feature = "basic-pipeline"))]
+            enable_reflection_probes: true,
+            #[cfg(not(feature = "basic-pipeline"))]
+            enable_ssr: false,
+            #[cfg(not(feature = "basic-pipeline"))]
+            ssr_options: Default::default(),
+            #[cfg(not(feature = "basic-pipeline"))]
+            enable_volumetric_fog: false,
+            #[cfg(not(feature = "basic-pipeline"))]
+            volumetric_fog_options: Default::default(),
+            #[cfg(not(feature = "basic-pipeline"))]
+            enable_depth_of_field: false,
+            #[cfg(not(feature = "basic-pipeline"))]
+            depth_of_field_options: Default::default(),
+            #[cfg(not(feature = "basic-pipeline"))]
+            camera_lens: Default::default(),
+            #[cfg(not(feature = "basic-pipeline"))]
+            enable_motion_blur: false,
+            #[cfg(not(feature = "basic-pipeline"))]
+            motion_blur_options: Default::default(),
+            #[cfg(not(feature = "basic-pipeline"))]
+            enable_decals: true,
+            #[cfg(not(feature = "basic-pipeline"))]
+            enable_lods: true,
+            #[cfg(not(feature = "basic-pipeline"))]
+            lod_bias: 1.0,
+            #[cfg(not(feature = "basic-pipeline"))]
+            enable_lod_cross_fade: true,
+            #[cfg(not(feature = "basic-pipeline"))]
+            shadow_cascade_count: 4,
+            #[cfg(not(feature = "basic-pipeline"))]
+            max_shadow_distance: 100.0,
         }
     }
 }
@@ -174,6 +309,25 @@ impl RenderOptions {
                         }
                     });
 
+                // Only used when presenting to an HDR10 or scRGB swapchain
+                #[cfg(not(feature = "basic-pipeline"))]
+                {
+                    let hdr_output_options = &mut self.hdr_output_options;
+                    ui.add(
+                        egui::Slider::new(&mut hdr_output_options.paper_white_nits, 80.0..=500.0)
+                            .text("paper_white_nits"),
+                    );
+                    ui.checkbox(
+                        &mut hdr_output_options.use_display_peak_nits,
+                        "use_display_peak_nits",
+                    );
+                    ui.add(
+                        egui::Slider::new(&mut hdr_output_options.peak_nits, 200.0..=4000.0)
+                            .logarithmic(true)
+                            .text("peak_nits"),
+                    );
+                }
+
                 ui.checkbox(&mut self.enable_bloom, "enable_bloom");
                 if self.enable_bloom {
                     ui.indent("", |ui| {
@@ -223,6 +377,11 @@ impl RenderOptions {
             "enable_visibility_update",
         );
 
+        ui.checkbox(
+            &mut self.enable_software_occlusion_culling,
+            "enable_software_occlusion_culling",
+        );
+
         #[cfg(not(feature = "basic-pipeline"))]
         ui.add(egui::Slider::new(&mut self.ndf_filter_amount, 0.0..=4.0).text("ndf_filter_amount"));
 
@@ -235,6 +394,148 @@ impl RenderOptions {
             &mut self.enable_occlusion_culling,
             "enable_occlusion_culling",
         );
+        #[cfg(not(feature = "basic-pipeline"))]
+        ui.checkbox(
+            &mut self.enable_order_independent_transparency,
+            "enable_order_independent_transparency",
+        );
+
+        #[cfg(not(feature = "basic-pipeline"))]
+        ui.checkbox(&mut self.enable_ibl, "enable_ibl");
+        #[cfg(not(feature = "basic-pipeline"))]
+        ui.checkbox(
+            &mut self.enable_reflection_probes,
+            "enable_reflection_probes",
+        );
+
+        #[cfg(not(feature = "basic-pipeline"))]
+        if self.anti_alias_method != AntiAliasMethod::Msaa4x {
+            ui.checkbox(&mut self.enable_ssr, "enable_ssr");
+            if self.enable_ssr {
+                ui.indent("SSR options", |ui| {
+                    let ssr_options = &mut self.ssr_options;
+                    ui.add(
+                        egui::Slider::new(&mut ssr_options.max_steps, 8..=256).text("max_steps"),
+                    );
+                    ui.add(
+                        egui::Slider::new(&mut ssr_options.max_distance, 1.0..=200.0)
+                            .text("max_distance"),
+                    );
+                    ui.add(
+                        egui::Slider::new(&mut ssr_options.thickness, 0.01..=5.0).text("thickness"),
+                    );
+                    ui.add(
+                        egui::Slider::new(&mut ssr_options.max_roughness, 0.0..=1.0)
+                            .text("max_roughness"),
+                    );
+                    ui.add(
+                        egui::Slider::new(&mut ssr_options.edge_fade, 0.0..=0.5).text("edge_fade"),
+                    );
+                    ui.add(
+                        egui::Slider::new(&mut ssr_options.current_frame_weight, 0.01..=1.0)
+                            .text("current_frame_weight"),
+                    );
+                });
+            }
+        }
+
+        #[cfg(not(feature = "basic-pipeline"))]
+        {
+            ui.checkbox(&mut self.enable_volumetric_fog, "enable_volumetric_fog");
+            if self.enable_volumetric_fog {
+                ui.indent("Volumetric fog options", |ui| {
+                    let volumetric_fog_options = &mut self.volumetric_fog_options;
+                    ui.add(
+                        egui::Slider::new(&mut volumetric_fog_options.density, 0.0..=0.2)
+                            .text("density"),
+                    );
+                    ui.add(
+                        egui::Slider::new(&mut volumetric_fog_options.height_falloff, 0.0..=2.0)
+                            .text("height_falloff"),
+                    );
+                    ui.add(
+                        egui::Slider::new(&mut volumetric_fog_options.base_height, -50.0..=50.0)
+                            .text("base_height"),
+                    );
+                    ui.add(
+                        egui::Slider::new(&mut volumetric_fog_options.anisotropy, -0.9..=0.9)
+                            .text("anisotropy"),
+                    );
+                    ui.add(
+                        egui::Slider::new(&mut volumetric_fog_options.max_distance, 10.0..=500.0)
+                            .text("max_distance"),
+                    );
+                });
+            }
+        }
+
+        #[cfg(not(feature = "basic-pipeline"))]
+        if self.anti_alias_method != AntiAliasMethod::Msaa4x {
+            ui.checkbox(&mut self.enable_depth_of_field, "enable_depth_of_field");
+            if self.enable_depth_of_field {
+                ui.indent("Depth of field options", |ui| {
+                    let camera_lens = &mut self.camera_lens;
+                    ui.add(
+                        egui::Slider::new(&mut camera_lens.focus_distance, 0.1..=100.0)
+                            .logarithmic(true)
+                            .text("focus_distance"),
+                    );
+                    ui.add(
+                        egui::Slider::new(&mut camera_lens.focal_length, 0.012..=0.3)
+                            .text("focal_length"),
+                    );
+                    ui.add(egui::Slider::new(&mut camera_lens.f_stop, 1.0..=22.0).text("f_stop"));
+
+                    let depth_of_field_options = &mut self.depth_of_field_options;
+                    ui.add(
+                        egui::Slider::new(&mut depth_of_field_options.max_coc_radius, 1.0..=32.0)
+                            .text("max_coc_radius"),
+                    );
+                    ui.add(
+                        egui::Slider::new(&mut depth_of_field_options.sample_spacing, 0.5..=4.0)
+                            .text("sample_spacing"),
+                    );
+                });
+            }
+
+            ui.checkbox(&mut self.enable_motion_blur, "enable_motion_blur");
+            if self.enable_motion_blur {
+                ui.indent("Motion blur options", |ui| {
+                    let motion_blur_options = &mut self.motion_blur_options;
+                    ui.add(
+                        egui::Slider::new(&mut motion_blur_options.shutter_fraction, 0.0..=1.0)
+                            .text("shutter_fraction"),
+                    );
+                    ui.add(
+                        egui::Slider::new(&mut motion_blur_options.max_blur_length, 1.0..=128.0)
+                            .text("max_blur_length"),
+                    );
+                    ui.add(
+                        egui::Slider::new(&mut motion_blur_options.sample_count, 1..=32)
+                            .text("sample_count"),
+                    );
+                });
+            }
+
+            ui.checkbox(&mut self.enable_decals, "enable_decals");
+        }
+
+        #[cfg(not(feature = "basic-pipeline"))]
+        ui.checkbox(&mut self.enable_lods, "enable_lods");
+        #[cfg(not(feature = "basic-pipeline"))]
+        ui.add(egui::Slider::new(&mut self.lod_bias, 0.25..=4.0).text("lod_bias"));
+        #[cfg(not(feature = "basic-pipeline"))]
+        ui.checkbox(&mut self.enable_lod_cross_fade, "enable_lod_cross_fade");
+
+        #[cfg(not(feature = "basic-pipeline"))]
+        ui.add(
+            egui::Slider::new(&mut self.shadow_cascade_count, 1..=4).text("shadow_cascade_count"),
+        );
+        #[cfg(not(feature = "basic-pipeline"))]
+        ui.add(
+            egui::Slider::new(&mut self.max_shadow_distance, 10.0..=500.0)
+                .text("max_shadow_distance"),
+        );
     }
 }
 
@@ -302,6 +603,31 @@ pub fn draw_taa_options(
                 );
             }
         });
+
+    ui.separator();
+
+    ui.add(egui::Slider::new(&mut render_options.render_scale, 0.25..=1.0).text("render_scale"));
+
+    let dynamic_resolution_options = &mut render_options.dynamic_resolution_options;
+    ui.checkbox(
+        &mut dynamic_resolution_options.enabled,
+        "enable_dynamic_resolution",
+    );
+    ui.add(
+        egui::Slider::new(
+            &mut dynamic_resolution_options.target_frame_time_ms,
+            4.0..=50.0,
+        )
+        .text("target_frame_time_ms"),
+    );
+    ui.add(
+        egui::Slider::new(&mut dynamic_resolution_options.min_render_scale, 0.25..=1.0)
+            .text("min_render_scale"),
+    );
+    ui.add(
+        egui::Slider::new(&mut dynamic_resolution_options.max_render_scale, 0.25..=1.0)
+            .text("max_render_scale"),
+    );
 }
 
 #[cfg(feature = "egui")]
@@ -14,6 +14,7 @@ use rafx::renderer::{
 use rafx_plugins::assets::anim::AnimAssetTypeRendererPlugin;
 use rafx_plugins::assets::font::FontAssetTypeRendererPlugin;
 use rafx_plugins::assets::ldtk::LdtkAssetTypeRendererPlugin;
+use rafx_plugins::assets::particles::ParticleEmitterAssetTypeRendererPlugin;
 use rafx_plugins::features::debug3d::Debug3DRendererPlugin;
 use rafx_plugins::features::debug_pip::DebugPipRendererPlugin;
 use rafx_plugins::features::skybox::SkyboxRendererPlugin;
@@ -38,8 +39,12 @@ use rafx_plugins::pipelines::basic::BasicPipelineRendererPlugin;
 #[cfg(not(feature = "basic-pipeline"))]
 use rafx_plugins::assets::mesh_adv::MeshAdvAssetTypeRendererPlugin;
 #[cfg(not(feature = "basic-pipeline"))]
+use rafx_plugins::features::decal::DecalRendererPlugin;
+#[cfg(not(feature = "basic-pipeline"))]
 use rafx_plugins::features::mesh_adv::MeshAdvRendererPlugin;
 #[cfg(not(feature = "basic-pipeline"))]
+use rafx_plugins::features::particles::ParticleRendererPlugin;
+#[cfg(not(feature = "basic-pipeline"))]
 use rafx_plugins::pipelines::modern::ModernPipelineRendererPlugin;
 
 pub fn rendering_init(
@@ -49,12 +54,17 @@ pub fn rendering_init(
     window: &dyn HasRawWindowHandle,
     window_width: u32,
     window_height: u32,
+    color_space_priority: Vec<RafxSwapchainColorSpace>,
 ) -> RafxResult<()> {
     resources.insert(VisibilityResource::new());
     resources.insert(ViewportsResource::default());
 
     #[cfg(not(feature = "basic-pipeline"))]
     let mesh_renderer_plugin = Arc::new(MeshAdvRendererPlugin::new(Some(32)));
+    #[cfg(not(feature = "basic-pipeline"))]
+    let decal_renderer_plugin = Arc::new(DecalRendererPlugin::default());
+    #[cfg(not(feature = "basic-pipeline"))]
+    let particle_renderer_plugin = Arc::new(ParticleRendererPlugin::default());
     let sprite_renderer_plugin = Arc::new(SpriteRendererPlugin::default());
     let skybox_renderer_plugin = Arc::new(SkyboxRendererPlugin::default());
     let tile_layer_renderer_plugin = Arc::new(TileLayerRendererPlugin::default());
@@ -67,6 +77,10 @@ pub fn rendering_init(
         Arc::new(rafx_plugins::features::egui::EguiRendererPlugin::default());
     #[cfg(not(feature = "basic-pipeline"))]
     mesh_renderer_plugin.legion_init(resources);
+    #[cfg(not(feature = "basic-pipeline"))]
+    decal_renderer_plugin.legion_init(resources);
+    #[cfg(not(feature = "basic-pipeline"))]
+    particle_renderer_plugin.legion_init(resources);
     sprite_renderer_plugin.legion_init(resources);
     skybox_renderer_plugin.legion_init(resources);
     tile_layer_renderer_plugin.legion_init(resources);
@@ -144,6 +158,7 @@ pub fn rendering_init(
         .add_asset_plugin(Arc::new(FontAssetTypeRendererPlugin))
         .add_asset_plugin(Arc::new(LdtkAssetTypeRendererPlugin))
         .add_asset_plugin(Arc::new(AnimAssetTypeRendererPlugin))
+        .add_asset_plugin(Arc::new(ParticleEmitterAssetTypeRendererPlugin))
         .add_render_feature_plugin(sprite_renderer_plugin)
         .add_render_feature_plugin(skybox_renderer_plugin)
         .add_render_feature_plugin(tile_layer_renderer_plugin)
@@ -156,6 +171,8 @@ pub fn rendering_init(
     {
         renderer_builder = renderer_builder
             .add_render_feature_plugin(mesh_renderer_plugin)
+            .add_render_feature_plugin(decal_renderer_plugin)
+            .add_render_feature_plugin(particle_renderer_plugin)
             .add_asset_plugin(Arc::new(MeshAdvAssetTypeRendererPlugin));
     }
 
@@ -188,7 +205,7 @@ pub fn rendering_init(
         height: window_height,
         width: window_width,
         enable_vsync: true,
-        color_space_priority: vec![RafxSwapchainColorSpace::Srgb],
+        color_space_priority,
     };
 
     let swapchain_helper = SwapchainHandler::create_swapchain(
@@ -231,6 +248,10 @@ pub fn rendering_destroy(resources: &mut Resources) -> RafxResult<()> {
 
         #[cfg(not(feature = "basic-pipeline"))]
         MeshAdvRendererPlugin::legion_destroy(resources);
+        #[cfg(not(feature = "basic-pipeline"))]
+        DecalRendererPlugin::legion_destroy(resources);
+        #[cfg(not(feature = "basic-pipeline"))]
+        ParticleRendererPlugin::legion_destroy(resources);
         SpriteRendererPlugin::legion_destroy(resources);
         SkyboxRendererPlugin::legion_destroy(resources);
         TileLayerRendererPlugin::legion_destroy(resources);
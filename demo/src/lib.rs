@@ -9,7 +9,7 @@ use std::path::PathBuf;
 use legion::*;
 use structopt::StructOpt;
 
-use rafx::api::{RafxExtents2D, RafxResult, RafxSwapchainHelper};
+use rafx::api::{RafxExtents2D, RafxResult, RafxSwapchainColorSpace, RafxSwapchainHelper};
 use rafx::assets::AssetManager;
 
 use crate::scenes::SceneManager;
@@ -108,6 +108,10 @@ pub struct DemoArgs {
 
     #[structopt(name = "external-daemon", long)]
     pub external_daemon: bool,
+
+    /// Present to an HDR10 or scRGB swapchain if the display supports it (modern pipeline only)
+    #[structopt(name = "hdr", long)]
+    pub hdr: bool,
 }
 
 impl DemoArgs {
@@ -157,6 +161,16 @@ impl DemoApp {
 
         let asset_source = args.asset_source().unwrap();
 
+        let color_space_priority = if args.hdr {
+            vec![
+                RafxSwapchainColorSpace::Hdr10,
+                RafxSwapchainColorSpace::SrgbExtended,
+                RafxSwapchainColorSpace::Srgb,
+            ]
+        } else {
+            vec![RafxSwapchainColorSpace::Srgb]
+        };
+
         let physical_size = window.inner_size();
         init::rendering_init(
             &mut resources,
@@ -165,6 +179,7 @@ impl DemoApp {
             window,
             physical_size.width,
             physical_size.height,
+            color_space_priority,
         )?;
 
         let world = World::default();
@@ -340,10 +355,38 @@ impl DemoApp {
             {
                 pipeline_render_options.enable_ssao = render_options.enable_ssao;
                 pipeline_render_options.taa_options = render_options.taa_options.clone();
+                pipeline_render_options.hdr_output_options =
+                    render_options.hdr_output_options.clone();
+                pipeline_render_options.render_scale = render_options.render_scale;
+                pipeline_render_options.dynamic_resolution_options =
+                    render_options.dynamic_resolution_options.clone();
                 pipeline_render_options.enable_sharpening = render_options.enable_sharpening;
                 pipeline_render_options.sharpening_amount = render_options.sharpening_amount;
                 pipeline_render_options.enable_occlusion_culling =
                     render_options.enable_occlusion_culling;
+                pipeline_render_options.enable_order_independent_transparency =
+                    render_options.enable_order_independent_transparency;
+                pipeline_render_options.enable_ibl = render_options.enable_ibl;
+                pipeline_render_options.enable_ssr = render_options.enable_ssr;
+                pipeline_render_options.ssr_options = render_options.ssr_options.clone();
+                pipeline_render_options.enable_volumetric_fog =
+                    render_options.enable_volumetric_fog;
+                pipeline_render_options.volumetric_fog_options =
+                    render_options.volumetric_fog_options.clone();
+                pipeline_render_options.enable_depth_of_field =
+                    render_options.enable_depth_of_field;
+                pipeline_render_options.depth_of_field_options =
+                    render_options.depth_of_field_options.clone();
+                pipeline_render_options.enable_motion_blur = render_options.enable_motion_blur;
+                pipeline_render_options.motion_blur_options =
+                    render_options.motion_blur_options.clone();
+                pipeline_render_options.enable_decals = render_options.enable_decals;
+
+                // The scenes only place the camera, its lens is configured in the UI
+                let mut viewports_resource = self.resources.get_mut::<ViewportsResource>().unwrap();
+                if let Some(main_view_meta) = &mut viewports_resource.main_view_meta {
+                    main_view_meta.camera_lens = Some(render_options.camera_lens.clone());
+                }
             }
 
             let mut render_config_resource =
@@ -351,6 +394,9 @@ impl DemoApp {
             render_config_resource
                 .visibility_config
                 .enable_visibility_update = render_options.enable_visibility_update;
+            render_config_resource
+                .visibility_config
+                .enable_occlusion_culling = render_options.enable_software_occlusion_culling;
 
             #[cfg(not(feature = "basic-pipeline"))]
             {
@@ -361,6 +407,21 @@ impl DemoApp {
                 mesh_render_options.enable_lighting = render_options.enable_lighting;
                 mesh_render_options.ndf_filter_amount = render_options.ndf_filter_amount;
                 mesh_render_options.use_clustered_lighting = render_options.use_clustered_lighting;
+                mesh_render_options.enable_reflection_probes =
+                    render_options.enable_reflection_probes;
+                mesh_render_options.lod_options.enable_lods = render_options.enable_lods;
+                mesh_render_options.lod_options.lod_bias = render_options.lod_bias;
+                mesh_render_options.lod_options.cross_fade_frames =
+                    if render_options.enable_lod_cross_fade {
+                        8
+                    } else {
+                        0
+                    };
+                mesh_render_options.shadow_cascade_options.cascade_count =
+                    render_options.shadow_cascade_count;
+                mesh_render_options
+                    .shadow_cascade_options
+                    .max_shadow_distance = render_options.max_shadow_distance;
             }
         }
 
@@ -21,6 +21,8 @@ impl BistroScene {
         let mut render_options = resources.get_mut::<RenderOptions>().unwrap();
         *render_options = RenderOptions::default_3d();
         render_options.show_skybox = false;
+        // The skybox isn't drawn, but it's still used for image-based lighting
+        super::util::setup_skybox(resources, "demo-assets://textures/skybox.basis");
 
         super::util::set_ambient_light(resources, glam::Vec3::new(0.05, 0.05, 0.05));
 
@@ -124,6 +126,7 @@ fn update_main_view_3d(
         view,
         proj: projection.as_rh_mat4(),
         depth_range: RenderViewDepthRange::from_projection(&projection),
+        camera_lens: None,
         render_phase_mask: phase_mask_builder.build(),
         render_feature_mask: feature_mask_builder.build(),
         render_feature_flag_mask: feature_flag_mask_builder.build(),
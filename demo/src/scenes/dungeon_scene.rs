@@ -2,15 +2,124 @@ use crate::input::InputResource;
 use crate::scenes::util::{DemoCamera, PathData, SpawnablePrefab};
 use crate::time::TimeState;
 use crate::RenderOptions;
-use legion::{Resources, World};
+use legion::IntoQuery;
+use legion::{Read, Resources, World};
 use rafx::rafx_visibility::{DepthRange, PerspectiveParameters, Projection};
 use rafx::render_features::RenderViewDepthRange;
 use rafx::renderer::{RenderViewMeta, ViewportsResource};
-use rafx::visibility::{ViewFrustumArc, VisibilityResource};
+use rafx::visibility::{ViewFrustumArc, VisibilityPortalArc, VisibilityResource, VisibilityZone};
+use rafx_plugins::components::{
+    DirectionalLightComponent, PointLightComponent, ReflectionProbeComponent, SpotLightComponent,
+    TransformComponent, VisibilityComponent,
+};
+
+// The dungeon is split into a west and an east room at x = 0. The portal between them spans the whole
+// cross-section of the level, so each room sees everything in the other room that is beyond the portal.
+struct DungeonRooms {
+    west: VisibilityZone,
+    east: VisibilityZone,
+    _portal: VisibilityPortalArc,
+}
+
+impl DungeonRooms {
+    const PORTAL_HALF_EXTENT: f32 = 100.0;
+
+    fn new(visibility_resource: &mut VisibilityResource) -> Self {
+        let west = visibility_resource.default_zone();
+        let east = visibility_resource.register_zone();
+
+        let half_extent = Self::PORTAL_HALF_EXTENT;
+        let portal = visibility_resource.register_portal(
+            west,
+            east,
+            vec![
+                glam::Vec3::new(0.0, -half_extent, -half_extent),
+                glam::Vec3::new(0.0, half_extent, -half_extent),
+                glam::Vec3::new(0.0, half_extent, half_extent),
+                glam::Vec3::new(0.0, -half_extent, half_extent),
+            ],
+        );
+
+        DungeonRooms {
+            west,
+            east,
+            _portal: portal,
+        }
+    }
+
+    fn zone_at(
+        &self,
+        position: glam::Vec3,
+    ) -> VisibilityZone {
+        if position.x > 0.0 {
+            self.east
+        } else {
+            self.west
+        }
+    }
+
+    // The prefab registers static objects in the default zone, which is the west room
+    fn move_static_objects(
+        &self,
+        world: &World,
+        visibility_resource: &VisibilityResource,
+    ) {
+        let mut query = <(Read<TransformComponent>, Read<VisibilityComponent>)>::query();
+        for (transform, visibility) in query.iter(world) {
+            let zone = self.zone_at(transform.translation);
+            if zone != self.west {
+                visibility_resource
+                    .set_static_object_zone(&visibility.visibility_object_handle, zone);
+            }
+        }
+    }
+
+    // Each view is moved into the room containing its eye. This includes shadow views, so that a
+    // directional light's cascades see through the portal in the direction the light travels.
+    fn update_view_frustum_zones(
+        &self,
+        world: &World,
+        visibility_resource: &VisibilityResource,
+        main_view_frustum: &ViewFrustumArc,
+    ) {
+        let update_zone = |view_frustum: &ViewFrustumArc| {
+            if let Some(eye_position) = view_frustum.eye_position(visibility_resource) {
+                view_frustum.set_zone(self.zone_at(eye_position));
+            }
+        };
+
+        update_zone(main_view_frustum);
+
+        for light in <Read<PointLightComponent>>::query().iter(world) {
+            light
+                .shadow_view_frustums
+                .iter()
+                .flatten()
+                .for_each(update_zone);
+        }
+
+        for light in <Read<SpotLightComponent>>::query().iter(world) {
+            light.shadow_view_frustum.iter().for_each(update_zone);
+        }
+
+        for light in <Read<DirectionalLightComponent>>::query().iter(world) {
+            light
+                .shadow_view_frustums
+                .iter()
+                .flatten()
+                .for_each(update_zone);
+        }
+
+        for probe in <Read<ReflectionProbeComponent>>::query().iter(world) {
+            probe.capture_view_frustums.iter().for_each(update_zone);
+        }
+    }
+}
 
 pub(super) struct DungeonScene {
     main_view_frustum: ViewFrustumArc,
     demo_camera: DemoCamera,
+    rooms: DungeonRooms,
 }
 
 impl DungeonScene {
@@ -37,14 +146,27 @@ impl DungeonScene {
         );
         prefab.spawn_prefab(world, resources);
 
+        // Keeps the interior from reflecting the outdoor sky
+        super::util::add_reflection_probe(
+            resources,
+            world,
+            glam::Vec3::new(0.0, 0.0, 2.0),
+            glam::Vec3::new(15.0, 15.0, 4.0),
+            1.0,
+        );
+
         let mut visibility_resource = resources.get_mut::<VisibilityResource>().unwrap();
         let main_view_frustum = visibility_resource.register_view_frustum();
 
+        let rooms = DungeonRooms::new(&mut *visibility_resource);
+        rooms.move_static_objects(world, &*visibility_resource);
+
         log::info!("scene loaded");
 
         DungeonScene {
             main_view_frustum,
             demo_camera,
+            rooms,
         }
     }
 }
@@ -52,7 +174,7 @@ impl DungeonScene {
 impl super::TestScene for DungeonScene {
     fn update(
         &mut self,
-        _world: &mut World,
+        world: &mut World,
         resources: &mut Resources,
     ) {
         //super::add_light_debug_draw(&resources, &world);
@@ -75,6 +197,15 @@ impl super::TestScene for DungeonScene {
                 &self.demo_camera,
             );
         }
+
+        {
+            let visibility_resource = resources.get::<VisibilityResource>().unwrap();
+            self.rooms.update_view_frustum_zones(
+                world,
+                &*visibility_resource,
+                &self.main_view_frustum,
+            );
+        }
     }
 
     fn process_input(
@@ -126,6 +257,7 @@ fn update_main_view_3d(
         view,
         proj: projection.as_rh_mat4(),
         depth_range: RenderViewDepthRange::from_projection(&projection),
+        camera_lens: None,
         render_phase_mask: phase_mask_builder.build(),
         render_feature_mask: feature_mask_builder.build(),
         render_feature_flag_mask: feature_flag_mask_builder.build(),
@@ -151,6 +151,7 @@ fn update_main_view_3d(
         view,
         proj: projection.as_rh_mat4(),
         depth_range: RenderViewDepthRange::from_projection(&projection),
+        camera_lens: None,
         render_phase_mask: phase_mask_builder.build(),
         render_feature_mask: feature_mask_builder.build(),
         render_feature_flag_mask: feature_flag_mask_builder.build(),
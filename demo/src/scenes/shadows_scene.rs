@@ -4,12 +4,18 @@ use crate::RenderOptions;
 use glam::Vec3;
 use legion::{IntoQuery, Read};
 use legion::{Resources, World, Write};
+use rafx::assets::{AssetResource, ImageAsset};
 use rafx::rafx_visibility::{DepthRange, PerspectiveParameters, Projection};
 use rafx::render_features::RenderViewDepthRange;
 use rafx::renderer::{RenderViewMeta, ViewportsResource};
-use rafx::visibility::{ViewFrustumArc, VisibilityResource};
-use rafx_plugins::components::DirectionalLightComponent;
-use rafx_plugins::components::{PointLightComponent, TransformComponent};
+use rafx::visibility::{CullModel, ObjectId, ViewFrustumArc, VisibilityResource};
+use rafx_plugins::assets::particles::ParticleEmitterAsset;
+use rafx_plugins::components::{DecalComponent, DirectionalLightComponent, VisibilityComponent};
+use rafx_plugins::components::{ParticleEmitterComponent, PointLightComponent, TransformComponent};
+use rafx_plugins::features::decal::{DecalRenderObject, DecalRenderObjectSet};
+use rafx_plugins::features::particles::{
+    ParticleEmitterRenderObject, ParticleEmitterRenderObjectSet,
+};
 use rand::{thread_rng, Rng};
 
 pub(super) struct ShadowsScene {
@@ -60,13 +66,19 @@ impl ShadowsScene {
         // Add some meshes
         //
         {
-            let example_meshes = vec![container_1, container_2, blue_icosphere];
+            // The containers are solid boxes, so they also occlude the meshes behind them when
+            // software occlusion culling is enabled
+            let example_meshes = vec![
+                (container_1, true),
+                (container_2, true),
+                (blue_icosphere, false),
+            ];
 
             //let mesh_render_objects = mesh_render_objects.read();
             let mut rng = thread_rng();
             for i in 0..250 {
                 let position = Vec3::new(((i / 9) * 3) as f32, ((i % 9) * 3) as f32, 0.0);
-                let example_mesh = &example_meshes[i % example_meshes.len()];
+                let (example_mesh, is_occluder) = &example_meshes[i % example_meshes.len()];
                 //let asset_handle = &mesh_render_objects.get(&mesh_render_object).mesh;
 
                 let rand_scale = rng.gen_range(0.8..1.2);
@@ -77,10 +89,115 @@ impl ShadowsScene {
                     ..Default::default()
                 };
 
-                example_mesh.spawn(resources, world, transform_component);
+                if *is_occluder {
+                    example_mesh.spawn_occluder(resources, world, transform_component, 0.1);
+                } else {
+                    example_mesh.spawn(resources, world, transform_component);
+                }
             }
         }
 
+        //
+        // Add some decals to the floor between the meshes
+        //
+        {
+            let decal_image = {
+                let asset_resource = resources.get::<AssetResource>().unwrap();
+                asset_resource.load_artifact_symbol_name::<ImageAsset>(
+                    "demo-assets://textures/texture-tiny-rust.jpeg",
+                )
+            };
+
+            let mut visibility_resource = resources.get_mut::<VisibilityResource>().unwrap();
+            let mut decal_render_objects = resources.get_mut::<DecalRenderObjectSet>().unwrap();
+
+            for i in 0..8 {
+                let mut decal = DecalRenderObject::new(Vec3::new(1.0, 1.0, 1.0));
+                decal.albedo_texture = Some(decal_image.clone());
+                decal.fade = 0.5 + (i % 2) as f32 * 0.5;
+                decal.sort_order = i;
+                let visible_bounds = decal.visible_bounds();
+                let decal_render_object = decal_render_objects.register_render_object(decal);
+
+                let transform_component = TransformComponent {
+                    translation: Vec3::new(1.5 + (i * 3) as f32, 1.5 + (i % 3) as f32 * 3.0, -1.0),
+                    rotation: glam::Quat::from_rotation_z(i as f32 * 0.4),
+                    ..Default::default()
+                };
+
+                let decal_component = DecalComponent {
+                    render_object_handle: decal_render_object.clone(),
+                };
+
+                let entity = world.push((transform_component.clone(), decal_component));
+                let mut entry = world.entry(entity).unwrap();
+                entry.add_component(VisibilityComponent {
+                    visibility_object_handle: {
+                        let handle = visibility_resource.register_dynamic_object(
+                            ObjectId::from(entity),
+                            CullModel::VisibleBounds(visible_bounds),
+                            vec![decal_render_object],
+                        );
+                        handle.set_transform(
+                            transform_component.translation,
+                            transform_component.rotation,
+                            transform_component.scale,
+                        );
+                        handle
+                    },
+                });
+            }
+        }
+
+        //
+        // Add a particle fountain between the meshes
+        //
+        {
+            let emitter = {
+                let asset_resource = resources.get::<AssetResource>().unwrap();
+                asset_resource.load_artifact_symbol_name::<ParticleEmitterAsset>(
+                    "demo-assets://particles/fountain.particles",
+                )
+            };
+
+            let mut visibility_resource = resources.get_mut::<VisibilityResource>().unwrap();
+            let mut particle_render_objects = resources
+                .get_mut::<ParticleEmitterRenderObjectSet>()
+                .unwrap();
+
+            // The particles rise about 3 units and spread out as they fall back down
+            let emitter = ParticleEmitterRenderObject::new(emitter, Vec3::new(4.0, 4.0, 4.0));
+            let visible_bounds = emitter.visible_bounds();
+            let particle_render_object = particle_render_objects.register_render_object(emitter);
+
+            let transform_component = TransformComponent {
+                translation: Vec3::new(13.5, 13.5, -1.0),
+                ..Default::default()
+            };
+
+            let particle_emitter_component = ParticleEmitterComponent {
+                render_object_handle: particle_render_object.clone(),
+            };
+
+            let entity = world.push((transform_component, particle_emitter_component));
+            let mut entry = world.entry(entity).unwrap();
+            entry.add_component(VisibilityComponent {
+                visibility_object_handle: {
+                    let handle = visibility_resource.register_dynamic_object(
+                        ObjectId::from(entity),
+                        CullModel::VisibleBounds(visible_bounds),
+                        vec![particle_render_object],
+                    );
+                    handle.set_transform(
+                        transform_component.translation,
+                        transform_component.rotation,
+                        transform_component.scale,
+                    );
+                    handle
+                },
+            });
+        }
+
         //
         // POINT LIGHT
         //
@@ -252,6 +369,7 @@ fn update_main_view_3d(
         view,
         proj: projection.as_rh_mat4(),
         depth_range: RenderViewDepthRange::from_projection(&projection),
+        camera_lens: None,
         render_phase_mask: phase_mask_builder.build(),
         render_feature_mask: feature_mask_builder.build(),
         render_feature_flag_mask: feature_flag_mask_builder.build(),
@@ -7,7 +7,8 @@ use rafx::render_features::{
     RenderFeatureFlagMaskBuilder, RenderFeatureMaskBuilder, RenderPhaseMaskBuilder,
 };
 use rafx_plugins::components::{
-    DirectionalLightComponent, PointLightComponent, SpotLightComponent, TransformComponent,
+    DirectionalLightComponent, PointLightComponent, ReflectionProbeComponent, SpotLightComponent,
+    TransformComponent,
 };
 use rafx_plugins::features::debug3d::{Debug3DRenderFeature, Debug3DResource};
 use rafx_plugins::features::skybox::{SkyboxRenderFeature, SkyboxResource};
@@ -19,6 +20,8 @@ use rafx_plugins::phases::{
     UiRenderPhase, WireframeRenderPhase,
 };
 
+#[cfg(not(feature = "basic-pipeline"))]
+use rafx_plugins::features::decal::DecalRenderFeature;
 #[cfg(not(feature = "basic-pipeline"))]
 use rafx_plugins::features::mesh_adv::{
     MeshAdvNoShadowsRenderFeatureFlag as MeshNoShadowsRenderFeatureFlag,
@@ -27,6 +30,10 @@ use rafx_plugins::features::mesh_adv::{
     MeshAdvUntexturedRenderFeatureFlag as MeshUntexturedRenderFeatureFlag,
     MeshAdvWireframeRenderFeatureFlag as MeshWireframeRenderFeatureFlag,
 };
+#[cfg(not(feature = "basic-pipeline"))]
+use rafx_plugins::features::particles::ParticleRenderFeature;
+#[cfg(not(feature = "basic-pipeline"))]
+use rafx_plugins::phases::DecalRenderPhase;
 
 #[cfg(not(feature = "basic-pipeline"))]
 mod fly_camera;
@@ -110,9 +117,14 @@ pub(super) fn add_directional_light(
     intensity: f32,
     cast_shadows: bool,
 ) {
-    let view_frustum = if cast_shadows {
+    let view_frustums = if cast_shadows {
         let mut visibility_resource = resources.get_mut::<VisibilityResource>().unwrap();
-        Some(visibility_resource.register_view_frustum())
+        Some([
+            visibility_resource.register_view_frustum(),
+            visibility_resource.register_view_frustum(),
+            visibility_resource.register_view_frustum(),
+            visibility_resource.register_view_frustum(),
+        ])
     } else {
         None
     };
@@ -121,7 +133,7 @@ pub(super) fn add_directional_light(
         direction,
         color,
         intensity,
-        shadow_view_frustum: view_frustum,
+        shadow_view_frustums: view_frustums,
     };
 
     world.extend(vec![(light_component,)]);
@@ -201,6 +213,39 @@ pub(super) fn add_point_light(
     world.extend(vec![(position_component, light_component)]);
 }
 
+pub(super) fn add_reflection_probe(
+    resources: &Resources,
+    world: &mut World,
+    position: glam::Vec3,
+    half_extents: glam::Vec3,
+    blend_distance: f32,
+) {
+    let mut visibility_resource = resources.get_mut::<VisibilityResource>().unwrap();
+    let capture_view_frustums = [
+        visibility_resource.register_view_frustum(),
+        visibility_resource.register_view_frustum(),
+        visibility_resource.register_view_frustum(),
+        visibility_resource.register_view_frustum(),
+        visibility_resource.register_view_frustum(),
+        visibility_resource.register_view_frustum(),
+    ];
+
+    let position_component = TransformComponent {
+        translation: position,
+        ..Default::default()
+    };
+
+    let probe_component = ReflectionProbeComponent {
+        half_extents,
+        blend_distance,
+        intensity: 1.0,
+        capture_generation: 0,
+        capture_view_frustums,
+    };
+
+    world.extend(vec![(position_component, probe_component)]);
+}
+
 pub fn default_main_view_masks(
     render_options: &RenderOptions
 ) -> (
@@ -208,7 +253,8 @@ pub fn default_main_view_masks(
     RenderFeatureMaskBuilder,
     RenderFeatureFlagMaskBuilder,
 ) {
-    let phase_mask_builder = RenderPhaseMaskBuilder::default()
+    #[allow(unused_mut)]
+    let mut phase_mask_builder = RenderPhaseMaskBuilder::default()
         .add_render_phase::<DepthPrepassRenderPhase>()
         .add_render_phase::<OpaqueRenderPhase>()
         .add_render_phase::<TransparentRenderPhase>()
@@ -224,7 +270,11 @@ pub fn default_main_view_masks(
 
     #[cfg(not(feature = "basic-pipeline"))]
     {
-        feature_mask_builder = feature_mask_builder.add_render_feature::<MeshRenderFeature>();
+        phase_mask_builder = phase_mask_builder.add_render_phase::<DecalRenderPhase>();
+        feature_mask_builder = feature_mask_builder
+            .add_render_feature::<MeshRenderFeature>()
+            .add_render_feature::<DecalRenderFeature>()
+            .add_render_feature::<ParticleRenderFeature>();
     }
 
     #[cfg(feature = "egui")]
@@ -3,7 +3,7 @@ use hydrate_base::ArtifactId;
 use legion::{Resources, World};
 use rafx::assets::AssetManager;
 use rafx::assets::AssetResource;
-use rafx::rafx_visibility::VisibleBounds;
+use rafx::rafx_visibility::{PolygonSoup, PolygonSoupIndex, VisibleBounds};
 use rafx::render_features::RenderObjectHandle;
 use rafx::renderer::Renderer;
 use rafx::visibility::{CullModel, ObjectId, VisibilityResource};
@@ -27,6 +27,42 @@ impl SpawnableMesh {
         resources: &Resources,
         world: &mut World,
         transform_component: TransformComponent,
+    ) {
+        self.do_spawn(
+            resources,
+            world,
+            transform_component,
+            CullModel::VisibleBounds(self.visible_bounds.clone()),
+            false,
+        );
+    }
+
+    /// Spawns the mesh as an occluder for software occlusion culling. The occluder is the mesh's
+    /// oriented bounding box shrunk by `inset` (a fraction of its size), so this only suits solid,
+    /// box-shaped meshes. An occluder larger than the mesh would hide objects that are visible.
+    pub fn spawn_occluder(
+        &self,
+        resources: &Resources,
+        world: &mut World,
+        transform_component: TransformComponent,
+        inset: f32,
+    ) {
+        self.do_spawn(
+            resources,
+            world,
+            transform_component,
+            CullModel::exact_mesh(occluder_box(&self.visible_bounds, inset)),
+            true,
+        );
+    }
+
+    fn do_spawn(
+        &self,
+        resources: &Resources,
+        world: &mut World,
+        transform_component: TransformComponent,
+        cull_model: CullModel,
+        occluder: bool,
     ) {
         let mut visibility_resource = resources.get_mut::<VisibilityResource>().unwrap();
         let mesh_component = MeshComponent {
@@ -39,14 +75,16 @@ impl SpawnableMesh {
             visibility_object_handle: {
                 let handle = visibility_resource.register_dynamic_object(
                     ObjectId::from(entity),
-                    CullModel::VisibleBounds(self.visible_bounds.clone()),
+                    cull_model,
                     vec![self.render_object.clone()],
                 );
-                handle.set_transform(
-                    transform_component.translation,
-                    transform_component.rotation,
-                    transform_component.scale,
-                );
+                handle
+                    .set_transform(
+                        transform_component.translation,
+                        transform_component.rotation,
+                        transform_component.scale,
+                    )
+                    .set_occluder(occluder);
                 handle
             },
         });
@@ -61,9 +99,8 @@ impl SpawnableMesh {
         let mut mesh_render_objects = resources.get_mut::<MeshRenderObjectSet>().unwrap();
         let renderer = resources.get::<Renderer>().unwrap();
 
-        let render_object = mesh_render_objects.register_render_object(MeshRenderObject {
-            mesh: asset_handle.clone(),
-        });
+        let render_object =
+            mesh_render_objects.register_render_object(MeshRenderObject::new(asset_handle.clone()));
 
         renderer
             .wait_for_asset_to_load(
@@ -105,3 +142,35 @@ impl SpawnableMesh {
         Self::do_load_spawnable_mesh(resources, &mut *asset_resource, handle)
     }
 }
+
+fn occluder_box(
+    visible_bounds: &VisibleBounds,
+    inset: f32,
+) -> PolygonSoup {
+    let obb = &visible_bounds.obb;
+    let center = (obb.min + obb.max) * 0.5;
+    let half_extents = obb.half_extents() * (1.0 - inset);
+
+    let mut vertex_positions = Vec::with_capacity(8);
+    for corner in 0..8 {
+        let sign = |bit: usize| if corner & bit == 0 { -1.0 } else { 1.0 };
+        let local = center + half_extents * glam::Vec3::new(sign(1), sign(2), sign(4));
+        vertex_positions.push(obb.rotation * local);
+    }
+
+    // Two triangles for each face of the box, using the corner bits above as x, y and z
+    #[rustfmt::skip]
+    let indices = vec![
+        0, 2, 6, 0, 6, 4, // -x
+        1, 5, 7, 1, 7, 3, // +x
+        0, 4, 5, 0, 5, 1, // -y
+        2, 3, 7, 2, 7, 6, // +y
+        0, 1, 3, 0, 3, 2, // -z
+        4, 6, 7, 4, 7, 5, // +z
+    ];
+
+    PolygonSoup {
+        vertex_positions,
+        index: PolygonSoupIndex::Indexed16(indices),
+    }
+}
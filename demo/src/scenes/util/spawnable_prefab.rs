@@ -86,11 +86,10 @@ impl SpawnablePrefab {
                     continue;
                 }
                 let model_asset = model_asset_handle.unwrap();
-                let mesh_asset = model_asset.inner.lods[0].mesh.clone();
+                let mesh_render_object = MeshRenderObject::from_model(model_asset);
+                let mesh_asset = mesh_render_object.mesh().clone();
 
-                let render_object = mesh_render_objects.register_render_object(MeshRenderObject {
-                    mesh: mesh_asset.clone(),
-                });
+                let render_object = mesh_render_objects.register_render_object(mesh_render_object);
 
                 let transform_component = TransformComponent {
                     translation: object.transform.position,
@@ -7,7 +7,8 @@ use crate::dx12::RafxApiDx12;
         feature = "rafx-metal",
         feature = "rafx-vulkan",
         feature = "rafx-gles2",
-        feature = "rafx-gles3"
+        feature = "rafx-gles3",
+        feature = "rafx-webgpu"
     ))
 ))]
 use crate::empty::RafxApiEmpty;
@@ -19,6 +20,8 @@ use crate::gles3::RafxApiGles3;
 use crate::metal::RafxApiMetal;
 #[cfg(feature = "rafx-vulkan")]
 use crate::vulkan::RafxApiVulkan;
+#[cfg(feature = "rafx-webgpu")]
+use crate::webgpu::RafxApiWebGpu;
 
 use crate::*;
 use raw_window_handle::{HasRawDisplayHandle, HasRawWindowHandle};
@@ -45,6 +48,8 @@ pub enum RafxApi {
     Gles2(RafxApiGles2),
     #[cfg(feature = "rafx-gles3")]
     Gles3(RafxApiGles3),
+    #[cfg(feature = "rafx-webgpu")]
+    WebGpu(RafxApiWebGpu),
     #[cfg(any(
         feature = "rafx-empty",
         not(any(
@@ -52,7 +57,8 @@ pub enum RafxApi {
             feature = "rafx-metal",
             feature = "rafx-vulkan",
             feature = "rafx-gles2",
-            feature = "rafx-gles3"
+            feature = "rafx-gles3",
+            feature = "rafx-webgpu"
         ))
     ))]
     Empty(RafxApiEmpty),
@@ -91,6 +97,10 @@ impl RafxApi {
         {
             return RafxApi::new_gles3(_display, _window, _api_def);
         }
+        #[cfg(feature = "rafx-webgpu")]
+        {
+            return RafxApi::new_webgpu(_display, _window, _api_def);
+        }
 
         #[cfg(feature = "rafx-gles2")]
         {
@@ -210,6 +220,50 @@ impl RafxApi {
         )?))
     }
 
+    /// Initialize a device using WebGPU (via wgpu)
+    ///
+    /// # Safety
+    ///
+    /// GPU programming is fundamentally unsafe, so all rafx APIs that interact with the GPU should
+    /// be considered unsafe. However, rafx APIs are only gated by unsafe if they can cause undefined
+    /// behavior on the CPU for reasons other than interacting with the GPU.
+    #[cfg(feature = "rafx-webgpu")]
+    pub unsafe fn new_webgpu(
+        display: &dyn HasRawDisplayHandle,
+        window: &dyn HasRawWindowHandle,
+        api_def: &RafxApiDef,
+    ) -> RafxResult<Self> {
+        Ok(RafxApi::WebGpu(RafxApiWebGpu::new(
+            display,
+            window,
+            api_def,
+            api_def
+                .webgpu_options
+                .as_ref()
+                .unwrap_or(&Default::default()),
+        )?))
+    }
+
+    /// Initialize a WebGPU device that is not associated with a window. Swapchains can't be
+    /// created, but everything else works. Set `RafxApiDefWebGpu::force_fallback_adapter` to run
+    /// against a software adapter, for example in tests.
+    ///
+    /// # Safety
+    ///
+    /// GPU programming is fundamentally unsafe, so all rafx APIs that interact with the GPU should
+    /// be considered unsafe. However, rafx APIs are only gated by unsafe if they can cause undefined
+    /// behavior on the CPU for reasons other than interacting with the GPU.
+    #[cfg(feature = "rafx-webgpu")]
+    pub unsafe fn new_webgpu_headless(api_def: &RafxApiDef) -> RafxResult<Self> {
+        Ok(RafxApi::WebGpu(RafxApiWebGpu::new_headless(
+            api_def,
+            api_def
+                .webgpu_options
+                .as_ref()
+                .unwrap_or(&Default::default()),
+        )?))
+    }
+
     /// Create a cloneable handle to the device. Most of the interaction with the graphics backend
     /// is done through this handle.
     ///
@@ -231,6 +285,8 @@ impl RafxApi {
             RafxApi::Gles2(inner) => RafxDeviceContext::Gles2(inner.device_context().clone()),
             #[cfg(feature = "rafx-gles3")]
             RafxApi::Gles3(inner) => RafxDeviceContext::Gles3(inner.device_context().clone()),
+            #[cfg(feature = "rafx-webgpu")]
+            RafxApi::WebGpu(inner) => RafxDeviceContext::WebGpu(inner.device_context().clone()),
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -238,7 +294,8 @@ impl RafxApi {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxApi::Empty(inner) => RafxDeviceContext::Empty(inner.device_context().clone()),
@@ -262,6 +319,8 @@ impl RafxApi {
             RafxApi::Gles2(inner) => inner.destroy(),
             #[cfg(feature = "rafx-gles3")]
             RafxApi::Gles3(inner) => inner.destroy(),
+            #[cfg(feature = "rafx-webgpu")]
+            RafxApi::WebGpu(inner) => inner.destroy(),
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -269,7 +328,8 @@ impl RafxApi {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxApi::Empty(inner) => inner.destroy(),
@@ -291,6 +351,8 @@ impl RafxApi {
             RafxApi::Gles2(_) => None,
             #[cfg(feature = "rafx-gles3")]
             RafxApi::Gles3(_) => None,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxApi::WebGpu(_) => None,
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -298,7 +360,8 @@ impl RafxApi {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxApi::Empty(_) => None,
@@ -320,6 +383,8 @@ impl RafxApi {
             RafxApi::Gles2(_) => None,
             #[cfg(feature = "rafx-gles3")]
             RafxApi::Gles3(_) => None,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxApi::WebGpu(_) => None,
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -327,7 +392,8 @@ impl RafxApi {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxApi::Empty(_) => None,
@@ -349,6 +415,8 @@ impl RafxApi {
             RafxApi::Gles2(_) => None,
             #[cfg(feature = "rafx-gles3")]
             RafxApi::Gles3(_) => None,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxApi::WebGpu(_) => None,
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -356,7 +424,8 @@ impl RafxApi {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxApi::Empty(_) => None,
@@ -378,6 +447,8 @@ impl RafxApi {
             RafxApi::Gles2(inner) => Some(inner),
             #[cfg(feature = "rafx-gles3")]
             RafxApi::Gles3(_) => None,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxApi::WebGpu(_) => None,
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -385,7 +456,8 @@ impl RafxApi {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxApi::Empty(_) => None,
@@ -407,6 +479,40 @@ impl RafxApi {
             RafxApi::Gles2(_) => None,
             #[cfg(feature = "rafx-gles3")]
             RafxApi::Gles3(inner) => Some(inner),
+            #[cfg(feature = "rafx-webgpu")]
+            RafxApi::WebGpu(_) => None,
+            #[cfg(any(
+                feature = "rafx-empty",
+                not(any(
+                    feature = "rafx-dx12",
+                    feature = "rafx-metal",
+                    feature = "rafx-vulkan",
+                    feature = "rafx-gles2",
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
+                ))
+            ))]
+            RafxApi::Empty(_) => None,
+        }
+    }
+
+    /// Get the underlying webgpu API object. This provides access to any internally created
+    /// wgpu objects.
+    #[cfg(feature = "rafx-webgpu")]
+    pub fn webgpu_api(&self) -> Option<&RafxApiWebGpu> {
+        match self {
+            #[cfg(feature = "rafx-dx12")]
+            RafxApi::Dx12(_) => None,
+            #[cfg(feature = "rafx-vulkan")]
+            RafxApi::Vk(_) => None,
+            #[cfg(feature = "rafx-metal")]
+            RafxApi::Metal(_) => None,
+            #[cfg(feature = "rafx-gles2")]
+            RafxApi::Gles2(_) => None,
+            #[cfg(feature = "rafx-gles3")]
+            RafxApi::Gles3(_) => None,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxApi::WebGpu(inner) => Some(inner),
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -414,7 +520,8 @@ impl RafxApi {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxApi::Empty(_) => None,
@@ -430,7 +537,8 @@ impl RafxApi {
             feature = "rafx-metal",
             feature = "rafx-vulkan",
             feature = "rafx-gles2",
-            feature = "rafx-gles3"
+            feature = "rafx-gles3",
+            feature = "rafx-webgpu"
         ))
     ))]
     pub fn empty_api(&self) -> Option<&RafxApiEmpty> {
@@ -445,6 +553,8 @@ impl RafxApi {
             RafxApi::Gles2(_) => None,
             #[cfg(feature = "rafx-gles3")]
             RafxApi::Gles3(_) => None,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxApi::WebGpu(_) => None,
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -452,7 +562,8 @@ impl RafxApi {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxApi::Empty(inner) => Some(inner),
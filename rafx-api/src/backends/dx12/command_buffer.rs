@@ -746,6 +746,30 @@ impl RafxCommandBufferDx12 {
         Ok(())
     }
 
+    pub fn cmd_dispatch_indirect(
+        &self,
+        indirect_buffer: &RafxBufferDx12,
+        indirect_buffer_offset_in_bytes: u32,
+    ) -> RafxResult<()> {
+        let inner = self.inner.borrow();
+        unsafe {
+            inner.command_list.ExecuteIndirect(
+                &self
+                    .queue
+                    .device_context()
+                    .inner
+                    .indirect_dispatch_command_signature,
+                1,
+                indirect_buffer.dx12_resource(),
+                indirect_buffer_offset_in_bytes as u64,
+                None,
+                0,
+            );
+        }
+
+        Ok(())
+    }
+
     pub fn cmd_resource_barrier(
         &self,
         buffer_barriers: &[RafxBufferBarrier],
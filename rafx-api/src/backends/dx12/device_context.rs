@@ -1,9 +1,10 @@
 use crate::{
     RafxApiDefDx12, RafxBufferDef, RafxComputePipelineDef, RafxDescriptorSetArrayDef,
-    RafxDeviceContext, RafxDeviceInfo, RafxDrawIndexedIndirectCommand, RafxDrawIndirectCommand,
-    RafxError, RafxFormat, RafxGraphicsPipelineDef, RafxQueueType, RafxResourceType, RafxResult,
-    RafxRootSignatureDef, RafxSampleCount, RafxSamplerDef, RafxShaderModuleDefDx12,
-    RafxShaderStageDef, RafxSwapchainDef, RafxTextureDef, RafxValidationMode,
+    RafxDeviceContext, RafxDeviceInfo, RafxDispatchIndirectCommand, RafxDrawIndexedIndirectCommand,
+    RafxDrawIndirectCommand, RafxError, RafxFormat, RafxGraphicsPipelineDef, RafxQueueType,
+    RafxResourceType, RafxResult, RafxRootSignatureDef, RafxSampleCount, RafxSamplerDef,
+    RafxShaderModuleDefDx12, RafxShaderStageDef, RafxSwapchainDef, RafxTextureDef,
+    RafxValidationMode,
 };
 use raw_window_handle::{HasRawDisplayHandle, HasRawWindowHandle};
 use std::mem::ManuallyDrop;
@@ -111,6 +112,27 @@ fn create_indirect_draw_command_signature(
     Ok(result.unwrap())
 }
 
+fn create_indirect_dispatch_command_signature(
+    device: &d3d12::ID3D12Device
+) -> RafxResult<d3d12::ID3D12CommandSignature> {
+    let mut sig = d3d12::D3D12_COMMAND_SIGNATURE_DESC::default();
+    let mut arg = d3d12::D3D12_INDIRECT_ARGUMENT_DESC::default();
+
+    arg.Type = d3d12::D3D12_INDIRECT_ARGUMENT_TYPE_DISPATCH;
+    sig.ByteStride = std::mem::size_of::<RafxDispatchIndirectCommand>() as u32;
+
+    sig.NumArgumentDescs = 1;
+    sig.pArgumentDescs = &arg;
+
+    let mut result: Option<d3d12::ID3D12CommandSignature> = None;
+
+    unsafe {
+        device.CreateCommandSignature(&sig, None, &mut result)?;
+    }
+
+    Ok(result.unwrap())
+}
+
 fn create_device(
     dx12_api_def: &RafxApiDefDx12
 ) -> RafxResult<(
@@ -217,6 +239,7 @@ pub struct RafxDeviceContextDx12Inner {
 
     pub(crate) indirect_command_signature: d3d12::ID3D12CommandSignature,
     pub(crate) indirect_command_signature_indexed: d3d12::ID3D12CommandSignature,
+    pub(crate) indirect_dispatch_command_signature: d3d12::ID3D12CommandSignature,
 
     d3d12_device: d3d12::ID3D12Device,
     dxgi_adapter: dxgi::IDXGIAdapter1,
@@ -282,6 +305,8 @@ impl RafxDeviceContextDx12Inner {
             create_indirect_draw_command_signature(&d3d12_device, false)?;
         let indirect_command_signature_indexed =
             create_indirect_draw_command_signature(&d3d12_device, true)?;
+        let indirect_dispatch_command_signature =
+            create_indirect_dispatch_command_signature(&d3d12_device)?;
 
         let device_info = RafxDeviceInfo {
             supports_multithreaded_usage: true,
@@ -313,6 +338,7 @@ impl RafxDeviceContextDx12Inner {
 
             indirect_command_signature,
             indirect_command_signature_indexed,
+            indirect_dispatch_command_signature,
 
             d3d12_device,
             dxgi_adapter,
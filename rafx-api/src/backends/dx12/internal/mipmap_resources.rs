@@ -317,6 +317,7 @@ impl Dx12MipmapResources {
                 let pipeline = RafxPipeline::Dx12(dx12_device_context.create_compute_pipeline(&RafxComputePipelineDef {
                     shader: &shader,
                     root_signature: &root_signature,
+                    specialization_constants: &[],
                 })?);
         */
         Ok(Dx12MipmapResources {
@@ -290,9 +290,11 @@ impl RafxPipelineDx12 {
                 .shader_stage
                 .intersects(RafxShaderStageFlags::VERTEX)
             {
-                vs_bytecode = Some(
-                    module.get_or_compile_bytecode(&stage.reflection.entry_point_name, "vs_6_0")?,
-                );
+                vs_bytecode = Some(module.get_or_compile_bytecode(
+                    &stage.reflection.entry_point_name,
+                    "vs_6_0",
+                    pipeline_def.specialization_constants,
+                )?);
             }
 
             if stage
@@ -300,9 +302,11 @@ impl RafxPipelineDx12 {
                 .shader_stage
                 .intersects(RafxShaderStageFlags::FRAGMENT)
             {
-                ps_bytecode = Some(
-                    module.get_or_compile_bytecode(&stage.reflection.entry_point_name, "ps_6_0")?,
-                );
+                ps_bytecode = Some(module.get_or_compile_bytecode(
+                    &stage.reflection.entry_point_name,
+                    "ps_6_0",
+                    pipeline_def.specialization_constants,
+                )?);
             }
 
             if stage
@@ -310,9 +314,11 @@ impl RafxPipelineDx12 {
                 .shader_stage
                 .intersects(RafxShaderStageFlags::TESSELLATION_EVALUATION)
             {
-                ds_bytecode = Some(
-                    module.get_or_compile_bytecode(&stage.reflection.entry_point_name, "ds_6_0")?,
-                );
+                ds_bytecode = Some(module.get_or_compile_bytecode(
+                    &stage.reflection.entry_point_name,
+                    "ds_6_0",
+                    pipeline_def.specialization_constants,
+                )?);
             }
 
             if stage
@@ -320,9 +326,11 @@ impl RafxPipelineDx12 {
                 .shader_stage
                 .intersects(RafxShaderStageFlags::TESSELLATION_CONTROL)
             {
-                hs_bytecode = Some(
-                    module.get_or_compile_bytecode(&stage.reflection.entry_point_name, "hs_6_0")?,
-                );
+                hs_bytecode = Some(module.get_or_compile_bytecode(
+                    &stage.reflection.entry_point_name,
+                    "hs_6_0",
+                    pipeline_def.specialization_constants,
+                )?);
             }
 
             if stage
@@ -330,9 +338,11 @@ impl RafxPipelineDx12 {
                 .shader_stage
                 .intersects(RafxShaderStageFlags::GEOMETRY)
             {
-                gs_bytecode = Some(
-                    module.get_or_compile_bytecode(&stage.reflection.entry_point_name, "gs_6_0")?,
-                );
+                gs_bytecode = Some(module.get_or_compile_bytecode(
+                    &stage.reflection.entry_point_name,
+                    "gs_6_0",
+                    pipeline_def.specialization_constants,
+                )?);
             }
 
             if stage
@@ -340,9 +350,11 @@ impl RafxPipelineDx12 {
                 .shader_stage
                 .intersects(RafxShaderStageFlags::MESH)
             {
-                ms_bytecode = Some(
-                    module.get_or_compile_bytecode(&stage.reflection.entry_point_name, "ms_6_5")?,
-                );
+                ms_bytecode = Some(module.get_or_compile_bytecode(
+                    &stage.reflection.entry_point_name,
+                    "ms_6_5",
+                    pipeline_def.specialization_constants,
+                )?);
             }
 
             if stage
@@ -350,9 +362,11 @@ impl RafxPipelineDx12 {
                 .shader_stage
                 .intersects(RafxShaderStageFlags::AMPLIFICATION)
             {
-                as_bytecode = Some(
-                    module.get_or_compile_bytecode(&stage.reflection.entry_point_name, "as_6_5")?,
-                );
+                as_bytecode = Some(module.get_or_compile_bytecode(
+                    &stage.reflection.entry_point_name,
+                    "as_6_5",
+                    pipeline_def.specialization_constants,
+                )?);
             }
         }
 
@@ -619,9 +633,11 @@ impl RafxPipelineDx12 {
                 .intersects(RafxShaderStageFlags::COMPUTE)
             {
                 assert!(cs_bytecode.is_none());
-                cs_bytecode = Some(
-                    module.get_or_compile_bytecode(&stage.reflection.entry_point_name, "cs_6_0")?,
-                );
+                cs_bytecode = Some(module.get_or_compile_bytecode(
+                    &stage.reflection.entry_point_name,
+                    "cs_6_0",
+                    pipeline_def.specialization_constants,
+                )?);
             } else {
                 Err("Tried to create compute pipeline with a non-compute shader stage specified")?;
             }
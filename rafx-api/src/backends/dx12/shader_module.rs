@@ -1,6 +1,6 @@
 use super::d3d12;
 use crate::dx12::RafxDeviceContextDx12;
-use crate::{RafxResult, RafxShaderModule, RafxShaderModuleDefDx12};
+use crate::{RafxResult, RafxShaderModule, RafxShaderModuleDefDx12, RafxSpecializationConstant};
 use fnv::{FnvHashMap, FnvHasher};
 use std::hash::{Hash, Hasher};
 use std::sync::{Arc, Mutex};
@@ -8,10 +8,12 @@ use std::sync::{Arc, Mutex};
 fn hash_compile_params(
     entry_point: &str,
     target_profile: &str,
+    specialization_constants: &[RafxSpecializationConstant],
 ) -> u64 {
     let mut hasher = FnvHasher::default();
     entry_point.hash(&mut hasher);
     target_profile.hash(&mut hasher);
+    specialization_constants.hash(&mut hasher);
     hasher.finish()
 }
 
@@ -65,8 +67,10 @@ impl RafxShaderModuleDx12 {
         &self,
         entry_point: &str,
         target_profile: &str,
+        specialization_constants: &[RafxSpecializationConstant],
     ) -> RafxResult<Dx12ShaderBytecode> {
-        let compile_params_hash = hash_compile_params(entry_point, target_profile);
+        let compile_params_hash =
+            hash_compile_params(entry_point, target_profile, specialization_constants);
 
         let mut dxil_cache = self.inner.dxil_cache.lock().unwrap();
         if let Some(cached_dxil) = dxil_cache.get(&compile_params_hash) {
@@ -75,13 +79,19 @@ impl RafxShaderModuleDx12 {
 
         // We don't have bytecode available, need to compile it
         let src = self.inner.hlsl_src.as_ref().unwrap();
+        let defines =
+            crate::internal_shared::specialization_constant_defines(specialization_constants);
+        let defines: Vec<_> = defines
+            .iter()
+            .map(|(name, value)| (name.as_str(), Some(value.as_str())))
+            .collect();
         let mut bytecode = hassle_rs::compile_hlsl(
             "shader.hlsl",
             src,
             entry_point,
             target_profile,
             &["/Zi"],
-            &[],
+            &defines,
         )?;
 
         hassle_rs::fake_sign_dxil_in_place(&mut bytecode);
@@ -4,8 +4,8 @@ use crate::dx12::{
 };
 use crate::{
     RafxExtents3D, RafxFormat, RafxResourceType, RafxResult, RafxSampleCount,
-    RafxSwapchainColorSpace, RafxSwapchainDef, RafxSwapchainImage, RafxTexture, RafxTextureDef,
-    RafxTextureDimensions,
+    RafxSwapchainColorSpace, RafxSwapchainDef, RafxSwapchainDisplayLuminance, RafxSwapchainImage,
+    RafxTexture, RafxTextureDef, RafxTextureDimensions,
 };
 use raw_window_handle::{HasRawDisplayHandle, HasRawWindowHandle, RawWindowHandle};
 use windows::core::Interface;
@@ -15,11 +15,43 @@ use super::dxgi;
 
 const SWAPCHAIN_IMAGE_COUNT: u32 = 3;
 
+// Returns the swapchain buffer format, the format we expose for it, and the DXGI color space to
+// present with. Only DXGI_FORMAT_R16G16B16A16_FLOAT, DXGI_FORMAT_B8G8R8A8_UNORM,
+// DXGI_FORMAT_R8G8B8A8_UNORM and DXGI_FORMAT_R10G10B10A2_UNORM are allowed for flip model swapchains
+fn swapchain_formats(
+    color_space: RafxSwapchainColorSpace
+) -> Option<(
+    dxgi::Common::DXGI_FORMAT,
+    RafxFormat,
+    dxgi::Common::DXGI_COLOR_SPACE_TYPE,
+)> {
+    match color_space {
+        RafxSwapchainColorSpace::Srgb => Some((
+            dxgi::Common::DXGI_FORMAT_B8G8R8A8_UNORM,
+            RafxFormat::B8G8R8A8_SRGB,
+            dxgi::Common::DXGI_COLOR_SPACE_RGB_FULL_G22_NONE_P709,
+        )),
+        // scRGB
+        RafxSwapchainColorSpace::SrgbExtended => Some((
+            dxgi::Common::DXGI_FORMAT_R16G16B16A16_FLOAT,
+            RafxFormat::R16G16B16A16_SFLOAT,
+            dxgi::Common::DXGI_COLOR_SPACE_RGB_FULL_G10_NONE_P709,
+        )),
+        RafxSwapchainColorSpace::DisplayP3Extended => None,
+        RafxSwapchainColorSpace::Hdr10 => Some((
+            dxgi::Common::DXGI_FORMAT_R10G10B10A2_UNORM,
+            RafxFormat::A2R10G10B10_UNORM_PACK32,
+            dxgi::Common::DXGI_COLOR_SPACE_RGB_FULL_G2084_NONE_P2020,
+        )),
+    }
+}
+
 pub struct RafxSwapchainDx12 {
     device_context: RafxDeviceContextDx12,
     swapchain_def: RafxSwapchainDef,
     format: RafxFormat,
     color_space: RafxSwapchainColorSpace,
+    display_luminance: Option<RafxSwapchainDisplayLuminance>,
     swapchain_images: Vec<RafxSwapchainImage>,
     swapchain: dxgi::IDXGISwapChain3,
     swapchain_flags: dxgi::DXGI_SWAP_CHAIN_FLAG,
@@ -47,6 +79,10 @@ impl RafxSwapchainDx12 {
         self.color_space
     }
 
+    pub fn display_luminance(&self) -> Option<RafxSwapchainDisplayLuminance> {
+        self.display_luminance
+    }
+
     pub fn dx12_swapchain(&self) -> &dxgi::IDXGISwapChain3 {
         &self.swapchain
     }
@@ -62,26 +98,9 @@ impl RafxSwapchainDx12 {
         swapchain_def: &RafxSwapchainDef,
         present_queue: &RafxQueueDx12,
     ) -> RafxResult<RafxSwapchainDx12> {
-        //TODO: Select format
-        let preferred_color_space = RafxSwapchainColorSpace::Srgb;
-        //let preferred_color_space = swapchain_def.color_space_priority[0];
-        // let (pixel_format, swapchain_format, is_extended) = match preferred_color_space {
-        //     RafxSwapchainColorSpace::Srgb => (
-        //         metal_rs::MTLPixelFormat::BGRA8Unorm_sRGB,
-        //         RafxFormat::B8G8R8A8_SRGB,
-        //         false,
-        //     ),
-        //     RafxSwapchainColorSpace::SrgbExtended | RafxSwapchainColorSpace::DisplayP3Extended => (
-        //         metal_rs::MTLPixelFormat::RGBA16Float,
-        //         RafxFormat::R16G16B16A16_SFLOAT,
-        //         true,
-        //     ),
-        // };
-
-        // Only DXGI_FORMAT_R16G16B16A16_FLOAT, DXGI_FORMAT_B8G8R8A8_UNORM, DXGI_FORMAT_R8G8B8A8_UNORM, DXGI_FORMAT_R10G10B10A2_UNORM allowed?
-        let pixel_format = dxgi::Common::DXGI_FORMAT_B8G8R8A8_UNORM;
-        let swapchain_format = RafxFormat::B8G8R8A8_SRGB;
-        //let is_extended = false;
+        // The swapchain is created as SRGB and then switched to the preferred color space, since
+        // checking color space support requires an existing swapchain
+        let (pixel_format, _, _) = swapchain_formats(RafxSwapchainColorSpace::Srgb).unwrap();
 
         let hwnd = match raw_window_handle.raw_window_handle() {
             RawWindowHandle::Win32(hwnd) => {
@@ -123,6 +142,10 @@ impl RafxSwapchainDx12 {
             swapchain
         };
 
+        let (color_space, swapchain_format) =
+            Self::configure_color_space(&swapchain, swapchain_def)?;
+        let display_luminance = Self::query_display_luminance(&swapchain);
+
         let swapchain_images = Self::create_swapchain_images(
             device_context,
             &swapchain_def,
@@ -136,7 +159,8 @@ impl RafxSwapchainDx12 {
             device_context: device_context.clone(),
             swapchain_def,
             format: swapchain_format,
-            color_space: preferred_color_space,
+            color_space,
+            display_luminance,
             swapchain_images,
             swapchain,
             swapchain_flags: dxgi::DXGI_SWAP_CHAIN_FLAG(0),
@@ -144,6 +168,93 @@ impl RafxSwapchainDx12 {
         })
     }
 
+    // Resizes the swapchain buffers to the format of the first color space in the def's priority
+    // list that the output supports presenting, falling back to SRGB. The swapchain's buffers must
+    // not be referenced when this is called.
+    fn configure_color_space(
+        swapchain: &IDXGISwapChain3,
+        swapchain_def: &RafxSwapchainDef,
+    ) -> RafxResult<(RafxSwapchainColorSpace, RafxFormat)> {
+        let candidates = swapchain_def
+            .color_space_priority
+            .iter()
+            .copied()
+            .chain(std::iter::once(RafxSwapchainColorSpace::Srgb));
+
+        for color_space in candidates {
+            let (pixel_format, swapchain_format, dxgi_color_space) =
+                match swapchain_formats(color_space) {
+                    Some(formats) => formats,
+                    None => {
+                        log::debug!("Color space {:?} not supported on dx12", color_space);
+                        continue;
+                    }
+                };
+
+            unsafe {
+                // The output can't present HDR color spaces if HDR is disabled in the OS display
+                // settings. Check before resizing so that unsupported color spaces don't change the
+                // buffer format.
+                let supports_present = swapchain
+                    .CheckColorSpaceSupport(dxgi_color_space)
+                    .map(|support| {
+                        (support & dxgi::DXGI_SWAP_CHAIN_COLOR_SPACE_SUPPORT_FLAG_PRESENT.0 as u32)
+                            != 0
+                    })
+                    .unwrap_or(false);
+                if !supports_present {
+                    log::debug!("Color space {:?} not supported by output", color_space);
+                    continue;
+                }
+
+                swapchain.ResizeBuffers(
+                    SWAPCHAIN_IMAGE_COUNT,
+                    swapchain_def.width,
+                    swapchain_def.height,
+                    pixel_format,
+                    0,
+                )?;
+
+                if let Err(e) = swapchain.SetColorSpace1(dxgi_color_space) {
+                    log::debug!(
+                        "Color space {:?} not supported by output: {}",
+                        color_space,
+                        e
+                    );
+                    continue;
+                }
+            }
+
+            log::debug!(
+                "Choosing color space {:?} with format {:?}",
+                color_space,
+                swapchain_format
+            );
+            return Ok((color_space, swapchain_format));
+        }
+
+        Err("Could not find a supported swapchain color space".into())
+    }
+
+    fn query_display_luminance(
+        swapchain: &IDXGISwapChain3
+    ) -> Option<RafxSwapchainDisplayLuminance> {
+        // Requires IDXGIOutput6 (windows 10 1703+) and fails if the window isn't on an output yet
+        let desc = unsafe {
+            let output = swapchain.GetContainingOutput().ok()?;
+            let output = output.cast::<dxgi::IDXGIOutput6>().ok()?;
+            let mut desc = Default::default();
+            output.GetDesc1(&mut desc).ok()?;
+            desc
+        };
+
+        Some(RafxSwapchainDisplayLuminance {
+            min_luminance_nits: desc.MinLuminance,
+            max_luminance_nits: desc.MaxLuminance,
+            max_full_frame_luminance_nits: desc.MaxFullFrameLuminance,
+        })
+    }
+
     fn create_swapchain_images(
         device_context: &RafxDeviceContextDx12,
         swapchain_def: &&RafxSwapchainDef,
@@ -200,20 +311,11 @@ impl RafxSwapchainDx12 {
         // set frame fence events
 
         // release swapchain buffers
-        //TODO: Select format
-        //let preferred_color_space = RafxSwapchainColorSpace::Srgb;
-        let pixel_format = dxgi::Common::DXGI_FORMAT_B8G8R8A8_UNORM;
-        let swapchain_format = RafxFormat::B8G8R8A8_SRGB;
-        //let is_extended = false;
-        unsafe {
-            self.swapchain.ResizeBuffers(
-                SWAPCHAIN_IMAGE_COUNT,
-                swapchain_def.width,
-                swapchain_def.height,
-                pixel_format,
-                0,
-            )?;
-        }
+        let (color_space, swapchain_format) =
+            Self::configure_color_space(&self.swapchain, swapchain_def)?;
+        self.color_space = color_space;
+        self.format = swapchain_format;
+        self.display_luminance = Self::query_display_luminance(&self.swapchain);
 
         let swapchain_images = Self::create_swapchain_images(
             &self.device_context,
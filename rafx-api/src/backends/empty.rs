@@ -163,6 +163,7 @@ impl RafxCommandBufferEmpty {
     pub fn cmd_draw_mesh(&self, group_count_x: u32, group_count_y: u32, group_count_z: u32) -> RafxResult<()> { unimplemented!() }
 
     pub fn cmd_dispatch(&self, group_count_x: u32, group_count_y: u32, group_count_z: u32) -> RafxResult<()> { unimplemented!() }
+    pub fn cmd_dispatch_indirect(&self, indirect_buffer: &RafxBufferEmpty, indirect_buffer_offset_in_bytes: u32) -> RafxResult<()> { unimplemented!() }
 
     pub fn cmd_resource_barrier(&self, buffer_barriers: &[RafxBufferBarrier], texture_barriers: &[RafxTextureBarrier]) -> RafxResult<()> { unimplemented!() }
     pub fn cmd_copy_buffer_to_buffer(&self, src_buffer: &RafxBufferEmpty, dst_buffer: &RafxBufferEmpty, params: &RafxCmdCopyBufferToBufferParams) -> RafxResult<()> { unimplemented!() }
@@ -194,6 +195,7 @@ impl RafxSwapchainEmpty {
     pub fn image_count(&self) -> usize { unimplemented!() }
     pub fn format(&self) -> RafxFormat { unimplemented!() }
     pub fn color_space(&self) -> RafxSwapchainColorSpace { unimplemented!() }
+    pub fn display_luminance(&self) -> Option<RafxSwapchainDisplayLuminance> { unimplemented!() }
     pub fn acquire_next_image_fence(&mut self, fence: &RafxFenceEmpty) -> RafxResult<RafxSwapchainImage> { unimplemented!() }
     pub fn acquire_next_image_semaphore(&mut self, semaphore: &RafxSemaphoreEmpty) -> RafxResult<RafxSwapchainImage> { unimplemented!() }
     pub fn rebuild(&mut self, swapchain_def: &RafxSwapchainDef) -> RafxResult<()> { unimplemented!() }
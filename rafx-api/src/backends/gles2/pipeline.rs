@@ -103,10 +103,21 @@ impl RafxPipelineGles2 {
         let gl_context = device_context.gl_context();
         let shader = pipeline_def.shader.gles2_shader().unwrap();
 
+        // Specialization constants are compiled into the shader source, so specialized pipelines
+        // need their own shader objects. They can be dropped once the program is linked.
+        let (vertex_shader, fragment_shader) = if pipeline_def.specialization_constants.is_empty() {
+            (
+                shader.gl_vertex_shader().clone(),
+                shader.gl_fragment_shader().clone(),
+            )
+        } else {
+            shader.compile_specialized_shaders(pipeline_def.specialization_constants)?
+        };
+
         // Create a new program so that we can customize the vertex attributes
         let program_id = gl_context.gl_create_program()?;
-        gl_context.gl_attach_shader(program_id, shader.gl_vertex_shader().shader_id())?;
-        gl_context.gl_attach_shader(program_id, shader.gl_fragment_shader().shader_id())?;
+        gl_context.gl_attach_shader(program_id, vertex_shader.shader_id())?;
+        gl_context.gl_attach_shader(program_id, fragment_shader.shader_id())?;
 
         let gl_root_signature = pipeline_def.root_signature.gles2_root_signature().unwrap();
 
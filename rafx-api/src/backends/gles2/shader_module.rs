@@ -1,5 +1,8 @@
 use crate::gles2::{gles2_bindings, RafxDeviceContextGles2, ShaderId};
-use crate::{RafxResult, RafxShaderModule, RafxShaderModuleDefGles2, RafxShaderStageFlags};
+use crate::{
+    RafxResult, RafxShaderModule, RafxShaderModuleDefGles2, RafxShaderStageFlags,
+    RafxSpecializationConstant,
+};
 use rafx_base::trust_cell::TrustCell;
 use std::ffi::{CStr, CString};
 use std::sync::Arc;
@@ -102,6 +105,38 @@ impl RafxShaderModuleGles2 {
             };
         }
 
+        let compiled_shader = self.compile_shader_src(stage, &self.inner.src)?;
+        *previously_compiled_shader = Some(compiled_shader.clone());
+
+        Ok(compiled_shader)
+    }
+
+    // Specialization constants are baked into the source code, so unlike compile_shader() the
+    // result is not cached on the module
+    pub(crate) fn compile_specialized_shader(
+        &self,
+        stage: RafxShaderStageFlags,
+        specialization_constants: &[RafxSpecializationConstant],
+    ) -> RafxResult<Gles2CompiledShader> {
+        let src = self
+            .inner
+            .src
+            .to_str()
+            .map_err(|_| "Could not convert GL src from cstring to string")?;
+        let defines =
+            crate::internal_shared::specialization_constant_defines(specialization_constants);
+        let specialized_src = crate::internal_shared::insert_glsl_defines(src, &defines);
+        let specialized_src = CString::new(specialized_src)
+            .map_err(|_| "Could not convert GL src from string to cstring")?;
+
+        self.compile_shader_src(stage, &specialized_src)
+    }
+
+    fn compile_shader_src(
+        &self,
+        stage: RafxShaderStageFlags,
+        src: &CString,
+    ) -> RafxResult<Gles2CompiledShader> {
         let gl_stage = if stage == RafxShaderStageFlags::VERTEX {
             gles2_bindings::VERTEX_SHADER
         } else if stage == RafxShaderStageFlags::FRAGMENT {
@@ -111,7 +146,7 @@ impl RafxShaderModuleGles2 {
         };
 
         let gl_context = self.inner.device_context.gl_context();
-        let shader_id = gl_context.compile_shader(gl_stage, &self.inner.src)?;
+        let shader_id = gl_context.compile_shader(gl_stage, src)?;
 
         let inner = Gles2CompiledShaderInner {
             device_context: self.inner.device_context.clone(),
@@ -119,13 +154,9 @@ impl RafxShaderModuleGles2 {
             stage,
         };
 
-        let compiled_shader = Gles2CompiledShader {
+        Ok(Gles2CompiledShader {
             inner: Arc::new(inner),
-        };
-
-        *previously_compiled_shader = Some(compiled_shader.clone());
-
-        Ok(compiled_shader)
+        })
     }
 }
 
@@ -1,5 +1,8 @@
 use crate::gles3::{Gles3CompiledShader, ProgramId, RafxDeviceContextGles3};
-use crate::{RafxPipelineReflection, RafxResult, RafxShaderStageDef, RafxShaderStageFlags};
+use crate::{
+    RafxPipelineReflection, RafxResult, RafxShaderStageDef, RafxShaderStageFlags,
+    RafxSpecializationConstant,
+};
 use std::sync::Arc;
 
 #[derive(Debug)]
@@ -52,6 +55,33 @@ impl RafxShaderGles3 {
         &self.inner.fragment_shader
     }
 
+    // Returns the vertex and fragment shaders compiled with the given specialization constants
+    pub(crate) fn compile_specialized_shaders(
+        &self,
+        specialization_constants: &[RafxSpecializationConstant],
+    ) -> RafxResult<(Gles3CompiledShader, Gles3CompiledShader)> {
+        let mut vertex_shader = None;
+        let mut fragment_shader = None;
+        for stage in &self.inner.stages {
+            let compiled = stage
+                .shader_module
+                .gles3_shader_module()
+                .unwrap()
+                .compile_specialized_shader(
+                    stage.reflection.shader_stage,
+                    specialization_constants,
+                )?;
+            if stage.reflection.shader_stage == RafxShaderStageFlags::VERTEX {
+                vertex_shader = Some(compiled);
+            } else if stage.reflection.shader_stage == RafxShaderStageFlags::FRAGMENT {
+                fragment_shader = Some(compiled);
+            }
+        }
+
+        // RafxShaderGles3::new() already verified that both stages exist
+        Ok((vertex_shader.unwrap(), fragment_shader.unwrap()))
+    }
+
     pub fn new(
         device_context: &RafxDeviceContextGles3,
         stages: Vec<RafxShaderStageDef>,
@@ -2,8 +2,8 @@ use crate::backends::gles3::RafxTextureGles3;
 use crate::gles3::{GlContext, RafxDeviceContextGles3, RafxFenceGles3, RafxSemaphoreGles3};
 use crate::{
     RafxExtents3D, RafxFormat, RafxResourceType, RafxResult, RafxSampleCount,
-    RafxSwapchainColorSpace, RafxSwapchainDef, RafxSwapchainImage, RafxTexture, RafxTextureDef,
-    RafxTextureDimensions,
+    RafxSwapchainColorSpace, RafxSwapchainDef, RafxSwapchainDisplayLuminance, RafxSwapchainImage,
+    RafxTexture, RafxTextureDef, RafxTextureDimensions,
 };
 use raw_window_handle::{HasRawDisplayHandle, HasRawWindowHandle};
 use std::sync::Arc;
@@ -39,6 +39,11 @@ impl RafxSwapchainGles3 {
         RafxSwapchainColorSpace::Srgb
     }
 
+    pub fn display_luminance(&self) -> Option<RafxSwapchainDisplayLuminance> {
+        // Not exposed by this API
+        None
+    }
+
     pub fn surface_context(&self) -> &Arc<GlContext> {
         &self.surface_context
     }
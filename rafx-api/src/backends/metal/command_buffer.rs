@@ -989,6 +989,29 @@ impl RafxCommandBufferMetal {
         Ok(())
     }
 
+    pub fn cmd_dispatch_indirect(
+        &self,
+        indirect_buffer: &RafxBufferMetal,
+        indirect_buffer_offset_in_bytes: u32,
+    ) -> RafxResult<()> {
+        let features = self.queue.device_context().metal_features();
+        assert!(features.supports_indirect_buffers);
+
+        let inner = self.inner.borrow();
+        self.wait_for_barriers(&*inner)?;
+
+        inner
+            .compute_encoder
+            .as_ref()
+            .unwrap()
+            .dispatch_thread_groups_indirect(
+                indirect_buffer.metal_buffer(),
+                indirect_buffer_offset_in_bytes as _,
+                inner.threads_per_compute_threadgroup,
+            );
+        Ok(())
+    }
+
     pub fn cmd_resource_barrier(
         &self,
         buffer_barriers: &[RafxBufferBarrier],
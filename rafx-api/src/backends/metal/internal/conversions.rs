@@ -24,6 +24,7 @@ impl Into<CFStringRef> for RafxSwapchainColorSpace {
                 RafxSwapchainColorSpace::DisplayP3Extended => {
                     super::extra_ffi::kCGColorSpaceExtendedLinearDisplayP3
                 }
+                RafxSwapchainColorSpace::Hdr10 => super::extra_ffi::kCGColorSpaceITUR_2100_PQ,
             }
         }
     }
@@ -10,6 +10,7 @@ extern "C" {
     pub static kCGColorSpaceSRGB: CFStringRef;
     pub static kCGColorSpaceExtendedLinearSRGB: CFStringRef;
     pub static kCGColorSpaceExtendedLinearDisplayP3: CFStringRef;
+    pub static kCGColorSpaceITUR_2100_PQ: CFStringRef;
 }
 
 pub fn set_colorspace(
@@ -1,7 +1,8 @@
 use crate::metal::RafxDeviceContextMetal;
 use crate::{
     RafxComputePipelineDef, RafxGraphicsPipelineDef, RafxPipelineType, RafxResult,
-    RafxRootSignature, RafxShaderStageFlags,
+    RafxRootSignature, RafxShaderStageFlags, RafxSpecializationConstant,
+    RafxSpecializationConstantValue,
 };
 
 fn threads_per_group_to_mtl_size(
@@ -16,6 +17,45 @@ fn threads_per_group_to_mtl_size(
     ))
 }
 
+// spirv_cross declares specialization constants as function constants, using the constant ID as
+// the function constant index
+fn metal_function_constant_values(
+    specialization_constants: &[RafxSpecializationConstant]
+) -> Option<metal_rs::FunctionConstantValues> {
+    if specialization_constants.is_empty() {
+        return None;
+    }
+
+    let values = metal_rs::FunctionConstantValues::new();
+    for constant in specialization_constants {
+        let index = constant.constant_id as _;
+        match &constant.value {
+            RafxSpecializationConstantValue::Bool(x) => values.set_constant_value_at_index(
+                x as *const bool as _,
+                metal_rs::MTLDataType::Bool,
+                index,
+            ),
+            RafxSpecializationConstantValue::Int(x) => values.set_constant_value_at_index(
+                x as *const i32 as _,
+                metal_rs::MTLDataType::Int,
+                index,
+            ),
+            RafxSpecializationConstantValue::UInt(x) => values.set_constant_value_at_index(
+                x as *const u32 as _,
+                metal_rs::MTLDataType::UInt,
+                index,
+            ),
+            RafxSpecializationConstantValue::Float(x) => values.set_constant_value_at_index(
+                x as *const f32 as _,
+                metal_rs::MTLDataType::Float,
+                index,
+            ),
+        }
+    }
+
+    Some(values)
+}
+
 fn metal_entry_point_name(name: &str) -> &str {
     // "main" is not an allowed entry point name. spirv_cross adds a 0 to the end of any
     // unallowed entry point names so do that here too
@@ -100,6 +140,9 @@ impl RafxPipelineMetal {
         device_context: &RafxDeviceContextMetal,
         pipeline_def: &RafxGraphicsPipelineDef,
     ) -> RafxResult<Self> {
+        let function_constant_values =
+            metal_function_constant_values(pipeline_def.specialization_constants);
+
         let mut vertex_function = None;
         let mut fragment_function = None;
         let mut mesh_function = None;
@@ -122,7 +165,7 @@ impl RafxPipelineMetal {
                         .metal_shader_module()
                         .unwrap()
                         .library()
-                        .get_function(entry_point, None)?,
+                        .get_function(entry_point, function_constant_values.clone())?,
                 );
             }
 
@@ -139,7 +182,7 @@ impl RafxPipelineMetal {
                         .metal_shader_module()
                         .unwrap()
                         .library()
-                        .get_function(entry_point, None)?,
+                        .get_function(entry_point, function_constant_values.clone())?,
                 );
             }
 
@@ -156,7 +199,7 @@ impl RafxPipelineMetal {
                         .metal_shader_module()
                         .unwrap()
                         .library()
-                        .get_function(entry_point, None)?,
+                        .get_function(entry_point, function_constant_values.clone())?,
                 );
                 threads_per_mesh_threadgroup =
                     threads_per_group_to_mtl_size(stage.reflection.compute_threads_per_group)?;
@@ -175,7 +218,7 @@ impl RafxPipelineMetal {
                         .metal_shader_module()
                         .unwrap()
                         .library()
-                        .get_function(entry_point, None)?,
+                        .get_function(entry_point, function_constant_values.clone())?,
                 );
                 threads_per_object_threadgroup =
                     threads_per_group_to_mtl_size(stage.reflection.compute_threads_per_group)?;
@@ -350,6 +393,9 @@ impl RafxPipelineMetal {
         device_context: &RafxDeviceContextMetal,
         pipeline_def: &RafxComputePipelineDef,
     ) -> RafxResult<Self> {
+        let function_constant_values =
+            metal_function_constant_values(pipeline_def.specialization_constants);
+
         let mut compute_function = None;
         let mut compute_threads_per_group = None;
 
@@ -368,7 +414,7 @@ impl RafxPipelineMetal {
                         .metal_shader_module()
                         .unwrap()
                         .library()
-                        .get_function(entry_point, None)?,
+                        .get_function(entry_point, function_constant_values.clone())?,
                 );
 
                 compute_threads_per_group = stage.reflection.compute_threads_per_group;
@@ -3,8 +3,8 @@ use crate::backends::metal::RafxTextureMetal;
 use crate::metal::{RafxDeviceContextMetal, RafxFenceMetal, RafxRawImageMetal, RafxSemaphoreMetal};
 use crate::{
     RafxExtents3D, RafxFormat, RafxResourceType, RafxResult, RafxSampleCount,
-    RafxSwapchainColorSpace, RafxSwapchainDef, RafxSwapchainImage, RafxTexture, RafxTextureDef,
-    RafxTextureDimensions,
+    RafxSwapchainColorSpace, RafxSwapchainDef, RafxSwapchainDisplayLuminance, RafxSwapchainImage,
+    RafxTexture, RafxTextureDef, RafxTextureDimensions,
 };
 use core_graphics_types::geometry::CGSize;
 use rafx_base::trust_cell::TrustCell;
@@ -80,6 +80,11 @@ impl RafxSwapchainMetal {
         &self.edr_info
     }
 
+    pub fn display_luminance(&self) -> Option<RafxSwapchainDisplayLuminance> {
+        // AppKit only reports brightness relative to SDR white (see edr_info()), not in nits
+        None
+    }
+
     pub fn new(
         device_context: &RafxDeviceContextMetal,
         _raw_display_handle: &dyn HasRawDisplayHandle,
@@ -130,6 +135,12 @@ impl RafxSwapchainMetal {
                 RafxFormat::R16G16B16A16_SFLOAT,
                 true,
             ),
+            // PQ-encoded content is only presented as HDR if the layer opts into EDR
+            RafxSwapchainColorSpace::Hdr10 => (
+                metal_rs::MTLPixelFormat::BGR10A2Unorm,
+                RafxFormat::A2R10G10B10_UNORM_PACK32,
+                true,
+            ),
         };
 
         // Metal supports all color spaces
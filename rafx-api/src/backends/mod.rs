@@ -13,6 +13,9 @@ pub mod gles2;
 #[cfg(feature = "rafx-gles3")]
 pub mod gles3;
 
+#[cfg(feature = "rafx-webgpu")]
+pub mod webgpu;
+
 #[cfg(any(
     feature = "rafx-empty",
     not(any(
@@ -20,7 +23,8 @@ pub mod gles3;
         feature = "rafx-metal",
         feature = "rafx-vulkan",
         feature = "rafx-gles2",
-        feature = "rafx-gles3"
+        feature = "rafx-gles3",
+        feature = "rafx-webgpu"
     ))
 ))]
 #[doc(hidden)]
@@ -610,6 +610,22 @@ impl RafxCommandBufferVulkan {
         Ok(())
     }
 
+    pub fn cmd_dispatch_indirect(
+        &self,
+        indirect_buffer: &RafxBufferVulkan,
+        indirect_buffer_offset_in_bytes: u32,
+    ) -> RafxResult<()> {
+        unsafe {
+            self.device_context.device().cmd_dispatch_indirect(
+                self.vk_command_buffer,
+                indirect_buffer.vk_buffer(),
+                indirect_buffer_offset_in_bytes as _,
+            )
+        }
+
+        Ok(())
+    }
+
     pub fn cmd_resource_barrier(
         &self,
         buffer_barriers: &[RafxBufferBarrier],
@@ -15,6 +15,7 @@ impl Into<vk::ColorSpaceKHR> for RafxSwapchainColorSpace {
             // Vulkan API only supports non-extended P3 and hardware support for even that is pretty
             // much non-existent
             RafxSwapchainColorSpace::DisplayP3Extended => unimplemented!(),
+            RafxSwapchainColorSpace::Hdr10 => vk::ColorSpaceKHR::HDR10_ST2084_EXT,
         }
     }
 }
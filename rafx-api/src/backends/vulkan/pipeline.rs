@@ -114,6 +114,12 @@ impl RafxPipelineVulkan {
                 .push(CString::new(stage.reflection.entry_point_name.clone()).unwrap());
         }
 
+        let (specialization_map_entries, specialization_data) =
+            Self::specialization_map_entries_and_data(pipeline_def.specialization_constants);
+        let specialization_info = vk::SpecializationInfo::builder()
+            .map_entries(&specialization_map_entries)
+            .data(&specialization_data);
+
         let mut stages = vec![];
         for (stage, entry_point_cstr) in pipeline_def
             .shader
@@ -134,6 +140,7 @@ impl RafxPipelineVulkan {
                             .vk_shader_module(),
                     )
                     .stage(stage.reflection.shader_stage.into())
+                    .specialization_info(&specialization_info)
                     .build(),
             );
         }
@@ -243,6 +250,25 @@ impl RafxPipelineVulkan {
         Ok(pipeline)
     }
 
+    // Every constant is 32 bits, packed tightly. Entries for constant IDs that a stage doesn't
+    // declare are ignored by vulkan, so the same data can be used for all stages.
+    fn specialization_map_entries_and_data(
+        specialization_constants: &[RafxSpecializationConstant]
+    ) -> (Vec<vk::SpecializationMapEntry>, Vec<u8>) {
+        let mut map_entries = Vec::with_capacity(specialization_constants.len());
+        let mut data = Vec::with_capacity(specialization_constants.len() * 4);
+        for constant in specialization_constants {
+            map_entries.push(vk::SpecializationMapEntry {
+                constant_id: constant.constant_id,
+                offset: data.len() as u32,
+                size: 4,
+            });
+            data.extend_from_slice(&constant.value.to_bits().to_ne_bytes());
+        }
+
+        (map_entries, data)
+    }
+
     pub fn new_compute_pipeline(
         device_context: &RafxDeviceContextVulkan,
         pipeline_def: &RafxComputePipelineDef,
@@ -262,6 +288,12 @@ impl RafxPipelineVulkan {
                 .push(CString::new(stage.reflection.entry_point_name.clone()).unwrap());
         }
 
+        let (specialization_map_entries, specialization_data) =
+            Self::specialization_map_entries_and_data(pipeline_def.specialization_constants);
+        let specialization_info = vk::SpecializationInfo::builder()
+            .map_entries(&specialization_map_entries)
+            .data(&specialization_data);
+
         let compute_stage = &vk_shader.stages()[0];
         let entry_point_name =
             CString::new(compute_stage.reflection.entry_point_name.clone()).unwrap();
@@ -274,7 +306,8 @@ impl RafxPipelineVulkan {
                     .unwrap()
                     .vk_shader_module(),
             )
-            .stage(vk::ShaderStageFlags::COMPUTE);
+            .stage(vk::ShaderStageFlags::COMPUTE)
+            .specialization_info(&specialization_info);
 
         let pipeline_create_info = vk::ComputePipelineCreateInfo::builder()
             .stage(*stage)
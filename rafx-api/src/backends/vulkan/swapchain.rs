@@ -128,6 +128,12 @@ impl RafxSwapchainVulkan {
         self.swapchain.swapchain_info.color_space
     }
 
+    pub fn display_luminance(&self) -> Option<RafxSwapchainDisplayLuminance> {
+        // Vulkan has no way to query the display's luminance range (VK_EXT_hdr_metadata only goes
+        // the other direction)
+        None
+    }
+
     pub fn new(
         device_context: &RafxDeviceContextVulkan,
         raw_display_handle: &dyn HasRawDisplayHandle,
@@ -844,7 +850,7 @@ fn add_compatible_surface_formats(
         }
         RafxSwapchainColorSpace::SrgbExtended => {
             formats.push(PreferredFormat::new(
-                RafxSwapchainColorSpace::Srgb,
+                RafxSwapchainColorSpace::SrgbExtended,
                 vk::Format::R16G16B16A16_SFLOAT,
                 vk::ColorSpaceKHR::EXTENDED_SRGB_LINEAR_EXT,
             ));
@@ -852,5 +858,71 @@ fn add_compatible_surface_formats(
         RafxSwapchainColorSpace::DisplayP3Extended => {
             unimplemented!("Not supported in vulkan backend");
         }
+        RafxSwapchainColorSpace::Hdr10 => {
+            formats.push(PreferredFormat::new(
+                RafxSwapchainColorSpace::Hdr10,
+                vk::Format::A2B10G10R10_UNORM_PACK32,
+                vk::ColorSpaceKHR::HDR10_ST2084_EXT,
+            ));
+            formats.push(PreferredFormat::new(
+                RafxSwapchainColorSpace::Hdr10,
+                vk::Format::A2R10G10B10_UNORM_PACK32,
+                vk::ColorSpaceKHR::HDR10_ST2084_EXT,
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn surface_format(
+        format: vk::Format,
+        color_space: vk::ColorSpaceKHR,
+    ) -> vk::SurfaceFormatKHR {
+        vk::SurfaceFormatKHR {
+            format,
+            color_space,
+        }
+    }
+
+    #[test]
+    fn choose_swapchain_format_reports_chosen_color_space() {
+        let srgb = surface_format(vk::Format::B8G8R8A8_SRGB, vk::ColorSpaceKHR::SRGB_NONLINEAR);
+        let scrgb = surface_format(
+            vk::Format::R16G16B16A16_SFLOAT,
+            vk::ColorSpaceKHR::EXTENDED_SRGB_LINEAR_EXT,
+        );
+        let hdr10 = surface_format(
+            vk::Format::A2B10G10R10_UNORM_PACK32,
+            vk::ColorSpaceKHR::HDR10_ST2084_EXT,
+        );
+        let available_formats = [srgb, scrgb, hdr10];
+
+        let (color_space, format) = RafxSwapchainVulkanInstance::choose_swapchain_format(
+            &[RafxSwapchainColorSpace::SrgbExtended],
+            &available_formats,
+        );
+        assert_eq!(color_space, RafxSwapchainColorSpace::SrgbExtended);
+        assert_eq!(format, scrgb);
+
+        let (color_space, format) = RafxSwapchainVulkanInstance::choose_swapchain_format(
+            &[
+                RafxSwapchainColorSpace::Hdr10,
+                RafxSwapchainColorSpace::SrgbExtended,
+            ],
+            &available_formats,
+        );
+        assert_eq!(color_space, RafxSwapchainColorSpace::Hdr10);
+        assert_eq!(format, hdr10);
+
+        // Falls back to SRGB when no preferred color space is available
+        let (color_space, format) = RafxSwapchainVulkanInstance::choose_swapchain_format(
+            &[RafxSwapchainColorSpace::SrgbExtended],
+            &[srgb],
+        );
+        assert_eq!(color_space, RafxSwapchainColorSpace::Srgb);
+        assert_eq!(format, srgb);
     }
 }
@@ -0,0 +1,963 @@
+use crate::{RafxApiDef, RafxResult};
+use raw_window_handle::{HasRawDisplayHandle, HasRawWindowHandle};
+use std::sync::Arc;
+
+use crate::webgpu::{RafxDeviceContextWebGpu, RafxDeviceContextWebGpuInner, WebGpuWindowHandles};
+
+/// WebGPU-specific configuration
+pub struct RafxApiDefWebGpu {
+    /// Which native APIs wgpu is allowed to use. On the web this should include BROWSER_WEBGPU.
+    pub backends: wgpu::Backends,
+
+    /// Used when selecting an adapter if the system has more than one
+    pub power_preference: wgpu::PowerPreference,
+
+    /// Request a software adapter. Mainly useful for testing, see `RafxApi::new_webgpu_headless`.
+    pub force_fallback_adapter: bool,
+}
+
+impl Default for RafxApiDefWebGpu {
+    fn default() -> Self {
+        RafxApiDefWebGpu {
+            backends: wgpu::Backends::all(),
+            power_preference: wgpu::PowerPreference::HighPerformance,
+            force_fallback_adapter: false,
+        }
+    }
+}
+
+pub struct RafxApiWebGpu {
+    device_context: Option<RafxDeviceContextWebGpu>,
+}
+
+impl Drop for RafxApiWebGpu {
+    fn drop(&mut self) {
+        self.destroy().unwrap();
+    }
+}
+
+impl RafxApiWebGpu {
+    pub fn device_context(&self) -> &RafxDeviceContextWebGpu {
+        self.device_context.as_ref().unwrap()
+    }
+
+    pub fn new(
+        display: &dyn HasRawDisplayHandle,
+        window: &dyn HasRawWindowHandle,
+        _api_def: &RafxApiDef,
+        webgpu_api_def: &RafxApiDefWebGpu,
+    ) -> RafxResult<Self> {
+        let window_handles = WebGpuWindowHandles::new(display, window);
+        let inner = Arc::new(RafxDeviceContextWebGpuInner::new(
+            Some(&window_handles),
+            webgpu_api_def,
+        )?);
+        let device_context = RafxDeviceContextWebGpu::new(inner)?;
+
+        Ok(RafxApiWebGpu {
+            device_context: Some(device_context),
+        })
+    }
+
+    /// Create a device that is not associated with a window. Swapchains can't be created from it.
+    pub fn new_headless(
+        _api_def: &RafxApiDef,
+        webgpu_api_def: &RafxApiDefWebGpu,
+    ) -> RafxResult<Self> {
+        let inner = Arc::new(RafxDeviceContextWebGpuInner::new(None, webgpu_api_def)?);
+        let device_context = RafxDeviceContextWebGpu::new(inner)?;
+
+        Ok(RafxApiWebGpu {
+            device_context: Some(device_context),
+        })
+    }
+
+    pub fn destroy(&mut self) -> RafxResult<()> {
+        if let Some(device_context) = self.device_context.take() {
+            // Clear any internal caches that may hold references to the device
+            let inner = device_context.inner.clone();
+
+            #[cfg(debug_assertions)]
+            #[cfg(feature = "track-device-contexts")]
+            let _create_index = device_context.create_index;
+
+            // This should be the final device context
+            std::mem::drop(device_context);
+
+            let _strong_count = Arc::strong_count(&inner);
+            match Arc::try_unwrap(inner) {
+                Ok(inner) => std::mem::drop(inner),
+                Err(_arc) => {
+                    Err(format!(
+                        "Could not destroy device, {} references to it exist",
+                        _strong_count
+                    ))?;
+
+                    #[cfg(debug_assertions)]
+                    #[cfg(feature = "track-device-contexts")]
+                    {
+                        let mut all_contexts = _arc.all_contexts.lock().unwrap();
+                        all_contexts.remove(&_create_index);
+                        for (k, v) in all_contexts.iter_mut() {
+                            v.resolve();
+                            println!("context allocation: {}\n{:?}", k, v);
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+// The fields of RafxApiDef and RafxShaderModuleDef depend on which backends are enabled
+#[allow(clippy::needless_update)]
+mod tests {
+    use crate::*;
+
+    const DOUBLE_VALUES_WGSL: &str = r#"
+struct Values {
+    data: array<u32>,
+}
+
+@group(0) @binding(0)
+var<storage, read_write> values: Values;
+
+@compute @workgroup_size(64, 1, 1)
+fn main(@builtin(global_invocation_id) id: vec3<u32>) {
+    if (id.x < arrayLength(&values.data)) {
+        values.data[id.x] = values.data[id.x] * 2u;
+    }
+}
+"#;
+
+    const COPY_PARAMS_WGSL: &str = r#"
+struct Params {
+    value: vec4<u32>,
+}
+
+struct Output {
+    value: vec4<u32>,
+}
+
+@group(0) @binding(0)
+var<uniform> params: Params;
+
+@group(0) @binding(1)
+var<storage, read_write> output: Output;
+
+@compute @workgroup_size(1, 1, 1)
+fn main() {
+    output.value = params.value;
+}
+"#;
+
+    const SOLID_COLOR_WGSL: &str = r#"
+struct Material {
+    color: vec4<f32>,
+}
+
+@group(0) @binding(0)
+var<uniform> material: Material;
+
+@vertex
+fn vs_main(@location(0) position: vec2<f32>) -> @builtin(position) vec4<f32> {
+    return vec4<f32>(position, 0.0, 1.0);
+}
+
+@fragment
+fn fs_main() -> @location(0) vec4<f32> {
+    return material.color;
+}
+"#;
+
+    // Runs against a software adapter (for example llvmpipe or lavapipe). Machines that have no
+    // software adapter skip the test rather than fail it.
+    fn create_headless_api() -> Option<RafxApi> {
+        let api_def = RafxApiDef {
+            webgpu_options: Some(RafxApiDefWebGpu {
+                force_fallback_adapter: true,
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        match unsafe { RafxApi::new_webgpu_headless(&api_def) } {
+            Ok(api) => Some(api),
+            Err(e) => {
+                println!(
+                    "Skipping WebGPU test, no software adapter available: {:?}",
+                    e
+                );
+                None
+            }
+        }
+    }
+
+    fn submit_and_wait(
+        device_context: &RafxDeviceContext,
+        queue: &RafxQueue,
+        command_buffer: &RafxCommandBuffer,
+    ) {
+        let fence = device_context.create_fence().unwrap();
+        queue
+            .submit(&[command_buffer], &[], &[], Some(&fence))
+            .unwrap();
+        fence.wait().unwrap();
+    }
+
+    // rafx-api has no texture to buffer copy, so read the texture back through wgpu directly.
+    // Returns tightly packed rows.
+    fn read_back_texture(
+        device_context: &RafxDeviceContext,
+        texture: &RafxTexture,
+        bytes_per_pixel: u32,
+    ) -> Vec<u8> {
+        let device_context = device_context.webgpu_device_context().unwrap();
+        let texture = texture.webgpu_texture().unwrap();
+        let extents = texture.texture_def().extents;
+
+        let unpadded_bytes_per_row = extents.width * bytes_per_pixel;
+        let padded_bytes_per_row = rafx_base::memory::round_size_up_to_alignment_u32(
+            unpadded_bytes_per_row,
+            crate::webgpu::WEBGPU_COPY_BYTES_PER_ROW_ALIGNMENT,
+        );
+
+        let readback_buffer = device_context
+            .device()
+            .create_buffer(&wgpu::BufferDescriptor {
+                label: None,
+                size: (padded_bytes_per_row * extents.height) as u64,
+                usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
+                mapped_at_creation: false,
+            });
+
+        let mut encoder = device_context
+            .device()
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor { label: None });
+        encoder.copy_texture_to_buffer(
+            wgpu::ImageCopyTexture {
+                texture: texture.webgpu_texture(),
+                mip_level: 0,
+                origin: wgpu::Origin3d::ZERO,
+                aspect: wgpu::TextureAspect::All,
+            },
+            wgpu::ImageCopyBuffer {
+                buffer: &readback_buffer,
+                layout: wgpu::ImageDataLayout {
+                    offset: 0,
+                    bytes_per_row: Some(padded_bytes_per_row),
+                    rows_per_image: Some(extents.height),
+                },
+            },
+            wgpu::Extent3d {
+                width: extents.width,
+                height: extents.height,
+                depth_or_array_layers: 1,
+            },
+        );
+        device_context.queue().submit(Some(encoder.finish()));
+
+        let slice = readback_buffer.slice(..);
+        slice.map_async(wgpu::MapMode::Read, |result| result.unwrap());
+        device_context.device().poll(wgpu::Maintain::Wait);
+
+        let mut pixels = Vec::with_capacity((unpadded_bytes_per_row * extents.height) as usize);
+        for row in slice
+            .get_mapped_range()
+            .chunks(padded_bytes_per_row as usize)
+        {
+            pixels.extend_from_slice(&row[..unpadded_bytes_per_row as usize]);
+        }
+        readback_buffer.unmap();
+
+        pixels
+    }
+
+    #[test]
+    fn headless_compute_dispatch() {
+        let mut api = match create_headless_api() {
+            Some(api) => api,
+            None => return,
+        };
+
+        {
+            let device_context = api.device_context();
+            assert!(device_context.is_webgpu());
+
+            let input: Vec<u32> = (0..256).collect();
+            let buffer_size = (input.len() * std::mem::size_of::<u32>()) as u64;
+
+            let staging_buffer = device_context
+                .create_buffer(&RafxBufferDef::for_staging_buffer_data(
+                    &input,
+                    RafxResourceType::BUFFER,
+                ))
+                .unwrap();
+            staging_buffer.copy_to_host_visible_buffer(&input).unwrap();
+
+            let values_buffer = device_context
+                .create_buffer(&RafxBufferDef {
+                    size: buffer_size,
+                    memory_usage: RafxMemoryUsage::GpuOnly,
+                    resource_type: RafxResourceType::BUFFER_READ_WRITE,
+                    ..Default::default()
+                })
+                .unwrap();
+
+            let readback_buffer = device_context
+                .create_buffer(&RafxBufferDef {
+                    size: buffer_size,
+                    memory_usage: RafxMemoryUsage::GpuToCpu,
+                    resource_type: RafxResourceType::BUFFER,
+                    ..Default::default()
+                })
+                .unwrap();
+
+            let shader_module = device_context
+                .create_shader_module(RafxShaderModuleDef {
+                    webgpu: Some(RafxShaderModuleDefWebGpu::WgslSrc(DOUBLE_VALUES_WGSL)),
+                    ..Default::default()
+                })
+                .unwrap();
+
+            let shader = device_context
+                .create_shader(vec![RafxShaderStageDef {
+                    shader_module,
+                    reflection: RafxShaderStageReflection {
+                        shader_stage: RafxShaderStageFlags::COMPUTE,
+                        resources: vec![RafxShaderResource {
+                            resource_type: RafxResourceType::BUFFER_READ_WRITE,
+                            set_index: 0,
+                            binding: 0,
+                            element_count: 1,
+                            used_in_shader_stages: RafxShaderStageFlags::COMPUTE,
+                            name: Some("values".to_string()),
+                            ..Default::default()
+                        }],
+                        compute_threads_per_group: Some([64, 1, 1]),
+                        entry_point_name: "main".to_string(),
+                    },
+                }])
+                .unwrap();
+
+            let root_signature = device_context
+                .create_root_signature(&RafxRootSignatureDef {
+                    shaders: std::slice::from_ref(&shader),
+                    immutable_samplers: &[],
+                })
+                .unwrap();
+
+            let pipeline = device_context
+                .create_compute_pipeline(&RafxComputePipelineDef {
+                    shader: &shader,
+                    root_signature: &root_signature,
+                    specialization_constants: &[],
+                    debug_name: Some("double_values"),
+                })
+                .unwrap();
+
+            let mut descriptor_set_array = device_context
+                .create_descriptor_set_array(&RafxDescriptorSetArrayDef {
+                    root_signature: &root_signature,
+                    set_index: 0,
+                    array_length: 1,
+                })
+                .unwrap();
+            descriptor_set_array
+                .update_descriptor_set(&[RafxDescriptorUpdate {
+                    array_index: 0,
+                    descriptor_key: RafxDescriptorKey::Binding(0),
+                    elements: RafxDescriptorElements {
+                        buffers: Some(&[&values_buffer]),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                }])
+                .unwrap();
+
+            let queue = device_context
+                .create_queue(RafxQueueType::Graphics)
+                .unwrap();
+            let mut command_pool = queue
+                .create_command_pool(&RafxCommandPoolDef { transient: true })
+                .unwrap();
+            let command_buffer = command_pool
+                .create_command_buffer(&RafxCommandBufferDef {
+                    is_secondary: false,
+                })
+                .unwrap();
+
+            command_buffer.begin().unwrap();
+            command_buffer
+                .cmd_copy_buffer_to_buffer(
+                    &staging_buffer,
+                    &values_buffer,
+                    &RafxCmdCopyBufferToBufferParams {
+                        size: buffer_size,
+                        ..Default::default()
+                    },
+                )
+                .unwrap();
+            command_buffer
+                .cmd_resource_barrier(
+                    &[RafxBufferBarrier {
+                        buffer: &values_buffer,
+                        src_state: RafxResourceState::COPY_DST,
+                        dst_state: RafxResourceState::UNORDERED_ACCESS,
+                        queue_transition: RafxBarrierQueueTransition::None,
+                        offset_size: None,
+                    }],
+                    &[],
+                )
+                .unwrap();
+            command_buffer.cmd_bind_pipeline(&pipeline).unwrap();
+            command_buffer
+                .cmd_bind_descriptor_set(&descriptor_set_array, 0)
+                .unwrap();
+            command_buffer
+                .cmd_dispatch((input.len() as u32).div_ceil(64), 1, 1)
+                .unwrap();
+            command_buffer
+                .cmd_resource_barrier(
+                    &[RafxBufferBarrier {
+                        buffer: &values_buffer,
+                        src_state: RafxResourceState::UNORDERED_ACCESS,
+                        dst_state: RafxResourceState::COPY_SRC,
+                        queue_transition: RafxBarrierQueueTransition::None,
+                        offset_size: None,
+                    }],
+                    &[],
+                )
+                .unwrap();
+            command_buffer
+                .cmd_copy_buffer_to_buffer(
+                    &values_buffer,
+                    &readback_buffer,
+                    &RafxCmdCopyBufferToBufferParams {
+                        size: buffer_size,
+                        ..Default::default()
+                    },
+                )
+                .unwrap();
+            command_buffer.end().unwrap();
+
+            let fence = device_context.create_fence().unwrap();
+            queue
+                .submit(&[&command_buffer], &[], &[], Some(&fence))
+                .unwrap();
+            fence.wait().unwrap();
+
+            let mapped = readback_buffer.map_buffer().unwrap();
+            let output =
+                unsafe { std::slice::from_raw_parts(mapped as *const u32, input.len()) }.to_vec();
+            readback_buffer.unmap_buffer().unwrap();
+
+            let expected: Vec<u32> = input.iter().map(|x| x * 2).collect();
+            assert_eq!(output, expected);
+        }
+
+        api.destroy().unwrap();
+    }
+
+    #[test]
+    fn headless_texture_upload_and_readback() {
+        let mut api = match create_headless_api() {
+            Some(api) => api,
+            None => return,
+        };
+
+        {
+            let device_context = api.device_context();
+
+            const WIDTH: u32 = 16;
+            const HEIGHT: u32 = 8;
+            let pixels: Vec<u8> = (0..WIDTH * HEIGHT * 4).map(|x| (x * 7) as u8).collect();
+
+            // Buffer to texture copies read rows padded to the copy alignment
+            let padded_bytes_per_row = rafx_base::memory::round_size_up_to_alignment_u32(
+                WIDTH * 4,
+                crate::webgpu::WEBGPU_COPY_BYTES_PER_ROW_ALIGNMENT,
+            );
+            let mut staging_data = vec![0u8; (padded_bytes_per_row * HEIGHT) as usize];
+            for (row, pixel_row) in pixels.chunks((WIDTH * 4) as usize).enumerate() {
+                let offset = row * padded_bytes_per_row as usize;
+                staging_data[offset..offset + pixel_row.len()].copy_from_slice(pixel_row);
+            }
+
+            let staging_buffer = device_context
+                .create_buffer(&RafxBufferDef::for_staging_buffer_data(
+                    &staging_data,
+                    RafxResourceType::BUFFER,
+                ))
+                .unwrap();
+            staging_buffer
+                .copy_to_host_visible_buffer(&staging_data)
+                .unwrap();
+
+            let texture = device_context
+                .create_texture(&RafxTextureDef {
+                    extents: RafxExtents3D {
+                        width: WIDTH,
+                        height: HEIGHT,
+                        depth: 1,
+                    },
+                    format: RafxFormat::R8G8B8A8_UNORM,
+                    resource_type: RafxResourceType::TEXTURE,
+                    ..Default::default()
+                })
+                .unwrap();
+
+            let queue = device_context
+                .create_queue(RafxQueueType::Graphics)
+                .unwrap();
+            let mut command_pool = queue
+                .create_command_pool(&RafxCommandPoolDef { transient: true })
+                .unwrap();
+            let command_buffer = command_pool
+                .create_command_buffer(&RafxCommandBufferDef {
+                    is_secondary: false,
+                })
+                .unwrap();
+
+            command_buffer.begin().unwrap();
+            command_buffer
+                .cmd_copy_buffer_to_texture(
+                    &staging_buffer,
+                    &texture,
+                    &RafxCmdCopyBufferToTextureParams::default(),
+                )
+                .unwrap();
+            command_buffer.end().unwrap();
+            submit_and_wait(&device_context, &queue, &command_buffer);
+
+            assert_eq!(read_back_texture(&device_context, &texture, 4), pixels);
+        }
+
+        api.destroy().unwrap();
+    }
+
+    #[test]
+    fn headless_descriptor_set_updates() {
+        let mut api = match create_headless_api() {
+            Some(api) => api,
+            None => return,
+        };
+
+        {
+            let device_context = api.device_context();
+
+            let create_params_buffer = |value: [u32; 4]| {
+                let buffer = device_context
+                    .create_buffer(&RafxBufferDef::for_staging_uniform_buffer_data(&value))
+                    .unwrap();
+                buffer.copy_to_host_visible_buffer(&value).unwrap();
+                buffer
+            };
+            let create_output_buffer = || {
+                device_context
+                    .create_buffer(&RafxBufferDef {
+                        size: 16,
+                        memory_usage: RafxMemoryUsage::GpuToCpu,
+                        resource_type: RafxResourceType::BUFFER_READ_WRITE,
+                        ..Default::default()
+                    })
+                    .unwrap()
+            };
+            let read_output = |buffer: &RafxBuffer| {
+                let mapped = buffer.map_buffer().unwrap();
+                let output = unsafe { *(mapped as *const [u32; 4]) };
+                buffer.unmap_buffer().unwrap();
+                output
+            };
+
+            let params_a = create_params_buffer([1, 2, 3, 4]);
+            let params_b = create_params_buffer([5, 6, 7, 8]);
+            let params_c = create_params_buffer([9, 10, 11, 12]);
+            let output_0 = create_output_buffer();
+            let output_1 = create_output_buffer();
+
+            let shader_module = device_context
+                .create_shader_module(RafxShaderModuleDef {
+                    webgpu: Some(RafxShaderModuleDefWebGpu::WgslSrc(COPY_PARAMS_WGSL)),
+                    ..Default::default()
+                })
+                .unwrap();
+
+            let shader = device_context
+                .create_shader(vec![RafxShaderStageDef {
+                    shader_module,
+                    reflection: RafxShaderStageReflection {
+                        shader_stage: RafxShaderStageFlags::COMPUTE,
+                        resources: vec![
+                            RafxShaderResource {
+                                resource_type: RafxResourceType::UNIFORM_BUFFER,
+                                set_index: 0,
+                                binding: 0,
+                                element_count: 1,
+                                used_in_shader_stages: RafxShaderStageFlags::COMPUTE,
+                                name: Some("params".to_string()),
+                                ..Default::default()
+                            },
+                            RafxShaderResource {
+                                resource_type: RafxResourceType::BUFFER_READ_WRITE,
+                                set_index: 0,
+                                binding: 1,
+                                element_count: 1,
+                                used_in_shader_stages: RafxShaderStageFlags::COMPUTE,
+                                name: Some("output".to_string()),
+                                ..Default::default()
+                            },
+                        ],
+                        compute_threads_per_group: Some([1, 1, 1]),
+                        entry_point_name: "main".to_string(),
+                    },
+                }])
+                .unwrap();
+
+            let root_signature = device_context
+                .create_root_signature(&RafxRootSignatureDef {
+                    shaders: std::slice::from_ref(&shader),
+                    immutable_samplers: &[],
+                })
+                .unwrap();
+
+            let pipeline = device_context
+                .create_compute_pipeline(&RafxComputePipelineDef {
+                    shader: &shader,
+                    root_signature: &root_signature,
+                    specialization_constants: &[],
+                    debug_name: Some("copy_params"),
+                })
+                .unwrap();
+
+            // Each set in the array gets its own buffers
+            let mut descriptor_set_array = device_context
+                .create_descriptor_set_array(&RafxDescriptorSetArrayDef {
+                    root_signature: &root_signature,
+                    set_index: 0,
+                    array_length: 2,
+                })
+                .unwrap();
+            for (array_index, params, output) in
+                [(0, &params_a, &output_0), (1, &params_b, &output_1)]
+            {
+                descriptor_set_array
+                    .update_descriptor_set(&[
+                        RafxDescriptorUpdate {
+                            array_index,
+                            descriptor_key: RafxDescriptorKey::Binding(0),
+                            elements: RafxDescriptorElements {
+                                buffers: Some(&[params]),
+                                ..Default::default()
+                            },
+                            ..Default::default()
+                        },
+                        RafxDescriptorUpdate {
+                            array_index,
+                            descriptor_key: RafxDescriptorKey::Binding(1),
+                            elements: RafxDescriptorElements {
+                                buffers: Some(&[output]),
+                                ..Default::default()
+                            },
+                            ..Default::default()
+                        },
+                    ])
+                    .unwrap();
+            }
+
+            let queue = device_context
+                .create_queue(RafxQueueType::Graphics)
+                .unwrap();
+            let mut command_pool = queue
+                .create_command_pool(&RafxCommandPoolDef { transient: true })
+                .unwrap();
+            let command_buffer = command_pool
+                .create_command_buffer(&RafxCommandBufferDef {
+                    is_secondary: false,
+                })
+                .unwrap();
+
+            command_buffer.begin().unwrap();
+            command_buffer.cmd_bind_pipeline(&pipeline).unwrap();
+            for array_index in 0..2 {
+                command_buffer
+                    .cmd_bind_descriptor_set(&descriptor_set_array, array_index)
+                    .unwrap();
+                command_buffer.cmd_dispatch(1, 1, 1).unwrap();
+            }
+            command_buffer.end().unwrap();
+            submit_and_wait(&device_context, &queue, &command_buffer);
+
+            assert_eq!(read_output(&output_0), [1, 2, 3, 4]);
+            assert_eq!(read_output(&output_1), [5, 6, 7, 8]);
+
+            // Rewriting a descriptor must be picked up the next time the set is bound
+            descriptor_set_array
+                .update_descriptor_set(&[RafxDescriptorUpdate {
+                    array_index: 0,
+                    descriptor_key: RafxDescriptorKey::Binding(0),
+                    elements: RafxDescriptorElements {
+                        buffers: Some(&[&params_c]),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                }])
+                .unwrap();
+
+            command_pool.reset_command_pool().unwrap();
+            command_buffer.begin().unwrap();
+            command_buffer.cmd_bind_pipeline(&pipeline).unwrap();
+            command_buffer
+                .cmd_bind_descriptor_set(&descriptor_set_array, 0)
+                .unwrap();
+            command_buffer.cmd_dispatch(1, 1, 1).unwrap();
+            command_buffer.end().unwrap();
+            submit_and_wait(&device_context, &queue, &command_buffer);
+
+            assert_eq!(read_output(&output_0), [9, 10, 11, 12]);
+            assert_eq!(read_output(&output_1), [5, 6, 7, 8]);
+
+            // WebGPU can't bind a set with unwritten descriptors, so binding one is an error
+            let mut incomplete_descriptor_set_array = device_context
+                .create_descriptor_set_array(&RafxDescriptorSetArrayDef {
+                    root_signature: &root_signature,
+                    set_index: 0,
+                    array_length: 1,
+                })
+                .unwrap();
+            incomplete_descriptor_set_array
+                .update_descriptor_set(&[RafxDescriptorUpdate {
+                    array_index: 0,
+                    descriptor_key: RafxDescriptorKey::Binding(0),
+                    elements: RafxDescriptorElements {
+                        buffers: Some(&[&params_a]),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                }])
+                .unwrap();
+
+            command_pool.reset_command_pool().unwrap();
+            command_buffer.begin().unwrap();
+            assert!(command_buffer
+                .cmd_bind_descriptor_set(&incomplete_descriptor_set_array, 0)
+                .is_err());
+            command_buffer.end().unwrap();
+        }
+
+        api.destroy().unwrap();
+    }
+
+    #[test]
+    fn headless_graphics_pipeline() {
+        let mut api = match create_headless_api() {
+            Some(api) => api,
+            None => return,
+        };
+
+        {
+            let device_context = api.device_context();
+
+            const SIZE: u32 = 16;
+            const FORMAT: RafxFormat = RafxFormat::R8G8B8A8_UNORM;
+
+            // Two triangles covering the left half of the render target
+            let vertices: [[f32; 2]; 6] = [
+                [-1.0, -1.0],
+                [0.0, -1.0],
+                [0.0, 1.0],
+                [-1.0, -1.0],
+                [0.0, 1.0],
+                [-1.0, 1.0],
+            ];
+            let vertex_buffer = device_context
+                .create_buffer(&RafxBufferDef::for_staging_vertex_buffer_data(&vertices))
+                .unwrap();
+            vertex_buffer
+                .copy_to_host_visible_buffer(&vertices)
+                .unwrap();
+
+            let color = [1.0f32, 0.0, 0.0, 1.0];
+            let material_buffer = device_context
+                .create_buffer(&RafxBufferDef::for_staging_uniform_buffer_data(&color))
+                .unwrap();
+            material_buffer.copy_to_host_visible_buffer(&color).unwrap();
+
+            let render_target = device_context
+                .create_texture(&RafxTextureDef {
+                    extents: RafxExtents3D {
+                        width: SIZE,
+                        height: SIZE,
+                        depth: 1,
+                    },
+                    format: FORMAT,
+                    resource_type: RafxResourceType::RENDER_TARGET_COLOR,
+                    ..Default::default()
+                })
+                .unwrap();
+
+            let create_stage =
+                |shader_stage, entry_point_name: &str, resources| RafxShaderStageDef {
+                    shader_module: device_context
+                        .create_shader_module(RafxShaderModuleDef {
+                            webgpu: Some(RafxShaderModuleDefWebGpu::WgslSrc(SOLID_COLOR_WGSL)),
+                            ..Default::default()
+                        })
+                        .unwrap(),
+                    reflection: RafxShaderStageReflection {
+                        shader_stage,
+                        resources,
+                        compute_threads_per_group: None,
+                        entry_point_name: entry_point_name.to_string(),
+                    },
+                };
+
+            let shader = device_context
+                .create_shader(vec![
+                    create_stage(RafxShaderStageFlags::VERTEX, "vs_main", vec![]),
+                    create_stage(
+                        RafxShaderStageFlags::FRAGMENT,
+                        "fs_main",
+                        vec![RafxShaderResource {
+                            resource_type: RafxResourceType::UNIFORM_BUFFER,
+                            set_index: 0,
+                            binding: 0,
+                            element_count: 1,
+                            used_in_shader_stages: RafxShaderStageFlags::FRAGMENT,
+                            name: Some("material".to_string()),
+                            ..Default::default()
+                        }],
+                    ),
+                ])
+                .unwrap();
+
+            let root_signature = device_context
+                .create_root_signature(&RafxRootSignatureDef {
+                    shaders: std::slice::from_ref(&shader),
+                    immutable_samplers: &[],
+                })
+                .unwrap();
+
+            let vertex_layout = RafxVertexLayout {
+                attributes: vec![RafxVertexLayoutAttribute {
+                    format: RafxFormat::R32G32_SFLOAT,
+                    buffer_index: 0,
+                    location: 0,
+                    byte_offset: 0,
+                    hlsl_semantic: "POSITION".to_string(),
+                    gl_attribute_name: None,
+                }],
+                buffers: vec![RafxVertexLayoutBuffer {
+                    stride: std::mem::size_of::<[f32; 2]>() as u32,
+                    rate: RafxVertexAttributeRate::Vertex,
+                }],
+            };
+
+            let pipeline = device_context
+                .create_graphics_pipeline(&RafxGraphicsPipelineDef {
+                    shader: &shader,
+                    root_signature: &root_signature,
+                    vertex_layout: &vertex_layout,
+                    blend_state: &Default::default(),
+                    depth_state: &Default::default(),
+                    rasterizer_state: &Default::default(),
+                    primitive_topology: RafxPrimitiveTopology::TriangleList,
+                    color_formats: &[FORMAT],
+                    depth_stencil_format: None,
+                    sample_count: RafxSampleCount::SampleCount1,
+                    specialization_constants: &[],
+                    debug_name: Some("solid_color"),
+                })
+                .unwrap();
+
+            let mut descriptor_set_array = device_context
+                .create_descriptor_set_array(&RafxDescriptorSetArrayDef {
+                    root_signature: &root_signature,
+                    set_index: 0,
+                    array_length: 1,
+                })
+                .unwrap();
+            descriptor_set_array
+                .update_descriptor_set(&[RafxDescriptorUpdate {
+                    array_index: 0,
+                    descriptor_key: RafxDescriptorKey::Binding(0),
+                    elements: RafxDescriptorElements {
+                        buffers: Some(&[&material_buffer]),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                }])
+                .unwrap();
+
+            let queue = device_context
+                .create_queue(RafxQueueType::Graphics)
+                .unwrap();
+            let mut command_pool = queue
+                .create_command_pool(&RafxCommandPoolDef { transient: true })
+                .unwrap();
+            let command_buffer = command_pool
+                .create_command_buffer(&RafxCommandBufferDef {
+                    is_secondary: false,
+                })
+                .unwrap();
+
+            command_buffer.begin().unwrap();
+            command_buffer
+                .cmd_begin_render_pass(
+                    &[RafxColorRenderTargetBinding {
+                        texture: &render_target,
+                        load_op: RafxLoadOp::Clear,
+                        store_op: RafxStoreOp::Store,
+                        clear_value: RafxColorClearValue([0.0, 0.0, 1.0, 1.0]),
+                        mip_slice: None,
+                        array_slice: None,
+                        resolve_target: None,
+                        resolve_store_op: RafxStoreOp::DontCare,
+                        resolve_mip_slice: None,
+                        resolve_array_slice: None,
+                    }],
+                    None,
+                )
+                .unwrap();
+            command_buffer.cmd_bind_pipeline(&pipeline).unwrap();
+            command_buffer
+                .cmd_bind_descriptor_set(&descriptor_set_array, 0)
+                .unwrap();
+            command_buffer
+                .cmd_bind_vertex_buffers(
+                    0,
+                    &[RafxVertexBufferBinding {
+                        buffer: &vertex_buffer,
+                        byte_offset: 0,
+                    }],
+                )
+                .unwrap();
+            command_buffer.cmd_draw(vertices.len() as u32, 0).unwrap();
+            command_buffer.cmd_end_render_pass().unwrap();
+            command_buffer.end().unwrap();
+            submit_and_wait(&device_context, &queue, &command_buffer);
+
+            let pixels = read_back_texture(&device_context, &render_target, 4);
+            for (index, pixel) in pixels.chunks(4).enumerate() {
+                let x = index as u32 % SIZE;
+                let expected = if x < SIZE / 2 {
+                    [255, 0, 0, 255]
+                } else {
+                    [0, 0, 255, 255]
+                };
+                assert_eq!(
+                    pixel,
+                    expected,
+                    "pixel {} of row {}",
+                    x,
+                    index as u32 / SIZE
+                );
+            }
+        }
+
+        api.destroy().unwrap();
+    }
+}
@@ -0,0 +1,289 @@
+use crate::webgpu::RafxDeviceContextWebGpu;
+use crate::{RafxBufferDef, RafxMemoryUsage, RafxResourceType, RafxResult};
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Arc, Mutex};
+
+// WebGPU does not allow persistently mapping a buffer, so CPU-visible buffers keep a CPU-side copy
+// of their contents. It is uploaded with queue.write_buffer() on unmap, and on every submit for as
+// long as the buffer stays mapped.
+#[derive(Debug)]
+pub(crate) struct WebGpuBufferContents {
+    buffer: Arc<wgpu::Buffer>,
+    data: Option<Mutex<Box<[u8]>>>,
+    allocation_size: u64,
+    mapped_count: AtomicU32,
+}
+
+impl WebGpuBufferContents {
+    pub(crate) fn is_mapped(&self) -> bool {
+        self.mapped_count.load(Ordering::Relaxed) > 0
+    }
+
+    fn data_ptr(&self) -> Option<*mut u8> {
+        Some(self.data.as_ref()?.lock().unwrap().as_mut_ptr())
+    }
+
+    pub(crate) fn flush(
+        &self,
+        queue: &wgpu::Queue,
+    ) {
+        self.flush_range(queue, 0, self.allocation_size);
+    }
+
+    fn flush_range(
+        &self,
+        queue: &wgpu::Queue,
+        offset: u64,
+        size: u64,
+    ) {
+        if let Some(data) = &self.data {
+            // write_buffer() requires 4-byte alignment. The allocation size is always a multiple
+            // of 4 so the rounded range never goes out of bounds
+            let begin = offset & !(wgpu::COPY_BUFFER_ALIGNMENT - 1);
+            let end = rafx_base::memory::round_size_up_to_alignment_u64(
+                offset + size,
+                wgpu::COPY_BUFFER_ALIGNMENT,
+            )
+            .min(self.allocation_size);
+
+            if end > begin {
+                let data = data.lock().unwrap();
+                queue.write_buffer(&self.buffer, begin, &data[begin as usize..end as usize]);
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct RafxBufferWebGpu {
+    device_context: RafxDeviceContextWebGpu,
+    buffer_def: RafxBufferDef,
+    contents: Arc<WebGpuBufferContents>,
+}
+
+impl Drop for RafxBufferWebGpu {
+    fn drop(&mut self) {
+        self.contents.buffer.destroy();
+    }
+}
+
+impl RafxBufferWebGpu {
+    pub fn buffer_def(&self) -> &RafxBufferDef {
+        &self.buffer_def
+    }
+
+    pub fn webgpu_buffer(&self) -> &wgpu::Buffer {
+        &self.contents.buffer
+    }
+
+    pub(crate) fn webgpu_buffer_arc(&self) -> &Arc<wgpu::Buffer> {
+        &self.contents.buffer
+    }
+
+    pub fn map_buffer(&self) -> RafxResult<*mut u8> {
+        if self.buffer_def.memory_usage == RafxMemoryUsage::GpuOnly {
+            return Err("Cannot map a buffer with RafxMemoryUsage::GpuOnly")?;
+        }
+
+        if self.buffer_def.memory_usage == RafxMemoryUsage::GpuToCpu {
+            self.read_back()?;
+        }
+
+        let previous_mapped_count = self.contents.mapped_count.fetch_add(1, Ordering::Acquire);
+        if previous_mapped_count == 0 {
+            self.device_context.register_mapped_buffer(&self.contents);
+        }
+
+        Ok(self
+            .contents
+            .data_ptr()
+            .expect("Buffer must be CPU-visible to be mapped"))
+    }
+
+    pub fn unmap_buffer(&self) -> RafxResult<()> {
+        // Flush on every unmap. Mapped buffers are also flushed on submit, so this only matters
+        // for buffers that are unmapped before commands are submitted.
+        if self.buffer_def.memory_usage != RafxMemoryUsage::GpuToCpu {
+            self.contents.flush(self.device_context.queue());
+        }
+
+        self.contents.mapped_count.fetch_sub(1, Ordering::Release);
+        Ok(())
+    }
+
+    pub fn mapped_memory(&self) -> Option<*mut u8> {
+        if self.contents.is_mapped() {
+            self.contents.data_ptr()
+        } else {
+            None
+        }
+    }
+
+    pub fn copy_to_host_visible_buffer<T: Copy>(
+        &self,
+        data: &[T],
+    ) -> RafxResult<()> {
+        self.copy_to_host_visible_buffer_with_offset(data, 0)
+    }
+
+    pub fn copy_to_host_visible_buffer_with_offset<T: Copy>(
+        &self,
+        data: &[T],
+        buffer_byte_offset: u64,
+    ) -> RafxResult<()> {
+        let data_size_in_bytes = rafx_base::memory::slice_size_in_bytes(data) as u64;
+        assert!(buffer_byte_offset + data_size_in_bytes <= self.buffer_def.size);
+
+        let src = data.as_ptr() as *const u8;
+
+        let required_alignment = std::mem::align_of::<T>();
+
+        unsafe {
+            let dst = self
+                .contents
+                .data_ptr()
+                .ok_or("Buffer must be CPU-visible to be written")?
+                .add(buffer_byte_offset as usize);
+            assert_eq!(((dst as usize) % required_alignment), 0);
+            std::ptr::copy_nonoverlapping(src, dst, data_size_in_bytes as usize);
+        }
+
+        self.contents.flush_range(
+            self.device_context.queue(),
+            buffer_byte_offset,
+            data_size_in_bytes,
+        );
+
+        Ok(())
+    }
+
+    // Copies the buffer into a staging buffer, waits for the GPU and copies the result into the
+    // CPU-side contents. This stalls, so GpuToCpu buffers should only be mapped after a fence has
+    // been waited on.
+    fn read_back(&self) -> RafxResult<()> {
+        let device = self.device_context.device();
+        let queue = self.device_context.queue();
+
+        let staging_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("rafx readback"),
+            size: self.contents.allocation_size,
+            usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+            label: Some("rafx readback"),
+        });
+        encoder.copy_buffer_to_buffer(
+            &self.contents.buffer,
+            0,
+            &staging_buffer,
+            0,
+            self.contents.allocation_size,
+        );
+        queue.submit(Some(encoder.finish()));
+
+        let (sender, receiver) = crossbeam_channel::bounded(1);
+        let slice = staging_buffer.slice(..);
+        slice.map_async(wgpu::MapMode::Read, move |result| {
+            let _ = sender.send(result);
+        });
+        device.poll(wgpu::Maintain::Wait);
+
+        receiver
+            .recv()
+            .map_err(|_| "Buffer readback did not complete")?
+            .map_err(|e| format!("Could not map buffer for readback: {:?}", e))?;
+
+        {
+            let mapped = slice.get_mapped_range();
+            let mut data = self.contents.data.as_ref().unwrap().lock().unwrap();
+            data.copy_from_slice(&mapped);
+        }
+
+        staging_buffer.unmap();
+        staging_buffer.destroy();
+        Ok(())
+    }
+
+    pub fn new(
+        device_context: &RafxDeviceContextWebGpu,
+        buffer_def: &RafxBufferDef,
+    ) -> RafxResult<Self> {
+        buffer_def.verify();
+
+        let mut usage = wgpu::BufferUsages::COPY_SRC | wgpu::BufferUsages::COPY_DST;
+        if buffer_def
+            .resource_type
+            .intersects(RafxResourceType::VERTEX_BUFFER)
+        {
+            usage |= wgpu::BufferUsages::VERTEX;
+        }
+        if buffer_def
+            .resource_type
+            .intersects(RafxResourceType::INDEX_BUFFER)
+        {
+            usage |= wgpu::BufferUsages::INDEX;
+        }
+        if buffer_def
+            .resource_type
+            .intersects(RafxResourceType::UNIFORM_BUFFER)
+        {
+            usage |= wgpu::BufferUsages::UNIFORM;
+        }
+        if buffer_def
+            .resource_type
+            .intersects(RafxResourceType::BUFFER | RafxResourceType::BUFFER_READ_WRITE)
+        {
+            usage |= wgpu::BufferUsages::STORAGE;
+        }
+        if buffer_def
+            .resource_type
+            .intersects(RafxResourceType::INDIRECT_BUFFER)
+        {
+            usage |= wgpu::BufferUsages::INDIRECT;
+        }
+
+        // Buffer sizes and copies must be 4-byte aligned
+        let allocation_size = rafx_base::memory::round_size_up_to_alignment_u64(
+            buffer_def.size.max(1),
+            wgpu::COPY_BUFFER_ALIGNMENT,
+        );
+
+        let buffer = device_context
+            .device()
+            .create_buffer(&wgpu::BufferDescriptor {
+                label: None,
+                size: allocation_size,
+                usage,
+                mapped_at_creation: false,
+            });
+
+        let data = if buffer_def.memory_usage != RafxMemoryUsage::GpuOnly {
+            Some(Mutex::new(
+                vec![0_u8; allocation_size as usize].into_boxed_slice(),
+            ))
+        } else {
+            None
+        };
+
+        let contents = Arc::new(WebGpuBufferContents {
+            buffer: Arc::new(buffer),
+            data,
+            allocation_size,
+            mapped_count: AtomicU32::new(0),
+        });
+
+        let buffer = RafxBufferWebGpu {
+            device_context: device_context.clone(),
+            buffer_def: buffer_def.clone(),
+            contents,
+        };
+
+        if buffer_def.always_mapped {
+            buffer.map_buffer()?;
+        }
+
+        Ok(buffer)
+    }
+}
@@ -0,0 +1,1146 @@
+use crate::webgpu::{
+    RafxBufferWebGpu, RafxCommandPoolWebGpu, RafxDescriptorSetArrayWebGpu,
+    RafxDescriptorSetHandleWebGpu, RafxDeviceContextWebGpu, RafxPipelineWebGpu, RafxQueueWebGpu,
+    RafxRootSignatureWebGpu, RafxTextureWebGpu, WebGpuPipeline,
+};
+use crate::{
+    RafxBufferBarrier, RafxCmdCopyBufferToBufferParams, RafxCmdCopyBufferToTextureParams,
+    RafxCmdCopyTextureToTextureParams, RafxColorRenderTargetBinding, RafxCommandBufferDef,
+    RafxDepthStencilRenderTargetBinding, RafxDescriptorIndex, RafxIndexBufferBinding, RafxResult,
+    RafxTextureBarrier, RafxVertexBufferBinding, MAX_DESCRIPTOR_SET_LAYOUTS,
+    MAX_VERTEX_INPUT_BINDINGS,
+};
+use rafx_base::trust_cell::TrustCell;
+use std::ops::Range;
+use std::sync::Arc;
+
+// Size of the arguments read by an indirect draw, see wgpu::util::DrawIndirect
+const DRAW_INDIRECT_STRIDE: u64 = 16;
+const DRAW_INDEXED_INDIRECT_STRIDE: u64 = 20;
+
+#[derive(Debug)]
+pub(crate) struct WebGpuColorAttachment {
+    view: Arc<wgpu::TextureView>,
+    resolve_target: Option<Arc<wgpu::TextureView>>,
+    ops: wgpu::Operations<wgpu::Color>,
+}
+
+#[derive(Debug)]
+pub(crate) struct WebGpuDepthStencilAttachment {
+    view: Arc<wgpu::TextureView>,
+    depth_ops: Option<wgpu::Operations<f32>>,
+    stencil_ops: Option<wgpu::Operations<u32>>,
+}
+
+#[derive(Debug)]
+pub(crate) struct WebGpuDispatch {
+    pipeline: Arc<wgpu::ComputePipeline>,
+    bind_groups: Vec<(u32, Arc<wgpu::BindGroup>)>,
+    push_constants: Option<Vec<u8>>,
+    group_count: WebGpuDispatchGroupCount,
+}
+
+#[derive(Debug)]
+pub(crate) enum WebGpuDispatchGroupCount {
+    Direct([u32; 3]),
+    Indirect {
+        buffer: Arc<wgpu::Buffer>,
+        offset: u64,
+    },
+}
+
+// wgpu render and compute passes borrow everything that is bound to them, so they can't outlive
+// a single function call. Commands are recorded into a list that owns the referenced resources
+// and are encoded when the command buffer is submitted.
+#[derive(Debug)]
+pub(crate) enum WebGpuCommand {
+    BeginRenderPass {
+        color_attachments: Vec<WebGpuColorAttachment>,
+        depth_stencil_attachment: Option<WebGpuDepthStencilAttachment>,
+    },
+    EndRenderPass,
+    SetViewport {
+        x: f32,
+        y: f32,
+        width: f32,
+        height: f32,
+        depth_min: f32,
+        depth_max: f32,
+    },
+    SetScissor {
+        x: u32,
+        y: u32,
+        width: u32,
+        height: u32,
+    },
+    SetStencilReference(u32),
+    SetRenderPipeline(Arc<wgpu::RenderPipeline>),
+    SetVertexBuffer {
+        slot: u32,
+        buffer: Arc<wgpu::Buffer>,
+        offset: u64,
+    },
+    SetIndexBuffer {
+        buffer: Arc<wgpu::Buffer>,
+        offset: u64,
+        format: wgpu::IndexFormat,
+    },
+    SetBindGroup {
+        index: u32,
+        bind_group: Arc<wgpu::BindGroup>,
+    },
+    SetPushConstants {
+        stages: wgpu::ShaderStages,
+        data: Vec<u8>,
+    },
+    Draw {
+        vertices: Range<u32>,
+        instances: Range<u32>,
+    },
+    DrawIndexed {
+        indices: Range<u32>,
+        base_vertex: i32,
+        instances: Range<u32>,
+    },
+    DrawIndirect {
+        buffer: Arc<wgpu::Buffer>,
+        offset: u64,
+    },
+    DrawIndexedIndirect {
+        buffer: Arc<wgpu::Buffer>,
+        offset: u64,
+    },
+    Dispatch(WebGpuDispatch),
+    CopyBufferToBuffer {
+        src: Arc<wgpu::Buffer>,
+        src_offset: u64,
+        dst: Arc<wgpu::Buffer>,
+        dst_offset: u64,
+        size: u64,
+    },
+    CopyBufferToTexture {
+        src: Arc<wgpu::Buffer>,
+        layout: wgpu::ImageDataLayout,
+        dst: Arc<wgpu::Texture>,
+        mip_level: u32,
+        origin: wgpu::Origin3d,
+        size: wgpu::Extent3d,
+    },
+    CopyTextureToTexture {
+        src: Arc<wgpu::Texture>,
+        src_mip_level: u32,
+        src_origin: wgpu::Origin3d,
+        dst: Arc<wgpu::Texture>,
+        dst_mip_level: u32,
+        dst_origin: wgpu::Origin3d,
+        size: wgpu::Extent3d,
+    },
+    PushDebugGroup(String),
+    PopDebugGroup,
+    GenerateMipmaps {
+        texture: Arc<wgpu::Texture>,
+        format: wgpu::TextureFormat,
+        mip_count: u32,
+        layer_count: u32,
+    },
+}
+
+#[derive(Debug, Default)]
+struct CommandBufferWebGpuState {
+    commands: Vec<WebGpuCommand>,
+    is_started: bool,
+    has_active_renderpass: bool,
+
+    // wgpu resets all bindings at the start of a pass, but rafx (like vulkan) keeps them for the
+    // lifetime of the command buffer. Track them so they can be re-applied when a pass begins.
+    current_pipeline: Option<WebGpuPipeline>,
+    current_root_signature: Option<RafxRootSignatureWebGpu>,
+    bind_groups: [Option<Arc<wgpu::BindGroup>>; MAX_DESCRIPTOR_SET_LAYOUTS],
+    vertex_buffers: [Option<(Arc<wgpu::Buffer>, u64)>; MAX_VERTEX_INPUT_BINDINGS],
+    index_buffer: Option<(Arc<wgpu::Buffer>, u64, wgpu::IndexFormat)>,
+    push_constants: Option<(wgpu::ShaderStages, Vec<u8>)>,
+
+    // Debug groups may not span across the boundary of a render pass
+    debug_group_depth: u32,
+    debug_group_depth_in_pass: u32,
+    deferred_debug_group_pops: u32,
+}
+
+impl CommandBufferWebGpuState {
+    fn push(
+        &mut self,
+        command: WebGpuCommand,
+    ) {
+        self.commands.push(command);
+    }
+
+    fn clear_bindings(&mut self) {
+        self.current_pipeline = None;
+        self.current_root_signature = None;
+        self.bind_groups = Default::default();
+        self.vertex_buffers = Default::default();
+        self.index_buffer = None;
+        self.push_constants = None;
+    }
+
+    fn apply_render_bindings(&mut self) {
+        if let Some(WebGpuPipeline::Render(pipeline)) = &self.current_pipeline {
+            self.commands
+                .push(WebGpuCommand::SetRenderPipeline(pipeline.clone()));
+        }
+
+        for (index, bind_group) in self.bind_groups.iter().enumerate() {
+            if let Some(bind_group) = bind_group {
+                self.commands.push(WebGpuCommand::SetBindGroup {
+                    index: index as u32,
+                    bind_group: bind_group.clone(),
+                });
+            }
+        }
+
+        for (slot, vertex_buffer) in self.vertex_buffers.iter().enumerate() {
+            if let Some((buffer, offset)) = vertex_buffer {
+                self.commands.push(WebGpuCommand::SetVertexBuffer {
+                    slot: slot as u32,
+                    buffer: buffer.clone(),
+                    offset: *offset,
+                });
+            }
+        }
+
+        if let Some((buffer, offset, format)) = &self.index_buffer {
+            self.commands.push(WebGpuCommand::SetIndexBuffer {
+                buffer: buffer.clone(),
+                offset: *offset,
+                format: *format,
+            });
+        }
+
+        if let Some((stages, data)) = &self.push_constants {
+            self.commands.push(WebGpuCommand::SetPushConstants {
+                stages: *stages,
+                data: data.clone(),
+            });
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct RafxCommandBufferWebGpu {
+    queue: RafxQueueWebGpu,
+    state: TrustCell<CommandBufferWebGpuState>,
+}
+
+impl RafxCommandBufferWebGpu {
+    fn device_context(&self) -> &RafxDeviceContextWebGpu {
+        self.queue.device_context()
+    }
+
+    pub fn new(
+        command_pool: &RafxCommandPoolWebGpu,
+        _command_buffer_def: &RafxCommandBufferDef,
+    ) -> RafxResult<RafxCommandBufferWebGpu> {
+        Ok(RafxCommandBufferWebGpu {
+            queue: command_pool.queue().clone(),
+            state: Default::default(),
+        })
+    }
+
+    pub fn begin(&self) -> RafxResult<()> {
+        let mut state = self.state.borrow_mut();
+        assert!(!state.is_started);
+        *state = CommandBufferWebGpuState::default();
+        state.is_started = true;
+
+        Ok(())
+    }
+
+    pub fn end(&self) -> RafxResult<()> {
+        if self.state.borrow().has_active_renderpass {
+            self.cmd_end_render_pass()?;
+        }
+
+        let mut state = self.state.borrow_mut();
+        assert!(state.is_started);
+
+        for _ in 0..state.debug_group_depth {
+            state.push(WebGpuCommand::PopDebugGroup);
+        }
+        state.debug_group_depth = 0;
+
+        state.is_started = false;
+        state.clear_bindings();
+
+        Ok(())
+    }
+
+    pub fn return_to_pool(&self) -> RafxResult<()> {
+        // don't need to do anything
+        Ok(())
+    }
+
+    pub fn cmd_begin_render_pass(
+        &self,
+        color_targets: &[RafxColorRenderTargetBinding],
+        depth_target: Option<RafxDepthStencilRenderTargetBinding>,
+    ) -> RafxResult<()> {
+        if self.state.borrow().has_active_renderpass {
+            self.cmd_end_render_pass()?;
+        }
+
+        let mut color_attachments = Vec::with_capacity(color_targets.len());
+        for color_target in color_targets {
+            let texture = color_target.texture.webgpu_texture().unwrap();
+            let view = texture.render_target_view(color_target.mip_slice, color_target.array_slice);
+
+            let resolve_target = color_target.resolve_target.map(|resolve_target| {
+                resolve_target.webgpu_texture().unwrap().render_target_view(
+                    color_target.resolve_mip_slice,
+                    color_target.resolve_array_slice,
+                )
+            });
+
+            color_attachments.push(WebGpuColorAttachment {
+                view,
+                resolve_target,
+                ops: wgpu::Operations {
+                    load: color_target
+                        .load_op
+                        .webgpu_load_op(color_target.clear_value.into()),
+                    store: color_target.store_op.webgpu_store(),
+                },
+            });
+        }
+
+        let depth_stencil_attachment = depth_target.map(|depth_target| {
+            let texture = depth_target.texture.webgpu_texture().unwrap();
+            let format = texture.texture_def().format;
+            let view = texture.render_target_view(depth_target.mip_slice, depth_target.array_slice);
+
+            // Ops may only be specified for aspects that the format has
+            let depth_ops = if format.has_depth() {
+                Some(wgpu::Operations {
+                    load: depth_target
+                        .depth_load_op
+                        .webgpu_load_op(depth_target.clear_value.depth),
+                    store: depth_target.depth_store_op.webgpu_store(),
+                })
+            } else {
+                None
+            };
+
+            let stencil_ops = if format.has_stencil() {
+                Some(wgpu::Operations {
+                    load: depth_target
+                        .stencil_load_op
+                        .webgpu_load_op(depth_target.clear_value.stencil),
+                    store: depth_target.stencil_store_op.webgpu_store(),
+                })
+            } else {
+                None
+            };
+
+            WebGpuDepthStencilAttachment {
+                view,
+                depth_ops,
+                stencil_ops,
+            }
+        });
+
+        let mut state = self.state.borrow_mut();
+        state.push(WebGpuCommand::BeginRenderPass {
+            color_attachments,
+            depth_stencil_attachment,
+        });
+        state.has_active_renderpass = true;
+        state.apply_render_bindings();
+
+        Ok(())
+    }
+
+    pub fn cmd_end_render_pass(&self) -> RafxResult<()> {
+        let mut state = self.state.borrow_mut();
+        if state.has_active_renderpass {
+            for _ in 0..state.debug_group_depth_in_pass {
+                state.push(WebGpuCommand::PopDebugGroup);
+            }
+            state.debug_group_depth_in_pass = 0;
+
+            state.push(WebGpuCommand::EndRenderPass);
+            state.has_active_renderpass = false;
+
+            for _ in 0..state.deferred_debug_group_pops {
+                state.push(WebGpuCommand::PopDebugGroup);
+            }
+            state.deferred_debug_group_pops = 0;
+        }
+
+        Ok(())
+    }
+
+    pub fn cmd_set_viewport(
+        &self,
+        x: f32,
+        y: f32,
+        width: f32,
+        height: f32,
+        depth_min: f32,
+        depth_max: f32,
+    ) -> RafxResult<()> {
+        self.state.borrow_mut().push(WebGpuCommand::SetViewport {
+            x,
+            y,
+            width,
+            height,
+            depth_min,
+            depth_max,
+        });
+        Ok(())
+    }
+
+    pub fn cmd_set_scissor(
+        &self,
+        x: u32,
+        y: u32,
+        width: u32,
+        height: u32,
+    ) -> RafxResult<()> {
+        self.state.borrow_mut().push(WebGpuCommand::SetScissor {
+            x,
+            y,
+            width,
+            height,
+        });
+        Ok(())
+    }
+
+    pub fn cmd_set_stencil_reference_value(
+        &self,
+        value: u32,
+    ) -> RafxResult<()> {
+        self.state
+            .borrow_mut()
+            .push(WebGpuCommand::SetStencilReference(value));
+        Ok(())
+    }
+
+    pub fn cmd_bind_pipeline(
+        &self,
+        pipeline: &RafxPipelineWebGpu,
+    ) -> RafxResult<()> {
+        let root_signature = pipeline.root_signature().webgpu_root_signature().unwrap();
+
+        let mut state = self.state.borrow_mut();
+        if state.current_root_signature.as_ref() != Some(root_signature) {
+            state.bind_groups = Default::default();
+            state.push_constants = None;
+            state.current_root_signature = Some(root_signature.clone());
+        }
+
+        state.current_pipeline = Some(pipeline.webgpu_pipeline().clone());
+        if let WebGpuPipeline::Render(render_pipeline) = pipeline.webgpu_pipeline() {
+            if state.has_active_renderpass {
+                state.push(WebGpuCommand::SetRenderPipeline(render_pipeline.clone()));
+            }
+        }
+
+        // WebGPU requires a bind group for every set in the pipeline layout, even if no shader
+        // uses it
+        for (set_index, layout) in root_signature.inner.layouts.iter().enumerate() {
+            if !layout.has_bindings
+                && set_index < root_signature.inner.bind_group_layouts.len()
+                && state.bind_groups[set_index].is_none()
+            {
+                let bind_group = root_signature.inner.empty_bind_group.clone();
+                state.bind_groups[set_index] = Some(bind_group.clone());
+                if state.has_active_renderpass {
+                    state.push(WebGpuCommand::SetBindGroup {
+                        index: set_index as u32,
+                        bind_group,
+                    });
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn cmd_bind_vertex_buffers(
+        &self,
+        first_binding: u32,
+        bindings: &[RafxVertexBufferBinding],
+    ) -> RafxResult<()> {
+        let mut state = self.state.borrow_mut();
+        for (i, binding) in bindings.iter().enumerate() {
+            let slot = first_binding + i as u32;
+            let buffer = binding
+                .buffer
+                .webgpu_buffer()
+                .unwrap()
+                .webgpu_buffer_arc()
+                .clone();
+
+            state.vertex_buffers[slot as usize] = Some((buffer.clone(), binding.byte_offset));
+            if state.has_active_renderpass {
+                state.push(WebGpuCommand::SetVertexBuffer {
+                    slot,
+                    buffer,
+                    offset: binding.byte_offset,
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn cmd_bind_index_buffer(
+        &self,
+        binding: &RafxIndexBufferBinding,
+    ) -> RafxResult<()> {
+        let buffer = binding
+            .buffer
+            .webgpu_buffer()
+            .unwrap()
+            .webgpu_buffer_arc()
+            .clone();
+        let format = binding.index_type.into();
+
+        let mut state = self.state.borrow_mut();
+        state.index_buffer = Some((buffer.clone(), binding.byte_offset, format));
+        if state.has_active_renderpass {
+            state.push(WebGpuCommand::SetIndexBuffer {
+                buffer,
+                offset: binding.byte_offset,
+                format,
+            });
+        }
+
+        Ok(())
+    }
+
+    pub fn cmd_bind_descriptor_set(
+        &self,
+        descriptor_set_array: &RafxDescriptorSetArrayWebGpu,
+        index: u32,
+    ) -> RafxResult<()> {
+        let bind_group = descriptor_set_array.bind_group(index)?;
+        self.do_bind_bind_group(descriptor_set_array.set_index(), bind_group);
+        Ok(())
+    }
+
+    pub fn cmd_bind_descriptor_set_handle(
+        &self,
+        _root_signature: &RafxRootSignatureWebGpu,
+        set_index: u32,
+        descriptor_set_handle: &RafxDescriptorSetHandleWebGpu,
+    ) -> RafxResult<()> {
+        let bind_group = descriptor_set_handle.bind_group()?;
+        self.do_bind_bind_group(set_index, bind_group);
+        Ok(())
+    }
+
+    fn do_bind_bind_group(
+        &self,
+        set_index: u32,
+        bind_group: Arc<wgpu::BindGroup>,
+    ) {
+        let mut state = self.state.borrow_mut();
+        state.bind_groups[set_index as usize] = Some(bind_group.clone());
+        if state.has_active_renderpass {
+            state.push(WebGpuCommand::SetBindGroup {
+                index: set_index,
+                bind_group,
+            });
+        }
+    }
+
+    pub fn cmd_bind_push_constant<T: Copy>(
+        &self,
+        root_signature: &RafxRootSignatureWebGpu,
+        descriptor_index: RafxDescriptorIndex,
+        data: &T,
+    ) -> RafxResult<()> {
+        let descriptor = root_signature.descriptor(descriptor_index).unwrap();
+        assert_eq!(
+            std::mem::size_of::<T>(),
+            descriptor.push_constant_size as usize
+        );
+        let stages = descriptor.webgpu_stages;
+        let data = rafx_base::memory::any_as_bytes(data).to_vec();
+
+        let mut state = self.state.borrow_mut();
+        state.push_constants = Some((stages, data.clone()));
+        if state.has_active_renderpass {
+            state.push(WebGpuCommand::SetPushConstants { stages, data });
+        }
+
+        Ok(())
+    }
+
+    pub fn cmd_draw(
+        &self,
+        vertex_count: u32,
+        first_vertex: u32,
+    ) -> RafxResult<()> {
+        self.cmd_draw_instanced(vertex_count, first_vertex, 1, 0)
+    }
+
+    pub fn cmd_draw_instanced(
+        &self,
+        vertex_count: u32,
+        first_vertex: u32,
+        instance_count: u32,
+        first_instance: u32,
+    ) -> RafxResult<()> {
+        self.state.borrow_mut().push(WebGpuCommand::Draw {
+            vertices: first_vertex..(first_vertex + vertex_count),
+            instances: first_instance..(first_instance + instance_count),
+        });
+        Ok(())
+    }
+
+    pub fn cmd_draw_indexed(
+        &self,
+        index_count: u32,
+        first_index: u32,
+        vertex_offset: i32,
+    ) -> RafxResult<()> {
+        self.cmd_draw_indexed_instanced(index_count, first_index, 1, 0, vertex_offset)
+    }
+
+    pub fn cmd_draw_indexed_instanced(
+        &self,
+        index_count: u32,
+        first_index: u32,
+        instance_count: u32,
+        first_instance: u32,
+        vertex_offset: i32,
+    ) -> RafxResult<()> {
+        self.state.borrow_mut().push(WebGpuCommand::DrawIndexed {
+            indices: first_index..(first_index + index_count),
+            base_vertex: vertex_offset,
+            instances: first_instance..(first_instance + instance_count),
+        });
+        Ok(())
+    }
+
+    pub fn cmd_draw_indirect(
+        &self,
+        indirect_buffer: &RafxBufferWebGpu,
+        indirect_buffer_offset_in_bytes: u32,
+        draw_count: u32,
+    ) -> RafxResult<()> {
+        // Multi-draw indirect is a native-only extension, so issue the draws one at a time
+        let mut state = self.state.borrow_mut();
+        for i in 0..draw_count as u64 {
+            state.push(WebGpuCommand::DrawIndirect {
+                buffer: indirect_buffer.webgpu_buffer_arc().clone(),
+                offset: indirect_buffer_offset_in_bytes as u64 + i * DRAW_INDIRECT_STRIDE,
+            });
+        }
+
+        Ok(())
+    }
+
+    pub fn cmd_draw_indexed_indirect(
+        &self,
+        indirect_buffer: &RafxBufferWebGpu,
+        indirect_buffer_offset_in_bytes: u32,
+        draw_count: u32,
+    ) -> RafxResult<()> {
+        let mut state = self.state.borrow_mut();
+        for i in 0..draw_count as u64 {
+            state.push(WebGpuCommand::DrawIndexedIndirect {
+                buffer: indirect_buffer.webgpu_buffer_arc().clone(),
+                offset: indirect_buffer_offset_in_bytes as u64 + i * DRAW_INDEXED_INDIRECT_STRIDE,
+            });
+        }
+
+        Ok(())
+    }
+
+    pub fn cmd_dispatch(
+        &self,
+        group_count_x: u32,
+        group_count_y: u32,
+        group_count_z: u32,
+    ) -> RafxResult<()> {
+        self.push_dispatch(
+            "cmd_dispatch",
+            WebGpuDispatchGroupCount::Direct([group_count_x, group_count_y, group_count_z]),
+        )
+    }
+
+    pub fn cmd_dispatch_indirect(
+        &self,
+        indirect_buffer: &RafxBufferWebGpu,
+        indirect_buffer_offset_in_bytes: u32,
+    ) -> RafxResult<()> {
+        self.push_dispatch(
+            "cmd_dispatch_indirect",
+            WebGpuDispatchGroupCount::Indirect {
+                buffer: indirect_buffer.webgpu_buffer_arc().clone(),
+                offset: indirect_buffer_offset_in_bytes as u64,
+            },
+        )
+    }
+
+    fn push_dispatch(
+        &self,
+        command_name: &str,
+        group_count: WebGpuDispatchGroupCount,
+    ) -> RafxResult<()> {
+        let mut state = self.state.borrow_mut();
+        assert!(
+            !state.has_active_renderpass,
+            "{} may not be called if inside render pass",
+            command_name
+        );
+
+        let pipeline = match &state.current_pipeline {
+            Some(WebGpuPipeline::Compute(pipeline)) => pipeline.clone(),
+            _ => Err(format!(
+                "{} called without a compute pipeline bound",
+                command_name
+            ))?,
+        };
+
+        let bind_groups = state
+            .bind_groups
+            .iter()
+            .enumerate()
+            .filter_map(|(index, bind_group)| {
+                bind_group
+                    .as_ref()
+                    .map(|bind_group| (index as u32, bind_group.clone()))
+            })
+            .collect();
+
+        let push_constants = state.push_constants.as_ref().map(|(_, data)| data.clone());
+
+        state.push(WebGpuCommand::Dispatch(WebGpuDispatch {
+            pipeline,
+            bind_groups,
+            push_constants,
+            group_count,
+        }));
+
+        Ok(())
+    }
+
+    pub fn cmd_resource_barrier(
+        &self,
+        _buffer_barriers: &[RafxBufferBarrier],
+        _texture_barriers: &[RafxTextureBarrier],
+    ) -> RafxResult<()> {
+        assert!(
+            !self.state.borrow().has_active_renderpass,
+            "cmd_resource_barrier may not be called if inside render pass"
+        );
+
+        // wgpu tracks resource usage and inserts barriers automatically
+        Ok(())
+    }
+
+    pub fn cmd_copy_buffer_to_buffer(
+        &self,
+        src_buffer: &RafxBufferWebGpu,
+        dst_buffer: &RafxBufferWebGpu,
+        params: &RafxCmdCopyBufferToBufferParams,
+    ) -> RafxResult<()> {
+        assert!(!self.state.borrow().has_active_renderpass);
+
+        self.state
+            .borrow_mut()
+            .push(WebGpuCommand::CopyBufferToBuffer {
+                src: src_buffer.webgpu_buffer_arc().clone(),
+                src_offset: params.src_byte_offset,
+                dst: dst_buffer.webgpu_buffer_arc().clone(),
+                dst_offset: params.dst_byte_offset,
+                // Buffer allocations are padded to 4 bytes so rounding up the size is safe
+                size: rafx_base::memory::round_size_up_to_alignment_u64(
+                    params.size,
+                    wgpu::COPY_BUFFER_ALIGNMENT,
+                ),
+            });
+        Ok(())
+    }
+
+    pub fn cmd_copy_buffer_to_texture(
+        &self,
+        src_buffer: &RafxBufferWebGpu,
+        dst_texture: &RafxTextureWebGpu,
+        params: &RafxCmdCopyBufferToTextureParams,
+    ) -> RafxResult<()> {
+        assert!(!self.state.borrow().has_active_renderpass);
+
+        let texture_def = dst_texture.texture_def();
+        let format = texture_def.format;
+        let mip_level = params.mip_level as u32;
+
+        let width = 1.max(texture_def.extents.width >> mip_level);
+        let height = 1.max(texture_def.extents.height >> mip_level);
+        let depth = 1.max(texture_def.extents.depth >> mip_level);
+
+        // Copies of block-compressed textures must cover whole blocks
+        let block_width = format.block_width_in_pixels();
+        let block_height = format.block_height_in_pixels();
+        let blocks_wide = width.div_ceil(block_width);
+        let blocks_high = height.div_ceil(block_height);
+
+        // Rows are padded to upload_texture_row_alignment by the uploader
+        let bytes_per_row = rafx_base::memory::round_size_up_to_alignment_u32(
+            blocks_wide * format.block_or_pixel_size_in_bytes(),
+            crate::webgpu::WEBGPU_COPY_BYTES_PER_ROW_ALIGNMENT,
+        );
+
+        self.state
+            .borrow_mut()
+            .push(WebGpuCommand::CopyBufferToTexture {
+                src: src_buffer.webgpu_buffer_arc().clone(),
+                layout: wgpu::ImageDataLayout {
+                    offset: params.buffer_offset,
+                    bytes_per_row: Some(bytes_per_row),
+                    rows_per_image: Some(blocks_high),
+                },
+                dst: dst_texture.webgpu_texture_arc().clone(),
+                mip_level,
+                origin: wgpu::Origin3d {
+                    x: 0,
+                    y: 0,
+                    z: params.array_layer as u32,
+                },
+                size: wgpu::Extent3d {
+                    width: blocks_wide * block_width,
+                    height: blocks_high * block_height,
+                    depth_or_array_layers: depth,
+                },
+            });
+
+        Ok(())
+    }
+
+    pub fn cmd_copy_texture_to_texture(
+        &self,
+        src_texture: &RafxTextureWebGpu,
+        dst_texture: &RafxTextureWebGpu,
+        params: &RafxCmdCopyTextureToTextureParams,
+    ) -> RafxResult<()> {
+        assert!(!self.state.borrow().has_active_renderpass);
+
+        let (first_layer, layer_count) = if let Some(array_slices) = params.array_slices {
+            (array_slices[0] as u32, 1)
+        } else {
+            (0, src_texture.texture_def().array_length)
+        };
+
+        let (src_z, dst_z, depth_or_array_layers) =
+            if src_texture.default_view_dimension() == wgpu::TextureViewDimension::D3 {
+                (
+                    params.src_offset.depth,
+                    params.dst_offset.depth,
+                    params.extents.depth,
+                )
+            } else {
+                let dst_layer = params
+                    .array_slices
+                    .map(|x| x[1] as u32)
+                    .unwrap_or(first_layer);
+                (first_layer, dst_layer, layer_count)
+            };
+
+        self.state
+            .borrow_mut()
+            .push(WebGpuCommand::CopyTextureToTexture {
+                src: src_texture.webgpu_texture_arc().clone(),
+                src_mip_level: params.src_mip_level as u32,
+                src_origin: wgpu::Origin3d {
+                    x: params.src_offset.width,
+                    y: params.src_offset.height,
+                    z: src_z,
+                },
+                dst: dst_texture.webgpu_texture_arc().clone(),
+                dst_mip_level: params.dst_mip_level as u32,
+                dst_origin: wgpu::Origin3d {
+                    x: params.dst_offset.width,
+                    y: params.dst_offset.height,
+                    z: dst_z,
+                },
+                size: wgpu::Extent3d {
+                    width: params.extents.width,
+                    height: params.extents.height,
+                    depth_or_array_layers,
+                },
+            });
+
+        Ok(())
+    }
+
+    pub(crate) fn cmd_generate_mipmaps(
+        &self,
+        texture: &RafxTextureWebGpu,
+    ) -> RafxResult<()> {
+        assert!(!self.state.borrow().has_active_renderpass);
+
+        let texture_def = texture.texture_def();
+        if texture_def.mip_count <= 1 {
+            return Ok(());
+        }
+
+        if texture.default_view_dimension() == wgpu::TextureViewDimension::D3 {
+            Err("Generating mipmaps for 3D textures is not supported by the WebGPU backend")?;
+        }
+
+        self.state
+            .borrow_mut()
+            .push(WebGpuCommand::GenerateMipmaps {
+                texture: texture.webgpu_texture_arc().clone(),
+                format: texture.webgpu_format(),
+                mip_count: texture_def.mip_count,
+                layer_count: texture_def.array_length,
+            });
+
+        Ok(())
+    }
+
+    pub fn cmd_push_group_debug_name(
+        &self,
+        name: impl AsRef<str>,
+    ) {
+        if self.device_context().device_info().debug_names_enabled {
+            let mut state = self.state.borrow_mut();
+            if state.has_active_renderpass {
+                state.debug_group_depth_in_pass += 1;
+            } else {
+                state.debug_group_depth += 1;
+            }
+
+            state.push(WebGpuCommand::PushDebugGroup(name.as_ref().to_string()));
+        }
+    }
+
+    pub fn cmd_pop_group_debug_name(&self) {
+        if self.device_context().device_info().debug_names_enabled {
+            let mut state = self.state.borrow_mut();
+            if state.has_active_renderpass && state.debug_group_depth_in_pass > 0 {
+                state.debug_group_depth_in_pass -= 1;
+                state.push(WebGpuCommand::PopDebugGroup);
+            } else if state.debug_group_depth > 0 {
+                state.debug_group_depth -= 1;
+                if state.has_active_renderpass {
+                    // The group was pushed outside the pass, pop it once the pass ends
+                    state.deferred_debug_group_pops += 1;
+                } else {
+                    state.push(WebGpuCommand::PopDebugGroup);
+                }
+            }
+        }
+    }
+
+    // Called by the queue on submit to encode the recorded commands
+    pub(crate) fn encode(
+        &self,
+        encoder: &mut wgpu::CommandEncoder,
+    ) -> RafxResult<()> {
+        let state = self.state.borrow();
+        assert!(!state.is_started);
+
+        let device_context = self.device_context();
+
+        let mut commands = state.commands.iter();
+        while let Some(command) = commands.next() {
+            match command {
+                WebGpuCommand::BeginRenderPass {
+                    color_attachments,
+                    depth_stencil_attachment,
+                } => {
+                    let color_attachments: Vec<_> = color_attachments
+                        .iter()
+                        .map(|x| {
+                            Some(wgpu::RenderPassColorAttachment {
+                                view: &x.view,
+                                resolve_target: x.resolve_target.as_deref(),
+                                ops: x.ops,
+                            })
+                        })
+                        .collect();
+
+                    let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                        label: None,
+                        color_attachments: &color_attachments,
+                        depth_stencil_attachment: depth_stencil_attachment.as_ref().map(|x| {
+                            wgpu::RenderPassDepthStencilAttachment {
+                                view: &x.view,
+                                depth_ops: x.depth_ops,
+                                stencil_ops: x.stencil_ops,
+                            }
+                        }),
+                    });
+
+                    for command in commands.by_ref() {
+                        if let WebGpuCommand::EndRenderPass = command {
+                            break;
+                        }
+
+                        Self::encode_render_pass_command(&mut render_pass, command);
+                    }
+                }
+                WebGpuCommand::Dispatch(dispatch) => {
+                    let mut compute_pass =
+                        encoder.begin_compute_pass(&wgpu::ComputePassDescriptor { label: None });
+                    compute_pass.set_pipeline(&dispatch.pipeline);
+                    for (index, bind_group) in &dispatch.bind_groups {
+                        compute_pass.set_bind_group(*index, bind_group, &[]);
+                    }
+                    if let Some(push_constants) = &dispatch.push_constants {
+                        compute_pass.set_push_constants(0, push_constants);
+                    }
+                    match &dispatch.group_count {
+                        WebGpuDispatchGroupCount::Direct(group_count) => compute_pass
+                            .dispatch_workgroups(group_count[0], group_count[1], group_count[2]),
+                        WebGpuDispatchGroupCount::Indirect { buffer, offset } => {
+                            compute_pass.dispatch_workgroups_indirect(buffer, *offset)
+                        }
+                    }
+                }
+                WebGpuCommand::CopyBufferToBuffer {
+                    src,
+                    src_offset,
+                    dst,
+                    dst_offset,
+                    size,
+                } => {
+                    encoder.copy_buffer_to_buffer(src, *src_offset, dst, *dst_offset, *size);
+                }
+                WebGpuCommand::CopyBufferToTexture {
+                    src,
+                    layout,
+                    dst,
+                    mip_level,
+                    origin,
+                    size,
+                } => {
+                    encoder.copy_buffer_to_texture(
+                        wgpu::ImageCopyBuffer {
+                            buffer: src,
+                            layout: *layout,
+                        },
+                        wgpu::ImageCopyTexture {
+                            texture: dst,
+                            mip_level: *mip_level,
+                            origin: *origin,
+                            aspect: wgpu::TextureAspect::All,
+                        },
+                        *size,
+                    );
+                }
+                WebGpuCommand::CopyTextureToTexture {
+                    src,
+                    src_mip_level,
+                    src_origin,
+                    dst,
+                    dst_mip_level,
+                    dst_origin,
+                    size,
+                } => {
+                    encoder.copy_texture_to_texture(
+                        wgpu::ImageCopyTexture {
+                            texture: src,
+                            mip_level: *src_mip_level,
+                            origin: *src_origin,
+                            aspect: wgpu::TextureAspect::All,
+                        },
+                        wgpu::ImageCopyTexture {
+                            texture: dst,
+                            mip_level: *dst_mip_level,
+                            origin: *dst_origin,
+                            aspect: wgpu::TextureAspect::All,
+                        },
+                        *size,
+                    );
+                }
+                WebGpuCommand::GenerateMipmaps {
+                    texture,
+                    format,
+                    mip_count,
+                    layer_count,
+                } => {
+                    device_context.inner.mipmap_generator.generate_mipmaps(
+                        device_context.device(),
+                        encoder,
+                        texture,
+                        *format,
+                        *mip_count,
+                        *layer_count,
+                    );
+                }
+                WebGpuCommand::PushDebugGroup(name) => encoder.push_debug_group(name),
+                WebGpuCommand::PopDebugGroup => encoder.pop_debug_group(),
+                _ => Err(format!(
+                    "Command {:?} may only be used inside a render pass",
+                    command
+                ))?,
+            }
+        }
+
+        Ok(())
+    }
+
+    fn encode_render_pass_command<'a>(
+        render_pass: &mut wgpu::RenderPass<'a>,
+        command: &'a WebGpuCommand,
+    ) {
+        match command {
+            WebGpuCommand::SetViewport {
+                x,
+                y,
+                width,
+                height,
+                depth_min,
+                depth_max,
+            } => render_pass.set_viewport(*x, *y, *width, *height, *depth_min, *depth_max),
+            WebGpuCommand::SetScissor {
+                x,
+                y,
+                width,
+                height,
+            } => render_pass.set_scissor_rect(*x, *y, *width, *height),
+            WebGpuCommand::SetStencilReference(value) => render_pass.set_stencil_reference(*value),
+            WebGpuCommand::SetRenderPipeline(pipeline) => render_pass.set_pipeline(pipeline),
+            WebGpuCommand::SetVertexBuffer {
+                slot,
+                buffer,
+                offset,
+            } => render_pass.set_vertex_buffer(*slot, buffer.slice(*offset..)),
+            WebGpuCommand::SetIndexBuffer {
+                buffer,
+                offset,
+                format,
+            } => render_pass.set_index_buffer(buffer.slice(*offset..), *format),
+            WebGpuCommand::SetBindGroup { index, bind_group } => {
+                render_pass.set_bind_group(*index, bind_group, &[])
+            }
+            WebGpuCommand::SetPushConstants { stages, data } => {
+                render_pass.set_push_constants(*stages, 0, data)
+            }
+            WebGpuCommand::Draw {
+                vertices,
+                instances,
+            } => render_pass.draw(vertices.clone(), instances.clone()),
+            WebGpuCommand::DrawIndexed {
+                indices,
+                base_vertex,
+                instances,
+            } => render_pass.draw_indexed(indices.clone(), *base_vertex, instances.clone()),
+            WebGpuCommand::DrawIndirect { buffer, offset } => {
+                render_pass.draw_indirect(buffer, *offset)
+            }
+            WebGpuCommand::DrawIndexedIndirect { buffer, offset } => {
+                render_pass.draw_indexed_indirect(buffer, *offset)
+            }
+            WebGpuCommand::PushDebugGroup(name) => render_pass.push_debug_group(name),
+            WebGpuCommand::PopDebugGroup => render_pass.pop_debug_group(),
+            _ => panic!("Command {:?} may not be used inside a render pass", command),
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use crate::webgpu::{RafxCommandBufferWebGpu, RafxDeviceContextWebGpu, RafxQueueWebGpu};
+use crate::{RafxCommandBufferDef, RafxCommandPoolDef, RafxQueueType, RafxResult};
+
+// wgpu has no equivalent to a command pool. Command buffers record into their own command list
+// and are encoded when they are submitted.
+pub struct RafxCommandPoolWebGpu {
+    queue: RafxQueueWebGpu,
+}
+
+impl RafxCommandPoolWebGpu {
+    pub fn device_context(&self) -> &RafxDeviceContextWebGpu {
+        self.queue.device_context()
+    }
+
+    pub fn queue_type(&self) -> RafxQueueType {
+        self.queue.queue_type()
+    }
+
+    pub fn queue(&self) -> &RafxQueueWebGpu {
+        &self.queue
+    }
+
+    pub fn create_command_buffer(
+        &self,
+        command_buffer_def: &RafxCommandBufferDef,
+    ) -> RafxResult<RafxCommandBufferWebGpu> {
+        RafxCommandBufferWebGpu::new(self, command_buffer_def)
+    }
+
+    pub fn reset_command_pool(&self) -> RafxResult<()> {
+        // Command buffers are cleared when begin() is called
+        Ok(())
+    }
+
+    pub fn new(
+        queue: &RafxQueueWebGpu,
+        _command_pool_def: &RafxCommandPoolDef,
+    ) -> RafxResult<RafxCommandPoolWebGpu> {
+        Ok(RafxCommandPoolWebGpu {
+            queue: queue.clone(),
+        })
+    }
+}
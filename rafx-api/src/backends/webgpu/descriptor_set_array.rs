@@ -0,0 +1,544 @@
+use crate::webgpu::{
+    DescriptorInfo, DescriptorSetLayoutInfo, RafxDeviceContextWebGpu, RafxRootSignatureWebGpu,
+    RafxTextureWebGpu, WebGpuTextureViewKey,
+};
+use crate::{
+    RafxDescriptorKey, RafxDescriptorSetArrayDef, RafxDescriptorUpdate, RafxResourceType,
+    RafxResult, RafxRootSignature, RafxTextureBindType,
+};
+use std::sync::{Arc, Mutex};
+
+#[derive(Clone, Debug)]
+pub(crate) enum WebGpuDescriptorResource {
+    Buffer {
+        buffer: Arc<wgpu::Buffer>,
+        offset: u64,
+        size: Option<wgpu::BufferSize>,
+    },
+    TextureView(Arc<wgpu::TextureView>),
+    Sampler(Arc<wgpu::Sampler>),
+}
+
+// Borrowed form of a descriptor's elements, needed because wgpu bind group entries hold slices of
+// references
+enum WebGpuBindingResourceRefs<'a> {
+    Buffers(Vec<wgpu::BufferBinding<'a>>),
+    TextureViews(Vec<&'a wgpu::TextureView>),
+    Samplers(Vec<&'a wgpu::Sampler>),
+}
+
+pub(crate) struct DescriptorSetArrayData {
+    root_signature: RafxRootSignatureWebGpu,
+    set_index: u32,
+    update_data_count_per_set: u32,
+    resources: Vec<Option<WebGpuDescriptorResource>>,
+    // Bind groups are immutable in WebGPU, so they are created lazily when bound and discarded
+    // whenever the descriptor set is modified
+    bind_groups: Vec<Option<Arc<wgpu::BindGroup>>>,
+}
+
+impl DescriptorSetArrayData {
+    pub(crate) fn bind_group(
+        &mut self,
+        array_index: u32,
+    ) -> RafxResult<Arc<wgpu::BindGroup>> {
+        if let Some(bind_group) = &self.bind_groups[array_index as usize] {
+            return Ok(bind_group.clone());
+        }
+
+        let layout_info: &DescriptorSetLayoutInfo =
+            &self.root_signature.inner.layouts[self.set_index as usize];
+        let first_resource_index = (array_index * self.update_data_count_per_set) as usize;
+
+        let mut binding_resources = Vec::with_capacity(
+            layout_info.descriptors.len() + layout_info.immutable_samplers.len(),
+        );
+
+        for &descriptor_index in &layout_info.descriptors {
+            let descriptor = self.root_signature.descriptor(descriptor_index).unwrap();
+            let begin =
+                first_resource_index + descriptor.update_data_offset_in_set.unwrap() as usize;
+            let end = begin + descriptor.element_count as usize;
+
+            let mut buffers = vec![];
+            let mut texture_views = vec![];
+            let mut samplers = vec![];
+            for resource in &self.resources[begin..end] {
+                match resource {
+                    Some(WebGpuDescriptorResource::Buffer {
+                        buffer,
+                        offset,
+                        size,
+                    }) => buffers.push(wgpu::BufferBinding {
+                        buffer,
+                        offset: *offset,
+                        size: *size,
+                    }),
+                    Some(WebGpuDescriptorResource::TextureView(texture_view)) => {
+                        texture_views.push(&**texture_view)
+                    }
+                    Some(WebGpuDescriptorResource::Sampler(sampler)) => {
+                        samplers.push(&**sampler)
+                    }
+                    None => Err(format!(
+                        "Descriptor (set={:?} binding={:?}) named {:?} was not written before the descriptor set was bound. WebGPU requires all descriptors to be written.",
+                        descriptor.set_index, descriptor.binding, descriptor.name
+                    ))?,
+                }
+            }
+
+            let refs = if !buffers.is_empty() {
+                WebGpuBindingResourceRefs::Buffers(buffers)
+            } else if !texture_views.is_empty() {
+                WebGpuBindingResourceRefs::TextureViews(texture_views)
+            } else {
+                WebGpuBindingResourceRefs::Samplers(samplers)
+            };
+
+            binding_resources.push((descriptor.binding, descriptor.element_count > 1, refs));
+        }
+
+        for (binding, samplers) in &layout_info.immutable_samplers {
+            let sampler_refs = samplers.iter().map(|x| x.webgpu_sampler()).collect();
+            binding_resources.push((
+                *binding,
+                samplers.len() > 1,
+                WebGpuBindingResourceRefs::Samplers(sampler_refs),
+            ));
+        }
+
+        let entries: Vec<_> = binding_resources
+            .iter()
+            .map(|(binding, is_array, refs)| wgpu::BindGroupEntry {
+                binding: *binding,
+                resource: match (refs, is_array) {
+                    (WebGpuBindingResourceRefs::Buffers(x), false) => {
+                        wgpu::BindingResource::Buffer(x[0].clone())
+                    }
+                    (WebGpuBindingResourceRefs::Buffers(x), true) => {
+                        wgpu::BindingResource::BufferArray(x)
+                    }
+                    (WebGpuBindingResourceRefs::TextureViews(x), false) => {
+                        wgpu::BindingResource::TextureView(x[0])
+                    }
+                    (WebGpuBindingResourceRefs::TextureViews(x), true) => {
+                        wgpu::BindingResource::TextureViewArray(x)
+                    }
+                    (WebGpuBindingResourceRefs::Samplers(x), false) => {
+                        wgpu::BindingResource::Sampler(x[0])
+                    }
+                    (WebGpuBindingResourceRefs::Samplers(x), true) => {
+                        wgpu::BindingResource::SamplerArray(x)
+                    }
+                },
+            })
+            .collect();
+
+        let bind_group = Arc::new(
+            self.root_signature
+                .device_context()
+                .device()
+                .create_bind_group(&wgpu::BindGroupDescriptor {
+                    label: None,
+                    layout: self
+                        .root_signature
+                        .webgpu_bind_group_layout(self.set_index)
+                        .unwrap(),
+                    entries: &entries,
+                }),
+        );
+
+        self.bind_groups[array_index as usize] = Some(bind_group.clone());
+        Ok(bind_group)
+    }
+}
+
+#[derive(Clone)]
+pub struct RafxDescriptorSetHandleWebGpu {
+    descriptor_set_array_data: Arc<Mutex<DescriptorSetArrayData>>,
+    array_index: u32,
+}
+
+impl std::fmt::Debug for RafxDescriptorSetHandleWebGpu {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        f.debug_struct("RafxDescriptorSetHandleWebGpu")
+            .field("array_index", &self.array_index)
+            .finish()
+    }
+}
+
+impl RafxDescriptorSetHandleWebGpu {
+    pub fn array_index(&self) -> u32 {
+        self.array_index
+    }
+
+    pub(crate) fn bind_group(&self) -> RafxResult<Arc<wgpu::BindGroup>> {
+        self.descriptor_set_array_data
+            .lock()
+            .unwrap()
+            .bind_group(self.array_index)
+    }
+}
+
+pub struct RafxDescriptorSetArrayWebGpu {
+    root_signature: RafxRootSignature,
+    set_index: u32,
+    data: Arc<Mutex<DescriptorSetArrayData>>,
+    array_length: u32,
+}
+
+impl RafxDescriptorSetArrayWebGpu {
+    pub fn root_signature(&self) -> &RafxRootSignature {
+        &self.root_signature
+    }
+
+    pub fn set_index(&self) -> u32 {
+        self.set_index
+    }
+
+    pub(crate) fn bind_group(
+        &self,
+        array_index: u32,
+    ) -> RafxResult<Arc<wgpu::BindGroup>> {
+        self.data.lock().unwrap().bind_group(array_index)
+    }
+
+    pub fn handle(
+        &self,
+        array_index: u32,
+    ) -> Option<RafxDescriptorSetHandleWebGpu> {
+        if array_index >= self.array_length {
+            return None;
+        }
+
+        Some(RafxDescriptorSetHandleWebGpu {
+            descriptor_set_array_data: self.data.clone(),
+            array_index,
+        })
+    }
+
+    pub(crate) fn new(
+        _device_context: &RafxDeviceContextWebGpu,
+        descriptor_set_array_def: &RafxDescriptorSetArrayDef,
+    ) -> RafxResult<Self> {
+        let root_signature = descriptor_set_array_def
+            .root_signature
+            .webgpu_root_signature()
+            .unwrap()
+            .clone();
+
+        let layout_index = descriptor_set_array_def.set_index as usize;
+        let update_data_count_per_set =
+            root_signature.inner.layouts[layout_index].update_data_count_per_set;
+
+        let data = DescriptorSetArrayData {
+            root_signature: root_signature.clone(),
+            set_index: descriptor_set_array_def.set_index,
+            update_data_count_per_set,
+            resources: vec![
+                None;
+                descriptor_set_array_def.array_length
+                    * update_data_count_per_set as usize
+            ],
+            bind_groups: vec![None; descriptor_set_array_def.array_length],
+        };
+
+        Ok(RafxDescriptorSetArrayWebGpu {
+            root_signature: RafxRootSignature::WebGpu(root_signature),
+            set_index: descriptor_set_array_def.set_index,
+            data: Arc::new(Mutex::new(data)),
+            array_length: descriptor_set_array_def.array_length as u32,
+        })
+    }
+
+    pub fn update_descriptor_set(
+        &mut self,
+        descriptor_updates: &[RafxDescriptorUpdate],
+    ) -> RafxResult<()> {
+        for update in descriptor_updates {
+            self.queue_descriptor_set_update(update)?;
+        }
+        self.flush_descriptor_set_updates()
+    }
+
+    pub fn flush_descriptor_set_updates(&mut self) -> RafxResult<()> {
+        // Bind groups are created when the descriptor set is bound
+        Ok(())
+    }
+
+    pub fn queue_descriptor_set_update(
+        &mut self,
+        update: &RafxDescriptorUpdate,
+    ) -> RafxResult<()> {
+        let root_signature = self.root_signature.webgpu_root_signature().unwrap();
+        let layout: &DescriptorSetLayoutInfo =
+            &root_signature.inner.layouts[self.set_index as usize];
+        let descriptor_index = match &update.descriptor_key {
+            RafxDescriptorKey::Name(name) => {
+                let descriptor_index = root_signature.find_descriptor_by_name(name);
+                if let Some(descriptor_index) = descriptor_index {
+                    let set_index = root_signature
+                        .descriptor(descriptor_index)
+                        .unwrap()
+                        .set_index;
+                    if set_index == self.set_index {
+                        descriptor_index
+                    } else {
+                        return Err(format!(
+                            "Found descriptor {:?} but it's set_index ({:?}) does not match the set ({:?})",
+                            &update.descriptor_key,
+                            set_index,
+                            self.set_index
+                        ))?;
+                    }
+                } else {
+                    return Err(format!(
+                        "Could not find descriptor {:?}",
+                        &update.descriptor_key
+                    ))?;
+                }
+            }
+            RafxDescriptorKey::Binding(binding) => layout
+                .binding_to_descriptor_index
+                .get(binding)
+                .copied()
+                .ok_or_else(|| format!("Could not find descriptor {:?}", update.descriptor_key,))?,
+            RafxDescriptorKey::DescriptorIndex(descriptor_index) => *descriptor_index,
+            RafxDescriptorKey::Undefined => {
+                return Err("Passed RafxDescriptorKey::Undefined to update_descriptor_set()")?
+            }
+        };
+
+        let descriptor = root_signature.descriptor(descriptor_index).unwrap();
+
+        log::trace!(
+            "update descriptor set {:?} (set_index: {:?} binding: {} name: {:?} type: {:?} array_index: {})",
+            update.descriptor_key,
+            descriptor.set_index,
+            descriptor.binding,
+            descriptor.name,
+            descriptor.resource_type,
+            update.array_index,
+        );
+
+        let mut data = self.data.lock().unwrap();
+        data.bind_groups[update.array_index as usize] = None;
+
+        let first_index = (layout.update_data_count_per_set * update.array_index
+            + descriptor.update_data_offset_in_set.unwrap()
+            + update.dst_element_offset) as usize;
+
+        match descriptor.resource_type {
+            RafxResourceType::SAMPLER => {
+                let samplers = update.elements.samplers.ok_or_else(||
+                    format!(
+                        "Tried to update binding {:?} (set: {:?} binding: {} name: {:?} type: {:?}) but the samplers element list was None",
+                        update.descriptor_key,
+                        descriptor.set_index,
+                        descriptor.binding,
+                        descriptor.name,
+                        descriptor.resource_type,
+                    )
+                )?;
+
+                assert!(
+                    update.dst_element_offset + samplers.len() as u32 <= descriptor.element_count
+                );
+
+                for (i, sampler) in samplers.iter().enumerate() {
+                    data.resources[first_index + i] = Some(WebGpuDescriptorResource::Sampler(
+                        sampler.webgpu_sampler().unwrap().webgpu_sampler_arc().clone(),
+                    ));
+                }
+            }
+            RafxResourceType::TEXTURE | RafxResourceType::TEXTURE_READ_WRITE => {
+                let textures = update.elements.textures.ok_or_else(||
+                    format!(
+                        "Tried to update binding {:?} (set: {:?} binding: {} name: {:?} type: {:?}) but the texture element list was None",
+                        update.descriptor_key,
+                        descriptor.set_index,
+                        descriptor.binding,
+                        descriptor.name,
+                        descriptor.resource_type,
+                    )
+                )?;
+
+                // Defaults to UavMipSlice(0) for TEXTURE_READ_WRITE and Srv for TEXTURE
+                let texture_bind_type =
+                    if descriptor.resource_type == RafxResourceType::TEXTURE_READ_WRITE {
+                        update
+                            .texture_bind_type
+                            .unwrap_or(RafxTextureBindType::UavMipSlice(0))
+                    } else {
+                        update.texture_bind_type.unwrap_or(RafxTextureBindType::Srv)
+                    };
+
+                if texture_bind_type == RafxTextureBindType::UavMipChain {
+                    let texture = textures.first().unwrap().webgpu_texture().unwrap();
+                    let mip_count = texture.texture_def().mip_count;
+                    if mip_count > descriptor.element_count {
+                        Err(format!(
+                            "Tried to update binding {:?} (set: {:?} binding: {} name: {:?} type: {:?}) using UavMipChain but the mip chain has {} images and the descriptor has {} elements",
+                            update.descriptor_key,
+                            descriptor.set_index,
+                            descriptor.binding,
+                            descriptor.name,
+                            descriptor.resource_type,
+                            mip_count,
+                            descriptor.element_count
+                        ))?;
+                    }
+
+                    for mip_level in 0..mip_count {
+                        let view =
+                            Self::texture_view(descriptor, texture, texture_bind_type, mip_level);
+                        data.resources[first_index + mip_level as usize] =
+                            Some(WebGpuDescriptorResource::TextureView(view));
+                    }
+                } else {
+                    assert!(
+                        update.dst_element_offset + textures.len() as u32
+                            <= descriptor.element_count
+                    );
+
+                    let mip_level = match texture_bind_type {
+                        RafxTextureBindType::UavMipSlice(slice) => slice,
+                        _ => 0,
+                    };
+
+                    for (i, texture) in textures.iter().enumerate() {
+                        let texture = texture.webgpu_texture().unwrap();
+                        if mip_level >= texture.texture_def().mip_count {
+                            Err(format!(
+                                "Tried to update binding {:?} (set: {:?} binding: {} name: {:?} type: {:?}) but the chosen mip slice {} exceeds the mip count of {} in the image",
+                                update.descriptor_key,
+                                descriptor.set_index,
+                                descriptor.binding,
+                                descriptor.name,
+                                descriptor.resource_type,
+                                mip_level,
+                                texture.texture_def().mip_count
+                            ))?;
+                        }
+
+                        let view =
+                            Self::texture_view(descriptor, texture, texture_bind_type, mip_level);
+                        data.resources[first_index + i] =
+                            Some(WebGpuDescriptorResource::TextureView(view));
+                    }
+                }
+            }
+            RafxResourceType::UNIFORM_BUFFER
+            | RafxResourceType::BUFFER
+            | RafxResourceType::BUFFER_READ_WRITE => {
+                let buffers = update.elements.buffers.ok_or_else(||
+                    format!(
+                        "Tried to update binding {:?} (set: {:?} binding: {} name: {:?} type: {:?}) but the buffers element list was None",
+                        update.descriptor_key,
+                        descriptor.set_index,
+                        descriptor.binding,
+                        descriptor.name,
+                        descriptor.resource_type,
+                    )
+                )?;
+
+                assert!(
+                    update.dst_element_offset + buffers.len() as u32 <= descriptor.element_count
+                );
+
+                for (buffer_index, buffer) in buffers.iter().enumerate() {
+                    let offset_size = update
+                        .elements
+                        .buffer_offset_sizes
+                        .map(|x| x[buffer_index]);
+
+                    let offset = offset_size.map(|x| x.byte_offset).unwrap_or(0);
+                    let size = offset_size
+                        .and_then(|x| wgpu::BufferSize::new(x.size))
+                        .filter(|x| x.get() != u64::MAX);
+
+                    data.resources[first_index + buffer_index] =
+                        Some(WebGpuDescriptorResource::Buffer {
+                            buffer: buffer.webgpu_buffer().unwrap().webgpu_buffer_arc().clone(),
+                            offset,
+                            size,
+                        });
+                }
+            }
+            _ => Err(format!(
+                "Tried to update binding {:?} (set: {:?} binding: {} name: {:?}) but resource type {:?} is not supported by the WebGPU backend",
+                update.descriptor_key,
+                descriptor.set_index,
+                descriptor.binding,
+                descriptor.name,
+                descriptor.resource_type,
+            ))?,
+        }
+
+        Ok(())
+    }
+
+    fn texture_view(
+        descriptor: &DescriptorInfo,
+        texture: &RafxTextureWebGpu,
+        texture_bind_type: RafxTextureBindType,
+        mip_level: u32,
+    ) -> Arc<wgpu::TextureView> {
+        let (view_dimension, is_depth, is_storage) = match descriptor.binding_type {
+            Some(wgpu::BindingType::Texture {
+                view_dimension,
+                sample_type,
+                ..
+            }) => (
+                view_dimension,
+                sample_type == wgpu::TextureSampleType::Depth,
+                false,
+            ),
+            Some(wgpu::BindingType::StorageTexture { view_dimension, .. }) => {
+                (view_dimension, false, true)
+            }
+            _ => (texture.default_view_dimension(), false, false),
+        };
+
+        let format = texture.texture_def().format;
+        let aspect = if texture_bind_type == RafxTextureBindType::SrvStencil {
+            wgpu::TextureAspect::StencilOnly
+        } else if format.is_depth_and_stencil() || (is_depth && format.has_stencil()) {
+            wgpu::TextureAspect::DepthOnly
+        } else {
+            wgpu::TextureAspect::All
+        };
+
+        // Storage textures must be bound one mip at a time
+        let (base_mip_level, mip_level_count) = if is_storage {
+            (mip_level, Some(1))
+        } else {
+            (0, None)
+        };
+
+        texture.view(WebGpuTextureViewKey {
+            dimension: Some(view_dimension),
+            aspect,
+            base_mip_level,
+            mip_level_count,
+            base_array_layer: 0,
+            array_layer_count: None,
+        })
+    }
+}
+
+impl std::fmt::Debug for RafxDescriptorSetArrayWebGpu {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        f.debug_struct("RafxDescriptorSetArrayWebGpu")
+            .field("root_signature", &self.root_signature)
+            .field("set_index", &self.set_index)
+            .field("array_length", &self.array_length)
+            .finish()
+    }
+}
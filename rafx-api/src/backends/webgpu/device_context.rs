@@ -0,0 +1,464 @@
+use crate::{
+    RafxApiDefWebGpu, RafxBufferDef, RafxComputePipelineDef, RafxDescriptorSetArrayDef,
+    RafxDeviceContext, RafxDeviceInfo, RafxFormat, RafxGraphicsPipelineDef, RafxQueueType,
+    RafxResourceType, RafxResult, RafxRootSignatureDef, RafxSampleCount, RafxSamplerDef,
+    RafxShaderModuleDefWebGpu, RafxShaderStageDef, RafxSwapchainDef, RafxTextureDef,
+};
+use raw_window_handle::{
+    HasRawDisplayHandle, HasRawWindowHandle, RawDisplayHandle, RawWindowHandle,
+};
+use std::sync::{Arc, Mutex, Weak};
+
+use crate::webgpu::{
+    RafxBufferWebGpu, RafxDescriptorSetArrayWebGpu, RafxFenceWebGpu, RafxPipelineWebGpu,
+    RafxQueueWebGpu, RafxRootSignatureWebGpu, RafxSamplerWebGpu, RafxSemaphoreWebGpu,
+    RafxShaderModuleWebGpu, RafxShaderWebGpu, RafxSwapchainWebGpu, RafxTextureWebGpu,
+    WebGpuBufferContents, WebGpuMipmapGenerator,
+};
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+#[cfg(debug_assertions)]
+#[cfg(feature = "track-device-contexts")]
+use std::sync::atomic::AtomicU64;
+
+// Features that are used if the adapter has them, but are not required
+const OPTIONAL_FEATURES: wgpu::Features = wgpu::Features::DEPTH_CLIP_CONTROL
+    .union(wgpu::Features::DEPTH32FLOAT_STENCIL8)
+    .union(wgpu::Features::TEXTURE_COMPRESSION_BC)
+    .union(wgpu::Features::TEXTURE_COMPRESSION_ETC2)
+    .union(wgpu::Features::TEXTURE_COMPRESSION_ASTC)
+    .union(wgpu::Features::INDIRECT_FIRST_INSTANCE)
+    .union(wgpu::Features::TEXTURE_ADAPTER_SPECIFIC_FORMAT_FEATURES)
+    .union(wgpu::Features::PUSH_CONSTANTS)
+    .union(wgpu::Features::POLYGON_MODE_LINE)
+    .union(wgpu::Features::ADDRESS_MODE_CLAMP_TO_BORDER)
+    .union(wgpu::Features::TEXTURE_BINDING_ARRAY);
+
+// WebGPU requires bytes_per_row to be aligned to 256 when copying buffers to textures
+pub(crate) const WEBGPU_COPY_BYTES_PER_ROW_ALIGNMENT: u32 = wgpu::COPY_BYTES_PER_ROW_ALIGNMENT;
+
+// wgpu's create_surface() wants a single object that provides both handles
+pub(crate) struct WebGpuWindowHandles {
+    display: RawDisplayHandle,
+    window: RawWindowHandle,
+}
+
+impl WebGpuWindowHandles {
+    pub(crate) fn new(
+        display: &dyn HasRawDisplayHandle,
+        window: &dyn HasRawWindowHandle,
+    ) -> Self {
+        WebGpuWindowHandles {
+            display: display.raw_display_handle(),
+            window: window.raw_window_handle(),
+        }
+    }
+}
+
+unsafe impl HasRawDisplayHandle for WebGpuWindowHandles {
+    fn raw_display_handle(&self) -> RawDisplayHandle {
+        self.display
+    }
+}
+
+unsafe impl HasRawWindowHandle for WebGpuWindowHandles {
+    fn raw_window_handle(&self) -> RawWindowHandle {
+        self.window
+    }
+}
+
+pub struct RafxDeviceContextWebGpuInner {
+    pub(crate) device_info: RafxDeviceInfo,
+
+    instance: wgpu::Instance,
+    adapter: wgpu::Adapter,
+    device: wgpu::Device,
+    queue: wgpu::Queue,
+    destroyed: AtomicBool,
+
+    pub(crate) mipmap_generator: WebGpuMipmapGenerator,
+
+    // Buffers that are currently mapped. WebGPU has no persistently mapped memory, so the CPU-side
+    // copy of these buffers is uploaded every time commands are submitted.
+    pub(crate) mapped_buffers: Mutex<Vec<Weak<WebGpuBufferContents>>>,
+
+    #[cfg(debug_assertions)]
+    #[cfg(feature = "track-device-contexts")]
+    next_create_index: AtomicU64,
+
+    #[cfg(debug_assertions)]
+    #[cfg(feature = "track-device-contexts")]
+    pub(crate) all_contexts: Mutex<fnv::FnvHashMap<u64, backtrace::Backtrace>>,
+}
+
+impl Drop for RafxDeviceContextWebGpuInner {
+    fn drop(&mut self) {
+        log::trace!("destroying device");
+        self.destroyed.swap(true, Ordering::AcqRel);
+    }
+}
+
+impl RafxDeviceContextWebGpuInner {
+    // If no window is provided, the device is headless and can't create swapchains
+    pub(crate) fn new(
+        window_handles: Option<&WebGpuWindowHandles>,
+        webgpu_api_def: &RafxApiDefWebGpu,
+    ) -> RafxResult<Self> {
+        log::debug!("Initializing WebGPU backend");
+        let instance = wgpu::Instance::new(wgpu::InstanceDescriptor {
+            backends: webgpu_api_def.backends,
+            dx12_shader_compiler: Default::default(),
+        });
+
+        // The surface is only used to pick an adapter that can present to the window. The
+        // swapchain creates its own surface.
+        let surface = window_handles
+            .map(|window_handles| unsafe { instance.create_surface(window_handles) })
+            .transpose()
+            .map_err(|e| format!("Could not create WebGPU surface: {:?}", e))?;
+
+        let adapter = pollster::block_on(instance.request_adapter(&wgpu::RequestAdapterOptions {
+            power_preference: webgpu_api_def.power_preference,
+            force_fallback_adapter: webgpu_api_def.force_fallback_adapter,
+            compatible_surface: surface.as_ref(),
+        }))
+        .ok_or(if surface.is_some() {
+            "Could not find a WebGPU adapter compatible with the window"
+        } else {
+            "Could not find a WebGPU adapter"
+        })?;
+
+        let adapter_info = adapter.get_info();
+        log::debug!("Adapter: {:?}", adapter_info);
+
+        let features = adapter.features() & OPTIONAL_FEATURES;
+        log::debug!("Enabled features: {:?}", features);
+
+        let limits = adapter.limits();
+        let (device, queue) = pollster::block_on(adapter.request_device(
+            &wgpu::DeviceDescriptor {
+                label: Some("rafx"),
+                features,
+                limits: limits.clone(),
+            },
+            None,
+        ))
+        .map_err(|e| format!("Could not create WebGPU device: {:?}", e))?;
+
+        let device_info = RafxDeviceInfo {
+            supports_multithreaded_usage: true,
+            debug_names_enabled: true,
+            min_uniform_buffer_offset_alignment: limits.min_uniform_buffer_offset_alignment,
+            min_storage_buffer_offset_alignment: limits.min_storage_buffer_offset_alignment,
+            upload_texture_alignment: WEBGPU_COPY_BYTES_PER_ROW_ALIGNMENT,
+            upload_texture_row_alignment: WEBGPU_COPY_BYTES_PER_ROW_ALIGNMENT,
+            supports_clamp_to_border_color: features
+                .contains(wgpu::Features::ADDRESS_MODE_CLAMP_TO_BORDER),
+            max_vertex_attribute_count: limits.max_vertex_attributes,
+        };
+
+        let mipmap_generator = WebGpuMipmapGenerator::new(&device);
+
+        #[cfg(debug_assertions)]
+        #[cfg(feature = "track-device-contexts")]
+        let all_contexts = {
+            let create_backtrace = backtrace::Backtrace::new_unresolved();
+            let mut all_contexts = fnv::FnvHashMap::<u64, backtrace::Backtrace>::default();
+            all_contexts.insert(0, create_backtrace);
+            all_contexts
+        };
+
+        Ok(RafxDeviceContextWebGpuInner {
+            device_info,
+            instance,
+            adapter,
+            device,
+            queue,
+            destroyed: AtomicBool::new(false),
+            mipmap_generator,
+            mapped_buffers: Default::default(),
+
+            #[cfg(debug_assertions)]
+            #[cfg(feature = "track-device-contexts")]
+            all_contexts: Mutex::new(all_contexts),
+
+            #[cfg(debug_assertions)]
+            #[cfg(feature = "track-device-contexts")]
+            next_create_index: AtomicU64::new(1),
+        })
+    }
+}
+
+pub struct RafxDeviceContextWebGpu {
+    pub(crate) inner: Arc<RafxDeviceContextWebGpuInner>,
+    #[cfg(debug_assertions)]
+    #[cfg(feature = "track-device-contexts")]
+    pub(crate) create_index: u64,
+}
+
+impl std::fmt::Debug for RafxDeviceContextWebGpu {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter,
+    ) -> std::fmt::Result {
+        f.debug_struct("RafxDeviceContextWebGpu")
+            .field("adapter", &self.inner.adapter.get_info().name)
+            .finish()
+    }
+}
+
+impl Clone for RafxDeviceContextWebGpu {
+    fn clone(&self) -> Self {
+        #[cfg(debug_assertions)]
+        #[cfg(feature = "track-device-contexts")]
+        let create_index = {
+            let create_index = self.inner.next_create_index.fetch_add(1, Ordering::Relaxed);
+
+            #[cfg(feature = "track-device-contexts")]
+            {
+                let create_backtrace = backtrace::Backtrace::new_unresolved();
+                self.inner
+                    .as_ref()
+                    .all_contexts
+                    .lock()
+                    .unwrap()
+                    .insert(create_index, create_backtrace);
+            }
+
+            log::trace!(
+                "Cloned RafxDeviceContextWebGpu create_index {}",
+                create_index
+            );
+            create_index
+        };
+
+        RafxDeviceContextWebGpu {
+            inner: self.inner.clone(),
+            #[cfg(debug_assertions)]
+            #[cfg(feature = "track-device-contexts")]
+            create_index,
+        }
+    }
+}
+
+impl Drop for RafxDeviceContextWebGpu {
+    fn drop(&mut self) {
+        #[cfg(debug_assertions)]
+        #[cfg(feature = "track-device-contexts")]
+        {
+            self.inner
+                .all_contexts
+                .lock()
+                .unwrap()
+                .remove(&self.create_index);
+        }
+    }
+}
+
+impl From<RafxDeviceContextWebGpu> for RafxDeviceContext {
+    fn from(device_context: RafxDeviceContextWebGpu) -> Self {
+        RafxDeviceContext::WebGpu(device_context)
+    }
+}
+
+impl RafxDeviceContextWebGpu {
+    pub fn device_info(&self) -> &RafxDeviceInfo {
+        &self.inner.device_info
+    }
+
+    pub fn instance(&self) -> &wgpu::Instance {
+        &self.inner.instance
+    }
+
+    pub fn adapter(&self) -> &wgpu::Adapter {
+        &self.inner.adapter
+    }
+
+    pub fn device(&self) -> &wgpu::Device {
+        &self.inner.device
+    }
+
+    pub fn queue(&self) -> &wgpu::Queue {
+        &self.inner.queue
+    }
+
+    pub fn features(&self) -> wgpu::Features {
+        self.inner.device.features()
+    }
+
+    pub fn new(inner: Arc<RafxDeviceContextWebGpuInner>) -> RafxResult<Self> {
+        Ok(RafxDeviceContextWebGpu {
+            inner,
+            #[cfg(debug_assertions)]
+            #[cfg(feature = "track-device-contexts")]
+            create_index: 0,
+        })
+    }
+
+    pub(crate) fn register_mapped_buffer(
+        &self,
+        buffer_contents: &Arc<WebGpuBufferContents>,
+    ) {
+        self.inner
+            .mapped_buffers
+            .lock()
+            .unwrap()
+            .push(Arc::downgrade(buffer_contents));
+    }
+
+    // Called before submitting command buffers so that writes through mapped pointers are visible
+    // to the GPU
+    pub(crate) fn flush_mapped_buffers(&self) {
+        let mut mapped_buffers = self.inner.mapped_buffers.lock().unwrap();
+        mapped_buffers.retain(|buffer_contents| {
+            if let Some(buffer_contents) = buffer_contents.upgrade() {
+                if buffer_contents.is_mapped() {
+                    buffer_contents.flush(self.queue());
+                    return true;
+                }
+            }
+
+            false
+        });
+    }
+
+    pub fn create_queue(
+        &self,
+        queue_type: RafxQueueType,
+    ) -> RafxResult<RafxQueueWebGpu> {
+        RafxQueueWebGpu::new(self, queue_type)
+    }
+
+    pub fn create_fence(&self) -> RafxResult<RafxFenceWebGpu> {
+        RafxFenceWebGpu::new(self)
+    }
+
+    pub fn create_semaphore(&self) -> RafxResult<RafxSemaphoreWebGpu> {
+        RafxSemaphoreWebGpu::new(self)
+    }
+
+    pub fn create_swapchain(
+        &self,
+        raw_display_handle: &dyn HasRawDisplayHandle,
+        raw_window_handle: &dyn HasRawWindowHandle,
+        _present_queue: &RafxQueueWebGpu,
+        swapchain_def: &RafxSwapchainDef,
+    ) -> RafxResult<RafxSwapchainWebGpu> {
+        RafxSwapchainWebGpu::new(self, raw_display_handle, raw_window_handle, swapchain_def)
+    }
+
+    pub fn wait_for_fences(
+        &self,
+        fences: &[&RafxFenceWebGpu],
+    ) -> RafxResult<()> {
+        RafxFenceWebGpu::wait_for_fences(self, fences)
+    }
+
+    pub fn create_sampler(
+        &self,
+        sampler_def: &RafxSamplerDef,
+    ) -> RafxResult<RafxSamplerWebGpu> {
+        RafxSamplerWebGpu::new(self, sampler_def)
+    }
+
+    pub fn create_texture(
+        &self,
+        texture_def: &RafxTextureDef,
+    ) -> RafxResult<RafxTextureWebGpu> {
+        RafxTextureWebGpu::new(self, texture_def)
+    }
+
+    pub fn create_buffer(
+        &self,
+        buffer_def: &RafxBufferDef,
+    ) -> RafxResult<RafxBufferWebGpu> {
+        RafxBufferWebGpu::new(self, buffer_def)
+    }
+
+    pub fn create_shader(
+        &self,
+        stages: Vec<RafxShaderStageDef>,
+    ) -> RafxResult<RafxShaderWebGpu> {
+        RafxShaderWebGpu::new(self, stages)
+    }
+
+    pub fn create_root_signature(
+        &self,
+        root_signature_def: &RafxRootSignatureDef,
+    ) -> RafxResult<RafxRootSignatureWebGpu> {
+        RafxRootSignatureWebGpu::new(self, root_signature_def)
+    }
+
+    pub fn create_descriptor_set_array(
+        &self,
+        descriptor_set_array_def: &RafxDescriptorSetArrayDef,
+    ) -> RafxResult<RafxDescriptorSetArrayWebGpu> {
+        RafxDescriptorSetArrayWebGpu::new(self, descriptor_set_array_def)
+    }
+
+    pub fn create_graphics_pipeline(
+        &self,
+        graphics_pipeline_def: &RafxGraphicsPipelineDef,
+    ) -> RafxResult<RafxPipelineWebGpu> {
+        RafxPipelineWebGpu::new_graphics_pipeline(self, graphics_pipeline_def)
+    }
+
+    pub fn create_compute_pipeline(
+        &self,
+        compute_pipeline_def: &RafxComputePipelineDef,
+    ) -> RafxResult<RafxPipelineWebGpu> {
+        RafxPipelineWebGpu::new_compute_pipeline(self, compute_pipeline_def)
+    }
+
+    pub fn create_shader_module(
+        &self,
+        data: RafxShaderModuleDefWebGpu,
+    ) -> RafxResult<RafxShaderModuleWebGpu> {
+        RafxShaderModuleWebGpu::new(self, data)
+    }
+
+    pub fn find_supported_format(
+        &self,
+        candidates: &[RafxFormat],
+        resource_type: RafxResourceType,
+    ) -> Option<RafxFormat> {
+        let mut required_usages = wgpu::TextureUsages::empty();
+        if resource_type.intersects(RafxResourceType::TEXTURE) {
+            required_usages |= wgpu::TextureUsages::TEXTURE_BINDING;
+        }
+        if resource_type.intersects(RafxResourceType::TEXTURE_READ_WRITE) {
+            required_usages |= wgpu::TextureUsages::STORAGE_BINDING;
+        }
+        if resource_type.intersects(
+            RafxResourceType::RENDER_TARGET_COLOR | RafxResourceType::RENDER_TARGET_DEPTH_STENCIL,
+        ) {
+            required_usages |= wgpu::TextureUsages::RENDER_ATTACHMENT;
+        }
+
+        for &candidate in candidates {
+            if let Some(format) = candidate.webgpu_texture_format() {
+                if !self.features().contains(format.required_features()) {
+                    continue;
+                }
+
+                let format_features = self.adapter().get_texture_format_features(format);
+                if format_features.allowed_usages.contains(required_usages) {
+                    return Some(candidate);
+                }
+            }
+        }
+
+        None
+    }
+
+    pub fn find_supported_sample_count(
+        &self,
+        candidates: &[RafxSampleCount],
+    ) -> Option<RafxSampleCount> {
+        // WebGPU guarantees 1 and 4, other counts are not supported
+        candidates.iter().copied().find(|&candidate| {
+            candidate == RafxSampleCount::SampleCount1 || candidate == RafxSampleCount::SampleCount4
+        })
+    }
+}
@@ -0,0 +1,103 @@
+use crate::webgpu::RafxDeviceContextWebGpu;
+use crate::{RafxFenceStatus, RafxResult};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+
+pub struct RafxFenceWebGpu {
+    device_context: RafxDeviceContextWebGpu,
+    // Set to true when an operation is scheduled to signal this fence
+    // Cleared when an operation is scheduled to consume this fence
+    submitted: AtomicBool,
+    // Set by the callback passed to wgpu::Queue::on_submitted_work_done(). A new flag is created
+    // each time the fence is submitted so that a stale callback can't complete a later submit.
+    complete: Mutex<Arc<AtomicBool>>,
+}
+
+impl RafxFenceWebGpu {
+    pub fn new(device_context: &RafxDeviceContextWebGpu) -> RafxResult<RafxFenceWebGpu> {
+        Ok(RafxFenceWebGpu {
+            device_context: device_context.clone(),
+            submitted: AtomicBool::new(false),
+            complete: Mutex::new(Arc::new(AtomicBool::new(false))),
+        })
+    }
+
+    pub(crate) fn submitted(&self) -> bool {
+        self.submitted.load(Ordering::Relaxed)
+    }
+
+    fn is_complete(&self) -> bool {
+        self.complete.lock().unwrap().load(Ordering::Acquire)
+    }
+
+    pub fn wait(&self) -> RafxResult<()> {
+        if self.submitted() {
+            // On the web, polling does nothing and callbacks fire from the browser's event loop.
+            // There is no way to block there, so waiting is best-effort.
+            while !self.is_complete() {
+                self.device_context.device().poll(wgpu::Maintain::Wait);
+
+                if cfg!(target_arch = "wasm32") {
+                    break;
+                }
+            }
+        }
+
+        self.set_submitted(false);
+        Ok(())
+    }
+
+    pub fn wait_for_fences(
+        _device_context: &RafxDeviceContextWebGpu,
+        fences: &[&RafxFenceWebGpu],
+    ) -> RafxResult<()> {
+        for fence in fences {
+            fence.wait()?;
+        }
+
+        Ok(())
+    }
+
+    // Marks the fence as submitted and completes it once all work submitted to the queue so far
+    // has finished
+    pub(crate) fn signal_on_queue(
+        &self,
+        queue: &wgpu::Queue,
+    ) {
+        let complete = Arc::new(AtomicBool::new(false));
+        *self.complete.lock().unwrap() = complete.clone();
+        self.submitted.store(true, Ordering::Relaxed);
+
+        queue.on_submitted_work_done(move || {
+            complete.store(true, Ordering::Release);
+        });
+    }
+
+    // Marks the fence as submitted and already complete
+    pub(crate) fn signal_immediately(&self) {
+        *self.complete.lock().unwrap() = Arc::new(AtomicBool::new(true));
+        self.submitted.store(true, Ordering::Relaxed);
+    }
+
+    pub(crate) fn set_submitted(
+        &self,
+        available: bool,
+    ) {
+        self.submitted.store(available, Ordering::Relaxed);
+    }
+
+    pub fn get_fence_status(&self) -> RafxResult<RafxFenceStatus> {
+        if !self.submitted() {
+            Ok(RafxFenceStatus::Unsubmitted)
+        } else {
+            self.device_context.device().poll(wgpu::Maintain::Poll);
+
+            if self.is_complete() {
+                self.set_submitted(false);
+                Ok(RafxFenceStatus::Complete)
+            } else {
+                Ok(RafxFenceStatus::Incomplete)
+            }
+        }
+    }
+}
@@ -0,0 +1,169 @@
+use crate::RafxResult;
+use fnv::FnvHashMap;
+
+// WebGPU bind group layouts need to know details about textures and samplers that are not part of
+// rafx's reflection data (sample type, view dimension, comparison samplers, storage formats). This
+// information is pulled out of the WGSL by parsing it with naga.
+pub(crate) type WebGpuBindingTypes = FnvHashMap<(u32, u32), wgpu::BindingType>;
+
+pub(crate) fn reflect_binding_types(module: &naga::Module) -> RafxResult<WebGpuBindingTypes> {
+    let mut binding_types = WebGpuBindingTypes::default();
+
+    for (_, global_variable) in module.global_variables.iter() {
+        let resource_binding = match &global_variable.binding {
+            Some(resource_binding) => resource_binding,
+            None => continue,
+        };
+
+        let binding_type = match global_variable.space {
+            naga::AddressSpace::Uniform => wgpu::BindingType::Buffer {
+                ty: wgpu::BufferBindingType::Uniform,
+                has_dynamic_offset: false,
+                min_binding_size: None,
+            },
+            naga::AddressSpace::Storage { access } => wgpu::BindingType::Buffer {
+                ty: wgpu::BufferBindingType::Storage {
+                    read_only: !access.contains(naga::StorageAccess::STORE),
+                },
+                has_dynamic_offset: false,
+                min_binding_size: None,
+            },
+            naga::AddressSpace::Handle => {
+                let mut ty = &module.types[global_variable.ty].inner;
+                if let naga::TypeInner::BindingArray { base, .. } = ty {
+                    ty = &module.types[*base].inner;
+                }
+
+                handle_binding_type(ty).ok_or_else(|| {
+                    format!(
+                        "Unsupported type for resource {:?} (group={} binding={})",
+                        global_variable.name, resource_binding.group, resource_binding.binding
+                    )
+                })?
+            }
+            _ => continue,
+        };
+
+        binding_types.insert(
+            (resource_binding.group, resource_binding.binding),
+            binding_type,
+        );
+    }
+
+    Ok(binding_types)
+}
+
+fn handle_binding_type(ty: &naga::TypeInner) -> Option<wgpu::BindingType> {
+    match *ty {
+        naga::TypeInner::Sampler { comparison } => {
+            Some(wgpu::BindingType::Sampler(if comparison {
+                wgpu::SamplerBindingType::Comparison
+            } else {
+                wgpu::SamplerBindingType::Filtering
+            }))
+        }
+        naga::TypeInner::Image {
+            dim,
+            arrayed,
+            class,
+        } => {
+            let view_dimension = match (dim, arrayed) {
+                (naga::ImageDimension::D1, _) => wgpu::TextureViewDimension::D1,
+                (naga::ImageDimension::D2, false) => wgpu::TextureViewDimension::D2,
+                (naga::ImageDimension::D2, true) => wgpu::TextureViewDimension::D2Array,
+                (naga::ImageDimension::D3, _) => wgpu::TextureViewDimension::D3,
+                (naga::ImageDimension::Cube, false) => wgpu::TextureViewDimension::Cube,
+                (naga::ImageDimension::Cube, true) => wgpu::TextureViewDimension::CubeArray,
+            };
+
+            match class {
+                naga::ImageClass::Sampled { kind, multi } => {
+                    let sample_type = match kind {
+                        naga::ScalarKind::Float => {
+                            wgpu::TextureSampleType::Float { filterable: true }
+                        }
+                        naga::ScalarKind::Sint => wgpu::TextureSampleType::Sint,
+                        naga::ScalarKind::Uint => wgpu::TextureSampleType::Uint,
+                        naga::ScalarKind::Bool => return None,
+                    };
+
+                    Some(wgpu::BindingType::Texture {
+                        sample_type,
+                        view_dimension,
+                        multisampled: multi,
+                    })
+                }
+                naga::ImageClass::Depth { multi } => Some(wgpu::BindingType::Texture {
+                    sample_type: wgpu::TextureSampleType::Depth,
+                    view_dimension,
+                    multisampled: multi,
+                }),
+                naga::ImageClass::Storage { format, access } => {
+                    let access = if access
+                        .contains(naga::StorageAccess::LOAD | naga::StorageAccess::STORE)
+                    {
+                        wgpu::StorageTextureAccess::ReadWrite
+                    } else if access.contains(naga::StorageAccess::STORE) {
+                        wgpu::StorageTextureAccess::WriteOnly
+                    } else {
+                        wgpu::StorageTextureAccess::ReadOnly
+                    };
+
+                    Some(wgpu::BindingType::StorageTexture {
+                        access,
+                        format: storage_format(format),
+                        view_dimension,
+                    })
+                }
+            }
+        }
+        _ => None,
+    }
+}
+
+#[rustfmt::skip]
+fn storage_format(format: naga::StorageFormat) -> wgpu::TextureFormat {
+    use naga::StorageFormat as Sf;
+    use wgpu::TextureFormat as Tf;
+
+    match format {
+        Sf::R8Unorm => Tf::R8Unorm,
+        Sf::R8Snorm => Tf::R8Snorm,
+        Sf::R8Uint => Tf::R8Uint,
+        Sf::R8Sint => Tf::R8Sint,
+        Sf::R16Uint => Tf::R16Uint,
+        Sf::R16Sint => Tf::R16Sint,
+        Sf::R16Float => Tf::R16Float,
+        Sf::Rg8Unorm => Tf::Rg8Unorm,
+        Sf::Rg8Snorm => Tf::Rg8Snorm,
+        Sf::Rg8Uint => Tf::Rg8Uint,
+        Sf::Rg8Sint => Tf::Rg8Sint,
+        Sf::R32Uint => Tf::R32Uint,
+        Sf::R32Sint => Tf::R32Sint,
+        Sf::R32Float => Tf::R32Float,
+        Sf::Rg16Uint => Tf::Rg16Uint,
+        Sf::Rg16Sint => Tf::Rg16Sint,
+        Sf::Rg16Float => Tf::Rg16Float,
+        Sf::Rgba8Unorm => Tf::Rgba8Unorm,
+        Sf::Rgba8Snorm => Tf::Rgba8Snorm,
+        Sf::Rgba8Uint => Tf::Rgba8Uint,
+        Sf::Rgba8Sint => Tf::Rgba8Sint,
+        Sf::Rgb10a2Unorm => Tf::Rgb10a2Unorm,
+        Sf::Rg11b10Float => Tf::Rg11b10Float,
+        Sf::Rg32Uint => Tf::Rg32Uint,
+        Sf::Rg32Sint => Tf::Rg32Sint,
+        Sf::Rg32Float => Tf::Rg32Float,
+        Sf::Rgba16Uint => Tf::Rgba16Uint,
+        Sf::Rgba16Sint => Tf::Rgba16Sint,
+        Sf::Rgba16Float => Tf::Rgba16Float,
+        Sf::Rgba32Uint => Tf::Rgba32Uint,
+        Sf::Rgba32Sint => Tf::Rgba32Sint,
+        Sf::Rgba32Float => Tf::Rgba32Float,
+        Sf::R16Unorm => Tf::R16Unorm,
+        Sf::R16Snorm => Tf::R16Snorm,
+        Sf::Rg16Unorm => Tf::Rg16Unorm,
+        Sf::Rg16Snorm => Tf::Rg16Snorm,
+        Sf::Rgba16Unorm => Tf::Rgba16Unorm,
+        Sf::Rgba16Snorm => Tf::Rgba16Snorm,
+    }
+}
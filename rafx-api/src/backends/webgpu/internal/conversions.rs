@@ -0,0 +1,269 @@
+use crate::{
+    RafxAddressMode, RafxBlendFactor, RafxBlendOp, RafxBlendStateRenderTarget, RafxColorClearValue,
+    RafxColorFlags, RafxCompareOp, RafxCullMode, RafxDepthState, RafxFillMode, RafxFilterType,
+    RafxFrontFace, RafxIndexType, RafxLoadOp, RafxMipMapMode, RafxPrimitiveTopology,
+    RafxShaderStageFlags, RafxStencilOp, RafxStoreOp, RafxVertexAttributeRate,
+};
+
+impl From<RafxFilterType> for wgpu::FilterMode {
+    fn from(filter_type: RafxFilterType) -> Self {
+        match filter_type {
+            RafxFilterType::Nearest => wgpu::FilterMode::Nearest,
+            RafxFilterType::Linear => wgpu::FilterMode::Linear,
+        }
+    }
+}
+
+impl From<RafxMipMapMode> for wgpu::FilterMode {
+    fn from(mip_map_mode: RafxMipMapMode) -> Self {
+        match mip_map_mode {
+            RafxMipMapMode::Nearest => wgpu::FilterMode::Nearest,
+            RafxMipMapMode::Linear => wgpu::FilterMode::Linear,
+        }
+    }
+}
+
+impl From<RafxAddressMode> for wgpu::AddressMode {
+    fn from(address_mode: RafxAddressMode) -> Self {
+        match address_mode {
+            RafxAddressMode::Mirror => wgpu::AddressMode::MirrorRepeat,
+            RafxAddressMode::Repeat => wgpu::AddressMode::Repeat,
+            RafxAddressMode::ClampToEdge => wgpu::AddressMode::ClampToEdge,
+            RafxAddressMode::ClampToBorder => wgpu::AddressMode::ClampToBorder,
+        }
+    }
+}
+
+impl From<RafxCompareOp> for wgpu::CompareFunction {
+    fn from(compare_op: RafxCompareOp) -> Self {
+        match compare_op {
+            RafxCompareOp::Never => wgpu::CompareFunction::Never,
+            RafxCompareOp::Less => wgpu::CompareFunction::Less,
+            RafxCompareOp::Equal => wgpu::CompareFunction::Equal,
+            RafxCompareOp::LessOrEqual => wgpu::CompareFunction::LessEqual,
+            RafxCompareOp::Greater => wgpu::CompareFunction::Greater,
+            RafxCompareOp::NotEqual => wgpu::CompareFunction::NotEqual,
+            RafxCompareOp::GreaterOrEqual => wgpu::CompareFunction::GreaterEqual,
+            RafxCompareOp::Always => wgpu::CompareFunction::Always,
+        }
+    }
+}
+
+impl From<RafxBlendFactor> for wgpu::BlendFactor {
+    fn from(blend_factor: RafxBlendFactor) -> Self {
+        match blend_factor {
+            RafxBlendFactor::Zero => wgpu::BlendFactor::Zero,
+            RafxBlendFactor::One => wgpu::BlendFactor::One,
+            RafxBlendFactor::SrcColor => wgpu::BlendFactor::Src,
+            RafxBlendFactor::OneMinusSrcColor => wgpu::BlendFactor::OneMinusSrc,
+            RafxBlendFactor::DstColor => wgpu::BlendFactor::Dst,
+            RafxBlendFactor::OneMinusDstColor => wgpu::BlendFactor::OneMinusDst,
+            RafxBlendFactor::SrcAlpha => wgpu::BlendFactor::SrcAlpha,
+            RafxBlendFactor::OneMinusSrcAlpha => wgpu::BlendFactor::OneMinusSrcAlpha,
+            RafxBlendFactor::DstAlpha => wgpu::BlendFactor::DstAlpha,
+            RafxBlendFactor::OneMinusDstAlpha => wgpu::BlendFactor::OneMinusDstAlpha,
+            RafxBlendFactor::SrcAlphaSaturate => wgpu::BlendFactor::SrcAlphaSaturated,
+            RafxBlendFactor::ConstantColor => wgpu::BlendFactor::Constant,
+            RafxBlendFactor::OneMinusConstantColor => wgpu::BlendFactor::OneMinusConstant,
+        }
+    }
+}
+
+impl From<RafxBlendOp> for wgpu::BlendOperation {
+    fn from(blend_op: RafxBlendOp) -> Self {
+        match blend_op {
+            RafxBlendOp::Add => wgpu::BlendOperation::Add,
+            RafxBlendOp::Subtract => wgpu::BlendOperation::Subtract,
+            RafxBlendOp::ReverseSubtract => wgpu::BlendOperation::ReverseSubtract,
+            RafxBlendOp::Min => wgpu::BlendOperation::Min,
+            RafxBlendOp::Max => wgpu::BlendOperation::Max,
+        }
+    }
+}
+
+impl From<&RafxBlendStateRenderTarget> for wgpu::BlendState {
+    fn from(blend_state_render_target: &RafxBlendStateRenderTarget) -> Self {
+        wgpu::BlendState {
+            color: wgpu::BlendComponent {
+                src_factor: blend_state_render_target.src_factor.into(),
+                dst_factor: blend_state_render_target.dst_factor.into(),
+                operation: blend_state_render_target.blend_op.into(),
+            },
+            alpha: wgpu::BlendComponent {
+                src_factor: blend_state_render_target.src_factor_alpha.into(),
+                dst_factor: blend_state_render_target.dst_factor_alpha.into(),
+                operation: blend_state_render_target.blend_op_alpha.into(),
+            },
+        }
+    }
+}
+
+impl From<RafxColorFlags> for wgpu::ColorWrites {
+    fn from(color_flags: RafxColorFlags) -> Self {
+        let mut color_writes = wgpu::ColorWrites::empty();
+        if color_flags.intersects(RafxColorFlags::RED) {
+            color_writes |= wgpu::ColorWrites::RED;
+        }
+        if color_flags.intersects(RafxColorFlags::GREEN) {
+            color_writes |= wgpu::ColorWrites::GREEN;
+        }
+        if color_flags.intersects(RafxColorFlags::BLUE) {
+            color_writes |= wgpu::ColorWrites::BLUE;
+        }
+        if color_flags.intersects(RafxColorFlags::ALPHA) {
+            color_writes |= wgpu::ColorWrites::ALPHA;
+        }
+        color_writes
+    }
+}
+
+impl From<RafxStencilOp> for wgpu::StencilOperation {
+    fn from(stencil_op: RafxStencilOp) -> Self {
+        match stencil_op {
+            RafxStencilOp::Keep => wgpu::StencilOperation::Keep,
+            RafxStencilOp::Zero => wgpu::StencilOperation::Zero,
+            RafxStencilOp::Replace => wgpu::StencilOperation::Replace,
+            RafxStencilOp::IncrementAndClamp => wgpu::StencilOperation::IncrementClamp,
+            RafxStencilOp::DecrementAndClamp => wgpu::StencilOperation::DecrementClamp,
+            RafxStencilOp::Invert => wgpu::StencilOperation::Invert,
+            RafxStencilOp::IncrementAndWrap => wgpu::StencilOperation::IncrementWrap,
+            RafxStencilOp::DecrementAndWrap => wgpu::StencilOperation::DecrementWrap,
+        }
+    }
+}
+
+impl From<&RafxDepthState> for wgpu::StencilState {
+    fn from(depth_state: &RafxDepthState) -> Self {
+        if !depth_state.stencil_test_enable {
+            return wgpu::StencilState::default();
+        }
+
+        wgpu::StencilState {
+            front: wgpu::StencilFaceState {
+                compare: depth_state.front_stencil_compare_op.into(),
+                fail_op: depth_state.front_stencil_fail_op.into(),
+                depth_fail_op: depth_state.front_depth_fail_op.into(),
+                pass_op: depth_state.front_stencil_pass_op.into(),
+            },
+            back: wgpu::StencilFaceState {
+                compare: depth_state.back_stencil_compare_op.into(),
+                fail_op: depth_state.back_stencil_fail_op.into(),
+                depth_fail_op: depth_state.back_depth_fail_op.into(),
+                pass_op: depth_state.back_stencil_pass_op.into(),
+            },
+            read_mask: depth_state.stencil_read_mask as u32,
+            write_mask: depth_state.stencil_write_mask as u32,
+        }
+    }
+}
+
+impl From<RafxCullMode> for Option<wgpu::Face> {
+    fn from(cull_mode: RafxCullMode) -> Self {
+        match cull_mode {
+            RafxCullMode::None => None,
+            RafxCullMode::Back => Some(wgpu::Face::Back),
+            RafxCullMode::Front => Some(wgpu::Face::Front),
+        }
+    }
+}
+
+impl From<RafxFrontFace> for wgpu::FrontFace {
+    fn from(front_face: RafxFrontFace) -> Self {
+        match front_face {
+            RafxFrontFace::CounterClockwise => wgpu::FrontFace::Ccw,
+            RafxFrontFace::Clockwise => wgpu::FrontFace::Cw,
+        }
+    }
+}
+
+impl From<RafxFillMode> for wgpu::PolygonMode {
+    fn from(fill_mode: RafxFillMode) -> Self {
+        match fill_mode {
+            RafxFillMode::Solid => wgpu::PolygonMode::Fill,
+            RafxFillMode::Wireframe => wgpu::PolygonMode::Line,
+        }
+    }
+}
+
+impl RafxPrimitiveTopology {
+    // Returns None for patch lists, WebGPU has no tessellation
+    pub fn webgpu_primitive_topology(self) -> Option<wgpu::PrimitiveTopology> {
+        match self {
+            RafxPrimitiveTopology::PointList => Some(wgpu::PrimitiveTopology::PointList),
+            RafxPrimitiveTopology::LineList => Some(wgpu::PrimitiveTopology::LineList),
+            RafxPrimitiveTopology::LineStrip => Some(wgpu::PrimitiveTopology::LineStrip),
+            RafxPrimitiveTopology::TriangleList => Some(wgpu::PrimitiveTopology::TriangleList),
+            RafxPrimitiveTopology::TriangleStrip => Some(wgpu::PrimitiveTopology::TriangleStrip),
+            RafxPrimitiveTopology::PatchList => None,
+        }
+    }
+}
+
+impl From<RafxIndexType> for wgpu::IndexFormat {
+    fn from(index_type: RafxIndexType) -> Self {
+        match index_type {
+            RafxIndexType::Uint32 => wgpu::IndexFormat::Uint32,
+            RafxIndexType::Uint16 => wgpu::IndexFormat::Uint16,
+        }
+    }
+}
+
+impl From<RafxVertexAttributeRate> for wgpu::VertexStepMode {
+    fn from(vertex_attribute_rate: RafxVertexAttributeRate) -> Self {
+        match vertex_attribute_rate {
+            RafxVertexAttributeRate::Vertex => wgpu::VertexStepMode::Vertex,
+            RafxVertexAttributeRate::Instance => wgpu::VertexStepMode::Instance,
+        }
+    }
+}
+
+impl From<RafxColorClearValue> for wgpu::Color {
+    fn from(color_clear_value: RafxColorClearValue) -> Self {
+        wgpu::Color {
+            r: color_clear_value.0[0] as f64,
+            g: color_clear_value.0[1] as f64,
+            b: color_clear_value.0[2] as f64,
+            a: color_clear_value.0[3] as f64,
+        }
+    }
+}
+
+impl From<RafxShaderStageFlags> for wgpu::ShaderStages {
+    fn from(shader_stage_flags: RafxShaderStageFlags) -> Self {
+        let mut shader_stages = wgpu::ShaderStages::NONE;
+        if shader_stage_flags.intersects(RafxShaderStageFlags::VERTEX) {
+            shader_stages |= wgpu::ShaderStages::VERTEX;
+        }
+        if shader_stage_flags.intersects(RafxShaderStageFlags::FRAGMENT) {
+            shader_stages |= wgpu::ShaderStages::FRAGMENT;
+        }
+        if shader_stage_flags.intersects(RafxShaderStageFlags::COMPUTE) {
+            shader_stages |= wgpu::ShaderStages::COMPUTE;
+        }
+        shader_stages
+    }
+}
+
+impl RafxStoreOp {
+    pub fn webgpu_store(self) -> bool {
+        match self {
+            RafxStoreOp::DontCare => false,
+            RafxStoreOp::Store => true,
+        }
+    }
+}
+
+impl RafxLoadOp {
+    // WebGPU has no "don't care" load op, so treat it as a clear which is the cheaper option on
+    // tile-based GPUs
+    pub fn webgpu_load_op<V>(
+        self,
+        clear_value: V,
+    ) -> wgpu::LoadOp<V> {
+        match self {
+            RafxLoadOp::DontCare => wgpu::LoadOp::Clear(clear_value),
+            RafxLoadOp::Load => wgpu::LoadOp::Load,
+            RafxLoadOp::Clear => wgpu::LoadOp::Clear(clear_value),
+        }
+    }
+}
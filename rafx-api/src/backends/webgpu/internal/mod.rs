@@ -0,0 +1,7 @@
+pub mod conversions;
+
+mod binding_reflection;
+pub(crate) use binding_reflection::*;
+
+mod mipmap_generator;
+pub(crate) use mipmap_generator::*;
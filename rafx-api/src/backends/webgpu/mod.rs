@@ -0,0 +1,53 @@
+mod api;
+pub use api::*;
+
+mod device_context;
+pub use device_context::*;
+
+mod swapchain;
+pub use swapchain::*;
+
+mod texture;
+pub use texture::*;
+
+mod semaphore;
+pub use semaphore::*;
+
+mod fence;
+pub use fence::*;
+
+mod queue;
+pub use queue::*;
+
+mod command_pool;
+pub use command_pool::*;
+
+mod command_buffer;
+pub use command_buffer::*;
+
+mod buffer;
+pub use buffer::*;
+
+mod shader_module;
+pub use shader_module::*;
+
+mod shader;
+pub use shader::*;
+
+mod root_signature;
+pub use root_signature::*;
+
+mod descriptor_set_array;
+pub use descriptor_set_array::*;
+
+mod sampler;
+pub use sampler::*;
+
+mod pipeline;
+pub use pipeline::*;
+
+mod internal;
+pub(crate) use internal::*;
+
+// Re-export so that downstream code can use wgpu types without adding a separate dependency
+pub use wgpu;
@@ -0,0 +1,289 @@
+use crate::webgpu::RafxDeviceContextWebGpu;
+use crate::{
+    RafxBlendStateTargets, RafxComputePipelineDef, RafxGraphicsPipelineDef, RafxPipelineType,
+    RafxResult, RafxRootSignature, RafxShaderStageFlags, RafxSpecializationConstant,
+};
+use std::sync::Arc;
+
+// The WGSL produced by naga declares specialization constants as plain constants, so they can't be
+// overridden when creating the pipeline
+fn verify_no_specialization_constants(
+    specialization_constants: &[RafxSpecializationConstant]
+) -> RafxResult<()> {
+    if !specialization_constants.is_empty() {
+        Err("Specialization constants are not supported by the WebGPU backend")?;
+    }
+
+    Ok(())
+}
+
+#[derive(Debug, Clone)]
+pub(crate) enum WebGpuPipeline {
+    Render(Arc<wgpu::RenderPipeline>),
+    Compute(Arc<wgpu::ComputePipeline>),
+}
+
+#[derive(Debug)]
+pub struct RafxPipelineWebGpu {
+    pipeline_type: RafxPipelineType,
+    // It's a RafxRootSignatureWebGpu, but stored as RafxRootSignature so we can return refs to it
+    root_signature: RafxRootSignature,
+    pipeline: WebGpuPipeline,
+}
+
+impl RafxPipelineWebGpu {
+    pub fn pipeline_type(&self) -> RafxPipelineType {
+        self.pipeline_type
+    }
+
+    pub fn root_signature(&self) -> &RafxRootSignature {
+        &self.root_signature
+    }
+
+    pub fn webgpu_render_pipeline(&self) -> Option<&wgpu::RenderPipeline> {
+        match &self.pipeline {
+            WebGpuPipeline::Render(pipeline) => Some(pipeline),
+            WebGpuPipeline::Compute(_) => None,
+        }
+    }
+
+    pub fn webgpu_compute_pipeline(&self) -> Option<&wgpu::ComputePipeline> {
+        match &self.pipeline {
+            WebGpuPipeline::Render(_) => None,
+            WebGpuPipeline::Compute(pipeline) => Some(pipeline),
+        }
+    }
+
+    pub(crate) fn webgpu_pipeline(&self) -> &WebGpuPipeline {
+        &self.pipeline
+    }
+
+    pub fn new_graphics_pipeline(
+        device_context: &RafxDeviceContextWebGpu,
+        pipeline_def: &RafxGraphicsPipelineDef,
+    ) -> RafxResult<Self> {
+        verify_no_specialization_constants(pipeline_def.specialization_constants)?;
+        let root_signature = pipeline_def.root_signature.webgpu_root_signature().unwrap();
+
+        let mut vertex_stage = None;
+        let mut fragment_stage = None;
+        for stage in pipeline_def.shader.webgpu_shader().unwrap().stages() {
+            if stage
+                .reflection
+                .shader_stage
+                .intersects(RafxShaderStageFlags::VERTEX)
+            {
+                vertex_stage = Some(stage);
+            } else if stage
+                .reflection
+                .shader_stage
+                .intersects(RafxShaderStageFlags::FRAGMENT)
+            {
+                fragment_stage = Some(stage);
+            } else {
+                Err(format!(
+                    "WebGPU only supports vertex and fragment stages in graphics pipelines, but stage {:?} was provided",
+                    stage.reflection.shader_stage
+                ))?;
+            }
+        }
+
+        let vertex_stage = vertex_stage.ok_or("Graphics pipelines require a vertex shader")?;
+
+        let topology = pipeline_def
+            .primitive_topology
+            .webgpu_primitive_topology()
+            .ok_or("WebGPU does not support patch lists")?;
+
+        //
+        // Vertex layout
+        //
+        let mut vertex_attributes = vec![vec![]; pipeline_def.vertex_layout.buffers.len()];
+        for attribute in &pipeline_def.vertex_layout.attributes {
+            let format = attribute.format.webgpu_vertex_format().ok_or_else(|| {
+                format!(
+                    "Format {:?} is not supported as a vertex format",
+                    attribute.format
+                )
+            })?;
+
+            vertex_attributes[attribute.buffer_index as usize].push(wgpu::VertexAttribute {
+                format,
+                offset: attribute.byte_offset as u64,
+                shader_location: attribute.location,
+            });
+        }
+
+        let vertex_buffers: Vec<_> = pipeline_def
+            .vertex_layout
+            .buffers
+            .iter()
+            .zip(&vertex_attributes)
+            .map(|(buffer, attributes)| wgpu::VertexBufferLayout {
+                array_stride: buffer.stride as u64,
+                step_mode: buffer.rate.into(),
+                attributes,
+            })
+            .collect();
+
+        //
+        // Color targets and blending
+        //
+        let blend_state = pipeline_def.blend_state;
+        blend_state.verify(pipeline_def.color_formats.len());
+
+        let mut color_targets = Vec::with_capacity(pipeline_def.color_formats.len());
+        for (attachment_index, color_format) in pipeline_def.color_formats.iter().enumerate() {
+            let format = color_format
+                .webgpu_texture_format()
+                .ok_or_else(|| format!("Format {:?} not supported", color_format))?;
+
+            let target_blend_state = if blend_state
+                .render_target_mask
+                .intersects(RafxBlendStateTargets::from_bits(1 << attachment_index).unwrap())
+            {
+                if blend_state.independent_blend {
+                    Some(&blend_state.render_target_blend_states[attachment_index])
+                } else {
+                    blend_state.render_target_blend_states.first()
+                }
+            } else {
+                None
+            };
+
+            color_targets.push(Some(wgpu::ColorTargetState {
+                format,
+                blend: target_blend_state
+                    .filter(|x| x.blend_enabled())
+                    .map(|x| x.into()),
+                write_mask: target_blend_state
+                    .map(|x| x.masks.into())
+                    .unwrap_or(wgpu::ColorWrites::ALL),
+            }));
+        }
+
+        //
+        // Depth/stencil
+        //
+        let depth_stencil = if let Some(depth_stencil_format) = pipeline_def.depth_stencil_format {
+            let format = depth_stencil_format
+                .webgpu_texture_format()
+                .ok_or_else(|| format!("Format {:?} not supported", depth_stencil_format))?;
+
+            let depth_state = pipeline_def.depth_state;
+            let rasterizer_state = pipeline_def.rasterizer_state;
+            Some(wgpu::DepthStencilState {
+                format,
+                depth_write_enabled: depth_state.depth_test_enable
+                    && depth_state.depth_write_enable,
+                depth_compare: if depth_state.depth_test_enable {
+                    depth_state.depth_compare_op.into()
+                } else {
+                    wgpu::CompareFunction::Always
+                },
+                stencil: depth_state.into(),
+                bias: wgpu::DepthBiasState {
+                    constant: rasterizer_state.depth_bias,
+                    slope_scale: rasterizer_state.depth_bias_slope_scaled,
+                    clamp: 0.0,
+                },
+            })
+        } else {
+            None
+        };
+
+        let unclipped_depth = pipeline_def.rasterizer_state.depth_clamp_enable
+            && device_context
+                .features()
+                .contains(wgpu::Features::DEPTH_CLIP_CONTROL);
+
+        let render_pipeline =
+            device_context
+                .device()
+                .create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+                    label: pipeline_def.debug_name,
+                    layout: Some(root_signature.webgpu_pipeline_layout()),
+                    vertex: wgpu::VertexState {
+                        module: vertex_stage
+                            .shader_module
+                            .webgpu_shader_module()
+                            .unwrap()
+                            .webgpu_shader_module(),
+                        entry_point: &vertex_stage.reflection.entry_point_name,
+                        buffers: &vertex_buffers,
+                    },
+                    primitive: wgpu::PrimitiveState {
+                        topology,
+                        strip_index_format: None,
+                        front_face: pipeline_def.rasterizer_state.front_face.into(),
+                        cull_mode: pipeline_def.rasterizer_state.cull_mode.into(),
+                        unclipped_depth,
+                        polygon_mode: pipeline_def.rasterizer_state.fill_mode.into(),
+                        conservative: false,
+                    },
+                    depth_stencil,
+                    multisample: wgpu::MultisampleState {
+                        count: pipeline_def.sample_count.as_u32(),
+                        mask: !0,
+                        alpha_to_coverage_enabled: false,
+                    },
+                    fragment: fragment_stage.map(|fragment_stage| wgpu::FragmentState {
+                        module: fragment_stage
+                            .shader_module
+                            .webgpu_shader_module()
+                            .unwrap()
+                            .webgpu_shader_module(),
+                        entry_point: &fragment_stage.reflection.entry_point_name,
+                        targets: &color_targets,
+                    }),
+                    multiview: None,
+                });
+
+        Ok(RafxPipelineWebGpu {
+            pipeline_type: root_signature.pipeline_type(),
+            root_signature: pipeline_def.root_signature.clone(),
+            pipeline: WebGpuPipeline::Render(Arc::new(render_pipeline)),
+        })
+    }
+
+    pub fn new_compute_pipeline(
+        device_context: &RafxDeviceContextWebGpu,
+        pipeline_def: &RafxComputePipelineDef,
+    ) -> RafxResult<Self> {
+        verify_no_specialization_constants(pipeline_def.specialization_constants)?;
+        let root_signature = pipeline_def.root_signature.webgpu_root_signature().unwrap();
+
+        let mut compute_stage = None;
+        for stage in pipeline_def.shader.webgpu_shader().unwrap().stages() {
+            if stage.reflection.shader_stage == RafxShaderStageFlags::COMPUTE {
+                assert!(compute_stage.is_none());
+                compute_stage = Some(stage);
+            } else {
+                Err("Tried to set a non-compute stage on a compute pipeline")?;
+            }
+        }
+
+        let compute_stage =
+            compute_stage.ok_or("Tried to create a compute pipeline with no compute stage")?;
+
+        let compute_pipeline =
+            device_context
+                .device()
+                .create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+                    label: pipeline_def.debug_name,
+                    layout: Some(root_signature.webgpu_pipeline_layout()),
+                    module: compute_stage
+                        .shader_module
+                        .webgpu_shader_module()
+                        .unwrap()
+                        .webgpu_shader_module(),
+                    entry_point: &compute_stage.reflection.entry_point_name,
+                });
+
+        Ok(RafxPipelineWebGpu {
+            pipeline_type: root_signature.pipeline_type(),
+            root_signature: pipeline_def.root_signature.clone(),
+            pipeline: WebGpuPipeline::Compute(Arc::new(compute_pipeline)),
+        })
+    }
+}
@@ -0,0 +1,125 @@
+use crate::webgpu::{
+    RafxCommandBufferWebGpu, RafxCommandPoolWebGpu, RafxDeviceContextWebGpu, RafxFenceWebGpu,
+    RafxSemaphoreWebGpu, RafxSwapchainWebGpu,
+};
+use crate::{RafxCommandPoolDef, RafxPresentSuccessResult, RafxQueueType, RafxResult};
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Arc;
+
+static NEXT_QUEUE_ID: AtomicU32 = AtomicU32::new(0);
+
+#[derive(Debug)]
+pub struct RafxQueueWebGpuInner {
+    device_context: RafxDeviceContextWebGpu,
+    queue_type: RafxQueueType,
+    queue_id: u32,
+}
+
+// All rafx queues share the single wgpu::Queue. wgpu executes submissions in order, so semaphores
+// only need to track whether they have been signaled.
+#[derive(Clone, Debug)]
+pub struct RafxQueueWebGpu {
+    inner: Arc<RafxQueueWebGpuInner>,
+}
+
+impl RafxQueueWebGpu {
+    pub fn queue_id(&self) -> u32 {
+        self.inner.queue_id
+    }
+
+    pub fn queue_type(&self) -> RafxQueueType {
+        self.inner.queue_type
+    }
+
+    pub fn device_context(&self) -> &RafxDeviceContextWebGpu {
+        &self.inner.device_context
+    }
+
+    pub fn webgpu_queue(&self) -> &wgpu::Queue {
+        self.inner.device_context.queue()
+    }
+
+    pub fn create_command_pool(
+        &self,
+        command_pool_def: &RafxCommandPoolDef,
+    ) -> RafxResult<RafxCommandPoolWebGpu> {
+        RafxCommandPoolWebGpu::new(self, command_pool_def)
+    }
+
+    pub fn new(
+        device_context: &RafxDeviceContextWebGpu,
+        queue_type: RafxQueueType,
+    ) -> RafxResult<RafxQueueWebGpu> {
+        let queue_id = NEXT_QUEUE_ID.fetch_add(1, Ordering::Relaxed);
+        let inner = RafxQueueWebGpuInner {
+            device_context: device_context.clone(),
+            queue_type,
+            queue_id,
+        };
+
+        Ok(RafxQueueWebGpu {
+            inner: Arc::new(inner),
+        })
+    }
+
+    pub fn wait_for_queue_idle(&self) -> RafxResult<()> {
+        self.device_context().device().poll(wgpu::Maintain::Wait);
+        Ok(())
+    }
+
+    fn submit_semaphore_wait(
+        &self,
+        wait_semaphores: &[&RafxSemaphoreWebGpu],
+    ) {
+        for &semaphore in wait_semaphores {
+            if semaphore.signal_available() {
+                semaphore.set_signal_available(false);
+            }
+        }
+    }
+
+    pub fn submit(
+        &self,
+        command_buffers: &[&RafxCommandBufferWebGpu],
+        wait_semaphores: &[&RafxSemaphoreWebGpu],
+        signal_semaphores: &[&RafxSemaphoreWebGpu],
+        signal_fence: Option<&RafxFenceWebGpu>,
+    ) -> RafxResult<()> {
+        assert!(!command_buffers.is_empty());
+
+        self.submit_semaphore_wait(wait_semaphores);
+
+        let device_context = self.device_context();
+        device_context.flush_mapped_buffers();
+
+        let mut encoder = device_context
+            .device()
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor { label: None });
+
+        for command_buffer in command_buffers {
+            command_buffer.encode(&mut encoder)?;
+        }
+
+        device_context.queue().submit(Some(encoder.finish()));
+
+        for semaphore in signal_semaphores {
+            semaphore.set_signal_available(true);
+        }
+
+        if let Some(fence) = signal_fence {
+            fence.signal_on_queue(device_context.queue());
+        }
+
+        Ok(())
+    }
+
+    pub fn present(
+        &self,
+        swapchain: &RafxSwapchainWebGpu,
+        wait_semaphores: &[&RafxSemaphoreWebGpu],
+        _image_index: u32,
+    ) -> RafxResult<RafxPresentSuccessResult> {
+        self.submit_semaphore_wait(wait_semaphores);
+        swapchain.present()
+    }
+}
@@ -0,0 +1,410 @@
+use crate::webgpu::{RafxDeviceContextWebGpu, RafxSamplerWebGpu, WebGpuBindingTypes};
+use crate::*;
+use fnv::FnvHashMap;
+use std::num::NonZeroU32;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+static NEXT_ROOT_SIGNATURE_ID: std::sync::atomic::AtomicU32 = std::sync::atomic::AtomicU32::new(1);
+
+#[derive(Clone, Debug)]
+pub(crate) struct DescriptorInfo {
+    pub(crate) name: Option<String>,
+    pub(crate) resource_type: RafxResourceType,
+
+    // Also the set layout
+    pub(crate) set_index: u32,
+    // Binding within the set
+    pub(crate) binding: u32,
+    // Used for arrays of textures, samplers, etc.
+    pub(crate) element_count: u32,
+
+    pub(crate) push_constant_size: u32,
+
+    // --- webgpu-specific ---
+    // The index to the first descriptor in the flattened list of all descriptors in the layout
+    pub(crate) update_data_offset_in_set: Option<u32>,
+    pub(crate) binding_type: Option<wgpu::BindingType>,
+    pub(crate) webgpu_stages: wgpu::ShaderStages,
+}
+
+#[derive(Default, Debug)]
+pub(crate) struct DescriptorSetLayoutInfo {
+    // Settable descriptors, immutable samplers are omitted
+    pub(crate) descriptors: Vec<RafxDescriptorIndex>,
+    // Indexes binding index to the descriptors list
+    pub(crate) binding_to_descriptor_index: FnvHashMap<u32, RafxDescriptorIndex>,
+
+    // --- webgpu-specific ---
+    pub(crate) update_data_count_per_set: u32,
+    // Immutable samplers have no descriptor. They are written into every bind group created for
+    // this layout
+    pub(crate) immutable_samplers: Vec<(u32, Vec<RafxSamplerWebGpu>)>,
+    // False if no shader uses this set. An empty bind group is bound for these sets.
+    pub(crate) has_bindings: bool,
+}
+
+#[derive(Debug)]
+pub(crate) struct RafxRootSignatureWebGpuInner {
+    pub(crate) device_context: RafxDeviceContextWebGpu,
+    pub(crate) pipeline_type: RafxPipelineType,
+    pub(crate) layouts: [DescriptorSetLayoutInfo; MAX_DESCRIPTOR_SET_LAYOUTS],
+    pub(crate) descriptors: Vec<DescriptorInfo>,
+    pub(crate) name_to_descriptor_index: FnvHashMap<String, RafxDescriptorIndex>,
+    pub(crate) push_constant_descriptors:
+        [Option<RafxDescriptorIndex>; ALL_SHADER_STAGE_FLAGS.len()],
+    pub(crate) root_signature_id: u32,
+
+    // --- webgpu-specific ---
+    pub(crate) pipeline_layout: wgpu::PipelineLayout,
+    // One per set up to the highest set index that is used
+    pub(crate) bind_group_layouts: Vec<Arc<wgpu::BindGroupLayout>>,
+    pub(crate) empty_bind_group: Arc<wgpu::BindGroup>,
+}
+
+#[derive(Clone, Debug)]
+pub struct RafxRootSignatureWebGpu {
+    pub(crate) inner: Arc<RafxRootSignatureWebGpuInner>,
+}
+
+impl PartialEq for RafxRootSignatureWebGpu {
+    fn eq(
+        &self,
+        other: &Self,
+    ) -> bool {
+        self.inner.root_signature_id == other.inner.root_signature_id
+    }
+}
+
+impl RafxRootSignatureWebGpu {
+    pub fn device_context(&self) -> &RafxDeviceContextWebGpu {
+        &self.inner.device_context
+    }
+
+    pub fn pipeline_type(&self) -> RafxPipelineType {
+        self.inner.pipeline_type
+    }
+
+    pub fn webgpu_pipeline_layout(&self) -> &wgpu::PipelineLayout {
+        &self.inner.pipeline_layout
+    }
+
+    pub fn webgpu_bind_group_layout(
+        &self,
+        set_index: u32,
+    ) -> Option<&wgpu::BindGroupLayout> {
+        self.inner
+            .bind_group_layouts
+            .get(set_index as usize)
+            .map(|x| &**x)
+    }
+
+    pub fn find_descriptor_by_name(
+        &self,
+        name: &str,
+    ) -> Option<RafxDescriptorIndex> {
+        self.inner.name_to_descriptor_index.get(name).copied()
+    }
+
+    pub fn find_descriptor_by_binding(
+        &self,
+        set_index: u32,
+        binding: u32,
+    ) -> Option<RafxDescriptorIndex> {
+        self.inner
+            .layouts
+            .get(set_index as usize)
+            .and_then(|x| x.binding_to_descriptor_index.get(&binding))
+            .copied()
+    }
+
+    pub fn find_push_constant_descriptor(
+        &self,
+        stage: RafxShaderStageFlags,
+    ) -> Option<RafxDescriptorIndex> {
+        let mut found_descriptor = None;
+        for (stage_index, s) in ALL_SHADER_STAGE_FLAGS.iter().enumerate() {
+            if s.intersects(stage) {
+                let s_descriptor_index = self.inner.push_constant_descriptors[stage_index];
+                if s_descriptor_index.is_some() {
+                    if let Some(found_descriptor) = found_descriptor {
+                        if found_descriptor != s_descriptor_index {
+                            // The caller passed multiple stages and they do not use the same push constant descriptor
+                            return None;
+                        }
+                    } else {
+                        found_descriptor = Some(s_descriptor_index);
+                    }
+                }
+            }
+        }
+
+        found_descriptor.flatten()
+    }
+
+    pub(crate) fn descriptor(
+        &self,
+        descriptor_index: RafxDescriptorIndex,
+    ) -> Option<&DescriptorInfo> {
+        self.inner.descriptors.get(descriptor_index.0 as usize)
+    }
+
+    pub fn new(
+        device_context: &RafxDeviceContextWebGpu,
+        root_signature_def: &RafxRootSignatureDef,
+    ) -> RafxResult<Self> {
+        log::trace!("Create RafxRootSignatureWebGpu");
+
+        // If we update this constant, update the arrays in this function
+        assert_eq!(MAX_DESCRIPTOR_SET_LAYOUTS, 4);
+
+        let mut descriptors = vec![];
+        let mut layouts = [
+            DescriptorSetLayoutInfo::default(),
+            DescriptorSetLayoutInfo::default(),
+            DescriptorSetLayoutInfo::default(),
+            DescriptorSetLayoutInfo::default(),
+        ];
+
+        let mut webgpu_set_entries = [vec![], vec![], vec![], vec![]];
+        let mut webgpu_push_constant_ranges = vec![];
+        let mut push_constant_descriptors = [None; ALL_SHADER_STAGE_FLAGS.len()];
+
+        let mut name_to_descriptor_index = FnvHashMap::default();
+
+        // Make sure all shaders are compatible/build lookup of shared data from them
+        let (pipeline_type, merged_resources, _merged_resources_name_index_map) =
+            crate::internal_shared::merge_resources(root_signature_def)?;
+
+        // The types reflected from WGSL carry more detail than the rafx reflection data
+        let mut binding_types = WebGpuBindingTypes::default();
+        for shader in root_signature_def.shaders {
+            binding_types.extend(shader.webgpu_shader().unwrap().binding_types());
+        }
+
+        for resource in &merged_resources {
+            resource.validate()?;
+
+            let webgpu_stages: wgpu::ShaderStages = resource.used_in_shader_stages.into();
+
+            if resource.resource_type == RafxResourceType::ROOT_CONSTANT {
+                if !device_context
+                    .features()
+                    .contains(wgpu::Features::PUSH_CONSTANTS)
+                {
+                    Err(format!(
+                        "Push constant {:?} is used but push constants are not supported by this WebGPU device",
+                        resource.name
+                    ))?;
+                }
+
+                webgpu_push_constant_ranges.push(wgpu::PushConstantRange {
+                    stages: webgpu_stages,
+                    range: 0..resource.size_in_bytes,
+                });
+
+                // Add it to the descriptor list
+                let descriptor_index = RafxDescriptorIndex(descriptors.len() as u32);
+                descriptors.push(DescriptorInfo {
+                    name: resource.name.clone(),
+                    resource_type: resource.resource_type,
+                    set_index: u32::MAX,
+                    binding: u32::MAX,
+                    push_constant_size: resource.size_in_bytes,
+                    element_count: 0,
+                    update_data_offset_in_set: None,
+                    binding_type: None,
+                    webgpu_stages,
+                });
+
+                if let Some(name) = resource.name.as_ref() {
+                    name_to_descriptor_index.insert(name.clone(), descriptor_index);
+                }
+
+                for (i, stage) in ALL_SHADER_STAGE_FLAGS.iter().enumerate() {
+                    if stage.intersects(resource.used_in_shader_stages) {
+                        push_constant_descriptors[i] = Some(descriptor_index);
+                    }
+                }
+
+                continue;
+            }
+
+            let element_count = resource.element_count_normalized();
+            let binding_type = binding_types
+                .get(&(resource.set_index, resource.binding))
+                .copied()
+                .or_else(|| default_binding_type(resource.resource_type))
+                .ok_or_else(|| {
+                    format!(
+                        "Resource type {:?} not supported by WebGPU (set={:?} binding={:?} name={:?})",
+                        resource.resource_type,
+                        resource.set_index,
+                        resource.binding,
+                        resource.name
+                    )
+                })?;
+
+            webgpu_set_entries[resource.set_index as usize].push(wgpu::BindGroupLayoutEntry {
+                binding: resource.binding,
+                visibility: webgpu_stages,
+                ty: binding_type,
+                count: if element_count > 1 {
+                    NonZeroU32::new(element_count)
+                } else {
+                    None
+                },
+            });
+
+            let layout: &mut DescriptorSetLayoutInfo = &mut layouts[resource.set_index as usize];
+            layout.has_bindings = true;
+
+            let immutable_sampler = crate::internal_shared::find_immutable_sampler_index(
+                root_signature_def.immutable_samplers,
+                &resource.name,
+                resource.set_index,
+                resource.binding,
+            );
+
+            if let Some(immutable_sampler_index) = immutable_sampler {
+                let samplers = root_signature_def.immutable_samplers[immutable_sampler_index]
+                    .samplers
+                    .iter()
+                    .map(|x| x.webgpu_sampler().unwrap().clone())
+                    .collect::<Vec<_>>();
+
+                if element_count as usize != samplers.len() {
+                    Err(format!(
+                        "Descriptor (set={:?} binding={:?}) named {:?} specifies {} elements but the count of provided immutable samplers ({}) did not match",
+                        resource.set_index,
+                        resource.binding,
+                        resource.name,
+                        element_count,
+                        samplers.len()
+                    ))?;
+                }
+
+                layout.immutable_samplers.push((resource.binding, samplers));
+            } else {
+                // Add it to the descriptor list
+                let descriptor_index = RafxDescriptorIndex(descriptors.len() as u32);
+                descriptors.push(DescriptorInfo {
+                    name: resource.name.clone(),
+                    resource_type: resource.resource_type,
+                    set_index: resource.set_index,
+                    binding: resource.binding,
+                    push_constant_size: 0,
+                    element_count,
+                    update_data_offset_in_set: Some(layout.update_data_count_per_set),
+                    binding_type: Some(binding_type),
+                    webgpu_stages,
+                });
+
+                if let Some(name) = resource.name.as_ref() {
+                    name_to_descriptor_index.insert(name.clone(), descriptor_index);
+                }
+
+                layout.descriptors.push(descriptor_index);
+                layout
+                    .binding_to_descriptor_index
+                    .insert(resource.binding, descriptor_index);
+
+                layout.update_data_count_per_set += element_count;
+            }
+        }
+
+        //
+        // Create bind group layouts. Sets that aren't used get an empty layout, but only up to the
+        // highest set index in use
+        //
+        let device = device_context.device();
+        let empty_bind_group_layout = Arc::new(device.create_bind_group_layout(
+            &wgpu::BindGroupLayoutDescriptor {
+                label: None,
+                entries: &[],
+            },
+        ));
+
+        let empty_bind_group = Arc::new(device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: None,
+            layout: &empty_bind_group_layout,
+            entries: &[],
+        }));
+
+        let set_count = layouts
+            .iter()
+            .rposition(|x| x.has_bindings)
+            .map(|x| x + 1)
+            .unwrap_or(0);
+
+        let mut bind_group_layouts = Vec::with_capacity(set_count);
+        for set_index in 0..set_count {
+            if layouts[set_index].has_bindings {
+                bind_group_layouts.push(Arc::new(device.create_bind_group_layout(
+                    &wgpu::BindGroupLayoutDescriptor {
+                        label: None,
+                        entries: &webgpu_set_entries[set_index],
+                    },
+                )));
+            } else {
+                bind_group_layouts.push(empty_bind_group_layout.clone());
+            }
+        }
+
+        let bind_group_layout_refs: Vec<_> = bind_group_layouts.iter().map(|x| &**x).collect();
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: None,
+            bind_group_layouts: &bind_group_layout_refs,
+            push_constant_ranges: &webgpu_push_constant_ranges,
+        });
+
+        let root_signature_id = NEXT_ROOT_SIGNATURE_ID.fetch_add(1, Ordering::Relaxed);
+
+        let inner = RafxRootSignatureWebGpuInner {
+            device_context: device_context.clone(),
+            pipeline_type,
+            layouts,
+            descriptors,
+            name_to_descriptor_index,
+            push_constant_descriptors,
+            root_signature_id,
+            pipeline_layout,
+            bind_group_layouts,
+            empty_bind_group,
+        };
+
+        Ok(RafxRootSignatureWebGpu {
+            inner: Arc::new(inner),
+        })
+    }
+}
+
+// Used if the binding wasn't found in the WGSL, which shouldn't normally happen
+fn default_binding_type(resource_type: RafxResourceType) -> Option<wgpu::BindingType> {
+    match resource_type {
+        RafxResourceType::SAMPLER => Some(wgpu::BindingType::Sampler(
+            wgpu::SamplerBindingType::Filtering,
+        )),
+        RafxResourceType::TEXTURE => Some(wgpu::BindingType::Texture {
+            sample_type: wgpu::TextureSampleType::Float { filterable: true },
+            view_dimension: wgpu::TextureViewDimension::D2,
+            multisampled: false,
+        }),
+        RafxResourceType::UNIFORM_BUFFER => Some(wgpu::BindingType::Buffer {
+            ty: wgpu::BufferBindingType::Uniform,
+            has_dynamic_offset: false,
+            min_binding_size: None,
+        }),
+        RafxResourceType::BUFFER => Some(wgpu::BindingType::Buffer {
+            ty: wgpu::BufferBindingType::Storage { read_only: true },
+            has_dynamic_offset: false,
+            min_binding_size: None,
+        }),
+        RafxResourceType::BUFFER_READ_WRITE => Some(wgpu::BindingType::Buffer {
+            ty: wgpu::BufferBindingType::Storage { read_only: false },
+            has_dynamic_offset: false,
+            min_binding_size: None,
+        }),
+        _ => None,
+    }
+}
@@ -0,0 +1,80 @@
+use crate::webgpu::RafxDeviceContextWebGpu;
+use crate::{RafxAddressMode, RafxCompareOp, RafxResult, RafxSamplerDef};
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct RafxSamplerWebGpuInner {
+    pub(crate) _device_context: RafxDeviceContextWebGpu,
+    pub(crate) sampler: Arc<wgpu::Sampler>,
+}
+
+#[derive(Debug, Clone)]
+pub struct RafxSamplerWebGpu {
+    pub(crate) inner: Arc<RafxSamplerWebGpuInner>,
+}
+
+impl RafxSamplerWebGpu {
+    pub fn webgpu_sampler(&self) -> &wgpu::Sampler {
+        &self.inner.sampler
+    }
+
+    pub(crate) fn webgpu_sampler_arc(&self) -> &Arc<wgpu::Sampler> {
+        &self.inner.sampler
+    }
+
+    pub fn new(
+        device_context: &RafxDeviceContextWebGpu,
+        sampler_def: &RafxSamplerDef,
+    ) -> RafxResult<RafxSamplerWebGpu> {
+        let uses_clamp_to_border = sampler_def.address_mode_u == RafxAddressMode::ClampToBorder
+            || sampler_def.address_mode_v == RafxAddressMode::ClampToBorder
+            || sampler_def.address_mode_w == RafxAddressMode::ClampToBorder;
+        if uses_clamp_to_border && !device_context.device_info().supports_clamp_to_border_color {
+            Err("RafxAddressMode::ClampToBorder is not supported by this WebGPU device")?;
+        }
+
+        // WebGPU requires all filters to be linear if anisotropy is enabled
+        let anisotropy_clamp = if sampler_def.max_anisotropy > 1.0 {
+            (sampler_def.max_anisotropy as u16).min(16)
+        } else {
+            1
+        };
+
+        let is_comparison = sampler_def.compare_op != RafxCompareOp::Never;
+
+        //TODO: mip_lod_bias is not supported by WebGPU
+        let sampler = device_context
+            .device()
+            .create_sampler(&wgpu::SamplerDescriptor {
+                label: None,
+                address_mode_u: sampler_def.address_mode_u.into(),
+                address_mode_v: sampler_def.address_mode_v.into(),
+                address_mode_w: sampler_def.address_mode_w.into(),
+                mag_filter: sampler_def.mag_filter.into(),
+                min_filter: sampler_def.min_filter.into(),
+                mipmap_filter: sampler_def.mip_map_mode.into(),
+                lod_min_clamp: 0.0,
+                lod_max_clamp: 1000.0,
+                compare: if is_comparison {
+                    Some(sampler_def.compare_op.into())
+                } else {
+                    None
+                },
+                anisotropy_clamp,
+                border_color: if uses_clamp_to_border {
+                    Some(wgpu::SamplerBorderColor::TransparentBlack)
+                } else {
+                    None
+                },
+            });
+
+        let inner = RafxSamplerWebGpuInner {
+            _device_context: device_context.clone(),
+            sampler: Arc::new(sampler),
+        };
+
+        Ok(RafxSamplerWebGpu {
+            inner: Arc::new(inner),
+        })
+    }
+}
@@ -0,0 +1,32 @@
+use crate::webgpu::RafxDeviceContextWebGpu;
+use crate::RafxResult;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+// WebGPU executes submissions in order on a single queue, so GPU->GPU sync is implicit
+pub struct RafxSemaphoreWebGpu {
+    _device_context: RafxDeviceContextWebGpu,
+
+    // Set to true when an operation is scheduled to signal this semaphore
+    // Cleared when an operation is scheduled to consume this semaphore
+    signal_available: AtomicBool,
+}
+
+impl RafxSemaphoreWebGpu {
+    pub fn new(device_context: &RafxDeviceContextWebGpu) -> RafxResult<RafxSemaphoreWebGpu> {
+        Ok(RafxSemaphoreWebGpu {
+            _device_context: device_context.clone(),
+            signal_available: AtomicBool::new(false),
+        })
+    }
+
+    pub(crate) fn signal_available(&self) -> bool {
+        self.signal_available.load(Ordering::Relaxed)
+    }
+
+    pub(crate) fn set_signal_available(
+        &self,
+        available: bool,
+    ) {
+        self.signal_available.store(available, Ordering::Relaxed);
+    }
+}
@@ -0,0 +1,71 @@
+use crate::webgpu::{RafxDeviceContextWebGpu, WebGpuBindingTypes};
+use crate::{RafxPipelineReflection, RafxResult, RafxShaderStageDef, RafxShaderStageFlags};
+use std::sync::Arc;
+
+#[derive(Debug)]
+struct RafxShaderWebGpuInner {
+    stage_flags: RafxShaderStageFlags,
+    stages: Vec<RafxShaderStageDef>,
+    pipeline_reflection: RafxPipelineReflection,
+    binding_types: WebGpuBindingTypes,
+}
+
+#[derive(Clone, Debug)]
+pub struct RafxShaderWebGpu {
+    inner: Arc<RafxShaderWebGpuInner>,
+}
+
+impl RafxShaderWebGpu {
+    pub fn new(
+        _device_context: &RafxDeviceContextWebGpu,
+        stages: Vec<RafxShaderStageDef>,
+    ) -> RafxResult<Self> {
+        let pipeline_reflection = RafxPipelineReflection::from_stages(&stages)?;
+        let mut stage_flags = RafxShaderStageFlags::empty();
+        let mut binding_types = WebGpuBindingTypes::default();
+        for stage in &stages {
+            stage_flags |= stage.reflection.shader_stage;
+
+            let shader_module = stage.shader_module.webgpu_shader_module().unwrap();
+            for (&key, &binding_type) in shader_module.binding_types() {
+                // Stages are often compiled from the same WGSL module, so the same binding will
+                // show up more than once
+                if let Some(existing) = binding_types.insert(key, binding_type) {
+                    if existing != binding_type {
+                        Err(format!(
+                            "Binding (set={} binding={}) has mismatching types {:?} and {:?} across shader stages",
+                            key.0, key.1, existing, binding_type
+                        ))?;
+                    }
+                }
+            }
+        }
+
+        let inner = RafxShaderWebGpuInner {
+            stages,
+            pipeline_reflection,
+            stage_flags,
+            binding_types,
+        };
+
+        Ok(RafxShaderWebGpu {
+            inner: Arc::new(inner),
+        })
+    }
+
+    pub fn stages(&self) -> &[RafxShaderStageDef] {
+        &self.inner.stages
+    }
+
+    pub fn pipeline_reflection(&self) -> &RafxPipelineReflection {
+        &self.inner.pipeline_reflection
+    }
+
+    pub fn stage_flags(&self) -> RafxShaderStageFlags {
+        self.inner.stage_flags
+    }
+
+    pub(crate) fn binding_types(&self) -> &WebGpuBindingTypes {
+        &self.inner.binding_types
+    }
+}
@@ -0,0 +1,81 @@
+use crate::webgpu::{reflect_binding_types, RafxDeviceContextWebGpu, WebGpuBindingTypes};
+use crate::{RafxResult, RafxShaderModule, RafxShaderModuleDefWebGpu};
+use std::sync::Arc;
+
+pub struct RafxShaderModuleWebGpuInner {
+    device_context: RafxDeviceContextWebGpu,
+    shader_module: wgpu::ShaderModule,
+    binding_types: WebGpuBindingTypes,
+}
+
+impl std::fmt::Debug for RafxShaderModuleWebGpuInner {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        f.debug_struct("RafxShaderModuleWebGpuInner")
+            .field("device_context", &self.device_context)
+            .finish()
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct RafxShaderModuleWebGpu {
+    inner: Arc<RafxShaderModuleWebGpuInner>,
+}
+
+impl RafxShaderModuleWebGpu {
+    pub fn webgpu_shader_module(&self) -> &wgpu::ShaderModule {
+        &self.inner.shader_module
+    }
+
+    pub(crate) fn binding_types(&self) -> &WebGpuBindingTypes {
+        &self.inner.binding_types
+    }
+
+    pub fn new(
+        device_context: &RafxDeviceContextWebGpu,
+        data: RafxShaderModuleDefWebGpu,
+    ) -> RafxResult<Self> {
+        match data {
+            RafxShaderModuleDefWebGpu::WgslSrc(src) => {
+                RafxShaderModuleWebGpu::new_from_src(device_context, src)
+            }
+        }
+    }
+
+    pub fn new_from_src(
+        device_context: &RafxDeviceContextWebGpu,
+        src: &str,
+    ) -> RafxResult<Self> {
+        // wgpu parses the source too, but doesn't expose the result. We need the types of textures
+        // and samplers to build bind group layouts.
+        let module = naga::front::wgsl::parse_str(src)
+            .map_err(|e| format!("Could not parse WGSL: {}", e.emit_to_string(src)))?;
+        let binding_types = reflect_binding_types(&module)?;
+
+        let shader_module =
+            device_context
+                .device()
+                .create_shader_module(wgpu::ShaderModuleDescriptor {
+                    label: None,
+                    source: wgpu::ShaderSource::Wgsl(src.into()),
+                });
+
+        let inner = RafxShaderModuleWebGpuInner {
+            device_context: device_context.clone(),
+            shader_module,
+            binding_types,
+        };
+
+        Ok(RafxShaderModuleWebGpu {
+            inner: Arc::new(inner),
+        })
+    }
+}
+
+impl From<RafxShaderModuleWebGpu> for RafxShaderModule {
+    fn from(shader_module: RafxShaderModuleWebGpu) -> Self {
+        RafxShaderModule::WebGpu(shader_module)
+    }
+}
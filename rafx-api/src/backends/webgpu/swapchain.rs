@@ -0,0 +1,251 @@
+use crate::webgpu::{
+    RafxDeviceContextWebGpu, RafxFenceWebGpu, RafxSemaphoreWebGpu, RafxTextureWebGpu,
+    WebGpuWindowHandles,
+};
+use crate::{
+    RafxExtents3D, RafxFormat, RafxPresentSuccessResult, RafxResourceType, RafxResult,
+    RafxSampleCount, RafxSwapchainColorSpace, RafxSwapchainDef, RafxSwapchainDisplayLuminance,
+    RafxSwapchainImage, RafxTexture, RafxTextureDef, RafxTextureDimensions,
+};
+use raw_window_handle::{HasRawDisplayHandle, HasRawWindowHandle};
+
+const SWAPCHAIN_IMAGE_COUNT: u32 = 3;
+
+// A wgpu::SurfaceTexture can't be held across frames and must be presented by the same object
+// that acquired it, so rendering goes to an offscreen image that is copied to the surface on
+// present, similar to the GL backends.
+pub struct RafxSwapchainWebGpu {
+    device_context: RafxDeviceContextWebGpu,
+    surface: wgpu::Surface,
+    surface_configuration: wgpu::SurfaceConfiguration,
+    swapchain_def: RafxSwapchainDef,
+    format: RafxFormat,
+    // Just fake this
+    next_swapchain_image_index: u32,
+    swapchain_image: RafxTextureWebGpu,
+}
+
+impl RafxSwapchainWebGpu {
+    pub fn swapchain_def(&self) -> &RafxSwapchainDef {
+        &self.swapchain_def
+    }
+
+    pub fn image_count(&self) -> usize {
+        SWAPCHAIN_IMAGE_COUNT as usize
+    }
+
+    pub fn format(&self) -> RafxFormat {
+        self.format
+    }
+
+    pub fn color_space(&self) -> RafxSwapchainColorSpace {
+        // Only SRGB supported
+        RafxSwapchainColorSpace::Srgb
+    }
+
+    pub fn display_luminance(&self) -> Option<RafxSwapchainDisplayLuminance> {
+        // Not exposed by this API
+        None
+    }
+
+    pub fn webgpu_surface(&self) -> &wgpu::Surface {
+        &self.surface
+    }
+
+    pub fn new(
+        device_context: &RafxDeviceContextWebGpu,
+        raw_display_handle: &dyn HasRawDisplayHandle,
+        raw_window_handle: &dyn HasRawWindowHandle,
+        swapchain_def: &RafxSwapchainDef,
+    ) -> RafxResult<RafxSwapchainWebGpu> {
+        if !swapchain_def
+            .color_space_priority
+            .contains(&RafxSwapchainColorSpace::Srgb)
+        {
+            unimplemented!("WebGPU backend only supports sRGB Non-Linear color space");
+        }
+
+        let window_handles = WebGpuWindowHandles::new(raw_display_handle, raw_window_handle);
+        let surface = unsafe {
+            device_context
+                .instance()
+                .create_surface(&window_handles)
+                .map_err(|e| format!("Could not create surface: {:?}", e))?
+        };
+
+        let capabilities = surface.get_capabilities(device_context.adapter());
+
+        // The swapchain image is always an sRGB format. The surface may use either the sRGB or
+        // linear variant since copies may change the sRGB-ness of a format.
+        let (surface_format, format) = capabilities
+            .formats
+            .iter()
+            .find_map(|&surface_format| match surface_format {
+                wgpu::TextureFormat::Bgra8Unorm | wgpu::TextureFormat::Bgra8UnormSrgb => {
+                    Some((surface_format, RafxFormat::B8G8R8A8_SRGB))
+                }
+                wgpu::TextureFormat::Rgba8Unorm | wgpu::TextureFormat::Rgba8UnormSrgb => {
+                    Some((surface_format, RafxFormat::R8G8B8A8_SRGB))
+                }
+                _ => None,
+            })
+            .ok_or_else(|| {
+                format!(
+                    "Surface does not support an 8-bit RGBA/BGRA format. Supported formats: {:?}",
+                    capabilities.formats
+                )
+            })?;
+
+        let present_mode = if swapchain_def.enable_vsync {
+            wgpu::PresentMode::AutoVsync
+        } else {
+            wgpu::PresentMode::AutoNoVsync
+        };
+
+        let surface_configuration = wgpu::SurfaceConfiguration {
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::COPY_DST,
+            format: surface_format,
+            width: swapchain_def.width,
+            height: swapchain_def.height,
+            present_mode,
+            alpha_mode: capabilities.alpha_modes[0],
+            view_formats: vec![],
+        };
+        surface.configure(device_context.device(), &surface_configuration);
+
+        let swapchain_image = Self::create_swapchain_image(device_context, swapchain_def, format)?;
+
+        Ok(RafxSwapchainWebGpu {
+            device_context: device_context.clone(),
+            surface,
+            surface_configuration,
+            swapchain_def: swapchain_def.clone(),
+            next_swapchain_image_index: 0,
+            format,
+            swapchain_image,
+        })
+    }
+
+    fn create_swapchain_image(
+        device_context: &RafxDeviceContextWebGpu,
+        swapchain_def: &RafxSwapchainDef,
+        format: RafxFormat,
+    ) -> RafxResult<RafxTextureWebGpu> {
+        RafxTextureWebGpu::new(
+            device_context,
+            &RafxTextureDef {
+                extents: RafxExtents3D {
+                    width: swapchain_def.width,
+                    height: swapchain_def.height,
+                    depth: 1,
+                },
+                array_length: 1,
+                mip_count: 1,
+                format,
+                resource_type: RafxResourceType::TEXTURE | RafxResourceType::RENDER_TARGET_COLOR,
+                sample_count: RafxSampleCount::SampleCount1,
+                dimensions: RafxTextureDimensions::Dim2D,
+            },
+        )
+    }
+
+    pub fn rebuild(
+        &mut self,
+        swapchain_def: &RafxSwapchainDef,
+    ) -> RafxResult<()> {
+        self.swapchain_def = swapchain_def.clone();
+        self.surface_configuration.width = swapchain_def.width;
+        self.surface_configuration.height = swapchain_def.height;
+        self.surface_configuration.present_mode = if swapchain_def.enable_vsync {
+            wgpu::PresentMode::AutoVsync
+        } else {
+            wgpu::PresentMode::AutoNoVsync
+        };
+        self.surface
+            .configure(self.device_context.device(), &self.surface_configuration);
+
+        self.swapchain_image =
+            Self::create_swapchain_image(&self.device_context, swapchain_def, self.format)?;
+        Ok(())
+    }
+
+    pub fn acquire_next_image_fence(
+        &mut self,
+        fence: &RafxFenceWebGpu,
+    ) -> RafxResult<RafxSwapchainImage> {
+        fence.signal_immediately();
+        self.acquire_next_image()
+    }
+
+    pub fn acquire_next_image_semaphore(
+        &mut self,
+        semaphore: &RafxSemaphoreWebGpu,
+    ) -> RafxResult<RafxSwapchainImage> {
+        semaphore.set_signal_available(true);
+        self.acquire_next_image()
+    }
+
+    pub fn acquire_next_image(&mut self) -> RafxResult<RafxSwapchainImage> {
+        let swapchain_image_index = self.next_swapchain_image_index;
+        self.next_swapchain_image_index += 1;
+        if self.next_swapchain_image_index >= SWAPCHAIN_IMAGE_COUNT {
+            self.next_swapchain_image_index = 0;
+        }
+
+        Ok(RafxSwapchainImage {
+            texture: RafxTexture::WebGpu(self.swapchain_image.clone()),
+            swapchain_image_index,
+        })
+    }
+
+    // Copies the swapchain image to the surface and presents it
+    pub(crate) fn present(&self) -> RafxResult<RafxPresentSuccessResult> {
+        let surface_texture = match self.surface.get_current_texture() {
+            Ok(surface_texture) => surface_texture,
+            Err(wgpu::SurfaceError::Outdated) | Err(wgpu::SurfaceError::Lost) => {
+                // The caller is expected to rebuild the swapchain, which reconfigures the surface
+                return Ok(RafxPresentSuccessResult::DeviceReset);
+            }
+            Err(wgpu::SurfaceError::Timeout) => {
+                log::warn!("Timed out acquiring surface texture, skipping present");
+                return Ok(RafxPresentSuccessResult::SuccessSuboptimal);
+            }
+            Err(e) => Err(format!("Could not acquire surface texture: {:?}", e))?,
+        };
+
+        let device_context = &self.device_context;
+        let mut encoder =
+            device_context
+                .device()
+                .create_command_encoder(&wgpu::CommandEncoderDescriptor {
+                    label: Some("rafx present"),
+                });
+
+        encoder.copy_texture_to_texture(
+            self.swapchain_image.webgpu_texture().as_image_copy(),
+            surface_texture.texture.as_image_copy(),
+            wgpu::Extent3d {
+                width: self
+                    .swapchain_def
+                    .width
+                    .min(self.surface_configuration.width),
+                height: self
+                    .swapchain_def
+                    .height
+                    .min(self.surface_configuration.height),
+                depth_or_array_layers: 1,
+            },
+        );
+
+        device_context.queue().submit(Some(encoder.finish()));
+
+        let suboptimal = surface_texture.suboptimal;
+        surface_texture.present();
+
+        if suboptimal {
+            Ok(RafxPresentSuccessResult::SuccessSuboptimal)
+        } else {
+            Ok(RafxPresentSuccessResult::Success)
+        }
+    }
+}
@@ -0,0 +1,239 @@
+use crate::webgpu::RafxDeviceContextWebGpu;
+use crate::{RafxResourceType, RafxResult, RafxSampleCount, RafxTextureDef, RafxTextureDimensions};
+use fnv::FnvHashMap;
+use std::hash::{Hash, Hasher};
+use std::sync::atomic::Ordering;
+use std::sync::{Arc, Mutex};
+
+// Views are created on demand (descriptor updates, render target bindings) and cached
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub(crate) struct WebGpuTextureViewKey {
+    pub(crate) dimension: Option<wgpu::TextureViewDimension>,
+    pub(crate) aspect: wgpu::TextureAspect,
+    pub(crate) base_mip_level: u32,
+    pub(crate) mip_level_count: Option<u32>,
+    pub(crate) base_array_layer: u32,
+    pub(crate) array_layer_count: Option<u32>,
+}
+
+#[derive(Debug)]
+pub struct RafxTextureWebGpuInner {
+    device_context: RafxDeviceContextWebGpu,
+    texture_def: RafxTextureDef,
+    texture: Arc<wgpu::Texture>,
+    format: wgpu::TextureFormat,
+    default_view_dimension: wgpu::TextureViewDimension,
+    texture_id: u32,
+    views: Mutex<FnvHashMap<WebGpuTextureViewKey, Arc<wgpu::TextureView>>>,
+}
+
+impl Drop for RafxTextureWebGpuInner {
+    fn drop(&mut self) {
+        self.texture.destroy();
+    }
+}
+
+/// Holds the wgpu::Texture and a cache of views that have been requested for it
+#[derive(Clone, Debug)]
+pub struct RafxTextureWebGpu {
+    inner: Arc<RafxTextureWebGpuInner>,
+}
+
+impl PartialEq for RafxTextureWebGpu {
+    fn eq(
+        &self,
+        other: &Self,
+    ) -> bool {
+        self.inner.texture_id == other.inner.texture_id
+    }
+}
+
+impl Eq for RafxTextureWebGpu {}
+
+impl Hash for RafxTextureWebGpu {
+    fn hash<H: Hasher>(
+        &self,
+        state: &mut H,
+    ) {
+        self.inner.texture_id.hash(state);
+    }
+}
+
+impl RafxTextureWebGpu {
+    pub fn texture_def(&self) -> &RafxTextureDef {
+        &self.inner.texture_def
+    }
+
+    pub fn device_context(&self) -> &RafxDeviceContextWebGpu {
+        &self.inner.device_context
+    }
+
+    pub fn webgpu_texture(&self) -> &wgpu::Texture {
+        &self.inner.texture
+    }
+
+    pub(crate) fn webgpu_texture_arc(&self) -> &Arc<wgpu::Texture> {
+        &self.inner.texture
+    }
+
+    pub fn webgpu_format(&self) -> wgpu::TextureFormat {
+        self.inner.format
+    }
+
+    pub(crate) fn default_view_dimension(&self) -> wgpu::TextureViewDimension {
+        self.inner.default_view_dimension
+    }
+
+    pub(crate) fn view(
+        &self,
+        key: WebGpuTextureViewKey,
+    ) -> Arc<wgpu::TextureView> {
+        let mut views = self.inner.views.lock().unwrap();
+        views
+            .entry(key)
+            .or_insert_with(|| {
+                Arc::new(
+                    self.inner
+                        .texture
+                        .create_view(&wgpu::TextureViewDescriptor {
+                            label: None,
+                            format: None,
+                            dimension: key.dimension,
+                            aspect: key.aspect,
+                            base_mip_level: key.base_mip_level,
+                            mip_level_count: key.mip_level_count,
+                            base_array_layer: key.base_array_layer,
+                            array_layer_count: key.array_layer_count,
+                        }),
+                )
+            })
+            .clone()
+    }
+
+    // Used for binding render targets, always a single 2D slice of a single mip
+    pub(crate) fn render_target_view(
+        &self,
+        mip_slice: Option<u8>,
+        array_slice: Option<u16>,
+    ) -> Arc<wgpu::TextureView> {
+        let dimension = if self.inner.default_view_dimension == wgpu::TextureViewDimension::D3 {
+            wgpu::TextureViewDimension::D3
+        } else {
+            wgpu::TextureViewDimension::D2
+        };
+
+        self.view(WebGpuTextureViewKey {
+            dimension: Some(dimension),
+            aspect: wgpu::TextureAspect::All,
+            base_mip_level: mip_slice.unwrap_or(0) as u32,
+            mip_level_count: Some(1),
+            base_array_layer: array_slice.unwrap_or(0) as u32,
+            array_layer_count: Some(1),
+        })
+    }
+
+    pub fn new(
+        device_context: &RafxDeviceContextWebGpu,
+        texture_def: &RafxTextureDef,
+    ) -> RafxResult<RafxTextureWebGpu> {
+        texture_def.verify();
+
+        let format = texture_def
+            .format
+            .webgpu_texture_format()
+            .ok_or_else(|| format!("Format {:?} not supported", texture_def.format))?;
+
+        let dimensions = texture_def
+            .dimensions
+            .determine_dimensions(texture_def.extents);
+
+        let (dimension, depth_or_array_layers) = match dimensions {
+            RafxTextureDimensions::Dim1D => (wgpu::TextureDimension::D1, texture_def.array_length),
+            RafxTextureDimensions::Dim2D => (wgpu::TextureDimension::D2, texture_def.array_length),
+            RafxTextureDimensions::Dim3D => (wgpu::TextureDimension::D3, texture_def.extents.depth),
+            RafxTextureDimensions::Auto => unreachable!(),
+        };
+
+        let is_cube = texture_def
+            .resource_type
+            .contains(RafxResourceType::TEXTURE_CUBE);
+
+        let default_view_dimension = match dimensions {
+            RafxTextureDimensions::Dim1D => wgpu::TextureViewDimension::D1,
+            RafxTextureDimensions::Dim3D => wgpu::TextureViewDimension::D3,
+            _ if is_cube && texture_def.array_length > 6 => wgpu::TextureViewDimension::CubeArray,
+            _ if is_cube => wgpu::TextureViewDimension::Cube,
+            _ if texture_def.array_length > 1 => wgpu::TextureViewDimension::D2Array,
+            _ => wgpu::TextureViewDimension::D2,
+        };
+
+        let mut usage = wgpu::TextureUsages::empty();
+        if texture_def
+            .resource_type
+            .intersects(RafxResourceType::TEXTURE)
+        {
+            usage |= wgpu::TextureUsages::TEXTURE_BINDING;
+        }
+        if texture_def
+            .resource_type
+            .intersects(RafxResourceType::TEXTURE_READ_WRITE)
+        {
+            usage |= wgpu::TextureUsages::STORAGE_BINDING;
+        }
+        if texture_def.resource_type.intersects(
+            RafxResourceType::RENDER_TARGET_COLOR | RafxResourceType::RENDER_TARGET_DEPTH_STENCIL,
+        ) {
+            usage |= wgpu::TextureUsages::RENDER_ATTACHMENT;
+        }
+
+        let format_features = device_context.adapter().get_texture_format_features(format);
+
+        // Multisampled textures can't be copied
+        if texture_def.sample_count == RafxSampleCount::SampleCount1 {
+            usage |= wgpu::TextureUsages::COPY_SRC | wgpu::TextureUsages::COPY_DST;
+
+            // Mipmaps are generated by rendering into each mip level, so allow binding them as
+            // render targets if the format supports it
+            if texture_def.mip_count > 1
+                && format_features
+                    .allowed_usages
+                    .contains(wgpu::TextureUsages::RENDER_ATTACHMENT)
+            {
+                usage |= wgpu::TextureUsages::RENDER_ATTACHMENT;
+            }
+        }
+
+        let texture = device_context
+            .device()
+            .create_texture(&wgpu::TextureDescriptor {
+                label: None,
+                size: wgpu::Extent3d {
+                    width: texture_def.extents.width,
+                    height: texture_def.extents.height,
+                    depth_or_array_layers,
+                },
+                mip_level_count: texture_def.mip_count,
+                sample_count: texture_def.sample_count.as_u32(),
+                dimension,
+                format,
+                usage,
+                view_formats: &[],
+            });
+
+        let texture_id = crate::internal_shared::NEXT_TEXTURE_ID.fetch_add(1, Ordering::Relaxed);
+
+        let inner = RafxTextureWebGpuInner {
+            device_context: device_context.clone(),
+            texture_def: texture_def.clone(),
+            texture: Arc::new(texture),
+            format,
+            default_view_dimension,
+            texture_id,
+            views: Default::default(),
+        };
+
+        Ok(RafxTextureWebGpu {
+            inner: Arc::new(inner),
+        })
+    }
+}
@@ -7,7 +7,8 @@ use crate::dx12::RafxBufferDx12;
         feature = "rafx-metal",
         feature = "rafx-vulkan",
         feature = "rafx-gles2",
-        feature = "rafx-gles3"
+        feature = "rafx-gles3",
+        feature = "rafx-webgpu"
     ))
 ))]
 use crate::empty::RafxBufferEmpty;
@@ -19,6 +20,8 @@ use crate::gles3::RafxBufferGles3;
 use crate::metal::RafxBufferMetal;
 #[cfg(feature = "rafx-vulkan")]
 use crate::vulkan::RafxBufferVulkan;
+#[cfg(feature = "rafx-webgpu")]
+use crate::webgpu::RafxBufferWebGpu;
 use crate::{RafxBufferDef, RafxResult};
 
 /// Memory that can be accessed by the rendering API. It may reside in CPU or GPU memory.
@@ -36,6 +39,8 @@ pub enum RafxBuffer {
     Gles2(RafxBufferGles2),
     #[cfg(feature = "rafx-gles3")]
     Gles3(RafxBufferGles3),
+    #[cfg(feature = "rafx-webgpu")]
+    WebGpu(RafxBufferWebGpu),
     #[cfg(any(
         feature = "rafx-empty",
         not(any(
@@ -43,7 +48,8 @@ pub enum RafxBuffer {
             feature = "rafx-metal",
             feature = "rafx-vulkan",
             feature = "rafx-gles2",
-            feature = "rafx-gles3"
+            feature = "rafx-gles3",
+            feature = "rafx-webgpu"
         ))
     ))]
     Empty(RafxBufferEmpty),
@@ -68,6 +74,8 @@ impl RafxBuffer {
             RafxBuffer::Gles2(inner) => inner.copy_to_host_visible_buffer(data),
             #[cfg(feature = "rafx-gles3")]
             RafxBuffer::Gles3(inner) => inner.copy_to_host_visible_buffer(data),
+            #[cfg(feature = "rafx-webgpu")]
+            RafxBuffer::WebGpu(inner) => inner.copy_to_host_visible_buffer(data),
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -75,7 +83,8 @@ impl RafxBuffer {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxBuffer::Empty(inner) => inner.copy_to_host_visible_buffer(data),
@@ -110,6 +119,10 @@ impl RafxBuffer {
             RafxBuffer::Gles3(inner) => {
                 inner.copy_to_host_visible_buffer_with_offset(data, buffer_byte_offset)
             }
+            #[cfg(feature = "rafx-webgpu")]
+            RafxBuffer::WebGpu(inner) => {
+                inner.copy_to_host_visible_buffer_with_offset(data, buffer_byte_offset)
+            }
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -117,7 +130,8 @@ impl RafxBuffer {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxBuffer::Empty(inner) => {
@@ -139,6 +153,8 @@ impl RafxBuffer {
             RafxBuffer::Gles2(inner) => inner.buffer_def(),
             #[cfg(feature = "rafx-gles3")]
             RafxBuffer::Gles3(inner) => inner.buffer_def(),
+            #[cfg(feature = "rafx-webgpu")]
+            RafxBuffer::WebGpu(inner) => inner.buffer_def(),
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -146,7 +162,8 @@ impl RafxBuffer {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxBuffer::Empty(inner) => inner.buffer_def(),
@@ -172,6 +189,8 @@ impl RafxBuffer {
             RafxBuffer::Gles2(inner) => inner.map_buffer(),
             #[cfg(feature = "rafx-gles3")]
             RafxBuffer::Gles3(inner) => inner.map_buffer(),
+            #[cfg(feature = "rafx-webgpu")]
+            RafxBuffer::WebGpu(inner) => inner.map_buffer(),
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -179,7 +198,8 @@ impl RafxBuffer {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxBuffer::Empty(inner) => inner.map_buffer(),
@@ -204,6 +224,8 @@ impl RafxBuffer {
             RafxBuffer::Gles2(inner) => inner.unmap_buffer(),
             #[cfg(feature = "rafx-gles3")]
             RafxBuffer::Gles3(inner) => inner.unmap_buffer(),
+            #[cfg(feature = "rafx-webgpu")]
+            RafxBuffer::WebGpu(inner) => inner.unmap_buffer(),
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -211,7 +233,8 @@ impl RafxBuffer {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxBuffer::Empty(inner) => inner.unmap_buffer(),
@@ -231,6 +254,8 @@ impl RafxBuffer {
             RafxBuffer::Gles2(inner) => inner.mapped_memory(),
             #[cfg(feature = "rafx-gles3")]
             RafxBuffer::Gles3(inner) => inner.mapped_memory(),
+            #[cfg(feature = "rafx-webgpu")]
+            RafxBuffer::WebGpu(inner) => inner.mapped_memory(),
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -238,7 +263,8 @@ impl RafxBuffer {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxBuffer::Empty(inner) => inner.mapped_memory(),
@@ -262,6 +288,8 @@ impl RafxBuffer {
             RafxBuffer::Gles2(_) => {}
             #[cfg(feature = "rafx-gles3")]
             RafxBuffer::Gles3(_) => {}
+            #[cfg(feature = "rafx-webgpu")]
+            RafxBuffer::WebGpu(_) => {}
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -269,7 +297,8 @@ impl RafxBuffer {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxBuffer::Empty(inner) => inner.set_debug_name(_name),
@@ -291,6 +320,8 @@ impl RafxBuffer {
             RafxBuffer::Gles2(_) => None,
             #[cfg(feature = "rafx-gles3")]
             RafxBuffer::Gles3(_) => None,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxBuffer::WebGpu(_) => None,
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -298,7 +329,8 @@ impl RafxBuffer {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxBuffer::Empty(_) => None,
@@ -320,6 +352,8 @@ impl RafxBuffer {
             RafxBuffer::Gles2(_) => None,
             #[cfg(feature = "rafx-gles3")]
             RafxBuffer::Gles3(_) => None,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxBuffer::WebGpu(_) => None,
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -327,7 +361,8 @@ impl RafxBuffer {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxBuffer::Empty(_) => None,
@@ -349,6 +384,8 @@ impl RafxBuffer {
             RafxBuffer::Gles2(_) => None,
             #[cfg(feature = "rafx-gles3")]
             RafxBuffer::Gles3(_) => None,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxBuffer::WebGpu(_) => None,
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -356,7 +393,8 @@ impl RafxBuffer {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxBuffer::Empty(_) => None,
@@ -378,6 +416,8 @@ impl RafxBuffer {
             RafxBuffer::Gles2(inner) => Some(inner),
             #[cfg(feature = "rafx-gles3")]
             RafxBuffer::Gles3(_) => None,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxBuffer::WebGpu(_) => None,
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -385,7 +425,8 @@ impl RafxBuffer {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxBuffer::Empty(_) => None,
@@ -407,6 +448,40 @@ impl RafxBuffer {
             RafxBuffer::Gles2(_) => None,
             #[cfg(feature = "rafx-gles3")]
             RafxBuffer::Gles3(inner) => Some(inner),
+            #[cfg(feature = "rafx-webgpu")]
+            RafxBuffer::WebGpu(_) => None,
+            #[cfg(any(
+                feature = "rafx-empty",
+                not(any(
+                    feature = "rafx-dx12",
+                    feature = "rafx-metal",
+                    feature = "rafx-vulkan",
+                    feature = "rafx-gles2",
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
+                ))
+            ))]
+            RafxBuffer::Empty(_) => None,
+        }
+    }
+
+    /// Get the underlying webgpu API object. This provides access to any internally created
+    /// wgpu objects.
+    #[cfg(feature = "rafx-webgpu")]
+    pub fn webgpu_buffer(&self) -> Option<&RafxBufferWebGpu> {
+        match self {
+            #[cfg(feature = "rafx-dx12")]
+            RafxBuffer::Dx12(_) => None,
+            #[cfg(feature = "rafx-vulkan")]
+            RafxBuffer::Vk(_) => None,
+            #[cfg(feature = "rafx-metal")]
+            RafxBuffer::Metal(_) => None,
+            #[cfg(feature = "rafx-gles2")]
+            RafxBuffer::Gles2(_) => None,
+            #[cfg(feature = "rafx-gles3")]
+            RafxBuffer::Gles3(_) => None,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxBuffer::WebGpu(inner) => Some(inner),
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -414,7 +489,8 @@ impl RafxBuffer {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxBuffer::Empty(_) => None,
@@ -430,7 +506,8 @@ impl RafxBuffer {
             feature = "rafx-metal",
             feature = "rafx-vulkan",
             feature = "rafx-gles2",
-            feature = "rafx-gles3"
+            feature = "rafx-gles3",
+            feature = "rafx-webgpu"
         ))
     ))]
     pub fn empty_buffer(&self) -> Option<&RafxBufferEmpty> {
@@ -445,6 +522,8 @@ impl RafxBuffer {
             RafxBuffer::Gles2(_) => None,
             #[cfg(feature = "rafx-gles3")]
             RafxBuffer::Gles3(_) => None,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxBuffer::WebGpu(_) => None,
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -452,7 +531,8 @@ impl RafxBuffer {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxBuffer::Empty(inner) => Some(inner),
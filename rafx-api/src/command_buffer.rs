@@ -7,7 +7,8 @@ use crate::dx12::RafxCommandBufferDx12;
         feature = "rafx-metal",
         feature = "rafx-vulkan",
         feature = "rafx-gles2",
-        feature = "rafx-gles3"
+        feature = "rafx-gles3",
+        feature = "rafx-webgpu"
     ))
 ))]
 use crate::empty::RafxCommandBufferEmpty;
@@ -19,6 +20,8 @@ use crate::gles3::RafxCommandBufferGles3;
 use crate::metal::RafxCommandBufferMetal;
 #[cfg(feature = "rafx-vulkan")]
 use crate::vulkan::RafxCommandBufferVulkan;
+#[cfg(feature = "rafx-webgpu")]
+use crate::webgpu::RafxCommandBufferWebGpu;
 use crate::{
     RafxBuffer, RafxBufferBarrier, RafxCmdCopyBufferToBufferParams,
     RafxCmdCopyBufferToTextureParams, RafxCmdCopyTextureToTextureParams,
@@ -64,6 +67,8 @@ pub enum RafxCommandBuffer {
 
     #[cfg(feature = "rafx-gles3")]
     Gles3(RafxCommandBufferGles3),
+    #[cfg(feature = "rafx-webgpu")]
+    WebGpu(RafxCommandBufferWebGpu),
 
     #[cfg(any(
         feature = "rafx-empty",
@@ -72,7 +77,8 @@ pub enum RafxCommandBuffer {
             feature = "rafx-metal",
             feature = "rafx-vulkan",
             feature = "rafx-gles2",
-            feature = "rafx-gles3"
+            feature = "rafx-gles3",
+            feature = "rafx-webgpu"
         ))
     ))]
     Empty(RafxCommandBufferEmpty),
@@ -93,6 +99,8 @@ impl RafxCommandBuffer {
             RafxCommandBuffer::Gles2(inner) => inner.begin(),
             #[cfg(feature = "rafx-gles3")]
             RafxCommandBuffer::Gles3(inner) => inner.begin(),
+            #[cfg(feature = "rafx-webgpu")]
+            RafxCommandBuffer::WebGpu(inner) => inner.begin(),
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -100,7 +108,8 @@ impl RafxCommandBuffer {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxCommandBuffer::Empty(inner) => inner.begin(),
@@ -121,6 +130,8 @@ impl RafxCommandBuffer {
             RafxCommandBuffer::Gles2(inner) => inner.end(),
             #[cfg(feature = "rafx-gles3")]
             RafxCommandBuffer::Gles3(inner) => inner.end(),
+            #[cfg(feature = "rafx-webgpu")]
+            RafxCommandBuffer::WebGpu(inner) => inner.end(),
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -128,7 +139,8 @@ impl RafxCommandBuffer {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxCommandBuffer::Empty(inner) => inner.end(),
@@ -153,6 +165,8 @@ impl RafxCommandBuffer {
             RafxCommandBuffer::Gles2(inner) => inner.return_to_pool(),
             #[cfg(feature = "rafx-gles3")]
             RafxCommandBuffer::Gles3(inner) => inner.return_to_pool(),
+            #[cfg(feature = "rafx-webgpu")]
+            RafxCommandBuffer::WebGpu(inner) => inner.return_to_pool(),
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -160,7 +174,8 @@ impl RafxCommandBuffer {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxCommandBuffer::Empty(inner) => inner.return_to_pool(),
@@ -198,6 +213,10 @@ impl RafxCommandBuffer {
             RafxCommandBuffer::Gles3(inner) => {
                 inner.cmd_begin_render_pass(color_targets, depth_target)
             }
+            #[cfg(feature = "rafx-webgpu")]
+            RafxCommandBuffer::WebGpu(inner) => {
+                inner.cmd_begin_render_pass(color_targets, depth_target)
+            }
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -205,7 +224,8 @@ impl RafxCommandBuffer {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxCommandBuffer::Empty(inner) => {
@@ -227,6 +247,8 @@ impl RafxCommandBuffer {
             RafxCommandBuffer::Gles2(inner) => inner.cmd_end_render_pass(),
             #[cfg(feature = "rafx-gles3")]
             RafxCommandBuffer::Gles3(inner) => inner.cmd_end_render_pass(),
+            #[cfg(feature = "rafx-webgpu")]
+            RafxCommandBuffer::WebGpu(inner) => inner.cmd_end_render_pass(),
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -234,7 +256,8 @@ impl RafxCommandBuffer {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxCommandBuffer::Empty(inner) => inner.cmd_end_render_pass(),
@@ -274,6 +297,10 @@ impl RafxCommandBuffer {
             RafxCommandBuffer::Gles3(inner) => {
                 inner.cmd_set_viewport(x, y, width, height, depth_min, depth_max)
             }
+            #[cfg(feature = "rafx-webgpu")]
+            RafxCommandBuffer::WebGpu(inner) => {
+                inner.cmd_set_viewport(x, y, width, height, depth_min, depth_max)
+            }
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -281,7 +308,8 @@ impl RafxCommandBuffer {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxCommandBuffer::Empty(inner) => {
@@ -311,6 +339,8 @@ impl RafxCommandBuffer {
             RafxCommandBuffer::Gles2(inner) => inner.cmd_set_scissor(x, y, width, height),
             #[cfg(feature = "rafx-gles3")]
             RafxCommandBuffer::Gles3(inner) => inner.cmd_set_scissor(x, y, width, height),
+            #[cfg(feature = "rafx-webgpu")]
+            RafxCommandBuffer::WebGpu(inner) => inner.cmd_set_scissor(x, y, width, height),
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -318,7 +348,8 @@ impl RafxCommandBuffer {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxCommandBuffer::Empty(inner) => inner.cmd_set_scissor(x, y, width, height),
@@ -344,6 +375,8 @@ impl RafxCommandBuffer {
             RafxCommandBuffer::Gles2(inner) => inner.cmd_set_stencil_reference_value(value),
             #[cfg(feature = "rafx-gles3")]
             RafxCommandBuffer::Gles3(inner) => inner.cmd_set_stencil_reference_value(value),
+            #[cfg(feature = "rafx-webgpu")]
+            RafxCommandBuffer::WebGpu(inner) => inner.cmd_set_stencil_reference_value(value),
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -351,7 +384,8 @@ impl RafxCommandBuffer {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxCommandBuffer::Empty(inner) => inner.cmd_set_stencil_reference_value(value),
@@ -385,6 +419,10 @@ impl RafxCommandBuffer {
             RafxCommandBuffer::Gles3(inner) => {
                 inner.cmd_bind_pipeline(pipeline.gles3_pipeline().unwrap())
             }
+            #[cfg(feature = "rafx-webgpu")]
+            RafxCommandBuffer::WebGpu(inner) => {
+                inner.cmd_bind_pipeline(pipeline.webgpu_pipeline().unwrap())
+            }
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -392,7 +430,8 @@ impl RafxCommandBuffer {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxCommandBuffer::Empty(inner) => {
@@ -429,6 +468,10 @@ impl RafxCommandBuffer {
             RafxCommandBuffer::Gles3(inner) => {
                 inner.cmd_bind_vertex_buffers(first_binding, bindings)
             }
+            #[cfg(feature = "rafx-webgpu")]
+            RafxCommandBuffer::WebGpu(inner) => {
+                inner.cmd_bind_vertex_buffers(first_binding, bindings)
+            }
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -436,7 +479,8 @@ impl RafxCommandBuffer {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxCommandBuffer::Empty(inner) => {
@@ -464,6 +508,8 @@ impl RafxCommandBuffer {
             RafxCommandBuffer::Gles2(inner) => inner.cmd_bind_index_buffer(binding),
             #[cfg(feature = "rafx-gles3")]
             RafxCommandBuffer::Gles3(inner) => inner.cmd_bind_index_buffer(binding),
+            #[cfg(feature = "rafx-webgpu")]
+            RafxCommandBuffer::WebGpu(inner) => inner.cmd_bind_index_buffer(binding),
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -471,7 +517,8 @@ impl RafxCommandBuffer {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxCommandBuffer::Empty(inner) => inner.cmd_bind_index_buffer(binding),
@@ -512,6 +559,11 @@ impl RafxCommandBuffer {
                 descriptor_set_array.gles3_descriptor_set_array().unwrap(),
                 index,
             ),
+            #[cfg(feature = "rafx-webgpu")]
+            RafxCommandBuffer::WebGpu(inner) => inner.cmd_bind_descriptor_set(
+                descriptor_set_array.webgpu_descriptor_set_array().unwrap(),
+                index,
+            ),
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -519,7 +571,8 @@ impl RafxCommandBuffer {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxCommandBuffer::Empty(inner) => inner.cmd_bind_descriptor_set(
@@ -570,6 +623,14 @@ impl RafxCommandBuffer {
                 set_index,
                 descriptor_set_handle.gles3_descriptor_set_handle().unwrap(),
             ),
+            #[cfg(feature = "rafx-webgpu")]
+            RafxCommandBuffer::WebGpu(inner) => inner.cmd_bind_descriptor_set_handle(
+                root_signature.webgpu_root_signature().unwrap(),
+                set_index,
+                descriptor_set_handle
+                    .webgpu_descriptor_set_handle()
+                    .unwrap(),
+            ),
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -577,7 +638,8 @@ impl RafxCommandBuffer {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxCommandBuffer::Empty(inner) => inner.cmd_bind_descriptor_set_handle(
@@ -630,6 +692,12 @@ impl RafxCommandBuffer {
                 let _ = data;
                 unimplemented!()
             }
+            #[cfg(feature = "rafx-webgpu")]
+            RafxCommandBuffer::WebGpu(inner) => inner.cmd_bind_push_constant(
+                root_signature.webgpu_root_signature().unwrap(),
+                descriptor_index,
+                data,
+            ),
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -637,7 +705,8 @@ impl RafxCommandBuffer {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxCommandBuffer::Empty(inner) => inner.cmd_bind_push_constant(
@@ -665,6 +734,8 @@ impl RafxCommandBuffer {
             RafxCommandBuffer::Gles2(inner) => inner.cmd_draw(vertex_count, first_vertex),
             #[cfg(feature = "rafx-gles3")]
             RafxCommandBuffer::Gles3(inner) => inner.cmd_draw(vertex_count, first_vertex),
+            #[cfg(feature = "rafx-webgpu")]
+            RafxCommandBuffer::WebGpu(inner) => inner.cmd_draw(vertex_count, first_vertex),
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -672,7 +743,8 @@ impl RafxCommandBuffer {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxCommandBuffer::Empty(inner) => inner.cmd_draw(vertex_count, first_vertex),
@@ -708,6 +780,10 @@ impl RafxCommandBuffer {
             RafxCommandBuffer::Gles3(inner) => {
                 inner.cmd_draw_instanced(vertex_count, first_vertex, instance_count, first_instance)
             }
+            #[cfg(feature = "rafx-webgpu")]
+            RafxCommandBuffer::WebGpu(inner) => {
+                inner.cmd_draw_instanced(vertex_count, first_vertex, instance_count, first_instance)
+            }
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -715,7 +791,8 @@ impl RafxCommandBuffer {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxCommandBuffer::Empty(inner) => {
@@ -755,6 +832,10 @@ impl RafxCommandBuffer {
             RafxCommandBuffer::Gles3(inner) => {
                 inner.cmd_draw_indexed(index_count, first_index, vertex_offset)
             }
+            #[cfg(feature = "rafx-webgpu")]
+            RafxCommandBuffer::WebGpu(inner) => {
+                inner.cmd_draw_indexed(index_count, first_index, vertex_offset)
+            }
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -762,7 +843,8 @@ impl RafxCommandBuffer {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxCommandBuffer::Empty(inner) => {
@@ -821,6 +903,14 @@ impl RafxCommandBuffer {
                 first_instance,
                 vertex_offset,
             ),
+            #[cfg(feature = "rafx-webgpu")]
+            RafxCommandBuffer::WebGpu(inner) => inner.cmd_draw_indexed_instanced(
+                index_count,
+                first_index,
+                instance_count,
+                first_instance,
+                vertex_offset,
+            ),
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -828,7 +918,8 @@ impl RafxCommandBuffer {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxCommandBuffer::Empty(inner) => inner.cmd_draw_indexed_instanced(
@@ -880,6 +971,12 @@ impl RafxCommandBuffer {
                 let _ = draw_count;
                 unimplemented!()
             }
+            #[cfg(feature = "rafx-webgpu")]
+            RafxCommandBuffer::WebGpu(inner) => inner.cmd_draw_indirect(
+                indirect_buffer.webgpu_buffer().unwrap(),
+                indirect_buffer_offset_in_bytes,
+                draw_count,
+            ),
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -887,7 +984,8 @@ impl RafxCommandBuffer {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxCommandBuffer::Empty(inner) => inner.cmd_draw_indirect(
@@ -937,6 +1035,12 @@ impl RafxCommandBuffer {
                 let _ = draw_count;
                 unimplemented!()
             }
+            #[cfg(feature = "rafx-webgpu")]
+            RafxCommandBuffer::WebGpu(inner) => inner.cmd_draw_indexed_indirect(
+                indirect_buffer.webgpu_buffer().unwrap(),
+                indirect_buffer_offset_in_bytes,
+                draw_count,
+            ),
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -944,7 +1048,8 @@ impl RafxCommandBuffer {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxCommandBuffer::Empty(inner) => inner.cmd_draw_indexed_indirect(
@@ -991,6 +1096,13 @@ impl RafxCommandBuffer {
                 let _ = group_count_z;
                 unimplemented!()
             }
+            #[cfg(feature = "rafx-webgpu")]
+            RafxCommandBuffer::WebGpu(_) => {
+                let _ = group_count_x;
+                let _ = group_count_y;
+                let _ = group_count_z;
+                unimplemented!()
+            }
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -998,7 +1110,8 @@ impl RafxCommandBuffer {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxCommandBuffer::Empty(inner) => {
@@ -1035,6 +1148,10 @@ impl RafxCommandBuffer {
             RafxCommandBuffer::Gles3(inner) => {
                 inner.cmd_dispatch(group_count_x, group_count_y, group_count_z)
             }
+            #[cfg(feature = "rafx-webgpu")]
+            RafxCommandBuffer::WebGpu(inner) => {
+                inner.cmd_dispatch(group_count_x, group_count_y, group_count_z)
+            }
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -1042,7 +1159,8 @@ impl RafxCommandBuffer {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxCommandBuffer::Empty(inner) => {
@@ -1051,6 +1169,65 @@ impl RafxCommandBuffer {
         }
     }
 
+    /// Dispatch the current pipeline, reading the group counts from a RafxDispatchIndirectCommand
+    /// in the given buffer. Only usable with compute pipelines. The buffer must have been created
+    /// with RafxResourceType::INDIRECT_BUFFER.
+    pub fn cmd_dispatch_indirect(
+        &self,
+        indirect_buffer: &RafxBuffer,
+        indirect_buffer_offset_in_bytes: u32,
+    ) -> RafxResult<()> {
+        match self {
+            #[cfg(feature = "rafx-dx12")]
+            RafxCommandBuffer::Dx12(inner) => inner.cmd_dispatch_indirect(
+                indirect_buffer.dx12_buffer().unwrap(),
+                indirect_buffer_offset_in_bytes,
+            ),
+            #[cfg(feature = "rafx-vulkan")]
+            RafxCommandBuffer::Vk(inner) => inner.cmd_dispatch_indirect(
+                indirect_buffer.vk_buffer().unwrap(),
+                indirect_buffer_offset_in_bytes,
+            ),
+            #[cfg(feature = "rafx-metal")]
+            RafxCommandBuffer::Metal(inner) => inner.cmd_dispatch_indirect(
+                indirect_buffer.metal_buffer().unwrap(),
+                indirect_buffer_offset_in_bytes,
+            ),
+            #[cfg(feature = "rafx-gles2")]
+            RafxCommandBuffer::Gles2(_) => {
+                let _ = indirect_buffer;
+                let _ = indirect_buffer_offset_in_bytes;
+                unimplemented!()
+            }
+            #[cfg(feature = "rafx-gles3")]
+            RafxCommandBuffer::Gles3(_) => {
+                let _ = indirect_buffer;
+                let _ = indirect_buffer_offset_in_bytes;
+                unimplemented!()
+            }
+            #[cfg(feature = "rafx-webgpu")]
+            RafxCommandBuffer::WebGpu(inner) => inner.cmd_dispatch_indirect(
+                indirect_buffer.webgpu_buffer().unwrap(),
+                indirect_buffer_offset_in_bytes,
+            ),
+            #[cfg(any(
+                feature = "rafx-empty",
+                not(any(
+                    feature = "rafx-dx12",
+                    feature = "rafx-metal",
+                    feature = "rafx-vulkan",
+                    feature = "rafx-gles2",
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
+                ))
+            ))]
+            RafxCommandBuffer::Empty(inner) => inner.cmd_dispatch_indirect(
+                indirect_buffer.empty_buffer().unwrap(),
+                indirect_buffer_offset_in_bytes,
+            ),
+        }
+    }
+
     /// Add a memory barrier for one or more resources. This must occur OUTSIDE of a renderpass.
     pub fn cmd_resource_barrier(
         &self,
@@ -1078,6 +1255,10 @@ impl RafxCommandBuffer {
             RafxCommandBuffer::Gles3(inner) => {
                 inner.cmd_resource_barrier(buffer_barriers, texture_barriers)
             }
+            #[cfg(feature = "rafx-webgpu")]
+            RafxCommandBuffer::WebGpu(inner) => {
+                inner.cmd_resource_barrier(buffer_barriers, texture_barriers)
+            }
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -1085,7 +1266,8 @@ impl RafxCommandBuffer {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxCommandBuffer::Empty(inner) => {
@@ -1133,6 +1315,12 @@ impl RafxCommandBuffer {
                 dst_buffer.gles3_buffer().unwrap(),
                 params,
             ),
+            #[cfg(feature = "rafx-webgpu")]
+            RafxCommandBuffer::WebGpu(inner) => inner.cmd_copy_buffer_to_buffer(
+                src_buffer.webgpu_buffer().unwrap(),
+                dst_buffer.webgpu_buffer().unwrap(),
+                params,
+            ),
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -1140,7 +1328,8 @@ impl RafxCommandBuffer {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxCommandBuffer::Empty(inner) => inner.cmd_copy_buffer_to_buffer(
@@ -1190,6 +1379,12 @@ impl RafxCommandBuffer {
                 dst_texture.gles3_texture().unwrap(),
                 params,
             ),
+            #[cfg(feature = "rafx-webgpu")]
+            RafxCommandBuffer::WebGpu(inner) => inner.cmd_copy_buffer_to_texture(
+                src_buffer.webgpu_buffer().unwrap(),
+                dst_texture.webgpu_texture().unwrap(),
+                params,
+            ),
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -1197,7 +1392,8 @@ impl RafxCommandBuffer {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxCommandBuffer::Empty(inner) => inner.cmd_copy_buffer_to_texture(
@@ -1249,6 +1445,12 @@ impl RafxCommandBuffer {
                 let _ = params;
                 unimplemented!()
             }
+            #[cfg(feature = "rafx-webgpu")]
+            RafxCommandBuffer::WebGpu(inner) => inner.cmd_copy_texture_to_texture(
+                src_texture.webgpu_texture().unwrap(),
+                dst_texture.webgpu_texture().unwrap(),
+                params,
+            ),
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -1256,7 +1458,8 @@ impl RafxCommandBuffer {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxCommandBuffer::Empty(inner) => inner.cmd_copy_texture_to_texture(
@@ -1284,6 +1487,8 @@ impl RafxCommandBuffer {
             RafxCommandBuffer::Gles2(_) => {}
             #[cfg(feature = "rafx-gles3")]
             RafxCommandBuffer::Gles3(_) => {}
+            #[cfg(feature = "rafx-webgpu")]
+            RafxCommandBuffer::WebGpu(inner) => inner.cmd_push_group_debug_name(_name),
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -1291,7 +1496,8 @@ impl RafxCommandBuffer {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxCommandBuffer::Empty(inner) => inner.cmd_push_group_debug_name(_name),
@@ -1311,6 +1517,8 @@ impl RafxCommandBuffer {
             RafxCommandBuffer::Gles2(_) => {}
             #[cfg(feature = "rafx-gles3")]
             RafxCommandBuffer::Gles3(_) => {}
+            #[cfg(feature = "rafx-webgpu")]
+            RafxCommandBuffer::WebGpu(inner) => inner.cmd_pop_group_debug_name(),
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -1318,7 +1526,8 @@ impl RafxCommandBuffer {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxCommandBuffer::Empty(inner) => inner.cmd_pop_group_debug_name(),
@@ -1340,6 +1549,8 @@ impl RafxCommandBuffer {
             RafxCommandBuffer::Gles2(_) => None,
             #[cfg(feature = "rafx-gles3")]
             RafxCommandBuffer::Gles3(_) => None,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxCommandBuffer::WebGpu(_) => None,
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -1347,7 +1558,8 @@ impl RafxCommandBuffer {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxCommandBuffer::Empty(_) => None,
@@ -1369,6 +1581,8 @@ impl RafxCommandBuffer {
             RafxCommandBuffer::Gles2(_) => None,
             #[cfg(feature = "rafx-gles3")]
             RafxCommandBuffer::Gles3(_) => None,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxCommandBuffer::WebGpu(_) => None,
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -1376,7 +1590,8 @@ impl RafxCommandBuffer {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxCommandBuffer::Empty(_) => None,
@@ -1398,6 +1613,8 @@ impl RafxCommandBuffer {
             RafxCommandBuffer::Gles2(_) => None,
             #[cfg(feature = "rafx-gles3")]
             RafxCommandBuffer::Gles3(_) => None,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxCommandBuffer::WebGpu(_) => None,
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -1405,7 +1622,8 @@ impl RafxCommandBuffer {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxCommandBuffer::Empty(_) => None,
@@ -1427,6 +1645,8 @@ impl RafxCommandBuffer {
             RafxCommandBuffer::Gles2(inner) => Some(inner),
             #[cfg(feature = "rafx-gles3")]
             RafxCommandBuffer::Gles3(_) => None,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxCommandBuffer::WebGpu(_) => None,
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -1434,7 +1654,8 @@ impl RafxCommandBuffer {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxCommandBuffer::Empty(_) => None,
@@ -1456,6 +1677,40 @@ impl RafxCommandBuffer {
             RafxCommandBuffer::Gles2(_) => None,
             #[cfg(feature = "rafx-gles3")]
             RafxCommandBuffer::Gles3(inner) => Some(inner),
+            #[cfg(feature = "rafx-webgpu")]
+            RafxCommandBuffer::WebGpu(_) => None,
+            #[cfg(any(
+                feature = "rafx-empty",
+                not(any(
+                    feature = "rafx-dx12",
+                    feature = "rafx-metal",
+                    feature = "rafx-vulkan",
+                    feature = "rafx-gles2",
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
+                ))
+            ))]
+            RafxCommandBuffer::Empty(_) => None,
+        }
+    }
+
+    /// Get the underlying webgpu API object. This provides access to any internally created
+    /// wgpu objects.
+    #[cfg(feature = "rafx-webgpu")]
+    pub fn webgpu_command_buffer(&self) -> Option<&RafxCommandBufferWebGpu> {
+        match self {
+            #[cfg(feature = "rafx-dx12")]
+            RafxCommandBuffer::Dx12(_) => None,
+            #[cfg(feature = "rafx-vulkan")]
+            RafxCommandBuffer::Vk(_) => None,
+            #[cfg(feature = "rafx-metal")]
+            RafxCommandBuffer::Metal(_) => None,
+            #[cfg(feature = "rafx-gles2")]
+            RafxCommandBuffer::Gles2(_) => None,
+            #[cfg(feature = "rafx-gles3")]
+            RafxCommandBuffer::Gles3(_) => None,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxCommandBuffer::WebGpu(inner) => Some(inner),
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -1463,7 +1718,8 @@ impl RafxCommandBuffer {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxCommandBuffer::Empty(_) => None,
@@ -1479,7 +1735,8 @@ impl RafxCommandBuffer {
             feature = "rafx-metal",
             feature = "rafx-vulkan",
             feature = "rafx-gles2",
-            feature = "rafx-gles3"
+            feature = "rafx-gles3",
+            feature = "rafx-webgpu"
         ))
     ))]
     pub fn empty_command_buffer(&self) -> Option<&RafxCommandBufferEmpty> {
@@ -1494,6 +1751,8 @@ impl RafxCommandBuffer {
             RafxCommandBuffer::Gles2(_) => None,
             #[cfg(feature = "rafx-gles3")]
             RafxCommandBuffer::Gles3(_) => None,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxCommandBuffer::WebGpu(_) => None,
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -1501,7 +1760,8 @@ impl RafxCommandBuffer {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxCommandBuffer::Empty(inner) => Some(inner),
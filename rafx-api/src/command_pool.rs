@@ -7,7 +7,8 @@ use crate::dx12::RafxCommandPoolDx12;
         feature = "rafx-metal",
         feature = "rafx-vulkan",
         feature = "rafx-gles2",
-        feature = "rafx-gles3"
+        feature = "rafx-gles3",
+        feature = "rafx-webgpu"
     ))
 ))]
 use crate::empty::RafxCommandPoolEmpty;
@@ -19,6 +20,8 @@ use crate::gles3::RafxCommandPoolGles3;
 use crate::metal::RafxCommandPoolMetal;
 #[cfg(feature = "rafx-vulkan")]
 use crate::vulkan::RafxCommandPoolVulkan;
+#[cfg(feature = "rafx-webgpu")]
+use crate::webgpu::RafxCommandPoolWebGpu;
 use crate::{RafxCommandBuffer, RafxCommandBufferDef, RafxDeviceContext, RafxResult};
 
 /// A pool of command buffers. A command pool is necessary to create a command buffer.
@@ -43,6 +46,8 @@ pub enum RafxCommandPool {
     Gles2(RafxCommandPoolGles2),
     #[cfg(feature = "rafx-gles3")]
     Gles3(RafxCommandPoolGles3),
+    #[cfg(feature = "rafx-webgpu")]
+    WebGpu(RafxCommandPoolWebGpu),
     #[cfg(any(
         feature = "rafx-empty",
         not(any(
@@ -50,7 +55,8 @@ pub enum RafxCommandPool {
             feature = "rafx-metal",
             feature = "rafx-vulkan",
             feature = "rafx-gles2",
-            feature = "rafx-gles3"
+            feature = "rafx-gles3",
+            feature = "rafx-webgpu"
         ))
     ))]
     Empty(RafxCommandPoolEmpty),
@@ -75,6 +81,10 @@ impl RafxCommandPool {
             RafxCommandPool::Gles3(inner) => {
                 RafxDeviceContext::Gles3(inner.device_context().clone())
             }
+            #[cfg(feature = "rafx-webgpu")]
+            RafxCommandPool::WebGpu(inner) => {
+                RafxDeviceContext::WebGpu(inner.device_context().clone())
+            }
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -82,7 +92,8 @@ impl RafxCommandPool {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxCommandPool::Empty(inner) => {
@@ -118,6 +129,10 @@ impl RafxCommandPool {
             RafxCommandPool::Gles3(inner) => {
                 RafxCommandBuffer::Gles3(inner.create_command_buffer(command_buffer_def)?)
             }
+            #[cfg(feature = "rafx-webgpu")]
+            RafxCommandPool::WebGpu(inner) => {
+                RafxCommandBuffer::WebGpu(inner.create_command_buffer(command_buffer_def)?)
+            }
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -125,7 +140,8 @@ impl RafxCommandPool {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxCommandPool::Empty(inner) => {
@@ -151,6 +167,8 @@ impl RafxCommandPool {
             RafxCommandPool::Gles2(inner) => inner.reset_command_pool(),
             #[cfg(feature = "rafx-gles3")]
             RafxCommandPool::Gles3(inner) => inner.reset_command_pool(),
+            #[cfg(feature = "rafx-webgpu")]
+            RafxCommandPool::WebGpu(inner) => inner.reset_command_pool(),
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -158,7 +176,8 @@ impl RafxCommandPool {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxCommandPool::Empty(inner) => inner.reset_command_pool(),
@@ -180,6 +199,8 @@ impl RafxCommandPool {
             RafxCommandPool::Gles2(_) => None,
             #[cfg(feature = "rafx-gles3")]
             RafxCommandPool::Gles3(_) => None,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxCommandPool::WebGpu(_) => None,
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -187,7 +208,8 @@ impl RafxCommandPool {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxCommandPool::Empty(_) => None,
@@ -209,6 +231,8 @@ impl RafxCommandPool {
             RafxCommandPool::Gles2(_) => None,
             #[cfg(feature = "rafx-gles3")]
             RafxCommandPool::Gles3(_) => None,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxCommandPool::WebGpu(_) => None,
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -216,7 +240,8 @@ impl RafxCommandPool {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxCommandPool::Empty(_) => None,
@@ -238,6 +263,8 @@ impl RafxCommandPool {
             RafxCommandPool::Gles2(_) => None,
             #[cfg(feature = "rafx-gles3")]
             RafxCommandPool::Gles3(_) => None,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxCommandPool::WebGpu(_) => None,
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -245,7 +272,8 @@ impl RafxCommandPool {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxCommandPool::Empty(_) => None,
@@ -267,6 +295,8 @@ impl RafxCommandPool {
             RafxCommandPool::Gles2(inner) => Some(inner),
             #[cfg(feature = "rafx-gles3")]
             RafxCommandPool::Gles3(_) => None,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxCommandPool::WebGpu(_) => None,
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -274,7 +304,8 @@ impl RafxCommandPool {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxCommandPool::Empty(_) => None,
@@ -296,6 +327,40 @@ impl RafxCommandPool {
             RafxCommandPool::Gles2(_) => None,
             #[cfg(feature = "rafx-gles3")]
             RafxCommandPool::Gles3(inner) => Some(inner),
+            #[cfg(feature = "rafx-webgpu")]
+            RafxCommandPool::WebGpu(_) => None,
+            #[cfg(any(
+                feature = "rafx-empty",
+                not(any(
+                    feature = "rafx-dx12",
+                    feature = "rafx-metal",
+                    feature = "rafx-vulkan",
+                    feature = "rafx-gles2",
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
+                ))
+            ))]
+            RafxCommandPool::Empty(_) => None,
+        }
+    }
+
+    /// Get the underlying webgpu API object. This provides access to any internally created
+    /// wgpu objects.
+    #[cfg(feature = "rafx-webgpu")]
+    pub fn webgpu_command_pool(&self) -> Option<&RafxCommandPoolWebGpu> {
+        match self {
+            #[cfg(feature = "rafx-dx12")]
+            RafxCommandPool::Dx12(_) => None,
+            #[cfg(feature = "rafx-vulkan")]
+            RafxCommandPool::Vk(_) => None,
+            #[cfg(feature = "rafx-metal")]
+            RafxCommandPool::Metal(_) => None,
+            #[cfg(feature = "rafx-gles2")]
+            RafxCommandPool::Gles2(_) => None,
+            #[cfg(feature = "rafx-gles3")]
+            RafxCommandPool::Gles3(_) => None,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxCommandPool::WebGpu(inner) => Some(inner),
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -303,7 +368,8 @@ impl RafxCommandPool {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxCommandPool::Empty(_) => None,
@@ -317,7 +383,8 @@ impl RafxCommandPool {
             feature = "rafx-metal",
             feature = "rafx-vulkan",
             feature = "rafx-gles2",
-            feature = "rafx-gles3"
+            feature = "rafx-gles3",
+            feature = "rafx-webgpu"
         ))
     ))]
     pub fn empty_command_pool(&self) -> Option<&RafxCommandPoolEmpty> {
@@ -337,7 +404,8 @@ impl RafxCommandPool {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxCommandPool::Empty(inner) => Some(inner),
@@ -7,7 +7,8 @@ use crate::dx12::{RafxDescriptorSetArrayDx12, RafxDescriptorSetHandleDx12};
         feature = "rafx-metal",
         feature = "rafx-vulkan",
         feature = "rafx-gles2",
-        feature = "rafx-gles3"
+        feature = "rafx-gles3",
+        feature = "rafx-webgpu"
     ))
 ))]
 use crate::empty::{RafxDescriptorSetArrayEmpty, RafxDescriptorSetHandleEmpty};
@@ -19,6 +20,8 @@ use crate::gles3::{RafxDescriptorSetArrayGles3, RafxDescriptorSetHandleGles3};
 use crate::metal::{RafxDescriptorSetArrayMetal, RafxDescriptorSetHandleMetal};
 #[cfg(feature = "rafx-vulkan")]
 use crate::vulkan::{RafxDescriptorSetArrayVulkan, RafxDescriptorSetHandleVulkan};
+#[cfg(feature = "rafx-webgpu")]
+use crate::webgpu::{RafxDescriptorSetArrayWebGpu, RafxDescriptorSetHandleWebGpu};
 use crate::{RafxDescriptorUpdate, RafxResult, RafxRootSignature};
 
 /// A lightweight handle to a specific descriptor set in a `RafxDescriptorSetArray`.
@@ -45,6 +48,8 @@ pub enum RafxDescriptorSetHandle {
     Gles2(RafxDescriptorSetHandleGles2),
     #[cfg(feature = "rafx-gles3")]
     Gles3(RafxDescriptorSetHandleGles3),
+    #[cfg(feature = "rafx-webgpu")]
+    WebGpu(RafxDescriptorSetHandleWebGpu),
     #[cfg(any(
         feature = "rafx-empty",
         not(any(
@@ -52,7 +57,8 @@ pub enum RafxDescriptorSetHandle {
             feature = "rafx-metal",
             feature = "rafx-vulkan",
             feature = "rafx-gles2",
-            feature = "rafx-gles3"
+            feature = "rafx-gles3",
+            feature = "rafx-webgpu"
         ))
     ))]
     Empty(RafxDescriptorSetHandleEmpty),
@@ -72,6 +78,8 @@ impl RafxDescriptorSetHandle {
             RafxDescriptorSetHandle::Gles2(_) => None,
             #[cfg(feature = "rafx-gles3")]
             RafxDescriptorSetHandle::Gles3(_) => None,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxDescriptorSetHandle::WebGpu(_) => None,
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -79,7 +87,8 @@ impl RafxDescriptorSetHandle {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxDescriptorSetHandle::Empty(_) => None,
@@ -99,6 +108,8 @@ impl RafxDescriptorSetHandle {
             RafxDescriptorSetHandle::Gles2(_) => None,
             #[cfg(feature = "rafx-gles3")]
             RafxDescriptorSetHandle::Gles3(_) => None,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxDescriptorSetHandle::WebGpu(_) => None,
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -106,7 +117,8 @@ impl RafxDescriptorSetHandle {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxDescriptorSetHandle::Empty(_) => None,
@@ -126,6 +138,8 @@ impl RafxDescriptorSetHandle {
             RafxDescriptorSetHandle::Gles2(_) => None,
             #[cfg(feature = "rafx-gles3")]
             RafxDescriptorSetHandle::Gles3(_) => None,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxDescriptorSetHandle::WebGpu(_) => None,
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -133,7 +147,8 @@ impl RafxDescriptorSetHandle {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxDescriptorSetHandle::Empty(_) => None,
@@ -153,6 +168,8 @@ impl RafxDescriptorSetHandle {
             RafxDescriptorSetHandle::Gles2(inner) => Some(inner),
             #[cfg(feature = "rafx-gles3")]
             RafxDescriptorSetHandle::Gles3(_) => None,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxDescriptorSetHandle::WebGpu(_) => None,
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -160,7 +177,8 @@ impl RafxDescriptorSetHandle {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxDescriptorSetHandle::Empty(_) => None,
@@ -180,6 +198,8 @@ impl RafxDescriptorSetHandle {
             RafxDescriptorSetHandle::Gles2(_) => None,
             #[cfg(feature = "rafx-gles3")]
             RafxDescriptorSetHandle::Gles3(inner) => Some(inner),
+            #[cfg(feature = "rafx-webgpu")]
+            RafxDescriptorSetHandle::WebGpu(_) => None,
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -187,7 +207,38 @@ impl RafxDescriptorSetHandle {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
+                ))
+            ))]
+            RafxDescriptorSetHandle::Empty(_) => None,
+        }
+    }
+
+    #[cfg(feature = "rafx-webgpu")]
+    pub fn webgpu_descriptor_set_handle(&self) -> Option<&RafxDescriptorSetHandleWebGpu> {
+        match self {
+            #[cfg(feature = "rafx-dx12")]
+            RafxDescriptorSetHandle::Dx12(_) => None,
+            #[cfg(feature = "rafx-vulkan")]
+            RafxDescriptorSetHandle::Vk(_) => None,
+            #[cfg(feature = "rafx-metal")]
+            RafxDescriptorSetHandle::Metal(_) => None,
+            #[cfg(feature = "rafx-gles2")]
+            RafxDescriptorSetHandle::Gles2(_) => None,
+            #[cfg(feature = "rafx-gles3")]
+            RafxDescriptorSetHandle::Gles3(_) => None,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxDescriptorSetHandle::WebGpu(inner) => Some(inner),
+            #[cfg(any(
+                feature = "rafx-empty",
+                not(any(
+                    feature = "rafx-dx12",
+                    feature = "rafx-metal",
+                    feature = "rafx-vulkan",
+                    feature = "rafx-gles2",
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxDescriptorSetHandle::Empty(_) => None,
@@ -201,7 +252,8 @@ impl RafxDescriptorSetHandle {
             feature = "rafx-metal",
             feature = "rafx-vulkan",
             feature = "rafx-gles2",
-            feature = "rafx-gles3"
+            feature = "rafx-gles3",
+            feature = "rafx-webgpu"
         ))
     ))]
     pub fn empty_descriptor_set_handle(&self) -> Option<&RafxDescriptorSetHandleEmpty> {
@@ -216,6 +268,8 @@ impl RafxDescriptorSetHandle {
             RafxDescriptorSetHandle::Gles2(_) => None,
             #[cfg(feature = "rafx-gles3")]
             RafxDescriptorSetHandle::Gles3(_) => None,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxDescriptorSetHandle::WebGpu(_) => None,
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -223,7 +277,8 @@ impl RafxDescriptorSetHandle {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxDescriptorSetHandle::Empty(inner) => Some(inner),
@@ -264,6 +319,8 @@ pub enum RafxDescriptorSetArray {
     Gles2(RafxDescriptorSetArrayGles2),
     #[cfg(feature = "rafx-gles3")]
     Gles3(RafxDescriptorSetArrayGles3),
+    #[cfg(feature = "rafx-webgpu")]
+    WebGpu(RafxDescriptorSetArrayWebGpu),
     #[cfg(any(
         feature = "rafx-empty",
         not(any(
@@ -271,7 +328,8 @@ pub enum RafxDescriptorSetArray {
             feature = "rafx-metal",
             feature = "rafx-vulkan",
             feature = "rafx-gles2",
-            feature = "rafx-gles3"
+            feature = "rafx-gles3",
+            feature = "rafx-webgpu"
         ))
     ))]
     Empty(RafxDescriptorSetArrayEmpty),
@@ -303,6 +361,10 @@ impl RafxDescriptorSetArray {
             RafxDescriptorSetArray::Gles3(inner) => {
                 RafxDescriptorSetHandle::Gles3(inner.handle(index)?)
             }
+            #[cfg(feature = "rafx-webgpu")]
+            RafxDescriptorSetArray::WebGpu(inner) => {
+                RafxDescriptorSetHandle::WebGpu(inner.handle(index)?)
+            }
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -310,7 +372,8 @@ impl RafxDescriptorSetArray {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxDescriptorSetArray::Empty(inner) => {
@@ -332,6 +395,8 @@ impl RafxDescriptorSetArray {
             RafxDescriptorSetArray::Gles2(inner) => inner.root_signature(),
             #[cfg(feature = "rafx-gles3")]
             RafxDescriptorSetArray::Gles3(inner) => inner.root_signature(),
+            #[cfg(feature = "rafx-webgpu")]
+            RafxDescriptorSetArray::WebGpu(inner) => inner.root_signature(),
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -339,7 +404,8 @@ impl RafxDescriptorSetArray {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxDescriptorSetArray::Empty(inner) => inner.root_signature(),
@@ -363,6 +429,8 @@ impl RafxDescriptorSetArray {
             RafxDescriptorSetArray::Gles2(inner) => inner.update_descriptor_set(params),
             #[cfg(feature = "rafx-gles3")]
             RafxDescriptorSetArray::Gles3(inner) => inner.update_descriptor_set(params),
+            #[cfg(feature = "rafx-webgpu")]
+            RafxDescriptorSetArray::WebGpu(inner) => inner.update_descriptor_set(params),
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -370,7 +438,8 @@ impl RafxDescriptorSetArray {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxDescriptorSetArray::Empty(inner) => inner.update_descriptor_set(params),
@@ -397,6 +466,8 @@ impl RafxDescriptorSetArray {
             RafxDescriptorSetArray::Gles2(inner) => inner.queue_descriptor_set_update(update),
             #[cfg(feature = "rafx-gles3")]
             RafxDescriptorSetArray::Gles3(inner) => inner.queue_descriptor_set_update(update),
+            #[cfg(feature = "rafx-webgpu")]
+            RafxDescriptorSetArray::WebGpu(inner) => inner.queue_descriptor_set_update(update),
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -404,7 +475,8 @@ impl RafxDescriptorSetArray {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxDescriptorSetArray::Empty(inner) => inner.queue_descriptor_set_update(update),
@@ -424,6 +496,8 @@ impl RafxDescriptorSetArray {
             RafxDescriptorSetArray::Gles2(inner) => inner.flush_descriptor_set_updates(),
             #[cfg(feature = "rafx-gles3")]
             RafxDescriptorSetArray::Gles3(inner) => inner.flush_descriptor_set_updates(),
+            #[cfg(feature = "rafx-webgpu")]
+            RafxDescriptorSetArray::WebGpu(inner) => inner.flush_descriptor_set_updates(),
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -431,7 +505,8 @@ impl RafxDescriptorSetArray {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxDescriptorSetArray::Empty(inner) => inner.flush_descriptor_set_updates(),
@@ -453,6 +528,8 @@ impl RafxDescriptorSetArray {
             RafxDescriptorSetArray::Gles2(_) => None,
             #[cfg(feature = "rafx-gles3")]
             RafxDescriptorSetArray::Gles3(_) => None,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxDescriptorSetArray::WebGpu(_) => None,
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -460,7 +537,8 @@ impl RafxDescriptorSetArray {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxDescriptorSetArray::Empty(_) => None,
@@ -482,6 +560,8 @@ impl RafxDescriptorSetArray {
             RafxDescriptorSetArray::Gles2(_) => None,
             #[cfg(feature = "rafx-gles3")]
             RafxDescriptorSetArray::Gles3(_) => None,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxDescriptorSetArray::WebGpu(_) => None,
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -489,7 +569,8 @@ impl RafxDescriptorSetArray {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxDescriptorSetArray::Empty(_) => None,
@@ -511,6 +592,8 @@ impl RafxDescriptorSetArray {
             RafxDescriptorSetArray::Gles2(_) => None,
             #[cfg(feature = "rafx-gles3")]
             RafxDescriptorSetArray::Gles3(_) => None,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxDescriptorSetArray::WebGpu(_) => None,
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -518,7 +601,8 @@ impl RafxDescriptorSetArray {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxDescriptorSetArray::Empty(_) => None,
@@ -540,6 +624,8 @@ impl RafxDescriptorSetArray {
             RafxDescriptorSetArray::Gles2(inner) => Some(inner),
             #[cfg(feature = "rafx-gles3")]
             RafxDescriptorSetArray::Gles3(_) => None,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxDescriptorSetArray::WebGpu(_) => None,
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -547,7 +633,8 @@ impl RafxDescriptorSetArray {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxDescriptorSetArray::Empty(_) => None,
@@ -569,6 +656,40 @@ impl RafxDescriptorSetArray {
             RafxDescriptorSetArray::Gles2(_) => None,
             #[cfg(feature = "rafx-gles3")]
             RafxDescriptorSetArray::Gles3(inner) => Some(inner),
+            #[cfg(feature = "rafx-webgpu")]
+            RafxDescriptorSetArray::WebGpu(_) => None,
+            #[cfg(any(
+                feature = "rafx-empty",
+                not(any(
+                    feature = "rafx-dx12",
+                    feature = "rafx-metal",
+                    feature = "rafx-vulkan",
+                    feature = "rafx-gles2",
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
+                ))
+            ))]
+            RafxDescriptorSetArray::Empty(_) => None,
+        }
+    }
+
+    /// Get the underlying webgpu API object. This provides access to any internally created
+    /// wgpu objects.
+    #[cfg(feature = "rafx-webgpu")]
+    pub fn webgpu_descriptor_set_array(&self) -> Option<&RafxDescriptorSetArrayWebGpu> {
+        match self {
+            #[cfg(feature = "rafx-dx12")]
+            RafxDescriptorSetArray::Dx12(_) => None,
+            #[cfg(feature = "rafx-vulkan")]
+            RafxDescriptorSetArray::Vk(_) => None,
+            #[cfg(feature = "rafx-metal")]
+            RafxDescriptorSetArray::Metal(_) => None,
+            #[cfg(feature = "rafx-gles2")]
+            RafxDescriptorSetArray::Gles2(_) => None,
+            #[cfg(feature = "rafx-gles3")]
+            RafxDescriptorSetArray::Gles3(_) => None,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxDescriptorSetArray::WebGpu(inner) => Some(inner),
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -576,7 +697,8 @@ impl RafxDescriptorSetArray {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxDescriptorSetArray::Empty(_) => None,
@@ -592,7 +714,8 @@ impl RafxDescriptorSetArray {
             feature = "rafx-metal",
             feature = "rafx-vulkan",
             feature = "rafx-gles2",
-            feature = "rafx-gles3"
+            feature = "rafx-gles3",
+            feature = "rafx-webgpu"
         ))
     ))]
     pub fn empty_descriptor_set_array(&self) -> Option<&RafxDescriptorSetArrayEmpty> {
@@ -607,6 +730,8 @@ impl RafxDescriptorSetArray {
             RafxDescriptorSetArray::Gles2(_) => None,
             #[cfg(feature = "rafx-gles3")]
             RafxDescriptorSetArray::Gles3(_) => None,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxDescriptorSetArray::WebGpu(_) => None,
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -614,7 +739,8 @@ impl RafxDescriptorSetArray {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxDescriptorSetArray::Empty(inner) => Some(inner),
@@ -6,6 +6,7 @@
         feature = "rafx-vulkan",
         feature = "rafx-gles2",
         feature = "rafx-gles3",
+        feature = "rafx-webgpu",
     ))
 ))]
 use crate::backends::empty::RafxDeviceContextEmpty;
@@ -19,6 +20,8 @@ use crate::gles3::RafxDeviceContextGles3;
 use crate::metal::RafxDeviceContextMetal;
 #[cfg(feature = "rafx-vulkan")]
 use crate::vulkan::RafxDeviceContextVulkan;
+#[cfg(feature = "rafx-webgpu")]
+use crate::webgpu::RafxDeviceContextWebGpu;
 use crate::*;
 use raw_window_handle::{HasRawDisplayHandle, HasRawWindowHandle};
 
@@ -38,6 +41,8 @@ pub enum RafxDeviceContext {
     Gles2(RafxDeviceContextGles2),
     #[cfg(feature = "rafx-gles3")]
     Gles3(RafxDeviceContextGles3),
+    #[cfg(feature = "rafx-webgpu")]
+    WebGpu(RafxDeviceContextWebGpu),
     #[cfg(any(
         feature = "rafx-empty",
         not(any(
@@ -46,6 +51,7 @@ pub enum RafxDeviceContext {
             feature = "rafx-vulkan",
             feature = "rafx-gles2",
             feature = "rafx-gles3",
+            feature = "rafx-webgpu",
         ))
     ))]
     Empty(RafxDeviceContextEmpty),
@@ -88,6 +94,15 @@ impl RafxDeviceContext {
         }
     }
 
+    pub fn is_webgpu(&self) -> bool {
+        #[allow(unreachable_patterns)]
+        match self {
+            #[cfg(feature = "rafx-webgpu")]
+            RafxDeviceContext::WebGpu(_) => true,
+            _ => false,
+        }
+    }
+
     pub fn is_gles2(&self) -> bool {
         #[allow(unreachable_patterns)]
         match self {
@@ -109,6 +124,8 @@ impl RafxDeviceContext {
             RafxDeviceContext::Gles2(_) => RafxApiType::Gles2,
             #[cfg(feature = "rafx-gles3")]
             RafxDeviceContext::Gles3(_) => RafxApiType::Gles3,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxDeviceContext::WebGpu(_) => RafxApiType::WebGpu,
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -116,7 +133,8 @@ impl RafxDeviceContext {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxDeviceContext::Empty(_) => RafxApiType::Empty,
@@ -136,6 +154,8 @@ impl RafxDeviceContext {
             RafxDeviceContext::Gles2(inner) => inner.device_info(),
             #[cfg(feature = "rafx-gles3")]
             RafxDeviceContext::Gles3(inner) => inner.device_info(),
+            #[cfg(feature = "rafx-webgpu")]
+            RafxDeviceContext::WebGpu(inner) => inner.device_info(),
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -143,7 +163,8 @@ impl RafxDeviceContext {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxDeviceContext::Empty(inner) => inner.device_info(),
@@ -174,6 +195,10 @@ impl RafxDeviceContext {
             RafxDeviceContext::Gles3(inner) => {
                 inner.find_supported_format(candidates, resource_type)
             }
+            #[cfg(feature = "rafx-webgpu")]
+            RafxDeviceContext::WebGpu(inner) => {
+                inner.find_supported_format(candidates, resource_type)
+            }
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -181,7 +206,8 @@ impl RafxDeviceContext {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxDeviceContext::Empty(inner) => {
@@ -205,6 +231,8 @@ impl RafxDeviceContext {
             RafxDeviceContext::Gles2(inner) => inner.find_supported_sample_count(candidates),
             #[cfg(feature = "rafx-gles3")]
             RafxDeviceContext::Gles3(inner) => inner.find_supported_sample_count(candidates),
+            #[cfg(feature = "rafx-webgpu")]
+            RafxDeviceContext::WebGpu(inner) => inner.find_supported_sample_count(candidates),
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -213,6 +241,7 @@ impl RafxDeviceContext {
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
                     feature = "rafx-gles3",
+                    feature = "rafx-webgpu",
                 ))
             ))]
             RafxDeviceContext::Empty(inner) => inner.find_supported_sample_count(candidates),
@@ -235,6 +264,8 @@ impl RafxDeviceContext {
             RafxDeviceContext::Gles2(inner) => RafxQueue::Gles2(inner.create_queue(queue_type)?),
             #[cfg(feature = "rafx-gles3")]
             RafxDeviceContext::Gles3(inner) => RafxQueue::Gles3(inner.create_queue(queue_type)?),
+            #[cfg(feature = "rafx-webgpu")]
+            RafxDeviceContext::WebGpu(inner) => RafxQueue::WebGpu(inner.create_queue(queue_type)?),
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -243,6 +274,7 @@ impl RafxDeviceContext {
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
                     feature = "rafx-gles3",
+                    feature = "rafx-webgpu",
                 ))
             ))]
             RafxDeviceContext::Empty(inner) => RafxQueue::Empty(inner.create_queue(queue_type)?),
@@ -262,6 +294,8 @@ impl RafxDeviceContext {
             RafxDeviceContext::Gles2(inner) => RafxFence::Gles2(inner.create_fence()?),
             #[cfg(feature = "rafx-gles3")]
             RafxDeviceContext::Gles3(inner) => RafxFence::Gles3(inner.create_fence()?),
+            #[cfg(feature = "rafx-webgpu")]
+            RafxDeviceContext::WebGpu(inner) => RafxFence::WebGpu(inner.create_fence()?),
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -269,7 +303,8 @@ impl RafxDeviceContext {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxDeviceContext::Empty(inner) => RafxFence::Empty(inner.create_fence()?),
@@ -289,6 +324,8 @@ impl RafxDeviceContext {
             RafxDeviceContext::Gles2(inner) => RafxSemaphore::Gles2(inner.create_semaphore()?),
             #[cfg(feature = "rafx-gles3")]
             RafxDeviceContext::Gles3(inner) => RafxSemaphore::Gles3(inner.create_semaphore()?),
+            #[cfg(feature = "rafx-webgpu")]
+            RafxDeviceContext::WebGpu(inner) => RafxSemaphore::WebGpu(inner.create_semaphore()?),
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -296,7 +333,8 @@ impl RafxDeviceContext {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxDeviceContext::Empty(inner) => RafxSemaphore::Empty(inner.create_semaphore()?),
@@ -347,6 +385,13 @@ impl RafxDeviceContext {
                 present_queue.gles3_queue().unwrap(),
                 swapchain_def,
             )?),
+            #[cfg(feature = "rafx-webgpu")]
+            RafxDeviceContext::WebGpu(inner) => RafxSwapchain::WebGpu(inner.create_swapchain(
+                raw_display_handle,
+                raw_window_handle,
+                present_queue.webgpu_queue().unwrap(),
+                swapchain_def,
+            )?),
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -355,6 +400,7 @@ impl RafxDeviceContext {
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
                     feature = "rafx-gles3",
+                    feature = "rafx-webgpu",
                 ))
             ))]
             RafxDeviceContext::Empty(inner) => RafxSwapchain::Empty(inner.create_swapchain(
@@ -398,6 +444,11 @@ impl RafxDeviceContext {
                 let fences: Vec<_> = fences.iter().map(|x| x.gles3_fence().unwrap()).collect();
                 inner.wait_for_fences(&fences)?
             }
+            #[cfg(feature = "rafx-webgpu")]
+            RafxDeviceContext::WebGpu(inner) => {
+                let fences: Vec<_> = fences.iter().map(|x| x.webgpu_fence().unwrap()).collect();
+                inner.wait_for_fences(&fences)?
+            }
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -405,7 +456,8 @@ impl RafxDeviceContext {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxDeviceContext::Empty(inner) => {
@@ -437,6 +489,10 @@ impl RafxDeviceContext {
             RafxDeviceContext::Gles3(inner) => {
                 RafxSampler::Gles3(inner.create_sampler(sampler_def)?)
             }
+            #[cfg(feature = "rafx-webgpu")]
+            RafxDeviceContext::WebGpu(inner) => {
+                RafxSampler::WebGpu(inner.create_sampler(sampler_def)?)
+            }
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -444,7 +500,8 @@ impl RafxDeviceContext {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxDeviceContext::Empty(inner) => {
@@ -475,6 +532,10 @@ impl RafxDeviceContext {
             RafxDeviceContext::Gles3(inner) => {
                 RafxTexture::Gles3(inner.create_texture(texture_def)?)
             }
+            #[cfg(feature = "rafx-webgpu")]
+            RafxDeviceContext::WebGpu(inner) => {
+                RafxTexture::WebGpu(inner.create_texture(texture_def)?)
+            }
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -482,7 +543,8 @@ impl RafxDeviceContext {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxDeviceContext::Empty(inner) => {
@@ -507,6 +569,10 @@ impl RafxDeviceContext {
             RafxDeviceContext::Gles2(inner) => RafxBuffer::Gles2(inner.create_buffer(buffer_def)?),
             #[cfg(feature = "rafx-gles3")]
             RafxDeviceContext::Gles3(inner) => RafxBuffer::Gles3(inner.create_buffer(buffer_def)?),
+            #[cfg(feature = "rafx-webgpu")]
+            RafxDeviceContext::WebGpu(inner) => {
+                RafxBuffer::WebGpu(inner.create_buffer(buffer_def)?)
+            }
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -514,7 +580,8 @@ impl RafxDeviceContext {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxDeviceContext::Empty(inner) => RafxBuffer::Empty(inner.create_buffer(buffer_def)?),
@@ -546,6 +613,10 @@ impl RafxDeviceContext {
             RafxDeviceContext::Gles3(inner) => RafxShaderModule::Gles3(
                 inner.create_shader_module(shader_module_def.gles3.unwrap())?,
             ),
+            #[cfg(feature = "rafx-webgpu")]
+            RafxDeviceContext::WebGpu(inner) => RafxShaderModule::WebGpu(
+                inner.create_shader_module(shader_module_def.webgpu.unwrap())?,
+            ),
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -554,6 +625,7 @@ impl RafxDeviceContext {
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
                     feature = "rafx-gles3",
+                    feature = "rafx-webgpu",
                 ))
             ))]
             RafxDeviceContext::Empty(inner) => RafxShaderModule::Empty(
@@ -579,6 +651,8 @@ impl RafxDeviceContext {
             RafxDeviceContext::Gles2(inner) => RafxShader::Gles2(inner.create_shader(stages)?),
             #[cfg(feature = "rafx-gles3")]
             RafxDeviceContext::Gles3(inner) => RafxShader::Gles3(inner.create_shader(stages)?),
+            #[cfg(feature = "rafx-webgpu")]
+            RafxDeviceContext::WebGpu(inner) => RafxShader::WebGpu(inner.create_shader(stages)?),
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -587,6 +661,7 @@ impl RafxDeviceContext {
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
                     feature = "rafx-gles3",
+                    feature = "rafx-webgpu",
                 ))
             ))]
             RafxDeviceContext::Empty(inner) => RafxShader::Empty(inner.create_shader(stages)?),
@@ -619,6 +694,10 @@ impl RafxDeviceContext {
             RafxDeviceContext::Gles3(inner) => {
                 RafxRootSignature::Gles3(inner.create_root_signature(root_signature_def)?)
             }
+            #[cfg(feature = "rafx-webgpu")]
+            RafxDeviceContext::WebGpu(inner) => {
+                RafxRootSignature::WebGpu(inner.create_root_signature(root_signature_def)?)
+            }
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -626,7 +705,8 @@ impl RafxDeviceContext {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxDeviceContext::Empty(inner) => {
@@ -661,6 +741,10 @@ impl RafxDeviceContext {
             RafxDeviceContext::Gles3(inner) => {
                 RafxPipeline::Gles3(inner.create_graphics_pipeline(pipeline_def)?)
             }
+            #[cfg(feature = "rafx-webgpu")]
+            RafxDeviceContext::WebGpu(inner) => {
+                RafxPipeline::WebGpu(inner.create_graphics_pipeline(pipeline_def)?)
+            }
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -668,7 +752,8 @@ impl RafxDeviceContext {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxDeviceContext::Empty(inner) => {
@@ -703,6 +788,10 @@ impl RafxDeviceContext {
             RafxDeviceContext::Gles3(inner) => {
                 RafxPipeline::Gles3(inner.create_compute_pipeline(pipeline_def)?)
             }
+            #[cfg(feature = "rafx-webgpu")]
+            RafxDeviceContext::WebGpu(inner) => {
+                RafxPipeline::WebGpu(inner.create_compute_pipeline(pipeline_def)?)
+            }
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -710,7 +799,8 @@ impl RafxDeviceContext {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxDeviceContext::Empty(inner) => {
@@ -745,6 +835,10 @@ impl RafxDeviceContext {
             RafxDeviceContext::Gles3(inner) => RafxDescriptorSetArray::Gles3(
                 inner.create_descriptor_set_array(descriptor_set_array_def)?,
             ),
+            #[cfg(feature = "rafx-webgpu")]
+            RafxDeviceContext::WebGpu(inner) => RafxDescriptorSetArray::WebGpu(
+                inner.create_descriptor_set_array(descriptor_set_array_def)?,
+            ),
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -752,7 +846,8 @@ impl RafxDeviceContext {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxDeviceContext::Empty(inner) => RafxDescriptorSetArray::Empty(
@@ -776,6 +871,8 @@ impl RafxDeviceContext {
             RafxDeviceContext::Gles2(_) => None,
             #[cfg(feature = "rafx-gles3")]
             RafxDeviceContext::Gles3(_) => None,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxDeviceContext::WebGpu(_) => None,
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -783,7 +880,8 @@ impl RafxDeviceContext {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxDeviceContext::Empty(_) => None,
@@ -805,6 +903,8 @@ impl RafxDeviceContext {
             RafxDeviceContext::Gles2(_) => None,
             #[cfg(feature = "rafx-gles3")]
             RafxDeviceContext::Gles3(_) => None,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxDeviceContext::WebGpu(_) => None,
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -812,7 +912,8 @@ impl RafxDeviceContext {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxDeviceContext::Empty(_) => None,
@@ -834,6 +935,8 @@ impl RafxDeviceContext {
             RafxDeviceContext::Gles2(_) => None,
             #[cfg(feature = "rafx-gles3")]
             RafxDeviceContext::Gles3(_) => None,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxDeviceContext::WebGpu(_) => None,
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -841,7 +944,8 @@ impl RafxDeviceContext {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxDeviceContext::Empty(_) => None,
@@ -863,6 +967,8 @@ impl RafxDeviceContext {
             RafxDeviceContext::Gles2(inner) => Some(inner),
             #[cfg(feature = "rafx-gles3")]
             RafxDeviceContext::Gles3(_) => None,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxDeviceContext::WebGpu(_) => None,
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -870,7 +976,8 @@ impl RafxDeviceContext {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxDeviceContext::Empty(_) => None,
@@ -892,6 +999,40 @@ impl RafxDeviceContext {
             RafxDeviceContext::Gles2(_) => None,
             #[cfg(feature = "rafx-gles3")]
             RafxDeviceContext::Gles3(inner) => Some(inner),
+            #[cfg(feature = "rafx-webgpu")]
+            RafxDeviceContext::WebGpu(_) => None,
+            #[cfg(any(
+                feature = "rafx-empty",
+                not(any(
+                    feature = "rafx-dx12",
+                    feature = "rafx-metal",
+                    feature = "rafx-vulkan",
+                    feature = "rafx-gles2",
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
+                ))
+            ))]
+            RafxDeviceContext::Empty(_) => None,
+        }
+    }
+
+    /// Get the underlying webgpu API object. This provides access to any internally created
+    /// wgpu objects.
+    #[cfg(feature = "rafx-webgpu")]
+    pub fn webgpu_device_context(&self) -> Option<&RafxDeviceContextWebGpu> {
+        match self {
+            #[cfg(feature = "rafx-dx12")]
+            RafxDeviceContext::Dx12(_) => None,
+            #[cfg(feature = "rafx-vulkan")]
+            RafxDeviceContext::Vk(_) => None,
+            #[cfg(feature = "rafx-metal")]
+            RafxDeviceContext::Metal(_) => None,
+            #[cfg(feature = "rafx-gles2")]
+            RafxDeviceContext::Gles2(_) => None,
+            #[cfg(feature = "rafx-gles3")]
+            RafxDeviceContext::Gles3(_) => None,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxDeviceContext::WebGpu(inner) => Some(inner),
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -899,7 +1040,8 @@ impl RafxDeviceContext {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxDeviceContext::Empty(_) => None,
@@ -915,7 +1057,8 @@ impl RafxDeviceContext {
             feature = "rafx-metal",
             feature = "rafx-vulkan",
             feature = "rafx-gles2",
-            feature = "rafx-gles3"
+            feature = "rafx-gles3",
+            feature = "rafx-webgpu"
         ))
     ))]
     pub fn empty_device_context(&self) -> Option<&RafxDeviceContextEmpty> {
@@ -930,6 +1073,8 @@ impl RafxDeviceContext {
             RafxDeviceContext::Gles2(_) => None,
             #[cfg(feature = "rafx-gles3")]
             RafxDeviceContext::Gles3(_) => None,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxDeviceContext::WebGpu(_) => None,
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -937,7 +1082,8 @@ impl RafxDeviceContext {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxDeviceContext::Empty(inner) => Some(inner),
@@ -10,6 +10,8 @@ use crate::gles3::RafxCommandBufferGles3;
 use crate::metal::RafxCommandBufferMetal;
 #[cfg(feature = "rafx-vulkan")]
 use crate::vulkan::RafxCommandBufferVulkan;
+#[cfg(feature = "rafx-webgpu")]
+use crate::webgpu::RafxCommandBufferWebGpu;
 
 #[cfg(feature = "rafx-vulkan")]
 use crate::{
@@ -44,6 +46,8 @@ pub fn generate_mipmaps(
         RafxCommandBuffer::Gles2(inner) => generate_mipmaps_gles2(inner, _texture),
         #[cfg(feature = "rafx-gles3")]
         RafxCommandBuffer::Gles3(inner) => generate_mipmaps_gles3(inner, _texture),
+        #[cfg(feature = "rafx-webgpu")]
+        RafxCommandBuffer::WebGpu(inner) => generate_mipmaps_webgpu(inner, _texture),
         #[cfg(any(
             feature = "rafx-empty",
             not(any(
@@ -51,7 +55,8 @@ pub fn generate_mipmaps(
                 feature = "rafx-metal",
                 feature = "rafx-vulkan",
                 feature = "rafx-gles2",
-                feature = "rafx-gles3"
+                feature = "rafx-gles3",
+                feature = "rafx-webgpu"
             ))
         ))]
         RafxCommandBuffer::Empty(_) => unimplemented!(),
@@ -111,6 +116,16 @@ fn generate_mipmaps_gles3(
     Ok(())
 }
 
+#[cfg(feature = "rafx-webgpu")]
+fn generate_mipmaps_webgpu(
+    command_buffer: &RafxCommandBufferWebGpu,
+    texture: &RafxTexture,
+) -> RafxResult<()> {
+    // WebGPU has no built-in mipmap generation. The command buffer records a request that is
+    // expanded into a chain of downsampling render passes when the command buffer is submitted.
+    command_buffer.cmd_generate_mipmaps(texture.webgpu_texture().unwrap())
+}
+
 #[cfg(feature = "rafx-dx12")]
 fn generate_mipmaps_dx12(
     command_buffer: &RafxCommandBufferDx12,
@@ -7,7 +7,8 @@ use crate::dx12::RafxFenceDx12;
         feature = "rafx-metal",
         feature = "rafx-vulkan",
         feature = "rafx-gles2",
-        feature = "rafx-gles3"
+        feature = "rafx-gles3",
+        feature = "rafx-webgpu"
     ))
 ))]
 use crate::empty::RafxFenceEmpty;
@@ -19,6 +20,8 @@ use crate::gles3::RafxFenceGles3;
 use crate::metal::RafxFenceMetal;
 #[cfg(feature = "rafx-vulkan")]
 use crate::vulkan::RafxFenceVulkan;
+#[cfg(feature = "rafx-webgpu")]
+use crate::webgpu::RafxFenceWebGpu;
 use crate::{RafxFenceStatus, RafxResult};
 
 /// A GPU -> CPU synchronization mechanism.
@@ -44,6 +47,8 @@ pub enum RafxFence {
     Gles2(RafxFenceGles2),
     #[cfg(feature = "rafx-gles3")]
     Gles3(RafxFenceGles3),
+    #[cfg(feature = "rafx-webgpu")]
+    WebGpu(RafxFenceWebGpu),
     #[cfg(any(
         feature = "rafx-empty",
         not(any(
@@ -51,7 +56,8 @@ pub enum RafxFence {
             feature = "rafx-metal",
             feature = "rafx-vulkan",
             feature = "rafx-gles2",
-            feature = "rafx-gles3"
+            feature = "rafx-gles3",
+            feature = "rafx-webgpu"
         ))
     ))]
     Empty(RafxFenceEmpty),
@@ -75,6 +81,8 @@ impl RafxFence {
             RafxFence::Gles2(inner) => inner.get_fence_status(),
             #[cfg(feature = "rafx-gles3")]
             RafxFence::Gles3(inner) => inner.get_fence_status(),
+            #[cfg(feature = "rafx-webgpu")]
+            RafxFence::WebGpu(inner) => inner.get_fence_status(),
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -82,7 +90,8 @@ impl RafxFence {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxFence::Empty(inner) => inner.get_fence_status(),
@@ -102,6 +111,8 @@ impl RafxFence {
             RafxFence::Gles2(inner) => inner.wait(),
             #[cfg(feature = "rafx-gles3")]
             RafxFence::Gles3(inner) => inner.wait(),
+            #[cfg(feature = "rafx-webgpu")]
+            RafxFence::WebGpu(inner) => inner.wait(),
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -109,7 +120,8 @@ impl RafxFence {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxFence::Empty(inner) => inner.wait(),
@@ -131,6 +143,8 @@ impl RafxFence {
             RafxFence::Gles2(_) => None,
             #[cfg(feature = "rafx-gles3")]
             RafxFence::Gles3(_) => None,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxFence::WebGpu(_) => None,
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -138,7 +152,8 @@ impl RafxFence {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxFence::Empty(_) => None,
@@ -160,6 +175,8 @@ impl RafxFence {
             RafxFence::Gles2(_) => None,
             #[cfg(feature = "rafx-gles3")]
             RafxFence::Gles3(_) => None,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxFence::WebGpu(_) => None,
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -167,7 +184,8 @@ impl RafxFence {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxFence::Empty(_) => None,
@@ -189,6 +207,8 @@ impl RafxFence {
             RafxFence::Gles2(_) => None,
             #[cfg(feature = "rafx-gles3")]
             RafxFence::Gles3(_) => None,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxFence::WebGpu(_) => None,
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -196,7 +216,8 @@ impl RafxFence {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxFence::Empty(_) => None,
@@ -218,6 +239,8 @@ impl RafxFence {
             RafxFence::Gles2(inner) => Some(inner),
             #[cfg(feature = "rafx-gles3")]
             RafxFence::Gles3(_) => None,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxFence::WebGpu(_) => None,
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -225,7 +248,8 @@ impl RafxFence {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxFence::Empty(_) => None,
@@ -247,6 +271,8 @@ impl RafxFence {
             RafxFence::Gles2(_) => None,
             #[cfg(feature = "rafx-gles3")]
             RafxFence::Gles3(inner) => Some(inner),
+            #[cfg(feature = "rafx-webgpu")]
+            RafxFence::WebGpu(_) => None,
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -254,7 +280,40 @@ impl RafxFence {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
+                ))
+            ))]
+            RafxFence::Empty(_) => None,
+        }
+    }
+
+    /// Get the underlying webgpu API object. This provides access to any internally created
+    /// wgpu objects.
+    #[cfg(feature = "rafx-webgpu")]
+    pub fn webgpu_fence(&self) -> Option<&RafxFenceWebGpu> {
+        match self {
+            #[cfg(feature = "rafx-dx12")]
+            RafxFence::Dx12(_) => None,
+            #[cfg(feature = "rafx-vulkan")]
+            RafxFence::Vk(_) => None,
+            #[cfg(feature = "rafx-metal")]
+            RafxFence::Metal(_) => None,
+            #[cfg(feature = "rafx-gles2")]
+            RafxFence::Gles2(_) => None,
+            #[cfg(feature = "rafx-gles3")]
+            RafxFence::Gles3(_) => None,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxFence::WebGpu(inner) => Some(inner),
+            #[cfg(any(
+                feature = "rafx-empty",
+                not(any(
+                    feature = "rafx-dx12",
+                    feature = "rafx-metal",
+                    feature = "rafx-vulkan",
+                    feature = "rafx-gles2",
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxFence::Empty(_) => None,
@@ -270,7 +329,8 @@ impl RafxFence {
             feature = "rafx-metal",
             feature = "rafx-vulkan",
             feature = "rafx-gles2",
-            feature = "rafx-gles3"
+            feature = "rafx-gles3",
+            feature = "rafx-webgpu"
         ))
     ))]
     pub fn empty_fence(&self) -> Option<&RafxFenceEmpty> {
@@ -285,6 +345,8 @@ impl RafxFence {
             RafxFence::Gles2(_) => None,
             #[cfg(feature = "rafx-gles3")]
             RafxFence::Gles3(_) => None,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxFence::WebGpu(_) => None,
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -292,7 +354,8 @@ impl RafxFence {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxFence::Empty(inner) => Some(inner),
@@ -3,7 +3,8 @@
     feature = "rafx-metal",
     feature = "rafx-vulkan",
     feature = "rafx-gles2",
-    feature = "rafx-gles3"
+    feature = "rafx-gles3",
+    feature = "rafx-webgpu"
 ))]
 use crate::{
     RafxImmutableSamplerKey, RafxImmutableSamplers, RafxPipelineType, RafxResult,
@@ -15,7 +16,8 @@ use crate::{
     feature = "rafx-metal",
     feature = "rafx-vulkan",
     feature = "rafx-gles2",
-    feature = "rafx-gles3"
+    feature = "rafx-gles3",
+    feature = "rafx-webgpu"
 ))]
 pub(crate) static NEXT_TEXTURE_ID: std::sync::atomic::AtomicU32 =
     std::sync::atomic::AtomicU32::new(1);
@@ -25,7 +27,8 @@ pub(crate) static NEXT_TEXTURE_ID: std::sync::atomic::AtomicU32 =
     feature = "rafx-metal",
     feature = "rafx-vulkan",
     feature = "rafx-gles2",
-    feature = "rafx-gles3"
+    feature = "rafx-gles3",
+    feature = "rafx-webgpu"
 ))]
 use fnv::FnvHashMap;
 
@@ -49,7 +52,8 @@ impl Into<gpu_allocator::MemoryLocation> for crate::RafxMemoryUsage {
     feature = "rafx-metal",
     feature = "rafx-vulkan",
     feature = "rafx-gles2",
-    feature = "rafx-gles3"
+    feature = "rafx-gles3",
+    feature = "rafx-webgpu"
 ))]
 pub(crate) fn find_immutable_sampler_index(
     samplers: &[RafxImmutableSamplers],
@@ -82,7 +86,8 @@ pub(crate) fn find_immutable_sampler_index(
     feature = "rafx-metal",
     feature = "rafx-vulkan",
     feature = "rafx-gles2",
-    feature = "rafx-gles3"
+    feature = "rafx-gles3",
+    feature = "rafx-webgpu"
 ))]
 pub(crate) fn merge_resources<'a>(
     root_signature_def: &RafxRootSignatureDef<'a>
@@ -237,7 +242,8 @@ pub(crate) fn merge_resources<'a>(
     feature = "rafx-metal",
     feature = "rafx-vulkan",
     feature = "rafx-gles2",
-    feature = "rafx-gles3"
+    feature = "rafx-gles3",
+    feature = "rafx-webgpu"
 ))]
 fn verify_resources_can_overlap(
     resource: &RafxShaderResource,
@@ -284,3 +290,55 @@ fn verify_resources_can_overlap(
 
     Ok(())
 }
+
+// spirv_cross declares specialization constants in HLSL and non-vulkan GLSL as
+//   #ifndef SPIRV_CROSS_CONSTANT_ID_0
+//   #define SPIRV_CROSS_CONSTANT_ID_0 4
+//   #endif
+//   static const int SAMPLE_COUNT = SPIRV_CROSS_CONSTANT_ID_0;
+// so backends that compile source at runtime can specialize a shader by defining the macro
+#[cfg(any(feature = "rafx-dx12", feature = "rafx-gles2", feature = "rafx-gles3"))]
+pub(crate) fn specialization_constant_defines(
+    specialization_constants: &[crate::RafxSpecializationConstant]
+) -> Vec<(String, String)> {
+    specialization_constants
+        .iter()
+        .map(|constant| {
+            (
+                format!("SPIRV_CROSS_CONSTANT_ID_{}", constant.constant_id),
+                constant.value.to_source_literal(),
+            )
+        })
+        .collect()
+}
+
+// Inserts #defines into GLSL source. They must come after the #version directive if there is one
+#[cfg(any(feature = "rafx-gles2", feature = "rafx-gles3"))]
+pub(crate) fn insert_glsl_defines(
+    src: &str,
+    defines: &[(String, String)],
+) -> String {
+    let mut define_lines = String::default();
+    for (name, value) in defines {
+        define_lines.push_str(&format!("#define {} {}\n", name, value));
+    }
+
+    let version_line_end = if src.trim_start().starts_with("#version") {
+        let version_start = src.find("#version").unwrap();
+        src[version_start..]
+            .find('\n')
+            .map(|x| version_start + x + 1)
+            .unwrap_or(src.len())
+    } else {
+        0
+    };
+
+    let mut result = String::with_capacity(src.len() + define_lines.len() + 1);
+    result.push_str(&src[..version_line_end]);
+    if version_line_end == src.len() && !src.ends_with('\n') {
+        result.push('\n');
+    }
+    result.push_str(&define_lines);
+    result.push_str(&src[version_line_end..]);
+    result
+}
@@ -255,6 +255,11 @@ pub use backends::gles3;
 #[cfg(feature = "rafx-gles3")]
 pub use backends::gles3::RafxApiDefGles3;
 
+#[cfg(feature = "rafx-webgpu")]
+pub use backends::webgpu;
+#[cfg(feature = "rafx-webgpu")]
+pub use backends::webgpu::RafxApiDefWebGpu;
+
 #[cfg(any(
     feature = "rafx-empty",
     not(any(
@@ -262,7 +267,8 @@ pub use backends::gles3::RafxApiDefGles3;
         feature = "rafx-metal",
         feature = "rafx-vulkan",
         feature = "rafx-gles2",
-        feature = "rafx-gles3"
+        feature = "rafx-gles3",
+        feature = "rafx-webgpu"
     ))
 ))]
 pub use backends::empty;
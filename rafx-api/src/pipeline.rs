@@ -7,7 +7,8 @@ use crate::dx12::RafxPipelineDx12;
         feature = "rafx-metal",
         feature = "rafx-vulkan",
         feature = "rafx-gles2",
-        feature = "rafx-gles3"
+        feature = "rafx-gles3",
+        feature = "rafx-webgpu"
     ))
 ))]
 use crate::empty::RafxPipelineEmpty;
@@ -19,6 +20,8 @@ use crate::gles3::RafxPipelineGles3;
 use crate::metal::RafxPipelineMetal;
 #[cfg(feature = "rafx-vulkan")]
 use crate::vulkan::RafxPipelineVulkan;
+#[cfg(feature = "rafx-webgpu")]
+use crate::webgpu::RafxPipelineWebGpu;
 use crate::{RafxPipelineType, RafxRootSignature};
 
 /// Represents a complete GPU configuration for executing work.
@@ -46,6 +49,8 @@ pub enum RafxPipeline {
     Gles2(RafxPipelineGles2),
     #[cfg(feature = "rafx-gles3")]
     Gles3(RafxPipelineGles3),
+    #[cfg(feature = "rafx-webgpu")]
+    WebGpu(RafxPipelineWebGpu),
     #[cfg(any(
         feature = "rafx-empty",
         not(any(
@@ -53,7 +58,8 @@ pub enum RafxPipeline {
             feature = "rafx-metal",
             feature = "rafx-vulkan",
             feature = "rafx-gles2",
-            feature = "rafx-gles3"
+            feature = "rafx-gles3",
+            feature = "rafx-webgpu"
         ))
     ))]
     Empty(RafxPipelineEmpty),
@@ -73,6 +79,8 @@ impl RafxPipeline {
             RafxPipeline::Gles2(inner) => inner.pipeline_type(),
             #[cfg(feature = "rafx-gles3")]
             RafxPipeline::Gles3(inner) => inner.pipeline_type(),
+            #[cfg(feature = "rafx-webgpu")]
+            RafxPipeline::WebGpu(inner) => inner.pipeline_type(),
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -80,7 +88,8 @@ impl RafxPipeline {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxPipeline::Empty(inner) => inner.pipeline_type(),
@@ -100,6 +109,8 @@ impl RafxPipeline {
             RafxPipeline::Gles2(inner) => inner.root_signature(),
             #[cfg(feature = "rafx-gles3")]
             RafxPipeline::Gles3(inner) => inner.root_signature(),
+            #[cfg(feature = "rafx-webgpu")]
+            RafxPipeline::WebGpu(inner) => inner.root_signature(),
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -107,7 +118,8 @@ impl RafxPipeline {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxPipeline::Empty(inner) => inner.root_signature(),
@@ -136,6 +148,8 @@ impl RafxPipeline {
             RafxPipeline::Gles2(_) => {}
             #[cfg(feature = "rafx-gles3")]
             RafxPipeline::Gles3(_) => {}
+            #[cfg(feature = "rafx-webgpu")]
+            RafxPipeline::WebGpu(_) => {}
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -143,7 +157,8 @@ impl RafxPipeline {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxPipeline::Empty(inner) => inner.set_debug_name(_name),
@@ -165,6 +180,8 @@ impl RafxPipeline {
             RafxPipeline::Gles2(_) => None,
             #[cfg(feature = "rafx-gles3")]
             RafxPipeline::Gles3(_) => None,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxPipeline::WebGpu(_) => None,
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -172,7 +189,8 @@ impl RafxPipeline {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxPipeline::Empty(_) => None,
@@ -194,6 +212,8 @@ impl RafxPipeline {
             RafxPipeline::Gles2(_) => None,
             #[cfg(feature = "rafx-gles3")]
             RafxPipeline::Gles3(_) => None,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxPipeline::WebGpu(_) => None,
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -201,7 +221,8 @@ impl RafxPipeline {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxPipeline::Empty(_) => None,
@@ -223,6 +244,8 @@ impl RafxPipeline {
             RafxPipeline::Gles2(_) => None,
             #[cfg(feature = "rafx-gles3")]
             RafxPipeline::Gles3(_) => None,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxPipeline::WebGpu(_) => None,
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -230,7 +253,8 @@ impl RafxPipeline {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxPipeline::Empty(_) => None,
@@ -252,6 +276,8 @@ impl RafxPipeline {
             RafxPipeline::Gles2(inner) => Some(inner),
             #[cfg(feature = "rafx-gles3")]
             RafxPipeline::Gles3(_) => None,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxPipeline::WebGpu(_) => None,
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -259,7 +285,8 @@ impl RafxPipeline {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxPipeline::Empty(_) => None,
@@ -281,6 +308,8 @@ impl RafxPipeline {
             RafxPipeline::Gles2(_) => None,
             #[cfg(feature = "rafx-gles3")]
             RafxPipeline::Gles3(inner) => Some(inner),
+            #[cfg(feature = "rafx-webgpu")]
+            RafxPipeline::WebGpu(_) => None,
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -288,7 +317,40 @@ impl RafxPipeline {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
+                ))
+            ))]
+            RafxPipeline::Empty(_) => None,
+        }
+    }
+
+    /// Get the underlying webgpu API object. This provides access to any internally created
+    /// wgpu objects.
+    #[cfg(feature = "rafx-webgpu")]
+    pub fn webgpu_pipeline(&self) -> Option<&RafxPipelineWebGpu> {
+        match self {
+            #[cfg(feature = "rafx-dx12")]
+            RafxPipeline::Dx12(_) => None,
+            #[cfg(feature = "rafx-vulkan")]
+            RafxPipeline::Vk(_) => None,
+            #[cfg(feature = "rafx-metal")]
+            RafxPipeline::Metal(_) => None,
+            #[cfg(feature = "rafx-gles2")]
+            RafxPipeline::Gles2(_) => None,
+            #[cfg(feature = "rafx-gles3")]
+            RafxPipeline::Gles3(_) => None,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxPipeline::WebGpu(inner) => Some(inner),
+            #[cfg(any(
+                feature = "rafx-empty",
+                not(any(
+                    feature = "rafx-dx12",
+                    feature = "rafx-metal",
+                    feature = "rafx-vulkan",
+                    feature = "rafx-gles2",
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxPipeline::Empty(_) => None,
@@ -302,7 +364,8 @@ impl RafxPipeline {
             feature = "rafx-metal",
             feature = "rafx-vulkan",
             feature = "rafx-gles2",
-            feature = "rafx-gles3"
+            feature = "rafx-gles3",
+            feature = "rafx-webgpu"
         ))
     ))]
     pub fn empty_pipeline(&self) -> Option<&RafxPipelineEmpty> {
@@ -317,6 +380,8 @@ impl RafxPipeline {
             RafxPipeline::Gles2(_) => None,
             #[cfg(feature = "rafx-gles3")]
             RafxPipeline::Gles3(_) => None,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxPipeline::WebGpu(_) => None,
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -324,7 +389,8 @@ impl RafxPipeline {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxPipeline::Empty(inner) => Some(inner),
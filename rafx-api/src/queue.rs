@@ -7,7 +7,8 @@ use crate::dx12::RafxQueueDx12;
         feature = "rafx-metal",
         feature = "rafx-vulkan",
         feature = "rafx-gles2",
-        feature = "rafx-gles3"
+        feature = "rafx-gles3",
+        feature = "rafx-webgpu"
     ))
 ))]
 use crate::empty::RafxQueueEmpty;
@@ -19,6 +20,8 @@ use crate::gles3::RafxQueueGles3;
 use crate::metal::RafxQueueMetal;
 #[cfg(feature = "rafx-vulkan")]
 use crate::vulkan::RafxQueueVulkan;
+#[cfg(feature = "rafx-webgpu")]
+use crate::webgpu::RafxQueueWebGpu;
 use crate::{
     RafxCommandBuffer, RafxCommandPool, RafxCommandPoolDef, RafxDeviceContext, RafxFence,
     RafxPresentSuccessResult, RafxQueueType, RafxResult, RafxSemaphore, RafxSwapchain,
@@ -48,6 +51,8 @@ pub enum RafxQueue {
     Gles2(RafxQueueGles2),
     #[cfg(feature = "rafx-gles3")]
     Gles3(RafxQueueGles3),
+    #[cfg(feature = "rafx-webgpu")]
+    WebGpu(RafxQueueWebGpu),
     #[cfg(any(
         feature = "rafx-empty",
         not(any(
@@ -55,7 +60,8 @@ pub enum RafxQueue {
             feature = "rafx-metal",
             feature = "rafx-vulkan",
             feature = "rafx-gles2",
-            feature = "rafx-gles3"
+            feature = "rafx-gles3",
+            feature = "rafx-webgpu"
         ))
     ))]
     Empty(RafxQueueEmpty),
@@ -74,6 +80,8 @@ impl RafxQueue {
             RafxQueue::Gles2(inner) => RafxDeviceContext::Gles2(inner.device_context().clone()),
             #[cfg(feature = "rafx-gles3")]
             RafxQueue::Gles3(inner) => RafxDeviceContext::Gles3(inner.device_context().clone()),
+            #[cfg(feature = "rafx-webgpu")]
+            RafxQueue::WebGpu(inner) => RafxDeviceContext::WebGpu(inner.device_context().clone()),
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -81,7 +89,8 @@ impl RafxQueue {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxQueue::Empty(inner) => RafxDeviceContext::Empty(inner.device_context().clone()),
@@ -102,6 +111,8 @@ impl RafxQueue {
             RafxQueue::Gles2(inner) => inner.queue_id(),
             #[cfg(feature = "rafx-gles3")]
             RafxQueue::Gles3(inner) => inner.queue_id(),
+            #[cfg(feature = "rafx-webgpu")]
+            RafxQueue::WebGpu(inner) => inner.queue_id(),
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -109,7 +120,8 @@ impl RafxQueue {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxQueue::Empty(inner) => inner.queue_id(),
@@ -129,6 +141,8 @@ impl RafxQueue {
             RafxQueue::Gles2(inner) => inner.queue_type(),
             #[cfg(feature = "rafx-gles3")]
             RafxQueue::Gles3(inner) => inner.queue_type(),
+            #[cfg(feature = "rafx-webgpu")]
+            RafxQueue::WebGpu(inner) => inner.queue_type(),
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -136,7 +150,8 @@ impl RafxQueue {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxQueue::Empty(inner) => inner.queue_type(),
@@ -169,6 +184,10 @@ impl RafxQueue {
             RafxQueue::Gles3(inner) => {
                 RafxCommandPool::Gles3(inner.create_command_pool(command_pool_def)?)
             }
+            #[cfg(feature = "rafx-webgpu")]
+            RafxQueue::WebGpu(inner) => {
+                RafxCommandPool::WebGpu(inner.create_command_pool(command_pool_def)?)
+            }
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -176,7 +195,8 @@ impl RafxQueue {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxQueue::Empty(inner) => {
@@ -303,6 +323,27 @@ impl RafxQueue {
                     signal_fence.map(|x| x.gles3_fence().unwrap()),
                 )
             }
+            #[cfg(feature = "rafx-webgpu")]
+            RafxQueue::WebGpu(inner) => {
+                let command_buffers: Vec<_> = command_buffers
+                    .iter()
+                    .map(|x| x.webgpu_command_buffer().unwrap())
+                    .collect();
+                let wait_semaphores: Vec<_> = wait_semaphores
+                    .iter()
+                    .map(|x| x.webgpu_semaphore().unwrap())
+                    .collect();
+                let signal_semaphores: Vec<_> = signal_semaphores
+                    .iter()
+                    .map(|x| x.webgpu_semaphore().unwrap())
+                    .collect();
+                inner.submit(
+                    &command_buffers,
+                    &wait_semaphores,
+                    &signal_semaphores,
+                    signal_fence.map(|x| x.webgpu_fence().unwrap()),
+                )
+            }
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -310,7 +351,8 @@ impl RafxQueue {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxQueue::Empty(inner) => {
@@ -406,6 +448,18 @@ impl RafxQueue {
                     image_index,
                 )
             }
+            #[cfg(feature = "rafx-webgpu")]
+            RafxQueue::WebGpu(inner) => {
+                let wait_semaphores: Vec<_> = wait_semaphores
+                    .iter()
+                    .map(|x| x.webgpu_semaphore().unwrap())
+                    .collect();
+                inner.present(
+                    swapchain.webgpu_swapchain().unwrap(),
+                    &wait_semaphores,
+                    image_index,
+                )
+            }
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -413,7 +467,8 @@ impl RafxQueue {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxQueue::Empty(inner) => {
@@ -443,6 +498,8 @@ impl RafxQueue {
             RafxQueue::Gles2(inner) => inner.wait_for_queue_idle(),
             #[cfg(feature = "rafx-gles3")]
             RafxQueue::Gles3(inner) => inner.wait_for_queue_idle(),
+            #[cfg(feature = "rafx-webgpu")]
+            RafxQueue::WebGpu(inner) => inner.wait_for_queue_idle(),
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -450,7 +507,8 @@ impl RafxQueue {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxQueue::Empty(inner) => inner.wait_for_queue_idle(),
@@ -472,6 +530,8 @@ impl RafxQueue {
             RafxQueue::Gles2(_) => None,
             #[cfg(feature = "rafx-gles3")]
             RafxQueue::Gles3(_) => None,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxQueue::WebGpu(_) => None,
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -479,7 +539,8 @@ impl RafxQueue {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxQueue::Empty(_) => None,
@@ -501,6 +562,8 @@ impl RafxQueue {
             RafxQueue::Gles2(_) => None,
             #[cfg(feature = "rafx-gles3")]
             RafxQueue::Gles3(_) => None,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxQueue::WebGpu(_) => None,
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -508,7 +571,8 @@ impl RafxQueue {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxQueue::Empty(_) => None,
@@ -530,6 +594,8 @@ impl RafxQueue {
             RafxQueue::Gles2(_) => None,
             #[cfg(feature = "rafx-gles3")]
             RafxQueue::Gles3(_) => None,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxQueue::WebGpu(_) => None,
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -537,7 +603,8 @@ impl RafxQueue {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxQueue::Empty(_) => None,
@@ -559,6 +626,8 @@ impl RafxQueue {
             RafxQueue::Gles2(inner) => Some(inner),
             #[cfg(feature = "rafx-gles3")]
             RafxQueue::Gles3(_) => None,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxQueue::WebGpu(_) => None,
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -566,7 +635,8 @@ impl RafxQueue {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxQueue::Empty(_) => None,
@@ -588,6 +658,40 @@ impl RafxQueue {
             RafxQueue::Gles2(_) => None,
             #[cfg(feature = "rafx-gles3")]
             RafxQueue::Gles3(inner) => Some(inner),
+            #[cfg(feature = "rafx-webgpu")]
+            RafxQueue::WebGpu(_) => None,
+            #[cfg(any(
+                feature = "rafx-empty",
+                not(any(
+                    feature = "rafx-dx12",
+                    feature = "rafx-metal",
+                    feature = "rafx-vulkan",
+                    feature = "rafx-gles2",
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
+                ))
+            ))]
+            RafxQueue::Empty(_) => None,
+        }
+    }
+
+    /// Get the underlying webgpu API object. This provides access to any internally created
+    /// wgpu objects.
+    #[cfg(feature = "rafx-webgpu")]
+    pub fn webgpu_queue(&self) -> Option<&RafxQueueWebGpu> {
+        match self {
+            #[cfg(feature = "rafx-dx12")]
+            RafxQueue::Dx12(_) => None,
+            #[cfg(feature = "rafx-vulkan")]
+            RafxQueue::Vk(_) => None,
+            #[cfg(feature = "rafx-metal")]
+            RafxQueue::Metal(_) => None,
+            #[cfg(feature = "rafx-gles2")]
+            RafxQueue::Gles2(_) => None,
+            #[cfg(feature = "rafx-gles3")]
+            RafxQueue::Gles3(_) => None,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxQueue::WebGpu(inner) => Some(inner),
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -595,7 +699,8 @@ impl RafxQueue {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxQueue::Empty(_) => None,
@@ -611,7 +716,8 @@ impl RafxQueue {
             feature = "rafx-metal",
             feature = "rafx-vulkan",
             feature = "rafx-gles2",
-            feature = "rafx-gles3"
+            feature = "rafx-gles3",
+            feature = "rafx-webgpu"
         ))
     ))]
     pub fn empty_queue(&self) -> Option<&RafxQueueEmpty> {
@@ -626,6 +732,8 @@ impl RafxQueue {
             RafxQueue::Gles2(_) => None,
             #[cfg(feature = "rafx-gles3")]
             RafxQueue::Gles3(_) => None,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxQueue::WebGpu(_) => None,
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -633,7 +741,8 @@ impl RafxQueue {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxQueue::Empty(inner) => Some(inner),
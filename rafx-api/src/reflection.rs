@@ -1,4 +1,4 @@
-use crate::types::{RafxResourceType, RafxShaderStageFlags};
+use crate::types::{RafxResourceType, RafxShaderStageFlags, RafxSpecializationConstantValue};
 use crate::{RafxResult, RafxSamplerDef, RafxShaderStageDef, MAX_DESCRIPTOR_SET_LAYOUTS};
 use fnv::FnvHashMap;
 #[cfg(feature = "serde-support")]
@@ -416,6 +416,15 @@ pub struct RafxReflectedVertexInput {
     pub location: u32,
 }
 
+//TODO: Rename RafxReflected... to Rafx...Reflection
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde-support", derive(Serialize, Deserialize))]
+pub struct RafxReflectedSpecializationConstant {
+    pub name: String,
+    pub constant_id: u32,
+    pub default_value: RafxSpecializationConstantValue,
+}
+
 //TODO: Rename RafxReflected... to Rafx...Reflection
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde-support", derive(Serialize, Deserialize))]
@@ -428,4 +437,8 @@ pub struct RafxReflectedEntryPoint {
 
     // Additional reflection data used by the framework level for vertex inputs
     pub vertex_inputs: Vec<RafxReflectedVertexInput>,
+
+    // Specialization constants declared by the shader, used by the framework level to look up
+    // constants by name
+    pub specialization_constants: Vec<RafxReflectedSpecializationConstant>,
 }
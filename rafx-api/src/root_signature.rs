@@ -7,7 +7,8 @@ use crate::dx12::RafxRootSignatureDx12;
         feature = "rafx-metal",
         feature = "rafx-vulkan",
         feature = "rafx-gles2",
-        feature = "rafx-gles3"
+        feature = "rafx-gles3",
+        feature = "rafx-webgpu"
     ))
 ))]
 use crate::empty::RafxRootSignatureEmpty;
@@ -19,6 +20,8 @@ use crate::gles3::RafxRootSignatureGles3;
 use crate::metal::RafxRootSignatureMetal;
 #[cfg(feature = "rafx-vulkan")]
 use crate::vulkan::RafxRootSignatureVulkan;
+#[cfg(feature = "rafx-webgpu")]
+use crate::webgpu::RafxRootSignatureWebGpu;
 use crate::{RafxDescriptorIndex, RafxPipelineType, RafxShaderStageFlags};
 
 /// Represents the full "layout" or "interface" of a shader (or set of shaders.)
@@ -37,6 +40,8 @@ pub enum RafxRootSignature {
     Gles2(RafxRootSignatureGles2),
     #[cfg(feature = "rafx-gles3")]
     Gles3(RafxRootSignatureGles3),
+    #[cfg(feature = "rafx-webgpu")]
+    WebGpu(RafxRootSignatureWebGpu),
     #[cfg(any(
         feature = "rafx-empty",
         not(any(
@@ -44,7 +49,8 @@ pub enum RafxRootSignature {
             feature = "rafx-metal",
             feature = "rafx-vulkan",
             feature = "rafx-gles2",
-            feature = "rafx-gles3"
+            feature = "rafx-gles3",
+            feature = "rafx-webgpu"
         ))
     ))]
     Empty(RafxRootSignatureEmpty),
@@ -64,6 +70,8 @@ impl RafxRootSignature {
             RafxRootSignature::Gles2(inner) => inner.pipeline_type(),
             #[cfg(feature = "rafx-gles3")]
             RafxRootSignature::Gles3(inner) => inner.pipeline_type(),
+            #[cfg(feature = "rafx-webgpu")]
+            RafxRootSignature::WebGpu(inner) => inner.pipeline_type(),
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -71,7 +79,8 @@ impl RafxRootSignature {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxRootSignature::Empty(inner) => inner.pipeline_type(),
@@ -93,6 +102,8 @@ impl RafxRootSignature {
             RafxRootSignature::Gles2(inner) => inner.find_descriptor_by_name(name),
             #[cfg(feature = "rafx-gles3")]
             RafxRootSignature::Gles3(inner) => inner.find_descriptor_by_name(name),
+            #[cfg(feature = "rafx-webgpu")]
+            RafxRootSignature::WebGpu(inner) => inner.find_descriptor_by_name(name),
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -100,7 +111,8 @@ impl RafxRootSignature {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxRootSignature::Empty(inner) => inner.find_descriptor_by_name(name),
@@ -123,6 +135,10 @@ impl RafxRootSignature {
             RafxRootSignature::Gles2(inner) => inner.find_descriptor_by_binding(set_index, binding),
             #[cfg(feature = "rafx-gles3")]
             RafxRootSignature::Gles3(inner) => inner.find_descriptor_by_binding(set_index, binding),
+            #[cfg(feature = "rafx-webgpu")]
+            RafxRootSignature::WebGpu(inner) => {
+                inner.find_descriptor_by_binding(set_index, binding)
+            }
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -130,7 +146,8 @@ impl RafxRootSignature {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxRootSignature::Empty(inner) => inner.find_descriptor_by_binding(set_index, binding),
@@ -158,6 +175,8 @@ impl RafxRootSignature {
                 let _ = stage;
                 unimplemented!()
             }
+            #[cfg(feature = "rafx-webgpu")]
+            RafxRootSignature::WebGpu(inner) => inner.find_push_constant_descriptor(stage),
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -165,7 +184,8 @@ impl RafxRootSignature {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxRootSignature::Empty(inner) => inner.find_push_constant_descriptor(stage),
@@ -187,6 +207,8 @@ impl RafxRootSignature {
             RafxRootSignature::Gles2(_) => None,
             #[cfg(feature = "rafx-gles3")]
             RafxRootSignature::Gles3(_) => None,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxRootSignature::WebGpu(_) => None,
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -194,7 +216,8 @@ impl RafxRootSignature {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxRootSignature::Empty(_) => None,
@@ -216,6 +239,8 @@ impl RafxRootSignature {
             RafxRootSignature::Gles2(_) => None,
             #[cfg(feature = "rafx-gles3")]
             RafxRootSignature::Gles3(_) => None,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxRootSignature::WebGpu(_) => None,
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -223,7 +248,8 @@ impl RafxRootSignature {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxRootSignature::Empty(_) => None,
@@ -245,6 +271,8 @@ impl RafxRootSignature {
             RafxRootSignature::Gles2(_) => None,
             #[cfg(feature = "rafx-gles3")]
             RafxRootSignature::Gles3(_) => None,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxRootSignature::WebGpu(_) => None,
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -252,7 +280,8 @@ impl RafxRootSignature {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxRootSignature::Empty(_) => None,
@@ -274,6 +303,8 @@ impl RafxRootSignature {
             RafxRootSignature::Gles2(inner) => Some(inner),
             #[cfg(feature = "rafx-gles3")]
             RafxRootSignature::Gles3(_) => None,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxRootSignature::WebGpu(_) => None,
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -281,7 +312,8 @@ impl RafxRootSignature {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxRootSignature::Empty(_) => None,
@@ -303,6 +335,40 @@ impl RafxRootSignature {
             RafxRootSignature::Gles2(_) => None,
             #[cfg(feature = "rafx-gles3")]
             RafxRootSignature::Gles3(inner) => Some(inner),
+            #[cfg(feature = "rafx-webgpu")]
+            RafxRootSignature::WebGpu(_) => None,
+            #[cfg(any(
+                feature = "rafx-empty",
+                not(any(
+                    feature = "rafx-dx12",
+                    feature = "rafx-metal",
+                    feature = "rafx-vulkan",
+                    feature = "rafx-gles2",
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
+                ))
+            ))]
+            RafxRootSignature::Empty(_) => None,
+        }
+    }
+
+    /// Get the underlying webgpu API object. This provides access to any internally created
+    /// wgpu objects.
+    #[cfg(feature = "rafx-webgpu")]
+    pub fn webgpu_root_signature(&self) -> Option<&RafxRootSignatureWebGpu> {
+        match self {
+            #[cfg(feature = "rafx-dx12")]
+            RafxRootSignature::Dx12(_) => None,
+            #[cfg(feature = "rafx-vulkan")]
+            RafxRootSignature::Vk(_) => None,
+            #[cfg(feature = "rafx-metal")]
+            RafxRootSignature::Metal(_) => None,
+            #[cfg(feature = "rafx-gles2")]
+            RafxRootSignature::Gles2(_) => None,
+            #[cfg(feature = "rafx-gles3")]
+            RafxRootSignature::Gles3(_) => None,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxRootSignature::WebGpu(inner) => Some(inner),
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -310,7 +376,8 @@ impl RafxRootSignature {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxRootSignature::Empty(_) => None,
@@ -326,7 +393,8 @@ impl RafxRootSignature {
             feature = "rafx-metal",
             feature = "rafx-vulkan",
             feature = "rafx-gles2",
-            feature = "rafx-gles3"
+            feature = "rafx-gles3",
+            feature = "rafx-webgpu"
         ))
     ))]
     pub fn empty_root_signature(&self) -> Option<&RafxRootSignatureEmpty> {
@@ -341,6 +409,8 @@ impl RafxRootSignature {
             RafxRootSignature::Gles2(_) => None,
             #[cfg(feature = "rafx-gles3")]
             RafxRootSignature::Gles3(_) => None,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxRootSignature::WebGpu(_) => None,
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -348,7 +418,8 @@ impl RafxRootSignature {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxRootSignature::Empty(inner) => Some(inner),
@@ -7,7 +7,8 @@ use crate::dx12::RafxSamplerDx12;
         feature = "rafx-metal",
         feature = "rafx-vulkan",
         feature = "rafx-gles2",
-        feature = "rafx-gles3"
+        feature = "rafx-gles3",
+        feature = "rafx-webgpu"
     ))
 ))]
 use crate::empty::RafxSamplerEmpty;
@@ -19,6 +20,8 @@ use crate::gles3::RafxSamplerGles3;
 use crate::metal::RafxSamplerMetal;
 #[cfg(feature = "rafx-vulkan")]
 use crate::vulkan::RafxSamplerVulkan;
+#[cfg(feature = "rafx-webgpu")]
+use crate::webgpu::RafxSamplerWebGpu;
 
 /// Configures how images will be sampled by the GPU
 ///
@@ -35,6 +38,8 @@ pub enum RafxSampler {
     Gles2(RafxSamplerGles2),
     #[cfg(feature = "rafx-gles3")]
     Gles3(RafxSamplerGles3),
+    #[cfg(feature = "rafx-webgpu")]
+    WebGpu(RafxSamplerWebGpu),
     #[cfg(any(
         feature = "rafx-empty",
         not(any(
@@ -42,7 +47,8 @@ pub enum RafxSampler {
             feature = "rafx-metal",
             feature = "rafx-vulkan",
             feature = "rafx-gles2",
-            feature = "rafx-gles3"
+            feature = "rafx-gles3",
+            feature = "rafx-webgpu"
         ))
     ))]
     Empty(RafxSamplerEmpty),
@@ -64,6 +70,8 @@ impl RafxSampler {
             RafxSampler::Gles2(_) => None,
             #[cfg(feature = "rafx-gles3")]
             RafxSampler::Gles3(_) => None,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxSampler::WebGpu(_) => None,
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -71,7 +79,8 @@ impl RafxSampler {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxSampler::Empty(_) => None,
@@ -93,6 +102,8 @@ impl RafxSampler {
             RafxSampler::Gles2(_) => None,
             #[cfg(feature = "rafx-gles3")]
             RafxSampler::Gles3(_) => None,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxSampler::WebGpu(_) => None,
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -100,7 +111,8 @@ impl RafxSampler {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxSampler::Empty(_) => None,
@@ -122,6 +134,8 @@ impl RafxSampler {
             RafxSampler::Gles2(_) => None,
             #[cfg(feature = "rafx-gles3")]
             RafxSampler::Gles3(_) => None,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxSampler::WebGpu(_) => None,
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -129,7 +143,8 @@ impl RafxSampler {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxSampler::Empty(_) => None,
@@ -151,6 +166,8 @@ impl RafxSampler {
             RafxSampler::Gles2(inner) => Some(inner),
             #[cfg(feature = "rafx-gles3")]
             RafxSampler::Gles3(_) => None,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxSampler::WebGpu(_) => None,
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -158,7 +175,8 @@ impl RafxSampler {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxSampler::Empty(_) => None,
@@ -180,6 +198,8 @@ impl RafxSampler {
             RafxSampler::Gles2(_) => None,
             #[cfg(feature = "rafx-gles3")]
             RafxSampler::Gles3(inner) => Some(inner),
+            #[cfg(feature = "rafx-webgpu")]
+            RafxSampler::WebGpu(_) => None,
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -187,7 +207,40 @@ impl RafxSampler {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
+                ))
+            ))]
+            RafxSampler::Empty(_) => None,
+        }
+    }
+
+    /// Get the underlying webgpu API object. This provides access to any internally created
+    /// wgpu objects.
+    #[cfg(feature = "rafx-webgpu")]
+    pub fn webgpu_sampler(&self) -> Option<&RafxSamplerWebGpu> {
+        match self {
+            #[cfg(feature = "rafx-dx12")]
+            RafxSampler::Dx12(_) => None,
+            #[cfg(feature = "rafx-vulkan")]
+            RafxSampler::Vk(_) => None,
+            #[cfg(feature = "rafx-metal")]
+            RafxSampler::Metal(_) => None,
+            #[cfg(feature = "rafx-gles2")]
+            RafxSampler::Gles2(_) => None,
+            #[cfg(feature = "rafx-gles3")]
+            RafxSampler::Gles3(_) => None,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxSampler::WebGpu(inner) => Some(inner),
+            #[cfg(any(
+                feature = "rafx-empty",
+                not(any(
+                    feature = "rafx-dx12",
+                    feature = "rafx-metal",
+                    feature = "rafx-vulkan",
+                    feature = "rafx-gles2",
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxSampler::Empty(_) => None,
@@ -203,7 +256,8 @@ impl RafxSampler {
             feature = "rafx-metal",
             feature = "rafx-vulkan",
             feature = "rafx-gles2",
-            feature = "rafx-gles3"
+            feature = "rafx-gles3",
+            feature = "rafx-webgpu"
         ))
     ))]
     pub fn empty_sampler(&self) -> Option<&RafxSamplerEmpty> {
@@ -218,6 +272,8 @@ impl RafxSampler {
             RafxSampler::Gles2(_) => None,
             #[cfg(feature = "rafx-gles3")]
             RafxSampler::Gles3(_) => None,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxSampler::WebGpu(_) => None,
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -225,7 +281,8 @@ impl RafxSampler {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxSampler::Empty(inner) => Some(inner),
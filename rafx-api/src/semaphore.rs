@@ -7,7 +7,8 @@ use crate::dx12::RafxSemaphoreDx12;
         feature = "rafx-metal",
         feature = "rafx-vulkan",
         feature = "rafx-gles2",
-        feature = "rafx-gles3"
+        feature = "rafx-gles3",
+        feature = "rafx-webgpu"
     ))
 ))]
 use crate::empty::RafxSemaphoreEmpty;
@@ -19,6 +20,8 @@ use crate::gles3::RafxSemaphoreGles3;
 use crate::metal::RafxSemaphoreMetal;
 #[cfg(feature = "rafx-vulkan")]
 use crate::vulkan::RafxSemaphoreVulkan;
+#[cfg(feature = "rafx-webgpu")]
+use crate::webgpu::RafxSemaphoreWebGpu;
 
 /// A GPU -> GPU synchronization mechanism.
 ///
@@ -39,6 +42,8 @@ pub enum RafxSemaphore {
     Gles2(RafxSemaphoreGles2),
     #[cfg(feature = "rafx-gles3")]
     Gles3(RafxSemaphoreGles3),
+    #[cfg(feature = "rafx-webgpu")]
+    WebGpu(RafxSemaphoreWebGpu),
     #[cfg(any(
         feature = "rafx-empty",
         not(any(
@@ -46,7 +51,8 @@ pub enum RafxSemaphore {
             feature = "rafx-metal",
             feature = "rafx-vulkan",
             feature = "rafx-gles2",
-            feature = "rafx-gles3"
+            feature = "rafx-gles3",
+            feature = "rafx-webgpu"
         ))
     ))]
     Empty(RafxSemaphoreEmpty),
@@ -68,6 +74,8 @@ impl RafxSemaphore {
             RafxSemaphore::Gles2(_) => None,
             #[cfg(feature = "rafx-gles3")]
             RafxSemaphore::Gles3(_) => None,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxSemaphore::WebGpu(_) => None,
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -75,7 +83,8 @@ impl RafxSemaphore {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxSemaphore::Empty(_) => None,
@@ -97,6 +106,8 @@ impl RafxSemaphore {
             RafxSemaphore::Gles2(_) => None,
             #[cfg(feature = "rafx-gles3")]
             RafxSemaphore::Gles3(_) => None,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxSemaphore::WebGpu(_) => None,
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -104,7 +115,8 @@ impl RafxSemaphore {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxSemaphore::Empty(_) => None,
@@ -126,6 +138,8 @@ impl RafxSemaphore {
             RafxSemaphore::Gles2(_) => None,
             #[cfg(feature = "rafx-gles3")]
             RafxSemaphore::Gles3(_) => None,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxSemaphore::WebGpu(_) => None,
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -133,7 +147,8 @@ impl RafxSemaphore {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxSemaphore::Empty(_) => None,
@@ -155,6 +170,8 @@ impl RafxSemaphore {
             RafxSemaphore::Gles2(inner) => Some(inner),
             #[cfg(feature = "rafx-gles3")]
             RafxSemaphore::Gles3(_) => None,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxSemaphore::WebGpu(_) => None,
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -162,7 +179,8 @@ impl RafxSemaphore {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxSemaphore::Empty(_) => None,
@@ -184,6 +202,8 @@ impl RafxSemaphore {
             RafxSemaphore::Gles2(_) => None,
             #[cfg(feature = "rafx-gles3")]
             RafxSemaphore::Gles3(inner) => Some(inner),
+            #[cfg(feature = "rafx-webgpu")]
+            RafxSemaphore::WebGpu(_) => None,
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -191,7 +211,40 @@ impl RafxSemaphore {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
+                ))
+            ))]
+            RafxSemaphore::Empty(_) => None,
+        }
+    }
+
+    /// Get the underlying webgpu API object. This provides access to any internally created
+    /// wgpu objects.
+    #[cfg(feature = "rafx-webgpu")]
+    pub fn webgpu_semaphore(&self) -> Option<&RafxSemaphoreWebGpu> {
+        match self {
+            #[cfg(feature = "rafx-dx12")]
+            RafxSemaphore::Dx12(_) => None,
+            #[cfg(feature = "rafx-vulkan")]
+            RafxSemaphore::Vk(_) => None,
+            #[cfg(feature = "rafx-metal")]
+            RafxSemaphore::Metal(_) => None,
+            #[cfg(feature = "rafx-gles2")]
+            RafxSemaphore::Gles2(_) => None,
+            #[cfg(feature = "rafx-gles3")]
+            RafxSemaphore::Gles3(_) => None,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxSemaphore::WebGpu(inner) => Some(inner),
+            #[cfg(any(
+                feature = "rafx-empty",
+                not(any(
+                    feature = "rafx-dx12",
+                    feature = "rafx-metal",
+                    feature = "rafx-vulkan",
+                    feature = "rafx-gles2",
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxSemaphore::Empty(_) => None,
@@ -207,7 +260,8 @@ impl RafxSemaphore {
             feature = "rafx-metal",
             feature = "rafx-vulkan",
             feature = "rafx-gles2",
-            feature = "rafx-gles3"
+            feature = "rafx-gles3",
+            feature = "rafx-webgpu"
         ))
     ))]
     pub fn empty_semaphore(&self) -> Option<&RafxSemaphoreEmpty> {
@@ -222,6 +276,8 @@ impl RafxSemaphore {
             RafxSemaphore::Gles2(_) => None,
             #[cfg(feature = "rafx-gles3")]
             RafxSemaphore::Gles3(_) => None,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxSemaphore::WebGpu(_) => None,
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -229,7 +285,8 @@ impl RafxSemaphore {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxSemaphore::Empty(inner) => Some(inner),
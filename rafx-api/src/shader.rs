@@ -7,7 +7,8 @@ use crate::dx12::RafxShaderDx12;
         feature = "rafx-metal",
         feature = "rafx-vulkan",
         feature = "rafx-gles2",
-        feature = "rafx-gles3"
+        feature = "rafx-gles3",
+        feature = "rafx-webgpu"
     ))
 ))]
 use crate::empty::RafxShaderEmpty;
@@ -19,6 +20,8 @@ use crate::gles3::RafxShaderGles3;
 use crate::metal::RafxShaderMetal;
 #[cfg(feature = "rafx-vulkan")]
 use crate::vulkan::RafxShaderVulkan;
+#[cfg(feature = "rafx-webgpu")]
+use crate::webgpu::RafxShaderWebGpu;
 use crate::RafxPipelineReflection;
 
 /// Represents one or more shader stages, producing an entire "program" to execute on the GPU
@@ -34,6 +37,8 @@ pub enum RafxShader {
     Gles2(RafxShaderGles2),
     #[cfg(feature = "rafx-gles3")]
     Gles3(RafxShaderGles3),
+    #[cfg(feature = "rafx-webgpu")]
+    WebGpu(RafxShaderWebGpu),
     #[cfg(any(
         feature = "rafx-empty",
         not(any(
@@ -41,7 +46,8 @@ pub enum RafxShader {
             feature = "rafx-metal",
             feature = "rafx-vulkan",
             feature = "rafx-gles2",
-            feature = "rafx-gles3"
+            feature = "rafx-gles3",
+            feature = "rafx-webgpu"
         ))
     ))]
     Empty(RafxShaderEmpty),
@@ -60,6 +66,8 @@ impl RafxShader {
             RafxShader::Gles2(inner) => inner.pipeline_reflection(),
             #[cfg(feature = "rafx-gles3")]
             RafxShader::Gles3(inner) => inner.pipeline_reflection(),
+            #[cfg(feature = "rafx-webgpu")]
+            RafxShader::WebGpu(inner) => inner.pipeline_reflection(),
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -67,7 +75,8 @@ impl RafxShader {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxShader::Empty(inner) => inner.pipeline_reflection(),
@@ -89,6 +98,8 @@ impl RafxShader {
             RafxShader::Gles2(_) => None,
             #[cfg(feature = "rafx-gles3")]
             RafxShader::Gles3(_) => None,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxShader::WebGpu(_) => None,
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -96,7 +107,8 @@ impl RafxShader {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxShader::Empty(_) => None,
@@ -118,6 +130,8 @@ impl RafxShader {
             RafxShader::Gles2(_) => None,
             #[cfg(feature = "rafx-gles3")]
             RafxShader::Gles3(_) => None,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxShader::WebGpu(_) => None,
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -125,7 +139,8 @@ impl RafxShader {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxShader::Empty(_) => None,
@@ -147,6 +162,8 @@ impl RafxShader {
             RafxShader::Gles2(_) => None,
             #[cfg(feature = "rafx-gles3")]
             RafxShader::Gles3(_) => None,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxShader::WebGpu(_) => None,
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -154,7 +171,8 @@ impl RafxShader {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxShader::Empty(_) => None,
@@ -176,6 +194,8 @@ impl RafxShader {
             RafxShader::Gles2(inner) => Some(inner),
             #[cfg(feature = "rafx-gles3")]
             RafxShader::Gles3(_) => None,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxShader::WebGpu(_) => None,
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -183,7 +203,8 @@ impl RafxShader {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxShader::Empty(_) => None,
@@ -205,6 +226,8 @@ impl RafxShader {
             RafxShader::Gles2(_) => None,
             #[cfg(feature = "rafx-gles3")]
             RafxShader::Gles3(inner) => Some(inner),
+            #[cfg(feature = "rafx-webgpu")]
+            RafxShader::WebGpu(_) => None,
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -212,7 +235,40 @@ impl RafxShader {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
+                ))
+            ))]
+            RafxShader::Empty(_) => None,
+        }
+    }
+
+    /// Get the underlying webgpu API object. This provides access to any internally created
+    /// wgpu objects.
+    #[cfg(feature = "rafx-webgpu")]
+    pub fn webgpu_shader(&self) -> Option<&RafxShaderWebGpu> {
+        match self {
+            #[cfg(feature = "rafx-dx12")]
+            RafxShader::Dx12(_) => None,
+            #[cfg(feature = "rafx-vulkan")]
+            RafxShader::Vk(_) => None,
+            #[cfg(feature = "rafx-metal")]
+            RafxShader::Metal(_) => None,
+            #[cfg(feature = "rafx-gles2")]
+            RafxShader::Gles2(_) => None,
+            #[cfg(feature = "rafx-gles3")]
+            RafxShader::Gles3(_) => None,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxShader::WebGpu(inner) => Some(inner),
+            #[cfg(any(
+                feature = "rafx-empty",
+                not(any(
+                    feature = "rafx-dx12",
+                    feature = "rafx-metal",
+                    feature = "rafx-vulkan",
+                    feature = "rafx-gles2",
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxShader::Empty(_) => None,
@@ -232,6 +288,8 @@ impl RafxShader {
             RafxShader::Gles2(_) => None,
             #[cfg(feature = "rafx-gles3")]
             RafxShader::Gles3(_) => None,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxShader::WebGpu(_) => None,
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -239,7 +297,8 @@ impl RafxShader {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxShader::Empty(inner) => Some(inner),
@@ -7,7 +7,8 @@ use crate::dx12::RafxShaderModuleDx12;
         feature = "rafx-metal",
         feature = "rafx-vulkan",
         feature = "rafx-gles2",
-        feature = "rafx-gles3"
+        feature = "rafx-gles3",
+        feature = "rafx-webgpu"
     ))
 ))]
 use crate::empty::RafxShaderModuleEmpty;
@@ -19,6 +20,8 @@ use crate::gles3::RafxShaderModuleGles3;
 use crate::metal::RafxShaderModuleMetal;
 #[cfg(feature = "rafx-vulkan")]
 use crate::vulkan::RafxShaderModuleVulkan;
+#[cfg(feature = "rafx-webgpu")]
+use crate::webgpu::RafxShaderModuleWebGpu;
 
 /// Rrepresents loaded shader code that can be used to create a pipeline.
 ///
@@ -37,6 +40,8 @@ pub enum RafxShaderModule {
     Gles2(RafxShaderModuleGles2),
     #[cfg(feature = "rafx-gles3")]
     Gles3(RafxShaderModuleGles3),
+    #[cfg(feature = "rafx-webgpu")]
+    WebGpu(RafxShaderModuleWebGpu),
     #[cfg(any(
         feature = "rafx-empty",
         not(any(
@@ -44,7 +49,8 @@ pub enum RafxShaderModule {
             feature = "rafx-metal",
             feature = "rafx-vulkan",
             feature = "rafx-gles2",
-            feature = "rafx-gles3"
+            feature = "rafx-gles3",
+            feature = "rafx-webgpu"
         ))
     ))]
     Empty(RafxShaderModuleEmpty),
@@ -66,6 +72,8 @@ impl RafxShaderModule {
             RafxShaderModule::Gles2(_) => None,
             #[cfg(feature = "rafx-gles3")]
             RafxShaderModule::Gles3(_) => None,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxShaderModule::WebGpu(_) => None,
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -73,7 +81,8 @@ impl RafxShaderModule {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxShaderModule::Empty(_) => None,
@@ -95,6 +104,8 @@ impl RafxShaderModule {
             RafxShaderModule::Gles2(_) => None,
             #[cfg(feature = "rafx-gles3")]
             RafxShaderModule::Gles3(_) => None,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxShaderModule::WebGpu(_) => None,
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -102,7 +113,8 @@ impl RafxShaderModule {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxShaderModule::Empty(_) => None,
@@ -124,6 +136,8 @@ impl RafxShaderModule {
             RafxShaderModule::Gles2(_) => None,
             #[cfg(feature = "rafx-gles3")]
             RafxShaderModule::Gles3(_) => None,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxShaderModule::WebGpu(_) => None,
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -131,7 +145,8 @@ impl RafxShaderModule {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxShaderModule::Empty(_) => None,
@@ -153,6 +168,8 @@ impl RafxShaderModule {
             RafxShaderModule::Gles2(inner) => Some(inner),
             #[cfg(feature = "rafx-gles3")]
             RafxShaderModule::Gles3(_) => None,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxShaderModule::WebGpu(_) => None,
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -160,7 +177,8 @@ impl RafxShaderModule {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxShaderModule::Empty(_) => None,
@@ -182,6 +200,8 @@ impl RafxShaderModule {
             RafxShaderModule::Gles2(_) => None,
             #[cfg(feature = "rafx-gles3")]
             RafxShaderModule::Gles3(inner) => Some(inner),
+            #[cfg(feature = "rafx-webgpu")]
+            RafxShaderModule::WebGpu(_) => None,
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -189,7 +209,40 @@ impl RafxShaderModule {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
+                ))
+            ))]
+            RafxShaderModule::Empty(_) => None,
+        }
+    }
+
+    /// Get the underlying webgpu API object. This provides access to any internally created
+    /// wgpu objects.
+    #[cfg(feature = "rafx-webgpu")]
+    pub fn webgpu_shader_module(&self) -> Option<&RafxShaderModuleWebGpu> {
+        match self {
+            #[cfg(feature = "rafx-dx12")]
+            RafxShaderModule::Dx12(_) => None,
+            #[cfg(feature = "rafx-vulkan")]
+            RafxShaderModule::Vk(_) => None,
+            #[cfg(feature = "rafx-metal")]
+            RafxShaderModule::Metal(_) => None,
+            #[cfg(feature = "rafx-gles2")]
+            RafxShaderModule::Gles2(_) => None,
+            #[cfg(feature = "rafx-gles3")]
+            RafxShaderModule::Gles3(_) => None,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxShaderModule::WebGpu(inner) => Some(inner),
+            #[cfg(any(
+                feature = "rafx-empty",
+                not(any(
+                    feature = "rafx-dx12",
+                    feature = "rafx-metal",
+                    feature = "rafx-vulkan",
+                    feature = "rafx-gles2",
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxShaderModule::Empty(_) => None,
@@ -205,7 +258,8 @@ impl RafxShaderModule {
             feature = "rafx-metal",
             feature = "rafx-vulkan",
             feature = "rafx-gles2",
-            feature = "rafx-gles3"
+            feature = "rafx-gles3",
+            feature = "rafx-webgpu"
         ))
     ))]
     pub fn empty_shader_module(&self) -> Option<&RafxShaderModuleEmpty> {
@@ -220,6 +274,8 @@ impl RafxShaderModule {
             RafxShaderModule::Gles2(_) => None,
             #[cfg(feature = "rafx-gles3")]
             RafxShaderModule::Gles3(_) => None,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxShaderModule::WebGpu(_) => None,
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -227,7 +283,8 @@ impl RafxShaderModule {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxShaderModule::Empty(inner) => Some(inner),
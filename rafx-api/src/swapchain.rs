@@ -7,7 +7,8 @@ use crate::dx12::RafxSwapchainDx12;
         feature = "rafx-metal",
         feature = "rafx-vulkan",
         feature = "rafx-gles2",
-        feature = "rafx-gles3"
+        feature = "rafx-gles3",
+        feature = "rafx-webgpu"
     ))
 ))]
 use crate::empty::RafxSwapchainEmpty;
@@ -19,9 +20,11 @@ use crate::gles3::RafxSwapchainGles3;
 use crate::metal::RafxSwapchainMetal;
 #[cfg(feature = "rafx-vulkan")]
 use crate::vulkan::RafxSwapchainVulkan;
+#[cfg(feature = "rafx-webgpu")]
+use crate::webgpu::RafxSwapchainWebGpu;
 use crate::{
     RafxFence, RafxFormat, RafxResult, RafxSemaphore, RafxSwapchainColorSpace, RafxSwapchainDef,
-    RafxSwapchainImage,
+    RafxSwapchainDisplayLuminance, RafxSwapchainImage,
 };
 
 /// A set of images that act as a "backbuffer" of a window.
@@ -36,6 +39,8 @@ pub enum RafxSwapchain {
     Gles2(RafxSwapchainGles2),
     #[cfg(feature = "rafx-gles3")]
     Gles3(RafxSwapchainGles3),
+    #[cfg(feature = "rafx-webgpu")]
+    WebGpu(RafxSwapchainWebGpu),
     #[cfg(any(
         feature = "rafx-empty",
         not(any(
@@ -43,7 +48,8 @@ pub enum RafxSwapchain {
             feature = "rafx-metal",
             feature = "rafx-vulkan",
             feature = "rafx-gles2",
-            feature = "rafx-gles3"
+            feature = "rafx-gles3",
+            feature = "rafx-webgpu"
         ))
     ))]
     Empty(RafxSwapchainEmpty),
@@ -65,6 +71,8 @@ impl RafxSwapchain {
             RafxSwapchain::Gles2(inner) => inner.image_count(),
             #[cfg(feature = "rafx-gles3")]
             RafxSwapchain::Gles3(inner) => inner.image_count(),
+            #[cfg(feature = "rafx-webgpu")]
+            RafxSwapchain::WebGpu(inner) => inner.image_count(),
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -72,7 +80,8 @@ impl RafxSwapchain {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxSwapchain::Empty(inner) => inner.image_count(),
@@ -92,6 +101,8 @@ impl RafxSwapchain {
             RafxSwapchain::Gles2(inner) => inner.format(),
             #[cfg(feature = "rafx-gles3")]
             RafxSwapchain::Gles3(inner) => inner.format(),
+            #[cfg(feature = "rafx-webgpu")]
+            RafxSwapchain::WebGpu(inner) => inner.format(),
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -99,7 +110,8 @@ impl RafxSwapchain {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxSwapchain::Empty(inner) => inner.format(),
@@ -119,6 +131,8 @@ impl RafxSwapchain {
             RafxSwapchain::Gles2(inner) => inner.color_space(),
             #[cfg(feature = "rafx-gles3")]
             RafxSwapchain::Gles3(inner) => inner.color_space(),
+            #[cfg(feature = "rafx-webgpu")]
+            RafxSwapchain::WebGpu(inner) => inner.color_space(),
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -126,13 +140,45 @@ impl RafxSwapchain {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxSwapchain::Empty(inner) => inner.color_space(),
         }
     }
 
+    /// Get the luminance range of the display the swapchain presents to. Only the DX12 backend can
+    /// query it (on windows 10 1703+), all other backends return None
+    pub fn display_luminance(&self) -> Option<RafxSwapchainDisplayLuminance> {
+        match self {
+            #[cfg(feature = "rafx-dx12")]
+            RafxSwapchain::Dx12(inner) => inner.display_luminance(),
+            #[cfg(feature = "rafx-vulkan")]
+            RafxSwapchain::Vk(inner) => inner.display_luminance(),
+            #[cfg(feature = "rafx-metal")]
+            RafxSwapchain::Metal(inner) => inner.display_luminance(),
+            #[cfg(feature = "rafx-gles2")]
+            RafxSwapchain::Gles2(inner) => inner.display_luminance(),
+            #[cfg(feature = "rafx-gles3")]
+            RafxSwapchain::Gles3(inner) => inner.display_luminance(),
+            #[cfg(feature = "rafx-webgpu")]
+            RafxSwapchain::WebGpu(inner) => inner.display_luminance(),
+            #[cfg(any(
+                feature = "rafx-empty",
+                not(any(
+                    feature = "rafx-dx12",
+                    feature = "rafx-metal",
+                    feature = "rafx-vulkan",
+                    feature = "rafx-gles2",
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
+                ))
+            ))]
+            RafxSwapchain::Empty(inner) => inner.display_luminance(),
+        }
+    }
+
     /// Return the metadata used to create the swapchain
     pub fn swapchain_def(&self) -> &RafxSwapchainDef {
         match self {
@@ -146,6 +192,8 @@ impl RafxSwapchain {
             RafxSwapchain::Gles2(inner) => inner.swapchain_def(),
             #[cfg(feature = "rafx-gles3")]
             RafxSwapchain::Gles3(inner) => inner.swapchain_def(),
+            #[cfg(feature = "rafx-webgpu")]
+            RafxSwapchain::WebGpu(inner) => inner.swapchain_def(),
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -153,7 +201,8 @@ impl RafxSwapchain {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxSwapchain::Empty(inner) => inner.swapchain_def(),
@@ -186,6 +235,10 @@ impl RafxSwapchain {
             RafxSwapchain::Gles3(inner) => {
                 inner.acquire_next_image_fence(fence.gles3_fence().unwrap())
             }
+            #[cfg(feature = "rafx-webgpu")]
+            RafxSwapchain::WebGpu(inner) => {
+                inner.acquire_next_image_fence(fence.webgpu_fence().unwrap())
+            }
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -193,7 +246,8 @@ impl RafxSwapchain {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxSwapchain::Empty(inner) => {
@@ -230,6 +284,10 @@ impl RafxSwapchain {
             RafxSwapchain::Gles3(inner) => {
                 inner.acquire_next_image_semaphore(semaphore.gles3_semaphore().unwrap())
             }
+            #[cfg(feature = "rafx-webgpu")]
+            RafxSwapchain::WebGpu(inner) => {
+                inner.acquire_next_image_semaphore(semaphore.webgpu_semaphore().unwrap())
+            }
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -237,7 +295,8 @@ impl RafxSwapchain {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxSwapchain::Empty(inner) => {
@@ -262,6 +321,8 @@ impl RafxSwapchain {
             RafxSwapchain::Gles2(inner) => inner.rebuild(swapchain_def),
             #[cfg(feature = "rafx-gles3")]
             RafxSwapchain::Gles3(inner) => inner.rebuild(swapchain_def),
+            #[cfg(feature = "rafx-webgpu")]
+            RafxSwapchain::WebGpu(inner) => inner.rebuild(swapchain_def),
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -269,7 +330,8 @@ impl RafxSwapchain {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxSwapchain::Empty(inner) => inner.rebuild(swapchain_def),
@@ -291,6 +353,8 @@ impl RafxSwapchain {
             RafxSwapchain::Gles2(_) => None,
             #[cfg(feature = "rafx-gles3")]
             RafxSwapchain::Gles3(_) => None,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxSwapchain::WebGpu(_) => None,
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -298,7 +362,8 @@ impl RafxSwapchain {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxSwapchain::Empty(_) => None,
@@ -320,6 +385,8 @@ impl RafxSwapchain {
             RafxSwapchain::Gles2(_) => None,
             #[cfg(feature = "rafx-gles3")]
             RafxSwapchain::Gles3(_) => None,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxSwapchain::WebGpu(_) => None,
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -327,7 +394,8 @@ impl RafxSwapchain {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxSwapchain::Empty(_) => None,
@@ -349,6 +417,8 @@ impl RafxSwapchain {
             RafxSwapchain::Gles2(_) => None,
             #[cfg(feature = "rafx-gles3")]
             RafxSwapchain::Gles3(_) => None,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxSwapchain::WebGpu(_) => None,
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -356,7 +426,8 @@ impl RafxSwapchain {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxSwapchain::Empty(_) => None,
@@ -378,6 +449,8 @@ impl RafxSwapchain {
             RafxSwapchain::Gles2(inner) => Some(inner),
             #[cfg(feature = "rafx-gles3")]
             RafxSwapchain::Gles3(_) => None,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxSwapchain::WebGpu(_) => None,
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -385,7 +458,8 @@ impl RafxSwapchain {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxSwapchain::Empty(_) => None,
@@ -407,6 +481,40 @@ impl RafxSwapchain {
             RafxSwapchain::Gles2(_) => None,
             #[cfg(feature = "rafx-gles3")]
             RafxSwapchain::Gles3(inner) => Some(inner),
+            #[cfg(feature = "rafx-webgpu")]
+            RafxSwapchain::WebGpu(_) => None,
+            #[cfg(any(
+                feature = "rafx-empty",
+                not(any(
+                    feature = "rafx-dx12",
+                    feature = "rafx-metal",
+                    feature = "rafx-vulkan",
+                    feature = "rafx-gles2",
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
+                ))
+            ))]
+            RafxSwapchain::Empty(_) => None,
+        }
+    }
+
+    /// Get the underlying webgpu API object. This provides access to any internally created
+    /// wgpu objects.
+    #[cfg(feature = "rafx-webgpu")]
+    pub fn webgpu_swapchain(&self) -> Option<&RafxSwapchainWebGpu> {
+        match self {
+            #[cfg(feature = "rafx-dx12")]
+            RafxSwapchain::Dx12(_) => None,
+            #[cfg(feature = "rafx-vulkan")]
+            RafxSwapchain::Vk(_) => None,
+            #[cfg(feature = "rafx-metal")]
+            RafxSwapchain::Metal(_) => None,
+            #[cfg(feature = "rafx-gles2")]
+            RafxSwapchain::Gles2(_) => None,
+            #[cfg(feature = "rafx-gles3")]
+            RafxSwapchain::Gles3(_) => None,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxSwapchain::WebGpu(inner) => Some(inner),
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -414,7 +522,8 @@ impl RafxSwapchain {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxSwapchain::Empty(_) => None,
@@ -430,7 +539,8 @@ impl RafxSwapchain {
             feature = "rafx-metal",
             feature = "rafx-vulkan",
             feature = "rafx-gles2",
-            feature = "rafx-gles3"
+            feature = "rafx-gles3",
+            feature = "rafx-webgpu"
         ))
     ))]
     pub fn empty_swapchain(&self) -> Option<&RafxSwapchainEmpty> {
@@ -445,6 +555,8 @@ impl RafxSwapchain {
             RafxSwapchain::Gles2(_) => None,
             #[cfg(feature = "rafx-gles3")]
             RafxSwapchain::Gles3(_) => None,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxSwapchain::WebGpu(_) => None,
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -452,7 +564,8 @@ impl RafxSwapchain {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxSwapchain::Empty(inner) => Some(inner),
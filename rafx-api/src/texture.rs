@@ -7,7 +7,8 @@ use crate::dx12::RafxTextureDx12;
         feature = "rafx-metal",
         feature = "rafx-vulkan",
         feature = "rafx-gles2",
-        feature = "rafx-gles3"
+        feature = "rafx-gles3",
+        feature = "rafx-webgpu"
     ))
 ))]
 use crate::empty::RafxTextureEmpty;
@@ -19,6 +20,8 @@ use crate::gles3::RafxTextureGles3;
 use crate::metal::RafxTextureMetal;
 #[cfg(feature = "rafx-vulkan")]
 use crate::vulkan::RafxTextureVulkan;
+#[cfg(feature = "rafx-webgpu")]
+use crate::webgpu::RafxTextureWebGpu;
 use crate::RafxTextureDef;
 
 /// An image that can be used by the GPU.
@@ -36,6 +39,8 @@ pub enum RafxTexture {
     Gles2(RafxTextureGles2),
     #[cfg(feature = "rafx-gles3")]
     Gles3(RafxTextureGles3),
+    #[cfg(feature = "rafx-webgpu")]
+    WebGpu(RafxTextureWebGpu),
     #[cfg(any(
         feature = "rafx-empty",
         not(any(
@@ -43,7 +48,8 @@ pub enum RafxTexture {
             feature = "rafx-metal",
             feature = "rafx-vulkan",
             feature = "rafx-gles2",
-            feature = "rafx-gles3"
+            feature = "rafx-gles3",
+            feature = "rafx-webgpu"
         ))
     ))]
     Empty(RafxTextureEmpty),
@@ -63,6 +69,8 @@ impl RafxTexture {
             RafxTexture::Gles2(inner) => inner.texture_def(),
             #[cfg(feature = "rafx-gles3")]
             RafxTexture::Gles3(inner) => inner.texture_def(),
+            #[cfg(feature = "rafx-webgpu")]
+            RafxTexture::WebGpu(inner) => inner.texture_def(),
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -70,7 +78,8 @@ impl RafxTexture {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxTexture::Empty(inner) => inner.texture_def(),
@@ -94,6 +103,8 @@ impl RafxTexture {
             RafxTexture::Gles2(_) => {}
             #[cfg(feature = "rafx-gles3")]
             RafxTexture::Gles3(_) => {}
+            #[cfg(feature = "rafx-webgpu")]
+            RafxTexture::WebGpu(_) => {}
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -101,7 +112,8 @@ impl RafxTexture {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxTexture::Empty(inner) => inner.set_debug_name(_name),
@@ -123,6 +135,8 @@ impl RafxTexture {
             RafxTexture::Gles2(_) => None,
             #[cfg(feature = "rafx-gles3")]
             RafxTexture::Gles3(_) => None,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxTexture::WebGpu(_) => None,
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -130,7 +144,8 @@ impl RafxTexture {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxTexture::Empty(_) => None,
@@ -152,6 +167,8 @@ impl RafxTexture {
             RafxTexture::Gles2(_) => None,
             #[cfg(feature = "rafx-gles3")]
             RafxTexture::Gles3(_) => None,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxTexture::WebGpu(_) => None,
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -159,7 +176,8 @@ impl RafxTexture {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxTexture::Empty(_) => None,
@@ -181,6 +199,8 @@ impl RafxTexture {
             RafxTexture::Gles2(_) => None,
             #[cfg(feature = "rafx-gles3")]
             RafxTexture::Gles3(_) => None,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxTexture::WebGpu(_) => None,
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -188,7 +208,8 @@ impl RafxTexture {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxTexture::Empty(_) => None,
@@ -210,6 +231,8 @@ impl RafxTexture {
             RafxTexture::Gles2(inner) => Some(inner),
             #[cfg(feature = "rafx-gles3")]
             RafxTexture::Gles3(_) => None,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxTexture::WebGpu(_) => None,
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -217,7 +240,8 @@ impl RafxTexture {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxTexture::Empty(_) => None,
@@ -239,6 +263,8 @@ impl RafxTexture {
             RafxTexture::Gles2(_) => None,
             #[cfg(feature = "rafx-gles3")]
             RafxTexture::Gles3(inner) => Some(inner),
+            #[cfg(feature = "rafx-webgpu")]
+            RafxTexture::WebGpu(_) => None,
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -246,7 +272,40 @@ impl RafxTexture {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
+                ))
+            ))]
+            RafxTexture::Empty(_) => None,
+        }
+    }
+
+    /// Get the underlying webgpu API object. This provides access to any internally created
+    /// wgpu objects.
+    #[cfg(feature = "rafx-webgpu")]
+    pub fn webgpu_texture(&self) -> Option<&RafxTextureWebGpu> {
+        match self {
+            #[cfg(feature = "rafx-dx12")]
+            RafxTexture::Dx12(_) => None,
+            #[cfg(feature = "rafx-vulkan")]
+            RafxTexture::Vk(_) => None,
+            #[cfg(feature = "rafx-metal")]
+            RafxTexture::Metal(_) => None,
+            #[cfg(feature = "rafx-gles2")]
+            RafxTexture::Gles2(_) => None,
+            #[cfg(feature = "rafx-gles3")]
+            RafxTexture::Gles3(_) => None,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxTexture::WebGpu(inner) => Some(inner),
+            #[cfg(any(
+                feature = "rafx-empty",
+                not(any(
+                    feature = "rafx-dx12",
+                    feature = "rafx-metal",
+                    feature = "rafx-vulkan",
+                    feature = "rafx-gles2",
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxTexture::Empty(_) => None,
@@ -262,7 +321,8 @@ impl RafxTexture {
             feature = "rafx-metal",
             feature = "rafx-vulkan",
             feature = "rafx-gles2",
-            feature = "rafx-gles3"
+            feature = "rafx-gles3",
+            feature = "rafx-webgpu"
         ))
     ))]
     pub fn empty_texture(&self) -> Option<&RafxTextureEmpty> {
@@ -277,6 +337,8 @@ impl RafxTexture {
             RafxTexture::Gles2(_) => None,
             #[cfg(feature = "rafx-gles3")]
             RafxTexture::Gles3(_) => None,
+            #[cfg(feature = "rafx-webgpu")]
+            RafxTexture::WebGpu(_) => None,
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -284,7 +346,8 @@ impl RafxTexture {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             RafxTexture::Empty(inner) => Some(inner),
@@ -21,6 +21,8 @@ pub struct RafxApiDef {
     pub gles2_options: Option<crate::RafxApiDefGles2>,
     #[cfg(feature = "rafx-gles3")]
     pub gles3_options: Option<crate::RafxApiDefGles3>,
+    #[cfg(feature = "rafx-webgpu")]
+    pub webgpu_options: Option<crate::RafxApiDefWebGpu>,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -655,6 +657,76 @@ impl RafxBlendState {
     }
 }
 
+/// The value of a specialization constant. All specialization constants are 32 bits.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Serialize, Deserialize))]
+pub enum RafxSpecializationConstantValue {
+    Bool(bool),
+    Int(i32),
+    UInt(u32),
+    Float(f32),
+    // Hash implemented manually below, don't forget to update it!
+}
+
+impl Eq for RafxSpecializationConstantValue {}
+
+impl Hash for RafxSpecializationConstantValue {
+    fn hash<H: Hasher>(
+        &self,
+        mut state: &mut H,
+    ) {
+        std::mem::discriminant(self).hash(&mut state);
+        match self {
+            RafxSpecializationConstantValue::Bool(x) => x.hash(&mut state),
+            RafxSpecializationConstantValue::Int(x) => x.hash(&mut state),
+            RafxSpecializationConstantValue::UInt(x) => x.hash(&mut state),
+            RafxSpecializationConstantValue::Float(x) => DecimalF32(*x).hash(&mut state),
+        }
+    }
+}
+
+impl RafxSpecializationConstantValue {
+    /// The raw 32 bits of the value, as the shader would see them (bools are 0 or 1)
+    pub fn to_bits(self) -> u32 {
+        match self {
+            RafxSpecializationConstantValue::Bool(x) => x as u32,
+            RafxSpecializationConstantValue::Int(x) => x as u32,
+            RafxSpecializationConstantValue::UInt(x) => x,
+            RafxSpecializationConstantValue::Float(x) => x.to_bits(),
+        }
+    }
+
+    /// Returns true if both values are the same type (i.e. one can override the other)
+    pub fn is_same_type(
+        &self,
+        other: &RafxSpecializationConstantValue,
+    ) -> bool {
+        std::mem::discriminant(self) == std::mem::discriminant(other)
+    }
+
+    /// Formats the value as a literal that can be used in GLSL, HLSL, or MSL source code. Backends
+    /// that can't specialize a compiled shader use this to define the constant before compiling.
+    pub fn to_source_literal(self) -> String {
+        match self {
+            RafxSpecializationConstantValue::Bool(x) => x.to_string(),
+            RafxSpecializationConstantValue::Int(x) => x.to_string(),
+            RafxSpecializationConstantValue::UInt(x) => format!("{}u", x),
+            // {:?} always includes a decimal point or exponent, so the literal is never parsed as
+            // an integer
+            RafxSpecializationConstantValue::Float(x) => format!("{:?}", x),
+        }
+    }
+}
+
+/// Overrides the default value of a specialization constant declared in a shader, i.e.
+/// `layout(constant_id = 0) const int SAMPLE_COUNT = 4;`
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde-support", derive(Serialize, Deserialize))]
+pub struct RafxSpecializationConstant {
+    pub constant_id: u32,
+    pub value: RafxSpecializationConstantValue,
+}
+
 /// Used to create a `RafxPipeline` for graphics operations
 #[derive(Debug)]
 pub struct RafxGraphicsPipelineDef<'a> {
@@ -668,6 +740,9 @@ pub struct RafxGraphicsPipelineDef<'a> {
     pub color_formats: &'a [RafxFormat],
     pub depth_stencil_format: Option<RafxFormat>,
     pub sample_count: RafxSampleCount,
+    /// Overrides for specialization constants declared by the shader. Constants that are not
+    /// listed keep the default value they were declared with.
+    pub specialization_constants: &'a [RafxSpecializationConstant],
     pub debug_name: Option<&'a str>,
 }
 
@@ -676,6 +751,9 @@ pub struct RafxGraphicsPipelineDef<'a> {
 pub struct RafxComputePipelineDef<'a> {
     pub shader: &'a RafxShader,
     pub root_signature: &'a RafxRootSignature,
+    /// Overrides for specialization constants declared by the shader. Constants that are not
+    /// listed keep the default value they were declared with.
+    pub specialization_constants: &'a [RafxSpecializationConstant],
     pub debug_name: Option<&'a str>,
 }
 
@@ -1049,13 +1049,13 @@ impl Into<MTLPixelFormat> for RafxFormat {
             // RafxFormat::A8B8G8R8_UINT_PACK32 => MTLPixelFormat::A8B8G8R8_UINT_PACK32,
             // RafxFormat::A8B8G8R8_SINT_PACK32 => MTLPixelFormat::A8B8G8R8_SINT_PACK32,
             // RafxFormat::A8B8G8R8_SRGB_PACK32 => MTLPixelFormat::A8B8G8R8_SRGB_PACK32,
-            // RafxFormat::A2R10G10B10_UNORM_PACK32 => MTLPixelFormat::A2R10G10B10_UNORM_PACK32,
+            RafxFormat::A2R10G10B10_UNORM_PACK32 => MTLPixelFormat::BGR10A2Unorm,
             // RafxFormat::A2R10G10B10_SNORM_PACK32 => MTLPixelFormat::A2R10G10B10_SNORM_PACK32,
             // RafxFormat::A2R10G10B10_USCALED_PACK32 => MTLPixelFormat::A2R10G10B10_USCALED_PACK32,
             // RafxFormat::A2R10G10B10_SSCALED_PACK32 => MTLPixelFormat::A2R10G10B10_SSCALED_PACK32,
             // RafxFormat::A2R10G10B10_UINT_PACK32 => MTLPixelFormat::A2R10G10B10_UINT_PACK32,
             // RafxFormat::A2R10G10B10_SINT_PACK32 => MTLPixelFormat::A2R10G10B10_SINT_PACK32,
-            // RafxFormat::A2B10G10R10_UNORM_PACK32 => MTLPixelFormat::A2B10G10R10_UNORM_PACK32,
+            RafxFormat::A2B10G10R10_UNORM_PACK32 => MTLPixelFormat::RGB10A2Unorm,
             // RafxFormat::A2B10G10R10_SNORM_PACK32 => MTLPixelFormat::A2B10G10R10_SNORM_PACK32,
             // RafxFormat::A2B10G10R10_USCALED_PACK32 => MTLPixelFormat::A2B10G10R10_USCALED_PACK32,
             // RafxFormat::A2B10G10R10_SSCALED_PACK32 => MTLPixelFormat::A2B10G10R10_SSCALED_PACK32,
@@ -2042,6 +2042,158 @@ impl RafxFormat {
     }
 }
 
+#[rustfmt::skip]
+#[cfg(feature = "rafx-webgpu")]
+impl RafxFormat {
+    pub fn webgpu_texture_format(self) -> Option<wgpu::TextureFormat> {
+        use wgpu::TextureFormat as F;
+        match self {
+            RafxFormat::R8_UNORM => Some(F::R8Unorm),
+            RafxFormat::R8_SNORM => Some(F::R8Snorm),
+            RafxFormat::R8_UINT => Some(F::R8Uint),
+            RafxFormat::R8_SINT => Some(F::R8Sint),
+            RafxFormat::R8G8_UNORM => Some(F::Rg8Unorm),
+            RafxFormat::R8G8_SNORM => Some(F::Rg8Snorm),
+            RafxFormat::R8G8_UINT => Some(F::Rg8Uint),
+            RafxFormat::R8G8_SINT => Some(F::Rg8Sint),
+            RafxFormat::R8G8B8A8_UNORM => Some(F::Rgba8Unorm),
+            RafxFormat::R8G8B8A8_SNORM => Some(F::Rgba8Snorm),
+            RafxFormat::R8G8B8A8_UINT => Some(F::Rgba8Uint),
+            RafxFormat::R8G8B8A8_SINT => Some(F::Rgba8Sint),
+            RafxFormat::R8G8B8A8_SRGB => Some(F::Rgba8UnormSrgb),
+            RafxFormat::B8G8R8A8_UNORM => Some(F::Bgra8Unorm),
+            RafxFormat::B8G8R8A8_SRGB => Some(F::Bgra8UnormSrgb),
+            RafxFormat::A2B10G10R10_UNORM_PACK32 => Some(F::Rgb10a2Unorm),
+            RafxFormat::R16_UNORM => Some(F::R16Unorm),
+            RafxFormat::R16_SNORM => Some(F::R16Snorm),
+            RafxFormat::R16_UINT => Some(F::R16Uint),
+            RafxFormat::R16_SINT => Some(F::R16Sint),
+            RafxFormat::R16_SFLOAT => Some(F::R16Float),
+            RafxFormat::R16G16_UNORM => Some(F::Rg16Unorm),
+            RafxFormat::R16G16_SNORM => Some(F::Rg16Snorm),
+            RafxFormat::R16G16_UINT => Some(F::Rg16Uint),
+            RafxFormat::R16G16_SINT => Some(F::Rg16Sint),
+            RafxFormat::R16G16_SFLOAT => Some(F::Rg16Float),
+            RafxFormat::R16G16B16A16_UNORM => Some(F::Rgba16Unorm),
+            RafxFormat::R16G16B16A16_SNORM => Some(F::Rgba16Snorm),
+            RafxFormat::R16G16B16A16_UINT => Some(F::Rgba16Uint),
+            RafxFormat::R16G16B16A16_SINT => Some(F::Rgba16Sint),
+            RafxFormat::R16G16B16A16_SFLOAT => Some(F::Rgba16Float),
+            RafxFormat::R32_UINT => Some(F::R32Uint),
+            RafxFormat::R32_SINT => Some(F::R32Sint),
+            RafxFormat::R32_SFLOAT => Some(F::R32Float),
+            RafxFormat::R32G32_UINT => Some(F::Rg32Uint),
+            RafxFormat::R32G32_SINT => Some(F::Rg32Sint),
+            RafxFormat::R32G32_SFLOAT => Some(F::Rg32Float),
+            RafxFormat::R32G32B32A32_UINT => Some(F::Rgba32Uint),
+            RafxFormat::R32G32B32A32_SINT => Some(F::Rgba32Sint),
+            RafxFormat::R32G32B32A32_SFLOAT => Some(F::Rgba32Float),
+            RafxFormat::B10G11R11_UFLOAT_PACK32 => Some(F::Rg11b10Float),
+            RafxFormat::E5B9G9R9_UFLOAT_PACK32 => Some(F::Rgb9e5Ufloat),
+            RafxFormat::D16_UNORM => Some(F::Depth16Unorm),
+            RafxFormat::X8_D24_UNORM_PACK32 => Some(F::Depth24Plus),
+            RafxFormat::D32_SFLOAT => Some(F::Depth32Float),
+            RafxFormat::S8_UINT => Some(F::Stencil8),
+            RafxFormat::D24_UNORM_S8_UINT => Some(F::Depth24PlusStencil8),
+            RafxFormat::D32_SFLOAT_S8_UINT => Some(F::Depth32FloatStencil8),
+            RafxFormat::BC1_RGBA_UNORM_BLOCK => Some(F::Bc1RgbaUnorm),
+            RafxFormat::BC1_RGBA_SRGB_BLOCK => Some(F::Bc1RgbaUnormSrgb),
+            RafxFormat::BC2_UNORM_BLOCK => Some(F::Bc2RgbaUnorm),
+            RafxFormat::BC2_SRGB_BLOCK => Some(F::Bc2RgbaUnormSrgb),
+            RafxFormat::BC3_UNORM_BLOCK => Some(F::Bc3RgbaUnorm),
+            RafxFormat::BC3_SRGB_BLOCK => Some(F::Bc3RgbaUnormSrgb),
+            RafxFormat::BC4_UNORM_BLOCK => Some(F::Bc4RUnorm),
+            RafxFormat::BC4_SNORM_BLOCK => Some(F::Bc4RSnorm),
+            RafxFormat::BC5_UNORM_BLOCK => Some(F::Bc5RgUnorm),
+            RafxFormat::BC5_SNORM_BLOCK => Some(F::Bc5RgSnorm),
+            RafxFormat::BC6H_UFLOAT_BLOCK => Some(F::Bc6hRgbUfloat),
+            RafxFormat::BC6H_SFLOAT_BLOCK => Some(F::Bc6hRgbFloat),
+            RafxFormat::BC7_UNORM_BLOCK => Some(F::Bc7RgbaUnorm),
+            RafxFormat::BC7_SRGB_BLOCK => Some(F::Bc7RgbaUnormSrgb),
+            RafxFormat::ETC2_R8G8B8_UNORM_BLOCK => Some(F::Etc2Rgb8Unorm),
+            RafxFormat::ETC2_R8G8B8_SRGB_BLOCK => Some(F::Etc2Rgb8UnormSrgb),
+            RafxFormat::ETC2_R8G8B8A1_UNORM_BLOCK => Some(F::Etc2Rgb8A1Unorm),
+            RafxFormat::ETC2_R8G8B8A1_SRGB_BLOCK => Some(F::Etc2Rgb8A1UnormSrgb),
+            RafxFormat::ETC2_R8G8B8A8_UNORM_BLOCK => Some(F::Etc2Rgba8Unorm),
+            RafxFormat::ETC2_R8G8B8A8_SRGB_BLOCK => Some(F::Etc2Rgba8UnormSrgb),
+            RafxFormat::EAC_R11_UNORM_BLOCK => Some(F::EacR11Unorm),
+            RafxFormat::EAC_R11_SNORM_BLOCK => Some(F::EacR11Snorm),
+            RafxFormat::EAC_R11G11_UNORM_BLOCK => Some(F::EacRg11Unorm),
+            RafxFormat::EAC_R11G11_SNORM_BLOCK => Some(F::EacRg11Snorm),
+            RafxFormat::ASTC_4X4_UNORM_BLOCK => Some(F::Astc { block: wgpu::AstcBlock::B4x4, channel: wgpu::AstcChannel::Unorm }),
+            RafxFormat::ASTC_4X4_SRGB_BLOCK => Some(F::Astc { block: wgpu::AstcBlock::B4x4, channel: wgpu::AstcChannel::UnormSrgb }),
+            RafxFormat::ASTC_5X4_UNORM_BLOCK => Some(F::Astc { block: wgpu::AstcBlock::B5x4, channel: wgpu::AstcChannel::Unorm }),
+            RafxFormat::ASTC_5X4_SRGB_BLOCK => Some(F::Astc { block: wgpu::AstcBlock::B5x4, channel: wgpu::AstcChannel::UnormSrgb }),
+            RafxFormat::ASTC_5X5_UNORM_BLOCK => Some(F::Astc { block: wgpu::AstcBlock::B5x5, channel: wgpu::AstcChannel::Unorm }),
+            RafxFormat::ASTC_5X5_SRGB_BLOCK => Some(F::Astc { block: wgpu::AstcBlock::B5x5, channel: wgpu::AstcChannel::UnormSrgb }),
+            RafxFormat::ASTC_6X5_UNORM_BLOCK => Some(F::Astc { block: wgpu::AstcBlock::B6x5, channel: wgpu::AstcChannel::Unorm }),
+            RafxFormat::ASTC_6X5_SRGB_BLOCK => Some(F::Astc { block: wgpu::AstcBlock::B6x5, channel: wgpu::AstcChannel::UnormSrgb }),
+            RafxFormat::ASTC_6X6_UNORM_BLOCK => Some(F::Astc { block: wgpu::AstcBlock::B6x6, channel: wgpu::AstcChannel::Unorm }),
+            RafxFormat::ASTC_6X6_SRGB_BLOCK => Some(F::Astc { block: wgpu::AstcBlock::B6x6, channel: wgpu::AstcChannel::UnormSrgb }),
+            RafxFormat::ASTC_8X5_UNORM_BLOCK => Some(F::Astc { block: wgpu::AstcBlock::B8x5, channel: wgpu::AstcChannel::Unorm }),
+            RafxFormat::ASTC_8X5_SRGB_BLOCK => Some(F::Astc { block: wgpu::AstcBlock::B8x5, channel: wgpu::AstcChannel::UnormSrgb }),
+            RafxFormat::ASTC_8X6_UNORM_BLOCK => Some(F::Astc { block: wgpu::AstcBlock::B8x6, channel: wgpu::AstcChannel::Unorm }),
+            RafxFormat::ASTC_8X6_SRGB_BLOCK => Some(F::Astc { block: wgpu::AstcBlock::B8x6, channel: wgpu::AstcChannel::UnormSrgb }),
+            RafxFormat::ASTC_8X8_UNORM_BLOCK => Some(F::Astc { block: wgpu::AstcBlock::B8x8, channel: wgpu::AstcChannel::Unorm }),
+            RafxFormat::ASTC_8X8_SRGB_BLOCK => Some(F::Astc { block: wgpu::AstcBlock::B8x8, channel: wgpu::AstcChannel::UnormSrgb }),
+            RafxFormat::ASTC_10X5_UNORM_BLOCK => Some(F::Astc { block: wgpu::AstcBlock::B10x5, channel: wgpu::AstcChannel::Unorm }),
+            RafxFormat::ASTC_10X5_SRGB_BLOCK => Some(F::Astc { block: wgpu::AstcBlock::B10x5, channel: wgpu::AstcChannel::UnormSrgb }),
+            RafxFormat::ASTC_10X6_UNORM_BLOCK => Some(F::Astc { block: wgpu::AstcBlock::B10x6, channel: wgpu::AstcChannel::Unorm }),
+            RafxFormat::ASTC_10X6_SRGB_BLOCK => Some(F::Astc { block: wgpu::AstcBlock::B10x6, channel: wgpu::AstcChannel::UnormSrgb }),
+            RafxFormat::ASTC_10X8_UNORM_BLOCK => Some(F::Astc { block: wgpu::AstcBlock::B10x8, channel: wgpu::AstcChannel::Unorm }),
+            RafxFormat::ASTC_10X8_SRGB_BLOCK => Some(F::Astc { block: wgpu::AstcBlock::B10x8, channel: wgpu::AstcChannel::UnormSrgb }),
+            RafxFormat::ASTC_10X10_UNORM_BLOCK => Some(F::Astc { block: wgpu::AstcBlock::B10x10, channel: wgpu::AstcChannel::Unorm }),
+            RafxFormat::ASTC_10X10_SRGB_BLOCK => Some(F::Astc { block: wgpu::AstcBlock::B10x10, channel: wgpu::AstcChannel::UnormSrgb }),
+            RafxFormat::ASTC_12X10_UNORM_BLOCK => Some(F::Astc { block: wgpu::AstcBlock::B12x10, channel: wgpu::AstcChannel::Unorm }),
+            RafxFormat::ASTC_12X10_SRGB_BLOCK => Some(F::Astc { block: wgpu::AstcBlock::B12x10, channel: wgpu::AstcChannel::UnormSrgb }),
+            RafxFormat::ASTC_12X12_UNORM_BLOCK => Some(F::Astc { block: wgpu::AstcBlock::B12x12, channel: wgpu::AstcChannel::Unorm }),
+            RafxFormat::ASTC_12X12_SRGB_BLOCK => Some(F::Astc { block: wgpu::AstcBlock::B12x12, channel: wgpu::AstcChannel::UnormSrgb }),
+            _ => None,
+        }
+    }
+
+    pub fn webgpu_vertex_format(self) -> Option<wgpu::VertexFormat> {
+        use wgpu::VertexFormat as F;
+        match self {
+            RafxFormat::R8G8_UNORM => Some(F::Unorm8x2),
+            RafxFormat::R8G8_SNORM => Some(F::Snorm8x2),
+            RafxFormat::R8G8_UINT => Some(F::Uint8x2),
+            RafxFormat::R8G8_SINT => Some(F::Sint8x2),
+            RafxFormat::R8G8B8A8_UNORM => Some(F::Unorm8x4),
+            RafxFormat::R8G8B8A8_SNORM => Some(F::Snorm8x4),
+            RafxFormat::R8G8B8A8_UINT => Some(F::Uint8x4),
+            RafxFormat::R8G8B8A8_SINT => Some(F::Sint8x4),
+            RafxFormat::R16G16_UNORM => Some(F::Unorm16x2),
+            RafxFormat::R16G16_SNORM => Some(F::Snorm16x2),
+            RafxFormat::R16G16_UINT => Some(F::Uint16x2),
+            RafxFormat::R16G16_SINT => Some(F::Sint16x2),
+            RafxFormat::R16G16_SFLOAT => Some(F::Float16x2),
+            RafxFormat::R16G16B16A16_UNORM => Some(F::Unorm16x4),
+            RafxFormat::R16G16B16A16_SNORM => Some(F::Snorm16x4),
+            RafxFormat::R16G16B16A16_UINT => Some(F::Uint16x4),
+            RafxFormat::R16G16B16A16_SINT => Some(F::Sint16x4),
+            RafxFormat::R16G16B16A16_SFLOAT => Some(F::Float16x4),
+            RafxFormat::R32_UINT => Some(F::Uint32),
+            RafxFormat::R32_SINT => Some(F::Sint32),
+            RafxFormat::R32_SFLOAT => Some(F::Float32),
+            RafxFormat::R32G32_UINT => Some(F::Uint32x2),
+            RafxFormat::R32G32_SINT => Some(F::Sint32x2),
+            RafxFormat::R32G32_SFLOAT => Some(F::Float32x2),
+            RafxFormat::R32G32B32_UINT => Some(F::Uint32x3),
+            RafxFormat::R32G32B32_SINT => Some(F::Sint32x3),
+            RafxFormat::R32G32B32_SFLOAT => Some(F::Float32x3),
+            RafxFormat::R32G32B32A32_UINT => Some(F::Uint32x4),
+            RafxFormat::R32G32B32A32_SINT => Some(F::Sint32x4),
+            RafxFormat::R32G32B32A32_SFLOAT => Some(F::Float32x4),
+            RafxFormat::R64_SFLOAT => Some(F::Float64),
+            RafxFormat::R64G64_SFLOAT => Some(F::Float64x2),
+            RafxFormat::R64G64B64_SFLOAT => Some(F::Float64x3),
+            RafxFormat::R64G64B64A64_SFLOAT => Some(F::Float64x4),
+            _ => None,
+        }
+    }
+}
+
 // Returns None for formats unlikely to be used for vertices (like ATSC blocks) or undefined
 impl RafxFormat {
     pub fn is_depth_only(self) -> bool {
@@ -12,16 +12,18 @@ pub enum RafxApiType {
     Metal = 2,
     Gles2 = 3,
     Gles3 = 4,
-    Empty = 5,
+    WebGpu = 5,
+    Empty = 6,
 }
 
 // Generally we shouldn't use empty, it's excluded from this list
-pub const RAFX_VALID_API_TYPES: [RafxApiType; 5] = [
+pub const RAFX_VALID_API_TYPES: [RafxApiType; 6] = [
     RafxApiType::Vk,
     RafxApiType::Dx12,
     RafxApiType::Metal,
     RafxApiType::Gles2,
     RafxApiType::Gles3,
+    RafxApiType::WebGpu,
 ];
 
 /// Controls if validation is enabled or not. The requirements/behaviors of validation is
@@ -778,11 +780,19 @@ impl<'a> RafxTextureBarrier<'a> {
 #[cfg_attr(feature = "serde-support", derive(Serialize, Deserialize))]
 pub enum RafxSwapchainColorSpace {
     Srgb,
+    // Linear sRGB primaries with values outside 0-1 allowed. On windows this is scRGB, where 1.0 is
+    // 80 nits. On apple devices 1.0 is SDR white and values above 1.0 use the display's EDR headroom.
+    // Vulkan swapchains presenting EXTENDED_SRGB_LINEAR_EXT report this color space (they used to
+    // report Srgb), so code checking for Srgb must also handle SrgbExtended.
     SrgbExtended,
 
     // Only supported on apple/metal, SrgbExtended is recommended for all HDR, including
     // on apple devices.
     DisplayP3Extended,
+
+    // Rec.2020 primaries with the SMPTE ST 2084 (PQ) transfer function, written to a 10-bit UNORM
+    // swapchain. Values are absolute luminance, the shader writing to the swapchain must PQ-encode.
+    Hdr10,
 }
 
 impl RafxSwapchainColorSpace {
@@ -791,6 +801,7 @@ impl RafxSwapchainColorSpace {
             RafxSwapchainColorSpace::Srgb => false,
             RafxSwapchainColorSpace::SrgbExtended => true,
             RafxSwapchainColorSpace::DisplayP3Extended => true,
+            RafxSwapchainColorSpace::Hdr10 => false,
         }
     }
 
@@ -799,6 +810,17 @@ impl RafxSwapchainColorSpace {
             RafxSwapchainColorSpace::Srgb => true,
             RafxSwapchainColorSpace::SrgbExtended => true,
             RafxSwapchainColorSpace::DisplayP3Extended => false,
+            RafxSwapchainColorSpace::Hdr10 => false,
+        }
+    }
+
+    /// Returns true if the color space can represent brightness above SDR white
+    pub fn is_hdr(self) -> bool {
+        match self {
+            RafxSwapchainColorSpace::Srgb => false,
+            RafxSwapchainColorSpace::SrgbExtended => true,
+            RafxSwapchainColorSpace::DisplayP3Extended => true,
+            RafxSwapchainColorSpace::Hdr10 => true,
         }
     }
 }
@@ -809,6 +831,17 @@ impl Default for RafxSwapchainColorSpace {
     }
 }
 
+/// Luminance range of the display a swapchain presents to, as reported by the OS. Only reported by
+/// the DX12 backend, see `RafxSwapchain::display_luminance`.
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub struct RafxSwapchainDisplayLuminance {
+    pub min_luminance_nits: f32,
+    pub max_luminance_nits: f32,
+    // The brightness the display can sustain over the whole screen, usually lower than
+    // max_luminance_nits
+    pub max_full_frame_luminance_nits: f32,
+}
+
 /// Represents an image owned by the swapchain
 #[derive(Clone)]
 pub struct RafxSwapchainImage {
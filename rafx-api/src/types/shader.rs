@@ -27,6 +27,15 @@ pub enum RafxShaderPackageGles3 {
     Src(String),
 }
 
+/// WebGPU-specific shader package. Can be used to create a RafxShaderModuleDef, which in turn is
+/// used to initialize a shader module GPU object
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde-support", derive(Serialize, Deserialize))]
+pub enum RafxShaderPackageWebGpu {
+    /// Raw WGSL source code. Will be compiled at runtime.
+    Src(String),
+}
+
 /// Metal-specific shader package. Can be used to create a RafxShaderModuleDef, which in turn is
 /// used to initialize a shader module GPU object
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -84,12 +93,14 @@ pub struct RafxShaderPackage {
     pub dx12: Option<RafxShaderPackageDx12>,
     pub metal: Option<RafxShaderPackageMetal>,
     pub vk: Option<RafxShaderPackageVulkan>,
+    pub webgpu: Option<RafxShaderPackageWebGpu>,
 
     pub vk_reflection: Option<Vec<RafxReflectedEntryPoint>>,
     pub dx12_reflection: Option<Vec<RafxReflectedEntryPoint>>,
     pub metal_reflection: Option<Vec<RafxReflectedEntryPoint>>,
     pub gles2_reflection: Option<Vec<RafxReflectedEntryPoint>>,
     pub gles3_reflection: Option<Vec<RafxReflectedEntryPoint>>,
+    pub webgpu_reflection: Option<Vec<RafxReflectedEntryPoint>>,
 
     pub debug_name: Option<String>,
 }
@@ -145,6 +156,7 @@ impl RafxShaderPackage {
             RafxApiType::Metal => self.metal_reflection.as_ref(),
             RafxApiType::Gles2 => self.gles2_reflection.as_ref(),
             RafxApiType::Gles3 => self.gles3_reflection.as_ref(),
+            RafxApiType::WebGpu => self.webgpu_reflection.as_ref(),
             RafxApiType::Empty => None,
         }
     }
@@ -188,6 +200,19 @@ impl RafxShaderPackage {
         }
     }
 
+    /// Create a shader module def for use with a WebGPU RafxDevice. Returns none if the package
+    /// does not contain data necessary for WebGPU
+    #[cfg(feature = "rafx-webgpu")]
+    pub fn webgpu_module_def(&self) -> Option<RafxShaderModuleDefWebGpu> {
+        if let Some(webgpu) = self.webgpu.as_ref() {
+            Some(match webgpu {
+                RafxShaderPackageWebGpu::Src(src) => RafxShaderModuleDefWebGpu::WgslSrc(src),
+            })
+        } else {
+            None
+        }
+    }
+
     /// Create a shader module def for use with a dx12 RafxDevice. Returns none if the package does
     /// not contain data necessary for metal
     #[cfg(feature = "rafx-dx12")]
@@ -242,7 +267,8 @@ impl RafxShaderPackage {
             feature = "rafx-metal",
             feature = "rafx-vulkan",
             feature = "rafx-gles2",
-            feature = "rafx-gles3"
+            feature = "rafx-gles3",
+            feature = "rafx-webgpu"
         ))
     ))]
     #[doc(hidden)]
@@ -262,6 +288,8 @@ impl RafxShaderPackage {
             metal: self.metal_module_def(),
             #[cfg(feature = "rafx-vulkan")]
             vk: self.vulkan_module_def(),
+            #[cfg(feature = "rafx-webgpu")]
+            webgpu: self.webgpu_module_def(),
             #[cfg(any(
                 feature = "rafx-empty",
                 not(any(
@@ -269,7 +297,8 @@ impl RafxShaderPackage {
                     feature = "rafx-metal",
                     feature = "rafx-vulkan",
                     feature = "rafx-gles2",
-                    feature = "rafx-gles3"
+                    feature = "rafx-gles3",
+                    feature = "rafx-webgpu"
                 ))
             ))]
             empty: self.empty_module_def(),
@@ -297,6 +326,16 @@ pub enum RafxShaderModuleDefGles3<'a> {
     GlSrc(&'a str),
 }
 
+/// Used to create a RafxShaderModule
+///
+/// This enum may be populated manually or created from a RafxShaderPackage.
+#[derive(Copy, Clone, Hash)]
+#[cfg(feature = "rafx-webgpu")]
+pub enum RafxShaderModuleDefWebGpu<'a> {
+    /// WGSL source code
+    WgslSrc(&'a str),
+}
+
 /// Used to create a RafxShaderModule
 ///
 /// This enum may be populated manually or created from a RafxShaderPackage.
@@ -340,7 +379,8 @@ pub enum RafxShaderModuleDefVulkan<'a> {
         feature = "rafx-metal",
         feature = "rafx-vulkan",
         feature = "rafx-gles2",
-        feature = "rafx-gles3"
+        feature = "rafx-gles3",
+        feature = "rafx-webgpu"
     ))
 ))]
 #[derive(Copy, Clone, Hash)]
@@ -364,6 +404,8 @@ pub struct RafxShaderModuleDef<'a> {
     pub metal: Option<RafxShaderModuleDefMetal<'a>>,
     #[cfg(feature = "rafx-vulkan")]
     pub vk: Option<RafxShaderModuleDefVulkan<'a>>,
+    #[cfg(feature = "rafx-webgpu")]
+    pub webgpu: Option<RafxShaderModuleDefWebGpu<'a>>,
     #[cfg(any(
         feature = "rafx-empty",
         not(any(
@@ -371,7 +413,8 @@ pub struct RafxShaderModuleDef<'a> {
             feature = "rafx-metal",
             feature = "rafx-vulkan",
             feature = "rafx-gles2",
-            feature = "rafx-gles3"
+            feature = "rafx-gles3",
+            feature = "rafx-webgpu"
         ))
     ))]
     #[doc(hidden)]
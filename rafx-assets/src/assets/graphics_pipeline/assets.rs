@@ -12,8 +12,8 @@ use rafx_api::{
     RafxError, RafxFillMode, RafxFrontFace, RafxRasterizerState, RafxResult, RafxSamplerDef,
 };
 use rafx_framework::{
-    DescriptorSetArc, FixedFunctionState, MaterialPass, MaterialPassResource, MaterialShaderStage,
-    ResourceArc,
+    DescriptorSetArc, FixedFunctionState, MaterialPass, MaterialPassResource,
+    MaterialPassSpecializationConstant, MaterialShaderStage, ResourceArc,
 };
 use rafx_framework::{DescriptorSetWriteSet, SamplerResource};
 use std::hash::Hash;
@@ -179,6 +179,8 @@ pub struct MaterialPassRon {
     pub phase: Option<String>,
     pub fixed_function_state: FixedFunctionStateData,
     pub shaders: Vec<GraphicsPipelineShaderStageRon>,
+    #[serde(default)]
+    pub specialization_constants: Vec<MaterialPassSpecializationConstant>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
@@ -205,6 +207,7 @@ pub struct MaterialPassData {
     pub phase: Option<String>,
     pub fixed_function_state: FixedFunctionStateData,
     pub shaders: Vec<GraphicsPipelineShaderStage>,
+    pub specialization_constants: Vec<MaterialPassSpecializationConstant>,
 }
 
 impl MaterialPassData {
@@ -270,6 +273,7 @@ impl MaterialPassData {
             fixed_function_state,
             shader_modules,
             &entry_points,
+            &self.specialization_constants,
         )
         .map_err(|x| {
             RafxError::StringError(format!(
@@ -68,6 +68,13 @@ impl Importer for MaterialImporter {
                 .map_err(|e| format!("RON error {:?}", e))?;
 
             pass.fixed_function_state().set(fixed_function_state)?;
+
+            let specialization_constants = ron::ser::to_string(&pass_ron.specialization_constants)
+                .map_err(|e| format!("RON error {:?}", e))?;
+
+            pass.specialization_constants()
+                .set(specialization_constants)?;
+
             for stage_ron in pass_ron.shaders {
                 let stage = match stage_ron.stage {
                     MaterialShaderStage::Vertex => pass.vertex_stage(),
@@ -112,7 +119,7 @@ impl JobProcessor for MaterialJobProcessor {
     type OutputT = MaterialJobOutput;
 
     fn version(&self) -> u32 {
-        1
+        2
     }
 
     fn run<'a>(
@@ -135,6 +142,17 @@ impl JobProcessor for MaterialJobProcessor {
                         ron::de::from_str(&pass_entry.fixed_function_state().get()?)
                             .map_err(|e| format!("RON error {:?}", e))?;
 
+                    // Assets imported before specialization constants were supported have an
+                    // empty string here
+                    let specialization_constants_ron =
+                        pass_entry.specialization_constants().get()?;
+                    let specialization_constants = if specialization_constants_ron.is_empty() {
+                        Vec::default()
+                    } else {
+                        ron::de::from_str(&specialization_constants_ron)
+                            .map_err(|e| format!("RON error {:?}", e))?
+                    };
+
                     fn read_stage(
                         stage: MaterialShaderStage,
                         record: GraphicsPipelineShaderStageRef,
@@ -179,6 +197,7 @@ impl JobProcessor for MaterialJobProcessor {
                         phase: (!phase.is_empty()).then(|| (*phase).clone()),
                         fixed_function_state,
                         shaders,
+                        specialization_constants,
                     });
                 }
 
@@ -35,6 +35,7 @@ pub type ImageAssetUploadOpResult = UploadAssetOpResult<RafxTexture, ImageAsset>
 pub fn new_gpu_image_data_from_image_asset_data_subresources(
     width: u32,
     height: u32,
+    depth: u32,
     format: RafxFormat,
     subresources: ImageAssetDataPayloadSubresources,
 ) -> GpuImageData {
@@ -75,6 +76,7 @@ pub fn new_gpu_image_data_from_image_asset_data_subresources(
     GpuImageData {
         width,
         height,
+        depth,
         format,
         layers,
     }
@@ -157,6 +159,7 @@ impl ImageAssetUploadQueue {
                 new_gpu_image_data_from_image_asset_data_subresources(
                     request.asset.width,
                     request.asset.height,
+                    request.asset.depth,
                     rafx_format,
                     subresources,
                 )
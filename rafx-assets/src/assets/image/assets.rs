@@ -28,6 +28,15 @@ impl Into<GpuImageDataColorSpace> for ImageAssetColorSpaceConfig {
     }
 }
 
+//NOTE: This is serialized in image asset options, so may require asset schema change if modifying it
+#[derive(Copy, Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub enum ImageAssetLayout {
+    Image2D,
+    // A 3D image stored as a horizontal strip of square slices, i.e. a 1024x32 image is a 32x32x32
+    // volume. This is the usual way to author color grading LUTs in an image editor.
+    VolumeStrip,
+}
+
 //NOTE: This is serialized in image asset options, so may require asset schema change if modifying it
 #[derive(Serialize, Deserialize, Clone, Copy, Debug)]
 pub enum ImageAssetBasisCompressionType {
@@ -135,6 +144,8 @@ pub enum ImageAssetDataPayload {
 pub struct ImageAssetData {
     pub width: u32,
     pub height: u32,
+    // Greater than 1 for 3D images
+    pub depth: u32,
     pub format: ImageAssetDataFormat,
     pub resource_type: RafxResourceType,
     pub generate_mips_at_runtime: bool,
@@ -149,6 +160,7 @@ impl std::fmt::Debug for ImageAssetData {
         f.debug_struct("Point")
             .field("width", &self.width)
             .field("width", &self.height)
+            .field("depth", &self.depth)
             .field("format", &self.format)
             .finish()
     }
@@ -216,6 +228,7 @@ impl ImageAssetData {
                 Ok(ImageAssetData {
                     width,
                     height,
+                    depth: 1,
                     format,
                     generate_mips_at_runtime,
                     resource_type,
@@ -284,6 +297,7 @@ impl ImageAssetData {
                 Ok(ImageAssetData {
                     width,
                     height,
+                    depth: 1,
                     format,
                     generate_mips_at_runtime,
                     resource_type,
@@ -294,6 +308,54 @@ impl ImageAssetData {
             }
         }
     }
+
+    // Slices are stored one after another. Volume images are always uncompressed and have no mips.
+    pub fn from_raw_rgba32_volume(
+        width: u32,
+        height: u32,
+        depth: u32,
+        color_space: ImageAssetColorSpaceConfig,
+        resource_type: RafxResourceType,
+        raw_rgba32: &[u8],
+    ) -> RafxResult<ImageAssetData> {
+        if raw_rgba32.len() != (width * height * depth * 4) as usize {
+            Err(format!(
+                "Volume image data is {} bytes but a {}x{}x{} image requires {}",
+                raw_rgba32.len(),
+                width,
+                height,
+                depth,
+                width * height * depth * 4
+            ))?;
+        }
+
+        let mip = ImageAssetDataMipLevel {
+            width,
+            height,
+            bytes: raw_rgba32.to_vec(),
+        };
+
+        let layer = ImageAssetDataLayer {
+            mip_levels: vec![mip],
+        };
+
+        let format = match color_space {
+            ImageAssetColorSpaceConfig::Linear => ImageAssetDataFormat::RGBA32_Linear,
+            ImageAssetColorSpaceConfig::Srgb => ImageAssetDataFormat::RGBA32_Srgb,
+        };
+
+        Ok(ImageAssetData {
+            width,
+            height,
+            depth,
+            format,
+            generate_mips_at_runtime: false,
+            resource_type,
+            data: ImageAssetDataPayload::Subresources(ImageAssetDataPayloadSubresources {
+                layers: vec![layer],
+            }),
+        })
+    }
 }
 
 #[derive(TypeUuid, Clone)]
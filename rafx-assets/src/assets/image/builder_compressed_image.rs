@@ -55,6 +55,8 @@ impl JobProcessor for GpuCompressedImageJobProcessor {
 
         let width = imported_data.width().get()?;
         let height = imported_data.height().get()?;
+        // Zero if the image was imported before depth was recorded
+        let depth = imported_data.depth().get()?.max(1);
         let format = match imported_data.format().get()? {
             GpuImageAssetDataFormatEnum::RGBA32_Linear => ImageAssetDataFormat::RGBA32_Linear,
             GpuImageAssetDataFormatEnum::RGBA32_Srgb => ImageAssetDataFormat::RGBA32_Srgb,
@@ -113,6 +115,7 @@ impl JobProcessor for GpuCompressedImageJobProcessor {
         let processed_data = ImageAssetData {
             width,
             height,
+            depth,
             format,
             resource_type,
             generate_mips_at_runtime: false,
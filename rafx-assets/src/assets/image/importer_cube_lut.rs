@@ -0,0 +1,255 @@
+use crate::schema::{
+    GpuCompressedImageAssetRecord, GpuCompressedImageImportedDataRecord,
+    GpuImageAssetDataFormatEnum,
+};
+use hydrate_data::Record;
+use hydrate_pipeline::{ImportContext, Importer, PipelineResult, ScanContext};
+use std::sync::Arc;
+use type_uuid::*;
+
+// Imports .cube color grading LUTs (the format used by Resolve, Photoshop, etc.) as 3D images. The
+// values are quantized to 8 bits per channel, since image assets have no floating point formats,
+// and are not color space converted, so the LUT is applied to the same encoding it was authored
+// against. Values outside DOMAIN_MIN/DOMAIN_MAX are clamped.
+#[derive(TypeUuid, Default)]
+#[uuid = "34729ad9-1252-47b0-ba9d-b22a99e3f197"]
+pub struct GpuCompressedImageImporterCubeLut;
+
+// Larger LUTs than this are rejected. Common sizes are 17, 33 and 65.
+const MAX_LUT_3D_SIZE: u32 = 256;
+
+struct CubeLut {
+    size: u32,
+    // RGBA8, red varies fastest and blue slowest, which is the order of a 3D image's texels
+    rgba8: Vec<u8>,
+}
+
+fn parse_cube_lut(text: &str) -> PipelineResult<CubeLut> {
+    let mut size = None;
+    let mut domain_min = [0.0f32; 3];
+    let mut domain_max = [1.0f32; 3];
+    let mut values = Vec::default();
+
+    let parse_vec3 = |tokens: &[&str]| -> PipelineResult<[f32; 3]> {
+        if tokens.len() != 3 {
+            Err(format!(
+                "Expected 3 values in .cube file, found {:?}",
+                tokens
+            ))?;
+        }
+
+        let mut value = [0.0; 3];
+        for (i, token) in tokens.iter().enumerate() {
+            value[i] = token
+                .parse::<f32>()
+                .map_err(|e| format!("Could not parse {:?} in .cube file: {}", token, e))?;
+        }
+        Ok(value)
+    };
+
+    for line in text.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let tokens: Vec<&str> = line.split_whitespace().collect();
+        match tokens[0] {
+            "TITLE" => {}
+            "LUT_1D_SIZE" => Err("1D .cube LUTs are not supported")?,
+            "LUT_3D_SIZE" => {
+                let lut_size = tokens
+                    .get(1)
+                    .and_then(|x| x.parse::<u32>().ok())
+                    .ok_or_else(|| format!("Invalid LUT_3D_SIZE in .cube file: {:?}", line))?;
+                size = Some(lut_size);
+            }
+            "DOMAIN_MIN" => domain_min = parse_vec3(&tokens[1..])?,
+            "DOMAIN_MAX" => domain_max = parse_vec3(&tokens[1..])?,
+            _ => values.push(parse_vec3(&tokens)?),
+        }
+    }
+
+    let size = size.ok_or("No LUT_3D_SIZE in .cube file")?;
+    if size < 2 {
+        Err(format!("LUT_3D_SIZE {} in .cube file is too small", size))?;
+    }
+    if size > MAX_LUT_3D_SIZE {
+        Err(format!(
+            "LUT_3D_SIZE {} in .cube file is too large, the maximum is {}",
+            size, MAX_LUT_3D_SIZE
+        ))?;
+    }
+
+    let expected_value_count = size
+        .checked_mul(size)
+        .and_then(|x| x.checked_mul(size))
+        .ok_or_else(|| format!("LUT_3D_SIZE {} in .cube file is too large", size))?
+        as usize;
+    if values.len() != expected_value_count {
+        Err(format!(
+            ".cube file with LUT_3D_SIZE {} has {} values, expected {}",
+            size,
+            values.len(),
+            expected_value_count
+        ))?;
+    }
+
+    let mut rgba8 = Vec::with_capacity(expected_value_count * 4);
+    for value in values {
+        for channel in 0..3 {
+            let range = (domain_max[channel] - domain_min[channel]).max(f32::EPSILON);
+            let normalized = ((value[channel] - domain_min[channel]) / range).clamp(0.0, 1.0);
+            rgba8.push((normalized * 255.0).round() as u8);
+        }
+        rgba8.push(255);
+    }
+
+    Ok(CubeLut { size, rgba8 })
+}
+
+impl Importer for GpuCompressedImageImporterCubeLut {
+    fn supported_file_extensions(&self) -> &[&'static str] {
+        &["cube"]
+    }
+
+    fn scan_file(
+        &self,
+        context: ScanContext,
+    ) -> PipelineResult<()> {
+        context.add_default_importable::<GpuCompressedImageAssetRecord>()?;
+        Ok(())
+    }
+
+    fn import_file(
+        &self,
+        context: ImportContext,
+    ) -> PipelineResult<()> {
+        let text = std::fs::read_to_string(context.path)?;
+        let lut = parse_cube_lut(&text)?;
+
+        log::trace!("Imported .cube LUT {:?} size: {}", context.path, lut.size);
+
+        //
+        // Create import data
+        //
+        let import_data = GpuCompressedImageImportedDataRecord::new_builder(context.schema_set);
+
+        import_data.width().set(lut.size).unwrap();
+        import_data.height().set(lut.size).unwrap();
+        import_data.depth().set(lut.size).unwrap();
+        import_data
+            .format()
+            .set(GpuImageAssetDataFormatEnum::RGBA32_Linear)
+            .unwrap();
+
+        let layer_entry = import_data.data_layers().add_entry().unwrap();
+        let layer_record = import_data.data_layers().entry(layer_entry);
+        let mip_level_entry = layer_record.mip_levels().add_entry().unwrap();
+        let mip_record = layer_record.mip_levels().entry(mip_level_entry);
+        mip_record.width().set(lut.size).unwrap();
+        mip_record.height().set(lut.size).unwrap();
+        mip_record.bytes().set(Arc::new(lut.rgba8)).unwrap();
+
+        //
+        // Create the default asset
+        //
+        let default_asset = GpuCompressedImageAssetRecord::new_builder(context.schema_set);
+
+        //
+        // Return the created objects
+        //
+        context
+            .add_default_importable(default_asset.into_inner()?, Some(import_data.into_inner()?));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A LUT of the given size that maps every color to itself, red varying fastest
+    fn identity_cube_lut(size: u32) -> String {
+        let mut text = format!("TITLE \"Identity\"\n# A comment\n\nLUT_3D_SIZE {}\n", size);
+        let max = (size - 1) as f32;
+        for b in 0..size {
+            for g in 0..size {
+                for r in 0..size {
+                    text += &format!("{} {} {}\n", r as f32 / max, g as f32 / max, b as f32 / max);
+                }
+            }
+        }
+        text
+    }
+
+    fn parse_error(text: &str) -> String {
+        match parse_cube_lut(text) {
+            Ok(_) => panic!("Expected an error parsing {:?}", text),
+            Err(e) => format!("{:?}", e),
+        }
+    }
+
+    #[test]
+    fn parses_identity_lut() {
+        let lut = parse_cube_lut(&identity_cube_lut(2)).unwrap();
+        assert_eq!(lut.size, 2);
+        #[rustfmt::skip]
+        let expected: Vec<u8> = vec![
+            0, 0, 0, 255,       255, 0, 0, 255,
+            0, 255, 0, 255,     255, 255, 0, 255,
+            0, 0, 255, 255,     255, 0, 255, 255,
+            0, 255, 255, 255,   255, 255, 255, 255,
+        ];
+        assert_eq!(lut.rgba8, expected);
+
+        let lut = parse_cube_lut(&identity_cube_lut(17)).unwrap();
+        assert_eq!(lut.size, 17);
+        assert_eq!(lut.rgba8.len(), 17 * 17 * 17 * 4);
+    }
+
+    #[test]
+    fn applies_domain() {
+        let mut text = "LUT_3D_SIZE 2\nDOMAIN_MIN 0 0 -1\nDOMAIN_MAX 2 4 1\n".to_string();
+        for _ in 0..7 {
+            text += "1 1 0\n";
+        }
+        // Values outside the domain are clamped
+        text += "3 -1 1\n";
+
+        let lut = parse_cube_lut(&text).unwrap();
+        assert_eq!(&lut.rgba8[0..4], &[128, 64, 128, 255]);
+        assert_eq!(&lut.rgba8[28..32], &[255, 0, 255, 255]);
+
+        assert!(parse_error("LUT_3D_SIZE 2\nDOMAIN_MIN 0 0\n").contains("Expected 3 values"));
+        assert!(parse_error("LUT_3D_SIZE 2\nDOMAIN_MAX 1 x 1\n").contains("Could not parse"));
+    }
+
+    #[test]
+    fn rejects_missing_or_invalid_size() {
+        let values = "0 0 0\n".repeat(8);
+        assert!(parse_error(&values).contains("No LUT_3D_SIZE"));
+        assert!(parse_error(&format!("LUT_3D_SIZE\n{}", values)).contains("Invalid LUT_3D_SIZE"));
+        assert!(
+            parse_error(&format!("LUT_3D_SIZE two\n{}", values)).contains("Invalid LUT_3D_SIZE")
+        );
+        assert!(parse_error(&format!("LUT_3D_SIZE -2\n{}", values)).contains("Invalid LUT_3D_SIZE"));
+        assert!(parse_error("LUT_3D_SIZE 1\n0 0 0\n").contains("too small"));
+        assert!(parse_error("LUT_3D_SIZE 257\n").contains("too large"));
+        // Would overflow a u32 if the size wasn't limited
+        assert!(parse_error("LUT_3D_SIZE 2000000\n").contains("too large"));
+        assert!(parse_error("LUT_1D_SIZE 1024\n").contains("1D .cube LUTs are not supported"));
+    }
+
+    #[test]
+    fn rejects_wrong_value_count() {
+        let too_few = "LUT_3D_SIZE 2\n".to_string() + &"0 0 0\n".repeat(7);
+        assert!(parse_error(&too_few).contains("has 7 values, expected 8"));
+
+        let too_many = "LUT_3D_SIZE 2\n".to_string() + &"0 0 0\n".repeat(9);
+        assert!(parse_error(&too_many).contains("has 9 values, expected 8"));
+
+        let short_row = "LUT_3D_SIZE 2\n".to_string() + &"0 0 0\n".repeat(7) + "0 0\n";
+        assert!(parse_error(&short_row).contains("Expected 3 values"));
+    }
+}
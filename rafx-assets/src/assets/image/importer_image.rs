@@ -3,10 +3,12 @@ use crate::assets::image::{
 };
 use crate::schema::{
     GpuImageAssetAccessor, GpuImageAssetRecord, GpuImageBasisCompressionTypeEnum,
-    GpuImageColorSpaceEnum, GpuImageImportedDataRecord, GpuImageMipGenerationEnum,
+    GpuImageColorSpaceEnum, GpuImageImportedDataRecord, GpuImageLayoutEnum,
+    GpuImageMipGenerationEnum,
 };
 use crate::{
-    ImageAssetBasisCompressionSettings, ImageAssetBasisCompressionType, ImageAssetMipGeneration,
+    ImageAssetBasisCompressionSettings, ImageAssetBasisCompressionType, ImageAssetLayout,
+    ImageAssetMipGeneration,
 };
 use hydrate_base::AssetId;
 use hydrate_data::{Record, RecordAccessor, RecordBuilder};
@@ -87,6 +89,7 @@ pub struct ImageImporterOptions {
     pub mip_generation: ImageAssetMipGeneration,
     pub color_space: ImageAssetColorSpaceConfig,
     pub data_format: ImageAssetDataFormatConfig,
+    pub layout: ImageAssetLayout,
 }
 
 impl Default for ImageImporterOptions {
@@ -95,6 +98,7 @@ impl Default for ImageImporterOptions {
             mip_generation: ImageAssetMipGeneration::NoMips,
             color_space: ImageAssetColorSpaceConfig::Linear,
             data_format: ImageAssetDataFormatConfig::Uncompressed,
+            layout: ImageAssetLayout::Image2D,
         }
     }
 }
@@ -104,6 +108,7 @@ pub struct ImageImporterRuleOptions {
     pub mip_generation: ImageAssetMipGeneration,
     pub color_space: ImageAssetColorSpaceConfig,
     pub data_format: ImageAssetDataFormatConfig,
+    pub layout: ImageAssetLayout,
 }
 
 pub trait ImageImporterRule: Send + Sync + RefUnwindSafe {
@@ -187,6 +192,7 @@ impl GpuImageImporterSimple {
                     mip_generation: options.mip_generation,
                     data_format: options.data_format,
                     color_space: options.color_space,
+                    layout: options.layout,
                 };
             }
         }
@@ -194,6 +200,7 @@ impl GpuImageImporterSimple {
             mip_generation: self.image_importer_config.default.mip_generation,
             data_format: self.image_importer_config.default.data_format,
             color_space: self.image_importer_config.default.color_space,
+            layout: self.image_importer_config.default.layout,
         };
     }
 
@@ -242,6 +249,13 @@ impl GpuImageImporterSimple {
                 ImageAssetMipGeneration::Runtime => GpuImageMipGenerationEnum::Runtime,
             })
             .unwrap();
+        asset_record
+            .layout()
+            .set(match default_settings.layout {
+                ImageAssetLayout::Image2D => GpuImageLayoutEnum::Image2D,
+                ImageAssetLayout::VolumeStrip => GpuImageLayoutEnum::VolumeStrip,
+            })
+            .unwrap();
     }
 }
 
@@ -298,6 +312,32 @@ impl Importer for GpuImageImporterSimple {
     }
 }
 
+// Rearranges a strip of square slices laid out left to right into slices stored one after another
+fn volume_from_strip(
+    width: u32,
+    height: u32,
+    image_bytes: &[u8],
+) -> PipelineResult<(u32, Vec<u8>)> {
+    let size = height;
+    if width != size * size {
+        Err(format!(
+            "A {}x{} image can't be used as a volume strip, the width must be the height squared",
+            width, height
+        ))?;
+    }
+
+    let row_bytes = (size * 4) as usize;
+    let mut volume_bytes = Vec::with_capacity(image_bytes.len());
+    for z in 0..size as usize {
+        for y in 0..size as usize {
+            let begin = (y * width as usize * 4) + z * row_bytes;
+            volume_bytes.extend_from_slice(&image_bytes[begin..(begin + row_bytes)]);
+        }
+    }
+
+    Ok((size, volume_bytes))
+}
+
 #[derive(Hash, Serialize, Deserialize)]
 pub struct GpuImageJobInput {
     pub asset_id: AssetId,
@@ -340,6 +380,10 @@ impl JobProcessor for GpuImageJobProcessor {
             GpuImageMipGenerationEnum::Precomputed => ImageAssetMipGeneration::Precomupted,
             GpuImageMipGenerationEnum::Runtime => ImageAssetMipGeneration::Runtime,
         };
+        let layout = match asset_data.layout().get().unwrap() {
+            GpuImageLayoutEnum::Image2D => ImageAssetLayout::Image2D,
+            GpuImageLayoutEnum::VolumeStrip => ImageAssetLayout::VolumeStrip,
+        };
 
         let format_config = if basis_compression {
             let compression_type = match asset_data
@@ -379,16 +423,30 @@ impl JobProcessor for GpuImageJobProcessor {
         //
         // Create the processed data
         //
-        let processed_data = ImageAssetData::from_raw_rgba32(
-            width,
-            height,
-            color_space,
-            format_config,
-            mip_generation,
-            RafxResourceType::TEXTURE,
-            &image_bytes,
-        )
-        .unwrap();
+        let processed_data = match layout {
+            ImageAssetLayout::Image2D => ImageAssetData::from_raw_rgba32(
+                width,
+                height,
+                color_space,
+                format_config,
+                mip_generation,
+                RafxResourceType::TEXTURE,
+                &image_bytes,
+            )
+            .unwrap(),
+            ImageAssetLayout::VolumeStrip => {
+                let (size, volume_bytes) = volume_from_strip(width, height, &image_bytes)?;
+                ImageAssetData::from_raw_rgba32_volume(
+                    size,
+                    size,
+                    size,
+                    color_space,
+                    RafxResourceType::TEXTURE,
+                    &volume_bytes,
+                )
+                .unwrap()
+            }
+        };
 
         //
         // Serialize and return
@@ -12,6 +12,9 @@ pub use asset_type_handler::*;
 mod importer_image;
 pub use importer_image::*;
 
+mod importer_cube_lut;
+pub use importer_cube_lut::*;
+
 #[cfg(feature = "basis-universal")]
 mod importer_basis;
 #[cfg(feature = "basis-universal")]
@@ -39,12 +42,14 @@ impl AssetPlugin for GpuImageAssetPlugin {
         // edit the .meta file.
         let pbr_map_suffix = vec!["_pbr."];
         let normal_map_suffix = vec!["_n."];
+        let lut_suffix = vec!["_lut."];
 
         // Default config
         let mut image_importer_config = ImageImporterConfig::new(ImageImporterRuleOptions {
             mip_generation: ImageAssetMipGeneration::Runtime,
             color_space: ImageAssetColorSpaceConfig::Srgb,
             data_format: ImageAssetDataFormatConfig::Uncompressed,
+            layout: ImageAssetLayout::Image2D,
         });
 
         for suffix in normal_map_suffix {
@@ -55,6 +60,7 @@ impl AssetPlugin for GpuImageAssetPlugin {
                     mip_generation: ImageAssetMipGeneration::Runtime,
                     color_space: ImageAssetColorSpaceConfig::Linear,
                     data_format: ImageAssetDataFormatConfig::Uncompressed,
+                    layout: ImageAssetLayout::Image2D,
                 },
             );
         }
@@ -67,6 +73,21 @@ impl AssetPlugin for GpuImageAssetPlugin {
                     mip_generation: ImageAssetMipGeneration::Runtime,
                     color_space: ImageAssetColorSpaceConfig::Linear,
                     data_format: ImageAssetDataFormatConfig::Uncompressed,
+                    layout: ImageAssetLayout::Image2D,
+                },
+            );
+        }
+
+        // Override for color grading LUTs stored as a strip of slices. These are sampled as a 3D
+        // image, so they can't have mips and must not be color space converted.
+        for suffix in lut_suffix {
+            image_importer_config.add_filename_contains_override(
+                suffix,
+                ImageImporterRuleOptions {
+                    mip_generation: ImageAssetMipGeneration::NoMips,
+                    color_space: ImageAssetColorSpaceConfig::Linear,
+                    data_format: ImageAssetDataFormatConfig::Uncompressed,
+                    layout: ImageAssetLayout::VolumeStrip,
                 },
             );
         }
@@ -90,6 +111,10 @@ impl AssetPlugin for GpuImageAssetPlugin {
             .importer_registry
             .register_handler::<GpuCompressedImageImporterDds>();
 
+        context
+            .importer_registry
+            .register_handler::<GpuCompressedImageImporterCubeLut>();
+
         context
             .thumbnail_provider_registry
             .register_thumbnail_provider::<GpuCompressedImageThumbnailProvider>();
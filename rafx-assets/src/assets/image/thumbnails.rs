@@ -214,8 +214,33 @@ impl ThumbnailProvider for GpuCompressedImageThumbnailProvider {
 
                 decode_bcn(width, height, format, bytes)
             }
-            GpuImageAssetDataFormatEnum::RGBA32_Linear => unimplemented!(),
-            GpuImageAssetDataFormatEnum::RGBA32_Srgb => unimplemented!(),
+            GpuImageAssetDataFormatEnum::RGBA32_Linear
+            | GpuImageAssetDataFormatEnum::RGBA32_Srgb => {
+                let layer_entries = import_data.data_layers().resolve_entries()?;
+                if layer_entries.is_empty() {
+                    Err(PipelineError::ThumbnailUnavailable)?;
+                }
+
+                let mip_entries = import_data
+                    .data_layers()
+                    .entry(layer_entries[0])
+                    .mip_levels()
+                    .resolve_entries()?;
+                if mip_entries.is_empty() {
+                    Err(PipelineError::ThumbnailUnavailable)?;
+                }
+
+                // For 3D images (i.e. color grading LUTs), this shows the first slice
+                let layer0 = import_data.data_layers().entry(layer_entries[0]);
+                let layer0_mip0 = layer0.mip_levels().entry(mip_entries[0]);
+                let width = layer0_mip0.width().get()?;
+                let height = layer0_mip0.height().get()?;
+                let mut bytes = (**layer0_mip0.bytes().get()?).clone();
+                bytes.truncate((width * height * 4) as usize);
+
+                Ok(image::RgbaImage::from_raw(width, height, bytes)
+                    .ok_or(PipelineError::ThumbnailUnavailable)?)
+            }
         }?;
 
         resize_image_for_thumbnail(context, &image)
@@ -1,6 +1,7 @@
 mod image;
 #[cfg(feature = "basis-universal")]
 pub use self::image::GpuCompressedImageImporterBasis;
+pub use self::image::GpuCompressedImageImporterCubeLut;
 #[cfg(feature = "ddsfile")]
 pub use self::image::GpuCompressedImageImporterDds;
 pub use self::image::GpuImageAssetPlugin;
@@ -15,6 +16,7 @@ pub use self::image::ImageAssetDataFormatConfig;
 pub use self::image::ImageAssetDataPayload;
 pub use self::image::ImageAssetDataPayloadSingleBuffer;
 pub use self::image::ImageAssetDataPayloadSubresources;
+pub use self::image::ImageAssetLayout;
 pub use self::image::ImageAssetMipGeneration;
 pub use self::image::ImageFileFormat;
 //pub use self::image::ImageImporter;
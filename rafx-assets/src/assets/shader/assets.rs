@@ -101,6 +101,12 @@ impl DefaultAssetTypeLoadHandler<ShaderAssetData, ShaderAsset> for ShaderLoadHan
             asset_data.shader_package.shader_package(),
             RafxApiType::Gles3,
         );
+        #[cfg(feature = "rafx-webgpu")]
+        build_reflection_data_map(
+            &mut reflection_data_lookup,
+            asset_data.shader_package.shader_package(),
+            RafxApiType::WebGpu,
+        );
 
         let shader_module = asset_manager
             .resources()
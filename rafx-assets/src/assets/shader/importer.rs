@@ -46,11 +46,13 @@ impl Importer for ShaderPackageImporterSpv {
             vk: Some(RafxShaderPackageVulkan::SpvBytes(spv_bytes)),
             gles2: None,
             gles3: None,
+            webgpu: None,
             vk_reflection: None,
             dx12_reflection: None,
             metal_reflection: None,
             gles2_reflection: None,
             gles3_reflection: None,
+            webgpu_reflection: None,
             debug_name: None,
         };
 
@@ -189,6 +189,10 @@ impl GpuCompressedImageImportedDataAccessor {
         BytesFieldAccessor::new(self.0.push("data_single_buffer"))
     }
 
+    pub fn depth(&self) -> U32FieldAccessor {
+        U32FieldAccessor::new(self.0.push("depth"))
+    }
+
     pub fn format(&self) -> EnumFieldAccessor::<GpuImageAssetDataFormatEnum> {
         EnumFieldAccessor::<GpuImageAssetDataFormatEnum>::new(self.0.push("format"))
     }
@@ -228,6 +232,10 @@ impl<'a> GpuCompressedImageImportedDataRef<'a> {
         BytesFieldRef::new(self.0.push("data_single_buffer"), self.1.clone())
     }
 
+    pub fn depth(&self) -> U32FieldRef {
+        U32FieldRef::new(self.0.push("depth"), self.1.clone())
+    }
+
     pub fn format(&self) -> EnumFieldRef::<GpuImageAssetDataFormatEnum> {
         EnumFieldRef::<GpuImageAssetDataFormatEnum>::new(self.0.push("format"), self.1.clone())
     }
@@ -267,6 +275,10 @@ impl<'a> GpuCompressedImageImportedDataRefMut<'a> {
         BytesFieldRefMut::new(self.0.push("data_single_buffer"), &self.1)
     }
 
+    pub fn depth(self: &'a Self) -> U32FieldRefMut {
+        U32FieldRefMut::new(self.0.push("depth"), &self.1)
+    }
+
     pub fn format(self: &'a Self) -> EnumFieldRefMut::<GpuImageAssetDataFormatEnum> {
         EnumFieldRefMut::<GpuImageAssetDataFormatEnum>::new(self.0.push("format"), &self.1)
     }
@@ -310,6 +322,10 @@ impl GpuCompressedImageImportedDataRecord {
         BytesField::new(self.0.push("data_single_buffer"), &self.1)
     }
 
+    pub fn depth(self: &Self) -> U32Field {
+        U32Field::new(self.0.push("depth"), &self.1)
+    }
+
     pub fn format(self: &Self) -> EnumField::<GpuImageAssetDataFormatEnum> {
         EnumField::<GpuImageAssetDataFormatEnum>::new(self.0.push("format"), &self.1)
     }
@@ -354,6 +370,10 @@ impl GpuImageAssetAccessor {
         EnumFieldAccessor::<GpuImageColorSpaceEnum>::new(self.0.push("color_space"))
     }
 
+    pub fn layout(&self) -> EnumFieldAccessor::<GpuImageLayoutEnum> {
+        EnumFieldAccessor::<GpuImageLayoutEnum>::new(self.0.push("layout"))
+    }
+
     pub fn mip_generation(&self) -> EnumFieldAccessor::<GpuImageMipGenerationEnum> {
         EnumFieldAccessor::<GpuImageMipGenerationEnum>::new(self.0.push("mip_generation"))
     }
@@ -385,6 +405,10 @@ impl<'a> GpuImageAssetRef<'a> {
         EnumFieldRef::<GpuImageColorSpaceEnum>::new(self.0.push("color_space"), self.1.clone())
     }
 
+    pub fn layout(&self) -> EnumFieldRef::<GpuImageLayoutEnum> {
+        EnumFieldRef::<GpuImageLayoutEnum>::new(self.0.push("layout"), self.1.clone())
+    }
+
     pub fn mip_generation(&self) -> EnumFieldRef::<GpuImageMipGenerationEnum> {
         EnumFieldRef::<GpuImageMipGenerationEnum>::new(self.0.push("mip_generation"), self.1.clone())
     }
@@ -416,6 +440,10 @@ impl<'a> GpuImageAssetRefMut<'a> {
         EnumFieldRefMut::<GpuImageColorSpaceEnum>::new(self.0.push("color_space"), &self.1)
     }
 
+    pub fn layout(self: &'a Self) -> EnumFieldRefMut::<GpuImageLayoutEnum> {
+        EnumFieldRefMut::<GpuImageLayoutEnum>::new(self.0.push("layout"), &self.1)
+    }
+
     pub fn mip_generation(self: &'a Self) -> EnumFieldRefMut::<GpuImageMipGenerationEnum> {
         EnumFieldRefMut::<GpuImageMipGenerationEnum>::new(self.0.push("mip_generation"), &self.1)
     }
@@ -451,6 +479,10 @@ impl GpuImageAssetRecord {
         EnumField::<GpuImageColorSpaceEnum>::new(self.0.push("color_space"), &self.1)
     }
 
+    pub fn layout(self: &Self) -> EnumField::<GpuImageLayoutEnum> {
+        EnumField::<GpuImageLayoutEnum>::new(self.0.push("layout"), &self.1)
+    }
+
     pub fn mip_generation(self: &Self) -> EnumField::<GpuImageMipGenerationEnum> {
         EnumField::<GpuImageMipGenerationEnum>::new(self.0.push("mip_generation"), &self.1)
     }
@@ -798,6 +830,34 @@ impl GpuImageImportedDataRecord {
     }
 }
 #[derive(Copy, Clone)]
+pub enum GpuImageLayoutEnum {
+    Image2D,
+    VolumeStrip,
+}
+
+impl Enum for GpuImageLayoutEnum {
+    fn to_symbol_name(&self) -> &'static str {
+        match self {
+            GpuImageLayoutEnum::Image2D => "Image2D",
+            GpuImageLayoutEnum::VolumeStrip => "VolumeStrip",
+        }
+    }
+
+    fn from_symbol_name(str: &str) -> Option<GpuImageLayoutEnum> {
+        match str {
+            "Image2D" => Some(GpuImageLayoutEnum::Image2D),
+            "VolumeStrip" => Some(GpuImageLayoutEnum::VolumeStrip),
+            _ => None,
+        }
+    }
+}
+
+impl GpuImageLayoutEnum {
+    pub fn schema_name() -> &'static str {
+        "GpuImageLayout"
+    }
+}
+#[derive(Copy, Clone)]
 pub enum GpuImageMipGenerationEnum {
     NoMips,
     Precomputed,
@@ -1474,6 +1534,10 @@ impl MaterialPassAccessor {
         StringFieldAccessor::new(self.0.push("phase"))
     }
 
+    pub fn specialization_constants(&self) -> StringFieldAccessor {
+        StringFieldAccessor::new(self.0.push("specialization_constants"))
+    }
+
     pub fn vertex_stage(&self) -> GraphicsPipelineShaderStageAccessor {
         GraphicsPipelineShaderStageAccessor::new(self.0.push("vertex_stage"))
     }
@@ -1509,6 +1573,10 @@ impl<'a> MaterialPassRef<'a> {
         StringFieldRef::new(self.0.push("phase"), self.1.clone())
     }
 
+    pub fn specialization_constants(&self) -> StringFieldRef {
+        StringFieldRef::new(self.0.push("specialization_constants"), self.1.clone())
+    }
+
     pub fn vertex_stage(&self) -> GraphicsPipelineShaderStageRef {
         GraphicsPipelineShaderStageRef::new(self.0.push("vertex_stage"), self.1.clone())
     }
@@ -1544,6 +1612,10 @@ impl<'a> MaterialPassRefMut<'a> {
         StringFieldRefMut::new(self.0.push("phase"), &self.1)
     }
 
+    pub fn specialization_constants(self: &'a Self) -> StringFieldRefMut {
+        StringFieldRefMut::new(self.0.push("specialization_constants"), &self.1)
+    }
+
     pub fn vertex_stage(self: &'a Self) -> GraphicsPipelineShaderStageRefMut {
         GraphicsPipelineShaderStageRefMut::new(self.0.push("vertex_stage"), &self.1)
     }
@@ -1583,6 +1655,10 @@ impl MaterialPassRecord {
         StringField::new(self.0.push("phase"), &self.1)
     }
 
+    pub fn specialization_constants(self: &Self) -> StringField {
+        StringField::new(self.0.push("specialization_constants"), &self.1)
+    }
+
     pub fn vertex_stage(self: &Self) -> GraphicsPipelineShaderStageRecord {
         GraphicsPipelineShaderStageRecord::new(self.0.push("vertex_stage"), &self.1)
     }
@@ -9,4 +9,5 @@ cargo run --package rafx-shader-processor -- ^
 --package-vk ^
 --package-metal ^
 --package-dx12 ^
+--package-webgpu ^
 --for-rafx-framework-crate && cargo fmt && cargo test --package rafx-plugins
\ No newline at end of file
@@ -5,4 +5,5 @@ cargo run --package rafx-shader-processor -- \
 --package-vk \
 --package-metal \
 --package-dx12 \
+--package-webgpu \
 --for-rafx-framework-crate && cargo fmt && cargo test --package rafx-framework
@@ -298,6 +298,13 @@ impl<'extract, 'entry, ExtractJobEntryPointsT: ExtractJobEntryPoints<'extract>>
             .render_object_id
     }
 
+    /// The distance from the view frustum to the object's bounding sphere reported by the
+    /// visibility query for this view.
+    pub fn distance_from_view_frustum(&self) -> f32 {
+        self.render_object_instance_per_view
+            .distance_from_view_frustum
+    }
+
     pub fn render_object_instance_data(
         &self
     ) -> &<ExtractJobEntryPointsT::FramePacketDataT as FramePacketData>::RenderObjectInstanceData
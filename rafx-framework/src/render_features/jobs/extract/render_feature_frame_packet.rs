@@ -20,9 +20,14 @@ pub trait RenderFeatureFramePacket: Downcast + Send + Sync {
         view_index: ViewFrameIndex,
         render_object_instance_id: RenderObjectInstanceId,
         render_object_instance: RenderObjectInstance,
+        distance_from_view_frustum: f32,
     ) -> RenderObjectInstancePerViewId {
         let view_packet = self.render_feature_view_packet_mut(view_index);
-        view_packet.push_render_object_instance(render_object_instance_id, render_object_instance)
+        view_packet.push_render_object_instance(
+            render_object_instance_id,
+            render_object_instance,
+            distance_from_view_frustum,
+        )
     }
 
     fn push_volume(
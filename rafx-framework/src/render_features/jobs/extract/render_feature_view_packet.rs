@@ -23,6 +23,7 @@ pub trait RenderFeatureViewPacket: Downcast + Sync + Send {
         &mut self,
         render_object_instance_id: RenderObjectInstanceId,
         render_object_instance: RenderObjectInstance,
+        distance_from_view_frustum: f32,
     ) -> RenderObjectInstancePerViewId;
 
     fn push_volume(
@@ -37,7 +37,15 @@ pub struct FramePacketMetadata {
     pub frame_packet_size: FramePacketSize,
 }
 
-pub type VisibilityVecs = Vec<Vec<RenderObjectInstance>>;
+/// A `RenderObjectInstance` visible in a `RenderView` and the distance from the view frustum to the
+/// object's bounding sphere reported by the visibility query.
+#[derive(Copy, Clone, Debug)]
+pub struct VisibleRenderObjectInstance {
+    pub render_object_instance: RenderObjectInstance,
+    pub distance_from_view_frustum: f32,
+}
+
+pub type VisibilityVecs = Vec<Vec<VisibleRenderObjectInstance>>;
 
 pub struct RenderJobExtractAllocationContext {
     pub frame_packet_metadata: Vec<TrustCell<FramePacketMetadata>>,
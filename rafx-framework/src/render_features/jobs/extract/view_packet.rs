@@ -74,12 +74,14 @@ impl<FramePacketDataT: 'static + FramePacketData> RenderFeatureViewPacket
         &mut self,
         render_object_instance_id: RenderObjectInstanceId,
         render_object_instance: RenderObjectInstance,
+        distance_from_view_frustum: f32,
     ) -> RenderObjectInstancePerViewId {
         let index = self.render_object_instances.len();
         self.render_object_instances
             .push(RenderObjectInstancePerView::new(
                 render_object_instance_id,
                 render_object_instance,
+                distance_from_view_frustum,
             ));
         index as RenderObjectInstancePerViewId
     }
@@ -93,20 +95,23 @@ impl<FramePacketDataT: 'static + FramePacketData> RenderFeatureViewPacket
 }
 
 /// A specific `RenderObjectInstance` as viewed by some `RenderView`.
-#[derive(Copy, Eq, PartialEq, Hash, Clone, Debug)]
+#[derive(Copy, PartialEq, Clone, Debug)]
 pub struct RenderObjectInstancePerView {
     pub render_object_instance_id: RenderObjectInstanceId,
     pub render_object_instance: RenderObjectInstance,
+    pub distance_from_view_frustum: f32,
 }
 
 impl RenderObjectInstancePerView {
     pub fn new(
         render_object_instance_id: RenderObjectInstanceId,
         render_object_instance: RenderObjectInstance,
+        distance_from_view_frustum: f32,
     ) -> Self {
         Self {
             render_object_instance_id,
             render_object_instance,
+            distance_from_view_frustum,
         }
     }
 }
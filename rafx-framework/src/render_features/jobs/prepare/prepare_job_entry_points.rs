@@ -373,6 +373,13 @@ impl<'prepare, 'entry, PrepareJobEntryPointsT: PrepareJobEntryPoints<'prepare>>
         )
     }
 
+    pub fn render_object_instance_per_view_data(
+        &self
+    ) -> &<PrepareJobEntryPointsT::FramePacketDataT as FramePacketData>::RenderObjectInstancePerViewData
+    {
+        self.view_packet.render_object_instances_data.get(self.id)
+    }
+
     pub fn render_object_instance_submit_data(
         &self
     ) -> &<PrepareJobEntryPointsT::SubmitPacketDataT as SubmitPacketData>::RenderObjectInstanceSubmitData
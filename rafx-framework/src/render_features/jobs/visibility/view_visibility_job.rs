@@ -1,5 +1,5 @@
 use crate::render_features::render_features_prelude::*;
-use crate::render_features::VisibilityVecs;
+use crate::render_features::{VisibilityVecs, VisibleRenderObjectInstance};
 use crate::visibility::VisibilityObjectId;
 use rafx_base::owned_pool::Pooled;
 use rafx_visibility::{VisibilityObjectHandle, VisibilityResult};
@@ -22,7 +22,7 @@ impl RenderViewVisibilityQuery {
     pub fn render_object_instances_per_view(
         &self,
         feature_index: RenderFeatureIndex,
-    ) -> Option<&Vec<RenderObjectInstance>> {
+    ) -> Option<&Vec<VisibleRenderObjectInstance>> {
         self.per_view_render_objects
             .get(feature_index as usize)
             .and_then(|feature| {
@@ -92,11 +92,16 @@ impl<'a> ViewVisibilityJob<'a> {
                     continue;
                 }
 
-                all_render_objects[render_feature_index as usize].push(RenderObjectInstance::new(
-                    object_id,
-                    render_object_id.as_id(),
-                    visibility_object_id,
-                ));
+                all_render_objects[render_feature_index as usize].push(
+                    VisibleRenderObjectInstance {
+                        render_object_instance: RenderObjectInstance::new(
+                            object_id,
+                            render_object_id.as_id(),
+                            visibility_object_id,
+                        ),
+                        distance_from_view_frustum: visibility_handle.distance_from_view_frustum,
+                    },
+                );
             }
         }
 
@@ -107,7 +112,9 @@ impl<'a> ViewVisibilityJob<'a> {
             }
 
             profiling::scope!("sort visible render objects");
-            feature.sort_unstable_by_key(|render_object| render_object.render_object_id);
+            feature.sort_unstable_by_key(|render_object| {
+                render_object.render_object_instance.render_object_id
+            });
         }
 
         let per_view_render_objects = all_render_objects;
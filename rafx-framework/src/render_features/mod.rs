@@ -9,6 +9,7 @@ pub use render_objects::RenderObjectsMap;
 
 mod render_views;
 pub use render_views::RenderView;
+pub use render_views::RenderViewCameraLens;
 pub use render_views::RenderViewCount;
 pub use render_views::RenderViewDepthRange;
 pub use render_views::RenderViewIndex;
@@ -35,6 +35,7 @@ impl RenderViewSet {
         proj: Mat4,
         extents: (u32, u32),
         depth_range: RenderViewDepthRange,
+        camera_lens: Option<RenderViewCameraLens>,
         render_phase_mask: RenderPhaseMask,
         render_feature_mask: RenderFeatureMask,
         render_feature_flag_mask: RenderFeatureFlagMask,
@@ -49,6 +50,7 @@ impl RenderViewSet {
             proj,
             extents,
             depth_range,
+            camera_lens,
             render_phase_mask,
             render_feature_mask,
             render_feature_flag_mask,
@@ -75,6 +77,7 @@ pub struct RenderViewInner {
     // XY of the plane in screen coordinates, the size the framebuffer would be for 1:1
     extents: (u32, u32),
     depth_range: RenderViewDepthRange,
+    camera_lens: Option<RenderViewCameraLens>,
     render_phase_mask: RenderPhaseMask,
     render_feature_mask: RenderFeatureMask,
     render_feature_flag_mask: RenderFeatureFlagMask,
@@ -162,6 +165,33 @@ impl RenderViewDepthRange {
     }
 }
 
+/// Describes the lens of a physical camera. Views that have one can be rendered with effects that
+/// simulate the camera, like depth of field. Distances are in world units.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RenderViewCameraLens {
+    pub focal_length: f32,
+    /// Focal length divided by the diameter of the aperture
+    pub f_stop: f32,
+    /// Distance from the camera at which objects are perfectly in focus
+    pub focus_distance: f32,
+}
+
+impl Default for RenderViewCameraLens {
+    fn default() -> Self {
+        RenderViewCameraLens {
+            focal_length: 0.05,
+            f_stop: 2.8,
+            focus_distance: 10.0,
+        }
+    }
+}
+
+impl RenderViewCameraLens {
+    pub fn aperture_diameter(&self) -> f32 {
+        self.focal_length / self.f_stop
+    }
+}
+
 // This creates a `[AtomicI32; MAX_RENDER_FEATURE_COUNT as usize]` where all values are -1.
 fn init_view_frame_indices() -> [AtomicI32; MAX_RENDER_FEATURE_COUNT as usize] {
     let mut view_count_by_feature: [MaybeUninit<AtomicI32>; MAX_RENDER_FEATURE_COUNT as usize] =
@@ -198,6 +228,7 @@ impl RenderView {
         proj: Mat4,
         extents: (u32, u32),
         depth_range: RenderViewDepthRange,
+        camera_lens: Option<RenderViewCameraLens>,
         render_phase_mask: RenderPhaseMask,
         render_feature_mask: RenderFeatureMask,
         render_feature_flag_mask: RenderFeatureFlagMask,
@@ -217,6 +248,7 @@ impl RenderView {
             view_frame_indices: init_view_frame_indices(),
             extents,
             depth_range,
+            camera_lens,
             render_phase_mask,
             render_feature_mask,
             render_feature_flag_mask,
@@ -304,6 +336,10 @@ impl RenderView {
         &self.inner.depth_range
     }
 
+    pub fn camera_lens(&self) -> Option<&RenderViewCameraLens> {
+        self.inner.camera_lens.as_ref()
+    }
+
     pub fn debug_name(&self) -> &str {
         &self.inner.debug_name
     }
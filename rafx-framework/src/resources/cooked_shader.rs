@@ -65,5 +65,5 @@ pub fn load_material_pass_from_packages(
     }
 
     let reflected_shader = ReflectedShader::new(resources, &shader_modules, &entry_points)?;
-    reflected_shader.load_material_pass(resources, fixed_function_state, debug_name)
+    reflected_shader.load_material_pass(resources, fixed_function_state, &[], debug_name)
 }
@@ -3,7 +3,7 @@ use crate::{
     RafxResult, ReflectedShader, ResourceArc, ResourceContext, ShaderModuleResource,
     SlotNameLookup,
 };
-use rafx_api::{RafxReflectedEntryPoint, RafxShaderStageFlags};
+use rafx_api::{RafxReflectedEntryPoint, RafxShaderStageFlags, RafxSpecializationConstantValue};
 use serde::{Deserialize, Serialize};
 use std::ops::Deref;
 use std::sync::Arc;
@@ -33,6 +33,14 @@ impl Into<RafxShaderStageFlags> for MaterialShaderStage {
     }
 }
 
+/// Sets a specialization constant declared by the material pass's shaders. Constants are looked up
+/// by the name they were declared with in the shader.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct MaterialPassSpecializationConstant {
+    pub name: String,
+    pub value: RafxSpecializationConstantValue,
+}
+
 pub struct MaterialPassInner {
     pub shader_modules: Vec<ResourceArc<ShaderModuleResource>>,
 
@@ -62,6 +70,7 @@ impl MaterialPass {
         fixed_function_state: Arc<FixedFunctionState>,
         shader_modules: Vec<ResourceArc<ShaderModuleResource>>,
         entry_points: &[&RafxReflectedEntryPoint],
+        specialization_constants: &[MaterialPassSpecializationConstant],
     ) -> RafxResult<MaterialPass> {
         let reflected_shader =
             ReflectedShader::new(resource_context.resources(), &shader_modules, entry_points)?;
@@ -69,6 +78,7 @@ impl MaterialPass {
         let material_pass = reflected_shader.load_material_pass(
             resource_context.resources(),
             fixed_function_state,
+            specialization_constants,
             debug_name,
         )?;
 
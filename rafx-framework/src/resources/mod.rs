@@ -49,6 +49,7 @@ pub use dyn_commands::DynCommandPoolAllocator;
 
 mod material;
 pub use material::MaterialPass;
+pub use material::MaterialPassSpecializationConstant;
 pub use material::MaterialShaderStage;
 
 mod pipeline_cache;
@@ -6,8 +6,8 @@ use crate::resources::vertex_data::{VertexDataSetLayout, VertexDataSetLayoutHash
 use crate::{GraphicsPipelineResource, MaterialPassResource, ResourceArc, ResourceLookupSet};
 use fnv::{FnvHashMap, FnvHashSet, FnvHasher};
 use rafx_api::{
-    RafxFormat, RafxResult, RafxSampleCount, RafxVertexLayout, RafxVertexLayoutAttribute,
-    RafxVertexLayoutBuffer,
+    RafxFormat, RafxResult, RafxSampleCount, RafxSpecializationConstant, RafxVertexLayout,
+    RafxVertexLayoutAttribute, RafxVertexLayoutBuffer,
 };
 use std::hash::{Hash, Hasher};
 use std::sync::{Arc, Mutex};
@@ -79,11 +79,23 @@ impl GraphicsPipelineRenderTargetMetaHash {
     }
 }
 
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+struct SpecializationConstantsHash(u64);
+impl SpecializationConstantsHash {
+    fn new(specialization_constants: &[RafxSpecializationConstant]) -> Self {
+        let mut hasher = FnvHasher::default();
+        specialization_constants.hash(&mut hasher);
+        let hash = hasher.finish();
+        SpecializationConstantsHash(hash)
+    }
+}
+
 #[derive(PartialEq, Eq, Hash)]
 struct CachedGraphicsPipelineKey {
     material_pass: ResourceId,
     render_target_meta_hash: GraphicsPipelineRenderTargetMetaHash,
     vertex_data_set_layout: VertexDataSetLayoutHash,
+    specialization_constants: SpecializationConstantsHash,
 }
 
 #[derive(PartialEq, Eq)]
@@ -376,13 +388,33 @@ impl GraphicsPipelineCache {
         material_pass: &ResourceArc<MaterialPassResource>,
         render_target_meta: &GraphicsPipelineRenderTargetMeta,
         vertex_data_set_layout: &VertexDataSetLayout,
+    ) -> Option<ResourceArc<GraphicsPipelineResource>> {
+        self.try_get_specialized_graphics_pipeline(
+            render_phase_index,
+            material_pass,
+            render_target_meta,
+            vertex_data_set_layout,
+            &[],
+        )
+    }
+
+    // Like try_get_graphics_pipeline, but the given specialization constants override the ones set
+    // by the material pass
+    pub fn try_get_specialized_graphics_pipeline(
+        &self,
+        render_phase_index: Option<RenderPhaseIndex>,
+        material_pass: &ResourceArc<MaterialPassResource>,
+        render_target_meta: &GraphicsPipelineRenderTargetMeta,
+        vertex_data_set_layout: &VertexDataSetLayout,
+        specialization_constants: &[RafxSpecializationConstant],
     ) -> Option<ResourceArc<GraphicsPipelineResource>> {
         // RafxResult is always Ok if returning cached pipelines
-        self.graphics_pipeline(
+        self.specialized_graphics_pipeline(
             render_phase_index,
             material_pass,
             render_target_meta,
             vertex_data_set_layout,
+            specialization_constants,
             false,
         )
         .map(|x| x.unwrap())
@@ -395,12 +427,32 @@ impl GraphicsPipelineCache {
         render_target_meta: &GraphicsPipelineRenderTargetMeta,
         vertex_data_set_layout: &VertexDataSetLayout,
     ) -> RafxResult<ResourceArc<GraphicsPipelineResource>> {
-        // graphics_pipeline never returns none if create_if_missing is true
-        self.graphics_pipeline(
+        self.get_or_create_specialized_graphics_pipeline(
+            render_phase_index,
+            material_pass,
+            render_target_meta,
+            vertex_data_set_layout,
+            &[],
+        )
+    }
+
+    // Like get_or_create_graphics_pipeline, but the given specialization constants override the
+    // ones set by the material pass
+    pub fn get_or_create_specialized_graphics_pipeline(
+        &self,
+        render_phase_index: Option<RenderPhaseIndex>,
+        material_pass: &ResourceArc<MaterialPassResource>,
+        render_target_meta: &GraphicsPipelineRenderTargetMeta,
+        vertex_data_set_layout: &VertexDataSetLayout,
+        specialization_constants: &[RafxSpecializationConstant],
+    ) -> RafxResult<ResourceArc<GraphicsPipelineResource>> {
+        // specialized_graphics_pipeline never returns none if create_if_missing is true
+        self.specialized_graphics_pipeline(
             render_phase_index,
             material_pass,
             render_target_meta,
             vertex_data_set_layout,
+            specialization_constants,
             true,
         )
         .ok_or("Failed to create graphics pipeline")?
@@ -413,11 +465,31 @@ impl GraphicsPipelineCache {
         render_target_meta: &GraphicsPipelineRenderTargetMeta,
         vertex_data_set_layout: &VertexDataSetLayout,
         create_if_missing: bool,
+    ) -> Option<RafxResult<ResourceArc<GraphicsPipelineResource>>> {
+        self.specialized_graphics_pipeline(
+            render_phase_index,
+            material_pass,
+            render_target_meta,
+            vertex_data_set_layout,
+            &[],
+            create_if_missing,
+        )
+    }
+
+    pub fn specialized_graphics_pipeline(
+        &self,
+        render_phase_index: Option<RenderPhaseIndex>,
+        material_pass: &ResourceArc<MaterialPassResource>,
+        render_target_meta: &GraphicsPipelineRenderTargetMeta,
+        vertex_data_set_layout: &VertexDataSetLayout,
+        specialization_constants: &[RafxSpecializationConstant],
+        create_if_missing: bool,
     ) -> Option<RafxResult<ResourceArc<GraphicsPipelineResource>>> {
         let key = CachedGraphicsPipelineKey {
             material_pass: material_pass.resource_hash(),
             render_target_meta_hash: render_target_meta.render_target_meta_hash(),
             vertex_data_set_layout: vertex_data_set_layout.hash(),
+            specialization_constants: SpecializationConstantsHash::new(specialization_constants),
         };
 
         let mut guard = self.inner.lock().unwrap();
@@ -531,6 +603,7 @@ impl GraphicsPipelineCache {
                 render_target_meta,
                 vertex_data_set_layout.primitive_topology(),
                 &vertex_layout,
+                specialization_constants,
             );
 
             if let Ok(pipeline) = pipeline {
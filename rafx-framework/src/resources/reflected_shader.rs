@@ -1,12 +1,14 @@
 use crate::resources::resource_lookup::ShaderResource;
 use crate::{
-    ComputePipelineResource, FixedFunctionState, MaterialPassResource, MaterialPassVertexInput,
-    ResourceArc, ResourceLookupSet, SamplerResource, ShaderModuleResource,
+    ComputePipelineResource, FixedFunctionState, MaterialPassResource,
+    MaterialPassSpecializationConstant, MaterialPassVertexInput, ResourceArc, ResourceLookupSet,
+    SamplerResource, ShaderModuleResource,
 };
 use fnv::{FnvHashMap, FnvHashSet};
 use rafx_api::{
-    RafxImmutableSamplerKey, RafxReflectedDescriptorSetLayout, RafxReflectedEntryPoint, RafxResult,
-    RafxShaderStageFlags,
+    RafxImmutableSamplerKey, RafxReflectedDescriptorSetLayout, RafxReflectedEntryPoint,
+    RafxReflectedSpecializationConstant, RafxResult, RafxShaderStageFlags,
+    RafxSpecializationConstant,
 };
 use std::sync::Arc;
 
@@ -22,6 +24,7 @@ pub struct ReflectedShaderMetadata {
     pub descriptor_set_layout_defs: Vec<RafxReflectedDescriptorSetLayout>,
     pub slot_name_lookup: SlotNameLookup,
     pub vertex_inputs: Option<Arc<Vec<MaterialPassVertexInput>>>,
+    pub specialization_constants: Vec<RafxReflectedSpecializationConstant>,
 }
 
 impl ReflectedShaderMetadata {
@@ -29,6 +32,7 @@ impl ReflectedShaderMetadata {
         let mut descriptor_set_layout_defs = Vec::default();
         let mut slot_name_lookup: SlotNameLookup = Default::default();
         let mut vertex_inputs = None;
+        let mut specialization_constants = Vec::<RafxReflectedSpecializationConstant>::default();
 
         // We iterate through the entry points we will hit for each stage. Each stage may define
         // slightly different reflection data/bindings in use.
@@ -72,6 +76,27 @@ impl ReflectedShaderMetadata {
             //     }
             // }
 
+            for constant in &reflection_data.specialization_constants {
+                let existing_constant = specialization_constants
+                    .iter()
+                    .find(|x| x.constant_id == constant.constant_id);
+
+                if let Some(existing_constant) = existing_constant {
+                    if existing_constant != constant {
+                        let error = format!(
+                            "Load Material Failed - Pass is using shaders in different stages with different declarations for specialization constant constant_id={} ({} and {})",
+                            constant.constant_id,
+                            existing_constant.name,
+                            constant.name
+                        );
+                        log::error!("{}", error);
+                        return Err(error)?;
+                    }
+                } else {
+                    specialization_constants.push(constant.clone());
+                }
+            }
+
             for (set_index, layout) in reflection_data.descriptor_set_layouts.iter().enumerate() {
                 // Expand the layout def to include the given set index
                 while descriptor_set_layout_defs.len() <= set_index {
@@ -178,8 +203,50 @@ impl ReflectedShaderMetadata {
             vertex_inputs,
             descriptor_set_layout_defs,
             slot_name_lookup,
+            specialization_constants,
         })
     }
+
+    // Converts constants specified by name to the constant IDs declared by the shaders
+    pub fn resolve_specialization_constants(
+        &self,
+        specialization_constants: &[MaterialPassSpecializationConstant],
+    ) -> RafxResult<Vec<RafxSpecializationConstant>> {
+        let mut resolved = Vec::with_capacity(specialization_constants.len());
+        for constant in specialization_constants {
+            let reflected_constant = self
+                .specialization_constants
+                .iter()
+                .find(|x| x.name == constant.name)
+                .ok_or_else(|| {
+                    let error = format!(
+                        "Load Material Failed - Pass sets specialization constant {}, but the shaders do not declare it",
+                        constant.name
+                    );
+                    log::error!("{}", error);
+                    error
+                })?;
+
+            if !reflected_constant
+                .default_value
+                .is_same_type(&constant.value)
+            {
+                let error = format!(
+                    "Load Material Failed - Pass sets specialization constant {} to {:?}, but the shaders declare it as {:?}",
+                    constant.name, constant.value, reflected_constant.default_value
+                );
+                log::error!("{}", error);
+                return Err(error)?;
+            }
+
+            resolved.push(RafxSpecializationConstant {
+                constant_id: reflected_constant.constant_id,
+                value: constant.value,
+            });
+        }
+
+        Ok(resolved)
+    }
 }
 
 pub struct ReflectedShader {
@@ -240,6 +307,7 @@ impl ReflectedShader {
         &self,
         resources: &ResourceLookupSet,
         fixed_function_state: Arc<FixedFunctionState>,
+        specialization_constants: &[MaterialPassSpecializationConstant],
         debug_name: Option<&str>,
     ) -> RafxResult<ResourceArc<MaterialPassResource>> {
         let vertex_inputs = self
@@ -249,6 +317,11 @@ impl ReflectedShader {
             .ok_or_else(|| "The material pass does not specify a vertex shader")?
             .clone();
 
+        let specialization_constants = Arc::new(
+            self.metadata
+                .resolve_specialization_constants(specialization_constants)?,
+        );
+
         //
         // Root Signature
         //
@@ -290,6 +363,7 @@ impl ReflectedShader {
             descriptor_set_layouts,
             fixed_function_state,
             vertex_inputs.clone(),
+            specialization_constants,
             debug_name,
         )
     }
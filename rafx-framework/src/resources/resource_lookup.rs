@@ -373,6 +373,7 @@ impl MaterialPassHash {
         descriptor_set_layout_hashes: &[DescriptorSetLayoutHash],
         fixed_function_state: &FixedFunctionState,
         vertex_inputs: &[MaterialPassVertexInput],
+        specialization_constants: &[RafxSpecializationConstant],
     ) -> Self {
         let mut hasher = FnvHasher::default();
         shader_hash.hash(&mut hasher);
@@ -382,6 +383,7 @@ impl MaterialPassHash {
         for vertex_input in vertex_inputs {
             vertex_input.hash(&mut hasher);
         }
+        specialization_constants.hash(&mut hasher);
         let hash = hasher.finish();
         MaterialPassHash(hash)
     }
@@ -395,6 +397,7 @@ impl GraphicsPipelineHash {
         render_target_meta: &GraphicsPipelineRenderTargetMeta,
         primitive_topology: RafxPrimitiveTopology,
         vertex_layout: &RafxVertexLayout,
+        specialization_constants: &[RafxSpecializationConstant],
     ) -> Self {
         let mut hasher = FnvHasher::default();
         material_pass_key.hash(&mut hasher);
@@ -403,6 +406,7 @@ impl GraphicsPipelineHash {
             .hash(&mut hasher);
         primitive_topology.hash(&mut hasher);
         vertex_layout.hash(&mut hasher);
+        specialization_constants.hash(&mut hasher);
         let hash = hasher.finish();
         GraphicsPipelineHash(hash)
     }
@@ -553,6 +557,7 @@ pub struct MaterialPassResource {
     pub descriptor_set_layouts: Arc<Vec<ResourceArc<DescriptorSetLayoutResource>>>,
     pub fixed_function_state: Arc<FixedFunctionState>,
     pub vertex_inputs: Arc<Vec<MaterialPassVertexInput>>,
+    pub specialization_constants: Arc<Vec<RafxSpecializationConstant>>,
     pub debug_name: Option<String>,
 }
 
@@ -935,6 +940,7 @@ impl ResourceLookupSet {
         descriptor_sets: Vec<ResourceArc<DescriptorSetLayoutResource>>,
         fixed_function_state: Arc<FixedFunctionState>,
         vertex_inputs: Arc<Vec<MaterialPassVertexInput>>,
+        specialization_constants: Arc<Vec<RafxSpecializationConstant>>,
         debug_name: Option<&str>,
     ) -> RafxResult<ResourceArc<MaterialPassResource>> {
         let descriptor_set_hashes: Vec<_> = descriptor_sets
@@ -947,6 +953,7 @@ impl ResourceLookupSet {
             &descriptor_set_hashes,
             &*fixed_function_state,
             &*vertex_inputs,
+            &*specialization_constants,
         );
         let material_pass_key = MaterialPassKey { hash };
 
@@ -962,6 +969,7 @@ impl ResourceLookupSet {
                     shader,
                     fixed_function_state,
                     vertex_inputs,
+                    specialization_constants,
                     debug_name,
                 };
                 Ok(resource)
@@ -974,12 +982,14 @@ impl ResourceLookupSet {
         render_target_meta: &GraphicsPipelineRenderTargetMeta,
         primitive_topology: RafxPrimitiveTopology,
         vertex_layout: &RafxVertexLayout,
+        specialization_constants: &[RafxSpecializationConstant],
     ) -> RafxResult<ResourceArc<GraphicsPipelineResource>> {
         let hash = GraphicsPipelineHash::new(
             material_pass.get_raw().material_pass_key.hash,
             render_target_meta,
             primitive_topology,
             vertex_layout,
+            specialization_constants,
         );
 
         let pipeline_key = GraphicsPipelineKey { hash };
@@ -995,6 +1005,16 @@ impl ResourceLookupSet {
                     .map(|x| format!("RafxGraphicsPipeline {}", x));
 
                 let fixed_function_state = &material_pass.get_raw().fixed_function_state;
+
+                // Constants specified for this pipeline override the material pass's constants
+                let mut merged_specialization_constants =
+                    (*material_pass.get_raw().specialization_constants).clone();
+                for constant in specialization_constants {
+                    merged_specialization_constants
+                        .retain(|x| x.constant_id != constant.constant_id);
+                    merged_specialization_constants.push(*constant);
+                }
+
                 let pipeline = self.inner.device_context.create_graphics_pipeline(
                     &RafxGraphicsPipelineDef {
                         root_signature: &material_pass
@@ -1015,6 +1035,7 @@ impl ResourceLookupSet {
                         color_formats: &render_target_meta.color_formats(),
                         depth_stencil_format: render_target_meta.depth_stencil_format(),
                         sample_count: render_target_meta.sample_count(),
+                        specialization_constants: &merged_specialization_constants,
                         debug_name: debug_name.as_deref(),
                     },
                 )?;
@@ -1057,6 +1078,7 @@ impl ResourceLookupSet {
                         .create_compute_pipeline(&RafxComputePipelineDef {
                             root_signature: &root_signature.get_raw().root_signature,
                             shader: &shader.get_raw().shader,
+                            specialization_constants: &[],
                             debug_name: debug_name.as_deref(),
                         })?;
                 log::trace!("Created compute pipeline {:?}", rafx_pipeline);
@@ -33,6 +33,9 @@ impl GpuImageDataColorSpace {
 pub struct GpuImageData {
     pub width: u32,
     pub height: u32,
+    // Greater than 1 for 3D images. These only have a single layer and mip level, which holds each
+    // slice in order.
+    pub depth: u32,
     pub format: RafxFormat,
     pub layers: Vec<GpuImageDataLayer>,
 }
@@ -60,6 +63,7 @@ impl GpuImageData {
         GpuImageData {
             width: layers[0].mip_levels[0].width,
             height: layers[0].mip_levels[0].height,
+            depth: 1,
             format,
             layers,
         }
@@ -74,6 +78,23 @@ impl GpuImageData {
         GpuImageData {
             width,
             height,
+            depth: 1,
+            format,
+            layers: vec![GpuImageDataLayer::new_single_level(width, height, data)],
+        }
+    }
+
+    pub fn new_volume_image_from_bytes(
+        width: u32,
+        height: u32,
+        depth: u32,
+        format: RafxFormat,
+        data: Vec<u8>,
+    ) -> Self {
+        GpuImageData {
+            width,
+            height,
+            depth,
             format,
             layers: vec![GpuImageDataLayer::new_single_level(width, height, data)],
         }
@@ -92,11 +113,12 @@ impl GpuImageData {
 
                 // Determine size required for rows, factoring in required pitch per row. Don't
                 // count the first image
-                let row_size = level.data.len() as u64 / level.height as u64;
+                let row_count = level.height as u64 * self.depth as u64;
+                let row_size = level.data.len() as u64 / row_count;
                 bytes_required += rafx_base::memory::round_size_up_to_alignment_u64(
                     row_size,
                     required_row_alignment as u64,
-                ) * (level.height as u64 - 1);
+                ) * (row_count - 1);
             }
         }
 
@@ -109,6 +131,10 @@ impl GpuImageData {
         let first_level = &first_layer.mip_levels[0];
         assert_eq!(first_level.width, self.width);
         assert_eq!(first_level.height, self.height);
+        if self.depth > 1 {
+            assert_eq!(self.layers.len(), 1);
+            assert_eq!(first_layer.mip_levels.len(), 1);
+        }
 
         for layer in &self.layers {
             assert_eq!(first_layer.mip_levels.len(), layer.mip_levels.len());
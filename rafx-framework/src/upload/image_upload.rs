@@ -56,7 +56,7 @@ pub fn enqueue_load_image(
     //
     // Determine mip count
     //
-    let mip_count = if params.generate_mips {
+    let mip_count = if params.generate_mips && image_data.depth <= 1 {
         rafx_api::extra::mipmaps::mip_level_max_count_for_image_size(
             image_data.width,
             image_data.height,
@@ -82,6 +82,10 @@ pub fn enqueue_load_image(
                 image_data.format.block_height_in_pixels(),
             ) / image_data.format.block_height_in_pixels();
 
+            // The slices of a 3D image are stored one after another, so they can be pushed as if
+            // they were one tall image
+            let level_block_height = level_block_height * image_data.depth;
+
             // A block format's row may be multiple pixels high
             let row_size_in_bytes =
                 level_block_width * image_data.format.block_or_pixel_size_in_bytes();
@@ -121,14 +125,18 @@ pub fn enqueue_load_image(
         extents: RafxExtents3D {
             width: image_data.width,
             height: image_data.height,
-            depth: 1,
+            depth: image_data.depth,
         },
         array_length: layer_count,
         mip_count,
         sample_count: RafxSampleCount::SampleCount1,
         format: image_data.format,
         resource_type: params.resource_type,
-        dimensions: RafxTextureDimensions::Dim2D,
+        dimensions: if image_data.depth > 1 {
+            RafxTextureDimensions::Dim3D
+        } else {
+            RafxTextureDimensions::Dim2D
+        },
     })?;
 
     //
@@ -177,9 +185,10 @@ pub fn enqueue_load_image(
     }
 
     log::debug!(
-        "upload image {}x{} format {:?} layers: {} levels: {} generate mips: {} resource type: {:?}",
+        "upload image {}x{}x{} format {:?} layers: {} levels: {} generate mips: {} resource type: {:?}",
         image_data.width,
         image_data.height,
+        image_data.depth,
         image_data.format,
         layer_count,
         mip_count,
@@ -16,6 +16,12 @@ pub use visibility_object_allocator::VisibilityObjectId;
 mod object_id;
 pub use object_id::ObjectId;
 
+mod visibility_zone;
+pub use visibility_zone::VisibilityZone;
+
+mod visibility_portal_arc;
+pub use visibility_portal_arc::VisibilityPortalArc;
+
 mod visibility_resource;
 pub use visibility_resource::VisibilityObjectInfo;
 pub use visibility_resource::VisibilityResource;
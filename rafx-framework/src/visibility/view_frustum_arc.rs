@@ -1,16 +1,20 @@
-use crate::visibility::{VisibilityConfig, VisibilityResource};
+use crate::visibility::{VisibilityConfig, VisibilityResource, VisibilityZone};
 use crate::RafxResult;
 use crossbeam_channel::Sender;
 use glam::Vec3;
-use parking_lot::{RwLock, RwLockReadGuard};
+use parking_lot::{Mutex, RwLock, RwLockReadGuard};
 use rafx_api::RafxError;
-use rafx_visibility::{AsyncCommand, Projection, ViewFrustumHandle, VisibilityQuery, ZoneHandle};
+use rafx_visibility::{
+    AsyncCommand, OcclusionBuffer, Projection, ViewFrustumHandle, VisibilityQuery, ZoneHandle,
+};
 use std::sync::Arc;
 
 pub type ViewFrustumId = (Option<ViewFrustumHandle>, Option<ViewFrustumHandle>);
 
 struct ViewFrustumArcInner {
     visibility_query: RwLock<VisibilityQuery>,
+    // Allocated on the first query with `VisibilityConfig::enable_occlusion_culling` set.
+    occlusion_buffer: Mutex<Option<OcclusionBuffer>>,
     static_view_frustum: Option<ViewFrustumRaii>,
     dynamic_view_frustum: Option<ViewFrustumRaii>,
 }
@@ -28,6 +32,7 @@ impl ViewFrustumArc {
         Self {
             inner: Arc::new(ViewFrustumArcInner {
                 visibility_query: Default::default(),
+                occlusion_buffer: Mutex::new(None),
                 static_view_frustum,
                 dynamic_view_frustum,
             }),
@@ -41,23 +46,41 @@ impl ViewFrustumArc {
         )
     }
 
-    #[allow(dead_code)]
-    pub(super) fn set_zone(
+    /// Moves the view frustum into `zone`. It sees the objects in `zone` and the `VisibilityZones`
+    /// visible through portals.
+    pub fn set_zone(
         &self,
-        static_zone: Option<ZoneHandle>,
-        dynamic_zone: Option<ZoneHandle>,
+        zone: VisibilityZone,
     ) -> &Self {
         if let Some(static_view_frustum) = &self.inner.static_view_frustum {
-            static_view_frustum.set_zone(static_zone);
+            static_view_frustum.set_zone(Some(zone.static_zone));
         }
 
         if let Some(dynamic_view_frustum) = &self.inner.dynamic_view_frustum {
-            dynamic_view_frustum.set_zone(dynamic_zone);
+            dynamic_view_frustum.set_zone(Some(zone.dynamic_zone));
         }
 
         self
     }
 
+    /// Returns the eye position last applied by `VisibilityResource::update`.
+    pub fn eye_position(
+        &self,
+        visibility_resource: &VisibilityResource,
+    ) -> Option<Vec3> {
+        let handle = self
+            .inner
+            .static_view_frustum
+            .as_ref()
+            .or(self.inner.dynamic_view_frustum.as_ref())?
+            .handle;
+        visibility_resource
+            .world()
+            .inner
+            .view_frustum(handle)
+            .map(|view_frustum| view_frustum.eye_position())
+    }
+
     pub fn set_transform(
         &self,
         eye: Vec3,
@@ -107,10 +130,43 @@ impl ViewFrustumArc {
             if let Some(dynamic_view_frustum) = &self.inner.dynamic_view_frustum {
                 dynamic_view_frustum.query_visibility(visibility_resource, &mut results)?;
             }
+
+            if visibility_config.enable_occlusion_culling {
+                self.cull_occluded_objects(visibility_resource, &mut results);
+            }
         }
 
         Ok(self.inner.visibility_query.read())
     }
+
+    // Occluders in the static zone hide dynamic objects and vice versa, so both zones
+    // share one `OcclusionBuffer`.
+    fn cull_occluded_objects(
+        &self,
+        visibility_resource: &VisibilityResource,
+        results: &mut VisibilityQuery,
+    ) {
+        let world = visibility_resource.world();
+        let view_frustum = self
+            .inner
+            .static_view_frustum
+            .as_ref()
+            .or(self.inner.dynamic_view_frustum.as_ref())
+            .and_then(|view_frustum| world.inner.view_frustum(view_frustum.handle));
+
+        if let Some(view_frustum) = view_frustum {
+            let mut occlusion_buffer = self.inner.occlusion_buffer.lock();
+            let occlusion_buffer = occlusion_buffer.get_or_insert_with(|| {
+                OcclusionBuffer::new(
+                    OcclusionBuffer::DEFAULT_WIDTH,
+                    OcclusionBuffer::DEFAULT_HEIGHT,
+                )
+            });
+            occlusion_buffer.clear(view_frustum);
+            world.rasterize_occluders(&results.objects, occlusion_buffer);
+            world.remove_occluded_objects(occlusion_buffer, &mut results.objects);
+        }
+    }
 }
 
 // An RAII object for a ViewFrustumHandle
@@ -135,7 +191,6 @@ impl ViewFrustumRaii {
         Self { handle, commands }
     }
 
-    #[allow(dead_code)]
     pub(super) fn set_zone(
         &self,
         zone: Option<ZoneHandle>,
@@ -1,12 +1,15 @@
 #[derive(Copy, Clone, Debug)]
 pub struct VisibilityConfig {
     pub enable_visibility_update: bool,
+    // Objects hidden behind occluders are removed from the visibility results on the CPU
+    pub enable_occlusion_culling: bool,
 }
 
 impl Default for VisibilityConfig {
     fn default() -> Self {
         VisibilityConfig {
             enable_visibility_update: true,
+            enable_occlusion_culling: false,
         }
     }
 }
@@ -3,8 +3,9 @@ use crate::visibility::view_frustum_arc::{ViewFrustumArc, ViewFrustumRaii};
 use crate::visibility::visibility_object_arc::{
     CullModel, VisibilityObjectArc, VisibilityObjectRaii, VisibilityObjectWeakArcInner,
 };
-use crate::visibility::ObjectId;
+use crate::visibility::{ObjectId, VisibilityPortalArc, VisibilityZone};
 use crossbeam_channel::{Receiver, Sender};
+use glam::Vec3;
 use rafx_visibility::{ModelHandle, VisibilityWorld, ZoneHandle};
 use slotmap::SlotMap;
 use slotmap::{new_key_type, Key};
@@ -42,6 +43,14 @@ impl VisibilityObjectAllocator {
         self.visibility_world.update();
     }
 
+    pub(super) fn new_zone(&mut self) -> VisibilityZone {
+        let inner = &mut self.visibility_world.inner;
+        VisibilityZone {
+            static_zone: inner.new_zone(),
+            dynamic_zone: inner.new_zone(),
+        }
+    }
+
     pub fn try_destroy_model(
         &mut self,
         model: ModelHandle,
@@ -56,6 +65,7 @@ impl VisibilityObjectAllocator {
         let inner = &mut self.visibility_world.inner;
         match cull_model {
             CullModel::Mesh(polygons) => Some(inner.new_model(polygons)),
+            CullModel::ExactMesh(polygons) => Some(inner.new_model_with_triangles(polygons)),
             CullModel::Sphere(radius) => Some(inner.new_bounding_sphere(radius)),
             CullModel::Quad(width, height) => Some(inner.new_quad(width, height)),
             CullModel::VisibleBounds(bounds) => Some(inner.new_visible_bounds(bounds)),
@@ -138,4 +148,33 @@ impl VisibilityObjectAllocator {
 
         ViewFrustumArc::new(static_view_frustum, dynamic_view_frustum)
     }
+
+    pub fn new_portal(
+        &mut self,
+        zone_a: VisibilityZone,
+        zone_b: VisibilityZone,
+        polygon: Vec<Vec3>,
+    ) -> VisibilityPortalArc {
+        let inner = &mut self.visibility_world.inner;
+        let static_portal = inner.new_portal();
+        inner.set_portal_polygon(static_portal, polygon.clone());
+        inner.set_portal_zones(
+            static_portal,
+            Some((zone_a.static_zone, zone_b.static_zone)),
+        );
+
+        let dynamic_portal = inner.new_portal();
+        inner.set_portal_polygon(dynamic_portal, polygon);
+        inner.set_portal_zones(
+            dynamic_portal,
+            Some((zone_a.dynamic_zone, zone_b.dynamic_zone)),
+        );
+
+        VisibilityPortalArc::new(
+            static_portal,
+            dynamic_portal,
+            (zone_a, zone_b),
+            self.visibility_world.new_async_command_sender(),
+        )
+    }
 }
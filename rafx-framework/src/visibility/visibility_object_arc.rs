@@ -13,6 +13,8 @@ use std::sync::{Arc, Weak};
 
 pub enum CullModel {
     Mesh(PolygonSoup),
+    // Keeps the triangles for exact ray and overlap queries and for occluders
+    ExactMesh(PolygonSoup),
     VisibleBounds(VisibleBounds),
     Sphere(f32),
     Quad(f32, f32),
@@ -24,6 +26,10 @@ impl CullModel {
         CullModel::Mesh(polygon_soup)
     }
 
+    pub fn exact_mesh(polygon_soup: PolygonSoup) -> CullModel {
+        CullModel::ExactMesh(polygon_soup)
+    }
+
     pub fn visible_bounds(model: VisibleBounds) -> CullModel {
         CullModel::VisibleBounds(model)
     }
@@ -108,7 +114,6 @@ impl VisibilityObjectArc {
             .store(visibility_object_id.data().as_ffi(), Ordering::Relaxed);
     }
 
-    #[allow(dead_code)]
     pub(super) fn set_zone(
         &self,
         zone: Option<ZoneHandle>,
@@ -138,6 +143,16 @@ impl VisibilityObjectArc {
         self
     }
 
+    /// Occluders hide the objects behind them when `VisibilityConfig::enable_occlusion_culling` is set.
+    /// The cull model must be a `CullModel::ExactMesh`.
+    pub fn set_occluder(
+        &self,
+        occluder: bool,
+    ) -> &Self {
+        self.inner.object.set_occluder(occluder);
+        self
+    }
+
     pub fn set_transform(
         &self,
         translation: Vec3,
@@ -181,7 +196,6 @@ impl VisibilityObjectRaii {
         }
     }
 
-    #[allow(dead_code)]
     pub(super) fn set_zone(
         &self,
         zone: Option<ZoneHandle>,
@@ -210,6 +224,16 @@ impl VisibilityObjectRaii {
         self
     }
 
+    pub fn set_occluder(
+        &self,
+        occluder: bool,
+    ) -> &Self {
+        self.commands
+            .send(AsyncCommand::SetObjectOccluder(self.handle, occluder))
+            .expect("Unable to send SetObjectOccluder command.");
+        self
+    }
+
     pub fn set_transform(
         &self,
         translation: Vec3,
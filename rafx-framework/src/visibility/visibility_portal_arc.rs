@@ -0,0 +1,73 @@
+use crate::visibility::VisibilityZone;
+use crossbeam_channel::Sender;
+use glam::Vec3;
+use rafx_visibility::{AsyncCommand, PortalHandle};
+use std::sync::Arc;
+
+// An RAII object for the static and dynamic PortalHandles connecting two VisibilityZones
+struct VisibilityPortalRaii {
+    commands: Sender<AsyncCommand>,
+    static_portal: PortalHandle,
+    dynamic_portal: PortalHandle,
+    zones: (VisibilityZone, VisibilityZone),
+}
+
+impl Drop for VisibilityPortalRaii {
+    fn drop(&mut self) {
+        let _ = self
+            .commands
+            .send(AsyncCommand::DestroyPortal(self.static_portal));
+        let _ = self
+            .commands
+            .send(AsyncCommand::DestroyPortal(self.dynamic_portal));
+    }
+}
+
+#[derive(Clone)]
+pub struct VisibilityPortalArc {
+    inner: Arc<VisibilityPortalRaii>,
+}
+
+impl VisibilityPortalArc {
+    pub(super) fn new(
+        static_portal: PortalHandle,
+        dynamic_portal: PortalHandle,
+        zones: (VisibilityZone, VisibilityZone),
+        commands: Sender<AsyncCommand>,
+    ) -> Self {
+        Self {
+            inner: Arc::new(VisibilityPortalRaii {
+                commands,
+                static_portal,
+                dynamic_portal,
+                zones,
+            }),
+        }
+    }
+
+    pub fn zones(&self) -> (VisibilityZone, VisibilityZone) {
+        self.inner.zones
+    }
+
+    /// Sets the portal's convex polygon, e.g. to follow a door that is opening.
+    pub fn set_polygon(
+        &self,
+        polygon: Vec<Vec3>,
+    ) -> &Self {
+        self.inner
+            .commands
+            .send(AsyncCommand::SetPortalPolygon(
+                self.inner.static_portal,
+                polygon.clone(),
+            ))
+            .expect("Unable to send SetPortalPolygon command.");
+        self.inner
+            .commands
+            .send(AsyncCommand::SetPortalPolygon(
+                self.inner.dynamic_portal,
+                polygon,
+            ))
+            .expect("Unable to send SetPortalPolygon command.");
+        self
+    }
+}
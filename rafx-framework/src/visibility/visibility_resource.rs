@@ -4,10 +4,13 @@ use crate::visibility::visibility_object_allocator::{
     VisibilityObjectAllocator, VisibilityObjectId,
 };
 use crate::visibility::visibility_object_arc::{CullModel, VisibilityObjectArc};
-use crate::visibility::ObjectId;
+use crate::visibility::{ObjectId, VisibilityPortalArc, VisibilityZone};
 use crossbeam_channel::Sender;
-use rafx_visibility::geometry::Transform;
-use rafx_visibility::{AsyncCommand, ModelHandle, VisibilityObject, VisibilityWorld, ZoneHandle};
+use glam::Vec3;
+use rafx_visibility::geometry::{AxisAlignedBoundingBox, BoundingSphere, Ray, Transform};
+use rafx_visibility::{
+    AsyncCommand, ModelHandle, QueryHits, VisibilityObject, VisibilityWorld, ZoneHandle,
+};
 
 pub struct VisibilityObjectInfo<'a> {
     arc: VisibilityObjectArc,
@@ -41,6 +44,7 @@ pub struct VisibilityResource {
     commands: Sender<AsyncCommand>,
     dynamic_zone: ZoneHandle,
     static_zone: ZoneHandle,
+    zones: Vec<VisibilityZone>,
 }
 
 impl VisibilityResource {
@@ -55,6 +59,7 @@ impl VisibilityResource {
             commands,
             static_zone,
             dynamic_zone,
+            zones: Default::default(),
             allocator,
         }
     }
@@ -82,6 +87,55 @@ impl VisibilityResource {
             .new_view_frustum(None, Some(self.dynamic_zone))
     }
 
+    /// The `VisibilityZone` that objects and view frustums are registered in.
+    pub fn default_zone(&self) -> VisibilityZone {
+        VisibilityZone {
+            static_zone: self.static_zone,
+            dynamic_zone: self.dynamic_zone,
+        }
+    }
+
+    /// Returns a new `VisibilityZone`, e.g. for a room. Objects and view frustums are moved into it with
+    /// `set_static_object_zone`, `set_dynamic_object_zone`, and `ViewFrustumArc::set_zone`.
+    pub fn register_zone(&mut self) -> VisibilityZone {
+        let zone = self.allocator.new_zone();
+        self.zones.push(zone);
+        zone
+    }
+
+    /// Returns a smart pointer to a portal connecting `zone_a` and `zone_b`. The `polygon` must be convex.
+    /// A view in either `VisibilityZone` sees into the other `VisibilityZone` through the `polygon`.
+    pub fn register_portal(
+        &mut self,
+        zone_a: VisibilityZone,
+        zone_b: VisibilityZone,
+        polygon: Vec<Vec3>,
+    ) -> VisibilityPortalArc {
+        self.allocator.new_portal(zone_a, zone_b, polygon)
+    }
+
+    /// Moves an object returned by `register_static_object` into `zone`.
+    pub fn set_static_object_zone(
+        &self,
+        object: &VisibilityObjectArc,
+        zone: VisibilityZone,
+    ) {
+        object.set_zone(Some(zone.static_zone));
+    }
+
+    /// Moves an object returned by `register_dynamic_object` into `zone`.
+    pub fn set_dynamic_object_zone(
+        &self,
+        object: &VisibilityObjectArc,
+        zone: VisibilityZone,
+    ) {
+        object.set_zone(Some(zone.dynamic_zone));
+    }
+
+    fn all_zones(&self) -> impl Iterator<Item = VisibilityZone> + '_ {
+        std::iter::once(self.default_zone()).chain(self.zones.iter().copied())
+    }
+
     /// Returns a smart pointer to a handle representing a static object.
     /// A static object is a hint to the visibility world that the object's transform changes rarely.
     /// Most geometry in the world is static -- buildings, trees, rocks, grass, and so on.
@@ -117,6 +171,52 @@ impl VisibilityResource {
             .new_object(object_id, cull_model, render_objects, Some(zone))
     }
 
+    /// Returns the static and dynamic objects in every `VisibilityZone` hit by `ray` within `max_distance`, nearest first.
+    /// The `id` of each hit is the `VisibilityObjectId` of the object.
+    pub fn query_ray(
+        &self,
+        ray: &Ray,
+        max_distance: f32,
+    ) -> QueryHits {
+        let mut hits = QueryHits::default();
+        let world = self.world();
+        for zone in self.all_zones() {
+            world.query_ray(zone.static_zone, ray, max_distance, &mut hits);
+            world.query_ray(zone.dynamic_zone, ray, max_distance, &mut hits);
+        }
+        hits
+    }
+
+    /// Returns the static and dynamic objects in every `VisibilityZone` overlapping `sphere`.
+    /// The `id` of each hit is the `VisibilityObjectId` of the object.
+    pub fn query_sphere_overlap(
+        &self,
+        sphere: &BoundingSphere,
+    ) -> QueryHits {
+        let mut hits = QueryHits::default();
+        let world = self.world();
+        for zone in self.all_zones() {
+            world.query_sphere_overlap(zone.static_zone, sphere, &mut hits);
+            world.query_sphere_overlap(zone.dynamic_zone, sphere, &mut hits);
+        }
+        hits
+    }
+
+    /// Returns the static and dynamic objects in every `VisibilityZone` overlapping `aabb`.
+    /// The `id` of each hit is the `VisibilityObjectId` of the object.
+    pub fn query_aabb_overlap(
+        &self,
+        aabb: &AxisAlignedBoundingBox,
+    ) -> QueryHits {
+        let mut hits = QueryHits::default();
+        let world = self.world();
+        for zone in self.all_zones() {
+            world.query_aabb_overlap(zone.static_zone, aabb, &mut hits);
+            world.query_aabb_overlap(zone.dynamic_zone, aabb, &mut hits);
+        }
+        hits
+    }
+
     pub fn visibility_object_arc(
         &self,
         id: VisibilityObjectId,
@@ -151,5 +251,13 @@ impl Drop for VisibilityResource {
         let _ = self
             .commands
             .send(AsyncCommand::DestroyZone(self.dynamic_zone));
+        for zone in &self.zones {
+            let _ = self
+                .commands
+                .send(AsyncCommand::DestroyZone(zone.static_zone));
+            let _ = self
+                .commands
+                .send(AsyncCommand::DestroyZone(zone.dynamic_zone));
+        }
     }
 }
@@ -0,0 +1,9 @@
+use rafx_visibility::ZoneHandle;
+
+/// A pair of static and dynamic `Zones` in the `VisibilityResource`. A view only sees the objects in its
+/// own `VisibilityZone` and the `VisibilityZones` visible through portals, e.g. the rooms of a building.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct VisibilityZone {
+    pub(super) static_zone: ZoneHandle,
+    pub(super) dynamic_zone: ZoneHandle,
+}
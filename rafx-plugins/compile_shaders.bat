@@ -8,4 +8,5 @@ cargo run --package rafx-shader-processor -- ^
 --cooked-shaders-path assets/rafx-plugins/shaders ^
 --package-vk ^
 --package-dx12 ^
---package-metal && cargo fmt && cargo test --package rafx-plugins
\ No newline at end of file
+--package-metal ^
+--package-webgpu && cargo fmt && cargo test --package rafx-plugins
\ No newline at end of file
@@ -7,4 +7,5 @@ cargo run --package rafx-shader-processor -- \
 --metal-generated-src-path processed_shaders/msl \
 --package-vk \
 --package-dx12 \
---package-metal && cargo fmt && cargo test --package rafx-plugins
\ No newline at end of file
+--package-metal \
+--package-webgpu && cargo fmt && cargo test --package rafx-plugins
\ No newline at end of file
@@ -0,0 +1,86 @@
+#version 450
+#extension GL_ARB_separate_shader_objects : enable
+#extension GL_ARB_shading_language_420pack : enable
+
+#include "../util/taa_jitter.glsl"
+#include "decal.glsl"
+
+// Deferred box-projected decals. The decal's box is rasterized and each pixel's position is
+// reconstructed from the depth prepass. Pixels inside the box are projected along the box's -Z axis
+// onto the decal's textures.
+//
+// The outputs are blended with (One, OneMinusSrcAlpha), so each target accumulates premultiplied
+// values and the combined coverage of all decals. mesh_adv_pbr_frag.glsl applies them to the surface.
+layout (location = 0) out vec4 out_albedo;
+layout (location = 1) out vec4 out_normal;
+layout (location = 2) out vec4 out_roughness;
+
+// Surfaces facing away from the projection direction by more than this fade out, to hide the
+// stretching on the sides of objects
+const float DECAL_ANGLE_FADE_START = 0.5;
+const float DECAL_ANGLE_FADE_END = 0.2;
+
+void main() {
+    vec2 depth_texture_size = textureSize(sampler2D(depth_texture, smp_depth), 0);
+    vec2 screen_uv = gl_FragCoord.xy / depth_texture_size;
+    float depth = texture(sampler2D(depth_texture, smp_depth), screen_uv).r;
+
+    // The depth prepass was rendered with jitter, so remove it to get the unjittered position
+    vec4 position_cs = vec4((screen_uv * 2.0 - 1.0) * vec2(1, -1), depth, 1.0);
+    position_cs = subtract_jitter(position_cs, per_view_data.jitter_amount);
+    vec4 position_ws = per_view_data.inverse_view_proj * position_cs;
+    position_ws /= position_ws.w;
+
+    vec3 position_ds = (per_decal_data.inverse_model * position_ws).xyz;
+    vec2 decal_uv = vec2(position_ds.x + 0.5, 0.5 - position_ds.y);
+
+    // Derivatives must be taken before any pixels are discarded
+    vec3 position_ws_dx = dFdx(position_ws.xyz);
+    vec3 position_ws_dy = dFdy(position_ws.xyz);
+    vec2 decal_uv_dx = dFdx(decal_uv);
+    vec2 decal_uv_dy = dFdy(decal_uv);
+
+    // Depth of 0 is the far plane (reverse Z), so nothing was drawn there
+    if (depth == 0.0 || any(greaterThan(abs(position_ds), vec3(0.5)))) {
+        discard;
+    }
+
+    // Flat surface normal reconstructed from depth, flipped to face the camera
+    vec3 surface_normal_ws = normalize(cross(position_ws_dx, position_ws_dy));
+    vec3 position_vs = (per_view_data.view * position_ws).xyz;
+    if (dot(mat3(per_view_data.view) * surface_normal_ws, position_vs) > 0.0) {
+        surface_normal_ws = -surface_normal_ws;
+    }
+
+    vec3 decal_x_ws = normalize(per_decal_data.model[0].xyz);
+    vec3 decal_z_ws = normalize(per_decal_data.model[2].xyz);
+    float facing = dot(surface_normal_ws, decal_z_ws);
+
+    float alpha = per_decal_data.tint.a * smoothstep(DECAL_ANGLE_FADE_END, DECAL_ANGLE_FADE_START, facing);
+
+    out_albedo = vec4(0.0);
+    if (per_decal_data.has_albedo_texture) {
+        vec4 albedo = textureGrad(sampler2D(albedo_texture, smp), decal_uv, decal_uv_dx, decal_uv_dy);
+        alpha *= albedo.a;
+        out_albedo = vec4(albedo.rgb * per_decal_data.tint.rgb * alpha, alpha);
+    }
+
+    out_normal = vec4(0.0);
+    if (per_decal_data.has_normal_texture) {
+        vec3 normal_ts = textureGrad(sampler2D(normal_texture, smp), decal_uv, decal_uv_dx, decal_uv_dy).xyz * 2.0 - 1.0;
+
+        // The tangent frame follows the decal's x axis across the surface, so the normal map is
+        // applied relative to the surface rather than to the box
+        vec3 tangent_ws = normalize(decal_x_ws - surface_normal_ws * dot(decal_x_ws, surface_normal_ws));
+        vec3 binormal_ws = cross(surface_normal_ws, tangent_ws);
+        vec3 normal_ws = normalize(mat3(tangent_ws, binormal_ws, surface_normal_ws) * normal_ts);
+        vec3 normal_vs = normalize(mat3(per_view_data.view) * normal_ws);
+        out_normal = vec4((normal_vs * 0.5 + 0.5) * alpha, alpha);
+    }
+
+    out_roughness = vec4(0.0);
+    if (per_decal_data.has_roughness_texture) {
+        float roughness = textureGrad(sampler2D(roughness_texture, smp), decal_uv, decal_uv_dx, decal_uv_dy).g;
+        out_roughness = vec4(roughness * alpha, 0.0, 0.0, alpha);
+    }
+}
@@ -0,0 +1,60 @@
+// @[export]
+// @[internal_buffer]
+layout (set = 0, binding = 0) uniform PerViewData {
+    mat4 view;
+    mat4 view_proj;
+    mat4 inverse_view_proj;
+    vec2 jitter_amount;
+} per_view_data;
+
+// @[immutable_samplers([
+//     (
+//         mag_filter: Linear,
+//         min_filter: Linear,
+//         mip_map_mode: Linear,
+//         address_mode_u: ClampToEdge,
+//         address_mode_v: ClampToEdge,
+//         address_mode_w: ClampToEdge,
+//         max_anisotropy: 16.0,
+//     )
+// ])]
+layout (set = 0, binding = 1) uniform sampler smp;
+
+// @[immutable_samplers([
+//     (
+//         mag_filter: Nearest,
+//         min_filter: Nearest,
+//         mip_map_mode: Nearest,
+//         address_mode_u: ClampToEdge,
+//         address_mode_v: ClampToEdge,
+//         address_mode_w: ClampToEdge,
+//     )
+// ])]
+layout (set = 0, binding = 2) uniform sampler smp_depth;
+
+// Depth from the depth prepass, bound by the render graph
+// @[export]
+layout (set = 1, binding = 0) uniform texture2D depth_texture;
+
+// @[export]
+// @[internal_buffer]
+layout (set = 2, binding = 0) uniform PerDecalData {
+    // Transforms a unit cube centered on the origin to the decal's box
+    mat4 model;
+    mat4 inverse_model;
+    // rgb multiplies the albedo, a is the decal's opacity
+    vec4 tint;
+    bool has_albedo_texture;
+    bool has_normal_texture;
+    bool has_roughness_texture;
+} per_decal_data;
+
+// @[export]
+layout (set = 2, binding = 1) uniform texture2D albedo_texture;
+
+// @[export]
+layout (set = 2, binding = 2) uniform texture2D normal_texture;
+
+// Roughness is read from the green channel, the same as glTF metallic/roughness textures
+// @[export]
+layout (set = 2, binding = 3) uniform texture2D roughness_texture;
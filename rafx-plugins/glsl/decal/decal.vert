@@ -0,0 +1,24 @@
+#version 450
+#extension GL_ARB_separate_shader_objects : enable
+#extension GL_ARB_shading_language_420pack : enable
+
+#include "../util/taa_jitter.glsl"
+#include "decal.glsl"
+
+// Corners of the unit cube, with bit 0/1/2 of the index selecting +x/+y/+z. Triangles are wound
+// counter-clockwise when viewed from outside the cube.
+const uint CUBE_INDICES[36] = uint[](
+    1, 3, 7, 1, 7, 5, // +x
+    0, 4, 6, 0, 6, 2, // -x
+    2, 6, 7, 2, 7, 3, // +y
+    0, 1, 5, 0, 5, 4, // -y
+    4, 5, 7, 4, 7, 6, // +z
+    0, 2, 3, 0, 3, 1  // -z
+);
+
+void main() {
+    uint corner = CUBE_INDICES[gl_VertexIndex];
+    vec3 position = vec3(corner & 1, (corner >> 1) & 1, (corner >> 2) & 1) - 0.5;
+    vec4 position_clip = per_view_data.view_proj * per_decal_data.model * vec4(position, 1.0);
+    gl_Position = add_jitter(position_clip, per_view_data.jitter_amount);
+}
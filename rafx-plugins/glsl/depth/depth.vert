@@ -7,13 +7,28 @@
 // @[semantic("POSITION")]
 layout (location = 0) in vec3 in_pos;
 
+#ifdef PLATFORM_WEBGPU
+// WGSL doesn't allow matrix vertex inputs, so pass the columns separately
+// @[semantic("MODELMATRIX0")]
+layout (location = 1) in vec4 in_model_matrix_0;
+// @[semantic("MODELMATRIX1")]
+layout (location = 2) in vec4 in_model_matrix_1;
+// @[semantic("MODELMATRIX2")]
+layout (location = 3) in vec4 in_model_matrix_2;
+// @[semantic("MODELMATRIX3")]
+layout (location = 4) in vec4 in_model_matrix_3;
+#else
 // @[semantic("MODELMATRIX")]
 layout (location = 1) in mat4 in_model_matrix; // Uses locations 1-4. The semantic will be named `MODELMATRIX0` through `MODELMATRIX3`.
 // layout (location = 2) in mat4 in_model_matrix;
 // layout (location = 3) in mat4 in_model_matrix;
 // layout (location = 4) in mat4 in_model_matrix;
+#endif
 
 void main() {
+#ifdef PLATFORM_WEBGPU
+    mat4 in_model_matrix = mat4(in_model_matrix_0, in_model_matrix_1, in_model_matrix_2, in_model_matrix_3);
+#endif
     mat4 model_view_proj = per_view_data.view_proj * in_model_matrix;
     gl_Position = model_view_proj * vec4(in_pos, 1.0);
 }
@@ -4,13 +4,19 @@
 #include "../mesh_adv/mesh_adv_types.glsl"
 #include "depth_velocity.glsl"
 #include "../util/taa_jitter.glsl"
+#include "../mesh_adv/lod_fade.glsl"
 
 layout(location = 0) in vec4 in_old_position_clip;
 layout(location = 1) in vec4 in_new_position_clip;
+layout(location = 2) flat in float in_lod_fade;
 
 layout(location = 0) out vec2 out_velocity;
 
 void main() {
+    if (lod_fade_discard(in_lod_fade, gl_FragCoord.xy)) {
+        discard;
+    }
+
     // Perspective divide
     vec2 old_position_ndc = (in_old_position_clip.xy/abs(in_old_position_clip.w));
     vec2 new_position_ndc = (in_new_position_clip.xy/abs(in_new_position_clip.w));
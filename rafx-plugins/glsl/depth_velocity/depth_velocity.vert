@@ -11,6 +11,7 @@ layout (location = 0) in vec3 in_pos;
 
 layout (location = 0) out vec4 out_old_position_clip;
 layout (location = 1) out vec4 out_new_position_clip;
+layout (location = 2) flat out float out_lod_fade;
 
 invariant gl_Position;
 
@@ -41,4 +42,6 @@ void main() {
 
     mat4 previous_model_matrix = all_transforms.transforms[draw_data.transform_index].previous_model_matrix;
     out_old_position_clip = per_view_data.previous_view_proj * previous_model_matrix * vec4(in_pos, 1.0);
+
+    out_lod_fade = draw_data.lod_fade;
 }
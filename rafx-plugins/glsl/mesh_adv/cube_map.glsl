@@ -0,0 +1,55 @@
+// Helpers for addressing the faces of cubemaps that are stored as 2D images, following the Vulkan
+// face order (+X, -X, +Y, -Y, +Z, -Z)
+
+// Return value: xy=UV, z=face index
+// Based on https://www.gamedev.net/forums/topic/687535-implementing-a-cube-map-lookup-function/5337472/
+vec3 cube_sample_to_uv_and_face_index(vec3 dir)
+{
+	vec3 dirAbs = abs(dir);
+	float faceIndex;
+	float ma;
+	vec2 uv;
+
+	if(dirAbs.z >= dirAbs.x && dirAbs.z >= dirAbs.y)
+	{
+		// Either -Z or +Z
+		faceIndex = dir.z < 0.0 ? 5.0 : 4.0;
+		ma = 0.5 / dirAbs.z;
+		uv = vec2(dir.z < 0.0 ? -dir.x : dir.x, -dir.y);
+	}
+	else if(dirAbs.y >= dirAbs.x)
+	{
+	    // Either -Y or +Y
+		faceIndex = dir.y < 0.0 ? 3.0 : 2.0;
+		ma = 0.5 / dirAbs.y;
+		uv = vec2(dir.x, dir.y < 0.0 ? -dir.z : dir.z);
+	}
+	else
+	{
+	    // Either -X or +X
+		faceIndex = dir.x < 0.0 ? 1.0 : 0.0;
+		ma = 0.5 / dirAbs.x;
+		uv = vec2(dir.x < 0.0 ? dir.z : -dir.z, -dir.y);
+	}
+
+	return vec3(uv * ma + 0.5, faceIndex);
+}
+
+// Direction through the center of texel (x, y) of face z. This is the inverse of
+// cube_sample_to_uv_and_face_index
+vec3 cube_face_direction(uvec3 texel, uint face_size) {
+    vec2 uv = (vec2(texel.xy) + 0.5) / float(face_size);
+    vec2 st = uv * 2.0 - 1.0;
+
+    vec3 dir;
+    switch (texel.z) {
+        case 0: dir = vec3(1.0, -st.y, -st.x); break;
+        case 1: dir = vec3(-1.0, -st.y, st.x); break;
+        case 2: dir = vec3(st.x, 1.0, st.y); break;
+        case 3: dir = vec3(st.x, -1.0, -st.y); break;
+        case 4: dir = vec3(st.x, -st.y, 1.0); break;
+        default: dir = vec3(-st.x, -st.y, -1.0); break;
+    }
+
+    return normalize(dir);
+}
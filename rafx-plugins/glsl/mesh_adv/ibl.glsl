@@ -0,0 +1,41 @@
+// Shared helpers for generating split-sum image-based lighting from a cubemap
+
+#include "cube_map.glsl"
+
+const float IBL_PI = 3.14159265359;
+
+// Low-discrepancy 2D point set, i is in [0, n)
+vec2 hammersley(uint i, uint n) {
+    uint bits = i;
+    bits = (bits << 16u) | (bits >> 16u);
+    bits = ((bits & 0x55555555u) << 1u) | ((bits & 0xAAAAAAAAu) >> 1u);
+    bits = ((bits & 0x33333333u) << 2u) | ((bits & 0xCCCCCCCCu) >> 2u);
+    bits = ((bits & 0x0F0F0F0Fu) << 4u) | ((bits & 0xF0F0F0F0u) >> 4u);
+    bits = ((bits & 0x00FF00FFu) << 8u) | ((bits & 0xFF00FF00u) >> 8u);
+    float radical_inverse = float(bits) * 2.3283064365386963e-10;
+    return vec2(float(i) / float(n), radical_inverse);
+}
+
+// Builds an orthonormal basis around n and transforms a tangent space vector into it
+vec3 tangent_to_world(vec3 v, vec3 n) {
+    vec3 up = abs(n.z) < 0.999 ? vec3(0.0, 0.0, 1.0) : vec3(1.0, 0.0, 0.0);
+    vec3 tangent = normalize(cross(up, n));
+    vec3 bitangent = cross(n, tangent);
+    return normalize(tangent * v.x + bitangent * v.y + n * v.z);
+}
+
+// Samples a half vector from the GGX distribution. Roughness is perceptual (squared to get alpha)
+vec3 importance_sample_ggx(vec2 xi, vec3 n, float roughness) {
+    float a = roughness * roughness;
+    float phi = 2.0 * IBL_PI * xi.x;
+    float cos_theta = sqrt((1.0 - xi.y) / (1.0 + (a * a - 1.0) * xi.y));
+    float sin_theta = sqrt(1.0 - cos_theta * cos_theta);
+    return tangent_to_world(vec3(cos(phi) * sin_theta, sin(phi) * sin_theta, cos_theta), n);
+}
+
+float ibl_ndf_ggx(float n_dot_h, float roughness) {
+    float a = roughness * roughness;
+    float a2 = a * a;
+    float d = n_dot_h * n_dot_h * (a2 - 1.0) + 1.0;
+    return a2 / (IBL_PI * d * d);
+}
@@ -0,0 +1,83 @@
+// WebGPU version of lights_bin.comp. naga can't translate the SPIR-V atomics, so this must be kept
+// in sync with the GLSL by hand.
+
+struct Light {
+    position: vec3<f32>,
+    radius: f32,
+}
+
+struct ClusterAABB {
+    min: vec3<f32>,
+    max: vec3<f32>,
+}
+
+struct BinLightsConfig {
+    clusters: array<ClusterAABB, 3072>, // 8*16*24 clusters
+}
+
+struct LightsInputList {
+    light_count: u32,
+    lights: array<Light, 512>, // Support for 512 total lights in the scene
+}
+
+// LightBitfieldsData in lights_processing.glsl
+struct LightBitfieldsData {
+    light_count: array<atomic<u32>, 3072>, // 1 per cluster (8*16*24 clusters)
+    bitfields: array<u32, 49152>, // (512 lights * (8*16*24=3072) clusters) / 32 bits in a uint)
+}
+
+struct LightBitfields {
+    data: LightBitfieldsData,
+}
+
+@group(0) @binding(0)
+var<storage, read> config: BinLightsConfig;
+
+@group(0) @binding(1)
+var<storage, read> lights: LightsInputList;
+
+// Atomics need read_write storage
+@group(0) @binding(2)
+var<storage, read_write> bitfields: LightBitfields;
+
+// Each invocation handles 1 cluster and 512 lights (encoded as bits in a u32[16])
+@compute @workgroup_size(64, 16, 1)
+fn main(@builtin(global_invocation_id) global_invocation_id: vec3<u32>) {
+    // Get the cluster we will check against
+    let cluster_index = global_invocation_id.x;
+    let cluster = config.clusters[cluster_index];
+
+    // The Nth group of 32 (u32-sized) lights we will process
+    let light_u32_index = global_invocation_id.y;
+
+    // Iterate through the lights in the u32-sized group and accumulate high bits for intersecting lights into the
+    // bitfield
+    var count = 0u;
+    var bitfield = 0u;
+    for (var light_bit_index = 0u; light_bit_index < 32u; light_bit_index++) {
+        // Find the light we will process
+        let light_index = light_u32_index * 32u + light_bit_index;
+        if (light_index >= lights.light_count) {
+            break;
+        }
+
+        let light = lights.lights[light_index];
+
+        // Make the AABB bounds for this light
+        let light_min = light.position - vec3<f32>(light.radius);
+        let light_max = light.position + vec3<f32>(light.radius);
+
+        // Do an AABB vs. AABB intersection test. If intersecting, we set this light/cluster bit high
+        if (all(light_min <= cluster.max) && all(light_max >= cluster.min)) {
+            count++;
+            bitfield |= 1u << light_bit_index;
+        }
+    }
+
+    // Copy the bits for this group of 32 lights into the global bitfield
+    if (bitfield != 0u) {
+        bitfields.data.bitfields[cluster_index * (512u / 32u) + light_u32_index] = bitfield;
+    }
+
+    atomicAdd(&bitfields.data.light_count[cluster_index], count);
+}
@@ -0,0 +1,72 @@
+// WebGPU version of lights_build_lists.comp. naga can't translate the SPIR-V atomics, so this must
+// be kept in sync with the GLSL by hand.
+
+// LightBitfieldsData in lights_processing.glsl
+struct LightBitfieldsData {
+    light_count: array<u32, 3072>, // 1 per cluster (8*16*24 clusters)
+    bitfields: array<u32, 49152>, // (512 lights * (8*16*24=3072) clusters) / 32 bits in a uint)
+}
+
+struct ClusterMeta {
+    count: u32,
+    first_light: u32,
+}
+
+// LightBinningOutput in lights_processing.glsl
+struct LightBinningOutput {
+    data_write_ptr: atomic<u32>,
+    pad0: u32,
+    pad1: u32,
+    pad2: u32,
+    offsets: array<ClusterMeta, 3072>, // 1 per cluster
+    data: array<u32, 1572864>, // 3072 clusters * 512 lights per cluster
+}
+
+struct LightBitfields {
+    data: LightBitfieldsData,
+}
+
+struct LightBuildListsOutput {
+    data: LightBinningOutput,
+}
+
+@group(0) @binding(0)
+var<storage, read> input_data: LightBitfields;
+
+// Atomics need read_write storage
+@group(0) @binding(1)
+var<storage, read_write> output_data: LightBuildListsOutput;
+
+@compute @workgroup_size(1024, 1, 1)
+fn main(@builtin(global_invocation_id) global_invocation_id: vec3<u32>) {
+    // We will scan the bits associated with this cluster
+    let cluster_index = global_invocation_id.x;
+    let cluster_first_u32_index = cluster_index * (512u / 32u);
+
+    let count = input_data.data.light_count[cluster_index];
+    output_data.data.offsets[cluster_index].count = count;
+    if (count == 0u) {
+        output_data.data.offsets[cluster_index].first_light = 0u;
+        return;
+    }
+
+    let list_start_index = atomicAdd(&output_data.data.data_write_ptr, count);
+    output_data.data.offsets[cluster_index].first_light = list_start_index;
+
+    var written_light_count = 0u;
+    // iterate all u32 bitfields for this cluster
+    for (var u32_index = 0u; u32_index < 16u; u32_index++) {
+        var u32_value = input_data.data.bitfields[cluster_first_u32_index + u32_index];
+        while (u32_value != 0u) {
+            let lsb = firstTrailingBit(u32_value);
+
+            // clear this bit
+            u32_value &= ~(1u << lsb);
+
+            // append the light index to the list
+            let light_index = 32u * u32_index + lsb;
+            output_data.data.data[list_start_index + written_light_count] = light_index;
+            written_light_count++;
+        }
+    }
+}
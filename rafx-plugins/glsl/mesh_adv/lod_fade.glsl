@@ -0,0 +1,20 @@
+
+// Interleaved gradient noise (Jimenez 2014). Stable per pixel so that the depth prepass and the
+// forward pass discard exactly the same fragments.
+float lod_fade_noise(vec2 frag_coord) {
+    return fract(52.9829189 * fract(dot(floor(frag_coord), vec2(0.06711056, 0.00583715))));
+}
+
+// lod_fade is 0 when the mesh is not cross-fading. A positive value is the fraction of the outgoing
+// LOD that has faded out and a negative value is the (negated) fraction of the incoming LOD that is
+// not yet visible. The two draws cover complementary sets of pixels.
+bool lod_fade_discard(float lod_fade, vec2 frag_coord) {
+    float noise = lod_fade_noise(frag_coord);
+    if (lod_fade > 0.0) {
+        return noise < lod_fade;
+    } else if (lod_fade < 0.0) {
+        return noise >= 1.0 + lod_fade;
+    }
+
+    return false;
+}
@@ -0,0 +1,14 @@
+// Optional second output of the opaque pass, read by screen-space reflections (ssr.comp). Written by the
+// opaque_gbuffer passes in mesh_adv.material. Must be included before mesh_adv_pbr_frag.glsl.
+
+#include "../util/color.glsl"
+#include "../util/normal_encoding.glsl"
+
+#define PBR_GBUFFER
+
+// xy: octahedral encoded view space normal, z: roughness, w: reflectance at normal incidence
+layout (location = 1) out vec4 out_gbuffer;
+
+void gbuffer_write(vec3 normal_vs, float roughness, vec3 fresnel_base) {
+    out_gbuffer = vec4(octahedral_encode(normal_vs), roughness, rgb_to_luminosity(fresnel_base));
+}
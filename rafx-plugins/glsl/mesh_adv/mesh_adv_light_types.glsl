@@ -0,0 +1,77 @@
+// Light and shadow data uploaded by the mesh feature for each view. Shared by the mesh shaders and
+// the volumetric fog passes.
+
+struct PointLight {
+    vec3 position_ws;
+    float range;
+    vec3 position_vs;
+    float intensity;
+    vec4 color;
+
+    // Index into shadow_map_images_cube and per_view_data.shadow_map_cube_data
+    int shadow_map;
+};
+
+struct DirectionalLight {
+    vec3 direction_ws;
+    float intensity;
+    vec4 color;
+
+    vec3 direction_vs;
+    uint cascade_count;
+
+    // Index into shadow_map_images and per_view_data.shadow_map_2d_data for each cascade, -1 if the
+    // cascade has no shadow map
+    ivec4 cascade_shadow_maps;
+    // View space depth at the far end of each cascade
+    vec4 cascade_split_distances;
+    // Fraction at the far end of each cascade that is blended into the next cascade
+    float cascade_blend_fraction;
+};
+
+struct SpotLight {
+    vec3 position_ws;
+    float range;
+    vec3 position_vs;
+    float intensity;
+    vec4 color;
+
+    //spotlight only
+    vec3 direction_ws;
+    float spotlight_half_angle;
+    vec3 direction_vs;
+
+    // Index into shadow_map_images and per_view_data.shadow_map_2d_data
+    int shadow_map;
+};
+
+struct ShadowMap2DData {
+    vec2 uv_min;
+    vec2 uv_max;
+    mat4 shadow_map_view_proj;
+    vec3 shadow_map_light_dir;
+};
+
+struct ShadowMapCubeData {
+    // We just need the cubemap's near/far z values, not the whole projection matrix
+    // xy=min, zw=max
+    vec4 uv_min_uv_max[6];
+    float cube_map_projection_near_z;
+    float cube_map_projection_far_z;
+};
+
+struct LightInList {
+    vec3 position_ws;
+    float range;
+    vec3 position_vs;
+    float intensity;
+    vec4 color;
+
+    //spotlight only
+    vec3 spotlight_direction_ws;
+    float spotlight_half_angle;
+    vec3 spotlight_direction_vs;
+
+    // Index into shadow_map_images and per_view_data.shadow_map_2d_data
+    int shadow_map;
+};
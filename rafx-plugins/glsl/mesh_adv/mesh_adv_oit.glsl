@@ -0,0 +1,20 @@
+// Outputs for weighted blended order-independent transparency (McGuire and Bavoil, 2013). The
+// accumulation target is blended additively and the revealage target multiplicatively, see the
+// transparent_oit passes in mesh_adv.material and oit_composite.frag.
+
+layout (location = 0) out vec4 out_accum;
+layout (location = 1) out float out_revealage;
+
+// Equation 7 of the paper. Uses view space depth so it doesn't depend on the (reverse Z)
+// projection. Closer and more opaque surfaces get more weight.
+float oit_weight(float alpha, float depth_vs) {
+    float d0 = depth_vs / 5.0;
+    float d1 = depth_vs / 200.0;
+    return alpha * clamp(10.0 / (1e-5 + d0 * d0 + pow(d1, 6.0)), 1e-2, 3e3);
+}
+
+void oit_write(vec4 color) {
+    float w = oit_weight(color.a, -in_position_vs.z);
+    out_accum = vec4(color.rgb * color.a, color.a) * w;
+    out_revealage = color.a;
+}
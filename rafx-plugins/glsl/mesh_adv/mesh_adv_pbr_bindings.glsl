@@ -3,62 +3,11 @@
 //
 #include "mesh_adv_types.glsl"
 #include "lights_processing.glsl"
+#include "mesh_adv_light_types.glsl"
 
 //
 // Per-View Bindings
 //
-struct PointLight {
-    vec3 position_ws;
-    float range;
-    vec3 position_vs;
-    float intensity;
-    vec4 color;
-
-    // Index into shadow_map_images_cube and per_view_data.shadow_map_cube_data
-    int shadow_map;
-};
-
-struct DirectionalLight {
-    vec3 direction_ws;
-    float intensity;
-    vec4 color;
-
-    vec3 direction_vs;
-    // Index into shadow_map_images and per_view_data.shadow_map_2d_data
-    int shadow_map;
-};
-
-struct SpotLight {
-    vec3 position_ws;
-    float range;
-    vec3 position_vs;
-    float intensity;
-    vec4 color;
-
-    //spotlight only
-    vec3 direction_ws;
-    float spotlight_half_angle;
-    vec3 direction_vs;
-
-    // Index into shadow_map_images and per_view_data.shadow_map_2d_data
-    int shadow_map;
-};
-
-struct ShadowMap2DData {
-    vec2 uv_min;
-    vec2 uv_max;
-    mat4 shadow_map_view_proj;
-    vec3 shadow_map_light_dir;
-};
-
-struct ShadowMapCubeData {
-    // We just need the cubemap's near/far z values, not the whole projection matrix
-    // xy=min, zw=max
-    vec4 uv_min_uv_max[6];
-    float cube_map_projection_near_z;
-    float cube_map_projection_far_z;
-};
-
 // @[export]
 // @[internal_buffer]
 layout (set = 0, binding = 0) uniform PerViewData {
@@ -72,9 +21,19 @@ layout (set = 0, binding = 0) uniform PerViewData {
     float ndf_filter_amount;
     uint directional_light_count;
     bool use_clustered_lighting;
+    // Scales image-based lighting. If 0, ambient_light is used instead
+    float ibl_intensity;
+    // Mip of ibl_specular_cubemap that is prefiltered for roughness 1
+    float ibl_specular_max_lod;
+    // Mip of reflection_probe_cubemaps that is prefiltered for roughness 1
+    float reflection_probe_max_lod;
+    // Depth covered by volumetric_fog_volume. If 0, volumetric fog is disabled for this view
+    float volumetric_fog_max_distance;
     DirectionalLight directional_lights[8];
     ShadowMap2DData shadow_map_2d_data[96];
     ShadowMapCubeData shadow_map_cube_data[32];
+    // If true, the decal textures in set 1 hold the decals rendered for this view
+    bool enable_decals;
 } per_view_data;
 
 // @[immutable_samplers([
@@ -129,36 +88,88 @@ layout (set = 0, binding = 5) buffer LightBinOutput {
     LightBinningOutput data;
 } light_bin_output;
 
-struct LightInList {
-    vec3 position_ws;
-    float range;
-    vec3 position_vs;
-    float intensity;
-    vec4 color;
-
-    //spotlight only
-    vec3 spotlight_direction_ws;
-    float spotlight_half_angle;
-    vec3 spotlight_direction_vs;
-
-    // Index into shadow_map_images and per_view_data.shadow_map_2d_data
-    int shadow_map;
-};
-
 // @[export]
 layout (set = 0, binding = 6) buffer AllLights {
     uint light_count;
     LightInList data[512];
 } all_lights;
 
+// Cosine-convolved skybox, sampled with the surface normal
+// @[export]
+layout (set = 0, binding = 7) uniform textureCube ibl_irradiance_cubemap;
+
+// GGX-prefiltered skybox, sampled with the reflection vector at a mip chosen by roughness
+// @[export]
+layout (set = 0, binding = 8) uniform textureCube ibl_specular_cubemap;
+
+// Split-sum scale (r) and bias (g) applied to F0, indexed by (n.v, roughness)
+// @[export]
+layout (set = 0, binding = 9) uniform texture2D ibl_brdf_lut;
+
+// @[immutable_samplers([
+//     (
+//         mag_filter: Linear,
+//         min_filter: Linear,
+//         mip_map_mode: Linear,
+//         address_mode_u: ClampToEdge,
+//         address_mode_v: ClampToEdge,
+//         address_mode_w: ClampToEdge,
+//         max_anisotropy: 1.0,
+//     )
+// ])]
+layout (set = 0, binding = 10) uniform sampler smp_ibl;
+
+// Reflection probes are binned into the same clusters as lights
+// @[export]
+layout (set = 0, binding = 11) buffer ReflectionProbeBinOutput {
+    LightBinningOutput data;
+} reflection_probe_bin_output;
+
+struct ReflectionProbe {
+    // Axis-aligned box that the probe influences and projects reflections onto
+    vec3 box_min_ws;
+    float blend_distance;
+    vec3 box_max_ws;
+    float intensity;
+    vec3 position_ws;
+    // Layer of the probe's +X face in reflection_probe_cubemaps
+    uint first_layer;
+};
+
+// @[export]
+layout (set = 0, binding = 12) buffer ReflectionProbes {
+    uint probe_count;
+    ReflectionProbe data[16];
+} reflection_probes;
+
+// GGX-prefiltered captures of the scene, 6 layers per probe with the faces in the same order as
+// point light shadow cubemaps
+// @[export]
+layout (set = 0, binding = 13) uniform texture2DArray reflection_probe_cubemaps;
+
 //
-// Render Graph Supplied Bindings (SSAO)
+// Render Graph Supplied Bindings (SSAO, volumetric fog, decals)
 //
 
 // @[export]
 // @[slot_name("ssao_texture")]
 layout (set = 1, binding = 0) uniform texture2D ssao_texture;
 
+// Integrated froxel volume, see volumetric_fog.glsl. Sampled with smp_ibl.
+// @[export]
+layout (set = 1, binding = 1) uniform texture2D volumetric_fog_volume;
+
+// Decals rendered after the depth prepass, see decal.frag. Each texture holds premultiplied values
+// with the decals' combined coverage in alpha. Sampled with smp_ibl.
+// @[export]
+layout (set = 1, binding = 2) uniform texture2D decal_albedo_texture;
+
+// @[export]
+layout (set = 1, binding = 3) uniform texture2D decal_normal_texture;
+
+// @[export]
+layout (set = 1, binding = 4) uniform texture2D decal_roughness_texture;
+
 
 //
 // Per-Batch Bindings
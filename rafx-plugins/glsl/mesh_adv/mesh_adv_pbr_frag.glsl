@@ -1,3 +1,7 @@
+#include "lod_fade.glsl"
+#include "cube_map.glsl"
+#include "volumetric_fog.glsl"
+
 layout (location = 0) in vec3 in_position_vs;
 layout (location = 1) in vec3 in_normal_vs;
 // w component is a sign value (-1 or +1) indicating handedness of the tangent basis
@@ -6,9 +10,17 @@ layout (location = 2) in vec3 in_tangent_vs;
 layout (location = 3) in vec3 in_binormal_vs;
 layout (location = 4) in vec2 in_uv;
 layout (location = 5) in vec4 in_position_ws;
+#ifdef PLATFORM_WEBGPU
+// WGSL doesn't allow matrix varyings, so the columns are passed separately
+layout (location = 6) in vec3 in_model_view_0;
+layout (location = 7) in vec3 in_model_view_1;
+layout (location = 8) in vec3 in_model_view_2;
+#define in_model_view mat3(in_model_view_0, in_model_view_1, in_model_view_2)
+#else
 layout (location = 6) in mat3 in_model_view;
 //layout (location = 7) in mat3 in_model_view;
 //layout (location = 8) in mat3 in_model_view;
+#endif
 layout (location = 9) flat in uint in_instance_index;
 
 // References:
@@ -124,40 +136,6 @@ float calculate_cubemap_equivalent_depth(vec3 light_to_surface_ws, float near, f
     return (depth_value + 1.0) * 0.5;
 }
 
-// Return value: xy=UV, z=face index
-// Based on https://www.gamedev.net/forums/topic/687535-implementing-a-cube-map-lookup-function/5337472/
-vec3 cube_sample_to_uv_and_face_index(vec3 dir)
-{
-	vec3 dirAbs = abs(dir);
-	float faceIndex;
-	float ma;
-	vec2 uv;
-
-	if(dirAbs.z >= dirAbs.x && dirAbs.z >= dirAbs.y)
-	{
-		// Either -Z or +Z
-		faceIndex = dir.z < 0.0 ? 5.0 : 4.0;
-		ma = 0.5 / dirAbs.z;
-		uv = vec2(dir.z < 0.0 ? -dir.x : dir.x, -dir.y);
-	}
-	else if(dirAbs.y >= dirAbs.x)
-	{
-	    // Either -Y or +Y
-		faceIndex = dir.y < 0.0 ? 3.0 : 2.0;
-		ma = 0.5 / dirAbs.y;
-		uv = vec2(dir.x, dir.y < 0.0 ? -dir.z : dir.z);
-	}
-	else
-	{
-	    // Either -X or +X
-		faceIndex = dir.x < 0.0 ? 1.0 : 0.0;
-		ma = 0.5 / dirAbs.x;
-		uv = vec2(dir.x < 0.0 ? dir.z : -dir.z, -dir.y);
-	}
-
-	return vec3(uv * ma + 0.5, faceIndex);
-}
-
 float do_calculate_percent_lit_cube(vec3 light_position_ws, vec3 light_position_vs, vec3 normal_vs, int index, float bias_multiplier) {
     // Determine the equivalent depth value that would come out of the shadow cubemap if this surface
     // was the sampled depth. We have 6 different view/projections but those are defined by the spec.
@@ -472,6 +450,39 @@ float calculate_percent_lit(vec3 normal, int index, float bias_multiplier) {
     return do_calculate_percent_lit(normal, index, bias_multiplier);
 }
 
+// Picks the cascade by the view space depth of the surface. Near the far end of a cascade, the
+// result is blended with the next cascade so the change in resolution isn't visible. The last cascade
+// fades out to unshadowed.
+float calculate_percent_lit_cascades(vec3 normal_vs, DirectionalLight light, float bias_multiplier) {
+    float depth_vs = -in_position_vs.z;
+
+    float cascade_near = 0.0;
+    for (uint i = 0; i < light.cascade_count; ++i) {
+        float cascade_far = light.cascade_split_distances[i];
+        if (depth_vs < cascade_far) {
+            float percent_lit = calculate_percent_lit(normal_vs, light.cascade_shadow_maps[i], bias_multiplier);
+
+            float blend_start = cascade_far - (cascade_far - cascade_near) * light.cascade_blend_fraction;
+            if (depth_vs > blend_start) {
+                float next_percent_lit = 1.0;
+                if (i + 1 < light.cascade_count) {
+                    next_percent_lit = calculate_percent_lit(normal_vs, light.cascade_shadow_maps[i + 1], bias_multiplier);
+                }
+
+                float blend = (depth_vs - blend_start) / max(cascade_far - blend_start, 0.0001);
+                percent_lit = mix(percent_lit, next_percent_lit, blend);
+            }
+
+            return percent_lit;
+        }
+
+        cascade_near = cascade_far;
+    }
+
+    // Beyond the last cascade
+    return 1.0;
+}
+
 vec4 diffuse_light(
     vec3 surface_to_light_dir,
     vec3 normal,
@@ -1034,6 +1045,130 @@ vec3 iterate_point_and_spot_lights_clustered(
     return total_light;
 }
 
+// Fresnel for image-based lighting. There is no single half vector, so rough surfaces reflect less at
+// grazing angles to approximate the average over the lobe
+vec3 fresnel_schlick_roughness(
+    float n_dot_v,
+    vec3 fresnel_base,
+    float roughness
+) {
+    return fresnel_base + (max(vec3(1.0 - roughness), fresnel_base) - fresnel_base) * pow(1.0 - n_dot_v, 5.0);
+}
+
+// The IBL cubemaps are generated from the skybox, which assumes Y up, and we're Z up
+vec3 ibl_cubemap_dir(vec3 dir_vs) {
+    vec3 dir_ws = transpose(mat3(per_view_data.view)) * dir_vs;
+    return vec3(dir_ws.x, dir_ws.z, -dir_ws.y);
+}
+
+// 1 inside the probe's box, fading out to 0 over blend_distance towards the faces of the box
+float reflection_probe_weight(ReflectionProbe probe, vec3 position_ws) {
+    vec3 distance_to_faces = min(position_ws - probe.box_min_ws, probe.box_max_ws - position_ws);
+    float distance_to_box = min(distance_to_faces.x, min(distance_to_faces.y, distance_to_faces.z));
+    return clamp(distance_to_box / max(probe.blend_distance, 0.0001), 0.0, 1.0);
+}
+
+// Parallax correction: intersect the reflection ray with the probe's box and look up the direction of
+// the hit point as seen from where the probe was captured
+vec3 box_project_reflection(ReflectionProbe probe, vec3 position_ws, vec3 reflect_ws) {
+    vec3 to_max = (probe.box_max_ws - position_ws) / reflect_ws;
+    vec3 to_min = (probe.box_min_ws - position_ws) / reflect_ws;
+    vec3 furthest = max(to_max, to_min);
+    float distance = min(furthest.x, min(furthest.y, furthest.z));
+    return position_ws + reflect_ws * distance - probe.position_ws;
+}
+
+// Blends in a probe until the weights of all probes blended so far add up to 1. rgb is the blended
+// reflection and a is the total weight.
+vec4 accumulate_reflection_probe(
+    vec4 accumulated,
+    uint probe_index,
+    vec3 position_ws,
+    vec3 reflect_ws,
+    float roughness
+) {
+    ReflectionProbe probe = reflection_probes.data[probe_index];
+    float weight = min(reflection_probe_weight(probe, position_ws), 1.0 - accumulated.a);
+    if (weight <= 0.0) {
+        return accumulated;
+    }
+
+    vec3 uv_and_face = cube_sample_to_uv_and_face_index(box_project_reflection(probe, position_ws, reflect_ws));
+    vec3 uv_and_layer = vec3(uv_and_face.xy, float(probe.first_layer) + uv_and_face.z);
+    float lod = roughness * per_view_data.reflection_probe_max_lod;
+    vec3 reflection = textureLod(sampler2DArray(reflection_probe_cubemaps, smp_ibl), uv_and_layer, lod).rgb;
+    return accumulated + vec4(reflection * probe.intensity * weight, weight);
+}
+
+vec4 iterate_reflection_probes_all(
+    vec3 position_ws,
+    vec3 reflect_ws,
+    float roughness
+) {
+    vec4 accumulated = vec4(0.0);
+    for (uint probe_index = 0; probe_index < reflection_probes.probe_count; ++probe_index) {
+        accumulated = accumulate_reflection_probe(accumulated, probe_index, position_ws, reflect_ws, roughness);
+    }
+
+    return accumulated;
+}
+
+vec4 iterate_reflection_probes_clustered(
+    vec3 position_ws,
+    vec3 reflect_ws,
+    float roughness,
+    uint light_cluster_index
+) {
+    uint probe_first = reflection_probe_bin_output.data.offsets[light_cluster_index].first_light;
+    uint probe_last = probe_first + reflection_probe_bin_output.data.offsets[light_cluster_index].count;
+
+    vec4 accumulated = vec4(0.0);
+    for (uint probe_list_index = probe_first; probe_list_index < probe_last; ++probe_list_index) {
+        uint probe_index = reflection_probe_bin_output.data.data[probe_list_index];
+        accumulated = accumulate_reflection_probe(accumulated, probe_index, position_ws, reflect_ws, roughness);
+    }
+
+    return accumulated;
+}
+
+// Split-sum image-based lighting (Karis, "Real Shading in Unreal Engine 4"). Local reflection probes
+// replace the skybox's specular reflection where they overlap the fragment.
+vec3 ibl_ambient(
+    vec3 surface_to_eye_dir_vs,
+    vec3 normal_vs,
+    vec3 fresnel_base,
+    vec3 base_color,
+    float metalness,
+    float roughness,
+    uint light_cluster_index
+) {
+    float n_dot_v = max(dot(normal_vs, surface_to_eye_dir_vs), 0.0);
+    vec3 reflect_vs = reflect(-surface_to_eye_dir_vs, normal_vs);
+
+    vec3 F = fresnel_schlick_roughness(n_dot_v, fresnel_base, roughness);
+    vec3 kd = (vec3(1.0) - F) * (1.0 - metalness);
+
+    vec3 irradiance = texture(samplerCube(ibl_irradiance_cubemap, smp_ibl), ibl_cubemap_dir(normal_vs)).rgb;
+    vec3 diffuse = irradiance * base_color;
+
+    float lod = roughness * per_view_data.ibl_specular_max_lod;
+    vec3 prefiltered = textureLod(samplerCube(ibl_specular_cubemap, smp_ibl), ibl_cubemap_dir(reflect_vs), lod).rgb;
+    if (reflection_probes.probe_count > 0) {
+        vec3 reflect_ws = transpose(mat3(per_view_data.view)) * reflect_vs;
+        vec4 probes;
+        if (per_view_data.use_clustered_lighting) {
+            probes = iterate_reflection_probes_clustered(in_position_ws.xyz, reflect_ws, roughness, light_cluster_index);
+        } else {
+            probes = iterate_reflection_probes_all(in_position_ws.xyz, reflect_ws, roughness);
+        }
+        prefiltered = probes.rgb + prefiltered * (1.0 - probes.a);
+    }
+    vec2 brdf = texture(sampler2D(ibl_brdf_lut, smp_ibl), vec2(n_dot_v, roughness)).rg;
+    vec3 specular = prefiltered * (fresnel_base * brdf.x + brdf.y);
+
+    return (kd * diffuse + specular) * per_view_data.ibl_intensity;
+}
+
 vec4 pbr_path(
     vec3 surface_to_eye_vs,
     vec4 base_color,
@@ -1096,9 +1231,9 @@ vec4 pbr_path(
 
         float percent_lit = 1.0;
         if (any(greaterThan(pbr, vec3(0.0)))) {
-            percent_lit = calculate_percent_lit(
+            percent_lit = calculate_percent_lit_cascades(
                 normal_vs,
-                per_view_data.directional_lights[i].shadow_map,
+                per_view_data.directional_lights[i],
                 DIRECTIONAL_LIGHT_SHADOW_MAP_BIAS_MULTIPLIER
             );
         }
@@ -1109,7 +1244,20 @@ vec4 pbr_path(
     //
     // There are still issues here, not sure how alpha interacts and gamma looks terrible
     //
-    vec3 ambient = per_view_data.ambient_light.rgb * base_color.rgb * ambient_factor;
+    vec3 ambient;
+    if (per_view_data.ibl_intensity > 0.0) {
+        ambient = ibl_ambient(
+            normalize(surface_to_eye_vs),
+            normal_vs,
+            fresnel_base,
+            base_color.rgb,
+            metalness,
+            roughness,
+            light_cluster_index
+        ) * ambient_factor;
+    } else {
+        ambient = per_view_data.ambient_light.rgb * base_color.rgb * ambient_factor;
+    }
 
     uint material_index = all_draw_data.draw_data[in_instance_index].material_index;
     MaterialDbEntry per_material_data = all_materials.materials[material_index];
@@ -1160,7 +1308,34 @@ uint hash_light_list(uint light_cluster_index) {
     return hash;
 }
 
+// Blends the decals rendered into the decal textures over the surface. The textures hold the sum of
+// the decals' premultiplied values, so a surface covered with alpha a keeps (1 - a) of its own value.
+void apply_decals(
+    inout vec3 base_color,
+    inout vec3 normal_vs,
+    inout float roughness
+) {
+    vec2 screen_uv = gl_FragCoord.xy / vec2(per_view_data.viewport_width, per_view_data.viewport_height);
+
+    vec4 decal_albedo = texture(sampler2D(decal_albedo_texture, smp_ibl), screen_uv);
+    base_color = base_color * (1.0 - decal_albedo.a) + decal_albedo.rgb;
+
+    // Normals are encoded as n * 0.5 + 0.5 before being premultiplied
+    vec4 decal_normal = texture(sampler2D(decal_normal_texture, smp_ibl), screen_uv);
+    if (decal_normal.a > 0.0) {
+        vec3 decal_normal_vs = decal_normal.rgb * 2.0 - decal_normal.a;
+        normal_vs = normalize(normal_vs * (1.0 - decal_normal.a) + decal_normal_vs);
+    }
+
+    vec4 decal_roughness = texture(sampler2D(decal_roughness_texture, smp_ibl), screen_uv);
+    roughness = roughness * (1.0 - decal_roughness.a) + decal_roughness.r;
+}
+
 vec4 pbr_main() {
+    if (lod_fade_discard(all_draw_data.draw_data[in_instance_index].lod_fade, gl_FragCoord.xy)) {
+        discard;
+    }
+
     uint material_index = all_draw_data.draw_data[in_instance_index].material_index;
     MaterialDbEntry per_material_data = all_materials.materials[material_index];
 
@@ -1228,6 +1403,11 @@ vec4 pbr_main() {
     normal_vs = normalize(vec4(in_normal_vs, 0)).xyz;
 #endif
 
+    // Decals are only projected onto opaque surfaces
+    if (per_view_data.enable_decals && !per_material_data.enable_alpha_blend) {
+        apply_decals(base_color.rgb, normal_vs, roughness);
+    }
+
     //TOOD: AO
 
     vec3 eye_position_vs = vec3(0, 0, 0);
@@ -1265,5 +1445,23 @@ vec4 pbr_main() {
     //out_color = vec4(vec3(ambient_factor), 1.0);
     //out_color = vec4(in_uv.x, in_uv.y, 0.0, 1.0);
 
+    // Light scattered by volumetric fog between the camera and the surface, and how much of the
+    // surface is still visible through it
+    if (per_view_data.volumetric_fog_max_distance > 0.0) {
+        vec2 screen_uv = gl_FragCoord.xy / vec2(per_view_data.viewport_width, per_view_data.viewport_height);
+        vec4 fog = volumetric_fog_sample(
+            volumetric_fog_volume,
+            smp_ibl,
+            screen_uv,
+            -in_position_vs.z,
+            per_view_data.volumetric_fog_max_distance
+        );
+        out_color.rgb = out_color.rgb * fog.a + fog.rgb;
+    }
+
+#ifdef PBR_GBUFFER
+    gbuffer_write(normal_vs, roughness, mix(vec3(0.04), base_color.rgb, vec3(metalness)));
+#endif
+
     return out_color;
 }
\ No newline at end of file
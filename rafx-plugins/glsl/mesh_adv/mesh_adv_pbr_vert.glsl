@@ -27,9 +27,16 @@ layout (location = 4) out vec2 out_uv;
 
 // for shadows
 layout (location = 5) out vec4 out_position_ws;
+#ifdef PLATFORM_WEBGPU
+// WGSL doesn't allow matrix varyings, so pass the columns separately
+layout (location = 6) out vec3 out_model_view_0;
+layout (location = 7) out vec3 out_model_view_1;
+layout (location = 8) out vec3 out_model_view_2;
+#else
 layout (location = 6) out mat3 out_model_view;
 //layout (location = 7) out mat3 out_model_view;
 //layout (location = 8) out mat3 out_model_view;
+#endif
 layout (location = 9) flat out uint out_instance_index;
 
 invariant gl_Position;
@@ -60,7 +67,13 @@ void pbr_main() {
     out_position_vs = (model_view * vec4(in_pos, 1.0)).xyz;
 
     // This can be skipped if just using rotation/uniform scale. Required for non-uniform scale/shear
+#ifdef PLATFORM_WEBGPU
+    // WGSL has no inverse(), the inverse transpose is the cofactor matrix divided by the determinant
+    mat3 m = mat3(model_view);
+    mat3 normalMatrix = mat3(cross(m[1], m[2]), cross(m[2], m[0]), cross(m[0], m[1])) / determinant(m);
+#else
     mat3 normalMatrix = transpose(inverse(mat3(model_view)));
+#endif
     vec3 n = normalize(normalMatrix * in_normal);
     vec3 t = normalize(normalMatrix * in_tangent);
     vec3 b = normalize(normalMatrix * in_binormal);
@@ -73,7 +86,13 @@ void pbr_main() {
     // Used to sample the shadow map
     out_position_ws = model_matrix * vec4(in_pos, 1.0);
 
+#ifdef PLATFORM_WEBGPU
+    out_model_view_0 = model_view[0].xyz;
+    out_model_view_1 = model_view[1].xyz;
+    out_model_view_2 = model_view[2].xyz;
+#else
     out_model_view = mat3(model_view);
+#endif
     out_instance_index = instance_index;
 
 }
\ No newline at end of file
@@ -14,4 +14,6 @@ struct TransformWithHistory {
 struct DrawData {
     uint transform_index;
     uint material_index;
+    // Dithered LOD cross-fade, see lod_fade.glsl. Zero when the mesh is not fading.
+    float lod_fade;
 };
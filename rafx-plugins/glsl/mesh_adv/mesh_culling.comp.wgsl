@@ -0,0 +1,242 @@
+// WebGPU version of mesh_culling.comp. naga can't translate the SPIR-V atomics, so this must be
+// kept in sync with the GLSL by hand.
+
+// DrawData in mesh_adv_types.glsl
+struct DrawData {
+    transform_index: u32,
+    material_index: u32,
+    lod_fade: f32,
+}
+
+struct BoundingSphere {
+    position: vec3<f32>,
+    radius: f32,
+}
+
+struct IndirectCommand {
+    index_count: u32,
+    instance_count: u32,
+    first_index: u32,
+    vertex_offset: i32,
+    first_instance: u32,
+}
+
+// Data for the view we are processing
+struct Config {
+    view_matrix: mat4x4<f32>,
+    proj_matrix: mat4x4<f32>,
+    draw_data_count: u32,
+    indirect_first_command_index: u32,
+    depth_mip_slice_count: u32,
+    viewport_width: u32,
+    viewport_height: u32,
+    z_near: f32,
+    // A bool in the GLSL, WGSL doesn't allow bools in uniform buffers
+    write_debug_output: u32,
+}
+
+struct AllDrawData {
+    count: u32,
+    pad0: u32,
+    pad1: u32,
+    pad2: u32,
+    draw_data: array<DrawData>,
+}
+
+struct AllBoundingSpheres {
+    bounding_spheres: array<BoundingSphere>,
+}
+
+struct IndirectData {
+    indirect_commands: array<IndirectCommand>,
+}
+
+struct DebugOutput {
+    total_mesh_count: atomic<u32>,
+    culled_mesh_count: atomic<u32>,
+    total_primitive_count: atomic<u32>,
+    culled_primitive_count: atomic<u32>,
+    // PerCullInfo in the GLSL, a single bool was_culled
+    per_cull_info: array<u32, 4000>,
+}
+
+@group(0) @binding(0)
+var<uniform> config: Config;
+
+// The draw_data - we use this to pair transform/bounding data with a slot in the indirect command buffer
+@group(0) @binding(1)
+var<storage, read_write> all_draw_data: AllDrawData;
+
+// Bounding sphere of each object, indexed by transform_index (this is a parallel array with transforms)
+@group(0) @binding(2)
+var<storage, read_write> all_bounding_spheres: AllBoundingSpheres;
+
+// The output data - we will poke a 0 or 1 into the instance_count field depending on if it's visible
+@group(0) @binding(3)
+var<storage, read_write> all_indirect_commands: IndirectData;
+
+@group(0) @binding(4)
+var depth_mip_slices: binding_array<texture_2d<f32>, 16>;
+
+@group(0) @binding(5)
+var smp: sampler;
+
+@group(0) @binding(6)
+var<storage, read_write> debug_output: DebugOutput;
+
+// Each mip is indexed with a constant so the texture index is always uniform
+fn gather_depth(mip_slice: u32, uv: vec2<f32>) -> vec4<f32> {
+    switch (mip_slice) {
+        case 0u: { return textureGather(0, depth_mip_slices[0], smp, uv); }
+        case 1u: { return textureGather(0, depth_mip_slices[1], smp, uv); }
+        case 2u: { return textureGather(0, depth_mip_slices[2], smp, uv); }
+        case 3u: { return textureGather(0, depth_mip_slices[3], smp, uv); }
+        case 4u: { return textureGather(0, depth_mip_slices[4], smp, uv); }
+        case 5u: { return textureGather(0, depth_mip_slices[5], smp, uv); }
+        case 6u: { return textureGather(0, depth_mip_slices[6], smp, uv); }
+        case 7u: { return textureGather(0, depth_mip_slices[7], smp, uv); }
+        case 8u: { return textureGather(0, depth_mip_slices[8], smp, uv); }
+        case 9u: { return textureGather(0, depth_mip_slices[9], smp, uv); }
+        case 10u: { return textureGather(0, depth_mip_slices[10], smp, uv); }
+        case 11u: { return textureGather(0, depth_mip_slices[11], smp, uv); }
+        case 12u: { return textureGather(0, depth_mip_slices[12], smp, uv); }
+        case 13u: { return textureGather(0, depth_mip_slices[13], smp, uv); }
+        case 14u: { return textureGather(0, depth_mip_slices[14], smp, uv); }
+        default: { return textureGather(0, depth_mip_slices[15], smp, uv); }
+    }
+}
+
+@compute @workgroup_size(1, 64, 1)
+fn main(@builtin(global_invocation_id) global_invocation_id: vec3<u32>) {
+    //
+    // Bail if this thread is not in-bounds of the draw data list
+    //
+    let draw_index = global_invocation_id.y;
+    if (draw_index >= config.draw_data_count) {
+        return;
+    }
+
+    //
+    // Debug output
+    //
+    if (config.write_debug_output != 0u) {
+        let indirect_index = config.indirect_first_command_index + draw_index;
+        atomicAdd(&debug_output.total_mesh_count, 1u);
+        atomicAdd(&debug_output.total_primitive_count, all_indirect_commands.indirect_commands[indirect_index].index_count / 3u);
+    }
+
+    //
+    // Determine location of bounding sphere in view space
+    // TODO: We currently pre-transform position/radius on CPU
+    //
+    let transform_index = all_draw_data.draw_data[draw_index].transform_index;
+    let bs = all_bounding_spheres.bounding_spheres[transform_index];
+    let center_vs = (config.view_matrix * vec4<f32>(bs.position, 1.0)).xyz;
+    let radius = bs.radius;
+
+    //
+    // If radius is -1, assume it's never culled
+    //
+    if (radius < 0.0) {
+        return;
+    }
+
+    //
+    // Determine UV coordinates of all 8 corners of viewspace AABB around the bounding sphere. Store the min/max uv
+    // and max depth value
+    //
+    var min_uv: vec2<f32>;
+    var max_uv: vec2<f32>;
+    var max_ndc_z: f32;
+    for (var i = 0u; i < 8u; i++) {
+        let corner_vs = center_vs + vec3<f32>(
+            select(-radius, radius, (i & 1u) != 0u),
+            select(-radius, radius, (i & 2u) != 0u),
+            select(-radius, radius, (i & 4u) != 0u)
+        );
+
+        let corner_clip = config.proj_matrix * vec4<f32>(corner_vs, 1.0);
+        let corner_ndc = corner_clip.xyz / corner_clip.w;
+        if (corner_ndc.z < 0.0) {
+            // Assume we can't cull anything that's clipping through the screen
+            return;
+        }
+        let corner_uv = corner_ndc.xy * vec2<f32>(0.5, -0.5) + 0.5;
+        if (i == 0u) {
+            min_uv = corner_uv;
+            max_uv = corner_uv;
+            max_ndc_z = corner_ndc.z;
+        } else {
+            min_uv = min(min_uv, corner_uv);
+            max_uv = max(max_uv, corner_uv);
+            max_ndc_z = max(max_ndc_z, corner_ndc.z);
+        }
+    }
+
+    // clip UV to be on-screen
+    min_uv = clamp(min_uv, vec2<f32>(0.0), vec2<f32>(1.0));
+    max_uv = clamp(max_uv, vec2<f32>(0.0), vec2<f32>(1.0));
+
+    //
+    // Linear search for the mip level where the UV coordinates cover no more than a 2x2 patch
+    //
+    var mip_slice_size: vec2<f32>;
+    var min_sample_pixel: vec2<i32>;
+    var max_sample_pixel: vec2<i32>;
+    var mip_slice = config.depth_mip_slice_count;
+    for (var i = 0u; i < config.depth_mip_slice_count; i++) {
+        mip_slice_size = vec2<f32>(max(vec2<u32>(1u, 1u), vec2<u32>(config.viewport_width >> i, config.viewport_height >> i)));
+        min_sample_pixel = vec2<i32>(min_uv * mip_slice_size);
+        max_sample_pixel = vec2<i32>(max_uv * mip_slice_size);
+
+        if ((max_sample_pixel.x - min_sample_pixel.x < 2) && (max_sample_pixel.y - min_sample_pixel.y < 2)) {
+            mip_slice = i;
+            break;
+        }
+    }
+
+    //
+    // Bail if no mip satisfied the 2x2 patch size requirement
+    //
+    if (mip_slice == config.depth_mip_slice_count) {
+        return;
+    }
+
+    //
+    // Gather the 4 pixels of the patch
+    //
+    let gather_pixel = (vec2<f32>(min_sample_pixel) + vec2<f32>(max_sample_pixel)) / 2.0;
+    let gather_uv = (gather_pixel + vec2<f32>(0.5, 0.5)) / mip_slice_size;
+    let gathered = gather_depth(mip_slice, gather_uv);
+
+    //
+    // Find minimum of all 4 pixels that are covered by the uv coordinates
+    //
+    let use_both_samples_for_x = min_sample_pixel.x != max_sample_pixel.x;
+    let use_both_samples_for_y = min_sample_pixel.y != max_sample_pixel.y;
+
+    var hiz_depth = gathered.w;
+    if (use_both_samples_for_x) {
+        hiz_depth = min(hiz_depth, gathered.z);
+    }
+    if (use_both_samples_for_y) {
+        hiz_depth = min(hiz_depth, gathered.x);
+    }
+    if (use_both_samples_for_x && use_both_samples_for_y) {
+        hiz_depth = min(hiz_depth, gathered.y);
+    }
+
+    //
+    // Cull if the AABB is behind all the depth buffer samples. max_ndc_z represents the highest depth value within the
+    // bounding sphere.
+    //
+    if (max_ndc_z < hiz_depth) {
+        let indirect_index = config.indirect_first_command_index + draw_index;
+        all_indirect_commands.indirect_commands[indirect_index].instance_count = 0u;
+
+        if (config.write_debug_output != 0u) {
+            atomicAdd(&debug_output.culled_mesh_count, 1u);
+            atomicAdd(&debug_output.culled_primitive_count, all_indirect_commands.indirect_commands[indirect_index].index_count / 3u);
+        }
+    }
+}
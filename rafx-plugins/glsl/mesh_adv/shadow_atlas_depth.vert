@@ -8,7 +8,10 @@
 // @[semantic("POSITION")]
 layout (location = 0) in vec3 in_pos;
 
+#ifndef PLATFORM_WEBGPU
+// WGSL has no clip distances, on WebGPU the renderer sets a scissor rect around the atlas tile instead
 out float gl_ClipDistance[4];
+#endif
 
 void main() {
 #ifdef PLATFORM_DX12
@@ -29,10 +32,12 @@ void main() {
     vec4 clip_space = model_view_proj * vec4(in_pos, 1.0);
 
     // We implicitly clip 0 < w < 1, we also clip -w < x < w to be in the view frustum
+#ifndef PLATFORM_WEBGPU
     gl_ClipDistance[0] = clip_space.x + clip_space.w;
     gl_ClipDistance[1] = clip_space.w - clip_space.x;
     gl_ClipDistance[2] = clip_space.y + clip_space.w;
     gl_ClipDistance[3] = clip_space.w - clip_space.y;
+#endif
 
     // 2d coordinates with perspective divide
     vec2 ndc_xy = clip_space.xy / clip_space.w;
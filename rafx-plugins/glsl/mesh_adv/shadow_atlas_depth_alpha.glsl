@@ -0,0 +1,78 @@
+// Bindings for shadow casters that discard fragments based on the alpha of their base color. Sets 0
+// and 1 match shadow_atlas_depth.glsl, set 2 has the same material data as the PBR shaders.
+
+// @[export]
+// @[internal_buffer]
+layout (set = 0, binding = 0) uniform PerViewData {
+    mat4 view;
+    mat4 view_proj;
+    vec2 uv_min;
+    vec2 uv_max;
+} per_view_data;
+
+// @[immutable_samplers([
+//     (
+//         mag_filter: Linear,
+//         min_filter: Linear,
+//         mip_map_mode: Linear,
+//         address_mode_u: Repeat,
+//         address_mode_v: Repeat,
+//         address_mode_w: Repeat,
+//     )
+// ])]
+layout (set = 0, binding = 1) uniform sampler smp;
+
+layout (set = 1, binding = 0) buffer AllTransforms {
+    Transform transforms[];
+} all_transforms;
+
+layout (set = 1, binding = 1) buffer AllDrawData {
+    // The count is used to avoid a bug on nvidia when GBV is enabled where it seems the push constant is just invalid
+    // and walks past the end of the array
+    uint count;
+    uint pad0;
+    uint pad1;
+    uint pad2;
+    DrawData draw_data[];
+} all_draw_data;
+
+// @[export]
+struct MaterialDbEntry {
+    vec4 base_color_factor;
+    vec3 emissive_factor;
+    float metallic_factor;
+    float roughness_factor;
+    float normal_texture_scale;
+    float alpha_threshold;
+    bool enable_alpha_blend;
+    bool enable_alpha_clip;
+    int color_texture;
+    bool base_color_texture_has_alpha_channel;
+    int metallic_roughness_texture;
+    int normal_texture;
+    int emissive_texture;
+};
+
+layout (set = 2, binding = 0) buffer AllMaterials {
+    MaterialDbEntry materials[];
+} all_materials;
+
+layout (set = 2, binding = 1) uniform texture2D all_material_textures[768];
+
+#ifdef PLATFORM_DX12
+    layout (push_constant) uniform PushConstantData {
+        uint instance_offset;
+    } push_constants;
+#endif // PLATFORM_DX12
+
+// Returns the alpha of the base color of the material, the same way the PBR shaders compute it
+float shadow_caster_alpha(uint material_index, vec2 uv) {
+    MaterialDbEntry per_material_data = all_materials.materials[material_index];
+
+    float alpha = per_material_data.base_color_factor.a;
+    if (per_material_data.color_texture != -1 && per_material_data.base_color_texture_has_alpha_channel) {
+        alpha *= texture(sampler2D(all_material_textures[per_material_data.color_texture], smp), uv).a;
+    }
+
+    return alpha;
+}
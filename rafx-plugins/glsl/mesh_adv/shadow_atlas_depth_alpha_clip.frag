@@ -0,0 +1,17 @@
+#version 450
+#extension GL_ARB_separate_shader_objects : enable
+#extension GL_ARB_shading_language_420pack : enable
+
+#include "mesh_adv_types.glsl"
+#include "shadow_atlas_depth_alpha.glsl"
+
+layout (location = 0) in vec2 in_uv;
+layout (location = 1) flat in uint in_material_index;
+
+// Fragments below the material's alpha threshold don't cast shadows
+void main() {
+    float alpha_threshold = all_materials.materials[in_material_index].alpha_threshold;
+    if (shadow_caster_alpha(in_material_index, in_uv) < alpha_threshold) {
+        discard;
+    }
+}
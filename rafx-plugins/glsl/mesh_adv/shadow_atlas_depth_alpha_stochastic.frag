@@ -0,0 +1,18 @@
+#version 450
+#extension GL_ARB_separate_shader_objects : enable
+#extension GL_ARB_shading_language_420pack : enable
+
+#include "mesh_adv_types.glsl"
+#include "shadow_atlas_depth_alpha.glsl"
+#include "lod_fade.glsl"
+
+layout (location = 0) in vec2 in_uv;
+layout (location = 1) flat in uint in_material_index;
+
+// Each fragment casts a shadow with a probability equal to its alpha. Filtering the shadow map
+// averages neighboring texels, so semi-transparent casters produce proportionally lighter shadows.
+void main() {
+    if (shadow_caster_alpha(in_material_index, in_uv) <= lod_fade_noise(gl_FragCoord.xy)) {
+        discard;
+    }
+}
@@ -0,0 +1,51 @@
+// Layout of the froxel volume used for volumetric fog. It covers the main view's frustum and is
+// aligned with the light clusters in x and y, with 8x8 froxels per cluster. Depth slices are
+// distributed quadratically out to the fog's max distance, so they are thinner near the camera.
+//
+// The volume is stored as a 2D texture with each depth slice in its own tile, so the mesh shaders
+// can bind an ordinary 2D image when fog is disabled.
+const uint VOLUMETRIC_FOG_WIDTH = 128;
+const uint VOLUMETRIC_FOG_HEIGHT = 64;
+const uint VOLUMETRIC_FOG_DEPTH = 64;
+const uint VOLUMETRIC_FOG_TILES_X = 8;
+
+// View space depth of the near side of a slice. Fractional slices are allowed.
+float volumetric_fog_slice_to_depth(float slice, float max_distance) {
+    float t = slice / float(VOLUMETRIC_FOG_DEPTH);
+    return t * t * max_distance;
+}
+
+float volumetric_fog_depth_to_slice(float depth, float max_distance) {
+    return sqrt(clamp(depth / max_distance, 0.0, 1.0)) * float(VOLUMETRIC_FOG_DEPTH);
+}
+
+ivec2 volumetric_fog_texel(uvec3 froxel) {
+    uvec2 tile = uvec2(froxel.z % VOLUMETRIC_FOG_TILES_X, froxel.z / VOLUMETRIC_FOG_TILES_X);
+    return ivec2(tile * uvec2(VOLUMETRIC_FOG_WIDTH, VOLUMETRIC_FOG_HEIGHT) + froxel.xy);
+}
+
+// Bilinear sample within one slice of the integrated volume. The uv is clamped so that the
+// neighbouring tiles don't bleed in.
+vec4 volumetric_fog_sample_slice(texture2D volume, sampler smp, vec2 screen_uv, uint slice) {
+    vec2 size = vec2(VOLUMETRIC_FOG_WIDTH, VOLUMETRIC_FOG_HEIGHT);
+    vec2 texel = clamp(screen_uv * size, vec2(0.5), size - 0.5);
+    vec2 tile = vec2(slice % VOLUMETRIC_FOG_TILES_X, slice / VOLUMETRIC_FOG_TILES_X);
+    vec2 texture_size = size * vec2(VOLUMETRIC_FOG_TILES_X, VOLUMETRIC_FOG_DEPTH / VOLUMETRIC_FOG_TILES_X);
+    return textureLod(sampler2D(volume, smp), (tile * size + texel) / texture_size, 0.0);
+}
+
+// Returns the light scattered towards the camera in rgb and the transmittance in a, between the
+// camera and the given view space depth. Slice n of the integrated volume holds the result at the far
+// side of that slice.
+vec4 volumetric_fog_sample(texture2D volume, sampler smp, vec2 screen_uv, float depth, float max_distance) {
+    float slice = volumetric_fog_depth_to_slice(depth, max_distance);
+    uint near_boundary = min(uint(slice), VOLUMETRIC_FOG_DEPTH - 1);
+
+    vec4 near_value = vec4(0.0, 0.0, 0.0, 1.0);
+    if (near_boundary > 0) {
+        near_value = volumetric_fog_sample_slice(volume, smp, screen_uv, near_boundary - 1);
+    }
+    vec4 far_value = volumetric_fog_sample_slice(volume, smp, screen_uv, near_boundary);
+
+    return mix(near_value, far_value, clamp(slice - float(near_boundary), 0.0, 1.0));
+}
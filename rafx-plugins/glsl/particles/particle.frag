@@ -0,0 +1,12 @@
+#version 450
+#extension GL_ARB_separate_shader_objects : enable
+#extension GL_ARB_shading_language_420pack : enable
+
+#include "particle.glsl"
+#include "particle_shade.glsl"
+
+layout (location = 0) out vec4 out_color;
+
+void main() {
+    out_color = particle_color();
+}
@@ -0,0 +1,63 @@
+#include "particles.glsl"
+
+// @[export]
+// @[internal_buffer]
+layout (set = 0, binding = 0) uniform PerViewData {
+    mat4 view;
+    mat4 proj;
+    vec2 jitter_amount;
+} per_view_data;
+
+// @[immutable_samplers([
+//     (
+//         mag_filter: Linear,
+//         min_filter: Linear,
+//         mip_map_mode: Linear,
+//         address_mode_u: ClampToEdge,
+//         address_mode_v: ClampToEdge,
+//         address_mode_w: ClampToEdge,
+//         max_anisotropy: 16.0,
+//     )
+// ])]
+layout (set = 0, binding = 1) uniform sampler smp;
+
+// @[immutable_samplers([
+//     (
+//         mag_filter: Nearest,
+//         min_filter: Nearest,
+//         mip_map_mode: Nearest,
+//         address_mode_u: ClampToEdge,
+//         address_mode_v: ClampToEdge,
+//         address_mode_w: ClampToEdge,
+//     )
+// ])]
+layout (set = 0, binding = 2) uniform sampler smp_depth;
+
+// Half resolution mip of the depth pyramid, bound by the render graph. The nearest depth of each
+// 2x2 block is in the green channel.
+// @[export]
+layout (set = 1, binding = 0) uniform texture2D depth_texture;
+
+// @[export]
+// @[internal_buffer]
+layout (set = 2, binding = 0) uniform PerEmitterData {
+    vec4 start_color;
+    vec4 end_color;
+    float start_size;
+    float end_size;
+    // Particles fade out when they are closer than this to the surface behind them
+    float soft_particle_distance;
+    bool has_texture;
+} per_emitter_data;
+
+// @[export]
+layout (set = 2, binding = 1) uniform texture2D particle_texture;
+
+layout (set = 2, binding = 2) readonly buffer Particles {
+    Particle particles[];
+} particles;
+
+// The alive list written by particle_simulate.comp this frame
+layout (set = 2, binding = 3) readonly buffer AliveList {
+    uint indices[];
+} alive_list;
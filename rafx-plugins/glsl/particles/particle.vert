@@ -0,0 +1,31 @@
+#version 450
+#extension GL_ARB_separate_shader_objects : enable
+#extension GL_ARB_shading_language_420pack : enable
+
+#include "../util/taa_jitter.glsl"
+#include "particle.glsl"
+
+// Each instance is one alive particle, drawn as a camera-facing quad built from the vertex index
+const vec2 QUAD_CORNERS[6] = vec2[](
+    vec2(-0.5, -0.5), vec2(0.5, -0.5), vec2(0.5, 0.5),
+    vec2(-0.5, -0.5), vec2(0.5, 0.5), vec2(-0.5, 0.5)
+);
+
+layout (location = 0) out vec2 out_uv;
+layout (location = 1) out vec4 out_color;
+layout (location = 2) out vec3 out_position_vs;
+
+void main() {
+    Particle particle = particles.particles[alive_list.indices[gl_InstanceIndex]];
+    float t = clamp(particle.age / particle.lifetime, 0.0, 1.0);
+    float size = mix(per_emitter_data.start_size, per_emitter_data.end_size, t);
+
+    vec2 corner = QUAD_CORNERS[gl_VertexIndex];
+    vec4 position_vs = per_view_data.view * vec4(particle.position, 1.0);
+    position_vs.xy += corner * size;
+
+    out_uv = vec2(corner.x + 0.5, 0.5 - corner.y);
+    out_color = mix(per_emitter_data.start_color, per_emitter_data.end_color, t);
+    out_position_vs = position_vs.xyz;
+    gl_Position = add_jitter(per_view_data.proj * position_vs, per_view_data.jitter_amount);
+}
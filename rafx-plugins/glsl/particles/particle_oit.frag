@@ -0,0 +1,11 @@
+#version 450
+#extension GL_ARB_separate_shader_objects : enable
+#extension GL_ARB_shading_language_420pack : enable
+
+#include "particle.glsl"
+#include "particle_shade.glsl"
+#include "../mesh_adv/mesh_adv_oit.glsl"
+
+void main() {
+    oit_write(particle_color());
+}
@@ -0,0 +1,34 @@
+layout (location = 0) in vec2 in_uv;
+layout (location = 1) in vec4 in_color;
+layout (location = 2) in vec3 in_position_vs;
+
+// View space Z of a depth value, the same as depth_vs in ssao.frag
+float depth_vs(float depth) {
+    return -per_view_data.proj[3][2] / (depth - per_view_data.proj[2][2]);
+}
+
+vec4 particle_color() {
+    vec4 color = in_color;
+    if (per_emitter_data.has_texture) {
+        color *= texture(sampler2D(particle_texture, smp), in_uv);
+    } else {
+        // Untextured particles are drawn as soft round dots
+        color.a *= 1.0 - smoothstep(0.5, 1.0, length(in_uv * 2.0 - 1.0));
+    }
+
+    // Soft particles: fade out where the quad intersects opaque geometry. The depth texture is half
+    // resolution, so gl_FragCoord is scaled to match it.
+    if (per_emitter_data.soft_particle_distance > 0.0) {
+        vec2 depth_texture_size = textureSize(sampler2D(depth_texture, smp_depth), 0);
+        vec2 screen_uv = gl_FragCoord.xy / (depth_texture_size * 2.0);
+        float scene_depth = texture(sampler2D(depth_texture, smp_depth), screen_uv).g;
+
+        // Depth of 0 is the far plane (reverse Z), so there is nothing to intersect
+        if (scene_depth > 0.0) {
+            float distance_to_scene = in_position_vs.z - depth_vs(scene_depth);
+            color.a *= clamp(distance_to_scene / per_emitter_data.soft_particle_distance, 0.0, 1.0);
+        }
+    }
+
+    return color;
+}
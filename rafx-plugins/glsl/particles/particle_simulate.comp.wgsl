@@ -0,0 +1,66 @@
+// WebGPU version of particle_simulate.comp, see particles.wgsl
+
+#include "particles.wgsl"
+
+struct Config {
+    gravity: vec3<f32>,
+    drag: f32,
+    dt: f32,
+}
+
+struct Particles {
+    particles: array<Particle>,
+}
+
+struct IndexList {
+    indices: array<u32>,
+}
+
+struct EmitterState {
+    state: ParticleState,
+}
+
+@group(0) @binding(0)
+var<uniform> config: Config;
+
+@group(0) @binding(1)
+var<storage, read_write> particles: Particles;
+
+@group(0) @binding(2)
+var<storage, read_write> alive_list_in: IndexList;
+
+@group(0) @binding(3)
+var<storage, read_write> alive_list_out: IndexList;
+
+@group(0) @binding(4)
+var<storage, read_write> dead_list: IndexList;
+
+@group(0) @binding(5)
+var<storage, read_write> emitter: EmitterState;
+
+// Must match PARTICLE_SIMULATE_GROUP_SIZE
+@compute @workgroup_size(64, 1, 1)
+fn main(@builtin(global_invocation_id) global_invocation_id: vec3<u32>) {
+    let alive_index = global_invocation_id.x;
+    if (alive_index >= emitter.state.simulate_count) {
+        return;
+    }
+
+    let particle_index = alive_list_in.indices[alive_index];
+    var particle = particles.particles[particle_index];
+
+    particle.age += config.dt;
+    if (particle.age >= particle.lifetime) {
+        let dead_index = atomicAdd(&emitter.state.dead_count, 1);
+        dead_list.indices[dead_index] = particle_index;
+        return;
+    }
+
+    particle.velocity += config.gravity * config.dt;
+    particle.velocity *= 1.0 / (1.0 + config.drag * config.dt);
+    particle.position += particle.velocity * config.dt;
+    particles.particles[particle_index] = particle;
+
+    let out_index = atomicAdd(&emitter.state.alive_count, 1u);
+    alive_list_out.indices[out_index] = particle_index;
+}
@@ -0,0 +1,73 @@
+// WebGPU version of particle_spawn.comp, see particles.wgsl
+
+#include "particles.wgsl"
+
+struct Config {
+    emitter_transform: mat4x4<f32>,
+    // World space
+    initial_velocity: vec3<f32>,
+    velocity_randomness: f32,
+    // In the emitter's local space
+    spawn_radius: f32,
+    min_lifetime: f32,
+    max_lifetime: f32,
+    spawn_count: u32,
+    seed: u32,
+}
+
+struct Particles {
+    particles: array<Particle>,
+}
+
+struct IndexList {
+    indices: array<u32>,
+}
+
+struct EmitterState {
+    state: ParticleState,
+}
+
+@group(0) @binding(0)
+var<uniform> config: Config;
+
+@group(0) @binding(1)
+var<storage, read_write> particles: Particles;
+
+@group(0) @binding(2)
+var<storage, read_write> alive_list: IndexList;
+
+@group(0) @binding(3)
+var<storage, read_write> dead_list: IndexList;
+
+@group(0) @binding(4)
+var<storage, read_write> emitter: EmitterState;
+
+@compute @workgroup_size(64, 1, 1)
+fn main(@builtin(global_invocation_id) global_invocation_id: vec3<u32>) {
+    let spawn_index = global_invocation_id.x;
+    if (spawn_index >= config.spawn_count) {
+        return;
+    }
+
+    let dead_index = atomicAdd(&emitter.state.dead_count, -1) - 1;
+    if (dead_index < 0) {
+        atomicAdd(&emitter.state.dead_count, 1);
+        return;
+    }
+
+    let particle_index = dead_list.indices[dead_index];
+
+    var seed = pcg_hash(config.seed ^ pcg_hash(spawn_index));
+    let position_local = random_in_unit_sphere(&seed) * config.spawn_radius;
+    let velocity = config.initial_velocity + random_in_unit_sphere(&seed) * config.velocity_randomness;
+
+    var particle: Particle;
+    particle.position = (config.emitter_transform * vec4<f32>(position_local, 1.0)).xyz;
+    particle.age = 0.0;
+    particle.velocity = velocity;
+    particle.lifetime = mix(config.min_lifetime, config.max_lifetime, random_float(&seed));
+    particles.particles[particle_index] = particle;
+
+    let alive_index = atomicAdd(&emitter.state.alive_count, 1u);
+    alive_list.indices[alive_index] = particle_index;
+}
@@ -0,0 +1,61 @@
+// Shared by the particle simulation compute shaders. Each emitter owns a fixed-size pool of
+// particles. Indices of live particles are kept in two alive lists that are swapped every frame,
+// and indices of free particles in a dead list, so spawning and killing never touch the whole pool.
+
+struct Particle {
+    vec3 position;
+    float age;
+    vec3 velocity;
+    float lifetime;
+};
+
+// The emitter's counters. The layout must match PARTICLE_STATE_SIZE in
+// features/particles/simulation.rs.
+struct ParticleState {
+    // Number of entries in the alive list that is being written
+    uint alive_count;
+    // Number of entries in the dead list, signed so spawning can briefly go below zero
+    int dead_count;
+    // Number of entries in the alive list that is being simulated
+    uint simulate_count;
+};
+
+// Written from ParticleState by particle_indirect_args.comp. These are kept out of ParticleState
+// because a buffer can't be read as indirect arguments while the simulation is writing it. The
+// layout must match PARTICLE_INDIRECT_ARGS_* in features/particles/simulation.rs.
+struct ParticleIndirectArgs {
+    // RafxDispatchIndirectCommand for particle_simulate.comp
+    uint dispatch_x;
+    uint dispatch_y;
+    uint dispatch_z;
+    // RafxDrawIndirectCommand for drawing the alive particles
+    uint vertex_count;
+    uint instance_count;
+    uint first_vertex;
+    uint first_instance;
+};
+
+const uint PARTICLE_QUAD_VERTEX_COUNT = 6;
+const uint PARTICLE_SIMULATE_GROUP_SIZE = 64;
+
+// PCG hash (Jarzynski and Olano, 2020)
+uint pcg_hash(uint value) {
+    uint state = value * 747796405u + 2891336453u;
+    uint word = ((state >> ((state >> 28u) + 4u)) ^ state) * 277803737u;
+    return (word >> 22u) ^ word;
+}
+
+// Returns a value in [0, 1] and advances the seed
+float random_float(inout uint seed) {
+    seed = pcg_hash(seed);
+    return float(seed) / 4294967295.0;
+}
+
+// Uniformly distributed point inside the unit sphere
+vec3 random_in_unit_sphere(inout uint seed) {
+    float z = random_float(seed) * 2.0 - 1.0;
+    float angle = random_float(seed) * 6.28318530718;
+    float radius = sqrt(1.0 - z * z);
+    vec3 direction = vec3(radius * cos(angle), radius * sin(angle), z);
+    return direction * pow(random_float(seed), 1.0 / 3.0);
+}
@@ -0,0 +1,43 @@
+// WGSL version of particles.glsl for the shaders that need hand-written WebGPU overrides. naga
+// can't translate the SPIR-V atomics used by the simulation, so these must be kept in sync with the
+// GLSL by hand.
+
+struct Particle {
+    position: vec3<f32>,
+    age: f32,
+    velocity: vec3<f32>,
+    lifetime: f32,
+}
+
+// The emitter's counters. The layout must match PARTICLE_STATE_SIZE in
+// features/particles/simulation.rs.
+struct ParticleState {
+    // Number of entries in the alive list that is being written
+    alive_count: atomic<u32>,
+    // Number of entries in the dead list, signed so spawning can briefly go below zero
+    dead_count: atomic<i32>,
+    // Number of entries in the alive list that is being simulated
+    simulate_count: u32,
+}
+
+// PCG hash (Jarzynski and Olano, 2020)
+fn pcg_hash(value: u32) -> u32 {
+    let state = value * 747796405u + 2891336453u;
+    let word = ((state >> ((state >> 28u) + 4u)) ^ state) * 277803737u;
+    return (word >> 22u) ^ word;
+}
+
+// Returns a value in [0, 1] and advances the seed
+fn random_float(seed: ptr<function, u32>) -> f32 {
+    *seed = pcg_hash(*seed);
+    return f32(*seed) / 4294967295.0;
+}
+
+// Uniformly distributed point inside the unit sphere
+fn random_in_unit_sphere(seed: ptr<function, u32>) -> vec3<f32> {
+    let z = random_float(seed) * 2.0 - 1.0;
+    let angle = random_float(seed) * 6.28318530718;
+    let radius = sqrt(1.0 - z * z);
+    let direction = vec3<f32>(radius * cos(angle), radius * sin(angle), z);
+    return direction * pow(random_float(seed), 1.0 / 3.0);
+}
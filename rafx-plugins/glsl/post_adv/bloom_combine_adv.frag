@@ -24,6 +24,8 @@ layout (set = 0, binding = 2) uniform sampler smp;
 // Should be kept in sync with the constants in OutputColorSpace
 const int OUTPUT_COLOR_SPACE_SRGB = 0;
 const int OUTPUT_COLOR_SPACE_P3 = 1;
+const int OUTPUT_COLOR_SPACE_SCRGB = 2;
+const int OUTPUT_COLOR_SPACE_HDR10 = 3;
 
 // @[export]
 // @[internal_buffer]
@@ -31,6 +33,15 @@ layout (set = 0, binding = 3) uniform Config {
     int tonemapper_type;
     int output_color_space;
     float max_color_component_value;
+    bool enable_color_grading;
+    // 0 grades entirely with color_grading_lut, 1 entirely with color_grading_blend_lut
+    float color_grading_blend;
+    // 0 leaves the tonemapped color ungraded
+    float color_grading_strength;
+    // Brightness of a tonemapped value of 1.0 on scRGB and HDR10 outputs
+    float paper_white_nits;
+    // HDR10 output is clamped to this brightness
+    float peak_nits;
 } config;
 
 layout(set = 0, binding = 4) buffer HistogramResultBuffer
@@ -38,6 +49,24 @@ layout(set = 0, binding = 4) buffer HistogramResultBuffer
     HistogramResult result;
 } histogram_result;
 
+// @[export]
+layout (set = 0, binding = 5) uniform texture3D color_grading_lut;
+
+// @[export]
+layout (set = 0, binding = 6) uniform texture3D color_grading_blend_lut;
+
+// @[immutable_samplers([
+//         (
+//             mag_filter: Linear,
+//             min_filter: Linear,
+//             mip_map_mode: Nearest,
+//             address_mode_u: ClampToEdge,
+//             address_mode_v: ClampToEdge,
+//             address_mode_w: ClampToEdge,
+//         )
+// ])]
+layout (set = 0, binding = 7) uniform sampler smp_lut;
+
 layout (location = 0) in vec2 inUV;
 
 layout (location = 0) out vec4 out_sdr;
@@ -49,6 +78,16 @@ const mat3 sRGB_to_P3 = mat3(
     0.00000000000000005551115, -0.000000000000000017347235, 0.9104994
 );
 
+// Source: ITU-R BT.2087
+const mat3 sRGB_to_Rec2020 = mat3(
+    0.6274040, 0.0690970, 0.0163916,
+    0.3292820, 0.9195400, 0.0880132,
+    0.0433136, 0.0113612, 0.8955950
+);
+
+// scRGB defines a component value of 1.0 as 80 nits
+const float SCRGB_REFERENCE_WHITE_NITS = 80.0;
+
 void main()
 {
     // Combine SDR + blurred HDR
@@ -70,6 +109,16 @@ void main()
         histogram_result.result.max_luminosity_interpolated
     );
 
+    if (config.enable_color_grading) {
+        vec3 graded = apply_color_grading_lut(color_srgb_linear, color_grading_lut, smp_lut);
+        if (config.color_grading_blend > 0.0) {
+            vec3 graded_blend = apply_color_grading_lut(color_srgb_linear, color_grading_blend_lut, smp_lut);
+            graded = mix(graded, graded_blend, config.color_grading_blend);
+        }
+
+        color_srgb_linear = mix(color_srgb_linear, graded, config.color_grading_strength);
+    }
+
     switch (config.output_color_space)
     {
         case OUTPUT_COLOR_SPACE_SRGB:
@@ -79,5 +128,12 @@ void main()
             vec3 color_linear_p3 = sRGB_to_P3 * color_srgb_linear;
             out_sdr = vec4(color_linear_p3, 1.0);
             break;
+        case OUTPUT_COLOR_SPACE_SCRGB:
+            out_sdr = vec4(color_srgb_linear * (config.paper_white_nits / SCRGB_REFERENCE_WHITE_NITS), 1.0);
+            break;
+        case OUTPUT_COLOR_SPACE_HDR10:
+            vec3 color_nits_rec2020 = max(sRGB_to_Rec2020 * color_srgb_linear, vec3(0.0)) * config.paper_white_nits;
+            out_sdr = vec4(nits_to_pq(min(color_nits_rec2020, vec3(config.peak_nits))), 1.0);
+            break;
     }
 }
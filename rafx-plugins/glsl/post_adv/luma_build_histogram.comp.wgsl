@@ -0,0 +1,69 @@
+// WebGPU version of luma_build_histogram.comp. naga can't translate the SPIR-V atomics, so this
+// must be kept in sync with the GLSL by hand.
+
+struct BuildHistogramConfig {
+    // Size of image in pixels
+    input_width: u32,
+    input_height: u32,
+
+    // See luma_build_histogram.comp
+    min_log_luma: f32,
+    one_over_log_luma_range: f32,
+}
+
+struct HistogramData {
+    data: array<atomic<u32>, 256>,
+}
+
+@group(0) @binding(0)
+var<uniform> config: BuildHistogramConfig;
+
+@group(0) @binding(1)
+var tex: texture_2d<f32>;
+
+@group(0) @binding(2)
+var smp: sampler;
+
+@group(0) @binding(3)
+var<storage, read_write> histogram_data: HistogramData;
+
+var<workgroup> HistogramShared: array<atomic<u32>, 256>;
+
+fn color_to_bin(color: vec3<f32>) -> u32 {
+    // Constant from https://en.wikipedia.org/wiki/Relative_luminance
+    let luminance = dot(color, vec3<f32>(0.2126, 0.7152, 0.0722));
+
+    // Avoid div/0
+    if (luminance < 0.00075) {
+        return 0u;
+    }
+
+    // Map luminance from [min_log_luma, log_luma_range] to [1,255] (zero returned by previous conditional)
+    let log_luminance = clamp((log2(luminance) - config.min_log_luma) * config.one_over_log_luma_range, 0.0, 1.0);
+    return u32(log_luminance * 254.0 + 1.0);
+}
+
+@compute @workgroup_size(16, 16, 1)
+fn main(
+    @builtin(global_invocation_id) global_invocation_id: vec3<u32>,
+    @builtin(local_invocation_index) local_invocation_index: u32,
+) {
+    // Clear the shared memory
+    atomicStore(&HistogramShared[local_invocation_index], 0u);
+    workgroupBarrier();
+
+    // Sample the pixel and increment the appropriate bin
+    if (global_invocation_id.x < config.input_width && global_invocation_id.y < config.input_height) {
+        // Sample the HDR texture
+        let uv = vec2<f32>(global_invocation_id.xy) / vec2<f32>(f32(config.input_width), f32(config.input_height));
+        let c = textureSampleLevel(tex, smp, uv, 0.0).rgb;
+
+        // Assign it to a histogram bin and increment the bin
+        let bin_index = color_to_bin(c);
+        atomicAdd(&HistogramShared[bin_index], 1u);
+    }
+
+    // Merge shared group memory of all groups into output buffer
+    workgroupBarrier();
+    atomicAdd(&histogram_data.data[local_invocation_index], atomicLoad(&HistogramShared[local_invocation_index]));
+}
@@ -0,0 +1,54 @@
+#version 450
+#extension GL_ARB_separate_shader_objects : enable
+
+// Resolves weighted blended order-independent transparency onto the opaque color. This pass uses
+// SrcAlpha/OneMinusSrcAlpha blending, so writing alpha = 1 - revealage gives
+// average_color * (1 - revealage) + opaque_color * revealage
+
+// @[export]
+layout (set = 0, binding = 0) uniform texture2D accum_tex;
+
+// @[export]
+layout (set = 0, binding = 1) uniform texture2D revealage_tex;
+
+// @[immutable_samplers([
+//         (
+//             mag_filter: Nearest,
+//             min_filter: Nearest,
+//             mip_map_mode: Nearest,
+//             address_mode_u: ClampToEdge,
+//             address_mode_v: ClampToEdge,
+//             address_mode_w: ClampToEdge,
+//         )
+// ])]
+layout (set = 0, binding = 2) uniform sampler smp;
+
+layout (location = 0) in vec2 inUV;
+
+layout (location = 0) out vec4 out_color;
+
+void main()
+{
+    ivec2 pixel = ivec2(gl_FragCoord.xy);
+    float revealage = texelFetch(sampler2D(revealage_tex, smp), pixel, 0).r;
+
+    // Nothing transparent was drawn here
+    if (revealage == 1.0) {
+        discard;
+    }
+
+    vec4 accum = texelFetch(sampler2D(accum_tex, smp), pixel, 0);
+
+    // Many bright overlapping surfaces can overflow the 16-bit float accumulation
+#ifdef PLATFORM_WEBGPU
+    // WGSL has no isinf(), anything above the largest finite float is infinite
+    if (max(max(abs(accum.r), abs(accum.g)), abs(accum.b)) > 3.402823466e38) {
+#else
+    if (isinf(max(max(abs(accum.r), abs(accum.g)), abs(accum.b)))) {
+#endif
+        accum.rgb = vec3(accum.a);
+    }
+
+    vec3 average_color = accum.rgb / max(accum.a, 0.00001);
+    out_color = vec4(average_color, 1.0 - revealage);
+}
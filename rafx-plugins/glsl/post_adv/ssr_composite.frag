@@ -0,0 +1,69 @@
+#version 450
+#extension GL_ARB_separate_shader_objects : enable
+
+#include "../util/normal_encoding.glsl"
+
+// Blends screen-space reflections over the lit opaque color. This pass uses SrcAlpha/OneMinusSrcAlpha blending, so
+// the alpha written here is how much of the reflection replaces the color already in the target.
+
+// @[export]
+layout (set = 0, binding = 0) uniform texture2D ssr_tex;
+
+// @[export]
+layout (set = 0, binding = 1) uniform texture2D gbuffer_tex;
+
+// @[export]
+layout (set = 0, binding = 2) uniform texture2D depth_tex;
+
+// @[immutable_samplers([
+//         (
+//             mag_filter: Nearest,
+//             min_filter: Nearest,
+//             mip_map_mode: Nearest,
+//             address_mode_u: ClampToEdge,
+//             address_mode_v: ClampToEdge,
+//             address_mode_w: ClampToEdge,
+//         )
+// ])]
+layout (set = 0, binding = 3) uniform sampler smp;
+
+// @[export]
+// @[internal_buffer]
+layout (set = 0, binding = 4) uniform SsrCompositeConfig {
+    mat4 inverse_proj_matrix;
+    float max_roughness;
+} config;
+
+layout (location = 0) in vec2 inUV;
+
+layout (location = 0) out vec4 out_color;
+
+void main()
+{
+    ivec2 pixel = ivec2(gl_FragCoord.xy);
+    vec4 ssr = texelFetch(sampler2D(ssr_tex, smp), pixel, 0);
+
+    // No reflection was found here
+    if (ssr.a <= 0.0) {
+        discard;
+    }
+
+    vec4 gbuffer = texelFetch(sampler2D(gbuffer_tex, smp), pixel, 0);
+    float depth = texelFetch(sampler2D(depth_tex, smp), pixel, 0).r;
+
+    vec2 ndc = inUV * 2.0 - 1.0;
+    ndc.y *= -1.0;
+    vec4 position_vs = config.inverse_proj_matrix * vec4(ndc, depth, 1.0);
+    vec3 view_dir_vs = -normalize(position_vs.xyz / position_vs.w);
+    vec3 normal_vs = octahedral_decode(gbuffer.xy);
+
+    float roughness = gbuffer.z;
+    float reflectance = gbuffer.w;
+    float n_dot_v = clamp(dot(normal_vs, view_dir_vs), 0.0, 1.0);
+    float fresnel = reflectance + (1.0 - reflectance) * pow(1.0 - n_dot_v, 5.0);
+
+    // Rough surfaces blur their reflections, which this pass doesn't do, so fade them out instead
+    float roughness_fade = clamp(1.0 - roughness / max(config.max_roughness, 0.0001), 0.0, 1.0);
+
+    out_color = vec4(ssr.rgb, ssr.a * fresnel * roughness_fade);
+}
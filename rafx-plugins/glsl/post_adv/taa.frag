@@ -243,9 +243,13 @@ void main() {
     vec3 m2 = vec3(0.0);
     float m_weight = 0.0;
 
-    // We assume all textures are the same size
+    // The current color, velocity and depth textures are the same size. History is the size of the output, which is
+    // larger when upscaling.
     vec2 texture_size = textureSize(sampler2D(current_tex, smp_nearest), 0);
     vec2 texel_size = 1.0 / texture_size;
+    vec2 history_texture_size = textureSize(sampler2D(history_tex, smp_nearest), 0);
+    vec2 history_texel_size = 1.0 / history_texture_size;
+    bool is_upscaling = texture_size.x < history_texture_size.x || texture_size.y < history_texture_size.y;
     vec3 current_color;
     for (int y = -COLOR_SAMPLE_RADIUS; y <= COLOR_SAMPLE_RADIUS; ++y)
     {
@@ -271,6 +275,15 @@ void main() {
         }
     }
 
+    //
+    // When upscaling, the nearest current pixel may be far from the center of this output pixel. Remove the jitter and
+    // filter the current frame so that it lines up with the history.
+    //
+    if (is_upscaling) {
+        vec2 unjittered_uv = clamp(in_uv + config.jitter_amount * vec2(0.5, -0.5), 0.0, 1.0);
+        current_color = textureLod(sampler2D(current_tex, smp_bilinear), unjittered_uv, 0.0).rgb;
+    }
+
     //
     // 2. Get velocity - with 1px dilation favoring closest fragment. The dialation ensures we grab the closest value
     //    even if the aliasing in the input would make us miss it on some pixels
@@ -327,7 +340,7 @@ void main() {
     vec2 history_sample_uv = inUV - (velocity_ndc * vec2(0.5, -0.5)); // ndc -> uv
     if (history_sample_uv.x <= 1.0 && history_sample_uv.x >= 0.0 && history_sample_uv.y <= 1.0 && history_sample_uv.y >= 0.0) {
         // catmull-rom filtering reduces accumulation of blur
-        history_color = sample_history_catmull_rom(history_sample_uv, texel_size);
+        history_color = sample_history_catmull_rom(history_sample_uv, history_texel_size);
         //history_color = texture(sampler2D(history_tex, smp), history_sample_uv).rgb;
     }
 
@@ -352,6 +365,10 @@ void main() {
     //
     // does velocity need to be normalized by viewport size?
     float current_weight = config.history_weight + min(length(velocity_ndc) * config.history_weight_velocity_adjust_multiplier, config.history_weight_velocity_adjust_max);
+    if (is_upscaling) {
+        // Fewer new samples land in each output pixel, so more of the result has to come from history
+        current_weight *= (texture_size.x * texture_size.y) / (history_texture_size.x * history_texture_size.y);
+    }
     float history_weight = 1.0 - current_weight;
 
     current_weight *= 1.0 / (1.0 + rgb_to_luminosity(current_color));
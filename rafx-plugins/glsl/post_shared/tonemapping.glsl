@@ -102,6 +102,32 @@ vec3 srgb_to_linear(vec3 sRGB)
     return mix(higher, lower, cutoff);
 }
 
+// Converts absolute luminance in nits (cd/m^2) to the SMPTE ST 2084 (PQ) encoding used by HDR10
+vec3 nits_to_pq(vec3 nits)
+{
+    const float m1 = 0.1593017578125;
+    const float m2 = 78.84375;
+    const float c1 = 0.8359375;
+    const float c2 = 18.8515625;
+    const float c3 = 18.6875;
+
+    vec3 y = pow(clamp(nits / 10000.0, vec3(0.0), vec3(1.0)), vec3(m1));
+    return pow((c1 + c2 * y) / (1.0 + c3 * y), vec3(m2));
+}
+
+// Grades a color through a 3D LUT indexed by its sRGB-encoded value, which is how LUTs are authored
+// in image editors. The LUT's outermost texel centers are mapped to 0 and 1 so that its first and
+// last entries are hit exactly. Components above 1 (extended range output) are added back on top of
+// the graded value so that grading doesn't clip them.
+vec3 apply_color_grading_lut(vec3 color_linear, texture3D lut, sampler smp)
+{
+    vec3 lut_size = vec3(textureSize(sampler3D(lut, smp), 0));
+    vec3 encoded = linear_to_srgb(clamp(color_linear, vec3(0.0), vec3(1.0)));
+    vec3 uvw = encoded * ((lut_size - 1.0) / lut_size) + 0.5 / lut_size;
+    vec3 graded = srgb_to_linear(textureLod(sampler3D(lut, smp), uvw, 0.0).rgb);
+    return graded + max(color_linear - vec3(1.0), vec3(0.0));
+}
+
 const float SHOULDER_STRENGTH = 4.0;
 const float LINEAR_STRENGTH = 5.0;
 const float LINEAR_ANGLE = 0.1200;
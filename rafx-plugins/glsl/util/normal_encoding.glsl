@@ -0,0 +1,19 @@
+
+// Octahedral encoding of a unit vector into [-1, 1]^2 ("A Survey of Efficient Representations for Independent Unit
+// Vectors", Cigolle et al. 2014)
+vec2 octahedral_sign_not_zero(vec2 v) {
+    return vec2(v.x >= 0.0 ? 1.0 : -1.0, v.y >= 0.0 ? 1.0 : -1.0);
+}
+
+vec2 octahedral_encode(vec3 n) {
+    vec2 p = n.xy * (1.0 / (abs(n.x) + abs(n.y) + abs(n.z)));
+    return (n.z <= 0.0) ? ((1.0 - abs(p.yx)) * octahedral_sign_not_zero(p)) : p;
+}
+
+vec3 octahedral_decode(vec2 e) {
+    vec3 v = vec3(e.xy, 1.0 - abs(e.x) - abs(e.y));
+    if (v.z < 0.0) {
+        v.xy = (1.0 - abs(v.yx)) * octahedral_sign_not_zero(v.xy);
+    }
+    return normalize(v);
+}
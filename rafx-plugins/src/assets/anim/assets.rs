@@ -1,3 +1,4 @@
+use fnv::FnvHashMap;
 use hydrate_base::LoadHandle;
 use rafx::api::RafxResult;
 use rafx::assets::{AssetManager, DefaultAssetTypeHandler, DefaultAssetTypeLoadHandler};
@@ -65,6 +66,7 @@ pub struct AnimAssetData {
 pub struct AnimAssetInner {
     pub skeleton: Arc<Skeleton>,
     pub clips: Vec<Arc<AnimClip>>,
+    pub bone_index_lookup: FnvHashMap<String, usize>,
 }
 
 #[derive(TypeUuid, Clone, Debug)]
@@ -84,6 +86,24 @@ impl AnimAsset {
     ) -> &Arc<AnimClip> {
         &self.inner.clips[index]
     }
+
+    pub fn clip_count(&self) -> usize {
+        self.inner.clips.len()
+    }
+
+    pub fn find_clip(
+        &self,
+        name: &str,
+    ) -> Option<usize> {
+        self.inner.clips.iter().position(|x| x.name == name)
+    }
+
+    pub fn bone_index(
+        &self,
+        name: &str,
+    ) -> Option<usize> {
+        self.inner.bone_index_lookup.get(name).copied()
+    }
 }
 
 pub struct AnimLoadHandler;
@@ -95,10 +115,21 @@ impl DefaultAssetTypeLoadHandler<AnimAssetData, AnimAsset> for AnimLoadHandler {
         anim_asset: AnimAssetData,
         _load_handle: LoadHandle,
     ) -> RafxResult<AnimAsset> {
+        let bone_index_lookup = anim_asset
+            .skeleton
+            .bones
+            .iter()
+            .enumerate()
+            .map(|(index, bone)| (bone.name.clone(), index))
+            .collect();
         let skeleton = Arc::new(anim_asset.skeleton);
         let clips = anim_asset.clips.into_iter().map(|x| Arc::new(x)).collect();
 
-        let inner = AnimAssetInner { skeleton, clips };
+        let inner = AnimAssetInner {
+            skeleton,
+            clips,
+            bone_index_lookup,
+        };
 
         Ok(AnimAsset {
             inner: Arc::new(inner),
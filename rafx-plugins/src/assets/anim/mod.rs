@@ -1,6 +1,9 @@
 mod assets;
 pub use assets::*;
 
+mod pose;
+pub use pose::*;
+
 mod blender_anim_importer;
 pub use blender_anim_importer::*;
 
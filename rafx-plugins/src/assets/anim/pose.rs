@@ -0,0 +1,318 @@
+use super::{AnimClip, BoneChannelGroup, BoneChannelQuat, BoneChannelVec3, Skeleton};
+use glam::{Mat4, Quat, Vec3};
+
+impl BoneChannelVec3 {
+    // Linearly interpolates between the keys on either side of `frame`, clamping to the first/last
+    // key. Returns None if the channel has no keys.
+    pub fn sample(
+        &self,
+        frame: f32,
+    ) -> Option<Vec3> {
+        let (index0, index1, t) = channel_keys(self.min_frame, self.values.len(), frame)?;
+        Some(self.values[index0].lerp(self.values[index1], t))
+    }
+}
+
+impl BoneChannelQuat {
+    // Spherically interpolates between the keys on either side of `frame`, clamping to the
+    // first/last key. Returns None if the channel has no keys.
+    pub fn sample(
+        &self,
+        frame: f32,
+    ) -> Option<Quat> {
+        let (index0, index1, t) = channel_keys(self.min_frame, self.values.len(), frame)?;
+        //TODO: Normalize on import, the exported values are not always unit length
+        Some(
+            self.values[index0]
+                .normalize()
+                .slerp(self.values[index1].normalize(), t),
+        )
+    }
+}
+
+// Returns the indices of the keys before and after `frame` and the weight of the second one, or
+// None if there are no keys
+fn channel_keys(
+    min_frame: u32,
+    key_count: usize,
+    frame: f32,
+) -> Option<(usize, usize, f32)> {
+    if key_count == 0 {
+        return None;
+    }
+
+    let offset = (frame - min_frame as f32).clamp(0.0, (key_count - 1) as f32);
+    let index0 = offset.floor() as usize;
+    let index1 = (index0 + 1).min(key_count - 1);
+    Some((index0, index1, offset - index0 as f32))
+}
+
+impl BoneChannelGroup {
+    // The bone's transform relative to its rest pose. Missing or empty channels keep the rest pose.
+    pub fn sample(
+        &self,
+        frame: f32,
+    ) -> (Vec3, Quat, Vec3) {
+        let position = self
+            .position
+            .as_ref()
+            .and_then(|x| x.sample(frame))
+            .unwrap_or(Vec3::ZERO);
+        let rotation = self
+            .rotation
+            .as_ref()
+            .and_then(|x| x.sample(frame))
+            .unwrap_or(Quat::IDENTITY);
+        let scale = self
+            .scale
+            .as_ref()
+            .and_then(|x| x.sample(frame))
+            .unwrap_or(Vec3::ONE);
+        (position, rotation, scale)
+    }
+}
+
+impl AnimClip {
+    // The last frame that has a key in any channel
+    pub fn max_frame(&self) -> u32 {
+        self.bone_channel_groups
+            .iter()
+            .flat_map(|x| {
+                [
+                    x.position.as_ref().map(|x| x.max_frame),
+                    x.rotation.as_ref().map(|x| x.max_frame),
+                    x.scale.as_ref().map(|x| x.max_frame),
+                ]
+            })
+            .flatten()
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+/// Model-space transform of every bone in a skeleton, in the same order as `Skeleton::bones`
+#[derive(Debug, Clone)]
+pub struct SkeletonPose {
+    pub bone_transforms: Vec<Mat4>,
+}
+
+impl SkeletonPose {
+    pub fn rest(skeleton: &Skeleton) -> Self {
+        Self::evaluate(skeleton, |_| (Vec3::ZERO, Quat::IDENTITY, Vec3::ONE))
+    }
+
+    /// Evaluates the clip's channels at `frame`, which may be fractional. Bones without channels
+    /// stay in their rest pose relative to their parent.
+    pub fn sample(
+        skeleton: &Skeleton,
+        clip: &AnimClip,
+        frame: f32,
+    ) -> Self {
+        Self::evaluate(skeleton, |bone_index| {
+            clip.bone_channel_groups
+                .get(bone_index)
+                .map(|x| x.sample(frame))
+                .unwrap_or((Vec3::ZERO, Quat::IDENTITY, Vec3::ONE))
+        })
+    }
+
+    fn evaluate<F: Fn(usize) -> (Vec3, Quat, Vec3)>(
+        skeleton: &Skeleton,
+        local_transform: F,
+    ) -> Self {
+        let mut bone_transforms = Vec::<Mat4>::with_capacity(skeleton.bones.len());
+        for (bone_index, bone) in skeleton.bones.iter().enumerate() {
+            // The animated transform is in the local space of the bone's rest pose. Bones are sorted
+            // by chain depth so the parent's transform is always already known.
+            let (anim_position, anim_rotation, anim_scale) = local_transform(bone_index);
+            let rest_transform =
+                Mat4::from_rotation_translation(bone.rotation_rel.normalize(), bone.position_rel);
+            let anim_transform =
+                Mat4::from_scale_rotation_translation(anim_scale, anim_rotation, anim_position);
+            let mut transform = rest_transform * anim_transform;
+
+            if bone.parent != -1 {
+                transform = bone_transforms[bone.parent as usize] * transform;
+            }
+
+            bone_transforms.push(transform);
+        }
+
+        SkeletonPose { bone_transforms }
+    }
+
+    pub fn bone_position(
+        &self,
+        bone_index: usize,
+    ) -> Vec3 {
+        self.bone_transforms[bone_index].w_axis.truncate()
+    }
+
+    pub fn bone_rotation(
+        &self,
+        bone_index: usize,
+    ) -> Quat {
+        self.bone_transforms[bone_index]
+            .to_scale_rotation_translation()
+            .1
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::assets::anim::Bone;
+
+    // glam's slerp uses an approximate acos
+    const EPSILON: f32 = 0.001;
+
+    fn assert_vec3_eq(
+        actual: Vec3,
+        expected: Vec3,
+    ) {
+        assert!(
+            actual.abs_diff_eq(expected, EPSILON),
+            "{} != {}",
+            actual,
+            expected
+        );
+    }
+
+    fn vec3_channel(
+        min_frame: u32,
+        values: Vec<Vec3>,
+    ) -> BoneChannelVec3 {
+        BoneChannelVec3 {
+            min_frame,
+            max_frame: min_frame + values.len().max(1) as u32 - 1,
+            values,
+        }
+    }
+
+    fn bone(
+        parent: i16,
+        position_rel: Vec3,
+    ) -> Bone {
+        Bone {
+            name: String::default(),
+            position_rel,
+            rotation_rel: Quat::IDENTITY,
+            parent,
+            chain_depth: 0,
+        }
+    }
+
+    #[test]
+    fn sample_interpolates_and_clamps() {
+        let channel = vec3_channel(10, vec![Vec3::ZERO, Vec3::new(2.0, 4.0, 0.0), Vec3::X]);
+
+        assert_vec3_eq(channel.sample(10.0).unwrap(), Vec3::ZERO);
+        assert_vec3_eq(channel.sample(10.5).unwrap(), Vec3::new(1.0, 2.0, 0.0));
+        assert_vec3_eq(channel.sample(11.0).unwrap(), Vec3::new(2.0, 4.0, 0.0));
+        assert_vec3_eq(channel.sample(11.75).unwrap(), Vec3::new(1.25, 1.0, 0.0));
+
+        // Frames before the first key and after the last key hold the end keys
+        assert_vec3_eq(channel.sample(0.0).unwrap(), Vec3::ZERO);
+        assert_vec3_eq(channel.sample(9.5).unwrap(), Vec3::ZERO);
+        assert_vec3_eq(channel.sample(12.0).unwrap(), Vec3::X);
+        assert_vec3_eq(channel.sample(100.0).unwrap(), Vec3::X);
+
+        // A single key is constant
+        let single_key = vec3_channel(3, vec![Vec3::Y]);
+        assert_vec3_eq(single_key.sample(0.0).unwrap(), Vec3::Y);
+        assert_vec3_eq(single_key.sample(3.5).unwrap(), Vec3::Y);
+    }
+
+    #[test]
+    fn sample_slerps_rotations() {
+        let channel = BoneChannelQuat {
+            min_frame: 0,
+            max_frame: 1,
+            // The second key isn't unit length, which is normalized when sampling
+            values: vec![
+                Quat::IDENTITY,
+                Quat::from_rotation_z(std::f32::consts::FRAC_PI_2) * 2.0,
+            ],
+        };
+
+        let halfway = channel.sample(0.5).unwrap();
+        assert!((halfway.length() - 1.0).abs() < EPSILON);
+        assert!(halfway.abs_diff_eq(Quat::from_rotation_z(std::f32::consts::FRAC_PI_4), EPSILON));
+
+        // Slerp keeps a constant angular velocity, unlike a normalized lerp
+        let quarter = channel.sample(0.25).unwrap();
+        assert!(quarter.abs_diff_eq(Quat::from_rotation_z(std::f32::consts::FRAC_PI_8), EPSILON));
+
+        assert!(channel
+            .sample(2.0)
+            .unwrap()
+            .abs_diff_eq(Quat::from_rotation_z(std::f32::consts::FRAC_PI_2), EPSILON));
+    }
+
+    #[test]
+    fn empty_channels_keep_the_rest_pose() {
+        let group = BoneChannelGroup {
+            position: Some(vec3_channel(0, vec![])),
+            rotation: Some(BoneChannelQuat {
+                min_frame: 0,
+                max_frame: 0,
+                values: vec![],
+            }),
+            scale: None,
+        };
+
+        assert!(group.position.as_ref().unwrap().sample(0.0).is_none());
+        assert!(group.rotation.as_ref().unwrap().sample(0.0).is_none());
+        assert_eq!(group.sample(1.0), (Vec3::ZERO, Quat::IDENTITY, Vec3::ONE));
+    }
+
+    #[test]
+    fn evaluate_applies_parent_transforms() {
+        let skeleton = Skeleton {
+            bones: vec![
+                bone(-1, Vec3::new(0.0, 1.0, 0.0)),
+                bone(0, Vec3::new(0.0, 2.0, 0.0)),
+                bone(1, Vec3::new(1.0, 0.0, 0.0)),
+            ],
+        };
+
+        let rest = SkeletonPose::rest(&skeleton);
+        assert_vec3_eq(rest.bone_position(0), Vec3::new(0.0, 1.0, 0.0));
+        assert_vec3_eq(rest.bone_position(1), Vec3::new(0.0, 3.0, 0.0));
+        assert_vec3_eq(rest.bone_position(2), Vec3::new(1.0, 3.0, 0.0));
+
+        // Rotating the middle bone a quarter turn around z moves its child, but not its parent
+        let clip = AnimClip {
+            name: String::default(),
+            bone_channel_groups: vec![
+                BoneChannelGroup::default(),
+                BoneChannelGroup {
+                    rotation: Some(BoneChannelQuat {
+                        min_frame: 0,
+                        max_frame: 1,
+                        values: vec![Quat::IDENTITY, Quat::from_rotation_z(std::f32::consts::PI)],
+                    }),
+                    ..Default::default()
+                },
+            ],
+        };
+
+        let pose = SkeletonPose::sample(&skeleton, &clip, 0.5);
+        assert_vec3_eq(pose.bone_position(0), Vec3::new(0.0, 1.0, 0.0));
+        assert_vec3_eq(pose.bone_position(1), Vec3::new(0.0, 3.0, 0.0));
+        assert_vec3_eq(pose.bone_position(2), Vec3::new(0.0, 4.0, 0.0));
+        assert!(pose
+            .bone_rotation(2)
+            .abs_diff_eq(Quat::from_rotation_z(std::f32::consts::FRAC_PI_2), EPSILON));
+
+        // Bones past the end of the clip's channel groups stay in their rest pose relative to
+        // their parent, and frame 0 is the rest pose
+        let pose = SkeletonPose::sample(&skeleton, &clip, 0.0);
+        for bone_index in 0..3 {
+            assert_vec3_eq(
+                pose.bone_position(bone_index),
+                rest.bone_position(bone_index),
+            );
+        }
+    }
+}
@@ -1,5 +1,5 @@
 use crate::assets::mesh_adv::{MeshAdvBufferAsset, MeshMaterialAdvAsset};
-use crate::features::mesh_adv::MeshAdvUntexturedRenderFeatureFlag;
+use crate::features::mesh_adv::{MeshAdvUntexturedRenderFeatureFlag, MeshVertexPosition};
 use crate::phases::{OpaqueRenderPhase, TransparentRenderPhase, WireframeRenderPhase};
 use hydrate_base::{Handle, LoadHandle};
 use rafx::api::{RafxIndexType, RafxResult};
@@ -17,8 +17,8 @@ use type_uuid::*;
 pub enum MeshAdvShadowMethod {
     None,
     Opaque,
-    //AlphaClip,
-    //AlphaStochastic,
+    AlphaClip,
+    AlphaStochastic,
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
@@ -83,8 +83,47 @@ pub struct MeshAdvPartAssetData {
     pub vertex_position_buffer_size_in_bytes: u32,
     pub index_buffer_offset_in_bytes: u32,
     pub index_buffer_size_in_bytes: u32,
+    // Only set for skinned meshes, relative to MeshAdvAssetData::vertex_skin_buffer
+    pub vertex_skin_buffer_offset_in_bytes: u32,
+    pub vertex_skin_buffer_size_in_bytes: u32,
     pub mesh_material: Handle<MeshMaterialAdvAsset>,
     pub index_type: RafxIndexType,
+    // Empty unless meshlets were generated on import
+    pub meshlets: Vec<MeshAdvMeshletAssetData>,
+    // Simplified versions of the part generated on import, most detailed first
+    pub lods: Vec<MeshAdvPartLodAssetData>,
+}
+
+// A simplified version of a mesh part. It shares the vertices of the part and only has its own
+// indices.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct MeshAdvPartLodAssetData {
+    pub index_buffer_offset_in_bytes: u32,
+    pub index_buffer_size_in_bytes: u32,
+    pub meshlets: Vec<MeshAdvMeshletAssetData>,
+}
+
+// A cluster of up to 124 triangles that are contiguous in the index buffer of the part. The bounds
+// are in mesh space and can be used to cull clusters individually. A cluster is backfacing when
+// dot(normalize(cone_apex - camera_position), cone_axis) >= cone_cutoff.
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, Default)]
+pub struct MeshAdvMeshletAssetData {
+    pub index_offset: u32, // In number of indices, relative to the first index of the part
+    pub index_count: u32,  // In number of indices
+    pub bounding_sphere_center: [f32; 3],
+    pub bounding_sphere_radius: f32,
+    pub cone_apex: [f32; 3],
+    pub cone_axis: [f32; 3],
+    pub cone_cutoff: f32,
+}
+
+// Joints referenced by MeshVertexSkin::joint_indices. Joints are bound to the bones of an AnimAsset's
+// skeleton by name when the mesh is animated.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct MeshAdvSkinAssetData {
+    pub joint_names: Vec<String>,
+    // Column-major, transforms from mesh space into the joint's space at bind time
+    pub inverse_bind_matrices: Vec<[f32; 16]>,
 }
 
 #[derive(TypeUuid, Serialize, Deserialize, Clone)]
@@ -94,6 +133,8 @@ pub struct MeshAdvAssetData {
     pub vertex_full_buffer: Handle<MeshAdvBufferAsset>, // Vertex type is MeshVertexFull
     pub vertex_position_buffer: Handle<MeshAdvBufferAsset>, // Vertex type is MeshVertexPosition
     pub index_buffer: Handle<MeshAdvBufferAsset>,       // u16 indices
+    pub vertex_skin_buffer: Option<Handle<MeshAdvBufferAsset>>, // Vertex type is MeshVertexSkin
+    pub skin: Option<MeshAdvSkinAssetData>,
     pub visible_bounds: VisibleBounds,
 }
 
@@ -121,6 +162,8 @@ pub struct MeshAdvShaderPassIndices {
     // For shadow maps we don't do any face culling
     pub shadow_map: u8,
     //pub shadow_map_backface: u8,
+    pub shadow_map_alpha_clip: u8,
+    pub shadow_map_alpha_stochastic: u8,
     pub opaque: u8,
     pub opaque_backface: u8,
     //pub opaque_alphaclip: u8,
@@ -129,10 +172,20 @@ pub struct MeshAdvShaderPassIndices {
     pub opaque_backface_untextured: u8,
     //pub opaque_alphaclip_untextured: u8,
     //pub opaque_alphaclip_backface_untextured: u8,
+    // Used instead of the opaque passes when the opaque pass also writes a G-buffer
+    pub opaque_gbuffer: u8,
+    pub opaque_gbuffer_backface: u8,
+    pub opaque_gbuffer_untextured: u8,
+    pub opaque_gbuffer_backface_untextured: u8,
     pub transparent: u8,
     pub transparent_backface: u8,
     pub transparent_untextured: u8,
     pub transparent_backface_untextured: u8,
+    // Used instead of the transparent passes when drawing into weighted blended OIT targets
+    pub transparent_oit: u8,
+    pub transparent_oit_backface: u8,
+    pub transparent_oit_untextured: u8,
+    pub transparent_oit_backface_untextured: u8,
 
     pub wireframe: u8,
 }
@@ -148,6 +201,8 @@ impl MeshAdvShaderPassIndices {
         ////let depth_prepass_velocity_backface_moved = material.find_pass_index_by_name("depth_prepass_velocity_backface_moved").expect("Mesh shader must have pass named 'depth_prepass_velocity_backface_moved'") as u8;
         let shadow_map = material.find_pass_index_by_name("shadow_map").expect("Mesh shader must have pass named 'shadow_map'") as u8;
         //let shadow_map_backface = material.find_pass_index_by_name("shadow_map_backface").expect("Mesh shader must have pass named 'shadow_map_backface'") as u8;
+        let shadow_map_alpha_clip = material.find_pass_index_by_name("shadow_map_alpha_clip").expect("Mesh shader must have pass named 'shadow_map_alpha_clip'") as u8;
+        let shadow_map_alpha_stochastic = material.find_pass_index_by_name("shadow_map_alpha_stochastic").expect("Mesh shader must have pass named 'shadow_map_alpha_stochastic'") as u8;
         let opaque = material.find_pass_index_by_name("opaque").expect("Mesh shader must have pass named 'opaque'") as u8;
         let opaque_backface = material.find_pass_index_by_name("opaque_backface").expect("Mesh shader must have pass named 'opaque_backface'") as u8;
         //let opaque_alphaclip = material.find_pass_index_by_name("opaque_alphaclip").expect("Mesh shader must have pass named 'opaque_alphaclip'") as u8;
@@ -156,10 +211,18 @@ impl MeshAdvShaderPassIndices {
         let opaque_backface_untextured = material.find_pass_index_by_name("opaque_backface_untextured").expect("Mesh shader must have pass named 'opaque_backface'") as u8;
         //let opaque_alphaclip_untextured = material.find_pass_index_by_name("opaque_alphaclip").expect("Mesh shader must have pass named 'opaque_alphaclip'") as u8;
         //let opaque_alphaclip_backface_untextured = material.find_pass_index_by_name("opaque_alphaclip_backface").expect("Mesh shader must have pass named 'opaque_alphaclip_backface'") as u8;
+        let opaque_gbuffer = material.find_pass_index_by_name("opaque_gbuffer").expect("Mesh shader must have pass named 'opaque_gbuffer'") as u8;
+        let opaque_gbuffer_backface = material.find_pass_index_by_name("opaque_gbuffer_backface").expect("Mesh shader must have pass named 'opaque_gbuffer_backface'") as u8;
+        let opaque_gbuffer_untextured = material.find_pass_index_by_name("opaque_gbuffer_untextured").expect("Mesh shader must have pass named 'opaque_gbuffer_untextured'") as u8;
+        let opaque_gbuffer_backface_untextured = material.find_pass_index_by_name("opaque_gbuffer_backface_untextured").expect("Mesh shader must have pass named 'opaque_gbuffer_backface_untextured'") as u8;
         let transparent = material.find_pass_index_by_name("transparent").expect("Mesh shader must have pass named 'transparent'") as u8;
         let transparent_backface = material.find_pass_index_by_name("transparent_backface").expect("Mesh shader must have pass named 'transparent_backface'") as u8;
         let transparent_untextured = material.find_pass_index_by_name("transparent_untextured").expect("Mesh shader must have pass named 'transparent'") as u8;
         let transparent_backface_untextured = material.find_pass_index_by_name("transparent_backface_untextured").expect("Mesh shader must have pass named 'transparent_backface'") as u8;
+        let transparent_oit = material.find_pass_index_by_name("transparent_oit").expect("Mesh shader must have pass named 'transparent_oit'") as u8;
+        let transparent_oit_backface = material.find_pass_index_by_name("transparent_oit_backface").expect("Mesh shader must have pass named 'transparent_oit_backface'") as u8;
+        let transparent_oit_untextured = material.find_pass_index_by_name("transparent_oit_untextured").expect("Mesh shader must have pass named 'transparent_oit_untextured'") as u8;
+        let transparent_oit_backface_untextured = material.find_pass_index_by_name("transparent_oit_backface_untextured").expect("Mesh shader must have pass named 'transparent_oit_backface_untextured'") as u8;
         let wireframe = material.find_pass_index_by_name("wireframe").expect("Mesh shader must have pass named 'wireframe'") as u8;
 
         MeshAdvShaderPassIndices {
@@ -171,6 +234,8 @@ impl MeshAdvShaderPassIndices {
             ////depth_prepass_velocity_backface_moved,
             shadow_map,
             //shadow_map_backface,
+            shadow_map_alpha_clip,
+            shadow_map_alpha_stochastic,
             opaque,
             opaque_backface,
             //opaque_alphaclip,
@@ -179,10 +244,18 @@ impl MeshAdvShaderPassIndices {
             opaque_backface_untextured,
             //opaque_alphaclip_untextured,
             //opaque_alphaclip_backface_untextured,
+            opaque_gbuffer,
+            opaque_gbuffer_backface,
+            opaque_gbuffer_untextured,
+            opaque_gbuffer_backface_untextured,
             transparent,
             transparent_backface,
             transparent_untextured,
             transparent_backface_untextured,
+            transparent_oit,
+            transparent_oit_backface,
+            transparent_oit_untextured,
+            transparent_oit_backface_untextured,
             wireframe
         }
     }
@@ -192,43 +265,76 @@ impl MeshAdvShaderPassIndices {
         material_data: &MeshAdvMaterialData,
         render_phase_index: RenderPhaseIndex,
         untextured: bool,
+        order_independent_transparency: bool,
+        write_gbuffer: bool,
     ) -> usize {
-        let pass_index = if render_phase_index == OpaqueRenderPhase::render_phase_index() {
-            if material_data.backface_culling {
-                if untextured {
-                    self.opaque_untextured
+        let pass_index =
+            if render_phase_index == OpaqueRenderPhase::render_phase_index() && write_gbuffer {
+                if material_data.backface_culling {
+                    if untextured {
+                        self.opaque_gbuffer_untextured
+                    } else {
+                        self.opaque_gbuffer
+                    }
                 } else {
-                    self.opaque
+                    if untextured {
+                        self.opaque_gbuffer_backface_untextured
+                    } else {
+                        self.opaque_gbuffer_backface
+                    }
                 }
-            } else {
-                if untextured {
-                    self.opaque_backface_untextured
+            } else if render_phase_index == OpaqueRenderPhase::render_phase_index() {
+                if material_data.backface_culling {
+                    if untextured {
+                        self.opaque_untextured
+                    } else {
+                        self.opaque
+                    }
                 } else {
-                    self.opaque_backface
+                    if untextured {
+                        self.opaque_backface_untextured
+                    } else {
+                        self.opaque_backface
+                    }
                 }
-            }
-        } else if render_phase_index == TransparentRenderPhase::render_phase_index() {
-            if material_data.backface_culling {
-                if untextured {
-                    self.transparent_untextured
+            } else if render_phase_index == TransparentRenderPhase::render_phase_index()
+                && order_independent_transparency
+            {
+                if material_data.backface_culling {
+                    if untextured {
+                        self.transparent_oit_untextured
+                    } else {
+                        self.transparent_oit
+                    }
                 } else {
-                    self.transparent
+                    if untextured {
+                        self.transparent_oit_backface_untextured
+                    } else {
+                        self.transparent_oit_backface
+                    }
                 }
-            } else {
-                if untextured {
-                    self.transparent_backface_untextured
+            } else if render_phase_index == TransparentRenderPhase::render_phase_index() {
+                if material_data.backface_culling {
+                    if untextured {
+                        self.transparent_untextured
+                    } else {
+                        self.transparent
+                    }
                 } else {
-                    self.transparent_backface
+                    if untextured {
+                        self.transparent_backface_untextured
+                    } else {
+                        self.transparent_backface
+                    }
                 }
-            }
-        } else if render_phase_index == WireframeRenderPhase::render_phase_index() {
-            self.wireframe
-        } else {
-            panic!(
-                "mesh does not support render phase index {}",
-                render_phase_index
-            )
-        };
+            } else if render_phase_index == WireframeRenderPhase::render_phase_index() {
+                self.wireframe
+            } else {
+                panic!(
+                    "mesh does not support render phase index {}",
+                    render_phase_index
+                )
+            };
 
         pass_index as usize
     }
@@ -244,20 +350,30 @@ pub struct MeshAdvAssetPart {
     pub vertex_position_buffer_size_in_bytes: u32,
     pub index_buffer_offset_in_bytes: u32,
     pub index_buffer_size_in_bytes: u32,
+    pub vertex_skin_buffer_offset_in_bytes: Option<u32>,
     pub index_type: RafxIndexType,
+    pub meshlets: Vec<MeshAdvMeshletAssetData>,
 }
 
 impl MeshAdvAssetPart {
+    pub fn vertex_count(&self) -> u32 {
+        self.vertex_position_buffer_size_in_bytes / std::mem::size_of::<MeshVertexPosition>() as u32
+    }
+
     fn get_material_pass_index(
         &self,
         view: &RenderView,
         render_phase_index: RenderPhaseIndex,
+        order_independent_transparency: bool,
+        write_gbuffer: bool,
     ) -> usize {
         let untextured = view.feature_flag_is_relevant::<MeshAdvUntexturedRenderFeatureFlag>();
         self.pass_indices.get_material_pass_index(
             self.mesh_material.material_data(),
             render_phase_index,
             untextured,
+            order_independent_transparency,
+            write_gbuffer,
         )
     }
 
@@ -265,9 +381,15 @@ impl MeshAdvAssetPart {
         &self,
         view: &RenderView,
         render_phase_index: RenderPhaseIndex,
+        order_independent_transparency: bool,
+        write_gbuffer: bool,
     ) -> &ResourceArc<MaterialPassResource> {
-        &self.mesh_material.material_asset().passes
-            [self.get_material_pass_index(view, render_phase_index)]
+        &self.mesh_material.material_asset().passes[self.get_material_pass_index(
+            view,
+            render_phase_index,
+            order_independent_transparency,
+            write_gbuffer,
+        )]
         .material_pass_resource
     }
 }
@@ -275,10 +397,13 @@ impl MeshAdvAssetPart {
 #[derive(Debug)]
 pub struct MeshAdvAssetInner {
     pub mesh_parts: Vec<MeshAdvAssetPart>,
-    pub asset_data: MeshAdvAssetData,
+    pub asset_data: Arc<MeshAdvAssetData>,
+    // LODs generated on import, most detailed first. These draw the same vertices as this mesh with
+    // fewer indices and have no generated LODs of their own.
+    pub generated_lods: Vec<MeshAdvAsset>,
 }
 
-#[derive(TypeUuid, Clone)]
+#[derive(TypeUuid, Clone, Debug)]
 #[uuid = "8a7afe47-8abc-4383-a7c8-0f09026b3019"]
 pub struct MeshAdvAsset {
     pub inner: Arc<MeshAdvAssetInner>,
@@ -305,6 +430,10 @@ impl DefaultAssetTypeLoadHandler<MeshAdvAssetData, MeshAdvAsset> for MeshAdvLoad
             .latest_asset(&mesh_asset.index_buffer)
             .unwrap()
             .buffer_byte_offset();
+        let vertex_skin_buffer_byte_offset = mesh_asset
+            .vertex_skin_buffer
+            .as_ref()
+            .map(|x| asset_manager.latest_asset(x).unwrap().buffer_byte_offset());
 
         let mesh_parts: Vec<_> = mesh_asset
             .mesh_parts
@@ -330,14 +459,57 @@ impl DefaultAssetTypeLoadHandler<MeshAdvAssetData, MeshAdvAsset> for MeshAdvLoad
                     index_buffer_offset_in_bytes: index_buffer_byte_offset
                         + mesh_part.index_buffer_offset_in_bytes,
                     index_buffer_size_in_bytes: mesh_part.index_buffer_size_in_bytes,
+                    vertex_skin_buffer_offset_in_bytes: vertex_skin_buffer_byte_offset
+                        .map(|x| x + mesh_part.vertex_skin_buffer_offset_in_bytes),
                     index_type: mesh_part.index_type,
+                    meshlets: mesh_part.meshlets.clone(),
+                }
+            })
+            .collect();
+
+        let asset_data = Arc::new(mesh_asset);
+
+        // Every part has the same number of generated LODs
+        let generated_lod_count = asset_data
+            .mesh_parts
+            .iter()
+            .map(|x| x.lods.len())
+            .min()
+            .unwrap_or(0);
+
+        let generated_lods = (0..generated_lod_count)
+            .map(|lod_index| {
+                let mesh_parts = mesh_parts
+                    .iter()
+                    .zip(&asset_data.mesh_parts)
+                    .map(|(mesh_part, mesh_part_data)| {
+                        let lod = &mesh_part_data.lods[lod_index];
+                        MeshAdvAssetPart {
+                            mesh_material: mesh_part.mesh_material.clone(),
+                            pass_indices: mesh_part.pass_indices.clone(),
+                            index_buffer_offset_in_bytes: index_buffer_byte_offset
+                                + lod.index_buffer_offset_in_bytes,
+                            index_buffer_size_in_bytes: lod.index_buffer_size_in_bytes,
+                            meshlets: lod.meshlets.clone(),
+                            ..*mesh_part
+                        }
+                    })
+                    .collect();
+
+                MeshAdvAsset {
+                    inner: Arc::new(MeshAdvAssetInner {
+                        mesh_parts,
+                        asset_data: asset_data.clone(),
+                        generated_lods: Vec::default(),
+                    }),
                 }
             })
             .collect();
 
         let inner = MeshAdvAssetInner {
-            asset_data: mesh_asset,
+            asset_data,
             mesh_parts,
+            generated_lods,
         };
 
         Ok(MeshAdvAsset {
@@ -57,6 +57,10 @@ impl MeshAdvBufferAsset {
 pub struct MeshAdvBindlessBuffers {
     pub vertex: ResourceArc<BufferResource>,
     pub index: ResourceArc<BufferResource>,
+    // Region at the end of the vertex heap that the skinning compute pass writes skinned vertices
+    // into. It is not used by the suballocator.
+    pub skinned_vertex_offset_in_bytes: u32,
+    pub skinned_vertex_size_in_bytes: u32,
 }
 
 pub struct MeshAdvBufferAssetTypeHandler {
@@ -72,6 +76,8 @@ pub struct MeshAdvBufferAssetTypeHandler {
 const VERTEX_BUFFER_SIZE: u32 = 512 * 1024 * 1024;
 const INDEX_BUFFER_SIZE: u32 = 128 * 1024 * 1024;
 const BUFFER_ALIGNMENT: u32 = 1024;
+// Reserved at the end of VERTEX_BUFFER_SIZE for skinned vertices
+const SKINNED_VERTEX_BUFFER_SIZE: u32 = 64 * 1024 * 1024;
 
 impl MeshAdvBufferAssetTypeHandler {
     pub fn create(
@@ -95,7 +101,9 @@ impl MeshAdvBufferAssetTypeHandler {
                 alignment: BUFFER_ALIGNMENT,
                 memory_usage: RafxMemoryUsage::GpuOnly,
                 queue_type: RafxQueueType::Transfer,
-                resource_type: RafxResourceType::VERTEX_BUFFER,
+                // Read/write so that skinned vertices can be written by a compute shader
+                resource_type: RafxResourceType::VERTEX_BUFFER
+                    | RafxResourceType::BUFFER_READ_WRITE,
                 ..Default::default()
             })?;
         vertex_data_heap.set_debug_name("MeshAdv Vertex Data Heap");
@@ -123,6 +131,8 @@ impl MeshAdvBufferAssetTypeHandler {
         let heaps = MeshAdvBindlessBuffers {
             index: index_data_heap,
             vertex: vertex_data_heap,
+            skinned_vertex_offset_in_bytes: VERTEX_BUFFER_SIZE - SKINNED_VERTEX_BUFFER_SIZE,
+            skinned_vertex_size_in_bytes: SKINNED_VERTEX_BUFFER_SIZE,
         };
 
         render_resources.insert(heaps.clone());
@@ -132,7 +142,9 @@ impl MeshAdvBufferAssetTypeHandler {
             load_queues,
             buffer_upload_queue,
             buffers: heaps,
-            vertex_buffer_suballocator: FreeListSuballocator::new(VERTEX_BUFFER_SIZE),
+            vertex_buffer_suballocator: FreeListSuballocator::new(
+                VERTEX_BUFFER_SIZE - SKINNED_VERTEX_BUFFER_SIZE,
+            ),
             index_buffer_suballocator: FreeListSuballocator::new(INDEX_BUFFER_SIZE),
             offset_lookup: FnvHashMap::default(),
         }))
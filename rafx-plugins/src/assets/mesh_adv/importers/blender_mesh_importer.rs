@@ -27,14 +27,29 @@ struct MeshPartJson {
     pub tangent: Option<u32>,
     #[serde(default)]
     pub uv: Vec<u32>,
+    // Skinned meshes have four u32 joint indices and four f32 weights per vertex
+    #[serde(default)]
+    pub joints: Option<u32>,
+    #[serde(default)]
+    pub weights: Option<u32>,
     pub indices: u32,
     pub index_type: MeshPartJsonIndexType,
     pub material: PathBuf,
 }
 
+#[derive(Serialize, Deserialize, Debug)]
+struct SkinJointJson {
+    // Name of the bone in the armature
+    pub name: String,
+    // Column-major
+    pub inverse_bind_matrix: [f32; 16],
+}
+
 #[derive(Serialize, Deserialize, Debug)]
 struct MeshJson {
     pub mesh_parts: Vec<MeshPartJson>,
+    #[serde(default)]
+    pub skin_joints: Vec<SkinJointJson>,
 }
 
 fn try_cast_u8_slice<T: Copy + 'static>(data: &[u8]) -> Option<&[T]> {
@@ -161,6 +176,24 @@ impl Importer for BlenderMeshImporter {
                 .set(Arc::new(tex_coords_bytes.to_vec()))?;
             entry.indices().set(Arc::new(part_indices))?;
             entry.material_index().set(material_index)?;
+
+            if let (Some(joints), Some(weights)) = (mesh_part.joints, mesh_part.weights) {
+                entry
+                    .joint_indices()
+                    .set(Arc::new(b3f_reader.get_block(joints as usize).to_vec()))?;
+                entry
+                    .joint_weights()
+                    .set(Arc::new(b3f_reader.get_block(weights as usize).to_vec()))?;
+            }
+        }
+
+        for skin_joint in &mesh_as_json.skin_joints {
+            let entry_uuid = import_data.skin_joints().add_entry()?;
+            let entry = import_data.skin_joints().entry(entry_uuid);
+            entry.name().set(skin_joint.name.clone())?;
+            entry.inverse_bind_matrix().set(Arc::new(
+                PushBuffer::from_vec(&skin_joint.inverse_bind_matrix.to_vec()).into_data(),
+            ))?;
         }
 
         //
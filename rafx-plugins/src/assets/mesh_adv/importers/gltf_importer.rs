@@ -1,5 +1,6 @@
 use crate::schema::{
-    MeshAdvMaterialAssetRecord, MeshAdvMeshAssetRecord, MeshAdvMeshImportedDataRecord,
+    MeshAdvBlendMethodEnum, MeshAdvMaterialAssetRecord, MeshAdvMeshAssetRecord,
+    MeshAdvMeshImportedDataRecord, MeshAdvShadowMethodEnum,
 };
 use fnv::FnvHashMap;
 use gltf::buffer::Data as GltfBufferData;
@@ -11,7 +12,7 @@ use hydrate_pipeline::{
 };
 use rafx::assets::schema::{GpuImageAssetRecord, GpuImageImportedDataRecord};
 use rafx::assets::PushBuffer;
-use rafx::assets::{GpuImageImporterSimple, ImageImporterOptions};
+use rafx::assets::{GpuImageImporterSimple, ImageAssetLayout, ImageImporterOptions};
 use rafx::assets::{ImageAssetColorSpaceConfig, ImageAssetData};
 use std::sync::Arc;
 use type_uuid::*;
@@ -147,6 +148,7 @@ fn hydrate_import_image(
         mip_generation,
         color_space,
         data_format,
+        layout: ImageAssetLayout::Image2D,
     };
 
     //
@@ -238,16 +240,35 @@ fn hydrate_import_material(
         default_asset.occlusion_texture().set(texture_object_id)?;
     }
 
-    //x.shadow_method()
+    // Masked materials cast alpha-tested shadows. Blended materials can't be partially opaque in
+    // the shadow map so they cast stochastic shadows that average out to the right density when
+    // the shadow map is filtered.
+    let (blend_method, shadow_method) = match material.alpha_mode() {
+        gltf::material::AlphaMode::Opaque => (
+            MeshAdvBlendMethodEnum::Opaque,
+            MeshAdvShadowMethodEnum::Opaque,
+        ),
+        gltf::material::AlphaMode::Mask => (
+            MeshAdvBlendMethodEnum::AlphaClip,
+            MeshAdvShadowMethodEnum::AlphaClip,
+        ),
+        gltf::material::AlphaMode::Blend => (
+            MeshAdvBlendMethodEnum::AlphaBlend,
+            MeshAdvShadowMethodEnum::AlphaStochastic,
+        ),
+    };
 
-    //x.shadow_method().set(&mut default_asset_data_container, shadow_method)?;
-    //x.blend_method().set(&mut default_asset_data_container, blend_method)?;
+    default_asset.shadow_method().set(shadow_method)?;
+    default_asset.blend_method().set(blend_method)?;
     default_asset
         .alpha_threshold()
         .set(material.alpha_cutoff().unwrap_or(0.5))?;
     default_asset.backface_culling().set(false)?;
     //TODO: Does this incorrectly write older enum string names when code is older than schema file?
-    default_asset.color_texture_has_alpha_channel().set(false)?;
+    // glTF only uses the alpha channel of the base color texture when the material is not opaque
+    default_asset
+        .color_texture_has_alpha_channel()
+        .set(material.alpha_mode() != gltf::material::AlphaMode::Opaque)?;
 
     //
     // Return the created objects
@@ -261,6 +282,7 @@ fn hydrate_import_mesh(
     asset_name: &ImportableName,
     buffers: &[GltfBufferData],
     mesh: &gltf::Mesh,
+    skin: Option<gltf::Skin>,
     material_index_to_asset_id: &HashMap<Option<usize>, AssetId>,
 ) -> PipelineResult<()> {
     //
@@ -298,6 +320,36 @@ fn hydrate_import_mesh(
     //
     let import_data = MeshAdvMeshImportedDataRecord::new_builder(context.schema_set);
 
+    //
+    // Joints are identified by node name so that they can be matched to the bones of an animation
+    //
+    if let Some(skin) = &skin {
+        let reader = skin.reader(|buffer| buffers.get(buffer.index()).map(|x| &**x));
+        let inverse_bind_matrices: Vec<[[f32; 4]; 4]> = reader
+            .read_inverse_bind_matrices()
+            .map(|x| x.collect())
+            .unwrap_or_default();
+
+        for (i, joint) in skin.joints().enumerate() {
+            // Missing inverse bind matrices are defined to be identity
+            let inverse_bind_matrix = inverse_bind_matrices
+                .get(i)
+                .map(glam::Mat4::from_cols_array_2d)
+                .unwrap_or(glam::Mat4::IDENTITY);
+            let joint_name = joint
+                .name()
+                .map(|x| x.to_string())
+                .unwrap_or_else(|| format!("joint_{}", joint.index()));
+
+            let entry_uuid = import_data.skin_joints().add_entry()?;
+            let entry = import_data.skin_joints().entry(entry_uuid);
+            entry.name().set(joint_name)?;
+            entry.inverse_bind_matrix().set(Arc::new(
+                PushBuffer::from_vec(&inverse_bind_matrix.to_cols_array().to_vec()).into_data(),
+            ))?;
+        }
+    }
+
     //
     // Iterate all mesh parts, building a single vertex and index buffer. Each MeshPart will
     // hold offsets/lengths to their sections in the vertex/index buffers
@@ -346,6 +398,24 @@ fn hydrate_import_mesh(
                 .set(Arc::new(tex_coords_bytes.to_vec()))?;
             entry.indices().set(Arc::new(part_indices_bytes))?;
             entry.material_index().set(material_index)?;
+
+            if skin.is_some() {
+                if let (Some(joints), Some(weights)) =
+                    (reader.read_joints(0), reader.read_weights(0))
+                {
+                    let joints: Vec<[u32; 4]> = joints
+                        .into_u16()
+                        .map(|x| x.map(|joint| joint as u32))
+                        .collect();
+                    let weights: Vec<_> = weights.into_f32().collect();
+                    entry
+                        .joint_indices()
+                        .set(Arc::new(PushBuffer::from_vec(&joints).into_data()))?;
+                    entry
+                        .joint_weights()
+                        .set(Arc::new(PushBuffer::from_vec(&weights).into_data()))?;
+                }
+            }
         } else {
             log::error!(
                 "Mesh primitives must specify indices, positions, normals, tangents, and tex_coords"
@@ -476,11 +546,19 @@ impl Importer for GltfImporter {
         for (i, mesh) in doc.meshes().enumerate() {
             let asset_name = name_or_index("mesh", mesh.name(), i);
             if context.should_import(&asset_name) {
+                // The skin is referenced by the node that instantiates the mesh. If several nodes
+                // instantiate the mesh with different skins, the first one is used.
+                let skin = doc
+                    .nodes()
+                    .filter(|node| node.mesh().map(|x| x.index()) == Some(mesh.index()))
+                    .find_map(|node| node.skin());
+
                 hydrate_import_mesh(
                     &context,
                     &asset_name,
                     &buffers,
                     &mesh,
+                    skin,
                     &material_index_to_object_id,
                 )?;
             }
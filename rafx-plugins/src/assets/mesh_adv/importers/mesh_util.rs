@@ -1,8 +1,19 @@
-use crate::features::mesh_adv::{MeshVertexFull, MeshVertexPosition};
+use crate::assets::mesh_adv::{MeshAdvMeshletAssetData, MeshAdvPartLodAssetData};
+use crate::features::mesh_adv::{MeshVertexFull, MeshVertexPosition, MeshVertexSkin};
 use glam::{Vec2, Vec3};
 use rafx::api::RafxIndexType;
 use rafx::assets::PushBuffer;
 
+// How much vertex cache efficiency the overdraw optimization may give up (1.05 = up to 5%)
+#[cfg(feature = "meshopt")]
+const OVERDRAW_VERTEX_CACHE_THRESHOLD: f32 = 1.05;
+
+// Meshlet size limits, the meshopt meshlet builder supports up to 64 vertices and 126 triangles
+#[cfg(feature = "meshopt")]
+const MESHLET_MAX_VERTICES: usize = 64;
+#[cfg(feature = "meshopt")]
+const MESHLET_MAX_TRIANGLES: usize = 124;
+
 // Calculates a tangent and binormal that are orthogonal to the polygon normal and align with the
 // uv coordinate x and y axes respectively. May return zero vector if uv coordinates are the same
 pub(super) fn calculate_tangent_binormal(
@@ -77,17 +88,45 @@ pub(crate) struct MeshPartData {
     pub vertex_position_buffer_size_in_bytes: u32,
     pub index_buffer_offset_in_bytes: u32,
     pub index_buffer_size_in_bytes: u32,
+    pub vertex_skin_buffer_offset_in_bytes: u32,
+    pub vertex_skin_buffer_size_in_bytes: u32,
     pub index_type: RafxIndexType,
+    pub meshlets: Vec<MeshAdvMeshletAssetData>,
+    pub lods: Vec<MeshAdvPartLodAssetData>,
+}
+
+// Optional processing of mesh parts, configured by the import settings of the mesh asset. LOD and
+// meshlet generation and overdraw optimization require the meshopt feature.
+pub(crate) struct MeshProcessSettings {
+    pub generated_lod_count: u32,
+    // Fraction of the triangles of the previous LOD kept by each generated LOD
+    #[cfg(feature = "meshopt")]
+    pub generated_lod_triangle_ratio: f32,
+    // Maximum simplification error, relative to the size of the mesh part
+    #[cfg(feature = "meshopt")]
+    pub generated_lod_target_error: f32,
+    pub optimize_overdraw: bool,
+    pub generate_meshlets: bool,
+}
+
+// Per-vertex joint indices and weights of a skinned mesh part
+pub(crate) struct MeshPartSkin<'a> {
+    pub joint_indices: &'a [[u32; 4]],
+    pub joint_weights: &'a [[f32; 4]],
 }
 
 // Appends index/vertex data to buffers and returns metadata about the processed mesh part
+#[allow(clippy::too_many_arguments)]
 pub(crate) fn process_mesh_part(
     part_indices: &[u32],
     positions: &[[f32; 3]],
     normals: &[[f32; 3]],
     tex_coords: &[[f32; 2]],
+    skin: Option<MeshPartSkin>,
+    settings: &MeshProcessSettings,
     all_vertices_full: &mut PushBuffer,
     all_vertices_position: &mut PushBuffer,
+    all_vertices_skin: &mut PushBuffer,
     all_indices: &mut PushBuffer,
 ) -> MeshPartData {
     //
@@ -128,6 +167,7 @@ pub(crate) fn process_mesh_part(
     //
     let mut part_vertices_full = Vec::with_capacity(positions.len());
     let mut part_vertices_position = Vec::with_capacity(positions.len());
+    let mut part_vertices_skin = Vec::default();
     for i in 0..positions.len() {
         let (t, b) = fix_tangent_binormal(glam::Vec3::from(normals[i]), tangents[i], binormals[i]);
 
@@ -141,31 +181,65 @@ pub(crate) fn process_mesh_part(
         part_vertices_position.push(MeshVertexPosition {
             position: positions[i],
         });
+        if let Some(skin) = &skin {
+            // Renormalize the weights, they don't always sum to exactly 1.0
+            let weights = glam::Vec4::from(skin.joint_weights[i]);
+            let weight_sum = weights.dot(glam::Vec4::ONE);
+            let weights = if weight_sum > 0.0 {
+                weights / weight_sum
+            } else {
+                glam::Vec4::X
+            };
+
+            part_vertices_skin.push(MeshVertexSkin {
+                joint_indices: skin.joint_indices[i],
+                joint_weights: weights.into(),
+            });
+        }
     }
 
     //
-    // Optimize vertex/index buffers
+    // Optimize vertex/index buffers and generate LODs
     //
     #[cfg(feature = "meshopt")]
-    let (part_indices_data, part_vertices_full, part_vertices_position) = {
+    let (
+        part_indices_data,
+        lod_indices,
+        part_vertices_full,
+        part_vertices_position,
+        part_vertices_skin,
+    ) = {
         //WARNING: meshopt functions mutate values, even if they only take non-mut borrows. This is
         // technically unsound, so we need to be careful here. (And in theory it could become UB if
         // the compiler assumes data won't change)
         meshopt::optimize_vertex_cache_in_place(&part_indices, part_vertices_full.len());
 
-        // This step is intentionally disabled, depth prepass should avoid overdraws
-        //let threshold = 1.05f32;
-        //let vertex_data_slice = part_vertices_full.as_slice();
-        //let vertex_adapter = meshopt::VertexDataAdapter::new(
-        //    rafx::base::memory::any_slice_as_bytes(vertex_data_slice),
-        //    std::mem::size_of::<MeshVertexFull>(),
-        //    rafx::base::offset_of!(MeshVertexFull, position) as usize
-        //).unwrap();
-        //meshopt::optimize_overdraw_in_place(&part_indices, &vertex_adapter, threshold);
+        let vertex_adapter = meshopt::VertexDataAdapter::new(
+            rafx::base::memory::any_slice_as_bytes(part_vertices_position.as_slice()),
+            std::mem::size_of::<MeshVertexPosition>(),
+            0,
+        )
+        .unwrap();
+
+        // Off by default, depth prepass should avoid most overdraw
+        if settings.optimize_overdraw {
+            meshopt::optimize_overdraw_in_place(
+                part_indices,
+                &vertex_adapter,
+                OVERDRAW_VERTEX_CACHE_THRESHOLD,
+            );
+        }
+
+        let lod_indices = generate_lod_indices(part_indices, &vertex_adapter, settings);
 
+        // LODs only reference vertices of the full detail part, so they can share its remap
         let remap = meshopt::optimize_vertex_fetch_remap(&part_indices, part_vertices_full.len());
         let part_indices =
             meshopt::remap_index_buffer(Some(&part_indices), part_indices.len(), &remap);
+        let lod_indices: Vec<_> = lod_indices
+            .iter()
+            .map(|indices| meshopt::remap_index_buffer(Some(indices), indices.len(), &remap))
+            .collect();
         let part_vertices_full =
             meshopt::remap_vertex_buffer(&part_vertices_full, part_vertices_full.len(), &remap);
         let part_vertices_position = meshopt::remap_vertex_buffer(
@@ -173,12 +247,58 @@ pub(crate) fn process_mesh_part(
             part_vertices_position.len(),
             &remap,
         );
+        let part_vertices_skin = if !part_vertices_skin.is_empty() {
+            meshopt::remap_vertex_buffer(&part_vertices_skin, part_vertices_skin.len(), &remap)
+        } else {
+            part_vertices_skin
+        };
 
-        (part_indices, part_vertices_full, part_vertices_position)
+        (
+            part_indices,
+            lod_indices,
+            part_vertices_full,
+            part_vertices_position,
+            part_vertices_skin,
+        )
+    };
+
+    //
+    // Reorder the indices into meshlets
+    //
+    #[cfg(feature = "meshopt")]
+    let (part_indices_data, part_meshlets, lods) = {
+        let vertex_adapter = meshopt::VertexDataAdapter::new(
+            rafx::base::memory::any_slice_as_bytes(part_vertices_position.as_slice()),
+            std::mem::size_of::<MeshVertexPosition>(),
+            0,
+        )
+        .unwrap();
+
+        let build = |indices: Vec<u32>| {
+            if settings.generate_meshlets {
+                build_meshlets(&indices, &vertex_adapter)
+            } else {
+                (indices, Vec::default())
+            }
+        };
+
+        let (part_indices, part_meshlets) = build(part_indices_data);
+        let lods: Vec<_> = lod_indices.into_iter().map(build).collect();
+        (part_indices, part_meshlets, lods)
     };
     #[cfg(feature = "meshopt")]
     let part_indices = &part_indices_data;
 
+    // Without meshopt the settings are ignored, the job warns about this
+    #[cfg(not(feature = "meshopt"))]
+    let (part_meshlets, lods) = {
+        let _ = settings;
+        (
+            Vec::<MeshAdvMeshletAssetData>::default(),
+            Vec::<(Vec<u32>, Vec<MeshAdvMeshletAssetData>)>::default(),
+        )
+    };
+
     //
     // Push the optimized vertex info into the combined buffer for the mesh
     //
@@ -190,6 +310,12 @@ pub(crate) fn process_mesh_part(
     all_vertices_position.push(&part_vertices_position, 1);
     let vertex_position_size = all_vertices_position.len() - vertex_position_offset;
 
+    let vertex_skin_offset = all_vertices_skin.len();
+    if !part_vertices_skin.is_empty() {
+        all_vertices_skin.push(&part_vertices_skin, 1);
+    }
+    let vertex_skin_size = all_vertices_skin.len() - vertex_skin_offset;
+
     //
     // Do we need to use u32 index buffers?
     //
@@ -201,28 +327,377 @@ pub(crate) fn process_mesh_part(
     };
 
     //
-    // Push the optimized index info into the combined buffer for the mesh
+    // Push the optimized index info into the combined buffer for the mesh, LODs after the full
+    // detail part
     //
+    let (indices_offset, indices_size) = push_indices(part_indices, index_type, all_indices);
+
+    let lods = lods
+        .into_iter()
+        .map(|(lod_indices, meshlets)| {
+            let (lod_indices_offset, lod_indices_size) =
+                push_indices(&lod_indices, index_type, all_indices);
+            MeshAdvPartLodAssetData {
+                index_buffer_offset_in_bytes: lod_indices_offset as u32,
+                index_buffer_size_in_bytes: lod_indices_size as u32,
+                meshlets,
+            }
+        })
+        .collect();
+
+    MeshPartData {
+        vertex_full_buffer_offset_in_bytes: vertex_full_offset as u32,
+        vertex_full_buffer_size_in_bytes: vertex_full_size as u32,
+        vertex_position_buffer_offset_in_bytes: vertex_position_offset as u32,
+        vertex_position_buffer_size_in_bytes: vertex_position_size as u32,
+        index_buffer_offset_in_bytes: indices_offset as u32,
+        index_buffer_size_in_bytes: indices_size as u32,
+        vertex_skin_buffer_offset_in_bytes: vertex_skin_offset as u32,
+        vertex_skin_buffer_size_in_bytes: vertex_skin_size as u32,
+        index_type,
+        meshlets: part_meshlets,
+        lods,
+    }
+}
+
+// Simplifies the part into `settings.generated_lod_count` index buffers, most detailed first. Each
+// LOD has fewer indices than the previous one, unless the error limit stops the simplification, in
+// which case the previous LOD is repeated.
+#[cfg(feature = "meshopt")]
+fn generate_lod_indices(
+    part_indices: &[u32],
+    vertices: &meshopt::VertexDataAdapter,
+    settings: &MeshProcessSettings,
+) -> Vec<Vec<u32>> {
+    // Each LOD is simplified from the full detail part so that errors don't accumulate
+    let mut lod_indices: Vec<Vec<u32>> = Vec::with_capacity(settings.generated_lod_count as usize);
+    let mut target_index_count = part_indices.len();
+    for _ in 0..settings.generated_lod_count {
+        target_index_count =
+            (target_index_count as f32 * settings.generated_lod_triangle_ratio) as usize / 3 * 3;
+        let mut indices = meshopt::simplify(
+            part_indices,
+            vertices,
+            target_index_count,
+            settings.generated_lod_target_error,
+        );
+
+        // The error limit can stop the simplification early. Repeat the previous LOD in that
+        // case so that every part of the mesh has the same number of LODs.
+        if let Some(previous_indices) = lod_indices.last() {
+            if indices.len() >= previous_indices.len() {
+                indices = previous_indices.clone();
+            }
+        }
+
+        meshopt::optimize_vertex_cache_in_place(&indices, vertices.vertex_count);
+        if settings.optimize_overdraw {
+            meshopt::optimize_overdraw_in_place(
+                &indices,
+                vertices,
+                OVERDRAW_VERTEX_CACHE_THRESHOLD,
+            );
+        }
+
+        lod_indices.push(indices);
+    }
+
+    lod_indices
+}
+
+// Appends indices to the buffer and returns their offset and size in bytes
+fn push_indices(
+    indices: &[u32],
+    index_type: RafxIndexType,
+    all_indices: &mut PushBuffer,
+) -> (usize, usize) {
     let indices_offset = all_indices.len();
     match index_type {
         RafxIndexType::Uint32 => {
-            all_indices.push(&part_indices, 1);
+            all_indices.push(indices, 1);
         }
         RafxIndexType::Uint16 => {
-            for &index in part_indices {
+            for &index in indices {
                 all_indices.push(&[index as u16], 1);
             }
         }
     }
     let indices_size = all_indices.len() - indices_offset;
+    (indices_offset, indices_size)
+}
 
-    MeshPartData {
-        vertex_full_buffer_offset_in_bytes: vertex_full_offset as u32,
-        vertex_full_buffer_size_in_bytes: vertex_full_size as u32,
-        vertex_position_buffer_offset_in_bytes: vertex_position_offset as u32,
-        vertex_position_buffer_size_in_bytes: vertex_position_size as u32,
-        index_buffer_offset_in_bytes: indices_offset as u32,
-        index_buffer_size_in_bytes: indices_size as u32,
-        index_type,
+// Splits the triangles into meshlets. Returns the indices reordered so that the triangles of each
+// meshlet are contiguous, and the range and bounds of each meshlet.
+#[cfg(feature = "meshopt")]
+fn build_meshlets(
+    indices: &[u32],
+    vertices: &meshopt::VertexDataAdapter,
+) -> (Vec<u32>, Vec<MeshAdvMeshletAssetData>) {
+    let meshlets = meshopt::build_meshlets(
+        indices,
+        vertices.vertex_count,
+        MESHLET_MAX_VERTICES,
+        MESHLET_MAX_TRIANGLES,
+    );
+
+    let mut meshlet_indices = Vec::with_capacity(indices.len());
+    let mut meshlet_data = Vec::with_capacity(meshlets.len());
+    for meshlet in &meshlets {
+        let index_offset = meshlet_indices.len() as u32;
+        for triangle in &meshlet.indices[0..meshlet.triangle_count as usize] {
+            for &meshlet_vertex_index in triangle {
+                meshlet_indices.push(meshlet.vertices[meshlet_vertex_index as usize]);
+            }
+        }
+
+        let bounds = meshopt::compute_meshlet_bounds(meshlet, vertices);
+        meshlet_data.push(MeshAdvMeshletAssetData {
+            index_offset,
+            index_count: meshlet.triangle_count as u32 * 3,
+            bounding_sphere_center: bounds.center,
+            bounding_sphere_radius: bounds.radius,
+            cone_apex: bounds.cone_apex,
+            cone_axis: bounds.cone_axis,
+            cone_cutoff: bounds.cone_cutoff,
+        });
+    }
+
+    (meshlet_indices, meshlet_data)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // A bumpy grid of `size` x `size` quads
+    #[cfg(feature = "meshopt")]
+    fn grid(size: u32) -> (Vec<[f32; 3]>, Vec<u32>) {
+        let mut positions = Vec::default();
+        for y in 0..=size {
+            for x in 0..=size {
+                let height = ((x as f32 * 0.7).sin() + (y as f32 * 0.4).cos()) * 0.5;
+                positions.push([x as f32, y as f32, height]);
+            }
+        }
+
+        let mut indices = Vec::default();
+        for y in 0..size {
+            for x in 0..size {
+                let i0 = y * (size + 1) + x;
+                let i1 = i0 + 1;
+                let i2 = i0 + size + 1;
+                let i3 = i2 + 1;
+                indices.extend_from_slice(&[i0, i1, i2, i2, i1, i3]);
+            }
+        }
+
+        (positions, indices)
+    }
+
+    // Triangles rotated so that the smallest index is first, keeping the winding, and sorted
+    #[cfg(feature = "meshopt")]
+    fn sorted_triangles(indices: &[u32]) -> Vec<[u32; 3]> {
+        let mut triangles: Vec<_> = indices
+            .chunks_exact(3)
+            .map(|t| {
+                let min = (0..3).min_by_key(|&i| t[i]).unwrap();
+                [t[min], t[(min + 1) % 3], t[(min + 2) % 3]]
+            })
+            .collect();
+        triangles.sort();
+        triangles
+    }
+
+    #[test]
+    fn push_indices_uint16() {
+        let mut all_indices = PushBuffer::new(0);
+        all_indices.push(&[0xFFu8; 3], 1);
+
+        let (offset, size) = push_indices(&[1, 2, 65534], RafxIndexType::Uint16, &mut all_indices);
+        assert_eq!(offset, 3);
+        assert_eq!(size, 3 * std::mem::size_of::<u16>());
+
+        let data = all_indices.into_data();
+        assert_eq!(data.len(), offset + size);
+        let indices: Vec<_> = data[offset..]
+            .chunks_exact(2)
+            .map(|x| u16::from_ne_bytes([x[0], x[1]]))
+            .collect();
+        assert_eq!(indices, vec![1, 2, 65534]);
+    }
+
+    #[test]
+    fn push_indices_uint32() {
+        let mut all_indices = PushBuffer::new(0);
+        let (offset, size) = push_indices(&[1, 2, 3], RafxIndexType::Uint32, &mut all_indices);
+        let (offset2, size2) =
+            push_indices(&[70000, 5, 6], RafxIndexType::Uint32, &mut all_indices);
+        assert_eq!(offset, 0);
+        assert_eq!(size, 3 * std::mem::size_of::<u32>());
+        assert_eq!(offset2, offset + size);
+        assert_eq!(size2, 3 * std::mem::size_of::<u32>());
+
+        let data = all_indices.into_data();
+        let indices: Vec<_> = data[offset2..offset2 + size2]
+            .chunks_exact(4)
+            .map(|x| u32::from_ne_bytes([x[0], x[1], x[2], x[3]]))
+            .collect();
+        assert_eq!(indices, vec![70000, 5, 6]);
+    }
+
+    #[cfg(feature = "meshopt")]
+    fn vertex_adapter(positions: &[[f32; 3]]) -> meshopt::VertexDataAdapter<'_> {
+        meshopt::VertexDataAdapter::new(
+            rafx::base::memory::any_slice_as_bytes(positions),
+            std::mem::size_of::<[f32; 3]>(),
+            0,
+        )
+        .unwrap()
+    }
+
+    #[cfg(feature = "meshopt")]
+    fn lod_settings(
+        generated_lod_count: u32,
+        generated_lod_target_error: f32,
+    ) -> MeshProcessSettings {
+        MeshProcessSettings {
+            generated_lod_count,
+            generated_lod_triangle_ratio: 0.5,
+            generated_lod_target_error,
+            optimize_overdraw: false,
+            generate_meshlets: true,
+        }
+    }
+
+    #[cfg(feature = "meshopt")]
+    #[test]
+    fn build_meshlets_respects_limits_and_keeps_triangles() {
+        let (positions, indices) = grid(32);
+        let vertices = vertex_adapter(&positions);
+
+        let (meshlet_indices, meshlets) = build_meshlets(&indices, &vertices);
+        assert!(meshlets.len() > 1);
+        assert_eq!(
+            sorted_triangles(&meshlet_indices),
+            sorted_triangles(&indices)
+        );
+
+        // Meshlets cover the reordered indices contiguously and stay within the size limits
+        let mut index_offset = 0;
+        for meshlet in &meshlets {
+            assert_eq!(meshlet.index_offset, index_offset);
+            assert_eq!(meshlet.index_count % 3, 0);
+            assert!(meshlet.index_count > 0);
+            assert!(meshlet.index_count as usize <= MESHLET_MAX_TRIANGLES * 3);
+
+            let range = meshlet.index_offset as usize
+                ..(meshlet.index_offset + meshlet.index_count) as usize;
+            let mut meshlet_vertices = meshlet_indices[range].to_vec();
+            meshlet_vertices.sort_unstable();
+            meshlet_vertices.dedup();
+            assert!(meshlet_vertices.len() <= MESHLET_MAX_VERTICES);
+
+            // The bounding sphere contains the meshlet's vertices
+            let center = glam::Vec3::from(meshlet.bounding_sphere_center);
+            for vertex in meshlet_vertices {
+                let distance = center.distance(glam::Vec3::from(positions[vertex as usize]));
+                assert!(distance <= meshlet.bounding_sphere_radius * 1.001);
+            }
+
+            index_offset += meshlet.index_count;
+        }
+        assert_eq!(index_offset as usize, meshlet_indices.len());
+    }
+
+    #[cfg(feature = "meshopt")]
+    #[test]
+    fn generated_lods_shrink_and_stay_in_bounds() {
+        let (positions, indices) = grid(32);
+        let vertices = vertex_adapter(&positions);
+
+        let lods = generate_lod_indices(&indices, &vertices, &lod_settings(4, 1.0));
+        assert_eq!(lods.len(), 4);
+
+        let mut previous_len = indices.len();
+        for (lod_index, lod) in lods.iter().enumerate() {
+            assert_eq!(lod.len() % 3, 0);
+            assert!(!lod.is_empty());
+            assert!(lod.iter().all(|&x| (x as usize) < positions.len()));
+            if lod_index == 0 {
+                assert!(lod.len() < previous_len);
+            } else {
+                assert!(lod.len() <= previous_len);
+            }
+            previous_len = lod.len();
+        }
+    }
+
+    #[cfg(feature = "meshopt")]
+    #[test]
+    fn generated_lods_repeat_when_error_limit_is_reached() {
+        let (positions, indices) = grid(8);
+        let vertices = vertex_adapter(&positions);
+
+        // A zero error limit stops the simplification of a bumpy grid almost immediately, the loop
+        // still produces the requested number of LODs without growing
+        let lods = generate_lod_indices(&indices, &vertices, &lod_settings(6, 0.0));
+        assert_eq!(lods.len(), 6);
+        assert!(lods[0].len() <= indices.len());
+        for pair in lods.windows(2) {
+            assert!(pair[1].len() <= pair[0].len());
+        }
+        assert_eq!(lods[5].len(), lods[4].len());
+
+        assert!(generate_lod_indices(&indices, &vertices, &lod_settings(0, 1.0)).is_empty());
+    }
+
+    #[cfg(feature = "meshopt")]
+    #[test]
+    fn process_mesh_part_writes_lods_after_the_part() {
+        let (positions, indices) = grid(16);
+        let normals = vec![[0.0, 0.0, 1.0]; positions.len()];
+        let tex_coords: Vec<_> = positions.iter().map(|p| [p[0], p[1]]).collect();
+
+        let mut all_vertices_full = PushBuffer::new(0);
+        let mut all_vertices_position = PushBuffer::new(0);
+        let mut all_vertices_skin = PushBuffer::new(0);
+        let mut all_indices = PushBuffer::new(0);
+        let part = process_mesh_part(
+            &indices,
+            &positions,
+            &normals,
+            &tex_coords,
+            None,
+            &lod_settings(2, 1.0),
+            &mut all_vertices_full,
+            &mut all_vertices_position,
+            &mut all_vertices_skin,
+            &mut all_indices,
+        );
+
+        assert_eq!(part.index_type, RafxIndexType::Uint16);
+        assert_eq!(part.index_buffer_offset_in_bytes, 0);
+        assert_eq!(
+            part.index_buffer_size_in_bytes as usize,
+            indices.len() * std::mem::size_of::<u16>()
+        );
+        assert_eq!(part.vertex_skin_buffer_size_in_bytes, 0);
+        assert!(!part.meshlets.is_empty());
+
+        assert_eq!(part.lods.len(), 2);
+        let mut offset = part.index_buffer_size_in_bytes;
+        for lod in &part.lods {
+            assert_eq!(lod.index_buffer_offset_in_bytes, offset);
+            assert!(lod.index_buffer_size_in_bytes < part.index_buffer_size_in_bytes);
+            assert!(!lod.meshlets.is_empty());
+
+            let lod_index_count: u32 = lod.meshlets.iter().map(|x| x.index_count).sum();
+            assert_eq!(
+                lod_index_count as usize * std::mem::size_of::<u16>(),
+                lod.index_buffer_size_in_bytes as usize
+            );
+            offset += lod.index_buffer_size_in_bytes;
+        }
+        assert_eq!(offset as usize, all_indices.len());
     }
 }
@@ -292,52 +292,60 @@ impl MaterialDB {
         self.image_view_lookup.clear();
     }
 
+    // Uploads the material data and returns a descriptor set with all data/textures for each of the
+    // given layouts. All layouts must have the materials and textures at the same bindings as
+    // mesh_adv_textured_frag.
     pub fn update_gpu_resources(
         &self,
         resource_context: &ResourceContext,
-        bindless_materials_layout: &ResourceArc<DescriptorSetLayoutResource>,
+        bindless_materials_layouts: &[&ResourceArc<DescriptorSetLayoutResource>],
         invalid_image: &ResourceArc<ImageViewResource>,
-    ) -> RafxResult<DescriptorSetArc> {
+    ) -> RafxResult<Vec<DescriptorSetArc>> {
         let all_materials = self.create_all_materials_buffer(resource_context)?;
 
-        // Create array of textures
         let mut descriptor_set_allocator = resource_context.create_descriptor_set_allocator();
-        let mut descriptor_set = descriptor_set_allocator
-            .create_dyn_descriptor_set_uninitialized(bindless_materials_layout)?;
-
-        descriptor_set.set_buffer(
-            mesh_adv_textured_frag::ALL_MATERIALS_DESCRIPTOR_BINDING_INDEX as u32,
-            &all_materials,
-        );
-
-        // For now this is necessary because 1) vulkan wants everything bound, unless opting into
-        // certain features 2) there is nothing clearing old bindings from previous frames. We can
-        // end up with textures from previous frames that were dropped being bound. (Even if we
-        // don't try to index them, binding the descriptor set with stale resources can cause UB)
-        for i in 0..768 {
-            descriptor_set.set_image_at_index(
-                mesh_adv_textured_frag::ALL_MATERIAL_TEXTURES_DESCRIPTOR_BINDING_INDEX as u32,
-                i,
-                invalid_image,
+        let mut descriptor_sets = Vec::with_capacity(bindless_materials_layouts.len());
+        for bindless_materials_layout in bindless_materials_layouts {
+            // Create array of textures
+            let mut descriptor_set = descriptor_set_allocator
+                .create_dyn_descriptor_set_uninitialized(bindless_materials_layout)?;
+
+            descriptor_set.set_buffer(
+                mesh_adv_textured_frag::ALL_MATERIALS_DESCRIPTOR_BINDING_INDEX as u32,
+                &all_materials,
             );
-        }
 
-        //println!("set image");
-        for (key, image_view) in self.image_views.iter() {
-            descriptor_set.set_image_at_index(
-                mesh_adv_textured_frag::ALL_MATERIAL_TEXTURES_DESCRIPTOR_BINDING_INDEX as u32,
-                key.index() as usize,
-                &image_view.image,
-            );
+            // For now this is necessary because 1) vulkan wants everything bound, unless opting into
+            // certain features 2) there is nothing clearing old bindings from previous frames. We can
+            // end up with textures from previous frames that were dropped being bound. (Even if we
+            // don't try to index them, binding the descriptor set with stale resources can cause UB)
+            for i in 0..768 {
+                descriptor_set.set_image_at_index(
+                    mesh_adv_textured_frag::ALL_MATERIAL_TEXTURES_DESCRIPTOR_BINDING_INDEX as u32,
+                    i,
+                    invalid_image,
+                );
+            }
+
+            //println!("set image");
+            for (key, image_view) in self.image_views.iter() {
+                descriptor_set.set_image_at_index(
+                    mesh_adv_textured_frag::ALL_MATERIAL_TEXTURES_DESCRIPTOR_BINDING_INDEX as u32,
+                    key.index() as usize,
+                    &image_view.image,
+                );
+            }
+            //println!("finished set image");
+
+            descriptor_set.flush(&mut descriptor_set_allocator)?;
+            descriptor_sets.push(descriptor_set.descriptor_set().clone());
         }
-        //println!("finished set image");
 
-        descriptor_set.flush(&mut descriptor_set_allocator)?;
         descriptor_set_allocator.flush_changes()?;
 
         // material data binding set?
 
-        Ok(descriptor_set.descriptor_set().clone())
+        Ok(descriptor_sets)
     }
 }
 
@@ -2,7 +2,7 @@ pub use super::*;
 use glam::Vec3;
 use rafx::api::RafxResourceType;
 
-use crate::features::mesh_adv::{MeshVertexFull, MeshVertexPosition};
+use crate::features::mesh_adv::{MeshVertexFull, MeshVertexPosition, MeshVertexSkin};
 use crate::schema::*;
 use hydrate_pipeline::{
     AssetId, AssetPluginSetupContext, BuilderContext, JobInput, JobOutput, JobProcessor,
@@ -180,6 +180,12 @@ fn try_cast_u8_slice<T: Copy + 'static>(data: &[u8]) -> Option<&[T]> {
     Some(casted)
 }
 
+// Used when the mesh asset does not set these import settings
+#[cfg(feature = "meshopt")]
+const DEFAULT_GENERATED_LOD_TRIANGLE_RATIO: f32 = 0.5;
+#[cfg(feature = "meshopt")]
+const DEFAULT_GENERATED_LOD_TARGET_ERROR: f32 = 0.05;
+
 #[derive(Hash, Serialize, Deserialize)]
 pub struct MeshAdvMeshPreprocessJobInput {
     pub asset_id: AssetId,
@@ -199,7 +205,7 @@ impl JobProcessor for MeshAdvMeshJobProcessor {
     type OutputT = MeshAdvMeshPreprocessJobOutput;
 
     fn version(&self) -> u32 {
-        1
+        4
     }
 
     fn run<'a>(
@@ -216,6 +222,42 @@ impl JobProcessor for MeshAdvMeshJobProcessor {
             materials.push(entry);
         }
 
+        // Zero ratio/error mean the settings were never set, use defaults
+        #[cfg(feature = "meshopt")]
+        let generated_lod_triangle_ratio = asset_data.generated_lod_triangle_ratio().get()?;
+        #[cfg(feature = "meshopt")]
+        let generated_lod_target_error = asset_data.generated_lod_target_error().get()?;
+        let settings = mesh_util::MeshProcessSettings {
+            generated_lod_count: asset_data.generated_lod_count().get()?,
+            #[cfg(feature = "meshopt")]
+            generated_lod_triangle_ratio: if generated_lod_triangle_ratio > 0.0
+                && generated_lod_triangle_ratio < 1.0
+            {
+                generated_lod_triangle_ratio
+            } else {
+                DEFAULT_GENERATED_LOD_TRIANGLE_RATIO
+            },
+            #[cfg(feature = "meshopt")]
+            generated_lod_target_error: if generated_lod_target_error > 0.0 {
+                generated_lod_target_error
+            } else {
+                DEFAULT_GENERATED_LOD_TARGET_ERROR
+            },
+            optimize_overdraw: asset_data.optimize_overdraw().get()?,
+            generate_meshlets: asset_data.generate_meshlets().get()?,
+        };
+
+        #[cfg(not(feature = "meshopt"))]
+        if settings.generated_lod_count > 0
+            || settings.optimize_overdraw
+            || settings.generate_meshlets
+        {
+            log::warn!(
+                "Mesh {:?} requests LOD generation, overdraw optimization or meshlets, which require the meshopt feature",
+                context.input.asset_id
+            );
+        }
+
         //
         // Read import data
         //
@@ -227,6 +269,7 @@ impl JobProcessor for MeshAdvMeshJobProcessor {
 
         let mut all_vertices_full = PushBuffer::new(16384);
         let mut all_vertices_position = PushBuffer::new(16384);
+        let mut all_vertices_skin = PushBuffer::new(16384);
         let mut all_indices = PushBuffer::new(16384);
 
         let mut mesh_part_data = Vec::default();
@@ -256,6 +299,31 @@ impl JobProcessor for MeshAdvMeshJobProcessor {
             let part_indices =
                 try_cast_u8_slice::<u32>(indices_bytes).ok_or("Could not cast due to alignment")?;
 
+            // Joint indices/weights are empty if the part is not skinned
+            let joint_indices_field_reader = entry.joint_indices();
+            let joint_indices_bytes = joint_indices_field_reader.get()?;
+            let joint_indices = try_cast_u8_slice::<[u32; 4]>(joint_indices_bytes)
+                .ok_or("Could not cast due to alignment")?;
+
+            let joint_weights_field_reader = entry.joint_weights();
+            let joint_weights_bytes = joint_weights_field_reader.get()?;
+            let joint_weights = try_cast_u8_slice::<[f32; 4]>(joint_weights_bytes)
+                .ok_or("Could not cast due to alignment")?;
+
+            let skin = if !joint_indices.is_empty() {
+                if joint_indices.len() != positions.len() || joint_weights.len() != positions.len()
+                {
+                    Err("Mesh part joint indices/weights do not match the vertex count")?;
+                }
+
+                Some(mesh_util::MeshPartSkin {
+                    joint_indices,
+                    joint_weights,
+                })
+            } else {
+                None
+            };
+
             //
             // Part data which mostly contains offsets in the buffers for this part
             //
@@ -264,8 +332,11 @@ impl JobProcessor for MeshAdvMeshJobProcessor {
                 positions,
                 normals,
                 tex_coords,
+                skin,
+                &settings,
                 &mut all_vertices_full,
                 &mut all_vertices_position,
+                &mut all_vertices_skin,
                 &mut all_indices,
             );
 
@@ -319,6 +390,37 @@ impl JobProcessor for MeshAdvMeshJobProcessor {
             Err("The mesh asset has no vertices")?
         };
 
+        //
+        // Vertex Skin Buffer, only produced for skinned meshes
+        //
+        let vertex_buffer_skin_artifact_id = if !all_vertices_skin.is_empty() {
+            Some(context.produce_artifact(
+                context.input.asset_id,
+                Some("skin"),
+                MeshAdvBufferAssetData {
+                    resource_type: RafxResourceType::VERTEX_BUFFER,
+                    alignment: std::mem::size_of::<MeshVertexSkin>() as u32,
+                    data: all_vertices_skin.into_data(),
+                },
+            )?)
+        } else {
+            None
+        };
+
+        let mut skin_joint_names = Vec::default();
+        let mut skin_inverse_bind_matrices = Vec::default();
+        for entry in &imported_data.skin_joints().resolve_entries()? {
+            let entry = imported_data.skin_joints().entry(*entry);
+            skin_joint_names.push(entry.name().get()?.to_string());
+
+            let inverse_bind_matrix_field_reader = entry.inverse_bind_matrix();
+            let inverse_bind_matrix_bytes = inverse_bind_matrix_field_reader.get()?;
+            let inverse_bind_matrix = try_cast_u8_slice::<[f32; 16]>(inverse_bind_matrix_bytes)
+                .and_then(|x| x.first())
+                .ok_or("Could not read skin joint inverse bind matrix")?;
+            skin_inverse_bind_matrices.push(*inverse_bind_matrix);
+        }
+
         //
         // Index Buffer
         //
@@ -369,8 +471,14 @@ impl JobProcessor for MeshAdvMeshJobProcessor {
                             .vertex_position_buffer_size_in_bytes,
                         index_buffer_offset_in_bytes: part_data.index_buffer_offset_in_bytes,
                         index_buffer_size_in_bytes: part_data.index_buffer_size_in_bytes,
+                        vertex_skin_buffer_offset_in_bytes: part_data
+                            .vertex_skin_buffer_offset_in_bytes,
+                        vertex_skin_buffer_size_in_bytes: part_data
+                            .vertex_skin_buffer_size_in_bytes,
                         mesh_material: material_handle,
                         index_type: part_data.index_type,
+                        meshlets: part_data.meshlets,
+                        lods: part_data.lods,
                     })
                 }
 
@@ -379,6 +487,17 @@ impl JobProcessor for MeshAdvMeshJobProcessor {
                 let vertex_position_buffer =
                     handle_factory.make_handle_to_artifact(vertex_buffer_position_artifact_id);
                 let index_buffer = handle_factory.make_handle_to_artifact(index_buffer_artifact_id);
+                let vertex_skin_buffer = vertex_buffer_skin_artifact_id
+                    .map(|x| handle_factory.make_handle_to_artifact(x));
+
+                let skin = if vertex_skin_buffer.is_some() {
+                    Some(MeshAdvSkinAssetData {
+                        joint_names: skin_joint_names,
+                        inverse_bind_matrices: skin_inverse_bind_matrices,
+                    })
+                } else {
+                    None
+                };
 
                 let visible_bounds = PolygonSoup {
                     vertex_positions: all_positions,
@@ -390,6 +509,8 @@ impl JobProcessor for MeshAdvMeshJobProcessor {
                     vertex_full_buffer,
                     vertex_position_buffer,
                     index_buffer,
+                    vertex_skin_buffer,
+                    skin,
                     visible_bounds: VisibleBounds::from(visible_bounds),
                 })
             },
@@ -1,6 +1,7 @@
 pub mod anim;
 pub mod font;
 pub mod ldtk;
+pub mod particles;
 
 #[cfg(all(not(feature = "basic-pipeline"), feature = "legion"))]
 pub mod mesh_adv;
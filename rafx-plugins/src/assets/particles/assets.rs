@@ -0,0 +1,84 @@
+use hydrate_base::handle::Handle;
+use hydrate_base::LoadHandle;
+use rafx::api::RafxResult;
+use rafx::assets::{
+    AssetManager, DefaultAssetTypeHandler, DefaultAssetTypeLoadHandler, ImageAsset,
+};
+use rafx::framework::{ImageViewResource, ResourceArc};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use type_uuid::*;
+
+#[derive(TypeUuid, Serialize, Deserialize, Clone)]
+#[uuid = "5d0e4bc6-7f6a-4c1b-9a0e-3f2d61a8c4b7"]
+pub struct ParticleEmitterAssetData {
+    // Multiplied with the particle's color. Without a texture, particles are solid quads.
+    pub texture: Option<Handle<ImageAsset>>,
+    // Size of the emitter's particle pool. Spawning stops while the pool is full.
+    pub max_particles: u32,
+    // Particles per second
+    pub spawn_rate: f32,
+    pub min_lifetime: f32,
+    pub max_lifetime: f32,
+    // Particles spawn inside a sphere of this radius around the emitter
+    pub spawn_radius: f32,
+    // In the emitter's local space
+    pub initial_velocity: [f32; 3],
+    // Length of a random vector added to the initial velocity
+    pub velocity_randomness: f32,
+    // In world space
+    pub gravity: [f32; 3],
+    pub drag: f32,
+    // Size and color are interpolated over each particle's lifetime
+    pub start_size: f32,
+    pub end_size: f32,
+    pub start_color: [f32; 4],
+    pub end_color: [f32; 4],
+    // Particles fade out when they are closer than this to opaque geometry, 0 disables fading
+    pub soft_particle_distance: f32,
+}
+
+pub struct ParticleEmitterAssetInner {
+    pub data: ParticleEmitterAssetData,
+    pub texture: Option<ResourceArc<ImageViewResource>>,
+}
+
+#[derive(TypeUuid, Clone)]
+#[uuid = "c1f7a3d2-58e4-4b0f-8d6c-2a9e7b41f305"]
+pub struct ParticleEmitterAsset {
+    pub inner: Arc<ParticleEmitterAssetInner>,
+}
+
+pub struct ParticleEmitterLoadHandler;
+
+impl DefaultAssetTypeLoadHandler<ParticleEmitterAssetData, ParticleEmitterAsset>
+    for ParticleEmitterLoadHandler
+{
+    #[profiling::function]
+    fn load(
+        asset_manager: &mut AssetManager,
+        particle_emitter_asset: ParticleEmitterAssetData,
+        _load_handle: LoadHandle,
+    ) -> RafxResult<ParticleEmitterAsset> {
+        let texture = particle_emitter_asset
+            .texture
+            .as_ref()
+            .and_then(|x| asset_manager.latest_asset(x))
+            .map(|x| x.image_view.clone());
+
+        let inner = ParticleEmitterAssetInner {
+            data: particle_emitter_asset,
+            texture,
+        };
+
+        Ok(ParticleEmitterAsset {
+            inner: Arc::new(inner),
+        })
+    }
+}
+
+pub type ParticleEmitterAssetType = DefaultAssetTypeHandler<
+    ParticleEmitterAssetData,
+    ParticleEmitterAsset,
+    ParticleEmitterLoadHandler,
+>;
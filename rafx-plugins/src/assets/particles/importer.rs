@@ -0,0 +1,402 @@
+use crate::assets::particles::ParticleEmitterAssetData;
+use crate::schema::{ParticleEmitterAssetAccessor, ParticleEmitterAssetRecord};
+use hydrate_base::AssetId;
+use hydrate_data::{ImportableName, Record, RecordAccessor};
+use hydrate_pipeline::{
+    AssetPlugin, AssetPluginSetupContext, Builder, BuilderContext, ImportContext, Importer,
+    JobInput, JobOutput, JobProcessor, PipelineResult, RunContext, ScanContext,
+};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use type_uuid::*;
+
+// Each particle takes a slot in GPU buffers sized from this, so reject values that are almost
+// certainly a typo rather than allocating gigabytes for them
+const MAX_PARTICLES_LIMIT: u32 = 1 << 20;
+
+// The format of .particles files. See ParticleEmitterAssetData for what the fields do.
+#[derive(Serialize, Deserialize)]
+#[serde(default)]
+struct ParticleEmitterRon {
+    pub texture: Option<PathBuf>,
+    pub max_particles: u32,
+    pub spawn_rate: f32,
+    pub min_lifetime: f32,
+    pub max_lifetime: f32,
+    pub spawn_radius: f32,
+    pub initial_velocity: [f32; 3],
+    pub velocity_randomness: f32,
+    pub gravity: [f32; 3],
+    pub drag: f32,
+    pub start_size: f32,
+    pub end_size: f32,
+    pub start_color: [f32; 4],
+    pub end_color: [f32; 4],
+    pub soft_particle_distance: f32,
+}
+
+impl Default for ParticleEmitterRon {
+    fn default() -> Self {
+        ParticleEmitterRon {
+            texture: None,
+            max_particles: 1024,
+            spawn_rate: 64.0,
+            min_lifetime: 1.0,
+            max_lifetime: 2.0,
+            spawn_radius: 0.0,
+            initial_velocity: [0.0, 0.0, 1.0],
+            velocity_randomness: 0.0,
+            gravity: [0.0, 0.0, 0.0],
+            drag: 0.0,
+            start_size: 0.1,
+            end_size: 0.1,
+            start_color: [1.0, 1.0, 1.0, 1.0],
+            end_color: [1.0, 1.0, 1.0, 0.0],
+            soft_particle_distance: 0.0,
+        }
+    }
+}
+
+impl ParticleEmitterRon {
+    fn parse(source: &str) -> Result<Self, String> {
+        let emitter = ron::de::from_str::<ParticleEmitterRon>(source)
+            .map_err(|e| format!("RON error {:?}", e))?;
+        emitter.validate()?;
+        Ok(emitter)
+    }
+
+    // Catches values that would make the simulation produce NaNs or nothing at all, so they fail
+    // at import instead of showing up as a missing emitter at runtime
+    fn validate(&self) -> Result<(), String> {
+        if self.max_particles == 0 || self.max_particles > MAX_PARTICLES_LIMIT {
+            return Err(format!(
+                "max_particles must be between 1 and {}, got {}",
+                MAX_PARTICLES_LIMIT, self.max_particles
+            ));
+        }
+
+        let scalars = [
+            ("spawn_rate", self.spawn_rate),
+            ("min_lifetime", self.min_lifetime),
+            ("max_lifetime", self.max_lifetime),
+            ("spawn_radius", self.spawn_radius),
+            ("velocity_randomness", self.velocity_randomness),
+            ("drag", self.drag),
+            ("start_size", self.start_size),
+            ("end_size", self.end_size),
+            ("soft_particle_distance", self.soft_particle_distance),
+        ];
+        for (name, value) in scalars.iter() {
+            if !value.is_finite() || *value < 0.0 {
+                return Err(format!(
+                    "{} must be finite and non-negative, got {}",
+                    name, value
+                ));
+            }
+        }
+
+        let vectors = [
+            ("initial_velocity", &self.initial_velocity[..]),
+            ("gravity", &self.gravity[..]),
+            ("start_color", &self.start_color[..]),
+            ("end_color", &self.end_color[..]),
+        ];
+        for (name, value) in vectors.iter() {
+            if !value.iter().all(|x| x.is_finite()) {
+                return Err(format!("{} must be finite, got {:?}", name, value));
+            }
+        }
+
+        if self.min_lifetime <= 0.0 || self.min_lifetime > self.max_lifetime {
+            return Err(format!(
+                "lifetimes must satisfy 0 < min_lifetime <= max_lifetime, got {} and {}",
+                self.min_lifetime, self.max_lifetime
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(TypeUuid, Default)]
+#[uuid = "7d4a21a8-c77a-4438-884c-85c26fff4426"]
+pub struct ParticleEmitterImporter;
+
+impl Importer for ParticleEmitterImporter {
+    fn supported_file_extensions(&self) -> &[&'static str] {
+        &["particles"]
+    }
+
+    fn scan_file(
+        &self,
+        context: ScanContext,
+    ) -> PipelineResult<()> {
+        //
+        // Read the file
+        //
+        let source = std::fs::read_to_string(context.path)?;
+        let parsed_source = ParticleEmitterRon::parse(&source)?;
+
+        let importable = context.add_default_importable::<ParticleEmitterAssetRecord>()?;
+        if let Some(path) = &parsed_source.texture {
+            importable.add_path_reference(path)?;
+        }
+        Ok(())
+    }
+
+    fn import_file(
+        &self,
+        context: ImportContext,
+    ) -> PipelineResult<()> {
+        //
+        // Read the file
+        //
+        let source = std::fs::read_to_string(context.path)?;
+        let emitter = ParticleEmitterRon::parse(&source)?;
+
+        //
+        // Create the default asset
+        //
+        let default_asset = ParticleEmitterAssetRecord::new_builder(context.schema_set);
+
+        if let Some(path) = &emitter.texture {
+            default_asset.texture().set(
+                context
+                    .asset_id_for_referenced_file_path(ImportableName::default(), &path.into())?,
+            )?;
+        }
+
+        default_asset.max_particles().set(emitter.max_particles)?;
+        default_asset.spawn_rate().set(emitter.spawn_rate)?;
+        default_asset.min_lifetime().set(emitter.min_lifetime)?;
+        default_asset.max_lifetime().set(emitter.max_lifetime)?;
+        default_asset.spawn_radius().set(emitter.spawn_radius)?;
+        default_asset
+            .initial_velocity()
+            .set_vec3(emitter.initial_velocity)?;
+        default_asset
+            .velocity_randomness()
+            .set(emitter.velocity_randomness)?;
+        default_asset.gravity().set_vec3(emitter.gravity)?;
+        default_asset.drag().set(emitter.drag)?;
+        default_asset.start_size().set(emitter.start_size)?;
+        default_asset.end_size().set(emitter.end_size)?;
+        default_asset.start_color().set_vec4(emitter.start_color)?;
+        default_asset.end_color().set_vec4(emitter.end_color)?;
+        default_asset
+            .soft_particle_distance()
+            .set(emitter.soft_particle_distance)?;
+
+        //
+        // Return the created objects
+        //
+        context.add_default_importable(default_asset.into_inner()?, None);
+        Ok(())
+    }
+}
+
+#[derive(Hash, Serialize, Deserialize)]
+pub struct ParticleEmitterJobInput {
+    pub asset_id: AssetId,
+}
+impl JobInput for ParticleEmitterJobInput {}
+
+#[derive(Serialize, Deserialize)]
+pub struct ParticleEmitterJobOutput {}
+impl JobOutput for ParticleEmitterJobOutput {}
+
+#[derive(Default, TypeUuid)]
+#[uuid = "2801e19c-6afd-455f-b189-3f56339ac59a"]
+pub struct ParticleEmitterJobProcessor;
+
+impl JobProcessor for ParticleEmitterJobProcessor {
+    type InputT = ParticleEmitterJobInput;
+    type OutputT = ParticleEmitterJobOutput;
+
+    fn version(&self) -> u32 {
+        1
+    }
+
+    fn run<'a>(
+        &self,
+        context: &'a RunContext<'a, Self::InputT>,
+    ) -> PipelineResult<ParticleEmitterJobOutput> {
+        //
+        // Read asset data
+        //
+        let asset_data = context.asset::<ParticleEmitterAssetRecord>(context.input.asset_id)?;
+
+        let texture = asset_data.texture().get()?;
+
+        let max_particles = asset_data.max_particles().get()?;
+        let spawn_rate = asset_data.spawn_rate().get()?;
+        let min_lifetime = asset_data.min_lifetime().get()?;
+        let max_lifetime = asset_data.max_lifetime().get()?;
+        let spawn_radius = asset_data.spawn_radius().get()?;
+        let initial_velocity = asset_data.initial_velocity().get_vec3()?;
+        let velocity_randomness = asset_data.velocity_randomness().get()?;
+        let gravity = asset_data.gravity().get_vec3()?;
+        let drag = asset_data.drag().get()?;
+        let start_size = asset_data.start_size().get()?;
+        let end_size = asset_data.end_size().get()?;
+        let start_color = asset_data.start_color().get_vec4()?;
+        let end_color = asset_data.end_color().get_vec4()?;
+        let soft_particle_distance = asset_data.soft_particle_distance().get()?;
+
+        //
+        // Create the processed data
+        //
+        context.produce_default_artifact_with_handles(
+            context.input.asset_id,
+            |handle_factory| {
+                let texture = if !texture.is_null() {
+                    Some(handle_factory.make_handle_to_default_artifact(texture))
+                } else {
+                    None
+                };
+
+                Ok(ParticleEmitterAssetData {
+                    texture,
+                    max_particles,
+                    spawn_rate,
+                    min_lifetime,
+                    max_lifetime,
+                    spawn_radius,
+                    initial_velocity,
+                    velocity_randomness,
+                    gravity,
+                    drag,
+                    start_size,
+                    end_size,
+                    start_color,
+                    end_color,
+                    soft_particle_distance,
+                })
+            },
+        )?;
+
+        Ok(ParticleEmitterJobOutput {})
+    }
+}
+
+#[derive(TypeUuid, Default)]
+#[uuid = "f5d28a4b-22e5-4ea7-8853-82a7ebf756f2"]
+pub struct ParticleEmitterBuilder {}
+
+impl Builder for ParticleEmitterBuilder {
+    fn asset_type(&self) -> &'static str {
+        ParticleEmitterAssetAccessor::schema_name()
+    }
+
+    fn start_jobs(
+        &self,
+        context: BuilderContext,
+    ) -> PipelineResult<()> {
+        context.enqueue_job::<ParticleEmitterJobProcessor>(
+            context.data_set,
+            context.schema_set,
+            context.job_api,
+            ParticleEmitterJobInput {
+                asset_id: context.asset_id,
+            },
+        )?;
+        Ok(())
+    }
+}
+
+pub struct ParticleEmitterAssetPlugin;
+
+impl AssetPlugin for ParticleEmitterAssetPlugin {
+    fn setup(context: AssetPluginSetupContext) {
+        context
+            .importer_registry
+            .register_handler::<ParticleEmitterImporter>();
+        context
+            .builder_registry
+            .register_handler::<ParticleEmitterBuilder>();
+        context
+            .job_processor_registry
+            .register_job_processor::<ParticleEmitterJobProcessor>();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn assert_rejected(
+        source: &str,
+        field: &str,
+    ) {
+        match ParticleEmitterRon::parse(source) {
+            Ok(_) => panic!("{} was accepted", source),
+            Err(e) => assert!(e.contains(field), "{:?} does not mention {}", e, field),
+        }
+    }
+
+    #[test]
+    fn defaults_are_valid() {
+        ParticleEmitterRon::default().validate().unwrap();
+        ParticleEmitterRon::parse("()").unwrap();
+    }
+
+    #[test]
+    fn demo_emitter_is_valid() {
+        let emitter = ParticleEmitterRon::parse(include_str!(
+            "../../../../demo/assets/particles/fountain.particles"
+        ))
+        .unwrap();
+        assert_eq!(emitter.max_particles, 4096);
+        assert_eq!(emitter.gravity, [0.0, 0.0, -6.0]);
+        assert!(emitter.texture.is_none());
+    }
+
+    #[test]
+    fn rejects_malformed_ron() {
+        assert_rejected("(max_particles: -1)", "RON error");
+        assert_rejected("(spawn_rate: \"fast\")", "RON error");
+        assert_rejected("(unknown_field: 1.0", "RON error");
+    }
+
+    #[test]
+    fn rejects_max_particles_out_of_range() {
+        assert_rejected("(max_particles: 0)", "max_particles");
+        assert_rejected("(max_particles: 4294967295)", "max_particles");
+        ParticleEmitterRon::parse(&format!("(max_particles: {})", MAX_PARTICLES_LIMIT)).unwrap();
+    }
+
+    #[test]
+    fn rejects_negative_or_non_finite_scalars() {
+        for field in &[
+            "spawn_rate",
+            "spawn_radius",
+            "velocity_randomness",
+            "drag",
+            "start_size",
+            "end_size",
+            "soft_particle_distance",
+        ] {
+            assert_rejected(&format!("({}: -1.0)", field), field);
+            assert_rejected(&format!("({}: inf)", field), field);
+            assert_rejected(&format!("({}: NaN)", field), field);
+        }
+
+        // Zero is allowed, e.g. for a burst emitter or particles that don't change size
+        ParticleEmitterRon::parse("(spawn_rate: 0.0, end_size: 0.0)").unwrap();
+    }
+
+    #[test]
+    fn rejects_non_finite_vectors() {
+        assert_rejected("(initial_velocity: (0.0, NaN, 1.0))", "initial_velocity");
+        assert_rejected("(gravity: (0.0, 0.0, -inf))", "gravity");
+        assert_rejected("(start_color: (1.0, 1.0, inf, 1.0))", "start_color");
+        assert_rejected("(end_color: (NaN, 1.0, 1.0, 0.0))", "end_color");
+    }
+
+    #[test]
+    fn rejects_invalid_lifetimes() {
+        assert_rejected("(min_lifetime: 0.0)", "lifetime");
+        assert_rejected("(min_lifetime: 3.0, max_lifetime: 2.0)", "lifetime");
+        ParticleEmitterRon::parse("(min_lifetime: 2.0, max_lifetime: 2.0)").unwrap();
+    }
+}
@@ -0,0 +1,8 @@
+mod assets;
+pub use assets::*;
+
+mod importer;
+pub use importer::*;
+
+mod plugin;
+pub use plugin::*;
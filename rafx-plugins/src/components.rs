@@ -1,5 +1,7 @@
+use crate::assets::anim::AnimAsset;
 use glam::f32::Vec3;
 use glam::Quat;
+use hydrate_base::Handle;
 use rafx::framework::visibility::VisibilityObjectArc;
 use rafx::render_features::RenderObjectHandle;
 use rafx::visibility::ViewFrustumArc;
@@ -14,6 +16,69 @@ pub struct MeshComponent {
     pub render_object_handle: RenderObjectHandle,
 }
 
+// Projects a DecalRenderObject from the entity's TransformComponent
+#[derive(Clone)]
+pub struct DecalComponent {
+    pub render_object_handle: RenderObjectHandle,
+}
+
+// Emits the particles of a ParticleEmitterRenderObject from the entity's TransformComponent
+#[derive(Clone)]
+pub struct ParticleEmitterComponent {
+    pub render_object_handle: RenderObjectHandle,
+}
+
+// Plays a clip of an AnimAsset on the skinned mesh of the entity's MeshComponent. Joints of the
+// mesh's skin are matched to the skeleton's bones by name.
+#[derive(Clone)]
+pub struct SkeletalAnimationComponent {
+    pub anim: Handle<AnimAsset>,
+    pub clip_index: usize,
+    // In seconds
+    pub time: f32,
+    pub playback_speed: f32,
+    pub frames_per_second: f32,
+    pub looping: bool,
+}
+
+impl SkeletalAnimationComponent {
+    pub fn new(
+        anim: Handle<AnimAsset>,
+        clip_index: usize,
+    ) -> Self {
+        SkeletalAnimationComponent {
+            anim,
+            clip_index,
+            time: 0.0,
+            playback_speed: 1.0,
+            frames_per_second: 30.0,
+            looping: true,
+        }
+    }
+
+    pub fn advance(
+        &mut self,
+        dt: f32,
+    ) {
+        self.time += dt * self.playback_speed;
+    }
+
+    // The frame to sample, wrapped or clamped to the clip's last frame
+    pub fn frame(
+        &self,
+        max_frame: u32,
+    ) -> f32 {
+        let frame = (self.time * self.frames_per_second).max(0.0);
+        if max_frame == 0 {
+            0.0
+        } else if self.looping {
+            frame % max_frame as f32
+        } else {
+            frame.min(max_frame as f32)
+        }
+    }
+}
+
 #[derive(Clone)]
 pub struct VisibilityComponent {
     pub visibility_object_handle: VisibilityObjectArc,
@@ -101,12 +166,15 @@ impl PointLightComponent {
     }
 }
 
+// Directional light shadows are split into up to this many cascades, each with its own view frustum
+pub const MAX_DIRECTIONAL_LIGHT_CASCADES: usize = 4;
+
 #[derive(Clone)]
 pub struct DirectionalLightComponent {
     pub direction: glam::Vec3,
     pub color: glam::Vec4,
     pub intensity: f32,
-    pub shadow_view_frustum: Option<ViewFrustumArc>,
+    pub shadow_view_frustums: Option<[ViewFrustumArc; MAX_DIRECTIONAL_LIGHT_CASCADES]>,
 }
 
 #[derive(Clone)]
@@ -140,3 +208,19 @@ impl SpotLightComponent {
         }
     }
 }
+
+// A local reflection cubemap captured from the entity's TransformComponent translation. Reflections
+// of surfaces inside the probe's box are parallax corrected by projecting the reflection vector onto
+// the box, so the box should roughly match the walls of the room the probe is in.
+#[derive(Clone)]
+pub struct ReflectionProbeComponent {
+    // Half size of the world-space axis-aligned box centered on the probe. The probe's rotation and
+    // scale are ignored.
+    pub half_extents: glam::Vec3,
+    // Distance inside the box over which the probe fades out into the global image-based lighting
+    pub blend_distance: f32,
+    pub intensity: f32,
+    // The probe is captured once and then only recaptured when it moves or this changes
+    pub capture_generation: u32,
+    pub capture_view_frustums: [ViewFrustumArc; 6],
+}
@@ -0,0 +1,62 @@
+use super::*;
+use glam::{Mat4, Vec4};
+use rafx::framework::render_features::render_features_prelude::*;
+use rafx::framework::{DescriptorSetArc, ImageViewResource, MaterialPassResource, ResourceArc};
+
+pub struct DecalRenderFeatureTypes;
+
+//---------
+// EXTRACT
+//---------
+
+pub struct DecalPerFrameData {
+    pub decal_material_pass: Option<ResourceArc<MaterialPassResource>>,
+}
+
+pub struct DecalRenderObjectInstanceData {
+    // Transforms a unit cube centered on the origin to the decal's box
+    pub model: Mat4,
+    pub tint: Vec4,
+    pub sort_order: u32,
+    pub albedo_texture: Option<ResourceArc<ImageViewResource>>,
+    pub normal_texture: Option<ResourceArc<ImageViewResource>>,
+    pub roughness_texture: Option<ResourceArc<ImageViewResource>>,
+}
+
+impl FramePacketData for DecalRenderFeatureTypes {
+    type PerFrameData = DecalPerFrameData;
+    type RenderObjectInstanceData = Option<DecalRenderObjectInstanceData>;
+    type PerViewData = ();
+    type RenderObjectInstancePerViewData = ();
+}
+
+pub type DecalFramePacket = FramePacket<DecalRenderFeatureTypes>;
+
+//---------
+// PREPARE
+//---------
+
+impl SubmitPacketData for DecalRenderFeatureTypes {
+    type PerFrameSubmitData = ();
+    type RenderObjectInstanceSubmitData = ();
+    type PerViewSubmitData = DecalPerViewSubmitData;
+    type RenderObjectInstancePerViewSubmitData = ();
+    type SubmitNodeData = DecalDrawCall;
+
+    type RenderFeature = DecalRenderFeature;
+}
+
+pub type DecalSubmitPacket = SubmitPacket<DecalRenderFeatureTypes>;
+
+//-------
+// WRITE
+//-------
+
+#[derive(Default)]
+pub struct DecalPerViewSubmitData {
+    pub descriptor_set_arc: Option<DescriptorSetArc>,
+}
+
+pub struct DecalDrawCall {
+    pub per_decal_descriptor_set: DescriptorSetArc,
+}
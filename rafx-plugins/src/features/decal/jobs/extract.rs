@@ -0,0 +1,128 @@
+use rafx::render_feature_extract_job_predule::*;
+
+use super::*;
+use crate::components::TransformComponent;
+use hydrate_base::handle::Handle;
+use legion::{EntityStore, World};
+use rafx::assets::{AssetManagerExtractRef, AssetManagerRenderResource, ImageAsset, MaterialAsset};
+use rafx::base::resource_ref_map::ResourceRefBorrow;
+use rafx::framework::{ImageViewResource, ResourceArc};
+
+pub struct DecalExtractJob<'extract> {
+    world: ResourceRefBorrow<'extract, World>,
+    asset_manager: AssetManagerExtractRef,
+    decal_material: Handle<MaterialAsset>,
+    render_objects: DecalRenderObjectSet,
+}
+
+impl<'extract> DecalExtractJob<'extract> {
+    pub fn new(
+        extract_context: &RenderJobExtractContext<'extract>,
+        frame_packet: Box<DecalFramePacket>,
+        decal_material: Handle<MaterialAsset>,
+        render_objects: DecalRenderObjectSet,
+    ) -> Arc<dyn RenderFeatureExtractJob<'extract> + 'extract> {
+        Arc::new(ExtractJob::new(
+            Self {
+                world: extract_context.extract_resources.fetch::<World>(),
+                asset_manager: extract_context
+                    .render_resources
+                    .fetch::<AssetManagerRenderResource>()
+                    .extract_ref(),
+                decal_material,
+                render_objects,
+            },
+            extract_context,
+            frame_packet,
+        ))
+    }
+
+    // Returns Err if the decal has a texture that has not finished loading
+    fn committed_image_view(
+        &self,
+        image: &Option<Handle<ImageAsset>>,
+    ) -> Result<Option<ResourceArc<ImageViewResource>>, ()> {
+        match image {
+            Some(image) => self
+                .asset_manager
+                .committed_asset(image)
+                .map(|image_asset| Some(image_asset.image_view.clone()))
+                .ok_or(()),
+            None => Ok(None),
+        }
+    }
+}
+
+impl<'extract> ExtractJobEntryPoints<'extract> for DecalExtractJob<'extract> {
+    fn begin_per_frame_extract(
+        &self,
+        context: &ExtractPerFrameContext<'extract, '_, Self>,
+    ) {
+        context
+            .frame_packet()
+            .per_frame_data()
+            .set(DecalPerFrameData {
+                decal_material_pass: self
+                    .asset_manager
+                    .committed_asset(&self.decal_material)
+                    .unwrap()
+                    .get_single_material_pass()
+                    .ok(),
+            });
+    }
+
+    fn extract_render_object_instance(
+        &self,
+        job_context: &mut RenderObjectsJobContext<'extract, DecalRenderObject>,
+        context: &ExtractRenderObjectInstanceContext<'extract, '_, Self>,
+    ) {
+        let render_object_static_data = job_context
+            .render_objects
+            .get_id(context.render_object_id());
+
+        // Skip decals with textures that are still loading rather than drawing them partially
+        let albedo_texture = self.committed_image_view(&render_object_static_data.albedo_texture);
+        let normal_texture = self.committed_image_view(&render_object_static_data.normal_texture);
+        let roughness_texture =
+            self.committed_image_view(&render_object_static_data.roughness_texture);
+
+        let instance_data = match (albedo_texture, normal_texture, roughness_texture) {
+            (Ok(albedo_texture), Ok(normal_texture), Ok(roughness_texture)) => {
+                let entry = self.world.entry_ref(context.object_id().into()).unwrap();
+                let transform_component = entry.get_component::<TransformComponent>().unwrap();
+                Some(DecalRenderObjectInstanceData {
+                    model: render_object_static_data.model_matrix(transform_component),
+                    tint: render_object_static_data
+                        .tint
+                        .extend(render_object_static_data.fade),
+                    sort_order: render_object_static_data.sort_order,
+                    albedo_texture,
+                    normal_texture,
+                    roughness_texture,
+                })
+            }
+            _ => None,
+        };
+
+        context.set_render_object_instance_data(instance_data);
+    }
+
+    fn feature_debug_constants(&self) -> &'static RenderFeatureDebugConstants {
+        super::render_feature_debug_constants()
+    }
+
+    fn feature_index(&self) -> RenderFeatureIndex {
+        super::render_feature_index()
+    }
+
+    fn new_render_object_instance_job_context(
+        &'extract self
+    ) -> Option<RenderObjectsJobContext<'extract, DecalRenderObject>> {
+        Some(RenderObjectsJobContext::new(self.render_objects.read()))
+    }
+
+    type RenderObjectInstanceJobContextT = RenderObjectsJobContext<'extract, DecalRenderObject>;
+    type RenderObjectInstancePerViewJobContextT = DefaultJobContext;
+
+    type FramePacketDataT = DecalRenderFeatureTypes;
+}
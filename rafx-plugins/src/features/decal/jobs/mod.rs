@@ -0,0 +1,8 @@
+mod extract;
+pub use extract::*;
+mod prepare;
+pub use prepare::*;
+mod write;
+pub use write::*;
+
+use super::*;
@@ -0,0 +1,134 @@
+use rafx::render_feature_prepare_job_predule::*;
+
+use super::*;
+use crate::phases::DecalRenderPhase;
+use crate::shaders::decal::{decal_frag, decal_vert};
+use rafx::base::resource_map::ReadBorrow;
+use rafx::renderer::InvalidResources;
+
+pub struct DecalPrepareJob<'prepare> {
+    pipeline_state: ReadBorrow<'prepare, DecalRenderPipelineState>,
+    invalid_resources: ReadBorrow<'prepare, InvalidResources>,
+}
+
+impl<'prepare> DecalPrepareJob<'prepare> {
+    pub fn new(
+        prepare_context: &RenderJobPrepareContext<'prepare>,
+        frame_packet: Box<DecalFramePacket>,
+        submit_packet: Box<DecalSubmitPacket>,
+    ) -> Arc<dyn RenderFeaturePrepareJob<'prepare> + 'prepare> {
+        Arc::new(PrepareJob::new(
+            Self {
+                pipeline_state: {
+                    prepare_context
+                        .render_resources
+                        .fetch::<DecalRenderPipelineState>()
+                },
+                invalid_resources: { prepare_context.render_resources.fetch::<InvalidResources>() },
+            },
+            prepare_context,
+            frame_packet,
+            submit_packet,
+        ))
+    }
+}
+
+impl<'prepare> PrepareJobEntryPoints<'prepare> for DecalPrepareJob<'prepare> {
+    fn end_per_view_prepare(
+        &self,
+        context: &PreparePerViewContext<'prepare, '_, Self>,
+    ) {
+        let per_frame_data = context.per_frame_data();
+        let decal_material_pass = match &per_frame_data.decal_material_pass {
+            Some(decal_material_pass) => decal_material_pass,
+            None => return,
+        };
+
+        let mut descriptor_set_allocator =
+            context.resource_context().create_descriptor_set_allocator();
+        let descriptor_set_layouts = &decal_material_pass.get_raw().descriptor_set_layouts;
+
+        let view = context.view();
+        let view_packet = context.view_packet();
+        let view_submit_packet = context.view_submit_packet();
+
+        let view_proj = view.view_proj();
+        let per_view_descriptor_set = descriptor_set_allocator
+            .create_descriptor_set_with_writer(
+                &descriptor_set_layouts[decal_vert::PER_VIEW_DATA_DESCRIPTOR_SET_INDEX],
+                decal_vert::DescriptorSet0Args {
+                    per_view_data: &decal_vert::PerViewDataUniform {
+                        view: view.view_matrix().to_cols_array_2d(),
+                        view_proj: view_proj.to_cols_array_2d(),
+                        inverse_view_proj: view_proj.inverse().to_cols_array_2d(),
+                        jitter_amount: self.pipeline_state.jitter_amount.into(),
+                        ..Default::default()
+                    },
+                },
+            )
+            .unwrap();
+
+        let invalid_image = &self.invalid_resources.invalid_image_color;
+        for render_object_instance in view_packet.render_object_instances() {
+            let decal = match context
+                .render_object_instances_data()
+                .get(render_object_instance.render_object_instance_id as usize)
+            {
+                Some(decal) => decal,
+                // The decal was not extracted, can occur if a texture is not loaded
+                None => continue,
+            };
+
+            let per_decal_descriptor_set = descriptor_set_allocator
+                .create_descriptor_set_with_writer(
+                    &descriptor_set_layouts[decal_frag::PER_DECAL_DATA_DESCRIPTOR_SET_INDEX],
+                    decal_frag::DescriptorSet2Args {
+                        per_decal_data: &decal_frag::PerDecalDataUniform {
+                            model: decal.model.to_cols_array_2d(),
+                            inverse_model: decal.model.inverse().to_cols_array_2d(),
+                            tint: decal.tint.into(),
+                            has_albedo_texture: decal.albedo_texture.is_some() as u32,
+                            has_normal_texture: decal.normal_texture.is_some() as u32,
+                            has_roughness_texture: decal.roughness_texture.is_some() as u32,
+                            ..Default::default()
+                        },
+                        albedo_texture: decal.albedo_texture.as_ref().unwrap_or(invalid_image),
+                        normal_texture: decal.normal_texture.as_ref().unwrap_or(invalid_image),
+                        roughness_texture: decal
+                            .roughness_texture
+                            .as_ref()
+                            .unwrap_or(invalid_image),
+                    },
+                )
+                .unwrap();
+
+            view_submit_packet.push_submit_node::<DecalRenderPhase>(
+                DecalDrawCall {
+                    per_decal_descriptor_set,
+                },
+                decal.sort_order,
+                0.,
+            );
+        }
+
+        view_submit_packet
+            .per_view_submit_data()
+            .set(DecalPerViewSubmitData {
+                descriptor_set_arc: Some(per_view_descriptor_set),
+            });
+    }
+
+    fn feature_debug_constants(&self) -> &'static RenderFeatureDebugConstants {
+        super::render_feature_debug_constants()
+    }
+
+    fn feature_index(&self) -> RenderFeatureIndex {
+        super::render_feature_index()
+    }
+
+    type RenderObjectInstanceJobContextT = DefaultJobContext;
+    type RenderObjectInstancePerViewJobContextT = DefaultJobContext;
+
+    type FramePacketDataT = DecalRenderFeatureTypes;
+    type SubmitPacketDataT = DecalRenderFeatureTypes;
+}
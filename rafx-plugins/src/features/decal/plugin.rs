@@ -0,0 +1,188 @@
+use rafx::render_feature_renderer_prelude::*;
+
+use super::*;
+use crate::phases::DecalRenderPhase;
+use hydrate_base::handle::Handle;
+use rafx::assets::MaterialAsset;
+use rafx::framework::DescriptorSetArc;
+use rafx::renderer::RendererLoadContext;
+
+pub struct DecalStaticResources {
+    pub decal_material: Handle<MaterialAsset>,
+}
+
+// This is state that's updated by the pipeline (pipeline pushes data to the feature plugin, not
+// the other way around)
+pub struct DecalRenderPipelineState {
+    // Decals reconstruct positions from the depth prepass, so they use the same jitter as the meshes
+    pub jitter_amount: glam::Vec2,
+    // Binds the depth prepass while the pipeline's decal pass is writing DecalRenderPhase
+    pub depth_descriptor_set: Option<DescriptorSetArc>,
+}
+
+impl Default for DecalRenderPipelineState {
+    fn default() -> Self {
+        DecalRenderPipelineState {
+            jitter_amount: glam::Vec2::ZERO,
+            depth_descriptor_set: None,
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct DecalRendererPlugin {
+    render_objects: DecalRenderObjectSet,
+}
+
+#[cfg(feature = "legion")]
+impl DecalRendererPlugin {
+    pub fn legion_init(
+        &self,
+        resources: &mut legion::Resources,
+    ) {
+        resources.insert(self.render_objects.clone());
+    }
+
+    pub fn legion_destroy(resources: &mut legion::Resources) {
+        resources.remove::<DecalRenderObjectSet>();
+    }
+}
+
+impl RenderFeaturePlugin for DecalRendererPlugin {
+    fn feature_debug_constants(&self) -> &'static RenderFeatureDebugConstants {
+        super::render_feature_debug_constants()
+    }
+
+    fn feature_index(&self) -> RenderFeatureIndex {
+        super::render_feature_index()
+    }
+
+    fn is_view_relevant(
+        &self,
+        view: &RenderView,
+    ) -> bool {
+        view.phase_is_relevant::<DecalRenderPhase>()
+    }
+
+    fn requires_visible_render_objects(&self) -> bool {
+        true
+    }
+
+    fn configure_render_registry(
+        &self,
+        render_registry: RenderRegistryBuilder,
+    ) -> RenderRegistryBuilder {
+        render_registry.register_feature::<DecalRenderFeature>()
+    }
+
+    fn initialize_static_resources(
+        &self,
+        renderer_load_context: &RendererLoadContext,
+        asset_manager: &mut AssetManager,
+        asset_resource: &mut AssetResource,
+        _extract_resources: &ExtractResources,
+        render_resources: &mut RenderResources,
+        _upload: &mut RafxTransferUpload,
+    ) -> RafxResult<()> {
+        let decal_material = asset_resource.load_artifact_symbol_name::<MaterialAsset>(
+            "rafx-plugins://materials/modern_pipeline/decal.material",
+        );
+
+        renderer_load_context.wait_for_asset_to_load(
+            render_resources,
+            asset_manager,
+            &decal_material,
+            asset_resource,
+            "decal_material",
+        )?;
+
+        render_resources.insert(DecalStaticResources { decal_material });
+        render_resources.insert(DecalRenderPipelineState::default());
+
+        Ok(())
+    }
+
+    fn new_frame_packet(
+        &self,
+        frame_packet_size: &FramePacketSize,
+    ) -> Box<dyn RenderFeatureFramePacket> {
+        Box::new(DecalFramePacket::new(
+            self.feature_index(),
+            frame_packet_size,
+        ))
+    }
+
+    fn new_extract_job<'extract>(
+        &self,
+        extract_context: &RenderJobExtractContext<'extract>,
+        frame_packet: Box<dyn RenderFeatureFramePacket>,
+    ) -> Arc<dyn RenderFeatureExtractJob<'extract> + 'extract> {
+        let decal_material = extract_context
+            .render_resources
+            .fetch::<DecalStaticResources>()
+            .decal_material
+            .clone();
+
+        DecalExtractJob::new(
+            extract_context,
+            frame_packet.into_concrete(),
+            decal_material,
+            self.render_objects.clone(),
+        )
+    }
+
+    fn new_submit_packet(
+        &self,
+        frame_packet: &Box<dyn RenderFeatureFramePacket>,
+    ) -> Box<dyn RenderFeatureSubmitPacket> {
+        let frame_packet: &DecalFramePacket = frame_packet.as_ref().as_concrete();
+
+        let mut view_submit_packets = Vec::with_capacity(frame_packet.view_packets().len());
+        for view_packet in frame_packet.view_packets() {
+            let view = view_packet.view();
+            let num_submit_nodes = view_packet.render_object_instances().len();
+            let submit_node_blocks = vec![SubmitNodeBlock::with_capacity::<DecalRenderPhase>(
+                view,
+                num_submit_nodes,
+            )];
+
+            view_submit_packets.push(ViewSubmitPacket::new(
+                submit_node_blocks,
+                &ViewPacketSize::size_of(view_packet),
+                view_packet.view_frame_index(),
+            ));
+        }
+
+        Box::new(DecalSubmitPacket::new(
+            self.feature_index(),
+            frame_packet.render_object_instances().len(),
+            view_submit_packets,
+        ))
+    }
+
+    fn new_prepare_job<'prepare>(
+        &self,
+        prepare_context: &RenderJobPrepareContext<'prepare>,
+        frame_packet: Box<dyn RenderFeatureFramePacket>,
+        submit_packet: Box<dyn RenderFeatureSubmitPacket>,
+    ) -> Arc<dyn RenderFeaturePrepareJob<'prepare> + 'prepare> {
+        DecalPrepareJob::new(
+            prepare_context,
+            frame_packet.into_concrete(),
+            submit_packet.into_concrete(),
+        )
+    }
+
+    fn new_write_job<'write>(
+        &self,
+        write_context: &RenderJobWriteContext<'write>,
+        frame_packet: Box<dyn RenderFeatureFramePacket>,
+        submit_packet: Box<dyn RenderFeatureSubmitPacket>,
+    ) -> Arc<dyn RenderFeatureWriteJob<'write> + 'write> {
+        DecalWriteJob::new(
+            write_context,
+            frame_packet.into_concrete(),
+            submit_packet.into_concrete(),
+        )
+    }
+}
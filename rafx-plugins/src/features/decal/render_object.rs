@@ -0,0 +1,214 @@
+use super::DecalRenderFeature;
+use crate::components::TransformComponent;
+use glam::{Mat4, Vec3};
+use hydrate_base::handle::Handle;
+use rafx::assets::ImageAsset;
+use rafx::rafx_visibility::{PolygonSoup, PolygonSoupIndex, VisibleBounds};
+use rafx::render_features::RenderObjectSet;
+
+// A decal projected through a box onto the opaque meshes inside it. The box is centered on the
+// entity's TransformComponent and projects along its -Z axis, so the textures land upright on a
+// surface facing +Z.
+#[derive(Clone)]
+pub struct DecalRenderObject {
+    // Half size of the box in the entity's local space, before the transform's scale is applied
+    pub half_extents: Vec3,
+    // The alpha channel masks the decal. Without an albedo texture, the whole box is covered.
+    pub albedo_texture: Option<Handle<ImageAsset>>,
+    // Tangent-space normal map, oriented along the box's X/Y axes
+    pub normal_texture: Option<Handle<ImageAsset>>,
+    // Roughness is read from the green channel, the same as glTF metallic/roughness textures
+    pub roughness_texture: Option<Handle<ImageAsset>>,
+    pub tint: Vec3,
+    // Opacity of the whole decal, lower this to fade the decal out
+    pub fade: f32,
+    // Overlapping decals are blended in increasing sort order, so newer decals like bullet holes
+    // should use a higher value to be drawn on top
+    pub sort_order: u32,
+}
+
+impl DecalRenderObject {
+    pub fn new(half_extents: Vec3) -> Self {
+        DecalRenderObject {
+            half_extents,
+            albedo_texture: None,
+            normal_texture: None,
+            roughness_texture: None,
+            tint: Vec3::ONE,
+            fade: 1.0,
+            sort_order: 0,
+        }
+    }
+
+    // Maps the unit cube centered on the origin onto the decal's box in world space. decal.frag
+    // projects surfaces back into the unit cube with the inverse of this matrix.
+    pub fn model_matrix(
+        &self,
+        transform: &TransformComponent,
+    ) -> Mat4 {
+        Mat4::from_scale_rotation_translation(
+            transform.scale * self.half_extents * 2.0,
+            transform.rotation,
+            transform.translation,
+        )
+    }
+
+    // Bounds of the decal's box, used to register the entity's VisibilityObjectArc
+    pub fn visible_bounds(&self) -> VisibleBounds {
+        let half_extents = self.half_extents;
+        let vertex_positions = (0..8)
+            .map(|corner| {
+                Vec3::new(
+                    if corner & 1 != 0 { 1.0 } else { -1.0 },
+                    if corner & 2 != 0 { 1.0 } else { -1.0 },
+                    if corner & 4 != 0 { 1.0 } else { -1.0 },
+                ) * half_extents
+            })
+            .collect();
+
+        VisibleBounds::from(PolygonSoup {
+            vertex_positions,
+            index: PolygonSoupIndex::None,
+        })
+    }
+}
+
+pub type DecalRenderObjectSet = RenderObjectSet<DecalRenderFeature, DecalRenderObject>;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use glam::{Quat, Vec2};
+
+    const EPSILON: f32 = 0.0001;
+
+    fn assert_vec3_eq(
+        actual: Vec3,
+        expected: Vec3,
+    ) {
+        assert!(
+            actual.abs_diff_eq(expected, EPSILON),
+            "{:?} != {:?}",
+            actual,
+            expected
+        );
+    }
+
+    fn test_transform() -> TransformComponent {
+        TransformComponent {
+            translation: Vec3::new(3.0, -2.0, 5.0),
+            rotation: Quat::from_rotation_ypr(0.4, -0.7, 1.1),
+            scale: Vec3::new(2.0, 0.5, 1.5),
+        }
+    }
+
+    fn corner(index: u32) -> Vec3 {
+        Vec3::new(
+            if index & 1 != 0 { 1.0 } else { -1.0 },
+            if index & 2 != 0 { 1.0 } else { -1.0 },
+            if index & 4 != 0 { 1.0 } else { -1.0 },
+        )
+    }
+
+    // Same projection as decal.frag: the shader gets the inverse model matrix and maps the
+    // decal-space XY onto the textures, discarding anything outside the unit cube
+    fn project(
+        inverse_model: &[[f32; 4]; 4],
+        position_ws: Vec3,
+    ) -> (Vec3, Vec2) {
+        let position_ds = Mat4::from_cols_array_2d(inverse_model).transform_point3(position_ws);
+        let decal_uv = Vec2::new(position_ds.x + 0.5, 0.5 - position_ds.y);
+        (position_ds, decal_uv)
+    }
+
+    #[test]
+    fn visible_bounds_cover_box() {
+        let half_extents = Vec3::new(1.0, 2.0, 0.25);
+        let bounds = DecalRenderObject::new(half_extents).visible_bounds();
+
+        assert_vec3_eq(bounds.aabb.min, -half_extents);
+        assert_vec3_eq(bounds.aabb.max, half_extents);
+
+        assert_vec3_eq(bounds.bounding_sphere.position, Vec3::ZERO);
+        assert!((bounds.bounding_sphere.radius - half_extents.length()).abs() < EPSILON);
+
+        // The box is its own tightest oriented box, so the OBB must match it exactly rather than
+        // some rotated fit with a larger volume
+        assert_vec3_eq(bounds.obb.center(), Vec3::ZERO);
+        let volume = 8.0 * half_extents.x * half_extents.y * half_extents.z;
+        assert!((bounds.obb.volume() - volume).abs() < EPSILON);
+        for index in 0..8 {
+            let local = bounds.obb.rotation.inverse() * (corner(index) * half_extents);
+            assert!(local.cmpge(bounds.obb.min - Vec3::splat(EPSILON)).all());
+            assert!(local.cmple(bounds.obb.max + Vec3::splat(EPSILON)).all());
+        }
+    }
+
+    #[test]
+    fn model_matrix_matches_visible_bounds() {
+        let decal = DecalRenderObject::new(Vec3::new(1.0, 2.0, 0.25));
+        let transform = test_transform();
+        let model = decal.model_matrix(&transform);
+
+        // The projected box must land on the same corners as the bounds used for culling, after
+        // the entity transform places them in the world
+        for index in 0..8 {
+            let bounds_corner_ws = transform.translation
+                + transform.rotation * (transform.scale * corner(index) * decal.half_extents);
+            assert_vec3_eq(
+                model.transform_point3(corner(index) * 0.5),
+                bounds_corner_ws,
+            );
+        }
+    }
+
+    #[test]
+    fn inverse_model_projects_into_unit_cube() {
+        let decal = DecalRenderObject::new(Vec3::new(1.0, 2.0, 0.25));
+        let transform = test_transform();
+        let model = decal.model_matrix(&transform);
+
+        // Pack the same way as the prepare job fills PerDecalDataUniform
+        let packed_model = model.to_cols_array_2d();
+        let packed_inverse_model = model.inverse().to_cols_array_2d();
+        let round_trip = Mat4::from_cols_array_2d(&packed_inverse_model)
+            * Mat4::from_cols_array_2d(&packed_model);
+        assert!(round_trip.abs_diff_eq(Mat4::IDENTITY, EPSILON));
+
+        let to_world = |position_ds: Vec3| model.transform_point3(position_ds);
+
+        let (position_ds, _) = project(&packed_inverse_model, transform.translation);
+        assert_vec3_eq(position_ds, Vec3::ZERO);
+
+        for index in 0..8 {
+            let inside = corner(index) * 0.45;
+            let (position_ds, _) = project(&packed_inverse_model, to_world(inside));
+            assert_vec3_eq(position_ds, inside);
+            assert!(position_ds.abs().cmple(Vec3::splat(0.5)).all());
+
+            let outside = corner(index) * Vec3::new(0.55, 0.25, 0.25);
+            let (position_ds, _) = project(&packed_inverse_model, to_world(outside));
+            assert!(position_ds.abs().cmpgt(Vec3::splat(0.5)).any());
+        }
+    }
+
+    #[test]
+    fn decal_uv_is_upright() {
+        let decal = DecalRenderObject::new(Vec3::new(1.0, 2.0, 0.25));
+        let transform = test_transform();
+        let model = decal.model_matrix(&transform);
+        let packed_inverse_model = model.inverse().to_cols_array_2d();
+
+        // The top left of the box (-X, +Y) samples the top left of the textures and the bottom
+        // right (+X, -Y) the bottom right, regardless of depth along the projection axis
+        for depth in [-0.5, 0.0, 0.5].iter() {
+            let top_left = model.transform_point3(Vec3::new(-0.5, 0.5, *depth));
+            let (_, decal_uv) = project(&packed_inverse_model, top_left);
+            assert!(decal_uv.abs_diff_eq(Vec2::new(0.0, 0.0), EPSILON));
+
+            let bottom_right = model.transform_point3(Vec3::new(0.5, -0.5, *depth));
+            let (_, decal_uv) = project(&packed_inverse_model, bottom_right);
+            assert!(decal_uv.abs_diff_eq(Vec2::new(1.0, 1.0), EPSILON));
+        }
+    }
+}
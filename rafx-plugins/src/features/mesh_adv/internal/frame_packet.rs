@@ -1,4 +1,5 @@
 use super::*;
+use crate::assets::anim::{AnimAsset, SkeletonPose};
 use crate::assets::mesh_adv::{MeshAdvAsset, MeshAdvShaderPassIndices};
 use crate::components::{
     DirectionalLightComponent, PointLightComponent, SpotLightComponent, TransformComponent,
@@ -33,10 +34,31 @@ pub struct MeshAdvPerFrameData {
 }
 
 pub struct MeshAdvRenderObjectInstanceData {
-    pub mesh_asset: MeshAdvAsset,
+    // The loaded LODs of the render object and the LODs generated from them on import, most detailed
+    // first. Never empty.
+    pub lods: Vec<MeshAdvAsset>,
     pub transform: Transform,
     pub previous_transform: Option<Transform>,
     pub bounding_sphere: Option<BoundingSphere>,
+    // Set if the object is animated by a SkeletalAnimationComponent
+    pub skeletal_pose: Option<MeshAdvSkeletalPose>,
+}
+
+pub struct MeshAdvSkeletalPose {
+    pub anim: AnimAsset,
+    pub pose: SkeletonPose,
+}
+
+impl MeshAdvRenderObjectInstanceData {
+    // Returns the position and radius of the bounding sphere in world space
+    pub fn world_bounding_sphere(&self) -> Option<(glam::Vec3, f32)> {
+        self.bounding_sphere.map(|x| {
+            let t = self.transform;
+            let position = t.translation + t.rotation * x.position * t.scale;
+            let radius = x.radius * t.scale.abs().max_element();
+            (position, radius)
+        })
+    }
 }
 
 #[derive(Default)]
@@ -48,6 +70,7 @@ pub struct MeshAdvPerViewData {
     pub spot_lights: Vec<ExtractedSpotLight>,
     pub ndf_filter_amount: f32,
     pub ambient_light: glam::Vec3,
+    pub ibl_intensity: f32,
     pub use_clustered_lighting: bool,
 }
 
@@ -72,7 +95,7 @@ impl FramePacketData for MeshAdvRenderFeatureTypes {
     type PerFrameData = MeshAdvPerFrameData;
     type RenderObjectInstanceData = Option<MeshAdvRenderObjectInstanceData>;
     type PerViewData = MeshAdvPerViewData;
-    type RenderObjectInstancePerViewData = ();
+    type RenderObjectInstancePerViewData = MeshAdvLodSelection;
 }
 
 pub type MeshAdvFramePacket = FramePacket<MeshAdvRenderFeatureTypes>;
@@ -97,6 +120,7 @@ pub struct MeshAdvBatchDrawData {
     pub vertex_offset: u32, // in number of vertices, not bytes
     pub index_count: u32,   // In number of indices, not bytes
     pub index_offset: u32,  // In number of indices, not bytes
+    pub lod_fade: f32,
 }
 
 pub struct MeshAdvBatchedPassInfo {
@@ -128,6 +152,8 @@ pub struct MeshAdvPerFrameSubmitData {
     pub model_matrix_buffer: TrustCell<Option<ResourceArc<BufferResource>>>,
     pub model_matrix_with_history_buffer: TrustCell<Option<ResourceArc<BufferResource>>>,
     pub all_materials_descriptor_set: TrustCell<Option<DescriptorSetArc>>,
+    // Same material data as all_materials_descriptor_set, laid out for the alpha shadow map passes
+    pub shadow_map_alpha_materials_descriptor_set: TrustCell<Option<DescriptorSetArc>>,
     pub batched_pass_lookup: AtomicOnceCell<FnvHashMap<MeshAdvBatchedPassKey, usize>>,
     pub batched_passes: AtomicOnceCell<Vec<MeshAdvBatchedPreparedPassInfo>>,
     pub per_batch_descriptor_sets: AtomicOnceCell<Vec<Option<DescriptorSetArc>>>,
@@ -159,6 +185,7 @@ pub struct MeshAdvPerViewSubmitData {
     pub depth_descriptor_set: Option<DescriptorSetArc>,
     pub wireframe_desriptor_set: Option<DescriptorSetArc>,
     pub shadow_map_atlas_depth_descriptor_set: Option<DescriptorSetArc>,
+    pub shadow_map_atlas_depth_alpha_descriptor_set: Option<DescriptorSetArc>,
 }
 
 pub enum MeshAdvDrawCall {
@@ -1,12 +1,15 @@
 use rafx::render_feature_extract_job_predule::*;
 
 use super::*;
+use crate::assets::anim::SkeletonPose;
 use crate::assets::mesh_adv::MeshAdvShaderPassIndices;
 use crate::components::{
-    DirectionalLightComponent, PointLightComponent, SpotLightComponent, TransformComponent,
+    DirectionalLightComponent, PointLightComponent, SkeletalAnimationComponent, SpotLightComponent,
+    TransformComponent,
 };
+use crate::phases::ShadowMapRenderPhase;
 use hydrate_base::handle::Handle;
-use legion::{Entity, IntoQuery, Read, World};
+use legion::{Entity, EntityStore, IntoQuery, Read, World};
 use rafx::assets::{AssetManagerExtractRef, AssetManagerRenderResource, MaterialAsset};
 use rafx::base::resource_map::ReadBorrow;
 use rafx::base::resource_ref_map::ResourceRefBorrow;
@@ -16,6 +19,8 @@ use rafx::renderer::InvalidResources;
 pub struct MeshAdvExtractJob<'extract> {
     world: ResourceRefBorrow<'extract, World>,
     mesh_render_options: Option<ResourceRefBorrow<'extract, MeshAdvRenderOptions>>,
+    lod_options: MeshAdvLodOptions,
+    lod_resource: ReadBorrow<'extract, MeshAdvLodRenderResource>,
     shadow_map_atlas: ReadBorrow<'extract, ShadowMapAtlas>,
     asset_manager: AssetManagerExtractRef,
     invalid_image_color: ResourceArc<ImageViewResource>,
@@ -34,12 +39,22 @@ impl<'extract> MeshAdvExtractJob<'extract> {
         shadow_map_atlas_depth_material: Handle<MaterialAsset>,
         render_objects: MeshAdvRenderObjectSet,
     ) -> Arc<dyn RenderFeatureExtractJob<'extract> + 'extract> {
+        let mesh_render_options = extract_context
+            .extract_resources
+            .try_fetch::<MeshAdvRenderOptions>();
+        let lod_options = mesh_render_options
+            .as_ref()
+            .map(|x| x.lod_options)
+            .unwrap_or_default();
+
         Arc::new(ExtractJob::new(
             Self {
                 world: extract_context.extract_resources.fetch::<World>(),
-                mesh_render_options: extract_context
-                    .extract_resources
-                    .try_fetch::<MeshAdvRenderOptions>(),
+                mesh_render_options,
+                lod_options,
+                lod_resource: extract_context
+                    .render_resources
+                    .fetch::<MeshAdvLodRenderResource>(),
                 asset_manager: extract_context
                     .render_resources
                     .fetch::<AssetManagerRenderResource>()
@@ -61,6 +76,25 @@ impl<'extract> MeshAdvExtractJob<'extract> {
     }
 }
 
+impl<'extract> MeshAdvExtractJob<'extract> {
+    fn extract_skeletal_pose(
+        &self,
+        object_id: ObjectId,
+    ) -> Option<MeshAdvSkeletalPose> {
+        let entry = self.world.entry_ref(object_id.into()).ok()?;
+        let animation = entry.get_component::<SkeletalAnimationComponent>().ok()?;
+        let anim = self.asset_manager.committed_asset(&animation.anim)?.clone();
+        if animation.clip_index >= anim.clip_count() {
+            return None;
+        }
+
+        let clip = anim.clip(animation.clip_index);
+        let frame = animation.frame(clip.max_frame());
+        let pose = SkeletonPose::sample(anim.skeleton(), clip, frame);
+        Some(MeshAdvSkeletalPose { anim, pose })
+    }
+}
+
 impl<'extract> ExtractJobEntryPoints<'extract> for MeshAdvExtractJob<'extract> {
     fn begin_per_frame_extract(
         &self,
@@ -105,9 +139,22 @@ impl<'extract> ExtractJobEntryPoints<'extract> for MeshAdvExtractJob<'extract> {
             .render_objects
             .get_id(context.render_object_id());
 
-        let mesh_asset = self
-            .asset_manager
-            .committed_asset(&render_object_static_data.mesh);
+        // Only the loaded LODs up to the first one that isn't loaded can be selected. LODs generated
+        // on import are coarser than any authored LOD, so only the ones generated from the last
+        // authored LOD follow it, and only once every authored LOD is loaded.
+        let mut lods: Vec<_> = render_object_static_data
+            .lods
+            .iter()
+            .map_while(|lod| self.asset_manager.committed_asset(lod))
+            .cloned()
+            .collect();
+
+        if lods.len() == render_object_static_data.lods.len() {
+            if let Some(last_lod) = lods.last() {
+                let generated_lods = last_lod.inner.generated_lods.clone();
+                lods.extend(generated_lods);
+            }
+        }
 
         let visibility_info = context.visibility_object_info();
         let transform = visibility_info.transform();
@@ -124,20 +171,87 @@ impl<'extract> ExtractJobEntryPoints<'extract> for MeshAdvExtractJob<'extract> {
             None
         };
 
-        context.set_render_object_instance_data(mesh_asset.and_then(|mesh_asset| {
+        // Only skinned meshes are posed, the joints are bound to bones in prepare
+        let is_skinned = lods.iter().any(|x| x.inner.asset_data.skin.is_some());
+        let skeletal_pose = if is_skinned {
+            self.extract_skeletal_pose(context.object_id())
+        } else {
+            None
+        };
+
+        context.set_render_object_instance_data(if lods.is_empty() {
+            None
+        } else {
             Some(MeshAdvRenderObjectInstanceData {
-                mesh_asset: mesh_asset.clone(),
+                lods,
                 transform,
                 previous_transform,
                 bounding_sphere,
+                skeletal_pose,
             })
-        }));
+        });
+    }
+
+    fn extract_render_object_instance_per_view(
+        &self,
+        _job_context: &mut DefaultJobContext,
+        context: &ExtractRenderObjectInstancePerViewContext<'extract, '_, Self>,
+    ) {
+        let selection = context
+            .render_object_instance_data()
+            .as_ref()
+            .and_then(|extracted_data| {
+                let (_, radius) = extracted_data.world_bounding_sphere()?;
+                let view = context.view();
+                let screen_size = mesh_adv_screen_size(
+                    &view.projection_matrix(),
+                    context.distance_from_view_frustum(),
+                    radius,
+                );
+                let previous_selection = self.lod_resource.previous_selection(
+                    view,
+                    context.object_id(),
+                    *context.render_object_id(),
+                );
+
+                // Shadow map passes don't support dithering so they always switch immediately
+                let allow_cross_fade = !view.phase_is_relevant::<ShadowMapRenderPhase>();
+
+                Some(MeshAdvLodSelection::select(
+                    &self.lod_options,
+                    extracted_data.lods.len(),
+                    screen_size,
+                    previous_selection.as_ref(),
+                    allow_cross_fade,
+                ))
+            })
+            .unwrap_or_default();
+
+        context.set_render_object_instance_per_view_data(selection);
     }
 
     fn end_per_view_extract(
         &self,
         context: &ExtractPerViewContext<'extract, '_, Self>,
     ) {
+        let view_packet = context.view_packet();
+        self.lod_resource.store_selections(
+            context.view(),
+            view_packet
+                .render_object_instances()
+                .iter()
+                .enumerate()
+                .map(|(index, render_object_instance_per_view)| {
+                    let render_object_instance =
+                        &render_object_instance_per_view.render_object_instance;
+                    (
+                        render_object_instance.object_id,
+                        render_object_instance.render_object_id,
+                        *view_packet.render_object_instances_data().get(index),
+                    )
+                }),
+        );
+
         let mut per_view = MeshAdvPerViewData::default();
         let is_lit = !context
             .view()
@@ -181,10 +295,12 @@ impl<'extract> ExtractJobEntryPoints<'extract> for MeshAdvExtractJob<'extract> {
 
         if let Some(mesh_render_options) = &self.mesh_render_options {
             per_view.ambient_light = mesh_render_options.ambient_light;
+            per_view.ibl_intensity = mesh_render_options.ibl_intensity;
             per_view.ndf_filter_amount = mesh_render_options.ndf_filter_amount;
             per_view.use_clustered_lighting = mesh_render_options.use_clustered_lighting;
         } else {
             per_view.ambient_light = glam::Vec3::ZERO;
+            per_view.ibl_intensity = 1.0;
             per_view.ndf_filter_amount = 1.0;
             per_view.use_clustered_lighting = true;
         }
@@ -192,6 +308,16 @@ impl<'extract> ExtractJobEntryPoints<'extract> for MeshAdvExtractJob<'extract> {
         context.view_packet().per_view_data().set(per_view);
     }
 
+    fn end_per_frame_extract(
+        &self,
+        context: &ExtractPerFrameContext<'extract, '_, Self>,
+    ) {
+        if let Some(view_packet) = context.frame_packet().view_packets().first() {
+            self.lod_resource
+                .remove_stale_selections(view_packet.view().frame_index());
+        }
+    }
+
     fn feature_debug_constants(&self) -> &'static RenderFeatureDebugConstants {
         super::render_feature_debug_constants()
     }
@@ -206,6 +332,12 @@ impl<'extract> ExtractJobEntryPoints<'extract> for MeshAdvExtractJob<'extract> {
         Some(RenderObjectsJobContext::new(self.render_objects.read()))
     }
 
+    fn new_render_object_instance_per_view_job_context(
+        &'extract self
+    ) -> Option<Self::RenderObjectInstancePerViewJobContextT> {
+        Some(DefaultJobContext::new())
+    }
+
     type RenderObjectInstanceJobContextT = RenderObjectsJobContext<'extract, MeshAdvRenderObject>;
     type RenderObjectInstancePerViewJobContextT = DefaultJobContext;
 
@@ -14,28 +14,46 @@ use rafx::framework::{
 };
 
 use crate::shaders::mesh_adv::{
-    mesh_adv_textured_frag, mesh_adv_wireframe_vert, mesh_culling_comp,
+    mesh_adv_textured_frag, mesh_adv_wireframe_vert, mesh_culling_comp, mesh_skinning_comp,
 };
 use glam::Mat4;
 use rafx::api::{RafxBufferDef, RafxDrawIndexedIndirectCommand, RafxMemoryUsage, RafxResourceType};
 
 use crate::assets::mesh_adv::material_db::MaterialDB;
-use crate::assets::mesh_adv::{MeshAdvAssetPart, MeshAdvBlendMethod, MeshAdvShaderPassIndices};
+use crate::assets::mesh_adv::{
+    MeshAdvAssetPart, MeshAdvBindlessBuffers, MeshAdvBlendMethod, MeshAdvShaderPassIndices,
+    MeshAdvShadowMethod,
+};
+use crate::components::MAX_DIRECTIONAL_LIGHT_CASCADES;
 use crate::features::mesh_adv::gpu_occlusion_cull::{
     MeshAdvGpuOcclusionCullRenderResource, OcclusionJob,
 };
-use crate::features::mesh_adv::light_binning::MeshAdvLightBinRenderResource;
+use crate::features::mesh_adv::light_binning::{
+    MeshAdvLightBinList, MeshAdvLightBinRenderResource,
+};
 use crate::shaders::depth_velocity::depth_velocity_vert;
 use crate::shaders::mesh_adv::lights_bin_comp;
 use crate::shaders::mesh_adv::mesh_adv_textured_frag::LightInListStd430;
-use crate::shaders::mesh_adv::shadow_atlas_depth_vert;
+use crate::shaders::mesh_adv::{shadow_atlas_depth_alpha_vert, shadow_atlas_depth_vert};
 use mesh_adv_textured_frag::PerViewDataUniform as MeshPerViewFragmentShaderParam;
 use rafx::assets::MaterialAsset;
-use rafx::renderer::MainViewRenderResource;
+use rafx::renderer::{InvalidResources, MainViewRenderResource};
 
 const PER_VIEW_DESCRIPTOR_SET_INDEX: u32 =
     mesh_adv_textured_frag::PER_VIEW_DATA_DESCRIPTOR_SET_INDEX as u32;
 
+// Where the skinning pass writes the vertices of a skinned mesh part this frame
+#[derive(Copy, Clone)]
+struct MeshAdvSkinnedPartVertices {
+    vertex_full_buffer_offset_in_bytes: u32,
+    vertex_position_buffer_offset_in_bytes: u32,
+}
+
+// Keyed by render object instance and LOD, with an entry per mesh part of the LOD. Mesh parts
+// without an entry are drawn unskinned.
+type MeshAdvSkinnedMeshLookup =
+    FnvHashMap<(RenderObjectInstanceId, usize), Vec<Option<MeshAdvSkinnedPartVertices>>>;
+
 pub struct MeshAdvPrepareJob<'prepare> {
     #[allow(dead_code)]
     requires_textured_descriptor_sets: bool,
@@ -47,9 +65,12 @@ pub struct MeshAdvPrepareJob<'prepare> {
     shadow_map_atlas_depth_material_pass: Option<ResourceArc<MaterialPassResource>>,
     shadow_map_data: ReadBorrow<'prepare, MeshAdvShadowMapResource>,
     light_bin_resource: WriteBorrow<'prepare, MeshAdvLightBinRenderResource>,
+    reflection_probe_resource: ReadBorrow<'prepare, MeshAdvReflectionProbeResource>,
     main_view_resource: ReadBorrow<'prepare, MainViewRenderResource>,
     pipeline_state: ReadBorrow<'prepare, MeshAdvRenderPipelineState>,
+    invalid_resources: ReadBorrow<'prepare, InvalidResources>,
     material_db: ReadBorrow<'prepare, MaterialDB>,
+    bindless_buffers: ReadBorrow<'prepare, MeshAdvBindlessBuffers>,
     render_object_instance_transforms: Arc<AtomicOnceCellStack<MeshModelMatrix>>,
     render_object_instance_transforms_with_history:
         Arc<AtomicOnceCellStack<MeshModelMatrixWithHistory>>,
@@ -59,6 +80,7 @@ pub struct MeshAdvPrepareJob<'prepare> {
     render_objects: MeshAdvRenderObjectSet,
     batched_pass_lookup: AtomicOnceCell<FnvHashMap<MeshAdvBatchedPassKey, usize>>,
     batched_passes: AtomicOnceCell<Vec<MeshAdvBatchedPassInfo>>,
+    skinned_meshes: AtomicOnceCell<MeshAdvSkinnedMeshLookup>,
 }
 
 impl<'prepare> MeshAdvPrepareJob<'prepare> {
@@ -121,6 +143,11 @@ impl<'prepare> MeshAdvPrepareJob<'prepare> {
                         .render_resources
                         .fetch_mut::<MeshAdvLightBinRenderResource>()
                 },
+                reflection_probe_resource: {
+                    prepare_context
+                        .render_resources
+                        .fetch::<MeshAdvReflectionProbeResource>()
+                },
                 main_view_resource: {
                     prepare_context
                         .render_resources
@@ -131,12 +158,19 @@ impl<'prepare> MeshAdvPrepareJob<'prepare> {
                         .render_resources
                         .fetch::<MeshAdvRenderPipelineState>()
                 },
+                invalid_resources: { prepare_context.render_resources.fetch::<InvalidResources>() },
                 material_db: { prepare_context.render_resources.fetch::<MaterialDB>() },
+                bindless_buffers: {
+                    prepare_context
+                        .render_resources
+                        .fetch::<MeshAdvBindlessBuffers>()
+                },
                 requires_textured_descriptor_sets,
                 requires_untextured_descriptor_sets,
                 render_objects,
                 batched_pass_lookup: AtomicOnceCell::new(),
                 batched_passes: AtomicOnceCell::new(),
+                skinned_meshes: AtomicOnceCell::new(),
             },
             prepare_context,
             frame_packet,
@@ -217,6 +251,256 @@ impl<'prepare> MeshAdvPrepareJob<'prepare> {
             dyn_descriptor_set.descriptor_set().clone(),
         )
     }
+
+    // Binds the joints of every visible skinned mesh to its extracted pose and reserves space in
+    // the vertex heap for the skinning pass to write the skinned vertices to. The palettes and
+    // dispatches are handed to the skinning pass through MeshAdvSkinningRenderResource.
+    fn prepare_skinning(
+        &self,
+        context: &PreparePerFrameContext<'prepare, '_, Self>,
+    ) -> MeshAdvSkinnedMeshLookup {
+        let mut skinning_resource = context
+            .render_resources()
+            .fetch_mut::<MeshAdvSkinningRenderResource>();
+        skinning_resource.jobs.clear();
+        skinning_resource.joint_palettes = None;
+
+        let (slice_offset, slice_size) =
+            skinning_resource.next_skinned_vertex_slice(&self.bindless_buffers);
+        let slice_end = slice_offset + slice_size;
+        let mut next_offset = slice_offset;
+        let mut out_of_space = false;
+
+        // Vertex offsets are in vertices, not bytes, so the allocation must be aligned to the vertex size
+        let mut allocate = |size_in_bytes: u32, vertex_size: u32| {
+            let offset = next_offset.div_ceil(vertex_size) * vertex_size;
+            if offset + size_in_bytes > slice_end {
+                out_of_space = true;
+                return None;
+            }
+
+            next_offset = offset + size_in_bytes;
+            Some(offset)
+        };
+
+        let mut skinned_meshes = MeshAdvSkinnedMeshLookup::default();
+        let mut joint_palettes = Vec::<Mat4>::default();
+        for view_packet in context.frame_packet().view_packets() {
+            for (object_instance_index, object_instance) in
+                view_packet.render_object_instances().iter().enumerate()
+            {
+                let render_object_instance_id = object_instance.render_object_instance_id;
+                let render_object_instance_data = context
+                    .frame_packet()
+                    .render_object_instances_data()
+                    .get(render_object_instance_id as usize)
+                    .as_ref()
+                    .unwrap();
+
+                let skeletal_pose = match &render_object_instance_data.skeletal_pose {
+                    Some(skeletal_pose) => skeletal_pose,
+                    None => continue,
+                };
+
+                let lod_selection = view_packet
+                    .render_object_instances_data()
+                    .get(object_instance_index);
+                for lod in std::iter::once(lod_selection.lod).chain(lod_selection.fade_from_lod) {
+                    // Skin each LOD once even if it is visible in several views
+                    let key = (render_object_instance_id, lod);
+                    if skinned_meshes.contains_key(&key) {
+                        continue;
+                    }
+
+                    let mesh = &render_object_instance_data.lods[lod];
+                    let skin = match &mesh.inner.asset_data.skin {
+                        Some(skin) => skin,
+                        None => continue,
+                    };
+
+                    // Joints without a matching bone stay in their bind pose
+                    let joint_palette_first_index = joint_palettes.len() as u32;
+                    for (joint_name, inverse_bind_matrix) in
+                        skin.joint_names.iter().zip(&skin.inverse_bind_matrices)
+                    {
+                        let joint_palette = skeletal_pose
+                            .anim
+                            .bone_index(joint_name)
+                            .map(|bone_index| {
+                                skeletal_pose.pose.bone_transforms[bone_index]
+                                    * Mat4::from_cols_array(inverse_bind_matrix)
+                            })
+                            .unwrap_or(Mat4::IDENTITY);
+                        joint_palettes.push(joint_palette);
+                    }
+
+                    let mut skinned_parts = Vec::with_capacity(mesh.inner.mesh_parts.len());
+                    for mesh_part in &mesh.inner.mesh_parts {
+                        let vertex_skin_buffer_offset_in_bytes =
+                            match mesh_part.vertex_skin_buffer_offset_in_bytes {
+                                Some(offset) => offset,
+                                None => {
+                                    skinned_parts.push(None);
+                                    continue;
+                                }
+                            };
+
+                        let vertex_full_buffer_offset_in_bytes = allocate(
+                            mesh_part.vertex_full_buffer_size_in_bytes,
+                            std::mem::size_of::<MeshVertexFull>() as u32,
+                        );
+                        let vertex_position_buffer_offset_in_bytes = allocate(
+                            mesh_part.vertex_position_buffer_size_in_bytes,
+                            std::mem::size_of::<MeshVertexPosition>() as u32,
+                        );
+
+                        if let (
+                            Some(vertex_full_buffer_offset_in_bytes),
+                            Some(vertex_position_buffer_offset_in_bytes),
+                        ) = (
+                            vertex_full_buffer_offset_in_bytes,
+                            vertex_position_buffer_offset_in_bytes,
+                        ) {
+                            // The shader addresses the heap in 32-bit words
+                            skinning_resource
+                                .jobs
+                                .push(mesh_skinning_comp::ConfigUniform {
+                                    vertex_count: mesh_part.vertex_count(),
+                                    joint_palette_first_index,
+                                    vertex_full_input_offset: mesh_part
+                                        .vertex_full_buffer_offset_in_bytes
+                                        / 4,
+                                    vertex_skin_input_offset: vertex_skin_buffer_offset_in_bytes
+                                        / 4,
+                                    vertex_full_output_offset: vertex_full_buffer_offset_in_bytes
+                                        / 4,
+                                    vertex_position_output_offset:
+                                        vertex_position_buffer_offset_in_bytes / 4,
+                                    ..Default::default()
+                                });
+
+                            skinned_parts.push(Some(MeshAdvSkinnedPartVertices {
+                                vertex_full_buffer_offset_in_bytes,
+                                vertex_position_buffer_offset_in_bytes,
+                            }));
+                        } else {
+                            skinned_parts.push(None);
+                        }
+                    }
+
+                    skinned_meshes.insert(key, skinned_parts);
+                }
+            }
+        }
+
+        if out_of_space {
+            log::warn!(
+                "The skinned vertex region of the mesh vertex heap is full, some skinned meshes will be drawn in their bind pose"
+            );
+        }
+
+        if !joint_palettes.is_empty() {
+            let dyn_resource_allocator_set = context
+                .resource_context()
+                .create_dyn_resource_allocator_set();
+            let joint_palettes_buffer = context
+                .device_context()
+                .create_buffer(&RafxBufferDef {
+                    size: (joint_palettes.len() * std::mem::size_of::<Mat4>()) as u64,
+                    memory_usage: RafxMemoryUsage::CpuToGpu,
+                    resource_type: RafxResourceType::BUFFER_READ_WRITE,
+                    ..Default::default()
+                })
+                .unwrap();
+            joint_palettes_buffer
+                .copy_to_host_visible_buffer(&joint_palettes)
+                .unwrap();
+            skinning_resource.joint_palettes =
+                Some(dyn_resource_allocator_set.insert_buffer(joint_palettes_buffer));
+        }
+
+        skinned_meshes
+    }
+
+    // Adds uniform data for a spot light or directional light cascade shadow map
+    fn add_shadow_map_2d(
+        shadow_map_data: &MeshAdvShadowMapResource,
+        per_frame_submit_data: &mut MeshAdvPerFrameSubmitData,
+        shadow_view_index: ShadowViewIndex,
+    ) {
+        let shadow_assignment =
+            shadow_map_data.shadow_map_atlas_element_assignment(shadow_view_index);
+        let shadow_view = shadow_map_data.shadow_map_render_views_meta(shadow_view_index);
+
+        let num_shadow_map_2d = per_frame_submit_data.num_shadow_map_2d;
+        if num_shadow_map_2d >= MAX_SHADOW_MAPS_2D {
+            log::warn!(
+                "More 2D shadow maps than the mesh shader can support {}",
+                MAX_SHADOW_MAPS_2D
+            );
+            return;
+        }
+
+        let shadow_info = shadow_assignment.info();
+        per_frame_submit_data.shadow_map_2d_data[num_shadow_map_2d] =
+            mesh_adv_textured_frag::ShadowMap2DDataStd140 {
+                uv_min: shadow_info.uv_min.into(),
+                uv_max: shadow_info.uv_max.into(),
+                shadow_map_view_proj: shadow_view.view_proj.to_cols_array_2d(),
+                shadow_map_light_dir: shadow_view.view_dir.into(),
+                ..Default::default()
+            };
+
+        let old = per_frame_submit_data
+            .shadow_map_image_index_remap
+            .insert(shadow_view_index, num_shadow_map_2d);
+        assert!(old.is_none());
+
+        per_frame_submit_data.num_shadow_map_2d += 1;
+    }
+
+    // Returns the pass used to draw a mesh part into shadow maps, or None if it does not cast shadows
+    fn shadow_map_material_pass(
+        &self,
+        mesh_part: &MeshAdvAssetPart,
+    ) -> Option<ResourceArc<MaterialPassResource>> {
+        let alpha_pass_index = match mesh_part.mesh_material.material_data().shadow_method {
+            MeshAdvShadowMethod::None => return None,
+            MeshAdvShadowMethod::Opaque => {
+                return self.shadow_map_atlas_depth_material_pass.clone()
+            }
+            MeshAdvShadowMethod::AlphaClip => {
+                self.default_pbr_material_pass_indices.shadow_map_alpha_clip
+            }
+            MeshAdvShadowMethod::AlphaStochastic => {
+                self.default_pbr_material_pass_indices
+                    .shadow_map_alpha_stochastic
+            }
+        };
+
+        self.default_pbr_material
+            .get_material_pass_by_index(alpha_pass_index as usize)
+    }
+
+    // Light binning, reflection probes, jitter, occlusion culling and the G-buffer only cover the
+    // main view
+    fn is_main_view(
+        &self,
+        view: &RenderView,
+    ) -> bool {
+        self.main_view_resource
+            .main_view
+            .as_ref()
+            .map(|main_view| main_view.view_index() == view.view_index())
+            .unwrap_or(true)
+    }
+
+    fn is_shadow_map_alpha_pass(
+        &self,
+        pass: &ResourceArc<MaterialPassResource>,
+    ) -> bool {
+        Some(pass) != self.shadow_map_atlas_depth_material_pass.as_ref()
+    }
 }
 
 impl<'prepare> PrepareJobEntryPoints<'prepare> for MeshAdvPrepareJob<'prepare> {
@@ -247,13 +531,17 @@ impl<'prepare> PrepareJobEntryPoints<'prepare> for MeshAdvPrepareJob<'prepare> {
             }
         }
 
+        self.skinned_meshes.set(self.prepare_skinning(context));
+
         //
         // Determine how large our batches will be for anything that can be submitted as a single large batch
         // (anything that doesn't need to be sorted by depth)
         //
         let mut batched_pass_counts = FnvHashMap::<_, usize>::default();
         for view_packet in context.frame_packet().view_packets() {
-            for object_instance in view_packet.render_object_instances() {
+            for (object_instance_index, object_instance) in
+                view_packet.render_object_instances().iter().enumerate()
+            {
                 let render_object_instance_id = object_instance.render_object_instance_id as usize;
                 let render_object_instance_data = context
                     .frame_packet()
@@ -261,7 +549,19 @@ impl<'prepare> PrepareJobEntryPoints<'prepare> for MeshAdvPrepareJob<'prepare> {
                     .get(render_object_instance_id)
                     .as_ref()
                     .unwrap();
-                for mesh_part in &render_object_instance_data.mesh_asset.inner.mesh_parts {
+
+                // Both LODs are drawn while cross-fading
+                let lod_selection = view_packet
+                    .render_object_instances_data()
+                    .get(object_instance_index);
+                let drawn_lods =
+                    std::iter::once(lod_selection.lod).chain(lod_selection.fade_from_lod);
+                for mesh_part in drawn_lods.flat_map(|lod| {
+                    render_object_instance_data.lods[lod]
+                        .inner
+                        .mesh_parts
+                        .iter()
+                }) {
                     let is_transparent = mesh_part.mesh_material.material_data().blend_method
                         != MeshAdvBlendMethod::Opaque;
 
@@ -275,18 +575,16 @@ impl<'prepare> PrepareJobEntryPoints<'prepare> for MeshAdvPrepareJob<'prepare> {
                                 &mut batched_pass_counts,
                             );
                         }
+                    }
 
-                        if let Some(shadow_map_atlas_depth_material_pass) =
-                            &self.shadow_map_atlas_depth_material_pass
-                        {
-                            add_batched_pass_count(
-                                view_packet,
-                                mesh_part,
-                                ShadowMapRenderPhase::render_phase_index(),
-                                shadow_map_atlas_depth_material_pass,
-                                &mut batched_pass_counts,
-                            );
-                        }
+                    if let Some(shadow_map_pass) = self.shadow_map_material_pass(mesh_part) {
+                        add_batched_pass_count(
+                            view_packet,
+                            mesh_part,
+                            ShadowMapRenderPhase::render_phase_index(),
+                            &shadow_map_pass,
+                            &mut batched_pass_counts,
+                        );
                     }
 
                     let wireframe_pass = self
@@ -317,7 +615,13 @@ impl<'prepare> PrepareJobEntryPoints<'prepare> for MeshAdvPrepareJob<'prepare> {
                     };
 
                     let pass = mesh_part
-                        .get_material_pass_resource(view_packet.view(), render_phase_index)
+                        .get_material_pass_resource(
+                            view_packet.view(),
+                            render_phase_index,
+                            self.pipeline_state.use_order_independent_transparency,
+                            self.pipeline_state.write_gbuffer
+                                && self.is_main_view(view_packet.view()),
+                        )
                         .clone();
 
                     add_batched_pass_count(
@@ -374,6 +678,7 @@ impl<'prepare> PrepareJobEntryPoints<'prepare> for MeshAdvPrepareJob<'prepare> {
             model_matrix_buffer: Default::default(),
             model_matrix_with_history_buffer: Default::default(),
             all_materials_descriptor_set: Default::default(),
+            shadow_map_alpha_materials_descriptor_set: Default::default(),
             batched_pass_lookup: Default::default(),
             batched_passes: Default::default(),
             per_batch_descriptor_sets: Default::default(),
@@ -403,36 +708,20 @@ impl<'prepare> PrepareJobEntryPoints<'prepare> for MeshAdvPrepareJob<'prepare> {
             {
                 match shadow_view_indices {
                     MeshAdvShadowMapRenderViewIndices::Single(shadow_view_index) => {
-                        let shadow_assignment =
-                            shadow_map_data.shadow_map_atlas_element_assignment(*shadow_view_index);
-                        let shadow_view =
-                            shadow_map_data.shadow_map_render_views_meta(*shadow_view_index);
-
-                        let num_shadow_map_2d = per_frame_submit_data.num_shadow_map_2d;
-                        if num_shadow_map_2d >= MAX_SHADOW_MAPS_2D {
-                            log::warn!(
-                                "More 2D shadow maps than the mesh shader can support {}",
-                                MAX_SHADOW_MAPS_2D
+                        Self::add_shadow_map_2d(
+                            shadow_map_data,
+                            &mut per_frame_submit_data,
+                            *shadow_view_index,
+                        );
+                    }
+                    MeshAdvShadowMapRenderViewIndices::Cascades(shadow_views) => {
+                        for shadow_view_index in shadow_views.iter().flatten() {
+                            Self::add_shadow_map_2d(
+                                shadow_map_data,
+                                &mut per_frame_submit_data,
+                                *shadow_view_index,
                             );
-                            continue;
                         }
-
-                        let shadow_info = shadow_assignment.info();
-                        per_frame_submit_data.shadow_map_2d_data[num_shadow_map_2d] =
-                            mesh_adv_textured_frag::ShadowMap2DDataStd140 {
-                                uv_min: shadow_info.uv_min.into(),
-                                uv_max: shadow_info.uv_max.into(),
-                                shadow_map_view_proj: shadow_view.view_proj.to_cols_array_2d(),
-                                shadow_map_light_dir: shadow_view.view_dir.into(),
-                                ..Default::default()
-                            };
-
-                        let old = per_frame_submit_data
-                            .shadow_map_image_index_remap
-                            .insert(*shadow_view_index, num_shadow_map_2d);
-                        assert!(old.is_none());
-
-                        per_frame_submit_data.num_shadow_map_2d += 1;
                     }
                     MeshAdvShadowMapRenderViewIndices::Cube(shadow_views) => {
                         let num_shadow_map_cube = per_frame_submit_data.num_shadow_map_cube;
@@ -531,19 +820,16 @@ impl<'prepare> PrepareJobEntryPoints<'prepare> for MeshAdvPrepareJob<'prepare> {
                 previous_model_matrix: previous_model,
             });
 
+        //TODO: Do this in the compute shader if possible. For now do it on CPU so I know it
+        // matches frustum culling
         let bounding_sphere = extracted_data
-            .bounding_sphere
-            .map(|x| {
-                //TODO: Do this in the compute shader if possible. For now do it on CPU so I know it
-                // matches frustum culling
-                let t = extracted_data.transform;
-                let position = t.translation + t.rotation.mul(x.position) * t.scale;
-                let radius = x.radius * t.scale.abs().max_element();
-                mesh_culling_comp::BoundingSphereBuffer {
+            .world_bounding_sphere()
+            .map(
+                |(position, radius)| mesh_culling_comp::BoundingSphereBuffer {
                     position: position.into(),
                     radius: radius,
-                }
-            })
+                },
+            )
             .unwrap_or_else(|| mesh_culling_comp::BoundingSphereBuffer {
                 position: [0.0, 0.0, 0.0],
                 radius: -1.0,
@@ -593,7 +879,9 @@ impl<'prepare> PrepareJobEntryPoints<'prepare> for MeshAdvPrepareJob<'prepare> {
                 batched_pass_lookup: &AtomicOnceCell<FnvHashMap<MeshAdvBatchedPassKey, usize>>,
                 model_matrix_offset: usize,
                 mesh_part_material_index: u32,
+                lod_fade: f32,
                 use_full_vertices: bool,
+                skinned_vertices: Option<MeshAdvSkinnedPartVertices>,
             ) -> PushDrawDataResult {
                 let batch_key = MeshAdvBatchedPassKey {
                     phase: render_phase_index,
@@ -608,15 +896,20 @@ impl<'prepare> PrepareJobEntryPoints<'prepare> for MeshAdvPrepareJob<'prepare> {
                 let batched_pass = &batched_passes.get()[batch_index];
                 let draw_data_index = batched_pass.draw_data.len() as u32;
 
+                // Skinned mesh parts are drawn from the vertices written by the skinning pass
                 let (vertex_size, vertex_buffer_offset_in_bytes) = if use_full_vertices {
                     (
                         std::mem::size_of::<MeshVertexFull>() as u32,
-                        mesh_part.vertex_full_buffer_offset_in_bytes,
+                        skinned_vertices
+                            .map(|x| x.vertex_full_buffer_offset_in_bytes)
+                            .unwrap_or(mesh_part.vertex_full_buffer_offset_in_bytes),
                     )
                 } else {
                     (
                         std::mem::size_of::<MeshVertexPosition>() as u32,
-                        mesh_part.vertex_position_buffer_offset_in_bytes,
+                        skinned_vertices
+                            .map(|x| x.vertex_position_buffer_offset_in_bytes)
+                            .unwrap_or(mesh_part.vertex_position_buffer_offset_in_bytes),
                     )
                 };
 
@@ -633,6 +926,7 @@ impl<'prepare> PrepareJobEntryPoints<'prepare> for MeshAdvPrepareJob<'prepare> {
                     index_offset: mesh_part.index_buffer_offset_in_bytes / index_size_in_bytes,
                     transform_index: model_matrix_offset as u32,
                     vertex_offset,
+                    lod_fade,
                 });
 
                 PushDrawDataResult {
@@ -642,47 +936,61 @@ impl<'prepare> PrepareJobEntryPoints<'prepare> for MeshAdvPrepareJob<'prepare> {
             }
 
             //
-            // Iterate all mesh parts and push draw calls into batches. Additionally push submit nodes
-            // for transparent meshes as we need to sort these by depth and draw them individually
+            // Iterate all mesh parts of the selected LOD (and the LOD being faded out, if any) and
+            // push draw calls into batches. Additionally push submit nodes for transparent meshes as
+            // we need to sort these by depth and draw them individually
             //
-            for (mesh_part_index, mesh_part) in extracted_data
-                .mesh_asset
-                .inner
-                .mesh_parts
-                .iter()
-                .enumerate()
+            let lod_selection = context.render_object_instance_per_view_data();
+            let drawn_lods = std::iter::once((lod_selection.lod, lod_selection.lod_fade())).chain(
+                lod_selection
+                    .fade_from_lod
+                    .map(|lod| (lod, lod_selection.fade_from_lod_fade())),
+            );
+            for (lod, lod_fade, (mesh_part_index, mesh_part)) in
+                drawn_lods.flat_map(|(lod, lod_fade)| {
+                    extracted_data.lods[lod]
+                        .inner
+                        .mesh_parts
+                        .iter()
+                        .enumerate()
+                        .map(move |mesh_part| (lod, lod_fade, mesh_part))
+                })
             {
+                let skinned_vertices = self
+                    .skinned_meshes
+                    .get()
+                    .get(&(render_object_instance_id, lod))
+                    .and_then(|skinned_parts| skinned_parts[mesh_part_index]);
+
                 let is_transparent = mesh_part.mesh_material.material_data().blend_method
                     != MeshAdvBlendMethod::Opaque;
                 let mesh_part_material_index =
                     mesh_part.mesh_material.inner.material.material_data_index();
 
-                if !is_transparent {
-                    if view.phase_is_relevant::<DepthPrepassRenderPhase>()
-                        && self.depth_material_pass.is_some()
-                    {
-                        let pass = self.depth_material_pass.as_ref().unwrap().clone();
-                        push_draw_data(
-                            context.view_frame_index(),
-                            DepthPrepassRenderPhase::render_phase_index(),
-                            pass,
-                            mesh_part,
-                            &self.batched_passes,
-                            &self.batched_pass_lookup,
-                            model_matrix_offset,
-                            mesh_part_material_index,
-                            false,
-                        );
-                    }
+                if !is_transparent
+                    && view.phase_is_relevant::<DepthPrepassRenderPhase>()
+                    && self.depth_material_pass.is_some()
+                {
+                    let pass = self.depth_material_pass.as_ref().unwrap().clone();
+                    push_draw_data(
+                        context.view_frame_index(),
+                        DepthPrepassRenderPhase::render_phase_index(),
+                        pass,
+                        mesh_part,
+                        &self.batched_passes,
+                        &self.batched_pass_lookup,
+                        model_matrix_offset,
+                        mesh_part_material_index,
+                        lod_fade,
+                        false,
+                        skinned_vertices,
+                    );
+                }
 
-                    if view.phase_is_relevant::<ShadowMapRenderPhase>()
-                        && self.shadow_map_atlas_depth_material_pass.is_some()
-                    {
-                        let pass = self
-                            .shadow_map_atlas_depth_material_pass
-                            .as_ref()
-                            .unwrap()
-                            .clone();
+                if view.phase_is_relevant::<ShadowMapRenderPhase>() {
+                    if let Some(pass) = self.shadow_map_material_pass(mesh_part) {
+                        // Alpha shadow casters need UVs to sample the base color texture
+                        let use_full_vertices = self.is_shadow_map_alpha_pass(&pass);
                         push_draw_data(
                             context.view_frame_index(),
                             ShadowMapRenderPhase::render_phase_index(),
@@ -692,7 +1000,9 @@ impl<'prepare> PrepareJobEntryPoints<'prepare> for MeshAdvPrepareJob<'prepare> {
                             &self.batched_pass_lookup,
                             model_matrix_offset,
                             mesh_part_material_index,
-                            false,
+                            lod_fade,
+                            use_full_vertices,
+                            skinned_vertices,
                         );
                     }
                 }
@@ -705,7 +1015,12 @@ impl<'prepare> PrepareJobEntryPoints<'prepare> for MeshAdvPrepareJob<'prepare> {
 
                 if view.phase_index_is_relevant(phase_index) {
                     let material_pass_resource = mesh_part
-                        .get_material_pass_resource(view, phase_index)
+                        .get_material_pass_resource(
+                            view,
+                            phase_index,
+                            self.pipeline_state.use_order_independent_transparency,
+                            self.pipeline_state.write_gbuffer && self.is_main_view(view),
+                        )
                         .clone();
 
                     let push_draw_data_result = push_draw_data(
@@ -717,7 +1032,9 @@ impl<'prepare> PrepareJobEntryPoints<'prepare> for MeshAdvPrepareJob<'prepare> {
                         &self.batched_pass_lookup,
                         model_matrix_offset,
                         mesh_part_material_index,
+                        lod_fade,
                         true,
+                        skinned_vertices,
                     );
 
                     //
@@ -764,7 +1081,9 @@ impl<'prepare> PrepareJobEntryPoints<'prepare> for MeshAdvPrepareJob<'prepare> {
                         &self.batched_pass_lookup,
                         model_matrix_offset,
                         mesh_part_material_index,
+                        lod_fade,
                         false,
+                        skinned_vertices,
                     );
                 }
             }
@@ -785,6 +1104,14 @@ impl<'prepare> PrepareJobEntryPoints<'prepare> for MeshAdvPrepareJob<'prepare> {
         let view = context.view();
         let is_lit = !view.feature_flag_is_relevant::<MeshAdvUnlitRenderFeatureFlag>();
         let has_shadows = !view.feature_flag_is_relevant::<MeshAdvNoShadowsRenderFeatureFlag>();
+        // Light binning and reflection probes only cover the main view. Other views that draw lit
+        // meshes (i.e. reflection probe captures) loop over all lights instead and are not jittered.
+        let is_main_view = self.is_main_view(view);
+        let jitter_amount = if is_main_view {
+            self.pipeline_state.jitter_amount
+        } else {
+            glam::Vec2::ZERO
+        };
 
         let opaque_descriptor_set = if view.phase_is_relevant::<OpaqueRenderPhase>()
             || view.phase_is_relevant::<TransparentRenderPhase>()
@@ -816,16 +1143,42 @@ impl<'prepare> PrepareJobEntryPoints<'prepare> for MeshAdvPrepareJob<'prepare> {
                 } else {
                     glam::Vec4::ONE.into()
                 };
-                per_view_frag_data.use_clustered_lighting = if per_view_data.use_clustered_lighting
-                {
-                    1
-                } else {
-                    0
+                // An intensity of 0 makes the shader fall back to the flat ambient light
+                per_view_frag_data.ibl_intensity = match &self.pipeline_state.ibl {
+                    Some(_) if is_lit => per_view_data.ibl_intensity,
+                    _ => 0.0,
                 };
+                per_view_frag_data.ibl_specular_max_lod = self
+                    .pipeline_state
+                    .ibl
+                    .as_ref()
+                    .map(|x| x.specular_mip_count.saturating_sub(1) as f32)
+                    .unwrap_or(0.0);
+                per_view_frag_data.reflection_probe_max_lod =
+                    (REFLECTION_PROBE_MIP_COUNT - 1) as f32;
+                per_view_frag_data.use_clustered_lighting =
+                    if per_view_data.use_clustered_lighting && is_main_view {
+                        1
+                    } else {
+                        0
+                    };
                 per_view_frag_data.viewport_width = view.extents_width();
                 per_view_frag_data.viewport_height = view.extents_height();
-                per_view_frag_data.jitter_amount = self.pipeline_state.jitter_amount.into();
-                per_view_frag_data.mip_bias = self.pipeline_state.forward_pass_mip_bias;
+                per_view_frag_data.jitter_amount = jitter_amount.into();
+                per_view_frag_data.mip_bias = if is_main_view {
+                    self.pipeline_state.forward_pass_mip_bias
+                } else {
+                    0.0
+                };
+                // The pipeline only builds the fog volume for the main view
+                per_view_frag_data.volumetric_fog_max_distance = if is_main_view && is_lit {
+                    self.pipeline_state.volumetric_fog_max_distance
+                } else {
+                    0.0
+                };
+                // Decals are likewise only rendered for the main view
+                per_view_frag_data.enable_decals =
+                    (is_main_view && self.pipeline_state.enable_decals) as u32;
 
                 let mut light_bounds_data = lights_bin_comp::LightsInputListBuffer {
                     light_count: 0,
@@ -842,12 +1195,34 @@ impl<'prepare> PrepareJobEntryPoints<'prepare> for MeshAdvPrepareJob<'prepare> {
                         break;
                     }
 
-                    let shadow_map_index = shadow_map_data
-                        .shadow_map_lookup_by_light_id
-                        .get(&MeshAdvLightId::DirectionalLight(light.object_id))
-                        .map(|x| {
-                            per_frame_submit_data.shadow_map_image_index_remap[&x.unwrap_single()]
-                        });
+                    // Cascades that did not get a shadow map (or overflowed the shader's list of
+                    // shadow maps) are left at -1 and are treated as unshadowed
+                    let mut cascade_shadow_maps = [-1; MAX_DIRECTIONAL_LIGHT_CASCADES];
+                    if has_shadows {
+                        if let Some(MeshAdvShadowMapRenderViewIndices::Cascades(shadow_views)) =
+                            shadow_map_data
+                                .shadow_map_lookup_by_light_id
+                                .get(&MeshAdvLightId::DirectionalLight(light.object_id))
+                        {
+                            for (cascade_shadow_map, shadow_view_index) in
+                                cascade_shadow_maps.iter_mut().zip(shadow_views.iter())
+                            {
+                                if let Some(index) = shadow_view_index.and_then(|x| {
+                                    per_frame_submit_data.shadow_map_image_index_remap.get(&x)
+                                }) {
+                                    *cascade_shadow_map = *index as i32;
+                                }
+                            }
+                        }
+                    }
+
+                    let mut cascade_split_distances = [0.0; MAX_DIRECTIONAL_LIGHT_CASCADES];
+                    for (split_distance, &x) in cascade_split_distances
+                        .iter_mut()
+                        .zip(&shadow_map_data.shadow_cascade_split_distances)
+                    {
+                        *split_distance = x;
+                    }
 
                     let light_from = glam::Vec3::ZERO;
                     let light_from_vs = (view.view_matrix() * light_from.extend(1.0)).truncate();
@@ -862,11 +1237,10 @@ impl<'prepare> PrepareJobEntryPoints<'prepare> for MeshAdvPrepareJob<'prepare> {
                     out.direction_vs = light_direction_vs.into();
                     out.color = light.light.color.into();
                     out.intensity = light.light.intensity;
-                    out.shadow_map = if has_shadows {
-                        shadow_map_index.map(|x| x as i32).unwrap_or(-1)
-                    } else {
-                        -1
-                    };
+                    out.cascade_count = shadow_map_data.shadow_cascade_split_distances.len() as u32;
+                    out.cascade_shadow_maps = cascade_shadow_maps;
+                    out.cascade_split_distances = cascade_split_distances;
+                    out.cascade_blend_fraction = shadow_map_data.shadow_cascade_blend_fraction;
 
                     per_view_frag_data.directional_light_count += 1;
                 }
@@ -971,18 +1345,95 @@ impl<'prepare> PrepareJobEntryPoints<'prepare> for MeshAdvPrepareJob<'prepare> {
                 per_view_frag_data.shadow_map_cube_data =
                     per_frame_submit_data.shadow_map_cube_data;
 
-                self.light_bin_resource
-                    .update_light_bounds(context.view().frame_index(), &light_bounds_data)
-                    .unwrap();
+                if is_main_view {
+                    self.light_bin_resource
+                        .update_light_bounds(
+                            MeshAdvLightBinList::Lights,
+                            context.view().frame_index(),
+                            &light_bounds_data,
+                        )
+                        .unwrap();
+
+                    if per_view_frag_data.volumetric_fog_max_distance > 0.0 {
+                        self.light_bin_resource
+                            .update_main_view_lights(
+                                context.view().frame_index(),
+                                &per_view_frag_data,
+                                &all_lights_buffer_data,
+                            )
+                            .unwrap();
+                    }
+                }
 
                 per_view_frag_data
             };
 
-            let all_lights_buffer = {
-                let dyn_resource_allocator_set = context
-                    .resource_context()
-                    .create_dyn_resource_allocator_set();
+            // Probes are not sampled while capturing a probe, the captured faces would otherwise
+            // reflect stale (or not yet captured) probes
+            let mut reflection_probes_buffer_data =
+                mesh_adv_textured_frag::ReflectionProbesBuffer {
+                    probe_count: 0,
+                    _padding0: Default::default(),
+                    data: [mesh_adv_textured_frag::ReflectionProbeStd430 {
+                        box_min_ws: Default::default(),
+                        blend_distance: Default::default(),
+                        box_max_ws: Default::default(),
+                        intensity: Default::default(),
+                        position_ws: Default::default(),
+                        first_layer: Default::default(),
+                    }; MAX_REFLECTION_PROBES],
+                };
 
+            if is_main_view {
+                let mut probe_bounds_data = lights_bin_comp::LightsInputListBuffer {
+                    light_count: 0,
+                    _padding0: Default::default(),
+                    lights: [lights_bin_comp::LightStd430 {
+                        position: [0.0, 0.0, 0.0],
+                        radius: 0.0,
+                    }; 512],
+                };
+
+                let probes = if is_lit {
+                    self.reflection_probe_resource.probes()
+                } else {
+                    &[]
+                };
+
+                for probe in probes {
+                    let position_vs = (view.view_matrix() * probe.position.extend(1.0)).truncate();
+                    probe_bounds_data.lights[probe_bounds_data.light_count as usize] =
+                        lights_bin_comp::LightBuffer {
+                            position: position_vs.into(),
+                            radius: probe.half_extents.length(),
+                        };
+                    probe_bounds_data.light_count += 1;
+
+                    let out = &mut reflection_probes_buffer_data.data
+                        [reflection_probes_buffer_data.probe_count as usize];
+                    out.box_min_ws = (probe.position - probe.half_extents).into();
+                    out.box_max_ws = (probe.position + probe.half_extents).into();
+                    out.position_ws = probe.position.into();
+                    out.blend_distance = probe.blend_distance;
+                    out.intensity = probe.intensity;
+                    out.first_layer = (probe.slot * 6) as u32;
+                    reflection_probes_buffer_data.probe_count += 1;
+                }
+
+                self.light_bin_resource
+                    .update_light_bounds(
+                        MeshAdvLightBinList::ReflectionProbes,
+                        context.view().frame_index(),
+                        &probe_bounds_data,
+                    )
+                    .unwrap();
+            }
+
+            let dyn_resource_allocator_set = context
+                .resource_context()
+                .create_dyn_resource_allocator_set();
+
+            let all_lights_buffer = {
                 let all_lights_buffer_size =
                     std::mem::size_of::<mesh_adv_textured_frag::AllLightsBuffer>();
                 let all_lights_buffer = context
@@ -1003,6 +1454,27 @@ impl<'prepare> PrepareJobEntryPoints<'prepare> for MeshAdvPrepareJob<'prepare> {
                 dyn_resource_allocator_set.insert_buffer(all_lights_buffer)
             };
 
+            let reflection_probes_buffer = {
+                let reflection_probes_buffer_size =
+                    std::mem::size_of::<mesh_adv_textured_frag::ReflectionProbesBuffer>();
+                let reflection_probes_buffer = context
+                    .device_context()
+                    .create_buffer(&RafxBufferDef {
+                        size: reflection_probes_buffer_size as u64,
+                        memory_usage: RafxMemoryUsage::CpuToGpu,
+                        //DX12TODO: Does not need to be BUFFER_READ_WRITE for other backends
+                        resource_type: RafxResourceType::BUFFER_READ_WRITE,
+                        ..Default::default()
+                    })
+                    .unwrap();
+
+                reflection_probes_buffer
+                    .copy_to_host_visible_buffer(&[reflection_probes_buffer_data])
+                    .unwrap();
+
+                dyn_resource_allocator_set.insert_buffer(reflection_probes_buffer)
+            };
+
             let shadow_map_atlas = context.per_frame_data().shadow_map_atlas.clone();
 
             // NOTE(dvd): This assumes that all opaque materials have the same per view descriptor set layout.
@@ -1026,15 +1498,54 @@ impl<'prepare> PrepareJobEntryPoints<'prepare> for MeshAdvPrepareJob<'prepare> {
                 mesh_adv_textured_frag::SHADOW_MAP_ATLAS_DESCRIPTOR_BINDING_INDEX as u32,
                 &shadow_map_atlas,
             );
+            let (ibl_irradiance_cubemap, ibl_specular_cubemap, ibl_brdf_lut) =
+                if let Some(ibl) = &self.pipeline_state.ibl {
+                    (
+                        &ibl.irradiance_cubemap,
+                        &ibl.specular_cubemap,
+                        &ibl.brdf_lut,
+                    )
+                } else {
+                    (
+                        &self.invalid_resources.invalid_cube_map_image_color,
+                        &self.invalid_resources.invalid_cube_map_image_color,
+                        &self.invalid_resources.invalid_image_color,
+                    )
+                };
+            dyn_descriptor_set.set_image(
+                mesh_adv_textured_frag::IBL_IRRADIANCE_CUBEMAP_DESCRIPTOR_BINDING_INDEX as u32,
+                ibl_irradiance_cubemap,
+            );
+            dyn_descriptor_set.set_image(
+                mesh_adv_textured_frag::IBL_SPECULAR_CUBEMAP_DESCRIPTOR_BINDING_INDEX as u32,
+                ibl_specular_cubemap,
+            );
+            dyn_descriptor_set.set_image(
+                mesh_adv_textured_frag::IBL_BRDF_LUT_DESCRIPTOR_BINDING_INDEX as u32,
+                ibl_brdf_lut,
+            );
             dyn_descriptor_set.set_buffer(
                 mesh_adv_textured_frag::LIGHT_BIN_OUTPUT_DESCRIPTOR_BINDING_INDEX as u32,
                 self.light_bin_resource
-                    .output_gpu_buffer(view.frame_index()),
+                    .output_gpu_buffer(MeshAdvLightBinList::Lights, view.frame_index()),
             );
             dyn_descriptor_set.set_buffer(
                 mesh_adv_textured_frag::ALL_LIGHTS_DESCRIPTOR_BINDING_INDEX as u32,
                 &all_lights_buffer,
             );
+            dyn_descriptor_set.set_buffer(
+                mesh_adv_textured_frag::REFLECTION_PROBE_BIN_OUTPUT_DESCRIPTOR_BINDING_INDEX as u32,
+                self.light_bin_resource
+                    .output_gpu_buffer(MeshAdvLightBinList::ReflectionProbes, view.frame_index()),
+            );
+            dyn_descriptor_set.set_buffer(
+                mesh_adv_textured_frag::REFLECTION_PROBES_DESCRIPTOR_BINDING_INDEX as u32,
+                &reflection_probes_buffer,
+            );
+            dyn_descriptor_set.set_image(
+                mesh_adv_textured_frag::REFLECTION_PROBE_CUBEMAPS_DESCRIPTOR_BINDING_INDEX as u32,
+                self.reflection_probe_resource.cubemap_array(),
+            );
             dyn_descriptor_set
                 .flush(&mut descriptor_set_allocator)
                 .unwrap();
@@ -1047,7 +1558,7 @@ impl<'prepare> PrepareJobEntryPoints<'prepare> for MeshAdvPrepareJob<'prepare> {
         // If we are rendering shadow maps to the shadow map atlas, make a descriptor set with uniform
         // data for this view
         //
-        let shadow_map_atlas_depth_descriptor_set = {
+        let (shadow_map_atlas_depth_descriptor_set, shadow_map_atlas_depth_alpha_descriptor_set) = {
             let atlas_info = self
                 .shadow_map_data
                 .shadow_map_atlas_element_info_for_view(view.view_index());
@@ -1082,16 +1593,47 @@ impl<'prepare> PrepareJobEntryPoints<'prepare> for MeshAdvPrepareJob<'prepare> {
                     .get_raw()
                     .descriptor_set_layouts[PER_VIEW_DESCRIPTOR_SET_INDEX as usize];
 
-                descriptor_set_allocator
+                let shadow_map_atlas_depth_descriptor_set = descriptor_set_allocator
                     .create_descriptor_set(
                         per_instance_descriptor_set_layout,
                         shadow_atlas_depth_vert::DescriptorSet0Args {
                             per_view_data: &per_view_data,
                         },
                     )
-                    .ok()
+                    .ok();
+
+                // The alpha clip and alpha stochastic passes share the same layout
+                let alpha_per_view_data = shadow_atlas_depth_alpha_vert::PerViewDataUniform {
+                    view: per_view_data.view,
+                    view_proj: per_view_data.view_proj,
+                    uv_min: per_view_data.uv_min,
+                    uv_max: per_view_data.uv_max,
+                };
+
+                let alpha_per_instance_descriptor_set_layout = &self
+                    .default_pbr_material
+                    .get_material_pass_by_index(
+                        self.default_pbr_material_pass_indices.shadow_map_alpha_clip as usize,
+                    )
+                    .unwrap()
+                    .get_raw()
+                    .descriptor_set_layouts[PER_VIEW_DESCRIPTOR_SET_INDEX as usize];
+
+                let shadow_map_atlas_depth_alpha_descriptor_set = descriptor_set_allocator
+                    .create_descriptor_set(
+                        alpha_per_instance_descriptor_set_layout,
+                        shadow_atlas_depth_alpha_vert::DescriptorSet0Args {
+                            per_view_data: &alpha_per_view_data,
+                        },
+                    )
+                    .ok();
+
+                (
+                    shadow_map_atlas_depth_descriptor_set,
+                    shadow_map_atlas_depth_alpha_descriptor_set,
+                )
             } else {
-                None
+                (None, None)
             }
         };
 
@@ -1105,9 +1647,13 @@ impl<'prepare> PrepareJobEntryPoints<'prepare> for MeshAdvPrepareJob<'prepare> {
             per_view_data.current_view_proj_inv = view.view_proj().inverse().to_cols_array_2d();
             per_view_data.viewport_width = view.extents_width();
             per_view_data.viewport_height = view.extents_height();
-            per_view_data.jitter_amount = self.pipeline_state.jitter_amount.into();
+            per_view_data.jitter_amount = jitter_amount.into();
 
-            if let Some(previous_main_view_info) = &self.main_view_resource.previous_main_view_info
+            // Velocity is only meaningful for the main view
+            if !is_main_view {
+                per_view_data.previous_view_proj = per_view_data.current_view_proj;
+            } else if let Some(previous_main_view_info) =
+                &self.main_view_resource.previous_main_view_info
             {
                 let previous_view_proj =
                     previous_main_view_info.projection_matrix * previous_main_view_info.view_matrix;
@@ -1173,6 +1719,7 @@ impl<'prepare> PrepareJobEntryPoints<'prepare> for MeshAdvPrepareJob<'prepare> {
                 opaque_descriptor_set,
                 depth_descriptor_set,
                 shadow_map_atlas_depth_descriptor_set,
+                shadow_map_atlas_depth_alpha_descriptor_set,
                 wireframe_desriptor_set,
             });
     }
@@ -1265,6 +1812,11 @@ impl<'prepare> PrepareJobEntryPoints<'prepare> for MeshAdvPrepareJob<'prepare> {
             .all_materials_descriptor_set
             .borrow_mut();
 
+        let mut shadow_map_alpha_materials_descriptor_set = context
+            .per_frame_submit_data()
+            .shadow_map_alpha_materials_descriptor_set
+            .borrow_mut();
+
         let pbr_material_descriptor_layout = &self
             .default_pbr_material
             .get_material_pass_by_index(self.default_pbr_material_pass_indices.opaque as usize)
@@ -1273,6 +1825,16 @@ impl<'prepare> PrepareJobEntryPoints<'prepare> for MeshAdvPrepareJob<'prepare> {
             .descriptor_set_layouts
             [mesh_adv_textured_frag::ALL_MATERIALS_DESCRIPTOR_SET_INDEX as usize];
 
+        let shadow_map_alpha_material_descriptor_layout = &self
+            .default_pbr_material
+            .get_material_pass_by_index(
+                self.default_pbr_material_pass_indices.shadow_map_alpha_clip as usize,
+            )
+            .unwrap()
+            .get_raw()
+            .descriptor_set_layouts
+            [shadow_atlas_depth_alpha_vert::ALL_MATERIALS_DESCRIPTOR_SET_INDEX];
+
         let invalid_image_color = context
             .frame_packet()
             .per_frame_data()
@@ -1280,15 +1842,20 @@ impl<'prepare> PrepareJobEntryPoints<'prepare> for MeshAdvPrepareJob<'prepare> {
             .invalid_image_color
             .clone();
 
-        *all_materials_descriptor_set = Some(
-            self.material_db
-                .update_gpu_resources(
-                    context.resource_context(),
+        let mut material_descriptor_sets = self
+            .material_db
+            .update_gpu_resources(
+                context.resource_context(),
+                &[
                     pbr_material_descriptor_layout,
-                    &invalid_image_color,
-                )
-                .unwrap(),
-        );
+                    shadow_map_alpha_material_descriptor_layout,
+                ],
+                &invalid_image_color,
+            )
+            .unwrap()
+            .into_iter();
+        *all_materials_descriptor_set = material_descriptor_sets.next();
+        *shadow_map_alpha_materials_descriptor_set = material_descriptor_sets.next();
 
         //NOTE: We make indirect commands even for non-batched render nodes so that we can do GPU
         // culling with them
@@ -1352,6 +1919,7 @@ impl<'prepare> PrepareJobEntryPoints<'prepare> for MeshAdvPrepareJob<'prepare> {
                         |src| shadow_atlas_depth_vert::DrawDataBuffer {
                             transform_index: src.transform_index,
                             material_index: src.material_index,
+                            lod_fade: src.lod_fade,
                         },
                     ))
                 } else if batch.phase == DepthPrepassRenderPhase::render_phase_index() {
@@ -1369,6 +1937,7 @@ impl<'prepare> PrepareJobEntryPoints<'prepare> for MeshAdvPrepareJob<'prepare> {
                         |src| depth_velocity_vert::DrawDataBuffer {
                             transform_index: src.transform_index,
                             material_index: src.material_index,
+                            lod_fade: src.lod_fade,
                         },
                     ))
                 } else if batch.phase == OpaqueRenderPhase::render_phase_index()
@@ -1389,6 +1958,7 @@ impl<'prepare> PrepareJobEntryPoints<'prepare> for MeshAdvPrepareJob<'prepare> {
                         |src| mesh_adv_textured_frag::DrawDataBuffer {
                             transform_index: src.transform_index,
                             material_index: src.material_index,
+                            lod_fade: src.lod_fade,
                         },
                     ))
                 } else if batch.phase == WireframeRenderPhase::render_phase_index() {
@@ -1406,6 +1976,7 @@ impl<'prepare> PrepareJobEntryPoints<'prepare> for MeshAdvPrepareJob<'prepare> {
                         |src| mesh_adv_wireframe_vert::DrawDataBuffer {
                             transform_index: src.transform_index,
                             material_index: src.material_index,
+                            lod_fade: src.lod_fade,
                         },
                     ))
                 } else {
@@ -1495,6 +2066,12 @@ impl<'prepare> PrepareJobEntryPoints<'prepare> for MeshAdvPrepareJob<'prepare> {
                     .view_packet(pass_info.view_frame_index)
                     .view();
 
+                // The depth pyramid is built from the main view's depth buffer, so other views
+                // (i.e. reflection probe captures) can't be occlusion culled against it
+                if !self.is_main_view(view) {
+                    continue;
+                }
+
                 if let Some(draw_data_buffer) = &draw_data_buffers[batch_index] {
                     // Set indirect_buffer, all_transforms, and bounding_spheres_buffer volume buffers for later usage?
                     occlusion_cull_resource.data.push(OcclusionJob {
@@ -8,7 +8,7 @@ use crate::phases::{
 };
 use rafx::api::{RafxIndexBufferBinding, RafxVertexAttributeRate, RafxVertexBufferBinding};
 use rafx::api::{RafxIndexedIndirectCommandSignature, RafxPrimitiveTopology};
-use rafx::framework::{VertexDataLayout, VertexDataSetLayout};
+use rafx::framework::{MaterialPassResource, ResourceArc, VertexDataLayout, VertexDataSetLayout};
 use rafx::render_features::{BeginSubmitNodeBatchArgs, RenderSubmitNodeArgs};
 use serde::{Deserialize, Serialize};
 use std::marker::PhantomData;
@@ -30,6 +30,15 @@ pub struct MeshVertexPosition {
     pub position: [f32; 3],
 }
 
+/// Per-vertex skin weights for skinned meshes. These are only read by the skinning compute shader,
+/// which writes skinned MeshVertexFull/MeshVertexPosition vertices for the regular draws.
+#[derive(Copy, Clone, Debug, Serialize, Deserialize, Default)]
+#[repr(C)]
+pub struct MeshVertexSkin {
+    pub joint_indices: [u32; 4],
+    pub joint_weights: [f32; 4],
+}
+
 #[derive(Copy, Clone, Debug, Serialize, Deserialize, Default)]
 #[repr(C)]
 pub struct ShadowMapAtlasClearTileVertex {
@@ -89,9 +98,11 @@ pub struct MeshAdvWriteJob<'write> {
     _frame_packet: Box<MeshAdvFramePacket>,
     submit_packet: Box<MeshSubmitPacket>,
     buffer_heaps: MeshAdvBindlessBuffers,
+    shadow_map_atlas_depth_material_pass: Option<ResourceArc<MaterialPassResource>>,
     pbr_indirect_signature: RafxIndexedIndirectCommandSignature,
     wireframe_indirect_signature: RafxIndexedIndirectCommandSignature,
     shadow_indirect_signature: RafxIndexedIndirectCommandSignature,
+    shadow_alpha_indirect_signature: RafxIndexedIndirectCommandSignature,
     depth_indirect_signature: RafxIndexedIndirectCommandSignature,
     phantom: PhantomData<&'write ()>,
 }
@@ -111,13 +122,23 @@ impl<'write> MeshAdvWriteJob<'write> {
             .render_resources
             .fetch::<MeshAdvStaticResources>();
 
+        let shadow_map_atlas_depth_material_pass = frame_packet
+            .per_frame_data()
+            .get()
+            .shadow_map_atlas_depth_material_pass
+            .clone();
+
         Arc::new(Self {
             _frame_packet: frame_packet,
             submit_packet,
             buffer_heaps,
+            shadow_map_atlas_depth_material_pass,
             pbr_indirect_signature: static_resources.pbr_indirect_signature.clone(),
             wireframe_indirect_signature: static_resources.wireframe_indirect_signature.clone(),
             shadow_indirect_signature: static_resources.shadow_indirect_signature.clone(),
+            shadow_alpha_indirect_signature: static_resources
+                .shadow_alpha_indirect_signature
+                .clone(),
             depth_indirect_signature: static_resources.depth_indirect_signature.clone(),
             phantom: Default::default(),
         })
@@ -125,9 +146,23 @@ impl<'write> MeshAdvWriteJob<'write> {
 }
 
 impl<'write> MeshAdvWriteJob<'write> {
-    fn vertex_layout_for_phase_index(phase_index: RenderPhaseIndex) -> &'write VertexDataSetLayout {
-        if phase_index == OpaqueRenderPhase::render_phase_index()
-            || phase_index == TransparentRenderPhase::render_phase_index()
+    // Shadow casters that are not opaque are drawn with one of the alpha shadow map passes of the
+    // default PBR material. These sample the base color texture so they need UVs and material data.
+    fn is_shadow_map_alpha_batch(
+        &self,
+        batch: &MeshAdvBatchedPreparedPassInfo,
+    ) -> bool {
+        batch.phase == ShadowMapRenderPhase::render_phase_index()
+            && Some(&batch.pass) != self.shadow_map_atlas_depth_material_pass.as_ref()
+    }
+
+    fn vertex_layout_for_batch(
+        &self,
+        batch: &MeshAdvBatchedPreparedPassInfo,
+    ) -> &'write VertexDataSetLayout {
+        if batch.phase == OpaqueRenderPhase::render_phase_index()
+            || batch.phase == TransparentRenderPhase::render_phase_index()
+            || self.is_shadow_map_alpha_batch(batch)
         {
             &*MESH_FULL_LAYOUT
         } else {
@@ -144,39 +179,6 @@ impl<'write> MeshAdvWriteJob<'write> {
     ) -> RafxResult<()> {
         let per_view_submit_data = view_submit_packet.per_view_submit_data().get();
 
-        let (per_view_descriptor_set, bind_ssao_and_materials) =
-            if render_phase_index == ShadowMapRenderPhase::render_phase_index() {
-                let per_view_descriptor_set = per_view_submit_data
-                    .shadow_map_atlas_depth_descriptor_set
-                    .as_ref()
-                    .unwrap();
-
-                (per_view_descriptor_set, false)
-            } else if render_phase_index == DepthPrepassRenderPhase::render_phase_index() {
-                let per_view_descriptor_set =
-                    per_view_submit_data.depth_descriptor_set.as_ref().unwrap();
-
-                (per_view_descriptor_set, false)
-            } else if render_phase_index == WireframeRenderPhase::render_phase_index() {
-                let per_view_descriptor_set =
-                    per_view_submit_data.opaque_descriptor_set.as_ref().unwrap();
-
-                (per_view_descriptor_set, false)
-            } else if render_phase_index == OpaqueRenderPhase::render_phase_index()
-                || render_phase_index == TransparentRenderPhase::render_phase_index()
-            {
-                let per_view_descriptor_set =
-                    per_view_submit_data.opaque_descriptor_set.as_ref().unwrap();
-
-                (per_view_descriptor_set, true)
-            } else {
-                panic!("Tried to render meshes as batch in unsupported render phase");
-            };
-
-        let vertex_layout = Self::vertex_layout_for_phase_index(render_phase_index);
-
-        let command_buffer = &write_context.command_buffer;
-
         let batch = &self
             .submit_packet
             .per_frame_submit_data()
@@ -184,6 +186,47 @@ impl<'write> MeshAdvWriteJob<'write> {
             .batched_passes
             .get()[batch_index as usize];
 
+        let is_shadow_map_alpha_batch = self.is_shadow_map_alpha_batch(batch);
+
+        let (per_view_descriptor_set, bind_ssao_and_materials) = if is_shadow_map_alpha_batch {
+            let per_view_descriptor_set = per_view_submit_data
+                .shadow_map_atlas_depth_alpha_descriptor_set
+                .as_ref()
+                .unwrap();
+
+            (per_view_descriptor_set, false)
+        } else if render_phase_index == ShadowMapRenderPhase::render_phase_index() {
+            let per_view_descriptor_set = per_view_submit_data
+                .shadow_map_atlas_depth_descriptor_set
+                .as_ref()
+                .unwrap();
+
+            (per_view_descriptor_set, false)
+        } else if render_phase_index == DepthPrepassRenderPhase::render_phase_index() {
+            let per_view_descriptor_set =
+                per_view_submit_data.depth_descriptor_set.as_ref().unwrap();
+
+            (per_view_descriptor_set, false)
+        } else if render_phase_index == WireframeRenderPhase::render_phase_index() {
+            let per_view_descriptor_set =
+                per_view_submit_data.opaque_descriptor_set.as_ref().unwrap();
+
+            (per_view_descriptor_set, false)
+        } else if render_phase_index == OpaqueRenderPhase::render_phase_index()
+            || render_phase_index == TransparentRenderPhase::render_phase_index()
+        {
+            let per_view_descriptor_set =
+                per_view_submit_data.opaque_descriptor_set.as_ref().unwrap();
+
+            (per_view_descriptor_set, true)
+        } else {
+            panic!("Tried to render meshes as batch in unsupported render phase");
+        };
+
+        let vertex_layout = self.vertex_layout_for_batch(batch);
+
+        let command_buffer = &write_context.command_buffer;
+
         let per_batch_descriptor_set = self
             .submit_packet
             .per_frame_submit_data()
@@ -236,6 +279,18 @@ impl<'write> MeshAdvWriteJob<'write> {
             all_materials_descriptor_set.bind(command_buffer)?;
         }
 
+        if is_shadow_map_alpha_batch {
+            let shadow_map_alpha_materials_descriptor_set = (*self
+                .submit_packet
+                .per_frame_submit_data()
+                .get()
+                .shadow_map_alpha_materials_descriptor_set
+                .borrow())
+            .clone()
+            .unwrap();
+            shadow_map_alpha_materials_descriptor_set.bind(command_buffer)?;
+        }
+
         command_buffer.cmd_bind_index_buffer(&RafxIndexBufferBinding {
             buffer: &self.buffer_heaps.index.get_raw().buffer,
             byte_offset: 0,
@@ -255,10 +310,13 @@ impl<'write> MeshAdvWriteJob<'write> {
 
     fn indirect_signature(
         &self,
-        render_phase_index: RenderPhaseIndex,
+        batch: &MeshAdvBatchedPreparedPassInfo,
     ) -> &RafxIndexedIndirectCommandSignature {
+        let render_phase_index = batch.phase;
         if render_phase_index == WireframeRenderPhase::render_phase_index() {
             &self.wireframe_indirect_signature
+        } else if self.is_shadow_map_alpha_batch(batch) {
+            &self.shadow_alpha_indirect_signature
         } else if render_phase_index == ShadowMapRenderPhase::render_phase_index() {
             &self.shadow_indirect_signature
         } else if render_phase_index == DepthPrepassRenderPhase::render_phase_index() {
@@ -299,7 +357,7 @@ impl<'write> MeshAdvWriteJob<'write> {
             write_context.graph_context.device_context(),
         );
 
-        let indirect_signature = self.indirect_signature(render_phase_index);
+        let indirect_signature = self.indirect_signature(batch);
         indirect_signature.draw_indexed_indirect(
             &*command_buffer,
             &*indirect_buffer.get_raw().buffer,
@@ -349,7 +407,7 @@ impl<'write> MeshAdvWriteJob<'write> {
             write_context.graph_context.device_context(),
         );
 
-        let indirect_signature = self.indirect_signature(render_phase_index);
+        let indirect_signature = self.indirect_signature(batch);
         indirect_signature.draw_indexed_indirect(
             &*command_buffer,
             &*indirect_buffer.get_raw().buffer,
@@ -1,4 +1,4 @@
-use crate::shaders::mesh_adv::{lights_bin_comp, lights_build_lists_comp};
+use crate::shaders::mesh_adv::{lights_bin_comp, lights_build_lists_comp, mesh_adv_textured_frag};
 use rafx::api::{RafxBufferDef, RafxMemoryUsage, RafxQueueType, RafxResourceType};
 use rafx::framework::{
     BufferResource, ResourceArc, ResourceContext, ResourceLookupSet, MAX_FRAMES_IN_FLIGHT,
@@ -207,18 +207,35 @@ impl LightBinningFrustumAABBStructure {
     }
 }
 
-pub struct MeshAdvLightBinRenderResource {
-    frustum_structure: Option<LightBinningFrustumAABBStructure>,
-    // Unlikely to change often so we allocate/drop as needed
-    frustum_bounds_gpu_buffer: Option<ResourceArc<BufferResource>>,
+// Reflection probes are binned into clusters the same way as lights, using their bounding spheres.
+// Each list has its own input and output buffers.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum MeshAdvLightBinList {
+    Lights,
+    ReflectionProbes,
+}
+
+impl MeshAdvLightBinList {
+    fn debug_name(self) -> &'static str {
+        match self {
+            MeshAdvLightBinList::Lights => "Light Binning",
+            MeshAdvLightBinList::ReflectionProbes => "Reflection Probe Binning",
+        }
+    }
+}
+
+struct LightBinListBuffers {
     // Uploaded by CPU, so MAX_FRAMES_IN_FLIGHT + 1 buffers needed
     light_bounds_gpu_buffers: Vec<ResourceArc<BufferResource>>,
     // GPU-only buffers, MAX_FRAMES_IN_FLIGHT buffers needed
     output_gpu_buffers: Vec<ResourceArc<BufferResource>>,
 }
 
-impl MeshAdvLightBinRenderResource {
-    pub fn new(resources: &ResourceLookupSet) -> RafxResult<Self> {
+impl LightBinListBuffers {
+    fn new(
+        resources: &ResourceLookupSet,
+        list: MeshAdvLightBinList,
+    ) -> RafxResult<Self> {
         // One for CPU to write + GPU frames in flight
         let mut light_bounds_gpu_buffers = Vec::with_capacity(MAX_FRAMES_IN_FLIGHT + 1);
         for _ in 0..=MAX_FRAMES_IN_FLIGHT {
@@ -231,7 +248,7 @@ impl MeshAdvLightBinRenderResource {
                 resource_type: RafxResourceType::BUFFER_READ_WRITE, //TODO: RW for dx12 because we forced storage buffers to be uav
                 ..Default::default()
             })?;
-            buffer.set_debug_name("Light Binning Lights Input List");
+            buffer.set_debug_name(format!("{} Input List", list.debug_name()));
             light_bounds_gpu_buffers.push(resources.insert_buffer(buffer));
         }
 
@@ -247,18 +264,94 @@ impl MeshAdvLightBinRenderResource {
                 resource_type: RafxResourceType::BUFFER_READ_WRITE,
                 ..Default::default()
             })?;
-            buffer.set_debug_name("Light Binning Output");
+            buffer.set_debug_name(format!("{} Output", list.debug_name()));
             output_gpu_buffers.push(resources.insert_buffer(buffer));
         }
 
+        Ok(LightBinListBuffers {
+            light_bounds_gpu_buffers,
+            output_gpu_buffers,
+        })
+    }
+}
+
+// The main view's per-view data and light list, as uploaded to the mesh shaders. Passes outside the
+// mesh feature that are lit by the binned lights (i.e. volumetric fog) read them from here. Uploaded
+// by CPU, so MAX_FRAMES_IN_FLIGHT + 1 buffers needed.
+struct MainViewLightBuffers {
+    per_view_data_gpu_buffers: Vec<ResourceArc<BufferResource>>,
+    all_lights_gpu_buffers: Vec<ResourceArc<BufferResource>>,
+}
+
+impl MainViewLightBuffers {
+    fn new(resources: &ResourceLookupSet) -> RafxResult<Self> {
+        let mut per_view_data_gpu_buffers = Vec::with_capacity(MAX_FRAMES_IN_FLIGHT + 1);
+        let mut all_lights_gpu_buffers = Vec::with_capacity(MAX_FRAMES_IN_FLIGHT + 1);
+        for _ in 0..=MAX_FRAMES_IN_FLIGHT {
+            let buffer = resources.device_context().create_buffer(&RafxBufferDef {
+                size: std::mem::size_of::<mesh_adv_textured_frag::PerViewDataUniform>() as u64,
+                alignment: 256,
+                memory_usage: RafxMemoryUsage::CpuToGpu,
+                queue_type: RafxQueueType::Graphics,
+                resource_type: RafxResourceType::UNIFORM_BUFFER,
+                ..Default::default()
+            })?;
+            buffer.set_debug_name("Main View Per View Data");
+            per_view_data_gpu_buffers.push(resources.insert_buffer(buffer));
+
+            let buffer = resources.device_context().create_buffer(&RafxBufferDef {
+                size: std::mem::size_of::<mesh_adv_textured_frag::AllLightsBuffer>() as u64,
+                alignment: 256,
+                memory_usage: RafxMemoryUsage::CpuToGpu,
+                queue_type: RafxQueueType::Graphics,
+                //DX12TODO: Does not need to be BUFFER_READ_WRITE for other backends
+                resource_type: RafxResourceType::BUFFER_READ_WRITE,
+                ..Default::default()
+            })?;
+            buffer.set_debug_name("Main View All Lights");
+            all_lights_gpu_buffers.push(resources.insert_buffer(buffer));
+        }
+
+        Ok(MainViewLightBuffers {
+            per_view_data_gpu_buffers,
+            all_lights_gpu_buffers,
+        })
+    }
+}
+
+pub struct MeshAdvLightBinRenderResource {
+    frustum_structure: Option<LightBinningFrustumAABBStructure>,
+    // Unlikely to change often so we allocate/drop as needed
+    frustum_bounds_gpu_buffer: Option<ResourceArc<BufferResource>>,
+    lights: LightBinListBuffers,
+    reflection_probes: LightBinListBuffers,
+    main_view_lights: MainViewLightBuffers,
+}
+
+impl MeshAdvLightBinRenderResource {
+    pub fn new(resources: &ResourceLookupSet) -> RafxResult<Self> {
         Ok(MeshAdvLightBinRenderResource {
             frustum_structure: None,
             frustum_bounds_gpu_buffer: None,
-            light_bounds_gpu_buffers,
-            output_gpu_buffers,
+            lights: LightBinListBuffers::new(resources, MeshAdvLightBinList::Lights)?,
+            reflection_probes: LightBinListBuffers::new(
+                resources,
+                MeshAdvLightBinList::ReflectionProbes,
+            )?,
+            main_view_lights: MainViewLightBuffers::new(resources)?,
         })
     }
 
+    fn list_buffers(
+        &self,
+        list: MeshAdvLightBinList,
+    ) -> &LightBinListBuffers {
+        match list {
+            MeshAdvLightBinList::Lights => &self.lights,
+            MeshAdvLightBinList::ReflectionProbes => &self.reflection_probes,
+        }
+    }
+
     pub fn update_projection(
         &mut self,
         resource_context: &ResourceContext,
@@ -334,15 +427,33 @@ impl MeshAdvLightBinRenderResource {
 
     pub fn update_light_bounds(
         &self,
+        list: MeshAdvLightBinList,
         frame_index: usize,
         lights: &lights_bin_comp::LightsInputListBuffer,
     ) -> RafxResult<()> {
-        self.light_bounds_gpu_buffers[frame_index % (MAX_FRAMES_IN_FLIGHT + 1)]
+        self.light_bounds_gpu_buffer(list, frame_index)
             .get_raw()
             .buffer
             .copy_to_host_visible_buffer(&[*lights])
     }
 
+    pub fn update_main_view_lights(
+        &self,
+        frame_index: usize,
+        per_view_data: &mesh_adv_textured_frag::PerViewDataUniform,
+        all_lights: &mesh_adv_textured_frag::AllLightsBuffer,
+    ) -> RafxResult<()> {
+        let index = frame_index % (MAX_FRAMES_IN_FLIGHT + 1);
+        self.main_view_lights.per_view_data_gpu_buffers[index]
+            .get_raw()
+            .buffer
+            .copy_to_host_visible_buffer(&[*per_view_data])?;
+        self.main_view_lights.all_lights_gpu_buffers[index]
+            .get_raw()
+            .buffer
+            .copy_to_host_visible_buffer(&[*all_lights])
+    }
+
     pub fn aabb_structure(&self) -> &Option<LightBinningFrustumAABBStructure> {
         &self.frustum_structure
     }
@@ -353,15 +464,31 @@ impl MeshAdvLightBinRenderResource {
 
     pub fn light_bounds_gpu_buffer(
         &self,
+        list: MeshAdvLightBinList,
         frame_index: usize,
     ) -> &ResourceArc<BufferResource> {
-        &self.light_bounds_gpu_buffers[frame_index % (MAX_FRAMES_IN_FLIGHT + 1)]
+        &self.list_buffers(list).light_bounds_gpu_buffers[frame_index % (MAX_FRAMES_IN_FLIGHT + 1)]
     }
 
     pub fn output_gpu_buffer(
+        &self,
+        list: MeshAdvLightBinList,
+        frame_index: usize,
+    ) -> &ResourceArc<BufferResource> {
+        &self.list_buffers(list).output_gpu_buffers[frame_index % MAX_FRAMES_IN_FLIGHT]
+    }
+
+    pub fn main_view_per_view_data_gpu_buffer(
+        &self,
+        frame_index: usize,
+    ) -> &ResourceArc<BufferResource> {
+        &self.main_view_lights.per_view_data_gpu_buffers[frame_index % (MAX_FRAMES_IN_FLIGHT + 1)]
+    }
+
+    pub fn main_view_all_lights_gpu_buffer(
         &self,
         frame_index: usize,
     ) -> &ResourceArc<BufferResource> {
-        &self.output_gpu_buffers[frame_index % MAX_FRAMES_IN_FLIGHT]
+        &self.main_view_lights.all_lights_gpu_buffers[frame_index % (MAX_FRAMES_IN_FLIGHT + 1)]
     }
 }
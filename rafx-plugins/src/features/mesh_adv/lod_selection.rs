@@ -0,0 +1,319 @@
+use super::MeshAdvLodOptions;
+use fnv::FnvHashMap;
+use glam::Mat4;
+use rafx::render_features::{RenderObjectId, RenderView};
+use rafx::visibility::{ObjectId, ViewFrustumId};
+use std::sync::RwLock;
+
+/// The LOD of a mesh that is drawn in a particular view. While cross-fading, `fade_from_lod` is
+/// drawn as well and the two meshes are dithered so that each pixel is covered by exactly one.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct MeshAdvLodSelection {
+    pub lod: usize,
+    pub fade_from_lod: Option<usize>,
+    // Number of frames the cross-fade has been running
+    pub fade_frame: u32,
+    // Fraction of the pixels drawn by `lod` rather than `fade_from_lod`, in (0, 1)
+    pub fade_amount: f32,
+}
+
+impl MeshAdvLodSelection {
+    /// The `lod_fade` shader value for draws of `lod`. See `lod_fade.glsl`.
+    pub fn lod_fade(&self) -> f32 {
+        if self.fade_from_lod.is_some() {
+            self.fade_amount - 1.0
+        } else {
+            0.0
+        }
+    }
+
+    /// The `lod_fade` shader value for draws of `fade_from_lod`. See `lod_fade.glsl`.
+    pub fn fade_from_lod_fade(&self) -> f32 {
+        self.fade_amount
+    }
+
+    /// Picks a LOD out of `lod_count` LODs for a mesh covering `screen_size` of the view. The
+    /// `previous` selection for the same mesh and view, if any, is used to apply hysteresis and to
+    /// continue or start a cross-fade.
+    pub fn select(
+        options: &MeshAdvLodOptions,
+        lod_count: usize,
+        screen_size: f32,
+        previous: Option<&MeshAdvLodSelection>,
+        allow_cross_fade: bool,
+    ) -> MeshAdvLodSelection {
+        if !options.enable_lods || lod_count <= 1 {
+            return MeshAdvLodSelection::default();
+        }
+
+        let screen_size = screen_size * options.lod_bias;
+        let previous_lod = previous.map(|x| x.lod.min(lod_count - 1));
+
+        // Threshold `lod` is the screen size below which `lod` (or a coarser one) is drawn. Moving
+        // across a threshold in either direction away from the previous LOD requires overshooting
+        // it by the hysteresis.
+        let mut lod = 0;
+        let mut threshold = options.lod1_screen_size;
+        while lod + 1 < lod_count {
+            let adjusted_threshold = match previous_lod {
+                Some(previous_lod) if lod < previous_lod => threshold * (1.0 + options.hysteresis),
+                Some(_) => threshold * (1.0 - options.hysteresis),
+                None => threshold,
+            };
+
+            if screen_size >= adjusted_threshold {
+                break;
+            }
+
+            lod += 1;
+            threshold *= options.screen_size_falloff;
+        }
+
+        let mut selection = MeshAdvLodSelection {
+            lod,
+            ..Default::default()
+        };
+
+        if !allow_cross_fade || options.cross_fade_frames == 0 {
+            return selection;
+        }
+
+        if let Some(previous) = previous {
+            if previous.lod != lod {
+                // Start a new fade from whatever was fully drawn (or fading in) last frame
+                selection.fade_from_lod = Some(previous.lod.min(lod_count - 1));
+                selection.fade_frame = 0;
+            } else if let Some(fade_from_lod) = previous.fade_from_lod {
+                selection.fade_from_lod = Some(fade_from_lod.min(lod_count - 1));
+                selection.fade_frame = previous.fade_frame + 1;
+            }
+
+            if selection.fade_from_lod.is_some() {
+                let fade_amount =
+                    (selection.fade_frame + 1) as f32 / (options.cross_fade_frames + 1) as f32;
+                if fade_amount < 1.0 {
+                    selection.fade_amount = fade_amount;
+                } else {
+                    selection.fade_from_lod = None;
+                    selection.fade_frame = 0;
+                }
+            }
+        }
+
+        selection
+    }
+}
+
+/// Returns the projected diameter of a bounding sphere as a fraction of the view's height. For
+/// perspective views this shrinks with `distance_from_view_frustum`, the distance reported by the
+/// visibility query for the sphere.
+pub fn mesh_adv_screen_size(
+    projection: &Mat4,
+    distance_from_view_frustum: f32,
+    radius: f32,
+) -> f32 {
+    // The projection scales view-space y into clip space, and clip space spans 2 units
+    let is_orthographic = projection.z_axis.w == 0.0;
+    let w = if is_orthographic {
+        1.0
+    } else {
+        distance_from_view_frustum.max(f32::EPSILON)
+    };
+
+    radius * projection.y_axis.y.abs() / w
+}
+
+type MeshAdvLodKey = (ViewFrustumId, ObjectId, RenderObjectId);
+
+struct MeshAdvStoredLodSelection {
+    frame_index: usize,
+    selection: MeshAdvLodSelection,
+    // The selection from the frame before `frame_index`, so that views sharing a view frustum all
+    // see the same history
+    previous_selection: Option<MeshAdvLodSelection>,
+}
+
+/// Remembers the LOD selected for each mesh in each view so that the next frame can apply
+/// hysteresis and advance cross-fades.
+#[derive(Default)]
+pub struct MeshAdvLodRenderResource {
+    selections: RwLock<FnvHashMap<MeshAdvLodKey, MeshAdvStoredLodSelection>>,
+}
+
+impl MeshAdvLodRenderResource {
+    pub fn previous_selection(
+        &self,
+        view: &RenderView,
+        object_id: ObjectId,
+        render_object_id: RenderObjectId,
+    ) -> Option<MeshAdvLodSelection> {
+        let key = (
+            view.view_frustum().view_frustum_id(),
+            object_id,
+            render_object_id,
+        );
+        self.selections
+            .read()
+            .unwrap()
+            .get(&key)
+            .and_then(|stored| {
+                if stored.frame_index == view.frame_index() {
+                    stored.previous_selection
+                } else {
+                    Some(stored.selection)
+                }
+            })
+    }
+
+    pub fn store_selections<I: Iterator<Item = (ObjectId, RenderObjectId, MeshAdvLodSelection)>>(
+        &self,
+        view: &RenderView,
+        selections: I,
+    ) {
+        let view_frustum_id = view.view_frustum().view_frustum_id();
+        let frame_index = view.frame_index();
+        let mut stored_selections = self.selections.write().unwrap();
+        for (object_id, render_object_id, selection) in selections {
+            let stored = stored_selections
+                .entry((view_frustum_id, object_id, render_object_id))
+                .or_insert(MeshAdvStoredLodSelection {
+                    frame_index,
+                    selection,
+                    previous_selection: None,
+                });
+
+            if stored.frame_index != frame_index {
+                stored.previous_selection = Some(stored.selection);
+                stored.selection = selection;
+                stored.frame_index = frame_index;
+            }
+        }
+    }
+
+    // Forgets meshes that were not visible during the frame `frame_index`
+    pub fn remove_stale_selections(
+        &self,
+        frame_index: usize,
+    ) {
+        self.selections
+            .write()
+            .unwrap()
+            .retain(|_, stored| stored.frame_index == frame_index);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // Selects a LOD out of 4 every frame, feeding back the previous selection
+    fn select_frames(
+        options: &MeshAdvLodOptions,
+        screen_sizes: &[f32],
+        mut previous: Option<MeshAdvLodSelection>,
+    ) -> Vec<MeshAdvLodSelection> {
+        screen_sizes
+            .iter()
+            .map(|&screen_size| {
+                let selection =
+                    MeshAdvLodSelection::select(options, 4, screen_size, previous.as_ref(), true);
+                previous = Some(selection);
+                selection
+            })
+            .collect()
+    }
+
+    #[test]
+    fn switches_lods_at_thresholds() {
+        let options = MeshAdvLodOptions::default();
+        let select = |lod_count, screen_size| {
+            MeshAdvLodSelection::select(&options, lod_count, screen_size, None, true).lod
+        };
+
+        // Thresholds are 0.5, 0.25 and 0.125
+        assert_eq!(select(4, 1.0), 0);
+        assert_eq!(select(4, 0.5), 0);
+        assert_eq!(select(4, 0.49), 1);
+        assert_eq!(select(4, 0.25), 1);
+        assert_eq!(select(4, 0.24), 2);
+        assert_eq!(select(4, 0.124), 3);
+        assert_eq!(select(4, 0.0), 3);
+
+        // Never selects a LOD the mesh doesn't have
+        assert_eq!(select(2, 0.0), 1);
+        assert_eq!(select(1, 0.0), 0);
+
+        let biased_options = MeshAdvLodOptions {
+            lod_bias: 2.0,
+            ..Default::default()
+        };
+        assert_eq!(
+            MeshAdvLodSelection::select(&biased_options, 4, 0.3, None, true).lod,
+            0
+        );
+
+        let disabled_options = MeshAdvLodOptions {
+            enable_lods: false,
+            ..Default::default()
+        };
+        assert_eq!(
+            MeshAdvLodSelection::select(&disabled_options, 4, 0.0, None, true),
+            MeshAdvLodSelection::default()
+        );
+    }
+
+    #[test]
+    fn hysteresis_band_does_not_flicker() {
+        let options = MeshAdvLodOptions::default();
+
+        // With 0.1 hysteresis the LOD 0/1 threshold of 0.5 becomes a band from 0.45 to 0.55
+        let oscillating = [0.53, 0.47, 0.52, 0.46, 0.54, 0.48];
+        let selections = select_frames(&options, &oscillating, None);
+        assert!(selections.iter().all(|x| x.lod == 0));
+
+        let selections = select_frames(&options, &oscillating, selections.last().copied());
+        assert!(selections.iter().all(|x| x.lod == 0));
+
+        // Leaving the band switches, after which oscillating inside it keeps the new LOD
+        let mut screen_sizes = vec![0.44];
+        screen_sizes.extend_from_slice(&oscillating);
+        let selections = select_frames(&options, &screen_sizes, selections.last().copied());
+        assert!(selections.iter().all(|x| x.lod == 1));
+
+        let selections = select_frames(&options, &[0.56], selections.last().copied());
+        assert_eq!(selections[0].lod, 0);
+    }
+
+    #[test]
+    fn cross_fades_for_configured_frames() {
+        let options = MeshAdvLodOptions {
+            cross_fade_frames: 3,
+            ..Default::default()
+        };
+
+        let previous = MeshAdvLodSelection::default();
+        let selections = select_frames(&options, &[0.3; 5], Some(previous));
+        assert!(selections.iter().all(|x| x.lod == 1));
+
+        // The fade runs for cross_fade_frames with the new LOD covering more pixels each frame
+        for (frame, selection) in selections[..3].iter().enumerate() {
+            let fade_amount = (frame + 1) as f32 / 4.0;
+            assert_eq!(selection.fade_from_lod, Some(0));
+            assert_eq!(selection.fade_frame, frame as u32);
+            assert_eq!(selection.fade_amount, fade_amount);
+            assert!(selection.fade_amount > 0.0 && selection.fade_amount < 1.0);
+            assert_eq!(selection.lod_fade(), fade_amount - 1.0);
+            assert_eq!(selection.fade_from_lod_fade(), fade_amount);
+        }
+
+        for selection in &selections[3..] {
+            assert_eq!(selection.fade_from_lod, None);
+            assert_eq!(selection.lod_fade(), 0.0);
+        }
+
+        // Shadow views switch immediately
+        let selection = MeshAdvLodSelection::select(&options, 4, 0.3, Some(&previous), false);
+        assert_eq!(selection.lod, 1);
+        assert_eq!(selection.fade_from_lod, None);
+    }
+}
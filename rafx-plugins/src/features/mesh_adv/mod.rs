@@ -35,6 +35,7 @@ pub use plugin::*;
 
 pub use jobs::MeshVertexFull;
 pub use jobs::MeshVertexPosition;
+pub use jobs::MeshVertexSkin;
 pub use jobs::ShadowMapAtlasClearTileVertex;
 pub use jobs::SHADOW_MAP_ATLAS_CLEAR_TILE_LAYOUT;
 
@@ -47,10 +48,19 @@ pub use shadow_map_resource::*;
 mod render_options;
 pub use render_options::*;
 
+mod lod_selection;
+pub use lod_selection::*;
+
 pub mod light_binning;
 mod shadow_map_atlas;
 
 mod gpu_occlusion_cull;
 pub use gpu_occlusion_cull::*;
 
+mod skinning;
+pub use skinning::*;
+
+mod reflection_probe_resource;
+pub use reflection_probe_resource::*;
+
 pub use shadow_map_atlas::*;
@@ -24,6 +24,7 @@ pub struct MeshAdvStaticResources {
     pub wireframe_indirect_signature: RafxIndexedIndirectCommandSignature,
     pub depth_indirect_signature: RafxIndexedIndirectCommandSignature,
     pub shadow_indirect_signature: RafxIndexedIndirectCommandSignature,
+    pub shadow_alpha_indirect_signature: RafxIndexedIndirectCommandSignature,
 }
 
 pub struct MeshAdvRendererPlugin {
@@ -191,6 +192,15 @@ impl RenderFeaturePlugin for MeshAdvRendererPlugin {
             .get_raw()
             .root_signature
             .clone();
+        // The alpha clip and alpha stochastic shadow passes share the same root signature
+        let shadow_alpha_root_signature = pbr_material
+            .get_material_pass_by_index(pbr_pass_indices.shadow_map_alpha_clip as usize)
+            .unwrap()
+            .get_raw()
+            .root_signature
+            .get_raw()
+            .root_signature
+            .clone();
 
         let depth_material = depth_material_handle
             .artifact(asset_resource.storage())
@@ -232,6 +242,10 @@ impl RenderFeaturePlugin for MeshAdvRendererPlugin {
             &shadow_root_signature,
             RafxShaderStageFlags::ALL,
         )?;
+        let shadow_alpha_indirect_signature = RafxIndexedIndirectCommandSignature::new(
+            &shadow_alpha_root_signature,
+            RafxShaderStageFlags::ALL,
+        )?;
 
         render_resources.insert(MeshAdvStaticResources {
             default_pbr_material: default_pbr_material_handle,
@@ -244,16 +258,22 @@ impl RenderFeaturePlugin for MeshAdvRendererPlugin {
             wireframe_indirect_signature,
             depth_indirect_signature,
             shadow_indirect_signature,
+            shadow_alpha_indirect_signature,
         });
 
         render_resources.insert(MeshAdvShadowMapResource::default());
         render_resources.insert(MeshAdvRenderPipelineState::default());
         render_resources.insert(MeshAdvGpuOcclusionCullRenderResource::default());
+        render_resources.insert(MeshAdvSkinningRenderResource::default());
+        render_resources.insert(MeshAdvLodRenderResource::default());
 
         render_resources.insert(MeshAdvLightBinRenderResource::new(
             &asset_manager.resources(),
         )?);
         render_resources.insert(ShadowMapAtlas::new(asset_manager.resources())?);
+        render_resources.insert(MeshAdvReflectionProbeResource::new(
+            asset_manager.resources(),
+        )?);
         Ok(())
     }
 
@@ -276,17 +296,26 @@ impl RenderFeaturePlugin for MeshAdvRendererPlugin {
         render_views: &mut Vec<RenderView>,
     ) {
         //TODO: HACK
-        let main_view_eye_position = render_views[0].eye_position();
+        let main_view = render_views[0].clone();
         let mut shadow_map_resource = render_resources.fetch_mut::<MeshAdvShadowMapResource>();
         let mut shadow_map_atlas = render_resources.fetch_mut::<ShadowMapAtlas>();
         shadow_map_resource.recalculate_shadow_map_views(
             &render_view_set,
             extract_resources,
             &mut *shadow_map_atlas,
-            main_view_eye_position,
+            &main_view,
         );
 
         shadow_map_resource.append_render_views(render_views);
+
+        let mut reflection_probe_resource =
+            render_resources.fetch_mut::<MeshAdvReflectionProbeResource>();
+        reflection_probe_resource.update_reflection_probes(
+            render_view_set,
+            extract_resources,
+            &main_view,
+        );
+        reflection_probe_resource.append_render_views(render_views);
     }
 
     fn new_frame_packet(
@@ -332,8 +361,9 @@ impl RenderFeaturePlugin for MeshAdvRendererPlugin {
 
         let mut view_submit_packets = Vec::with_capacity(frame_packet.view_packets().len());
         for view_packet in frame_packet.view_packets() {
+            // A mesh that is cross-fading between two LODs draws both of them
             let num_submit_nodes = if let Some(max_num_mesh_parts) = self.max_num_mesh_parts {
-                view_packet.num_render_object_instances() * max_num_mesh_parts
+                view_packet.num_render_object_instances() * max_num_mesh_parts * 2
             } else {
                 // TODO(dvd): Count exact number of submit nodes required.
                 todo!()
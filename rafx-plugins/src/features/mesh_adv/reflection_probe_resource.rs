@@ -0,0 +1,303 @@
+use super::MeshAdvRenderOptions;
+use crate::components::{ReflectionProbeComponent, TransformComponent};
+use crate::phases::{DepthPrepassRenderPhase, OpaqueRenderPhase};
+use fnv::FnvHasher;
+use legion::*;
+use rafx::api::{
+    RafxExtents3D, RafxFormat, RafxResourceType, RafxTextureBindType, RafxTextureDef,
+    RafxTextureDimensions,
+};
+use rafx::framework::render_features::RenderFeatureFlagMask;
+use rafx::framework::{ImageViewResource, ResourceArc, ResourceLookupSet};
+use rafx::rafx_visibility::{DepthRange, PerspectiveParameters, Projection};
+use rafx::render_features::{
+    ExtractResources, RenderPhaseMaskBuilder, RenderView, RenderViewDepthRange, RenderViewSet,
+};
+use rafx::visibility::ObjectId;
+use rafx::RafxResult;
+use std::hash::Hasher;
+
+//TODO: Pull this const from the shader
+// Probes beyond this many are ignored
+pub const MAX_REFLECTION_PROBES: usize = 16;
+// The last mip (roughness 1) is 4x4
+pub const REFLECTION_PROBE_SIZE: u32 = 128;
+pub const REFLECTION_PROBE_MIP_COUNT: u32 = 6;
+pub const REFLECTION_PROBE_FORMAT: RafxFormat = RafxFormat::R16G16B16A16_SFLOAT;
+
+const REFLECTION_PROBE_NEAR_Z: f32 = 0.1;
+const REFLECTION_PROBE_FAR_Z: f32 = 1000.0;
+
+// A probe that has been captured into a slot of the cubemap array
+#[derive(Clone, Copy, Debug)]
+pub struct MeshAdvReflectionProbe {
+    pub slot: usize,
+    pub position: glam::Vec3,
+    pub half_extents: glam::Vec3,
+    pub blend_distance: f32,
+    pub intensity: f32,
+}
+
+// The six views a probe is rendered from this frame. The pipeline renders them and prefilters the
+// result into the probe's slot.
+#[derive(Clone)]
+pub struct MeshAdvReflectionProbeCapture {
+    pub slot: usize,
+    pub render_views: [RenderView; 6],
+}
+
+struct ReflectionProbeSlot {
+    object_id: ObjectId,
+    // None until the probe has been captured
+    captured_state_hash: Option<u64>,
+}
+
+// Assigns reflection probes to slots of a cubemap array and decides which probe to capture each
+// frame. At most one probe is captured per frame, and a probe is only recaptured when it moves or
+// its capture_generation changes.
+//
+// The cubemap array is a 2D array texture with 6 faces per slot rather than a cube array texture,
+// because cube array views require a device feature that isn't enabled. Faces are laid out in the
+// same order as point light shadow maps, so shaders can share the same cubemap lookup.
+pub struct MeshAdvReflectionProbeResource {
+    cubemap_array: ResourceArc<ImageViewResource>,
+    cubemap_array_mip_uavs: Vec<ResourceArc<ImageViewResource>>,
+    slots: Vec<Option<ReflectionProbeSlot>>,
+    probes: Vec<MeshAdvReflectionProbe>,
+    capture: Option<MeshAdvReflectionProbeCapture>,
+    requires_initial_transition: bool,
+}
+
+impl MeshAdvReflectionProbeResource {
+    pub fn new(resources: &ResourceLookupSet) -> RafxResult<Self> {
+        let image = resources.device_context().create_texture(&RafxTextureDef {
+            extents: RafxExtents3D {
+                width: REFLECTION_PROBE_SIZE,
+                height: REFLECTION_PROBE_SIZE,
+                depth: 1,
+            },
+            array_length: 6 * MAX_REFLECTION_PROBES as u32,
+            mip_count: REFLECTION_PROBE_MIP_COUNT,
+            format: REFLECTION_PROBE_FORMAT,
+            resource_type: RafxResourceType::TEXTURE | RafxResourceType::TEXTURE_READ_WRITE,
+            dimensions: RafxTextureDimensions::Dim2D,
+            ..Default::default()
+        })?;
+        image.set_debug_name("Reflection Probe Cubemap Array");
+
+        let image = resources.insert_image(image);
+        let cubemap_array =
+            resources.get_or_create_image_view(&image, Some(RafxTextureBindType::Srv))?;
+        let cubemap_array_mip_uavs = (0..REFLECTION_PROBE_MIP_COUNT)
+            .map(|mip| {
+                resources
+                    .get_or_create_image_view(&image, Some(RafxTextureBindType::UavMipSlice(mip)))
+            })
+            .collect::<RafxResult<Vec<_>>>()?;
+
+        Ok(MeshAdvReflectionProbeResource {
+            cubemap_array,
+            cubemap_array_mip_uavs,
+            slots: (0..MAX_REFLECTION_PROBES).map(|_| None).collect(),
+            probes: Vec::default(),
+            capture: None,
+            requires_initial_transition: true,
+        })
+    }
+
+    // Sampled by the mesh shaders, each slot is 6 consecutive array layers
+    pub fn cubemap_array(&self) -> &ResourceArc<ImageViewResource> {
+        &self.cubemap_array
+    }
+
+    // Written by the pipeline when a probe is captured, one view per mip covering all layers
+    pub fn cubemap_array_mip_uavs(&self) -> &[ResourceArc<ImageViewResource>] {
+        &self.cubemap_array_mip_uavs
+    }
+
+    // The cubemap array starts out in an undefined state. The pipeline must transition it before it
+    // is sampled for the first time.
+    pub fn take_requires_initial_transition(&mut self) -> bool {
+        let requires_initial_transition = self.requires_initial_transition;
+        self.requires_initial_transition = false;
+        requires_initial_transition
+    }
+
+    // Probes that can be sampled this frame, including the one being captured
+    pub fn probes(&self) -> &[MeshAdvReflectionProbe] {
+        &self.probes
+    }
+
+    pub fn capture(&self) -> Option<&MeshAdvReflectionProbeCapture> {
+        self.capture.as_ref()
+    }
+
+    pub fn append_render_views(
+        &self,
+        render_views: &mut Vec<RenderView>,
+    ) {
+        if let Some(capture) = &self.capture {
+            render_views.extend(capture.render_views.iter().cloned());
+        }
+    }
+
+    pub fn update_reflection_probes(
+        &mut self,
+        render_view_set: &RenderViewSet,
+        extract_resources: &ExtractResources,
+        main_view: &RenderView,
+    ) {
+        self.probes.clear();
+        self.capture = None;
+
+        let render_options = extract_resources.fetch::<MeshAdvRenderOptions>();
+        if !render_options.enable_reflection_probes || !render_options.show_surfaces {
+            return;
+        }
+
+        let world_fetch = extract_resources.fetch::<World>();
+        let world = &*world_fetch;
+
+        let mut query = <(
+            Entity,
+            Read<ReflectionProbeComponent>,
+            Read<TransformComponent>,
+        )>::query();
+        let probe_components: Vec<_> = query
+            .iter(world)
+            .map(|(entity, probe, transform)| (ObjectId::from(*entity), probe, transform))
+            .collect();
+
+        // Release the slots of probes that no longer exist
+        for slot in &mut self.slots {
+            let probe_exists = slot
+                .as_ref()
+                .map(|slot| {
+                    probe_components
+                        .iter()
+                        .any(|(object_id, _, _)| *object_id == slot.object_id)
+                })
+                .unwrap_or(false);
+            if !probe_exists {
+                *slot = None;
+            }
+        }
+
+        for (object_id, probe, transform) in probe_components {
+            let slot_index = self
+                .slots
+                .iter()
+                .position(|slot| {
+                    slot.as_ref()
+                        .map(|slot| slot.object_id == object_id)
+                        .unwrap_or(false)
+                })
+                .or_else(|| self.slots.iter().position(|slot| slot.is_none()));
+
+            let slot_index = if let Some(slot_index) = slot_index {
+                slot_index
+            } else {
+                // All slots are in use
+                continue;
+            };
+
+            let slot = self.slots[slot_index].get_or_insert(ReflectionProbeSlot {
+                object_id,
+                captured_state_hash: None,
+            });
+
+            let mut hasher = FnvHasher::default();
+            let translation: [f32; 3] = transform.translation.into();
+            for value in translation {
+                hasher.write_u32(value.to_bits());
+            }
+            hasher.write_u32(probe.capture_generation);
+            let state_hash = hasher.finish();
+
+            if slot.captured_state_hash != Some(state_hash) && self.capture.is_none() {
+                self.capture = Some(Self::create_capture(
+                    render_view_set,
+                    main_view,
+                    slot_index,
+                    probe,
+                    transform.translation,
+                ));
+                slot.captured_state_hash = Some(state_hash);
+            }
+
+            if slot.captured_state_hash.is_some() {
+                self.probes.push(MeshAdvReflectionProbe {
+                    slot: slot_index,
+                    position: transform.translation,
+                    half_extents: probe.half_extents,
+                    blend_distance: probe.blend_distance,
+                    intensity: probe.intensity,
+                });
+            }
+        }
+    }
+
+    fn create_capture(
+        render_view_set: &RenderViewSet,
+        main_view: &RenderView,
+        slot: usize,
+        probe: &ReflectionProbeComponent,
+        eye_position: glam::Vec3,
+    ) -> MeshAdvReflectionProbeCapture {
+        // The same eye offsets and up vectors as point light shadow maps
+        #[rustfmt::skip]
+        let cube_map_view_directions = [
+            (glam::Vec3::X, glam::Vec3::Y),
+            (glam::Vec3::X * -1.0, glam::Vec3::Y),
+            (glam::Vec3::Y, glam::Vec3::Z * -1.0),
+            (glam::Vec3::Y * -1.0, glam::Vec3::Z),
+            (glam::Vec3::Z, glam::Vec3::Y),
+            (glam::Vec3::Z * -1.0, glam::Vec3::Y),
+        ];
+
+        let projection = Projection::Perspective(PerspectiveParameters::new(
+            std::f32::consts::FRAC_PI_2,
+            1.0,
+            REFLECTION_PROBE_NEAR_Z,
+            REFLECTION_PROBE_FAR_Z,
+            DepthRange::Reverse,
+        ));
+        let proj = projection.as_lh_mat4();
+
+        // Transparent meshes, wireframes and UI are not captured
+        let phase_mask = RenderPhaseMaskBuilder::default()
+            .add_render_phase::<DepthPrepassRenderPhase>()
+            .add_render_phase::<OpaqueRenderPhase>()
+            .build();
+
+        let render_views = [0, 1, 2, 3, 4, 5].map(|face_index| {
+            let (direction, up) = cube_map_view_directions[face_index];
+
+            // NOTE: Cubemaps always use LH
+            let view = glam::Mat4::look_at_lh(eye_position, eye_position + direction, up);
+
+            let view_frustum = probe.capture_view_frustums[face_index].clone();
+            view_frustum.set_projection(&projection).set_transform(
+                eye_position,
+                eye_position + direction,
+                up,
+            );
+
+            render_view_set.create_view(
+                view_frustum,
+                eye_position,
+                view,
+                proj,
+                (REFLECTION_PROBE_SIZE, REFLECTION_PROBE_SIZE),
+                RenderViewDepthRange::from_projection(&projection),
+                None,
+                phase_mask,
+                main_view.render_feature_mask(),
+                RenderFeatureFlagMask::empty(),
+                format!("reflection_probe_face_{}", face_index),
+            )
+        });
+
+        MeshAdvReflectionProbeCapture { slot, render_views }
+    }
+}
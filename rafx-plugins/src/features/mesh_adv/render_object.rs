@@ -1,11 +1,32 @@
 use super::MeshAdvRenderFeature;
-use crate::assets::mesh_adv::MeshAdvAsset;
+use crate::assets::mesh_adv::{MeshAdvAsset, ModelAdvAsset};
 use hydrate_base::handle::Handle;
 use rafx::render_features::RenderObjectSet;
 
 #[derive(Clone)]
 pub struct MeshAdvRenderObject {
-    pub mesh: Handle<MeshAdvAsset>,
+    // Ordered from most to least detailed. Never empty.
+    pub lods: Vec<Handle<MeshAdvAsset>>,
+}
+
+impl MeshAdvRenderObject {
+    pub fn new(mesh: Handle<MeshAdvAsset>) -> Self {
+        MeshAdvRenderObject { lods: vec![mesh] }
+    }
+
+    pub fn with_lods(lods: Vec<Handle<MeshAdvAsset>>) -> Self {
+        assert!(!lods.is_empty());
+        MeshAdvRenderObject { lods }
+    }
+
+    pub fn from_model(model: &ModelAdvAsset) -> Self {
+        Self::with_lods(model.inner.lods.iter().map(|x| x.mesh.clone()).collect())
+    }
+
+    // The most detailed LOD
+    pub fn mesh(&self) -> &Handle<MeshAdvAsset> {
+        &self.lods[0]
+    }
 }
 
 pub type MeshAdvRenderObjectSet = RenderObjectSet<MeshAdvRenderFeature, MeshAdvRenderObject>;
@@ -1,4 +1,4 @@
-use rafx::framework::DescriptorSetArc;
+use rafx::framework::{DescriptorSetArc, ImageViewResource, ResourceArc};
 
 // This is user-facing choices that don't change much frame-to-frame
 #[derive(Clone)]
@@ -7,8 +7,15 @@ pub struct MeshAdvRenderOptions {
     pub show_shadows: bool,
     pub enable_lighting: bool,
     pub ambient_light: glam::Vec3,
+    // Scales image-based lighting from the skybox. ambient_light is used instead if the pipeline
+    // has not generated IBL images.
+    pub ibl_intensity: f32,
     pub ndf_filter_amount: f32,
     pub use_clustered_lighting: bool,
+    // If false, ReflectionProbeComponents are not captured or sampled
+    pub enable_reflection_probes: bool,
+    pub lod_options: MeshAdvLodOptions,
+    pub shadow_cascade_options: MeshAdvShadowCascadeOptions,
 }
 
 impl Default for MeshAdvRenderOptions {
@@ -18,8 +25,75 @@ impl Default for MeshAdvRenderOptions {
             show_shadows: true,
             enable_lighting: true,
             ambient_light: glam::Vec3::ZERO,
+            ibl_intensity: 1.0,
             ndf_filter_amount: 1.0,
             use_clustered_lighting: true,
+            enable_reflection_probes: true,
+            lod_options: Default::default(),
+            shadow_cascade_options: Default::default(),
+        }
+    }
+}
+
+// Controls how a LOD is picked for each mesh in each view (including shadow views). The screen size
+// of a mesh is the projected diameter of its bounding sphere as a fraction of the view's height.
+#[derive(Copy, Clone, Debug)]
+pub struct MeshAdvLodOptions {
+    // If false, LOD 0 is always drawn
+    pub enable_lods: bool,
+    // Screen size below which LOD 1 is drawn
+    pub lod1_screen_size: f32,
+    // Each further LOD is drawn below this fraction of the screen size that selects the previous one
+    pub screen_size_falloff: f32,
+    // Multiplies the screen size before selecting a LOD. Values above 1.0 prefer more detailed LODs
+    pub lod_bias: f32,
+    // Fraction by which the screen size must cross a threshold before the LOD changes, to keep
+    // meshes near a threshold from switching back and forth every frame
+    pub hysteresis: f32,
+    // Number of frames a LOD change is dithered between the old and new mesh. 0 disables the
+    // cross-fade. Shadow views always switch immediately.
+    pub cross_fade_frames: u32,
+}
+
+impl Default for MeshAdvLodOptions {
+    fn default() -> Self {
+        MeshAdvLodOptions {
+            enable_lods: true,
+            lod1_screen_size: 0.5,
+            screen_size_falloff: 0.5,
+            lod_bias: 1.0,
+            hysteresis: 0.1,
+            cross_fade_frames: 0,
+        }
+    }
+}
+
+// Controls how the main view is split into cascades for directional light shadows. Each cascade
+// gets its own tile in the shadow map atlas.
+#[derive(Copy, Clone, Debug)]
+pub struct MeshAdvShadowCascadeOptions {
+    // Number of cascades per directional light, clamped to MAX_DIRECTIONAL_LIGHT_CASCADES
+    pub cascade_count: u32,
+    // Directional lights cast no shadows beyond this distance from the camera
+    pub max_shadow_distance: f32,
+    // Blends between uniform (0.0) and logarithmic (1.0) split distances. Logarithmic splits put
+    // more resolution near the camera.
+    pub split_lambda: f32,
+    // Fraction at the far end of each cascade that is cross-faded into the next cascade. The last
+    // cascade fades out to unshadowed.
+    pub blend_fraction: f32,
+    // How far towards the light beyond a cascade's bounds shadow casters are still drawn
+    pub caster_distance: f32,
+}
+
+impl Default for MeshAdvShadowCascadeOptions {
+    fn default() -> Self {
+        MeshAdvShadowCascadeOptions {
+            cascade_count: 4,
+            max_shadow_distance: 100.0,
+            split_lambda: 0.75,
+            blend_fraction: 0.1,
+            caster_distance: 100.0,
         }
     }
 }
@@ -30,6 +104,19 @@ pub struct MeshAdvRenderPipelineState {
     pub jitter_amount: glam::Vec2,
     pub forward_pass_mip_bias: f32,
     pub ssao_descriptor_set: Option<DescriptorSetArc>,
+    // Transparent meshes use the passes that write weighted blended OIT targets
+    pub use_order_independent_transparency: bool,
+    // Opaque meshes in the main view use the passes that also write a G-buffer for screen-space
+    // reflections
+    pub write_gbuffer: bool,
+    // Image-based lighting generated from the skybox, if there is one
+    pub ibl: Option<MeshAdvIblImages>,
+    // Depth covered by the volumetric fog volume the pipeline builds for the main view, or 0 if
+    // volumetric fog is disabled. The volume is bound with ssao_descriptor_set.
+    pub volumetric_fog_max_distance: f32,
+    // Opaque meshes in the main view blend in the decals the pipeline rendered after the depth
+    // prepass. The decal textures are bound with ssao_descriptor_set.
+    pub enable_decals: bool,
 }
 
 impl Default for MeshAdvRenderPipelineState {
@@ -38,6 +125,23 @@ impl Default for MeshAdvRenderPipelineState {
             jitter_amount: glam::Vec2::ZERO,
             forward_pass_mip_bias: 0.0,
             ssao_descriptor_set: None,
+            use_order_independent_transparency: false,
+            write_gbuffer: false,
+            ibl: None,
+            volumetric_fog_max_distance: 0.0,
+            enable_decals: false,
         }
     }
 }
+
+// Split-sum image-based lighting inputs generated by the pipeline from the skybox cubemap
+#[derive(Clone)]
+pub struct MeshAdvIblImages {
+    // Cosine-convolved skybox for diffuse lighting
+    pub irradiance_cubemap: ResourceArc<ImageViewResource>,
+    // GGX-prefiltered skybox, roughness increases linearly with each mip
+    pub specular_cubemap: ResourceArc<ImageViewResource>,
+    pub specular_mip_count: u32,
+    // Scale (r) and bias (g) applied to F0, indexed by (n.v, roughness)
+    pub brdf_lut: ResourceArc<ImageViewResource>,
+}
@@ -63,8 +63,9 @@ pub struct ShadowMapAtlas {
     _device_context: RafxDeviceContext,
     image_view: ResourceArc<ImageViewResource>,
     free_elements_by_quality: Vec<Vec<ShadowMapAtlasElementInner>>,
-    _image_width: u32,
-    _image_height: u32,
+    min_texture_size_pixels: u16,
+    image_width: u32,
+    image_height: u32,
     // First render should do a full-clear to get rid of NaN in the image. Call take_requires_full_clear()
     // to check for this condition and clear it.
     requires_full_clear: bool,
@@ -151,6 +152,8 @@ impl ShadowMapAtlas {
             last_divisions = divisions;
         }
 
+        let min_texture_size_pixels = (atlas_width_height / 2 / last_divisions as u32) as u16;
+
         let device_context = resources.device_context().clone();
         let image = device_context.create_texture(&RafxTextureDef {
             format: RafxFormat::D32_SFLOAT,
@@ -177,8 +180,9 @@ impl ShadowMapAtlas {
             _device_context: device_context,
             image_view,
             free_elements_by_quality,
-            _image_width: atlas_width_height,
-            _image_height: atlas_width_height,
+            min_texture_size_pixels,
+            image_width: atlas_width_height,
+            image_height: atlas_width_height,
             requires_full_clear: true,
             drop_tx,
             drop_rx,
@@ -189,6 +193,19 @@ impl ShadowMapAtlas {
         self.free_elements_by_quality.len()
     }
 
+    pub fn image_width(&self) -> u32 {
+        self.image_width
+    }
+
+    pub fn image_height(&self) -> u32 {
+        self.image_height
+    }
+
+    // The size of the smallest element the atlas can allocate. All element sizes are a multiple of it.
+    pub fn min_texture_size_pixels(&self) -> u16 {
+        self.min_texture_size_pixels
+    }
+
     // We do this here instead of in the graph builder so we can keep the atlas init/setup code
     // in sync more easily
     pub fn add_to_render_graph(
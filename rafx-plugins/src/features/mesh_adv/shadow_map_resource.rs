@@ -1,7 +1,8 @@
 use super::MeshAdvRenderFeature;
-use super::MeshAdvRenderOptions;
+use super::{MeshAdvRenderOptions, MeshAdvShadowCascadeOptions};
 use crate::components::{
     DirectionalLightComponent, PointLightComponent, SpotLightComponent, TransformComponent,
+    MAX_DIRECTIONAL_LIGHT_CASCADES,
 };
 use crate::features::mesh_adv::internal::{ShadowMapAtlas, ShadowMapAtlasElement};
 use crate::features::mesh_adv::ShadowMapAtlasElementInfo;
@@ -30,19 +31,23 @@ pub enum MeshAdvLightId {
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum MeshAdvShadowViewId {
     SpotLight(ObjectId),
-    DirectionalLight(ObjectId),
+    DirectionalLight(ObjectId, u8),
     PointLight(ObjectId, u8),
 }
 
 impl MeshAdvShadowViewId {
     // this function just exists to provide a Ord impl for sorting lights by score (we use the light
-    // id as a tiebreaker)
+    // id as a tiebreaker). Nearer cascades of a directional light sort first so they get the best
+    // atlas elements.
     fn light_type_to_int_and_object_id(&self) -> (u8, ObjectId) {
+        const CASCADE_COUNT: u8 = MAX_DIRECTIONAL_LIGHT_CASCADES as u8;
         match self {
-            MeshAdvShadowViewId::SpotLight(object_id) => (1, *object_id),
-            MeshAdvShadowViewId::DirectionalLight(object_id) => (0, *object_id),
+            MeshAdvShadowViewId::SpotLight(object_id) => (CASCADE_COUNT, *object_id),
+            MeshAdvShadowViewId::DirectionalLight(object_id, cascade_index) => {
+                (*cascade_index, *object_id)
+            }
             MeshAdvShadowViewId::PointLight(object_id, cube_map_index) => {
-                (2 + cube_map_index, *object_id)
+                (CASCADE_COUNT + 1 + cube_map_index, *object_id)
             }
         }
     }
@@ -75,6 +80,7 @@ impl Ord for MeshAdvShadowViewId {
 pub enum MeshAdvShadowMapRenderViewIndices {
     Single(ShadowViewIndex),
     Cube([Option<ShadowViewIndex>; 6]),
+    Cascades([Option<ShadowViewIndex>; MAX_DIRECTIONAL_LIGHT_CASCADES]),
 }
 
 // These functions are primarily used to easily grab the render view index when you already know
@@ -86,6 +92,9 @@ impl MeshAdvShadowMapRenderViewIndices {
             MeshAdvShadowMapRenderViewIndices::Cube(_) => {
                 panic!("Called unwrap_single() on MeshAdvShadowMapRenderViewIndices::Cube")
             }
+            MeshAdvShadowMapRenderViewIndices::Cascades(_) => {
+                panic!("Called unwrap_single() on MeshAdvShadowMapRenderViewIndices::Cascades")
+            }
         }
     }
 
@@ -94,6 +103,9 @@ impl MeshAdvShadowMapRenderViewIndices {
             MeshAdvShadowMapRenderViewIndices::Single(_) => {
                 panic!("Called unwrap_cube_any() on MeshAdvShadowMapRenderViewIndices::Single")
             }
+            MeshAdvShadowMapRenderViewIndices::Cascades(_) => {
+                panic!("Called unwrap_cube_any() on MeshAdvShadowMapRenderViewIndices::Cascades")
+            }
             MeshAdvShadowMapRenderViewIndices::Cube(views) => {
                 for view in views {
                     if view.is_some() {
@@ -164,6 +176,96 @@ impl MeshAdvShadowMapRenderViewMeta {
 #[derive(Copy, Clone, Hash, PartialEq, Eq)]
 pub struct ShadowViewIndex(usize);
 
+// The part of the main view's frustum covered by one shadow cascade, as a bounding sphere in world
+// space. This is shared by all directional lights.
+#[derive(Copy, Clone)]
+struct ShadowCascadeBounds {
+    center: glam::Vec3,
+    radius: f32,
+}
+
+// Everything needed to place the cascades of any directional light this frame
+struct DirectionalLightCascades {
+    bounds: Vec<ShadowCascadeBounds>,
+    min_texture_size_pixels: u16,
+    caster_distance: f32,
+}
+
+impl DirectionalLightCascades {
+    fn cascade(
+        &self,
+        direction: glam::Vec3,
+        cascade_index: usize,
+    ) -> DirectionalLightCascade {
+        DirectionalLightCascade::new(
+            direction,
+            &self.bounds[cascade_index],
+            self.min_texture_size_pixels,
+            self.caster_distance,
+        )
+    }
+}
+
+// The orthographic projection for one cascade of a directional light
+#[derive(Copy, Clone)]
+struct DirectionalLightCascade {
+    eye_position: glam::Vec3,
+    look_at: glam::Vec3,
+    up: glam::Vec3,
+    half_extent: f32,
+    far_plane: f32,
+}
+
+impl DirectionalLightCascade {
+    fn new(
+        direction: glam::Vec3,
+        bounds: &ShadowCascadeBounds,
+        min_texture_size_pixels: u16,
+        caster_distance: f32,
+    ) -> Self {
+        let direction = direction.normalize();
+        let up = if direction.z.abs() > 0.99 {
+            glam::Vec3::Y
+        } else {
+            glam::Vec3::Z
+        };
+
+        // Snap the center of the cascade to whole texels in light space so that shadow edges do not
+        // shimmer as the camera moves. Every atlas element is a multiple of the smallest element
+        // size, so snapping with the smallest size lines up with texels at every quality level. The
+        // extent is grown so that the bounding sphere still fits after the center moves.
+        let texture_size_pixels = min_texture_size_pixels as f32;
+        let half_extent = bounds.radius / (1.0 - 2.0 / texture_size_pixels);
+        let snap_size = 2.0 * half_extent / texture_size_pixels;
+
+        let light_rotation = glam::Mat4::look_at_rh(glam::Vec3::ZERO, direction, up);
+        let center_ls = light_rotation.transform_point3(bounds.center);
+        let snapped_center_ls = (center_ls / snap_size).round() * snap_size;
+        let center = light_rotation.inverse().transform_point3(snapped_center_ls);
+
+        let eye_distance = half_extent + snap_size + caster_distance;
+        DirectionalLightCascade {
+            eye_position: center - direction * eye_distance,
+            look_at: center,
+            up,
+            half_extent,
+            far_plane: eye_distance + half_extent + snap_size,
+        }
+    }
+
+    fn projection(&self) -> Projection {
+        Projection::Orthographic(OrthographicParameters::new(
+            -self.half_extent,
+            self.half_extent,
+            -self.half_extent,
+            self.half_extent,
+            0.0,
+            self.far_plane,
+            DepthRange::Reverse,
+        ))
+    }
+}
+
 // The data structures in this struct are primarily indexed by "shadow view index" which is looked up
 // via the maps.
 #[derive(Default)]
@@ -201,6 +303,14 @@ pub struct MeshAdvShadowMapResource {
     // Looks up the shadow view index for a light (for point lights, there will be 6 indices, and some of them may be None.)
     pub(super) shadow_map_lookup_by_light_id:
         FnvHashMap<MeshAdvLightId, MeshAdvShadowMapRenderViewIndices>,
+
+    //
+    // These are set in calculate_shadow_cascade_bounds(). They are the same for all directional lights.
+    //
+    // The view space depth (from the main view) at the far end of each cascade
+    pub(super) shadow_cascade_split_distances: Vec<f32>,
+    // Fraction at the far end of each cascade that is blended into the next cascade
+    pub(super) shadow_cascade_blend_fraction: f32,
 }
 
 impl MeshAdvShadowMapResource {
@@ -220,6 +330,7 @@ impl MeshAdvShadowMapResource {
         self.shadow_map_render_views.clear();
         self.shadow_map_render_views_meta.clear();
         self.shadow_map_lookup_by_light_id.clear();
+        self.shadow_cascade_split_distances.clear();
     }
 
     pub(super) fn shadow_map_atlas_element_assignment(
@@ -281,8 +392,28 @@ impl MeshAdvShadowMapResource {
         render_view_set: &RenderViewSet,
         extract_resources: &ExtractResources,
         shadow_map_atlas: &mut ShadowMapAtlas,
-        main_view_eye_position: glam::Vec3,
+        main_view: &RenderView,
     ) {
+        let cascade_options = extract_resources
+            .fetch::<MeshAdvRenderOptions>()
+            .shadow_cascade_options;
+        let cascade_bounds = Self::calculate_shadow_cascade_bounds(
+            main_view.view_matrix(),
+            main_view.projection_matrix(),
+            main_view.depth_range(),
+            &cascade_options,
+            &mut self.shadow_cascade_split_distances,
+        );
+        self.shadow_cascade_blend_fraction = cascade_options.blend_fraction;
+
+        // Only the smallest element size is needed to stabilize cascades, which keeps the cascade
+        // projection independent of which element ends up assigned to it
+        let cascades = DirectionalLightCascades {
+            bounds: cascade_bounds,
+            min_texture_size_pixels: shadow_map_atlas.min_texture_size_pixels(),
+            caster_distance: cascade_options.caster_distance,
+        };
+
         // After this function returns, shadow_map_lookup_by_shadow_view_id and shadow_map_atlas_element_assignments
         // will have any stale state removed. We need info from previous frame, so we do not clear these lists.
         Self::reassign_shadow_atlas_elements(
@@ -291,13 +422,15 @@ impl MeshAdvShadowMapResource {
             &mut self.previous_light_state_hashes,
             &mut self.shadow_maps_needing_redraw,
             extract_resources,
-            main_view_eye_position,
+            main_view.eye_position(),
+            &cascades,
             shadow_map_atlas,
         );
 
         Self::calculate_shadow_map_views(
             render_view_set,
             extract_resources,
+            &cascades,
             &self.shadow_map_lookup_by_shadow_view_id,
             &self.shadow_map_atlas_element_assignments,
             &self.shadow_maps_needing_redraw,
@@ -308,6 +441,94 @@ impl MeshAdvShadowMapResource {
         );
     }
 
+    // Splits the main view's depth range into cascades using the "practical" split scheme, a blend of
+    // logarithmic and uniform split distances. Each cascade starts early enough to cover the
+    // region where the previous cascade blends into it.
+    fn calculate_shadow_cascade_bounds(
+        view_matrix: glam::Mat4,
+        projection_matrix: glam::Mat4,
+        depth_range: &RenderViewDepthRange,
+        cascade_options: &MeshAdvShadowCascadeOptions,
+        out_split_distances: &mut Vec<f32>,
+    ) -> Vec<ShadowCascadeBounds> {
+        out_split_distances.clear();
+
+        let cascade_count =
+            (cascade_options.cascade_count as usize).min(MAX_DIRECTIONAL_LIGHT_CASCADES);
+        let near = depth_range.near.max(0.01);
+        let far = depth_range
+            .far
+            .map_or(cascade_options.max_shadow_distance, |far| {
+                far.min(cascade_options.max_shadow_distance)
+            });
+        if cascade_count == 0 || far <= near {
+            return Vec::default();
+        }
+
+        for i in 1..=cascade_count {
+            let fraction = i as f32 / cascade_count as f32;
+            let log_split = near * (far / near).powf(fraction);
+            let uniform_split = near + (far - near) * fraction;
+            out_split_distances.push(
+                cascade_options.split_lambda * log_split
+                    + (1.0 - cascade_options.split_lambda) * uniform_split,
+            );
+        }
+
+        // Find two points in view space along each edge of the main view's frustum. Intersecting
+        // these with planes of constant view depth works for any projection and depth range.
+        let inverse_projection = projection_matrix.inverse();
+        let inverse_view = view_matrix.inverse();
+        let frustum_edges: Vec<_> = [(-1.0, -1.0), (1.0, -1.0), (-1.0, 1.0), (1.0, 1.0)]
+            .iter()
+            .map(|&(x, y)| {
+                (
+                    inverse_projection.project_point3(glam::Vec3::new(x, y, 0.25)),
+                    inverse_projection.project_point3(glam::Vec3::new(x, y, 0.75)),
+                )
+            })
+            .collect();
+
+        let mut cascade_bounds = Vec::with_capacity(cascade_count);
+        for cascade_index in 0..cascade_count {
+            let slice_far = out_split_distances[cascade_index];
+            let slice_near = if cascade_index == 0 {
+                near
+            } else {
+                let previous_far = out_split_distances[cascade_index - 1];
+                let previous_near = if cascade_index >= 2 {
+                    out_split_distances[cascade_index - 2]
+                } else {
+                    0.0
+                };
+                previous_far - (previous_far - previous_near) * cascade_options.blend_fraction
+            };
+
+            let mut corners = Vec::with_capacity(8);
+            for depth in [slice_near, slice_far].iter() {
+                for (p0, p1) in &frustum_edges {
+                    let t = (-depth - p0.z) / (p1.z - p0.z);
+                    corners.push(inverse_view.transform_point3(p0.lerp(*p1, t)));
+                }
+            }
+
+            let center = corners.iter().fold(glam::Vec3::ZERO, |sum, &x| sum + x) / 8.0;
+            let radius = corners
+                .iter()
+                .map(|x| x.distance(center))
+                .fold(0.0, f32::max);
+
+            // Round the radius up so that floating point noise as the camera rotates does not change
+            // the size of the cascade
+            cascade_bounds.push(ShadowCascadeBounds {
+                center,
+                radius: (radius * 16.0).ceil() / 16.0,
+            });
+        }
+
+        cascade_bounds
+    }
+
     // If this hashing method becomes problematic, we can quantize it later. It just needs to work
     // well enough to decide when to redraw shadow maps due to lights moving, etc.
     fn hash_f32<HasherT: Hasher>(
@@ -356,6 +577,7 @@ impl MeshAdvShadowMapResource {
     fn find_potential_shadow_views(
         extract_resources: &ExtractResources,
         main_view_eye_position: glam::Vec3,
+        cascades: &DirectionalLightCascades,
     ) -> Vec<PotentialShadowView> {
         let world_fetch = extract_resources.fetch::<World>();
         let world = &*world_fetch;
@@ -393,23 +615,34 @@ impl MeshAdvShadowMapResource {
 
         let mut query = <(Entity, Read<DirectionalLightComponent>)>::query();
         for (entity, light) in query.iter(world) {
-            if light.shadow_view_frustum.is_none() {
+            if light.shadow_view_frustums.is_none() {
                 continue;
             }
 
-            // Hardcode a score of 0 for these because directional lights have no position, there
-            // tend to be few of them per scene, and they tend to be important.
-            let shadow_view_id = MeshAdvShadowViewId::DirectionalLight(ObjectId::from(*entity));
-            let score = 0.0;
+            for cascade_index in 0..cascades.bounds.len() {
+                // Hardcode a score of 0 for these because directional lights have no position, there
+                // tend to be few of them per scene, and they tend to be important.
+                let shadow_view_id = MeshAdvShadowViewId::DirectionalLight(
+                    ObjectId::from(*entity),
+                    cascade_index as u8,
+                );
+                let score = 0.0;
 
-            let mut h = FnvHasher::default();
-            Self::hash_vec3(light.direction, &mut h);
+                // The cascade is snapped to texels, so this only changes when the camera has moved
+                // far enough to need a redraw
+                let cascade = cascades.cascade(light.direction, cascade_index);
+                let mut h = FnvHasher::default();
+                Self::hash_vec3(light.direction, &mut h);
+                Self::hash_vec3(cascade.eye_position, &mut h);
+                Self::hash_f32(cascade.half_extent, &mut h);
+                Self::hash_f32(cascade.far_plane, &mut h);
 
-            heap.push(PotentialShadowView {
-                shadow_view_id,
-                score,
-                light_state_hash: h.finish(),
-            });
+                heap.push(PotentialShadowView {
+                    shadow_view_id,
+                    score,
+                    light_state_hash: h.finish(),
+                });
+            }
         }
 
         let mut query = <(Entity, Read<PointLightComponent>, Read<TransformComponent>)>::query();
@@ -440,6 +673,7 @@ impl MeshAdvShadowMapResource {
     // Determine shadow views we would like to draw in this frame. Assign space in the shadow map atlas
     // to them, first using free space if possible, then stealing space from lower-priority shadows.
     // This function will also release atlas space for lights that no longer exist.
+    #[allow(clippy::too_many_arguments)]
     fn reassign_shadow_atlas_elements(
         shadow_map_lookup_by_shadow_view_id: &mut FnvHashMap<MeshAdvShadowViewId, ShadowViewIndex>,
         shadow_map_atlas_element_assignments: &mut Vec<Option<ShadowMapAtlasElement>>,
@@ -447,6 +681,7 @@ impl MeshAdvShadowMapResource {
         shadow_maps_needing_redraw: &mut FnvHashSet<ShadowViewIndex>,
         extract_resources: &ExtractResources,
         main_view_eye_position: glam::Vec3,
+        cascades: &DirectionalLightCascades,
         shadow_map_atlas: &mut ShadowMapAtlas,
     ) {
         shadow_maps_needing_redraw.clear();
@@ -455,7 +690,7 @@ impl MeshAdvShadowMapResource {
         // Find all potential shadow views, sorted by priority
         //
         let mut potential_views =
-            Self::find_potential_shadow_views(extract_resources, main_view_eye_position);
+            Self::find_potential_shadow_views(extract_resources, main_view_eye_position, cascades);
 
         let mut new_assignments = Vec::with_capacity(potential_views.len());
         let mut new_light_state_hashes = Vec::with_capacity(potential_views.len());
@@ -569,6 +804,7 @@ impl MeshAdvShadowMapResource {
     fn calculate_shadow_map_views(
         render_view_set: &RenderViewSet,
         extract_resources: &ExtractResources,
+        cascades: &DirectionalLightCascades,
         shadow_map_lookup_by_shadow_view_id: &FnvHashMap<MeshAdvShadowViewId, ShadowViewIndex>,
         shadow_map_atlas_element_assignments: &Vec<Option<ShadowMapAtlasElement>>,
         shadow_maps_needing_redraw: &FnvHashSet<ShadowViewIndex>,
@@ -680,6 +916,7 @@ impl MeshAdvShadowMapResource {
                         proj,
                         (resolution, resolution),
                         depth_range,
+                        None,
                         shadow_map_phase_mask,
                         shadow_map_feature_mask,
                         RenderFeatureFlagMask::empty(),
@@ -704,75 +941,76 @@ impl MeshAdvShadowMapResource {
         //
         let mut query = <(Entity, Read<DirectionalLightComponent>)>::query();
         for (entity, light) in query.iter(world) {
-            let shadow_view_id = MeshAdvShadowViewId::DirectionalLight(ObjectId::from(*entity));
-            let shadow_view_index = shadow_map_lookup_by_shadow_view_id.get(&shadow_view_id);
-
-            if let Some(&shadow_view_index) = shadow_view_index {
-                //let shadow_map_assignment = shadow_map_atlas_element_assignments[shadow_view_index].as_ref().unwrap();
+            let mut any_cascade_has_shadow_map = false;
+            let mut shadow_view_indices = [None; MAX_DIRECTIONAL_LIGHT_CASCADES];
 
-                let eye_position = light.direction * -40.0;
-                let view = glam::Mat4::look_at_rh(
-                    eye_position,
-                    glam::Vec3::ZERO,
-                    glam::Vec3::new(0.0, 0.0, 1.0),
+            for (cascade_index, cascade_shadow_view_index) in shadow_view_indices
+                .iter_mut()
+                .enumerate()
+                .take(cascades.bounds.len())
+            {
+                let shadow_view_id = MeshAdvShadowViewId::DirectionalLight(
+                    ObjectId::from(*entity),
+                    cascade_index as u8,
                 );
+                let shadow_view_index = shadow_map_lookup_by_shadow_view_id.get(&shadow_view_id);
 
-                let near_plane = 0.25;
-                let far_plane = 1000.0;
-                let ortho_projection_size = 10.0;
-                let view_frustum: ViewFrustumArc =
-                    light.shadow_view_frustum.as_ref().unwrap().clone();
-                let projection = Projection::Orthographic(OrthographicParameters::new(
-                    -ortho_projection_size,
-                    ortho_projection_size,
-                    -ortho_projection_size,
-                    ortho_projection_size,
-                    near_plane,
-                    far_plane,
-                    DepthRange::Reverse,
-                ));
-                let proj = projection.as_rh_mat4();
+                if let Some(&shadow_view_index) = shadow_view_index {
+                    let cascade = cascades.cascade(light.direction, cascade_index);
+                    let view =
+                        glam::Mat4::look_at_rh(cascade.eye_position, cascade.look_at, cascade.up);
 
-                let depth_range = RenderViewDepthRange::from_projection(&projection);
-                shadow_map_render_views_meta[shadow_view_index.0] = Some(
-                    MeshAdvShadowMapRenderViewMeta::new(&view, &proj, depth_range.clone()),
-                );
+                    let projection = cascade.projection();
+                    let proj = projection.as_rh_mat4();
 
-                if shadow_maps_needing_redraw.contains(&shadow_view_index) {
-                    view_frustum.set_projection(&projection).set_transform(
-                        eye_position,
-                        glam::Vec3::ZERO,
-                        glam::Vec3::new(0.0, 0.0, 1.0),
+                    let depth_range = RenderViewDepthRange::from_projection(&projection);
+                    shadow_map_render_views_meta[shadow_view_index.0] = Some(
+                        MeshAdvShadowMapRenderViewMeta::new(&view, &proj, depth_range.clone()),
                     );
+                    *cascade_shadow_view_index = Some(shadow_view_index);
+                    any_cascade_has_shadow_map = true;
 
-                    let shadow_map_assignment = shadow_map_atlas_element_assignments
-                        [shadow_view_index.0]
-                        .as_ref()
-                        .unwrap();
-                    let resolution = shadow_map_assignment.texture_size_pixels() as u32;
+                    if shadow_maps_needing_redraw.contains(&shadow_view_index) {
+                        let view_frustum: ViewFrustumArc =
+                            light.shadow_view_frustums.as_ref().unwrap()[cascade_index].clone();
+                        view_frustum.set_projection(&projection).set_transform(
+                            cascade.eye_position,
+                            cascade.look_at,
+                            cascade.up,
+                        );
 
-                    let view = render_view_set.create_view(
-                        view_frustum,
-                        eye_position,
-                        view,
-                        proj,
-                        (resolution, resolution),
-                        depth_range,
-                        shadow_map_phase_mask,
-                        shadow_map_feature_mask,
-                        RenderFeatureFlagMask::empty(),
-                        "shadow_map_directional".to_string(),
-                    );
+                        let shadow_map_assignment = shadow_map_atlas_element_assignments
+                            [shadow_view_index.0]
+                            .as_ref()
+                            .unwrap();
+                        let resolution = shadow_map_assignment.texture_size_pixels() as u32;
 
-                    out_shadow_map_lookup_by_view_index
-                        .insert(view.view_index(), shadow_view_index);
-                    shadow_map_render_views[shadow_view_index.0] = Some(view);
+                        let view = render_view_set.create_view(
+                            view_frustum,
+                            cascade.eye_position,
+                            view,
+                            proj,
+                            (resolution, resolution),
+                            depth_range,
+                            None,
+                            shadow_map_phase_mask,
+                            shadow_map_feature_mask,
+                            RenderFeatureFlagMask::empty(),
+                            format!("shadow_map_directional_cascade_{}", cascade_index),
+                        );
+
+                        out_shadow_map_lookup_by_view_index
+                            .insert(view.view_index(), shadow_view_index);
+                        shadow_map_render_views[shadow_view_index.0] = Some(view);
+                    }
                 }
+            }
 
+            if any_cascade_has_shadow_map {
                 let light_id = MeshAdvLightId::DirectionalLight(ObjectId::from(*entity));
                 out_shadow_map_lookup_by_light_id.insert(
                     light_id,
-                    MeshAdvShadowMapRenderViewIndices::Single(shadow_view_index),
+                    MeshAdvShadowMapRenderViewIndices::Cascades(shadow_view_indices),
                 );
             }
         }
@@ -854,6 +1092,7 @@ impl MeshAdvShadowMapResource {
                             proj,
                             (resolution, resolution),
                             RenderViewDepthRange::from_projection(&projection),
+                            None,
                             shadow_map_phase_mask,
                             shadow_map_feature_mask,
                             RenderFeatureFlagMask::empty(),
@@ -885,3 +1124,236 @@ impl MeshAdvShadowMapResource {
             .collect();
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const FOV_Y: f32 = std::f32::consts::FRAC_PI_3;
+    const ASPECT_RATIO: f32 = 1.5;
+    const NEAR: f32 = 0.1;
+
+    struct TestView {
+        eye: glam::Vec3,
+        forward: glam::Vec3,
+        right: glam::Vec3,
+        up: glam::Vec3,
+        view_matrix: glam::Mat4,
+        projection_matrix: glam::Mat4,
+        depth_range: RenderViewDepthRange,
+    }
+
+    // A perspective view with an infinite reversed-z projection, like the demo's main view
+    fn test_view() -> TestView {
+        let eye = glam::Vec3::new(3.0, -2.0, 1.5);
+        let forward = glam::Vec3::new(1.0, 2.0, -0.5).normalize();
+        let right = forward.cross(glam::Vec3::Z).normalize();
+        let up = right.cross(forward);
+        let projection = Projection::Perspective(PerspectiveParameters::new(
+            FOV_Y,
+            ASPECT_RATIO,
+            NEAR,
+            10000.0,
+            DepthRange::InfiniteReverse,
+        ));
+
+        TestView {
+            eye,
+            forward,
+            right,
+            up,
+            view_matrix: glam::Mat4::look_at_rh(eye, eye + forward, glam::Vec3::Z),
+            projection_matrix: projection.as_rh_mat4(),
+            depth_range: RenderViewDepthRange::from_projection(&projection),
+        }
+    }
+
+    fn cascade_bounds(
+        view: &TestView,
+        cascade_options: &MeshAdvShadowCascadeOptions,
+    ) -> (Vec<ShadowCascadeBounds>, Vec<f32>) {
+        let mut split_distances = Vec::default();
+        let bounds = MeshAdvShadowMapResource::calculate_shadow_cascade_bounds(
+            view.view_matrix,
+            view.projection_matrix,
+            &view.depth_range,
+            cascade_options,
+            &mut split_distances,
+        );
+        (bounds, split_distances)
+    }
+
+    #[test]
+    fn split_distances_increase_to_max_shadow_distance() {
+        let view = test_view();
+        for &split_lambda in &[0.0, 0.5, 1.0] {
+            let cascade_options = MeshAdvShadowCascadeOptions {
+                split_lambda,
+                ..Default::default()
+            };
+            let (bounds, split_distances) = cascade_bounds(&view, &cascade_options);
+
+            assert_eq!(bounds.len(), 4);
+            assert_eq!(split_distances.len(), 4);
+            assert!(split_distances[0] > NEAR);
+            for pair in split_distances.windows(2) {
+                assert!(pair[0] < pair[1], "{:?}", split_distances);
+            }
+            let last = *split_distances.last().unwrap();
+            assert!((last - 100.0).abs() < 0.001, "{:?}", split_distances);
+        }
+
+        // Uniform splits at lambda 0, logarithmic splits at lambda 1
+        let (_, uniform) = cascade_bounds(
+            &view,
+            &MeshAdvShadowCascadeOptions {
+                split_lambda: 0.0,
+                ..Default::default()
+            },
+        );
+        let (_, logarithmic) = cascade_bounds(
+            &view,
+            &MeshAdvShadowCascadeOptions {
+                split_lambda: 1.0,
+                ..Default::default()
+            },
+        );
+        assert!((uniform[1] - (NEAR + (100.0 - NEAR) * 0.5)).abs() < 0.001);
+        assert!((logarithmic[1] - (NEAR * 100.0f32).sqrt()).abs() < 0.001);
+
+        // No cascades without a depth range to split
+        let (bounds, split_distances) = cascade_bounds(
+            &view,
+            &MeshAdvShadowCascadeOptions {
+                max_shadow_distance: NEAR,
+                ..Default::default()
+            },
+        );
+        assert!(bounds.is_empty());
+        assert!(split_distances.is_empty());
+    }
+
+    #[test]
+    fn cascades_cover_their_blended_slices() {
+        let view = test_view();
+        let tangent = (FOV_Y * 0.5).tan();
+
+        for &blend_fraction in &[0.0, 0.1, 0.5] {
+            let cascade_options = MeshAdvShadowCascadeOptions {
+                blend_fraction,
+                ..Default::default()
+            };
+            let (bounds, split_distances) = cascade_bounds(&view, &cascade_options);
+
+            for (cascade_index, cascade_bounds) in bounds.iter().enumerate() {
+                // Each cascade starts where the shader starts blending the previous cascade into
+                // it. Like the shader, the first cascade is measured from the eye.
+                let slice_far = split_distances[cascade_index];
+                let slice_near = match cascade_index {
+                    0 => NEAR,
+                    1 => split_distances[0] * (1.0 - blend_fraction),
+                    _ => {
+                        let previous_far = split_distances[cascade_index - 1];
+                        let previous_near = split_distances[cascade_index - 2];
+                        previous_far - (previous_far - previous_near) * blend_fraction
+                    }
+                };
+
+                for &depth in &[slice_near, slice_far] {
+                    let half_height = depth * tangent;
+                    let half_width = half_height * ASPECT_RATIO;
+                    for &(x, y) in &[(-1.0, -1.0), (1.0, -1.0), (-1.0, 1.0), (1.0, 1.0)] {
+                        let corner = view.eye
+                            + view.forward * depth
+                            + view.right * (x * half_width)
+                            + view.up * (y * half_height);
+                        let distance = corner.distance(cascade_bounds.center);
+                        assert!(
+                            distance <= cascade_bounds.radius + 0.001,
+                            "cascade {} at depth {} misses a corner by {}",
+                            cascade_index,
+                            depth,
+                            distance - cascade_bounds.radius
+                        );
+                    }
+                }
+            }
+        }
+
+        // Blending starts later cascades earlier, which makes them larger. The first cascade
+        // always starts at the near plane.
+        let (narrow_blend, _) = cascade_bounds(
+            &view,
+            &MeshAdvShadowCascadeOptions {
+                blend_fraction: 0.1,
+                ..Default::default()
+            },
+        );
+        let (wide_blend, _) = cascade_bounds(
+            &view,
+            &MeshAdvShadowCascadeOptions {
+                blend_fraction: 0.5,
+                ..Default::default()
+            },
+        );
+        assert_eq!(narrow_blend[0].radius, wide_blend[0].radius);
+        for cascade_index in 1..4 {
+            assert!(wide_blend[cascade_index].radius > narrow_blend[cascade_index].radius);
+        }
+    }
+
+    #[test]
+    fn cascade_snaps_to_shadow_map_texels() {
+        let direction = glam::Vec3::new(1.0, -2.0, -3.0).normalize();
+        let min_texture_size_pixels = 512;
+        let radius = 10.0;
+        let cascade_at = |center| {
+            DirectionalLightCascade::new(
+                direction,
+                &ShadowCascadeBounds { center, radius },
+                min_texture_size_pixels,
+                100.0,
+            )
+        };
+
+        let cascade = cascade_at(glam::Vec3::new(12.3, -4.56, 7.89));
+        let snapped_center = cascade.look_at;
+        let snap_size = 2.0 * cascade.half_extent / min_texture_size_pixels as f32;
+
+        // Offsets in the light's space, converted to world space
+        let light_rotation = glam::Mat4::look_at_rh(glam::Vec3::ZERO, direction, cascade.up);
+        let light_offset = |x: f32, y: f32, z: f32| {
+            light_rotation
+                .inverse()
+                .transform_vector3(glam::Vec3::new(x, y, z) * snap_size)
+        };
+
+        // Moving less than half a texel keeps the same projection, so shadow edges don't shimmer
+        for &x in &[-0.25, 0.0, 0.25] {
+            for &y in &[-0.25, 0.0, 0.25] {
+                for &z in &[-0.25, 0.0, 0.25] {
+                    let moved = cascade_at(snapped_center + light_offset(x, y, z));
+                    assert_eq!(moved.look_at, cascade.look_at);
+                    assert_eq!(moved.eye_position, cascade.eye_position);
+                }
+            }
+        }
+
+        // Moving a whole texel moves the projection by exactly one texel
+        for offset in [light_offset(1.0, 0.0, 0.0), light_offset(0.0, -1.0, 0.0)] {
+            let moved = cascade_at(snapped_center + offset);
+            assert!(moved.look_at.abs_diff_eq(cascade.look_at + offset, 0.0001));
+            assert!(moved
+                .eye_position
+                .abs_diff_eq(cascade.eye_position + offset, 0.0001));
+        }
+
+        // The extent leaves room for the bounding sphere wherever snapping moves the center
+        for step in 0..50 {
+            let center =
+                glam::Vec3::new(12.3, -4.56, 7.89) + glam::Vec3::splat(step as f32 * 0.013);
+            let moved = cascade_at(center);
+            assert!(moved.look_at.distance(center) + radius <= moved.half_extent);
+        }
+    }
+}
@@ -0,0 +1,35 @@
+use crate::assets::mesh_adv::MeshAdvBindlessBuffers;
+use crate::shaders::mesh_adv::mesh_skinning_comp;
+use rafx::framework::{BufferResource, ResourceArc, MAX_FRAMES_IN_FLIGHT};
+
+// The skinned vertex region of the vertex heap is split into slices that are used round-robin so
+// that the skinning pass never overwrites vertices that a frame still in flight is drawing
+const SKINNED_VERTEX_SLICE_COUNT: u32 = MAX_FRAMES_IN_FLIGHT as u32 + 1;
+
+// Filled by the mesh_adv prepare job and consumed by the skinning compute pass, which runs before
+// anything that draws meshes
+#[derive(Default)]
+pub struct MeshAdvSkinningRenderResource {
+    // Joint matrices of every skinned mesh this frame, indexed by ConfigUniform::joint_palette_first_index
+    pub joint_palettes: Option<ResourceArc<BufferResource>>,
+    // One dispatch per skinned mesh part
+    pub jobs: Vec<mesh_skinning_comp::ConfigUniform>,
+    next_slice_index: u32,
+}
+
+impl MeshAdvSkinningRenderResource {
+    // Returns the byte range in the vertex heap that skinned vertices for the current frame can be
+    // written to
+    pub(super) fn next_skinned_vertex_slice(
+        &mut self,
+        buffers: &MeshAdvBindlessBuffers,
+    ) -> (u32, u32) {
+        let slice_size = buffers.skinned_vertex_size_in_bytes / SKINNED_VERTEX_SLICE_COUNT;
+        let slice_index = self.next_slice_index;
+        self.next_slice_index = (self.next_slice_index + 1) % SKINNED_VERTEX_SLICE_COUNT;
+        (
+            buffers.skinned_vertex_offset_in_bytes + slice_index * slice_size,
+            slice_size,
+        )
+    }
+}
@@ -11,6 +11,12 @@ pub mod sprite;
 #[cfg(all(not(feature = "basic-pipeline"), feature = "legion"))]
 pub mod mesh_adv;
 
+#[cfg(all(not(feature = "basic-pipeline"), feature = "legion"))]
+pub mod decal;
+
+#[cfg(all(not(feature = "basic-pipeline"), feature = "legion"))]
+pub mod particles;
+
 #[cfg(feature = "egui")]
 pub mod egui;
 
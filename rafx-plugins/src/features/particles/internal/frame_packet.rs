@@ -0,0 +1,71 @@
+use super::*;
+use crate::assets::particles::ParticleEmitterAsset;
+use glam::{Mat4, Vec3};
+use rafx::framework::render_features::render_features_prelude::*;
+use rafx::framework::{BufferResource, DescriptorSetArc, MaterialPassResource, ResourceArc};
+
+pub struct ParticleRenderFeatureTypes;
+
+//---------
+// EXTRACT
+//---------
+
+pub struct ParticlePerFrameData {
+    pub particle_material_pass: Option<ResourceArc<MaterialPassResource>>,
+    pub particle_oit_material_pass: Option<ResourceArc<MaterialPassResource>>,
+}
+
+pub struct ParticleRenderObjectInstanceData {
+    pub transform: Mat4,
+    pub position: Vec3,
+    pub emitter: ParticleEmitterAsset,
+}
+
+impl FramePacketData for ParticleRenderFeatureTypes {
+    type PerFrameData = ParticlePerFrameData;
+    type RenderObjectInstanceData = Option<ParticleRenderObjectInstanceData>;
+    type PerViewData = ();
+    type RenderObjectInstancePerViewData = ();
+}
+
+pub type ParticleFramePacket = FramePacket<ParticleRenderFeatureTypes>;
+
+//---------
+// PREPARE
+//---------
+
+impl SubmitPacketData for ParticleRenderFeatureTypes {
+    type PerFrameSubmitData = ParticlePerFrameSubmitData;
+    type RenderObjectInstanceSubmitData = ();
+    type PerViewSubmitData = ParticlePerViewSubmitData;
+    type RenderObjectInstancePerViewSubmitData = ();
+    type SubmitNodeData = ParticleDrawCall;
+
+    type RenderFeature = ParticleRenderFeature;
+}
+
+pub type ParticleSubmitPacket = SubmitPacket<ParticleRenderFeatureTypes>;
+
+//-------
+// WRITE
+//-------
+
+#[derive(Default)]
+pub struct ParticlePerFrameSubmitData {
+    // The particle or particle_oit pass, depending on how the pipeline draws TransparentRenderPhase
+    pub material_pass: Option<ResourceArc<MaterialPassResource>>,
+    // Indexed by render object instance, None if the emitter was not extracted
+    pub emitters: Vec<Option<ParticleDrawCall>>,
+}
+
+#[derive(Default)]
+pub struct ParticlePerViewSubmitData {
+    pub descriptor_set_arc: Option<DescriptorSetArc>,
+}
+
+#[derive(Clone)]
+pub struct ParticleDrawCall {
+    pub per_emitter_descriptor_set: DescriptorSetArc,
+    // Holds the indirect draw arguments written by the simulation
+    pub indirect_args: ResourceArc<BufferResource>,
+}
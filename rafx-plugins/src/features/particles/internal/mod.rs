@@ -0,0 +1,4 @@
+mod frame_packet;
+pub use frame_packet::*;
+
+use super::*;
@@ -0,0 +1,109 @@
+use rafx::render_feature_extract_job_predule::*;
+
+use super::*;
+use crate::components::TransformComponent;
+use hydrate_base::handle::Handle;
+use legion::{EntityStore, World};
+use rafx::assets::{AssetManagerExtractRef, AssetManagerRenderResource, MaterialAsset};
+use rafx::base::resource_ref_map::ResourceRefBorrow;
+
+pub struct ParticleExtractJob<'extract> {
+    world: ResourceRefBorrow<'extract, World>,
+    asset_manager: AssetManagerExtractRef,
+    particle_material: Handle<MaterialAsset>,
+    render_objects: ParticleEmitterRenderObjectSet,
+}
+
+impl<'extract> ParticleExtractJob<'extract> {
+    pub fn new(
+        extract_context: &RenderJobExtractContext<'extract>,
+        frame_packet: Box<ParticleFramePacket>,
+        particle_material: Handle<MaterialAsset>,
+        render_objects: ParticleEmitterRenderObjectSet,
+    ) -> Arc<dyn RenderFeatureExtractJob<'extract> + 'extract> {
+        Arc::new(ExtractJob::new(
+            Self {
+                world: extract_context.extract_resources.fetch::<World>(),
+                asset_manager: extract_context
+                    .render_resources
+                    .fetch::<AssetManagerRenderResource>()
+                    .extract_ref(),
+                particle_material,
+                render_objects,
+            },
+            extract_context,
+            frame_packet,
+        ))
+    }
+}
+
+impl<'extract> ExtractJobEntryPoints<'extract> for ParticleExtractJob<'extract> {
+    fn begin_per_frame_extract(
+        &self,
+        context: &ExtractPerFrameContext<'extract, '_, Self>,
+    ) {
+        let particle_material = self
+            .asset_manager
+            .committed_asset(&self.particle_material)
+            .unwrap();
+
+        context
+            .frame_packet()
+            .per_frame_data()
+            .set(ParticlePerFrameData {
+                particle_material_pass: particle_material.get_material_pass_by_name("particle"),
+                particle_oit_material_pass: particle_material
+                    .get_material_pass_by_name("particle_oit"),
+            });
+    }
+
+    fn extract_render_object_instance(
+        &self,
+        job_context: &mut RenderObjectsJobContext<'extract, ParticleEmitterRenderObject>,
+        context: &ExtractRenderObjectInstanceContext<'extract, '_, Self>,
+    ) {
+        let render_object_static_data = job_context
+            .render_objects
+            .get_id(context.render_object_id());
+
+        // Emitters are skipped until their asset has loaded
+        let instance_data = self
+            .asset_manager
+            .committed_asset(&render_object_static_data.emitter)
+            .map(|emitter| {
+                let entry = self.world.entry_ref(context.object_id().into()).unwrap();
+                let transform_component = entry.get_component::<TransformComponent>().unwrap();
+                ParticleRenderObjectInstanceData {
+                    transform: glam::Mat4::from_scale_rotation_translation(
+                        transform_component.scale,
+                        transform_component.rotation,
+                        transform_component.translation,
+                    ),
+                    position: transform_component.translation,
+                    emitter: emitter.clone(),
+                }
+            });
+
+        context.set_render_object_instance_data(instance_data);
+    }
+
+    fn feature_debug_constants(&self) -> &'static RenderFeatureDebugConstants {
+        super::render_feature_debug_constants()
+    }
+
+    fn feature_index(&self) -> RenderFeatureIndex {
+        super::render_feature_index()
+    }
+
+    fn new_render_object_instance_job_context(
+        &'extract self
+    ) -> Option<RenderObjectsJobContext<'extract, ParticleEmitterRenderObject>> {
+        Some(RenderObjectsJobContext::new(self.render_objects.read()))
+    }
+
+    type RenderObjectInstanceJobContextT =
+        RenderObjectsJobContext<'extract, ParticleEmitterRenderObject>;
+    type RenderObjectInstancePerViewJobContextT = DefaultJobContext;
+
+    type FramePacketDataT = ParticleRenderFeatureTypes;
+}
@@ -0,0 +1,221 @@
+use rafx::render_feature_prepare_job_predule::*;
+
+use super::*;
+use crate::phases::TransparentRenderPhase;
+use crate::shaders::particles::particle_vert;
+use rafx::base::resource_map::ReadBorrow;
+use rafx::framework::DescriptorSetBindings;
+use rafx::renderer::{InvalidResources, TimeRenderResource};
+
+pub struct ParticlePrepareJob<'prepare> {
+    pipeline_state: ReadBorrow<'prepare, ParticleRenderPipelineState>,
+    invalid_resources: ReadBorrow<'prepare, InvalidResources>,
+    dt: f32,
+}
+
+impl<'prepare> ParticlePrepareJob<'prepare> {
+    pub fn new(
+        prepare_context: &RenderJobPrepareContext<'prepare>,
+        frame_packet: Box<ParticleFramePacket>,
+        submit_packet: Box<ParticleSubmitPacket>,
+    ) -> Arc<dyn RenderFeaturePrepareJob<'prepare> + 'prepare> {
+        Arc::new(PrepareJob::new(
+            Self {
+                pipeline_state: {
+                    prepare_context
+                        .render_resources
+                        .fetch::<ParticleRenderPipelineState>()
+                },
+                invalid_resources: { prepare_context.render_resources.fetch::<InvalidResources>() },
+                dt: {
+                    prepare_context
+                        .render_resources
+                        .fetch::<TimeRenderResource>()
+                        .previous_update_dt()
+                },
+            },
+            prepare_context,
+            frame_packet,
+            submit_packet,
+        ))
+    }
+}
+
+impl<'prepare> PrepareJobEntryPoints<'prepare> for ParticlePrepareJob<'prepare> {
+    fn begin_per_frame_prepare(
+        &self,
+        context: &PreparePerFrameContext<'prepare, '_, Self>,
+    ) {
+        let mut simulation = context
+            .render_resources()
+            .fetch_mut::<ParticleSimulationRenderResource>();
+        simulation.begin_frame();
+
+        // Particles are drawn by whichever pass writes TransparentRenderPhase, which may be the
+        // OIT accumulation pass
+        let per_frame_data = context.per_frame_data();
+        let material_pass = if self.pipeline_state.use_order_independent_transparency {
+            per_frame_data.particle_oit_material_pass.clone()
+        } else {
+            per_frame_data.particle_material_pass.clone()
+        };
+
+        let mut per_frame_submit_data = ParticlePerFrameSubmitData::default();
+        if let Some(material_pass) = &material_pass {
+            let dyn_resource_allocator_set = context
+                .resource_context()
+                .create_dyn_resource_allocator_set();
+            let mut descriptor_set_allocator =
+                context.resource_context().create_descriptor_set_allocator();
+            let per_emitter_descriptor_set_layout = &material_pass.get_raw().descriptor_set_layouts
+                [particle_vert::PER_EMITTER_DATA_DESCRIPTOR_SET_INDEX];
+
+            let frame_packet = context.frame_packet();
+            let invalid_image = &self.invalid_resources.invalid_image_color;
+            per_frame_submit_data.emitters = frame_packet
+                .render_object_instances()
+                .iter()
+                .enumerate()
+                .map(|(render_object_instance_id, render_object_instance)| {
+                    // The emitter was not extracted, can occur if its asset is not loaded
+                    let instance_data = frame_packet
+                        .render_object_instances_data()
+                        .get(render_object_instance_id)
+                        .as_ref()?;
+
+                    let (buffers, alive_list_index) = simulation
+                        .simulate_emitter(
+                            &dyn_resource_allocator_set,
+                            (
+                                render_object_instance.object_id,
+                                render_object_instance.render_object_id,
+                            ),
+                            &instance_data.emitter.inner,
+                            instance_data.transform,
+                            self.dt,
+                        )
+                        .unwrap();
+
+                    let emitter = &instance_data.emitter.inner;
+                    let mut dyn_descriptor_set = descriptor_set_allocator
+                        .create_dyn_descriptor_set_uninitialized(per_emitter_descriptor_set_layout)
+                        .unwrap();
+                    particle_vert::DescriptorSet2::set_args_static(
+                        &mut dyn_descriptor_set,
+                        particle_vert::DescriptorSet2Args {
+                            per_emitter_data: &particle_vert::PerEmitterDataUniform {
+                                start_color: emitter.data.start_color,
+                                end_color: emitter.data.end_color,
+                                start_size: emitter.data.start_size,
+                                end_size: emitter.data.end_size,
+                                soft_particle_distance: emitter.data.soft_particle_distance,
+                                has_texture: emitter.texture.is_some() as u32,
+                            },
+                            particle_texture: emitter.texture.as_ref().unwrap_or(invalid_image),
+                        },
+                    );
+                    dyn_descriptor_set.set_buffer(
+                        particle_vert::PARTICLES_DESCRIPTOR_BINDING_INDEX as u32,
+                        &buffers.particles,
+                    );
+                    dyn_descriptor_set.set_buffer(
+                        particle_vert::ALIVE_LIST_DESCRIPTOR_BINDING_INDEX as u32,
+                        &buffers.alive_lists[alive_list_index],
+                    );
+                    dyn_descriptor_set
+                        .flush(&mut descriptor_set_allocator)
+                        .unwrap();
+
+                    Some(ParticleDrawCall {
+                        per_emitter_descriptor_set: dyn_descriptor_set.descriptor_set().clone(),
+                        indirect_args: buffers.indirect_args,
+                    })
+                })
+                .collect();
+
+            descriptor_set_allocator.flush_changes().unwrap();
+        }
+
+        per_frame_submit_data.material_pass = material_pass;
+        context
+            .submit_packet()
+            .per_frame_submit_data()
+            .set(per_frame_submit_data);
+    }
+
+    fn end_per_view_prepare(
+        &self,
+        context: &PreparePerViewContext<'prepare, '_, Self>,
+    ) {
+        let per_frame_submit_data = context.per_frame_submit_data();
+        let material_pass = match &per_frame_submit_data.material_pass {
+            Some(material_pass) => material_pass,
+            None => return,
+        };
+
+        let mut descriptor_set_allocator =
+            context.resource_context().create_descriptor_set_allocator();
+        let descriptor_set_layouts = &material_pass.get_raw().descriptor_set_layouts;
+
+        let view = context.view();
+        let view_packet = context.view_packet();
+        let view_submit_packet = context.view_submit_packet();
+
+        let per_view_descriptor_set = descriptor_set_allocator
+            .create_descriptor_set_with_writer(
+                &descriptor_set_layouts[particle_vert::PER_VIEW_DATA_DESCRIPTOR_SET_INDEX],
+                particle_vert::DescriptorSet0Args {
+                    per_view_data: &particle_vert::PerViewDataUniform {
+                        view: view.view_matrix().to_cols_array_2d(),
+                        proj: view.projection_matrix().to_cols_array_2d(),
+                        jitter_amount: self.pipeline_state.jitter_amount.into(),
+                        ..Default::default()
+                    },
+                },
+            )
+            .unwrap();
+
+        for render_object_instance in view_packet.render_object_instances() {
+            let render_object_instance_id =
+                render_object_instance.render_object_instance_id as usize;
+            let draw_call = match &per_frame_submit_data.emitters[render_object_instance_id] {
+                Some(draw_call) => draw_call,
+                None => continue,
+            };
+
+            let position = context
+                .render_object_instances_data()
+                .get(render_object_instance_id)
+                .as_ref()
+                .unwrap()
+                .position;
+            let distance = view.eye_position().distance(position);
+
+            view_submit_packet.push_submit_node::<TransparentRenderPhase>(
+                draw_call.clone(),
+                0,
+                distance,
+            );
+        }
+
+        view_submit_packet
+            .per_view_submit_data()
+            .set(ParticlePerViewSubmitData {
+                descriptor_set_arc: Some(per_view_descriptor_set),
+            });
+    }
+
+    fn feature_debug_constants(&self) -> &'static RenderFeatureDebugConstants {
+        super::render_feature_debug_constants()
+    }
+
+    fn feature_index(&self) -> RenderFeatureIndex {
+        super::render_feature_index()
+    }
+
+    type RenderObjectInstanceJobContextT = DefaultJobContext;
+    type RenderObjectInstancePerViewJobContextT = DefaultJobContext;
+
+    type FramePacketDataT = ParticleRenderFeatureTypes;
+    type SubmitPacketDataT = ParticleRenderFeatureTypes;
+}
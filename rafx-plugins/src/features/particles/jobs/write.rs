@@ -0,0 +1,126 @@
+use rafx::render_feature_write_job_prelude::*;
+
+use super::*;
+use rafx::api::RafxPrimitiveTopology;
+use rafx::framework::{MaterialPassResource, ResourceArc, VertexDataSetLayout};
+use rafx::render_features::{BeginSubmitNodeBatchArgs, RenderSubmitNodeArgs};
+use std::marker::PhantomData;
+
+lazy_static::lazy_static! {
+    pub static ref PARTICLE_VERTEX_LAYOUT : VertexDataSetLayout = {
+        VertexDataSetLayout::new(vec![], RafxPrimitiveTopology::TriangleList)
+    };
+}
+
+pub struct ParticleWriteJob<'write> {
+    material_pass: Option<ResourceArc<MaterialPassResource>>,
+    _frame_packet: Box<ParticleFramePacket>,
+    submit_packet: Box<ParticleSubmitPacket>,
+    phantom: PhantomData<&'write ()>,
+}
+
+impl<'write> ParticleWriteJob<'write> {
+    pub fn new(
+        _write_context: &RenderJobWriteContext<'write>,
+        frame_packet: Box<ParticleFramePacket>,
+        submit_packet: Box<ParticleSubmitPacket>,
+    ) -> Arc<dyn RenderFeatureWriteJob<'write> + 'write> {
+        Arc::new(Self {
+            material_pass: {
+                submit_packet
+                    .per_frame_submit_data()
+                    .get()
+                    .material_pass
+                    .clone()
+            },
+            _frame_packet: frame_packet,
+            submit_packet,
+            phantom: Default::default(),
+        })
+    }
+}
+
+impl<'write> RenderFeatureWriteJob<'write> for ParticleWriteJob<'write> {
+    fn begin_submit_node_batch(
+        &self,
+        write_context: &mut RenderJobCommandBufferContext,
+        args: BeginSubmitNodeBatchArgs,
+    ) -> RafxResult<()> {
+        if !args.feature_changed {
+            return Ok(());
+        }
+
+        profiling::scope!(super::render_feature_debug_constants().begin_submit_node_batch);
+
+        let command_buffer = &write_context.command_buffer;
+
+        let pipeline = write_context
+            .resource_context()
+            .graphics_pipeline_cache()
+            .get_or_create_graphics_pipeline(
+                Some(args.render_phase_index),
+                self.material_pass.as_ref().unwrap(),
+                &write_context.render_target_meta,
+                &PARTICLE_VERTEX_LAYOUT,
+            )?;
+
+        command_buffer.cmd_bind_pipeline(&pipeline.get_raw().pipeline)?;
+
+        let view_submit_packet = self.submit_packet.view_submit_packet(args.view_frame_index);
+        view_submit_packet
+            .per_view_submit_data()
+            .get()
+            .descriptor_set_arc
+            .as_ref()
+            .unwrap()
+            .bind(command_buffer)?;
+
+        // Soft particles fade against the depth prepass, bound by the pipeline pass that is
+        // writing TransparentRenderPhase
+        let depth_descriptor_set = write_context
+            .graph_context
+            .render_resources()
+            .fetch::<ParticleRenderPipelineState>()
+            .depth_descriptor_set
+            .clone();
+
+        depth_descriptor_set.unwrap().bind(command_buffer)?;
+
+        Ok(())
+    }
+
+    fn render_submit_node(
+        &self,
+        write_context: &mut RenderJobCommandBufferContext,
+        args: RenderSubmitNodeArgs,
+    ) -> RafxResult<()> {
+        profiling::scope!(super::render_feature_debug_constants().render_submit_node);
+
+        let command_buffer = &write_context.command_buffer;
+
+        let view_submit_packet = self.submit_packet.view_submit_packet(args.view_frame_index);
+        let submit_node = view_submit_packet
+            .get_submit_node_data_from_render_phase(args.render_phase_index, args.submit_node_id);
+
+        submit_node
+            .per_emitter_descriptor_set
+            .bind(command_buffer)?;
+
+        // The particle simulation writes the vertex and instance count of the alive particles
+        command_buffer.cmd_draw_indirect(
+            &submit_node.indirect_args.get_raw().buffer,
+            PARTICLE_INDIRECT_ARGS_DRAW_OFFSET,
+            1,
+        )?;
+
+        Ok(())
+    }
+
+    fn feature_debug_constants(&self) -> &'static RenderFeatureDebugConstants {
+        super::render_feature_debug_constants()
+    }
+
+    fn feature_index(&self) -> RenderFeatureIndex {
+        super::render_feature_index()
+    }
+}
@@ -0,0 +1,50 @@
+use super::ParticleRenderFeature;
+use crate::assets::particles::ParticleEmitterAsset;
+use glam::Vec3;
+use hydrate_base::handle::Handle;
+use rafx::rafx_visibility::{PolygonSoup, PolygonSoupIndex, VisibleBounds};
+use rafx::render_features::RenderObjectSet;
+
+// A GPU-simulated particle emitter at the entity's TransformComponent. Particles are spawned
+// relative to the emitter and then simulated in world space, so moving the emitter leaves a trail.
+#[derive(Clone)]
+pub struct ParticleEmitterRenderObject {
+    pub emitter: Handle<ParticleEmitterAsset>,
+    // Half size of a box around the emitter that its particles stay inside of. Particles are not
+    // simulated or drawn while this box is culled, so it should cover how far particles can travel.
+    pub bounds_half_extents: Vec3,
+}
+
+impl ParticleEmitterRenderObject {
+    pub fn new(
+        emitter: Handle<ParticleEmitterAsset>,
+        bounds_half_extents: Vec3,
+    ) -> Self {
+        ParticleEmitterRenderObject {
+            emitter,
+            bounds_half_extents,
+        }
+    }
+
+    // Bounds of the emitter's box, used to register the entity's VisibilityObjectArc
+    pub fn visible_bounds(&self) -> VisibleBounds {
+        let half_extents = self.bounds_half_extents;
+        let vertex_positions = (0..8)
+            .map(|corner| {
+                Vec3::new(
+                    if corner & 1 != 0 { 1.0 } else { -1.0 },
+                    if corner & 2 != 0 { 1.0 } else { -1.0 },
+                    if corner & 4 != 0 { 1.0 } else { -1.0 },
+                ) * half_extents
+            })
+            .collect();
+
+        VisibleBounds::from(PolygonSoup {
+            vertex_positions,
+            index: PolygonSoupIndex::None,
+        })
+    }
+}
+
+pub type ParticleEmitterRenderObjectSet =
+    RenderObjectSet<ParticleRenderFeature, ParticleEmitterRenderObject>;
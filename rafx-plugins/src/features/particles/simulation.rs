@@ -0,0 +1,216 @@
+use crate::assets::particles::ParticleEmitterAssetInner;
+use crate::shaders::particles::{particle_init_comp, particle_simulate_comp, particle_spawn_comp};
+use fnv::FnvHashMap;
+use glam::{Mat4, Vec3};
+use rafx::api::{RafxBufferDef, RafxMemoryUsage, RafxResourceType, RafxResult};
+use rafx::framework::{BufferResource, DynResourceAllocatorSet, ResourceArc};
+use rafx::render_features::RenderObjectId;
+use rafx::visibility::ObjectId;
+use std::sync::Arc;
+
+// Size of ParticleState in particles.glsl
+const PARTICLE_STATE_SIZE: u64 = 12;
+
+// Byte offsets of the arguments in ParticleIndirectArgs, see particles.glsl
+pub const PARTICLE_INDIRECT_ARGS_DISPATCH_OFFSET: u32 = 0;
+pub const PARTICLE_INDIRECT_ARGS_DRAW_OFFSET: u32 = 12;
+const PARTICLE_INDIRECT_ARGS_SIZE: u64 = 28;
+
+// Size of Particle in particles.glsl
+const PARTICLE_SIZE: u64 = 32;
+
+// Local size of particle_init.comp and particle_spawn.comp
+pub const PARTICLE_THREAD_GROUP_SIZE: u32 = 64;
+
+// Emitters that have not been visible for this many frames release their buffers. They start over
+// with no particles if they become visible again.
+const PARTICLE_EMITTER_EVICT_FRAME_COUNT: u64 = 300;
+
+// The GPU buffers of one emitter. They persist across frames and are only written by the
+// particle simulation pass.
+#[derive(Clone)]
+pub struct ParticleEmitterBuffers {
+    pub particles: ResourceArc<BufferResource>,
+    pub alive_lists: [ResourceArc<BufferResource>; 2],
+    pub dead_list: ResourceArc<BufferResource>,
+    pub state: ResourceArc<BufferResource>,
+    pub indirect_args: ResourceArc<BufferResource>,
+}
+
+impl ParticleEmitterBuffers {
+    fn new(
+        dyn_resource_allocator_set: &DynResourceAllocatorSet,
+        max_particles: u32,
+    ) -> RafxResult<Self> {
+        let create_buffer = |size: u64, resource_type: RafxResourceType| -> RafxResult<_> {
+            let buffer =
+                dyn_resource_allocator_set
+                    .device_context
+                    .create_buffer(&RafxBufferDef {
+                        size,
+                        memory_usage: RafxMemoryUsage::GpuOnly,
+                        resource_type,
+                        ..Default::default()
+                    })?;
+            Ok(dyn_resource_allocator_set.insert_buffer(buffer))
+        };
+
+        let max_particles = max_particles.max(1) as u64;
+        let index_list_size = max_particles * std::mem::size_of::<u32>() as u64;
+        Ok(ParticleEmitterBuffers {
+            particles: create_buffer(
+                max_particles * PARTICLE_SIZE,
+                RafxResourceType::BUFFER_READ_WRITE,
+            )?,
+            alive_lists: [
+                create_buffer(index_list_size, RafxResourceType::BUFFER_READ_WRITE)?,
+                create_buffer(index_list_size, RafxResourceType::BUFFER_READ_WRITE)?,
+            ],
+            dead_list: create_buffer(index_list_size, RafxResourceType::BUFFER_READ_WRITE)?,
+            state: create_buffer(PARTICLE_STATE_SIZE, RafxResourceType::BUFFER_READ_WRITE)?,
+            indirect_args: create_buffer(
+                PARTICLE_INDIRECT_ARGS_SIZE,
+                RafxResourceType::BUFFER_READ_WRITE | RafxResourceType::INDIRECT_BUFFER,
+            )?,
+        })
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &ResourceArc<BufferResource>> {
+        [
+            &self.particles,
+            &self.alive_lists[0],
+            &self.alive_lists[1],
+            &self.dead_list,
+            &self.state,
+            &self.indirect_args,
+        ]
+        .into_iter()
+    }
+}
+
+// One emitter's work for the particle simulation pass this frame
+pub struct ParticleSimulationJob {
+    pub buffers: ParticleEmitterBuffers,
+    // Set if the buffers were just created and must be reset before anything else
+    pub init: Option<particle_init_comp::ConfigUniform>,
+    pub spawn: particle_spawn_comp::ConfigUniform,
+    pub simulate: particle_simulate_comp::ConfigUniform,
+    // The alive list that is spawned into and simulated. Survivors are compacted into the other one.
+    pub alive_list_index: usize,
+    key: (ObjectId, RenderObjectId),
+}
+
+struct ParticleEmitterSimulationState {
+    // Buffers are recreated if the asset is reloaded
+    emitter: Arc<ParticleEmitterAssetInner>,
+    buffers: ParticleEmitterBuffers,
+    alive_list_index: usize,
+    // Fraction of a particle left over from previous frames
+    spawn_accumulator: f32,
+    last_used_frame: u64,
+}
+
+// Owns the particles of every emitter. Filled by the particle prepare job and consumed by the
+// particle simulation pass, which runs before anything that draws TransparentRenderPhase.
+#[derive(Default)]
+pub struct ParticleSimulationRenderResource {
+    emitters: FnvHashMap<(ObjectId, RenderObjectId), ParticleEmitterSimulationState>,
+    pub jobs: Vec<ParticleSimulationJob>,
+    frame_index: u64,
+}
+
+impl ParticleSimulationRenderResource {
+    pub(super) fn begin_frame(&mut self) {
+        // Jobs are only left over if the particle simulation pass did not run. Emitters that were
+        // never initialized have to be recreated.
+        for job in self.jobs.drain(..) {
+            if job.init.is_some() {
+                self.emitters.remove(&job.key);
+            }
+        }
+        self.frame_index += 1;
+
+        let frame_index = self.frame_index;
+        self.emitters.retain(|_, emitter| {
+            frame_index - emitter.last_used_frame < PARTICLE_EMITTER_EVICT_FRAME_COUNT
+        });
+    }
+
+    // Queues the simulation of an emitter for this frame. Returns the emitter's buffers and the
+    // alive list that will hold the particles to draw.
+    pub(super) fn simulate_emitter(
+        &mut self,
+        dyn_resource_allocator_set: &DynResourceAllocatorSet,
+        key: (ObjectId, RenderObjectId),
+        emitter: &Arc<ParticleEmitterAssetInner>,
+        transform: Mat4,
+        dt: f32,
+    ) -> RafxResult<(ParticleEmitterBuffers, usize)> {
+        let data = &emitter.data;
+
+        let is_simulating = matches!(
+            self.emitters.get(&key),
+            Some(state) if Arc::ptr_eq(&state.emitter, emitter)
+        );
+
+        let mut init = None;
+        if !is_simulating {
+            init = Some(particle_init_comp::ConfigUniform {
+                max_particles: data.max_particles,
+                ..Default::default()
+            });
+
+            self.emitters.insert(
+                key,
+                ParticleEmitterSimulationState {
+                    emitter: emitter.clone(),
+                    buffers: ParticleEmitterBuffers::new(
+                        dyn_resource_allocator_set,
+                        data.max_particles,
+                    )?,
+                    alive_list_index: 0,
+                    spawn_accumulator: 0.0,
+                    last_used_frame: self.frame_index,
+                },
+            );
+        }
+
+        let state = self.emitters.get_mut(&key).unwrap();
+        state.last_used_frame = self.frame_index;
+
+        state.spawn_accumulator += data.spawn_rate * dt;
+        let spawn_count = state.spawn_accumulator.floor();
+        state.spawn_accumulator -= spawn_count;
+
+        let alive_list_index = state.alive_list_index;
+        state.alive_list_index = 1 - alive_list_index;
+
+        self.jobs.push(ParticleSimulationJob {
+            buffers: state.buffers.clone(),
+            init,
+            spawn: particle_spawn_comp::ConfigUniform {
+                emitter_transform: transform.to_cols_array_2d(),
+                initial_velocity: transform
+                    .transform_vector3(Vec3::from(data.initial_velocity))
+                    .into(),
+                velocity_randomness: data.velocity_randomness,
+                spawn_radius: data.spawn_radius,
+                min_lifetime: data.min_lifetime,
+                max_lifetime: data.max_lifetime.max(data.min_lifetime),
+                spawn_count: (spawn_count as u32).min(data.max_particles),
+                seed: rand::random(),
+                ..Default::default()
+            },
+            simulate: particle_simulate_comp::ConfigUniform {
+                gravity: data.gravity,
+                drag: data.drag,
+                dt,
+                ..Default::default()
+            },
+            alive_list_index,
+            key,
+        });
+
+        Ok((state.buffers.clone(), 1 - alive_list_index))
+    }
+}
@@ -3,14 +3,16 @@ use rafx::render_feature_prepare_job_predule::*;
 use super::*;
 use crate::phases::UiRenderPhase;
 use crate::shaders::text::{text_frag, text_vert};
-use rafx::api::{RafxBufferDef, RafxDeviceContext};
+use rafx::api::{RafxBufferDef, RafxDeviceContext, RafxExtents2D};
 use rafx::base::resource_map::WriteBorrow;
 use rafx::framework::ResourceContext;
+use rafx::renderer::SwapchainRenderResource;
 
 pub struct TextPrepareJob<'prepare> {
     font_atlas_cache: TrustCell<WriteBorrow<'prepare, FontAtlasCache>>,
     resource_context: ResourceContext,
     device_context: RafxDeviceContext,
+    swapchain_extents: RafxExtents2D,
 }
 
 impl<'prepare> TextPrepareJob<'prepare> {
@@ -30,6 +32,13 @@ impl<'prepare> TextPrepareJob<'prepare> {
                     )
                 },
                 device_context: prepare_context.device_context.clone(),
+                swapchain_extents: prepare_context
+                    .render_resources
+                    .fetch::<SwapchainRenderResource>()
+                    .surface_info()
+                    .unwrap()
+                    .swapchain_surface_info
+                    .extents,
             },
             prepare_context,
             frame_packet,
@@ -151,11 +160,12 @@ impl<'prepare> PrepareJobEntryPoints<'prepare> for TextPrepareJob<'prepare> {
         // Setup the vertex shader descriptor set
         //
 
-        let view = context.view();
+        // Text is positioned in swapchain pixels, which may not match the view extents if the
+        // pipeline renders the view at a lower resolution and upscales it
         let proj = glam::Mat4::orthographic_rh(
             0.0,
-            view.extents_width() as f32,
-            view.extents_height() as f32,
+            self.swapchain_extents.width as f32,
+            self.swapchain_extents.height as f32,
             0.0,
             -1000.0,
             100.0,
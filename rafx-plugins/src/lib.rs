@@ -11,6 +11,7 @@ mod shaders;
 use crate::assets::anim::BlenderAnimAssetPlugin;
 use crate::assets::font::FontAssetPlugin;
 use crate::assets::ldtk::LdtkAssetPlugin;
+use crate::assets::particles::ParticleEmitterAssetPlugin;
 
 #[cfg(all(not(feature = "basic-pipeline"), feature = "legion"))]
 use crate::assets::mesh_adv::{
@@ -30,7 +31,8 @@ pub fn register_default_hydrate_plugins(
 ) -> AssetPluginRegistryBuilders {
     plugin_registry = plugin_registry
         .register_plugin::<LdtkAssetPlugin>()
-        .register_plugin::<FontAssetPlugin>();
+        .register_plugin::<FontAssetPlugin>()
+        .register_plugin::<ParticleEmitterAssetPlugin>();
 
     #[cfg(all(not(feature = "basic-pipeline"), feature = "legion"))]
     {
@@ -0,0 +1,13 @@
+use rafx::render_features::RenderFeatureSubmitNode;
+
+rafx::declare_render_phase!(
+    DecalRenderPhase,
+    DECAL_RENDER_PHASE_INDEX,
+    decal_render_phase_sort_submit_nodes
+);
+
+#[profiling::function]
+fn decal_render_phase_sort_submit_nodes(submit_nodes: &mut Vec<RenderFeatureSubmitNode>) {
+    // Decals blend over each other, so they are drawn in the order given by their sort key
+    submit_nodes.sort_by_key(|x| x.sort_key());
+}
@@ -7,6 +7,9 @@ pub use debug_pip_render_phase::DebugPipRenderPhase;
 mod depth_prepass_render_phase;
 pub use depth_prepass_render_phase::DepthPrepassRenderPhase;
 
+mod decal_render_phase;
+pub use decal_render_phase::DecalRenderPhase;
+
 mod opaque_render_phase;
 pub use opaque_render_phase::OpaqueRenderPhase;
 
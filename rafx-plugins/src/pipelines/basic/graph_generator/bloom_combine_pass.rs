@@ -93,6 +93,8 @@ pub(super) fn bloom_combine_pass(
             RafxSwapchainColorSpace::Srgb => BasicPipelineOutputColorSpace::Srgb,
             RafxSwapchainColorSpace::SrgbExtended => BasicPipelineOutputColorSpace::Srgb,
             RafxSwapchainColorSpace::DisplayP3Extended => BasicPipelineOutputColorSpace::P3,
+            // Not supported by the basic pipeline, use the modern pipeline for HDR10 output
+            RafxSwapchainColorSpace::Hdr10 => BasicPipelineOutputColorSpace::Srgb,
         };
 
         let descriptor_set_layouts = &pipeline.get_raw().descriptor_set_layouts;
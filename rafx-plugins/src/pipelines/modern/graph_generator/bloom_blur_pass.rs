@@ -23,6 +23,7 @@ pub(super) fn blur_pass(
     bloom_blur_material_pass: ResourceArc<MaterialPassResource>,
     bloom_extract_hdr_image: RenderGraphImageUsageId,
     pass_count: usize,
+    extents: RenderGraphImageExtents,
 ) -> BloomBlurPass {
     let mut blur_src = bloom_extract_hdr_image;
 
@@ -32,12 +33,14 @@ pub(super) fn blur_pass(
             &bloom_blur_material_pass,
             blur_src,
             BlurDirection::Vertical,
+            extents,
         );
         blur_src = bloom_blur_internal_pass(
             context,
             &bloom_blur_material_pass,
             blur_src,
             BlurDirection::Horizontal,
+            extents,
         );
     }
 
@@ -54,6 +57,7 @@ pub(super) fn bloom_blur_pass(
         bloom_blur_material_pass,
         bloom_extract_hdr_image,
         context.graph_config.blur_pass_count,
+        RenderGraphImageExtents::MatchSurface,
     )
 }
 
@@ -62,6 +66,7 @@ fn bloom_blur_internal_pass(
     bloom_blur_material_pass: &ResourceArc<MaterialPassResource>,
     blur_src: RenderGraphImageUsageId,
     blur_direction: BlurDirection,
+    extents: RenderGraphImageExtents,
 ) -> RenderGraphImageUsageId {
     let node = context
         .graph
@@ -73,6 +78,7 @@ fn bloom_blur_internal_pass(
         RenderGraphImageConstraint {
             samples: Some(RafxSampleCount::SampleCount1),
             format: Some(context.graph_config.color_format),
+            extents: Some(extents),
             ..Default::default()
         },
         Default::default(),
@@ -1,9 +1,13 @@
 use super::ModernPipelineRenderOptions;
 use crate::phases::PostProcessRenderPhase;
 use crate::pipelines::modern::graph_generator::luma_pass::LumaAverageHistogramPass;
-use crate::pipelines::modern::ModernPipelineOutputColorSpace;
+use crate::pipelines::modern::{ModernPipelineOutputColorSpace, TonemapperTypeAdv};
+use hydrate_base::handle::Handle;
 use rafx::api::RafxSwapchainColorSpace;
-use rafx::framework::{DescriptorSetBindings, MaterialPassResource, ResourceArc};
+use rafx::assets::ImageAsset;
+use rafx::framework::{
+    DescriptorSetBindings, ImageViewResource, MaterialPassResource, ResourceArc,
+};
 use rafx::graph::*;
 use rafx::render_features::RenderPhase;
 use rafx::renderer::SwapchainRenderResource;
@@ -25,11 +29,23 @@ pub(super) fn bloom_combine_pass(
     blurred_color: RenderGraphImageUsageId,
     luma_average_histogram_pass: &LumaAverageHistogramPass,
     swapchain_render_resource: &SwapchainRenderResource,
+    identity_color_grading_lut: &ResourceArc<ImageViewResource>,
 ) -> BloomCombinePass {
     let render_options = context
         .extract_resources
         .fetch::<ModernPipelineRenderOptions>()
         .clone();
+
+    let color_grading_options = &render_options.color_grading_options;
+    let color_grading_lut = |lut: &Option<Handle<ImageAsset>>| {
+        lut.as_ref()
+            .and_then(|lut| context.asset_manager.committed_asset(lut))
+            .map(|lut| lut.image_view.clone())
+            .unwrap_or_else(|| identity_color_grading_lut.clone())
+    };
+    let lut = color_grading_lut(&color_grading_options.lut);
+    let blend_lut = color_grading_lut(&color_grading_options.blend_lut);
+
     let node = context
         .graph
         .add_renderpass_node("BloomCombine", RenderGraphQueue::DefaultGraphics);
@@ -69,7 +85,52 @@ pub(super) fn bloom_combine_pass(
         .unwrap()
         .swapchain_surface_info
         .color_space;
-    let max_color_component_value = swapchain_render_resource.max_color_component_value;
+
+    // Apple EDR is relative to SDR white and is handled like SDR output, scaled up to the EDR
+    // headroom. scRGB and HDR10 output are absolute, so they are scaled by the paper white level and
+    // tonemapped up to the display's peak brightness.
+    let output_color_space = match swapchain_color_space {
+        RafxSwapchainColorSpace::Srgb => ModernPipelineOutputColorSpace::Srgb,
+        RafxSwapchainColorSpace::SrgbExtended => {
+            if swapchain_render_resource.extended_range_relative_to_sdr_white {
+                ModernPipelineOutputColorSpace::Srgb
+            } else {
+                ModernPipelineOutputColorSpace::ScRgb
+            }
+        }
+        RafxSwapchainColorSpace::DisplayP3Extended => ModernPipelineOutputColorSpace::P3,
+        RafxSwapchainColorSpace::Hdr10 => ModernPipelineOutputColorSpace::Hdr10,
+    };
+
+    let hdr_output_options = &render_options.hdr_output_options;
+    let paper_white_nits = hdr_output_options.paper_white_nits.max(1.0);
+    let peak_nits = swapchain_render_resource
+        .display_luminance
+        .filter(|_| hdr_output_options.use_display_peak_nits)
+        .map(|display_luminance| display_luminance.max_luminance_nits)
+        .filter(|&max_luminance_nits| max_luminance_nits > 0.0)
+        .unwrap_or(hdr_output_options.peak_nits)
+        .max(paper_white_nits);
+
+    let is_absolute_hdr_output = matches!(
+        output_color_space,
+        ModernPipelineOutputColorSpace::ScRgb | ModernPipelineOutputColorSpace::Hdr10
+    );
+
+    let (max_color_component_value, tonemapper_type) = if is_absolute_hdr_output {
+        let tonemapper_type = if render_options.tonemapper_type.supports_hdr_output() {
+            render_options.tonemapper_type
+        } else {
+            TonemapperTypeAdv::Bergstrom
+        };
+
+        (peak_nits / paper_white_nits, tonemapper_type)
+    } else {
+        (
+            swapchain_render_resource.max_color_component_value,
+            render_options.tonemapper_type,
+        )
+    };
 
     context.graph.set_renderpass_callback(node, move |args| {
         // Get the color image from before
@@ -95,12 +156,6 @@ pub(super) fn bloom_combine_pass(
             .resource_context()
             .create_descriptor_set_allocator();
 
-        let output_color_space = match swapchain_color_space {
-            RafxSwapchainColorSpace::Srgb => ModernPipelineOutputColorSpace::Srgb,
-            RafxSwapchainColorSpace::SrgbExtended => ModernPipelineOutputColorSpace::Srgb,
-            RafxSwapchainColorSpace::DisplayP3Extended => ModernPipelineOutputColorSpace::P3,
-        };
-
         let descriptor_set_layouts = &pipeline.get_raw().descriptor_set_layouts;
         let mut bloom_combine_material_dyn_set = descriptor_set_allocator
             .create_dyn_descriptor_set(
@@ -109,11 +164,23 @@ pub(super) fn bloom_combine_pass(
                     in_color: &sdr_image,
                     in_blur: &hdr_image,
                     config: &bloom_combine_adv_frag::ConfigStd140 {
-                        tonemapper_type: render_options.tonemapper_type as i32,
+                        tonemapper_type: tonemapper_type as i32,
                         output_color_space: output_color_space as i32,
                         max_color_component_value,
-                        ..Default::default()
+                        enable_color_grading: render_options.enable_color_grading as u32,
+                        color_grading_blend: render_options
+                            .color_grading_options
+                            .blend
+                            .clamp(0.0, 1.0),
+                        color_grading_strength: render_options
+                            .color_grading_options
+                            .strength
+                            .clamp(0.0, 1.0),
+                        paper_white_nits,
+                        peak_nits,
                     },
+                    color_grading_lut: &lut,
+                    color_grading_blend_lut: &blend_lut,
                 },
             )?;
 
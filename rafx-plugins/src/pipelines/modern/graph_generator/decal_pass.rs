@@ -0,0 +1,115 @@
+use crate::features::decal::DecalRenderPipelineState;
+use crate::phases::DecalRenderPhase;
+use crate::pipelines::modern::graph_generator::ModernPipelineContext;
+use crate::shaders::decal::decal_frag;
+use rafx::api::{RafxColorClearValue, RafxFormat, RafxSampleCount};
+use rafx::framework::{MaterialPassResource, ResourceArc};
+use rafx::graph::*;
+use rafx::render_features::RenderJobCommandBufferContext;
+
+// Decal surface properties, blended over each other by the decal pass and then over the mesh
+// material when the opaque pass shades it. Each target is premultiplied with coverage in alpha.
+#[derive(Copy, Clone)]
+pub(super) struct DecalBuffers {
+    pub(super) albedo: RenderGraphImageUsageId,
+    // View-space normal encoded to 0..1
+    pub(super) normal: RenderGraphImageUsageId,
+    // Roughness in red
+    pub(super) roughness: RenderGraphImageUsageId,
+}
+
+pub(super) struct DecalPass {
+    #[allow(dead_code)]
+    pub(super) node: RenderGraphNodeId,
+    pub(super) decal_buffers: DecalBuffers,
+}
+
+pub(super) fn decal_pass(
+    context: &mut ModernPipelineContext,
+    decal_material_pass: &ResourceArc<MaterialPassResource>,
+    depth_prepass: RenderGraphImageUsageId,
+) -> DecalPass {
+    let node = context
+        .graph
+        .add_renderpass_node("Decal", RenderGraphQueue::DefaultGraphics);
+
+    // Decals are projected onto the surfaces in the depth prepass
+    let depth_rt = context.graph.sample_image(
+        node,
+        depth_prepass,
+        RenderGraphImageConstraint {
+            samples: Some(RafxSampleCount::SampleCount1),
+            ..Default::default()
+        },
+        Default::default(),
+    );
+
+    let mut create_decal_buffer = |attachment_index, name| {
+        let image = context.graph.create_color_attachment(
+            node,
+            attachment_index,
+            Some(RafxColorClearValue([0.0, 0.0, 0.0, 0.0])),
+            RenderGraphImageConstraint {
+                format: Some(RafxFormat::R8G8B8A8_UNORM),
+                extents: Some(context.graph_config.render_extents),
+                ..Default::default()
+            },
+            Default::default(),
+        );
+        context.graph.set_image_name(image, name);
+        image
+    };
+
+    let decal_buffers = DecalBuffers {
+        albedo: create_decal_buffer(0, "decal_albedo"),
+        normal: create_decal_buffer(1, "decal_normal"),
+        roughness: create_decal_buffer(2, "decal_roughness"),
+    };
+
+    context
+        .graph
+        .add_render_phase_dependency::<DecalRenderPhase>(node);
+
+    let main_view = context.main_view.clone();
+    let decal_material_pass = decal_material_pass.clone();
+    context.graph.set_renderpass_callback(node, move |args| {
+        profiling::scope!("Decal Pass");
+        let mut write_context =
+            RenderJobCommandBufferContext::from_graph_visit_render_pass_args(&args);
+
+        let depth_tex = args.graph_context.image_view(depth_rt).unwrap();
+        let mut descriptor_set_allocator = args
+            .graph_context
+            .resource_context()
+            .create_descriptor_set_allocator();
+        let depth_descriptor_set = descriptor_set_allocator.create_descriptor_set(
+            &decal_material_pass.get_raw().descriptor_set_layouts
+                [decal_frag::DEPTH_TEXTURE_DESCRIPTOR_SET_INDEX],
+            decal_frag::DescriptorSet1Args {
+                depth_texture: &depth_tex,
+            },
+        )?;
+        descriptor_set_allocator.flush_changes()?;
+
+        args.graph_context
+            .render_resources()
+            .fetch_mut::<DecalRenderPipelineState>()
+            .depth_descriptor_set = Some(depth_descriptor_set);
+
+        args.graph_context
+            .prepared_render_data()
+            .write_view_phase::<DecalRenderPhase>(&main_view, &mut write_context)?;
+
+        args.graph_context
+            .render_resources()
+            .fetch_mut::<DecalRenderPipelineState>()
+            .depth_descriptor_set = None;
+
+        Ok(())
+    });
+
+    DecalPass {
+        node,
+        decal_buffers,
+    }
+}
@@ -0,0 +1,122 @@
+use rafx::framework::{ComputePipelineResource, DescriptorSetBindings, ResourceArc};
+use rafx::graph::*;
+
+use super::ModernPipelineContext;
+use crate::pipelines::modern::DepthOfFieldOptions;
+use crate::shaders::post_adv::depth_of_field_comp;
+use rafx::api::{RafxExtents2D, RafxFormat, RafxSampleCount};
+use rafx::render_features::RenderViewCameraLens;
+
+pub(super) struct DepthOfFieldPass {
+    #[allow(dead_code)]
+    pub(super) node: RenderGraphNodeId,
+    pub(super) color_rt: RenderGraphImageUsageId,
+}
+
+// Blurs the color by each pixel's circle of confusion through the given lens. This runs before TAA,
+// so the extents and circle of confusion are at the render resolution.
+pub(super) fn depth_of_field_pass(
+    context: &mut ModernPipelineContext,
+    depth_of_field_pipeline: &ResourceArc<ComputePipelineResource>,
+    depth_of_field_options: &DepthOfFieldOptions,
+    camera_lens: &RenderViewCameraLens,
+    color_rt: RenderGraphImageUsageId,
+    depth_rt: RenderGraphImageUsageId,
+    render_extents: RafxExtents2D,
+    render_scale: f32,
+) -> DepthOfFieldPass {
+    let node = context
+        .graph
+        .add_callback_node("DepthOfField", RenderGraphQueue::DefaultGraphics);
+
+    let color_rt =
+        context
+            .graph
+            .sample_image(node, color_rt, Default::default(), Default::default());
+
+    let depth_rt =
+        context
+            .graph
+            .sample_image(node, depth_rt, Default::default(), Default::default());
+
+    let output_rt = context.graph.create_storage_image(
+        node,
+        RenderGraphImageConstraint {
+            samples: Some(RafxSampleCount::SampleCount1),
+            format: Some(RafxFormat::R16G16B16A16_SFLOAT),
+            extents: Some(context.graph_config.render_extents),
+            ..Default::default()
+        },
+        Default::default(),
+    );
+    context.graph.set_image_name(output_rt, "depth_of_field");
+
+    // With a thin lens, the circle of confusion on the sensor is A * f * |z - S| / (z * (S - f)) for
+    // aperture diameter A, focal length f and focus distance S. The sensor height is derived from the
+    // projection's field of view so that the circle of confusion can be converted to pixels.
+    let projection = context.main_view.projection_matrix();
+    let focus_distance = camera_lens.focus_distance;
+    let coc_scale =
+        camera_lens.aperture_diameter() * projection.y_axis.y * render_extents.height as f32
+            / (4.0 * (focus_distance - camera_lens.focal_length).max(0.001));
+
+    let config = depth_of_field_comp::DepthOfFieldConfigUniform {
+        depth_to_distance: [projection.z_axis.z, projection.w_axis.z],
+        focus_distance,
+        coc_scale,
+        max_coc_radius: depth_of_field_options.max_coc_radius * render_scale,
+        sample_spacing: depth_of_field_options.sample_spacing.max(0.1),
+        viewport_width: render_extents.width,
+        viewport_height: render_extents.height,
+    };
+
+    let depth_of_field_pipeline = depth_of_field_pipeline.clone();
+    context.graph.set_callback(node, move |args| {
+        let color_rt = args.graph_context.image_view(color_rt).unwrap();
+        let depth_rt = args.graph_context.image_view(depth_rt).unwrap();
+        let output_rt = args.graph_context.image_view(output_rt).unwrap();
+
+        let mut descriptor_set_allocator = args
+            .graph_context
+            .resource_context()
+            .create_descriptor_set_allocator();
+        let mut descriptor_set = descriptor_set_allocator.create_dyn_descriptor_set_uninitialized(
+            &depth_of_field_pipeline.get_raw().descriptor_set_layouts[0],
+        )?;
+
+        descriptor_set.set_buffer_data(
+            depth_of_field_comp::CONFIG_DESCRIPTOR_BINDING_INDEX as u32,
+            &config,
+        );
+        descriptor_set.set_image(
+            depth_of_field_comp::COLOR_TEX_DESCRIPTOR_BINDING_INDEX as u32,
+            &color_rt,
+        );
+        descriptor_set.set_image(
+            depth_of_field_comp::DEPTH_TEX_DESCRIPTOR_BINDING_INDEX as u32,
+            &depth_rt,
+        );
+        descriptor_set.set_image(
+            depth_of_field_comp::OUTPUT_TEX_DESCRIPTOR_BINDING_INDEX as u32,
+            &output_rt,
+        );
+        descriptor_set.flush(&mut descriptor_set_allocator)?;
+        descriptor_set_allocator.flush_changes()?;
+
+        let command_buffer = &args.command_buffer;
+        command_buffer.cmd_bind_pipeline(&*depth_of_field_pipeline.get_raw().pipeline)?;
+        descriptor_set.bind(command_buffer)?;
+        command_buffer.cmd_dispatch(
+            (render_extents.width + 15) / 16,
+            (render_extents.height + 15) / 16,
+            1,
+        )?;
+
+        Ok(())
+    });
+
+    DepthOfFieldPass {
+        node,
+        color_rt: output_rt,
+    }
+}
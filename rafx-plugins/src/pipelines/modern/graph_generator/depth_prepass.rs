@@ -25,6 +25,7 @@ pub(super) fn depth_prepass(context: &mut ModernPipelineContext) -> DepthPrepass
         RenderGraphImageConstraint {
             samples: Some(context.graph_config.samples),
             format: Some(context.graph_config.depth_format),
+            extents: Some(context.graph_config.render_extents),
             ..Default::default()
         },
         Default::default(),
@@ -38,6 +39,7 @@ pub(super) fn depth_prepass(context: &mut ModernPipelineContext) -> DepthPrepass
         RenderGraphImageConstraint {
             samples: Some(context.graph_config.samples),
             format: Some(RafxFormat::R32G32_SFLOAT),
+            extents: Some(context.graph_config.render_extents),
             ..Default::default()
         },
         Default::default(),
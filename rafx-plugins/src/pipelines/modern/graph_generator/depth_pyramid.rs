@@ -3,10 +3,13 @@ use rafx::graph::*;
 
 use super::ModernPipelineContext;
 use crate::shaders::depth::depth_pyramid_comp;
-use rafx::api::{RafxExtents3D, RafxFormat, RafxSampleCount};
+use rafx::api::{RafxExtents2D, RafxExtents3D, RafxFormat, RafxSampleCount};
 
 pub const MAX_DEPTH_PYRAMID_MIP_LAYERS: u32 = 16;
 
+// Farthest depth in x and nearest depth in y, see depth_pyramid.comp
+pub const DEPTH_PYRAMID_FORMAT: RafxFormat = RafxFormat::R32G32_SFLOAT;
+
 pub(super) struct DepthPyramidPass {
     #[allow(dead_code)]
     pub(super) node: RenderGraphNodeId,
@@ -20,6 +23,7 @@ fn depth_pyramid_mip_pass(
     dst_depth_rt: RenderGraphImageUsageId,
     input_width: u32,
     input_height: u32,
+    src_has_nearest_depth: bool,
     node: RenderGraphNodeId,
 ) {
     let depth_pyramid_pipeline = depth_pyramid_pipeline.clone();
@@ -43,6 +47,8 @@ fn depth_pyramid_mip_pass(
                 input_height,
                 odd_width: ((input_width % 2) == 1) as u32,
                 odd_height: ((input_height % 2) == 1) as u32,
+                src_has_nearest_depth: src_has_nearest_depth as u32,
+                ..Default::default()
             },
         );
         descriptor_set.set_image(
@@ -73,16 +79,14 @@ pub(super) fn depth_pyramid_pass(
     context: &mut ModernPipelineContext,
     depth_pyramid_pipeline: &ResourceArc<ComputePipelineResource>,
     src_depth_rt: RenderGraphImageUsageId,
-    swapchain_surface_info: &SwapchainSurfaceInfo,
+    render_extents: RafxExtents2D,
 ) -> DepthPyramidPass {
     let mip_levels = rafx::api::extra::mipmaps::mip_level_max_count_for_image_size(
-        swapchain_surface_info.extents.width,
-        swapchain_surface_info.extents.height,
+        render_extents.width,
+        render_extents.height,
     )
     .min(MAX_DEPTH_PYRAMID_MIP_LAYERS);
 
-    let swapchain_extents = swapchain_surface_info.extents;
-
     let mut previous_node = None;
     let mut previous_dst_depth_rt = None;
 
@@ -94,10 +98,10 @@ pub(super) fn depth_pyramid_pass(
             .graph
             .add_callback_node("DepthPyramid", RenderGraphQueue::DefaultGraphics);
 
-        let input_width = 1.max(swapchain_extents.width >> (dst_mip_level - 1));
-        let input_height = 1.max(swapchain_extents.height >> (dst_mip_level - 1));
-        let output_width = 1.max(swapchain_extents.width >> dst_mip_level);
-        let output_height = 1.max(swapchain_extents.height >> dst_mip_level);
+        let input_width = 1.max(render_extents.width >> (dst_mip_level - 1));
+        let input_height = 1.max(render_extents.height >> (dst_mip_level - 1));
+        let output_width = 1.max(render_extents.width >> dst_mip_level);
+        let output_height = 1.max(render_extents.height >> dst_mip_level);
 
         //println!(
         //    "{}x{} -> {}x{}",
@@ -121,7 +125,7 @@ pub(super) fn depth_pyramid_pass(
                 previous_dst_depth_rt.unwrap(),
                 RenderGraphImageConstraint {
                     samples: Some(RafxSampleCount::SampleCount1),
-                    format: Some(RafxFormat::R32_SFLOAT),
+                    format: Some(DEPTH_PYRAMID_FORMAT),
                     ..Default::default()
                 },
                 Default::default(),
@@ -132,7 +136,7 @@ pub(super) fn depth_pyramid_pass(
             node,
             RenderGraphImageConstraint {
                 samples: Some(RafxSampleCount::SampleCount1),
-                format: Some(RafxFormat::R32_SFLOAT),
+                format: Some(DEPTH_PYRAMID_FORMAT),
                 extents: Some(RenderGraphImageExtents::Custom(RafxExtents3D {
                     width: output_width,
                     height: output_height,
@@ -150,6 +154,7 @@ pub(super) fn depth_pyramid_pass(
             dst_depth_rt,
             input_width,
             input_height,
+            dst_mip_level > 1,
             node,
         );
         previous_node = Some(node);
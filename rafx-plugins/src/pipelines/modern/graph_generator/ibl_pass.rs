@@ -0,0 +1,316 @@
+use rafx::api::{
+    RafxExtents3D, RafxFormat, RafxResourceState, RafxResourceType, RafxResult, RafxTextureBarrier,
+    RafxTextureBindType, RafxTextureDef, RafxTextureDimensions,
+};
+use rafx::assets::AssetManager;
+use rafx::framework::{
+    ComputePipelineResource, DescriptorSetBindings, ImageResource, ImageViewResource, ResourceArc,
+};
+use rafx::graph::*;
+
+use super::ModernPipelineContext;
+use crate::features::mesh_adv::MeshAdvIblImages;
+use crate::shaders::mesh_adv::{ibl_brdf_lut_comp, ibl_irradiance_comp, ibl_specular_comp};
+
+const IBL_BRDF_LUT_SIZE: u32 = 256;
+// Only two channels are used, but WebGPU doesn't support two-channel 16-bit float storage textures
+const IBL_BRDF_LUT_FORMAT: RafxFormat = RafxFormat::R16G16B16A16_SFLOAT;
+const IBL_IRRADIANCE_SIZE: u32 = 32;
+// The specular cubemap's last mip (roughness 1) is 4x4
+const IBL_SPECULAR_SIZE: u32 = 128;
+const IBL_SPECULAR_MIP_COUNT: u32 = 6;
+const IBL_SPECULAR_SAMPLE_COUNT: u32 = 512;
+const IBL_CUBEMAP_FORMAT: RafxFormat = RafxFormat::R16G16B16A16_SFLOAT;
+
+// The images sampled by the mesh shaders, plus the per-mip views the compute shaders write to
+pub(super) struct IblTargets {
+    pub(super) images: MeshAdvIblImages,
+    brdf_lut_uav: ResourceArc<ImageViewResource>,
+    irradiance_cubemap_uav: ResourceArc<ImageViewResource>,
+    specular_cubemap_mip_uavs: Vec<ResourceArc<ImageViewResource>>,
+}
+
+pub(super) struct IblPass {
+    pub(super) node: RenderGraphNodeId,
+}
+
+fn create_ibl_image(
+    asset_manager: &AssetManager,
+    texture_def: &RafxTextureDef,
+    debug_name: &str,
+) -> RafxResult<ResourceArc<ImageResource>> {
+    let texture = asset_manager.device_context().create_texture(texture_def)?;
+    texture.set_debug_name(debug_name);
+    Ok(asset_manager.resources().insert_image(texture))
+}
+
+pub(super) fn create_ibl_targets(asset_manager: &AssetManager) -> RafxResult<IblTargets> {
+    let resources = asset_manager.resources();
+
+    let brdf_lut = create_ibl_image(
+        asset_manager,
+        &RafxTextureDef {
+            extents: RafxExtents3D {
+                width: IBL_BRDF_LUT_SIZE,
+                height: IBL_BRDF_LUT_SIZE,
+                depth: 1,
+            },
+            format: IBL_BRDF_LUT_FORMAT,
+            resource_type: RafxResourceType::TEXTURE | RafxResourceType::TEXTURE_READ_WRITE,
+            dimensions: RafxTextureDimensions::Dim2D,
+            ..Default::default()
+        },
+        "IBL BRDF LUT",
+    )?;
+
+    let irradiance_cubemap = create_ibl_image(
+        asset_manager,
+        &RafxTextureDef {
+            extents: RafxExtents3D {
+                width: IBL_IRRADIANCE_SIZE,
+                height: IBL_IRRADIANCE_SIZE,
+                depth: 1,
+            },
+            array_length: 6,
+            format: IBL_CUBEMAP_FORMAT,
+            resource_type: RafxResourceType::TEXTURE_CUBE | RafxResourceType::TEXTURE_READ_WRITE,
+            dimensions: RafxTextureDimensions::Dim2D,
+            ..Default::default()
+        },
+        "IBL Irradiance Cubemap",
+    )?;
+
+    let specular_cubemap = create_ibl_image(
+        asset_manager,
+        &RafxTextureDef {
+            extents: RafxExtents3D {
+                width: IBL_SPECULAR_SIZE,
+                height: IBL_SPECULAR_SIZE,
+                depth: 1,
+            },
+            array_length: 6,
+            mip_count: IBL_SPECULAR_MIP_COUNT,
+            format: IBL_CUBEMAP_FORMAT,
+            resource_type: RafxResourceType::TEXTURE_CUBE | RafxResourceType::TEXTURE_READ_WRITE,
+            dimensions: RafxTextureDimensions::Dim2D,
+            ..Default::default()
+        },
+        "IBL Specular Cubemap",
+    )?;
+
+    let specular_cubemap_mip_uavs = (0..IBL_SPECULAR_MIP_COUNT)
+        .map(|mip| {
+            resources.get_or_create_image_view(
+                &specular_cubemap,
+                Some(RafxTextureBindType::UavMipSlice(mip)),
+            )
+        })
+        .collect::<RafxResult<Vec<_>>>()?;
+
+    Ok(IblTargets {
+        images: MeshAdvIblImages {
+            irradiance_cubemap: resources
+                .get_or_create_image_view(&irradiance_cubemap, Some(RafxTextureBindType::Srv))?,
+            specular_cubemap: resources
+                .get_or_create_image_view(&specular_cubemap, Some(RafxTextureBindType::Srv))?,
+            specular_mip_count: IBL_SPECULAR_MIP_COUNT,
+            brdf_lut: resources
+                .get_or_create_image_view(&brdf_lut, Some(RafxTextureBindType::Srv))?,
+        },
+        brdf_lut_uav: resources
+            .get_or_create_image_view(&brdf_lut, Some(RafxTextureBindType::UavMipSlice(0)))?,
+        irradiance_cubemap_uav: resources.get_or_create_image_view(
+            &irradiance_cubemap,
+            Some(RafxTextureBindType::UavMipSlice(0)),
+        )?,
+        specular_cubemap_mip_uavs,
+    })
+}
+
+// Generates the BRDF LUT and the irradiance and prefiltered specular cubemaps from the skybox.
+// This only needs to run once after the targets are created. Anything that draws lit meshes must
+// explicitly depend on this node.
+pub(super) fn ibl_pass(
+    context: &mut ModernPipelineContext,
+    ibl_brdf_lut_pipeline: &ResourceArc<ComputePipelineResource>,
+    ibl_irradiance_pipeline: &ResourceArc<ComputePipelineResource>,
+    ibl_specular_pipeline: &ResourceArc<ComputePipelineResource>,
+    source_cubemap: &ResourceArc<ImageViewResource>,
+    targets: IblTargets,
+) -> IblPass {
+    let node = context
+        .graph
+        .add_callback_node("Ibl", RenderGraphQueue::DefaultGraphics);
+
+    let ibl_brdf_lut_pipeline = ibl_brdf_lut_pipeline.clone();
+    let ibl_irradiance_pipeline = ibl_irradiance_pipeline.clone();
+    let ibl_specular_pipeline = ibl_specular_pipeline.clone();
+    let source_cubemap = source_cubemap.clone();
+    context.graph.set_callback(node, move |args| {
+        let command_buffer = &args.command_buffer;
+
+        let brdf_lut = &targets.images.brdf_lut.get_raw().image.get_raw().image;
+        let irradiance_cubemap = &targets
+            .images
+            .irradiance_cubemap
+            .get_raw()
+            .image
+            .get_raw()
+            .image;
+        let specular_cubemap = &targets
+            .images
+            .specular_cubemap
+            .get_raw()
+            .image
+            .get_raw()
+            .image;
+        let source_face_size = source_cubemap
+            .get_raw()
+            .image
+            .get_raw()
+            .image
+            .texture_def()
+            .extents
+            .width;
+
+        // We need manual barriers here because the IBL images are not managed by the render graph
+        command_buffer.cmd_resource_barrier(
+            &[],
+            &[
+                RafxTextureBarrier::state_transition(
+                    brdf_lut,
+                    RafxResourceState::UNDEFINED,
+                    RafxResourceState::UNORDERED_ACCESS,
+                ),
+                RafxTextureBarrier::state_transition(
+                    irradiance_cubemap,
+                    RafxResourceState::UNDEFINED,
+                    RafxResourceState::UNORDERED_ACCESS,
+                ),
+                RafxTextureBarrier::state_transition(
+                    specular_cubemap,
+                    RafxResourceState::UNDEFINED,
+                    RafxResourceState::UNORDERED_ACCESS,
+                ),
+            ],
+        )?;
+
+        let mut descriptor_set_allocator = args
+            .graph_context
+            .resource_context()
+            .create_descriptor_set_allocator();
+
+        //
+        // BRDF LUT
+        //
+        {
+            let mut descriptor_set = descriptor_set_allocator
+                .create_dyn_descriptor_set_uninitialized(
+                    &ibl_brdf_lut_pipeline.get_raw().descriptor_set_layouts[0],
+                )?;
+            descriptor_set.set_image(
+                ibl_brdf_lut_comp::DST_LUT_DESCRIPTOR_BINDING_INDEX as u32,
+                &targets.brdf_lut_uav,
+            );
+            descriptor_set.flush(&mut descriptor_set_allocator)?;
+            descriptor_set_allocator.flush_changes()?;
+
+            command_buffer.cmd_bind_pipeline(&ibl_brdf_lut_pipeline.get_raw().pipeline)?;
+            descriptor_set.bind(command_buffer)?;
+            command_buffer.cmd_dispatch(
+                IBL_BRDF_LUT_SIZE.div_ceil(8),
+                IBL_BRDF_LUT_SIZE.div_ceil(8),
+                1,
+            )?;
+        }
+
+        //
+        // Diffuse irradiance, one z group per cubemap face
+        //
+        {
+            let mut descriptor_set = descriptor_set_allocator
+                .create_dyn_descriptor_set_uninitialized(
+                    &ibl_irradiance_pipeline.get_raw().descriptor_set_layouts[0],
+                )?;
+            descriptor_set.set_image(
+                ibl_irradiance_comp::SRC_CUBEMAP_DESCRIPTOR_BINDING_INDEX as u32,
+                &source_cubemap,
+            );
+            descriptor_set.set_image(
+                ibl_irradiance_comp::DST_CUBEMAP_DESCRIPTOR_BINDING_INDEX as u32,
+                &targets.irradiance_cubemap_uav,
+            );
+            descriptor_set.flush(&mut descriptor_set_allocator)?;
+            descriptor_set_allocator.flush_changes()?;
+
+            command_buffer.cmd_bind_pipeline(&ibl_irradiance_pipeline.get_raw().pipeline)?;
+            descriptor_set.bind(command_buffer)?;
+            command_buffer.cmd_dispatch(
+                IBL_IRRADIANCE_SIZE.div_ceil(8),
+                IBL_IRRADIANCE_SIZE.div_ceil(8),
+                6,
+            )?;
+        }
+
+        //
+        // Prefiltered specular, roughness increases linearly with each mip
+        //
+        command_buffer.cmd_bind_pipeline(&ibl_specular_pipeline.get_raw().pipeline)?;
+        for (mip, mip_uav) in targets.specular_cubemap_mip_uavs.iter().enumerate() {
+            let face_size = (IBL_SPECULAR_SIZE >> mip).max(1);
+            let config = ibl_specular_comp::IblSpecularConfigUniform {
+                roughness: mip as f32 / (IBL_SPECULAR_MIP_COUNT - 1) as f32,
+                sample_count: IBL_SPECULAR_SAMPLE_COUNT,
+                face_size,
+                src_face_size: source_face_size as f32,
+            };
+
+            let mut descriptor_set = descriptor_set_allocator
+                .create_dyn_descriptor_set_uninitialized(
+                    &ibl_specular_pipeline.get_raw().descriptor_set_layouts[0],
+                )?;
+            descriptor_set.set_buffer_data(
+                ibl_specular_comp::CONFIG_DESCRIPTOR_BINDING_INDEX as u32,
+                &config,
+            );
+            descriptor_set.set_image(
+                ibl_specular_comp::SRC_CUBEMAP_DESCRIPTOR_BINDING_INDEX as u32,
+                &source_cubemap,
+            );
+            descriptor_set.set_image(
+                ibl_specular_comp::DST_CUBEMAP_DESCRIPTOR_BINDING_INDEX as u32,
+                mip_uav,
+            );
+            descriptor_set.flush(&mut descriptor_set_allocator)?;
+            descriptor_set_allocator.flush_changes()?;
+
+            descriptor_set.bind(command_buffer)?;
+            command_buffer.cmd_dispatch(face_size.div_ceil(8), face_size.div_ceil(8), 6)?;
+        }
+
+        command_buffer.cmd_resource_barrier(
+            &[],
+            &[
+                RafxTextureBarrier::state_transition(
+                    brdf_lut,
+                    RafxResourceState::UNORDERED_ACCESS,
+                    RafxResourceState::SHADER_RESOURCE,
+                ),
+                RafxTextureBarrier::state_transition(
+                    irradiance_cubemap,
+                    RafxResourceState::UNORDERED_ACCESS,
+                    RafxResourceState::SHADER_RESOURCE,
+                ),
+                RafxTextureBarrier::state_transition(
+                    specular_cubemap,
+                    RafxResourceState::UNORDERED_ACCESS,
+                    RafxResourceState::SHADER_RESOURCE,
+                ),
+            ],
+        )?;
+
+        Ok(())
+    });
+
+    IblPass { node }
+}
@@ -2,7 +2,9 @@ use rafx::framework::DescriptorSetBindings;
 use rafx::graph::*;
 
 use super::ModernPipelineContext;
-use crate::features::mesh_adv::light_binning::MeshAdvLightBinRenderResource;
+use crate::features::mesh_adv::light_binning::{
+    MeshAdvLightBinList, MeshAdvLightBinRenderResource,
+};
 use crate::features::mesh_adv::MeshAdvStaticResources;
 use crate::shaders::mesh_adv::{lights_bin_comp, lights_build_lists_comp};
 use rafx::api::{RafxLoadOp, RafxResourceState};
@@ -11,7 +13,10 @@ pub struct LightBinPass {
     pub bitfields_buffer: RenderGraphBufferUsageId,
 }
 
-pub(super) fn lights_bin_pass(context: &mut ModernPipelineContext) -> LightBinPass {
+pub(super) fn lights_bin_pass(
+    context: &mut ModernPipelineContext,
+    list: MeshAdvLightBinList,
+) -> LightBinPass {
     //
     // Rebuild the frustum AABB structure if the projection matrix has changed
     //
@@ -63,7 +68,7 @@ pub(super) fn lights_bin_pass(context: &mut ModernPipelineContext) -> LightBinPa
     // Get the external lights buffer
     //
     let lights_buffer = light_bin_render_resource
-        .light_bounds_gpu_buffer(context.main_view.frame_index())
+        .light_bounds_gpu_buffer(list, context.main_view.frame_index())
         .clone();
     let lights_buffer = context.graph.add_external_buffer(
         lights_buffer,
@@ -75,9 +80,13 @@ pub(super) fn lights_bin_pass(context: &mut ModernPipelineContext) -> LightBinPa
     //
     // Setup the node
     //
+    let node_name = match list {
+        MeshAdvLightBinList::Lights => "LightsBin",
+        MeshAdvLightBinList::ReflectionProbes => "ReflectionProbesBin",
+    };
     let node = context
         .graph
-        .add_callback_node("LightsBin", RenderGraphQueue::DefaultGraphics);
+        .add_callback_node(node_name, RenderGraphQueue::DefaultGraphics);
 
     let clusters_buffer =
         context
@@ -151,6 +160,7 @@ pub struct LightBuildListsPass {
 
 pub(super) fn lights_build_lists_pass(
     context: &mut ModernPipelineContext,
+    list: MeshAdvLightBinList,
     light_bin_pass: LightBinPass,
 ) -> LightBuildListsPass {
     // Get the compute pipeline
@@ -166,9 +176,13 @@ pub(super) fn lights_build_lists_pass(
         .render_resources
         .fetch::<MeshAdvLightBinRenderResource>();
 
+    let node_name = match list {
+        MeshAdvLightBinList::Lights => "LightsBuildLists",
+        MeshAdvLightBinList::ReflectionProbes => "ReflectionProbesBuildLists",
+    };
     let node = context
         .graph
-        .add_callback_node("LightsBuildLists", RenderGraphQueue::DefaultGraphics);
+        .add_callback_node(node_name, RenderGraphQueue::DefaultGraphics);
 
     let input_buffer = context.graph.read_storage_buffer(
         node,
@@ -186,7 +200,7 @@ pub(super) fn lights_build_lists_pass(
 
     // OUTPUT
     let output_buffer = light_bin_render_resource
-        .output_gpu_buffer(context.main_view.frame_index())
+        .output_gpu_buffer(list, context.main_view.frame_index())
         .clone();
     let output_buffer = context.graph.add_external_buffer(
         output_buffer,
@@ -11,7 +11,9 @@ use crate::pipelines::modern::graph_generator::depth_pyramid::{
 };
 use crate::pipelines::modern::ModernPipelineMeshCullingDebugData;
 use crate::shaders::mesh_adv::mesh_culling_comp;
-use rafx::api::{RafxBarrierQueueTransition, RafxBufferBarrier, RafxLoadOp, RafxResourceState};
+use rafx::api::{
+    RafxBarrierQueueTransition, RafxBufferBarrier, RafxExtents2D, RafxLoadOp, RafxResourceState,
+};
 use rafx::renderer::InvalidResources;
 
 pub(super) struct MeshCullingPass {
@@ -22,7 +24,7 @@ pub(super) struct MeshCullingPass {
 pub(super) fn mesh_culling_pass(
     context: &mut ModernPipelineContext,
     mesh_culling_pipeline: &ResourceArc<ComputePipelineResource>,
-    swapchain_surface_info: &SwapchainSurfaceInfo,
+    render_extents: RafxExtents2D,
     depth_pyramid_pass: &DepthPyramidPass,
     mesh_culling_debug_data: Option<ModernPipelineMeshCullingDebugData>,
     mesh_culling_debug_output: RenderGraphExternalBufferId,
@@ -63,7 +65,6 @@ pub(super) fn mesh_culling_pass(
     );
 
     let mesh_culling_pipeline = mesh_culling_pipeline.clone();
-    let swapchain_extents = swapchain_surface_info.extents;
     context.graph.set_callback(node, move |args| {
         let mut occlusion_jobs = args
             .graph_context
@@ -136,8 +137,8 @@ pub(super) fn mesh_culling_pass(
                     draw_data_count: occlusion_job.draw_data_count,
                     indirect_first_command_index: occlusion_job.indirect_first_command_index,
                     depth_mip_slice_count: depth_pyramid_mips.len() as u32,
-                    viewport_width: swapchain_extents.width,
-                    viewport_height: swapchain_extents.height,
+                    viewport_width: render_extents.width,
+                    viewport_height: render_extents.height,
                     z_near: occlusion_job.render_view.depth_range().near,
                     write_debug_output: enable_debug_data_collection as u32,
                     _padding0: Default::default(),
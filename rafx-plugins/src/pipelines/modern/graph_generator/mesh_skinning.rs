@@ -0,0 +1,108 @@
+use rafx::framework::{ComputePipelineResource, DescriptorSetBindings, ResourceArc};
+use rafx::graph::*;
+
+use super::ModernPipelineContext;
+use crate::assets::mesh_adv::MeshAdvBindlessBuffers;
+use crate::features::mesh_adv::MeshAdvSkinningRenderResource;
+use crate::shaders::mesh_adv::mesh_skinning_comp;
+use rafx::api::{RafxBarrierQueueTransition, RafxBufferBarrier, RafxResourceState};
+
+pub(super) struct MeshSkinningPass {
+    pub(super) node: RenderGraphNodeId,
+}
+
+// Writes the skinned vertices of all animated meshes into the vertex heap. Anything that draws
+// meshes must explicitly depend on this node.
+pub(super) fn mesh_skinning_pass(
+    context: &mut ModernPipelineContext,
+    mesh_skinning_pipeline: &ResourceArc<ComputePipelineResource>,
+) -> MeshSkinningPass {
+    let node = context
+        .graph
+        .add_callback_node("MeshSkinning", RenderGraphQueue::DefaultGraphics);
+
+    let mesh_skinning_pipeline = mesh_skinning_pipeline.clone();
+    context.graph.set_callback(node, move |args| {
+        let mut skinning_resource = args
+            .graph_context
+            .render_resources()
+            .fetch_mut::<MeshAdvSkinningRenderResource>();
+
+        let joint_palettes = match &skinning_resource.joint_palettes {
+            Some(joint_palettes) if !skinning_resource.jobs.is_empty() => joint_palettes.clone(),
+            _ => return Ok(()),
+        };
+
+        let vertex_heap = args
+            .graph_context
+            .render_resources()
+            .fetch::<MeshAdvBindlessBuffers>()
+            .vertex
+            .clone();
+
+        // We need a manual barrier here because the vertex heap is not managed by the render graph
+        args.command_buffer.cmd_resource_barrier(
+            &[RafxBufferBarrier {
+                buffer: &vertex_heap.get_raw().buffer,
+                src_state: RafxResourceState::VERTEX_AND_CONSTANT_BUFFER,
+                dst_state: RafxResourceState::UNORDERED_ACCESS,
+                queue_transition: RafxBarrierQueueTransition::None,
+                offset_size: None,
+            }],
+            &[],
+        )?;
+
+        let command_buffer = &args.command_buffer;
+        command_buffer.cmd_bind_pipeline(&mesh_skinning_pipeline.get_raw().pipeline)?;
+
+        let mut descriptor_set_allocator = args
+            .graph_context
+            .resource_context()
+            .create_descriptor_set_allocator();
+
+        for job in &skinning_resource.jobs {
+            let mut descriptor_set = descriptor_set_allocator
+                .create_dyn_descriptor_set_uninitialized(
+                    &mesh_skinning_pipeline.get_raw().descriptor_set_layouts[0],
+                )?;
+
+            descriptor_set.set_buffer_data(
+                mesh_skinning_comp::CONFIG_DESCRIPTOR_BINDING_INDEX as u32,
+                job,
+            );
+            descriptor_set.set_buffer(
+                mesh_skinning_comp::ALL_JOINT_PALETTES_DESCRIPTOR_BINDING_INDEX as u32,
+                &joint_palettes,
+            );
+            descriptor_set.set_buffer(
+                mesh_skinning_comp::VERTEX_DATA_DESCRIPTOR_BINDING_INDEX as u32,
+                &vertex_heap,
+            );
+            descriptor_set.flush(&mut descriptor_set_allocator)?;
+            descriptor_set_allocator.flush_changes()?;
+
+            descriptor_set.bind(command_buffer)?;
+
+            let group_count =
+                rafx::base::memory::round_size_up_to_alignment_u32(job.vertex_count, 64) / 64;
+            command_buffer.cmd_dispatch(group_count, 1, 1)?;
+        }
+
+        args.command_buffer.cmd_resource_barrier(
+            &[RafxBufferBarrier {
+                buffer: &vertex_heap.get_raw().buffer,
+                src_state: RafxResourceState::UNORDERED_ACCESS,
+                dst_state: RafxResourceState::VERTEX_AND_CONSTANT_BUFFER,
+                queue_transition: RafxBarrierQueueTransition::None,
+                offset_size: None,
+            }],
+            &[],
+        )?;
+
+        skinning_resource.jobs.clear();
+
+        Ok(())
+    });
+
+    MeshSkinningPass { node }
+}
@@ -1,6 +1,7 @@
 use rafx::api::{
-    RafxExtents3D, RafxFormat, RafxPrimitiveTopology, RafxResourceState, RafxResourceType,
-    RafxResult, RafxSampleCount, RafxTextureDef, RafxTextureDimensions,
+    RafxExtents2D, RafxExtents3D, RafxFormat, RafxPrimitiveTopology, RafxResourceState,
+    RafxResourceType, RafxResult, RafxSampleCount, RafxSwapchainColorSpace, RafxTextureDef,
+    RafxTextureDimensions,
 };
 use rafx::framework::VertexDataSetLayout;
 use rafx::framework::{ImageViewResource, ResourceArc};
@@ -12,6 +13,8 @@ mod shadow_map_pass;
 
 mod opaque_pass;
 
+mod oit_pass;
+
 mod depth_prepass;
 
 mod ssao_pass;
@@ -20,9 +23,14 @@ mod bloom_extract_pass;
 use super::ModernPipelineRenderOptions;
 use super::ModernPipelineStaticResources;
 use crate::features::debug_pip::DebugPipRenderResource;
+use crate::features::decal::{DecalRenderPipelineState, DecalStaticResources};
+use crate::features::mesh_adv::light_binning::MeshAdvLightBinList;
 use crate::features::mesh_adv::{MeshAdvRenderPipelineState, ShadowMapAtlas};
+use crate::features::particles::{ParticleRenderPipelineState, ParticleStaticResources};
+use crate::features::skybox::SkyboxResource;
 use crate::pipelines::modern::{
-    AntiAliasMethodAdv, ModernPipelineMeshCullingDebugData, ModernPipelineTonemapDebugData,
+    AntiAliasMethodAdv, ModernPipelineIbl, ModernPipelineMeshCullingDebugData,
+    ModernPipelineTonemapDebugData,
 };
 use rafx::assets::AssetManager;
 use rafx::renderer::SwapchainRenderResource;
@@ -46,8 +54,26 @@ mod cas_pass;
 
 mod mesh_culling;
 
+mod mesh_skinning;
+
 mod depth_pyramid;
 
+mod ibl_pass;
+
+mod reflection_probe_pass;
+
+mod ssr_pass;
+
+mod volumetric_fog_pass;
+
+mod depth_of_field_pass;
+
+mod motion_blur_pass;
+
+mod decal_pass;
+
+mod particle_pass;
+
 lazy_static::lazy_static! {
     pub static ref EMPTY_VERTEX_LAYOUT : VertexDataSetLayout = {
         VertexDataSetLayout::new(vec![], RafxPrimitiveTopology::TriangleList)
@@ -67,6 +93,15 @@ pub struct ModernPipelineRenderGraphConfig {
     pub blur_pass_count: usize,
     pub jitter_amount: glam::Vec2,
     pub sharpening_amount: f32,
+    pub enable_order_independent_transparency: bool,
+    pub enable_ssr: bool,
+    pub enable_volumetric_fog: bool,
+    pub enable_depth_of_field: bool,
+    pub enable_motion_blur: bool,
+    pub enable_decals: bool,
+    pub enable_particles: bool,
+    // Extents of everything drawn from the main view before TAA upscales it to the swapchain
+    pub render_extents: RenderGraphImageExtents,
 }
 
 // This just wraps a bunch of values so they don't have to be passed individually to all the passes
@@ -101,6 +136,14 @@ pub(super) fn generate_render_graph(
         .fetch::<TimeRenderResource>()
         .previous_update_dt();
 
+    // The main view may be rendered at a lower resolution than the swapchain, in which case TAA
+    // upscales it (see ModernPipelineRenderOptions::render_scale)
+    let render_extents = RafxExtents2D {
+        width: main_view.extents_width(),
+        height: main_view.extents_height(),
+    };
+    let render_scale = render_extents.width as f32 / swapchain_extents.width as f32;
+
     if let Some(taa_history_rt) = &static_resources.taa_history_rt {
         let taa_history_rt_extents = taa_history_rt
             .get_raw()
@@ -133,7 +176,11 @@ pub(super) fn generate_render_graph(
             RafxSampleCount::SampleCount1
         };
 
-        let color_format = if render_options.enable_hdr {
+        // HDR10 output is PQ-encoded by the tonemapping pass, so that pass can't be skipped
+        let enable_hdr = render_options.enable_hdr
+            || swapchain_info.swapchain_surface_info.color_space == RafxSwapchainColorSpace::Hdr10;
+
+        let color_format = if enable_hdr {
             RafxFormat::R16G16B16A16_SFLOAT
         } else {
             swapchain_info.default_color_format_sdr
@@ -153,7 +200,7 @@ pub(super) fn generate_render_graph(
             color_format,
             depth_format: swapchain_info.default_depth_format,
             samples: sample_count,
-            enable_hdr: render_options.enable_hdr,
+            enable_hdr,
             swapchain_format,
             enable_ssao: render_options.enable_ssao
                 && sample_count == RafxSampleCount::SampleCount1,
@@ -162,16 +209,119 @@ pub(super) fn generate_render_graph(
             blur_pass_count: render_options.blur_pass_count,
             jitter_amount,
             sharpening_amount: render_options.sharpening_amount,
+            // The composite pass samples the OIT targets directly, so they can't be multisampled
+            enable_order_independent_transparency: render_options
+                .enable_order_independent_transparency
+                && render_options.show_surfaces
+                && sample_count == RafxSampleCount::SampleCount1,
+            // The G-buffer is sampled per-pixel, so it can't be multisampled either
+            enable_ssr: render_options.enable_ssr
+                && render_options.show_surfaces
+                && sample_count == RafxSampleCount::SampleCount1,
+            enable_volumetric_fog: render_options.enable_volumetric_fog
+                && render_options.show_surfaces,
+            // Depth and velocity are sampled per-pixel, so these can't be multisampled either
+            enable_depth_of_field: render_options.enable_depth_of_field
+                && main_view.camera_lens().is_some()
+                && sample_count == RafxSampleCount::SampleCount1,
+            enable_motion_blur: render_options.enable_motion_blur
+                && sample_count == RafxSampleCount::SampleCount1,
+            // The decal buffers are sampled per-pixel when shading, and decals can only be drawn
+            // if the decal feature is registered
+            enable_decals: render_options.enable_decals
+                && render_options.show_surfaces
+                && sample_count == RafxSampleCount::SampleCount1
+                && render_resources
+                    .try_fetch::<DecalStaticResources>()
+                    .is_some(),
+            // Particles are drawn with the transparent meshes, and only if the particle feature is
+            // registered
+            enable_particles: render_options.show_surfaces
+                && render_resources
+                    .try_fetch::<ParticleStaticResources>()
+                    .is_some(),
+            render_extents: if render_extents == swapchain_extents {
+                RenderGraphImageExtents::MatchSurface
+            } else {
+                RenderGraphImageExtents::Custom(RafxExtents3D {
+                    width: render_extents.width,
+                    height: render_extents.height,
+                    depth: 1,
+                })
+            },
         }
     };
 
+    // Image-based lighting is generated from the skybox, and only needs to be regenerated when the
+    // skybox changes
+    let skybox_cubemap = if render_options.enable_ibl {
+        extract_resources
+            .try_fetch::<SkyboxResource>()
+            .and_then(|skybox_resource| {
+                skybox_resource
+                    .skybox_texture()
+                    .as_ref()
+                    .and_then(|x| asset_manager.committed_asset(x))
+                    .map(|x| x.image_view.clone())
+            })
+    } else {
+        None
+    };
+
+    let mut new_ibl_targets = None;
+    if let Some(skybox_cubemap) = &skybox_cubemap {
+        let ibl_is_current = static_resources
+            .ibl
+            .as_ref()
+            .map(|x| x.source_cubemap == *skybox_cubemap)
+            .unwrap_or(false);
+        if !ibl_is_current {
+            let ibl_targets = ibl_pass::create_ibl_targets(asset_manager)?;
+            static_resources.ibl = Some(ModernPipelineIbl {
+                source_cubemap: skybox_cubemap.clone(),
+                images: ibl_targets.images.clone(),
+            });
+            new_ibl_targets = Some(ibl_targets);
+        }
+    } else {
+        static_resources.ibl = None;
+    }
+
     // Push pipeline options into the mesh feature
     {
         let mut mesh_render_pipeline_state =
             render_resources.fetch_mut::<MeshAdvRenderPipelineState>();
         mesh_render_pipeline_state.jitter_amount = graph_config.jitter_amount;
+        // Textures are sampled at the mip the output resolution would use when upscaling
         mesh_render_pipeline_state.forward_pass_mip_bias =
-            render_options.taa_options.forward_pass_mip_bias;
+            render_options.taa_options.forward_pass_mip_bias + render_scale.log2().min(0.0);
+        mesh_render_pipeline_state.use_order_independent_transparency =
+            graph_config.enable_order_independent_transparency;
+        mesh_render_pipeline_state.ibl = static_resources.ibl.as_ref().map(|x| x.images.clone());
+        mesh_render_pipeline_state.write_gbuffer = graph_config.enable_ssr;
+        mesh_render_pipeline_state.volumetric_fog_max_distance =
+            if graph_config.enable_volumetric_fog {
+                render_options.volumetric_fog_options.max_distance
+            } else {
+                0.0
+            };
+        mesh_render_pipeline_state.enable_decals = graph_config.enable_decals;
+    }
+
+    // Push pipeline options into the decal feature
+    if let Some(mut decal_render_pipeline_state) =
+        render_resources.try_fetch_mut::<DecalRenderPipelineState>()
+    {
+        decal_render_pipeline_state.jitter_amount = graph_config.jitter_amount;
+    }
+
+    // Push pipeline options into the particle feature
+    if let Some(mut particle_render_pipeline_state) =
+        render_resources.try_fetch_mut::<ParticleRenderPipelineState>()
+    {
+        particle_render_pipeline_state.jitter_amount = graph_config.jitter_amount;
+        particle_render_pipeline_state.use_order_independent_transparency =
+            graph_config.enable_order_independent_transparency;
     }
 
     let mut taa_history_rt_has_data = false;
@@ -224,6 +374,49 @@ pub(super) fn generate_render_graph(
         None
     };
 
+    // SSR history is accumulated at the render resolution, so it's reset whenever that changes
+    let mut ssr_history_rt_has_data = false;
+    let ssr_history_rt = if graph_config.enable_ssr {
+        let required_extents = RafxExtents3D {
+            width: render_extents.width,
+            height: render_extents.height,
+            depth: 1,
+        };
+
+        let history_texture_compatible = static_resources
+            .ssr_history_rt
+            .as_ref()
+            .map(|x| x.get_raw().image.get_raw().image.texture_def().extents == required_extents)
+            .unwrap_or(false);
+
+        if !history_texture_compatible {
+            let ssr_history_rt =
+                asset_manager
+                    .device_context()
+                    .create_texture(&RafxTextureDef {
+                        resource_type: RafxResourceType::RENDER_TARGET_COLOR
+                            | RafxResourceType::TEXTURE,
+                        format: RafxFormat::R16G16B16A16_SFLOAT,
+                        extents: required_extents,
+                        dimensions: RafxTextureDimensions::Dim2D,
+                        ..Default::default()
+                    })?;
+            ssr_history_rt.set_debug_name("SSR History RT");
+            let ssr_history_rt = asset_manager.resources().insert_image(ssr_history_rt);
+            let ssr_history_rt = asset_manager
+                .resources()
+                .get_or_create_image_view(&ssr_history_rt, None)?;
+            static_resources.ssr_history_rt = Some(ssr_history_rt.clone());
+            Some(ssr_history_rt)
+        } else {
+            ssr_history_rt_has_data = true;
+            static_resources.ssr_history_rt.clone()
+        }
+    } else {
+        static_resources.ssr_history_rt = None;
+        None
+    };
+
     let tonemap_debug_data = extract_resources
         .try_fetch::<ModernPipelineTonemapDebugData>()
         .map(|x| x.clone());
@@ -253,6 +446,10 @@ pub(super) fn generate_render_graph(
 
     let shadow_atlas_image = shadow_atlas.add_to_render_graph(graph_context.graph);
     let shadow_atlas_needs_full_clear = shadow_atlas.take_requires_full_clear();
+    let shadow_atlas_extents = RafxExtents2D {
+        width: shadow_atlas.image_width(),
+        height: shadow_atlas.image_height(),
+    };
     drop(shadow_atlas);
 
     let tonemap_histogram_result = graph_context.graph.add_external_buffer(
@@ -273,7 +470,50 @@ pub(super) fn generate_render_graph(
         RafxResourceState::UNORDERED_ACCESS,
     );
 
+    let mesh_skinning_pipeline = asset_manager
+        .committed_asset(&static_resources.mesh_skinning_pipeline)
+        .unwrap()
+        .compute_pipeline
+        .clone();
+
+    // Skinned vertices must be written before anything draws meshes
+    let mesh_skinning_pass =
+        mesh_skinning::mesh_skinning_pass(&mut graph_context, &mesh_skinning_pipeline);
+
+    let ibl_pass =
+        if let (Some(skybox_cubemap), Some(ibl_targets)) = (&skybox_cubemap, new_ibl_targets) {
+            let ibl_brdf_lut_pipeline = asset_manager
+                .committed_asset(&static_resources.ibl_brdf_lut_pipeline)
+                .unwrap()
+                .compute_pipeline
+                .clone();
+            let ibl_irradiance_pipeline = asset_manager
+                .committed_asset(&static_resources.ibl_irradiance_pipeline)
+                .unwrap()
+                .compute_pipeline
+                .clone();
+            let ibl_specular_pipeline = asset_manager
+                .committed_asset(&static_resources.ibl_specular_pipeline)
+                .unwrap()
+                .compute_pipeline
+                .clone();
+
+            Some(ibl_pass::ibl_pass(
+                &mut graph_context,
+                &ibl_brdf_lut_pipeline,
+                &ibl_irradiance_pipeline,
+                &ibl_specular_pipeline,
+                skybox_cubemap,
+                ibl_targets,
+            ))
+        } else {
+            None
+        };
+
     let depth_prepass = depth_prepass::depth_prepass(&mut graph_context);
+    graph_context
+        .graph
+        .add_explicit_dependency(mesh_skinning_pass.node, depth_prepass.node);
 
     let depth_pyramid_pipeline = asset_manager
         .committed_asset(&static_resources.depth_pyramid_pipeline)
@@ -285,9 +525,61 @@ pub(super) fn generate_render_graph(
         &mut graph_context,
         &depth_pyramid_pipeline,
         depth_prepass.depth,
-        &swapchain_info.swapchain_surface_info,
+        render_extents,
     );
 
+    let particle_simulation_pass = if graph_config.enable_particles {
+        let particle_pipelines = particle_pass::ParticleComputePipelines {
+            init: asset_manager
+                .committed_asset(&static_resources.particle_init_pipeline)
+                .unwrap()
+                .compute_pipeline
+                .clone(),
+            spawn: asset_manager
+                .committed_asset(&static_resources.particle_spawn_pipeline)
+                .unwrap()
+                .compute_pipeline
+                .clone(),
+            indirect_args: asset_manager
+                .committed_asset(&static_resources.particle_indirect_args_pipeline)
+                .unwrap()
+                .compute_pipeline
+                .clone(),
+            simulate: asset_manager
+                .committed_asset(&static_resources.particle_simulate_pipeline)
+                .unwrap()
+                .compute_pipeline
+                .clone(),
+        };
+
+        Some(particle_pass::particle_simulation_pass(
+            &mut graph_context,
+            &particle_pipelines,
+        ))
+    } else {
+        None
+    };
+
+    let particle_depth = if graph_config.enable_particles {
+        let particle_material = render_resources
+            .fetch::<ParticleStaticResources>()
+            .particle_material
+            .clone();
+        let particle_material = asset_manager.committed_asset(&particle_material).unwrap();
+        let material_pass = if graph_config.enable_order_independent_transparency {
+            particle_material.get_material_pass_by_name("particle_oit")
+        } else {
+            particle_material.get_material_pass_by_name("particle")
+        };
+
+        Some(particle_pass::ParticleDepth {
+            depth_pyramid_mip: depth_pyramid_pass.depth_pyramid_mips[1],
+            material_pass: material_pass.unwrap(),
+        })
+    } else {
+        None
+    };
+
     let ssao_material_pass = asset_manager
         .committed_asset(&static_resources.ssao_material)
         .unwrap()
@@ -320,6 +612,7 @@ pub(super) fn generate_render_graph(
             bloom_blur_material_pass.clone(),
             ssao_rt,
             1,
+            graph_config.render_extents,
         )
         .color;
 
@@ -331,12 +624,55 @@ pub(super) fn generate_render_graph(
     let shadow_map_pass_output = shadow_map_pass::shadow_map_passes(
         &mut graph_context,
         shadow_atlas_image,
+        shadow_atlas_extents,
         shadow_atlas_needs_full_clear,
     );
+    if let Some(shadow_map_pass_node) = shadow_map_pass_output.node {
+        graph_context
+            .graph
+            .add_explicit_dependency(mesh_skinning_pass.node, shadow_map_pass_node);
+    }
+
+    let light_bin_pass =
+        light_binning::lights_bin_pass(&mut graph_context, MeshAdvLightBinList::Lights);
+    let build_light_lists_pass = light_binning::lights_build_lists_pass(
+        &mut graph_context,
+        MeshAdvLightBinList::Lights,
+        light_bin_pass,
+    );
 
-    let light_bin_pass = light_binning::lights_bin_pass(&mut graph_context);
-    let build_light_lists_pass =
-        light_binning::lights_build_lists_pass(&mut graph_context, light_bin_pass);
+    // Reflection probes are binned the same way as lights
+    let reflection_probe_bin_pass =
+        light_binning::lights_bin_pass(&mut graph_context, MeshAdvLightBinList::ReflectionProbes);
+    let build_reflection_probe_lists_pass = light_binning::lights_build_lists_pass(
+        &mut graph_context,
+        MeshAdvLightBinList::ReflectionProbes,
+        reflection_probe_bin_pass,
+    );
+
+    let reflection_probe_prefilter_pipeline = asset_manager
+        .committed_asset(&static_resources.reflection_probe_prefilter_pipeline)
+        .unwrap()
+        .compute_pipeline
+        .clone();
+
+    let reflection_probe_pass = reflection_probe_pass::reflection_probe_pass(
+        &mut graph_context,
+        &reflection_probe_prefilter_pipeline,
+        &shadow_map_pass_output,
+    );
+    if let Some(reflection_probe_pass) = &reflection_probe_pass {
+        for &capture_node in &reflection_probe_pass.capture_nodes {
+            graph_context
+                .graph
+                .add_explicit_dependency(mesh_skinning_pass.node, capture_node);
+            if let Some(ibl_pass) = &ibl_pass {
+                graph_context
+                    .graph
+                    .add_explicit_dependency(ibl_pass.node, capture_node);
+            }
+        }
+    }
 
     let mesh_culling_pipeline = asset_manager
         .committed_asset(&static_resources.mesh_culling_pipeline)
@@ -349,7 +685,7 @@ pub(super) fn generate_render_graph(
         Some(mesh_culling::mesh_culling_pass(
             &mut graph_context,
             &mesh_culling_pipeline,
-            &swapchain_info.swapchain_surface_info,
+            render_extents,
             &depth_pyramid_pass,
             mesh_culling_debug_data,
             mesh_culling_debug_output,
@@ -358,12 +694,64 @@ pub(super) fn generate_render_graph(
         None
     };
 
+    let volumetric_fog_volume = if graph_config.enable_volumetric_fog {
+        let volumetric_fog_inject_pipeline = asset_manager
+            .committed_asset(&static_resources.volumetric_fog_inject_pipeline)
+            .unwrap()
+            .compute_pipeline
+            .clone();
+        let volumetric_fog_integrate_pipeline = asset_manager
+            .committed_asset(&static_resources.volumetric_fog_integrate_pipeline)
+            .unwrap()
+            .compute_pipeline
+            .clone();
+
+        let volumetric_fog_pass = volumetric_fog_pass::volumetric_fog_pass(
+            &mut graph_context,
+            &volumetric_fog_inject_pipeline,
+            &volumetric_fog_integrate_pipeline,
+            &render_options.volumetric_fog_options,
+            &shadow_map_pass_output,
+            &build_light_lists_pass,
+        );
+
+        Some(volumetric_fog_pass.volume)
+    } else {
+        None
+    };
+
+    let decal_buffers = if graph_config.enable_decals {
+        let decal_material = render_resources
+            .fetch::<DecalStaticResources>()
+            .decal_material
+            .clone();
+        let decal_material_pass = asset_manager
+            .committed_asset(&decal_material)
+            .unwrap()
+            .get_single_material_pass()
+            .unwrap();
+
+        let decal_pass = decal_pass::decal_pass(
+            &mut graph_context,
+            &decal_material_pass,
+            depth_prepass.depth,
+        );
+
+        Some(decal_pass.decal_buffers)
+    } else {
+        None
+    };
+
     let opaque_pass = opaque_pass::opaque_pass(
         &mut graph_context,
         depth_prepass.depth,
         &shadow_map_pass_output,
         &build_light_lists_pass,
+        &build_reflection_probe_lists_pass,
         ssao_rt,
+        volumetric_fog_volume,
+        decal_buffers,
+        particle_depth.clone(),
     );
 
     if render_options.enable_occlusion_culling {
@@ -371,6 +759,162 @@ pub(super) fn generate_render_graph(
             .graph
             .add_explicit_dependency(mesh_culling_node.unwrap().node, opaque_pass.node);
     }
+    graph_context
+        .graph
+        .add_explicit_dependency(mesh_skinning_pass.node, opaque_pass.node);
+    if let Some(particle_simulation_pass) = &particle_simulation_pass {
+        graph_context
+            .graph
+            .add_explicit_dependency(particle_simulation_pass.node, opaque_pass.node);
+    }
+    if let Some(ibl_pass) = &ibl_pass {
+        graph_context
+            .graph
+            .add_explicit_dependency(ibl_pass.node, opaque_pass.node);
+    }
+    if let Some(reflection_probe_pass) = &reflection_probe_pass {
+        graph_context
+            .graph
+            .add_explicit_dependency(reflection_probe_pass.node, opaque_pass.node);
+    }
+
+    let mut opaque_color = opaque_pass.color;
+    if let Some(gbuffer) = opaque_pass.gbuffer {
+        let ssr_pipeline = asset_manager
+            .committed_asset(&static_resources.ssr_pipeline)
+            .unwrap()
+            .compute_pipeline
+            .clone();
+
+        let ssr_history_rt_image_id = graph_context.graph.add_external_image(
+            ssr_history_rt.unwrap(),
+            Default::default(),
+            RafxResourceState::COPY_DST,
+            RafxResourceState::COPY_DST,
+        );
+
+        let ssr_pass = ssr_pass::ssr_pass(
+            &mut graph_context,
+            &ssr_pipeline,
+            &render_options.ssr_options,
+            &depth_pyramid_pass,
+            gbuffer,
+            opaque_color,
+            depth_prepass.velocity_rt,
+            ssr_history_rt_image_id,
+            ssr_history_rt_has_data,
+            render_extents,
+        );
+
+        let ssr_composite_material_pass = asset_manager
+            .committed_asset(&static_resources.ssr_composite_material)
+            .unwrap()
+            .get_single_material_pass()
+            .unwrap();
+
+        opaque_color = ssr_pass::ssr_composite_pass(
+            &mut graph_context,
+            ssr_composite_material_pass,
+            &render_options.ssr_options,
+            opaque_color,
+            depth_prepass.depth,
+            gbuffer,
+            &ssr_pass,
+        )
+        .color;
+
+        // Sorted transparent meshes are drawn over the reflections. (OIT composites them below.)
+        if !graph_config.enable_order_independent_transparency {
+            let transparent_pass = opaque_pass::transparent_pass(
+                &mut graph_context,
+                opaque_color,
+                depth_prepass.depth,
+                &shadow_map_pass_output,
+                &build_light_lists_pass,
+                &build_reflection_probe_lists_pass,
+                ssao_rt,
+                volumetric_fog_volume,
+                particle_depth.clone(),
+            );
+            if let Some(particle_simulation_pass) = &particle_simulation_pass {
+                graph_context
+                    .graph
+                    .add_explicit_dependency(particle_simulation_pass.node, transparent_pass.node);
+            }
+            opaque_color = transparent_pass.color;
+        }
+    }
+
+    let opaque_color = if graph_config.enable_order_independent_transparency {
+        let oit_accumulate_pass = oit_pass::oit_accumulate_pass(
+            &mut graph_context,
+            depth_prepass.depth,
+            &shadow_map_pass_output,
+            &build_light_lists_pass,
+            &build_reflection_probe_lists_pass,
+            ssao_rt,
+            volumetric_fog_volume,
+            particle_depth,
+        );
+        graph_context
+            .graph
+            .add_explicit_dependency(mesh_skinning_pass.node, oit_accumulate_pass.node);
+        if let Some(particle_simulation_pass) = &particle_simulation_pass {
+            graph_context
+                .graph
+                .add_explicit_dependency(particle_simulation_pass.node, oit_accumulate_pass.node);
+        }
+        if let Some(ibl_pass) = &ibl_pass {
+            graph_context
+                .graph
+                .add_explicit_dependency(ibl_pass.node, oit_accumulate_pass.node);
+        }
+        if let Some(reflection_probe_pass) = &reflection_probe_pass {
+            graph_context
+                .graph
+                .add_explicit_dependency(reflection_probe_pass.node, oit_accumulate_pass.node);
+        }
+
+        let oit_composite_material_pass = asset_manager
+            .committed_asset(&static_resources.oit_composite_material)
+            .unwrap()
+            .get_single_material_pass()
+            .unwrap();
+
+        oit_pass::oit_composite_pass(
+            &mut graph_context,
+            oit_composite_material_pass,
+            opaque_color,
+            depth_prepass.depth,
+            &oit_accumulate_pass,
+        )
+        .color
+    } else {
+        opaque_color
+    };
+
+    // Applied before TAA so that TAA resolves the noise from its limited sample count
+    let opaque_color = if graph_config.enable_depth_of_field {
+        let depth_of_field_pipeline = asset_manager
+            .committed_asset(&static_resources.depth_of_field_pipeline)
+            .unwrap()
+            .compute_pipeline
+            .clone();
+
+        depth_of_field_pass::depth_of_field_pass(
+            &mut graph_context,
+            &depth_of_field_pipeline,
+            &render_options.depth_of_field_options,
+            main_view.camera_lens().unwrap(),
+            opaque_color,
+            depth_prepass.depth,
+            render_extents,
+            render_scale,
+        )
+        .color_rt
+    } else {
+        opaque_color
+    };
 
     let taa_material_pass = asset_manager
         .committed_asset(&static_resources.taa_material)
@@ -390,16 +934,40 @@ pub(super) fn generate_render_graph(
             &mut graph_context,
             &render_options.taa_options,
             taa_material_pass,
-            opaque_pass.color,
+            opaque_color,
             depth_prepass.depth,
             depth_prepass.velocity_rt,
             taa_history_rt_image_id,
             taa_history_rt_has_data,
+            swapchain_extents,
         );
 
         taa_pass.color_rt
     } else {
-        opaque_pass.color
+        opaque_color
+    };
+
+    // Applied after TAA so that TAA doesn't reproject the blur, and before bloom so that bright
+    // objects bloom along their blur
+    let color_rt = if graph_config.enable_motion_blur {
+        let motion_blur_pipeline = asset_manager
+            .committed_asset(&static_resources.motion_blur_pipeline)
+            .unwrap()
+            .compute_pipeline
+            .clone();
+
+        motion_blur_pass::motion_blur_pass(
+            &mut graph_context,
+            &motion_blur_pipeline,
+            &render_options.motion_blur_options,
+            color_rt,
+            depth_prepass.depth,
+            depth_prepass.velocity_rt,
+            swapchain_extents,
+        )
+        .color_rt
+    } else {
+        color_rt
     };
 
     let mut previous_pass_color = if graph_config.enable_hdr {
@@ -494,6 +1062,7 @@ pub(super) fn generate_render_graph(
             blurred_color,
             &luma_average_histogram_pass,
             &*swapchain_render_resource,
+            &static_resources.identity_color_grading_lut,
         );
 
         bloom_combine_pass.color
@@ -0,0 +1,135 @@
+use rafx::framework::{ComputePipelineResource, DescriptorSetBindings, ResourceArc};
+use rafx::graph::*;
+
+use super::ModernPipelineContext;
+use crate::pipelines::modern::MotionBlurOptions;
+use crate::shaders::post_adv::motion_blur_comp;
+use rafx::api::{RafxExtents2D, RafxFormat, RafxSampleCount};
+use rafx::renderer::MainViewRenderResource;
+
+pub(super) struct MotionBlurPass {
+    #[allow(dead_code)]
+    pub(super) node: RenderGraphNodeId,
+    pub(super) color_rt: RenderGraphImageUsageId,
+}
+
+// Blurs the color along the velocity buffer. This runs after TAA, so the color is at the swapchain
+// resolution while the velocity and depth may be at the lower render resolution.
+pub(super) fn motion_blur_pass(
+    context: &mut ModernPipelineContext,
+    motion_blur_pipeline: &ResourceArc<ComputePipelineResource>,
+    motion_blur_options: &MotionBlurOptions,
+    color_rt: RenderGraphImageUsageId,
+    depth_rt: RenderGraphImageUsageId,
+    velocity_rt: RenderGraphImageUsageId,
+    output_extents: RafxExtents2D,
+) -> MotionBlurPass {
+    let node = context
+        .graph
+        .add_callback_node("MotionBlur", RenderGraphQueue::DefaultGraphics);
+
+    let color_rt =
+        context
+            .graph
+            .sample_image(node, color_rt, Default::default(), Default::default());
+
+    let depth_rt =
+        context
+            .graph
+            .sample_image(node, depth_rt, Default::default(), Default::default());
+
+    let velocity_rt =
+        context
+            .graph
+            .sample_image(node, velocity_rt, Default::default(), Default::default());
+
+    let output_rt = context.graph.create_storage_image(
+        node,
+        RenderGraphImageConstraint {
+            samples: Some(RafxSampleCount::SampleCount1),
+            format: Some(RafxFormat::R16G16B16A16_SFLOAT),
+            extents: Some(RenderGraphImageExtents::MatchSurface),
+            ..Default::default()
+        },
+        Default::default(),
+    );
+    context.graph.set_image_name(output_rt, "motion_blur");
+
+    let motion_blur_pipeline = motion_blur_pipeline.clone();
+    let motion_blur_options = motion_blur_options.clone();
+    context.graph.set_callback(node, move |args| {
+        let color_rt = args.graph_context.image_view(color_rt).unwrap();
+        let depth_rt = args.graph_context.image_view(depth_rt).unwrap();
+        let velocity_rt = args.graph_context.image_view(velocity_rt).unwrap();
+        let output_rt = args.graph_context.image_view(output_rt).unwrap();
+
+        let main_view_resource = args
+            .graph_context
+            .render_resources()
+            .fetch::<MainViewRenderResource>();
+        let main_view = main_view_resource.main_view.clone().unwrap();
+        let current_view_proj_inv = main_view.view_proj().inverse();
+        let previous_view_proj =
+            if let Some(previous_main_view_info) = &main_view_resource.previous_main_view_info {
+                previous_main_view_info.projection_matrix * previous_main_view_info.view_matrix
+            } else {
+                main_view.view_proj()
+            };
+
+        let mut descriptor_set_allocator = args
+            .graph_context
+            .resource_context()
+            .create_descriptor_set_allocator();
+        let mut descriptor_set = descriptor_set_allocator.create_dyn_descriptor_set_uninitialized(
+            &motion_blur_pipeline.get_raw().descriptor_set_layouts[0],
+        )?;
+
+        descriptor_set.set_buffer_data(
+            motion_blur_comp::CONFIG_DESCRIPTOR_BINDING_INDEX as u32,
+            &motion_blur_comp::MotionBlurConfigUniform {
+                current_view_proj_inv: current_view_proj_inv.to_cols_array_2d(),
+                previous_view_proj: previous_view_proj.to_cols_array_2d(),
+                shutter_fraction: motion_blur_options.shutter_fraction,
+                max_blur_length: motion_blur_options.max_blur_length,
+                sample_count: motion_blur_options.sample_count,
+                viewport_width: output_extents.width,
+                viewport_height: output_extents.height,
+                ..Default::default()
+            },
+        );
+        descriptor_set.set_image(
+            motion_blur_comp::COLOR_TEX_DESCRIPTOR_BINDING_INDEX as u32,
+            &color_rt,
+        );
+        descriptor_set.set_image(
+            motion_blur_comp::VELOCITY_TEX_DESCRIPTOR_BINDING_INDEX as u32,
+            &velocity_rt,
+        );
+        descriptor_set.set_image(
+            motion_blur_comp::DEPTH_TEX_DESCRIPTOR_BINDING_INDEX as u32,
+            &depth_rt,
+        );
+        descriptor_set.set_image(
+            motion_blur_comp::OUTPUT_TEX_DESCRIPTOR_BINDING_INDEX as u32,
+            &output_rt,
+        );
+        descriptor_set.flush(&mut descriptor_set_allocator)?;
+        descriptor_set_allocator.flush_changes()?;
+
+        let command_buffer = &args.command_buffer;
+        command_buffer.cmd_bind_pipeline(&*motion_blur_pipeline.get_raw().pipeline)?;
+        descriptor_set.bind(command_buffer)?;
+        command_buffer.cmd_dispatch(
+            (output_extents.width + 15) / 16,
+            (output_extents.height + 15) / 16,
+            1,
+        )?;
+
+        Ok(())
+    });
+
+    MotionBlurPass {
+        node,
+        color_rt: output_rt,
+    }
+}
@@ -0,0 +1,292 @@
+use crate::phases::{PostProcessRenderPhase, TransparentRenderPhase, WireframeRenderPhase};
+use rafx::api::{RafxColorClearValue, RafxFormat};
+use rafx::framework::{MaterialPassResource, ResourceArc};
+use rafx::graph::*;
+
+use super::opaque_pass::create_ssao_descriptor_set;
+use super::particle_pass::{self, ParticleDepth};
+use super::ModernPipelineContext;
+use super::EMPTY_VERTEX_LAYOUT;
+use crate::features::mesh_adv::{MeshAdvRenderPipelineState, MeshAdvStaticResources};
+use crate::pipelines::modern::graph_generator::light_binning::LightBuildListsPass;
+use crate::pipelines::modern::graph_generator::shadow_map_pass::ShadowMapPassOutput;
+use crate::shaders::post_adv::oit_composite_frag;
+use rafx::render_features::{RenderJobCommandBufferContext, RenderPhase};
+
+// Weighted blended order-independent transparency (McGuire and Bavoil, 2013). Transparent meshes
+// are drawn unsorted into an accumulation target (sum of weighted premultiplied color) and a
+// revealage target (product of 1 - alpha), and a fullscreen pass blends the weighted average over
+// the opaque color.
+const OIT_ACCUM_FORMAT: RafxFormat = RafxFormat::R16G16B16A16_SFLOAT;
+const OIT_REVEALAGE_FORMAT: RafxFormat = RafxFormat::R16_SFLOAT;
+
+pub(super) struct OitAccumulatePass {
+    #[allow(dead_code)]
+    pub(super) node: RenderGraphNodeId,
+    pub(super) accum: RenderGraphImageUsageId,
+    pub(super) revealage: RenderGraphImageUsageId,
+}
+
+pub(super) fn oit_accumulate_pass(
+    context: &mut ModernPipelineContext,
+    depth_prepass: RenderGraphImageUsageId,
+    shadow_map_pass_output: &ShadowMapPassOutput,
+    light_build_lists_pass: &LightBuildListsPass,
+    reflection_probe_build_lists_pass: &LightBuildListsPass,
+    ssao_rt: Option<RenderGraphImageUsageId>,
+    volumetric_fog_volume: Option<RenderGraphImageUsageId>,
+    particle_depth: Option<ParticleDepth>,
+) -> OitAccumulatePass {
+    let node = context
+        .graph
+        .add_renderpass_node("OitAccumulate", RenderGraphQueue::DefaultGraphics);
+
+    let accum = context.graph.create_color_attachment(
+        node,
+        0,
+        Some(RafxColorClearValue([0.0, 0.0, 0.0, 0.0])),
+        RenderGraphImageConstraint {
+            samples: Some(context.graph_config.samples),
+            format: Some(OIT_ACCUM_FORMAT),
+            extents: Some(context.graph_config.render_extents),
+            ..Default::default()
+        },
+        Default::default(),
+    );
+    context.graph.set_image_name(accum, "oit_accum");
+
+    let revealage = context.graph.create_color_attachment(
+        node,
+        1,
+        Some(RafxColorClearValue([1.0, 0.0, 0.0, 0.0])),
+        RenderGraphImageConstraint {
+            samples: Some(context.graph_config.samples),
+            format: Some(OIT_REVEALAGE_FORMAT),
+            extents: Some(context.graph_config.render_extents),
+            ..Default::default()
+        },
+        Default::default(),
+    );
+    context.graph.set_image_name(revealage, "oit_revealage");
+
+    context.graph.read_depth_attachment(
+        node,
+        depth_prepass,
+        RenderGraphImageConstraint {
+            samples: Some(context.graph_config.samples),
+            format: Some(context.graph_config.depth_format),
+            ..Default::default()
+        },
+        Default::default(),
+    );
+
+    let ssao_rt = ssao_rt.map(|x| {
+        context.graph.sample_image(
+            node,
+            x,
+            RenderGraphImageConstraint::default(),
+            RenderGraphImageViewOptions::default(),
+        )
+    });
+
+    let volumetric_fog_volume = volumetric_fog_volume.map(|x| {
+        context.graph.sample_image(
+            node,
+            x,
+            RenderGraphImageConstraint::default(),
+            RenderGraphImageViewOptions::default(),
+        )
+    });
+
+    let particle_depth =
+        particle_depth.map(|x| particle_pass::sample_particle_depth(context, node, x));
+
+    // These are buffers owned by MeshAdvLightBinRenderResource
+    context.graph.read_storage_buffer(
+        node,
+        light_build_lists_pass.light_lists_buffer,
+        Default::default(),
+    );
+    context.graph.read_storage_buffer(
+        node,
+        reflection_probe_build_lists_pass.light_lists_buffer,
+        Default::default(),
+    );
+
+    context.graph.sample_image(
+        node,
+        shadow_map_pass_output.shadow_atlas_image,
+        Default::default(),
+        Default::default(),
+    );
+
+    context
+        .graph
+        .add_render_phase_dependency::<TransparentRenderPhase>(node);
+
+    let main_view = context.main_view.clone();
+
+    let default_pbr_material = context
+        .render_resources
+        .fetch::<MeshAdvStaticResources>()
+        .default_pbr_material
+        .clone();
+    let default_pbr_material = context
+        .asset_manager
+        .committed_asset(&default_pbr_material)
+        .unwrap()
+        .clone();
+
+    context.graph.set_renderpass_callback(node, move |args| {
+        let mut write_context =
+            RenderJobCommandBufferContext::from_graph_visit_render_pass_args(&args);
+
+        // Decals are only applied to opaque meshes
+        let descriptor_set = create_ssao_descriptor_set(
+            &args,
+            &default_pbr_material,
+            ssao_rt,
+            volumetric_fog_volume,
+            None,
+        )?;
+
+        args.graph_context
+            .render_resources()
+            .fetch_mut::<MeshAdvRenderPipelineState>()
+            .ssao_descriptor_set = Some(descriptor_set);
+
+        {
+            profiling::scope!("Transparent Pass");
+            particle_pass::set_particle_depth_descriptor_set(&args, particle_depth.as_ref())?;
+            args.graph_context
+                .prepared_render_data()
+                .write_view_phase::<TransparentRenderPhase>(&main_view, &mut write_context)?;
+            particle_pass::set_particle_depth_descriptor_set(&args, None)?;
+        }
+
+        args.graph_context
+            .render_resources()
+            .fetch_mut::<MeshAdvRenderPipelineState>()
+            .ssao_descriptor_set = None;
+
+        Ok(())
+    });
+
+    OitAccumulatePass {
+        node,
+        accum,
+        revealage,
+    }
+}
+
+pub(super) struct OitCompositePass {
+    #[allow(dead_code)]
+    pub(super) node: RenderGraphNodeId,
+    pub(super) color: RenderGraphImageUsageId,
+}
+
+pub(super) fn oit_composite_pass(
+    context: &mut ModernPipelineContext,
+    oit_composite_material_pass: ResourceArc<MaterialPassResource>,
+    opaque_color: RenderGraphImageUsageId,
+    depth_prepass: RenderGraphImageUsageId,
+    oit_accumulate_pass: &OitAccumulatePass,
+) -> OitCompositePass {
+    let node = context
+        .graph
+        .add_renderpass_node("OitComposite", RenderGraphQueue::DefaultGraphics);
+
+    let color = context.graph.modify_color_attachment(
+        node,
+        opaque_color,
+        0,
+        None,
+        RenderGraphImageConstraint {
+            samples: Some(context.graph_config.samples),
+            format: Some(context.graph_config.color_format),
+            ..Default::default()
+        },
+        Default::default(),
+    );
+    context.graph.set_image_name(color, "color");
+
+    // Wireframes are drawn after compositing and may depth test
+    context.graph.read_depth_attachment(
+        node,
+        depth_prepass,
+        RenderGraphImageConstraint {
+            samples: Some(context.graph_config.samples),
+            format: Some(context.graph_config.depth_format),
+            ..Default::default()
+        },
+        Default::default(),
+    );
+
+    let accum = context.graph.sample_image(
+        node,
+        oit_accumulate_pass.accum,
+        Default::default(),
+        Default::default(),
+    );
+    context.graph.set_image_name(accum, "oit_accum");
+
+    let revealage = context.graph.sample_image(
+        node,
+        oit_accumulate_pass.revealage,
+        Default::default(),
+        Default::default(),
+    );
+    context.graph.set_image_name(revealage, "oit_revealage");
+
+    let main_view = context.main_view.clone();
+
+    context.graph.set_renderpass_callback(node, move |args| {
+        let accum = args.graph_context.image_view(accum).unwrap();
+        let revealage = args.graph_context.image_view(revealage).unwrap();
+
+        let pipeline = args
+            .graph_context
+            .resource_context()
+            .graphics_pipeline_cache()
+            .get_or_create_graphics_pipeline(
+                Some(PostProcessRenderPhase::render_phase_index()),
+                &oit_composite_material_pass,
+                &args.render_target_meta,
+                &EMPTY_VERTEX_LAYOUT,
+            )?;
+
+        let mut descriptor_set_allocator = args
+            .graph_context
+            .resource_context()
+            .create_descriptor_set_allocator();
+
+        let descriptor_set_layouts = &pipeline.get_raw().descriptor_set_layouts;
+        let oit_composite_material_dyn_set = descriptor_set_allocator.create_descriptor_set(
+            &descriptor_set_layouts[oit_composite_frag::ACCUM_TEX_DESCRIPTOR_SET_INDEX],
+            oit_composite_frag::DescriptorSet0Args {
+                accum_tex: &accum,
+                revealage_tex: &revealage,
+            },
+        )?;
+
+        descriptor_set_allocator.flush_changes()?;
+
+        let command_buffer = &args.command_buffer;
+        command_buffer.cmd_bind_pipeline(&pipeline.get_raw().pipeline)?;
+        oit_composite_material_dyn_set.bind(command_buffer)?;
+        command_buffer.cmd_draw(3, 0)?;
+
+        {
+            profiling::scope!("Wireframes Pass");
+            let mut write_context =
+                RenderJobCommandBufferContext::from_graph_visit_render_pass_args(&args);
+            args.graph_context
+                .prepared_render_data()
+                .write_view_phase::<WireframeRenderPhase>(&main_view, &mut write_context)?;
+        }
+
+        Ok(())
+    });
+
+    OitCompositePass { node, color }
+}
@@ -1,13 +1,17 @@
 use crate::phases::{OpaqueRenderPhase, TransparentRenderPhase, WireframeRenderPhase};
 use rafx::graph::*;
 
+use super::decal_pass::DecalBuffers;
+use super::particle_pass::{self, ParticleDepth};
 use super::ModernPipelineContext;
 use crate::assets::mesh_adv::MeshAdvShaderPassIndices;
 use crate::features::mesh_adv::{MeshAdvRenderPipelineState, MeshAdvStaticResources};
 use crate::pipelines::modern::graph_generator::light_binning::LightBuildListsPass;
 use crate::pipelines::modern::graph_generator::shadow_map_pass::ShadowMapPassOutput;
 use crate::shaders::mesh_adv::mesh_adv_textured_frag;
-use rafx::api::RafxColorClearValue;
+use rafx::api::{RafxColorClearValue, RafxFormat, RafxResult};
+use rafx::assets::MaterialAsset;
+use rafx::framework::DescriptorSetArc;
 use rafx::render_features::RenderJobCommandBufferContext;
 use rafx::renderer::InvalidResources;
 
@@ -15,6 +19,8 @@ pub(super) struct OpaquePass {
     #[allow(dead_code)]
     pub(super) node: RenderGraphNodeId,
     pub(super) color: RenderGraphImageUsageId,
+    // Only written when screen-space reflections are enabled, see mesh_adv_gbuffer.glsl
+    pub(super) gbuffer: Option<RenderGraphImageUsageId>,
     #[allow(dead_code)]
     pub(super) shadow_map_atlas: RenderGraphImageUsageId,
 }
@@ -24,7 +30,11 @@ pub(super) fn opaque_pass(
     depth_prepass: RenderGraphImageUsageId,
     shadow_map_pass_output: &ShadowMapPassOutput,
     light_build_lists_pass: &LightBuildListsPass,
+    reflection_probe_build_lists_pass: &LightBuildListsPass,
     ssao_rt: Option<RenderGraphImageUsageId>,
+    volumetric_fog_volume: Option<RenderGraphImageUsageId>,
+    decal_buffers: Option<DecalBuffers>,
+    particle_depth: Option<ParticleDepth>,
 ) -> OpaquePass {
     let node = context
         .graph
@@ -37,12 +47,32 @@ pub(super) fn opaque_pass(
         RenderGraphImageConstraint {
             samples: Some(context.graph_config.samples),
             format: Some(context.graph_config.color_format),
+            extents: Some(context.graph_config.render_extents),
             ..Default::default()
         },
         Default::default(),
     );
     context.graph.set_image_name(color, "color");
 
+    let gbuffer = if context.graph_config.enable_ssr {
+        let gbuffer = context.graph.create_color_attachment(
+            node,
+            1,
+            Some(RafxColorClearValue([0.0, 0.0, 0.0, 0.0])),
+            RenderGraphImageConstraint {
+                samples: Some(context.graph_config.samples),
+                format: Some(RafxFormat::R16G16B16A16_SFLOAT),
+                extents: Some(context.graph_config.render_extents),
+                ..Default::default()
+            },
+            Default::default(),
+        );
+        context.graph.set_image_name(gbuffer, "gbuffer");
+        Some(gbuffer)
+    } else {
+        None
+    };
+
     //let mut shadow_maps = Vec::with_capacity(shadow_map_passes.len());
 
     if context.graph_config.show_surfaces {
@@ -67,12 +97,46 @@ pub(super) fn opaque_pass(
         )
     });
 
-    // This is a buffer owned by MeshAdvLightBinRenderResource
+    let volumetric_fog_volume = volumetric_fog_volume.map(|x| {
+        context.graph.sample_image(
+            node,
+            x,
+            RenderGraphImageConstraint::default(),
+            RenderGraphImageViewOptions::default(),
+        )
+    });
+
+    let decal_buffers = decal_buffers.map(|decal_buffers| {
+        let mut sample_decal_buffer = |x| {
+            context.graph.sample_image(
+                node,
+                x,
+                RenderGraphImageConstraint::default(),
+                RenderGraphImageViewOptions::default(),
+            )
+        };
+
+        DecalBuffers {
+            albedo: sample_decal_buffer(decal_buffers.albedo),
+            normal: sample_decal_buffer(decal_buffers.normal),
+            roughness: sample_decal_buffer(decal_buffers.roughness),
+        }
+    });
+
+    let particle_depth =
+        particle_depth.map(|x| particle_pass::sample_particle_depth(context, node, x));
+
+    // These are buffers owned by MeshAdvLightBinRenderResource
     context.graph.read_storage_buffer(
         node,
         light_build_lists_pass.light_lists_buffer,
         Default::default(),
     );
+    context.graph.read_storage_buffer(
+        node,
+        reflection_probe_build_lists_pass.light_lists_buffer,
+        Default::default(),
+    );
 
     let shadow_map_atlas = context.graph.sample_image(
         node,
@@ -87,6 +151,10 @@ pub(super) fn opaque_pass(
 
     let main_view = context.main_view.clone();
     let show_models = context.graph_config.show_surfaces;
+    // With OIT, transparent meshes and wireframes are drawn by the OIT composite pass. With SSR,
+    // they are drawn by transparent_pass after reflections are composited onto the opaque color.
+    let draw_transparent = !context.graph_config.enable_order_independent_transparency
+        && !context.graph_config.enable_ssr;
 
     let default_pbr_material = context
         .render_resources
@@ -103,34 +171,13 @@ pub(super) fn opaque_pass(
         let mut write_context =
             RenderJobCommandBufferContext::from_graph_visit_render_pass_args(&args);
 
-        let invalid_image = args
-            .graph_context
-            .render_resources()
-            .fetch::<InvalidResources>()
-            .invalid_image_color
-            .clone();
-
-        let ssao_rt = ssao_rt.map(|x| args.graph_context.image_view(x).unwrap());
-        let ssao_rt = ssao_rt.unwrap_or(invalid_image);
-
-        let default_pbr_material_pass_indices =
-            MeshAdvShaderPassIndices::new(&default_pbr_material);
-        let default_pass = default_pbr_material
-            .get_material_pass_by_index(default_pbr_material_pass_indices.opaque as usize)
-            .unwrap();
-
-        let descriptor_set_layouts = &default_pass.get_raw().descriptor_set_layouts;
-        let mut descriptor_set_allocator = args
-            .graph_context
-            .resource_context()
-            .create_descriptor_set_allocator();
-        let descriptor_set = descriptor_set_allocator.create_descriptor_set(
-            &descriptor_set_layouts[mesh_adv_textured_frag::SSAO_TEXTURE_DESCRIPTOR_SET_INDEX],
-            mesh_adv_textured_frag::DescriptorSet1Args {
-                ssao_texture: &ssao_rt,
-            },
+        let descriptor_set = create_ssao_descriptor_set(
+            &args,
+            &default_pbr_material,
+            ssao_rt,
+            volumetric_fog_volume,
+            decal_buffers,
         )?;
-        descriptor_set_allocator.flush_changes()?;
 
         args.graph_context
             .render_resources()
@@ -145,11 +192,13 @@ pub(super) fn opaque_pass(
                     .write_view_phase::<OpaqueRenderPhase>(&main_view, &mut write_context)?;
             }
 
-            {
+            if draw_transparent {
                 profiling::scope!("Transparent Pass");
+                particle_pass::set_particle_depth_descriptor_set(&args, particle_depth.as_ref())?;
                 args.graph_context
                     .prepared_render_data()
                     .write_view_phase::<TransparentRenderPhase>(&main_view, &mut write_context)?;
+                particle_pass::set_particle_depth_descriptor_set(&args, None)?;
             }
         }
 
@@ -158,7 +207,7 @@ pub(super) fn opaque_pass(
             .fetch_mut::<MeshAdvRenderPipelineState>()
             .ssao_descriptor_set = None;
 
-        {
+        if draw_transparent {
             profiling::scope!("Wireframes Pass");
             args.graph_context
                 .prepared_render_data()
@@ -171,6 +220,213 @@ pub(super) fn opaque_pass(
     OpaquePass {
         node,
         color,
+        gbuffer,
         shadow_map_atlas,
     }
 }
+
+pub(super) struct TransparentPass {
+    #[allow(dead_code)]
+    pub(super) node: RenderGraphNodeId,
+    pub(super) color: RenderGraphImageUsageId,
+}
+
+// Draws sorted transparent meshes and wireframes over the opaque color. Only used when something is
+// composited onto the opaque color before them, otherwise they are drawn by the opaque pass.
+pub(super) fn transparent_pass(
+    context: &mut ModernPipelineContext,
+    color: RenderGraphImageUsageId,
+    depth_prepass: RenderGraphImageUsageId,
+    shadow_map_pass_output: &ShadowMapPassOutput,
+    light_build_lists_pass: &LightBuildListsPass,
+    reflection_probe_build_lists_pass: &LightBuildListsPass,
+    ssao_rt: Option<RenderGraphImageUsageId>,
+    volumetric_fog_volume: Option<RenderGraphImageUsageId>,
+    particle_depth: Option<ParticleDepth>,
+) -> TransparentPass {
+    let node = context
+        .graph
+        .add_renderpass_node("Transparent", RenderGraphQueue::DefaultGraphics);
+
+    let color = context.graph.modify_color_attachment(
+        node,
+        color,
+        0,
+        None,
+        RenderGraphImageConstraint {
+            samples: Some(context.graph_config.samples),
+            format: Some(context.graph_config.color_format),
+            ..Default::default()
+        },
+        Default::default(),
+    );
+    context.graph.set_image_name(color, "color");
+
+    context.graph.read_depth_attachment(
+        node,
+        depth_prepass,
+        RenderGraphImageConstraint {
+            samples: Some(context.graph_config.samples),
+            format: Some(context.graph_config.depth_format),
+            ..Default::default()
+        },
+        Default::default(),
+    );
+
+    let ssao_rt = ssao_rt.map(|x| {
+        context.graph.sample_image(
+            node,
+            x,
+            RenderGraphImageConstraint::default(),
+            RenderGraphImageViewOptions::default(),
+        )
+    });
+
+    let volumetric_fog_volume = volumetric_fog_volume.map(|x| {
+        context.graph.sample_image(
+            node,
+            x,
+            RenderGraphImageConstraint::default(),
+            RenderGraphImageViewOptions::default(),
+        )
+    });
+
+    let particle_depth =
+        particle_depth.map(|x| particle_pass::sample_particle_depth(context, node, x));
+
+    // These are buffers owned by MeshAdvLightBinRenderResource
+    context.graph.read_storage_buffer(
+        node,
+        light_build_lists_pass.light_lists_buffer,
+        Default::default(),
+    );
+    context.graph.read_storage_buffer(
+        node,
+        reflection_probe_build_lists_pass.light_lists_buffer,
+        Default::default(),
+    );
+
+    context.graph.sample_image(
+        node,
+        shadow_map_pass_output.shadow_atlas_image,
+        Default::default(),
+        Default::default(),
+    );
+
+    context
+        .graph
+        .add_render_phase_dependency::<TransparentRenderPhase>(node);
+
+    let main_view = context.main_view.clone();
+
+    let default_pbr_material = context
+        .render_resources
+        .fetch::<MeshAdvStaticResources>()
+        .default_pbr_material
+        .clone();
+    let default_pbr_material = context
+        .asset_manager
+        .committed_asset(&default_pbr_material)
+        .unwrap()
+        .clone();
+
+    context.graph.set_renderpass_callback(node, move |args| {
+        let mut write_context =
+            RenderJobCommandBufferContext::from_graph_visit_render_pass_args(&args);
+
+        // Decals are only applied to opaque meshes
+        let descriptor_set = create_ssao_descriptor_set(
+            &args,
+            &default_pbr_material,
+            ssao_rt,
+            volumetric_fog_volume,
+            None,
+        )?;
+
+        args.graph_context
+            .render_resources()
+            .fetch_mut::<MeshAdvRenderPipelineState>()
+            .ssao_descriptor_set = Some(descriptor_set);
+
+        {
+            profiling::scope!("Transparent Pass");
+            particle_pass::set_particle_depth_descriptor_set(&args, particle_depth.as_ref())?;
+            args.graph_context
+                .prepared_render_data()
+                .write_view_phase::<TransparentRenderPhase>(&main_view, &mut write_context)?;
+            particle_pass::set_particle_depth_descriptor_set(&args, None)?;
+        }
+
+        args.graph_context
+            .render_resources()
+            .fetch_mut::<MeshAdvRenderPipelineState>()
+            .ssao_descriptor_set = None;
+
+        {
+            profiling::scope!("Wireframes Pass");
+            args.graph_context
+                .prepared_render_data()
+                .write_view_phase::<WireframeRenderPhase>(&main_view, &mut write_context)?;
+        }
+
+        Ok(())
+    });
+
+    TransparentPass { node, color }
+}
+
+// The PBR shaders sample SSAO, volumetric fog and decals from a descriptor set that is bound by the
+// mesh feature. Any pass that draws mesh_adv surfaces must create it and put it in MeshAdvRenderPipelineState.
+pub(super) fn create_ssao_descriptor_set(
+    args: &VisitRenderpassNodeArgs,
+    default_pbr_material: &MaterialAsset,
+    ssao_rt: Option<RenderGraphImageUsageId>,
+    volumetric_fog_volume: Option<RenderGraphImageUsageId>,
+    decal_buffers: Option<DecalBuffers>,
+) -> RafxResult<DescriptorSetArc> {
+    let invalid_image = args
+        .graph_context
+        .render_resources()
+        .fetch::<InvalidResources>()
+        .invalid_image_color
+        .clone();
+
+    let ssao_rt = ssao_rt.map(|x| args.graph_context.image_view(x).unwrap());
+    let ssao_rt = ssao_rt.unwrap_or_else(|| invalid_image.clone());
+
+    let volumetric_fog_volume =
+        volumetric_fog_volume.map(|x| args.graph_context.image_view(x).unwrap());
+    let volumetric_fog_volume = volumetric_fog_volume.unwrap_or_else(|| invalid_image.clone());
+
+    let decal_buffer_view = |x: Option<RenderGraphImageUsageId>| {
+        x.map(|x| args.graph_context.image_view(x).unwrap())
+            .unwrap_or_else(|| invalid_image.clone())
+    };
+    let decal_albedo = decal_buffer_view(decal_buffers.map(|x| x.albedo));
+    let decal_normal = decal_buffer_view(decal_buffers.map(|x| x.normal));
+    let decal_roughness = decal_buffer_view(decal_buffers.map(|x| x.roughness));
+
+    let default_pbr_material_pass_indices = MeshAdvShaderPassIndices::new(default_pbr_material);
+    let default_pass = default_pbr_material
+        .get_material_pass_by_index(default_pbr_material_pass_indices.opaque as usize)
+        .unwrap();
+
+    let descriptor_set_layouts = &default_pass.get_raw().descriptor_set_layouts;
+    let mut descriptor_set_allocator = args
+        .graph_context
+        .resource_context()
+        .create_descriptor_set_allocator();
+    let descriptor_set = descriptor_set_allocator.create_descriptor_set(
+        &descriptor_set_layouts[mesh_adv_textured_frag::SSAO_TEXTURE_DESCRIPTOR_SET_INDEX],
+        mesh_adv_textured_frag::DescriptorSet1Args {
+            ssao_texture: &ssao_rt,
+            volumetric_fog_volume: &volumetric_fog_volume,
+            decal_albedo_texture: &decal_albedo,
+            decal_normal_texture: &decal_normal,
+            decal_roughness_texture: &decal_roughness,
+        },
+    )?;
+    descriptor_set_allocator.flush_changes()?;
+
+    Ok(descriptor_set)
+}
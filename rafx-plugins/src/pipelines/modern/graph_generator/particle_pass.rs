@@ -0,0 +1,438 @@
+use rafx::framework::{
+    BufferResource, ComputePipelineResource, DescriptorSetAllocator, DescriptorSetBindings,
+    MaterialPassResource, ResourceArc,
+};
+use rafx::graph::*;
+
+use super::ModernPipelineContext;
+use crate::features::particles::{
+    ParticleRenderPipelineState, ParticleSimulationRenderResource,
+    PARTICLE_INDIRECT_ARGS_DISPATCH_OFFSET, PARTICLE_THREAD_GROUP_SIZE,
+};
+use crate::shaders::particles::{
+    particle_indirect_args_comp, particle_init_comp, particle_simulate_comp, particle_spawn_comp,
+    particle_vert,
+};
+use rafx::api::{
+    RafxBarrierQueueTransition, RafxBufferBarrier, RafxCommandBuffer, RafxResourceState, RafxResult,
+};
+
+#[derive(Clone)]
+pub(super) struct ParticleComputePipelines {
+    pub(super) init: ResourceArc<ComputePipelineResource>,
+    pub(super) spawn: ResourceArc<ComputePipelineResource>,
+    pub(super) indirect_args: ResourceArc<ComputePipelineResource>,
+    pub(super) simulate: ResourceArc<ComputePipelineResource>,
+}
+
+pub(super) struct ParticleSimulationPass {
+    pub(super) node: RenderGraphNodeId,
+}
+
+// Soft particles read the depth prepass through the half resolution mip of the depth pyramid, since
+// the passes that draw particles have the depth prepass bound as their depth attachment
+#[derive(Clone)]
+pub(super) struct ParticleDepth {
+    pub(super) depth_pyramid_mip: RenderGraphImageUsageId,
+    // The particle or particle_oit pass, matching how the pipeline draws TransparentRenderPhase
+    pub(super) material_pass: ResourceArc<MaterialPassResource>,
+}
+
+fn cmd_particle_buffer_barriers(
+    command_buffer: &RafxCommandBuffer,
+    barriers: &[(
+        &ResourceArc<BufferResource>,
+        RafxResourceState,
+        RafxResourceState,
+    )],
+) -> RafxResult<()> {
+    let buffers: Vec<_> = barriers
+        .iter()
+        .map(|(buffer, _, _)| buffer.get_raw().buffer)
+        .collect();
+    let buffer_barriers: Vec<_> = barriers
+        .iter()
+        .zip(&buffers)
+        .map(|((_, src_state, dst_state), buffer)| RafxBufferBarrier {
+            buffer,
+            src_state: *src_state,
+            dst_state: *dst_state,
+            queue_transition: RafxBarrierQueueTransition::None,
+            offset_size: None,
+        })
+        .collect();
+
+    command_buffer.cmd_resource_barrier(&buffer_barriers, &[])
+}
+
+// Runs the spawn, simulate and compact steps of every emitter queued by the particle prepare job.
+// The emitter buffers are not managed by the render graph, so anything that draws particles must
+// explicitly depend on this node.
+pub(super) fn particle_simulation_pass(
+    context: &mut ModernPipelineContext,
+    particle_pipelines: &ParticleComputePipelines,
+) -> ParticleSimulationPass {
+    let node = context
+        .graph
+        .add_callback_node("ParticleSimulation", RenderGraphQueue::DefaultGraphics);
+
+    let particle_pipelines = particle_pipelines.clone();
+    context.graph.set_callback(node, move |args| {
+        let mut simulation = args
+            .graph_context
+            .render_resources()
+            .fetch_mut::<ParticleSimulationRenderResource>();
+
+        if simulation.jobs.is_empty() {
+            return Ok(());
+        }
+
+        let command_buffer = &args.command_buffer;
+        let mut descriptor_set_allocator = args
+            .graph_context
+            .resource_context()
+            .create_descriptor_set_allocator();
+
+        // Particles and alive lists are left readable by the particle vertex shader and the
+        // indirect arguments by the draw. Newly created buffers have no contents yet.
+        let mut barriers = Vec::default();
+        for job in &simulation.jobs {
+            let buffers = &job.buffers;
+            if job.init.is_some() {
+                for buffer in buffers.iter() {
+                    barriers.push((
+                        buffer,
+                        RafxResourceState::UNDEFINED,
+                        RafxResourceState::UNORDERED_ACCESS,
+                    ));
+                }
+            } else {
+                for buffer in [
+                    &buffers.particles,
+                    &buffers.alive_lists[0],
+                    &buffers.alive_lists[1],
+                ] {
+                    barriers.push((
+                        buffer,
+                        RafxResourceState::SHADER_RESOURCE,
+                        RafxResourceState::UNORDERED_ACCESS,
+                    ));
+                }
+                for buffer in [&buffers.dead_list, &buffers.state] {
+                    barriers.push((
+                        buffer,
+                        RafxResourceState::UNORDERED_ACCESS,
+                        RafxResourceState::UNORDERED_ACCESS,
+                    ));
+                }
+                barriers.push((
+                    &buffers.indirect_args,
+                    RafxResourceState::INDIRECT_ARGUMENT,
+                    RafxResourceState::UNORDERED_ACCESS,
+                ));
+            }
+        }
+        cmd_particle_buffer_barriers(command_buffer, &barriers)?;
+
+        // Each step reads what the previous step wrote to the particles, lists and counters
+        let simulation_barriers: Vec<_> = simulation
+            .jobs
+            .iter()
+            .flat_map(|job| {
+                [
+                    &job.buffers.particles,
+                    &job.buffers.alive_lists[0],
+                    &job.buffers.alive_lists[1],
+                    &job.buffers.dead_list,
+                    &job.buffers.state,
+                ]
+            })
+            .map(|buffer| {
+                (
+                    buffer,
+                    RafxResourceState::UNORDERED_ACCESS,
+                    RafxResourceState::UNORDERED_ACCESS,
+                )
+            })
+            .collect();
+
+        //
+        // Reset new emitters so that every particle is in the dead list
+        //
+        command_buffer.cmd_bind_pipeline(&particle_pipelines.init.get_raw().pipeline)?;
+        for job in &simulation.jobs {
+            let init = match &job.init {
+                Some(init) => init,
+                None => continue,
+            };
+
+            let mut descriptor_set = descriptor_set_allocator
+                .create_dyn_descriptor_set_uninitialized(
+                    &particle_pipelines.init.get_raw().descriptor_set_layouts[0],
+                )?;
+            descriptor_set.set_buffer_data(
+                particle_init_comp::CONFIG_DESCRIPTOR_BINDING_INDEX as u32,
+                init,
+            );
+            descriptor_set.set_buffer(
+                particle_init_comp::DEAD_LIST_DESCRIPTOR_BINDING_INDEX as u32,
+                &job.buffers.dead_list,
+            );
+            descriptor_set.set_buffer(
+                particle_init_comp::EMITTER_DESCRIPTOR_BINDING_INDEX as u32,
+                &job.buffers.state,
+            );
+            descriptor_set.flush(&mut descriptor_set_allocator)?;
+            descriptor_set_allocator.flush_changes()?;
+            descriptor_set.bind(command_buffer)?;
+
+            let group_count = rafx::base::memory::round_size_up_to_alignment_u32(
+                init.max_particles.max(1),
+                PARTICLE_THREAD_GROUP_SIZE,
+            ) / PARTICLE_THREAD_GROUP_SIZE;
+            command_buffer.cmd_dispatch(group_count, 1, 1)?;
+        }
+
+        cmd_particle_buffer_barriers(command_buffer, &simulation_barriers)?;
+
+        //
+        // Spawn new particles into the alive list that is about to be simulated
+        //
+        command_buffer.cmd_bind_pipeline(&particle_pipelines.spawn.get_raw().pipeline)?;
+        for job in &simulation.jobs {
+            if job.spawn.spawn_count == 0 {
+                continue;
+            }
+
+            let mut descriptor_set = descriptor_set_allocator
+                .create_dyn_descriptor_set_uninitialized(
+                    &particle_pipelines.spawn.get_raw().descriptor_set_layouts[0],
+                )?;
+            descriptor_set.set_buffer_data(
+                particle_spawn_comp::CONFIG_DESCRIPTOR_BINDING_INDEX as u32,
+                &job.spawn,
+            );
+            descriptor_set.set_buffer(
+                particle_spawn_comp::PARTICLES_DESCRIPTOR_BINDING_INDEX as u32,
+                &job.buffers.particles,
+            );
+            descriptor_set.set_buffer(
+                particle_spawn_comp::ALIVE_LIST_DESCRIPTOR_BINDING_INDEX as u32,
+                &job.buffers.alive_lists[job.alive_list_index],
+            );
+            descriptor_set.set_buffer(
+                particle_spawn_comp::DEAD_LIST_DESCRIPTOR_BINDING_INDEX as u32,
+                &job.buffers.dead_list,
+            );
+            descriptor_set.set_buffer(
+                particle_spawn_comp::EMITTER_DESCRIPTOR_BINDING_INDEX as u32,
+                &job.buffers.state,
+            );
+            descriptor_set.flush(&mut descriptor_set_allocator)?;
+            descriptor_set_allocator.flush_changes()?;
+            descriptor_set.bind(command_buffer)?;
+
+            let group_count = rafx::base::memory::round_size_up_to_alignment_u32(
+                job.spawn.spawn_count,
+                PARTICLE_THREAD_GROUP_SIZE,
+            ) / PARTICLE_THREAD_GROUP_SIZE;
+            command_buffer.cmd_dispatch(group_count, 1, 1)?;
+        }
+
+        cmd_particle_buffer_barriers(command_buffer, &simulation_barriers)?;
+
+        let cmd_indirect_args = |descriptor_set_allocator: &mut DescriptorSetAllocator,
+                                 draw_args: bool|
+         -> RafxResult<()> {
+            command_buffer
+                .cmd_bind_pipeline(&particle_pipelines.indirect_args.get_raw().pipeline)?;
+            for job in &simulation.jobs {
+                let mut descriptor_set = descriptor_set_allocator
+                    .create_dyn_descriptor_set_uninitialized(
+                        &particle_pipelines
+                            .indirect_args
+                            .get_raw()
+                            .descriptor_set_layouts[0],
+                    )?;
+                descriptor_set.set_buffer_data(
+                    particle_indirect_args_comp::CONFIG_DESCRIPTOR_BINDING_INDEX as u32,
+                    &particle_indirect_args_comp::ConfigUniform {
+                        draw_args: draw_args as u32,
+                        ..Default::default()
+                    },
+                );
+                descriptor_set.set_buffer(
+                    particle_indirect_args_comp::EMITTER_DESCRIPTOR_BINDING_INDEX as u32,
+                    &job.buffers.state,
+                );
+                descriptor_set.set_buffer(
+                    particle_indirect_args_comp::INDIRECT_ARGS_DESCRIPTOR_BINDING_INDEX as u32,
+                    &job.buffers.indirect_args,
+                );
+                descriptor_set.flush(descriptor_set_allocator)?;
+                descriptor_set_allocator.flush_changes()?;
+                descriptor_set.bind(command_buffer)?;
+
+                command_buffer.cmd_dispatch(1, 1, 1)?;
+            }
+
+            Ok(())
+        };
+
+        //
+        // Simulate the alive particles, dispatched with one thread per particle
+        //
+        cmd_indirect_args(&mut descriptor_set_allocator, false)?;
+
+        let mut barriers = simulation_barriers.clone();
+        for job in &simulation.jobs {
+            barriers.push((
+                &job.buffers.indirect_args,
+                RafxResourceState::UNORDERED_ACCESS,
+                RafxResourceState::INDIRECT_ARGUMENT,
+            ));
+        }
+        cmd_particle_buffer_barriers(command_buffer, &barriers)?;
+
+        command_buffer.cmd_bind_pipeline(&particle_pipelines.simulate.get_raw().pipeline)?;
+        for job in &simulation.jobs {
+            let mut descriptor_set = descriptor_set_allocator
+                .create_dyn_descriptor_set_uninitialized(
+                    &particle_pipelines.simulate.get_raw().descriptor_set_layouts[0],
+                )?;
+            descriptor_set.set_buffer_data(
+                particle_simulate_comp::CONFIG_DESCRIPTOR_BINDING_INDEX as u32,
+                &job.simulate,
+            );
+            descriptor_set.set_buffer(
+                particle_simulate_comp::PARTICLES_DESCRIPTOR_BINDING_INDEX as u32,
+                &job.buffers.particles,
+            );
+            descriptor_set.set_buffer(
+                particle_simulate_comp::ALIVE_LIST_IN_DESCRIPTOR_BINDING_INDEX as u32,
+                &job.buffers.alive_lists[job.alive_list_index],
+            );
+            descriptor_set.set_buffer(
+                particle_simulate_comp::ALIVE_LIST_OUT_DESCRIPTOR_BINDING_INDEX as u32,
+                &job.buffers.alive_lists[1 - job.alive_list_index],
+            );
+            descriptor_set.set_buffer(
+                particle_simulate_comp::DEAD_LIST_DESCRIPTOR_BINDING_INDEX as u32,
+                &job.buffers.dead_list,
+            );
+            descriptor_set.set_buffer(
+                particle_simulate_comp::EMITTER_DESCRIPTOR_BINDING_INDEX as u32,
+                &job.buffers.state,
+            );
+            descriptor_set.flush(&mut descriptor_set_allocator)?;
+            descriptor_set_allocator.flush_changes()?;
+            descriptor_set.bind(command_buffer)?;
+
+            command_buffer.cmd_dispatch_indirect(
+                &job.buffers.indirect_args.get_raw().buffer,
+                PARTICLE_INDIRECT_ARGS_DISPATCH_OFFSET,
+            )?;
+        }
+
+        let mut barriers = simulation_barriers.clone();
+        for job in &simulation.jobs {
+            barriers.push((
+                &job.buffers.indirect_args,
+                RafxResourceState::INDIRECT_ARGUMENT,
+                RafxResourceState::UNORDERED_ACCESS,
+            ));
+        }
+        cmd_particle_buffer_barriers(command_buffer, &barriers)?;
+
+        //
+        // Write the draw arguments for the survivors
+        //
+        cmd_indirect_args(&mut descriptor_set_allocator, true)?;
+
+        let mut barriers = Vec::default();
+        for job in &simulation.jobs {
+            let buffers = &job.buffers;
+            for buffer in [
+                &buffers.particles,
+                &buffers.alive_lists[0],
+                &buffers.alive_lists[1],
+            ] {
+                barriers.push((
+                    buffer,
+                    RafxResourceState::UNORDERED_ACCESS,
+                    RafxResourceState::SHADER_RESOURCE,
+                ));
+            }
+            barriers.push((
+                &buffers.indirect_args,
+                RafxResourceState::UNORDERED_ACCESS,
+                RafxResourceState::INDIRECT_ARGUMENT,
+            ));
+        }
+        cmd_particle_buffer_barriers(command_buffer, &barriers)?;
+
+        simulation.jobs.clear();
+
+        Ok(())
+    });
+
+    ParticleSimulationPass { node }
+}
+
+pub(super) fn sample_particle_depth(
+    context: &mut ModernPipelineContext,
+    node: RenderGraphNodeId,
+    particle_depth: ParticleDepth,
+) -> ParticleDepth {
+    ParticleDepth {
+        depth_pyramid_mip: context.graph.sample_image(
+            node,
+            particle_depth.depth_pyramid_mip,
+            Default::default(),
+            Default::default(),
+        ),
+        material_pass: particle_depth.material_pass,
+    }
+}
+
+// Sets the depth descriptor set used by the particle feature while a pass writes
+// TransparentRenderPhase. Called with None to clear it afterwards.
+pub(super) fn set_particle_depth_descriptor_set(
+    args: &VisitRenderpassNodeArgs,
+    particle_depth: Option<&ParticleDepth>,
+) -> RafxResult<()> {
+    let mut pipeline_state = match args
+        .graph_context
+        .render_resources()
+        .try_fetch_mut::<ParticleRenderPipelineState>()
+    {
+        Some(pipeline_state) => pipeline_state,
+        None => return Ok(()),
+    };
+
+    pipeline_state.depth_descriptor_set = if let Some(particle_depth) = particle_depth {
+        let depth_tex = args
+            .graph_context
+            .image_view(particle_depth.depth_pyramid_mip)
+            .unwrap();
+        let mut descriptor_set_allocator = args
+            .graph_context
+            .resource_context()
+            .create_descriptor_set_allocator();
+        let depth_descriptor_set = descriptor_set_allocator.create_descriptor_set(
+            &particle_depth
+                .material_pass
+                .get_raw()
+                .descriptor_set_layouts[particle_vert::DEPTH_TEXTURE_DESCRIPTOR_SET_INDEX],
+            particle_vert::DescriptorSet1Args {
+                depth_texture: &depth_tex,
+            },
+        )?;
+        descriptor_set_allocator.flush_changes()?;
+        Some(depth_descriptor_set)
+    } else {
+        None
+    };
+
+    Ok(())
+}
@@ -0,0 +1,397 @@
+use crate::phases::{DepthPrepassRenderPhase, OpaqueRenderPhase};
+use rafx::api::{
+    RafxColorClearValue, RafxDepthStencilClearValue, RafxExtents3D, RafxFormat, RafxResourceState,
+    RafxSampleCount, RafxTextureBarrier,
+};
+use rafx::framework::{
+    ComputePipelineResource, DescriptorSetBindings, ImageViewResource, ResourceArc,
+};
+use rafx::graph::*;
+use rafx::render_features::{RenderJobCommandBufferContext, RenderView};
+
+use super::opaque_pass::create_ssao_descriptor_set;
+use super::shadow_map_pass::ShadowMapPassOutput;
+use super::ModernPipelineContext;
+use crate::features::mesh_adv::{
+    MeshAdvReflectionProbeResource, MeshAdvRenderPipelineState, MeshAdvStaticResources,
+    REFLECTION_PROBE_FORMAT, REFLECTION_PROBE_MIP_COUNT, REFLECTION_PROBE_SIZE,
+};
+use crate::shaders::mesh_adv::reflection_probe_prefilter_comp;
+
+const REFLECTION_PROBE_SAMPLE_COUNT: u32 = 256;
+
+pub(super) struct ReflectionProbePass {
+    // Anything that samples the reflection probe cubemap array must explicitly depend on this node
+    pub(super) node: RenderGraphNodeId,
+    // Nodes that draw meshes into the captured faces
+    pub(super) capture_nodes: Vec<RenderGraphNodeId>,
+}
+
+fn reflection_probe_extents() -> Option<RenderGraphImageExtents> {
+    Some(RenderGraphImageExtents::Custom(RafxExtents3D {
+        width: REFLECTION_PROBE_SIZE,
+        height: REFLECTION_PROBE_SIZE,
+        depth: 1,
+    }))
+}
+
+// Renders one face of a probe capture. Depth and velocity come from a depth prepass, because the
+// opaque mesh passes only test against depth.
+fn capture_face_pass(
+    context: &mut ModernPipelineContext,
+    face_index: usize,
+    render_view: &RenderView,
+    shadow_map_pass_output: &ShadowMapPassOutput,
+) -> (
+    RenderGraphNodeId,
+    RenderGraphNodeId,
+    RenderGraphImageUsageId,
+) {
+    const DEPTH_PREPASS_NODE_NAMES: [&str; 6] = [
+        "ReflectionProbeDepthPrepass0",
+        "ReflectionProbeDepthPrepass1",
+        "ReflectionProbeDepthPrepass2",
+        "ReflectionProbeDepthPrepass3",
+        "ReflectionProbeDepthPrepass4",
+        "ReflectionProbeDepthPrepass5",
+    ];
+    const OPAQUE_NODE_NAMES: [&str; 6] = [
+        "ReflectionProbeOpaque0",
+        "ReflectionProbeOpaque1",
+        "ReflectionProbeOpaque2",
+        "ReflectionProbeOpaque3",
+        "ReflectionProbeOpaque4",
+        "ReflectionProbeOpaque5",
+    ];
+
+    //
+    // Depth prepass
+    //
+    let depth_prepass_node = context.graph.add_renderpass_node(
+        DEPTH_PREPASS_NODE_NAMES[face_index],
+        RenderGraphQueue::DefaultGraphics,
+    );
+
+    let depth = context.graph.create_depth_attachment(
+        depth_prepass_node,
+        Some(RafxDepthStencilClearValue {
+            depth: 0.0,
+            stencil: 0,
+        }),
+        RenderGraphImageConstraint {
+            samples: Some(RafxSampleCount::SampleCount1),
+            format: Some(context.graph_config.depth_format),
+            extents: reflection_probe_extents(),
+            ..Default::default()
+        },
+        Default::default(),
+    );
+    context
+        .graph
+        .set_image_name(depth, "reflection_probe_depth");
+
+    // The depth prepass material always writes velocity, it is discarded here
+    context.graph.create_color_attachment(
+        depth_prepass_node,
+        0,
+        Some(RafxColorClearValue([9999999.0, 9999999.0, 0.0, 0.0])),
+        RenderGraphImageConstraint {
+            samples: Some(RafxSampleCount::SampleCount1),
+            format: Some(RafxFormat::R32G32_SFLOAT),
+            extents: reflection_probe_extents(),
+            ..Default::default()
+        },
+        Default::default(),
+    );
+
+    context
+        .graph
+        .add_render_phase_dependency::<DepthPrepassRenderPhase>(depth_prepass_node);
+
+    let depth_prepass_view = render_view.clone();
+    context
+        .graph
+        .set_renderpass_callback(depth_prepass_node, move |args| {
+            profiling::scope!("Reflection Probe Depth Prepass");
+            let mut write_context =
+                RenderJobCommandBufferContext::from_graph_visit_render_pass_args(&args);
+            args.graph_context
+                .prepared_render_data()
+                .write_view_phase::<DepthPrepassRenderPhase>(
+                    &depth_prepass_view,
+                    &mut write_context,
+                )
+        });
+
+    //
+    // Opaque
+    //
+    let opaque_node = context.graph.add_renderpass_node(
+        OPAQUE_NODE_NAMES[face_index],
+        RenderGraphQueue::DefaultGraphics,
+    );
+
+    let color = context.graph.create_color_attachment(
+        opaque_node,
+        0,
+        Some(RafxColorClearValue([0.0, 0.0, 0.0, 0.0])),
+        RenderGraphImageConstraint {
+            samples: Some(RafxSampleCount::SampleCount1),
+            format: Some(REFLECTION_PROBE_FORMAT),
+            extents: reflection_probe_extents(),
+            ..Default::default()
+        },
+        Default::default(),
+    );
+    context
+        .graph
+        .set_image_name(color, "reflection_probe_color");
+
+    context.graph.read_depth_attachment(
+        opaque_node,
+        depth,
+        RenderGraphImageConstraint {
+            samples: Some(RafxSampleCount::SampleCount1),
+            format: Some(context.graph_config.depth_format),
+            extents: reflection_probe_extents(),
+            ..Default::default()
+        },
+        Default::default(),
+    );
+
+    context.graph.sample_image(
+        opaque_node,
+        shadow_map_pass_output.shadow_atlas_image,
+        Default::default(),
+        Default::default(),
+    );
+
+    context
+        .graph
+        .add_render_phase_dependency::<OpaqueRenderPhase>(opaque_node);
+
+    let default_pbr_material = context
+        .render_resources
+        .fetch::<MeshAdvStaticResources>()
+        .default_pbr_material
+        .clone();
+    let default_pbr_material = context
+        .asset_manager
+        .committed_asset(&default_pbr_material)
+        .unwrap()
+        .clone();
+
+    let opaque_view = render_view.clone();
+    context
+        .graph
+        .set_renderpass_callback(opaque_node, move |args| {
+            profiling::scope!("Reflection Probe Opaque");
+            let mut write_context =
+                RenderJobCommandBufferContext::from_graph_visit_render_pass_args(&args);
+
+            // Captures are not affected by SSAO or decals
+            let descriptor_set =
+                create_ssao_descriptor_set(&args, &default_pbr_material, None, None, None)?;
+
+            args.graph_context
+                .render_resources()
+                .fetch_mut::<MeshAdvRenderPipelineState>()
+                .ssao_descriptor_set = Some(descriptor_set);
+
+            args.graph_context
+                .prepared_render_data()
+                .write_view_phase::<OpaqueRenderPhase>(&opaque_view, &mut write_context)?;
+
+            args.graph_context
+                .render_resources()
+                .fetch_mut::<MeshAdvRenderPipelineState>()
+                .ssao_descriptor_set = None;
+
+            Ok(())
+        });
+
+    (depth_prepass_node, opaque_node, color)
+}
+
+// Renders the six faces of the probe being captured this frame (if any) and prefilters them into
+// the probe's slot of the cubemap array. The cubemap array is not managed by the render graph, so
+// this also transitions it out of its initial undefined state the first time it runs. Returns None
+// if there is nothing to do this frame.
+pub(super) fn reflection_probe_pass(
+    context: &mut ModernPipelineContext,
+    reflection_probe_prefilter_pipeline: &ResourceArc<ComputePipelineResource>,
+    shadow_map_pass_output: &ShadowMapPassOutput,
+) -> Option<ReflectionProbePass> {
+    let mut reflection_probe_resource = context
+        .render_resources
+        .fetch_mut::<MeshAdvReflectionProbeResource>();
+
+    let requires_initial_transition = reflection_probe_resource.take_requires_initial_transition();
+    let capture = reflection_probe_resource.capture().cloned();
+    let cubemap_array = reflection_probe_resource.cubemap_array().clone();
+    let cubemap_array_mip_uavs = reflection_probe_resource.cubemap_array_mip_uavs().to_vec();
+    drop(reflection_probe_resource);
+
+    let mut capture_nodes = Vec::default();
+
+    //
+    // The capture passes sample the cubemap array (even though no probes are bound while capturing),
+    // so it has to be in a valid state before anything draws lit meshes
+    //
+    let initialize_node = if requires_initial_transition {
+        let node = context.graph.add_callback_node(
+            "ReflectionProbeInitialize",
+            RenderGraphQueue::DefaultGraphics,
+        );
+
+        let cubemap_array = cubemap_array.clone();
+        context.graph.set_callback(node, move |args| {
+            args.command_buffer.cmd_resource_barrier(
+                &[],
+                &[RafxTextureBarrier::state_transition(
+                    &cubemap_array.get_raw().image.get_raw().image,
+                    RafxResourceState::UNDEFINED,
+                    RafxResourceState::SHADER_RESOURCE,
+                )],
+            )
+        });
+
+        Some(node)
+    } else {
+        None
+    };
+
+    let capture = if let Some(capture) = capture {
+        capture
+    } else {
+        return initialize_node.map(|node| ReflectionProbePass {
+            node,
+            capture_nodes,
+        });
+    };
+
+    //
+    // Capture each face
+    //
+    let mut captured_faces = Vec::with_capacity(6);
+    for (face_index, render_view) in capture.render_views.iter().enumerate() {
+        let (depth_prepass_node, opaque_node, color) =
+            capture_face_pass(context, face_index, render_view, shadow_map_pass_output);
+
+        if let Some(initialize_node) = initialize_node {
+            context
+                .graph
+                .add_explicit_dependency(initialize_node, opaque_node);
+        }
+
+        capture_nodes.push(depth_prepass_node);
+        capture_nodes.push(opaque_node);
+        captured_faces.push(color);
+    }
+
+    //
+    // Prefilter the captured faces into the probe's slot, roughness increases linearly with each mip
+    //
+    let node = context.graph.add_callback_node(
+        "ReflectionProbePrefilter",
+        RenderGraphQueue::DefaultGraphics,
+    );
+
+    let captured_faces: Vec<_> = captured_faces
+        .into_iter()
+        .map(|color| {
+            context.graph.sample_image(
+                node,
+                color,
+                Default::default(),
+                RenderGraphImageViewOptions::default(),
+            )
+        })
+        .collect();
+
+    if let Some(initialize_node) = initialize_node {
+        context.graph.add_explicit_dependency(initialize_node, node);
+    }
+
+    let reflection_probe_prefilter_pipeline = reflection_probe_prefilter_pipeline.clone();
+    let dst_first_layer = (capture.slot * 6) as u32;
+    context.graph.set_callback(node, move |args| {
+        let command_buffer = &args.command_buffer;
+        let cubemap_array_image = &cubemap_array.get_raw().image.get_raw().image;
+
+        // Only this probe's layers are written, the rest of the array keeps its contents
+        command_buffer.cmd_resource_barrier(
+            &[],
+            &[RafxTextureBarrier::state_transition(
+                cubemap_array_image,
+                RafxResourceState::SHADER_RESOURCE,
+                RafxResourceState::UNORDERED_ACCESS,
+            )],
+        )?;
+
+        let captured_faces: Vec<ResourceArc<ImageViewResource>> = captured_faces
+            .iter()
+            .map(|&face| args.graph_context.image_view(face).unwrap())
+            .collect();
+
+        let mut descriptor_set_allocator = args
+            .graph_context
+            .resource_context()
+            .create_descriptor_set_allocator();
+
+        command_buffer
+            .cmd_bind_pipeline(&reflection_probe_prefilter_pipeline.get_raw().pipeline)?;
+        for (mip, mip_uav) in cubemap_array_mip_uavs.iter().enumerate() {
+            let face_size = (REFLECTION_PROBE_SIZE >> mip).max(1);
+            let config = reflection_probe_prefilter_comp::ReflectionProbePrefilterConfigUniform {
+                roughness: mip as f32 / (REFLECTION_PROBE_MIP_COUNT - 1) as f32,
+                sample_count: REFLECTION_PROBE_SAMPLE_COUNT,
+                face_size,
+                dst_first_layer,
+            };
+
+            let mut descriptor_set = descriptor_set_allocator
+                .create_dyn_descriptor_set_uninitialized(
+                    &reflection_probe_prefilter_pipeline
+                        .get_raw()
+                        .descriptor_set_layouts[0],
+                )?;
+            descriptor_set.set_buffer_data(
+                reflection_probe_prefilter_comp::CONFIG_DESCRIPTOR_BINDING_INDEX as u32,
+                &config,
+            );
+            for (face_index, face) in captured_faces.iter().enumerate() {
+                descriptor_set.set_image_at_index(
+                    reflection_probe_prefilter_comp::SRC_FACES_DESCRIPTOR_BINDING_INDEX as u32,
+                    face_index,
+                    face,
+                );
+            }
+            descriptor_set.set_image(
+                reflection_probe_prefilter_comp::DST_CUBEMAPS_DESCRIPTOR_BINDING_INDEX as u32,
+                mip_uav,
+            );
+            descriptor_set.flush(&mut descriptor_set_allocator)?;
+            descriptor_set_allocator.flush_changes()?;
+
+            descriptor_set.bind(command_buffer)?;
+            command_buffer.cmd_dispatch(face_size.div_ceil(8), face_size.div_ceil(8), 6)?;
+        }
+
+        command_buffer.cmd_resource_barrier(
+            &[],
+            &[RafxTextureBarrier::state_transition(
+                cubemap_array_image,
+                RafxResourceState::UNORDERED_ACCESS,
+                RafxResourceState::SHADER_RESOURCE,
+            )],
+        )?;
+
+        Ok(())
+    });
+
+    Some(ReflectionProbePass {
+        node,
+        capture_nodes,
+    })
+}
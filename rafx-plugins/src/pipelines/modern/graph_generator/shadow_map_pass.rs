@@ -5,7 +5,7 @@ use crate::features::mesh_adv::{
 };
 use crate::phases::ShadowMapRenderPhase;
 use rafx::api::{
-    RafxBufferDef, RafxDepthStencilClearValue, RafxMemoryUsage, RafxResourceType,
+    RafxBufferDef, RafxDepthStencilClearValue, RafxExtents2D, RafxMemoryUsage, RafxResourceType,
     RafxVertexBufferBinding,
 };
 use rafx::framework::render_features::RenderPhase;
@@ -13,12 +13,15 @@ use rafx::graph::*;
 use rafx::render_features::{RenderJobCommandBufferContext, RenderView};
 
 pub(super) struct ShadowMapPassOutput {
+    // None if no shadow maps need to be redrawn this frame
+    pub(super) node: Option<RenderGraphNodeId>,
     pub(super) shadow_atlas_image: RenderGraphImageUsageId,
 }
 
 pub(super) fn shadow_map_passes(
     context: &mut ModernPipelineContext,
     shadow_atlas_image: RenderGraphExternalImageId,
+    shadow_atlas_extents: RafxExtents2D,
     shadow_atlas_needs_full_clear: bool,
 ) -> ShadowMapPassOutput {
     let shadow_map_resource = context.render_resources.fetch::<MeshAdvShadowMapResource>();
@@ -34,6 +37,7 @@ pub(super) fn shadow_map_passes(
         //    .add_render_graph_image(shadow_atlas_usage);
 
         return ShadowMapPassOutput {
+            node: None,
             shadow_atlas_image: shadow_atlas_usage,
         };
     }
@@ -189,7 +193,40 @@ pub(super) fn shadow_map_passes(
         let mut write_context =
             RenderJobCommandBufferContext::from_graph_visit_render_pass_args(&args);
 
+        let shadow_map_resource = args
+            .graph_context
+            .render_resources()
+            .fetch::<MeshAdvShadowMapResource>();
+
         for render_view in &render_views_to_draw {
+            // The shadow vertex shaders use clip distances to keep geometry inside the view's tile. WGSL
+            // doesn't support them, so also scissor to the tile.
+            let (x, y, width, height) = if let Some(info) =
+                shadow_map_resource.shadow_map_atlas_element_info_for_view(render_view.view_index())
+            {
+                // These are UV coordinates so Y is positive going down, same as the scissor
+                let atlas_size = glam::Vec2::new(
+                    shadow_atlas_extents.width as f32,
+                    shadow_atlas_extents.height as f32,
+                );
+                let min = (info.uv_min * atlas_size).floor();
+                let max = (info.uv_max * atlas_size).ceil();
+                (
+                    min.x as u32,
+                    min.y as u32,
+                    (max.x - min.x) as u32,
+                    (max.y - min.y) as u32,
+                )
+            } else {
+                (
+                    0,
+                    0,
+                    shadow_atlas_extents.width,
+                    shadow_atlas_extents.height,
+                )
+            };
+            args.command_buffer.cmd_set_scissor(x, y, width, height)?;
+
             args.graph_context
                 .prepared_render_data()
                 .write_view_phase::<ShadowMapRenderPhase>(render_view, &mut write_context)?;
@@ -204,6 +241,7 @@ pub(super) fn shadow_map_passes(
     //    .add_render_graph_image(shadow_atlas_usage);
 
     ShadowMapPassOutput {
+        node: Some(node),
         shadow_atlas_image: shadow_atlas_usage,
     }
 }
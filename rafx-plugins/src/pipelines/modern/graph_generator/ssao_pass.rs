@@ -79,6 +79,7 @@ pub(super) fn ssao_pass(
         None,
         RenderGraphImageConstraint {
             format: Some(RafxFormat::R16G16B16A16_SFLOAT),
+            extents: Some(context.graph_config.render_extents),
             ..Default::default()
         },
         Default::default(),
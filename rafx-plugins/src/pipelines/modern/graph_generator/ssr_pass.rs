@@ -0,0 +1,319 @@
+use rafx::framework::{
+    ComputePipelineResource, DescriptorSetBindings, MaterialPassResource, ResourceArc,
+};
+use rafx::graph::*;
+
+use super::ModernPipelineContext;
+use super::EMPTY_VERTEX_LAYOUT;
+use crate::phases::PostProcessRenderPhase;
+use crate::pipelines::modern::graph_generator::depth_pyramid::{
+    DepthPyramidPass, MAX_DEPTH_PYRAMID_MIP_LAYERS,
+};
+use crate::pipelines::modern::ScreenSpaceReflectionOptions;
+use crate::shaders::post_adv::{ssr_comp, ssr_composite_frag};
+use rafx::api::{RafxExtents2D, RafxFormat, RafxSampleCount};
+use rafx::render_features::RenderPhase;
+use rafx::renderer::{InvalidResources, MainViewRenderResource};
+
+pub(super) struct SsrPass {
+    #[allow(dead_code)]
+    pub(super) node: RenderGraphNodeId,
+    pub(super) ssr_rt: RenderGraphImageUsageId,
+}
+
+// Traces reflections for every pixel of the G-buffer and accumulates them with the previous frame.
+// The result is also copied into ssr_history_rt for the next frame.
+pub(super) fn ssr_pass(
+    context: &mut ModernPipelineContext,
+    ssr_pipeline: &ResourceArc<ComputePipelineResource>,
+    ssr_options: &ScreenSpaceReflectionOptions,
+    depth_pyramid_pass: &DepthPyramidPass,
+    gbuffer: RenderGraphImageUsageId,
+    color_rt: RenderGraphImageUsageId,
+    velocity_rt: RenderGraphImageUsageId,
+    ssr_history_rt_external_image_id: RenderGraphExternalImageId,
+    ssr_history_rt_has_data: bool,
+    render_extents: RafxExtents2D,
+) -> SsrPass {
+    let invalid_image = context
+        .render_resources
+        .fetch::<InvalidResources>()
+        .invalid_image_color
+        .clone();
+
+    let node = context
+        .graph
+        .add_callback_node("Ssr", RenderGraphQueue::DefaultGraphics);
+
+    let depth_pyramid_mips: Vec<_> = depth_pyramid_pass
+        .depth_pyramid_mips
+        .iter()
+        .map(|x| {
+            context
+                .graph
+                .sample_image(node, *x, Default::default(), Default::default())
+        })
+        .collect();
+
+    let gbuffer = context
+        .graph
+        .sample_image(node, gbuffer, Default::default(), Default::default());
+
+    let color_rt =
+        context
+            .graph
+            .sample_image(node, color_rt, Default::default(), Default::default());
+
+    let velocity_rt =
+        context
+            .graph
+            .sample_image(node, velocity_rt, Default::default(), Default::default());
+
+    let ssr_history_rt = context
+        .graph
+        .read_external_image(ssr_history_rt_external_image_id);
+    let ssr_history_rt =
+        context
+            .graph
+            .sample_image(node, ssr_history_rt, Default::default(), Default::default());
+
+    let ssr_rt = context.graph.create_storage_image(
+        node,
+        RenderGraphImageConstraint {
+            samples: Some(RafxSampleCount::SampleCount1),
+            format: Some(RafxFormat::R16G16B16A16_SFLOAT),
+            extents: Some(context.graph_config.render_extents),
+            ..Default::default()
+        },
+        Default::default(),
+    );
+    context.graph.set_image_name(ssr_rt, "ssr");
+
+    let ssr_pipeline = ssr_pipeline.clone();
+    let ssr_options = ssr_options.clone();
+    context.graph.set_callback(node, move |args| {
+        let mut descriptor_set_allocator = args
+            .graph_context
+            .resource_context()
+            .create_descriptor_set_allocator();
+        let mut descriptor_set = descriptor_set_allocator.create_dyn_descriptor_set_uninitialized(
+            &ssr_pipeline.get_raw().descriptor_set_layouts[0],
+        )?;
+
+        let main_view_resource = args
+            .graph_context
+            .render_resources()
+            .fetch::<MainViewRenderResource>();
+        let main_view = main_view_resource.main_view.clone().unwrap();
+        let current_view_proj_inv = main_view.view_proj().inverse();
+        let previous_view_proj =
+            if let Some(previous_main_view_info) = &main_view_resource.previous_main_view_info {
+                previous_main_view_info.projection_matrix * previous_main_view_info.view_matrix
+            } else {
+                main_view.view_proj()
+            };
+
+        descriptor_set.set_buffer_data(
+            ssr_comp::CONFIG_DESCRIPTOR_BINDING_INDEX as u32,
+            &ssr_comp::SsrConfigUniform {
+                proj_matrix: main_view.projection_matrix().to_cols_array_2d(),
+                inverse_proj_matrix: main_view.projection_matrix().inverse().to_cols_array_2d(),
+                current_view_proj_inv: current_view_proj_inv.to_cols_array_2d(),
+                previous_view_proj: previous_view_proj.to_cols_array_2d(),
+                viewport_width: render_extents.width,
+                viewport_height: render_extents.height,
+                depth_mip_slice_count: depth_pyramid_mips.len() as u32,
+                max_steps: ssr_options.max_steps,
+                z_near: main_view.depth_range().near,
+                max_distance: ssr_options.max_distance,
+                thickness: ssr_options.thickness,
+                max_roughness: ssr_options.max_roughness,
+                edge_fade: ssr_options.edge_fade,
+                current_frame_weight: ssr_options.current_frame_weight,
+                has_history_data: ssr_history_rt_has_data as u32,
+                ..Default::default()
+            },
+        );
+
+        for (index, &depth_image) in depth_pyramid_mips.iter().enumerate() {
+            let depth_image = args.graph_context.image_view(depth_image).unwrap();
+            descriptor_set.set_image_at_index(
+                ssr_comp::DEPTH_MIP_SLICES_DESCRIPTOR_BINDING_INDEX as u32,
+                index,
+                &depth_image,
+            );
+        }
+
+        for i in depth_pyramid_mips.len()..(MAX_DEPTH_PYRAMID_MIP_LAYERS as usize) {
+            descriptor_set.set_image_at_index(
+                ssr_comp::DEPTH_MIP_SLICES_DESCRIPTOR_BINDING_INDEX as u32,
+                i,
+                &invalid_image,
+            );
+        }
+
+        let gbuffer = args.graph_context.image_view(gbuffer).unwrap();
+        let color_rt = args.graph_context.image_view(color_rt).unwrap();
+        let velocity_rt = args.graph_context.image_view(velocity_rt).unwrap();
+        let ssr_history_rt = args.graph_context.image_view(ssr_history_rt).unwrap();
+        let ssr_rt = args.graph_context.image_view(ssr_rt).unwrap();
+
+        descriptor_set.set_image(
+            ssr_comp::GBUFFER_TEX_DESCRIPTOR_BINDING_INDEX as u32,
+            &gbuffer,
+        );
+        descriptor_set.set_image(
+            ssr_comp::COLOR_TEX_DESCRIPTOR_BINDING_INDEX as u32,
+            &color_rt,
+        );
+        descriptor_set.set_image(
+            ssr_comp::VELOCITY_TEX_DESCRIPTOR_BINDING_INDEX as u32,
+            &velocity_rt,
+        );
+        descriptor_set.set_image(
+            ssr_comp::HISTORY_TEX_DESCRIPTOR_BINDING_INDEX as u32,
+            &ssr_history_rt,
+        );
+        descriptor_set.set_image(
+            ssr_comp::OUTPUT_TEX_DESCRIPTOR_BINDING_INDEX as u32,
+            &ssr_rt,
+        );
+        descriptor_set.flush(&mut descriptor_set_allocator)?;
+        descriptor_set_allocator.flush_changes()?;
+
+        // Draw calls
+        let command_buffer = &args.command_buffer;
+
+        command_buffer.cmd_bind_pipeline(&*ssr_pipeline.get_raw().pipeline)?;
+        descriptor_set.bind(command_buffer)?;
+        command_buffer.cmd_dispatch(
+            (render_extents.width + 15) / 16,
+            (render_extents.height + 15) / 16,
+            1,
+        )?;
+
+        Ok(())
+    });
+
+    let ssr_history_rt = context.graph.copy_image_to_image(
+        "copy ssr to history",
+        RenderGraphQueue::DefaultGraphics,
+        ssr_rt,
+        Some(ssr_history_rt),
+        None,
+    );
+
+    context
+        .graph
+        .write_external_image(ssr_history_rt_external_image_id, ssr_history_rt);
+
+    SsrPass { node, ssr_rt }
+}
+
+pub(super) struct SsrCompositePass {
+    #[allow(dead_code)]
+    pub(super) node: RenderGraphNodeId,
+    pub(super) color: RenderGraphImageUsageId,
+}
+
+pub(super) fn ssr_composite_pass(
+    context: &mut ModernPipelineContext,
+    ssr_composite_material_pass: ResourceArc<MaterialPassResource>,
+    ssr_options: &ScreenSpaceReflectionOptions,
+    opaque_color: RenderGraphImageUsageId,
+    depth_prepass: RenderGraphImageUsageId,
+    gbuffer: RenderGraphImageUsageId,
+    ssr_pass: &SsrPass,
+) -> SsrCompositePass {
+    let node = context
+        .graph
+        .add_renderpass_node("SsrComposite", RenderGraphQueue::DefaultGraphics);
+
+    let color = context.graph.modify_color_attachment(
+        node,
+        opaque_color,
+        0,
+        None,
+        RenderGraphImageConstraint {
+            samples: Some(context.graph_config.samples),
+            format: Some(context.graph_config.color_format),
+            ..Default::default()
+        },
+        Default::default(),
+    );
+    context.graph.set_image_name(color, "color");
+
+    let ssr_rt = context.graph.sample_image(
+        node,
+        ssr_pass.ssr_rt,
+        Default::default(),
+        Default::default(),
+    );
+
+    let gbuffer = context
+        .graph
+        .sample_image(node, gbuffer, Default::default(), Default::default());
+
+    let depth =
+        context
+            .graph
+            .sample_image(node, depth_prepass, Default::default(), Default::default());
+
+    let max_roughness = ssr_options.max_roughness;
+
+    context.graph.set_renderpass_callback(node, move |args| {
+        let ssr_rt = args.graph_context.image_view(ssr_rt).unwrap();
+        let gbuffer = args.graph_context.image_view(gbuffer).unwrap();
+        let depth = args.graph_context.image_view(depth).unwrap();
+
+        let main_view = args
+            .graph_context
+            .render_resources()
+            .fetch::<MainViewRenderResource>()
+            .main_view
+            .clone()
+            .unwrap();
+
+        let pipeline = args
+            .graph_context
+            .resource_context()
+            .graphics_pipeline_cache()
+            .get_or_create_graphics_pipeline(
+                Some(PostProcessRenderPhase::render_phase_index()),
+                &ssr_composite_material_pass,
+                &args.render_target_meta,
+                &EMPTY_VERTEX_LAYOUT,
+            )?;
+
+        let mut descriptor_set_allocator = args
+            .graph_context
+            .resource_context()
+            .create_descriptor_set_allocator();
+
+        let descriptor_set_layouts = &pipeline.get_raw().descriptor_set_layouts;
+        let ssr_composite_material_dyn_set = descriptor_set_allocator.create_descriptor_set(
+            &descriptor_set_layouts[ssr_composite_frag::SSR_TEX_DESCRIPTOR_SET_INDEX],
+            ssr_composite_frag::DescriptorSet0Args {
+                ssr_tex: &ssr_rt,
+                gbuffer_tex: &gbuffer,
+                depth_tex: &depth,
+                config: &ssr_composite_frag::SsrCompositeConfigUniform {
+                    inverse_proj_matrix: main_view.projection_matrix().inverse().to_cols_array_2d(),
+                    max_roughness,
+                    ..Default::default()
+                },
+            },
+        )?;
+
+        descriptor_set_allocator.flush_changes()?;
+
+        let command_buffer = &args.command_buffer;
+        command_buffer.cmd_bind_pipeline(&pipeline.get_raw().pipeline)?;
+        ssr_composite_material_dyn_set.bind(command_buffer)?;
+        command_buffer.cmd_draw(3, 0)?;
+
+        Ok(())
+    });
+
+    SsrCompositePass { node, color }
+}
@@ -1,6 +1,7 @@
 use crate::pipelines::modern::graph_generator::ModernPipelineContext;
 use crate::pipelines::modern::TemporalAAOptions;
 use crate::shaders::post_adv::taa_frag;
+use rafx::api::RafxExtents2D;
 use rafx::framework::{MaterialPassResource, ResourceArc};
 use rafx::graph::{
     RenderGraphExternalImageId, RenderGraphImageConstraint, RenderGraphImageUsageId,
@@ -21,6 +22,7 @@ pub(super) fn taa_pass(
     velocity_rt: RenderGraphImageUsageId,
     taa_history_rt_external_image_id: RenderGraphExternalImageId,
     taa_history_rt_has_data: bool,
+    output_extents: RafxExtents2D,
 ) -> TaaPass {
     let node = context
         .graph
@@ -57,6 +59,8 @@ pub(super) fn taa_pass(
         Default::default(),
     );
 
+    // The inputs may be rendered at a lower resolution than the output, in which case this pass
+    // also upscales them. The output and history are always the size of the swapchain.
     let color_rt = context.graph.create_color_attachment(
         node,
         0,
@@ -124,8 +128,8 @@ pub(super) fn taa_pass(
                         .history_weight_velocity_adjust_multiplier,
                     history_weight_velocity_adjust_max: taa_options
                         .history_weight_velocity_adjust_max,
-                    viewport_width: main_view.extents_width(),
-                    viewport_height: main_view.extents_height(),
+                    viewport_width: output_extents.width,
+                    viewport_height: output_extents.height,
                     ..Default::default()
                 },
             },
@@ -0,0 +1,251 @@
+use rafx::framework::{ComputePipelineResource, DescriptorSetBindings, ResourceArc};
+use rafx::graph::*;
+
+use super::shadow_map_pass::ShadowMapPassOutput;
+use super::ModernPipelineContext;
+use crate::features::mesh_adv::light_binning::MeshAdvLightBinRenderResource;
+use crate::pipelines::modern::graph_generator::light_binning::LightBuildListsPass;
+use crate::pipelines::modern::VolumetricFogOptions;
+use crate::shaders::mesh_adv::{volumetric_fog_inject_comp, volumetric_fog_integrate_comp};
+use rafx::api::{RafxExtents3D, RafxFormat, RafxResourceState, RafxSampleCount};
+
+// Must match the constants in volumetric_fog.glsl
+const VOLUMETRIC_FOG_WIDTH: u32 = 128;
+const VOLUMETRIC_FOG_HEIGHT: u32 = 64;
+const VOLUMETRIC_FOG_DEPTH: u32 = 64;
+const VOLUMETRIC_FOG_TILES_X: u32 = 8;
+
+pub(super) struct VolumetricFogPass {
+    #[allow(dead_code)]
+    pub(super) node: RenderGraphNodeId,
+    pub(super) volume: RenderGraphImageUsageId,
+}
+
+fn volume_constraint() -> RenderGraphImageConstraint {
+    RenderGraphImageConstraint {
+        samples: Some(RafxSampleCount::SampleCount1),
+        format: Some(RafxFormat::R16G16B16A16_SFLOAT),
+        extents: Some(RenderGraphImageExtents::Custom(RafxExtents3D {
+            width: VOLUMETRIC_FOG_WIDTH * VOLUMETRIC_FOG_TILES_X,
+            height: VOLUMETRIC_FOG_HEIGHT * VOLUMETRIC_FOG_DEPTH / VOLUMETRIC_FOG_TILES_X,
+            depth: 1,
+        })),
+        ..Default::default()
+    }
+}
+
+// Injects scattered light from the binned lights into a froxel volume covering the main view, then
+// integrates it along the view rays. The opaque and transparent passes sample the result to apply fog.
+pub(super) fn volumetric_fog_pass(
+    context: &mut ModernPipelineContext,
+    inject_pipeline: &ResourceArc<ComputePipelineResource>,
+    integrate_pipeline: &ResourceArc<ComputePipelineResource>,
+    options: &VolumetricFogOptions,
+    shadow_map_pass_output: &ShadowMapPassOutput,
+    light_build_lists_pass: &LightBuildListsPass,
+) -> VolumetricFogPass {
+    let light_bin_render_resource = context
+        .render_resources
+        .fetch::<MeshAdvLightBinRenderResource>();
+
+    let cluster_config = light_bin_render_resource
+        .aabb_structure()
+        .as_ref()
+        .unwrap()
+        .config
+        .clone();
+
+    let per_view_data_buffer = light_bin_render_resource
+        .main_view_per_view_data_gpu_buffer(context.main_view.frame_index())
+        .clone();
+
+    // DX12TODO: Hack to handle different resource types for dx12/other backends. Need to find a
+    // better solution than this later.
+    let initial_state = if context.asset_manager.device_context().is_dx12() {
+        RafxResourceState::GENERIC_READ
+    } else {
+        RafxResourceState::SHADER_RESOURCE
+    };
+
+    let all_lights_buffer = light_bin_render_resource
+        .main_view_all_lights_gpu_buffer(context.main_view.frame_index())
+        .clone();
+    let all_lights_buffer = context.graph.add_external_buffer(
+        all_lights_buffer,
+        initial_state,
+        RafxResourceState::SHADER_RESOURCE,
+    );
+    let all_lights_buffer = context.graph.read_external_buffer(all_lights_buffer);
+
+    //
+    // Inject
+    //
+    let inject_node = context
+        .graph
+        .add_callback_node("VolumetricFogInject", RenderGraphQueue::DefaultGraphics);
+
+    let shadow_atlas = context.graph.sample_image(
+        inject_node,
+        shadow_map_pass_output.shadow_atlas_image,
+        Default::default(),
+        Default::default(),
+    );
+    let light_lists_buffer = context.graph.read_storage_buffer(
+        inject_node,
+        light_build_lists_pass.light_lists_buffer,
+        Default::default(),
+    );
+    let all_lights_buffer =
+        context
+            .graph
+            .read_storage_buffer(inject_node, all_lights_buffer, Default::default());
+
+    let scattering =
+        context
+            .graph
+            .create_storage_image(inject_node, volume_constraint(), Default::default());
+    context
+        .graph
+        .set_image_name(scattering, "volumetric_fog_scattering");
+
+    let inverse_projection = context.main_view.projection_matrix().inverse();
+    let inject_config = volumetric_fog_inject_comp::VolumetricFogInjectConfigUniform {
+        inverse_projection: inverse_projection.to_cols_array_2d(),
+        inverse_view: context.main_view.view_matrix().inverse().to_cols_array_2d(),
+        albedo: options.albedo.extend(1.0).into(),
+        density: options.density,
+        height_falloff: options.height_falloff,
+        base_height: options.base_height,
+        anisotropy: options.anisotropy,
+        max_distance: options.max_distance,
+        cluster_near_z: cluster_config.near_z,
+        cluster_far_z: cluster_config.far_z,
+        cluster_x_bins: cluster_config.x_bins,
+        cluster_y_bins: cluster_config.y_bins,
+        cluster_z_bins: cluster_config.z_bins,
+        ..Default::default()
+    };
+
+    let inject_pipeline = inject_pipeline.clone();
+    context.graph.set_callback(inject_node, move |args| {
+        let shadow_atlas = args.graph_context.image_view(shadow_atlas).unwrap();
+        let light_lists_buffer = args.graph_context.buffer(light_lists_buffer).unwrap();
+        let all_lights_buffer = args.graph_context.buffer(all_lights_buffer).unwrap();
+        let scattering = args.graph_context.image_view(scattering).unwrap();
+
+        let mut descriptor_set_allocator = args
+            .graph_context
+            .resource_context()
+            .create_descriptor_set_allocator();
+        let mut descriptor_set = descriptor_set_allocator.create_dyn_descriptor_set_uninitialized(
+            &inject_pipeline.get_raw().descriptor_set_layouts[0],
+        )?;
+
+        descriptor_set.set_buffer_data(
+            volumetric_fog_inject_comp::CONFIG_DESCRIPTOR_BINDING_INDEX as u32,
+            &inject_config,
+        );
+        descriptor_set.set_buffer(
+            volumetric_fog_inject_comp::PER_VIEW_DATA_DESCRIPTOR_BINDING_INDEX as u32,
+            &per_view_data_buffer,
+        );
+        descriptor_set.set_image(
+            volumetric_fog_inject_comp::SHADOW_MAP_ATLAS_DESCRIPTOR_BINDING_INDEX as u32,
+            &shadow_atlas,
+        );
+        descriptor_set.set_buffer(
+            volumetric_fog_inject_comp::LIGHT_BIN_OUTPUT_DESCRIPTOR_BINDING_INDEX as u32,
+            &light_lists_buffer,
+        );
+        descriptor_set.set_buffer(
+            volumetric_fog_inject_comp::ALL_LIGHTS_DESCRIPTOR_BINDING_INDEX as u32,
+            &all_lights_buffer,
+        );
+        descriptor_set.set_image(
+            volumetric_fog_inject_comp::OUTPUT_TEX_DESCRIPTOR_BINDING_INDEX as u32,
+            &scattering,
+        );
+        descriptor_set.flush(&mut descriptor_set_allocator)?;
+        descriptor_set_allocator.flush_changes()?;
+
+        let command_buffer = &args.command_buffer;
+        command_buffer.cmd_bind_pipeline(&*inject_pipeline.get_raw().pipeline)?;
+        descriptor_set.bind(command_buffer)?;
+        // One invocation per froxel, 8x8 froxels per workgroup
+        command_buffer.cmd_dispatch(
+            VOLUMETRIC_FOG_WIDTH / 8,
+            VOLUMETRIC_FOG_HEIGHT / 8,
+            VOLUMETRIC_FOG_DEPTH,
+        )?;
+
+        Ok(())
+    });
+
+    //
+    // Integrate
+    //
+    let integrate_node = context
+        .graph
+        .add_callback_node("VolumetricFogIntegrate", RenderGraphQueue::DefaultGraphics);
+
+    let scattering = context.graph.read_storage_image(
+        integrate_node,
+        scattering,
+        volume_constraint(),
+        Default::default(),
+    );
+
+    let volume =
+        context
+            .graph
+            .create_storage_image(integrate_node, volume_constraint(), Default::default());
+    context.graph.set_image_name(volume, "volumetric_fog");
+
+    let integrate_config = volumetric_fog_integrate_comp::VolumetricFogIntegrateConfigUniform {
+        inverse_projection: inverse_projection.to_cols_array_2d(),
+        max_distance: options.max_distance,
+        ..Default::default()
+    };
+
+    let integrate_pipeline = integrate_pipeline.clone();
+    context.graph.set_callback(integrate_node, move |args| {
+        let scattering = args.graph_context.image_view(scattering).unwrap();
+        let volume = args.graph_context.image_view(volume).unwrap();
+
+        let mut descriptor_set_allocator = args
+            .graph_context
+            .resource_context()
+            .create_descriptor_set_allocator();
+        let mut descriptor_set = descriptor_set_allocator.create_dyn_descriptor_set_uninitialized(
+            &integrate_pipeline.get_raw().descriptor_set_layouts[0],
+        )?;
+
+        descriptor_set.set_buffer_data(
+            volumetric_fog_integrate_comp::CONFIG_DESCRIPTOR_BINDING_INDEX as u32,
+            &integrate_config,
+        );
+        descriptor_set.set_image(
+            volumetric_fog_integrate_comp::SCATTERING_TEX_DESCRIPTOR_BINDING_INDEX as u32,
+            &scattering,
+        );
+        descriptor_set.set_image(
+            volumetric_fog_integrate_comp::OUTPUT_TEX_DESCRIPTOR_BINDING_INDEX as u32,
+            &volume,
+        );
+        descriptor_set.flush(&mut descriptor_set_allocator)?;
+        descriptor_set_allocator.flush_changes()?;
+
+        let command_buffer = &args.command_buffer;
+        command_buffer.cmd_bind_pipeline(&*integrate_pipeline.get_raw().pipeline)?;
+        descriptor_set.bind(command_buffer)?;
+        // One invocation per column of froxels
+        command_buffer.cmd_dispatch(VOLUMETRIC_FOG_WIDTH / 8, VOLUMETRIC_FOG_HEIGHT / 8, 1)?;
+
+        Ok(())
+    });
+
+    VolumetricFogPass {
+        node: integrate_node,
+        volume,
+    }
+}
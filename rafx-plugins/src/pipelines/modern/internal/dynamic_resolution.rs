@@ -0,0 +1,240 @@
+use crate::pipelines::modern::DynamicResolutionOptions;
+
+// The render scale is never allowed to go below this, regardless of options
+pub const MIN_RENDER_SCALE: f32 = 0.25;
+
+// How much of each new frame time is blended into the smoothed frame time
+const FRAME_TIME_SMOOTHING: f32 = 0.1;
+
+// A frame taking this much longer than the smoothed frame time is a hitch, and is ignored unless
+// the next frame is also a hitch. Keeps a single hitch from causing a resolution change.
+const HITCH_FRAME_TIME_RATIO: f32 = 1.5;
+
+// Frame times within this fraction of the target are considered to be on budget
+const FRAME_TIME_TOLERANCE: f32 = 0.05;
+
+// How far the scale moves toward the ideal scale each frame when over budget
+const DECREASE_RATE: f32 = 0.25;
+
+// How much the scale increases each frame when on or under budget
+const INCREASE_STEP: f32 = 0.005;
+
+// The returned scale is a multiple of this and only changes once the controller's scale has moved a
+// whole step away from it. Every change of the render extents reallocates the render graph images
+// sized from them, so they must not change every frame.
+const RENDER_SCALE_STEP: f32 = 1.0 / 32.0;
+
+pub struct DynamicResolutionController {
+    render_scale: f32,
+    quantized_render_scale: f32,
+    smoothed_frame_time_ms: Option<f32>,
+    previous_frame_was_hitch: bool,
+}
+
+impl Default for DynamicResolutionController {
+    fn default() -> Self {
+        DynamicResolutionController {
+            render_scale: 1.0,
+            quantized_render_scale: 1.0,
+            smoothed_frame_time_ms: None,
+            previous_frame_was_hitch: false,
+        }
+    }
+}
+
+impl DynamicResolutionController {
+    // Start from the given scale the next time the controller is updated
+    pub fn reset(
+        &mut self,
+        render_scale: f32,
+    ) {
+        self.render_scale = render_scale;
+        self.quantized_render_scale = render_scale;
+        self.smoothed_frame_time_ms = None;
+        self.previous_frame_was_hitch = false;
+    }
+
+    pub fn update(
+        &mut self,
+        frame_time_ms: f32,
+        options: &DynamicResolutionOptions,
+    ) -> f32 {
+        let smoothed_frame_time_ms = match self.smoothed_frame_time_ms {
+            Some(smoothed) => {
+                let is_hitch = frame_time_ms > smoothed * HITCH_FRAME_TIME_RATIO;
+                let ignore_frame = is_hitch && !self.previous_frame_was_hitch;
+                self.previous_frame_was_hitch = is_hitch;
+                if ignore_frame {
+                    smoothed
+                } else {
+                    smoothed + (frame_time_ms - smoothed) * FRAME_TIME_SMOOTHING
+                }
+            }
+            None => frame_time_ms,
+        };
+        self.smoothed_frame_time_ms = Some(smoothed_frame_time_ms);
+
+        // A min above the max wins, so the scale is pinned to the min
+        let min_render_scale = options.min_render_scale.clamp(MIN_RENDER_SCALE, 1.0);
+        let max_render_scale = options.max_render_scale.clamp(min_render_scale, 1.0);
+
+        let budget_ratio = options.target_frame_time_ms / smoothed_frame_time_ms.max(0.001);
+        let render_scale = if budget_ratio < 1.0 - FRAME_TIME_TOLERANCE {
+            // Shading cost is roughly proportional to pixel count, which is the square of the scale
+            let ideal_render_scale = self.render_scale * budget_ratio.sqrt();
+            self.render_scale + (ideal_render_scale - self.render_scale) * DECREASE_RATE
+        } else {
+            // When presentation is synced to the display, the frame time can't go below the target
+            // and so isn't a measure of headroom. Creep upward until frames start taking too long.
+            self.render_scale + INCREASE_STEP
+        };
+
+        self.render_scale = render_scale.clamp(min_render_scale, max_render_scale);
+
+        let quantized_render_scale_out_of_range = self.quantized_render_scale < min_render_scale
+            || self.quantized_render_scale > max_render_scale;
+        if quantized_render_scale_out_of_range
+            || (self.render_scale - self.quantized_render_scale).abs() >= RENDER_SCALE_STEP
+        {
+            self.quantized_render_scale = ((self.render_scale / RENDER_SCALE_STEP).round()
+                * RENDER_SCALE_STEP)
+                .clamp(min_render_scale, max_render_scale);
+        }
+
+        self.quantized_render_scale
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const TARGET_FRAME_TIME_MS: f32 = 1000.0 / 60.0;
+
+    fn options(
+        min_render_scale: f32,
+        max_render_scale: f32,
+    ) -> DynamicResolutionOptions {
+        DynamicResolutionOptions {
+            enabled: true,
+            target_frame_time_ms: TARGET_FRAME_TIME_MS,
+            min_render_scale,
+            max_render_scale,
+        }
+    }
+
+    fn update_frames(
+        controller: &mut DynamicResolutionController,
+        frame_times_ms: impl IntoIterator<Item = f32>,
+        options: &DynamicResolutionOptions,
+    ) -> Vec<f32> {
+        frame_times_ms
+            .into_iter()
+            .map(|frame_time_ms| controller.update(frame_time_ms, options))
+            .collect()
+    }
+
+    fn is_quantized(render_scale: f32) -> bool {
+        (render_scale / RENDER_SCALE_STEP).fract() == 0.0
+    }
+
+    #[test]
+    fn decreases_when_over_budget() {
+        let options = options(0.5, 1.0);
+        let mut controller = DynamicResolutionController::default();
+
+        let scales = update_frames(&mut controller, [TARGET_FRAME_TIME_MS * 2.0; 200], &options);
+
+        assert!(scales.windows(2).all(|x| x[1] <= x[0]));
+        assert!(scales.iter().all(|&x| is_quantized(x)));
+        assert!(scales[10] < 1.0);
+        assert_eq!(*scales.last().unwrap(), 0.5);
+    }
+
+    #[test]
+    fn increases_when_under_budget() {
+        let options = options(0.5, 1.0);
+        let mut controller = DynamicResolutionController::default();
+        controller.reset(0.5);
+
+        let scales = update_frames(&mut controller, [TARGET_FRAME_TIME_MS * 0.5; 200], &options);
+
+        assert!(scales.windows(2).all(|x| x[1] >= x[0]));
+        assert!(scales.iter().all(|&x| is_quantized(x)));
+        assert!(scales[10] > 0.5);
+        assert_eq!(*scales.last().unwrap(), 1.0);
+    }
+
+    #[test]
+    fn clamps_to_options() {
+        let mut controller = DynamicResolutionController::default();
+
+        // A max below 1.0 is applied immediately
+        let scales = update_frames(
+            &mut controller,
+            [TARGET_FRAME_TIME_MS; 10],
+            &options(0.5, 0.8),
+        );
+        assert!(scales.iter().all(|&x| x == 0.8));
+
+        // A min above the max pins the scale to the min
+        let scales = update_frames(
+            &mut controller,
+            [TARGET_FRAME_TIME_MS * 4.0; 50],
+            &options(0.7, 0.6),
+        );
+        assert!(scales.iter().all(|&x| x == 0.7));
+        let scales = update_frames(
+            &mut controller,
+            [TARGET_FRAME_TIME_MS * 0.25; 50],
+            &options(0.7, 0.6),
+        );
+        assert!(scales.iter().all(|&x| x == 0.7));
+
+        // Options outside the supported range are clamped
+        let scales = update_frames(
+            &mut controller,
+            [TARGET_FRAME_TIME_MS * 0.25; 50],
+            &options(2.0, 3.0),
+        );
+        assert!(scales.iter().all(|&x| x == 1.0));
+        let scales = update_frames(
+            &mut controller,
+            [TARGET_FRAME_TIME_MS * 100.0; 200],
+            &options(0.0, 1.0),
+        );
+        assert_eq!(*scales.last().unwrap(), MIN_RENDER_SCALE);
+    }
+
+    #[test]
+    fn single_hitch_does_not_change_scale() {
+        let options = options(0.5, 1.0);
+
+        for hitch_frame_time_ms in [TARGET_FRAME_TIME_MS * 2.0, TARGET_FRAME_TIME_MS * 10.0] {
+            let mut frame_times_ms = vec![TARGET_FRAME_TIME_MS; 20];
+            frame_times_ms.push(hitch_frame_time_ms);
+            frame_times_ms.extend_from_slice(&[TARGET_FRAME_TIME_MS; 20]);
+
+            let mut controller = DynamicResolutionController::default();
+            let scales = update_frames(&mut controller, frame_times_ms.clone(), &options);
+            assert!(scales.iter().all(|&x| x == 1.0));
+
+            // While the scale is creeping upward, the hitch changes nothing either
+            let mut controller = DynamicResolutionController::default();
+            controller.reset(0.5);
+            let mut steady_controller = DynamicResolutionController::default();
+            steady_controller.reset(0.5);
+            assert_eq!(
+                update_frames(&mut controller, frame_times_ms, &options),
+                update_frames(&mut steady_controller, [TARGET_FRAME_TIME_MS; 41], &options)
+            );
+        }
+
+        // A hitch that lasts more than a frame is a slowdown
+        let mut controller = DynamicResolutionController::default();
+        let mut frame_times_ms = vec![TARGET_FRAME_TIME_MS; 20];
+        frame_times_ms.extend_from_slice(&[TARGET_FRAME_TIME_MS * 2.0; 20]);
+        let scales = update_frames(&mut controller, frame_times_ms, &options);
+        assert!(*scales.last().unwrap() < 1.0);
+    }
+}
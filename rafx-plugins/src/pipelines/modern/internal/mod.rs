@@ -1 +1,2 @@
+pub(super) mod dynamic_resolution;
 pub(super) mod jitter;
@@ -1,22 +1,29 @@
+use super::internal::dynamic_resolution::{DynamicResolutionController, MIN_RENDER_SCALE};
+use super::{AntiAliasMethodAdv, ModernPipelineGpuFrameTime, ModernPipelineRenderOptions};
+use crate::features::mesh_adv::MeshAdvIblImages;
 use crate::phases::{
-    DebugPipRenderPhase, DepthPrepassRenderPhase, OpaqueRenderPhase, PostProcessRenderPhase,
-    ShadowMapRenderPhase, TransparentRenderPhase, UiRenderPhase, WireframeRenderPhase,
+    DebugPipRenderPhase, DecalRenderPhase, DepthPrepassRenderPhase, OpaqueRenderPhase,
+    PostProcessRenderPhase, ShadowMapRenderPhase, TransparentRenderPhase, UiRenderPhase,
+    WireframeRenderPhase,
 };
 use crate::shaders::mesh_adv::mesh_culling_comp;
 use crate::shaders::post_adv::luma_average_histogram_comp;
 use hydrate_base::handle::Handle;
 use rafx::api::extra::upload::RafxTransferUpload;
 use rafx::api::{
-    RafxBufferDef, RafxFormat, RafxMemoryUsage, RafxQueueType, RafxResourceType, RafxResult,
+    RafxBufferDef, RafxError, RafxExtents2D, RafxFormat, RafxMemoryUsage, RafxQueueType,
+    RafxResourceType, RafxResult,
 };
 use rafx::assets::AssetResource;
 use rafx::assets::{AssetManager, ComputePipelineAsset, ImageAsset, MaterialAsset};
+use rafx::framework::upload::image_upload::{self, ImageUploadParams};
+use rafx::framework::upload::{GpuImageData, GpuImageDataColorSpace};
 use rafx::framework::{
     BufferResource, ImageViewResource, RenderResources, ResourceArc, MAX_FRAMES_IN_FLIGHT,
 };
 use rafx::graph::PreparedRenderGraph;
 use rafx::render_features::{ExtractResources, RenderRegistryBuilder, RenderView};
-use rafx::renderer::{RendererLoadContext, RendererPipelinePlugin};
+use rafx::renderer::{RendererLoadContext, RendererPipelinePlugin, TimeRenderResource};
 use std::sync::{Arc, Mutex};
 
 // A plugin that add demo-specific configuration
@@ -112,15 +119,42 @@ pub struct ModernPipelineStaticResources {
     pub ssao_material: Handle<MaterialAsset>,
     pub blue_noise_texture: Handle<ImageAsset>,
     pub taa_material: Handle<MaterialAsset>,
+    pub oit_composite_material: Handle<MaterialAsset>,
+    pub ssr_composite_material: Handle<MaterialAsset>,
     pub luma_build_histogram: Handle<ComputePipelineAsset>,
     pub luma_average_histogram: Handle<ComputePipelineAsset>,
     pub cas_pipeline: Handle<ComputePipelineAsset>,
     pub mesh_culling_pipeline: Handle<ComputePipelineAsset>,
+    pub mesh_skinning_pipeline: Handle<ComputePipelineAsset>,
+    pub particle_init_pipeline: Handle<ComputePipelineAsset>,
+    pub particle_spawn_pipeline: Handle<ComputePipelineAsset>,
+    pub particle_indirect_args_pipeline: Handle<ComputePipelineAsset>,
+    pub particle_simulate_pipeline: Handle<ComputePipelineAsset>,
     pub depth_pyramid_pipeline: Handle<ComputePipelineAsset>,
+    pub ibl_brdf_lut_pipeline: Handle<ComputePipelineAsset>,
+    pub ibl_irradiance_pipeline: Handle<ComputePipelineAsset>,
+    pub ibl_specular_pipeline: Handle<ComputePipelineAsset>,
+    pub reflection_probe_prefilter_pipeline: Handle<ComputePipelineAsset>,
+    pub ssr_pipeline: Handle<ComputePipelineAsset>,
+    pub volumetric_fog_inject_pipeline: Handle<ComputePipelineAsset>,
+    pub volumetric_fog_integrate_pipeline: Handle<ComputePipelineAsset>,
+    pub depth_of_field_pipeline: Handle<ComputePipelineAsset>,
+    pub motion_blur_pipeline: Handle<ComputePipelineAsset>,
+    // Bound in place of color grading LUTs that aren't set or haven't loaded yet
+    pub identity_color_grading_lut: ResourceArc<ImageViewResource>,
     pub tonemap_histogram_result: ResourceArc<BufferResource>,
     pub tonemap_debug_output: Vec<ResourceArc<BufferResource>>,
     pub mesh_culling_debug_output: Vec<ResourceArc<BufferResource>>,
     pub taa_history_rt: Option<ResourceArc<ImageViewResource>>,
+    pub ssr_history_rt: Option<ResourceArc<ImageViewResource>>,
+    pub ibl: Option<ModernPipelineIbl>,
+    pub dynamic_resolution: DynamicResolutionController,
+}
+
+// Image-based lighting generated from a skybox. It's regenerated when the skybox changes.
+pub struct ModernPipelineIbl {
+    pub source_cubemap: ResourceArc<ImageViewResource>,
+    pub images: MeshAdvIblImages,
 }
 
 pub struct ModernPipelineRendererPlugin;
@@ -133,6 +167,7 @@ impl RendererPipelinePlugin for ModernPipelineRendererPlugin {
         render_registry_builder
             .register_render_phase::<DepthPrepassRenderPhase>("DepthPrepass")
             .register_render_phase::<ShadowMapRenderPhase>("ShadowMap")
+            .register_render_phase::<DecalRenderPhase>("Decal")
             .register_render_phase::<OpaqueRenderPhase>("Opaque")
             .register_render_phase::<TransparentRenderPhase>("Transparent")
             .register_render_phase::<WireframeRenderPhase>("Wireframe")
@@ -148,7 +183,7 @@ impl RendererPipelinePlugin for ModernPipelineRendererPlugin {
         asset_resource: &mut AssetResource,
         _extract_resources: &ExtractResources,
         render_resources: &mut RenderResources,
-        _upload: &mut RafxTransferUpload,
+        upload: &mut RafxTransferUpload,
     ) -> RafxResult<()> {
         //
         // Bloom extract resources
@@ -178,6 +213,14 @@ impl RendererPipelinePlugin for ModernPipelineRendererPlugin {
             "rafx-plugins://materials/modern_pipeline/taa.material",
         );
 
+        let oit_composite_material = asset_resource.load_artifact_symbol_name::<MaterialAsset>(
+            "rafx-plugins://materials/modern_pipeline/oit_composite.material",
+        );
+
+        let ssr_composite_material = asset_resource.load_artifact_symbol_name::<MaterialAsset>(
+            "rafx-plugins://materials/modern_pipeline/ssr_composite.material",
+        );
+
         //
         // Bloom combine resources
         //
@@ -210,11 +253,80 @@ impl RendererPipelinePlugin for ModernPipelineRendererPlugin {
                 "rafx-plugins://compute_pipelines/mesh_culling.compute",
             );
 
+        let mesh_skinning_pipeline = asset_resource
+            .load_artifact_symbol_name::<ComputePipelineAsset>(
+                "rafx-plugins://compute_pipelines/mesh_skinning.compute",
+            );
+
+        let particle_init_pipeline = asset_resource
+            .load_artifact_symbol_name::<ComputePipelineAsset>(
+                "rafx-plugins://compute_pipelines/particle_init.compute",
+            );
+
+        let particle_spawn_pipeline = asset_resource
+            .load_artifact_symbol_name::<ComputePipelineAsset>(
+                "rafx-plugins://compute_pipelines/particle_spawn.compute",
+            );
+
+        let particle_indirect_args_pipeline = asset_resource
+            .load_artifact_symbol_name::<ComputePipelineAsset>(
+                "rafx-plugins://compute_pipelines/particle_indirect_args.compute",
+            );
+
+        let particle_simulate_pipeline = asset_resource
+            .load_artifact_symbol_name::<ComputePipelineAsset>(
+                "rafx-plugins://compute_pipelines/particle_simulate.compute",
+            );
+
         let depth_pyramid_pipeline = asset_resource
             .load_artifact_symbol_name::<ComputePipelineAsset>(
                 "rafx-plugins://compute_pipelines/depth_pyramid.compute",
             );
 
+        let ibl_brdf_lut_pipeline = asset_resource
+            .load_artifact_symbol_name::<ComputePipelineAsset>(
+                "rafx-plugins://compute_pipelines/ibl_brdf_lut.compute",
+            );
+
+        let ibl_irradiance_pipeline = asset_resource
+            .load_artifact_symbol_name::<ComputePipelineAsset>(
+                "rafx-plugins://compute_pipelines/ibl_irradiance.compute",
+            );
+
+        let ibl_specular_pipeline = asset_resource
+            .load_artifact_symbol_name::<ComputePipelineAsset>(
+                "rafx-plugins://compute_pipelines/ibl_specular.compute",
+            );
+
+        let reflection_probe_prefilter_pipeline = asset_resource
+            .load_artifact_symbol_name::<ComputePipelineAsset>(
+                "rafx-plugins://compute_pipelines/reflection_probe_prefilter.compute",
+            );
+
+        let ssr_pipeline = asset_resource.load_artifact_symbol_name::<ComputePipelineAsset>(
+            "rafx-plugins://compute_pipelines/ssr.compute",
+        );
+
+        let volumetric_fog_inject_pipeline = asset_resource
+            .load_artifact_symbol_name::<ComputePipelineAsset>(
+                "rafx-plugins://compute_pipelines/volumetric_fog_inject.compute",
+            );
+
+        let volumetric_fog_integrate_pipeline = asset_resource
+            .load_artifact_symbol_name::<ComputePipelineAsset>(
+                "rafx-plugins://compute_pipelines/volumetric_fog_integrate.compute",
+            );
+
+        let depth_of_field_pipeline = asset_resource
+            .load_artifact_symbol_name::<ComputePipelineAsset>(
+                "rafx-plugins://compute_pipelines/depth_of_field.compute",
+            );
+
+        let motion_blur_pipeline = asset_resource
+            .load_artifact_symbol_name::<ComputePipelineAsset>(
+                "rafx-plugins://compute_pipelines/motion_blur.compute",
+            );
+
         renderer_load_context.wait_for_asset_to_load(
             render_resources,
             asset_manager,
@@ -246,6 +358,22 @@ impl RendererPipelinePlugin for ModernPipelineRendererPlugin {
             "taa material",
         )?;
 
+        renderer_load_context.wait_for_asset_to_load(
+            render_resources,
+            asset_manager,
+            &oit_composite_material,
+            asset_resource,
+            "oit composite material",
+        )?;
+
+        renderer_load_context.wait_for_asset_to_load(
+            render_resources,
+            asset_manager,
+            &ssr_composite_material,
+            asset_resource,
+            "ssr composite material",
+        )?;
+
         renderer_load_context.wait_for_asset_to_load(
             render_resources,
             asset_manager,
@@ -286,6 +414,46 @@ impl RendererPipelinePlugin for ModernPipelineRendererPlugin {
             "mesh_culling_pipeline",
         )?;
 
+        renderer_load_context.wait_for_asset_to_load(
+            render_resources,
+            asset_manager,
+            &mesh_skinning_pipeline,
+            asset_resource,
+            "mesh_skinning_pipeline",
+        )?;
+
+        renderer_load_context.wait_for_asset_to_load(
+            render_resources,
+            asset_manager,
+            &particle_init_pipeline,
+            asset_resource,
+            "particle_init_pipeline",
+        )?;
+
+        renderer_load_context.wait_for_asset_to_load(
+            render_resources,
+            asset_manager,
+            &particle_spawn_pipeline,
+            asset_resource,
+            "particle_spawn_pipeline",
+        )?;
+
+        renderer_load_context.wait_for_asset_to_load(
+            render_resources,
+            asset_manager,
+            &particle_indirect_args_pipeline,
+            asset_resource,
+            "particle_indirect_args_pipeline",
+        )?;
+
+        renderer_load_context.wait_for_asset_to_load(
+            render_resources,
+            asset_manager,
+            &particle_simulate_pipeline,
+            asset_resource,
+            "particle_simulate_pipeline",
+        )?;
+
         renderer_load_context.wait_for_asset_to_load(
             render_resources,
             asset_manager,
@@ -294,6 +462,78 @@ impl RendererPipelinePlugin for ModernPipelineRendererPlugin {
             "depth_pyramid_pipeline",
         )?;
 
+        renderer_load_context.wait_for_asset_to_load(
+            render_resources,
+            asset_manager,
+            &ibl_brdf_lut_pipeline,
+            asset_resource,
+            "ibl_brdf_lut_pipeline",
+        )?;
+
+        renderer_load_context.wait_for_asset_to_load(
+            render_resources,
+            asset_manager,
+            &ibl_irradiance_pipeline,
+            asset_resource,
+            "ibl_irradiance_pipeline",
+        )?;
+
+        renderer_load_context.wait_for_asset_to_load(
+            render_resources,
+            asset_manager,
+            &ibl_specular_pipeline,
+            asset_resource,
+            "ibl_specular_pipeline",
+        )?;
+
+        renderer_load_context.wait_for_asset_to_load(
+            render_resources,
+            asset_manager,
+            &reflection_probe_prefilter_pipeline,
+            asset_resource,
+            "reflection_probe_prefilter_pipeline",
+        )?;
+
+        renderer_load_context.wait_for_asset_to_load(
+            render_resources,
+            asset_manager,
+            &ssr_pipeline,
+            asset_resource,
+            "ssr_pipeline",
+        )?;
+
+        renderer_load_context.wait_for_asset_to_load(
+            render_resources,
+            asset_manager,
+            &volumetric_fog_inject_pipeline,
+            asset_resource,
+            "volumetric_fog_inject_pipeline",
+        )?;
+
+        renderer_load_context.wait_for_asset_to_load(
+            render_resources,
+            asset_manager,
+            &volumetric_fog_integrate_pipeline,
+            asset_resource,
+            "volumetric_fog_integrate_pipeline",
+        )?;
+
+        renderer_load_context.wait_for_asset_to_load(
+            render_resources,
+            asset_manager,
+            &depth_of_field_pipeline,
+            asset_resource,
+            "depth_of_field_pipeline",
+        )?;
+
+        renderer_load_context.wait_for_asset_to_load(
+            render_resources,
+            asset_manager,
+            &motion_blur_pipeline,
+            asset_resource,
+            "motion_blur_pipeline",
+        )?;
+
         let tonemap_histogram_result =
             asset_manager
                 .device_context()
@@ -366,6 +606,42 @@ impl RendererPipelinePlugin for ModernPipelineRendererPlugin {
             );
         }
 
+        // With linear filtering, a 2x2x2 LUT holding the corners of the color cube maps every color
+        // to itself
+        let mut identity_color_grading_lut_bytes = Vec::with_capacity(2 * 2 * 2 * 4);
+        for b in 0..2 {
+            for g in 0..2 {
+                for r in 0..2 {
+                    identity_color_grading_lut_bytes.extend_from_slice(&[
+                        r * 255,
+                        g * 255,
+                        b * 255,
+                        255,
+                    ]);
+                }
+            }
+        }
+
+        let identity_color_grading_lut = image_upload::enqueue_load_image(
+            asset_manager.device_context(),
+            upload,
+            &GpuImageData::new_volume_image_from_bytes(
+                2,
+                2,
+                2,
+                GpuImageDataColorSpace::Linear.rgba8(),
+                identity_color_grading_lut_bytes,
+            ),
+            ImageUploadParams::default(),
+        )
+        .map_err(|x| Into::<RafxError>::into(x))?;
+        let identity_color_grading_lut = asset_manager
+            .resources()
+            .insert_image(identity_color_grading_lut);
+        let identity_color_grading_lut = asset_manager
+            .resources()
+            .get_or_create_image_view(&identity_color_grading_lut, None)?;
+
         let taa_history_rt = None;
 
         render_resources.insert(ModernPipelineStaticResources {
@@ -375,20 +651,89 @@ impl RendererPipelinePlugin for ModernPipelineRendererPlugin {
             ssao_material,
             blue_noise_texture,
             taa_material,
+            oit_composite_material,
+            ssr_composite_material,
             luma_build_histogram,
             luma_average_histogram,
             cas_pipeline,
             mesh_culling_pipeline,
+            mesh_skinning_pipeline,
+            particle_init_pipeline,
+            particle_spawn_pipeline,
+            particle_indirect_args_pipeline,
+            particle_simulate_pipeline,
             depth_pyramid_pipeline,
+            ibl_brdf_lut_pipeline,
+            ibl_irradiance_pipeline,
+            ibl_specular_pipeline,
+            reflection_probe_prefilter_pipeline,
+            ssr_pipeline,
+            volumetric_fog_inject_pipeline,
+            volumetric_fog_integrate_pipeline,
+            depth_of_field_pipeline,
+            motion_blur_pipeline,
+            identity_color_grading_lut,
             tonemap_histogram_result,
             tonemap_debug_output,
             mesh_culling_debug_output,
             taa_history_rt,
+            ssr_history_rt: None,
+            ibl: None,
+            dynamic_resolution: Default::default(),
         });
 
         Ok(())
     }
 
+    fn main_view_extents(
+        &self,
+        window_extents: RafxExtents2D,
+        extract_resources: &ExtractResources,
+        render_resources: &RenderResources,
+    ) -> RafxExtents2D {
+        let render_options = match extract_resources.try_fetch::<ModernPipelineRenderOptions>() {
+            Some(render_options) => render_options,
+            None => return window_extents,
+        };
+
+        let mut static_resources = render_resources.fetch_mut::<ModernPipelineStaticResources>();
+
+        // Only TAA can upscale the main view back to the swapchain resolution
+        if render_options.anti_alias_method != AntiAliasMethodAdv::Taa {
+            static_resources.dynamic_resolution.reset(1.0);
+            return window_extents;
+        }
+
+        let render_scale = if render_options.dynamic_resolution_options.enabled {
+            // rafx-api has no GPU timestamp queries, so GPU time is only known if the app measured
+            // it. Otherwise use the time between frames. It is a good approximation of GPU time when
+            // the GPU is the bottleneck, which is the only case lowering the resolution helps.
+            let gpu_frame_time_ms = extract_resources
+                .try_fetch::<ModernPipelineGpuFrameTime>()
+                .and_then(|x| x.frame_time_ms);
+            let frame_time_ms = gpu_frame_time_ms.unwrap_or_else(|| {
+                render_resources
+                    .fetch::<TimeRenderResource>()
+                    .previous_update_dt()
+                    * 1000.0
+            });
+            static_resources
+                .dynamic_resolution
+                .update(frame_time_ms, &render_options.dynamic_resolution_options)
+        } else {
+            static_resources
+                .dynamic_resolution
+                .reset(render_options.render_scale);
+            render_options.render_scale
+        };
+
+        let render_scale = render_scale.clamp(MIN_RENDER_SCALE, 1.0);
+        RafxExtents2D {
+            width: ((window_extents.width as f32 * render_scale).round() as u32).max(1),
+            height: ((window_extents.height as f32 * render_scale).round() as u32).max(1),
+        }
+    }
+
     fn generate_render_graph(
         &self,
         asset_manager: &AssetManager,
@@ -1,9 +1,14 @@
+use hydrate_base::handle::Handle;
+use rafx::assets::ImageAsset;
+
 // Should be kept in sync with the constants in bloom_combine.frag prefixed with OUTPUT_COLOR_SPACE_
 #[derive(Debug, Clone, Copy, PartialEq)]
 #[repr(C)]
 pub enum ModernPipelineOutputColorSpace {
     Srgb,
     P3,
+    ScRgb,
+    Hdr10,
 }
 
 // Should be kept in sync with the constants in tonemapper.glsl prefixed with TM_
@@ -31,6 +36,18 @@ impl Default for TonemapperTypeAdv {
 }
 
 impl TonemapperTypeAdv {
+    // Tonemappers that can map into a range brighter than SDR white. Others are replaced with
+    // Bergstrom when outputting to an HDR10 or scRGB swapchain.
+    pub fn supports_hdr_output(&self) -> bool {
+        matches!(
+            self,
+            TonemapperTypeAdv::None
+                | TonemapperTypeAdv::VisualizeRGBMax
+                | TonemapperTypeAdv::VisualizeLuma
+                | TonemapperTypeAdv::Bergstrom
+        )
+    }
+
     pub fn display_name(&self) -> &'static str {
         match self {
             TonemapperTypeAdv::None => "None",
@@ -126,6 +143,188 @@ impl Default for TemporalAAOptions {
     }
 }
 
+// Adjusts the render scale every frame to hold a target frame time. The scale is lowered quickly
+// when frames take too long and raised slowly while they are within budget.
+//
+// rafx-api has no GPU timestamp queries, so unless the app provides ModernPipelineGpuFrameTime the
+// frame time is the wall-clock time between frames rather than GPU time. The two only agree while
+// the GPU is the bottleneck. A CPU-bound app also sees long frames and lowers the resolution even
+// though that doesn't make frames any faster.
+#[derive(Clone)]
+pub struct DynamicResolutionOptions {
+    pub enabled: bool,
+    pub target_frame_time_ms: f32,
+    pub min_render_scale: f32,
+    pub max_render_scale: f32,
+}
+
+impl Default for DynamicResolutionOptions {
+    fn default() -> Self {
+        DynamicResolutionOptions {
+            enabled: false,
+            target_frame_time_ms: 1000.0 / 60.0,
+            min_render_scale: 0.5,
+            max_render_scale: 1.0,
+        }
+    }
+}
+
+// GPU time of the previous frame for dynamic resolution, for apps that can measure it, for example
+// with backend-specific timestamp queries. Add it to the extract resources next to
+// ModernPipelineRenderOptions. If the resource is missing or frame_time_ms is None, dynamic
+// resolution falls back to the wall-clock time between frames.
+#[derive(Clone, Default)]
+pub struct ModernPipelineGpuFrameTime {
+    pub frame_time_ms: Option<f32>,
+}
+
+// Reflections traced against the depth pyramid. Surfaces rougher than max_roughness don't receive
+// them, and anything offscreen is still only reflected by IBL and reflection probes.
+#[derive(Clone)]
+pub struct ScreenSpaceReflectionOptions {
+    // Steps taken along each ray. Steps get longer as the ray passes over empty areas of the depth
+    // pyramid, so this is much less than the number of pixels a ray can cross.
+    pub max_steps: u32,
+    // Length of a ray in world units
+    pub max_distance: f32,
+    // How far behind the depth buffer a ray may be and still count as hitting it
+    pub thickness: f32,
+    pub max_roughness: f32,
+    // Fraction of the screen at each edge over which reflections fade out
+    pub edge_fade: f32,
+    // How much of each new frame is blended into the accumulated reflections
+    pub current_frame_weight: f32,
+}
+
+impl Default for ScreenSpaceReflectionOptions {
+    fn default() -> Self {
+        ScreenSpaceReflectionOptions {
+            max_steps: 64,
+            max_distance: 50.0,
+            thickness: 0.5,
+            max_roughness: 0.6,
+            edge_fade: 0.1,
+            current_frame_weight: 0.1,
+        }
+    }
+}
+
+// Fog that fills the main view's frustum out to max_distance and scatters light from the ambient
+// light, directional lights and the clustered point and spot lights, including their shadows.
+// Density falls off exponentially above base_height (along +Z).
+#[derive(Clone)]
+pub struct VolumetricFogOptions {
+    // Extinction per world unit at or below base_height
+    pub density: f32,
+    pub height_falloff: f32,
+    pub base_height: f32,
+    // Henyey-Greenstein anisotropy in (-1, 1). Positive values scatter light forwards, which makes
+    // light shafts brighter when looking towards the light.
+    pub anisotropy: f32,
+    // Fraction of the light that is scattered rather than absorbed, per color channel
+    pub albedo: glam::Vec3,
+    // Surfaces further than this are fogged as if they were at this distance
+    pub max_distance: f32,
+}
+
+impl Default for VolumetricFogOptions {
+    fn default() -> Self {
+        VolumetricFogOptions {
+            density: 0.02,
+            height_falloff: 0.2,
+            base_height: 0.0,
+            anisotropy: 0.5,
+            albedo: glam::Vec3::ONE,
+            max_distance: 100.0,
+        }
+    }
+}
+
+// Blurs everything that isn't at the focus distance of the main view's camera lens. Views without a
+// lens are always in focus.
+#[derive(Clone)]
+pub struct DepthOfFieldOptions {
+    // Largest circle of confusion radius in pixels at full resolution. The cost of the pass grows
+    // with the square of this.
+    pub max_coc_radius: f32,
+    // Distance in pixels between samples. Larger values are faster but noisier.
+    pub sample_spacing: f32,
+}
+
+impl Default for DepthOfFieldOptions {
+    fn default() -> Self {
+        DepthOfFieldOptions {
+            max_coc_radius: 12.0,
+            sample_spacing: 1.0,
+        }
+    }
+}
+
+// Blurs moving objects and the camera's motion along the velocities written by the depth prepass
+#[derive(Clone)]
+pub struct MotionBlurOptions {
+    // Fraction of the frame the shutter is open for, so 0.5 is a 180 degree shutter
+    pub shutter_fraction: f32,
+    // Longest blur in pixels at full resolution
+    pub max_blur_length: f32,
+    pub sample_count: u32,
+}
+
+impl Default for MotionBlurOptions {
+    fn default() -> Self {
+        MotionBlurOptions {
+            shutter_fraction: 0.5,
+            max_blur_length: 32.0,
+            sample_count: 12,
+        }
+    }
+}
+
+// Grades the tonemapped image through 3D LUTs, which can be imported from .cube files or from strip
+// images with "_lut." in the filename. Blending towards blend_lut allows transitioning between two
+// grades during gameplay. A LUT that isn't set or hasn't loaded is treated as an identity grade.
+// LUTs are stored with 8 bits per channel, so grades with steep curves can band.
+#[derive(Clone)]
+pub struct ColorGradingOptions {
+    pub lut: Option<Handle<ImageAsset>>,
+    pub blend_lut: Option<Handle<ImageAsset>>,
+    // 0 grades entirely with lut, 1 entirely with blend_lut
+    pub blend: f32,
+    // 0 leaves the image ungraded, 1 applies the grade fully
+    pub strength: f32,
+}
+
+impl Default for ColorGradingOptions {
+    fn default() -> Self {
+        ColorGradingOptions {
+            lut: None,
+            blend_lut: None,
+            blend: 0.0,
+            strength: 1.0,
+        }
+    }
+}
+
+// Used when the swapchain is HDR10 or scRGB. A tonemapped value of 1.0 is shown at paper_white_nits
+// and the tonemapper maps the brightest parts of the image up to peak_nits.
+#[derive(Clone)]
+pub struct HdrOutputOptions {
+    pub paper_white_nits: f32,
+    pub peak_nits: f32,
+    // Use the display's reported max luminance instead of peak_nits when the backend can query it
+    pub use_display_peak_nits: bool,
+}
+
+impl Default for HdrOutputOptions {
+    fn default() -> Self {
+        HdrOutputOptions {
+            paper_white_nits: 200.0,
+            peak_nits: 1000.0,
+            use_display_peak_nits: true,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 #[repr(C)]
 pub enum AntiAliasMethodAdv {
@@ -163,6 +362,11 @@ impl From<i32> for AntiAliasMethodAdv {
 pub struct ModernPipelineRenderOptions {
     pub anti_alias_method: AntiAliasMethodAdv,
     pub taa_options: TemporalAAOptions,
+    // Fraction of the swapchain resolution the main view is rendered at. TAA upscales the result to
+    // the swapchain, so this is ignored unless TAA is the anti-alias method. Overridden by dynamic
+    // resolution when it is enabled.
+    pub render_scale: f32,
+    pub dynamic_resolution_options: DynamicResolutionOptions,
     pub enable_hdr: bool,
     pub enable_ssao: bool,
     pub enable_bloom: bool,
@@ -179,6 +383,33 @@ pub struct ModernPipelineRenderOptions {
     pub enable_sharpening: bool,
     pub sharpening_amount: f32,
     pub enable_occlusion_culling: bool,
+    // Draw transparent meshes with weighted blended order-independent transparency instead of
+    // sorting them. Not supported with MSAA, falls back to sorted blending.
+    pub enable_order_independent_transparency: bool,
+    // Light meshes with diffuse and specular image-based lighting generated from the skybox
+    // instead of the flat ambient light
+    pub enable_ibl: bool,
+    // Opaque meshes also write a G-buffer that screen-space reflections are traced from. Not
+    // supported with MSAA.
+    pub enable_ssr: bool,
+    pub ssr_options: ScreenSpaceReflectionOptions,
+    pub enable_volumetric_fog: bool,
+    pub volumetric_fog_options: VolumetricFogOptions,
+    // Project decals onto opaque meshes. The decals are rendered after the depth prepass and are
+    // applied when the meshes are shaded. Not supported with MSAA.
+    pub enable_decals: bool,
+    // Depth of field is applied before TAA, which resolves its noise. Motion blur is applied after
+    // TAA so that TAA doesn't reproject the blur. Neither is supported with MSAA.
+    pub enable_depth_of_field: bool,
+    pub depth_of_field_options: DepthOfFieldOptions,
+    pub enable_motion_blur: bool,
+    pub motion_blur_options: MotionBlurOptions,
+    // Applied when tonemapping, so it has no effect unless HDR is enabled
+    pub enable_color_grading: bool,
+    pub color_grading_options: ColorGradingOptions,
+    // HDR10 swapchains always render with HDR enabled, since the PQ encoding is applied when
+    // tonemapping
+    pub hdr_output_options: HdrOutputOptions,
 }
 
 impl Default for ModernPipelineRenderOptions {
@@ -186,6 +417,8 @@ impl Default for ModernPipelineRenderOptions {
         ModernPipelineRenderOptions {
             anti_alias_method: AntiAliasMethodAdv::Taa,
             taa_options: TemporalAAOptions::default(),
+            render_scale: 1.0,
+            dynamic_resolution_options: DynamicResolutionOptions::default(),
             enable_hdr: true,
             enable_ssao: true,
             enable_bloom: true,
@@ -202,6 +435,20 @@ impl Default for ModernPipelineRenderOptions {
             enable_sharpening: true,
             sharpening_amount: 1.0,
             enable_occlusion_culling: true,
+            enable_order_independent_transparency: false,
+            enable_ibl: true,
+            enable_ssr: false,
+            ssr_options: ScreenSpaceReflectionOptions::default(),
+            enable_volumetric_fog: false,
+            volumetric_fog_options: VolumetricFogOptions::default(),
+            enable_decals: true,
+            enable_depth_of_field: false,
+            depth_of_field_options: DepthOfFieldOptions::default(),
+            enable_motion_blur: false,
+            motion_blur_options: MotionBlurOptions::default(),
+            enable_color_grading: false,
+            color_grading_options: ColorGradingOptions::default(),
+            hdr_output_options: HdrOutputOptions::default(),
         }
     }
 }
@@ -26,6 +26,8 @@ impl Into<MeshAdvShadowMethod> for MeshAdvShadowMethodEnum {
         match self {
             MeshAdvShadowMethodEnum::None => MeshAdvShadowMethod::None,
             MeshAdvShadowMethodEnum::Opaque => MeshAdvShadowMethod::Opaque,
+            MeshAdvShadowMethodEnum::AlphaClip => MeshAdvShadowMethod::AlphaClip,
+            MeshAdvShadowMethodEnum::AlphaStochastic => MeshAdvShadowMethod::AlphaStochastic,
         }
     }
 }
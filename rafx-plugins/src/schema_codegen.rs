@@ -902,9 +902,29 @@ impl RecordAccessor for MeshAdvMeshAssetAccessor {
 }
 
 impl MeshAdvMeshAssetAccessor {
+    pub fn generate_meshlets(&self) -> BooleanFieldAccessor {
+        BooleanFieldAccessor::new(self.0.push("generate_meshlets"))
+    }
+
+    pub fn generated_lod_count(&self) -> U32FieldAccessor {
+        U32FieldAccessor::new(self.0.push("generated_lod_count"))
+    }
+
+    pub fn generated_lod_target_error(&self) -> F32FieldAccessor {
+        F32FieldAccessor::new(self.0.push("generated_lod_target_error"))
+    }
+
+    pub fn generated_lod_triangle_ratio(&self) -> F32FieldAccessor {
+        F32FieldAccessor::new(self.0.push("generated_lod_triangle_ratio"))
+    }
+
     pub fn material_slots(&self) -> DynamicArrayFieldAccessor::<AssetRefFieldAccessor> {
         DynamicArrayFieldAccessor::<AssetRefFieldAccessor>::new(self.0.push("material_slots"))
     }
+
+    pub fn optimize_overdraw(&self) -> BooleanFieldAccessor {
+        BooleanFieldAccessor::new(self.0.push("optimize_overdraw"))
+    }
 }
 pub struct MeshAdvMeshAssetRef<'a>(PropertyPath, DataContainerRef<'a>);
 
@@ -921,9 +941,29 @@ impl<'a> RecordRef for MeshAdvMeshAssetRef<'a> {
 }
 
 impl<'a> MeshAdvMeshAssetRef<'a> {
+    pub fn generate_meshlets(&self) -> BooleanFieldRef {
+        BooleanFieldRef::new(self.0.push("generate_meshlets"), self.1.clone())
+    }
+
+    pub fn generated_lod_count(&self) -> U32FieldRef {
+        U32FieldRef::new(self.0.push("generated_lod_count"), self.1.clone())
+    }
+
+    pub fn generated_lod_target_error(&self) -> F32FieldRef {
+        F32FieldRef::new(self.0.push("generated_lod_target_error"), self.1.clone())
+    }
+
+    pub fn generated_lod_triangle_ratio(&self) -> F32FieldRef {
+        F32FieldRef::new(self.0.push("generated_lod_triangle_ratio"), self.1.clone())
+    }
+
     pub fn material_slots(&self) -> DynamicArrayFieldRef::<AssetRefFieldRef> {
         DynamicArrayFieldRef::<AssetRefFieldRef>::new(self.0.push("material_slots"), self.1.clone())
     }
+
+    pub fn optimize_overdraw(&self) -> BooleanFieldRef {
+        BooleanFieldRef::new(self.0.push("optimize_overdraw"), self.1.clone())
+    }
 }
 pub struct MeshAdvMeshAssetRefMut<'a>(PropertyPath, Rc<RefCell<DataContainerRefMut<'a>>>);
 
@@ -940,9 +980,29 @@ impl<'a> RecordRefMut for MeshAdvMeshAssetRefMut<'a> {
 }
 
 impl<'a> MeshAdvMeshAssetRefMut<'a> {
+    pub fn generate_meshlets(self: &'a Self) -> BooleanFieldRefMut {
+        BooleanFieldRefMut::new(self.0.push("generate_meshlets"), &self.1)
+    }
+
+    pub fn generated_lod_count(self: &'a Self) -> U32FieldRefMut {
+        U32FieldRefMut::new(self.0.push("generated_lod_count"), &self.1)
+    }
+
+    pub fn generated_lod_target_error(self: &'a Self) -> F32FieldRefMut {
+        F32FieldRefMut::new(self.0.push("generated_lod_target_error"), &self.1)
+    }
+
+    pub fn generated_lod_triangle_ratio(self: &'a Self) -> F32FieldRefMut {
+        F32FieldRefMut::new(self.0.push("generated_lod_triangle_ratio"), &self.1)
+    }
+
     pub fn material_slots(self: &'a Self) -> DynamicArrayFieldRefMut::<AssetRefFieldRefMut> {
         DynamicArrayFieldRefMut::<AssetRefFieldRefMut>::new(self.0.push("material_slots"), &self.1)
     }
+
+    pub fn optimize_overdraw(self: &'a Self) -> BooleanFieldRefMut {
+        BooleanFieldRefMut::new(self.0.push("optimize_overdraw"), &self.1)
+    }
 }
 pub struct MeshAdvMeshAssetRecord(PropertyPath, Rc<RefCell<Option<DataContainer>>>);
 
@@ -963,9 +1023,29 @@ impl Record for MeshAdvMeshAssetRecord {
 }
 
 impl MeshAdvMeshAssetRecord {
+    pub fn generate_meshlets(self: &Self) -> BooleanField {
+        BooleanField::new(self.0.push("generate_meshlets"), &self.1)
+    }
+
+    pub fn generated_lod_count(self: &Self) -> U32Field {
+        U32Field::new(self.0.push("generated_lod_count"), &self.1)
+    }
+
+    pub fn generated_lod_target_error(self: &Self) -> F32Field {
+        F32Field::new(self.0.push("generated_lod_target_error"), &self.1)
+    }
+
+    pub fn generated_lod_triangle_ratio(self: &Self) -> F32Field {
+        F32Field::new(self.0.push("generated_lod_triangle_ratio"), &self.1)
+    }
+
     pub fn material_slots(self: &Self) -> DynamicArrayField::<AssetRefField> {
         DynamicArrayField::<AssetRefField>::new(self.0.push("material_slots"), &self.1)
     }
+
+    pub fn optimize_overdraw(self: &Self) -> BooleanField {
+        BooleanField::new(self.0.push("optimize_overdraw"), &self.1)
+    }
 }
 #[derive(Default)]
 pub struct MeshAdvMeshImportedDataAccessor(PropertyPath);
@@ -986,6 +1066,10 @@ impl MeshAdvMeshImportedDataAccessor {
     pub fn mesh_parts(&self) -> DynamicArrayFieldAccessor::<MeshAdvMeshImportedDataMeshPartAccessor> {
         DynamicArrayFieldAccessor::<MeshAdvMeshImportedDataMeshPartAccessor>::new(self.0.push("mesh_parts"))
     }
+
+    pub fn skin_joints(&self) -> DynamicArrayFieldAccessor::<MeshAdvMeshImportedDataSkinJointAccessor> {
+        DynamicArrayFieldAccessor::<MeshAdvMeshImportedDataSkinJointAccessor>::new(self.0.push("skin_joints"))
+    }
 }
 pub struct MeshAdvMeshImportedDataRef<'a>(PropertyPath, DataContainerRef<'a>);
 
@@ -1005,6 +1089,10 @@ impl<'a> MeshAdvMeshImportedDataRef<'a> {
     pub fn mesh_parts(&self) -> DynamicArrayFieldRef::<MeshAdvMeshImportedDataMeshPartRef> {
         DynamicArrayFieldRef::<MeshAdvMeshImportedDataMeshPartRef>::new(self.0.push("mesh_parts"), self.1.clone())
     }
+
+    pub fn skin_joints(&self) -> DynamicArrayFieldRef::<MeshAdvMeshImportedDataSkinJointRef> {
+        DynamicArrayFieldRef::<MeshAdvMeshImportedDataSkinJointRef>::new(self.0.push("skin_joints"), self.1.clone())
+    }
 }
 pub struct MeshAdvMeshImportedDataRefMut<'a>(PropertyPath, Rc<RefCell<DataContainerRefMut<'a>>>);
 
@@ -1024,6 +1112,10 @@ impl<'a> MeshAdvMeshImportedDataRefMut<'a> {
     pub fn mesh_parts(self: &'a Self) -> DynamicArrayFieldRefMut::<MeshAdvMeshImportedDataMeshPartRefMut> {
         DynamicArrayFieldRefMut::<MeshAdvMeshImportedDataMeshPartRefMut>::new(self.0.push("mesh_parts"), &self.1)
     }
+
+    pub fn skin_joints(self: &'a Self) -> DynamicArrayFieldRefMut::<MeshAdvMeshImportedDataSkinJointRefMut> {
+        DynamicArrayFieldRefMut::<MeshAdvMeshImportedDataSkinJointRefMut>::new(self.0.push("skin_joints"), &self.1)
+    }
 }
 pub struct MeshAdvMeshImportedDataRecord(PropertyPath, Rc<RefCell<Option<DataContainer>>>);
 
@@ -1047,6 +1139,10 @@ impl MeshAdvMeshImportedDataRecord {
     pub fn mesh_parts(self: &Self) -> DynamicArrayField::<MeshAdvMeshImportedDataMeshPartRecord> {
         DynamicArrayField::<MeshAdvMeshImportedDataMeshPartRecord>::new(self.0.push("mesh_parts"), &self.1)
     }
+
+    pub fn skin_joints(self: &Self) -> DynamicArrayField::<MeshAdvMeshImportedDataSkinJointRecord> {
+        DynamicArrayField::<MeshAdvMeshImportedDataSkinJointRecord>::new(self.0.push("skin_joints"), &self.1)
+    }
 }
 #[derive(Default)]
 pub struct MeshAdvMeshImportedDataMeshPartAccessor(PropertyPath);
@@ -1068,6 +1164,14 @@ impl MeshAdvMeshImportedDataMeshPartAccessor {
         BytesFieldAccessor::new(self.0.push("indices"))
     }
 
+    pub fn joint_indices(&self) -> BytesFieldAccessor {
+        BytesFieldAccessor::new(self.0.push("joint_indices"))
+    }
+
+    pub fn joint_weights(&self) -> BytesFieldAccessor {
+        BytesFieldAccessor::new(self.0.push("joint_weights"))
+    }
+
     pub fn material_index(&self) -> U32FieldAccessor {
         U32FieldAccessor::new(self.0.push("material_index"))
     }
@@ -1103,6 +1207,14 @@ impl<'a> MeshAdvMeshImportedDataMeshPartRef<'a> {
         BytesFieldRef::new(self.0.push("indices"), self.1.clone())
     }
 
+    pub fn joint_indices(&self) -> BytesFieldRef {
+        BytesFieldRef::new(self.0.push("joint_indices"), self.1.clone())
+    }
+
+    pub fn joint_weights(&self) -> BytesFieldRef {
+        BytesFieldRef::new(self.0.push("joint_weights"), self.1.clone())
+    }
+
     pub fn material_index(&self) -> U32FieldRef {
         U32FieldRef::new(self.0.push("material_index"), self.1.clone())
     }
@@ -1138,6 +1250,14 @@ impl<'a> MeshAdvMeshImportedDataMeshPartRefMut<'a> {
         BytesFieldRefMut::new(self.0.push("indices"), &self.1)
     }
 
+    pub fn joint_indices(self: &'a Self) -> BytesFieldRefMut {
+        BytesFieldRefMut::new(self.0.push("joint_indices"), &self.1)
+    }
+
+    pub fn joint_weights(self: &'a Self) -> BytesFieldRefMut {
+        BytesFieldRefMut::new(self.0.push("joint_weights"), &self.1)
+    }
+
     pub fn material_index(self: &'a Self) -> U32FieldRefMut {
         U32FieldRefMut::new(self.0.push("material_index"), &self.1)
     }
@@ -1177,6 +1297,14 @@ impl MeshAdvMeshImportedDataMeshPartRecord {
         BytesField::new(self.0.push("indices"), &self.1)
     }
 
+    pub fn joint_indices(self: &Self) -> BytesField {
+        BytesField::new(self.0.push("joint_indices"), &self.1)
+    }
+
+    pub fn joint_weights(self: &Self) -> BytesField {
+        BytesField::new(self.0.push("joint_weights"), &self.1)
+    }
+
     pub fn material_index(self: &Self) -> U32Field {
         U32Field::new(self.0.push("material_index"), &self.1)
     }
@@ -1194,6 +1322,103 @@ impl MeshAdvMeshImportedDataMeshPartRecord {
     }
 }
 #[derive(Default)]
+pub struct MeshAdvMeshImportedDataSkinJointAccessor(PropertyPath);
+
+impl FieldAccessor for MeshAdvMeshImportedDataSkinJointAccessor {
+    fn new(property_path: PropertyPath) -> Self {
+        MeshAdvMeshImportedDataSkinJointAccessor(property_path)
+    }
+}
+
+impl RecordAccessor for MeshAdvMeshImportedDataSkinJointAccessor {
+    fn schema_name() -> &'static str {
+        "MeshAdvMeshImportedDataSkinJoint"
+    }
+}
+
+impl MeshAdvMeshImportedDataSkinJointAccessor {
+    pub fn inverse_bind_matrix(&self) -> BytesFieldAccessor {
+        BytesFieldAccessor::new(self.0.push("inverse_bind_matrix"))
+    }
+
+    pub fn name(&self) -> StringFieldAccessor {
+        StringFieldAccessor::new(self.0.push("name"))
+    }
+}
+pub struct MeshAdvMeshImportedDataSkinJointRef<'a>(PropertyPath, DataContainerRef<'a>);
+
+impl<'a> FieldRef<'a> for MeshAdvMeshImportedDataSkinJointRef<'a> {
+    fn new(property_path: PropertyPath, data_container: DataContainerRef<'a>) -> Self {
+        MeshAdvMeshImportedDataSkinJointRef(property_path, data_container)
+    }
+}
+
+impl<'a> RecordRef for MeshAdvMeshImportedDataSkinJointRef<'a> {
+    fn schema_name() -> &'static str {
+        "MeshAdvMeshImportedDataSkinJoint"
+    }
+}
+
+impl<'a> MeshAdvMeshImportedDataSkinJointRef<'a> {
+    pub fn inverse_bind_matrix(&self) -> BytesFieldRef {
+        BytesFieldRef::new(self.0.push("inverse_bind_matrix"), self.1.clone())
+    }
+
+    pub fn name(&self) -> StringFieldRef {
+        StringFieldRef::new(self.0.push("name"), self.1.clone())
+    }
+}
+pub struct MeshAdvMeshImportedDataSkinJointRefMut<'a>(PropertyPath, Rc<RefCell<DataContainerRefMut<'a>>>);
+
+impl<'a> FieldRefMut<'a> for MeshAdvMeshImportedDataSkinJointRefMut<'a> {
+    fn new(property_path: PropertyPath, data_container: &Rc<RefCell<DataContainerRefMut<'a>>>) -> Self {
+        MeshAdvMeshImportedDataSkinJointRefMut(property_path, data_container.clone())
+    }
+}
+
+impl<'a> RecordRefMut for MeshAdvMeshImportedDataSkinJointRefMut<'a> {
+    fn schema_name() -> &'static str {
+        "MeshAdvMeshImportedDataSkinJoint"
+    }
+}
+
+impl<'a> MeshAdvMeshImportedDataSkinJointRefMut<'a> {
+    pub fn inverse_bind_matrix(self: &'a Self) -> BytesFieldRefMut {
+        BytesFieldRefMut::new(self.0.push("inverse_bind_matrix"), &self.1)
+    }
+
+    pub fn name(self: &'a Self) -> StringFieldRefMut {
+        StringFieldRefMut::new(self.0.push("name"), &self.1)
+    }
+}
+pub struct MeshAdvMeshImportedDataSkinJointRecord(PropertyPath, Rc<RefCell<Option<DataContainer>>>);
+
+impl Field for MeshAdvMeshImportedDataSkinJointRecord {
+    fn new(property_path: PropertyPath, data_container: &Rc<RefCell<Option<DataContainer>>>) -> Self {
+        MeshAdvMeshImportedDataSkinJointRecord(property_path, data_container.clone())
+    }
+}
+
+impl Record for MeshAdvMeshImportedDataSkinJointRecord {
+    type Reader<'a> = MeshAdvMeshImportedDataSkinJointRef<'a>;
+    type Writer<'a> = MeshAdvMeshImportedDataSkinJointRefMut<'a>;
+    type Accessor = MeshAdvMeshImportedDataSkinJointAccessor;
+
+    fn schema_name() -> &'static str {
+        "MeshAdvMeshImportedDataSkinJoint"
+    }
+}
+
+impl MeshAdvMeshImportedDataSkinJointRecord {
+    pub fn inverse_bind_matrix(self: &Self) -> BytesField {
+        BytesField::new(self.0.push("inverse_bind_matrix"), &self.1)
+    }
+
+    pub fn name(self: &Self) -> StringField {
+        StringField::new(self.0.push("name"), &self.1)
+    }
+}
+#[derive(Default)]
 pub struct MeshAdvModelAssetAccessor(PropertyPath);
 
 impl FieldAccessor for MeshAdvModelAssetAccessor {
@@ -1509,6 +1734,8 @@ impl MeshAdvPrefabImportDataRecord {
 pub enum MeshAdvShadowMethodEnum {
     None,
     Opaque,
+    AlphaClip,
+    AlphaStochastic,
 }
 
 impl Enum for MeshAdvShadowMethodEnum {
@@ -1516,6 +1743,8 @@ impl Enum for MeshAdvShadowMethodEnum {
         match self {
             MeshAdvShadowMethodEnum::None => "None",
             MeshAdvShadowMethodEnum::Opaque => "Opaque",
+            MeshAdvShadowMethodEnum::AlphaClip => "AlphaClip",
+            MeshAdvShadowMethodEnum::AlphaStochastic => "AlphaStochastic",
         }
     }
 
@@ -1525,6 +1754,12 @@ impl Enum for MeshAdvShadowMethodEnum {
             "NONE" => Some(MeshAdvShadowMethodEnum::None),
             "Opaque" => Some(MeshAdvShadowMethodEnum::Opaque),
             "OPAQUE" => Some(MeshAdvShadowMethodEnum::Opaque),
+            "AlphaClip" => Some(MeshAdvShadowMethodEnum::AlphaClip),
+            "ALPHA_CLIP" => Some(MeshAdvShadowMethodEnum::AlphaClip),
+            "CLIP" => Some(MeshAdvShadowMethodEnum::AlphaClip),
+            "AlphaStochastic" => Some(MeshAdvShadowMethodEnum::AlphaStochastic),
+            "ALPHA_STOCHASTIC" => Some(MeshAdvShadowMethodEnum::AlphaStochastic),
+            "HASHED" => Some(MeshAdvShadowMethodEnum::AlphaStochastic),
             _ => None,
         }
     }
@@ -1535,3 +1770,308 @@ impl MeshAdvShadowMethodEnum {
         "MeshAdvShadowMethod"
     }
 }
+#[derive(Default)]
+pub struct ParticleEmitterAssetAccessor(PropertyPath);
+
+impl FieldAccessor for ParticleEmitterAssetAccessor {
+    fn new(property_path: PropertyPath) -> Self {
+        ParticleEmitterAssetAccessor(property_path)
+    }
+}
+
+impl RecordAccessor for ParticleEmitterAssetAccessor {
+    fn schema_name() -> &'static str {
+        "ParticleEmitterAsset"
+    }
+}
+
+impl ParticleEmitterAssetAccessor {
+    pub fn drag(&self) -> F32FieldAccessor {
+        F32FieldAccessor::new(self.0.push("drag"))
+    }
+
+    pub fn end_color(&self) -> Vec4Accessor {
+        Vec4Accessor::new(self.0.push("end_color"))
+    }
+
+    pub fn end_size(&self) -> F32FieldAccessor {
+        F32FieldAccessor::new(self.0.push("end_size"))
+    }
+
+    pub fn gravity(&self) -> Vec3Accessor {
+        Vec3Accessor::new(self.0.push("gravity"))
+    }
+
+    pub fn initial_velocity(&self) -> Vec3Accessor {
+        Vec3Accessor::new(self.0.push("initial_velocity"))
+    }
+
+    pub fn max_lifetime(&self) -> F32FieldAccessor {
+        F32FieldAccessor::new(self.0.push("max_lifetime"))
+    }
+
+    pub fn max_particles(&self) -> U32FieldAccessor {
+        U32FieldAccessor::new(self.0.push("max_particles"))
+    }
+
+    pub fn min_lifetime(&self) -> F32FieldAccessor {
+        F32FieldAccessor::new(self.0.push("min_lifetime"))
+    }
+
+    pub fn soft_particle_distance(&self) -> F32FieldAccessor {
+        F32FieldAccessor::new(self.0.push("soft_particle_distance"))
+    }
+
+    pub fn spawn_radius(&self) -> F32FieldAccessor {
+        F32FieldAccessor::new(self.0.push("spawn_radius"))
+    }
+
+    pub fn spawn_rate(&self) -> F32FieldAccessor {
+        F32FieldAccessor::new(self.0.push("spawn_rate"))
+    }
+
+    pub fn start_color(&self) -> Vec4Accessor {
+        Vec4Accessor::new(self.0.push("start_color"))
+    }
+
+    pub fn start_size(&self) -> F32FieldAccessor {
+        F32FieldAccessor::new(self.0.push("start_size"))
+    }
+
+    pub fn texture(&self) -> AssetRefFieldAccessor {
+        AssetRefFieldAccessor::new(self.0.push("texture"))
+    }
+
+    pub fn velocity_randomness(&self) -> F32FieldAccessor {
+        F32FieldAccessor::new(self.0.push("velocity_randomness"))
+    }
+}
+pub struct ParticleEmitterAssetRef<'a>(PropertyPath, DataContainerRef<'a>);
+
+impl<'a> FieldRef<'a> for ParticleEmitterAssetRef<'a> {
+    fn new(property_path: PropertyPath, data_container: DataContainerRef<'a>) -> Self {
+        ParticleEmitterAssetRef(property_path, data_container)
+    }
+}
+
+impl<'a> RecordRef for ParticleEmitterAssetRef<'a> {
+    fn schema_name() -> &'static str {
+        "ParticleEmitterAsset"
+    }
+}
+
+impl<'a> ParticleEmitterAssetRef<'a> {
+    pub fn drag(&self) -> F32FieldRef {
+        F32FieldRef::new(self.0.push("drag"), self.1.clone())
+    }
+
+    pub fn end_color(&self) -> Vec4Ref {
+        Vec4Ref::new(self.0.push("end_color"), self.1.clone())
+    }
+
+    pub fn end_size(&self) -> F32FieldRef {
+        F32FieldRef::new(self.0.push("end_size"), self.1.clone())
+    }
+
+    pub fn gravity(&self) -> Vec3Ref {
+        Vec3Ref::new(self.0.push("gravity"), self.1.clone())
+    }
+
+    pub fn initial_velocity(&self) -> Vec3Ref {
+        Vec3Ref::new(self.0.push("initial_velocity"), self.1.clone())
+    }
+
+    pub fn max_lifetime(&self) -> F32FieldRef {
+        F32FieldRef::new(self.0.push("max_lifetime"), self.1.clone())
+    }
+
+    pub fn max_particles(&self) -> U32FieldRef {
+        U32FieldRef::new(self.0.push("max_particles"), self.1.clone())
+    }
+
+    pub fn min_lifetime(&self) -> F32FieldRef {
+        F32FieldRef::new(self.0.push("min_lifetime"), self.1.clone())
+    }
+
+    pub fn soft_particle_distance(&self) -> F32FieldRef {
+        F32FieldRef::new(self.0.push("soft_particle_distance"), self.1.clone())
+    }
+
+    pub fn spawn_radius(&self) -> F32FieldRef {
+        F32FieldRef::new(self.0.push("spawn_radius"), self.1.clone())
+    }
+
+    pub fn spawn_rate(&self) -> F32FieldRef {
+        F32FieldRef::new(self.0.push("spawn_rate"), self.1.clone())
+    }
+
+    pub fn start_color(&self) -> Vec4Ref {
+        Vec4Ref::new(self.0.push("start_color"), self.1.clone())
+    }
+
+    pub fn start_size(&self) -> F32FieldRef {
+        F32FieldRef::new(self.0.push("start_size"), self.1.clone())
+    }
+
+    pub fn texture(&self) -> AssetRefFieldRef {
+        AssetRefFieldRef::new(self.0.push("texture"), self.1.clone())
+    }
+
+    pub fn velocity_randomness(&self) -> F32FieldRef {
+        F32FieldRef::new(self.0.push("velocity_randomness"), self.1.clone())
+    }
+}
+pub struct ParticleEmitterAssetRefMut<'a>(PropertyPath, Rc<RefCell<DataContainerRefMut<'a>>>);
+
+impl<'a> FieldRefMut<'a> for ParticleEmitterAssetRefMut<'a> {
+    fn new(property_path: PropertyPath, data_container: &Rc<RefCell<DataContainerRefMut<'a>>>) -> Self {
+        ParticleEmitterAssetRefMut(property_path, data_container.clone())
+    }
+}
+
+impl<'a> RecordRefMut for ParticleEmitterAssetRefMut<'a> {
+    fn schema_name() -> &'static str {
+        "ParticleEmitterAsset"
+    }
+}
+
+impl<'a> ParticleEmitterAssetRefMut<'a> {
+    pub fn drag(self: &'a Self) -> F32FieldRefMut {
+        F32FieldRefMut::new(self.0.push("drag"), &self.1)
+    }
+
+    pub fn end_color(self: &'a Self) -> Vec4RefMut {
+        Vec4RefMut::new(self.0.push("end_color"), &self.1)
+    }
+
+    pub fn end_size(self: &'a Self) -> F32FieldRefMut {
+        F32FieldRefMut::new(self.0.push("end_size"), &self.1)
+    }
+
+    pub fn gravity(self: &'a Self) -> Vec3RefMut {
+        Vec3RefMut::new(self.0.push("gravity"), &self.1)
+    }
+
+    pub fn initial_velocity(self: &'a Self) -> Vec3RefMut {
+        Vec3RefMut::new(self.0.push("initial_velocity"), &self.1)
+    }
+
+    pub fn max_lifetime(self: &'a Self) -> F32FieldRefMut {
+        F32FieldRefMut::new(self.0.push("max_lifetime"), &self.1)
+    }
+
+    pub fn max_particles(self: &'a Self) -> U32FieldRefMut {
+        U32FieldRefMut::new(self.0.push("max_particles"), &self.1)
+    }
+
+    pub fn min_lifetime(self: &'a Self) -> F32FieldRefMut {
+        F32FieldRefMut::new(self.0.push("min_lifetime"), &self.1)
+    }
+
+    pub fn soft_particle_distance(self: &'a Self) -> F32FieldRefMut {
+        F32FieldRefMut::new(self.0.push("soft_particle_distance"), &self.1)
+    }
+
+    pub fn spawn_radius(self: &'a Self) -> F32FieldRefMut {
+        F32FieldRefMut::new(self.0.push("spawn_radius"), &self.1)
+    }
+
+    pub fn spawn_rate(self: &'a Self) -> F32FieldRefMut {
+        F32FieldRefMut::new(self.0.push("spawn_rate"), &self.1)
+    }
+
+    pub fn start_color(self: &'a Self) -> Vec4RefMut {
+        Vec4RefMut::new(self.0.push("start_color"), &self.1)
+    }
+
+    pub fn start_size(self: &'a Self) -> F32FieldRefMut {
+        F32FieldRefMut::new(self.0.push("start_size"), &self.1)
+    }
+
+    pub fn texture(self: &'a Self) -> AssetRefFieldRefMut {
+        AssetRefFieldRefMut::new(self.0.push("texture"), &self.1)
+    }
+
+    pub fn velocity_randomness(self: &'a Self) -> F32FieldRefMut {
+        F32FieldRefMut::new(self.0.push("velocity_randomness"), &self.1)
+    }
+}
+pub struct ParticleEmitterAssetRecord(PropertyPath, Rc<RefCell<Option<DataContainer>>>);
+
+impl Field for ParticleEmitterAssetRecord {
+    fn new(property_path: PropertyPath, data_container: &Rc<RefCell<Option<DataContainer>>>) -> Self {
+        ParticleEmitterAssetRecord(property_path, data_container.clone())
+    }
+}
+
+impl Record for ParticleEmitterAssetRecord {
+    type Reader<'a> = ParticleEmitterAssetRef<'a>;
+    type Writer<'a> = ParticleEmitterAssetRefMut<'a>;
+    type Accessor = ParticleEmitterAssetAccessor;
+
+    fn schema_name() -> &'static str {
+        "ParticleEmitterAsset"
+    }
+}
+
+impl ParticleEmitterAssetRecord {
+    pub fn drag(self: &Self) -> F32Field {
+        F32Field::new(self.0.push("drag"), &self.1)
+    }
+
+    pub fn end_color(self: &Self) -> Vec4Record {
+        Vec4Record::new(self.0.push("end_color"), &self.1)
+    }
+
+    pub fn end_size(self: &Self) -> F32Field {
+        F32Field::new(self.0.push("end_size"), &self.1)
+    }
+
+    pub fn gravity(self: &Self) -> Vec3Record {
+        Vec3Record::new(self.0.push("gravity"), &self.1)
+    }
+
+    pub fn initial_velocity(self: &Self) -> Vec3Record {
+        Vec3Record::new(self.0.push("initial_velocity"), &self.1)
+    }
+
+    pub fn max_lifetime(self: &Self) -> F32Field {
+        F32Field::new(self.0.push("max_lifetime"), &self.1)
+    }
+
+    pub fn max_particles(self: &Self) -> U32Field {
+        U32Field::new(self.0.push("max_particles"), &self.1)
+    }
+
+    pub fn min_lifetime(self: &Self) -> F32Field {
+        F32Field::new(self.0.push("min_lifetime"), &self.1)
+    }
+
+    pub fn soft_particle_distance(self: &Self) -> F32Field {
+        F32Field::new(self.0.push("soft_particle_distance"), &self.1)
+    }
+
+    pub fn spawn_radius(self: &Self) -> F32Field {
+        F32Field::new(self.0.push("spawn_radius"), &self.1)
+    }
+
+    pub fn spawn_rate(self: &Self) -> F32Field {
+        F32Field::new(self.0.push("spawn_rate"), &self.1)
+    }
+
+    pub fn start_color(self: &Self) -> Vec4Record {
+        Vec4Record::new(self.0.push("start_color"), &self.1)
+    }
+
+    pub fn start_size(self: &Self) -> F32Field {
+        F32Field::new(self.0.push("start_size"), &self.1)
+    }
+
+    pub fn texture(self: &Self) -> AssetRefField {
+        AssetRefField::new(self.0.push("texture"), &self.1)
+    }
+
+    pub fn velocity_randomness(self: &Self) -> F32Field {
+        F32Field::new(self.0.push("velocity_randomness"), &self.1)
+    }
+}
@@ -0,0 +1,430 @@
+// This code is auto-generated by the shader processor.
+
+#[allow(unused_imports)]
+use rafx::RafxResult;
+
+#[allow(unused_imports)]
+use rafx::framework::{
+    DescriptorSetAllocator, DescriptorSetArc, DescriptorSetBindings, DescriptorSetInitializer,
+    DescriptorSetWriter, DescriptorSetWriterContext, DynDescriptorSet, ImageViewResource,
+    ResourceArc,
+};
+
+#[derive(Copy, Clone, Debug)]
+#[repr(C)]
+pub struct PerViewDataStd140 {
+    pub view: [[f32; 4]; 4],              // +0 (size: 64)
+    pub view_proj: [[f32; 4]; 4],         // +64 (size: 64)
+    pub inverse_view_proj: [[f32; 4]; 4], // +128 (size: 64)
+    pub jitter_amount: [f32; 2],          // +192 (size: 8)
+    pub _padding0: [u8; 8],               // +200 (size: 8)
+} // 208 bytes
+
+impl Default for PerViewDataStd140 {
+    fn default() -> Self {
+        PerViewDataStd140 {
+            view: <[[f32; 4]; 4]>::default(),
+            view_proj: <[[f32; 4]; 4]>::default(),
+            inverse_view_proj: <[[f32; 4]; 4]>::default(),
+            jitter_amount: <[f32; 2]>::default(),
+            _padding0: [u8::default(); 8],
+        }
+    }
+}
+
+pub type PerViewDataUniform = PerViewDataStd140;
+
+#[derive(Copy, Clone, Debug)]
+#[repr(C)]
+pub struct PerDecalDataStd140 {
+    pub model: [[f32; 4]; 4],         // +0 (size: 64)
+    pub inverse_model: [[f32; 4]; 4], // +64 (size: 64)
+    pub tint: [f32; 4],               // +128 (size: 16)
+    pub has_albedo_texture: u32,      // +144 (size: 4)
+    pub has_normal_texture: u32,      // +148 (size: 4)
+    pub has_roughness_texture: u32,   // +152 (size: 4)
+    pub _padding0: [u8; 4],           // +156 (size: 4)
+} // 160 bytes
+
+impl Default for PerDecalDataStd140 {
+    fn default() -> Self {
+        PerDecalDataStd140 {
+            model: <[[f32; 4]; 4]>::default(),
+            inverse_model: <[[f32; 4]; 4]>::default(),
+            tint: <[f32; 4]>::default(),
+            has_albedo_texture: <u32>::default(),
+            has_normal_texture: <u32>::default(),
+            has_roughness_texture: <u32>::default(),
+            _padding0: [u8::default(); 4],
+        }
+    }
+}
+
+pub type PerDecalDataUniform = PerDecalDataStd140;
+
+pub const PER_VIEW_DATA_DESCRIPTOR_SET_INDEX: usize = 0;
+pub const PER_VIEW_DATA_DESCRIPTOR_BINDING_INDEX: usize = 0;
+pub const SMP_DESCRIPTOR_SET_INDEX: usize = 0;
+pub const SMP_DESCRIPTOR_BINDING_INDEX: usize = 1;
+pub const SMP_DEPTH_DESCRIPTOR_SET_INDEX: usize = 0;
+pub const SMP_DEPTH_DESCRIPTOR_BINDING_INDEX: usize = 2;
+pub const DEPTH_TEXTURE_DESCRIPTOR_SET_INDEX: usize = 1;
+pub const DEPTH_TEXTURE_DESCRIPTOR_BINDING_INDEX: usize = 0;
+pub const PER_DECAL_DATA_DESCRIPTOR_SET_INDEX: usize = 2;
+pub const PER_DECAL_DATA_DESCRIPTOR_BINDING_INDEX: usize = 0;
+pub const ALBEDO_TEXTURE_DESCRIPTOR_SET_INDEX: usize = 2;
+pub const ALBEDO_TEXTURE_DESCRIPTOR_BINDING_INDEX: usize = 1;
+pub const NORMAL_TEXTURE_DESCRIPTOR_SET_INDEX: usize = 2;
+pub const NORMAL_TEXTURE_DESCRIPTOR_BINDING_INDEX: usize = 2;
+pub const ROUGHNESS_TEXTURE_DESCRIPTOR_SET_INDEX: usize = 2;
+pub const ROUGHNESS_TEXTURE_DESCRIPTOR_BINDING_INDEX: usize = 3;
+
+pub struct DescriptorSet0Args<'a> {
+    pub per_view_data: &'a PerViewDataUniform,
+}
+
+impl<'a> DescriptorSetInitializer<'a> for DescriptorSet0Args<'a> {
+    type Output = DescriptorSet0;
+
+    fn create_dyn_descriptor_set(
+        descriptor_set: DynDescriptorSet,
+        args: Self,
+    ) -> Self::Output {
+        let mut descriptor = DescriptorSet0(descriptor_set);
+        descriptor.set_args(args);
+        descriptor
+    }
+
+    fn create_descriptor_set(
+        descriptor_set_allocator: &mut DescriptorSetAllocator,
+        descriptor_set: DynDescriptorSet,
+        args: Self,
+    ) -> RafxResult<DescriptorSetArc> {
+        let mut descriptor = Self::create_dyn_descriptor_set(descriptor_set, args);
+        descriptor.0.flush(descriptor_set_allocator)?;
+        Ok(descriptor.0.descriptor_set().clone())
+    }
+}
+
+impl<'a> DescriptorSetWriter<'a> for DescriptorSet0Args<'a> {
+    fn write_to(
+        descriptor_set: &mut DescriptorSetWriterContext,
+        args: Self,
+    ) {
+        descriptor_set.set_buffer_data(
+            PER_VIEW_DATA_DESCRIPTOR_BINDING_INDEX as u32,
+            args.per_view_data,
+        );
+    }
+}
+
+pub struct DescriptorSet0(pub DynDescriptorSet);
+
+impl DescriptorSet0 {
+    pub fn set_args_static(
+        descriptor_set: &mut DynDescriptorSet,
+        args: DescriptorSet0Args,
+    ) {
+        descriptor_set.set_buffer_data(
+            PER_VIEW_DATA_DESCRIPTOR_BINDING_INDEX as u32,
+            args.per_view_data,
+        );
+    }
+
+    pub fn set_args(
+        &mut self,
+        args: DescriptorSet0Args,
+    ) {
+        self.set_per_view_data(args.per_view_data);
+    }
+
+    pub fn set_per_view_data(
+        &mut self,
+        per_view_data: &PerViewDataUniform,
+    ) {
+        self.0
+            .set_buffer_data(PER_VIEW_DATA_DESCRIPTOR_BINDING_INDEX as u32, per_view_data);
+    }
+
+    pub fn flush(
+        &mut self,
+        descriptor_set_allocator: &mut DescriptorSetAllocator,
+    ) -> RafxResult<()> {
+        self.0.flush(descriptor_set_allocator)
+    }
+}
+
+pub struct DescriptorSet1Args<'a> {
+    pub depth_texture: &'a ResourceArc<ImageViewResource>,
+}
+
+impl<'a> DescriptorSetInitializer<'a> for DescriptorSet1Args<'a> {
+    type Output = DescriptorSet1;
+
+    fn create_dyn_descriptor_set(
+        descriptor_set: DynDescriptorSet,
+        args: Self,
+    ) -> Self::Output {
+        let mut descriptor = DescriptorSet1(descriptor_set);
+        descriptor.set_args(args);
+        descriptor
+    }
+
+    fn create_descriptor_set(
+        descriptor_set_allocator: &mut DescriptorSetAllocator,
+        descriptor_set: DynDescriptorSet,
+        args: Self,
+    ) -> RafxResult<DescriptorSetArc> {
+        let mut descriptor = Self::create_dyn_descriptor_set(descriptor_set, args);
+        descriptor.0.flush(descriptor_set_allocator)?;
+        Ok(descriptor.0.descriptor_set().clone())
+    }
+}
+
+impl<'a> DescriptorSetWriter<'a> for DescriptorSet1Args<'a> {
+    fn write_to(
+        descriptor_set: &mut DescriptorSetWriterContext,
+        args: Self,
+    ) {
+        descriptor_set.set_image(
+            DEPTH_TEXTURE_DESCRIPTOR_BINDING_INDEX as u32,
+            args.depth_texture,
+        );
+    }
+}
+
+pub struct DescriptorSet1(pub DynDescriptorSet);
+
+impl DescriptorSet1 {
+    pub fn set_args_static(
+        descriptor_set: &mut DynDescriptorSet,
+        args: DescriptorSet1Args,
+    ) {
+        descriptor_set.set_image(
+            DEPTH_TEXTURE_DESCRIPTOR_BINDING_INDEX as u32,
+            args.depth_texture,
+        );
+    }
+
+    pub fn set_args(
+        &mut self,
+        args: DescriptorSet1Args,
+    ) {
+        self.set_depth_texture(args.depth_texture);
+    }
+
+    pub fn set_depth_texture(
+        &mut self,
+        depth_texture: &ResourceArc<ImageViewResource>,
+    ) {
+        self.0
+            .set_image(DEPTH_TEXTURE_DESCRIPTOR_BINDING_INDEX as u32, depth_texture);
+    }
+
+    pub fn flush(
+        &mut self,
+        descriptor_set_allocator: &mut DescriptorSetAllocator,
+    ) -> RafxResult<()> {
+        self.0.flush(descriptor_set_allocator)
+    }
+}
+
+pub struct DescriptorSet2Args<'a> {
+    pub per_decal_data: &'a PerDecalDataUniform,
+    pub albedo_texture: &'a ResourceArc<ImageViewResource>,
+    pub normal_texture: &'a ResourceArc<ImageViewResource>,
+    pub roughness_texture: &'a ResourceArc<ImageViewResource>,
+}
+
+impl<'a> DescriptorSetInitializer<'a> for DescriptorSet2Args<'a> {
+    type Output = DescriptorSet2;
+
+    fn create_dyn_descriptor_set(
+        descriptor_set: DynDescriptorSet,
+        args: Self,
+    ) -> Self::Output {
+        let mut descriptor = DescriptorSet2(descriptor_set);
+        descriptor.set_args(args);
+        descriptor
+    }
+
+    fn create_descriptor_set(
+        descriptor_set_allocator: &mut DescriptorSetAllocator,
+        descriptor_set: DynDescriptorSet,
+        args: Self,
+    ) -> RafxResult<DescriptorSetArc> {
+        let mut descriptor = Self::create_dyn_descriptor_set(descriptor_set, args);
+        descriptor.0.flush(descriptor_set_allocator)?;
+        Ok(descriptor.0.descriptor_set().clone())
+    }
+}
+
+impl<'a> DescriptorSetWriter<'a> for DescriptorSet2Args<'a> {
+    fn write_to(
+        descriptor_set: &mut DescriptorSetWriterContext,
+        args: Self,
+    ) {
+        descriptor_set.set_buffer_data(
+            PER_DECAL_DATA_DESCRIPTOR_BINDING_INDEX as u32,
+            args.per_decal_data,
+        );
+        descriptor_set.set_image(
+            ALBEDO_TEXTURE_DESCRIPTOR_BINDING_INDEX as u32,
+            args.albedo_texture,
+        );
+        descriptor_set.set_image(
+            NORMAL_TEXTURE_DESCRIPTOR_BINDING_INDEX as u32,
+            args.normal_texture,
+        );
+        descriptor_set.set_image(
+            ROUGHNESS_TEXTURE_DESCRIPTOR_BINDING_INDEX as u32,
+            args.roughness_texture,
+        );
+    }
+}
+
+pub struct DescriptorSet2(pub DynDescriptorSet);
+
+impl DescriptorSet2 {
+    pub fn set_args_static(
+        descriptor_set: &mut DynDescriptorSet,
+        args: DescriptorSet2Args,
+    ) {
+        descriptor_set.set_buffer_data(
+            PER_DECAL_DATA_DESCRIPTOR_BINDING_INDEX as u32,
+            args.per_decal_data,
+        );
+        descriptor_set.set_image(
+            ALBEDO_TEXTURE_DESCRIPTOR_BINDING_INDEX as u32,
+            args.albedo_texture,
+        );
+        descriptor_set.set_image(
+            NORMAL_TEXTURE_DESCRIPTOR_BINDING_INDEX as u32,
+            args.normal_texture,
+        );
+        descriptor_set.set_image(
+            ROUGHNESS_TEXTURE_DESCRIPTOR_BINDING_INDEX as u32,
+            args.roughness_texture,
+        );
+    }
+
+    pub fn set_args(
+        &mut self,
+        args: DescriptorSet2Args,
+    ) {
+        self.set_per_decal_data(args.per_decal_data);
+        self.set_albedo_texture(args.albedo_texture);
+        self.set_normal_texture(args.normal_texture);
+        self.set_roughness_texture(args.roughness_texture);
+    }
+
+    pub fn set_per_decal_data(
+        &mut self,
+        per_decal_data: &PerDecalDataUniform,
+    ) {
+        self.0.set_buffer_data(
+            PER_DECAL_DATA_DESCRIPTOR_BINDING_INDEX as u32,
+            per_decal_data,
+        );
+    }
+
+    pub fn set_albedo_texture(
+        &mut self,
+        albedo_texture: &ResourceArc<ImageViewResource>,
+    ) {
+        self.0.set_image(
+            ALBEDO_TEXTURE_DESCRIPTOR_BINDING_INDEX as u32,
+            albedo_texture,
+        );
+    }
+
+    pub fn set_normal_texture(
+        &mut self,
+        normal_texture: &ResourceArc<ImageViewResource>,
+    ) {
+        self.0.set_image(
+            NORMAL_TEXTURE_DESCRIPTOR_BINDING_INDEX as u32,
+            normal_texture,
+        );
+    }
+
+    pub fn set_roughness_texture(
+        &mut self,
+        roughness_texture: &ResourceArc<ImageViewResource>,
+    ) {
+        self.0.set_image(
+            ROUGHNESS_TEXTURE_DESCRIPTOR_BINDING_INDEX as u32,
+            roughness_texture,
+        );
+    }
+
+    pub fn flush(
+        &mut self,
+        descriptor_set_allocator: &mut DescriptorSetAllocator,
+    ) -> RafxResult<()> {
+        self.0.flush(descriptor_set_allocator)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_struct_per_view_data_std140() {
+        assert_eq!(std::mem::size_of::<PerViewDataStd140>(), 208);
+        assert_eq!(std::mem::size_of::<[[f32; 4]; 4]>(), 64);
+        assert_eq!(std::mem::align_of::<[[f32; 4]; 4]>(), 4);
+        assert_eq!(memoffset::offset_of!(PerViewDataStd140, view), 0);
+        assert_eq!(std::mem::size_of::<[[f32; 4]; 4]>(), 64);
+        assert_eq!(std::mem::align_of::<[[f32; 4]; 4]>(), 4);
+        assert_eq!(memoffset::offset_of!(PerViewDataStd140, view_proj), 64);
+        assert_eq!(std::mem::size_of::<[[f32; 4]; 4]>(), 64);
+        assert_eq!(std::mem::align_of::<[[f32; 4]; 4]>(), 4);
+        assert_eq!(
+            memoffset::offset_of!(PerViewDataStd140, inverse_view_proj),
+            128
+        );
+        assert_eq!(std::mem::size_of::<[f32; 2]>(), 8);
+        assert_eq!(std::mem::align_of::<[f32; 2]>(), 4);
+        assert_eq!(memoffset::offset_of!(PerViewDataStd140, jitter_amount), 192);
+        assert_eq!(std::mem::size_of::<[u8; 8]>(), 8);
+        assert_eq!(std::mem::align_of::<[u8; 8]>(), 1);
+        assert_eq!(memoffset::offset_of!(PerViewDataStd140, _padding0), 200);
+    }
+
+    #[test]
+    fn test_struct_per_decal_data_std140() {
+        assert_eq!(std::mem::size_of::<PerDecalDataStd140>(), 160);
+        assert_eq!(std::mem::size_of::<[[f32; 4]; 4]>(), 64);
+        assert_eq!(std::mem::align_of::<[[f32; 4]; 4]>(), 4);
+        assert_eq!(memoffset::offset_of!(PerDecalDataStd140, model), 0);
+        assert_eq!(std::mem::size_of::<[[f32; 4]; 4]>(), 64);
+        assert_eq!(std::mem::align_of::<[[f32; 4]; 4]>(), 4);
+        assert_eq!(memoffset::offset_of!(PerDecalDataStd140, inverse_model), 64);
+        assert_eq!(std::mem::size_of::<[f32; 4]>(), 16);
+        assert_eq!(std::mem::align_of::<[f32; 4]>(), 4);
+        assert_eq!(memoffset::offset_of!(PerDecalDataStd140, tint), 128);
+        assert_eq!(std::mem::size_of::<u32>(), 4);
+        assert_eq!(std::mem::align_of::<u32>(), 4);
+        assert_eq!(
+            memoffset::offset_of!(PerDecalDataStd140, has_albedo_texture),
+            144
+        );
+        assert_eq!(std::mem::size_of::<u32>(), 4);
+        assert_eq!(std::mem::align_of::<u32>(), 4);
+        assert_eq!(
+            memoffset::offset_of!(PerDecalDataStd140, has_normal_texture),
+            148
+        );
+        assert_eq!(std::mem::size_of::<u32>(), 4);
+        assert_eq!(std::mem::align_of::<u32>(), 4);
+        assert_eq!(
+            memoffset::offset_of!(PerDecalDataStd140, has_roughness_texture),
+            152
+        );
+        assert_eq!(std::mem::size_of::<[u8; 4]>(), 4);
+        assert_eq!(std::mem::align_of::<[u8; 4]>(), 1);
+        assert_eq!(memoffset::offset_of!(PerDecalDataStd140, _padding0), 156);
+    }
+}
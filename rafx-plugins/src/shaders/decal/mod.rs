@@ -0,0 +1,6 @@
+// This code is auto-generated by the shader processor.
+
+#![allow(dead_code)]
+
+pub mod decal_frag;
+pub mod decal_vert;
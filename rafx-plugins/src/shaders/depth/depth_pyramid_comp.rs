@@ -13,11 +13,13 @@ use rafx::framework::{
 #[derive(Copy, Clone, Debug)]
 #[repr(C)]
 pub struct DepthPyramidConfigStd140 {
-    pub input_width: u32,  // +0 (size: 4)
-    pub input_height: u32, // +4 (size: 4)
-    pub odd_width: u32,    // +8 (size: 4)
-    pub odd_height: u32,   // +12 (size: 4)
-} // 16 bytes
+    pub input_width: u32,           // +0 (size: 4)
+    pub input_height: u32,          // +4 (size: 4)
+    pub odd_width: u32,             // +8 (size: 4)
+    pub odd_height: u32,            // +12 (size: 4)
+    pub src_has_nearest_depth: u32, // +16 (size: 4)
+    pub _padding0: [u8; 12],        // +20 (size: 12)
+} // 32 bytes
 
 impl Default for DepthPyramidConfigStd140 {
     fn default() -> Self {
@@ -26,6 +28,8 @@ impl Default for DepthPyramidConfigStd140 {
             input_height: <u32>::default(),
             odd_width: <u32>::default(),
             odd_height: <u32>::default(),
+            src_has_nearest_depth: <u32>::default(),
+            _padding0: [u8::default(); 12],
         }
     }
 }
@@ -152,7 +156,7 @@ mod test {
 
     #[test]
     fn test_struct_depth_pyramid_config_std140() {
-        assert_eq!(std::mem::size_of::<DepthPyramidConfigStd140>(), 16);
+        assert_eq!(std::mem::size_of::<DepthPyramidConfigStd140>(), 32);
         assert_eq!(std::mem::size_of::<u32>(), 4);
         assert_eq!(std::mem::align_of::<u32>(), 4);
         assert_eq!(
@@ -177,5 +181,17 @@ mod test {
             memoffset::offset_of!(DepthPyramidConfigStd140, odd_height),
             12
         );
+        assert_eq!(std::mem::size_of::<u32>(), 4);
+        assert_eq!(std::mem::align_of::<u32>(), 4);
+        assert_eq!(
+            memoffset::offset_of!(DepthPyramidConfigStd140, src_has_nearest_depth),
+            16
+        );
+        assert_eq!(std::mem::size_of::<[u8; 12]>(), 12);
+        assert_eq!(std::mem::align_of::<[u8; 12]>(), 1);
+        assert_eq!(
+            memoffset::offset_of!(DepthPyramidConfigStd140, _padding0),
+            20
+        );
     }
 }
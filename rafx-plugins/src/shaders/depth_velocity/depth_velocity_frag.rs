@@ -41,7 +41,8 @@ pub type TransformBuffer = TransformStd430;
 pub struct DrawDataStd140 {
     pub transform_index: u32, // +0 (size: 4)
     pub material_index: u32,  // +4 (size: 4)
-    pub _padding0: [u8; 8],   // +8 (size: 8)
+    pub lod_fade: f32,        // +8 (size: 4)
+    pub _padding0: [u8; 4],   // +12 (size: 4)
 } // 16 bytes
 
 impl Default for DrawDataStd140 {
@@ -49,7 +50,8 @@ impl Default for DrawDataStd140 {
         DrawDataStd140 {
             transform_index: <u32>::default(),
             material_index: <u32>::default(),
-            _padding0: [u8::default(); 8],
+            lod_fade: <f32>::default(),
+            _padding0: [u8::default(); 4],
         }
     }
 }
@@ -61,7 +63,8 @@ pub type DrawDataUniform = DrawDataStd140;
 pub struct DrawDataStd430 {
     pub transform_index: u32, // +0 (size: 4)
     pub material_index: u32,  // +4 (size: 4)
-} // 8 bytes
+    pub lod_fade: f32,        // +8 (size: 4)
+} // 12 bytes
 
 pub type DrawDataPushConstant = DrawDataStd430;
 
@@ -233,20 +236,26 @@ mod test {
         assert_eq!(std::mem::size_of::<u32>(), 4);
         assert_eq!(std::mem::align_of::<u32>(), 4);
         assert_eq!(memoffset::offset_of!(DrawDataStd140, material_index), 4);
-        assert_eq!(std::mem::size_of::<[u8; 8]>(), 8);
-        assert_eq!(std::mem::align_of::<[u8; 8]>(), 1);
-        assert_eq!(memoffset::offset_of!(DrawDataStd140, _padding0), 8);
+        assert_eq!(std::mem::size_of::<f32>(), 4);
+        assert_eq!(std::mem::align_of::<f32>(), 4);
+        assert_eq!(memoffset::offset_of!(DrawDataStd140, lod_fade), 8);
+        assert_eq!(std::mem::size_of::<[u8; 4]>(), 4);
+        assert_eq!(std::mem::align_of::<[u8; 4]>(), 1);
+        assert_eq!(memoffset::offset_of!(DrawDataStd140, _padding0), 12);
     }
 
     #[test]
     fn test_struct_draw_data_std430() {
-        assert_eq!(std::mem::size_of::<DrawDataStd430>(), 8);
+        assert_eq!(std::mem::size_of::<DrawDataStd430>(), 12);
         assert_eq!(std::mem::size_of::<u32>(), 4);
         assert_eq!(std::mem::align_of::<u32>(), 4);
         assert_eq!(memoffset::offset_of!(DrawDataStd430, transform_index), 0);
         assert_eq!(std::mem::size_of::<u32>(), 4);
         assert_eq!(std::mem::align_of::<u32>(), 4);
         assert_eq!(memoffset::offset_of!(DrawDataStd430, material_index), 4);
+        assert_eq!(std::mem::size_of::<f32>(), 4);
+        assert_eq!(std::mem::align_of::<f32>(), 4);
+        assert_eq!(memoffset::offset_of!(DrawDataStd430, lod_fade), 8);
     }
 
     #[test]
@@ -0,0 +1,111 @@
+// This code is auto-generated by the shader processor.
+
+#[allow(unused_imports)]
+use rafx::RafxResult;
+
+#[allow(unused_imports)]
+use rafx::framework::{
+    DescriptorSetAllocator, DescriptorSetArc, DescriptorSetBindings, DescriptorSetInitializer,
+    DescriptorSetWriter, DescriptorSetWriterContext, DynDescriptorSet, ImageViewResource,
+    ResourceArc,
+};
+
+pub const SRC_CUBEMAP_DESCRIPTOR_SET_INDEX: usize = 0;
+pub const SRC_CUBEMAP_DESCRIPTOR_BINDING_INDEX: usize = 0;
+pub const SMP_DESCRIPTOR_SET_INDEX: usize = 0;
+pub const SMP_DESCRIPTOR_BINDING_INDEX: usize = 1;
+pub const DST_CUBEMAP_DESCRIPTOR_SET_INDEX: usize = 0;
+pub const DST_CUBEMAP_DESCRIPTOR_BINDING_INDEX: usize = 2;
+
+pub struct DescriptorSet0Args<'a> {
+    pub src_cubemap: &'a ResourceArc<ImageViewResource>,
+    pub dst_cubemap: &'a ResourceArc<ImageViewResource>,
+}
+
+impl<'a> DescriptorSetInitializer<'a> for DescriptorSet0Args<'a> {
+    type Output = DescriptorSet0;
+
+    fn create_dyn_descriptor_set(
+        descriptor_set: DynDescriptorSet,
+        args: Self,
+    ) -> Self::Output {
+        let mut descriptor = DescriptorSet0(descriptor_set);
+        descriptor.set_args(args);
+        descriptor
+    }
+
+    fn create_descriptor_set(
+        descriptor_set_allocator: &mut DescriptorSetAllocator,
+        descriptor_set: DynDescriptorSet,
+        args: Self,
+    ) -> RafxResult<DescriptorSetArc> {
+        let mut descriptor = Self::create_dyn_descriptor_set(descriptor_set, args);
+        descriptor.0.flush(descriptor_set_allocator)?;
+        Ok(descriptor.0.descriptor_set().clone())
+    }
+}
+
+impl<'a> DescriptorSetWriter<'a> for DescriptorSet0Args<'a> {
+    fn write_to(
+        descriptor_set: &mut DescriptorSetWriterContext,
+        args: Self,
+    ) {
+        descriptor_set.set_image(
+            SRC_CUBEMAP_DESCRIPTOR_BINDING_INDEX as u32,
+            args.src_cubemap,
+        );
+        descriptor_set.set_image(
+            DST_CUBEMAP_DESCRIPTOR_BINDING_INDEX as u32,
+            args.dst_cubemap,
+        );
+    }
+}
+
+pub struct DescriptorSet0(pub DynDescriptorSet);
+
+impl DescriptorSet0 {
+    pub fn set_args_static(
+        descriptor_set: &mut DynDescriptorSet,
+        args: DescriptorSet0Args,
+    ) {
+        descriptor_set.set_image(
+            SRC_CUBEMAP_DESCRIPTOR_BINDING_INDEX as u32,
+            args.src_cubemap,
+        );
+        descriptor_set.set_image(
+            DST_CUBEMAP_DESCRIPTOR_BINDING_INDEX as u32,
+            args.dst_cubemap,
+        );
+    }
+
+    pub fn set_args(
+        &mut self,
+        args: DescriptorSet0Args,
+    ) {
+        self.set_src_cubemap(args.src_cubemap);
+        self.set_dst_cubemap(args.dst_cubemap);
+    }
+
+    pub fn set_src_cubemap(
+        &mut self,
+        src_cubemap: &ResourceArc<ImageViewResource>,
+    ) {
+        self.0
+            .set_image(SRC_CUBEMAP_DESCRIPTOR_BINDING_INDEX as u32, src_cubemap);
+    }
+
+    pub fn set_dst_cubemap(
+        &mut self,
+        dst_cubemap: &ResourceArc<ImageViewResource>,
+    ) {
+        self.0
+            .set_image(DST_CUBEMAP_DESCRIPTOR_BINDING_INDEX as u32, dst_cubemap);
+    }
+
+    pub fn flush(
+        &mut self,
+        descriptor_set_allocator: &mut DescriptorSetAllocator,
+    ) -> RafxResult<()> {
+        self.0.flush(descriptor_set_allocator)
+    }
+}
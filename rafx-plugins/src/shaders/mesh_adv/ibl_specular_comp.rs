@@ -0,0 +1,175 @@
+// This code is auto-generated by the shader processor.
+
+#[allow(unused_imports)]
+use rafx::RafxResult;
+
+#[allow(unused_imports)]
+use rafx::framework::{
+    DescriptorSetAllocator, DescriptorSetArc, DescriptorSetBindings, DescriptorSetInitializer,
+    DescriptorSetWriter, DescriptorSetWriterContext, DynDescriptorSet, ImageViewResource,
+    ResourceArc,
+};
+
+#[derive(Copy, Clone, Debug)]
+#[repr(C)]
+pub struct IblSpecularConfigStd140 {
+    pub roughness: f32,     // +0 (size: 4)
+    pub sample_count: u32,  // +4 (size: 4)
+    pub face_size: u32,     // +8 (size: 4)
+    pub src_face_size: f32, // +12 (size: 4)
+} // 16 bytes
+
+impl Default for IblSpecularConfigStd140 {
+    fn default() -> Self {
+        IblSpecularConfigStd140 {
+            roughness: <f32>::default(),
+            sample_count: <u32>::default(),
+            face_size: <u32>::default(),
+            src_face_size: <f32>::default(),
+        }
+    }
+}
+
+pub type IblSpecularConfigUniform = IblSpecularConfigStd140;
+
+pub const CONFIG_DESCRIPTOR_SET_INDEX: usize = 0;
+pub const CONFIG_DESCRIPTOR_BINDING_INDEX: usize = 0;
+pub const SRC_CUBEMAP_DESCRIPTOR_SET_INDEX: usize = 0;
+pub const SRC_CUBEMAP_DESCRIPTOR_BINDING_INDEX: usize = 1;
+pub const SMP_DESCRIPTOR_SET_INDEX: usize = 0;
+pub const SMP_DESCRIPTOR_BINDING_INDEX: usize = 2;
+pub const DST_CUBEMAP_DESCRIPTOR_SET_INDEX: usize = 0;
+pub const DST_CUBEMAP_DESCRIPTOR_BINDING_INDEX: usize = 3;
+
+pub struct DescriptorSet0Args<'a> {
+    pub config: &'a IblSpecularConfigUniform,
+    pub src_cubemap: &'a ResourceArc<ImageViewResource>,
+    pub dst_cubemap: &'a ResourceArc<ImageViewResource>,
+}
+
+impl<'a> DescriptorSetInitializer<'a> for DescriptorSet0Args<'a> {
+    type Output = DescriptorSet0;
+
+    fn create_dyn_descriptor_set(
+        descriptor_set: DynDescriptorSet,
+        args: Self,
+    ) -> Self::Output {
+        let mut descriptor = DescriptorSet0(descriptor_set);
+        descriptor.set_args(args);
+        descriptor
+    }
+
+    fn create_descriptor_set(
+        descriptor_set_allocator: &mut DescriptorSetAllocator,
+        descriptor_set: DynDescriptorSet,
+        args: Self,
+    ) -> RafxResult<DescriptorSetArc> {
+        let mut descriptor = Self::create_dyn_descriptor_set(descriptor_set, args);
+        descriptor.0.flush(descriptor_set_allocator)?;
+        Ok(descriptor.0.descriptor_set().clone())
+    }
+}
+
+impl<'a> DescriptorSetWriter<'a> for DescriptorSet0Args<'a> {
+    fn write_to(
+        descriptor_set: &mut DescriptorSetWriterContext,
+        args: Self,
+    ) {
+        descriptor_set.set_buffer_data(CONFIG_DESCRIPTOR_BINDING_INDEX as u32, args.config);
+        descriptor_set.set_image(
+            SRC_CUBEMAP_DESCRIPTOR_BINDING_INDEX as u32,
+            args.src_cubemap,
+        );
+        descriptor_set.set_image(
+            DST_CUBEMAP_DESCRIPTOR_BINDING_INDEX as u32,
+            args.dst_cubemap,
+        );
+    }
+}
+
+pub struct DescriptorSet0(pub DynDescriptorSet);
+
+impl DescriptorSet0 {
+    pub fn set_args_static(
+        descriptor_set: &mut DynDescriptorSet,
+        args: DescriptorSet0Args,
+    ) {
+        descriptor_set.set_buffer_data(CONFIG_DESCRIPTOR_BINDING_INDEX as u32, args.config);
+        descriptor_set.set_image(
+            SRC_CUBEMAP_DESCRIPTOR_BINDING_INDEX as u32,
+            args.src_cubemap,
+        );
+        descriptor_set.set_image(
+            DST_CUBEMAP_DESCRIPTOR_BINDING_INDEX as u32,
+            args.dst_cubemap,
+        );
+    }
+
+    pub fn set_args(
+        &mut self,
+        args: DescriptorSet0Args,
+    ) {
+        self.set_config(args.config);
+        self.set_src_cubemap(args.src_cubemap);
+        self.set_dst_cubemap(args.dst_cubemap);
+    }
+
+    pub fn set_config(
+        &mut self,
+        config: &IblSpecularConfigUniform,
+    ) {
+        self.0
+            .set_buffer_data(CONFIG_DESCRIPTOR_BINDING_INDEX as u32, config);
+    }
+
+    pub fn set_src_cubemap(
+        &mut self,
+        src_cubemap: &ResourceArc<ImageViewResource>,
+    ) {
+        self.0
+            .set_image(SRC_CUBEMAP_DESCRIPTOR_BINDING_INDEX as u32, src_cubemap);
+    }
+
+    pub fn set_dst_cubemap(
+        &mut self,
+        dst_cubemap: &ResourceArc<ImageViewResource>,
+    ) {
+        self.0
+            .set_image(DST_CUBEMAP_DESCRIPTOR_BINDING_INDEX as u32, dst_cubemap);
+    }
+
+    pub fn flush(
+        &mut self,
+        descriptor_set_allocator: &mut DescriptorSetAllocator,
+    ) -> RafxResult<()> {
+        self.0.flush(descriptor_set_allocator)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_struct_ibl_specular_config_std140() {
+        assert_eq!(std::mem::size_of::<IblSpecularConfigStd140>(), 16);
+        assert_eq!(std::mem::size_of::<f32>(), 4);
+        assert_eq!(std::mem::align_of::<f32>(), 4);
+        assert_eq!(memoffset::offset_of!(IblSpecularConfigStd140, roughness), 0);
+        assert_eq!(std::mem::size_of::<u32>(), 4);
+        assert_eq!(std::mem::align_of::<u32>(), 4);
+        assert_eq!(
+            memoffset::offset_of!(IblSpecularConfigStd140, sample_count),
+            4
+        );
+        assert_eq!(std::mem::size_of::<u32>(), 4);
+        assert_eq!(std::mem::align_of::<u32>(), 4);
+        assert_eq!(memoffset::offset_of!(IblSpecularConfigStd140, face_size), 8);
+        assert_eq!(std::mem::size_of::<f32>(), 4);
+        assert_eq!(std::mem::align_of::<f32>(), 4);
+        assert_eq!(
+            memoffset::offset_of!(IblSpecularConfigStd140, src_face_size),
+            12
+        );
+    }
+}
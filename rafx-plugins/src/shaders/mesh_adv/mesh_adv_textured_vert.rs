@@ -117,10 +117,16 @@ pub struct PerViewDataStd140 {
     pub ndf_filter_amount: f32,                              // +164 (size: 4)
     pub directional_light_count: u32,                        // +168 (size: 4)
     pub use_clustered_lighting: u32,                         // +172 (size: 4)
-    pub directional_lights: [DirectionalLightStd140; 8],     // +176 (size: 384)
-    pub shadow_map_2d_data: [ShadowMap2DDataStd140; 96],     // +560 (size: 9216)
-    pub shadow_map_cube_data: [ShadowMapCubeDataStd140; 32], // +9776 (size: 3584)
-} // 13360 bytes
+    pub ibl_intensity: f32,                                  // +176 (size: 4)
+    pub ibl_specular_max_lod: f32,                           // +180 (size: 4)
+    pub reflection_probe_max_lod: f32,                       // +184 (size: 4)
+    pub volumetric_fog_max_distance: f32,                    // +188 (size: 4)
+    pub directional_lights: [DirectionalLightStd140; 8],     // +192 (size: 768)
+    pub shadow_map_2d_data: [ShadowMap2DDataStd140; 96],     // +960 (size: 9216)
+    pub shadow_map_cube_data: [ShadowMapCubeDataStd140; 32], // +10176 (size: 3584)
+    pub enable_decals: u32,                                  // +13760 (size: 4)
+    pub _padding0: [u8; 12],                                 // +13764 (size: 12)
+} // 13776 bytes
 
 impl Default for PerViewDataStd140 {
     fn default() -> Self {
@@ -135,9 +141,15 @@ impl Default for PerViewDataStd140 {
             ndf_filter_amount: <f32>::default(),
             directional_light_count: <u32>::default(),
             use_clustered_lighting: <u32>::default(),
+            ibl_intensity: <f32>::default(),
+            ibl_specular_max_lod: <f32>::default(),
+            reflection_probe_max_lod: <f32>::default(),
+            volumetric_fog_max_distance: <f32>::default(),
             directional_lights: [<DirectionalLightStd140>::default(); 8],
             shadow_map_2d_data: [<ShadowMap2DDataStd140>::default(); 96],
             shadow_map_cube_data: [<ShadowMapCubeDataStd140>::default(); 32],
+            enable_decals: <u32>::default(),
+            _padding0: [u8::default(); 12],
         }
     }
 }
@@ -205,7 +217,8 @@ pub type TransformBuffer = TransformStd430;
 pub struct DrawDataStd140 {
     pub transform_index: u32, // +0 (size: 4)
     pub material_index: u32,  // +4 (size: 4)
-    pub _padding0: [u8; 8],   // +8 (size: 8)
+    pub lod_fade: f32,        // +8 (size: 4)
+    pub _padding0: [u8; 4],   // +12 (size: 4)
 } // 16 bytes
 
 impl Default for DrawDataStd140 {
@@ -213,7 +226,8 @@ impl Default for DrawDataStd140 {
         DrawDataStd140 {
             transform_index: <u32>::default(),
             material_index: <u32>::default(),
-            _padding0: [u8::default(); 8],
+            lod_fade: <f32>::default(),
+            _padding0: [u8::default(); 4],
         }
     }
 }
@@ -225,12 +239,31 @@ pub type DrawDataUniform = DrawDataStd140;
 pub struct DrawDataStd430 {
     pub transform_index: u32, // +0 (size: 4)
     pub material_index: u32,  // +4 (size: 4)
-} // 8 bytes
+    pub lod_fade: f32,        // +8 (size: 4)
+} // 12 bytes
 
 pub type DrawDataPushConstant = DrawDataStd430;
 
 pub type DrawDataBuffer = DrawDataStd430;
 
+#[derive(Copy, Clone, Debug)]
+#[repr(C)]
+pub struct ReflectionProbesStd430 {
+    pub probe_count: u32,                  // +0 (size: 4)
+    pub _padding0: [u8; 12],               // +4 (size: 12)
+    pub data: [ReflectionProbeStd430; 16], // +16 (size: 768)
+} // 784 bytes
+
+pub type ReflectionProbesBuffer = ReflectionProbesStd430;
+
+#[derive(Copy, Clone, Debug)]
+#[repr(C)]
+pub struct ReflectionProbeBinOutputStd430 {
+    pub data: LightBinningOutputStd430, // +0 (size: 6316048)
+} // 6316048 bytes
+
+pub type ReflectionProbeBinOutputBuffer = ReflectionProbeBinOutputStd430;
+
 #[derive(Copy, Clone, Debug)]
 #[repr(C)]
 pub struct ShadowMap2DDataStd140 {
@@ -274,12 +307,16 @@ pub type LightInListBuffer = LightInListStd430;
 #[derive(Copy, Clone, Debug)]
 #[repr(C)]
 pub struct DirectionalLightStd140 {
-    pub direction_ws: [f32; 3], // +0 (size: 12)
-    pub intensity: f32,         // +12 (size: 4)
-    pub color: [f32; 4],        // +16 (size: 16)
-    pub direction_vs: [f32; 3], // +32 (size: 12)
-    pub shadow_map: i32,        // +44 (size: 4)
-} // 48 bytes
+    pub direction_ws: [f32; 3],            // +0 (size: 12)
+    pub intensity: f32,                    // +12 (size: 4)
+    pub color: [f32; 4],                   // +16 (size: 16)
+    pub direction_vs: [f32; 3],            // +32 (size: 12)
+    pub cascade_count: u32,                // +44 (size: 4)
+    pub cascade_shadow_maps: [i32; 4],     // +48 (size: 16)
+    pub cascade_split_distances: [f32; 4], // +64 (size: 16)
+    pub cascade_blend_fraction: f32,       // +80 (size: 4)
+    pub _padding0: [u8; 12],               // +84 (size: 12)
+} // 96 bytes
 
 impl Default for DirectionalLightStd140 {
     fn default() -> Self {
@@ -288,7 +325,11 @@ impl Default for DirectionalLightStd140 {
             intensity: <f32>::default(),
             color: <[f32; 4]>::default(),
             direction_vs: <[f32; 3]>::default(),
-            shadow_map: <i32>::default(),
+            cascade_count: <u32>::default(),
+            cascade_shadow_maps: <[i32; 4]>::default(),
+            cascade_split_distances: <[f32; 4]>::default(),
+            cascade_blend_fraction: <f32>::default(),
+            _padding0: [u8::default(); 12],
         }
     }
 }
@@ -297,13 +338,16 @@ pub type DirectionalLightUniform = DirectionalLightStd140;
 
 #[derive(Copy, Clone, Debug)]
 #[repr(C)]
-pub struct AllLightsStd430 {
-    pub light_count: u32,               // +0 (size: 4)
-    pub _padding0: [u8; 12],            // +4 (size: 12)
-    pub data: [LightInListStd430; 512], // +16 (size: 40960)
-} // 40976 bytes
+pub struct ReflectionProbeStd430 {
+    pub box_min_ws: [f32; 3],  // +0 (size: 12)
+    pub blend_distance: f32,   // +12 (size: 4)
+    pub box_max_ws: [f32; 3],  // +16 (size: 12)
+    pub intensity: f32,        // +28 (size: 4)
+    pub position_ws: [f32; 3], // +32 (size: 12)
+    pub first_layer: u32,      // +44 (size: 4)
+} // 48 bytes
 
-pub type AllLightsBuffer = AllLightsStd430;
+pub type ReflectionProbeBuffer = ReflectionProbeStd430;
 
 #[derive(Copy, Clone, Debug)]
 #[repr(C)]
@@ -327,6 +371,16 @@ pub struct ClusterMetaStd430 {
 
 pub type ClusterMetaBuffer = ClusterMetaStd430;
 
+#[derive(Copy, Clone, Debug)]
+#[repr(C)]
+pub struct AllLightsStd430 {
+    pub light_count: u32,               // +0 (size: 4)
+    pub _padding0: [u8; 12],            // +4 (size: 12)
+    pub data: [LightInListStd430; 512], // +16 (size: 40960)
+} // 40976 bytes
+
+pub type AllLightsBuffer = AllLightsStd430;
+
 pub const PER_VIEW_DATA_DESCRIPTOR_SET_INDEX: usize = 0;
 pub const PER_VIEW_DATA_DESCRIPTOR_BINDING_INDEX: usize = 0;
 pub const SMP_DESCRIPTOR_SET_INDEX: usize = 0;
@@ -341,8 +395,30 @@ pub const LIGHT_BIN_OUTPUT_DESCRIPTOR_SET_INDEX: usize = 0;
 pub const LIGHT_BIN_OUTPUT_DESCRIPTOR_BINDING_INDEX: usize = 5;
 pub const ALL_LIGHTS_DESCRIPTOR_SET_INDEX: usize = 0;
 pub const ALL_LIGHTS_DESCRIPTOR_BINDING_INDEX: usize = 6;
+pub const IBL_IRRADIANCE_CUBEMAP_DESCRIPTOR_SET_INDEX: usize = 0;
+pub const IBL_IRRADIANCE_CUBEMAP_DESCRIPTOR_BINDING_INDEX: usize = 7;
+pub const IBL_SPECULAR_CUBEMAP_DESCRIPTOR_SET_INDEX: usize = 0;
+pub const IBL_SPECULAR_CUBEMAP_DESCRIPTOR_BINDING_INDEX: usize = 8;
+pub const IBL_BRDF_LUT_DESCRIPTOR_SET_INDEX: usize = 0;
+pub const IBL_BRDF_LUT_DESCRIPTOR_BINDING_INDEX: usize = 9;
+pub const SMP_IBL_DESCRIPTOR_SET_INDEX: usize = 0;
+pub const SMP_IBL_DESCRIPTOR_BINDING_INDEX: usize = 10;
+pub const REFLECTION_PROBE_BIN_OUTPUT_DESCRIPTOR_SET_INDEX: usize = 0;
+pub const REFLECTION_PROBE_BIN_OUTPUT_DESCRIPTOR_BINDING_INDEX: usize = 11;
+pub const REFLECTION_PROBES_DESCRIPTOR_SET_INDEX: usize = 0;
+pub const REFLECTION_PROBES_DESCRIPTOR_BINDING_INDEX: usize = 12;
+pub const REFLECTION_PROBE_CUBEMAPS_DESCRIPTOR_SET_INDEX: usize = 0;
+pub const REFLECTION_PROBE_CUBEMAPS_DESCRIPTOR_BINDING_INDEX: usize = 13;
 pub const SSAO_TEXTURE_DESCRIPTOR_SET_INDEX: usize = 1;
 pub const SSAO_TEXTURE_DESCRIPTOR_BINDING_INDEX: usize = 0;
+pub const VOLUMETRIC_FOG_VOLUME_DESCRIPTOR_SET_INDEX: usize = 1;
+pub const VOLUMETRIC_FOG_VOLUME_DESCRIPTOR_BINDING_INDEX: usize = 1;
+pub const DECAL_ALBEDO_TEXTURE_DESCRIPTOR_SET_INDEX: usize = 1;
+pub const DECAL_ALBEDO_TEXTURE_DESCRIPTOR_BINDING_INDEX: usize = 2;
+pub const DECAL_NORMAL_TEXTURE_DESCRIPTOR_SET_INDEX: usize = 1;
+pub const DECAL_NORMAL_TEXTURE_DESCRIPTOR_BINDING_INDEX: usize = 3;
+pub const DECAL_ROUGHNESS_TEXTURE_DESCRIPTOR_SET_INDEX: usize = 1;
+pub const DECAL_ROUGHNESS_TEXTURE_DESCRIPTOR_BINDING_INDEX: usize = 4;
 pub const ALL_TRANSFORMS_DESCRIPTOR_SET_INDEX: usize = 2;
 pub const ALL_TRANSFORMS_DESCRIPTOR_BINDING_INDEX: usize = 0;
 pub const ALL_DRAW_DATA_DESCRIPTOR_SET_INDEX: usize = 2;
@@ -357,6 +433,12 @@ pub struct DescriptorSet0Args<'a> {
     pub shadow_map_atlas: &'a ResourceArc<ImageViewResource>,
     pub light_bin_output: &'a LightBinOutputBuffer,
     pub all_lights: &'a AllLightsBuffer,
+    pub ibl_irradiance_cubemap: &'a ResourceArc<ImageViewResource>,
+    pub ibl_specular_cubemap: &'a ResourceArc<ImageViewResource>,
+    pub ibl_brdf_lut: &'a ResourceArc<ImageViewResource>,
+    pub reflection_probe_bin_output: &'a ReflectionProbeBinOutputBuffer,
+    pub reflection_probes: &'a ReflectionProbesBuffer,
+    pub reflection_probe_cubemaps: &'a ResourceArc<ImageViewResource>,
 }
 
 impl<'a> DescriptorSetInitializer<'a> for DescriptorSet0Args<'a> {
@@ -400,6 +482,30 @@ impl<'a> DescriptorSetWriter<'a> for DescriptorSet0Args<'a> {
             args.light_bin_output,
         );
         descriptor_set.set_buffer_data(ALL_LIGHTS_DESCRIPTOR_BINDING_INDEX as u32, args.all_lights);
+        descriptor_set.set_image(
+            IBL_IRRADIANCE_CUBEMAP_DESCRIPTOR_BINDING_INDEX as u32,
+            args.ibl_irradiance_cubemap,
+        );
+        descriptor_set.set_image(
+            IBL_SPECULAR_CUBEMAP_DESCRIPTOR_BINDING_INDEX as u32,
+            args.ibl_specular_cubemap,
+        );
+        descriptor_set.set_image(
+            IBL_BRDF_LUT_DESCRIPTOR_BINDING_INDEX as u32,
+            args.ibl_brdf_lut,
+        );
+        descriptor_set.set_buffer_data(
+            REFLECTION_PROBE_BIN_OUTPUT_DESCRIPTOR_BINDING_INDEX as u32,
+            args.reflection_probe_bin_output,
+        );
+        descriptor_set.set_buffer_data(
+            REFLECTION_PROBES_DESCRIPTOR_BINDING_INDEX as u32,
+            args.reflection_probes,
+        );
+        descriptor_set.set_image(
+            REFLECTION_PROBE_CUBEMAPS_DESCRIPTOR_BINDING_INDEX as u32,
+            args.reflection_probe_cubemaps,
+        );
     }
 }
 
@@ -423,6 +529,30 @@ impl DescriptorSet0 {
             args.light_bin_output,
         );
         descriptor_set.set_buffer_data(ALL_LIGHTS_DESCRIPTOR_BINDING_INDEX as u32, args.all_lights);
+        descriptor_set.set_image(
+            IBL_IRRADIANCE_CUBEMAP_DESCRIPTOR_BINDING_INDEX as u32,
+            args.ibl_irradiance_cubemap,
+        );
+        descriptor_set.set_image(
+            IBL_SPECULAR_CUBEMAP_DESCRIPTOR_BINDING_INDEX as u32,
+            args.ibl_specular_cubemap,
+        );
+        descriptor_set.set_image(
+            IBL_BRDF_LUT_DESCRIPTOR_BINDING_INDEX as u32,
+            args.ibl_brdf_lut,
+        );
+        descriptor_set.set_buffer_data(
+            REFLECTION_PROBE_BIN_OUTPUT_DESCRIPTOR_BINDING_INDEX as u32,
+            args.reflection_probe_bin_output,
+        );
+        descriptor_set.set_buffer_data(
+            REFLECTION_PROBES_DESCRIPTOR_BINDING_INDEX as u32,
+            args.reflection_probes,
+        );
+        descriptor_set.set_image(
+            REFLECTION_PROBE_CUBEMAPS_DESCRIPTOR_BINDING_INDEX as u32,
+            args.reflection_probe_cubemaps,
+        );
     }
 
     pub fn set_args(
@@ -433,6 +563,12 @@ impl DescriptorSet0 {
         self.set_shadow_map_atlas(args.shadow_map_atlas);
         self.set_light_bin_output(args.light_bin_output);
         self.set_all_lights(args.all_lights);
+        self.set_ibl_irradiance_cubemap(args.ibl_irradiance_cubemap);
+        self.set_ibl_specular_cubemap(args.ibl_specular_cubemap);
+        self.set_ibl_brdf_lut(args.ibl_brdf_lut);
+        self.set_reflection_probe_bin_output(args.reflection_probe_bin_output);
+        self.set_reflection_probes(args.reflection_probes);
+        self.set_reflection_probe_cubemaps(args.reflection_probe_cubemaps);
     }
 
     pub fn set_per_view_data(
@@ -471,6 +607,64 @@ impl DescriptorSet0 {
             .set_buffer_data(ALL_LIGHTS_DESCRIPTOR_BINDING_INDEX as u32, all_lights);
     }
 
+    pub fn set_ibl_irradiance_cubemap(
+        &mut self,
+        ibl_irradiance_cubemap: &ResourceArc<ImageViewResource>,
+    ) {
+        self.0.set_image(
+            IBL_IRRADIANCE_CUBEMAP_DESCRIPTOR_BINDING_INDEX as u32,
+            ibl_irradiance_cubemap,
+        );
+    }
+
+    pub fn set_ibl_specular_cubemap(
+        &mut self,
+        ibl_specular_cubemap: &ResourceArc<ImageViewResource>,
+    ) {
+        self.0.set_image(
+            IBL_SPECULAR_CUBEMAP_DESCRIPTOR_BINDING_INDEX as u32,
+            ibl_specular_cubemap,
+        );
+    }
+
+    pub fn set_ibl_brdf_lut(
+        &mut self,
+        ibl_brdf_lut: &ResourceArc<ImageViewResource>,
+    ) {
+        self.0
+            .set_image(IBL_BRDF_LUT_DESCRIPTOR_BINDING_INDEX as u32, ibl_brdf_lut);
+    }
+
+    pub fn set_reflection_probe_bin_output(
+        &mut self,
+        reflection_probe_bin_output: &ReflectionProbeBinOutputBuffer,
+    ) {
+        self.0.set_buffer_data(
+            REFLECTION_PROBE_BIN_OUTPUT_DESCRIPTOR_BINDING_INDEX as u32,
+            reflection_probe_bin_output,
+        );
+    }
+
+    pub fn set_reflection_probes(
+        &mut self,
+        reflection_probes: &ReflectionProbesBuffer,
+    ) {
+        self.0.set_buffer_data(
+            REFLECTION_PROBES_DESCRIPTOR_BINDING_INDEX as u32,
+            reflection_probes,
+        );
+    }
+
+    pub fn set_reflection_probe_cubemaps(
+        &mut self,
+        reflection_probe_cubemaps: &ResourceArc<ImageViewResource>,
+    ) {
+        self.0.set_image(
+            REFLECTION_PROBE_CUBEMAPS_DESCRIPTOR_BINDING_INDEX as u32,
+            reflection_probe_cubemaps,
+        );
+    }
+
     pub fn flush(
         &mut self,
         descriptor_set_allocator: &mut DescriptorSetAllocator,
@@ -481,6 +675,10 @@ impl DescriptorSet0 {
 
 pub struct DescriptorSet1Args<'a> {
     pub ssao_texture: &'a ResourceArc<ImageViewResource>,
+    pub volumetric_fog_volume: &'a ResourceArc<ImageViewResource>,
+    pub decal_albedo_texture: &'a ResourceArc<ImageViewResource>,
+    pub decal_normal_texture: &'a ResourceArc<ImageViewResource>,
+    pub decal_roughness_texture: &'a ResourceArc<ImageViewResource>,
 }
 
 impl<'a> DescriptorSetInitializer<'a> for DescriptorSet1Args<'a> {
@@ -515,6 +713,22 @@ impl<'a> DescriptorSetWriter<'a> for DescriptorSet1Args<'a> {
             SSAO_TEXTURE_DESCRIPTOR_BINDING_INDEX as u32,
             args.ssao_texture,
         );
+        descriptor_set.set_image(
+            VOLUMETRIC_FOG_VOLUME_DESCRIPTOR_BINDING_INDEX as u32,
+            args.volumetric_fog_volume,
+        );
+        descriptor_set.set_image(
+            DECAL_ALBEDO_TEXTURE_DESCRIPTOR_BINDING_INDEX as u32,
+            args.decal_albedo_texture,
+        );
+        descriptor_set.set_image(
+            DECAL_NORMAL_TEXTURE_DESCRIPTOR_BINDING_INDEX as u32,
+            args.decal_normal_texture,
+        );
+        descriptor_set.set_image(
+            DECAL_ROUGHNESS_TEXTURE_DESCRIPTOR_BINDING_INDEX as u32,
+            args.decal_roughness_texture,
+        );
     }
 }
 
@@ -529,6 +743,22 @@ impl DescriptorSet1 {
             SSAO_TEXTURE_DESCRIPTOR_BINDING_INDEX as u32,
             args.ssao_texture,
         );
+        descriptor_set.set_image(
+            VOLUMETRIC_FOG_VOLUME_DESCRIPTOR_BINDING_INDEX as u32,
+            args.volumetric_fog_volume,
+        );
+        descriptor_set.set_image(
+            DECAL_ALBEDO_TEXTURE_DESCRIPTOR_BINDING_INDEX as u32,
+            args.decal_albedo_texture,
+        );
+        descriptor_set.set_image(
+            DECAL_NORMAL_TEXTURE_DESCRIPTOR_BINDING_INDEX as u32,
+            args.decal_normal_texture,
+        );
+        descriptor_set.set_image(
+            DECAL_ROUGHNESS_TEXTURE_DESCRIPTOR_BINDING_INDEX as u32,
+            args.decal_roughness_texture,
+        );
     }
 
     pub fn set_args(
@@ -536,6 +766,10 @@ impl DescriptorSet1 {
         args: DescriptorSet1Args,
     ) {
         self.set_ssao_texture(args.ssao_texture);
+        self.set_volumetric_fog_volume(args.volumetric_fog_volume);
+        self.set_decal_albedo_texture(args.decal_albedo_texture);
+        self.set_decal_normal_texture(args.decal_normal_texture);
+        self.set_decal_roughness_texture(args.decal_roughness_texture);
     }
 
     pub fn set_ssao_texture(
@@ -546,6 +780,46 @@ impl DescriptorSet1 {
             .set_image(SSAO_TEXTURE_DESCRIPTOR_BINDING_INDEX as u32, ssao_texture);
     }
 
+    pub fn set_volumetric_fog_volume(
+        &mut self,
+        volumetric_fog_volume: &ResourceArc<ImageViewResource>,
+    ) {
+        self.0.set_image(
+            VOLUMETRIC_FOG_VOLUME_DESCRIPTOR_BINDING_INDEX as u32,
+            volumetric_fog_volume,
+        );
+    }
+
+    pub fn set_decal_albedo_texture(
+        &mut self,
+        decal_albedo_texture: &ResourceArc<ImageViewResource>,
+    ) {
+        self.0.set_image(
+            DECAL_ALBEDO_TEXTURE_DESCRIPTOR_BINDING_INDEX as u32,
+            decal_albedo_texture,
+        );
+    }
+
+    pub fn set_decal_normal_texture(
+        &mut self,
+        decal_normal_texture: &ResourceArc<ImageViewResource>,
+    ) {
+        self.0.set_image(
+            DECAL_NORMAL_TEXTURE_DESCRIPTOR_BINDING_INDEX as u32,
+            decal_normal_texture,
+        );
+    }
+
+    pub fn set_decal_roughness_texture(
+        &mut self,
+        decal_roughness_texture: &ResourceArc<ImageViewResource>,
+    ) {
+        self.0.set_image(
+            DECAL_ROUGHNESS_TEXTURE_DESCRIPTOR_BINDING_INDEX as u32,
+            decal_roughness_texture,
+        );
+    }
+
     pub fn flush(
         &mut self,
         descriptor_set_allocator: &mut DescriptorSetAllocator,
@@ -766,7 +1040,7 @@ mod test {
 
     #[test]
     fn test_struct_per_view_data_std140() {
-        assert_eq!(std::mem::size_of::<PerViewDataStd140>(), 13360);
+        assert_eq!(std::mem::size_of::<PerViewDataStd140>(), 13776);
         assert_eq!(std::mem::size_of::<[[f32; 4]; 4]>(), 64);
         assert_eq!(std::mem::align_of::<[[f32; 4]; 4]>(), 4);
         assert_eq!(memoffset::offset_of!(PerViewDataStd140, view), 0);
@@ -812,24 +1086,54 @@ mod test {
             memoffset::offset_of!(PerViewDataStd140, use_clustered_lighting),
             172
         );
-        assert_eq!(std::mem::size_of::<[DirectionalLightStd140; 8]>(), 384);
+        assert_eq!(std::mem::size_of::<f32>(), 4);
+        assert_eq!(std::mem::align_of::<f32>(), 4);
+        assert_eq!(memoffset::offset_of!(PerViewDataStd140, ibl_intensity), 176);
+        assert_eq!(std::mem::size_of::<f32>(), 4);
+        assert_eq!(std::mem::align_of::<f32>(), 4);
+        assert_eq!(
+            memoffset::offset_of!(PerViewDataStd140, ibl_specular_max_lod),
+            180
+        );
+        assert_eq!(std::mem::size_of::<f32>(), 4);
+        assert_eq!(std::mem::align_of::<f32>(), 4);
+        assert_eq!(
+            memoffset::offset_of!(PerViewDataStd140, reflection_probe_max_lod),
+            184
+        );
+        assert_eq!(std::mem::size_of::<f32>(), 4);
+        assert_eq!(std::mem::align_of::<f32>(), 4);
+        assert_eq!(
+            memoffset::offset_of!(PerViewDataStd140, volumetric_fog_max_distance),
+            188
+        );
+        assert_eq!(std::mem::size_of::<[DirectionalLightStd140; 8]>(), 768);
         assert_eq!(std::mem::align_of::<[DirectionalLightStd140; 8]>(), 4);
         assert_eq!(
             memoffset::offset_of!(PerViewDataStd140, directional_lights),
-            176
+            192
         );
         assert_eq!(std::mem::size_of::<[ShadowMap2DDataStd140; 96]>(), 9216);
         assert_eq!(std::mem::align_of::<[ShadowMap2DDataStd140; 96]>(), 4);
         assert_eq!(
             memoffset::offset_of!(PerViewDataStd140, shadow_map_2d_data),
-            560
+            960
         );
         assert_eq!(std::mem::size_of::<[ShadowMapCubeDataStd140; 32]>(), 3584);
         assert_eq!(std::mem::align_of::<[ShadowMapCubeDataStd140; 32]>(), 4);
         assert_eq!(
             memoffset::offset_of!(PerViewDataStd140, shadow_map_cube_data),
-            9776
+            10176
         );
+        assert_eq!(std::mem::size_of::<u32>(), 4);
+        assert_eq!(std::mem::align_of::<u32>(), 4);
+        assert_eq!(
+            memoffset::offset_of!(PerViewDataStd140, enable_decals),
+            13760
+        );
+        assert_eq!(std::mem::size_of::<[u8; 12]>(), 12);
+        assert_eq!(std::mem::align_of::<[u8; 12]>(), 1);
+        assert_eq!(memoffset::offset_of!(PerViewDataStd140, _padding0), 13764);
     }
 
     #[test]
@@ -893,20 +1197,53 @@ mod test {
         assert_eq!(std::mem::size_of::<u32>(), 4);
         assert_eq!(std::mem::align_of::<u32>(), 4);
         assert_eq!(memoffset::offset_of!(DrawDataStd140, material_index), 4);
-        assert_eq!(std::mem::size_of::<[u8; 8]>(), 8);
-        assert_eq!(std::mem::align_of::<[u8; 8]>(), 1);
-        assert_eq!(memoffset::offset_of!(DrawDataStd140, _padding0), 8);
+        assert_eq!(std::mem::size_of::<f32>(), 4);
+        assert_eq!(std::mem::align_of::<f32>(), 4);
+        assert_eq!(memoffset::offset_of!(DrawDataStd140, lod_fade), 8);
+        assert_eq!(std::mem::size_of::<[u8; 4]>(), 4);
+        assert_eq!(std::mem::align_of::<[u8; 4]>(), 1);
+        assert_eq!(memoffset::offset_of!(DrawDataStd140, _padding0), 12);
     }
 
     #[test]
     fn test_struct_draw_data_std430() {
-        assert_eq!(std::mem::size_of::<DrawDataStd430>(), 8);
+        assert_eq!(std::mem::size_of::<DrawDataStd430>(), 12);
         assert_eq!(std::mem::size_of::<u32>(), 4);
         assert_eq!(std::mem::align_of::<u32>(), 4);
         assert_eq!(memoffset::offset_of!(DrawDataStd430, transform_index), 0);
         assert_eq!(std::mem::size_of::<u32>(), 4);
         assert_eq!(std::mem::align_of::<u32>(), 4);
         assert_eq!(memoffset::offset_of!(DrawDataStd430, material_index), 4);
+        assert_eq!(std::mem::size_of::<f32>(), 4);
+        assert_eq!(std::mem::align_of::<f32>(), 4);
+        assert_eq!(memoffset::offset_of!(DrawDataStd430, lod_fade), 8);
+    }
+
+    #[test]
+    fn test_struct_reflection_probes_std430() {
+        assert_eq!(std::mem::size_of::<ReflectionProbesStd430>(), 784);
+        assert_eq!(std::mem::size_of::<u32>(), 4);
+        assert_eq!(std::mem::align_of::<u32>(), 4);
+        assert_eq!(
+            memoffset::offset_of!(ReflectionProbesStd430, probe_count),
+            0
+        );
+        assert_eq!(std::mem::size_of::<[u8; 12]>(), 12);
+        assert_eq!(std::mem::align_of::<[u8; 12]>(), 1);
+        assert_eq!(memoffset::offset_of!(ReflectionProbesStd430, _padding0), 4);
+        assert_eq!(std::mem::size_of::<[ReflectionProbeStd430; 16]>(), 768);
+        assert_eq!(std::mem::align_of::<[ReflectionProbeStd430; 16]>(), 4);
+        assert_eq!(memoffset::offset_of!(ReflectionProbesStd430, data), 16);
+    }
+
+    #[test]
+    fn test_struct_reflection_probe_bin_output_std430() {
+        assert_eq!(
+            std::mem::size_of::<ReflectionProbeBinOutputStd430>(),
+            6316048
+        );
+        assert_eq!(std::mem::size_of::<LightBinningOutputStd430>(), 6316048);
+        assert_eq!(std::mem::align_of::<LightBinningOutputStd430>(), 4);
     }
 
     #[test]
@@ -978,7 +1315,7 @@ mod test {
 
     #[test]
     fn test_struct_directional_light_std140() {
-        assert_eq!(std::mem::size_of::<DirectionalLightStd140>(), 48);
+        assert_eq!(std::mem::size_of::<DirectionalLightStd140>(), 96);
         assert_eq!(std::mem::size_of::<[f32; 3]>(), 12);
         assert_eq!(std::mem::align_of::<[f32; 3]>(), 4);
         assert_eq!(
@@ -997,26 +1334,65 @@ mod test {
             memoffset::offset_of!(DirectionalLightStd140, direction_vs),
             32
         );
-        assert_eq!(std::mem::size_of::<i32>(), 4);
-        assert_eq!(std::mem::align_of::<i32>(), 4);
+        assert_eq!(std::mem::size_of::<u32>(), 4);
+        assert_eq!(std::mem::align_of::<u32>(), 4);
         assert_eq!(
-            memoffset::offset_of!(DirectionalLightStd140, shadow_map),
+            memoffset::offset_of!(DirectionalLightStd140, cascade_count),
             44
         );
+        assert_eq!(std::mem::size_of::<[i32; 4]>(), 16);
+        assert_eq!(std::mem::align_of::<[i32; 4]>(), 4);
+        assert_eq!(
+            memoffset::offset_of!(DirectionalLightStd140, cascade_shadow_maps),
+            48
+        );
+        assert_eq!(std::mem::size_of::<[f32; 4]>(), 16);
+        assert_eq!(std::mem::align_of::<[f32; 4]>(), 4);
+        assert_eq!(
+            memoffset::offset_of!(DirectionalLightStd140, cascade_split_distances),
+            64
+        );
+        assert_eq!(std::mem::size_of::<f32>(), 4);
+        assert_eq!(std::mem::align_of::<f32>(), 4);
+        assert_eq!(
+            memoffset::offset_of!(DirectionalLightStd140, cascade_blend_fraction),
+            80
+        );
+        assert_eq!(std::mem::size_of::<[u8; 12]>(), 12);
+        assert_eq!(std::mem::align_of::<[u8; 12]>(), 1);
+        assert_eq!(memoffset::offset_of!(DirectionalLightStd140, _padding0), 84);
     }
 
     #[test]
-    fn test_struct_all_lights_std430() {
-        assert_eq!(std::mem::size_of::<AllLightsStd430>(), 40976);
+    fn test_struct_reflection_probe_std430() {
+        assert_eq!(std::mem::size_of::<ReflectionProbeStd430>(), 48);
+        assert_eq!(std::mem::size_of::<[f32; 3]>(), 12);
+        assert_eq!(std::mem::align_of::<[f32; 3]>(), 4);
+        assert_eq!(memoffset::offset_of!(ReflectionProbeStd430, box_min_ws), 0);
+        assert_eq!(std::mem::size_of::<f32>(), 4);
+        assert_eq!(std::mem::align_of::<f32>(), 4);
+        assert_eq!(
+            memoffset::offset_of!(ReflectionProbeStd430, blend_distance),
+            12
+        );
+        assert_eq!(std::mem::size_of::<[f32; 3]>(), 12);
+        assert_eq!(std::mem::align_of::<[f32; 3]>(), 4);
+        assert_eq!(memoffset::offset_of!(ReflectionProbeStd430, box_max_ws), 16);
+        assert_eq!(std::mem::size_of::<f32>(), 4);
+        assert_eq!(std::mem::align_of::<f32>(), 4);
+        assert_eq!(memoffset::offset_of!(ReflectionProbeStd430, intensity), 28);
+        assert_eq!(std::mem::size_of::<[f32; 3]>(), 12);
+        assert_eq!(std::mem::align_of::<[f32; 3]>(), 4);
+        assert_eq!(
+            memoffset::offset_of!(ReflectionProbeStd430, position_ws),
+            32
+        );
         assert_eq!(std::mem::size_of::<u32>(), 4);
         assert_eq!(std::mem::align_of::<u32>(), 4);
-        assert_eq!(memoffset::offset_of!(AllLightsStd430, light_count), 0);
-        assert_eq!(std::mem::size_of::<[u8; 12]>(), 12);
-        assert_eq!(std::mem::align_of::<[u8; 12]>(), 1);
-        assert_eq!(memoffset::offset_of!(AllLightsStd430, _padding0), 4);
-        assert_eq!(std::mem::size_of::<[LightInListStd430; 512]>(), 40960);
-        assert_eq!(std::mem::align_of::<[LightInListStd430; 512]>(), 4);
-        assert_eq!(memoffset::offset_of!(AllLightsStd430, data), 16);
+        assert_eq!(
+            memoffset::offset_of!(ReflectionProbeStd430, first_layer),
+            44
+        );
     }
 
     #[test]
@@ -1046,4 +1422,18 @@ mod test {
         assert_eq!(std::mem::align_of::<u32>(), 4);
         assert_eq!(memoffset::offset_of!(ClusterMetaStd430, first_light), 4);
     }
+
+    #[test]
+    fn test_struct_all_lights_std430() {
+        assert_eq!(std::mem::size_of::<AllLightsStd430>(), 40976);
+        assert_eq!(std::mem::size_of::<u32>(), 4);
+        assert_eq!(std::mem::align_of::<u32>(), 4);
+        assert_eq!(memoffset::offset_of!(AllLightsStd430, light_count), 0);
+        assert_eq!(std::mem::size_of::<[u8; 12]>(), 12);
+        assert_eq!(std::mem::align_of::<[u8; 12]>(), 1);
+        assert_eq!(memoffset::offset_of!(AllLightsStd430, _padding0), 4);
+        assert_eq!(std::mem::size_of::<[LightInListStd430; 512]>(), 40960);
+        assert_eq!(std::mem::align_of::<[LightInListStd430; 512]>(), 4);
+        assert_eq!(memoffset::offset_of!(AllLightsStd430, data), 16);
+    }
 }
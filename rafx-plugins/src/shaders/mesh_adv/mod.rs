@@ -2,14 +2,28 @@
 
 #![allow(dead_code)]
 
+pub mod ibl_brdf_lut_comp;
+pub mod ibl_irradiance_comp;
+pub mod ibl_specular_comp;
 pub mod lights_bin_comp;
 pub mod lights_build_lists_comp;
 pub mod mesh_adv_textured_frag;
+pub mod mesh_adv_textured_gbuffer_frag;
+pub mod mesh_adv_textured_oit_frag;
 pub mod mesh_adv_textured_vert;
 pub mod mesh_adv_untextured_frag;
+pub mod mesh_adv_untextured_gbuffer_frag;
+pub mod mesh_adv_untextured_oit_frag;
 pub mod mesh_adv_untextured_vert;
 pub mod mesh_adv_wireframe_frag;
 pub mod mesh_adv_wireframe_vert;
 pub mod mesh_culling_comp;
+pub mod mesh_skinning_comp;
+pub mod reflection_probe_prefilter_comp;
 pub mod shadow_atlas_clear_tiles_vert;
+pub mod shadow_atlas_depth_alpha_clip_frag;
+pub mod shadow_atlas_depth_alpha_stochastic_frag;
+pub mod shadow_atlas_depth_alpha_vert;
 pub mod shadow_atlas_depth_vert;
+pub mod volumetric_fog_inject_comp;
+pub mod volumetric_fog_integrate_comp;
@@ -0,0 +1,550 @@
+// This code is auto-generated by the shader processor.
+
+#[allow(unused_imports)]
+use rafx::RafxResult;
+
+#[allow(unused_imports)]
+use rafx::framework::{
+    DescriptorSetAllocator, DescriptorSetArc, DescriptorSetBindings, DescriptorSetInitializer,
+    DescriptorSetWriter, DescriptorSetWriterContext, DynDescriptorSet, ImageViewResource,
+    ResourceArc,
+};
+
+#[derive(Copy, Clone, Debug)]
+#[repr(C)]
+pub struct PerViewDataStd140 {
+    pub view: [[f32; 4]; 4],      // +0 (size: 64)
+    pub view_proj: [[f32; 4]; 4], // +64 (size: 64)
+    pub uv_min: [f32; 2],         // +128 (size: 8)
+    pub uv_max: [f32; 2],         // +136 (size: 8)
+} // 144 bytes
+
+impl Default for PerViewDataStd140 {
+    fn default() -> Self {
+        PerViewDataStd140 {
+            view: <[[f32; 4]; 4]>::default(),
+            view_proj: <[[f32; 4]; 4]>::default(),
+            uv_min: <[f32; 2]>::default(),
+            uv_max: <[f32; 2]>::default(),
+        }
+    }
+}
+
+pub type PerViewDataUniform = PerViewDataStd140;
+
+#[derive(Copy, Clone, Debug)]
+#[repr(C)]
+pub struct TransformWithHistoryStd140 {
+    pub current_model_matrix: [[f32; 4]; 4],  // +0 (size: 64)
+    pub previous_model_matrix: [[f32; 4]; 4], // +64 (size: 64)
+} // 128 bytes
+
+impl Default for TransformWithHistoryStd140 {
+    fn default() -> Self {
+        TransformWithHistoryStd140 {
+            current_model_matrix: <[[f32; 4]; 4]>::default(),
+            previous_model_matrix: <[[f32; 4]; 4]>::default(),
+        }
+    }
+}
+
+pub type TransformWithHistoryUniform = TransformWithHistoryStd140;
+
+#[derive(Copy, Clone, Debug)]
+#[repr(C)]
+pub struct TransformWithHistoryStd430 {
+    pub current_model_matrix: [[f32; 4]; 4],  // +0 (size: 64)
+    pub previous_model_matrix: [[f32; 4]; 4], // +64 (size: 64)
+} // 128 bytes
+
+pub type TransformWithHistoryPushConstant = TransformWithHistoryStd430;
+
+pub type TransformWithHistoryBuffer = TransformWithHistoryStd430;
+
+#[derive(Copy, Clone, Debug)]
+#[repr(C)]
+pub struct MaterialDbEntryStd140 {
+    pub base_color_factor: [f32; 4],               // +0 (size: 16)
+    pub emissive_factor: [f32; 3],                 // +16 (size: 12)
+    pub metallic_factor: f32,                      // +28 (size: 4)
+    pub roughness_factor: f32,                     // +32 (size: 4)
+    pub normal_texture_scale: f32,                 // +36 (size: 4)
+    pub alpha_threshold: f32,                      // +40 (size: 4)
+    pub enable_alpha_blend: u32,                   // +44 (size: 4)
+    pub enable_alpha_clip: u32,                    // +48 (size: 4)
+    pub color_texture: i32,                        // +52 (size: 4)
+    pub base_color_texture_has_alpha_channel: u32, // +56 (size: 4)
+    pub metallic_roughness_texture: i32,           // +60 (size: 4)
+    pub normal_texture: i32,                       // +64 (size: 4)
+    pub emissive_texture: i32,                     // +68 (size: 4)
+    pub _padding0: [u8; 8],                        // +72 (size: 8)
+} // 80 bytes
+
+impl Default for MaterialDbEntryStd140 {
+    fn default() -> Self {
+        MaterialDbEntryStd140 {
+            base_color_factor: <[f32; 4]>::default(),
+            emissive_factor: <[f32; 3]>::default(),
+            metallic_factor: <f32>::default(),
+            roughness_factor: <f32>::default(),
+            normal_texture_scale: <f32>::default(),
+            alpha_threshold: <f32>::default(),
+            enable_alpha_blend: <u32>::default(),
+            enable_alpha_clip: <u32>::default(),
+            color_texture: <i32>::default(),
+            base_color_texture_has_alpha_channel: <u32>::default(),
+            metallic_roughness_texture: <i32>::default(),
+            normal_texture: <i32>::default(),
+            emissive_texture: <i32>::default(),
+            _padding0: [u8::default(); 8],
+        }
+    }
+}
+
+pub type MaterialDbEntryUniform = MaterialDbEntryStd140;
+
+#[derive(Copy, Clone, Debug)]
+#[repr(C)]
+pub struct MaterialDbEntryStd430 {
+    pub base_color_factor: [f32; 4],               // +0 (size: 16)
+    pub emissive_factor: [f32; 3],                 // +16 (size: 12)
+    pub metallic_factor: f32,                      // +28 (size: 4)
+    pub roughness_factor: f32,                     // +32 (size: 4)
+    pub normal_texture_scale: f32,                 // +36 (size: 4)
+    pub alpha_threshold: f32,                      // +40 (size: 4)
+    pub enable_alpha_blend: u32,                   // +44 (size: 4)
+    pub enable_alpha_clip: u32,                    // +48 (size: 4)
+    pub color_texture: i32,                        // +52 (size: 4)
+    pub base_color_texture_has_alpha_channel: u32, // +56 (size: 4)
+    pub metallic_roughness_texture: i32,           // +60 (size: 4)
+    pub normal_texture: i32,                       // +64 (size: 4)
+    pub emissive_texture: i32,                     // +68 (size: 4)
+    pub _padding0: [u8; 8],                        // +72 (size: 8)
+} // 80 bytes
+
+pub type MaterialDbEntryPushConstant = MaterialDbEntryStd430;
+
+pub type MaterialDbEntryBuffer = MaterialDbEntryStd430;
+
+#[derive(Copy, Clone, Debug)]
+#[repr(C)]
+pub struct TransformStd140 {
+    pub model_matrix: [[f32; 4]; 4], // +0 (size: 64)
+} // 64 bytes
+
+impl Default for TransformStd140 {
+    fn default() -> Self {
+        TransformStd140 {
+            model_matrix: <[[f32; 4]; 4]>::default(),
+        }
+    }
+}
+
+pub type TransformUniform = TransformStd140;
+
+#[derive(Copy, Clone, Debug)]
+#[repr(C)]
+pub struct TransformStd430 {
+    pub model_matrix: [[f32; 4]; 4], // +0 (size: 64)
+} // 64 bytes
+
+pub type TransformPushConstant = TransformStd430;
+
+pub type TransformBuffer = TransformStd430;
+
+#[derive(Copy, Clone, Debug)]
+#[repr(C)]
+pub struct DrawDataStd140 {
+    pub transform_index: u32, // +0 (size: 4)
+    pub material_index: u32,  // +4 (size: 4)
+    pub lod_fade: f32,        // +8 (size: 4)
+    pub _padding0: [u8; 4],   // +12 (size: 4)
+} // 16 bytes
+
+impl Default for DrawDataStd140 {
+    fn default() -> Self {
+        DrawDataStd140 {
+            transform_index: <u32>::default(),
+            material_index: <u32>::default(),
+            lod_fade: <f32>::default(),
+            _padding0: [u8::default(); 4],
+        }
+    }
+}
+
+pub type DrawDataUniform = DrawDataStd140;
+
+#[derive(Copy, Clone, Debug)]
+#[repr(C)]
+pub struct DrawDataStd430 {
+    pub transform_index: u32, // +0 (size: 4)
+    pub material_index: u32,  // +4 (size: 4)
+    pub lod_fade: f32,        // +8 (size: 4)
+} // 12 bytes
+
+pub type DrawDataPushConstant = DrawDataStd430;
+
+pub type DrawDataBuffer = DrawDataStd430;
+
+pub const PER_VIEW_DATA_DESCRIPTOR_SET_INDEX: usize = 0;
+pub const PER_VIEW_DATA_DESCRIPTOR_BINDING_INDEX: usize = 0;
+pub const SMP_DESCRIPTOR_SET_INDEX: usize = 0;
+pub const SMP_DESCRIPTOR_BINDING_INDEX: usize = 1;
+pub const ALL_TRANSFORMS_DESCRIPTOR_SET_INDEX: usize = 1;
+pub const ALL_TRANSFORMS_DESCRIPTOR_BINDING_INDEX: usize = 0;
+pub const ALL_DRAW_DATA_DESCRIPTOR_SET_INDEX: usize = 1;
+pub const ALL_DRAW_DATA_DESCRIPTOR_BINDING_INDEX: usize = 1;
+pub const ALL_MATERIALS_DESCRIPTOR_SET_INDEX: usize = 2;
+pub const ALL_MATERIALS_DESCRIPTOR_BINDING_INDEX: usize = 0;
+pub const ALL_MATERIAL_TEXTURES_DESCRIPTOR_SET_INDEX: usize = 2;
+pub const ALL_MATERIAL_TEXTURES_DESCRIPTOR_BINDING_INDEX: usize = 1;
+
+pub struct DescriptorSet0Args<'a> {
+    pub per_view_data: &'a PerViewDataUniform,
+}
+
+impl<'a> DescriptorSetInitializer<'a> for DescriptorSet0Args<'a> {
+    type Output = DescriptorSet0;
+
+    fn create_dyn_descriptor_set(
+        descriptor_set: DynDescriptorSet,
+        args: Self,
+    ) -> Self::Output {
+        let mut descriptor = DescriptorSet0(descriptor_set);
+        descriptor.set_args(args);
+        descriptor
+    }
+
+    fn create_descriptor_set(
+        descriptor_set_allocator: &mut DescriptorSetAllocator,
+        descriptor_set: DynDescriptorSet,
+        args: Self,
+    ) -> RafxResult<DescriptorSetArc> {
+        let mut descriptor = Self::create_dyn_descriptor_set(descriptor_set, args);
+        descriptor.0.flush(descriptor_set_allocator)?;
+        Ok(descriptor.0.descriptor_set().clone())
+    }
+}
+
+impl<'a> DescriptorSetWriter<'a> for DescriptorSet0Args<'a> {
+    fn write_to(
+        descriptor_set: &mut DescriptorSetWriterContext,
+        args: Self,
+    ) {
+        descriptor_set.set_buffer_data(
+            PER_VIEW_DATA_DESCRIPTOR_BINDING_INDEX as u32,
+            args.per_view_data,
+        );
+    }
+}
+
+pub struct DescriptorSet0(pub DynDescriptorSet);
+
+impl DescriptorSet0 {
+    pub fn set_args_static(
+        descriptor_set: &mut DynDescriptorSet,
+        args: DescriptorSet0Args,
+    ) {
+        descriptor_set.set_buffer_data(
+            PER_VIEW_DATA_DESCRIPTOR_BINDING_INDEX as u32,
+            args.per_view_data,
+        );
+    }
+
+    pub fn set_args(
+        &mut self,
+        args: DescriptorSet0Args,
+    ) {
+        self.set_per_view_data(args.per_view_data);
+    }
+
+    pub fn set_per_view_data(
+        &mut self,
+        per_view_data: &PerViewDataUniform,
+    ) {
+        self.0
+            .set_buffer_data(PER_VIEW_DATA_DESCRIPTOR_BINDING_INDEX as u32, per_view_data);
+    }
+
+    pub fn flush(
+        &mut self,
+        descriptor_set_allocator: &mut DescriptorSetAllocator,
+    ) -> RafxResult<()> {
+        self.0.flush(descriptor_set_allocator)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_struct_per_view_data_std140() {
+        assert_eq!(std::mem::size_of::<PerViewDataStd140>(), 144);
+        assert_eq!(std::mem::size_of::<[[f32; 4]; 4]>(), 64);
+        assert_eq!(std::mem::align_of::<[[f32; 4]; 4]>(), 4);
+        assert_eq!(memoffset::offset_of!(PerViewDataStd140, view), 0);
+        assert_eq!(std::mem::size_of::<[[f32; 4]; 4]>(), 64);
+        assert_eq!(std::mem::align_of::<[[f32; 4]; 4]>(), 4);
+        assert_eq!(memoffset::offset_of!(PerViewDataStd140, view_proj), 64);
+        assert_eq!(std::mem::size_of::<[f32; 2]>(), 8);
+        assert_eq!(std::mem::align_of::<[f32; 2]>(), 4);
+        assert_eq!(memoffset::offset_of!(PerViewDataStd140, uv_min), 128);
+        assert_eq!(std::mem::size_of::<[f32; 2]>(), 8);
+        assert_eq!(std::mem::align_of::<[f32; 2]>(), 4);
+        assert_eq!(memoffset::offset_of!(PerViewDataStd140, uv_max), 136);
+    }
+
+    #[test]
+    fn test_struct_transform_with_history_std140() {
+        assert_eq!(std::mem::size_of::<TransformWithHistoryStd140>(), 128);
+        assert_eq!(std::mem::size_of::<[[f32; 4]; 4]>(), 64);
+        assert_eq!(std::mem::align_of::<[[f32; 4]; 4]>(), 4);
+        assert_eq!(
+            memoffset::offset_of!(TransformWithHistoryStd140, current_model_matrix),
+            0
+        );
+        assert_eq!(std::mem::size_of::<[[f32; 4]; 4]>(), 64);
+        assert_eq!(std::mem::align_of::<[[f32; 4]; 4]>(), 4);
+        assert_eq!(
+            memoffset::offset_of!(TransformWithHistoryStd140, previous_model_matrix),
+            64
+        );
+    }
+
+    #[test]
+    fn test_struct_transform_with_history_std430() {
+        assert_eq!(std::mem::size_of::<TransformWithHistoryStd430>(), 128);
+        assert_eq!(std::mem::size_of::<[[f32; 4]; 4]>(), 64);
+        assert_eq!(std::mem::align_of::<[[f32; 4]; 4]>(), 4);
+        assert_eq!(
+            memoffset::offset_of!(TransformWithHistoryStd430, current_model_matrix),
+            0
+        );
+        assert_eq!(std::mem::size_of::<[[f32; 4]; 4]>(), 64);
+        assert_eq!(std::mem::align_of::<[[f32; 4]; 4]>(), 4);
+        assert_eq!(
+            memoffset::offset_of!(TransformWithHistoryStd430, previous_model_matrix),
+            64
+        );
+    }
+
+    #[test]
+    fn test_struct_material_db_entry_std140() {
+        assert_eq!(std::mem::size_of::<MaterialDbEntryStd140>(), 80);
+        assert_eq!(std::mem::size_of::<[f32; 4]>(), 16);
+        assert_eq!(std::mem::align_of::<[f32; 4]>(), 4);
+        assert_eq!(
+            memoffset::offset_of!(MaterialDbEntryStd140, base_color_factor),
+            0
+        );
+        assert_eq!(std::mem::size_of::<[f32; 3]>(), 12);
+        assert_eq!(std::mem::align_of::<[f32; 3]>(), 4);
+        assert_eq!(
+            memoffset::offset_of!(MaterialDbEntryStd140, emissive_factor),
+            16
+        );
+        assert_eq!(std::mem::size_of::<f32>(), 4);
+        assert_eq!(std::mem::align_of::<f32>(), 4);
+        assert_eq!(
+            memoffset::offset_of!(MaterialDbEntryStd140, metallic_factor),
+            28
+        );
+        assert_eq!(std::mem::size_of::<f32>(), 4);
+        assert_eq!(std::mem::align_of::<f32>(), 4);
+        assert_eq!(
+            memoffset::offset_of!(MaterialDbEntryStd140, roughness_factor),
+            32
+        );
+        assert_eq!(std::mem::size_of::<f32>(), 4);
+        assert_eq!(std::mem::align_of::<f32>(), 4);
+        assert_eq!(
+            memoffset::offset_of!(MaterialDbEntryStd140, normal_texture_scale),
+            36
+        );
+        assert_eq!(std::mem::size_of::<f32>(), 4);
+        assert_eq!(std::mem::align_of::<f32>(), 4);
+        assert_eq!(
+            memoffset::offset_of!(MaterialDbEntryStd140, alpha_threshold),
+            40
+        );
+        assert_eq!(std::mem::size_of::<u32>(), 4);
+        assert_eq!(std::mem::align_of::<u32>(), 4);
+        assert_eq!(
+            memoffset::offset_of!(MaterialDbEntryStd140, enable_alpha_blend),
+            44
+        );
+        assert_eq!(std::mem::size_of::<u32>(), 4);
+        assert_eq!(std::mem::align_of::<u32>(), 4);
+        assert_eq!(
+            memoffset::offset_of!(MaterialDbEntryStd140, enable_alpha_clip),
+            48
+        );
+        assert_eq!(std::mem::size_of::<i32>(), 4);
+        assert_eq!(std::mem::align_of::<i32>(), 4);
+        assert_eq!(
+            memoffset::offset_of!(MaterialDbEntryStd140, color_texture),
+            52
+        );
+        assert_eq!(std::mem::size_of::<u32>(), 4);
+        assert_eq!(std::mem::align_of::<u32>(), 4);
+        assert_eq!(
+            memoffset::offset_of!(MaterialDbEntryStd140, base_color_texture_has_alpha_channel),
+            56
+        );
+        assert_eq!(std::mem::size_of::<i32>(), 4);
+        assert_eq!(std::mem::align_of::<i32>(), 4);
+        assert_eq!(
+            memoffset::offset_of!(MaterialDbEntryStd140, metallic_roughness_texture),
+            60
+        );
+        assert_eq!(std::mem::size_of::<i32>(), 4);
+        assert_eq!(std::mem::align_of::<i32>(), 4);
+        assert_eq!(
+            memoffset::offset_of!(MaterialDbEntryStd140, normal_texture),
+            64
+        );
+        assert_eq!(std::mem::size_of::<i32>(), 4);
+        assert_eq!(std::mem::align_of::<i32>(), 4);
+        assert_eq!(
+            memoffset::offset_of!(MaterialDbEntryStd140, emissive_texture),
+            68
+        );
+        assert_eq!(std::mem::size_of::<[u8; 8]>(), 8);
+        assert_eq!(std::mem::align_of::<[u8; 8]>(), 1);
+        assert_eq!(memoffset::offset_of!(MaterialDbEntryStd140, _padding0), 72);
+    }
+
+    #[test]
+    fn test_struct_material_db_entry_std430() {
+        assert_eq!(std::mem::size_of::<MaterialDbEntryStd430>(), 80);
+        assert_eq!(std::mem::size_of::<[f32; 4]>(), 16);
+        assert_eq!(std::mem::align_of::<[f32; 4]>(), 4);
+        assert_eq!(
+            memoffset::offset_of!(MaterialDbEntryStd430, base_color_factor),
+            0
+        );
+        assert_eq!(std::mem::size_of::<[f32; 3]>(), 12);
+        assert_eq!(std::mem::align_of::<[f32; 3]>(), 4);
+        assert_eq!(
+            memoffset::offset_of!(MaterialDbEntryStd430, emissive_factor),
+            16
+        );
+        assert_eq!(std::mem::size_of::<f32>(), 4);
+        assert_eq!(std::mem::align_of::<f32>(), 4);
+        assert_eq!(
+            memoffset::offset_of!(MaterialDbEntryStd430, metallic_factor),
+            28
+        );
+        assert_eq!(std::mem::size_of::<f32>(), 4);
+        assert_eq!(std::mem::align_of::<f32>(), 4);
+        assert_eq!(
+            memoffset::offset_of!(MaterialDbEntryStd430, roughness_factor),
+            32
+        );
+        assert_eq!(std::mem::size_of::<f32>(), 4);
+        assert_eq!(std::mem::align_of::<f32>(), 4);
+        assert_eq!(
+            memoffset::offset_of!(MaterialDbEntryStd430, normal_texture_scale),
+            36
+        );
+        assert_eq!(std::mem::size_of::<f32>(), 4);
+        assert_eq!(std::mem::align_of::<f32>(), 4);
+        assert_eq!(
+            memoffset::offset_of!(MaterialDbEntryStd430, alpha_threshold),
+            40
+        );
+        assert_eq!(std::mem::size_of::<u32>(), 4);
+        assert_eq!(std::mem::align_of::<u32>(), 4);
+        assert_eq!(
+            memoffset::offset_of!(MaterialDbEntryStd430, enable_alpha_blend),
+            44
+        );
+        assert_eq!(std::mem::size_of::<u32>(), 4);
+        assert_eq!(std::mem::align_of::<u32>(), 4);
+        assert_eq!(
+            memoffset::offset_of!(MaterialDbEntryStd430, enable_alpha_clip),
+            48
+        );
+        assert_eq!(std::mem::size_of::<i32>(), 4);
+        assert_eq!(std::mem::align_of::<i32>(), 4);
+        assert_eq!(
+            memoffset::offset_of!(MaterialDbEntryStd430, color_texture),
+            52
+        );
+        assert_eq!(std::mem::size_of::<u32>(), 4);
+        assert_eq!(std::mem::align_of::<u32>(), 4);
+        assert_eq!(
+            memoffset::offset_of!(MaterialDbEntryStd430, base_color_texture_has_alpha_channel),
+            56
+        );
+        assert_eq!(std::mem::size_of::<i32>(), 4);
+        assert_eq!(std::mem::align_of::<i32>(), 4);
+        assert_eq!(
+            memoffset::offset_of!(MaterialDbEntryStd430, metallic_roughness_texture),
+            60
+        );
+        assert_eq!(std::mem::size_of::<i32>(), 4);
+        assert_eq!(std::mem::align_of::<i32>(), 4);
+        assert_eq!(
+            memoffset::offset_of!(MaterialDbEntryStd430, normal_texture),
+            64
+        );
+        assert_eq!(std::mem::size_of::<i32>(), 4);
+        assert_eq!(std::mem::align_of::<i32>(), 4);
+        assert_eq!(
+            memoffset::offset_of!(MaterialDbEntryStd430, emissive_texture),
+            68
+        );
+        assert_eq!(std::mem::size_of::<[u8; 8]>(), 8);
+        assert_eq!(std::mem::align_of::<[u8; 8]>(), 1);
+        assert_eq!(memoffset::offset_of!(MaterialDbEntryStd430, _padding0), 72);
+    }
+
+    #[test]
+    fn test_struct_transform_std140() {
+        assert_eq!(std::mem::size_of::<TransformStd140>(), 64);
+        assert_eq!(std::mem::size_of::<[[f32; 4]; 4]>(), 64);
+        assert_eq!(std::mem::align_of::<[[f32; 4]; 4]>(), 4);
+        assert_eq!(memoffset::offset_of!(TransformStd140, model_matrix), 0);
+    }
+
+    #[test]
+    fn test_struct_transform_std430() {
+        assert_eq!(std::mem::size_of::<TransformStd430>(), 64);
+        assert_eq!(std::mem::size_of::<[[f32; 4]; 4]>(), 64);
+        assert_eq!(std::mem::align_of::<[[f32; 4]; 4]>(), 4);
+        assert_eq!(memoffset::offset_of!(TransformStd430, model_matrix), 0);
+    }
+
+    #[test]
+    fn test_struct_draw_data_std140() {
+        assert_eq!(std::mem::size_of::<DrawDataStd140>(), 16);
+        assert_eq!(std::mem::size_of::<u32>(), 4);
+        assert_eq!(std::mem::align_of::<u32>(), 4);
+        assert_eq!(memoffset::offset_of!(DrawDataStd140, transform_index), 0);
+        assert_eq!(std::mem::size_of::<u32>(), 4);
+        assert_eq!(std::mem::align_of::<u32>(), 4);
+        assert_eq!(memoffset::offset_of!(DrawDataStd140, material_index), 4);
+        assert_eq!(std::mem::size_of::<f32>(), 4);
+        assert_eq!(std::mem::align_of::<f32>(), 4);
+        assert_eq!(memoffset::offset_of!(DrawDataStd140, lod_fade), 8);
+        assert_eq!(std::mem::size_of::<[u8; 4]>(), 4);
+        assert_eq!(std::mem::align_of::<[u8; 4]>(), 1);
+        assert_eq!(memoffset::offset_of!(DrawDataStd140, _padding0), 12);
+    }
+
+    #[test]
+    fn test_struct_draw_data_std430() {
+        assert_eq!(std::mem::size_of::<DrawDataStd430>(), 12);
+        assert_eq!(std::mem::size_of::<u32>(), 4);
+        assert_eq!(std::mem::align_of::<u32>(), 4);
+        assert_eq!(memoffset::offset_of!(DrawDataStd430, transform_index), 0);
+        assert_eq!(std::mem::size_of::<u32>(), 4);
+        assert_eq!(std::mem::align_of::<u32>(), 4);
+        assert_eq!(memoffset::offset_of!(DrawDataStd430, material_index), 4);
+        assert_eq!(std::mem::size_of::<f32>(), 4);
+        assert_eq!(std::mem::align_of::<f32>(), 4);
+        assert_eq!(memoffset::offset_of!(DrawDataStd430, lod_fade), 8);
+    }
+}
@@ -0,0 +1,265 @@
+// This code is auto-generated by the shader processor.
+
+#[allow(unused_imports)]
+use rafx::RafxResult;
+
+#[allow(unused_imports)]
+use rafx::framework::{
+    DescriptorSetAllocator, DescriptorSetArc, DescriptorSetBindings, DescriptorSetInitializer,
+    DescriptorSetWriter, DescriptorSetWriterContext, DynDescriptorSet, ImageViewResource,
+    ResourceArc,
+};
+
+#[derive(Copy, Clone, Debug)]
+#[repr(C)]
+pub struct VolumetricFogInjectConfigStd140 {
+    pub inverse_projection: [[f32; 4]; 4], // +0 (size: 64)
+    pub inverse_view: [[f32; 4]; 4],       // +64 (size: 64)
+    pub albedo: [f32; 4],                  // +128 (size: 16)
+    pub density: f32,                      // +144 (size: 4)
+    pub height_falloff: f32,               // +148 (size: 4)
+    pub base_height: f32,                  // +152 (size: 4)
+    pub anisotropy: f32,                   // +156 (size: 4)
+    pub max_distance: f32,                 // +160 (size: 4)
+    pub cluster_near_z: f32,               // +164 (size: 4)
+    pub cluster_far_z: f32,                // +168 (size: 4)
+    pub cluster_x_bins: u32,               // +172 (size: 4)
+    pub cluster_y_bins: u32,               // +176 (size: 4)
+    pub cluster_z_bins: u32,               // +180 (size: 4)
+    pub _padding0: [u8; 8],                // +184 (size: 8)
+} // 192 bytes
+
+impl Default for VolumetricFogInjectConfigStd140 {
+    fn default() -> Self {
+        VolumetricFogInjectConfigStd140 {
+            inverse_projection: <[[f32; 4]; 4]>::default(),
+            inverse_view: <[[f32; 4]; 4]>::default(),
+            albedo: <[f32; 4]>::default(),
+            density: <f32>::default(),
+            height_falloff: <f32>::default(),
+            base_height: <f32>::default(),
+            anisotropy: <f32>::default(),
+            max_distance: <f32>::default(),
+            cluster_near_z: <f32>::default(),
+            cluster_far_z: <f32>::default(),
+            cluster_x_bins: <u32>::default(),
+            cluster_y_bins: <u32>::default(),
+            cluster_z_bins: <u32>::default(),
+            _padding0: [u8::default(); 8],
+        }
+    }
+}
+
+pub type VolumetricFogInjectConfigUniform = VolumetricFogInjectConfigStd140;
+
+pub const CONFIG_DESCRIPTOR_SET_INDEX: usize = 0;
+pub const CONFIG_DESCRIPTOR_BINDING_INDEX: usize = 0;
+pub const PER_VIEW_DATA_DESCRIPTOR_SET_INDEX: usize = 0;
+pub const PER_VIEW_DATA_DESCRIPTOR_BINDING_INDEX: usize = 1;
+pub const SMP_DEPTH_LINEAR_DESCRIPTOR_SET_INDEX: usize = 0;
+pub const SMP_DEPTH_LINEAR_DESCRIPTOR_BINDING_INDEX: usize = 2;
+pub const SMP_DEPTH_NEAREST_DESCRIPTOR_SET_INDEX: usize = 0;
+pub const SMP_DEPTH_NEAREST_DESCRIPTOR_BINDING_INDEX: usize = 3;
+pub const SHADOW_MAP_ATLAS_DESCRIPTOR_SET_INDEX: usize = 0;
+pub const SHADOW_MAP_ATLAS_DESCRIPTOR_BINDING_INDEX: usize = 4;
+pub const LIGHT_BIN_OUTPUT_DESCRIPTOR_SET_INDEX: usize = 0;
+pub const LIGHT_BIN_OUTPUT_DESCRIPTOR_BINDING_INDEX: usize = 5;
+pub const ALL_LIGHTS_DESCRIPTOR_SET_INDEX: usize = 0;
+pub const ALL_LIGHTS_DESCRIPTOR_BINDING_INDEX: usize = 6;
+pub const OUTPUT_TEX_DESCRIPTOR_SET_INDEX: usize = 0;
+pub const OUTPUT_TEX_DESCRIPTOR_BINDING_INDEX: usize = 7;
+
+pub struct DescriptorSet0Args<'a> {
+    pub config: &'a VolumetricFogInjectConfigUniform,
+    pub shadow_map_atlas: &'a ResourceArc<ImageViewResource>,
+    pub output_tex: &'a ResourceArc<ImageViewResource>,
+}
+
+impl<'a> DescriptorSetInitializer<'a> for DescriptorSet0Args<'a> {
+    type Output = DescriptorSet0;
+
+    fn create_dyn_descriptor_set(
+        descriptor_set: DynDescriptorSet,
+        args: Self,
+    ) -> Self::Output {
+        let mut descriptor = DescriptorSet0(descriptor_set);
+        descriptor.set_args(args);
+        descriptor
+    }
+
+    fn create_descriptor_set(
+        descriptor_set_allocator: &mut DescriptorSetAllocator,
+        descriptor_set: DynDescriptorSet,
+        args: Self,
+    ) -> RafxResult<DescriptorSetArc> {
+        let mut descriptor = Self::create_dyn_descriptor_set(descriptor_set, args);
+        descriptor.0.flush(descriptor_set_allocator)?;
+        Ok(descriptor.0.descriptor_set().clone())
+    }
+}
+
+impl<'a> DescriptorSetWriter<'a> for DescriptorSet0Args<'a> {
+    fn write_to(
+        descriptor_set: &mut DescriptorSetWriterContext,
+        args: Self,
+    ) {
+        descriptor_set.set_buffer_data(CONFIG_DESCRIPTOR_BINDING_INDEX as u32, args.config);
+        descriptor_set.set_image(
+            SHADOW_MAP_ATLAS_DESCRIPTOR_BINDING_INDEX as u32,
+            args.shadow_map_atlas,
+        );
+        descriptor_set.set_image(OUTPUT_TEX_DESCRIPTOR_BINDING_INDEX as u32, args.output_tex);
+    }
+}
+
+pub struct DescriptorSet0(pub DynDescriptorSet);
+
+impl DescriptorSet0 {
+    pub fn set_args_static(
+        descriptor_set: &mut DynDescriptorSet,
+        args: DescriptorSet0Args,
+    ) {
+        descriptor_set.set_buffer_data(CONFIG_DESCRIPTOR_BINDING_INDEX as u32, args.config);
+        descriptor_set.set_image(
+            SHADOW_MAP_ATLAS_DESCRIPTOR_BINDING_INDEX as u32,
+            args.shadow_map_atlas,
+        );
+        descriptor_set.set_image(OUTPUT_TEX_DESCRIPTOR_BINDING_INDEX as u32, args.output_tex);
+    }
+
+    pub fn set_args(
+        &mut self,
+        args: DescriptorSet0Args,
+    ) {
+        self.set_config(args.config);
+        self.set_shadow_map_atlas(args.shadow_map_atlas);
+        self.set_output_tex(args.output_tex);
+    }
+
+    pub fn set_config(
+        &mut self,
+        config: &VolumetricFogInjectConfigUniform,
+    ) {
+        self.0
+            .set_buffer_data(CONFIG_DESCRIPTOR_BINDING_INDEX as u32, config);
+    }
+
+    pub fn set_shadow_map_atlas(
+        &mut self,
+        shadow_map_atlas: &ResourceArc<ImageViewResource>,
+    ) {
+        self.0.set_image(
+            SHADOW_MAP_ATLAS_DESCRIPTOR_BINDING_INDEX as u32,
+            shadow_map_atlas,
+        );
+    }
+
+    pub fn set_output_tex(
+        &mut self,
+        output_tex: &ResourceArc<ImageViewResource>,
+    ) {
+        self.0
+            .set_image(OUTPUT_TEX_DESCRIPTOR_BINDING_INDEX as u32, output_tex);
+    }
+
+    pub fn flush(
+        &mut self,
+        descriptor_set_allocator: &mut DescriptorSetAllocator,
+    ) -> RafxResult<()> {
+        self.0.flush(descriptor_set_allocator)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_struct_volumetric_fog_inject_config_std140() {
+        assert_eq!(std::mem::size_of::<VolumetricFogInjectConfigStd140>(), 192);
+        assert_eq!(std::mem::size_of::<[[f32; 4]; 4]>(), 64);
+        assert_eq!(std::mem::align_of::<[[f32; 4]; 4]>(), 4);
+        assert_eq!(
+            memoffset::offset_of!(VolumetricFogInjectConfigStd140, inverse_projection),
+            0
+        );
+        assert_eq!(std::mem::size_of::<[[f32; 4]; 4]>(), 64);
+        assert_eq!(std::mem::align_of::<[[f32; 4]; 4]>(), 4);
+        assert_eq!(
+            memoffset::offset_of!(VolumetricFogInjectConfigStd140, inverse_view),
+            64
+        );
+        assert_eq!(std::mem::size_of::<[f32; 4]>(), 16);
+        assert_eq!(std::mem::align_of::<[f32; 4]>(), 4);
+        assert_eq!(
+            memoffset::offset_of!(VolumetricFogInjectConfigStd140, albedo),
+            128
+        );
+        assert_eq!(std::mem::size_of::<f32>(), 4);
+        assert_eq!(std::mem::align_of::<f32>(), 4);
+        assert_eq!(
+            memoffset::offset_of!(VolumetricFogInjectConfigStd140, density),
+            144
+        );
+        assert_eq!(std::mem::size_of::<f32>(), 4);
+        assert_eq!(std::mem::align_of::<f32>(), 4);
+        assert_eq!(
+            memoffset::offset_of!(VolumetricFogInjectConfigStd140, height_falloff),
+            148
+        );
+        assert_eq!(std::mem::size_of::<f32>(), 4);
+        assert_eq!(std::mem::align_of::<f32>(), 4);
+        assert_eq!(
+            memoffset::offset_of!(VolumetricFogInjectConfigStd140, base_height),
+            152
+        );
+        assert_eq!(std::mem::size_of::<f32>(), 4);
+        assert_eq!(std::mem::align_of::<f32>(), 4);
+        assert_eq!(
+            memoffset::offset_of!(VolumetricFogInjectConfigStd140, anisotropy),
+            156
+        );
+        assert_eq!(std::mem::size_of::<f32>(), 4);
+        assert_eq!(std::mem::align_of::<f32>(), 4);
+        assert_eq!(
+            memoffset::offset_of!(VolumetricFogInjectConfigStd140, max_distance),
+            160
+        );
+        assert_eq!(std::mem::size_of::<f32>(), 4);
+        assert_eq!(std::mem::align_of::<f32>(), 4);
+        assert_eq!(
+            memoffset::offset_of!(VolumetricFogInjectConfigStd140, cluster_near_z),
+            164
+        );
+        assert_eq!(std::mem::size_of::<f32>(), 4);
+        assert_eq!(std::mem::align_of::<f32>(), 4);
+        assert_eq!(
+            memoffset::offset_of!(VolumetricFogInjectConfigStd140, cluster_far_z),
+            168
+        );
+        assert_eq!(std::mem::size_of::<u32>(), 4);
+        assert_eq!(std::mem::align_of::<u32>(), 4);
+        assert_eq!(
+            memoffset::offset_of!(VolumetricFogInjectConfigStd140, cluster_x_bins),
+            172
+        );
+        assert_eq!(std::mem::size_of::<u32>(), 4);
+        assert_eq!(std::mem::align_of::<u32>(), 4);
+        assert_eq!(
+            memoffset::offset_of!(VolumetricFogInjectConfigStd140, cluster_y_bins),
+            176
+        );
+        assert_eq!(std::mem::size_of::<u32>(), 4);
+        assert_eq!(std::mem::align_of::<u32>(), 4);
+        assert_eq!(
+            memoffset::offset_of!(VolumetricFogInjectConfigStd140, cluster_z_bins),
+            180
+        );
+        assert_eq!(std::mem::size_of::<[u8; 8]>(), 8);
+        assert_eq!(std::mem::align_of::<[u8; 8]>(), 1);
+        assert_eq!(
+            memoffset::offset_of!(VolumetricFogInjectConfigStd140, _padding0),
+            184
+        );
+    }
+}
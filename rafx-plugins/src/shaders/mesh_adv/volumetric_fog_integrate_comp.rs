@@ -0,0 +1,170 @@
+// This code is auto-generated by the shader processor.
+
+#[allow(unused_imports)]
+use rafx::RafxResult;
+
+#[allow(unused_imports)]
+use rafx::framework::{
+    DescriptorSetAllocator, DescriptorSetArc, DescriptorSetBindings, DescriptorSetInitializer,
+    DescriptorSetWriter, DescriptorSetWriterContext, DynDescriptorSet, ImageViewResource,
+    ResourceArc,
+};
+
+#[derive(Copy, Clone, Debug)]
+#[repr(C)]
+pub struct VolumetricFogIntegrateConfigStd140 {
+    pub inverse_projection: [[f32; 4]; 4], // +0 (size: 64)
+    pub max_distance: f32,                 // +64 (size: 4)
+    pub _padding0: [u8; 12],               // +68 (size: 12)
+} // 80 bytes
+
+impl Default for VolumetricFogIntegrateConfigStd140 {
+    fn default() -> Self {
+        VolumetricFogIntegrateConfigStd140 {
+            inverse_projection: <[[f32; 4]; 4]>::default(),
+            max_distance: <f32>::default(),
+            _padding0: [u8::default(); 12],
+        }
+    }
+}
+
+pub type VolumetricFogIntegrateConfigUniform = VolumetricFogIntegrateConfigStd140;
+
+pub const CONFIG_DESCRIPTOR_SET_INDEX: usize = 0;
+pub const CONFIG_DESCRIPTOR_BINDING_INDEX: usize = 0;
+pub const SCATTERING_TEX_DESCRIPTOR_SET_INDEX: usize = 0;
+pub const SCATTERING_TEX_DESCRIPTOR_BINDING_INDEX: usize = 1;
+pub const OUTPUT_TEX_DESCRIPTOR_SET_INDEX: usize = 0;
+pub const OUTPUT_TEX_DESCRIPTOR_BINDING_INDEX: usize = 2;
+
+pub struct DescriptorSet0Args<'a> {
+    pub config: &'a VolumetricFogIntegrateConfigUniform,
+    pub scattering_tex: &'a ResourceArc<ImageViewResource>,
+    pub output_tex: &'a ResourceArc<ImageViewResource>,
+}
+
+impl<'a> DescriptorSetInitializer<'a> for DescriptorSet0Args<'a> {
+    type Output = DescriptorSet0;
+
+    fn create_dyn_descriptor_set(
+        descriptor_set: DynDescriptorSet,
+        args: Self,
+    ) -> Self::Output {
+        let mut descriptor = DescriptorSet0(descriptor_set);
+        descriptor.set_args(args);
+        descriptor
+    }
+
+    fn create_descriptor_set(
+        descriptor_set_allocator: &mut DescriptorSetAllocator,
+        descriptor_set: DynDescriptorSet,
+        args: Self,
+    ) -> RafxResult<DescriptorSetArc> {
+        let mut descriptor = Self::create_dyn_descriptor_set(descriptor_set, args);
+        descriptor.0.flush(descriptor_set_allocator)?;
+        Ok(descriptor.0.descriptor_set().clone())
+    }
+}
+
+impl<'a> DescriptorSetWriter<'a> for DescriptorSet0Args<'a> {
+    fn write_to(
+        descriptor_set: &mut DescriptorSetWriterContext,
+        args: Self,
+    ) {
+        descriptor_set.set_buffer_data(CONFIG_DESCRIPTOR_BINDING_INDEX as u32, args.config);
+        descriptor_set.set_image(
+            SCATTERING_TEX_DESCRIPTOR_BINDING_INDEX as u32,
+            args.scattering_tex,
+        );
+        descriptor_set.set_image(OUTPUT_TEX_DESCRIPTOR_BINDING_INDEX as u32, args.output_tex);
+    }
+}
+
+pub struct DescriptorSet0(pub DynDescriptorSet);
+
+impl DescriptorSet0 {
+    pub fn set_args_static(
+        descriptor_set: &mut DynDescriptorSet,
+        args: DescriptorSet0Args,
+    ) {
+        descriptor_set.set_buffer_data(CONFIG_DESCRIPTOR_BINDING_INDEX as u32, args.config);
+        descriptor_set.set_image(
+            SCATTERING_TEX_DESCRIPTOR_BINDING_INDEX as u32,
+            args.scattering_tex,
+        );
+        descriptor_set.set_image(OUTPUT_TEX_DESCRIPTOR_BINDING_INDEX as u32, args.output_tex);
+    }
+
+    pub fn set_args(
+        &mut self,
+        args: DescriptorSet0Args,
+    ) {
+        self.set_config(args.config);
+        self.set_scattering_tex(args.scattering_tex);
+        self.set_output_tex(args.output_tex);
+    }
+
+    pub fn set_config(
+        &mut self,
+        config: &VolumetricFogIntegrateConfigUniform,
+    ) {
+        self.0
+            .set_buffer_data(CONFIG_DESCRIPTOR_BINDING_INDEX as u32, config);
+    }
+
+    pub fn set_scattering_tex(
+        &mut self,
+        scattering_tex: &ResourceArc<ImageViewResource>,
+    ) {
+        self.0.set_image(
+            SCATTERING_TEX_DESCRIPTOR_BINDING_INDEX as u32,
+            scattering_tex,
+        );
+    }
+
+    pub fn set_output_tex(
+        &mut self,
+        output_tex: &ResourceArc<ImageViewResource>,
+    ) {
+        self.0
+            .set_image(OUTPUT_TEX_DESCRIPTOR_BINDING_INDEX as u32, output_tex);
+    }
+
+    pub fn flush(
+        &mut self,
+        descriptor_set_allocator: &mut DescriptorSetAllocator,
+    ) -> RafxResult<()> {
+        self.0.flush(descriptor_set_allocator)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_struct_volumetric_fog_integrate_config_std140() {
+        assert_eq!(
+            std::mem::size_of::<VolumetricFogIntegrateConfigStd140>(),
+            80
+        );
+        assert_eq!(std::mem::size_of::<[[f32; 4]; 4]>(), 64);
+        assert_eq!(std::mem::align_of::<[[f32; 4]; 4]>(), 4);
+        assert_eq!(
+            memoffset::offset_of!(VolumetricFogIntegrateConfigStd140, inverse_projection),
+            0
+        );
+        assert_eq!(std::mem::size_of::<f32>(), 4);
+        assert_eq!(std::mem::align_of::<f32>(), 4);
+        assert_eq!(
+            memoffset::offset_of!(VolumetricFogIntegrateConfigStd140, max_distance),
+            64
+        );
+        assert_eq!(std::mem::size_of::<[u8; 12]>(), 12);
+        assert_eq!(std::mem::align_of::<[u8; 12]>(), 1);
+        assert_eq!(
+            memoffset::offset_of!(VolumetricFogIntegrateConfigStd140, _padding0),
+            68
+        );
+    }
+}
@@ -5,11 +5,13 @@
 pub mod cas;
 pub mod debug3d;
 pub mod debug_pip;
+pub mod decal;
 pub mod depth;
 pub mod depth_velocity;
 pub mod egui;
 pub mod imgui;
 pub mod mesh_adv;
+pub mod particles;
 pub mod post_adv;
 pub mod post_basic;
 pub mod post_shared;
@@ -0,0 +1,11 @@
+// This code is auto-generated by the shader processor.
+
+#![allow(dead_code)]
+
+pub mod particle_frag;
+pub mod particle_indirect_args_comp;
+pub mod particle_init_comp;
+pub mod particle_oit_frag;
+pub mod particle_simulate_comp;
+pub mod particle_spawn_comp;
+pub mod particle_vert;
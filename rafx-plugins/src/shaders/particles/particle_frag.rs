@@ -0,0 +1,371 @@
+// This code is auto-generated by the shader processor.
+
+#[allow(unused_imports)]
+use rafx::RafxResult;
+
+#[allow(unused_imports)]
+use rafx::framework::{
+    DescriptorSetAllocator, DescriptorSetArc, DescriptorSetBindings, DescriptorSetInitializer,
+    DescriptorSetWriter, DescriptorSetWriterContext, DynDescriptorSet, ImageViewResource,
+    ResourceArc,
+};
+
+#[derive(Copy, Clone, Debug)]
+#[repr(C)]
+pub struct PerViewDataStd140 {
+    pub view: [[f32; 4]; 4],     // +0 (size: 64)
+    pub proj: [[f32; 4]; 4],     // +64 (size: 64)
+    pub jitter_amount: [f32; 2], // +128 (size: 8)
+    pub _padding0: [u8; 8],      // +136 (size: 8)
+} // 144 bytes
+
+impl Default for PerViewDataStd140 {
+    fn default() -> Self {
+        PerViewDataStd140 {
+            view: <[[f32; 4]; 4]>::default(),
+            proj: <[[f32; 4]; 4]>::default(),
+            jitter_amount: <[f32; 2]>::default(),
+            _padding0: [u8::default(); 8],
+        }
+    }
+}
+
+pub type PerViewDataUniform = PerViewDataStd140;
+
+#[derive(Copy, Clone, Debug)]
+#[repr(C)]
+pub struct PerEmitterDataStd140 {
+    pub start_color: [f32; 4],       // +0 (size: 16)
+    pub end_color: [f32; 4],         // +16 (size: 16)
+    pub start_size: f32,             // +32 (size: 4)
+    pub end_size: f32,               // +36 (size: 4)
+    pub soft_particle_distance: f32, // +40 (size: 4)
+    pub has_texture: u32,            // +44 (size: 4)
+} // 48 bytes
+
+impl Default for PerEmitterDataStd140 {
+    fn default() -> Self {
+        PerEmitterDataStd140 {
+            start_color: <[f32; 4]>::default(),
+            end_color: <[f32; 4]>::default(),
+            start_size: <f32>::default(),
+            end_size: <f32>::default(),
+            soft_particle_distance: <f32>::default(),
+            has_texture: <u32>::default(),
+        }
+    }
+}
+
+pub type PerEmitterDataUniform = PerEmitterDataStd140;
+
+pub const PER_VIEW_DATA_DESCRIPTOR_SET_INDEX: usize = 0;
+pub const PER_VIEW_DATA_DESCRIPTOR_BINDING_INDEX: usize = 0;
+pub const SMP_DESCRIPTOR_SET_INDEX: usize = 0;
+pub const SMP_DESCRIPTOR_BINDING_INDEX: usize = 1;
+pub const SMP_DEPTH_DESCRIPTOR_SET_INDEX: usize = 0;
+pub const SMP_DEPTH_DESCRIPTOR_BINDING_INDEX: usize = 2;
+pub const DEPTH_TEXTURE_DESCRIPTOR_SET_INDEX: usize = 1;
+pub const DEPTH_TEXTURE_DESCRIPTOR_BINDING_INDEX: usize = 0;
+pub const PER_EMITTER_DATA_DESCRIPTOR_SET_INDEX: usize = 2;
+pub const PER_EMITTER_DATA_DESCRIPTOR_BINDING_INDEX: usize = 0;
+pub const PARTICLE_TEXTURE_DESCRIPTOR_SET_INDEX: usize = 2;
+pub const PARTICLE_TEXTURE_DESCRIPTOR_BINDING_INDEX: usize = 1;
+pub const PARTICLES_DESCRIPTOR_SET_INDEX: usize = 2;
+pub const PARTICLES_DESCRIPTOR_BINDING_INDEX: usize = 2;
+pub const ALIVE_LIST_DESCRIPTOR_SET_INDEX: usize = 2;
+pub const ALIVE_LIST_DESCRIPTOR_BINDING_INDEX: usize = 3;
+
+pub struct DescriptorSet0Args<'a> {
+    pub per_view_data: &'a PerViewDataUniform,
+}
+
+impl<'a> DescriptorSetInitializer<'a> for DescriptorSet0Args<'a> {
+    type Output = DescriptorSet0;
+
+    fn create_dyn_descriptor_set(
+        descriptor_set: DynDescriptorSet,
+        args: Self,
+    ) -> Self::Output {
+        let mut descriptor = DescriptorSet0(descriptor_set);
+        descriptor.set_args(args);
+        descriptor
+    }
+
+    fn create_descriptor_set(
+        descriptor_set_allocator: &mut DescriptorSetAllocator,
+        descriptor_set: DynDescriptorSet,
+        args: Self,
+    ) -> RafxResult<DescriptorSetArc> {
+        let mut descriptor = Self::create_dyn_descriptor_set(descriptor_set, args);
+        descriptor.0.flush(descriptor_set_allocator)?;
+        Ok(descriptor.0.descriptor_set().clone())
+    }
+}
+
+impl<'a> DescriptorSetWriter<'a> for DescriptorSet0Args<'a> {
+    fn write_to(
+        descriptor_set: &mut DescriptorSetWriterContext,
+        args: Self,
+    ) {
+        descriptor_set.set_buffer_data(
+            PER_VIEW_DATA_DESCRIPTOR_BINDING_INDEX as u32,
+            args.per_view_data,
+        );
+    }
+}
+
+pub struct DescriptorSet0(pub DynDescriptorSet);
+
+impl DescriptorSet0 {
+    pub fn set_args_static(
+        descriptor_set: &mut DynDescriptorSet,
+        args: DescriptorSet0Args,
+    ) {
+        descriptor_set.set_buffer_data(
+            PER_VIEW_DATA_DESCRIPTOR_BINDING_INDEX as u32,
+            args.per_view_data,
+        );
+    }
+
+    pub fn set_args(
+        &mut self,
+        args: DescriptorSet0Args,
+    ) {
+        self.set_per_view_data(args.per_view_data);
+    }
+
+    pub fn set_per_view_data(
+        &mut self,
+        per_view_data: &PerViewDataUniform,
+    ) {
+        self.0
+            .set_buffer_data(PER_VIEW_DATA_DESCRIPTOR_BINDING_INDEX as u32, per_view_data);
+    }
+
+    pub fn flush(
+        &mut self,
+        descriptor_set_allocator: &mut DescriptorSetAllocator,
+    ) -> RafxResult<()> {
+        self.0.flush(descriptor_set_allocator)
+    }
+}
+
+pub struct DescriptorSet1Args<'a> {
+    pub depth_texture: &'a ResourceArc<ImageViewResource>,
+}
+
+impl<'a> DescriptorSetInitializer<'a> for DescriptorSet1Args<'a> {
+    type Output = DescriptorSet1;
+
+    fn create_dyn_descriptor_set(
+        descriptor_set: DynDescriptorSet,
+        args: Self,
+    ) -> Self::Output {
+        let mut descriptor = DescriptorSet1(descriptor_set);
+        descriptor.set_args(args);
+        descriptor
+    }
+
+    fn create_descriptor_set(
+        descriptor_set_allocator: &mut DescriptorSetAllocator,
+        descriptor_set: DynDescriptorSet,
+        args: Self,
+    ) -> RafxResult<DescriptorSetArc> {
+        let mut descriptor = Self::create_dyn_descriptor_set(descriptor_set, args);
+        descriptor.0.flush(descriptor_set_allocator)?;
+        Ok(descriptor.0.descriptor_set().clone())
+    }
+}
+
+impl<'a> DescriptorSetWriter<'a> for DescriptorSet1Args<'a> {
+    fn write_to(
+        descriptor_set: &mut DescriptorSetWriterContext,
+        args: Self,
+    ) {
+        descriptor_set.set_image(
+            DEPTH_TEXTURE_DESCRIPTOR_BINDING_INDEX as u32,
+            args.depth_texture,
+        );
+    }
+}
+
+pub struct DescriptorSet1(pub DynDescriptorSet);
+
+impl DescriptorSet1 {
+    pub fn set_args_static(
+        descriptor_set: &mut DynDescriptorSet,
+        args: DescriptorSet1Args,
+    ) {
+        descriptor_set.set_image(
+            DEPTH_TEXTURE_DESCRIPTOR_BINDING_INDEX as u32,
+            args.depth_texture,
+        );
+    }
+
+    pub fn set_args(
+        &mut self,
+        args: DescriptorSet1Args,
+    ) {
+        self.set_depth_texture(args.depth_texture);
+    }
+
+    pub fn set_depth_texture(
+        &mut self,
+        depth_texture: &ResourceArc<ImageViewResource>,
+    ) {
+        self.0
+            .set_image(DEPTH_TEXTURE_DESCRIPTOR_BINDING_INDEX as u32, depth_texture);
+    }
+
+    pub fn flush(
+        &mut self,
+        descriptor_set_allocator: &mut DescriptorSetAllocator,
+    ) -> RafxResult<()> {
+        self.0.flush(descriptor_set_allocator)
+    }
+}
+
+pub struct DescriptorSet2Args<'a> {
+    pub per_emitter_data: &'a PerEmitterDataUniform,
+    pub particle_texture: &'a ResourceArc<ImageViewResource>,
+}
+
+impl<'a> DescriptorSetInitializer<'a> for DescriptorSet2Args<'a> {
+    type Output = DescriptorSet2;
+
+    fn create_dyn_descriptor_set(
+        descriptor_set: DynDescriptorSet,
+        args: Self,
+    ) -> Self::Output {
+        let mut descriptor = DescriptorSet2(descriptor_set);
+        descriptor.set_args(args);
+        descriptor
+    }
+
+    fn create_descriptor_set(
+        descriptor_set_allocator: &mut DescriptorSetAllocator,
+        descriptor_set: DynDescriptorSet,
+        args: Self,
+    ) -> RafxResult<DescriptorSetArc> {
+        let mut descriptor = Self::create_dyn_descriptor_set(descriptor_set, args);
+        descriptor.0.flush(descriptor_set_allocator)?;
+        Ok(descriptor.0.descriptor_set().clone())
+    }
+}
+
+impl<'a> DescriptorSetWriter<'a> for DescriptorSet2Args<'a> {
+    fn write_to(
+        descriptor_set: &mut DescriptorSetWriterContext,
+        args: Self,
+    ) {
+        descriptor_set.set_buffer_data(
+            PER_EMITTER_DATA_DESCRIPTOR_BINDING_INDEX as u32,
+            args.per_emitter_data,
+        );
+        descriptor_set.set_image(
+            PARTICLE_TEXTURE_DESCRIPTOR_BINDING_INDEX as u32,
+            args.particle_texture,
+        );
+    }
+}
+
+pub struct DescriptorSet2(pub DynDescriptorSet);
+
+impl DescriptorSet2 {
+    pub fn set_args_static(
+        descriptor_set: &mut DynDescriptorSet,
+        args: DescriptorSet2Args,
+    ) {
+        descriptor_set.set_buffer_data(
+            PER_EMITTER_DATA_DESCRIPTOR_BINDING_INDEX as u32,
+            args.per_emitter_data,
+        );
+        descriptor_set.set_image(
+            PARTICLE_TEXTURE_DESCRIPTOR_BINDING_INDEX as u32,
+            args.particle_texture,
+        );
+    }
+
+    pub fn set_args(
+        &mut self,
+        args: DescriptorSet2Args,
+    ) {
+        self.set_per_emitter_data(args.per_emitter_data);
+        self.set_particle_texture(args.particle_texture);
+    }
+
+    pub fn set_per_emitter_data(
+        &mut self,
+        per_emitter_data: &PerEmitterDataUniform,
+    ) {
+        self.0.set_buffer_data(
+            PER_EMITTER_DATA_DESCRIPTOR_BINDING_INDEX as u32,
+            per_emitter_data,
+        );
+    }
+
+    pub fn set_particle_texture(
+        &mut self,
+        particle_texture: &ResourceArc<ImageViewResource>,
+    ) {
+        self.0.set_image(
+            PARTICLE_TEXTURE_DESCRIPTOR_BINDING_INDEX as u32,
+            particle_texture,
+        );
+    }
+
+    pub fn flush(
+        &mut self,
+        descriptor_set_allocator: &mut DescriptorSetAllocator,
+    ) -> RafxResult<()> {
+        self.0.flush(descriptor_set_allocator)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_struct_per_view_data_std140() {
+        assert_eq!(std::mem::size_of::<PerViewDataStd140>(), 144);
+        assert_eq!(std::mem::size_of::<[[f32; 4]; 4]>(), 64);
+        assert_eq!(std::mem::align_of::<[[f32; 4]; 4]>(), 4);
+        assert_eq!(memoffset::offset_of!(PerViewDataStd140, view), 0);
+        assert_eq!(std::mem::size_of::<[[f32; 4]; 4]>(), 64);
+        assert_eq!(std::mem::align_of::<[[f32; 4]; 4]>(), 4);
+        assert_eq!(memoffset::offset_of!(PerViewDataStd140, proj), 64);
+        assert_eq!(std::mem::size_of::<[f32; 2]>(), 8);
+        assert_eq!(std::mem::align_of::<[f32; 2]>(), 4);
+        assert_eq!(memoffset::offset_of!(PerViewDataStd140, jitter_amount), 128);
+        assert_eq!(std::mem::size_of::<[u8; 8]>(), 8);
+        assert_eq!(std::mem::align_of::<[u8; 8]>(), 1);
+        assert_eq!(memoffset::offset_of!(PerViewDataStd140, _padding0), 136);
+    }
+
+    #[test]
+    fn test_struct_per_emitter_data_std140() {
+        assert_eq!(std::mem::size_of::<PerEmitterDataStd140>(), 48);
+        assert_eq!(std::mem::size_of::<[f32; 4]>(), 16);
+        assert_eq!(std::mem::align_of::<[f32; 4]>(), 4);
+        assert_eq!(memoffset::offset_of!(PerEmitterDataStd140, start_color), 0);
+        assert_eq!(std::mem::size_of::<[f32; 4]>(), 16);
+        assert_eq!(std::mem::align_of::<[f32; 4]>(), 4);
+        assert_eq!(memoffset::offset_of!(PerEmitterDataStd140, end_color), 16);
+        assert_eq!(std::mem::size_of::<f32>(), 4);
+        assert_eq!(std::mem::align_of::<f32>(), 4);
+        assert_eq!(memoffset::offset_of!(PerEmitterDataStd140, start_size), 32);
+        assert_eq!(std::mem::size_of::<f32>(), 4);
+        assert_eq!(std::mem::align_of::<f32>(), 4);
+        assert_eq!(memoffset::offset_of!(PerEmitterDataStd140, end_size), 36);
+        assert_eq!(std::mem::size_of::<f32>(), 4);
+        assert_eq!(std::mem::align_of::<f32>(), 4);
+        assert_eq!(
+            memoffset::offset_of!(PerEmitterDataStd140, soft_particle_distance),
+            40
+        );
+        assert_eq!(std::mem::size_of::<u32>(), 4);
+        assert_eq!(std::mem::align_of::<u32>(), 4);
+        assert_eq!(memoffset::offset_of!(PerEmitterDataStd140, has_texture), 44);
+    }
+}
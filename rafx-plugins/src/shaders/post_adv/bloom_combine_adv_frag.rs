@@ -16,8 +16,12 @@ pub struct ConfigStd140 {
     pub tonemapper_type: i32,           // +0 (size: 4)
     pub output_color_space: i32,        // +4 (size: 4)
     pub max_color_component_value: f32, // +8 (size: 4)
-    pub _padding0: [u8; 4],             // +12 (size: 4)
-} // 16 bytes
+    pub enable_color_grading: u32,      // +12 (size: 4)
+    pub color_grading_blend: f32,       // +16 (size: 4)
+    pub color_grading_strength: f32,    // +20 (size: 4)
+    pub paper_white_nits: f32,          // +24 (size: 4)
+    pub peak_nits: f32,                 // +28 (size: 4)
+} // 32 bytes
 
 impl Default for ConfigStd140 {
     fn default() -> Self {
@@ -25,7 +29,11 @@ impl Default for ConfigStd140 {
             tonemapper_type: <i32>::default(),
             output_color_space: <i32>::default(),
             max_color_component_value: <f32>::default(),
-            _padding0: [u8::default(); 4],
+            enable_color_grading: <u32>::default(),
+            color_grading_blend: <f32>::default(),
+            color_grading_strength: <f32>::default(),
+            paper_white_nits: <f32>::default(),
+            peak_nits: <f32>::default(),
         }
     }
 }
@@ -42,11 +50,19 @@ pub const CONFIG_DESCRIPTOR_SET_INDEX: usize = 0;
 pub const CONFIG_DESCRIPTOR_BINDING_INDEX: usize = 3;
 pub const HISTOGRAM_RESULT_DESCRIPTOR_SET_INDEX: usize = 0;
 pub const HISTOGRAM_RESULT_DESCRIPTOR_BINDING_INDEX: usize = 4;
+pub const COLOR_GRADING_LUT_DESCRIPTOR_SET_INDEX: usize = 0;
+pub const COLOR_GRADING_LUT_DESCRIPTOR_BINDING_INDEX: usize = 5;
+pub const COLOR_GRADING_BLEND_LUT_DESCRIPTOR_SET_INDEX: usize = 0;
+pub const COLOR_GRADING_BLEND_LUT_DESCRIPTOR_BINDING_INDEX: usize = 6;
+pub const SMP_LUT_DESCRIPTOR_SET_INDEX: usize = 0;
+pub const SMP_LUT_DESCRIPTOR_BINDING_INDEX: usize = 7;
 
 pub struct DescriptorSet0Args<'a> {
     pub in_color: &'a ResourceArc<ImageViewResource>,
     pub in_blur: &'a ResourceArc<ImageViewResource>,
     pub config: &'a ConfigUniform,
+    pub color_grading_lut: &'a ResourceArc<ImageViewResource>,
+    pub color_grading_blend_lut: &'a ResourceArc<ImageViewResource>,
 }
 
 impl<'a> DescriptorSetInitializer<'a> for DescriptorSet0Args<'a> {
@@ -80,6 +96,14 @@ impl<'a> DescriptorSetWriter<'a> for DescriptorSet0Args<'a> {
         descriptor_set.set_image(IN_COLOR_DESCRIPTOR_BINDING_INDEX as u32, args.in_color);
         descriptor_set.set_image(IN_BLUR_DESCRIPTOR_BINDING_INDEX as u32, args.in_blur);
         descriptor_set.set_buffer_data(CONFIG_DESCRIPTOR_BINDING_INDEX as u32, args.config);
+        descriptor_set.set_image(
+            COLOR_GRADING_LUT_DESCRIPTOR_BINDING_INDEX as u32,
+            args.color_grading_lut,
+        );
+        descriptor_set.set_image(
+            COLOR_GRADING_BLEND_LUT_DESCRIPTOR_BINDING_INDEX as u32,
+            args.color_grading_blend_lut,
+        );
     }
 }
 
@@ -93,6 +117,14 @@ impl DescriptorSet0 {
         descriptor_set.set_image(IN_COLOR_DESCRIPTOR_BINDING_INDEX as u32, args.in_color);
         descriptor_set.set_image(IN_BLUR_DESCRIPTOR_BINDING_INDEX as u32, args.in_blur);
         descriptor_set.set_buffer_data(CONFIG_DESCRIPTOR_BINDING_INDEX as u32, args.config);
+        descriptor_set.set_image(
+            COLOR_GRADING_LUT_DESCRIPTOR_BINDING_INDEX as u32,
+            args.color_grading_lut,
+        );
+        descriptor_set.set_image(
+            COLOR_GRADING_BLEND_LUT_DESCRIPTOR_BINDING_INDEX as u32,
+            args.color_grading_blend_lut,
+        );
     }
 
     pub fn set_args(
@@ -102,6 +134,8 @@ impl DescriptorSet0 {
         self.set_in_color(args.in_color);
         self.set_in_blur(args.in_blur);
         self.set_config(args.config);
+        self.set_color_grading_lut(args.color_grading_lut);
+        self.set_color_grading_blend_lut(args.color_grading_blend_lut);
     }
 
     pub fn set_in_color(
@@ -128,6 +162,26 @@ impl DescriptorSet0 {
             .set_buffer_data(CONFIG_DESCRIPTOR_BINDING_INDEX as u32, config);
     }
 
+    pub fn set_color_grading_lut(
+        &mut self,
+        color_grading_lut: &ResourceArc<ImageViewResource>,
+    ) {
+        self.0.set_image(
+            COLOR_GRADING_LUT_DESCRIPTOR_BINDING_INDEX as u32,
+            color_grading_lut,
+        );
+    }
+
+    pub fn set_color_grading_blend_lut(
+        &mut self,
+        color_grading_blend_lut: &ResourceArc<ImageViewResource>,
+    ) {
+        self.0.set_image(
+            COLOR_GRADING_BLEND_LUT_DESCRIPTOR_BINDING_INDEX as u32,
+            color_grading_blend_lut,
+        );
+    }
+
     pub fn flush(
         &mut self,
         descriptor_set_allocator: &mut DescriptorSetAllocator,
@@ -142,7 +196,7 @@ mod test {
 
     #[test]
     fn test_struct_config_std140() {
-        assert_eq!(std::mem::size_of::<ConfigStd140>(), 16);
+        assert_eq!(std::mem::size_of::<ConfigStd140>(), 32);
         assert_eq!(std::mem::size_of::<i32>(), 4);
         assert_eq!(std::mem::align_of::<i32>(), 4);
         assert_eq!(memoffset::offset_of!(ConfigStd140, tonemapper_type), 0);
@@ -155,8 +209,26 @@ mod test {
             memoffset::offset_of!(ConfigStd140, max_color_component_value),
             8
         );
-        assert_eq!(std::mem::size_of::<[u8; 4]>(), 4);
-        assert_eq!(std::mem::align_of::<[u8; 4]>(), 1);
-        assert_eq!(memoffset::offset_of!(ConfigStd140, _padding0), 12);
+        assert_eq!(std::mem::size_of::<u32>(), 4);
+        assert_eq!(std::mem::align_of::<u32>(), 4);
+        assert_eq!(
+            memoffset::offset_of!(ConfigStd140, enable_color_grading),
+            12
+        );
+        assert_eq!(std::mem::size_of::<f32>(), 4);
+        assert_eq!(std::mem::align_of::<f32>(), 4);
+        assert_eq!(memoffset::offset_of!(ConfigStd140, color_grading_blend), 16);
+        assert_eq!(std::mem::size_of::<f32>(), 4);
+        assert_eq!(std::mem::align_of::<f32>(), 4);
+        assert_eq!(
+            memoffset::offset_of!(ConfigStd140, color_grading_strength),
+            20
+        );
+        assert_eq!(std::mem::size_of::<f32>(), 4);
+        assert_eq!(std::mem::align_of::<f32>(), 4);
+        assert_eq!(memoffset::offset_of!(ConfigStd140, paper_white_nits), 24);
+        assert_eq!(std::mem::size_of::<f32>(), 4);
+        assert_eq!(std::mem::align_of::<f32>(), 4);
+        assert_eq!(memoffset::offset_of!(ConfigStd140, peak_nits), 28);
     }
 }
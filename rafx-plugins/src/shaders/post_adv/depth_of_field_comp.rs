@@ -0,0 +1,209 @@
+// This code is auto-generated by the shader processor.
+
+#[allow(unused_imports)]
+use rafx::RafxResult;
+
+#[allow(unused_imports)]
+use rafx::framework::{
+    DescriptorSetAllocator, DescriptorSetArc, DescriptorSetBindings, DescriptorSetInitializer,
+    DescriptorSetWriter, DescriptorSetWriterContext, DynDescriptorSet, ImageViewResource,
+    ResourceArc,
+};
+
+#[derive(Copy, Clone, Debug)]
+#[repr(C)]
+pub struct DepthOfFieldConfigStd140 {
+    pub depth_to_distance: [f32; 2], // +0 (size: 8)
+    pub focus_distance: f32,         // +8 (size: 4)
+    pub coc_scale: f32,              // +12 (size: 4)
+    pub max_coc_radius: f32,         // +16 (size: 4)
+    pub sample_spacing: f32,         // +20 (size: 4)
+    pub viewport_width: u32,         // +24 (size: 4)
+    pub viewport_height: u32,        // +28 (size: 4)
+} // 32 bytes
+
+impl Default for DepthOfFieldConfigStd140 {
+    fn default() -> Self {
+        DepthOfFieldConfigStd140 {
+            depth_to_distance: <[f32; 2]>::default(),
+            focus_distance: <f32>::default(),
+            coc_scale: <f32>::default(),
+            max_coc_radius: <f32>::default(),
+            sample_spacing: <f32>::default(),
+            viewport_width: <u32>::default(),
+            viewport_height: <u32>::default(),
+        }
+    }
+}
+
+pub type DepthOfFieldConfigUniform = DepthOfFieldConfigStd140;
+
+pub const CONFIG_DESCRIPTOR_SET_INDEX: usize = 0;
+pub const CONFIG_DESCRIPTOR_BINDING_INDEX: usize = 0;
+pub const COLOR_TEX_DESCRIPTOR_SET_INDEX: usize = 0;
+pub const COLOR_TEX_DESCRIPTOR_BINDING_INDEX: usize = 1;
+pub const DEPTH_TEX_DESCRIPTOR_SET_INDEX: usize = 0;
+pub const DEPTH_TEX_DESCRIPTOR_BINDING_INDEX: usize = 2;
+pub const SMP_NEAREST_DESCRIPTOR_SET_INDEX: usize = 0;
+pub const SMP_NEAREST_DESCRIPTOR_BINDING_INDEX: usize = 3;
+pub const SMP_BILINEAR_DESCRIPTOR_SET_INDEX: usize = 0;
+pub const SMP_BILINEAR_DESCRIPTOR_BINDING_INDEX: usize = 4;
+pub const OUTPUT_TEX_DESCRIPTOR_SET_INDEX: usize = 0;
+pub const OUTPUT_TEX_DESCRIPTOR_BINDING_INDEX: usize = 5;
+
+pub struct DescriptorSet0Args<'a> {
+    pub config: &'a DepthOfFieldConfigUniform,
+    pub color_tex: &'a ResourceArc<ImageViewResource>,
+    pub depth_tex: &'a ResourceArc<ImageViewResource>,
+    pub output_tex: &'a ResourceArc<ImageViewResource>,
+}
+
+impl<'a> DescriptorSetInitializer<'a> for DescriptorSet0Args<'a> {
+    type Output = DescriptorSet0;
+
+    fn create_dyn_descriptor_set(
+        descriptor_set: DynDescriptorSet,
+        args: Self,
+    ) -> Self::Output {
+        let mut descriptor = DescriptorSet0(descriptor_set);
+        descriptor.set_args(args);
+        descriptor
+    }
+
+    fn create_descriptor_set(
+        descriptor_set_allocator: &mut DescriptorSetAllocator,
+        descriptor_set: DynDescriptorSet,
+        args: Self,
+    ) -> RafxResult<DescriptorSetArc> {
+        let mut descriptor = Self::create_dyn_descriptor_set(descriptor_set, args);
+        descriptor.0.flush(descriptor_set_allocator)?;
+        Ok(descriptor.0.descriptor_set().clone())
+    }
+}
+
+impl<'a> DescriptorSetWriter<'a> for DescriptorSet0Args<'a> {
+    fn write_to(
+        descriptor_set: &mut DescriptorSetWriterContext,
+        args: Self,
+    ) {
+        descriptor_set.set_buffer_data(CONFIG_DESCRIPTOR_BINDING_INDEX as u32, args.config);
+        descriptor_set.set_image(COLOR_TEX_DESCRIPTOR_BINDING_INDEX as u32, args.color_tex);
+        descriptor_set.set_image(DEPTH_TEX_DESCRIPTOR_BINDING_INDEX as u32, args.depth_tex);
+        descriptor_set.set_image(OUTPUT_TEX_DESCRIPTOR_BINDING_INDEX as u32, args.output_tex);
+    }
+}
+
+pub struct DescriptorSet0(pub DynDescriptorSet);
+
+impl DescriptorSet0 {
+    pub fn set_args_static(
+        descriptor_set: &mut DynDescriptorSet,
+        args: DescriptorSet0Args,
+    ) {
+        descriptor_set.set_buffer_data(CONFIG_DESCRIPTOR_BINDING_INDEX as u32, args.config);
+        descriptor_set.set_image(COLOR_TEX_DESCRIPTOR_BINDING_INDEX as u32, args.color_tex);
+        descriptor_set.set_image(DEPTH_TEX_DESCRIPTOR_BINDING_INDEX as u32, args.depth_tex);
+        descriptor_set.set_image(OUTPUT_TEX_DESCRIPTOR_BINDING_INDEX as u32, args.output_tex);
+    }
+
+    pub fn set_args(
+        &mut self,
+        args: DescriptorSet0Args,
+    ) {
+        self.set_config(args.config);
+        self.set_color_tex(args.color_tex);
+        self.set_depth_tex(args.depth_tex);
+        self.set_output_tex(args.output_tex);
+    }
+
+    pub fn set_config(
+        &mut self,
+        config: &DepthOfFieldConfigUniform,
+    ) {
+        self.0
+            .set_buffer_data(CONFIG_DESCRIPTOR_BINDING_INDEX as u32, config);
+    }
+
+    pub fn set_color_tex(
+        &mut self,
+        color_tex: &ResourceArc<ImageViewResource>,
+    ) {
+        self.0
+            .set_image(COLOR_TEX_DESCRIPTOR_BINDING_INDEX as u32, color_tex);
+    }
+
+    pub fn set_depth_tex(
+        &mut self,
+        depth_tex: &ResourceArc<ImageViewResource>,
+    ) {
+        self.0
+            .set_image(DEPTH_TEX_DESCRIPTOR_BINDING_INDEX as u32, depth_tex);
+    }
+
+    pub fn set_output_tex(
+        &mut self,
+        output_tex: &ResourceArc<ImageViewResource>,
+    ) {
+        self.0
+            .set_image(OUTPUT_TEX_DESCRIPTOR_BINDING_INDEX as u32, output_tex);
+    }
+
+    pub fn flush(
+        &mut self,
+        descriptor_set_allocator: &mut DescriptorSetAllocator,
+    ) -> RafxResult<()> {
+        self.0.flush(descriptor_set_allocator)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_struct_depth_of_field_config_std140() {
+        assert_eq!(std::mem::size_of::<DepthOfFieldConfigStd140>(), 32);
+        assert_eq!(std::mem::size_of::<[f32; 2]>(), 8);
+        assert_eq!(std::mem::align_of::<[f32; 2]>(), 4);
+        assert_eq!(
+            memoffset::offset_of!(DepthOfFieldConfigStd140, depth_to_distance),
+            0
+        );
+        assert_eq!(std::mem::size_of::<f32>(), 4);
+        assert_eq!(std::mem::align_of::<f32>(), 4);
+        assert_eq!(
+            memoffset::offset_of!(DepthOfFieldConfigStd140, focus_distance),
+            8
+        );
+        assert_eq!(std::mem::size_of::<f32>(), 4);
+        assert_eq!(std::mem::align_of::<f32>(), 4);
+        assert_eq!(
+            memoffset::offset_of!(DepthOfFieldConfigStd140, coc_scale),
+            12
+        );
+        assert_eq!(std::mem::size_of::<f32>(), 4);
+        assert_eq!(std::mem::align_of::<f32>(), 4);
+        assert_eq!(
+            memoffset::offset_of!(DepthOfFieldConfigStd140, max_coc_radius),
+            16
+        );
+        assert_eq!(std::mem::size_of::<f32>(), 4);
+        assert_eq!(std::mem::align_of::<f32>(), 4);
+        assert_eq!(
+            memoffset::offset_of!(DepthOfFieldConfigStd140, sample_spacing),
+            20
+        );
+        assert_eq!(std::mem::size_of::<u32>(), 4);
+        assert_eq!(std::mem::align_of::<u32>(), 4);
+        assert_eq!(
+            memoffset::offset_of!(DepthOfFieldConfigStd140, viewport_width),
+            24
+        );
+        assert_eq!(std::mem::size_of::<u32>(), 4);
+        assert_eq!(std::mem::align_of::<u32>(), 4);
+        assert_eq!(
+            memoffset::offset_of!(DepthOfFieldConfigStd140, viewport_height),
+            28
+        );
+    }
+}
@@ -3,6 +3,11 @@
 #![allow(dead_code)]
 
 pub mod bloom_combine_adv_frag;
+pub mod depth_of_field_comp;
 pub mod luma_average_histogram_comp;
 pub mod luma_build_histogram_comp;
+pub mod motion_blur_comp;
+pub mod oit_composite_frag;
+pub mod ssr_comp;
+pub mod ssr_composite_frag;
 pub mod taa_frag;
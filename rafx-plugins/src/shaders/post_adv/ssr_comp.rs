@@ -0,0 +1,331 @@
+// This code is auto-generated by the shader processor.
+
+#[allow(unused_imports)]
+use rafx::RafxResult;
+
+#[allow(unused_imports)]
+use rafx::framework::{
+    DescriptorSetAllocator, DescriptorSetArc, DescriptorSetBindings, DescriptorSetInitializer,
+    DescriptorSetWriter, DescriptorSetWriterContext, DynDescriptorSet, ImageViewResource,
+    ResourceArc,
+};
+
+#[derive(Copy, Clone, Debug)]
+#[repr(C)]
+pub struct SsrConfigStd140 {
+    pub proj_matrix: [[f32; 4]; 4],           // +0 (size: 64)
+    pub inverse_proj_matrix: [[f32; 4]; 4],   // +64 (size: 64)
+    pub current_view_proj_inv: [[f32; 4]; 4], // +128 (size: 64)
+    pub previous_view_proj: [[f32; 4]; 4],    // +192 (size: 64)
+    pub viewport_width: u32,                  // +256 (size: 4)
+    pub viewport_height: u32,                 // +260 (size: 4)
+    pub depth_mip_slice_count: u32,           // +264 (size: 4)
+    pub max_steps: u32,                       // +268 (size: 4)
+    pub z_near: f32,                          // +272 (size: 4)
+    pub max_distance: f32,                    // +276 (size: 4)
+    pub thickness: f32,                       // +280 (size: 4)
+    pub max_roughness: f32,                   // +284 (size: 4)
+    pub edge_fade: f32,                       // +288 (size: 4)
+    pub current_frame_weight: f32,            // +292 (size: 4)
+    pub has_history_data: u32,                // +296 (size: 4)
+    pub _padding0: [u8; 4],                   // +300 (size: 4)
+} // 304 bytes
+
+impl Default for SsrConfigStd140 {
+    fn default() -> Self {
+        SsrConfigStd140 {
+            proj_matrix: <[[f32; 4]; 4]>::default(),
+            inverse_proj_matrix: <[[f32; 4]; 4]>::default(),
+            current_view_proj_inv: <[[f32; 4]; 4]>::default(),
+            previous_view_proj: <[[f32; 4]; 4]>::default(),
+            viewport_width: <u32>::default(),
+            viewport_height: <u32>::default(),
+            depth_mip_slice_count: <u32>::default(),
+            max_steps: <u32>::default(),
+            z_near: <f32>::default(),
+            max_distance: <f32>::default(),
+            thickness: <f32>::default(),
+            max_roughness: <f32>::default(),
+            edge_fade: <f32>::default(),
+            current_frame_weight: <f32>::default(),
+            has_history_data: <u32>::default(),
+            _padding0: [u8::default(); 4],
+        }
+    }
+}
+
+pub type SsrConfigUniform = SsrConfigStd140;
+
+pub const CONFIG_DESCRIPTOR_SET_INDEX: usize = 0;
+pub const CONFIG_DESCRIPTOR_BINDING_INDEX: usize = 0;
+pub const DEPTH_MIP_SLICES_DESCRIPTOR_SET_INDEX: usize = 0;
+pub const DEPTH_MIP_SLICES_DESCRIPTOR_BINDING_INDEX: usize = 1;
+pub const GBUFFER_TEX_DESCRIPTOR_SET_INDEX: usize = 0;
+pub const GBUFFER_TEX_DESCRIPTOR_BINDING_INDEX: usize = 2;
+pub const COLOR_TEX_DESCRIPTOR_SET_INDEX: usize = 0;
+pub const COLOR_TEX_DESCRIPTOR_BINDING_INDEX: usize = 3;
+pub const VELOCITY_TEX_DESCRIPTOR_SET_INDEX: usize = 0;
+pub const VELOCITY_TEX_DESCRIPTOR_BINDING_INDEX: usize = 4;
+pub const HISTORY_TEX_DESCRIPTOR_SET_INDEX: usize = 0;
+pub const HISTORY_TEX_DESCRIPTOR_BINDING_INDEX: usize = 5;
+pub const SMP_NEAREST_DESCRIPTOR_SET_INDEX: usize = 0;
+pub const SMP_NEAREST_DESCRIPTOR_BINDING_INDEX: usize = 6;
+pub const SMP_BILINEAR_DESCRIPTOR_SET_INDEX: usize = 0;
+pub const SMP_BILINEAR_DESCRIPTOR_BINDING_INDEX: usize = 7;
+pub const OUTPUT_TEX_DESCRIPTOR_SET_INDEX: usize = 0;
+pub const OUTPUT_TEX_DESCRIPTOR_BINDING_INDEX: usize = 8;
+
+pub struct DescriptorSet0Args<'a> {
+    pub config: &'a SsrConfigUniform,
+    pub depth_mip_slices: &'a [Option<&'a ResourceArc<ImageViewResource>>; 16],
+    pub gbuffer_tex: &'a ResourceArc<ImageViewResource>,
+    pub color_tex: &'a ResourceArc<ImageViewResource>,
+    pub velocity_tex: &'a ResourceArc<ImageViewResource>,
+    pub history_tex: &'a ResourceArc<ImageViewResource>,
+    pub output_tex: &'a ResourceArc<ImageViewResource>,
+}
+
+impl<'a> DescriptorSetInitializer<'a> for DescriptorSet0Args<'a> {
+    type Output = DescriptorSet0;
+
+    fn create_dyn_descriptor_set(
+        descriptor_set: DynDescriptorSet,
+        args: Self,
+    ) -> Self::Output {
+        let mut descriptor = DescriptorSet0(descriptor_set);
+        descriptor.set_args(args);
+        descriptor
+    }
+
+    fn create_descriptor_set(
+        descriptor_set_allocator: &mut DescriptorSetAllocator,
+        descriptor_set: DynDescriptorSet,
+        args: Self,
+    ) -> RafxResult<DescriptorSetArc> {
+        let mut descriptor = Self::create_dyn_descriptor_set(descriptor_set, args);
+        descriptor.0.flush(descriptor_set_allocator)?;
+        Ok(descriptor.0.descriptor_set().clone())
+    }
+}
+
+impl<'a> DescriptorSetWriter<'a> for DescriptorSet0Args<'a> {
+    fn write_to(
+        descriptor_set: &mut DescriptorSetWriterContext,
+        args: Self,
+    ) {
+        descriptor_set.set_buffer_data(CONFIG_DESCRIPTOR_BINDING_INDEX as u32, args.config);
+        descriptor_set.set_images(
+            DEPTH_MIP_SLICES_DESCRIPTOR_BINDING_INDEX as u32,
+            args.depth_mip_slices,
+        );
+        descriptor_set.set_image(
+            GBUFFER_TEX_DESCRIPTOR_BINDING_INDEX as u32,
+            args.gbuffer_tex,
+        );
+        descriptor_set.set_image(COLOR_TEX_DESCRIPTOR_BINDING_INDEX as u32, args.color_tex);
+        descriptor_set.set_image(
+            VELOCITY_TEX_DESCRIPTOR_BINDING_INDEX as u32,
+            args.velocity_tex,
+        );
+        descriptor_set.set_image(
+            HISTORY_TEX_DESCRIPTOR_BINDING_INDEX as u32,
+            args.history_tex,
+        );
+        descriptor_set.set_image(OUTPUT_TEX_DESCRIPTOR_BINDING_INDEX as u32, args.output_tex);
+    }
+}
+
+pub struct DescriptorSet0(pub DynDescriptorSet);
+
+impl DescriptorSet0 {
+    pub fn set_args_static(
+        descriptor_set: &mut DynDescriptorSet,
+        args: DescriptorSet0Args,
+    ) {
+        descriptor_set.set_buffer_data(CONFIG_DESCRIPTOR_BINDING_INDEX as u32, args.config);
+        descriptor_set.set_images(
+            DEPTH_MIP_SLICES_DESCRIPTOR_BINDING_INDEX as u32,
+            args.depth_mip_slices,
+        );
+        descriptor_set.set_image(
+            GBUFFER_TEX_DESCRIPTOR_BINDING_INDEX as u32,
+            args.gbuffer_tex,
+        );
+        descriptor_set.set_image(COLOR_TEX_DESCRIPTOR_BINDING_INDEX as u32, args.color_tex);
+        descriptor_set.set_image(
+            VELOCITY_TEX_DESCRIPTOR_BINDING_INDEX as u32,
+            args.velocity_tex,
+        );
+        descriptor_set.set_image(
+            HISTORY_TEX_DESCRIPTOR_BINDING_INDEX as u32,
+            args.history_tex,
+        );
+        descriptor_set.set_image(OUTPUT_TEX_DESCRIPTOR_BINDING_INDEX as u32, args.output_tex);
+    }
+
+    pub fn set_args(
+        &mut self,
+        args: DescriptorSet0Args,
+    ) {
+        self.set_config(args.config);
+        self.set_depth_mip_slices(args.depth_mip_slices);
+        self.set_gbuffer_tex(args.gbuffer_tex);
+        self.set_color_tex(args.color_tex);
+        self.set_velocity_tex(args.velocity_tex);
+        self.set_history_tex(args.history_tex);
+        self.set_output_tex(args.output_tex);
+    }
+
+    pub fn set_config(
+        &mut self,
+        config: &SsrConfigUniform,
+    ) {
+        self.0
+            .set_buffer_data(CONFIG_DESCRIPTOR_BINDING_INDEX as u32, config);
+    }
+
+    pub fn set_depth_mip_slices(
+        &mut self,
+        depth_mip_slices: &[Option<&ResourceArc<ImageViewResource>>; 16],
+    ) {
+        self.0.set_images(
+            DEPTH_MIP_SLICES_DESCRIPTOR_BINDING_INDEX as u32,
+            depth_mip_slices,
+        );
+    }
+
+    pub fn set_depth_mip_slices_element(
+        &mut self,
+        index: usize,
+        element: &ResourceArc<ImageViewResource>,
+    ) {
+        self.0.set_image_at_index(
+            DEPTH_MIP_SLICES_DESCRIPTOR_BINDING_INDEX as u32,
+            index,
+            element,
+        );
+    }
+
+    pub fn set_gbuffer_tex(
+        &mut self,
+        gbuffer_tex: &ResourceArc<ImageViewResource>,
+    ) {
+        self.0
+            .set_image(GBUFFER_TEX_DESCRIPTOR_BINDING_INDEX as u32, gbuffer_tex);
+    }
+
+    pub fn set_color_tex(
+        &mut self,
+        color_tex: &ResourceArc<ImageViewResource>,
+    ) {
+        self.0
+            .set_image(COLOR_TEX_DESCRIPTOR_BINDING_INDEX as u32, color_tex);
+    }
+
+    pub fn set_velocity_tex(
+        &mut self,
+        velocity_tex: &ResourceArc<ImageViewResource>,
+    ) {
+        self.0
+            .set_image(VELOCITY_TEX_DESCRIPTOR_BINDING_INDEX as u32, velocity_tex);
+    }
+
+    pub fn set_history_tex(
+        &mut self,
+        history_tex: &ResourceArc<ImageViewResource>,
+    ) {
+        self.0
+            .set_image(HISTORY_TEX_DESCRIPTOR_BINDING_INDEX as u32, history_tex);
+    }
+
+    pub fn set_output_tex(
+        &mut self,
+        output_tex: &ResourceArc<ImageViewResource>,
+    ) {
+        self.0
+            .set_image(OUTPUT_TEX_DESCRIPTOR_BINDING_INDEX as u32, output_tex);
+    }
+
+    pub fn flush(
+        &mut self,
+        descriptor_set_allocator: &mut DescriptorSetAllocator,
+    ) -> RafxResult<()> {
+        self.0.flush(descriptor_set_allocator)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_struct_ssr_config_std140() {
+        assert_eq!(std::mem::size_of::<SsrConfigStd140>(), 304);
+        assert_eq!(std::mem::size_of::<[[f32; 4]; 4]>(), 64);
+        assert_eq!(std::mem::align_of::<[[f32; 4]; 4]>(), 4);
+        assert_eq!(memoffset::offset_of!(SsrConfigStd140, proj_matrix), 0);
+        assert_eq!(std::mem::size_of::<[[f32; 4]; 4]>(), 64);
+        assert_eq!(std::mem::align_of::<[[f32; 4]; 4]>(), 4);
+        assert_eq!(
+            memoffset::offset_of!(SsrConfigStd140, inverse_proj_matrix),
+            64
+        );
+        assert_eq!(std::mem::size_of::<[[f32; 4]; 4]>(), 64);
+        assert_eq!(std::mem::align_of::<[[f32; 4]; 4]>(), 4);
+        assert_eq!(
+            memoffset::offset_of!(SsrConfigStd140, current_view_proj_inv),
+            128
+        );
+        assert_eq!(std::mem::size_of::<[[f32; 4]; 4]>(), 64);
+        assert_eq!(std::mem::align_of::<[[f32; 4]; 4]>(), 4);
+        assert_eq!(
+            memoffset::offset_of!(SsrConfigStd140, previous_view_proj),
+            192
+        );
+        assert_eq!(std::mem::size_of::<u32>(), 4);
+        assert_eq!(std::mem::align_of::<u32>(), 4);
+        assert_eq!(memoffset::offset_of!(SsrConfigStd140, viewport_width), 256);
+        assert_eq!(std::mem::size_of::<u32>(), 4);
+        assert_eq!(std::mem::align_of::<u32>(), 4);
+        assert_eq!(memoffset::offset_of!(SsrConfigStd140, viewport_height), 260);
+        assert_eq!(std::mem::size_of::<u32>(), 4);
+        assert_eq!(std::mem::align_of::<u32>(), 4);
+        assert_eq!(
+            memoffset::offset_of!(SsrConfigStd140, depth_mip_slice_count),
+            264
+        );
+        assert_eq!(std::mem::size_of::<u32>(), 4);
+        assert_eq!(std::mem::align_of::<u32>(), 4);
+        assert_eq!(memoffset::offset_of!(SsrConfigStd140, max_steps), 268);
+        assert_eq!(std::mem::size_of::<f32>(), 4);
+        assert_eq!(std::mem::align_of::<f32>(), 4);
+        assert_eq!(memoffset::offset_of!(SsrConfigStd140, z_near), 272);
+        assert_eq!(std::mem::size_of::<f32>(), 4);
+        assert_eq!(std::mem::align_of::<f32>(), 4);
+        assert_eq!(memoffset::offset_of!(SsrConfigStd140, max_distance), 276);
+        assert_eq!(std::mem::size_of::<f32>(), 4);
+        assert_eq!(std::mem::align_of::<f32>(), 4);
+        assert_eq!(memoffset::offset_of!(SsrConfigStd140, thickness), 280);
+        assert_eq!(std::mem::size_of::<f32>(), 4);
+        assert_eq!(std::mem::align_of::<f32>(), 4);
+        assert_eq!(memoffset::offset_of!(SsrConfigStd140, max_roughness), 284);
+        assert_eq!(std::mem::size_of::<f32>(), 4);
+        assert_eq!(std::mem::align_of::<f32>(), 4);
+        assert_eq!(memoffset::offset_of!(SsrConfigStd140, edge_fade), 288);
+        assert_eq!(std::mem::size_of::<f32>(), 4);
+        assert_eq!(std::mem::align_of::<f32>(), 4);
+        assert_eq!(
+            memoffset::offset_of!(SsrConfigStd140, current_frame_weight),
+            292
+        );
+        assert_eq!(std::mem::size_of::<u32>(), 4);
+        assert_eq!(std::mem::align_of::<u32>(), 4);
+        assert_eq!(
+            memoffset::offset_of!(SsrConfigStd140, has_history_data),
+            296
+        );
+        assert_eq!(std::mem::size_of::<[u8; 4]>(), 4);
+        assert_eq!(std::mem::align_of::<[u8; 4]>(), 1);
+        assert_eq!(memoffset::offset_of!(SsrConfigStd140, _padding0), 300);
+    }
+}
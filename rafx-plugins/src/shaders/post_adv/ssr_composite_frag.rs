@@ -0,0 +1,181 @@
+// This code is auto-generated by the shader processor.
+
+#[allow(unused_imports)]
+use rafx::RafxResult;
+
+#[allow(unused_imports)]
+use rafx::framework::{
+    DescriptorSetAllocator, DescriptorSetArc, DescriptorSetBindings, DescriptorSetInitializer,
+    DescriptorSetWriter, DescriptorSetWriterContext, DynDescriptorSet, ImageViewResource,
+    ResourceArc,
+};
+
+#[derive(Copy, Clone, Debug)]
+#[repr(C)]
+pub struct SsrCompositeConfigStd140 {
+    pub inverse_proj_matrix: [[f32; 4]; 4], // +0 (size: 64)
+    pub max_roughness: f32,                 // +64 (size: 4)
+    pub _padding0: [u8; 12],                // +68 (size: 12)
+} // 80 bytes
+
+impl Default for SsrCompositeConfigStd140 {
+    fn default() -> Self {
+        SsrCompositeConfigStd140 {
+            inverse_proj_matrix: <[[f32; 4]; 4]>::default(),
+            max_roughness: <f32>::default(),
+            _padding0: [u8::default(); 12],
+        }
+    }
+}
+
+pub type SsrCompositeConfigUniform = SsrCompositeConfigStd140;
+
+pub const SSR_TEX_DESCRIPTOR_SET_INDEX: usize = 0;
+pub const SSR_TEX_DESCRIPTOR_BINDING_INDEX: usize = 0;
+pub const GBUFFER_TEX_DESCRIPTOR_SET_INDEX: usize = 0;
+pub const GBUFFER_TEX_DESCRIPTOR_BINDING_INDEX: usize = 1;
+pub const DEPTH_TEX_DESCRIPTOR_SET_INDEX: usize = 0;
+pub const DEPTH_TEX_DESCRIPTOR_BINDING_INDEX: usize = 2;
+pub const SMP_DESCRIPTOR_SET_INDEX: usize = 0;
+pub const SMP_DESCRIPTOR_BINDING_INDEX: usize = 3;
+pub const CONFIG_DESCRIPTOR_SET_INDEX: usize = 0;
+pub const CONFIG_DESCRIPTOR_BINDING_INDEX: usize = 4;
+
+pub struct DescriptorSet0Args<'a> {
+    pub ssr_tex: &'a ResourceArc<ImageViewResource>,
+    pub gbuffer_tex: &'a ResourceArc<ImageViewResource>,
+    pub depth_tex: &'a ResourceArc<ImageViewResource>,
+    pub config: &'a SsrCompositeConfigUniform,
+}
+
+impl<'a> DescriptorSetInitializer<'a> for DescriptorSet0Args<'a> {
+    type Output = DescriptorSet0;
+
+    fn create_dyn_descriptor_set(
+        descriptor_set: DynDescriptorSet,
+        args: Self,
+    ) -> Self::Output {
+        let mut descriptor = DescriptorSet0(descriptor_set);
+        descriptor.set_args(args);
+        descriptor
+    }
+
+    fn create_descriptor_set(
+        descriptor_set_allocator: &mut DescriptorSetAllocator,
+        descriptor_set: DynDescriptorSet,
+        args: Self,
+    ) -> RafxResult<DescriptorSetArc> {
+        let mut descriptor = Self::create_dyn_descriptor_set(descriptor_set, args);
+        descriptor.0.flush(descriptor_set_allocator)?;
+        Ok(descriptor.0.descriptor_set().clone())
+    }
+}
+
+impl<'a> DescriptorSetWriter<'a> for DescriptorSet0Args<'a> {
+    fn write_to(
+        descriptor_set: &mut DescriptorSetWriterContext,
+        args: Self,
+    ) {
+        descriptor_set.set_image(SSR_TEX_DESCRIPTOR_BINDING_INDEX as u32, args.ssr_tex);
+        descriptor_set.set_image(
+            GBUFFER_TEX_DESCRIPTOR_BINDING_INDEX as u32,
+            args.gbuffer_tex,
+        );
+        descriptor_set.set_image(DEPTH_TEX_DESCRIPTOR_BINDING_INDEX as u32, args.depth_tex);
+        descriptor_set.set_buffer_data(CONFIG_DESCRIPTOR_BINDING_INDEX as u32, args.config);
+    }
+}
+
+pub struct DescriptorSet0(pub DynDescriptorSet);
+
+impl DescriptorSet0 {
+    pub fn set_args_static(
+        descriptor_set: &mut DynDescriptorSet,
+        args: DescriptorSet0Args,
+    ) {
+        descriptor_set.set_image(SSR_TEX_DESCRIPTOR_BINDING_INDEX as u32, args.ssr_tex);
+        descriptor_set.set_image(
+            GBUFFER_TEX_DESCRIPTOR_BINDING_INDEX as u32,
+            args.gbuffer_tex,
+        );
+        descriptor_set.set_image(DEPTH_TEX_DESCRIPTOR_BINDING_INDEX as u32, args.depth_tex);
+        descriptor_set.set_buffer_data(CONFIG_DESCRIPTOR_BINDING_INDEX as u32, args.config);
+    }
+
+    pub fn set_args(
+        &mut self,
+        args: DescriptorSet0Args,
+    ) {
+        self.set_ssr_tex(args.ssr_tex);
+        self.set_gbuffer_tex(args.gbuffer_tex);
+        self.set_depth_tex(args.depth_tex);
+        self.set_config(args.config);
+    }
+
+    pub fn set_ssr_tex(
+        &mut self,
+        ssr_tex: &ResourceArc<ImageViewResource>,
+    ) {
+        self.0
+            .set_image(SSR_TEX_DESCRIPTOR_BINDING_INDEX as u32, ssr_tex);
+    }
+
+    pub fn set_gbuffer_tex(
+        &mut self,
+        gbuffer_tex: &ResourceArc<ImageViewResource>,
+    ) {
+        self.0
+            .set_image(GBUFFER_TEX_DESCRIPTOR_BINDING_INDEX as u32, gbuffer_tex);
+    }
+
+    pub fn set_depth_tex(
+        &mut self,
+        depth_tex: &ResourceArc<ImageViewResource>,
+    ) {
+        self.0
+            .set_image(DEPTH_TEX_DESCRIPTOR_BINDING_INDEX as u32, depth_tex);
+    }
+
+    pub fn set_config(
+        &mut self,
+        config: &SsrCompositeConfigUniform,
+    ) {
+        self.0
+            .set_buffer_data(CONFIG_DESCRIPTOR_BINDING_INDEX as u32, config);
+    }
+
+    pub fn flush(
+        &mut self,
+        descriptor_set_allocator: &mut DescriptorSetAllocator,
+    ) -> RafxResult<()> {
+        self.0.flush(descriptor_set_allocator)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_struct_ssr_composite_config_std140() {
+        assert_eq!(std::mem::size_of::<SsrCompositeConfigStd140>(), 80);
+        assert_eq!(std::mem::size_of::<[[f32; 4]; 4]>(), 64);
+        assert_eq!(std::mem::align_of::<[[f32; 4]; 4]>(), 4);
+        assert_eq!(
+            memoffset::offset_of!(SsrCompositeConfigStd140, inverse_proj_matrix),
+            0
+        );
+        assert_eq!(std::mem::size_of::<f32>(), 4);
+        assert_eq!(std::mem::align_of::<f32>(), 4);
+        assert_eq!(
+            memoffset::offset_of!(SsrCompositeConfigStd140, max_roughness),
+            64
+        );
+        assert_eq!(std::mem::size_of::<[u8; 12]>(), 12);
+        assert_eq!(std::mem::align_of::<[u8; 12]>(), 1);
+        assert_eq!(
+            memoffset::offset_of!(SsrCompositeConfigStd140, _padding0),
+            68
+        );
+    }
+}
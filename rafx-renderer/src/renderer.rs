@@ -461,18 +461,25 @@ impl Renderer {
         };
 
         let swapchain_guard = presentable_frame.swapchain().lock().unwrap();
-        let max_color_component_value = match &*swapchain_guard {
-            #[cfg(feature = "rafx-metal")]
-            rafx_api::RafxSwapchain::Metal(swapchain) => {
-                swapchain.edr_info().max_edr_color_component_value
-            }
-            #[allow(unreachable_patterns)]
-            _ => 1.0,
-        };
+        let (max_color_component_value, extended_range_relative_to_sdr_white) =
+            match &*swapchain_guard {
+                #[cfg(feature = "rafx-metal")]
+                rafx_api::RafxSwapchain::Metal(swapchain) => {
+                    (swapchain.edr_info().max_edr_color_component_value, true)
+                }
+                #[allow(unreachable_patterns)]
+                _ => (1.0, false),
+            };
+        let display_luminance = swapchain_guard.display_luminance();
 
-        render_resources
-            .fetch_mut::<SwapchainRenderResource>()
-            .set_max_color_component_value(max_color_component_value);
+        {
+            let mut swapchain_render_resource =
+                render_resources.fetch_mut::<SwapchainRenderResource>();
+            swapchain_render_resource.set_max_color_component_value(max_color_component_value);
+            swapchain_render_resource
+                .set_extended_range_relative_to_sdr_white(extended_range_relative_to_sdr_white);
+            swapchain_render_resource.set_display_luminance(display_luminance);
+        }
 
         let render_view_set = RenderViewSet::new(presentable_frame.incrementing_frame_index());
 
@@ -483,15 +490,20 @@ impl Renderer {
         let viewports_resource = extract_resources.fetch::<ViewportsResource>();
         let view_meta = viewports_resource.main_view_meta.clone().unwrap();
 
-        let main_window_size = viewports_resource.main_window_size;
+        let main_view_extents = renderer.pipeline_plugin.main_view_extents(
+            viewports_resource.main_window_size,
+            extract_resources,
+            render_resources,
+        );
 
         let main_view = render_view_set.create_view(
             view_meta.view_frustum,
             view_meta.eye_position,
             view_meta.view,
             view_meta.proj,
-            (main_window_size.width, main_window_size.height),
+            (main_view_extents.width, main_view_extents.height),
             view_meta.depth_range,
+            view_meta.camera_lens,
             view_meta.render_phase_mask,
             view_meta.render_feature_mask,
             view_meta.render_feature_flag_mask,
@@ -688,7 +700,7 @@ impl Renderer {
                 view_visibility_result.render_object_instances_per_view(feature_index)
             {
                 for render_object in visible_render_objects {
-                    render_object_instance_object_ids.insert(*render_object);
+                    render_object_instance_object_ids.insert(render_object.render_object_instance);
                 }
 
                 frame_packet_size.view_packet_sizes.push(ViewPacketSize {
@@ -805,14 +817,16 @@ impl Renderer {
             if let Some(visible_render_objects) =
                 view_visibility_result.render_object_instances_per_view(feature_index)
             {
-                for render_object_instance in visible_render_objects {
+                for visible_render_object in visible_render_objects {
+                    let render_object_instance = visible_render_object.render_object_instance;
                     let render_object_instance_id =
-                        frame_packet.get_or_push_render_object_instance(*render_object_instance);
+                        frame_packet.get_or_push_render_object_instance(render_object_instance);
 
                     frame_packet.push_render_object_instance_per_view(
                         view_frame_index,
                         render_object_instance_id,
-                        *render_object_instance,
+                        render_object_instance,
+                        visible_render_object.distance_from_view_frustum,
                     );
                 }
             }
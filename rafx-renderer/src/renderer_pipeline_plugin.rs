@@ -1,6 +1,6 @@
 use crate::RendererLoadContext;
 use rafx_api::extra::upload::RafxTransferUpload;
-use rafx_api::RafxResult;
+use rafx_api::{RafxExtents2D, RafxResult};
 use rafx_assets::AssetManager;
 use rafx_assets::AssetResource;
 use rafx_framework::graph::PreparedRenderGraph;
@@ -38,6 +38,17 @@ pub trait RendererPipelinePlugin: Send + Sync {
         Ok(())
     }
 
+    // Called once per frame before the main view is created. Pipelines that render at a lower
+    // internal resolution and upscale to the swapchain can return smaller extents here.
+    fn main_view_extents(
+        &self,
+        window_extents: RafxExtents2D,
+        _extract_resources: &ExtractResources,
+        _render_resources: &RenderResources,
+    ) -> RafxExtents2D {
+        window_extents
+    }
+
     fn generate_render_graph(
         &self,
         asset_manager: &AssetManager,
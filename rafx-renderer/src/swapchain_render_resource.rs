@@ -1,4 +1,6 @@
-use rafx_api::{RafxDeviceContext, RafxFormat, RafxResourceType, RafxResult};
+use rafx_api::{
+    RafxDeviceContext, RafxFormat, RafxResourceType, RafxResult, RafxSwapchainDisplayLuminance,
+};
 use rafx_framework::graph::SwapchainSurfaceInfo;
 
 pub struct SwapchainRenderResourceSurfaceInfo {
@@ -20,6 +22,10 @@ pub struct SwapchainRenderResource {
     // require adding/removing it when we create/destroy the swapchain
     surface_info: Option<SwapchainRenderResourceSurfaceInfo>,
     pub max_color_component_value: f32,
+    pub display_luminance: Option<RafxSwapchainDisplayLuminance>,
+    // True if a component value of 1.0 in an extended swapchain color space is the display's SDR
+    // white (apple EDR). Otherwise it is the scRGB reference of 80 nits.
+    pub extended_range_relative_to_sdr_white: bool,
 }
 
 impl SwapchainRenderResource {
@@ -72,6 +78,24 @@ impl SwapchainRenderResource {
         self.max_color_component_value = max_value;
     }
 
+    /// Set the luminance range of the display, if the OS reports it. HDR output uses it to pick the
+    /// brightest value it emits.
+    pub fn set_display_luminance(
+        &mut self,
+        display_luminance: Option<RafxSwapchainDisplayLuminance>,
+    ) {
+        self.display_luminance = display_luminance;
+    }
+
+    /// Set whether extended color spaces are relative to the display's SDR white (apple EDR) rather
+    /// than scRGB's fixed 80 nits.
+    pub fn set_extended_range_relative_to_sdr_white(
+        &mut self,
+        relative_to_sdr_white: bool,
+    ) {
+        self.extended_range_relative_to_sdr_white = relative_to_sdr_white;
+    }
+
     pub fn surface_info(&self) -> Option<&SwapchainRenderResourceSurfaceInfo> {
         self.surface_info.as_ref()
     }
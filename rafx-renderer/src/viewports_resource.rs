@@ -1,7 +1,8 @@
 use glam::{Mat4, Vec3};
 use rafx_api::RafxExtents2D;
 use rafx_framework::render_features::{
-    RenderFeatureFlagMask, RenderFeatureMask, RenderPhaseMask, RenderViewDepthRange,
+    RenderFeatureFlagMask, RenderFeatureMask, RenderPhaseMask, RenderViewCameraLens,
+    RenderViewDepthRange,
 };
 use rafx_framework::visibility::ViewFrustumArc;
 
@@ -16,6 +17,8 @@ pub struct RenderViewMeta {
     pub view: Mat4,
     pub proj: Mat4,
     pub depth_range: RenderViewDepthRange,
+    // Enables camera effects like depth of field when the pipeline supports them
+    pub camera_lens: Option<RenderViewCameraLens>,
     pub render_phase_mask: RenderPhaseMask,
     pub render_feature_mask: RenderFeatureMask,
     pub render_feature_flag_mask: RenderFeatureFlagMask,
@@ -674,6 +674,15 @@ fn rust_binding_constants(
         }
     }
 
+    for constant in &parsed_declarations.specialization_constants {
+        use heck::ShoutySnakeCase;
+        rust_code.push(format!(
+            "pub const {}_SPECIALIZATION_CONSTANT_ID: u32 = {};\n",
+            constant.name.to_shouty_snake_case(),
+            constant.constant_id
+        ));
+    }
+
     rust_code.push("\n".to_string());
 }
 
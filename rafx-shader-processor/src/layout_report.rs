@@ -0,0 +1,378 @@
+use crate::parse_declarations::{BindingType, ParseDeclarationsResult, ParseFieldResult};
+use crate::shader_types::*;
+use fnv::FnvHashMap;
+use std::collections::BTreeMap;
+
+// The kinds of blocks a struct is used in, directly or nested in another struct. This determines
+// which memory layouts the struct must be placed in.
+#[derive(Default, Debug, Copy, Clone)]
+struct StructUsage {
+    uniform: bool,
+    buffer: bool,
+    push_constant: bool,
+}
+
+#[derive(Default, Debug)]
+pub(crate) struct LayoutReport {
+    // Members whose offset or size differs between memory layouts or backends
+    pub(crate) mismatches: Vec<String>,
+    // Structs that would need less padding if their members were reordered
+    pub(crate) padding_suggestions: Vec<String>,
+    // The offset of every member in every layout the struct is used in. Only populated if a full
+    // report is requested
+    pub(crate) details: Vec<String>,
+}
+
+fn mark_struct_usage<F: Fn(&mut StructUsage)>(
+    user_types: &FnvHashMap<String, UserType>,
+    usages: &mut BTreeMap<String, StructUsage>,
+    type_name: &str,
+    f: &F,
+) {
+    if let Some(user_type) = user_types.get(type_name) {
+        (f)(usages.entry(type_name.to_string()).or_default());
+        for field in &*user_type.fields {
+            mark_struct_usage(user_types, usages, &field.type_name, f);
+        }
+    }
+}
+
+pub(crate) fn generate_layout_report(
+    builtin_types: &FnvHashMap<String, TypeAlignmentInfo>,
+    user_types: &FnvHashMap<String, UserType>,
+    parsed_declarations: &ParseDeclarationsResult,
+    include_details: bool,
+) -> LayoutReport {
+    //
+    // Find every struct that is placed in GPU memory, and in what kind of block
+    //
+    let mut usages = BTreeMap::<String, StructUsage>::default();
+    for b in &parsed_declarations.bindings {
+        if b.parsed.fields.is_none() {
+            continue;
+        }
+
+        if b.parsed.layout_parts.push_constant {
+            mark_struct_usage(user_types, &mut usages, &b.parsed.type_name, &|x| {
+                x.push_constant = true
+            });
+        } else if b.parsed.binding_type == BindingType::Uniform {
+            mark_struct_usage(user_types, &mut usages, &b.parsed.type_name, &|x| {
+                x.uniform = true
+            });
+        } else if b.parsed.binding_type == BindingType::Buffer {
+            mark_struct_usage(user_types, &mut usages, &b.parsed.type_name, &|x| {
+                x.buffer = true
+            });
+        }
+    }
+
+    let mut report = LayoutReport::default();
+    for (type_name, usage) in &usages {
+        let result = add_struct_to_report(
+            builtin_types,
+            user_types,
+            type_name,
+            *usage,
+            include_details,
+            &mut report,
+        );
+
+        // Types the codegen doesn't understand can still be used by the shader, they just can't be
+        // checked
+        if let Err(e) = result {
+            log::debug!("Skipping layout report for struct {}: {}", type_name, e);
+        }
+    }
+
+    report
+}
+
+fn add_struct_to_report(
+    builtin_types: &FnvHashMap<String, TypeAlignmentInfo>,
+    user_types: &FnvHashMap<String, UserType>,
+    type_name: &str,
+    usage: StructUsage,
+    include_details: bool,
+    report: &mut LayoutReport,
+) -> Result<(), String> {
+    let fields = &user_types[type_name].fields;
+
+    let std140 = if usage.uniform {
+        Some(determine_member_layouts(
+            builtin_types,
+            user_types,
+            fields,
+            MemoryLayout::Std140,
+        )?)
+    } else {
+        None
+    };
+
+    let std430 = if usage.buffer || usage.push_constant {
+        Some(determine_member_layouts(
+            builtin_types,
+            user_types,
+            fields,
+            MemoryLayout::Std430,
+        )?)
+    } else {
+        None
+    };
+
+    // The DX12 backend places uniforms and push constants in constant buffers
+    let hlsl_cbuffer = if usage.uniform || usage.push_constant {
+        Some(determine_member_layouts_hlsl_cbuffer(
+            builtin_types,
+            user_types,
+            fields,
+        )?)
+    } else {
+        None
+    };
+
+    //
+    // Report members that are placed differently depending on layout/backend
+    //
+    if let (Some(std140), Some(std430)) = (&std140, &std430) {
+        compare_member_layouts(
+            type_name,
+            "std140",
+            std140,
+            "std430",
+            std430,
+            &mut report.mismatches,
+        );
+    }
+
+    if let Some(hlsl_cbuffer) = &hlsl_cbuffer {
+        if let Some(std140) = &std140 {
+            compare_member_layouts(
+                type_name,
+                "std140",
+                std140,
+                "HLSL cbuffer",
+                hlsl_cbuffer,
+                &mut report.mismatches,
+            );
+        }
+
+        if usage.push_constant {
+            compare_member_layouts(
+                type_name,
+                "std430",
+                std430.as_ref().unwrap(),
+                "HLSL cbuffer",
+                hlsl_cbuffer,
+                &mut report.mismatches,
+            );
+        }
+    }
+
+    //
+    // Suggest a member order that needs less padding. Sorting by alignment (largest first) is a
+    // simple heuristic that is usually optimal.
+    //
+    let layouts = [
+        (MemoryLayout::Std140, &std140),
+        (MemoryLayout::Std430, &std430),
+    ];
+    for (layout, members) in &layouts {
+        let members = if let Some(members) = members {
+            members
+        } else {
+            continue;
+        };
+
+        let padding = determine_padding(builtin_types, user_types, type_name, fields, *layout)?;
+        let padding = match padding {
+            Some(padding) if padding > 0 => padding,
+            _ => continue,
+        };
+
+        let mut order: Vec<usize> = (0..members.len()).collect();
+        order.sort_by_key(|&i| std::cmp::Reverse(members[i].align));
+        let reordered_fields: Vec<ParseFieldResult> =
+            order.iter().map(|&i| fields[i].clone()).collect();
+
+        let reordered_padding = determine_padding(
+            builtin_types,
+            user_types,
+            type_name,
+            &reordered_fields,
+            *layout,
+        )?
+        .unwrap();
+
+        if reordered_padding < padding {
+            let reordered_names: Vec<&str> = reordered_fields
+                .iter()
+                .map(|x| x.field_name.as_str())
+                .collect();
+
+            report.padding_suggestions.push(format!(
+                "Struct {} has {} bytes of padding in {:?} layout. Ordering members as ({}) would reduce it to {} bytes",
+                type_name,
+                padding,
+                layout,
+                reordered_names.join(", "),
+                reordered_padding
+            ));
+        }
+    }
+
+    if include_details {
+        let layouts = [
+            ("std140", &std140),
+            ("std430", &std430),
+            ("HLSL cbuffer", &hlsl_cbuffer),
+        ];
+
+        for (layout_name, members) in &layouts {
+            if let Some(members) = members {
+                report
+                    .details
+                    .push(format!("Struct {} ({}):", type_name, layout_name));
+                for member in members.iter() {
+                    report.details.push(format!(
+                        "  +{} {} (size: {}, align: {})",
+                        member.offset,
+                        member.name,
+                        format_member_size(member.size),
+                        member.align
+                    ));
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn format_member_size(size: Option<usize>) -> String {
+    size.map(|x| x.to_string())
+        .unwrap_or_else(|| "variable".to_string())
+}
+
+fn compare_member_layouts(
+    type_name: &str,
+    a_layout_name: &str,
+    a_members: &[MemberLayout],
+    b_layout_name: &str,
+    b_members: &[MemberLayout],
+    mismatches: &mut Vec<String>,
+) {
+    for (a, b) in a_members.iter().zip(b_members) {
+        if a.offset != b.offset {
+            mismatches.push(format!(
+                "{}::{} is at offset {} in {} layout but offset {} in {} layout",
+                type_name, a.name, a.offset, a_layout_name, b.offset, b_layout_name
+            ));
+        } else if a.size != b.size {
+            mismatches.push(format!(
+                "{}::{} has size {} in {} layout but size {} in {} layout",
+                type_name,
+                a.name,
+                format_member_size(a.size),
+                a_layout_name,
+                format_member_size(b.size),
+                b_layout_name
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::parse_source::PreprocessorState;
+    use crate::shader_types::{create_builtin_type_lookup, create_user_type_lookup};
+
+    fn report_for_test(shader_code: &str) -> LayoutReport {
+        let mut preprocessor_state = PreprocessorState::default();
+        let parsed_source = crate::parse_source::parse_glsl_src(
+            std::path::Path::new(""),
+            shader_code,
+            &mut preprocessor_state,
+        )
+        .unwrap();
+        let parsed_declarations =
+            crate::parse_declarations::parse_declarations(&parsed_source.declarations).unwrap();
+        let user_types = create_user_type_lookup(&parsed_declarations).unwrap();
+        let builtin_types = create_builtin_type_lookup();
+
+        generate_layout_report(&builtin_types, &user_types, &parsed_declarations, true)
+    }
+
+    #[test]
+    fn test_std140_std430_mismatch() {
+        let report = report_for_test(
+            r#"
+            #version 450
+
+            struct Weights {
+                float weights[4];
+                float scale;
+            };
+
+            layout (set = 0, binding = 0) uniform UniformData {
+                Weights weights;
+            } uniform_data;
+
+            layout (set = 0, binding = 1) buffer BufferData {
+                Weights weights;
+            } buffer_data;
+            "#,
+        );
+
+        // The HLSL cbuffer layout differs too, so only check for the std140/std430 mismatches
+        assert!(report.mismatches.contains(
+            &"Weights::weights has size 64 in std140 layout but size 16 in std430 layout"
+                .to_string()
+        ));
+        assert!(report.mismatches.contains(
+            &"Weights::scale is at offset 64 in std140 layout but offset 16 in std430 layout"
+                .to_string()
+        ));
+    }
+
+    #[test]
+    fn test_hlsl_cbuffer_mismatch() {
+        let report = report_for_test(
+            r#"
+            #version 450
+
+            layout (set = 0, binding = 0) uniform UniformData {
+                float intensity;
+                vec3 direction;
+            } uniform_data;
+            "#,
+        );
+
+        assert_eq!(
+            report.mismatches,
+            vec!["UniformData::direction is at offset 16 in std140 layout but offset 4 in HLSL cbuffer layout"]
+        );
+    }
+
+    #[test]
+    fn test_padding_suggestion() {
+        let report = report_for_test(
+            r#"
+            #version 450
+
+            layout (set = 0, binding = 0) buffer BufferData {
+                float a;
+                vec4 b;
+                float c;
+            } buffer_data;
+            "#,
+        );
+
+        assert!(report.mismatches.is_empty());
+        assert_eq!(
+            report.padding_suggestions,
+            vec!["Struct BufferData has 24 bytes of padding in Std430 layout. Ordering members as (b, a, c) would reduce it to 8 bytes"]
+        );
+    }
+}
@@ -8,6 +8,8 @@ use parse_source::DeclarationText;
 
 mod parse_declarations;
 
+mod parse_hlsl;
+
 mod include;
 use crate::parse_declarations::ParseDeclarationsResult;
 use crate::parse_source::PreprocessorState;
@@ -19,6 +21,7 @@ use include::IncludeType;
 use rafx_api::{
     RafxHashedShaderPackage, RafxShaderPackage, RafxShaderPackageDx12, RafxShaderPackageGles2,
     RafxShaderPackageGles3, RafxShaderPackageMetal, RafxShaderPackageVulkan,
+    RafxShaderPackageWebGpu,
 };
 use shaderc::{CompilationArtifact, Compiler, ShaderKind};
 use spirv_cross::glsl::Target;
@@ -28,14 +31,35 @@ mod codegen;
 
 mod reflect;
 
+mod layout_report;
+
 mod shader_types;
 
+mod wgsl_override;
+
 const PREPROCESSOR_DEF_PLATFORM_RUST_CODEGEN: &'static str = "PLATFORM_RUST_CODEGEN";
 const PREPROCESSOR_DEF_PLATFORM_DX12: &'static str = "PLATFORM_DX12";
 const PREPROCESSOR_DEF_PLATFORM_VULKAN: &'static str = "PLATFORM_VULKAN";
 const PREPROCESSOR_DEF_PLATFORM_METAL: &'static str = "PLATFORM_METAL";
 const PREPROCESSOR_DEF_PLATFORM_GLES2: &'static str = "PLATFORM_GLES2";
 const PREPROCESSOR_DEF_PLATFORM_GLES3: &'static str = "PLATFORM_GLES3";
+const PREPROCESSOR_DEF_PLATFORM_WEBGPU: &'static str = "PLATFORM_WEBGPU";
+
+// Shaders are GLSL unless the file ends in .hlsl, i.e. shader.frag.hlsl
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum ShaderSourceLanguage {
+    Glsl,
+    Hlsl,
+}
+
+impl ShaderSourceLanguage {
+    fn from_path(path: &Path) -> Self {
+        match path.extension() {
+            Some(extension) if extension == "hlsl" => ShaderSourceLanguage::Hlsl,
+            _ => ShaderSourceLanguage::Glsl,
+        }
+    }
+}
 
 #[derive(Clone, Copy, Debug)]
 enum RsFileType {
@@ -68,6 +92,8 @@ pub struct ShaderProcessorArgs {
     pub gles2_generated_src_file: Option<PathBuf>,
     #[structopt(name = "gles3-generated-src-file", long, parse(from_os_str))]
     pub gles3_generated_src_file: Option<PathBuf>,
+    #[structopt(name = "webgpu-generated-src-file", long, parse(from_os_str))]
+    pub webgpu_generated_src_file: Option<PathBuf>,
     #[structopt(name = "cooked-shader-file", long, parse(from_os_str))]
     pub cooked_shader_file: Option<PathBuf>,
 
@@ -90,6 +116,8 @@ pub struct ShaderProcessorArgs {
     pub gles2_generated_src_path: Option<PathBuf>,
     #[structopt(name = "gles3-generated-src-path", long, parse(from_os_str))]
     pub gles3_generated_src_path: Option<PathBuf>,
+    #[structopt(name = "webgpu-generated-src-path", long, parse(from_os_str))]
+    pub webgpu_generated_src_path: Option<PathBuf>,
     #[structopt(name = "cooked-shaders-path", long, parse(from_os_str))]
     pub cooked_shaders_path: Option<PathBuf>,
 
@@ -102,6 +130,14 @@ pub struct ShaderProcessorArgs {
     #[structopt(name = "optimize-shaders", long)]
     pub optimize_shaders: bool,
 
+    // Log the offset of every member of structs used in uniforms, buffers, and push constants
+    #[structopt(name = "layout-report", long)]
+    pub layout_report: bool,
+
+    // Fail if a struct member's offset differs between memory layouts or backends
+    #[structopt(name = "deny-layout-mismatches", long)]
+    pub deny_layout_mismatches: bool,
+
     #[structopt(name = "package-vk", long)]
     pub package_vk: bool,
     #[structopt(name = "package-dx12", long)]
@@ -112,6 +148,8 @@ pub struct ShaderProcessorArgs {
     pub package_gles2: bool,
     #[structopt(name = "package-gles3", long)]
     pub package_gles3: bool,
+    #[structopt(name = "package-webgpu", long)]
+    pub package_webgpu: bool,
     #[structopt(name = "package-all", long)]
     pub package_all: bool,
 
@@ -163,6 +201,7 @@ pub fn run(args: &ShaderProcessorArgs) -> Result<(), Box<dyn Error>> {
             args.metal_generated_src_file.as_ref(),
             args.gles2_generated_src_file.as_ref(),
             args.gles3_generated_src_file.as_ref(),
+            args.webgpu_generated_src_file.as_ref(),
             args.cooked_shader_file.as_ref(),
             shader_kind,
             &args,
@@ -194,7 +233,7 @@ fn process_directory(
 
     let glob_walker = globwalk::GlobWalkerBuilder::from_patterns(
         glsl_files.to_str().unwrap(),
-        &["*.{vert,frag,comp}"],
+        &["*.{vert,frag,comp}", "*.{vert,frag,comp}.hlsl"],
     )
     .file_type(globwalk::FileType::FILE)
     .build()?;
@@ -204,9 +243,23 @@ fn process_directory(
         // Determine the files we will write out
         //
         let glsl_file = glob?;
+
+        // A .hlsl file next to a GLSL file of the same name is an override for that file, not a
+        // shader of its own
+        let source_language = ShaderSourceLanguage::from_path(glsl_file.path());
+        if source_language == ShaderSourceLanguage::Hlsl
+            && glsl_file.path().with_extension("").exists()
+        {
+            continue;
+        }
+
         log::info!("Processing file {:?}", glsl_file.path());
 
-        let file_name = glsl_file.file_name().to_string_lossy();
+        // Output files for shader.frag.hlsl are named the same as they would be for shader.frag
+        let file_name = match source_language {
+            ShaderSourceLanguage::Glsl => glsl_file.file_name().to_string_lossy(),
+            ShaderSourceLanguage::Hlsl => glsl_file.path().file_stem().unwrap().to_string_lossy(),
+        };
 
         let empty_path = PathBuf::new();
         let outfile_prefix = glsl_file
@@ -252,6 +305,12 @@ fn process_directory(
             .as_ref()
             .map(|x| x.join(outfile_prefix).join(gles3_src_name));
 
+        let webgpu_src_name = format!("{}.wgsl", file_name);
+        let webgpu_generated_src_path = args
+            .webgpu_generated_src_path
+            .as_ref()
+            .map(|x| x.join(outfile_prefix).join(webgpu_src_name));
+
         let cooked_shader_name = format!("{}.cookedshaderpackage", file_name);
         let cooked_shader_path = args
             .cooked_shaders_path
@@ -276,6 +335,7 @@ fn process_directory(
             metal_generated_src_path.as_ref(),
             gles2_generated_src_path.as_ref(),
             gles3_generated_src_path.as_ref(),
+            webgpu_generated_src_path.as_ref(),
             cooked_shader_path.as_ref(),
             shader_kind,
             &args,
@@ -348,6 +408,7 @@ fn process_glsl_shader(
     metal_generated_src_file: Option<&PathBuf>,
     gles2_generated_src_file: Option<&PathBuf>,
     gles3_generated_src_file: Option<&PathBuf>,
+    webgpu_generated_src_file: Option<&PathBuf>,
     cooked_shader_file: Option<&PathBuf>,
     shader_kind: shaderc::ShaderKind,
     args: &ShaderProcessorArgs,
@@ -360,6 +421,7 @@ fn process_glsl_shader(
     log::trace!("metal: {:?}", metal_generated_src_file);
     log::trace!("gles2: {:?}", gles2_generated_src_file);
     log::trace!("gles3: {:?}", gles3_generated_src_file);
+    log::trace!("webgpu: {:?}", webgpu_generated_src_file);
     log::trace!("cooked: {:?}", cooked_shader_file);
     log::trace!("shader kind: {:?}", shader_kind);
 
@@ -368,25 +430,40 @@ fn process_glsl_shader(
     let package_metal = (args.package_all || args.package_metal) && cooked_shader_file.is_some();
     let package_gles2 = (args.package_all || args.package_gles2) && cooked_shader_file.is_some();
     let package_gles3 = (args.package_all || args.package_gles3) && cooked_shader_file.is_some();
+    let package_webgpu = (args.package_all || args.package_webgpu) && cooked_shader_file.is_some();
 
     log::trace!(
-        "package VK: {} dx12: {} Metal: {} GLES2: {} GLES3: {}",
+        "package VK: {} dx12: {} Metal: {} GLES2: {} GLES3: {} WebGPU: {}",
         package_vk,
         package_dx12,
         package_metal,
         package_gles2,
-        package_gles3
+        package_gles3,
+        package_webgpu
     );
 
     if cooked_shader_file.is_some()
-        && !(package_vk || package_dx12 || package_metal || package_gles2 || package_gles3)
+        && !(package_vk
+            || package_dx12
+            || package_metal
+            || package_gles2
+            || package_gles3
+            || package_webgpu)
     {
-        Err("A cooked shader file or path was specified but no shader types are specified to package. Pass --package-vk, --package-dx12, --package-metal, --package-gles2, --package-gles3, or --package-all")?;
+        Err("A cooked shader file or path was specified but no shader types are specified to package. Pass --package-vk, --package-dx12, --package-metal, --package-gles2, --package-gles3, --package-webgpu, or --package-all")?;
     }
 
     let code = std::fs::read_to_string(&glsl_file)?;
     let entry_point_name = "main";
 
+    let source_language = ShaderSourceLanguage::from_path(glsl_file);
+    log::trace!("source language: {:?}", source_language);
+    if source_language == ShaderSourceLanguage::Hlsl
+        && shader_kind == shaderc::ShaderKind::InferFromSource
+    {
+        Err("Could not determine the shader stage of an HLSL shader. Name the file like shader.frag.hlsl or pass --shader-kind")?;
+    }
+
     //
     // First, compile the code with shaderc. This will validate that it's well-formed. We will also
     // use the produced spv to create reflection data. This first pass must be UNOPTIMIZED so that
@@ -400,13 +477,15 @@ fn process_glsl_shader(
         || cooked_shader_file.is_some()
         || dx12_generated_src_file.is_some()
         || metal_generated_src_file.is_some()
-        || gles2_generated_src_file.is_some();
+        || gles2_generated_src_file.is_some()
+        || webgpu_generated_src_file.is_some();
 
     let require_semantics = cooked_shader_file.is_some() || dx12_generated_src_file.is_some();
     let compiler = shaderc::Compiler::new().unwrap();
 
     let compile_parameters = CompileParameters {
         glsl_file,
+        source_language,
         shader_kind,
         code: &code,
         entry_point_name,
@@ -415,10 +494,47 @@ fn process_glsl_shader(
         compiler: &compiler,
     };
 
-    let rust_code = if rs_file.is_some() {
-        let mut compile_result =
-            compile_glsl(&compile_parameters, PREPROCESSOR_DEF_PLATFORM_RUST_CODEGEN)?;
+    // The rust codegen compile is also used for the layout report, so only parse the source again
+    // if the rust code is not being generated
+    let rust_codegen_compile_result = if rs_file.is_some() {
+        Some(compile_glsl(
+            &compile_parameters,
+            PREPROCESSOR_DEF_PLATFORM_RUST_CODEGEN,
+        )?)
+    } else {
+        None
+    };
+
+    match &rust_codegen_compile_result {
+        Some(compile_result) => report_struct_layouts(
+            glsl_file,
+            &compile_result.builtin_types,
+            &compile_result.user_types,
+            &compile_result.parsed_declarations,
+            args,
+        )?,
+        None => {
+            log::trace!("{:?}: parse source for layout report", glsl_file);
+            let mut preprocessor_state = PreprocessorState::default();
+            preprocessor_state.add_define(
+                PREPROCESSOR_DEF_PLATFORM_RUST_CODEGEN.to_string(),
+                "1".to_string(),
+            );
+            let parsed_source = parse_shader_source(&compile_parameters, &mut preprocessor_state)?;
+            let parsed_declarations =
+                parse_declarations::parse_declarations(&parsed_source.declarations)?;
+            let user_types = shader_types::create_user_type_lookup(&parsed_declarations)?;
+            report_struct_layouts(
+                glsl_file,
+                &shader_types::create_builtin_type_lookup(),
+                &user_types,
+                &parsed_declarations,
+                args,
+            )?;
+        }
+    }
 
+    let rust_code = if let Some(mut compile_result) = rust_codegen_compile_result {
         log::trace!("{:?}: generate rust code", glsl_file);
         let reflected_entry_point = compile_result
             .reflection_data
@@ -484,6 +600,12 @@ fn process_glsl_shader(
         None
     };
 
+    let webgpu_output = if webgpu_generated_src_file.is_some() || package_webgpu {
+        Some(cross_compile_to_webgpu(glsl_file, &compile_parameters)?)
+    } else {
+        None
+    };
+
     //
     // Write out the spv and rust files if desired
     //
@@ -523,6 +645,13 @@ fn process_glsl_shader(
         )?;
     }
 
+    if let Some(webgpu_generated_src_file) = &webgpu_generated_src_file {
+        write_output_file(
+            webgpu_generated_src_file,
+            &webgpu_output.as_ref().unwrap().webgpu_src,
+        )?;
+    }
+
     // Don't worry about the return value
     log::trace!("{:?}: cook shader", glsl_file);
     let cooked_shader = if cooked_shader_file.is_some() {
@@ -558,6 +687,11 @@ fn process_glsl_shader(
             shader_package.gles3_reflection = gles3_output.reflection_data.map(|x| x.reflection);
         };
 
+        if let Some(webgpu_output) = webgpu_output.filter(|_| package_webgpu) {
+            shader_package.webgpu = Some(RafxShaderPackageWebGpu::Src(webgpu_output.webgpu_src));
+            shader_package.webgpu_reflection = webgpu_output.reflection_data.map(|x| x.reflection);
+        };
+
         shader_package.debug_name =
             Some(glsl_file.file_name().unwrap().to_string_lossy().to_string());
         let hashed_shader_package = RafxHashedShaderPackage::new(shader_package);
@@ -576,8 +710,48 @@ fn process_glsl_shader(
     Ok(())
 }
 
+// Checks that structs placed in GPU memory have the same layout everywhere they are used, and
+// suggests member orderings that need less padding
+fn report_struct_layouts(
+    glsl_file: &Path,
+    builtin_types: &FnvHashMap<String, TypeAlignmentInfo>,
+    user_types: &FnvHashMap<String, UserType>,
+    parsed_declarations: &ParseDeclarationsResult,
+    args: &ShaderProcessorArgs,
+) -> Result<(), Box<dyn Error>> {
+    log::trace!("{:?}: report struct layouts", glsl_file);
+    let report = layout_report::generate_layout_report(
+        builtin_types,
+        user_types,
+        parsed_declarations,
+        args.layout_report,
+    );
+
+    for line in &report.details {
+        log::info!("  {}", line);
+    }
+
+    for padding_suggestion in &report.padding_suggestions {
+        log::info!("  {}", padding_suggestion);
+    }
+
+    for mismatch in &report.mismatches {
+        log::warn!("  {}", mismatch);
+    }
+
+    if args.deny_layout_mismatches && !report.mismatches.is_empty() {
+        Err(format!(
+            "Found {} struct members with a layout that differs between memory layouts or backends. Reorder or pad the members so that they match, or remove --deny-layout-mismatches",
+            report.mismatches.len()
+        ))?;
+    }
+
+    Ok(())
+}
+
 struct CompileParameters<'a> {
     glsl_file: &'a Path,
+    source_language: ShaderSourceLanguage,
     shader_kind: ShaderKind,
     code: &'a str,
     entry_point_name: &'a str,
@@ -622,14 +796,48 @@ fn try_load_override_src(
     }
 }
 
+fn parse_shader_source(
+    parameters: &CompileParameters,
+    preprocessor_state: &mut PreprocessorState,
+) -> Result<parse_source::ShaderText, String> {
+    match parameters.source_language {
+        ShaderSourceLanguage::Glsl => parse_source::parse_glsl_src(
+            &parameters.glsl_file,
+            &parameters.code,
+            preprocessor_state,
+        ),
+        ShaderSourceLanguage::Hlsl => parse_source::parse_hlsl_src(
+            &parameters.glsl_file,
+            &parameters.code,
+            parameters.entry_point_name,
+            preprocessor_state,
+        ),
+    }
+}
+
+fn create_compile_options(parameters: &CompileParameters) -> shaderc::CompileOptions<'static> {
+    let mut compile_options = shaderc::CompileOptions::new().unwrap();
+    compile_options.set_include_callback(include::shaderc_include_callback);
+
+    if parameters.source_language == ShaderSourceLanguage::Hlsl {
+        // register(t0, space1) becomes set 1, binding 0. We intentionally do not use HLSL packing
+        // rules for cbuffers so that they have the same std140 layout as GLSL uniform blocks, which
+        // is what the generated rust code expects
+        compile_options.set_source_language(shaderc::SourceLanguage::HLSL);
+        compile_options.set_hlsl_io_mapping(true);
+        compile_options.set_auto_map_locations(true);
+    }
+
+    compile_options
+}
+
 fn compile_glsl(
     parameters: &CompileParameters,
     platform_define: &str,
 ) -> Result<CompileResult, Box<dyn Error>> {
     log::trace!("{:?}: compile unoptimized", parameters.glsl_file);
     let (unoptimized_spv, parsed_source) = {
-        let mut compile_options = shaderc::CompileOptions::new().unwrap();
-        compile_options.set_include_callback(include::shaderc_include_callback);
+        let mut compile_options = create_compile_options(parameters);
         compile_options.set_generate_debug_info();
         compile_options.add_macro_definition(platform_define, Some("1"));
 
@@ -643,15 +851,11 @@ fn compile_glsl(
             Some(&compile_options),
         )?;
 
-        log::trace!("{:?}: parse glsl", parameters.glsl_file);
+        log::trace!("{:?}: parse source", parameters.glsl_file);
 
         let mut preprocessor_state = PreprocessorState::default();
         preprocessor_state.add_define(platform_define.to_string(), "1".to_string());
-        let parsed_source = parse_source::parse_glsl_src(
-            &parameters.glsl_file,
-            &parameters.code,
-            &mut preprocessor_state,
-        )?;
+        let parsed_source = parse_shader_source(parameters, &mut preprocessor_state)?;
 
         (unoptimized_spv, parsed_source)
     };
@@ -721,8 +925,7 @@ fn cross_compile_to_vulkan(
     let compile_result = compile_glsl(compile_parameters, PREPROCESSOR_DEF_PLATFORM_VULKAN)?;
 
     let vk_spv = if args.optimize_shaders {
-        let mut compile_options = shaderc::CompileOptions::new().unwrap();
-        compile_options.set_include_callback(include::shaderc_include_callback);
+        let mut compile_options = create_compile_options(compile_parameters);
         compile_options.set_optimization_level(shaderc::OptimizationLevel::Performance);
         //NOTE: Could also use shaderc::OptimizationLevel::Size
 
@@ -903,6 +1106,73 @@ fn cross_compile_to_gles3(
     })
 }
 
+pub struct CrossCompileOutputWebGpu {
+    webgpu_src: String,
+    reflection_data: Option<ShaderProcessorRefectionData>,
+}
+
+fn cross_compile_to_webgpu(
+    glsl_file: &Path,
+    compile_parameters: &CompileParameters,
+) -> Result<CrossCompileOutputWebGpu, Box<dyn Error>> {
+    log::trace!("{:?}: create wgsl", glsl_file);
+    let compile_result = compile_glsl(compile_parameters, PREPROCESSOR_DEF_PLATFORM_WEBGPU)?;
+
+    let webgpu_src = if let Some(src) = try_load_override_src(glsl_file, ".wgsl")? {
+        wgsl_override::parse_and_check(&src, &compile_result.ast)?;
+        src
+    } else {
+        // spirv_cross can't emit WGSL, so use naga to translate the spv. naga rejects the OpLine
+        // debug instructions that shaderc emits, so strip them first.
+        let spv = strip_spirv_line_instructions(compile_result.unoptimized_spv.as_binary());
+        // naga panics on some SPIR-V it doesn't support instead of returning an error
+        let module = std::panic::catch_unwind(|| {
+            naga::front::spv::Frontend::new(spv.into_iter(), &naga::front::spv::Options::default())
+                .parse()
+        })
+        .map_err(|_| "naga panicked while parsing spv for WGSL translation".to_string())?
+        .map_err(|e| format!("Failed to parse spv for WGSL translation: {:?}", e))?;
+
+        let module_info = naga::valid::Validator::new(
+            naga::valid::ValidationFlags::all(),
+            naga::valid::Capabilities::all(),
+        )
+        .validate(&module)
+        .map_err(|e| format!("Failed to validate module for WGSL translation: {:?}", e))?;
+
+        naga::back::wgsl::write_string(
+            &module,
+            &module_info,
+            naga::back::wgsl::WriterFlags::empty(),
+        )
+        .map_err(|e| format!("Failed to write WGSL: {:?}", e))?
+    };
+
+    Ok(CrossCompileOutputWebGpu {
+        webgpu_src,
+        reflection_data: compile_result.reflection_data,
+    })
+}
+
+fn strip_spirv_line_instructions(spv: &[u32]) -> Vec<u32> {
+    const SPIRV_HEADER_WORD_COUNT: usize = 5;
+    const SPIRV_OP_LINE: u32 = 8;
+    const SPIRV_OP_NO_LINE: u32 = 317;
+
+    let mut stripped = spv[..SPIRV_HEADER_WORD_COUNT].to_vec();
+    let mut i = SPIRV_HEADER_WORD_COUNT;
+    while i < spv.len() {
+        let word_count = ((spv[i] >> 16) as usize).max(1);
+        let opcode = spv[i] & 0xFFFF;
+        if opcode != SPIRV_OP_LINE && opcode != SPIRV_OP_NO_LINE {
+            stripped.extend_from_slice(&spv[i..(i + word_count).min(spv.len())]);
+        }
+        i += word_count;
+    }
+
+    stripped
+}
+
 pub struct CrossCompileOutputGles2 {
     gles2_src: String,
     reflection_data: Option<ShaderProcessorRefectionData>,
@@ -1073,6 +1343,12 @@ fn deduce_default_shader_kind_from_path(path: &Path) -> Option<shaderc::ShaderKi
         ("mesh", shaderc::ShaderKind::DefaultMesh),
     ];
 
+    // For HLSL, the stage is the extension before .hlsl, i.e. shader.frag.hlsl
+    let path = match ShaderSourceLanguage::from_path(path) {
+        ShaderSourceLanguage::Glsl => path.to_path_buf(),
+        ShaderSourceLanguage::Hlsl => path.with_extension(""),
+    };
+
     if let Some(extension) = path.extension() {
         let as_str = extension.to_string_lossy();
 
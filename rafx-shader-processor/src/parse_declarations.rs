@@ -5,7 +5,7 @@ use super::DeclarationText;
 use std::num::ParseIntError;
 use std::sync::Arc;
 
-use rafx_api::RafxSamplerDef;
+use rafx_api::{RafxSamplerDef, RafxSpecializationConstantValue};
 
 #[derive(Default, Deserialize, Debug)]
 #[serde(rename = "export")]
@@ -318,6 +318,7 @@ pub(crate) struct ParsedLayoutParts {
     pub(crate) set: Option<usize>,
     pub(crate) binding: Option<usize>,
     pub(crate) location: Option<usize>,
+    pub(crate) constant_id: Option<usize>,
     pub(crate) push_constant: bool,
 }
 
@@ -375,6 +376,23 @@ impl ParsedLayoutParts {
 
                     parsed.location = Some(location)
                 }
+                "constant_id" => {
+                    if parsed.constant_id.is_some() {
+                        return Err(
+                            "layout parts for a binding defines constant_id multiple times"
+                                .to_string(),
+                        );
+                    }
+
+                    let constant_id: usize = part
+                        .value
+                        .as_ref()
+                        .ok_or_else(|| "constant_id in layout but no index assigned".to_string())?
+                        .parse()
+                        .map_err(|x: ParseIntError| x.to_string())?;
+
+                    parsed.constant_id = Some(constant_id)
+                }
                 _ => {}
             }
         }
@@ -387,6 +405,7 @@ impl ParsedLayoutParts {
 pub(crate) enum ParseBindingOrGroupSizeResult {
     Binding(ParseBindingResult),
     GroupSize(ParseGroupSizeResult),
+    SpecializationConstant(ParseSpecializationConstantResult),
 }
 
 #[derive(Debug)]
@@ -406,6 +425,97 @@ pub(crate) struct ParseGroupSizeResult {
     pub(crate) z: u32,
 }
 
+// layout (constant_id = ...) const TYPE NAME = VALUE;
+#[derive(Debug)]
+pub(crate) struct ParseSpecializationConstantResult {
+    pub(crate) constant_id: u32,
+    pub(crate) type_name: String,
+    pub(crate) name: String,
+    pub(crate) default_value: RafxSpecializationConstantValue,
+}
+
+// Only literals are supported as default values since the value must be known to reflect it
+fn parse_specialization_constant_value(
+    type_name: &str,
+    value: &str,
+) -> Result<RafxSpecializationConstantValue, String> {
+    match type_name {
+        "bool" => match value {
+            "true" => Ok(RafxSpecializationConstantValue::Bool(true)),
+            "false" => Ok(RafxSpecializationConstantValue::Bool(false)),
+            _ => Err(format!("Expected true or false but found {}", value)),
+        },
+        "int" => value
+            .parse::<i32>()
+            .map(RafxSpecializationConstantValue::Int)
+            .map_err(|_| format!("Expected an integer literal but found {}", value)),
+        "uint" => value
+            .trim_end_matches(|c| c == 'u' || c == 'U')
+            .parse::<u32>()
+            .map(RafxSpecializationConstantValue::UInt)
+            .map_err(|_| format!("Expected an unsigned integer literal but found {}", value)),
+        "float" => value
+            .trim_end_matches(|c| c == 'f' || c == 'F')
+            .parse::<f32>()
+            .map(RafxSpecializationConstantValue::Float)
+            .map_err(|_| format!("Expected a float literal but found {}", value)),
+        _ => Err(format!(
+            "Specialization constants must be bool, int, uint, or float but found {}",
+            type_name
+        )),
+    }
+}
+
+fn parse_specialization_constant(
+    code: &[char],
+    position: &mut usize,
+    layout_parts: &[LayoutPart],
+    identifiers: &[String],
+) -> Result<ParseSpecializationConstantResult, String> {
+    let layout_parts = ParsedLayoutParts::from_parts(layout_parts)?;
+
+    if identifiers.len() != 3 || identifiers[0] != "const" {
+        return Err(format!(
+            "Expected specialization constant in the form layout (constant_id = ...) const TYPE NAME = VALUE;:\n{}",
+            crate::parse_source::characters_to_string(&code)
+        ));
+    }
+
+    let type_name = identifiers[1].clone();
+    let name = identifiers[2].clone();
+
+    crate::parse_source::try_consume_literal(code, position, "=").ok_or_else(|| {
+        format!(
+            "Expected = while parsing specialization constant:\n{}",
+            crate::parse_source::characters_to_string(&code)
+        )
+    })?;
+
+    let value_start = *position;
+    while *position < code.len() && code[*position] != ';' {
+        *position += 1;
+    }
+
+    if *position >= code.len() {
+        return Err(format!(
+            "Expected ; while parsing specialization constant:\n{}",
+            crate::parse_source::characters_to_string(&code)
+        ));
+    }
+
+    let value = crate::parse_source::characters_to_string(&code[value_start..*position]);
+    let default_value = parse_specialization_constant_value(&type_name, value.trim())
+        .map_err(|x| format!("Error parsing specialization constant '{}': {}", name, x))?;
+
+    Ok(ParseSpecializationConstantResult {
+        // from_parts() only returns a value here if constant_id was specified
+        constant_id: layout_parts.constant_id.unwrap() as u32,
+        type_name,
+        name,
+        default_value,
+    })
+}
+
 fn parse_layout_part(
     code: &[char],
     position: &mut usize,
@@ -528,6 +638,13 @@ fn try_parse_binding_or_group_size(
         identifiers.push(instance_name);
     }
 
+    // Specialization constants look like a binding but have a value assigned
+    if layout_parts.iter().any(|x| x.key == "constant_id") {
+        return Ok(Some(ParseBindingOrGroupSizeResult::SpecializationConstant(
+            parse_specialization_constant(code, &mut position, &layout_parts, &identifiers)?,
+        )));
+    }
+
     // If we see the special compute shader group size info, parse this "binding" differently
     if identifiers.len() == 1 && identifiers[0] == "in" {
         let mut local_size_x = 1;
@@ -677,6 +794,7 @@ pub(crate) struct ParseDeclarationsResult {
     pub(crate) structs: Vec<ParsedStructWithAnnotations>,
     pub(crate) bindings: Vec<ParsedBindingWithAnnotations>,
     pub(crate) group_size: Option<ParseGroupSizeResult>,
+    pub(crate) specialization_constants: Vec<ParseSpecializationConstantResult>,
 }
 
 pub(crate) fn parse_declarations(
@@ -685,6 +803,7 @@ pub(crate) fn parse_declarations(
     let mut structs = Vec::default();
     let mut bindings = Vec::default();
     let mut group_size = None;
+    let mut specialization_constants = Vec::<ParseSpecializationConstantResult>::default();
 
     //
     // Parse all declarations and their annotations
@@ -749,6 +868,27 @@ pub(crate) fn parse_declarations(
 
                     group_size = Some(group_size_result);
                 }
+                ParseBindingOrGroupSizeResult::SpecializationConstant(constant_result) => {
+                    log::trace!("Parsed a specialization constant {:?}", constant_result);
+                    if !declaration.annotations.is_empty() {
+                        return Err(format!(
+                            "Annotations on specialization constants not yet supported:\n{}",
+                            crate::parse_source::characters_to_string(&declaration.text)
+                        ));
+                    }
+
+                    if let Some(existing) = specialization_constants
+                        .iter()
+                        .find(|x| x.constant_id == constant_result.constant_id)
+                    {
+                        return Err(format!(
+                            "Specialization constants {} and {} both use constant_id {}",
+                            existing.name, constant_result.name, constant_result.constant_id
+                        ));
+                    }
+
+                    specialization_constants.push(constant_result);
+                }
             }
         } else if try_parse_const(&declaration.text)?.is_some() {
             //
@@ -772,5 +912,104 @@ pub(crate) fn parse_declarations(
         structs,
         bindings,
         group_size,
+        specialization_constants,
     })
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::parse_source::PreprocessorState;
+
+    fn parse_declarations_for_test(shader_code: &str) -> Result<ParseDeclarationsResult, String> {
+        let mut preprocessor_state = PreprocessorState::default();
+        let parsed_source = crate::parse_source::parse_glsl_src(
+            std::path::Path::new(""),
+            shader_code,
+            &mut preprocessor_state,
+        )
+        .unwrap();
+        parse_declarations(&parsed_source.declarations)
+    }
+
+    #[test]
+    fn test_parse_specialization_constants() {
+        let parsed = parse_declarations_for_test(
+            r#"
+            #version 450
+
+            layout (constant_id = 0) const bool ENABLE_SHADOWS = true;
+            layout (constant_id = 1) const int SAMPLE_COUNT = -4;
+            layout (constant_id = 2) const uint LIGHT_COUNT = 16u;
+            layout(constant_id=3) const float EXPOSURE = 1.5;
+            "#,
+        )
+        .unwrap();
+
+        let constants: Vec<_> = parsed
+            .specialization_constants
+            .iter()
+            .map(|x| {
+                (
+                    x.constant_id,
+                    x.type_name.as_str(),
+                    x.name.as_str(),
+                    x.default_value,
+                )
+            })
+            .collect();
+
+        assert_eq!(
+            constants,
+            vec![
+                (
+                    0,
+                    "bool",
+                    "ENABLE_SHADOWS",
+                    RafxSpecializationConstantValue::Bool(true)
+                ),
+                (
+                    1,
+                    "int",
+                    "SAMPLE_COUNT",
+                    RafxSpecializationConstantValue::Int(-4)
+                ),
+                (
+                    2,
+                    "uint",
+                    "LIGHT_COUNT",
+                    RafxSpecializationConstantValue::UInt(16)
+                ),
+                (
+                    3,
+                    "float",
+                    "EXPOSURE",
+                    RafxSpecializationConstantValue::Float(1.5)
+                ),
+            ]
+        );
+        assert!(parsed.bindings.is_empty());
+    }
+
+    #[test]
+    fn test_parse_specialization_constant_errors() {
+        // Default values must be literals
+        assert!(parse_declarations_for_test(
+            r#"
+            #version 450
+            layout (constant_id = 0) const int SAMPLE_COUNT = 2 * 2;
+            "#,
+        )
+        .is_err());
+
+        // Constant IDs must be unique
+        assert!(parse_declarations_for_test(
+            r#"
+            #version 450
+            layout (constant_id = 0) const int A = 1;
+            layout (constant_id = 0) const int B = 2;
+            "#,
+        )
+        .is_err());
+    }
+}
@@ -0,0 +1,1237 @@
+use super::AnnotationText;
+use super::DeclarationText;
+use crate::parse_source::{
+    characters_to_string, is_identifier_char, is_string_at_position, skip_whitespace,
+    try_consume_identifier, try_consume_literal,
+};
+use fnv::FnvHashMap;
+
+//
+// HLSL sources are scanned by the same code as GLSL sources, so includes, #ifdefs and annotations
+// in comments all work the same way. The declarations we care about (structs, cbuffers, resources,
+// specialization constants, the compute group size, and the entry point's inputs) are rewritten
+// into the equivalent GLSL declaration so that parse_declarations and everything after it does not
+// need to know which language the shader was written in.
+//
+
+// Resource types that are declared as `uniform <glsl type> name;` in GLSL
+const HLSL_RESOURCE_TYPES: [(&'static str, &'static str); 19] = [
+    ("Texture1D", "texture1D"),
+    ("Texture1DArray", "texture1DArray"),
+    ("Texture2D", "texture2D"),
+    ("Texture2DArray", "texture2DArray"),
+    ("Texture2DMS", "texture2DMS"),
+    ("Texture2DMSArray", "texture2DMSArray"),
+    ("Texture3D", "texture3D"),
+    ("TextureCube", "textureCube"),
+    ("TextureCubeArray", "textureCubeArray"),
+    ("RWTexture1D", "image1D"),
+    ("RWTexture1DArray", "image1DArray"),
+    ("RWTexture2D", "image2D"),
+    ("RWTexture2DArray", "image2DArray"),
+    ("RWTexture3D", "image3D"),
+    ("SamplerState", "sampler"),
+    ("SamplerComparisonState", "samplerShadow"),
+    ("Buffer", "textureBuffer"),
+    ("RWBuffer", "imageBuffer"),
+    (
+        "RaytracingAccelerationStructure",
+        "accelerationStructureEXT",
+    ),
+];
+
+// Resource types that are declared as `buffer Block { T name[]; } name;` in GLSL
+const HLSL_STORAGE_BUFFER_TYPES: [&'static str; 6] = [
+    "StructuredBuffer",
+    "RWStructuredBuffer",
+    "AppendStructuredBuffer",
+    "ConsumeStructuredBuffer",
+    "ByteAddressBuffer",
+    "RWByteAddressBuffer",
+];
+
+// Modifiers that don't affect how we reflect a field/parameter
+const HLSL_IGNORED_MODIFIERS: [&'static str; 11] = [
+    "row_major",
+    "column_major",
+    "precise",
+    "nointerpolation",
+    "linear",
+    "centroid",
+    "noperspective",
+    "sample",
+    "uniform",
+    "const",
+    "static",
+];
+
+#[derive(Debug, Clone)]
+struct HlslAttribute {
+    name: String,
+    args: Vec<String>,
+}
+
+fn find_attribute<'a>(
+    attributes: &'a [HlslAttribute],
+    name: &str,
+) -> Option<&'a HlslAttribute> {
+    attributes.iter().find(|x| x.name == name)
+}
+
+fn attribute_arg_as_usize(
+    attribute: &HlslAttribute,
+    index: usize,
+) -> Result<Option<usize>, String> {
+    attribute
+        .args
+        .get(index)
+        .map(|x| {
+            x.parse::<usize>().map_err(|_| {
+                format!(
+                    "Expected an integer literal for argument {} of attribute {} but found {}",
+                    index, attribute.name, x
+                )
+            })
+        })
+        .transpose()
+}
+
+#[derive(Debug, Clone)]
+struct HlslField {
+    attributes: Vec<HlslAttribute>,
+    modifiers: Vec<String>,
+    type_name: String,
+    name: String,
+    array_sizes: String,
+    semantic: Option<String>,
+}
+
+#[derive(Debug, Default)]
+struct HlslRegister {
+    binding: Option<usize>,
+    set: Option<usize>,
+}
+
+#[derive(Debug)]
+enum HlslDeclaration {
+    Struct {
+        name: String,
+        fields: Vec<HlslField>,
+    },
+    CBuffer {
+        attributes: Vec<HlslAttribute>,
+        name: String,
+        register: HlslRegister,
+        fields: Vec<HlslField>,
+    },
+    Resource {
+        attributes: Vec<HlslAttribute>,
+        type_name: String,
+        template_arg: Option<String>,
+        name: String,
+        array_sizes: String,
+        register: HlslRegister,
+    },
+    SpecializationConstant {
+        constant_id: usize,
+        type_name: String,
+        name: String,
+        value: String,
+    },
+    EntryPoint {
+        attributes: Vec<HlslAttribute>,
+        parameters: Vec<HlslField>,
+    },
+    Ignored {
+        attributes: Vec<HlslAttribute>,
+    },
+}
+
+// Handles both [numthreads(...)] and [[vk::binding(...)]] style attributes
+fn try_consume_attributes(
+    code: &[char],
+    position: &mut usize,
+) -> Option<Vec<HlslAttribute>> {
+    let mut attributes = Vec::default();
+    loop {
+        skip_whitespace(code, position);
+        let double_bracket = if try_consume_literal(code, position, "[[").is_some() {
+            true
+        } else if try_consume_literal(code, position, "[").is_some() {
+            false
+        } else {
+            break;
+        };
+
+        // Attribute names may be namespaced, i.e. vk::binding
+        let mut name = try_consume_identifier(code, position)?;
+        while try_consume_literal(code, position, "::").is_some() {
+            name.push_str("::");
+            name.push_str(&try_consume_identifier(code, position)?);
+        }
+
+        let mut args = Vec::default();
+        skip_whitespace(code, position);
+        if try_consume_literal(code, position, "(").is_some() {
+            let args_begin = *position;
+            let args_end = find_closing(code, *position, '(', ')')?;
+            args = split_top_level(&code[args_begin..args_end], ',')
+                .into_iter()
+                .map(|x| characters_to_string(x).trim().to_string())
+                .filter(|x| !x.is_empty())
+                .collect();
+            *position = args_end + 1;
+        }
+
+        skip_whitespace(code, position);
+        let closing = if double_bracket { "]]" } else { "]" };
+        try_consume_literal(code, position, closing)?;
+
+        attributes.push(HlslAttribute { name, args });
+    }
+
+    Some(attributes)
+}
+
+// Returns the position of the bracket that closes the one that was opened just before position
+fn find_closing(
+    code: &[char],
+    position: usize,
+    open: char,
+    close: char,
+) -> Option<usize> {
+    let mut depth = 1;
+    for i in position..code.len() {
+        if code[i] == open {
+            depth += 1;
+        } else if code[i] == close {
+            depth -= 1;
+            if depth == 0 {
+                return Some(i);
+            }
+        }
+    }
+
+    None
+}
+
+fn split_top_level(
+    code: &[char],
+    separator: char,
+) -> Vec<&[char]> {
+    let mut parts = Vec::default();
+    let mut depth = 0;
+    let mut begin = 0;
+    for (i, &c) in code.iter().enumerate() {
+        match c {
+            '(' | '[' | '<' | '{' => depth += 1,
+            ')' | ']' | '>' | '}' => depth -= 1,
+            _ => {}
+        }
+
+        if c == separator && depth == 0 {
+            parts.push(&code[begin..i]);
+            begin = i + 1;
+        }
+    }
+
+    parts.push(&code[begin..]);
+    parts
+}
+
+// Consume up to and including the next ; that is not inside a {} block
+fn consume_to_semicolon(
+    code: &[char],
+    position: usize,
+) -> Option<usize> {
+    let mut brace_count = 0;
+    for i in position..code.len() {
+        if code[i] == '{' {
+            brace_count += 1;
+        } else if code[i] == '}' {
+            brace_count -= 1;
+        }
+
+        if code[i] == ';' && brace_count == 0 {
+            return Some(i + 1);
+        }
+    }
+
+    None
+}
+
+// Consume a {} block and the ; that may follow it. (cbuffers do not require a trailing ;)
+fn consume_block(
+    code: &[char],
+    position: usize,
+) -> Option<usize> {
+    let mut position = position;
+    while position < code.len() && code[position] != '{' {
+        if code[position] == ';' {
+            return None;
+        }
+        position += 1;
+    }
+
+    let mut position = find_closing(code, position + 1, '{', '}')? + 1;
+    let end_of_block = position;
+    skip_whitespace(code, &mut position);
+    if try_consume_literal(code, &mut position, ";").is_some() {
+        Some(position)
+    } else {
+        Some(end_of_block)
+    }
+}
+
+fn try_consume_template_arg(
+    code: &[char],
+    position: &mut usize,
+) -> Option<String> {
+    let mut p = *position;
+    skip_whitespace(code, &mut p);
+    if try_consume_literal(code, &mut p, "<").is_some() {
+        let end = find_closing(code, p, '<', '>')?;
+        *position = end + 1;
+        Some(characters_to_string(&code[p..end]).trim().to_string())
+    } else {
+        None
+    }
+}
+
+fn is_entry_point_header(
+    code: &[char],
+    mut position: usize,
+    entry_point_name: &str,
+) -> bool {
+    // Return type, which may be templated
+    if try_consume_identifier(code, &mut position).is_none() {
+        return false;
+    }
+
+    let _ = try_consume_template_arg(code, &mut position);
+    match try_consume_identifier(code, &mut position) {
+        Some(name) if name == entry_point_name => {}
+        _ => return false,
+    }
+
+    skip_whitespace(code, &mut position);
+    try_consume_literal(code, &mut position, "(").is_some()
+}
+
+// The HLSL equivalent of parse_source::try_consume_declaration(). Returns the end of the
+// declaration that starts at position, if there is one.
+pub(crate) fn try_consume_hlsl_declaration(
+    code: &[char],
+    position: usize,
+    entry_point_name: &str,
+) -> Option<usize> {
+    assert!(position < code.len());
+    if position > 0 && is_identifier_char(code[position - 1]) {
+        return None;
+    }
+
+    if !is_identifier_char(code[position]) && code[position] != '[' {
+        // Quick early out
+        return None;
+    }
+
+    let mut end = position;
+    let attributes = try_consume_attributes(code, &mut end)?;
+    let end_of_attributes = end;
+
+    skip_whitespace(code, &mut end);
+    if end >= code.len() {
+        return Some(end_of_attributes);
+    }
+
+    if is_string_at_position(code, end, "struct", false)
+        || is_string_at_position(code, end, "cbuffer", false)
+    {
+        return consume_block(code, end);
+    }
+
+    let mut p = end;
+    if let Some(identifier) = try_consume_identifier(code, &mut p) {
+        let is_resource = identifier == "ConstantBuffer"
+            || HLSL_RESOURCE_TYPES.iter().any(|x| x.0 == identifier)
+            || HLSL_STORAGE_BUFFER_TYPES.contains(&identifier.as_str());
+
+        // Plain consts/globals are only interesting if they have an attribute making them a
+        // specialization constant/push constant
+        let is_specialization_constant =
+            identifier == "const" && find_attribute(&attributes, "vk::constant_id").is_some();
+        let is_push_constant = find_attribute(&attributes, "vk::push_constant").is_some()
+            && !is_entry_point_header(code, end, entry_point_name);
+
+        if is_resource || is_specialization_constant || is_push_constant {
+            return consume_to_semicolon(code, end);
+        }
+    }
+
+    if is_entry_point_header(code, end, entry_point_name) {
+        // Consume the return type, name, parameters and optional semantic. The function body will
+        // be skipped like any other unrecognized block
+        let open_paren = (end..code.len()).find(|&i| code[i] == '(')?;
+        let mut p = find_closing(code, open_paren + 1, '(', ')')? + 1;
+        let after_parameters = p;
+        skip_whitespace(code, &mut p);
+        if try_consume_literal(code, &mut p, ":").is_some()
+            && try_consume_identifier(code, &mut p).is_some()
+        {
+            return Some(p);
+        }
+
+        return Some(after_parameters);
+    }
+
+    if !attributes.is_empty() {
+        return Some(end_of_attributes);
+    }
+
+    None
+}
+
+// Parses `[attributes] [modifiers] type name[array sizes] [: semantic/register/packoffset] [= default]`
+fn parse_field(code: &[char]) -> Result<Option<HlslField>, String> {
+    let mut position = 0;
+    let attributes = try_consume_attributes(code, &mut position).ok_or_else(|| {
+        format!(
+            "Failed to parse attributes:\n{}",
+            characters_to_string(code)
+        )
+    })?;
+
+    let mut identifiers = Vec::default();
+    let mut template_arg = None;
+    while let Some(identifier) = try_consume_identifier(code, &mut position) {
+        identifiers.push(identifier);
+        if let Some(arg) = try_consume_template_arg(code, &mut position) {
+            template_arg = Some(arg);
+        }
+    }
+
+    if identifiers.is_empty() {
+        return Ok(None);
+    }
+
+    if identifiers.len() < 2 {
+        return Err(format!(
+            "Expected a type and a name while parsing field:\n{}",
+            characters_to_string(code)
+        ));
+    }
+
+    let name = identifiers.pop().unwrap();
+    let mut type_name = identifiers.pop().unwrap();
+    if let Some(template_arg) = template_arg {
+        type_name = translate_hlsl_template_type(&type_name, &template_arg)?;
+    }
+
+    let modifiers = identifiers
+        .into_iter()
+        .filter(|x| !HLSL_IGNORED_MODIFIERS.contains(&x.as_str()))
+        .collect();
+
+    skip_whitespace(code, &mut position);
+    let array_begin = position;
+    while position < code.len() && code[position] != ':' && code[position] != '=' {
+        position += 1;
+    }
+    let array_sizes = characters_to_string(&code[array_begin..position])
+        .trim()
+        .to_string();
+
+    let mut semantic = None;
+    if try_consume_literal(code, &mut position, ":").is_some() {
+        if let Some(identifier) = try_consume_identifier(code, &mut position) {
+            if identifier != "register" && identifier != "packoffset" {
+                semantic = Some(identifier);
+            }
+        }
+    }
+
+    Ok(Some(HlslField {
+        attributes,
+        modifiers,
+        type_name,
+        name,
+        array_sizes,
+        semantic,
+    }))
+}
+
+fn parse_fields(code: &[char]) -> Result<Vec<HlslField>, String> {
+    let mut fields = Vec::default();
+    for field in split_top_level(code, ';') {
+        if field.contains(&'{') {
+            return Err(format!(
+                "Nested struct declarations are not supported:\n{}",
+                characters_to_string(code)
+            ));
+        }
+
+        if let Some(field) = parse_field(field)? {
+            fields.push(field);
+        }
+    }
+
+    Ok(fields)
+}
+
+// Returns the fields between the next { and its matching }
+fn parse_block_fields(
+    code: &[char],
+    position: &mut usize,
+) -> Result<Vec<HlslField>, String> {
+    skip_whitespace(code, position);
+    try_consume_literal(code, position, "{").ok_or_else(|| {
+        format!(
+            "Expected {{ while parsing block:\n{}",
+            characters_to_string(code)
+        )
+    })?;
+
+    let end = find_closing(code, *position, '{', '}').ok_or_else(|| {
+        format!(
+            "Expected }} while parsing block:\n{}",
+            characters_to_string(code)
+        )
+    })?;
+
+    let fields = parse_fields(&code[*position..end])?;
+    *position = end + 1;
+    Ok(fields)
+}
+
+// Parses the optional `: register(t0, space1)` after a resource
+fn parse_register(
+    code: &[char],
+    position: &mut usize,
+) -> Result<HlslRegister, String> {
+    let mut register = HlslRegister::default();
+    skip_whitespace(code, position);
+    if try_consume_literal(code, position, ":").is_none() {
+        return Ok(register);
+    }
+
+    if try_consume_identifier(code, position).as_deref() != Some("register") {
+        return Err(format!(
+            "Expected register(...) after : while parsing resource:\n{}",
+            characters_to_string(code)
+        ));
+    }
+
+    skip_whitespace(code, position);
+    try_consume_literal(code, position, "(").ok_or_else(|| {
+        format!(
+            "Expected ( after register while parsing resource:\n{}",
+            characters_to_string(code)
+        )
+    })?;
+    let end = find_closing(code, *position, '(', ')').ok_or_else(|| {
+        format!(
+            "Expected ) after register while parsing resource:\n{}",
+            characters_to_string(code)
+        )
+    })?;
+
+    for part in split_top_level(&code[*position..end], ',') {
+        let part = characters_to_string(part).trim().to_string();
+        let (prefix, index) = if let Some(space) = part.strip_prefix("space") {
+            ("space", space)
+        } else if !part.is_empty() {
+            // The register type (b/t/s/u) doesn't matter, bindings are unique within a set
+            part.split_at(1)
+        } else {
+            continue;
+        };
+
+        let index = index.parse::<usize>().map_err(|_| {
+            format!(
+                "Could not parse register {:?} while parsing resource:\n{}",
+                part,
+                characters_to_string(code)
+            )
+        })?;
+
+        if prefix == "space" {
+            register.set = Some(index);
+        } else {
+            register.binding = Some(index);
+        }
+    }
+
+    *position = end + 1;
+    Ok(register)
+}
+
+fn parse_hlsl_declaration(
+    code: &[char],
+    entry_point_name: &str,
+) -> Result<HlslDeclaration, String> {
+    let mut position = 0;
+    let attributes = try_consume_attributes(code, &mut position).ok_or_else(|| {
+        format!(
+            "Failed to parse attributes:\n{}",
+            characters_to_string(code)
+        )
+    })?;
+
+    skip_whitespace(code, &mut position);
+    if is_entry_point_header(code, position, entry_point_name) {
+        let open_paren = (position..code.len()).find(|&i| code[i] == '(').unwrap();
+        let close_paren = find_closing(code, open_paren + 1, '(', ')').unwrap();
+        let mut parameters = Vec::default();
+        for parameter in split_top_level(&code[(open_paren + 1)..close_paren], ',') {
+            if let Some(parameter) = parse_field(parameter)? {
+                parameters.push(parameter);
+            }
+        }
+
+        return Ok(HlslDeclaration::EntryPoint {
+            attributes,
+            parameters,
+        });
+    }
+
+    let mut identifiers = Vec::default();
+    let mut template_arg = None;
+    while let Some(identifier) = try_consume_identifier(code, &mut position) {
+        let is_keyword = identifier == "struct" || identifier == "cbuffer";
+        identifiers.push(identifier);
+        if is_keyword {
+            // The next identifier is the name, then the fields follow
+            break;
+        }
+
+        if let Some(arg) = try_consume_template_arg(code, &mut position) {
+            template_arg = Some(arg);
+        }
+    }
+
+    match identifiers.first().map(|x| x.as_str()) {
+        Some("struct") => {
+            let name = try_consume_identifier(code, &mut position).ok_or_else(|| {
+                format!(
+                    "Expected name of struct while parsing struct:\n{}",
+                    characters_to_string(code)
+                )
+            })?;
+            let fields = parse_block_fields(code, &mut position)?;
+            Ok(HlslDeclaration::Struct { name, fields })
+        }
+        Some("cbuffer") => {
+            let name = try_consume_identifier(code, &mut position).ok_or_else(|| {
+                format!(
+                    "Expected name of cbuffer while parsing cbuffer:\n{}",
+                    characters_to_string(code)
+                )
+            })?;
+            let register = parse_register(code, &mut position)?;
+            let fields = parse_block_fields(code, &mut position)?;
+            Ok(HlslDeclaration::CBuffer {
+                attributes,
+                name,
+                register,
+                fields,
+            })
+        }
+        Some("const") if find_attribute(&attributes, "vk::constant_id").is_some() => {
+            let attribute = find_attribute(&attributes, "vk::constant_id").unwrap();
+            let constant_id = attribute_arg_as_usize(attribute, 0)?.ok_or_else(|| {
+                format!(
+                    "Expected vk::constant_id to have an id:\n{}",
+                    characters_to_string(code)
+                )
+            })?;
+
+            if identifiers.len() != 3 {
+                return Err(format!(
+                    "Expected specialization constant in the form [[vk::constant_id(...)]] const TYPE NAME = VALUE;:\n{}",
+                    characters_to_string(code)
+                ));
+            }
+
+            skip_whitespace(code, &mut position);
+            try_consume_literal(code, &mut position, "=").ok_or_else(|| {
+                format!(
+                    "Expected = while parsing specialization constant:\n{}",
+                    characters_to_string(code)
+                )
+            })?;
+            let value_end = (position..code.len())
+                .find(|&i| code[i] == ';')
+                .unwrap_or(code.len());
+
+            Ok(HlslDeclaration::SpecializationConstant {
+                constant_id,
+                type_name: identifiers[1].clone(),
+                name: identifiers[2].clone(),
+                value: characters_to_string(&code[position..value_end])
+                    .trim()
+                    .to_string(),
+            })
+        }
+        _ if identifiers.len() == 2 => {
+            let array_begin = position;
+            while position < code.len() && code[position] != ':' && code[position] != ';' {
+                position += 1;
+            }
+            let array_sizes = characters_to_string(&code[array_begin..position])
+                .trim()
+                .to_string();
+            let register = parse_register(code, &mut position)?;
+
+            Ok(HlslDeclaration::Resource {
+                attributes,
+                type_name: identifiers[0].clone(),
+                template_arg,
+                name: identifiers[1].clone(),
+                array_sizes,
+                register,
+            })
+        }
+        _ => Ok(HlslDeclaration::Ignored { attributes }),
+    }
+}
+
+// vector<float, 3> -> float3, matrix<float, 4, 4> -> float4x4
+fn translate_hlsl_template_type(
+    type_name: &str,
+    template_arg: &str,
+) -> Result<String, String> {
+    let args: Vec<_> = template_arg.split(',').map(|x| x.trim()).collect();
+    match (type_name, args.len()) {
+        ("vector", 2) => Ok(format!("{}{}", args[0], args[1])),
+        ("matrix", 3) => Ok(format!("{}{}x{}", args[0], args[1], args[2])),
+        _ => Err(format!(
+            "Templated type {}<{}> is not supported here",
+            type_name, template_arg
+        )),
+    }
+}
+
+// float4 -> vec4, uint2 -> uvec2, float4x4 -> mat4, etc. User-defined types are left alone.
+fn translate_hlsl_type(type_name: &str) -> String {
+    let scalar_types = [
+        ("float", "float", "vec", true),
+        ("half", "float", "vec", true),
+        ("double", "double", "dvec", true),
+        ("int", "int", "ivec", false),
+        ("uint", "uint", "uvec", false),
+        ("dword", "uint", "uvec", false),
+        ("bool", "bool", "bvec", false),
+    ];
+
+    for &(hlsl_scalar, glsl_scalar, glsl_vector_prefix, allows_matrix) in &scalar_types {
+        if let Some(dimensions) = type_name.strip_prefix(hlsl_scalar) {
+            let dimensions: Vec<_> = dimensions.chars().collect();
+            let is_dimension = |c: char| ('1'..='4').contains(&c);
+            match dimensions.as_slice() {
+                [] | ['1'] => return glsl_scalar.to_string(),
+                [n] if is_dimension(*n) => return format!("{}{}", glsl_vector_prefix, n),
+                [rows, 'x', columns]
+                    if allows_matrix && is_dimension(*rows) && is_dimension(*columns) =>
+                {
+                    // HLSL rows map to SPIR-V columns, so floatRxC has the same layout as matRxC
+                    let prefix = if glsl_scalar == "double" {
+                        "dmat"
+                    } else {
+                        "mat"
+                    };
+                    if rows == columns {
+                        return format!("{}{}", prefix, rows);
+                    } else {
+                        return format!("{}{}x{}", prefix, rows, columns);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    type_name.to_string()
+}
+
+// The number of vertex input locations consumed by a type, i.e. 4 for a float4x4
+fn location_count(glsl_type_name: &str) -> usize {
+    let matrix_dimensions = glsl_type_name
+        .strip_prefix("mat")
+        .or_else(|| glsl_type_name.strip_prefix("dmat"));
+    match matrix_dimensions.and_then(|x| x.chars().next()) {
+        Some(columns) => columns.to_digit(10).unwrap_or(1) as usize,
+        None => 1,
+    }
+}
+
+fn format_layout(
+    attributes: &[HlslAttribute],
+    register: &HlslRegister,
+    name: &str,
+) -> Result<String, String> {
+    if find_attribute(attributes, "vk::push_constant").is_some() {
+        return Ok("layout(push_constant)".to_string());
+    }
+
+    let (binding, set) = if let Some(attribute) = find_attribute(attributes, "vk::binding") {
+        (
+            attribute_arg_as_usize(attribute, 0)?,
+            attribute_arg_as_usize(attribute, 1)?.or(Some(0)),
+        )
+    } else {
+        (register.binding, register.set.or(Some(0)))
+    };
+
+    let binding = binding.ok_or_else(|| {
+        format!(
+            "HLSL resource {} must be assigned a binding with register(...) or [[vk::binding(...)]]",
+            name
+        )
+    })?;
+
+    Ok(format!(
+        "layout(set = {}, binding = {})",
+        set.unwrap(),
+        binding
+    ))
+}
+
+fn format_fields(fields: &[HlslField]) -> String {
+    let mut text = String::default();
+    for field in fields {
+        text += &format!(
+            "    {} {}{};\n",
+            translate_hlsl_type(&field.type_name),
+            field.name,
+            field.array_sizes
+        );
+    }
+    text
+}
+
+fn push_entry_point_inputs(
+    structs: &FnvHashMap<String, Vec<HlslField>>,
+    parameters: &[HlslField],
+    next_location: &mut usize,
+    translated: &mut Vec<DeclarationText>,
+) -> Result<(), String> {
+    for parameter in parameters {
+        if parameter.modifiers.iter().any(|x| x == "out") {
+            continue;
+        }
+
+        if let Some(fields) = structs.get(&parameter.type_name) {
+            // Struct members become individual inputs
+            push_entry_point_inputs(structs, fields, next_location, translated)?;
+            continue;
+        }
+
+        // System values like SV_VertexID are not vertex attributes
+        let semantic = match &parameter.semantic {
+            Some(semantic) if !semantic.to_uppercase().starts_with("SV_") => semantic,
+            _ => continue,
+        };
+
+        let location =
+            if let Some(attribute) = find_attribute(&parameter.attributes, "vk::location") {
+                attribute_arg_as_usize(attribute, 0)?.unwrap_or(*next_location)
+            } else {
+                *next_location
+            };
+
+        let type_name = translate_hlsl_type(&parameter.type_name);
+        *next_location = location + location_count(&type_name);
+
+        let text = format!(
+            "layout(location = {}) in {} {}{};",
+            location, type_name, parameter.name, parameter.array_sizes
+        );
+        translated.push(DeclarationText {
+            text: text.chars().collect(),
+            annotations: vec![AnnotationText {
+                text: format!("semantic({:?})", semantic).chars().collect(),
+                position: 0,
+            }],
+        });
+    }
+
+    Ok(())
+}
+
+// Rewrites HLSL declarations found by try_consume_hlsl_declaration() into the equivalent GLSL
+// declarations
+pub(crate) fn translate_hlsl_declarations(
+    declarations: Vec<DeclarationText>,
+    entry_point_name: &str,
+) -> Result<Vec<DeclarationText>, String> {
+    let mut parsed = Vec::with_capacity(declarations.len());
+    let mut structs = FnvHashMap::<String, Vec<HlslField>>::default();
+    for declaration in declarations {
+        let hlsl_declaration = parse_hlsl_declaration(&declaration.text, entry_point_name)?;
+        if let HlslDeclaration::Struct { name, fields } = &hlsl_declaration {
+            structs.insert(name.clone(), fields.clone());
+        }
+
+        parsed.push((hlsl_declaration, declaration.annotations));
+    }
+
+    let mut translated = Vec::default();
+    let mut push = |text: String, annotations: Vec<AnnotationText>| {
+        translated.push(DeclarationText {
+            text: text.chars().collect(),
+            annotations,
+        })
+    };
+
+    let mut entry_point_parameters = None;
+    for (declaration, annotations) in parsed {
+        let attributes = match &declaration {
+            HlslDeclaration::CBuffer { attributes, .. }
+            | HlslDeclaration::Resource { attributes, .. }
+            | HlslDeclaration::EntryPoint { attributes, .. }
+            | HlslDeclaration::Ignored { attributes } => attributes.clone(),
+            _ => Vec::default(),
+        };
+
+        if let Some(attribute) = find_attribute(&attributes, "numthreads") {
+            let group_size: Vec<_> = attribute.args.iter().map(|x| x.as_str()).collect();
+            if group_size.len() != 3 {
+                Err(format!(
+                    "Expected numthreads to have 3 arguments but found {:?}",
+                    attribute.args
+                ))?;
+            }
+
+            push(
+                format!(
+                    "layout(local_size_x = {}, local_size_y = {}, local_size_z = {}) in;",
+                    group_size[0], group_size[1], group_size[2]
+                ),
+                Vec::default(),
+            );
+        }
+
+        match declaration {
+            HlslDeclaration::Struct { name, fields } => {
+                push(
+                    format!("struct {} {{\n{}}};", name, format_fields(&fields)),
+                    annotations,
+                );
+            }
+            HlslDeclaration::CBuffer {
+                attributes,
+                name,
+                register,
+                fields,
+            } => {
+                push(
+                    format!(
+                        "{} uniform {} {{\n{}}} {};",
+                        format_layout(&attributes, &register, &name)?,
+                        name,
+                        format_fields(&fields),
+                        name
+                    ),
+                    annotations,
+                );
+            }
+            HlslDeclaration::Resource {
+                attributes,
+                type_name,
+                template_arg,
+                name,
+                array_sizes,
+                register,
+            } => {
+                let is_push_constant = find_attribute(&attributes, "vk::push_constant").is_some();
+                if let Some((_, glsl_type)) = HLSL_RESOURCE_TYPES.iter().find(|x| x.0 == type_name)
+                {
+                    let layout = format_layout(&attributes, &register, &name)?;
+                    push(
+                        format!("{} uniform {} {}{};", layout, glsl_type, name, array_sizes),
+                        annotations,
+                    );
+                } else if HLSL_STORAGE_BUFFER_TYPES.contains(&type_name.as_str()) {
+                    let layout = format_layout(&attributes, &register, &name)?;
+                    let element_type = template_arg
+                        .map(|x| translate_hlsl_type(&x))
+                        .unwrap_or_else(|| "uint".to_string());
+                    let mut block_name: Vec<char> = name.chars().collect();
+                    block_name[0] = block_name[0].to_ascii_uppercase();
+                    push(
+                        format!(
+                            "{} buffer {}Buffer {{\n    {} {}[];\n}} {}{};",
+                            layout,
+                            characters_to_string(&block_name),
+                            element_type,
+                            name,
+                            name,
+                            array_sizes
+                        ),
+                        annotations,
+                    );
+                } else if type_name == "ConstantBuffer" || is_push_constant {
+                    let layout = format_layout(&attributes, &register, &name)?;
+                    let struct_type = if type_name == "ConstantBuffer" {
+                        template_arg.ok_or_else(|| {
+                            format!("ConstantBuffer {} must have a template argument", name)
+                        })?
+                    } else {
+                        type_name
+                    };
+
+                    push(
+                        format!(
+                            "{} uniform {} {}{};",
+                            layout, struct_type, name, array_sizes
+                        ),
+                        annotations,
+                    );
+                }
+            }
+            HlslDeclaration::SpecializationConstant {
+                constant_id,
+                type_name,
+                name,
+                value,
+            } => {
+                push(
+                    format!(
+                        "layout(constant_id = {}) const {} {} = {};",
+                        constant_id,
+                        translate_hlsl_type(&type_name),
+                        name,
+                        value
+                    ),
+                    annotations,
+                );
+            }
+            HlslDeclaration::EntryPoint { parameters, .. } => {
+                if entry_point_parameters.is_some() {
+                    Err(format!(
+                        "Found multiple definitions of {}",
+                        entry_point_name
+                    ))?;
+                }
+
+                entry_point_parameters = Some(parameters);
+            }
+            HlslDeclaration::Ignored { .. } => {}
+        }
+    }
+
+    if let Some(parameters) = entry_point_parameters {
+        let mut next_location = 0;
+        push_entry_point_inputs(&structs, &parameters, &mut next_location, &mut translated)?;
+    }
+
+    Ok(translated)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::parse_declarations::{parse_declarations, BindingType};
+    use crate::parse_source::PreprocessorState;
+
+    fn parse_hlsl_for_test(
+        shader_code: &str
+    ) -> Result<crate::parse_declarations::ParseDeclarationsResult, String> {
+        let mut preprocessor_state = PreprocessorState::default();
+        let parsed_source = crate::parse_source::parse_hlsl_src(
+            std::path::Path::new(""),
+            shader_code,
+            "main",
+            &mut preprocessor_state,
+        )?;
+        parse_declarations(&parsed_source.declarations)
+    }
+
+    #[test]
+    fn test_translate_hlsl_types() {
+        assert_eq!(translate_hlsl_type("float"), "float");
+        assert_eq!(translate_hlsl_type("float1"), "float");
+        assert_eq!(translate_hlsl_type("float3"), "vec3");
+        assert_eq!(translate_hlsl_type("uint4"), "uvec4");
+        assert_eq!(translate_hlsl_type("float4x4"), "mat4");
+        assert_eq!(translate_hlsl_type("float3x4"), "mat3x4");
+        assert_eq!(translate_hlsl_type("int2x2"), "int2x2");
+        assert_eq!(translate_hlsl_type("floaty"), "floaty");
+        assert_eq!(translate_hlsl_type("MyStruct"), "MyStruct");
+    }
+
+    #[test]
+    fn test_parse_hlsl_bindings() {
+        let parsed = parse_hlsl_for_test(
+            r#"
+            struct Light {
+                float3 position;
+                float range;
+            };
+
+            // @[export]
+            cbuffer PerViewData : register(b0, space1) {
+                row_major float4x4 view_proj;
+                uint light_count;
+            }
+
+            // @[export]
+            [[vk::push_constant]] ConstantBuffer<Light> push_constants;
+
+            Texture2D textures[4] : register(t1, space1);
+            [[vk::binding(2, 0)]] SamplerState smp;
+            StructuredBuffer<Light> lights : register(t3);
+
+            float4 helper(float4 x) {
+                return x;
+            }
+
+            float4 main(float2 uv : TEXCOORD0) : SV_Target {
+                return textures[0].Sample(smp, uv) * helper(lights[0].position.xyzz);
+            }
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(parsed.structs.len(), 1);
+        assert_eq!(parsed.structs[0].parsed.type_name, "Light");
+        assert_eq!(parsed.structs[0].parsed.fields[0].type_name, "vec3");
+
+        let find = |name: &str| {
+            parsed
+                .bindings
+                .iter()
+                .find(|x| x.parsed.instance_name == name)
+                .unwrap()
+        };
+
+        let per_view_data = find("PerViewData");
+        assert_eq!(per_view_data.parsed.type_name, "PerViewData");
+        assert_eq!(per_view_data.parsed.binding_type, BindingType::Uniform);
+        assert_eq!(per_view_data.parsed.layout_parts.set, Some(1));
+        assert_eq!(per_view_data.parsed.layout_parts.binding, Some(0));
+        assert!(per_view_data.annotations.export.is_some());
+        let fields = per_view_data.parsed.fields.as_ref().unwrap();
+        assert_eq!(fields[0].type_name, "mat4");
+        assert_eq!(fields[1].type_name, "uint");
+
+        let push_constants = find("push_constants");
+        assert_eq!(push_constants.parsed.type_name, "Light");
+        assert!(push_constants.parsed.layout_parts.push_constant);
+        assert!(push_constants.annotations.export.is_some());
+
+        let textures = find("textures");
+        assert_eq!(textures.parsed.type_name, "texture2D");
+        assert_eq!(textures.parsed.array_sizes, vec![4]);
+        assert_eq!(textures.parsed.layout_parts.binding, Some(1));
+
+        let smp = find("smp");
+        assert_eq!(smp.parsed.type_name, "sampler");
+        assert_eq!(smp.parsed.layout_parts.set, Some(0));
+        assert_eq!(smp.parsed.layout_parts.binding, Some(2));
+
+        let lights = find("lights");
+        assert_eq!(lights.parsed.binding_type, BindingType::Buffer);
+        assert_eq!(lights.parsed.layout_parts.set, Some(0));
+        assert_eq!(lights.parsed.layout_parts.binding, Some(3));
+        assert_eq!(lights.parsed.fields.as_ref().unwrap()[0].type_name, "Light");
+
+        let uv = find("uv");
+        assert_eq!(uv.parsed.binding_type, BindingType::In);
+        assert_eq!(uv.parsed.layout_parts.location, Some(0));
+        assert_eq!(uv.annotations.semantic.as_ref().unwrap().0, "TEXCOORD0");
+    }
+
+    #[test]
+    fn test_parse_hlsl_vertex_inputs() {
+        let parsed = parse_hlsl_for_test(
+            r#"
+            struct VSInput {
+                float3 position : POSITION;
+                float4x4 model : MODEL;
+                [[vk::location(7)]] float2 uv : TEXCOORD;
+                uint vertex_id : SV_VertexID;
+            };
+
+            struct VSOutput {
+                float4 position : SV_Position;
+            };
+
+            VSOutput main(VSInput input, float4 color : COLOR, out float4 extra : EXTRA) {
+                VSOutput output;
+                output.position = float4(input.position, 1.0);
+                return output;
+            }
+            "#,
+        )
+        .unwrap();
+
+        let inputs: Vec<_> = parsed
+            .bindings
+            .iter()
+            .filter(|x| x.parsed.binding_type == BindingType::In)
+            .map(|x| {
+                (
+                    x.parsed.instance_name.as_str(),
+                    x.parsed.type_name.as_str(),
+                    x.parsed.layout_parts.location.unwrap(),
+                    x.annotations.semantic.as_ref().unwrap().0.as_str(),
+                )
+            })
+            .collect();
+
+        assert_eq!(
+            inputs,
+            vec![
+                ("position", "vec3", 0, "POSITION"),
+                ("model", "mat4", 1, "MODEL"),
+                ("uv", "vec2", 7, "TEXCOORD"),
+                ("color", "vec4", 8, "COLOR"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_hlsl_compute() {
+        let parsed = parse_hlsl_for_test(
+            r#"
+            #define UNUSED 1
+            [[vk::constant_id(0)]] const uint SAMPLE_COUNT = 4;
+            [[vk::constant_id(1)]] const float EXPOSURE = 1.5f;
+            static const float PI = 3.14159;
+
+            RWTexture2D<float4> output_image : register(u0, space0);
+
+            [numthreads(8, 4, 1)]
+            void main(uint3 thread_id : SV_DispatchThreadID) {
+                output_image[thread_id.xy] = float4(PI, 0, 0, 1);
+            }
+            "#,
+        )
+        .unwrap();
+
+        let group_size = parsed.group_size.as_ref().unwrap();
+        assert_eq!((group_size.x, group_size.y, group_size.z), (8, 4, 1));
+
+        assert_eq!(parsed.specialization_constants.len(), 2);
+        assert_eq!(parsed.specialization_constants[0].name, "SAMPLE_COUNT");
+        assert_eq!(
+            parsed.specialization_constants[1].default_value,
+            rafx_api::RafxSpecializationConstantValue::Float(1.5)
+        );
+
+        assert_eq!(parsed.bindings.len(), 1);
+        assert_eq!(parsed.bindings[0].parsed.type_name, "image2D");
+    }
+
+    #[test]
+    fn test_parse_hlsl_missing_register() {
+        assert!(parse_hlsl_for_test(
+            r#"
+            Texture2D tex;
+            float4 main() : SV_Target { return float4(1, 1, 1, 1); }
+            "#,
+        )
+        .is_err());
+    }
+}
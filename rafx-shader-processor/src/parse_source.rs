@@ -997,10 +997,52 @@ impl PreprocessorState {
     }
 }
 
+// Determines which declarations are extracted from the source
+#[derive(Copy, Clone, Debug)]
+pub(crate) enum DeclarationSyntax<'a> {
+    Glsl,
+    Hlsl { entry_point_name: &'a str },
+}
+
 pub fn parse_glsl_src(
     file_path: &Path,
     content: &str,
     preprocessor_state: &mut PreprocessorState,
+) -> Result<ShaderText, String> {
+    parse_src(
+        file_path,
+        content,
+        DeclarationSyntax::Glsl,
+        preprocessor_state,
+    )
+}
+
+// HLSL declarations are translated to the equivalent GLSL declarations, so the result can be used
+// the same way as the result of parse_glsl_src()
+pub fn parse_hlsl_src(
+    file_path: &Path,
+    content: &str,
+    entry_point_name: &str,
+    preprocessor_state: &mut PreprocessorState,
+) -> Result<ShaderText, String> {
+    let shader_text = parse_src(
+        file_path,
+        content,
+        DeclarationSyntax::Hlsl { entry_point_name },
+        preprocessor_state,
+    )?;
+
+    let declarations =
+        crate::parse_hlsl::translate_hlsl_declarations(shader_text.declarations, entry_point_name)?;
+
+    Ok(ShaderText { declarations })
+}
+
+fn parse_src(
+    file_path: &Path,
+    content: &str,
+    syntax: DeclarationSyntax,
+    preprocessor_state: &mut PreprocessorState,
 ) -> Result<ShaderText, String> {
     let first_file = FileToProcess {
         path: file_path.to_path_buf(),
@@ -1018,6 +1060,7 @@ pub fn parse_glsl_src(
         &first_file,
         &mut declarations,
         &mut included_files,
+        syntax,
         preprocessor_state,
         &code,
     )?;
@@ -1029,6 +1072,7 @@ fn parse_shader_source_recursive(
     file_to_process: &FileToProcess,
     declarations: &mut Vec<DeclarationText>,
     included_files: &mut FnvHashSet<PathBuf>,
+    syntax: DeclarationSyntax,
     preprocessor_state: &mut PreprocessorState,
 ) -> Result<(), String> {
     log::trace!("parse_shader_source_recursive {:?}", file_to_process);
@@ -1052,6 +1096,7 @@ fn parse_shader_source_recursive(
         &resolved_file_paths,
         declarations,
         included_files,
+        syntax,
         preprocessor_state,
         &code,
     )
@@ -1061,6 +1106,7 @@ pub(crate) fn parse_shader_source_text(
     file_to_process: &FileToProcess,
     declarations: &mut Vec<DeclarationText>,
     included_files: &mut FnvHashSet<PathBuf>,
+    syntax: DeclarationSyntax,
     preprocessor_state: &mut PreprocessorState,
     code: &Vec<char>,
 ) -> Result<(), String> {
@@ -1099,6 +1145,7 @@ pub(crate) fn parse_shader_source_text(
                             &included_file,
                             declarations,
                             included_files,
+                            syntax,
                             preprocessor_state,
                         )?;
                     }
@@ -1166,7 +1213,12 @@ pub(crate) fn parse_shader_source_text(
             }
 
             position = consume_directive_result.end_position;
-        } else if let Some(new_position) = try_consume_declaration(&code, position) {
+        } else if let Some(new_position) = match syntax {
+            DeclarationSyntax::Glsl => try_consume_declaration(&code, position),
+            DeclarationSyntax::Hlsl { entry_point_name } => {
+                crate::parse_hlsl::try_consume_hlsl_declaration(&code, position, entry_point_name)
+            }
+        } {
             // Drain comments that we've passed and haven't taken
             let relevant_comments = pop_comments_up_to_position(&mut comments, new_position);
             let annotations = find_annotations_in_comments(&relevant_comments);
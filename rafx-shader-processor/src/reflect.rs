@@ -5,9 +5,9 @@ use fnv::FnvHashMap;
 use rafx_api::{
     RafxAddressMode, RafxCompareOp, RafxFilterType, RafxGlUniformMember, RafxMipMapMode,
     RafxReflectedDescriptorSetLayout, RafxReflectedDescriptorSetLayoutBinding,
-    RafxReflectedEntryPoint, RafxReflectedVertexInput, RafxResourceType, RafxResult,
-    RafxSamplerDef, RafxShaderResource, RafxShaderStageFlags, RafxShaderStageReflection,
-    MAX_DESCRIPTOR_SET_LAYOUTS,
+    RafxReflectedEntryPoint, RafxReflectedSpecializationConstant, RafxReflectedVertexInput,
+    RafxResourceType, RafxResult, RafxSamplerDef, RafxShaderResource, RafxShaderStageFlags,
+    RafxShaderStageReflection, MAX_DESCRIPTOR_SET_LAYOUTS,
 };
 use spirv_cross::msl::{ResourceBinding, ResourceBindingLocation, SamplerData, SamplerLocation};
 use spirv_cross::spirv::{ExecutionModel, Type};
@@ -977,10 +977,23 @@ where
             ]),
         };
 
+        // Specialization constants are declared globally, so every entry point in the file can
+        // use them
+        let specialization_constants = declarations
+            .specialization_constants
+            .iter()
+            .map(|x| RafxReflectedSpecializationConstant {
+                name: x.name.clone(),
+                constant_id: x.constant_id,
+                default_value: x.default_value,
+            })
+            .collect();
+
         reflected_entry_points.push(RafxReflectedEntryPoint {
             descriptor_set_layouts,
             vertex_inputs: dsc_vertex_inputs,
             rafx_api_reflection: rafx_reflection,
+            specialization_constants,
         });
     }
 
@@ -165,6 +165,7 @@ pub(crate) fn create_builtin_type_lookup() -> FnvHashMap<String, TypeAlignmentIn
     add_type_alignment_info::<[f32; 4]>(&mut builtin_types, "vec4", "[f32; 4]");
     add_type_alignment_info::<[[f32; 4]; 4]>(&mut builtin_types, "mat4", "[[f32; 4]; 4]");
     add_type_alignment_info::<[u32; 4]>(&mut builtin_types, "uvec4", "[u32; 4]");
+    add_type_alignment_info::<[u32; 4]>(&mut builtin_types, "ivec4", "[i32; 4]");
     builtin_types
 }
 
@@ -724,6 +725,158 @@ fn determine_alignment_c(
     }
 }
 
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct MemberLayout {
+    pub(crate) name: String,
+    pub(crate) offset: usize,
+    // None for variable-sized arrays
+    pub(crate) size: Option<usize>,
+    pub(crate) align: usize,
+}
+
+// Determine the offset of every field of a struct when placed in the given GPU memory layout
+pub(crate) fn determine_member_layouts(
+    builtin_types: &FnvHashMap<String, TypeAlignmentInfo>,
+    user_types: &FnvHashMap<String, UserType>,
+    fields: &[ParseFieldResult],
+    layout: MemoryLayout,
+) -> Result<Vec<MemberLayout>, String> {
+    let mut members = Vec::with_capacity(fields.len());
+    let mut offset = 0;
+    for f in fields {
+        let align = determine_alignment(
+            builtin_types,
+            user_types,
+            &f.type_name,
+            &f.array_sizes,
+            layout,
+        )?;
+        offset = align_offset(offset, align);
+
+        let end_offset = determine_size(
+            builtin_types,
+            user_types,
+            &f.type_name,
+            &f.array_sizes,
+            offset,
+            offset,
+            &f.field_name,
+            layout,
+        )?;
+
+        members.push(MemberLayout {
+            name: f.field_name.clone(),
+            offset,
+            size: end_offset.map(|x| x - offset),
+            align,
+        });
+
+        if let Some(end_offset) = end_offset {
+            offset = end_offset;
+        }
+    }
+
+    Ok(members)
+}
+
+// Returns the number of bytes of padding the struct requires in the given layout, or None if it
+// is variable-sized
+pub(crate) fn determine_padding(
+    builtin_types: &FnvHashMap<String, TypeAlignmentInfo>,
+    user_types: &FnvHashMap<String, UserType>,
+    type_name: &str,
+    fields: &[ParseFieldResult],
+    layout: MemoryLayout,
+) -> Result<Option<usize>, String> {
+    let members = determine_member_layouts(builtin_types, user_types, fields, layout)?;
+
+    let mut used_size = 0;
+    let mut end_offset = 0;
+    for member in &members {
+        if let Some(size) = member.size {
+            used_size += size;
+            end_offset = member.offset + size;
+        } else {
+            return Ok(None);
+        }
+    }
+
+    let alignment = determine_alignment(builtin_types, user_types, type_name, &[], layout)?;
+    Ok(Some(align_offset(end_offset, alignment) - used_size))
+}
+
+// HLSL constant buffers (which are used for uniforms and push constants by the DX12 backend) pack
+// members into 16-byte registers. A member may not straddle a register, and arrays and structs
+// always start a new register but do not pad out the end of their last register. See
+// https://docs.microsoft.com/en-us/windows/win32/direct3dhlsl/dx-graphics-hlsl-packing-rules
+pub(crate) fn determine_member_layouts_hlsl_cbuffer(
+    builtin_types: &FnvHashMap<String, TypeAlignmentInfo>,
+    user_types: &FnvHashMap<String, UserType>,
+    fields: &[ParseFieldResult],
+) -> Result<Vec<MemberLayout>, String> {
+    let mut members = Vec::with_capacity(fields.len());
+    let mut offset = 0;
+    for f in fields {
+        let size =
+            determine_size_hlsl_cbuffer(builtin_types, user_types, &f.type_name, &f.array_sizes)?;
+
+        let starts_new_register =
+            !f.array_sizes.is_empty() || user_types.contains_key(&f.type_name);
+        let align = if starts_new_register || (offset % 16) + size > 16 {
+            16
+        } else {
+            4
+        };
+        offset = align_offset(offset, align);
+
+        members.push(MemberLayout {
+            name: f.field_name.clone(),
+            offset,
+            size: Some(size),
+            align,
+        });
+
+        offset += size;
+    }
+
+    Ok(members)
+}
+
+fn determine_size_hlsl_cbuffer(
+    builtin_types: &FnvHashMap<String, TypeAlignmentInfo>,
+    user_types: &FnvHashMap<String, UserType>,
+    query_type: &str,
+    array_sizes: &[usize],
+) -> Result<usize, String> {
+    let element_size = if let Some(builtin_type) = builtin_types.get(query_type) {
+        builtin_type.size
+    } else if let Some(user_type) = user_types.get(query_type) {
+        let members =
+            determine_member_layouts_hlsl_cbuffer(builtin_types, user_types, &user_type.fields)?;
+        members
+            .last()
+            .map(|x| x.offset + x.size.unwrap())
+            .unwrap_or(0)
+    } else {
+        return Err(format!("Could not find type {}. Is this a built in type that needs to be added to create_builtin_type_lookup()?", query_type));
+    };
+
+    if array_sizes.is_empty() {
+        return Ok(element_size);
+    }
+
+    let element_count = element_count(array_sizes);
+    if element_count == 0 {
+        return Err(format!(
+            "Variable sized array of {} can't be placed in an HLSL constant buffer",
+            query_type
+        ));
+    }
+
+    // Every element starts a new register, but the last element is not padded
+    Ok(align_offset(element_size, 16) * (element_count - 1) + element_size)
+}
+
 #[cfg(test)]
 pub(crate) fn verify_all_binding_layouts(
     builtin_types: &FnvHashMap<String, TypeAlignmentInfo>,
@@ -919,6 +1072,7 @@ mod test {
                 vec3 direction_vs;
                 vec4 color;
                 float intensity;
+                ivec4 cascade_shadow_maps;
             };
 
             struct SpotLight {
@@ -1173,6 +1327,7 @@ mod test {
             &file_to_process,
             &mut declarations,
             &mut included_files,
+            crate::parse_source::DeclarationSyntax::Glsl,
             &mut preprocessor_state,
             &code,
         )
@@ -0,0 +1,699 @@
+// Hand-written WGSL overrides replace the translated GLSL on WebGPU, but the pipeline layout and
+// generated rust code still come from the GLSL's reflection. Nothing else ties the two together,
+// so an override that isn't updated alongside its GLSL would bind the wrong resources at runtime.
+// This compares the override's naga module against the GLSL's spirv_cross reflection and fails
+// packaging when they disagree.
+
+use spirv_cross::glsl::Target;
+use spirv_cross::spirv::{Ast, Decoration, ExecutionModel, Resource, Type};
+use std::collections::BTreeMap;
+use std::error::Error;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum BindingKind {
+    UniformBuffer,
+    StorageBuffer,
+    StorageImage,
+    Texture,
+    CombinedImageSampler,
+    Sampler,
+}
+
+#[derive(Debug)]
+struct BindingInfo {
+    name: String,
+    kind: BindingKind,
+    // None if the GLSL array length is a specialization constant
+    count: Option<u32>,
+    // Flattened member layout, only set for buffers
+    layout: Option<Vec<MemberLayout>>,
+}
+
+// One member of a buffer, with nested structs flattened depth first
+#[derive(Debug, PartialEq, Eq)]
+struct MemberLayout {
+    offset: u32,
+    size: u32,
+    // (length, stride) for arrays, a length of 0 is a runtime-sized array
+    array: Option<(u32, u32)>,
+}
+
+/// Parses and validates a WGSL override, then checks that its bindings, buffer layouts, push
+/// constants and entry point match the reflection of the GLSL it replaces.
+pub(crate) fn parse_and_check(
+    src: &str,
+    ast: &Ast<Target>,
+) -> Result<(), Box<dyn Error>> {
+    // Catch mistakes in hand-written WGSL here rather than when the pipeline is created
+    let module = naga::front::wgsl::parse_str(src)
+        .map_err(|e| format!("Failed to parse WGSL override: {}", e.emit_to_string(src)))?;
+    naga::valid::Validator::new(
+        naga::valid::ValidationFlags::all(),
+        naga::valid::Capabilities::all(),
+    )
+    .validate(&module)
+    .map_err(|e| format!("Failed to validate WGSL override: {:?}", e))?;
+
+    check(&module, ast)
+        .map_err(|e| format!("WGSL override does not match the GLSL reflection: {}", e).into())
+}
+
+fn check(
+    module: &naga::Module,
+    ast: &Ast<Target>,
+) -> Result<(), Box<dyn Error>> {
+    check_entry_point(module, ast)?;
+
+    let glsl_bindings = glsl_bindings(ast)?;
+    let wgsl_bindings = wgsl_bindings(module)?;
+
+    for (&(set, binding), glsl) in &glsl_bindings {
+        let wgsl = wgsl_bindings.get(&(set, binding)).ok_or_else(|| {
+            format!(
+                "GLSL resource {} (set = {}, binding = {}) is missing from the WGSL",
+                glsl.name, set, binding
+            )
+        })?;
+        check_binding(set, binding, glsl, wgsl)?;
+    }
+
+    for (&(set, binding), wgsl) in &wgsl_bindings {
+        if !glsl_bindings.contains_key(&(set, binding)) {
+            return Err(format!(
+                "WGSL resource {} (@group({}) @binding({})) has no GLSL resource at set = {}, binding = {}",
+                wgsl.name, set, binding, set, binding
+            )
+            .into());
+        }
+    }
+
+    check_push_constants(module, ast)
+}
+
+fn check_entry_point(
+    module: &naga::Module,
+    ast: &Ast<Target>,
+) -> Result<(), Box<dyn Error>> {
+    let glsl_entry_point = ast
+        .get_entry_points()?
+        .into_iter()
+        .next()
+        .ok_or("GLSL has no entry point")?;
+
+    let stage = match glsl_entry_point.execution_model {
+        ExecutionModel::Vertex => naga::ShaderStage::Vertex,
+        ExecutionModel::Fragment => naga::ShaderStage::Fragment,
+        ExecutionModel::GlCompute => naga::ShaderStage::Compute,
+        execution_model => {
+            return Err(format!("WebGPU does not support {:?} shaders", execution_model).into())
+        }
+    };
+
+    let wgsl_entry_point = module
+        .entry_points
+        .iter()
+        .find(|x| x.name == glsl_entry_point.name)
+        .ok_or_else(|| format!("WGSL has no entry point named {}", glsl_entry_point.name))?;
+
+    if wgsl_entry_point.stage != stage {
+        return Err(format!(
+            "WGSL entry point {} is a {:?} shader but the GLSL is a {:?} shader",
+            wgsl_entry_point.name, wgsl_entry_point.stage, stage
+        )
+        .into());
+    }
+
+    if stage == naga::ShaderStage::Compute {
+        let size = glsl_entry_point.work_group_size;
+        let glsl_workgroup_size = [size.x, size.y, size.z];
+        if wgsl_entry_point.workgroup_size != glsl_workgroup_size {
+            return Err(format!(
+                "WGSL workgroup size {:?} does not match the GLSL local size {:?}",
+                wgsl_entry_point.workgroup_size, glsl_workgroup_size
+            )
+            .into());
+        }
+    }
+
+    Ok(())
+}
+
+fn check_binding(
+    set: u32,
+    binding: u32,
+    glsl: &BindingInfo,
+    wgsl: &BindingInfo,
+) -> Result<(), Box<dyn Error>> {
+    if glsl.kind != wgsl.kind {
+        return Err(format!(
+            "GLSL resource {} (set = {}, binding = {}) is a {:?} but WGSL resource {} is a {:?}",
+            glsl.name, set, binding, glsl.kind, wgsl.name, wgsl.kind
+        )
+        .into());
+    }
+
+    if let Some(glsl_count) = glsl.count {
+        if Some(glsl_count) != wgsl.count {
+            return Err(format!(
+                "GLSL resource {} (set = {}, binding = {}) has {} elements but WGSL resource {} has {:?}",
+                glsl.name, set, binding, glsl_count, wgsl.name, wgsl.count
+            )
+            .into());
+        }
+    }
+
+    if let (Some(glsl_layout), Some(wgsl_layout)) = (&glsl.layout, &wgsl.layout) {
+        check_layout(&glsl.name, glsl_layout, &wgsl.name, wgsl_layout)?;
+    }
+
+    Ok(())
+}
+
+fn check_layout(
+    glsl_name: &str,
+    glsl_layout: &[MemberLayout],
+    wgsl_name: &str,
+    wgsl_layout: &[MemberLayout],
+) -> Result<(), Box<dyn Error>> {
+    for (index, (glsl_member, wgsl_member)) in glsl_layout.iter().zip(wgsl_layout).enumerate() {
+        if glsl_member != wgsl_member {
+            return Err(format!(
+                "member {} of {} is {:?} in the GLSL but member {} of {} is {:?} in the WGSL",
+                index, glsl_name, glsl_member, index, wgsl_name, wgsl_member
+            )
+            .into());
+        }
+    }
+
+    if glsl_layout.len() != wgsl_layout.len() {
+        return Err(format!(
+            "{} has {} members in the GLSL but {} has {} members in the WGSL",
+            glsl_name,
+            glsl_layout.len(),
+            wgsl_name,
+            wgsl_layout.len()
+        )
+        .into());
+    }
+
+    Ok(())
+}
+
+fn check_push_constants(
+    module: &naga::Module,
+    ast: &Ast<Target>,
+) -> Result<(), Box<dyn Error>> {
+    let glsl_push_constants = ast.get_shader_resources()?.push_constant_buffers;
+    let wgsl_push_constants: Vec<_> = module
+        .global_variables
+        .iter()
+        .filter(|(_, x)| x.space == naga::AddressSpace::PushConstant)
+        .map(|(_, x)| x)
+        .collect();
+
+    match (glsl_push_constants.first(), wgsl_push_constants.first()) {
+        (None, None) => Ok(()),
+        (Some(glsl), Some(wgsl)) => {
+            let glsl_layout = glsl_struct_layout(ast, glsl.base_type_id, 0)?;
+            let wgsl_layout = wgsl_buffer_layout(module, wgsl.ty);
+            check_layout(
+                &glsl.name,
+                &glsl_layout,
+                wgsl.name.as_deref().unwrap_or("<unnamed>"),
+                &wgsl_layout,
+            )
+        }
+        (Some(glsl), None) => Err(format!(
+            "GLSL has push constants {} but the WGSL has none",
+            glsl.name
+        )
+        .into()),
+        (None, Some(_)) => Err("WGSL has push constants but the GLSL has none".into()),
+    }
+}
+
+//
+// GLSL reflection
+//
+fn glsl_bindings(ast: &Ast<Target>) -> Result<BTreeMap<(u32, u32), BindingInfo>, Box<dyn Error>> {
+    let resources = ast.get_shader_resources()?;
+
+    let mut bindings = BTreeMap::default();
+    let mut add_resources =
+        |resources: &[Resource], kind: BindingKind| -> Result<(), Box<dyn Error>> {
+            for resource in resources {
+                let set = ast.get_decoration(resource.id, Decoration::DescriptorSet)?;
+                let binding = ast.get_decoration(resource.id, Decoration::Binding)?;
+                let layout = match kind {
+                    BindingKind::UniformBuffer | BindingKind::StorageBuffer => {
+                        Some(glsl_struct_layout(ast, resource.base_type_id, 0)?)
+                    }
+                    _ => None,
+                };
+
+                bindings.insert(
+                    (set, binding),
+                    BindingInfo {
+                        name: resource.name.clone(),
+                        kind,
+                        count: glsl_binding_count(&ast.get_type(resource.type_id)?),
+                        layout,
+                    },
+                );
+            }
+
+            Ok(())
+        };
+
+    add_resources(&resources.uniform_buffers, BindingKind::UniformBuffer)?;
+    add_resources(&resources.storage_buffers, BindingKind::StorageBuffer)?;
+    add_resources(&resources.storage_images, BindingKind::StorageImage)?;
+    add_resources(&resources.separate_images, BindingKind::Texture)?;
+    add_resources(&resources.sampled_images, BindingKind::CombinedImageSampler)?;
+    add_resources(&resources.separate_samplers, BindingKind::Sampler)?;
+
+    Ok(bindings)
+}
+
+fn glsl_array(ty: &Type) -> (&[u32], &[bool]) {
+    match ty {
+        Type::Boolean {
+            array,
+            array_size_literal,
+            ..
+        }
+        | Type::Int {
+            array,
+            array_size_literal,
+            ..
+        }
+        | Type::UInt {
+            array,
+            array_size_literal,
+            ..
+        }
+        | Type::Half {
+            array,
+            array_size_literal,
+            ..
+        }
+        | Type::Float {
+            array,
+            array_size_literal,
+            ..
+        }
+        | Type::Double {
+            array,
+            array_size_literal,
+            ..
+        }
+        | Type::Struct {
+            array,
+            array_size_literal,
+            ..
+        }
+        | Type::Image {
+            array,
+            array_size_literal,
+            ..
+        }
+        | Type::SampledImage {
+            array,
+            array_size_literal,
+            ..
+        }
+        | Type::Sampler {
+            array,
+            array_size_literal,
+            ..
+        } => (array, array_size_literal),
+        _ => (&[], &[]),
+    }
+}
+
+fn glsl_binding_count(ty: &Type) -> Option<u32> {
+    let (array, array_size_literal) = glsl_array(ty);
+    let mut count = 1;
+    for (&length, &is_literal) in array.iter().zip(array_size_literal) {
+        if !is_literal {
+            return None;
+        }
+        count *= length;
+    }
+
+    Some(count)
+}
+
+fn glsl_struct_layout(
+    ast: &Ast<Target>,
+    struct_type_id: u32,
+    base_offset: u32,
+) -> Result<Vec<MemberLayout>, Box<dyn Error>> {
+    let member_types = match ast.get_type(struct_type_id)? {
+        Type::Struct { member_types, .. } => member_types,
+        _ => return Err(format!("GLSL type {} is not a struct", struct_type_id).into()),
+    };
+
+    let mut layout = Vec::default();
+    for (index, &member_type_id) in member_types.iter().enumerate() {
+        let index = index as u32;
+        let offset =
+            base_offset + ast.get_member_decoration(struct_type_id, index, Decoration::Offset)?;
+        let member_type = ast.get_type(member_type_id)?;
+        // Runtime-sized arrays are reported with a length of 0, which gives them a size of 0
+        let size = ast.get_declared_struct_member_size(struct_type_id, index)?;
+
+        // spirv_cross lists the outermost dimension last
+        let (array, array_size_literal) = glsl_array(&member_type);
+        let array = match (array.last(), array_size_literal.last()) {
+            (Some(&length), Some(true)) => Some((
+                length,
+                ast.get_decoration(member_type_id, Decoration::ArrayStride)?,
+            )),
+            (Some(_), _) => {
+                return Err(format!(
+                    "member {} of GLSL struct {} has a specialization constant length, which WGSL overrides don't support",
+                    index, struct_type_id
+                )
+                .into());
+            }
+            (None, _) => None,
+        };
+
+        layout.push(MemberLayout {
+            offset,
+            size,
+            array,
+        });
+
+        // The member decorations of an array of structs are on the element type, which spirv_cross
+        // doesn't expose, so only plain struct members are flattened. The array stride still
+        // catches most changes to the element struct.
+        if let (Type::Struct { .. }, None) = (&member_type, array) {
+            layout.append(&mut glsl_struct_layout(ast, member_type_id, offset)?);
+        }
+    }
+
+    Ok(layout)
+}
+
+//
+// WGSL reflection
+//
+fn wgsl_bindings(
+    module: &naga::Module
+) -> Result<BTreeMap<(u32, u32), BindingInfo>, Box<dyn Error>> {
+    let mut bindings = BTreeMap::default();
+    for (_, global) in module.global_variables.iter() {
+        let resource_binding = match &global.binding {
+            Some(binding) => binding,
+            None => continue,
+        };
+
+        let (ty, count) = match &module.types[global.ty].inner {
+            naga::TypeInner::BindingArray { base, size } => {
+                (*base, wgsl_array_length(module, *size))
+            }
+            _ => (global.ty, Some(1)),
+        };
+
+        let kind = match global.space {
+            naga::AddressSpace::Uniform => BindingKind::UniformBuffer,
+            naga::AddressSpace::Storage { .. } => BindingKind::StorageBuffer,
+            naga::AddressSpace::Handle => match &module.types[ty].inner {
+                naga::TypeInner::Image {
+                    class: naga::ImageClass::Storage { .. },
+                    ..
+                } => BindingKind::StorageImage,
+                naga::TypeInner::Image { .. } => BindingKind::Texture,
+                naga::TypeInner::Sampler { .. } => BindingKind::Sampler,
+                inner => {
+                    return Err(format!(
+                        "WGSL resource {:?} has unexpected type {:?}",
+                        global.name, inner
+                    )
+                    .into())
+                }
+            },
+            space => {
+                return Err(format!(
+                    "WGSL resource {:?} has unexpected address space {:?}",
+                    global.name, space
+                )
+                .into())
+            }
+        };
+
+        let layout = match kind {
+            BindingKind::UniformBuffer | BindingKind::StorageBuffer => {
+                Some(wgsl_buffer_layout(module, ty))
+            }
+            _ => None,
+        };
+
+        bindings.insert(
+            (resource_binding.group, resource_binding.binding),
+            BindingInfo {
+                name: global
+                    .name
+                    .clone()
+                    .unwrap_or_else(|| "<unnamed>".to_string()),
+                kind,
+                count,
+                layout,
+            },
+        );
+    }
+
+    Ok(bindings)
+}
+
+fn wgsl_array_length(
+    module: &naga::Module,
+    size: naga::ArraySize,
+) -> Option<u32> {
+    match size {
+        naga::ArraySize::Constant(constant) => match module.constants[constant].inner {
+            naga::ConstantInner::Scalar {
+                value: naga::ScalarValue::Uint(value),
+                ..
+            } => u32::try_from(value).ok(),
+            naga::ConstantInner::Scalar {
+                value: naga::ScalarValue::Sint(value),
+                ..
+            } => u32::try_from(value).ok(),
+            _ => None,
+        },
+        naga::ArraySize::Dynamic => Some(0),
+    }
+}
+
+fn wgsl_buffer_layout(
+    module: &naga::Module,
+    ty: naga::Handle<naga::Type>,
+) -> Vec<MemberLayout> {
+    let mut layout = Vec::default();
+    match &module.types[ty].inner {
+        naga::TypeInner::Struct { .. } => wgsl_struct_layout(module, ty, 0, &mut layout),
+        // A GLSL buffer is always a block, but WGSL can bind a bare array, which is equivalent to a
+        // block with a single member at offset 0
+        _ => layout.push(wgsl_member_layout(module, ty, 0)),
+    }
+
+    layout
+}
+
+fn wgsl_struct_layout(
+    module: &naga::Module,
+    ty: naga::Handle<naga::Type>,
+    base_offset: u32,
+    layout: &mut Vec<MemberLayout>,
+) {
+    if let naga::TypeInner::Struct { members, .. } = &module.types[ty].inner {
+        for member in members {
+            let offset = base_offset + member.offset;
+            layout.push(wgsl_member_layout(module, member.ty, offset));
+
+            // Mirrors glsl_struct_layout, which can't flatten arrays of structs
+            if let naga::TypeInner::Struct { .. } = module.types[member.ty].inner {
+                wgsl_struct_layout(module, member.ty, offset, layout);
+            }
+        }
+    }
+}
+
+fn wgsl_member_layout(
+    module: &naga::Module,
+    ty: naga::Handle<naga::Type>,
+    offset: u32,
+) -> MemberLayout {
+    let array = match &module.types[ty].inner {
+        naga::TypeInner::Array { size, stride, .. } => {
+            Some((wgsl_array_length(module, *size).unwrap_or(0), *stride))
+        }
+        _ => None,
+    };
+
+    MemberLayout {
+        offset,
+        size: wgsl_declared_size(module, ty),
+        array,
+    }
+}
+
+// Matches spirv_cross's get_declared_struct_member_size, which doesn't include the padding at the
+// end of a struct and treats runtime-sized arrays as empty
+fn wgsl_declared_size(
+    module: &naga::Module,
+    ty: naga::Handle<naga::Type>,
+) -> u32 {
+    match &module.types[ty].inner {
+        naga::TypeInner::Array { size, stride, .. } => {
+            wgsl_array_length(module, *size).unwrap_or(0) * stride
+        }
+        naga::TypeInner::Struct { members, .. } => members
+            .last()
+            .map(|x| x.offset + wgsl_declared_size(module, x.ty))
+            .unwrap_or(0),
+        inner => inner.size(&module.constants),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const GLSL: &str = r#"
+        #version 450
+
+        struct Item {
+            vec3 position;
+            float radius;
+        };
+
+        layout (set = 0, binding = 0) uniform Config {
+            mat4 transform;
+            uint item_count;
+        } config;
+
+        layout (set = 0, binding = 1) buffer Items {
+            uint count;
+            Item items[];
+        } items;
+
+        layout (set = 1, binding = 0) uniform texture2D source;
+        layout (set = 1, binding = 1) uniform sampler smp;
+
+        layout(local_size_x = 64, local_size_y = 1, local_size_z = 1) in;
+        void main() {
+            items.items[gl_GlobalInvocationID.x].radius =
+                texture(sampler2D(source, smp), vec2(0.0)).x * float(config.item_count);
+        }
+    "#;
+
+    const WGSL: &str = r#"
+        struct Item {
+            position: vec3<f32>,
+            radius: f32,
+        }
+
+        struct Config {
+            transform: mat4x4<f32>,
+            item_count: u32,
+        }
+
+        struct Items {
+            count: u32,
+            items: array<Item>,
+        }
+
+        @group(0) @binding(0)
+        var<uniform> config: Config;
+
+        @group(0) @binding(1)
+        var<storage, read_write> items: Items;
+
+        @group(1) @binding(0)
+        var source: texture_2d<f32>;
+
+        @group(1) @binding(1)
+        var smp: sampler;
+
+        @compute @workgroup_size(64, 1, 1)
+        fn main(@builtin(global_invocation_id) global_invocation_id: vec3<u32>) {
+            items.items[global_invocation_id.x].radius =
+                textureSampleLevel(source, smp, vec2<f32>(0.0), 0.0).x * f32(config.item_count);
+        }
+    "#;
+
+    fn check_for_test(
+        glsl: &str,
+        wgsl: &str,
+    ) -> Result<(), String> {
+        let spv = shaderc::Compiler::new()
+            .unwrap()
+            .compile_into_spirv(
+                glsl,
+                shaderc::ShaderKind::Compute,
+                "test.comp",
+                "main",
+                None,
+            )
+            .unwrap();
+        let module = spirv_cross::spirv::Module::from_words(spv.as_binary());
+        let ast = Ast::<Target>::parse(&module).unwrap();
+        parse_and_check(wgsl, &ast).map_err(|e| e.to_string())
+    }
+
+    #[test]
+    fn test_matching_override() {
+        check_for_test(GLSL, WGSL).unwrap();
+    }
+
+    #[test]
+    fn test_workgroup_size_mismatch() {
+        let wgsl = WGSL.replace("@workgroup_size(64, 1, 1)", "@workgroup_size(32, 1, 1)");
+        let error = check_for_test(GLSL, &wgsl).unwrap_err();
+        assert!(error.contains("workgroup size"), "{}", error);
+    }
+
+    #[test]
+    fn test_missing_binding() {
+        let glsl = GLSL.replace(
+            "layout(local_size_x",
+            "layout (set = 1, binding = 2) uniform texture2D extra;\nlayout(local_size_x",
+        );
+        let error = check_for_test(&glsl, WGSL).unwrap_err();
+        assert!(error.contains("extra"), "{}", error);
+    }
+
+    #[test]
+    fn test_moved_binding() {
+        let wgsl = WGSL.replace("@group(1) @binding(1)", "@group(1) @binding(2)");
+        let error = check_for_test(GLSL, &wgsl).unwrap_err();
+        assert!(error.contains("smp"), "{}", error);
+    }
+
+    #[test]
+    fn test_binding_kind_mismatch() {
+        let wgsl = WGSL.replace("var<uniform> config", "var<storage> config");
+        let error = check_for_test(GLSL, &wgsl).unwrap_err();
+        assert!(error.contains("StorageBuffer"), "{}", error);
+    }
+
+    #[test]
+    fn test_buffer_layout_mismatch() {
+        // Adding a member to the GLSL struct changes the array stride
+        let glsl = GLSL.replace("float radius;", "float radius;\n    uint flags;");
+        let error = check_for_test(&glsl, WGSL).unwrap_err();
+        assert!(error.contains("Items"), "{}", error);
+
+        // Reordering members keeps the size but changes the offsets
+        let wgsl = WGSL.replace(
+            "transform: mat4x4<f32>,\n            item_count: u32,",
+            "item_count: u32,\n            transform: mat4x4<f32>,",
+        );
+        let error = check_for_test(GLSL, &wgsl).unwrap_err();
+        assert!(error.contains("Config"), "{}", error);
+    }
+}
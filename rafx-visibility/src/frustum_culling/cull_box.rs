@@ -0,0 +1,73 @@
+use crate::geometry::{BoundingSphere, OrientedBoundingBox, Transform};
+use glam::{Vec3, Vec4};
+
+/// An `OrientedBoundingBox` transformed into the `Zone`. The `axes` are scaled by the half extents
+/// of the box, so a non-uniform scale on a rotated box is represented exactly as a parallelepiped.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct CullBox {
+    pub center: Vec3,
+    pub axes: [Vec3; 3],
+}
+
+impl Default for CullBox {
+    fn default() -> Self {
+        CullBox {
+            center: Vec3::ZERO,
+            axes: [Vec3::X, Vec3::Y, Vec3::Z],
+        }
+    }
+}
+
+impl CullBox {
+    /// Returns the box around the `sphere`. Used for objects without an `OrientedBoundingBox`.
+    pub fn from_bounding_sphere(sphere: &BoundingSphere) -> Self {
+        CullBox {
+            center: sphere.position,
+            axes: [
+                Vec3::X * sphere.radius,
+                Vec3::Y * sphere.radius,
+                Vec3::Z * sphere.radius,
+            ],
+        }
+    }
+
+    pub fn from_oriented_bounding_box(
+        obb: &OrientedBoundingBox,
+        transform: &Transform,
+    ) -> Self {
+        let matrix = transform.as_mat4();
+        let half_extents = obb.half_extents();
+        CullBox {
+            center: matrix.transform_point3(obb.center()),
+            axes: [
+                matrix.transform_vector3(obb.rotation * (Vec3::X * half_extents.x)),
+                matrix.transform_vector3(obb.rotation * (Vec3::Y * half_extents.y)),
+                matrix.transform_vector3(obb.rotation * (Vec3::Z * half_extents.z)),
+            ],
+        }
+    }
+
+    /// Returns `true` if the box is outside one of the `planes` selected by `mask`.
+    #[inline(always)]
+    pub fn is_outside(
+        &self,
+        planes: &[Vec4],
+        mask: u32,
+    ) -> bool {
+        for (index, plane) in planes.iter().enumerate() {
+            if mask & (1 << index) == 0 {
+                continue;
+            }
+
+            let normal = plane.truncate();
+            let radius = normal.dot(self.axes[0]).abs()
+                + normal.dot(self.axes[1]).abs()
+                + normal.dot(self.axes[2]).abs();
+            if plane.w + normal.dot(self.center) + radius <= 0. {
+                return true;
+            }
+        }
+
+        false
+    }
+}
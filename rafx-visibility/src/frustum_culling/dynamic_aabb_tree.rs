@@ -0,0 +1,769 @@
+use crate::frustum_culling::{CullBox, ObjectMetadata};
+use crate::geometry::{AxisAlignedBoundingBox, BoundingSphere, Frustum};
+use crate::{VisibilityObjectHandle, VisibilityResult, VisibleObjects};
+use glam::{Vec3, Vec4};
+
+const NULL_NODE: usize = usize::MAX;
+
+// Planes are tracked with a bitmask while descending the tree.
+pub(crate) const MAX_PLANES: usize = 32;
+
+#[derive(Copy, Clone)]
+struct TreeNode {
+    // For leaves, this is the "fat" AABB around the sphere.
+    aabb: AxisAlignedBoundingBox,
+    // For free nodes, this is the next node in the free list.
+    parent: usize,
+    child1: usize,
+    child2: usize,
+    // Leaves have a height of 0 and free nodes have a height of -1.
+    height: i32,
+    sphere: BoundingSphere,
+    cull_box: CullBox,
+    metadata: ObjectMetadata,
+}
+
+impl TreeNode {
+    fn empty() -> Self {
+        TreeNode {
+            aabb: AxisAlignedBoundingBox::default(),
+            parent: NULL_NODE,
+            child1: NULL_NODE,
+            child2: NULL_NODE,
+            height: -1,
+            sphere: BoundingSphere::default(),
+            cull_box: CullBox::default(),
+            metadata: ObjectMetadata::default(),
+        }
+    }
+
+    #[inline(always)]
+    fn is_leaf(&self) -> bool {
+        self.child1 == NULL_NODE
+    }
+}
+
+/// An incrementally updated bounding volume hierarchy over the bounding spheres of the objects in a
+/// `Zone`. Leaves store a slightly enlarged AABB so that small movements do not restructure the
+/// tree, and the tree is kept balanced with rotations as leaves are inserted and removed.
+/// See "Dynamic Bounding Volume Hierarchies", Erin Catto, GDC 2019.
+pub struct DynamicAabbTree {
+    nodes: Vec<TreeNode>,
+    root: usize,
+    free_list: usize,
+}
+
+impl DynamicAabbTree {
+    // Fraction of the radius used to fatten a leaf's AABB.
+    const FAT_AABB_MARGIN: f32 = 0.1;
+
+    pub fn new() -> Self {
+        DynamicAabbTree {
+            nodes: Vec::new(),
+            root: NULL_NODE,
+            free_list: NULL_NODE,
+        }
+    }
+
+    /// Adds an object to the tree and returns the index of its leaf. The index is stable until the
+    /// leaf is removed.
+    pub fn insert(
+        &mut self,
+        handle: VisibilityObjectHandle,
+        id: u64,
+        sphere: BoundingSphere,
+        cull_box: CullBox,
+    ) -> usize {
+        let leaf = self.allocate_node();
+        let node = &mut self.nodes[leaf];
+        node.aabb = DynamicAabbTree::fat_aabb(&sphere);
+        node.height = 0;
+        node.sphere = sphere;
+        node.cull_box = cull_box;
+        node.metadata = ObjectMetadata { handle, id };
+
+        self.insert_leaf(leaf);
+        leaf
+    }
+
+    /// Updates the bounding sphere of the leaf. The tree is only restructured if the sphere has
+    /// left the leaf's fat AABB.
+    pub fn update(
+        &mut self,
+        leaf: usize,
+        sphere: BoundingSphere,
+        cull_box: CullBox,
+    ) {
+        self.assert_leaf_valid(leaf);
+        self.nodes[leaf].sphere = sphere;
+        self.nodes[leaf].cull_box = cull_box;
+
+        let aabb = AxisAlignedBoundingBox::from_bounding_sphere(&sphere);
+        if self.nodes[leaf].aabb.contains(&aabb) {
+            return;
+        }
+
+        self.remove_leaf(leaf);
+        self.nodes[leaf].aabb = DynamicAabbTree::fat_aabb(&sphere);
+        self.insert_leaf(leaf);
+    }
+
+    pub fn update_id(
+        &mut self,
+        leaf: usize,
+        id: u64,
+    ) {
+        self.assert_leaf_valid(leaf);
+        self.nodes[leaf].metadata.id = id;
+    }
+
+    pub fn remove(
+        &mut self,
+        leaf: usize,
+    ) {
+        self.assert_leaf_valid(leaf);
+        self.remove_leaf(leaf);
+        self.free_node(leaf);
+    }
+
+    /// Calls `visit` for every object whose bounding sphere and `CullBox` are not outside one of the `planes`.
+    /// Subtrees that are entirely inside a plane skip testing against that plane.
+    pub(crate) fn query_planes<F: FnMut(&ObjectMetadata, &BoundingSphere)>(
+        &self,
+        planes: &[Vec4],
+        mut visit: F,
+    ) {
+        assert!(planes.len() <= MAX_PLANES);
+        if self.root == NULL_NODE {
+            return;
+        }
+
+        let all_planes = ((1_u64 << planes.len()) - 1) as u32;
+        let mut stack = Vec::with_capacity(64);
+        stack.push((self.root, all_planes));
+
+        while let Some((index, mask)) = stack.pop() {
+            let node = &self.nodes[index];
+
+            if node.is_leaf() {
+                if mask == 0
+                    || !(is_sphere_outside(planes, mask, &node.sphere)
+                        || node.cull_box.is_outside(planes, mask))
+                {
+                    visit(&node.metadata, &node.sphere);
+                }
+                continue;
+            }
+
+            let mask = if mask == 0 {
+                mask
+            } else if let Some(mask) = classify_aabb(planes, mask, &node.aabb) {
+                mask
+            } else {
+                continue;
+            };
+
+            stack.push((node.child2, mask));
+            stack.push((node.child1, mask));
+        }
+    }
+
+    /// Calls `visit` for every object whose fat AABB passes `test`. Subtrees are skipped when `test`
+    /// fails for their AABB.
+    pub(crate) fn query<
+        T: FnMut(&AxisAlignedBoundingBox) -> bool,
+        F: FnMut(&ObjectMetadata, &BoundingSphere),
+    >(
+        &self,
+        mut test: T,
+        mut visit: F,
+    ) {
+        if self.root == NULL_NODE {
+            return;
+        }
+
+        let mut stack = Vec::with_capacity(64);
+        stack.push(self.root);
+
+        while let Some(index) = stack.pop() {
+            let node = &self.nodes[index];
+            if !test(&node.aabb) {
+                continue;
+            }
+
+            if node.is_leaf() {
+                visit(&node.metadata, &node.sphere);
+            } else {
+                stack.push(node.child2);
+                stack.push(node.child1);
+            }
+        }
+    }
+
+    fn fat_aabb(sphere: &BoundingSphere) -> AxisAlignedBoundingBox {
+        AxisAlignedBoundingBox::from_bounding_sphere(sphere)
+            .expand(sphere.radius * DynamicAabbTree::FAT_AABB_MARGIN)
+    }
+
+    fn allocate_node(&mut self) -> usize {
+        if self.free_list == NULL_NODE {
+            self.nodes.push(TreeNode::empty());
+            self.nodes.len() - 1
+        } else {
+            let index = self.free_list;
+            self.free_list = self.nodes[index].parent;
+            self.nodes[index] = TreeNode::empty();
+            index
+        }
+    }
+
+    fn free_node(
+        &mut self,
+        index: usize,
+    ) {
+        let node = &mut self.nodes[index];
+        *node = TreeNode::empty();
+        node.parent = self.free_list;
+        self.free_list = index;
+    }
+
+    fn insert_leaf(
+        &mut self,
+        leaf: usize,
+    ) {
+        if self.root == NULL_NODE {
+            self.root = leaf;
+            self.nodes[leaf].parent = NULL_NODE;
+            return;
+        }
+
+        // Descend towards the sibling with the lowest surface area cost.
+        let leaf_aabb = self.nodes[leaf].aabb;
+        let mut index = self.root;
+        while !self.nodes[index].is_leaf() {
+            let node = &self.nodes[index];
+            let area = node.aabb.surface_area();
+            let combined_area = node.aabb.union(&leaf_aabb).surface_area();
+
+            // Cost of creating a new parent for this node and the new leaf.
+            let cost = 2. * combined_area;
+            // Minimum cost of pushing the leaf further down the tree.
+            let inheritance_cost = 2. * (combined_area - area);
+
+            let cost1 = self.descend_cost(node.child1, &leaf_aabb) + inheritance_cost;
+            let cost2 = self.descend_cost(node.child2, &leaf_aabb) + inheritance_cost;
+
+            if cost < cost1 && cost < cost2 {
+                break;
+            }
+
+            index = if cost1 < cost2 {
+                node.child1
+            } else {
+                node.child2
+            };
+        }
+
+        let sibling = index;
+        let old_parent = self.nodes[sibling].parent;
+        let new_parent = self.allocate_node();
+        {
+            let sibling_node = self.nodes[sibling];
+            let node = &mut self.nodes[new_parent];
+            node.parent = old_parent;
+            node.aabb = sibling_node.aabb.union(&leaf_aabb);
+            node.height = sibling_node.height + 1;
+            node.child1 = sibling;
+            node.child2 = leaf;
+        }
+
+        if old_parent == NULL_NODE {
+            self.root = new_parent;
+        } else {
+            self.replace_child(old_parent, sibling, new_parent);
+        }
+
+        self.nodes[sibling].parent = new_parent;
+        self.nodes[leaf].parent = new_parent;
+
+        self.refit_ancestors(new_parent);
+    }
+
+    fn remove_leaf(
+        &mut self,
+        leaf: usize,
+    ) {
+        if leaf == self.root {
+            self.root = NULL_NODE;
+            return;
+        }
+
+        let parent = self.nodes[leaf].parent;
+        let grand_parent = self.nodes[parent].parent;
+        let sibling = if self.nodes[parent].child1 == leaf {
+            self.nodes[parent].child2
+        } else {
+            self.nodes[parent].child1
+        };
+
+        self.free_node(parent);
+        self.nodes[leaf].parent = NULL_NODE;
+
+        if grand_parent == NULL_NODE {
+            self.root = sibling;
+            self.nodes[sibling].parent = NULL_NODE;
+        } else {
+            self.replace_child(grand_parent, parent, sibling);
+            self.nodes[sibling].parent = grand_parent;
+            self.refit_ancestors(grand_parent);
+        }
+    }
+
+    fn descend_cost(
+        &self,
+        child: usize,
+        leaf_aabb: &AxisAlignedBoundingBox,
+    ) -> f32 {
+        let node = &self.nodes[child];
+        let combined_area = node.aabb.union(leaf_aabb).surface_area();
+        if node.is_leaf() {
+            combined_area
+        } else {
+            combined_area - node.aabb.surface_area()
+        }
+    }
+
+    fn replace_child(
+        &mut self,
+        parent: usize,
+        old_child: usize,
+        new_child: usize,
+    ) {
+        let node = &mut self.nodes[parent];
+        if node.child1 == old_child {
+            node.child1 = new_child;
+        } else {
+            debug_assert_eq!(node.child2, old_child);
+            node.child2 = new_child;
+        }
+    }
+
+    fn refit(
+        &mut self,
+        index: usize,
+    ) {
+        let child1 = self.nodes[self.nodes[index].child1];
+        let child2 = self.nodes[self.nodes[index].child2];
+        let node = &mut self.nodes[index];
+        node.height = 1 + child1.height.max(child2.height);
+        node.aabb = child1.aabb.union(&child2.aabb);
+    }
+
+    fn refit_ancestors(
+        &mut self,
+        mut index: usize,
+    ) {
+        while index != NULL_NODE {
+            index = self.balance(index);
+            self.refit(index);
+            index = self.nodes[index].parent;
+        }
+    }
+
+    /// Performs a left or right rotation if `a` is imbalanced. Returns the new root of the subtree.
+    fn balance(
+        &mut self,
+        a: usize,
+    ) -> usize {
+        let node = &self.nodes[a];
+        if node.is_leaf() || node.height < 2 {
+            return a;
+        }
+
+        let b = node.child1;
+        let c = node.child2;
+        let balance = self.nodes[c].height - self.nodes[b].height;
+
+        if balance > 1 {
+            self.rotate_up(a, c)
+        } else if balance < -1 {
+            self.rotate_up(a, b)
+        } else {
+            a
+        }
+    }
+
+    /// Promotes the `child` of `a` to replace `a`, moving the shorter grandchild under `a`.
+    fn rotate_up(
+        &mut self,
+        a: usize,
+        child: usize,
+    ) -> usize {
+        let f = self.nodes[child].child1;
+        let g = self.nodes[child].child2;
+
+        // Swap `a` and `child`.
+        let a_parent = self.nodes[a].parent;
+        self.nodes[child].child1 = a;
+        self.nodes[child].parent = a_parent;
+        self.nodes[a].parent = child;
+
+        if a_parent == NULL_NODE {
+            self.root = child;
+        } else {
+            self.replace_child(a_parent, a, child);
+        }
+
+        // The taller grandchild stays under `child`, the shorter one moves under `a`.
+        let (keep, moved) = if self.nodes[f].height > self.nodes[g].height {
+            (f, g)
+        } else {
+            (g, f)
+        };
+
+        self.nodes[child].child2 = keep;
+        self.replace_child(a, child, moved);
+        self.nodes[moved].parent = a;
+
+        self.refit(a);
+        self.refit(child);
+        child
+    }
+
+    #[inline(always)]
+    fn assert_leaf_valid(
+        &self,
+        leaf: usize,
+    ) {
+        assert!(self.nodes[leaf].height == 0);
+    }
+}
+
+#[inline(always)]
+fn is_sphere_outside(
+    planes: &[Vec4],
+    mask: u32,
+    sphere: &BoundingSphere,
+) -> bool {
+    let position = sphere.position;
+    for (index, plane) in planes.iter().enumerate() {
+        if mask & (1 << index) != 0
+            && plane.w + plane.truncate().dot(position) + sphere.radius <= 0.
+        {
+            return true;
+        }
+    }
+
+    false
+}
+
+/// Returns `None` if the AABB is outside one of the planes in `mask`. Otherwise, returns `mask`
+/// with the planes that fully contain the AABB removed.
+#[inline(always)]
+fn classify_aabb(
+    planes: &[Vec4],
+    mut mask: u32,
+    aabb: &AxisAlignedBoundingBox,
+) -> Option<u32> {
+    let center = aabb.center();
+    let half_extents = aabb.half_extents();
+    for (index, plane) in planes.iter().enumerate() {
+        if mask & (1 << index) == 0 {
+            continue;
+        }
+
+        let normal = plane.truncate();
+        let distance = plane.w + normal.dot(center);
+        let radius = normal.abs().dot(half_extents);
+        if distance + radius <= 0. {
+            return None;
+        }
+
+        if distance - radius >= 0. {
+            mask &= !(1 << index);
+        }
+    }
+
+    Some(mask)
+}
+
+pub(crate) fn frustum_planes(frustum: &Frustum) -> [Vec4; 6] {
+    let mut planes = [Vec4::ZERO; 6];
+    for (plane, frustum_plane) in planes.iter_mut().zip(frustum.planes.iter()) {
+        *plane = frustum_plane.normal;
+    }
+    planes
+}
+
+pub(crate) fn collect_visible_objects_in_tree(
+    tree: &DynamicAabbTree,
+    view_frustum_position: Vec3,
+    planes: &[Vec4],
+    results: &mut VisibleObjects,
+) {
+    tree.query_planes(planes, |object, sphere| {
+        results.push(VisibilityResult::new(
+            object.handle,
+            object.id,
+            view_frustum_position,
+            *sphere,
+        ));
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use glam::Quat;
+    use slotmap::SlotMap;
+
+    // A fixed xorshift sequence keeps the tests deterministic without adding a dependency.
+    struct Random(u32);
+
+    impl Random {
+        fn next_f32(&mut self) -> f32 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 17;
+            self.0 ^= self.0 << 5;
+            (self.0 >> 8) as f32 / (1 << 24) as f32
+        }
+
+        fn range(
+            &mut self,
+            min: f32,
+            max: f32,
+        ) -> f32 {
+            min + (max - min) * self.next_f32()
+        }
+
+        fn position(&mut self) -> Vec3 {
+            Vec3::new(
+                self.range(-100., 100.),
+                self.range(-100., 100.),
+                self.range(-100., 100.),
+            )
+        }
+
+        fn object(&mut self) -> (BoundingSphere, CullBox) {
+            let sphere = BoundingSphere::new(self.position(), self.range(0.1, 10.));
+            let cull_box = if self.next_f32() < 0.5 {
+                CullBox::from_bounding_sphere(&sphere)
+            } else {
+                // A rotated box inside the sphere, so that the box test can reject objects
+                // whose sphere is inside the planes.
+                let rotation = Quat::from_rotation_ypr(self.range(0., 3.), self.range(0., 3.), 0.);
+                let half_extent = sphere.radius * 0.5;
+                CullBox {
+                    center: sphere.position,
+                    axes: [
+                        rotation * Vec3::X * half_extent,
+                        rotation * Vec3::Y * half_extent,
+                        rotation * Vec3::Z * half_extent,
+                    ],
+                }
+            };
+            (sphere, cull_box)
+        }
+    }
+
+    struct Object {
+        leaf: usize,
+        sphere: BoundingSphere,
+        cull_box: CullBox,
+    }
+
+    fn test_planes() -> Vec<Vec<Vec4>> {
+        let plane = |normal: Vec3, distance: f32| normal.normalize().extend(distance);
+        vec![
+            vec![],
+            vec![plane(Vec3::X, 0.)],
+            // A box from -40 to 60 on every axis.
+            vec![
+                plane(Vec3::X, 40.),
+                plane(-Vec3::X, 60.),
+                plane(Vec3::Y, 40.),
+                plane(-Vec3::Y, 60.),
+                plane(Vec3::Z, 40.),
+                plane(-Vec3::Z, 60.),
+            ],
+            // A thin oblique slab.
+            vec![
+                plane(Vec3::new(1., 1., 1.), 5.),
+                plane(Vec3::new(-1., -1., -1.), 5.),
+            ],
+            // A wedge.
+            vec![
+                plane(Vec3::new(1., 0., 1.), 0.),
+                plane(Vec3::new(-1., 0., 1.), 0.),
+                plane(Vec3::Y, 80.),
+            ],
+        ]
+    }
+
+    fn brute_force(
+        objects: &SlotMap<VisibilityObjectHandle, Object>,
+        planes: &[Vec4],
+    ) -> Vec<VisibilityObjectHandle> {
+        let all_planes = ((1_u64 << planes.len()) - 1) as u32;
+        let mut visible = objects
+            .iter()
+            .filter(|(_, object)| {
+                !(is_sphere_outside(planes, all_planes, &object.sphere)
+                    || object.cull_box.is_outside(planes, all_planes))
+            })
+            .map(|(handle, _)| handle)
+            .collect::<Vec<_>>();
+        visible.sort();
+        visible
+    }
+
+    fn assert_tree_valid(tree: &DynamicAabbTree) {
+        if tree.root == NULL_NODE {
+            return;
+        }
+
+        assert_eq!(tree.nodes[tree.root].parent, NULL_NODE);
+        let mut stack = vec![tree.root];
+        while let Some(index) = stack.pop() {
+            let node = &tree.nodes[index];
+            if node.is_leaf() {
+                assert_eq!(node.height, 0);
+                assert!(node
+                    .aabb
+                    .contains(&AxisAlignedBoundingBox::from_bounding_sphere(&node.sphere)));
+                continue;
+            }
+
+            let child1 = &tree.nodes[node.child1];
+            let child2 = &tree.nodes[node.child2];
+            assert_eq!(child1.parent, index);
+            assert_eq!(child2.parent, index);
+            assert_eq!(node.height, 1 + child1.height.max(child2.height));
+            assert!((child1.height - child2.height).abs() <= 1);
+            assert!(node.aabb.contains(&child1.aabb));
+            assert!(node.aabb.contains(&child2.aabb));
+            stack.push(node.child1);
+            stack.push(node.child2);
+        }
+    }
+
+    fn assert_query_planes_matches_brute_force(
+        tree: &DynamicAabbTree,
+        objects: &SlotMap<VisibilityObjectHandle, Object>,
+    ) {
+        assert_tree_valid(tree);
+
+        for planes in test_planes() {
+            let mut visible = Vec::new();
+            tree.query_planes(&planes, |object, sphere| {
+                assert_eq!(objects[object.handle].sphere, *sphere);
+                visible.push(object.handle);
+            });
+            visible.sort();
+
+            assert_eq!(visible, brute_force(objects, &planes));
+        }
+    }
+
+    #[test]
+    fn query_planes_matches_brute_force() {
+        let mut random = Random(0x9E37_79B9);
+        let mut tree = DynamicAabbTree::new();
+        let mut objects = SlotMap::<VisibilityObjectHandle, Object>::with_key();
+
+        assert_query_planes_matches_brute_force(&tree, &objects);
+
+        for _ in 0..500 {
+            let (sphere, cull_box) = random.object();
+            objects.insert_with_key(|handle| Object {
+                leaf: tree.insert(handle, 0, sphere, cull_box),
+                sphere,
+                cull_box,
+            });
+        }
+        assert_query_planes_matches_brute_force(&tree, &objects);
+
+        // Small moves stay inside the fat AABB, large moves restructure the tree.
+        for (_, object) in &mut objects {
+            let offset = if random.next_f32() < 0.5 {
+                Vec3::splat(object.sphere.radius * 0.05)
+            } else {
+                random.position()
+            };
+            object.sphere.position += offset;
+            object.cull_box.center += offset;
+            tree.update(object.leaf, object.sphere, object.cull_box);
+        }
+        assert_query_planes_matches_brute_force(&tree, &objects);
+
+        let handles = objects.keys().collect::<Vec<_>>();
+        for handle in handles.iter().step_by(2) {
+            tree.remove(objects.remove(*handle).unwrap().leaf);
+        }
+        assert_query_planes_matches_brute_force(&tree, &objects);
+
+        // Reinserting reuses the freed nodes.
+        for _ in 0..100 {
+            let (sphere, cull_box) = random.object();
+            objects.insert_with_key(|handle| Object {
+                leaf: tree.insert(handle, 0, sphere, cull_box),
+                sphere,
+                cull_box,
+            });
+        }
+        assert_query_planes_matches_brute_force(&tree, &objects);
+
+        for (_, object) in objects.drain() {
+            tree.remove(object.leaf);
+        }
+        assert_eq!(tree.root, NULL_NODE);
+        assert_query_planes_matches_brute_force(&tree, &objects);
+    }
+
+    #[test]
+    fn objects_outside_by_their_cull_box_are_culled() {
+        let mut handles = SlotMap::<VisibilityObjectHandle, ()>::with_key();
+        let planes = test_planes()[2].clone();
+
+        // The sphere overlaps the planes, but the box is entirely outside of them.
+        let outside_handle = handles.insert(());
+        let outside_sphere = BoundingSphere::new(Vec3::new(-41.5, 0., 0.), 3.);
+        let outside_box = CullBox {
+            center: outside_sphere.position,
+            axes: [Vec3::X, Vec3::Y, Vec3::Z],
+        };
+        assert!(!is_sphere_outside(&planes, 0b11_1111, &outside_sphere));
+        assert!(outside_box.is_outside(&planes, 0b11_1111));
+
+        let inside_handle = handles.insert(());
+        let inside_sphere = BoundingSphere::new(Vec3::ZERO, 1.);
+
+        let mut tree = DynamicAabbTree::new();
+        tree.insert(outside_handle, 0, outside_sphere, outside_box);
+        tree.insert(
+            inside_handle,
+            0,
+            inside_sphere,
+            CullBox::from_bounding_sphere(&inside_sphere),
+        );
+
+        let mut visible = Vec::new();
+        tree.query_planes(&planes, |object, _| visible.push(object.handle));
+        assert_eq!(visible, vec![inside_handle]);
+    }
+
+    #[test]
+    fn update_id_is_returned_by_queries() {
+        let mut handles = SlotMap::<VisibilityObjectHandle, ()>::with_key();
+        let handle = handles.insert(());
+        let sphere = BoundingSphere::new(Vec3::ZERO, 1.);
+
+        let mut tree = DynamicAabbTree::new();
+        let leaf = tree.insert(handle, 1, sphere, CullBox::from_bounding_sphere(&sphere));
+        tree.update_id(leaf, 2);
+
+        let mut ids = Vec::new();
+        tree.query_planes(&[], |object, _| ids.push((object.handle, object.id)));
+        assert_eq!(ids, vec![(handle, 2)]);
+    }
+}
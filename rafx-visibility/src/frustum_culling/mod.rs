@@ -1,3 +1,13 @@
+mod cull_box;
+pub(crate) use cull_box::CullBox;
+
 mod packed_bounding_sphere_chunk;
 pub(crate) use packed_bounding_sphere_chunk::collect_visible_objects;
+pub(crate) use packed_bounding_sphere_chunk::ObjectMetadata;
 pub(crate) use packed_bounding_sphere_chunk::PackedBoundingSphereChunk;
+
+mod dynamic_aabb_tree;
+pub(crate) use dynamic_aabb_tree::collect_visible_objects_in_tree;
+pub(crate) use dynamic_aabb_tree::frustum_planes;
+pub(crate) use dynamic_aabb_tree::DynamicAabbTree;
+pub(crate) use dynamic_aabb_tree::MAX_PLANES;
@@ -1,4 +1,5 @@
-use crate::geometry::{BoundingSphere, Frustum};
+use crate::frustum_culling::CullBox;
+use crate::geometry::BoundingSphere;
 use crate::{VisibilityObjectHandle, VisibilityResult, VisibleObjects};
 use glam::{Vec3, Vec4};
 
@@ -12,6 +13,7 @@ pub struct ObjectMetadata {
 pub struct PackedBoundingSphereChunk {
     spheres: [PackedBoundingSphere; PackedBoundingSphereChunk::CHUNK_SIZE],
     metadata: [ObjectMetadata; PackedBoundingSphereChunk::MAX_LEN],
+    boxes: [CullBox; PackedBoundingSphereChunk::MAX_LEN],
     len: usize,
 }
 
@@ -31,6 +33,7 @@ impl PackedBoundingSphereChunk {
         PackedBoundingSphereChunk {
             len: 0,
             metadata: [ObjectMetadata::default(); PackedBoundingSphereChunk::MAX_LEN],
+            boxes: [CullBox::default(); PackedBoundingSphereChunk::MAX_LEN],
             spheres: [PackedBoundingSphere::default(); PackedBoundingSphereChunk::CHUNK_SIZE],
         }
     }
@@ -45,11 +48,13 @@ impl PackedBoundingSphereChunk {
         handle: VisibilityObjectHandle,
         id: u64,
         sphere: BoundingSphere,
+        cull_box: CullBox,
     ) -> Option<usize> {
         let next_index = Index(self.len());
         let internal_index = self.get_internal_index(next_index);
         return if internal_index.0 < PackedBoundingSphereChunk::CHUNK_SIZE {
             self.metadata[next_index.0] = ObjectMetadata { handle, id };
+            self.boxes[next_index.0] = cull_box;
             self.set_internal(internal_index, sphere);
             self.len += 1;
             Some(next_index.0)
@@ -72,10 +77,12 @@ impl PackedBoundingSphereChunk {
         &mut self,
         index: usize,
         sphere: BoundingSphere,
+        cull_box: CullBox,
     ) {
         let index = Index(index);
         self.assert_index_valid(index);
         self.set_internal(self.get_internal_index(index), sphere);
+        self.boxes[index.0] = cull_box;
     }
 
     pub fn remove(
@@ -86,6 +93,7 @@ impl PackedBoundingSphereChunk {
         return if index.0 < self.len() {
             // NOTE(dvd): Overwrite with last sphere, starting with the handle.
             self.metadata[index.0] = self.metadata[self.len() - 1];
+            self.boxes[index.0] = self.boxes[self.len() - 1];
             let last_sphere = self.get_internal(self.get_internal_index(Index(self.len() - 1)));
             self.set_internal(self.get_internal_index(index), last_sphere);
             self.len -= 1;
@@ -104,7 +112,15 @@ impl PackedBoundingSphereChunk {
         &self.metadata[index.0]
     }
 
-    #[allow(dead_code)]
+    pub fn cull_box(
+        &self,
+        index: usize,
+    ) -> &CullBox {
+        let index = Index(index);
+        self.assert_index_valid(index);
+        &self.boxes[index.0]
+    }
+
     pub fn get(
         &self,
         index: usize,
@@ -160,31 +176,50 @@ impl PackedBoundingSphereChunk {
     }
 }
 
+/// Culls the objects in the chunk against the `planes`. Each object's bounding sphere is tested
+/// first, and only objects with a sphere inside all of the planes test their `CullBox`.
 pub(crate) fn collect_visible_objects(
     chunk: &PackedBoundingSphereChunk,
     view_frustum_position: Vec3,
-    frustum: &Frustum,
+    planes: &[Vec4],
     results: &mut VisibleObjects,
 ) {
-    return if frustum.planes.len() == 6 {
-        let mut planes = [Vec4::ZERO; 6];
-        planes[0] = frustum.planes[0].normal;
-        planes[1] = frustum.planes[1].normal;
-        planes[2] = frustum.planes[2].normal;
-        planes[3] = frustum.planes[3].normal;
-        planes[4] = frustum.planes[4].normal;
-        planes[5] = frustum.planes[5].normal;
-
+    return if planes.len() == 6 {
+        let planes = [
+            planes[0], planes[1], planes[2], planes[3], planes[4], planes[5],
+        ];
         collect_visible_objects_fast(chunk, view_frustum_position, &planes, results);
     } else {
-        // TODO(dvd): Write other methods if we need non-standard frustum.
-        panic!(
-            "Implement new find_visible_objects_in_vec for frustum with {} planes.",
-            frustum.planes.len()
-        );
+        // Non-standard frustums, e.g. a frustum clipped by a portal, test one sphere at a time.
+        collect_visible_objects_slow(chunk, view_frustum_position, planes, results);
     };
 }
 
+fn collect_visible_objects_slow(
+    chunk: &PackedBoundingSphereChunk,
+    view_frustum_position: Vec3,
+    planes: &[Vec4],
+    results: &mut VisibleObjects,
+) {
+    let mask = ((1_u64 << planes.len()) - 1) as u32;
+    for index in 0..chunk.len() {
+        let sphere = chunk.get(index);
+        let is_sphere_outside = planes
+            .iter()
+            .any(|plane| plane.w + plane.truncate().dot(sphere.position) + sphere.radius <= 0.);
+
+        if !is_sphere_outside && !chunk.boxes[index].is_outside(planes, mask) {
+            let object = chunk.metadata[index];
+            results.push(VisibilityResult::new(
+                object.handle,
+                object.id,
+                view_frustum_position,
+                sphere,
+            ));
+        }
+    }
+}
+
 fn collect_visible_objects_fast(
     chunk: &PackedBoundingSphereChunk,
     view_frustum_position: Vec3,
@@ -193,10 +228,12 @@ fn collect_visible_objects_fast(
 ) {
     let mut query = Query {
         view_frustum_position,
+        planes,
         spheres: &chunk.spheres[0],
         handle_index: 0,
         bitmask: 0,
         metadata: &chunk.metadata,
+        boxes: &chunk.boxes,
         results,
     };
 
@@ -232,10 +269,12 @@ fn collect_visible_objects_fast(
 
 struct Query<'a> {
     pub view_frustum_position: Vec3,
+    pub planes: &'a [Vec4; 6],
     pub spheres: &'a PackedBoundingSphere,
     pub handle_index: usize,
     pub bitmask: i32,
     pub metadata: &'a [ObjectMetadata; PackedBoundingSphereChunk::MAX_LEN],
+    pub boxes: &'a [CullBox; PackedBoundingSphereChunk::MAX_LEN],
     pub results: &'a mut VisibleObjects,
 }
 
@@ -246,7 +285,13 @@ impl Query<'_> {
         packed_index: usize,
     ) {
         if (self.bitmask & (1 << packed_index)) > 0 {
-            let object = self.metadata[self.handle_index + packed_index];
+            // The sphere passed. Reject long, thin objects with their box.
+            let index = self.handle_index + packed_index;
+            if self.boxes[index].is_outside(self.planes, 0b11_1111) {
+                return;
+            }
+
+            let object = self.metadata[index];
             self.results.push(VisibilityResult::new(
                 object.handle,
                 object.id,
@@ -365,3 +410,119 @@ impl PackedBoundingSphere {
         debug_assert!(index < PackedBoundingSphere::NUM_PACKED);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use slotmap::SlotMap;
+
+    // A box from -10 to 10 on every axis.
+    fn box_planes() -> [Vec4; 6] {
+        [
+            Vec4::new(1., 0., 0., 10.),
+            Vec4::new(-1., 0., 0., 10.),
+            Vec4::new(0., 1., 0., 10.),
+            Vec4::new(0., -1., 0., 10.),
+            Vec4::new(0., 0., 1., 10.),
+            Vec4::new(0., 0., -1., 10.),
+        ]
+    }
+
+    fn visible_handles(
+        chunk: &PackedBoundingSphereChunk,
+        planes: &[Vec4],
+    ) -> Vec<VisibilityObjectHandle> {
+        let mut results = VisibleObjects::new();
+        collect_visible_objects(chunk, Vec3::ZERO, planes, &mut results);
+        let mut handles = results
+            .iter()
+            .map(|result| result.handle)
+            .collect::<Vec<_>>();
+        handles.sort();
+        handles
+    }
+
+    #[test]
+    fn objects_outside_by_their_cull_box_are_culled() {
+        let mut handles = SlotMap::<VisibilityObjectHandle, ()>::with_key();
+        let mut chunk = PackedBoundingSphereChunk::new();
+        let box_planes = box_planes();
+
+        // The sphere overlaps the planes, but the box is entirely outside of them.
+        let outside_handle = handles.insert(());
+        let outside_sphere = BoundingSphere::new(Vec3::new(11.5, 0., 0.), 3.);
+        let outside_box = CullBox {
+            center: outside_sphere.position,
+            axes: [Vec3::X, Vec3::Y, Vec3::Z],
+        };
+        assert!(!box_planes.iter().any(|plane| plane.w
+            + plane.truncate().dot(outside_sphere.position)
+            + outside_sphere.radius
+            <= 0.));
+        assert!(outside_box.is_outside(&box_planes, 0b11_1111));
+
+        let inside_handle = handles.insert(());
+        let inside_sphere = BoundingSphere::new(Vec3::ZERO, 1.);
+
+        // Add enough objects to use both the packed loop and the last packed sphere.
+        for _ in 0..2 {
+            chunk.add(outside_handle, 0, outside_sphere, outside_box);
+            chunk.add(
+                inside_handle,
+                0,
+                inside_sphere,
+                CullBox::from_bounding_sphere(&inside_sphere),
+            );
+        }
+        chunk.add(outside_handle, 0, outside_sphere, outside_box);
+
+        let expected = vec![inside_handle, inside_handle];
+        assert_eq!(visible_handles(&chunk, &box_planes), expected);
+
+        // A 7th plane that contains everything forces the slow path.
+        let mut planes = box_planes.to_vec();
+        planes.push(Vec4::new(0., 0., 1., 1000.));
+        assert_eq!(visible_handles(&chunk, &planes), expected);
+    }
+
+    #[test]
+    fn chunk_matches_sphere_and_box_tests() {
+        let mut handles = SlotMap::<VisibilityObjectHandle, ()>::with_key();
+        let mut chunk = PackedBoundingSphereChunk::new();
+        let mut objects = Vec::new();
+        let box_planes = box_planes();
+
+        for x in -15..=15 {
+            let handle = handles.insert(());
+            let sphere = BoundingSphere::new(Vec3::new(x as f32, 0.5 * x as f32, 0.), 2.);
+            let cull_box = if x % 2 == 0 {
+                CullBox::from_bounding_sphere(&sphere)
+            } else {
+                CullBox {
+                    center: sphere.position,
+                    axes: [Vec3::X * 0.5, Vec3::Y * 0.5, Vec3::Z * 0.5],
+                }
+            };
+            chunk.add(handle, 0, sphere, cull_box);
+            objects.push((handle, sphere, cull_box));
+        }
+
+        let mut slow_planes = box_planes.to_vec();
+        slow_planes.push(Vec4::new(0., 0., 1., 1000.));
+        for planes in [&box_planes[..], &slow_planes[..]] {
+            let mask = ((1_u64 << planes.len()) - 1) as u32;
+            let mut expected = objects
+                .iter()
+                .filter(|(_, sphere, cull_box)| {
+                    !planes.iter().any(|plane| {
+                        plane.w + plane.truncate().dot(sphere.position) + sphere.radius <= 0.
+                    }) && !cull_box.is_outside(planes, mask)
+                })
+                .map(|(handle, _, _)| *handle)
+                .collect::<Vec<_>>();
+            expected.sort();
+
+            assert_eq!(visible_handles(&chunk, planes), expected);
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use crate::geometry::BoundingSphere;
 use glam::Vec3;
 use serde::Deserialize;
 use serde::Serialize;
@@ -7,3 +8,79 @@ pub struct AxisAlignedBoundingBox {
     pub min: Vec3,
     pub max: Vec3,
 }
+
+impl AxisAlignedBoundingBox {
+    pub fn new(
+        min: Vec3,
+        max: Vec3,
+    ) -> Self {
+        AxisAlignedBoundingBox { min, max }
+    }
+
+    pub fn from_bounding_sphere(sphere: &BoundingSphere) -> Self {
+        let extents = Vec3::splat(sphere.radius);
+        AxisAlignedBoundingBox {
+            min: sphere.position - extents,
+            max: sphere.position + extents,
+        }
+    }
+
+    pub fn center(&self) -> Vec3 {
+        (self.min + self.max) * 0.5
+    }
+
+    pub fn half_extents(&self) -> Vec3 {
+        (self.max - self.min) * 0.5
+    }
+
+    /// Returns the surface area of the box. Used as the cost metric when building hierarchies.
+    pub fn surface_area(&self) -> f32 {
+        let size = self.max - self.min;
+        2. * (size.x * size.y + size.y * size.z + size.z * size.x)
+    }
+
+    pub fn union(
+        &self,
+        other: &AxisAlignedBoundingBox,
+    ) -> Self {
+        AxisAlignedBoundingBox {
+            min: self.min.min(other.min),
+            max: self.max.max(other.max),
+        }
+    }
+
+    /// Returns a copy of the box grown by `margin` on every side.
+    pub fn expand(
+        &self,
+        margin: f32,
+    ) -> Self {
+        let margin = Vec3::splat(margin);
+        AxisAlignedBoundingBox {
+            min: self.min - margin,
+            max: self.max + margin,
+        }
+    }
+
+    /// Returns `true` if `other` is entirely inside this box.
+    pub fn contains(
+        &self,
+        other: &AxisAlignedBoundingBox,
+    ) -> bool {
+        self.min.cmple(other.min).all() && self.max.cmpge(other.max).all()
+    }
+
+    pub fn intersects_aabb(
+        &self,
+        other: &AxisAlignedBoundingBox,
+    ) -> bool {
+        self.min.cmple(other.max).all() && self.max.cmpge(other.min).all()
+    }
+
+    pub fn intersects_sphere(
+        &self,
+        sphere: &BoundingSphere,
+    ) -> bool {
+        let closest_point = sphere.position.max(self.min).min(self.max);
+        closest_point.distance_squared(sphere.position) <= sphere.radius * sphere.radius
+    }
+}
@@ -15,4 +15,12 @@ impl BoundingSphere {
     ) -> Self {
         BoundingSphere { position, radius }
     }
+
+    pub fn intersects_sphere(
+        &self,
+        other: &BoundingSphere,
+    ) -> bool {
+        let radius = self.radius + other.radius;
+        self.position.distance_squared(other.position) <= radius * radius
+    }
 }
@@ -13,5 +13,11 @@ pub use oriented_bounding_box::OrientedBoundingBox;
 mod plane;
 pub use plane::Plane;
 
+mod ray;
+pub use ray::Ray;
+
 mod transform;
 pub use transform::Transform;
+
+mod triangle;
+pub use triangle::Triangle;
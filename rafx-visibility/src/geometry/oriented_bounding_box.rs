@@ -1,10 +1,277 @@
-use glam::{Quat, Vec3};
+use crate::geometry::AxisAlignedBoundingBox;
+use glam::{Mat3, Quat, Vec3};
 use serde::Deserialize;
 use serde::Serialize;
 
+/// A box with extents `min` and `max` in the space rotated by `rotation`.
 #[derive(Default, Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct OrientedBoundingBox {
     pub min: Vec3,
     pub max: Vec3,
     pub rotation: Quat,
 }
+
+impl OrientedBoundingBox {
+    // Keeps flat geometry like quads from producing a box with no thickness.
+    const MIN_EXTENT: f32 = 0.01;
+
+    /// Fits a box to the `points` using the principal axes of their covariance. Falls back to the
+    /// axis-aligned box when that has less volume, e.g. for boxes that are already axis-aligned.
+    pub fn from_points(points: &[Vec3]) -> Self {
+        if points.is_empty() {
+            return OrientedBoundingBox::default();
+        }
+
+        let mean = points.iter().fold(Vec3::ZERO, |sum, point| sum + *point) / points.len() as f32;
+
+        let mut covariance = [[0.; 3]; 3];
+        for point in points {
+            let offset = <[f32; 3]>::from(*point - mean);
+            for row in 0..3 {
+                for column in 0..3 {
+                    covariance[row][column] += offset[row] * offset[column];
+                }
+            }
+        }
+
+        let axes = symmetric_eigenvectors(covariance);
+        let mut rotation_matrix = Mat3::from_cols(axes[0], axes[1], axes[2]);
+        if rotation_matrix.determinant() < 0. {
+            rotation_matrix = Mat3::from_cols(axes[0], axes[1], -axes[2]);
+        }
+
+        let rotation = Quat::from_rotation_mat3(&rotation_matrix).normalize();
+        let obb = OrientedBoundingBox::fit(points, rotation);
+
+        let aabb = OrientedBoundingBox::fit(points, Quat::IDENTITY);
+        if aabb.volume() <= obb.volume() {
+            aabb
+        } else {
+            obb
+        }
+    }
+
+    /// Returns a box with no rotation around the `aabb`.
+    pub fn from_aabb(aabb: &AxisAlignedBoundingBox) -> Self {
+        OrientedBoundingBox {
+            min: aabb.min,
+            max: aabb.max,
+            rotation: Quat::IDENTITY,
+        }
+    }
+
+    /// Returns `true` if the box has no volume, e.g. because it was created by `Default`.
+    pub fn is_empty(&self) -> bool {
+        self.min.cmpge(self.max).any()
+    }
+
+    pub fn center(&self) -> Vec3 {
+        self.rotation * ((self.min + self.max) * 0.5)
+    }
+
+    pub fn half_extents(&self) -> Vec3 {
+        (self.max - self.min) * 0.5
+    }
+
+    pub fn volume(&self) -> f32 {
+        let size = self.max - self.min;
+        size.x * size.y * size.z
+    }
+
+    fn fit(
+        points: &[Vec3],
+        rotation: Quat,
+    ) -> Self {
+        let inverse_rotation = rotation.conjugate();
+        let mut min = Vec3::splat(f32::MAX);
+        let mut max = Vec3::splat(f32::MIN);
+        for point in points {
+            let local = inverse_rotation * *point;
+            min = min.min(local);
+            max = max.max(local);
+        }
+
+        let padding =
+            (Vec3::splat(OrientedBoundingBox::MIN_EXTENT) - (max - min)).max(Vec3::ZERO) * 0.5;
+        OrientedBoundingBox {
+            min: min - padding,
+            max: max + padding,
+            rotation,
+        }
+    }
+}
+
+// Cyclic Jacobi eigenvalue algorithm. Returns the eigenvectors of a symmetric 3x3 matrix.
+fn symmetric_eigenvectors(mut matrix: [[f32; 3]; 3]) -> [Vec3; 3] {
+    let mut vectors = [[1., 0., 0.], [0., 1., 0.], [0., 0., 1.]];
+
+    for _ in 0..16 {
+        let off_diagonal =
+            matrix[0][1] * matrix[0][1] + matrix[0][2] * matrix[0][2] + matrix[1][2] * matrix[1][2];
+        if off_diagonal < 1e-12 {
+            break;
+        }
+
+        for (p, q) in [(0, 1), (0, 2), (1, 2)] {
+            if matrix[p][q].abs() < 1e-12 {
+                continue;
+            }
+
+            let theta = (matrix[q][q] - matrix[p][p]) / (2. * matrix[p][q]);
+            let t = theta.signum() / (theta.abs() + (theta * theta + 1.).sqrt());
+            let c = 1. / (t * t + 1.).sqrt();
+            let s = t * c;
+
+            for row in &mut matrix {
+                let kp = row[p];
+                let kq = row[q];
+                row[p] = c * kp - s * kq;
+                row[q] = s * kp + c * kq;
+            }
+
+            let (row_p, row_q) = (matrix[p], matrix[q]);
+            for (k, (pk, qk)) in row_p.iter().zip(row_q.iter()).enumerate() {
+                matrix[p][k] = c * pk - s * qk;
+                matrix[q][k] = s * pk + c * qk;
+            }
+
+            for vector in &mut vectors {
+                let vp = vector[p];
+                let vq = vector[q];
+                vector[p] = c * vp - s * vq;
+                vector[q] = s * vp + c * vq;
+            }
+        }
+    }
+
+    // `vectors[k][i]` is component `k` of eigenvector `i`.
+    [
+        Vec3::new(vectors[0][0], vectors[1][0], vectors[2][0]),
+        Vec3::new(vectors[0][1], vectors[1][1], vectors[2][1]),
+        Vec3::new(vectors[0][2], vectors[1][2], vectors[2][2]),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EPSILON: f32 = 0.001;
+
+    fn box_corners(
+        half_extents: Vec3,
+        rotation: Quat,
+        translation: Vec3,
+    ) -> Vec<Vec3> {
+        let mut corners = Vec::new();
+        for x in [-1., 1.] {
+            for y in [-1., 1.] {
+                for z in [-1., 1.] {
+                    corners.push(rotation * (half_extents * Vec3::new(x, y, z)) + translation);
+                }
+            }
+        }
+        corners
+    }
+
+    fn assert_contains(
+        obb: &OrientedBoundingBox,
+        points: &[Vec3],
+    ) {
+        let inverse_rotation = obb.rotation.conjugate();
+        for point in points {
+            let local = inverse_rotation * *point;
+            assert!(
+                local.cmpge(obb.min - Vec3::splat(EPSILON)).all()
+                    && local.cmple(obb.max + Vec3::splat(EPSILON)).all(),
+                "{:?} is outside {:?}",
+                point,
+                obb
+            );
+        }
+    }
+
+    #[test]
+    fn symmetric_eigenvectors_diagonalize() {
+        let rotation = Mat3::from_quat(Quat::from_axis_angle(
+            Vec3::new(1., 2., 3.).normalize(),
+            0.7,
+        ));
+        let eigenvalues = Vec3::new(5., 2., 1.);
+        let matrix = rotation * Mat3::from_diagonal(eigenvalues) * rotation.transpose();
+        let columns = matrix.to_cols_array_2d();
+
+        let vectors = symmetric_eigenvectors(columns);
+        for (i, vector) in vectors.iter().enumerate() {
+            assert!((vector.length() - 1.).abs() < EPSILON);
+            for other in &vectors[i + 1..] {
+                assert!(vector.dot(*other).abs() < EPSILON);
+            }
+
+            let transformed = matrix * *vector;
+            let eigenvalue = transformed.dot(*vector);
+            assert!(
+                <[f32; 3]>::from(eigenvalues)
+                    .iter()
+                    .any(|value| (value - eigenvalue).abs() < EPSILON),
+                "{} is not an eigenvalue",
+                eigenvalue
+            );
+            assert!((transformed - *vector * eigenvalue).length() < EPSILON);
+        }
+    }
+
+    #[test]
+    fn from_points_empty() {
+        assert!(OrientedBoundingBox::from_points(&[]).is_empty());
+    }
+
+    #[test]
+    fn from_points_axis_aligned() {
+        let points = box_corners(
+            Vec3::new(4., 1., 0.5),
+            Quat::IDENTITY,
+            Vec3::new(1., 2., 3.),
+        );
+        let obb = OrientedBoundingBox::from_points(&points);
+
+        assert_eq!(obb.rotation, Quat::IDENTITY);
+        assert!(obb.min.abs_diff_eq(Vec3::new(-3., 1., 2.5), EPSILON));
+        assert!(obb.max.abs_diff_eq(Vec3::new(5., 3., 3.5), EPSILON));
+    }
+
+    #[test]
+    fn from_points_rotated() {
+        let rotation = Quat::from_axis_angle(Vec3::new(1., 1., 0.).normalize(), 0.6);
+        let translation = Vec3::new(-2., 5., 1.);
+        let points = box_corners(Vec3::new(4., 1., 0.5), rotation, translation);
+        let obb = OrientedBoundingBox::from_points(&points);
+
+        assert_contains(&obb, &points);
+        assert!((obb.volume() - 16.).abs() < 0.01, "{}", obb.volume());
+        assert!(obb.center().abs_diff_eq(translation, EPSILON));
+
+        let aabb = OrientedBoundingBox::fit(&points, Quat::IDENTITY);
+        assert!(obb.volume() < aabb.volume());
+    }
+
+    #[test]
+    fn from_points_flat() {
+        let rotation = Quat::from_rotation_y(0.4);
+        let points = [
+            rotation * Vec3::new(-1., -1., 0.),
+            rotation * Vec3::new(1., -1., 0.),
+            rotation * Vec3::new(-1., 1., 0.),
+            rotation * Vec3::new(1., 1., 0.),
+        ];
+        let obb = OrientedBoundingBox::from_points(&points);
+
+        assert!(!obb.is_empty());
+        assert_contains(&obb, &points);
+        let mut half_extents = <[f32; 3]>::from(obb.half_extents());
+        half_extents.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        assert!((half_extents[0] - OrientedBoundingBox::MIN_EXTENT * 0.5).abs() < EPSILON);
+        assert!((half_extents[1] - 1.).abs() < EPSILON);
+        assert!((half_extents[2] - 1.).abs() < EPSILON);
+    }
+}
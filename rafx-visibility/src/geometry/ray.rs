@@ -0,0 +1,324 @@
+use crate::geometry::{AxisAlignedBoundingBox, BoundingSphere, Triangle};
+use glam::Vec3;
+
+#[derive(Default, Copy, Clone, Debug, PartialEq)]
+pub struct Ray {
+    pub origin: Vec3,
+    pub direction: Vec3,
+}
+
+impl Ray {
+    /// The `direction` is normalized so that hit distances are in world units. It must be finite and
+    /// non-zero. The intersection functions also expect a normalized, non-zero `direction` when the
+    /// fields are set directly.
+    pub fn new(
+        origin: Vec3,
+        direction: Vec3,
+    ) -> Self {
+        debug_assert!(
+            direction.is_finite() && direction != Vec3::ZERO,
+            "Ray direction must be finite and non-zero, got {}",
+            direction
+        );
+
+        Ray {
+            origin,
+            direction: direction.normalize(),
+        }
+    }
+
+    pub fn at(
+        &self,
+        distance: f32,
+    ) -> Vec3 {
+        self.origin + self.direction * distance
+    }
+
+    /// Returns the distance to the first intersection with the sphere, or zero if the origin is inside it.
+    pub fn intersect_sphere(
+        &self,
+        sphere: &BoundingSphere,
+    ) -> Option<f32> {
+        let m = self.origin - sphere.position;
+        let b = m.dot(self.direction);
+        let c = m.dot(m) - sphere.radius * sphere.radius;
+        if c > 0. && b > 0. {
+            return None;
+        }
+
+        let discriminant = b * b - c;
+        if discriminant < 0. {
+            return None;
+        }
+
+        Some((-b - discriminant.sqrt()).max(0.))
+    }
+
+    /// Returns the distance to the first intersection with the box, or zero if the origin is inside it.
+    pub fn intersect_aabb(
+        &self,
+        aabb: &AxisAlignedBoundingBox,
+    ) -> Option<f32> {
+        let mut t_min = 0_f32;
+        let mut t_max = f32::INFINITY;
+        for axis in 0..3 {
+            let origin = self.origin[axis];
+            let direction = self.direction[axis];
+            let (min, max) = (aabb.min[axis], aabb.max[axis]);
+
+            // A ray parallel to the slab never enters or leaves it. Dividing by the zero direction
+            // would give 0 * inf = NaN for an origin on one of the slab's planes.
+            if direction == 0. {
+                if origin < min || origin > max {
+                    return None;
+                }
+
+                continue;
+            }
+
+            let inverse_direction = 1. / direction;
+            let t1 = (min - origin) * inverse_direction;
+            let t2 = (max - origin) * inverse_direction;
+            t_min = t_min.max(t1.min(t2));
+            t_max = t_max.min(t1.max(t2));
+            if t_min > t_max {
+                return None;
+            }
+        }
+
+        Some(t_min)
+    }
+
+    /// Returns the distance to the intersection with either side of the triangle.
+    /// See "Fast, Minimum Storage Ray/Triangle Intersection", Möller & Trumbore, 1997.
+    pub fn intersect_triangle(
+        &self,
+        triangle: &Triangle,
+    ) -> Option<f32> {
+        let edge1 = triangle.b - triangle.a;
+        let edge2 = triangle.c - triangle.a;
+        let p = self.direction.cross(edge2);
+        let determinant = edge1.dot(p);
+        if determinant.abs() < f32::EPSILON {
+            return None;
+        }
+
+        let inverse_determinant = 1. / determinant;
+        let s = self.origin - triangle.a;
+        let u = s.dot(p) * inverse_determinant;
+        if !(0. ..=1.).contains(&u) {
+            return None;
+        }
+
+        let q = s.cross(edge1);
+        let v = self.direction.dot(q) * inverse_determinant;
+        if v < 0. || u + v > 1. {
+            return None;
+        }
+
+        let t = edge2.dot(q) * inverse_determinant;
+        if t >= 0. {
+            Some(t)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EPSILON: f32 = 0.0001;
+
+    fn assert_distance(
+        actual: Option<f32>,
+        expected: f32,
+    ) {
+        let actual = actual.unwrap();
+        assert!(
+            (actual - expected).abs() < EPSILON,
+            "{} != {}",
+            actual,
+            expected
+        );
+    }
+
+    #[test]
+    fn new_normalizes_direction() {
+        let ray = Ray::new(Vec3::ZERO, Vec3::new(0., 0., 5.));
+        assert_eq!(ray.direction, Vec3::Z);
+        assert_eq!(ray.at(2.), Vec3::new(0., 0., 2.));
+    }
+
+    #[test]
+    fn intersect_sphere() {
+        let sphere = BoundingSphere::new(Vec3::new(0., 0., 10.), 2.);
+
+        assert_distance(Ray::new(Vec3::ZERO, Vec3::Z).intersect_sphere(&sphere), 8.);
+        // Inside the sphere.
+        assert_distance(
+            Ray::new(Vec3::new(0., 0., 9.), Vec3::Z).intersect_sphere(&sphere),
+            0.,
+        );
+        // Grazing the sphere.
+        assert_distance(
+            Ray::new(Vec3::new(2., 0., 0.), Vec3::Z).intersect_sphere(&sphere),
+            10.,
+        );
+        // Missing the sphere.
+        assert!(Ray::new(Vec3::new(2.1, 0., 0.), Vec3::Z)
+            .intersect_sphere(&sphere)
+            .is_none());
+        // Pointing away from the sphere.
+        assert!(Ray::new(Vec3::ZERO, -Vec3::Z)
+            .intersect_sphere(&sphere)
+            .is_none());
+    }
+
+    #[test]
+    fn intersect_aabb() {
+        let aabb = AxisAlignedBoundingBox {
+            min: Vec3::new(-1., -1., 4.),
+            max: Vec3::new(1., 1., 6.),
+        };
+
+        assert_distance(Ray::new(Vec3::ZERO, Vec3::Z).intersect_aabb(&aabb), 4.);
+        assert_distance(
+            Ray::new(Vec3::new(0., 0., 10.), -Vec3::Z).intersect_aabb(&aabb),
+            4.,
+        );
+        // Inside the box.
+        assert_distance(
+            Ray::new(Vec3::new(0., 0., 5.), Vec3::X).intersect_aabb(&aabb),
+            0.,
+        );
+        // Diagonally through the corner.
+        assert_distance(
+            Ray::new(Vec3::new(-2., -2., 3.), Vec3::ONE).intersect_aabb(&aabb),
+            3_f32.sqrt(),
+        );
+        // Parallel to a face, outside the box.
+        assert!(Ray::new(Vec3::new(2., 0., 0.), Vec3::Z)
+            .intersect_aabb(&aabb)
+            .is_none());
+        // Pointing away from the box.
+        assert!(Ray::new(Vec3::ZERO, -Vec3::Z)
+            .intersect_aabb(&aabb)
+            .is_none());
+    }
+
+    #[test]
+    fn intersect_aabb_axis_aligned() {
+        let aabb = AxisAlignedBoundingBox {
+            min: Vec3::new(-1., -1., 4.),
+            max: Vec3::new(1., 1., 6.),
+        };
+
+        for (origin, direction, distance) in [
+            (Vec3::new(-5., 0., 5.), Vec3::X, 4.),
+            (Vec3::new(5., 0., 5.), -Vec3::X, 4.),
+            (Vec3::new(0., -5., 5.), Vec3::Y, 4.),
+            (Vec3::new(0., 5., 5.), -Vec3::Y, 4.),
+            (Vec3::new(0., 0., 0.), Vec3::Z, 4.),
+            (Vec3::new(0., 0., 10.), -Vec3::Z, 4.),
+        ] {
+            assert_distance(Ray::new(origin, direction).intersect_aabb(&aabb), distance);
+        }
+
+        // Grazing an edge and a face of the box.
+        assert_distance(
+            Ray::new(Vec3::new(1., 1., 0.), Vec3::Z).intersect_aabb(&aabb),
+            4.,
+        );
+        assert_distance(
+            Ray::new(Vec3::new(1., 0., 0.), Vec3::Z).intersect_aabb(&aabb),
+            4.,
+        );
+        // Just outside of a face.
+        assert!(Ray::new(Vec3::new(1.001, 0., 0.), Vec3::Z)
+            .intersect_aabb(&aabb)
+            .is_none());
+    }
+
+    #[test]
+    fn intersect_aabb_from_face() {
+        let aabb = AxisAlignedBoundingBox {
+            min: Vec3::new(-1., -1., 4.),
+            max: Vec3::new(1., 1., 6.),
+        };
+
+        // Starting on a face, pointing into the box.
+        assert_distance(
+            Ray::new(Vec3::new(0., 0., 4.), Vec3::Z).intersect_aabb(&aabb),
+            0.,
+        );
+        // Starting on a face, pointing away from the box, still touches the face.
+        assert_distance(
+            Ray::new(Vec3::new(0., 0., 4.), -Vec3::Z).intersect_aabb(&aabb),
+            0.,
+        );
+        // Starting on a face and moving along it. The zero components of the direction must not
+        // produce NaN.
+        assert_distance(
+            Ray::new(Vec3::new(-1., 0., 5.), Vec3::Y).intersect_aabb(&aabb),
+            0.,
+        );
+        assert_distance(
+            Ray::new(Vec3::new(-1., -5., 5.), Vec3::Y).intersect_aabb(&aabb),
+            4.,
+        );
+        // Starting on an edge, moving diagonally out of the box.
+        assert_distance(
+            Ray::new(Vec3::new(1., 1., 5.), Vec3::new(1., 1., 0.)).intersect_aabb(&aabb),
+            0.,
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "non-zero")]
+    #[cfg(debug_assertions)]
+    fn new_rejects_zero_direction() {
+        Ray::new(Vec3::ZERO, Vec3::ZERO);
+    }
+
+    #[test]
+    fn intersect_triangle() {
+        let triangle = Triangle::new(
+            Vec3::new(-1., -1., 5.),
+            Vec3::new(1., -1., 5.),
+            Vec3::new(0., 1., 5.),
+        );
+
+        assert_distance(
+            Ray::new(Vec3::ZERO, Vec3::Z).intersect_triangle(&triangle),
+            5.,
+        );
+        // Both sides of the triangle are hit.
+        assert_distance(
+            Ray::new(Vec3::new(0., 0., 10.), -Vec3::Z).intersect_triangle(&triangle),
+            5.,
+        );
+        // Through a vertex and an edge.
+        assert_distance(
+            Ray::new(Vec3::new(-1., -1., 0.), Vec3::Z).intersect_triangle(&triangle),
+            5.,
+        );
+        assert_distance(
+            Ray::new(Vec3::new(0., -1., 0.), Vec3::Z).intersect_triangle(&triangle),
+            5.,
+        );
+        // Beside the triangle.
+        assert!(Ray::new(Vec3::new(0.9, 0.9, 0.), Vec3::Z)
+            .intersect_triangle(&triangle)
+            .is_none());
+        // Behind the origin.
+        assert!(Ray::new(Vec3::ZERO, -Vec3::Z)
+            .intersect_triangle(&triangle)
+            .is_none());
+        // Parallel to the triangle.
+        assert!(Ray::new(Vec3::new(-5., 0., 5.), Vec3::X)
+            .intersect_triangle(&triangle)
+            .is_none());
+    }
+}
@@ -0,0 +1,206 @@
+use crate::geometry::{AxisAlignedBoundingBox, BoundingSphere};
+use glam::Vec3;
+
+#[derive(Default, Copy, Clone, Debug, PartialEq)]
+pub struct Triangle {
+    pub a: Vec3,
+    pub b: Vec3,
+    pub c: Vec3,
+}
+
+impl Triangle {
+    pub fn new(
+        a: Vec3,
+        b: Vec3,
+        c: Vec3,
+    ) -> Self {
+        Triangle { a, b, c }
+    }
+
+    /// See "Real-Time Collision Detection", Christer Ericson, 2005, 5.1.5.
+    pub fn closest_point(
+        &self,
+        point: Vec3,
+    ) -> Vec3 {
+        let (a, b, c) = (self.a, self.b, self.c);
+        let ab = b - a;
+        let ac = c - a;
+
+        let ap = point - a;
+        let d1 = ab.dot(ap);
+        let d2 = ac.dot(ap);
+        if d1 <= 0. && d2 <= 0. {
+            return a;
+        }
+
+        let bp = point - b;
+        let d3 = ab.dot(bp);
+        let d4 = ac.dot(bp);
+        if d3 >= 0. && d4 <= d3 {
+            return b;
+        }
+
+        let vc = d1 * d4 - d3 * d2;
+        if vc <= 0. && d1 >= 0. && d3 <= 0. {
+            return a + ab * (d1 / (d1 - d3));
+        }
+
+        let cp = point - c;
+        let d5 = ab.dot(cp);
+        let d6 = ac.dot(cp);
+        if d6 >= 0. && d5 <= d6 {
+            return c;
+        }
+
+        let vb = d5 * d2 - d1 * d6;
+        if vb <= 0. && d2 >= 0. && d6 <= 0. {
+            return a + ac * (d2 / (d2 - d6));
+        }
+
+        let va = d3 * d6 - d5 * d4;
+        if va <= 0. && (d4 - d3) >= 0. && (d5 - d6) >= 0. {
+            return b + (c - b) * ((d4 - d3) / ((d4 - d3) + (d5 - d6)));
+        }
+
+        let denominator = 1. / (va + vb + vc);
+        a + ab * (vb * denominator) + ac * (vc * denominator)
+    }
+
+    pub fn intersects_sphere(
+        &self,
+        sphere: &BoundingSphere,
+    ) -> bool {
+        self.closest_point(sphere.position)
+            .distance_squared(sphere.position)
+            <= sphere.radius * sphere.radius
+    }
+
+    /// Separating axis test between the triangle and the box.
+    /// See "Fast 3D Triangle-Box Overlap Testing", Tomas Akenine-Möller, 2001.
+    pub fn intersects_aabb(
+        &self,
+        aabb: &AxisAlignedBoundingBox,
+    ) -> bool {
+        let center = aabb.center();
+        let half_extents = aabb.half_extents();
+        let v0 = self.a - center;
+        let v1 = self.b - center;
+        let v2 = self.c - center;
+
+        // The box's face normals.
+        if v0.min(v1).min(v2).cmpgt(half_extents).any()
+            || v0.max(v1).max(v2).cmplt(-half_extents).any()
+        {
+            return false;
+        }
+
+        let edges = [v1 - v0, v2 - v1, v0 - v2];
+
+        // The triangle's face normal.
+        let normal = edges[0].cross(edges[1]);
+        if normal.dot(v0).abs() > normal.abs().dot(half_extents) {
+            return false;
+        }
+
+        // The cross products of the box's face normals and the triangle's edges.
+        for edge in &edges {
+            for axis in &[Vec3::X, Vec3::Y, Vec3::Z] {
+                let axis = axis.cross(*edge);
+                let p0 = axis.dot(v0);
+                let p1 = axis.dot(v1);
+                let p2 = axis.dot(v2);
+                let radius = axis.abs().dot(half_extents);
+                if p0.min(p1).min(p2) > radius || p0.max(p1).max(p2) < -radius {
+                    return false;
+                }
+            }
+        }
+
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn triangle() -> Triangle {
+        Triangle::new(
+            Vec3::new(0., 0., 0.),
+            Vec3::new(4., 0., 0.),
+            Vec3::new(0., 4., 0.),
+        )
+    }
+
+    fn aabb(
+        center: Vec3,
+        half_extent: f32,
+    ) -> AxisAlignedBoundingBox {
+        AxisAlignedBoundingBox {
+            min: center - Vec3::splat(half_extent),
+            max: center + Vec3::splat(half_extent),
+        }
+    }
+
+    #[test]
+    fn closest_point_regions() {
+        let triangle = triangle();
+        let cases = [
+            // Inside the face.
+            (Vec3::new(1., 1., 3.), Vec3::new(1., 1., 0.)),
+            // Vertex regions.
+            (Vec3::new(-1., -1., 0.), triangle.a),
+            (Vec3::new(5., -1., 0.), triangle.b),
+            (Vec3::new(-1., 5., 2.), triangle.c),
+            // Edge regions.
+            (Vec3::new(2., -3., 0.), Vec3::new(2., 0., 0.)),
+            (Vec3::new(-3., 2., 0.), Vec3::new(0., 2., 0.)),
+            (Vec3::new(3., 3., 1.), Vec3::new(2., 2., 0.)),
+        ];
+
+        for (point, expected) in cases.iter() {
+            let closest = triangle.closest_point(*point);
+            assert!(
+                closest.distance(*expected) < 0.0001,
+                "closest point to {:?} is {:?}, expected {:?}",
+                point,
+                closest,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn intersects_sphere() {
+        let triangle = triangle();
+        assert!(triangle.intersects_sphere(&BoundingSphere::new(Vec3::new(1., 1., 0.5), 1.)));
+        assert!(triangle.intersects_sphere(&BoundingSphere::new(Vec3::new(3., 3., 0.), 1.5)));
+        assert!(!triangle.intersects_sphere(&BoundingSphere::new(Vec3::new(1., 1., 2.), 1.)));
+        assert!(!triangle.intersects_sphere(&BoundingSphere::new(Vec3::new(3., 3., 0.), 1.)));
+    }
+
+    #[test]
+    fn intersects_aabb() {
+        let triangle = triangle();
+
+        // Around a vertex, and containing the whole triangle.
+        assert!(triangle.intersects_aabb(&aabb(Vec3::ZERO, 0.5)));
+        assert!(triangle.intersects_aabb(&aabb(Vec3::ONE, 10.)));
+        // Inside the triangle's plane, through the face only.
+        assert!(triangle.intersects_aabb(&aabb(Vec3::new(1., 1., 0.), 0.1)));
+
+        // Separated by a box face normal.
+        assert!(!triangle.intersects_aabb(&aabb(Vec3::new(1., 1., 2.), 1.)));
+        // Separated by the triangle's normal. The box overlaps the triangle's AABB.
+        let tilted = Triangle::new(
+            Vec3::new(0., 0., 0.),
+            Vec3::new(4., 0., 4.),
+            Vec3::new(0., 4., 4.),
+        );
+        assert!(!tilted.intersects_aabb(&aabb(Vec3::new(1., 1., 4.), 0.5)));
+        assert!(tilted.intersects_aabb(&aabb(Vec3::new(1., 1., 2.), 0.5)));
+        // Separated by an edge cross product. The box is beyond the hypotenuse.
+        assert!(!triangle.intersects_aabb(&aabb(Vec3::new(3., 3., 0.), 0.9)));
+        assert!(triangle.intersects_aabb(&aabb(Vec3::new(3., 3., 0.), 1.1)));
+    }
+}
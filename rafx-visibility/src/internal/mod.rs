@@ -3,6 +3,9 @@ pub(crate) use zone::Zone;
 
 pub use crate::visibility_object::VisibilityObject;
 
+mod portal;
+pub(crate) use portal::{Portal, PortalViewer};
+
 mod volume;
 pub(crate) use volume::Volume;
 
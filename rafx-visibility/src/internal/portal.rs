@@ -0,0 +1,456 @@
+use crate::frustum_culling::MAX_PLANES;
+use crate::ZoneHandle;
+use glam::{Vec3, Vec4};
+
+// Distances below this are treated as lying on a plane.
+const PORTAL_EPSILON: f32 = 0.0001;
+
+/// A convex polygon connecting two `Zones`. A `ViewFrustum` in one `Zone` is able to see into the
+/// other `Zone` through the polygon. Portals are two-sided.
+pub struct Portal {
+    pub(crate) zones: Option<[ZoneHandle; 2]>,
+    pub(crate) polygon: Vec<Vec3>,
+    pub(crate) plane: Vec4,
+}
+
+/// The parts of a `ViewFrustum` that stay the same while looking through a chain of `Portals`.
+#[derive(Copy, Clone)]
+pub(crate) struct PortalViewer {
+    pub(crate) eye_position: Vec3,
+    /// The view direction of an orthographic `ViewFrustum`, or `None` for a perspective one.
+    pub(crate) view_direction: Option<Vec3>,
+    pub(crate) far_plane: Vec4,
+}
+
+impl Portal {
+    pub const MAX_VERTICES: usize = 16;
+
+    /// Returns a `Portal` without a polygon that does not connect any `Zones`.
+    pub fn new() -> Self {
+        Portal {
+            zones: None,
+            polygon: Vec::default(),
+            plane: Vec4::ZERO,
+        }
+    }
+
+    pub fn zones(&self) -> Option<[ZoneHandle; 2]> {
+        self.zones
+    }
+
+    pub fn polygon(&self) -> &[Vec3] {
+        &self.polygon
+    }
+
+    /// The `polygon` must be convex and planar, with vertices in either winding order.
+    pub fn set_polygon(
+        &mut self,
+        polygon: Vec<Vec3>,
+    ) {
+        assert!(polygon.len() >= 3 && polygon.len() <= Portal::MAX_VERTICES);
+
+        // Newell's method is robust to collinear vertices.
+        let mut normal = Vec3::ZERO;
+        for (index, current) in polygon.iter().enumerate() {
+            let next = polygon[(index + 1) % polygon.len()];
+            normal.x += (current.y - next.y) * (current.z + next.z);
+            normal.y += (current.z - next.z) * (current.x + next.x);
+            normal.z += (current.x - next.x) * (current.y + next.y);
+        }
+
+        let normal = normal.normalize();
+        self.plane = normal.extend(-normal.dot(polygon[0]));
+        self.polygon = polygon;
+    }
+
+    /// Returns the `Zone` on the other side of the `Portal` from `zone`.
+    pub fn other_zone(
+        &self,
+        zone: ZoneHandle,
+    ) -> Option<ZoneHandle> {
+        match self.zones {
+            Some([zone_a, zone_b]) if zone_a == zone => Some(zone_b),
+            Some([zone_a, zone_b]) if zone_b == zone => Some(zone_a),
+            _ => None,
+        }
+    }
+
+    /// Returns the planes bounding the view through the `Portal`, or `None` if the `Portal` is not
+    /// visible within `planes`. The polygon is clipped to `planes` before building the new planes,
+    /// so the result is never larger than the view it was seen from. A `view_direction` is used for
+    /// orthographic views, otherwise the planes pass through the `eye_position`.
+    pub(crate) fn clip_view(
+        &self,
+        eye_position: Vec3,
+        view_direction: Option<Vec3>,
+        planes: &[Vec4],
+        far_plane: Vec4,
+    ) -> Option<Vec<Vec4>> {
+        let clipped = clip_polygon(&self.polygon, planes);
+        if clipped.len() < 3 {
+            return None;
+        }
+
+        // Orient the portal's plane so that the view looks through it.
+        let portal_plane = if let Some(view_direction) = view_direction {
+            let facing = self.plane.truncate().dot(view_direction);
+            if facing.abs() < PORTAL_EPSILON {
+                return None;
+            }
+
+            if facing > 0. {
+                self.plane
+            } else {
+                -self.plane
+            }
+        } else {
+            let distance = plane_distance(self.plane, eye_position);
+            if distance.abs() < PORTAL_EPSILON {
+                // The eye is inside the portal, so it sees everything it did before.
+                return Some(planes.to_vec());
+            }
+
+            if distance < 0. {
+                self.plane
+            } else {
+                -self.plane
+            }
+        };
+
+        if clipped.len() + 2 > MAX_PLANES {
+            // Clipping added too many edges. Fall back to the unclipped view.
+            let mut result = planes.to_vec();
+            if result.len() < MAX_PLANES {
+                result.push(portal_plane);
+            }
+            return Some(result);
+        }
+
+        let centroid =
+            clipped.iter().fold(Vec3::ZERO, |sum, vertex| sum + *vertex) / clipped.len() as f32;
+
+        let mut result = Vec::with_capacity(clipped.len() + 2);
+        result.push(portal_plane);
+        result.push(far_plane);
+
+        for (index, current) in clipped.iter().enumerate() {
+            let next = clipped[(index + 1) % clipped.len()];
+            let normal = if let Some(view_direction) = view_direction {
+                (next - *current).cross(view_direction)
+            } else {
+                (*current - eye_position).cross(next - eye_position)
+            };
+
+            let length = normal.length();
+            if length < PORTAL_EPSILON {
+                continue;
+            }
+
+            let normal = normal / length;
+            let plane = normal.extend(-normal.dot(*current));
+            if plane_distance(plane, centroid) < 0. {
+                result.push(-plane);
+            } else {
+                result.push(plane);
+            }
+        }
+
+        Some(result)
+    }
+}
+
+#[inline(always)]
+fn plane_distance(
+    plane: Vec4,
+    point: Vec3,
+) -> f32 {
+    plane.w + plane.truncate().dot(point)
+}
+
+// Sutherland-Hodgman clipping. Keeps the part of the polygon inside all of the planes.
+fn clip_polygon(
+    polygon: &[Vec3],
+    planes: &[Vec4],
+) -> Vec<Vec3> {
+    let mut output = polygon.to_vec();
+    let mut input = Vec::with_capacity(output.len());
+
+    for plane in planes {
+        if output.is_empty() {
+            break;
+        }
+
+        std::mem::swap(&mut input, &mut output);
+        output.clear();
+
+        for (index, current) in input.iter().enumerate() {
+            let next = input[(index + 1) % input.len()];
+            let current_distance = plane_distance(*plane, *current);
+            let next_distance = plane_distance(*plane, next);
+
+            if current_distance >= 0. {
+                output.push(*current);
+            }
+
+            // A vertex on the plane is kept as-is, so only add a vertex for edges that cross it.
+            if (current_distance > 0. && next_distance < 0.)
+                || (current_distance < 0. && next_distance > 0.)
+            {
+                let t = current_distance / (current_distance - next_distance);
+                output.push(*current + (next - *current) * t);
+            }
+        }
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn square(z: f32) -> Vec<Vec3> {
+        vec![
+            Vec3::new(-1., -1., z),
+            Vec3::new(1., -1., z),
+            Vec3::new(1., 1., z),
+            Vec3::new(-1., 1., z),
+        ]
+    }
+
+    fn assert_same_polygon(
+        actual: &[Vec3],
+        expected: &[Vec3],
+    ) {
+        assert_eq!(actual.len(), expected.len(), "{:?}", actual);
+        for vertex in expected {
+            assert!(
+                actual
+                    .iter()
+                    .any(|other| other.distance(*vertex) < PORTAL_EPSILON),
+                "{:?} is missing from {:?}",
+                vertex,
+                actual
+            );
+        }
+    }
+
+    fn assert_inside(
+        planes: &[Vec4],
+        point: Vec3,
+    ) {
+        assert!(planes
+            .iter()
+            .all(|plane| plane_distance(*plane, point) > 0.));
+    }
+
+    fn assert_outside(
+        planes: &[Vec4],
+        point: Vec3,
+    ) {
+        assert!(planes
+            .iter()
+            .any(|plane| plane_distance(*plane, point) < 0.));
+    }
+
+    #[test]
+    fn clip_polygon_inside_is_unchanged() {
+        let polygon = square(0.);
+        let planes = [Vec4::new(1., 0., 0., 2.), Vec4::new(0., 1., 0., 2.)];
+        assert_same_polygon(&clip_polygon(&polygon, &planes), &polygon);
+        assert_same_polygon(&clip_polygon(&polygon, &[]), &polygon);
+    }
+
+    #[test]
+    fn clip_polygon_outside_is_empty() {
+        let planes = [Vec4::new(1., 0., 0., -2.)];
+        assert!(clip_polygon(&square(0.), &planes).is_empty());
+    }
+
+    #[test]
+    fn clip_polygon_by_one_plane() {
+        // Keeps x >= 0.
+        let planes = [Vec4::new(1., 0., 0., 0.)];
+        assert_same_polygon(
+            &clip_polygon(&square(0.), &planes),
+            &[
+                Vec3::new(0., -1., 0.),
+                Vec3::new(1., -1., 0.),
+                Vec3::new(1., 1., 0.),
+                Vec3::new(0., 1., 0.),
+            ],
+        );
+    }
+
+    #[test]
+    fn clip_polygon_corner_adds_a_vertex() {
+        // Keeps x + y <= 1, which cuts off the (1, 1) corner.
+        let normal = Vec3::new(-1., -1., 0.).normalize();
+        let planes = [normal.extend(1. / 2_f32.sqrt())];
+        assert_same_polygon(
+            &clip_polygon(&square(0.), &planes),
+            &[
+                Vec3::new(-1., -1., 0.),
+                Vec3::new(1., -1., 0.),
+                Vec3::new(1., 0., 0.),
+                Vec3::new(0., 1., 0.),
+                Vec3::new(-1., 1., 0.),
+            ],
+        );
+    }
+
+    #[test]
+    fn clip_polygon_by_several_planes() {
+        // Keeps 0 <= x <= 0.5 and y <= 0.
+        let planes = [
+            Vec4::new(1., 0., 0., 0.),
+            Vec4::new(-1., 0., 0., 0.5),
+            Vec4::new(0., -1., 0., 0.),
+        ];
+        assert_same_polygon(
+            &clip_polygon(&square(0.), &planes),
+            &[
+                Vec3::new(0., -1., 0.),
+                Vec3::new(0.5, -1., 0.),
+                Vec3::new(0.5, 0., 0.),
+                Vec3::new(0., 0., 0.),
+            ],
+        );
+    }
+
+    #[test]
+    fn clip_polygon_touching_a_vertex() {
+        // Keeps x >= 1. Only the edge on the plane remains, which is not a polygon.
+        let planes = [Vec4::new(1., 0., 0., -1.)];
+        assert!(clip_polygon(&square(0.), &planes).len() < 3);
+    }
+
+    #[test]
+    fn other_zone() {
+        use crate::ZoneHandle;
+        use slotmap::SlotMap;
+
+        let mut zones = SlotMap::<ZoneHandle, ()>::with_key();
+        let zone_a = zones.insert(());
+        let zone_b = zones.insert(());
+        let zone_c = zones.insert(());
+
+        let mut portal = Portal::new();
+        assert_eq!(portal.other_zone(zone_a), None);
+
+        portal.zones = Some([zone_a, zone_b]);
+        assert_eq!(portal.other_zone(zone_a), Some(zone_b));
+        assert_eq!(portal.other_zone(zone_b), Some(zone_a));
+        assert_eq!(portal.other_zone(zone_c), None);
+    }
+
+    #[test]
+    fn set_polygon_either_winding() {
+        let mut portal = Portal::new();
+        portal.set_polygon(square(2.));
+        assert!(plane_distance(portal.plane, Vec3::new(0.5, 0.5, 2.)).abs() < PORTAL_EPSILON);
+        assert!(portal.plane.truncate().abs().distance(Vec3::Z) < PORTAL_EPSILON);
+
+        let mut reversed = square(2.);
+        reversed.reverse();
+        portal.set_polygon(reversed);
+        assert!(plane_distance(portal.plane, Vec3::new(0.5, 0.5, 2.)).abs() < PORTAL_EPSILON);
+        assert!(portal.plane.truncate().abs().distance(Vec3::Z) < PORTAL_EPSILON);
+    }
+
+    #[test]
+    fn clip_view_perspective() {
+        let mut portal = Portal::new();
+        portal.set_polygon(square(-5.));
+
+        // Wide planes around the -Z axis. The eye looks through the portal.
+        let eye_position = Vec3::ZERO;
+        let planes = [
+            Vec4::new(1., 0., -1., 0.).normalize(),
+            Vec4::new(-1., 0., -1., 0.).normalize(),
+            Vec4::new(0., 1., -1., 0.).normalize(),
+            Vec4::new(0., -1., -1., 0.).normalize(),
+        ];
+        let far_plane = Vec4::new(0., 0., 1., 100.);
+
+        let view = portal
+            .clip_view(eye_position, None, &planes, far_plane)
+            .unwrap();
+        assert_eq!(view.len(), 6);
+        assert_inside(&view, Vec3::new(0., 0., -10.));
+        assert_inside(&view, Vec3::new(1.9, 1.9, -10.));
+        // Beside the portal.
+        assert_outside(&view, Vec3::new(2.5, 0., -10.));
+        assert_outside(&view, Vec3::new(0., -2.5, -10.));
+        // In front of the portal.
+        assert_outside(&view, Vec3::new(0., 0., -4.));
+        // Beyond the far plane.
+        assert_outside(&view, Vec3::new(0., 0., -101.));
+    }
+
+    #[test]
+    fn clip_view_is_narrowed_by_planes() {
+        let mut portal = Portal::new();
+        portal.set_polygon(square(-5.));
+
+        // Only the right half of the portal is inside the planes.
+        let planes = [Vec4::new(1., 0., 0., 0.)];
+        let far_plane = Vec4::new(0., 0., 1., 100.);
+        let view = portal
+            .clip_view(Vec3::ZERO, None, &planes, far_plane)
+            .unwrap();
+        assert_inside(&view, Vec3::new(1., 0., -10.));
+        assert_outside(&view, Vec3::new(-1., 0., -10.));
+    }
+
+    #[test]
+    fn clip_view_not_visible() {
+        let mut portal = Portal::new();
+        portal.set_polygon(square(-5.));
+        let far_plane = Vec4::new(0., 0., 1., 100.);
+
+        // The planes only see x >= 3, so the portal is not visible.
+        let planes = [Vec4::new(1., 0., 0., -3.)];
+        assert!(portal
+            .clip_view(Vec3::ZERO, None, &planes, far_plane)
+            .is_none());
+
+        // An orthographic view parallel to the portal does not see through it.
+        assert!(portal
+            .clip_view(Vec3::ZERO, Some(Vec3::X), &[], far_plane)
+            .is_none());
+    }
+
+    #[test]
+    fn clip_view_eye_on_portal() {
+        let mut portal = Portal::new();
+        portal.set_polygon(square(0.));
+        let planes = [Vec4::new(0., 0., -1., 0.)];
+        let far_plane = Vec4::new(0., 0., 1., 100.);
+
+        let view = portal
+            .clip_view(Vec3::ZERO, None, &planes, far_plane)
+            .unwrap();
+        assert_eq!(view, planes.to_vec());
+    }
+
+    #[test]
+    fn clip_view_orthographic() {
+        let mut portal = Portal::new();
+        portal.set_polygon(square(-5.));
+        let far_plane = Vec4::new(0., 0., 1., 100.);
+
+        // The eye is behind the portal when looking along +Z, so the portal's plane flips.
+        for (eye_position, view_direction, behind, beyond) in [
+            (Vec3::ZERO, -Vec3::Z, -4., -10.),
+            (Vec3::new(0., 0., -20.), Vec3::Z, -6., -3.),
+        ] {
+            let view = portal
+                .clip_view(eye_position, Some(view_direction), &[], far_plane)
+                .unwrap();
+            assert_inside(&view, Vec3::new(0.9, -0.9, beyond));
+            assert_outside(&view, Vec3::new(1.1, 0., beyond));
+            assert_outside(&view, Vec3::new(0., 0., behind));
+        }
+    }
+}
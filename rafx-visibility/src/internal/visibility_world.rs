@@ -1,19 +1,21 @@
-use crate::frustum_culling::PackedBoundingSphereChunk;
-use crate::geometry::{BoundingSphere, Transform};
-use crate::internal::{VisibilityObject, Volume, Zone};
+use crate::frustum_culling::{CullBox, PackedBoundingSphereChunk};
+use crate::geometry::{BoundingSphere, OrientedBoundingBox, Transform, Triangle};
+use crate::internal::{Portal, PortalViewer, VisibilityObject, Volume, Zone};
 use crate::{
-    DepthRange, ModelHandle, PolygonSoup, PolygonSoupIndex, ViewFrustum, ViewFrustumHandle,
-    VisibilityObjectHandle, VisibleBounds, VolumeHandle, ZoneHandle,
+    CustomParameters, DepthRange, ModelHandle, OrthographicParameters, PolygonSoup,
+    PolygonSoupIndex, PortalHandle, ViewFrustum, ViewFrustumHandle, VisibilityObjectHandle,
+    VisibleBounds, VolumeHandle, ZoneHandle,
 };
-use glam::Vec3;
+use glam::{Vec3, Vec4};
 use rustc_hash::FxHashMap;
 use slotmap::{DenseSlotMap, SecondaryMap, SlotMap};
-use std::ops::Mul;
 
 pub struct VisibilityWorldInternal {
     pub(crate) zones: DenseSlotMap<ZoneHandle, Zone>,
+    pub(crate) portals: SlotMap<PortalHandle, Portal>,
 
     pub(crate) models: SlotMap<ModelHandle, VisibleBounds>,
+    pub(crate) model_polygons: SecondaryMap<ModelHandle, PolygonSoup>,
     pub(crate) model_ref_counts: SecondaryMap<ModelHandle, u64>,
     pub(crate) model_hashes: FxHashMap<u64, ModelHandle>,
 
@@ -28,11 +30,16 @@ pub struct VisibilityWorldInternal {
 }
 
 impl VisibilityWorldInternal {
+    // Limits how many `Portals` a single query will look through.
+    pub const MAX_PORTAL_DEPTH: usize = 8;
+
     pub fn new() -> Self {
         VisibilityWorldInternal {
             zones: Default::default(),
+            portals: Default::default(),
 
             models: Default::default(),
+            model_polygons: Default::default(),
             model_ref_counts: Default::default(),
             model_hashes: Default::default(),
 
@@ -51,12 +58,14 @@ impl VisibilityWorldInternal {
     // --------
 
     /// A `Zone` contains `Objects` & `ViewFrustums`, similar to the concept of a `World` or `Layer` in a collision API.
-    /// Visibility queries can only traverse zones through a portal. Portals are unimplemented.
+    /// Visibility queries can only traverse zones through a `Portal`.
+    /// Each `Zone` keeps its objects in a dynamic bounding volume hierarchy so that large zones only
+    /// test objects near the `ViewFrustum`.
     pub fn new_zone(&mut self) -> ZoneHandle {
         self.zones.insert(Zone::new())
     }
 
-    /// All `Objects`, `ViewFrustums`, and `Volumes` must be removed from `Zone`.
+    /// All `Objects`, `ViewFrustums`, `Volumes`, and `Portals` must be removed from `Zone`.
     pub fn destroy_zone(
         &mut self,
         zone: ZoneHandle,
@@ -64,6 +73,104 @@ impl VisibilityWorldInternal {
         let removed = self.zones.remove(zone).unwrap();
         assert_eq!(removed.objects.len(), 0);
         assert_eq!(removed.volumes.len(), 0);
+        assert_eq!(removed.portals.len(), 0);
+    }
+
+    // --------
+    // Portals
+    // --------
+
+    /// Creates a new `Portal`. A `Portal` must connect two `Zones` and have a polygon to be seen through.
+    pub fn new_portal(&mut self) -> PortalHandle {
+        self.portals.insert(Portal::new())
+    }
+
+    pub fn portal(
+        &self,
+        handle: PortalHandle,
+    ) -> Option<&Portal> {
+        self.portals.get(handle)
+    }
+
+    /// Sets the `Portal`'s convex polygon, e.g. to follow a door that is opening. `Zones` do not have a
+    /// position, so the polygon is in the same space as the `Objects` and `ViewFrustums` of both `Zones`.
+    pub fn set_portal_polygon(
+        &mut self,
+        portal: PortalHandle,
+        polygon: Vec<Vec3>,
+    ) {
+        let portal = self.portals.get_mut(portal).unwrap();
+        portal.set_polygon(polygon);
+    }
+
+    /// Sets the two `Zones` connected by the `Portal`. A `ViewFrustum` in either `Zone` can see into the
+    /// other `Zone` through the `Portal`.
+    pub fn set_portal_zones(
+        &mut self,
+        portal: PortalHandle,
+        zones: Option<(ZoneHandle, ZoneHandle)>,
+    ) {
+        let handle = portal;
+        let portal = self.portals.get_mut(handle).unwrap();
+        if let Some(previous_zones) = portal.zones.take() {
+            for zone in &previous_zones {
+                if let Some(zone) = self.zones.get_mut(*zone) {
+                    zone.portals.retain(|other| *other != handle);
+                }
+            }
+        }
+
+        if let Some((zone_a, zone_b)) = zones {
+            assert_ne!(zone_a, zone_b);
+            portal.zones = Some([zone_a, zone_b]);
+            self.zones.get_mut(zone_a).unwrap().portals.push(handle);
+            self.zones.get_mut(zone_b).unwrap().portals.push(handle);
+        }
+    }
+
+    /// Destroying a `Portal` will also remove it from both of its `Zones`.
+    pub fn destroy_portal(
+        &mut self,
+        portal: PortalHandle,
+    ) {
+        self.set_portal_zones(portal, None);
+        self.portals.remove(portal).unwrap();
+    }
+
+    /// Collects the `Zones` visible from `zone` through its `Portals`, along with the planes of the view
+    /// through each chain of `Portals`. The `path` prevents cycles back into a `Zone` that is already being viewed.
+    pub(crate) fn collect_portal_views(
+        &self,
+        zone: ZoneHandle,
+        viewer: &PortalViewer,
+        planes: &[Vec4],
+        path: &mut Vec<ZoneHandle>,
+        portal_views: &mut Vec<(ZoneHandle, Vec<Vec4>)>,
+    ) {
+        if path.len() > VisibilityWorldInternal::MAX_PORTAL_DEPTH {
+            return;
+        }
+
+        for portal in &self.zones.get(zone).unwrap().portals {
+            let portal = self.portals.get(*portal).unwrap();
+            let other_zone = portal.other_zone(zone).unwrap();
+            if path.contains(&other_zone) {
+                continue;
+            }
+
+            if let Some(portal_planes) = portal.clip_view(
+                viewer.eye_position,
+                viewer.view_direction,
+                planes,
+                viewer.far_plane,
+            ) {
+                path.push(other_zone);
+                self.collect_portal_views(other_zone, viewer, &portal_planes, path, portal_views);
+                path.pop();
+
+                portal_views.push((other_zone, portal_planes));
+            }
+        }
     }
 
     // --------
@@ -112,30 +219,34 @@ impl VisibilityWorldInternal {
         );
     }
 
-    /// Sets `left`, `right`, `bottom`, `top`, `near_distance`, and `far_distance` for orthographic `ViewFrustum`.
+    /// Sets an `OrthographicParameters` projection for the `ViewFrustum`.
     pub fn set_view_frustum_orthographic(
         &mut self,
         view_frustum: ViewFrustumHandle,
-        left: f32,
-        right: f32,
-        bottom: f32,
-        top: f32,
-        near_distance: f32,
-        far_distance: f32,
-        depth_range: DepthRange,
+        parameters: OrthographicParameters,
     ) {
         let view_frustum = self.view_frustums.get_mut(view_frustum).unwrap();
         view_frustum.set_orthographic(
-            left,
-            right,
-            bottom,
-            top,
-            near_distance,
-            far_distance,
-            depth_range,
+            parameters.left(),
+            parameters.right(),
+            parameters.bottom(),
+            parameters.top(),
+            parameters.near_distance(),
+            parameters.far_distance(),
+            parameters.depth_range(),
         );
     }
 
+    /// Sets a `CustomParameters` projection for the `ViewFrustum`, e.g. an off-center or oblique projection.
+    pub fn set_view_frustum_custom(
+        &mut self,
+        view_frustum: ViewFrustumHandle,
+        parameters: CustomParameters,
+    ) {
+        let view_frustum = self.view_frustums.get_mut(view_frustum).unwrap();
+        view_frustum.set_custom(parameters);
+    }
+
     /// Sets the `ViewFrustum`'s `Zone`. A `ViewFrustum` must be in a `Zone` to query visibility.
     pub fn set_view_frustum_zone(
         &mut self,
@@ -189,13 +300,46 @@ impl VisibilityWorldInternal {
         self.models.get(handle)
     }
 
+    /// Returns the `PolygonSoup` of a `Model`, if the `Model` was created with `new_model_with_triangles`.
+    /// It is used for exact intersection tests in ray and overlap queries, and for occluders.
+    pub fn model_polygons(
+        &self,
+        handle: ModelHandle,
+    ) -> Option<&PolygonSoup> {
+        self.model_polygons.get(handle)
+    }
+
     /// Returns a handle to a `Model` created from `PolygonSoup`
     pub fn new_model(
         &mut self,
         polygons: PolygonSoup,
     ) -> ModelHandle {
         let hash = polygons.calculate_hash();
-        return if let Some(handle) = self.model_hashes.get(&hash) {
+        self.new_model_from_hash(hash, &polygons)
+    }
+
+    /// Returns a handle to a `Model` created from `PolygonSoup` that keeps the triangles. Objects with
+    /// this `Model` are tested exactly in ray and overlap queries and may be occluders. Use `new_model`
+    /// when only the bounds are needed, since the triangles are kept for the lifetime of the `Model`.
+    pub fn new_model_with_triangles(
+        &mut self,
+        polygons: PolygonSoup,
+    ) -> ModelHandle {
+        let hash = polygons.calculate_hash();
+        let handle = self.new_model_from_hash(hash, &polygons);
+        if !self.model_polygons.contains_key(handle) {
+            self.model_polygons.insert(handle, polygons);
+        }
+
+        handle
+    }
+
+    fn new_model_from_hash(
+        &mut self,
+        hash: u64,
+        polygons: &PolygonSoup,
+    ) -> ModelHandle {
+        if let Some(handle) = self.model_hashes.get(&hash) {
             // NOTE(dvd): Return the existing model.
             *handle
         } else {
@@ -203,7 +347,7 @@ impl VisibilityWorldInternal {
             let handle = self.models.insert(VisibleBounds::new(hash, polygons));
             self.model_hashes.insert(hash, handle);
             handle
-        };
+        }
     }
 
     /// Returns a handle to a `Model` created from `VisibleBounds`
@@ -267,6 +411,7 @@ impl VisibilityWorldInternal {
 
         let removed_model = self.models.remove(model).unwrap();
         self.model_hashes.remove(&removed_model.hash);
+        self.model_polygons.remove(model);
         true
     }
 
@@ -303,6 +448,9 @@ impl VisibilityWorldInternal {
             let chunk: &mut PackedBoundingSphereChunk = zone.chunks.get_mut(chunk_idx).unwrap();
 
             chunk.update_id(in_chunk_idx, id);
+
+            let leaf = *zone.tree_leaves.get(object.handle).unwrap();
+            zone.tree.update_id(leaf, id);
         }
     }
 
@@ -370,6 +518,17 @@ impl VisibilityWorldInternal {
         }
     }
 
+    /// Sets whether the `Object` is an occluder. Occluders are rasterized into an `OcclusionBuffer` to hide
+    /// the objects behind them. An occluder must have a cull `Model` created with `new_model_with_triangles`.
+    pub fn set_object_occluder(
+        &mut self,
+        object: VisibilityObjectHandle,
+        occluder: bool,
+    ) {
+        let object = self.objects.get_mut(object).unwrap();
+        object.occluder = occluder;
+    }
+
     /// Destroying an `Object` will also remove it from the `Zone`.
     /// This will **NOT** destroy the cull `Model`.
     pub fn destroy_object(
@@ -463,6 +622,50 @@ impl VisibilityWorldInternal {
     }
     */
 
+    /// Calls `visit` with each triangle of the `Object`'s cull `Model`, relative to the `Zone`'s position.
+    /// Returns `false` if the `Object` has no cull `Model` or the `Model` was not created with `new_model_with_triangles`.
+    pub(crate) fn for_each_object_triangle<F: FnMut(&Triangle) -> bool>(
+        &self,
+        object: VisibilityObjectHandle,
+        mut visit: F,
+    ) -> bool {
+        let object = self.objects.get(object).unwrap();
+        let polygons = match object
+            .cull_model
+            .and_then(|model| self.model_polygons.get(model))
+        {
+            Some(polygons) => polygons,
+            None => return false,
+        };
+
+        let transform = object.transform.unwrap_or_default().as_mat4();
+        for index in 0..polygons.triangle_count() {
+            let triangle = polygons.triangle(index);
+            let triangle = Triangle::new(
+                transform.transform_point3(triangle.a),
+                transform.transform_point3(triangle.b),
+                transform.transform_point3(triangle.c),
+            );
+
+            if !visit(&triangle) {
+                break;
+            }
+        }
+
+        true
+    }
+
+    /// Returns the `CullBox` of an `Object` in a `Zone`.
+    pub(crate) fn object_cull_box(
+        &self,
+        object: VisibilityObjectHandle,
+    ) -> Option<CullBox> {
+        let object = self.objects.get(object)?;
+        let zone = self.zones.get(object.zone?)?;
+        let (chunk_idx, in_chunk_idx) = *zone.objects.get(object.handle)?;
+        Some(*zone.chunks[chunk_idx].cull_box(in_chunk_idx))
+    }
+
     fn internal_add_object_to_zone(
         &mut self,
         object: VisibilityObjectHandle,
@@ -490,15 +693,18 @@ impl VisibilityWorldInternal {
         };
 
         let transform = object.transform.unwrap_or_default();
+        let bounding_sphere = VisibilityObject::default_bounding_sphere(transform);
+        let cull_box = CullBox::from_bounding_sphere(&bounding_sphere);
         let in_chunk_idx = next_chunk
-            .add(
-                object.handle,
-                object.id,
-                VisibilityObject::default_bounding_sphere(transform),
-            )
+            .add(object.handle, object.id, bounding_sphere, cull_box)
             .unwrap();
         zone.objects
             .insert(object.handle, (chunk_idx, in_chunk_idx));
+
+        let leaf = zone
+            .tree
+            .insert(object.handle, object.id, bounding_sphere, cull_box);
+        zone.tree_leaves.insert(object.handle, leaf);
     }
 
     fn internal_update_object_in_zone(
@@ -513,19 +719,37 @@ impl VisibilityWorldInternal {
         let chunk: &mut PackedBoundingSphereChunk = zone.chunks.get_mut(chunk_idx).unwrap();
 
         let transform = object.transform.unwrap_or_default();
-        if let Some(model) = object.cull_model {
+        let (bounding_sphere, cull_box) = if let Some(model) = object.cull_model {
             // We need to account for rotation/scaling because bounding sphere might not be at origin
             let model = self.models.get(model).unwrap();
-            let position = transform.translation
-                + transform.rotation.mul(model.bounding_sphere.position) * transform.scale;
+            let position = transform
+                .as_mat4()
+                .transform_point3(model.bounding_sphere.position);
             let radius = model.bounding_sphere.radius * transform.scale.abs().max_element();
-            chunk.update(in_chunk_idx, BoundingSphere::new(position, radius))
+
+            // `VisibleBounds` deserialized from older data may not have an OBB.
+            let obb = if model.obb.is_empty() {
+                OrientedBoundingBox::from_aabb(&model.aabb)
+            } else {
+                model.obb
+            };
+
+            (
+                BoundingSphere::new(position, radius),
+                CullBox::from_oriented_bounding_box(&obb, &transform),
+            )
         } else {
-            chunk.update(
-                in_chunk_idx,
-                VisibilityObject::default_bounding_sphere(transform),
-            );
-        }
+            let bounding_sphere = VisibilityObject::default_bounding_sphere(transform);
+            (
+                bounding_sphere,
+                CullBox::from_bounding_sphere(&bounding_sphere),
+            )
+        };
+
+        chunk.update(in_chunk_idx, bounding_sphere, cull_box);
+
+        let leaf = *zone.tree_leaves.get(object.handle).unwrap();
+        zone.tree.update(leaf, bounding_sphere, cull_box);
     }
 
     fn internal_remove_object_in_zone(
@@ -544,5 +768,8 @@ impl VisibilityWorldInternal {
             zone.objects
                 .insert(metadata.handle, (chunk_idx, in_chunk_idx));
         }
+
+        let leaf = zone.tree_leaves.remove(object.handle).unwrap();
+        zone.tree.remove(leaf);
     }
 }
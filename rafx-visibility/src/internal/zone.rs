@@ -1,20 +1,53 @@
-use crate::frustum_culling::PackedBoundingSphereChunk;
+use crate::frustum_culling::{
+    collect_visible_objects, collect_visible_objects_in_tree, DynamicAabbTree,
+    PackedBoundingSphereChunk,
+};
 use crate::internal::Volume;
-use crate::{VisibilityObjectHandle, VolumeHandle};
+use crate::{PortalHandle, VisibilityObjectHandle, VisibleObjects, VolumeHandle};
+use glam::{Vec3, Vec4};
 use slotmap::{SecondaryMap, SlotMap};
 
 pub struct Zone {
     pub(crate) chunks: Vec<PackedBoundingSphereChunk>,
     pub(crate) objects: SecondaryMap<VisibilityObjectHandle, (usize, usize)>,
+    pub(crate) tree: DynamicAabbTree,
+    pub(crate) tree_leaves: SecondaryMap<VisibilityObjectHandle, usize>,
     pub(crate) volumes: SlotMap<VolumeHandle, Volume>,
+    pub(crate) portals: Vec<PortalHandle>,
 }
 
 impl Zone {
+    // Below this many objects, a linear scan of the chunks is cheaper than traversing the tree.
+    pub const MIN_OBJECTS_FOR_TREE: usize = PackedBoundingSphereChunk::MAX_LEN;
+
     pub fn new() -> Self {
         Zone {
             chunks: Default::default(),
             objects: Default::default(),
+            tree: DynamicAabbTree::new(),
+            tree_leaves: Default::default(),
             volumes: Default::default(),
+            portals: Default::default(),
+        }
+    }
+
+    pub fn use_tree(&self) -> bool {
+        self.objects.len() >= Zone::MIN_OBJECTS_FOR_TREE
+    }
+
+    /// Collects the objects inside all of the `planes`, using the tree for large `Zones`.
+    pub(crate) fn collect_visible_objects(
+        &self,
+        view_frustum_position: Vec3,
+        planes: &[Vec4],
+        results: &mut VisibleObjects,
+    ) {
+        if self.use_tree() {
+            collect_visible_objects_in_tree(&self.tree, view_frustum_position, planes, results);
+        } else {
+            for chunk in &self.chunks {
+                collect_visible_objects(chunk, view_frustum_position, planes, results);
+            }
         }
     }
 }
@@ -1,5 +1,6 @@
 mod frustum_culling;
 mod internal;
+mod occlusion_culling;
 mod polygon_soup;
 mod projection;
 mod view_frustum;
@@ -9,6 +10,7 @@ mod visible_bounds;
 
 pub mod geometry;
 
+pub use occlusion_culling::OcclusionBuffer;
 pub use polygon_soup::*;
 pub use projection::*;
 pub use view_frustum::*;
@@ -0,0 +1,2 @@
+mod occlusion_buffer;
+pub use occlusion_buffer::OcclusionBuffer;
@@ -0,0 +1,409 @@
+use crate::frustum_culling::CullBox;
+use crate::geometry::Triangle;
+use crate::ViewFrustum;
+use glam::{Mat4, Vec2, Vec3};
+
+/// A small software depth buffer for a `ViewFrustum`. Occluder triangles are rasterized into the
+/// buffer, and objects whose bounds are behind the occluders in every pixel they cover are occluded.
+/// Each pixel stores the view-space depth of the nearest occluder.
+pub struct OcclusionBuffer {
+    width: usize,
+    height: usize,
+    depth: Vec<f32>,
+    view: Mat4,
+    view_projection: Mat4,
+    near_distance: f32,
+    triangle_count: usize,
+}
+
+impl OcclusionBuffer {
+    pub const DEFAULT_WIDTH: usize = 256;
+    pub const DEFAULT_HEIGHT: usize = 128;
+
+    // Limits the cost of rasterizing occluders for a single view.
+    pub const MAX_TRIANGLES: usize = 8192;
+
+    /// The buffer's memory isn't allocated until the first call to `clear`.
+    pub fn new(
+        width: usize,
+        height: usize,
+    ) -> Self {
+        OcclusionBuffer {
+            width,
+            height,
+            depth: Vec::default(),
+            view: Mat4::IDENTITY,
+            view_projection: Mat4::IDENTITY,
+            near_distance: 0.,
+            triangle_count: 0,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns the number of occluder triangles rasterized since the last call to `clear`.
+    pub fn triangle_count(&self) -> usize {
+        self.triangle_count
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.triangle_count >= OcclusionBuffer::MAX_TRIANGLES
+    }
+
+    /// Removes all occluders and sets up the buffer for the `view_frustum`'s current transform and projection.
+    pub fn clear(
+        &mut self,
+        view_frustum: &ViewFrustum,
+    ) {
+        let projection = view_frustum.get_projection();
+        self.view = Mat4::look_at_rh(
+            view_frustum.eye_position(),
+            view_frustum.look_at(),
+            view_frustum.up(),
+        );
+        self.view_projection = projection.as_rh_mat4() * self.view;
+        self.near_distance = projection.near_distance();
+        self.triangle_count = 0;
+        self.depth.clear();
+        self.depth.resize(self.width * self.height, f32::MAX);
+    }
+
+    /// Rasterizes an occluder `triangle`, relative to the `Zone`'s position. Triangles crossing the
+    /// near plane are skipped. Each triangle is written with the depth of its farthest vertex, so the
+    /// buffer never places an occluder nearer than it really is.
+    pub fn rasterize_triangle(
+        &mut self,
+        triangle: &Triangle,
+    ) {
+        if self.is_full() || self.depth.is_empty() {
+            return;
+        }
+
+        let a = self.project(triangle.a);
+        let b = self.project(triangle.b);
+        let c = self.project(triangle.c);
+        let (a, b, c) = match (a, b, c) {
+            (Some(a), Some(b), Some(c)) => (a, b, c),
+            _ => return,
+        };
+
+        self.triangle_count += 1;
+
+        let area = edge(a.0, b.0, c.0);
+        if area.abs() <= f32::EPSILON {
+            return;
+        }
+
+        let depth = a.1.max(b.1).max(c.1);
+        let min = a.0.min(b.0).min(c.0);
+        let max = a.0.max(b.0).max(c.0);
+        let (min_x, min_y, max_x, max_y) = match self.pixel_rect(min, max) {
+            Some(rect) => rect,
+            None => return,
+        };
+
+        for y in min_y..=max_y {
+            let row = y * self.width;
+            for x in min_x..=max_x {
+                let pixel = Vec2::new(x as f32 + 0.5, y as f32 + 0.5);
+                let w0 = edge(b.0, c.0, pixel) * area;
+                let w1 = edge(c.0, a.0, pixel) * area;
+                let w2 = edge(a.0, b.0, pixel) * area;
+                if w0 >= 0. && w1 >= 0. && w2 >= 0. {
+                    let texel = &mut self.depth[row + x];
+                    *texel = texel.min(depth);
+                }
+            }
+        }
+    }
+
+    /// Returns `true` if every pixel covered by the `cull_box` has an occluder in front of the box.
+    /// Boxes crossing the near plane or outside of the buffer are never occluded.
+    pub(crate) fn is_occluded(
+        &self,
+        cull_box: &CullBox,
+    ) -> bool {
+        if self.triangle_count == 0 || self.depth.is_empty() {
+            return false;
+        }
+
+        let mut min = Vec2::splat(f32::MAX);
+        let mut max = Vec2::splat(f32::MIN);
+        let mut nearest_depth = f32::MAX;
+        for corner in 0..8 {
+            let sign = |bit: usize| if corner & bit == 0 { -1. } else { 1. };
+            let point = cull_box.center
+                + cull_box.axes[0] * sign(1)
+                + cull_box.axes[1] * sign(2)
+                + cull_box.axes[2] * sign(4);
+
+            let (position, depth) = match self.project(point) {
+                Some(projected) => projected,
+                None => return false,
+            };
+
+            min = min.min(position);
+            max = max.max(position);
+            nearest_depth = nearest_depth.min(depth);
+        }
+
+        let (min_x, min_y, max_x, max_y) = match self.pixel_rect(min, max) {
+            Some(rect) => rect,
+            None => return false,
+        };
+
+        for y in min_y..=max_y {
+            let row = y * self.width;
+            if self.depth[row + min_x..=row + max_x]
+                .iter()
+                .any(|depth| *depth >= nearest_depth)
+            {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    // Returns the position in pixels and the view-space depth, or `None` if the point is in front of the near plane.
+    fn project(
+        &self,
+        point: Vec3,
+    ) -> Option<(Vec2, f32)> {
+        let depth = -self.view.transform_point3(point).z;
+        if depth < self.near_distance {
+            return None;
+        }
+
+        let clip = self.view_projection * point.extend(1.);
+        let ndc = clip.truncate().truncate() / clip.w;
+        let position = Vec2::new(
+            (ndc.x * 0.5 + 0.5) * self.width as f32,
+            (0.5 - ndc.y * 0.5) * self.height as f32,
+        );
+
+        Some((position, depth))
+    }
+
+    // Returns the inclusive range of pixels touched by the rect, or `None` if it's outside of the buffer.
+    fn pixel_rect(
+        &self,
+        min: Vec2,
+        max: Vec2,
+    ) -> Option<(usize, usize, usize, usize)> {
+        let size = Vec2::new(self.width as f32, self.height as f32);
+        if max.x < 0. || max.y < 0. || min.x >= size.x || min.y >= size.y {
+            return None;
+        }
+
+        let min = min.max(Vec2::ZERO);
+        let max = max.min(size - Vec2::ONE);
+        Some((
+            min.x as usize,
+            min.y as usize,
+            max.x as usize,
+            max.y as usize,
+        ))
+    }
+}
+
+fn edge(
+    a: Vec2,
+    b: Vec2,
+    point: Vec2,
+) -> f32 {
+    (b.x - a.x) * (point.y - a.y) - (b.y - a.y) * (point.x - a.x)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DepthRange;
+
+    // An orthographic view down -Z where each pixel covers one unit. Pixel `x` spans
+    // `x - 8..x - 7` in world space, and pixel `y` spans `3 - y..4 - y`.
+    fn buffer() -> OcclusionBuffer {
+        let view_frustum = ViewFrustum::new_orthographic(
+            Vec3::ZERO,
+            -Vec3::Z,
+            Vec3::Y,
+            -8.,
+            8.,
+            -4.,
+            4.,
+            0.1,
+            100.,
+            DepthRange::Normal,
+        );
+        let mut buffer = OcclusionBuffer::new(16, 8);
+        buffer.clear(&view_frustum);
+        buffer
+    }
+
+    fn rasterize_quad(
+        buffer: &mut OcclusionBuffer,
+        min: Vec3,
+        max: Vec3,
+    ) {
+        let lb = Vec3::new(min.x, min.y, min.z);
+        let rb = Vec3::new(max.x, min.y, min.z);
+        let lt = Vec3::new(min.x, max.y, max.z);
+        let rt = Vec3::new(max.x, max.y, max.z);
+        buffer.rasterize_triangle(&Triangle::new(lb, rb, rt));
+        // The second triangle has the opposite winding.
+        buffer.rasterize_triangle(&Triangle::new(lb, lt, rt));
+    }
+
+    fn cull_box(
+        center: Vec3,
+        half_extents: Vec3,
+    ) -> CullBox {
+        CullBox {
+            center,
+            axes: [
+                Vec3::X * half_extents.x,
+                Vec3::Y * half_extents.y,
+                Vec3::Z * half_extents.z,
+            ],
+        }
+    }
+
+    #[test]
+    fn nothing_is_occluded_without_occluders() {
+        let mut buffer = OcclusionBuffer::new(16, 8);
+        let cull_box = cull_box(Vec3::new(0., 0., -20.), Vec3::ONE);
+        assert!(!buffer.is_occluded(&cull_box));
+
+        // Rasterizing before `clear` is ignored.
+        buffer.rasterize_triangle(&Triangle::new(
+            Vec3::new(-8., -4., -10.),
+            Vec3::new(8., -4., -10.),
+            Vec3::new(0., 4., -10.),
+        ));
+        assert_eq!(buffer.triangle_count(), 0);
+
+        assert!(!self::buffer().is_occluded(&cull_box));
+    }
+
+    #[test]
+    fn occluded_behind_both_windings() {
+        let mut buffer = buffer();
+        rasterize_quad(
+            &mut buffer,
+            Vec3::new(-8., -4., -10.),
+            Vec3::new(0., 4., -10.),
+        );
+        assert_eq!(buffer.triangle_count(), 2);
+
+        assert!(buffer.is_occluded(&cull_box(Vec3::new(-4., 2., -20.), Vec3::ONE)));
+        assert!(buffer.is_occluded(&cull_box(Vec3::new(-4., -2., -20.), Vec3::ONE)));
+        // In front of the occluder, or beside it.
+        assert!(!buffer.is_occluded(&cull_box(Vec3::new(-4., 0., -5.), Vec3::ONE)));
+        assert!(!buffer.is_occluded(&cull_box(Vec3::new(4., 0., -20.), Vec3::ONE)));
+    }
+
+    #[test]
+    fn depth_test_edge_cases() {
+        let mut buffer = buffer();
+        rasterize_quad(
+            &mut buffer,
+            Vec3::new(-8., -4., -10.),
+            Vec3::new(8., 4., -10.),
+        );
+
+        // Crossing the occluder, touching it from behind, and just behind it.
+        assert!(!buffer.is_occluded(&cull_box(Vec3::new(0., 0., -10.), Vec3::ONE)));
+        assert!(!buffer.is_occluded(&cull_box(Vec3::new(0., 0., -11.), Vec3::ONE)));
+        assert!(buffer.is_occluded(&cull_box(Vec3::new(0., 0., -11.1), Vec3::ONE)));
+
+        // Crossing the near plane, and entirely outside of the buffer.
+        assert!(!buffer.is_occluded(&cull_box(Vec3::new(0., 0., 0.), Vec3::ONE)));
+        assert!(!buffer.is_occluded(&cull_box(Vec3::new(20., 0., -20.), Vec3::ONE)));
+
+        // Only the part of the box inside the buffer can be seen.
+        assert!(buffer.is_occluded(&cull_box(Vec3::new(8., 0., -20.), Vec3::ONE)));
+    }
+
+    #[test]
+    fn sloped_occluders_use_the_farthest_depth() {
+        let mut buffer = buffer();
+        rasterize_quad(
+            &mut buffer,
+            Vec3::new(-8., -4., -10.),
+            Vec3::new(8., 4., -20.),
+        );
+
+        // Behind the near half of the occluder, but in front of its farthest point.
+        assert!(!buffer.is_occluded(&cull_box(Vec3::new(0., -3., -16.), Vec3::splat(0.5))));
+        assert!(buffer.is_occluded(&cull_box(Vec3::new(0., -3., -21.), Vec3::splat(0.5))));
+    }
+
+    #[test]
+    fn occluders_only_cover_pixel_centers() {
+        // The occluder's right edge at x = -0.4 covers the center of pixel 7 at x = -0.5.
+        let mut buffer = buffer();
+        rasterize_quad(
+            &mut buffer,
+            Vec3::new(-8., -4., -10.),
+            Vec3::new(-0.4, 4., -10.),
+        );
+        assert!(buffer.is_occluded(&cull_box(Vec3::new(-1.5, 0., -20.), Vec3::new(0.9, 1., 1.))));
+
+        // At x = -0.6 it doesn't, so a box reaching into pixel 7 isn't occluded.
+        let mut buffer = self::buffer();
+        rasterize_quad(
+            &mut buffer,
+            Vec3::new(-8., -4., -10.),
+            Vec3::new(-0.6, 4., -10.),
+        );
+        assert!(!buffer.is_occluded(&cull_box(Vec3::new(-1.5, 0., -20.), Vec3::new(0.9, 1., 1.))));
+        assert!(buffer.is_occluded(&cull_box(Vec3::new(-3., 0., -20.), Vec3::new(0.9, 1., 1.))));
+
+        // A box whose edge lands exactly on a pixel boundary also tests the next pixel.
+        let mut buffer = self::buffer();
+        rasterize_quad(
+            &mut buffer,
+            Vec3::new(-8., -4., -10.),
+            Vec3::new(0., 4., -10.),
+        );
+        assert!(!buffer.is_occluded(&cull_box(Vec3::new(-1., 0., -20.), Vec3::ONE)));
+    }
+
+    #[test]
+    fn skipped_triangles() {
+        let mut buffer = buffer();
+
+        // A triangle crossing the near plane isn't counted or rasterized.
+        buffer.rasterize_triangle(&Triangle::new(
+            Vec3::new(-8., -4., -10.),
+            Vec3::new(8., -4., -10.),
+            Vec3::new(0., 4., 1.),
+        ));
+        assert_eq!(buffer.triangle_count(), 0);
+
+        // A triangle seen edge-on is counted, but covers no pixels.
+        buffer.rasterize_triangle(&Triangle::new(
+            Vec3::new(-8., 0., -10.),
+            Vec3::new(8., 0., -10.),
+            Vec3::new(0., 0., -20.),
+        ));
+        assert_eq!(buffer.triangle_count(), 1);
+        assert!(!buffer.is_occluded(&cull_box(Vec3::new(0., 0., -30.), Vec3::splat(0.4))));
+
+        for _ in 0..OcclusionBuffer::MAX_TRIANGLES {
+            buffer.rasterize_triangle(&Triangle::new(
+                Vec3::new(-8., -4., -10.),
+                Vec3::new(8., -4., -10.),
+                Vec3::new(0., 4., -10.),
+            ));
+        }
+        assert!(buffer.is_full());
+        assert_eq!(buffer.triangle_count(), OcclusionBuffer::MAX_TRIANGLES);
+    }
+}
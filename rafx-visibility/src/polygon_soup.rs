@@ -1,3 +1,4 @@
+use crate::geometry::Triangle;
 use glam::Vec3;
 use std::collections::hash_map::DefaultHasher;
 use std::hash::{Hash, Hasher};
@@ -46,4 +47,38 @@ impl PolygonSoup {
         self.hash(&mut hash);
         hash.finish()
     }
+
+    pub fn triangle_count(&self) -> usize {
+        match &self.index {
+            PolygonSoupIndex::None => self.vertex_positions.len() / 3,
+            PolygonSoupIndex::Indexed16(indices) => indices.len() / 3,
+            PolygonSoupIndex::Indexed32(indices) => indices.len() / 3,
+        }
+    }
+
+    pub fn triangle(
+        &self,
+        index: usize,
+    ) -> Triangle {
+        let first = index * 3;
+        let (a, b, c) = match &self.index {
+            PolygonSoupIndex::None => (first, first + 1, first + 2),
+            PolygonSoupIndex::Indexed16(indices) => (
+                indices[first] as usize,
+                indices[first + 1] as usize,
+                indices[first + 2] as usize,
+            ),
+            PolygonSoupIndex::Indexed32(indices) => (
+                indices[first] as usize,
+                indices[first + 1] as usize,
+                indices[first + 2] as usize,
+            ),
+        };
+
+        Triangle::new(
+            self.vertex_positions[a],
+            self.vertex_positions[b],
+            self.vertex_positions[c],
+        )
+    }
 }
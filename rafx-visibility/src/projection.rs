@@ -1,11 +1,12 @@
 use crate::geometry::{Frustum, Plane};
 use crate::ViewFrustum;
-use glam::Mat4;
+use glam::{Mat4, Vec3, Vec4};
 
 #[derive(Clone, Debug, PartialEq)]
 pub enum Projection {
     Perspective(PerspectiveParameters),
     Orthographic(OrthographicParameters),
+    Custom(CustomParameters),
     Undefined,
 }
 
@@ -28,6 +29,7 @@ impl Projection {
         match &self {
             Projection::Perspective(parameters) => parameters.depth_range,
             Projection::Orthographic(parameters) => parameters.depth_range,
+            Projection::Custom(parameters) => parameters.depth_range,
             _ => {
                 panic!("`Undefined` is not a Projection.");
             }
@@ -38,6 +40,7 @@ impl Projection {
         match &self {
             Projection::Perspective(parameters) => parameters.near_distance,
             Projection::Orthographic(parameters) => parameters.near_distance,
+            Projection::Custom(parameters) => parameters.near_distance,
             _ => {
                 panic!("`Undefined` is not a Projection.");
             }
@@ -48,6 +51,7 @@ impl Projection {
         match &self {
             Projection::Perspective(parameters) => parameters.far_distance,
             Projection::Orthographic(parameters) => parameters.far_distance,
+            Projection::Custom(parameters) => parameters.far_distance,
             _ => {
                 panic!("`Undefined` is not a Projection.");
             }
@@ -91,6 +95,7 @@ impl Projection {
                 near,
                 far,
             ),
+            Projection::Custom(parameters) => parameters.rh_matrix,
             _ => {
                 panic!("`Undefined` is not a Projection.");
             }
@@ -134,6 +139,22 @@ impl Projection {
                 near,
                 far,
             ),
+            // A left-handed view space is the right-handed view space with z flipped.
+            Projection::Custom(parameters) => {
+                parameters.rh_matrix * Mat4::from_scale(Vec3::new(1., 1., -1.))
+            }
+            _ => {
+                panic!("`Undefined` is not a Projection.");
+            }
+        }
+    }
+
+    /// Returns `true` if the projection has parallel sides, like an `Orthographic` projection.
+    pub fn is_orthographic(&self) -> bool {
+        match &self {
+            Projection::Perspective(_) => false,
+            Projection::Orthographic(_) => true,
+            Projection::Custom(parameters) => parameters.is_orthographic(),
             _ => {
                 panic!("`Undefined` is not a Projection.");
             }
@@ -162,6 +183,9 @@ impl UpdateFrustum for Projection {
             Projection::Orthographic(parameters) => {
                 parameters.update_frustum(view_frustum, frustum);
             }
+            Projection::Custom(parameters) => {
+                parameters.update_frustum(view_frustum, frustum);
+            }
             _ => {
                 panic!("Call `set_perspective` or `set_orthographic` prior to calling `update_frustum`.");
             }
@@ -352,3 +376,278 @@ impl UpdateFrustum for PerspectiveParameters {
         frustum.planes[ViewFrustum::RIGHT] = Plane::new(normal, near_center + x * half_near_w);
     }
 }
+
+/// A projection defined by a right-handed projection matrix with a `[0, 1]` depth range, like the
+/// matrices returned by `Projection::as_rh_mat4`. Use this for projections that `Perspective` and
+/// `Orthographic` can't describe, like off-center or oblique projections. The frustum's planes are
+/// extracted from the matrix, so culling matches what the GPU will clip.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CustomParameters {
+    rh_matrix: Mat4,
+    near_distance: f32,
+    far_distance: f32,
+    depth_range: DepthRange,
+}
+
+impl CustomParameters {
+    /// The `near_distance` and `far_distance` must match the `rh_matrix`.
+    pub fn new(
+        rh_matrix: Mat4,
+        near_distance: f32,
+        far_distance: f32,
+        depth_range: DepthRange,
+    ) -> Self {
+        CustomParameters {
+            rh_matrix,
+            near_distance,
+            far_distance,
+            depth_range,
+        }
+    }
+
+    /// An asymmetric perspective projection, e.g. for VR or tiled rendering. `left`, `right`,
+    /// `bottom`, and `top` are the extents of the near plane.
+    pub fn off_center_perspective(
+        left: f32,
+        right: f32,
+        bottom: f32,
+        top: f32,
+        near_distance: f32,
+        far_distance: f32,
+        depth_range: DepthRange,
+    ) -> Self {
+        let n = near_distance;
+        let f = far_distance;
+        let (z_scale, z_offset) = match depth_range {
+            DepthRange::Normal => (f / (n - f), n * f / (n - f)),
+            DepthRange::Reverse => (n / (f - n), n * f / (f - n)),
+            DepthRange::Infinite => (-1., -n),
+            DepthRange::InfiniteReverse => (0., n),
+        };
+
+        let rh_matrix = Mat4::from_cols(
+            Vec4::new(2. * n / (right - left), 0., 0., 0.),
+            Vec4::new(0., 2. * n / (top - bottom), 0., 0.),
+            Vec4::new(
+                (right + left) / (right - left),
+                (top + bottom) / (top - bottom),
+                z_scale,
+                -1.,
+            ),
+            Vec4::new(0., 0., z_offset, 0.),
+        );
+
+        CustomParameters::new(rh_matrix, near_distance, far_distance, depth_range)
+    }
+
+    /// Replaces the near plane with `clip_plane`, given in view space and facing the visible side.
+    /// Used for planar reflections and portals to clip geometry behind the reflecting surface.
+    /// See "Oblique View Frustum Depth Projection and Clipping", Eric Lengyel, 2005.
+    pub fn with_oblique_near_plane(
+        mut self,
+        clip_plane: Vec4,
+    ) -> Self {
+        // The far corner of the frustum opposite the clip plane.
+        let far_depth = match self.depth_range {
+            DepthRange::Normal => 1.,
+            DepthRange::Reverse => 0.,
+            _ => {
+                panic!("An oblique near plane requires a finite far plane.");
+            }
+        };
+
+        let corner = self.rh_matrix.inverse()
+            * Vec4::new(clip_plane.x.signum(), clip_plane.y.signum(), far_depth, 1.);
+
+        let mut rows = self.rh_matrix.transpose();
+        let scaled_plane = clip_plane * (rows.w_axis.dot(corner) / clip_plane.dot(corner));
+        rows.z_axis = match self.depth_range {
+            DepthRange::Normal => scaled_plane,
+            _ => rows.w_axis - scaled_plane,
+        };
+
+        self.rh_matrix = rows.transpose();
+        self
+    }
+
+    pub fn rh_matrix(&self) -> Mat4 {
+        self.rh_matrix
+    }
+
+    pub fn near_distance(&self) -> f32 {
+        self.near_distance
+    }
+
+    pub fn far_distance(&self) -> f32 {
+        self.far_distance
+    }
+
+    pub fn depth_range(&self) -> DepthRange {
+        self.depth_range
+    }
+
+    pub fn is_orthographic(&self) -> bool {
+        self.rh_matrix.transpose().w_axis == Vec4::W
+    }
+}
+
+impl UpdateFrustum for CustomParameters {
+    fn update_frustum(
+        &self,
+        view_frustum: &ViewFrustum,
+        frustum: &mut Frustum,
+    ) {
+        let view = Mat4::look_at_rh(
+            view_frustum.eye_position(),
+            view_frustum.look_at(),
+            view_frustum.up(),
+        );
+
+        // Extract the planes from the rows of the view-projection matrix.
+        // See "Fast Extraction of Viewing Frustum Planes from the World-View-Projection Matrix", Gribb & Hartmann, 2001.
+        let rows = (self.rh_matrix * view).transpose();
+        let plane = |normal: Vec4| {
+            let length = normal.truncate().length();
+            if length > f32::EPSILON {
+                Plane {
+                    normal: normal / length,
+                }
+            } else {
+                // An infinite far plane doesn't cull anything.
+                Plane { normal: Vec4::W }
+            }
+        };
+
+        let (near, far) = match self.depth_range {
+            DepthRange::Normal | DepthRange::Infinite => (rows.z_axis, rows.w_axis - rows.z_axis),
+            DepthRange::Reverse | DepthRange::InfiniteReverse => {
+                (rows.w_axis - rows.z_axis, rows.z_axis)
+            }
+        };
+
+        frustum.planes[ViewFrustum::NEAR] = plane(near);
+        frustum.planes[ViewFrustum::FAR] = plane(far);
+        frustum.planes[ViewFrustum::LEFT] = plane(rows.w_axis + rows.x_axis);
+        frustum.planes[ViewFrustum::RIGHT] = plane(rows.w_axis - rows.x_axis);
+        frustum.planes[ViewFrustum::BOTTOM] = plane(rows.w_axis + rows.y_axis);
+        frustum.planes[ViewFrustum::TOP] = plane(rows.w_axis - rows.y_axis);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EPSILON: f32 = 0.0001;
+
+    fn frustum(
+        projection: Projection,
+        eye_position: Vec3,
+        look_at: Vec3,
+    ) -> Frustum {
+        let mut view_frustum = ViewFrustum::empty();
+        match projection {
+            Projection::Perspective(parameters) => view_frustum.set_perspective(
+                parameters.fov_y_radians(),
+                parameters.ratio(),
+                parameters.near_distance(),
+                parameters.far_distance(),
+                parameters.depth_range(),
+            ),
+            Projection::Custom(parameters) => view_frustum.set_custom(parameters),
+            _ => unreachable!(),
+        }
+        view_frustum.set_transforms(eye_position, look_at, Vec3::Y);
+        view_frustum.acquire_frustum()
+    }
+
+    #[test]
+    fn custom_matches_perspective() {
+        for depth_range in [DepthRange::Normal, DepthRange::Reverse] {
+            let perspective = PerspectiveParameters::new(1.2, 1.5, 0.5, 100., depth_range);
+            let custom = CustomParameters::new(
+                Projection::Perspective(perspective.clone()).as_rh_mat4(),
+                0.5,
+                100.,
+                depth_range,
+            );
+
+            let eye_position = Vec3::new(3., 4., 5.);
+            let look_at = Vec3::new(-2., 1., -7.);
+            let expected = frustum(Projection::Perspective(perspective), eye_position, look_at);
+            let actual = frustum(Projection::Custom(custom), eye_position, look_at);
+
+            for (expected, actual) in expected.planes.iter().zip(actual.planes.iter()) {
+                // The far plane's distance is large, so compare it relative to its size.
+                let tolerance = EPSILON * expected.normal.w.abs().max(1.);
+                assert!(
+                    expected
+                        .get_normal()
+                        .abs_diff_eq(actual.get_normal(), EPSILON)
+                        && (expected.normal.w - actual.normal.w).abs() < tolerance,
+                    "{:?} != {:?}",
+                    expected,
+                    actual
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn custom_off_center_perspective() {
+        for depth_range in [
+            DepthRange::Normal,
+            DepthRange::Reverse,
+            DepthRange::Infinite,
+            DepthRange::InfiniteReverse,
+        ] {
+            // The near plane spans x in [0, 2] and y in [-1, 1], so the frustum only
+            // contains points to the right of the view direction.
+            let custom =
+                CustomParameters::off_center_perspective(0., 2., -1., 1., 1., 50., depth_range);
+            assert!(!custom.is_orthographic());
+            let frustum = frustum(Projection::Custom(custom), Vec3::ZERO, -Vec3::Z);
+
+            assert!(frustum.contains_point(Vec3::new(1., 0., -2.)));
+            assert!(frustum.contains_point(Vec3::new(3.9, 1.9, -2.)));
+            assert!(!frustum.contains_point(Vec3::new(-0.1, 0., -2.)));
+            assert!(!frustum.contains_point(Vec3::new(4.1, 0., -2.)));
+            assert!(!frustum.contains_point(Vec3::new(1., 2.1, -2.)));
+            assert!(!frustum.contains_point(Vec3::new(0.5, 0., -0.9)));
+
+            let far_point = Vec3::new(1., 0., -60.);
+            let infinite = matches!(
+                depth_range,
+                DepthRange::Infinite | DepthRange::InfiniteReverse
+            );
+            assert_eq!(frustum.contains_point(far_point), infinite);
+            assert!(frustum.contains_point(Vec3::new(1., 0., -49.)));
+        }
+    }
+
+    #[test]
+    fn custom_oblique_near_plane() {
+        for depth_range in [DepthRange::Normal, DepthRange::Reverse] {
+            // A tilted clip plane in view space through (0, 0, -5), facing away from the eye.
+            let normal = Vec3::new(0.5, 0., -1.).normalize();
+            let clip_plane = normal.extend(-normal.dot(Vec3::new(0., 0., -5.)));
+            let custom =
+                CustomParameters::off_center_perspective(-1., 1., -1., 1., 1., 50., depth_range)
+                    .with_oblique_near_plane(clip_plane);
+
+            let frustum = frustum(Projection::Custom(custom), Vec3::ZERO, -Vec3::Z);
+            assert!(frustum.planes[ViewFrustum::NEAR]
+                .normal
+                .abs_diff_eq(clip_plane, EPSILON));
+
+            assert!(frustum.contains_point(Vec3::new(0., 0., -5.5)));
+            assert!(!frustum.contains_point(Vec3::new(0., 0., -4.5)));
+            // The tilt moves the plane closer on the right and further on the left.
+            assert!(frustum.contains_point(Vec3::new(2., 0., -4.5)));
+            assert!(!frustum.contains_point(Vec3::new(-2., 0., -5.5)));
+            // The sides are unchanged.
+            assert!(!frustum.contains_point(Vec3::new(0., 7., -6.)));
+            assert!(!frustum.contains_point(Vec3::new(-7., 0., -6.)));
+        }
+    }
+}
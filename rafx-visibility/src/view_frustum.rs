@@ -1,5 +1,8 @@
 use crate::geometry::Frustum;
-use crate::{DepthRange, OrthographicParameters, PerspectiveParameters, Projection, UpdateFrustum};
+use crate::{
+    CustomParameters, DepthRange, OrthographicParameters, PerspectiveParameters, Projection,
+    UpdateFrustum,
+};
 use glam::Vec3;
 
 #[derive(Clone, Debug)]
@@ -119,6 +122,13 @@ impl ViewFrustum {
         ));
     }
 
+    pub fn set_custom(
+        &mut self,
+        parameters: CustomParameters,
+    ) {
+        self.projection = Projection::Custom(parameters);
+    }
+
     pub fn set_transforms(
         &mut self,
         eye_position: Vec3,
@@ -9,6 +9,8 @@ pub struct VisibilityObject {
     pub id: u64,
     pub zone: Option<ZoneHandle>,
     pub cull_model: Option<ModelHandle>,
+    // Occluders hide other objects from view when occlusion culling is used
+    pub occluder: bool,
     pub transform: Option<Transform>,
     // This is updated before processing commands in VisibilityWorld::update
     pub previous_frame_transform: Option<Transform>,
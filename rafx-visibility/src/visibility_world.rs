@@ -1,9 +1,10 @@
-use crate::frustum_culling::collect_visible_objects;
-use crate::geometry::{BoundingSphere, Transform};
-use crate::internal::VisibilityWorldInternal;
-use crate::Projection;
+use crate::frustum_culling::frustum_planes;
+use crate::geometry::{AxisAlignedBoundingBox, BoundingSphere, Ray, Transform};
+use crate::internal::{PortalViewer, VisibilityWorldInternal};
+use crate::{OcclusionBuffer, Projection, ViewFrustum};
 use crossbeam_channel::{unbounded, Receiver, Sender};
-use glam::Vec3;
+use glam::{Vec3, Vec4};
+use rustc_hash::FxHashSet;
 use slotmap::new_key_type;
 use std::hash::Hash;
 
@@ -21,17 +22,22 @@ new_key_type! { pub struct ZoneHandle; }
 new_key_type! { pub struct VisibilityObjectHandle; }
 new_key_type! { pub struct ViewFrustumHandle; }
 new_key_type! { pub struct VolumeHandle; }
+new_key_type! { pub struct PortalHandle; }
 
 pub enum AsyncCommand {
     SetObjectTransform(VisibilityObjectHandle, Transform),
     SetObjectZone(VisibilityObjectHandle, Option<ZoneHandle>),
     SetObjectId(VisibilityObjectHandle, u64),
     SetObjectCullModel(VisibilityObjectHandle, Option<ModelHandle>),
+    SetObjectOccluder(VisibilityObjectHandle, bool),
     SetViewFrustumZone(ViewFrustumHandle, Option<ZoneHandle>),
     SetViewFrustumTransforms(ViewFrustumHandle, Vec3, Vec3, Vec3),
     SetViewFrustumId(ViewFrustumHandle, u64),
     SetViewFrustumProjection(ViewFrustumHandle, Projection),
+    SetPortalPolygon(PortalHandle, Vec<Vec3>),
+    SetPortalZones(PortalHandle, Option<(ZoneHandle, ZoneHandle)>),
     DestroyViewFrustum(ViewFrustumHandle),
+    DestroyPortal(PortalHandle),
     DestroyZone(ZoneHandle),
     DestroyObject(VisibilityObjectHandle),
     DestroyModel(ModelHandle),
@@ -62,6 +68,18 @@ impl<T> VisibilityResult<T> {
     }
 }
 
+/// The result of a ray or overlap query.
+#[derive(Copy, Clone, Default, Debug)]
+pub struct QueryHit {
+    pub handle: VisibilityObjectHandle,
+    pub id: u64,
+    /// For ray queries, the distance along the ray to the hit. For overlap queries, the distance from
+    /// the center of the query's shape to the center of the object's bounding sphere.
+    pub distance: f32,
+}
+
+pub type QueryHits = Vec<QueryHit>;
+
 #[derive(Default)]
 pub struct VisibilityQuery {
     pub objects: VisibleObjects,
@@ -125,6 +143,9 @@ impl VisibilityWorld {
             AsyncCommand::SetObjectCullModel(object, cull_model) => {
                 inner.set_object_cull_model(object, cull_model);
             }
+            AsyncCommand::SetObjectOccluder(object, occluder) => {
+                inner.set_object_occluder(object, occluder);
+            }
             AsyncCommand::SetViewFrustumZone(view_frustum, zone) => {
                 inner.set_view_frustum_zone(view_frustum, zone);
             }
@@ -146,16 +167,10 @@ impl VisibilityWorld {
                     );
                 }
                 Projection::Orthographic(parameters) => {
-                    inner.set_view_frustum_orthographic(
-                        view_frustum,
-                        parameters.left(),
-                        parameters.right(),
-                        parameters.bottom(),
-                        parameters.top(),
-                        parameters.near_distance(),
-                        parameters.far_distance(),
-                        parameters.depth_range(),
-                    );
+                    inner.set_view_frustum_orthographic(view_frustum, parameters);
+                }
+                Projection::Custom(parameters) => {
+                    inner.set_view_frustum_custom(view_frustum, parameters);
                 }
                 Projection::Undefined => {
                     panic!("Cannot send `Undefined` projection on View Frustum.");
@@ -164,6 +179,15 @@ impl VisibilityWorld {
             AsyncCommand::DestroyViewFrustum(view_frustum) => {
                 inner.destroy_view_frustum(view_frustum);
             }
+            AsyncCommand::SetPortalPolygon(portal, polygon) => {
+                inner.set_portal_polygon(portal, polygon);
+            }
+            AsyncCommand::SetPortalZones(portal, zones) => {
+                inner.set_portal_zones(portal, zones);
+            }
+            AsyncCommand::DestroyPortal(portal) => {
+                inner.destroy_portal(portal);
+            }
             AsyncCommand::DestroyZone(zone) => {
                 inner.destroy_zone(zone);
             }
@@ -182,6 +206,7 @@ impl VisibilityWorld {
     }
 
     /// Queries visibility for a `ViewFrustum`. The `result` is a `VisibilityQuery`. This function is thread-safe.
+    /// Objects in other `Zones` are included when they can be seen through a chain of `Portals`.
     #[profiling::function]
     pub fn query_visibility(
         &self,
@@ -201,23 +226,670 @@ impl VisibilityWorld {
         let view_frustum_position = active_view_frustum.eye_position();
         let frustum = active_view_frustum.acquire_frustum().clone();
 
-        let chunks = self.inner.zones.get(zone).unwrap().chunks.clone();
-        for chunk in &chunks {
-            collect_visible_objects(chunk, view_frustum_position, &frustum, &mut result.objects)
+        let zone_handle = zone;
+        let zone = self.inner.zones.get(zone_handle).unwrap();
+        let planes = frustum_planes(&frustum);
+        zone.collect_visible_objects(view_frustum_position, &planes, &mut result.objects);
+
+        if !zone.portals.is_empty() {
+            let view_direction = if active_view_frustum.get_projection().is_orthographic() {
+                Some((active_view_frustum.look_at() - view_frustum_position).normalize())
+            } else {
+                None
+            };
+
+            let mut portal_views = Vec::new();
+            let viewer = PortalViewer {
+                eye_position: view_frustum_position,
+                view_direction,
+                far_plane: planes[ViewFrustum::FAR],
+            };
+            self.inner.collect_portal_views(
+                zone_handle,
+                &viewer,
+                &planes,
+                &mut vec![zone_handle],
+                &mut portal_views,
+            );
+
+            let first_portal_result = result.objects.len();
+            let mut visited_zones = FxHashSet::default();
+            let mut visited_zone_twice = false;
+            for (zone, planes) in &portal_views {
+                visited_zone_twice |= !visited_zones.insert(*zone);
+                let zone = self.inner.zones.get(*zone).unwrap();
+                zone.collect_visible_objects(view_frustum_position, planes, &mut result.objects);
+            }
+
+            // A zone seen through more than one portal may return the same object more than once.
+            if visited_zone_twice {
+                let mut seen = FxHashSet::default();
+                let mut index = 0;
+                result.objects.retain(|object| {
+                    index += 1;
+                    index <= first_portal_result || seen.insert(object.handle)
+                });
+            }
         }
 
         Ok(())
     }
 
+    /// Queries the objects in `zone` hit by `ray` within `max_distance`. The hits are appended to `result`,
+    /// which is then sorted nearest first. Objects with a cull `Model` created with `new_model_with_triangles`
+    /// are tested against its triangles, other objects are tested against their bounding sphere.
+    /// This function is thread-safe.
+    #[profiling::function]
+    pub fn query_ray(
+        &self,
+        zone: ZoneHandle,
+        ray: &Ray,
+        max_distance: f32,
+        result: &mut QueryHits,
+    ) {
+        let zone = self.inner.zones.get(zone).unwrap();
+        zone.tree.query(
+            |aabb| {
+                ray.intersect_aabb(aabb)
+                    .is_some_and(|distance| distance <= max_distance)
+            },
+            |object, sphere| {
+                let mut distance = match ray.intersect_sphere(sphere) {
+                    Some(distance) if distance <= max_distance => Some(distance),
+                    _ => return,
+                };
+
+                let mut closest_triangle: Option<f32> = None;
+                if self
+                    .inner
+                    .for_each_object_triangle(object.handle, |triangle| {
+                        if let Some(triangle_distance) = ray.intersect_triangle(triangle) {
+                            closest_triangle =
+                                Some(closest_triangle.map_or(triangle_distance, |closest| {
+                                    closest.min(triangle_distance)
+                                }));
+                        }
+                        true
+                    })
+                {
+                    distance = closest_triangle.filter(|distance| *distance <= max_distance);
+                }
+
+                if let Some(distance) = distance {
+                    result.push(QueryHit {
+                        handle: object.handle,
+                        id: object.id,
+                        distance,
+                    });
+                }
+            },
+        );
+
+        result.sort_by(|a, b| {
+            a.distance
+                .partial_cmp(&b.distance)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+    }
+
+    /// Queries the objects in `zone` overlapping `sphere`. The hits are appended to `result`. Objects with a
+    /// cull `Model` created with `new_model_with_triangles` are tested against its triangles, other objects
+    /// are tested against their bounding sphere. This function is thread-safe.
+    #[profiling::function]
+    pub fn query_sphere_overlap(
+        &self,
+        zone: ZoneHandle,
+        sphere: &BoundingSphere,
+        result: &mut QueryHits,
+    ) {
+        let zone = self.inner.zones.get(zone).unwrap();
+        zone.tree.query(
+            |aabb| aabb.intersects_sphere(sphere),
+            |object, object_sphere| {
+                if !sphere.intersects_sphere(object_sphere) {
+                    return;
+                }
+
+                let mut overlaps = false;
+                let exact = self
+                    .inner
+                    .for_each_object_triangle(object.handle, |triangle| {
+                        overlaps = triangle.intersects_sphere(sphere);
+                        !overlaps
+                    });
+
+                if overlaps || !exact {
+                    result.push(QueryHit {
+                        handle: object.handle,
+                        id: object.id,
+                        distance: sphere.position.distance(object_sphere.position),
+                    });
+                }
+            },
+        );
+    }
+
+    /// Queries the objects in `zone` overlapping `aabb`. The hits are appended to `result`. Objects with a
+    /// cull `Model` created with `new_model_with_triangles` are tested against its triangles, other objects
+    /// are tested against their bounding sphere. This function is thread-safe.
+    #[profiling::function]
+    pub fn query_aabb_overlap(
+        &self,
+        zone: ZoneHandle,
+        aabb: &AxisAlignedBoundingBox,
+        result: &mut QueryHits,
+    ) {
+        let zone = self.inner.zones.get(zone).unwrap();
+        zone.tree.query(
+            |node_aabb| node_aabb.intersects_aabb(aabb),
+            |object, object_sphere| {
+                if !aabb.intersects_sphere(object_sphere) {
+                    return;
+                }
+
+                let mut overlaps = false;
+                let exact = self
+                    .inner
+                    .for_each_object_triangle(object.handle, |triangle| {
+                        overlaps = triangle.intersects_aabb(aabb);
+                        !overlaps
+                    });
+
+                if overlaps || !exact {
+                    result.push(QueryHit {
+                        handle: object.handle,
+                        id: object.id,
+                        distance: aabb.center().distance(object_sphere.position),
+                    });
+                }
+            },
+        );
+    }
+
+    /// Rasterizes the occluders in `objects` into the `buffer`, nearest first, until the `buffer` is full.
+    /// Call `OcclusionBuffer::clear` with the `ViewFrustum` before rasterizing occluders for a new frame.
+    /// This function is thread-safe.
+    #[profiling::function]
+    pub fn rasterize_occluders(
+        &self,
+        objects: &VisibleObjects,
+        buffer: &mut OcclusionBuffer,
+    ) {
+        let mut occluders = objects
+            .iter()
+            .filter(|object| {
+                self.inner
+                    .visibility_object(object.handle)
+                    .is_some_and(|object| object.occluder)
+            })
+            .collect::<Vec<_>>();
+
+        occluders.sort_by(|a, b| {
+            a.distance_from_view_frustum
+                .partial_cmp(&b.distance_from_view_frustum)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+
+        for occluder in occluders {
+            if buffer.is_full() {
+                break;
+            }
+
+            self.inner
+                .for_each_object_triangle(occluder.handle, |triangle| {
+                    buffer.rasterize_triangle(triangle);
+                    !buffer.is_full()
+                });
+        }
+    }
+
+    /// Removes the objects hidden behind the occluders in the `buffer` from `objects`. Objects are
+    /// tested with their oriented bounding box, so an occluder never hides itself. This function is thread-safe.
+    #[profiling::function]
+    pub fn remove_occluded_objects(
+        &self,
+        buffer: &OcclusionBuffer,
+        objects: &mut VisibleObjects,
+    ) {
+        if buffer.triangle_count() == 0 {
+            return;
+        }
+
+        objects.retain(|object| {
+            !self
+                .inner
+                .object_cull_box(object.handle)
+                .is_some_and(|cull_box| buffer.is_occluded(&cull_box))
+        });
+    }
+
     /// Queries shadow casters for a `ViewFrustum` representing a light. The `result` is a `VisibilityQuery`.
     /// The objects in `result` are able to cast shadows into at least one of the `shadowed` frustums.
     /// This function is thread-safe.
+    #[profiling::function]
     pub fn query_shadow_casters(
         &self,
-        _light: ViewFrustumHandle,
-        _shadowed: &[ViewFrustumHandle],
-        _result: &mut VisibilityQuery,
-    ) {
-        unimplemented!();
+        light: ViewFrustumHandle,
+        shadowed: &[ViewFrustumHandle],
+        result: &mut VisibilityQuery,
+    ) -> Result<(), QueryError> {
+        let zone = *self
+            .inner
+            .view_frustum_zones
+            .get(light)
+            .ok_or(QueryError::NoViewFrustumZone)?;
+
+        let light_view_frustum = self.inner.view_frustums.get(light).unwrap();
+        let light_position = light_view_frustum.eye_position();
+        let light_planes = frustum_planes(&light_view_frustum.acquire_frustum());
+
+        // Orthographic lights cast shadows along the view direction, perspective lights
+        // cast shadows away from the eye. Shadows end at the light's far plane.
+        let light_direction = if light_view_frustum.get_projection().is_orthographic() {
+            Some((light_view_frustum.look_at() - light_position).normalize())
+        } else {
+            None
+        };
+        let light_far_distance = light_view_frustum.get_projection().far_distance();
+
+        let shadowed_planes = shadowed
+            .iter()
+            .filter(|view_frustum| self.inner.view_frustum_zones.get(**view_frustum) == Some(&zone))
+            .map(|view_frustum| {
+                let view_frustum = self.inner.view_frustums.get(*view_frustum).unwrap();
+                frustum_planes(&view_frustum.acquire_frustum())
+            })
+            .collect::<Vec<_>>();
+
+        if shadowed_planes.is_empty() {
+            return Ok(());
+        }
+
+        let mut push_if_shadow_caster = |handle, id, sphere: &BoundingSphere| {
+            let to_object = sphere.position - light_position;
+            let (direction, length) = if let Some(direction) = light_direction {
+                (direction, light_far_distance - to_object.dot(direction))
+            } else {
+                let distance = to_object.length();
+                let direction = if distance > 0. {
+                    to_object / distance
+                } else {
+                    Vec3::ZERO
+                };
+                (direction, light_far_distance - distance)
+            };
+
+            let shadow_end = sphere.position + direction * length.max(0.);
+            if shadowed_planes
+                .iter()
+                .any(|planes| !is_capsule_outside(planes, sphere, shadow_end))
+            {
+                result
+                    .objects
+                    .push(VisibilityResult::new(handle, id, light_position, *sphere));
+            }
+        };
+
+        let zone = self.inner.zones.get(zone).unwrap();
+        if zone.use_tree() {
+            zone.tree.query_planes(&light_planes, |object, sphere| {
+                push_if_shadow_caster(object.handle, object.id, sphere)
+            });
+        } else {
+            let mut candidates = VisibleObjects::default();
+            zone.collect_visible_objects(light_position, &light_planes, &mut candidates);
+
+            for candidate in candidates {
+                let (chunk_idx, in_chunk_idx) = *zone.objects.get(candidate.handle).unwrap();
+                let sphere = zone.chunks[chunk_idx].get(in_chunk_idx);
+                push_if_shadow_caster(candidate.handle, candidate.id, &sphere);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// The shadow of a sphere is approximated by a capsule swept from the sphere along the light direction.
+fn is_capsule_outside(
+    planes: &[Vec4; 6],
+    sphere: &BoundingSphere,
+    end: Vec3,
+) -> bool {
+    planes.iter().any(|plane| {
+        let normal = plane.truncate();
+        plane.w + normal.dot(sphere.position) + sphere.radius <= 0.
+            && plane.w + normal.dot(end) + sphere.radius <= 0.
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::frustum_culling::CullBox;
+    use crate::internal::Zone;
+    use crate::{DepthRange, OrthographicParameters};
+    use crate::{PolygonSoup, PolygonSoupIndex};
+    use glam::Quat;
+
+    // Objects on a grid of `(2 * half_size)^2` cells, at heights that put some casters
+    // above the camera's frustum and some below it.
+    fn assert_shadow_casters_match_brute_force(half_size: i32) {
+        let mut world = VisibilityWorld::new();
+        let inner = &mut world.inner;
+        let zone = inner.new_zone();
+
+        let light = inner.new_view_frustum();
+        inner.set_view_frustum_zone(light, Some(zone));
+        inner.set_view_frustum_orthographic(
+            light,
+            OrthographicParameters::new(-60., 60., -60., 60., 0.1, 200., DepthRange::Normal),
+        );
+        inner.set_view_frustum_transforms(light, Vec3::new(0., 100., 0.), Vec3::ZERO, Vec3::Z);
+
+        let camera = inner.new_view_frustum();
+        inner.set_view_frustum_zone(camera, Some(zone));
+        inner.set_view_frustum_perspective(
+            camera,
+            std::f32::consts::FRAC_PI_4,
+            1.,
+            0.1,
+            30.,
+            DepthRange::Normal,
+        );
+        inner.set_view_frustum_transforms(camera, Vec3::new(0., 5., -20.), Vec3::ZERO, Vec3::Y);
+
+        let mut objects = Vec::new();
+        for x in -half_size..half_size {
+            for z in -half_size..half_size {
+                let translation =
+                    Vec3::new(x as f32 * 3., ((x * z) % 7) as f32 * 4., z as f32 * 3.);
+                let object = inner.new_object();
+                inner.set_object_id(object, objects.len() as u64);
+                inner.set_object_zone(object, Some(zone));
+                inner.set_object_transform(
+                    object,
+                    Transform {
+                        translation,
+                        rotation: Quat::IDENTITY,
+                        scale: Vec3::ONE,
+                    },
+                );
+                objects.push((object, translation));
+            }
+        }
+
+        let mut result = VisibilityQuery::default();
+        assert!(world
+            .query_shadow_casters(light, &[camera], &mut result)
+            .is_ok());
+        let mut casters = result
+            .objects
+            .iter()
+            .map(|object| object.handle)
+            .collect::<Vec<_>>();
+        casters.sort();
+
+        let light_view_frustum = world.inner.view_frustum(light).unwrap();
+        let light_planes = frustum_planes(&light_view_frustum.acquire_frustum());
+        let light_direction =
+            (light_view_frustum.look_at() - light_view_frustum.eye_position()).normalize();
+        let light_far_distance = light_view_frustum.get_projection().far_distance();
+        let camera_planes =
+            frustum_planes(&world.inner.view_frustum(camera).unwrap().acquire_frustum());
+
+        let mut expected = objects
+            .iter()
+            .filter(|(_, translation)| {
+                let sphere = BoundingSphere::new(*translation, 1.42);
+                let cull_box = CullBox::from_bounding_sphere(&sphere);
+                let in_light = light_planes.iter().all(|plane| {
+                    plane.w + plane.truncate().dot(sphere.position) + sphere.radius > 0.
+                }) && !cull_box.is_outside(&light_planes, 0b111111);
+
+                let to_object = sphere.position - light_view_frustum.eye_position();
+                let length = light_far_distance - to_object.dot(light_direction);
+                let shadow_end = sphere.position + light_direction * length.max(0.);
+                in_light && !is_capsule_outside(&camera_planes, &sphere, shadow_end)
+            })
+            .map(|(handle, _)| *handle)
+            .collect::<Vec<_>>();
+        expected.sort();
+
+        assert!(!expected.is_empty());
+        assert!(expected.len() < objects.len());
+        assert_eq!(casters, expected);
+    }
+
+    #[test]
+    fn query_shadow_casters_matches_brute_force_in_chunks() {
+        assert!(((2 * 15) * (2 * 15)) < Zone::MIN_OBJECTS_FOR_TREE);
+        assert_shadow_casters_match_brute_force(15);
+    }
+
+    #[test]
+    fn query_shadow_casters_matches_brute_force_in_tree() {
+        assert!(((2 * 20) * (2 * 20)) >= Zone::MIN_OBJECTS_FOR_TREE);
+        assert_shadow_casters_match_brute_force(20);
+    }
+
+    fn new_small_object(
+        inner: &mut VisibilityWorldInternal,
+        zone: ZoneHandle,
+        translation: Vec3,
+    ) -> VisibilityObjectHandle {
+        let object = inner.new_object();
+        inner.set_object_zone(object, Some(zone));
+        inner.set_object_transform(
+            object,
+            Transform {
+                translation,
+                rotation: Quat::IDENTITY,
+                scale: Vec3::splat(0.1),
+            },
+        );
+        object
+    }
+
+    fn doorway(z: f32) -> Vec<Vec3> {
+        vec![
+            Vec3::new(-1., -1., z),
+            Vec3::new(1., -1., z),
+            Vec3::new(1., 1., z),
+            Vec3::new(-1., 1., z),
+        ]
+    }
+
+    fn visible_objects(
+        world: &VisibilityWorld,
+        view_frustum: ViewFrustumHandle,
+    ) -> Vec<VisibilityObjectHandle> {
+        let mut result = VisibilityQuery::default();
+        assert!(world.query_visibility(view_frustum, &mut result).is_ok());
+        let mut visible = result
+            .objects
+            .iter()
+            .map(|object| object.handle)
+            .collect::<Vec<_>>();
+        visible.sort();
+        visible
+    }
+
+    fn sorted(mut handles: Vec<VisibilityObjectHandle>) -> Vec<VisibilityObjectHandle> {
+        handles.sort();
+        handles
+    }
+
+    #[test]
+    fn query_visibility_through_portals() {
+        let mut world = VisibilityWorld::new();
+        let commands = world.new_async_command_sender();
+        let inner = &mut world.inner;
+
+        // A corridor of rooms along -Z, connected by doorways at z = -10 and z = -20.
+        let room_a = inner.new_zone();
+        let room_b = inner.new_zone();
+        let room_c = inner.new_zone();
+
+        let in_a = new_small_object(inner, room_a, Vec3::new(0., 0., -5.));
+        let in_b = new_small_object(inner, room_b, Vec3::new(0., 0., -15.));
+        let in_b_beside_doorway = new_small_object(inner, room_b, Vec3::new(10.5, 0., -15.));
+        let in_c = new_small_object(inner, room_c, Vec3::new(0.5, 0.5, -25.));
+
+        let camera = inner.new_view_frustum();
+        inner.set_view_frustum_zone(camera, Some(room_a));
+        inner.set_view_frustum_perspective(
+            camera,
+            std::f32::consts::FRAC_PI_2,
+            1.,
+            0.1,
+            100.,
+            DepthRange::Normal,
+        );
+        inner.set_view_frustum_transforms(camera, Vec3::ZERO, -Vec3::Z, Vec3::Y);
+
+        let portal_ab = inner.new_portal();
+        let portal_bc = inner.new_portal();
+        commands
+            .send(AsyncCommand::SetPortalPolygon(portal_ab, doorway(-10.)))
+            .unwrap();
+        commands
+            .send(AsyncCommand::SetPortalPolygon(portal_bc, doorway(-20.)))
+            .unwrap();
+
+        // A portal that does not connect any zones is not seen through.
+        world.update();
+        assert_eq!(visible_objects(&world, camera), vec![in_a]);
+
+        commands
+            .send(AsyncCommand::QueuedCommands(vec![
+                AsyncCommand::SetPortalZones(portal_ab, Some((room_a, room_b))),
+                AsyncCommand::SetPortalZones(portal_bc, Some((room_b, room_c))),
+            ]))
+            .unwrap();
+        world.update();
+
+        // The object beside the doorway is inside the camera's frustum, but not the view through the portal.
+        assert_eq!(
+            visible_objects(&world, camera),
+            sorted(vec![in_a, in_b, in_c])
+        );
+
+        // Portals are two-sided.
+        world.inner.set_view_frustum_transforms(
+            camera,
+            Vec3::new(0., 0., -30.),
+            Vec3::ZERO,
+            Vec3::Y,
+        );
+        world.inner.set_view_frustum_zone(camera, Some(room_c));
+        assert_eq!(
+            visible_objects(&world, camera),
+            sorted(vec![in_a, in_b, in_c])
+        );
+
+        // Looking away from the doorway.
+        world.inner.set_view_frustum_transforms(
+            camera,
+            Vec3::new(0., 0., -30.),
+            Vec3::new(0., 0., -40.),
+            Vec3::Y,
+        );
+        assert!(visible_objects(&world, camera).is_empty());
+
+        world
+            .inner
+            .set_view_frustum_transforms(camera, Vec3::ZERO, -Vec3::Z, Vec3::Y);
+        world.inner.set_view_frustum_zone(camera, Some(room_a));
+
+        // A second doorway into room B sees the object beside the first doorway.
+        // Room B and C are seen twice, but their objects are only returned once.
+        let portal_ab_side = world.inner.new_portal();
+        world.inner.set_portal_polygon(
+            portal_ab_side,
+            doorway(-10.)
+                .iter()
+                .map(|vertex| *vertex + Vec3::new(7., 0., 0.))
+                .collect(),
+        );
+        world
+            .inner
+            .set_portal_zones(portal_ab_side, Some((room_a, room_b)));
+        assert_eq!(
+            visible_objects(&world, camera),
+            sorted(vec![in_a, in_b, in_b_beside_doorway, in_c])
+        );
+
+        // A cycle back into room A does not see room A's objects twice.
+        let portal_ca = world.inner.new_portal();
+        world.inner.set_portal_polygon(portal_ca, doorway(-28.));
+        world
+            .inner
+            .set_portal_zones(portal_ca, Some((room_c, room_a)));
+        assert_eq!(
+            visible_objects(&world, camera),
+            sorted(vec![in_a, in_b, in_b_beside_doorway, in_c])
+        );
+
+        commands
+            .send(AsyncCommand::QueuedCommands(vec![
+                AsyncCommand::DestroyPortal(portal_ab_side),
+                AsyncCommand::DestroyPortal(portal_ca),
+                AsyncCommand::SetPortalZones(portal_bc, None),
+            ]))
+            .unwrap();
+        world.update();
+        assert_eq!(visible_objects(&world, camera), sorted(vec![in_a, in_b]));
+        assert!(world.inner.zones[room_c].portals.is_empty());
+        assert_eq!(world.inner.zones[room_b].portals, vec![portal_ab]);
+
+        commands
+            .send(AsyncCommand::DestroyPortal(portal_ab))
+            .unwrap();
+        world.update();
+        assert_eq!(visible_objects(&world, camera), vec![in_a]);
+        assert!(world.inner.zones[room_a].portals.is_empty());
+    }
+
+    #[test]
+    fn query_ray_uses_triangles_only_when_kept() {
+        let mut world = VisibilityWorld::new();
+        let inner = &mut world.inner;
+        let zone = inner.new_zone();
+
+        // A single triangle in the lower left of its bounds, so a ray through the upper
+        // right hits the bounds but misses the triangle.
+        let triangle = || PolygonSoup {
+            vertex_positions: vec![
+                Vec3::new(-1., -1., 0.),
+                Vec3::new(1., -1., 0.),
+                Vec3::new(-1., 1., 0.),
+            ],
+            index: PolygonSoupIndex::None,
+        };
+
+        let bounds_only = inner.new_model(triangle());
+        let object = new_small_object(inner, zone, Vec3::ZERO);
+        inner.set_object_transform(object, Transform::default());
+        inner.set_object_cull_model(object, Some(bounds_only));
+
+        let ray = Ray::new(Vec3::new(0.5, 0.5, 5.), -Vec3::Z);
+        let mut hits = QueryHits::default();
+        world.query_ray(zone, &ray, 100., &mut hits);
+        assert_eq!(hits.len(), 1);
+
+        let inner = &mut world.inner;
+        let with_triangles = inner.new_model_with_triangles(triangle());
+        inner.set_object_cull_model(object, Some(with_triangles));
+
+        hits.clear();
+        world.query_ray(zone, &ray, 100., &mut hits);
+        assert!(hits.is_empty());
+
+        hits.clear();
+        let ray = Ray::new(Vec3::new(-0.5, -0.5, 5.), -Vec3::Z);
+        world.query_ray(zone, &ray, 100., &mut hits);
+        assert_eq!(hits.len(), 1);
+        assert!((hits[0].distance - 5.).abs() < 0.0001);
     }
 }
@@ -15,12 +15,12 @@ pub struct VisibleBounds {
 impl VisibleBounds {
     pub fn from(mesh_data: PolygonSoup) -> Self {
         let hash = mesh_data.calculate_hash();
-        VisibleBounds::new(hash, mesh_data)
+        VisibleBounds::new(hash, &mesh_data)
     }
 
     pub(crate) fn new(
         hash: u64,
-        mesh_data: PolygonSoup,
+        mesh_data: &PolygonSoup,
     ) -> Self {
         let mut min = Vec3::new(f32::MAX, f32::MAX, f32::MAX);
         let mut max = Vec3::new(f32::MIN, f32::MIN, f32::MIN);
@@ -57,7 +57,7 @@ impl VisibleBounds {
             hash,
             aabb,
             bounding_sphere,
-            obb: Default::default(), // TODO(dvd): Calculate an OBB.
+            obb: OrientedBoundingBox::from_points(&mesh_data.vertex_positions),
         }
     }
 }
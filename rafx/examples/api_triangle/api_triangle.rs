@@ -154,6 +154,7 @@ fn run() -> RafxResult<()> {
             "shader.vert.spv",
             "shader.vert.gles2",
             "shader.vert.gles3",
+            "shader.vert.wgsl",
         )?;
 
         let frag_shader_package = load_shader_packages(
@@ -163,6 +164,7 @@ fn run() -> RafxResult<()> {
             "shader.frag.spv",
             "shader.frag.gles2",
             "shader.frag.gles3",
+            "shader.frag.wgsl",
         )?;
 
         let vert_shader_module =
@@ -290,6 +292,7 @@ fn run() -> RafxResult<()> {
             sample_count: RafxSampleCount::SampleCount1,
             depth_stencil_format: None,
             primitive_topology: RafxPrimitiveTopology::TriangleList,
+            specialization_constants: &[],
             debug_name: None,
         })?;
 
@@ -497,6 +500,7 @@ fn load_shader_packages(
     _vk_spv_file: &str,
     _gles2_src_file: &str,
     _gles3_src_file: &str,
+    _webgpu_src_file: &str,
 ) -> RafxResult<RafxShaderPackage> {
     let mut _package = RafxShaderPackage::default();
 
@@ -535,5 +539,12 @@ fn load_shader_packages(
         _package.gles3 = Some(RafxShaderPackageGles3::Src(gles3_src));
     }
 
+    #[cfg(feature = "rafx-webgpu")]
+    {
+        let webgpu_path = _base_path.join(_webgpu_src_file);
+        let webgpu_src = std::fs::read_to_string(webgpu_path)?;
+        _package.webgpu = Some(RafxShaderPackageWebGpu::Src(webgpu_src));
+    }
+
     Ok(_package)
 }
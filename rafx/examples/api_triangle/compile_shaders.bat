@@ -8,4 +8,5 @@ cargo run --package rafx-shader-processor -- ^
 --dx12-generated-src-path processed_shaders ^
 --metal-generated-src-path processed_shaders ^
 --gles2-generated-src-path processed_shaders ^
---gles3-generated-src-path processed_shaders
\ No newline at end of file
+--gles3-generated-src-path processed_shaders ^
+--webgpu-generated-src-path processed_shaders
\ No newline at end of file
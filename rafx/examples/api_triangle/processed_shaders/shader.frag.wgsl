@@ -0,0 +1,22 @@
+struct UniformData {
+    uniform_color: vec4<f32>,
+}
+
+var<private> out_color: vec4<f32>;
+@group(0) @binding(0) 
+var<uniform> uniform_data: UniformData;
+var<private> in_color_1: vec4<f32>;
+
+fn main_1() {
+    let _e9 = uniform_data.uniform_color;
+    out_color = _e9;
+    return;
+}
+
+@fragment 
+fn main(@location(0) in_color: vec4<f32>) -> @location(0) vec4<f32> {
+    in_color_1 = in_color;
+    main_1();
+    let _e3 = out_color;
+    return _e3;
+}
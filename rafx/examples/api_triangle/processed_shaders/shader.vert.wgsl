@@ -0,0 +1,36 @@
+struct gl_PerVertex {
+    @builtin(position) gl_Position: vec4<f32>,
+    gl_PointSize: f32,
+    gl_ClipDistance: array<f32,1u>,
+    gl_CullDistance: array<f32,1u>,
+}
+
+struct VertexOutput {
+    @location(0) member: vec4<f32>,
+    @builtin(position) gl_Position: vec4<f32>,
+}
+
+var<private> out_color: vec4<f32>;
+var<private> in_color_1: vec4<f32>;
+var<private> perVertexStruct: gl_PerVertex = gl_PerVertex(vec4<f32>(0.0, 0.0, 0.0, 1.0), 1.0, array<f32,1u>(0.0), array<f32,1u>(0.0));
+var<private> pos_1: vec4<f32>;
+
+fn main_1() {
+    let _e10 = in_color_1;
+    out_color = _e10;
+    let _e11 = pos_1;
+    perVertexStruct.gl_Position = _e11;
+    return;
+}
+
+@vertex 
+fn main(@location(1) in_color: vec4<f32>, @location(0) pos: vec4<f32>) -> VertexOutput {
+    in_color_1 = in_color;
+    pos_1 = pos;
+    main_1();
+    let _e8 = perVertexStruct.gl_Position.y;
+    perVertexStruct.gl_Position.y = -(_e8);
+    let _e10 = out_color;
+    let _e11 = perVertexStruct.gl_Position;
+    return VertexOutput(_e10, _e11);
+}
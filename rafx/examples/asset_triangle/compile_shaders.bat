@@ -8,5 +8,6 @@ cargo run --package rafx-shader-processor -- ^
 --metal-generated-src-path processed_shaders ^
 --gles2-generated-src-path processed_shaders ^
 --gles3-generated-src-path processed_shaders ^
+--webgpu-generated-src-path processed_shaders ^
 --cooked-shaders-path assets ^
 --package-all
@@ -216,6 +216,7 @@ fn run() -> RafxResult<()> {
             sample_count: RafxSampleCount::SampleCount1,
             depth_stencil_format: None,
             primitive_topology: RafxPrimitiveTopology::TriangleList,
+            specialization_constants: &[],
             debug_name: None,
         })?;
 
@@ -218,6 +218,7 @@ fn run() -> RafxResult<()> {
                     view,
                     proj: projection.as_rh_mat4(),
                     depth_range: RenderViewDepthRange::from_projection(&projection),
+                    camera_lens: None,
                     render_phase_mask: main_camera_phase_mask,
                     render_feature_mask: main_camera_feature_mask,
                     render_feature_flag_mask: RenderFeatureFlagMask::empty(),